@@ -0,0 +1,103 @@
+//! Benchmarks comparing the linked collections against their `std` counterparts.
+//!
+//! Run with `cargo bench --features std` (add `stats` to print node allocation counts).
+
+#![feature(test)]
+
+extern crate test;
+
+use std::collections::VecDeque;
+
+use libx::collections::{list::doubly_linked::List, stack::linked_list::Stack};
+use test::{Bencher, black_box};
+
+const ELEMENTS: usize = 1_000;
+
+#[bench]
+fn list_push_back(b: &mut Bencher) {
+    b.iter(|| {
+        let mut list = List::new();
+        for i in 0..ELEMENTS {
+            list.push_back(black_box(i));
+        }
+        list
+    });
+}
+
+#[bench]
+fn vec_deque_push_back(b: &mut Bencher) {
+    b.iter(|| {
+        let mut deque = VecDeque::new();
+        for i in 0..ELEMENTS {
+            deque.push_back(black_box(i));
+        }
+        deque
+    });
+}
+
+#[bench]
+fn list_push_pop_front(b: &mut Bencher) {
+    let mut list = List::new();
+    b.iter(|| {
+        for i in 0..ELEMENTS {
+            list.push_front(black_box(i));
+        }
+        while let Some(value) = list.pop_front() {
+            black_box(value);
+        }
+    });
+}
+
+#[bench]
+fn vec_deque_push_pop_front(b: &mut Bencher) {
+    let mut deque = VecDeque::new();
+    b.iter(|| {
+        for i in 0..ELEMENTS {
+            deque.push_front(black_box(i));
+        }
+        while let Some(value) = deque.pop_front() {
+            black_box(value);
+        }
+    });
+}
+
+#[bench]
+fn stack_push_pop(b: &mut Bencher) {
+    let mut stack = Stack::new();
+    b.iter(|| {
+        for i in 0..ELEMENTS {
+            stack.push(black_box(i));
+        }
+        while let Some(value) = stack.pop() {
+            black_box(value);
+        }
+    });
+}
+
+#[bench]
+fn vec_push_pop(b: &mut Bencher) {
+    let mut vec = Vec::new();
+    b.iter(|| {
+        for i in 0..ELEMENTS {
+            vec.push(black_box(i));
+        }
+        while let Some(value) = vec.pop() {
+            black_box(value);
+        }
+    });
+}
+
+#[cfg(feature = "stats")]
+#[bench]
+fn list_node_allocations(b: &mut Bencher) {
+    let before = libx::stats::snapshot();
+    b.iter(|| {
+        let mut list = List::new();
+        for i in 0..ELEMENTS {
+            list.push_back(black_box(i));
+        }
+        list
+    });
+    let delta = libx::stats::snapshot().since(&before);
+    assert!(delta.node_allocations >= ELEMENTS);
+}
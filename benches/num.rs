@@ -0,0 +1,91 @@
+//! Benchmarks for the numeric traits and `Number` parsing and formatting.
+//!
+//! Run with `cargo bench --features std`.
+
+#![feature(test)]
+
+extern crate test;
+
+use std::fmt::Write;
+
+use libx::num::{
+    Number,
+    traits::{FloatingPoint, FloatingPointRoundingRule},
+};
+use test::{Bencher, black_box};
+
+const INPUTS: [f64; 8] = [0.25, 1.5, 2.0, 9.75, 144.0, 1e-3, 12_345.678, 1e9];
+
+#[bench]
+fn f64_square_root(b: &mut Bencher) {
+    b.iter(|| {
+        INPUTS
+            .iter()
+            .map(|&x| black_box(x).square_root())
+            .sum::<f64>()
+    });
+}
+
+#[bench]
+fn f32_square_root(b: &mut Bencher) {
+    b.iter(|| {
+        INPUTS
+            .iter()
+            .map(|&x| black_box(x as f32).square_root())
+            .sum::<f32>()
+    });
+}
+
+#[bench]
+fn f64_rounded(b: &mut Bencher) {
+    b.iter(|| INPUTS.iter().map(|&x| black_box(x).rounded()).sum::<f64>());
+}
+
+#[bench]
+fn f64_rounded_with_rules(b: &mut Bencher) {
+    let rules = [
+        FloatingPointRoundingRule::AwayFromZero,
+        FloatingPointRoundingRule::Down,
+        FloatingPointRoundingRule::ToNearestOrAwayFromZero,
+        FloatingPointRoundingRule::ToNearestOrEven,
+        FloatingPointRoundingRule::TowardZero,
+        FloatingPointRoundingRule::Up,
+    ];
+
+    b.iter(|| {
+        let mut total = 0.0;
+        for &rule in &rules {
+            for &x in &INPUTS {
+                total += black_box(x).rounded_with(rule);
+            }
+        }
+        total
+    });
+}
+
+#[bench]
+fn number_parse(b: &mut Bencher) {
+    let inputs = ["true", "-42", "255", "65535", "3.25", "1e9"];
+    b.iter(|| {
+        for input in inputs {
+            black_box(black_box(input).parse::<Number>().ok());
+        }
+    });
+}
+
+#[bench]
+fn number_format(b: &mut Bencher) {
+    let numbers = [
+        Number::Int(-42),
+        Number::UInt32(65_535),
+        Number::Double(12_345.678),
+    ];
+    let mut buffer = String::with_capacity(64);
+    b.iter(|| {
+        buffer.clear();
+        for number in &numbers {
+            write!(buffer, "{number}").ok();
+        }
+        black_box(buffer.len())
+    });
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libx::testing::{ListOperation, check_list_model};
+
+fuzz_target!(|data: &[u8]| {
+    if let Err(error) = check_list_model(&ListOperation::decode(data)) {
+        panic!("{error}");
+    }
+});
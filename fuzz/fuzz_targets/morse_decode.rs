@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use libx::testing::check_morse_round_trip;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = core::str::from_utf8(data) {
+        if let Err(error) = check_morse_round_trip(input) {
+            panic!("{error}");
+        }
+    }
+});
@@ -0,0 +1,636 @@
+//! Calendar-independent dates and the calendars that name them.
+//!
+//! A [`Date`] is a single day on a continuous timeline, identified by its Julian day
+//! number. A [`Calendar`] splits dates into [`DateComponents`] such as year, month and
+//! day, and builds dates back from them. The Gregorian and ISO 8601 week-based
+//! calendars are always available; the tabular Islamic calendar is enabled with the
+//! `islamic-calendar` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::calendar::{Calendar, DateComponents, Weekday};
+//!
+//! let gregorian = Calendar::gregorian();
+//! let date = gregorian.date(&DateComponents::ymd(2024, 12, 30)).unwrap();
+//!
+//! let iso = Calendar::iso8601().date_components(date);
+//! assert_eq!(iso.year_for_week_of_year, Some(2025));
+//! assert_eq!(iso.week_of_year, Some(1));
+//! assert_eq!(iso.weekday, Some(Weekday::Monday));
+//!
+//! assert_eq!(Calendar::iso8601().date(&iso), Some(date));
+//! ```
+
+pub mod business;
+mod gregorian;
+#[cfg(feature = "islamic-calendar")]
+mod islamic;
+
+pub use business::{BusinessCalendar, HolidaySet, Weekend};
+
+use core::fmt;
+
+use crate::locale::Locale;
+
+/// The largest Julian day number a [`Date`] may have, and the negation of the smallest.
+const MAX_JULIAN_DAY: i64 = 1 << 40;
+
+/// The largest year magnitude [`Calendar::date`] converts. Every such year in every
+/// calendar lies within a few times [`MAX_JULIAN_DAY`], so the conversion cannot overflow.
+const MAX_YEAR: u64 = 1 << 32;
+
+/// A day on the proleptic timeline, independent of any calendar.
+///
+/// Dates are identified by their Julian day number: the number of days since Monday,
+/// 1 January 4713 BC in the proleptic Julian calendar. They range from [`Date::MIN`] to
+/// [`Date::MAX`], about three billion years either side of that epoch, so every calendar
+/// can convert them without overflow.
+///
+/// # Examples
+///
+/// ```
+/// use libx::calendar::Date;
+///
+/// let millennium = Date::from_julian_day(2_451_545);
+///
+/// assert_eq!(millennium.adding_days(1).julian_day(), 2_451_546);
+/// assert_eq!(millennium.days_until(Date::from_julian_day(2_451_555)), 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    julian_day: i64,
+}
+
+impl Date {
+    /// The earliest supported date, with Julian day number -2<sup>40</sup>.
+    pub const MIN: Self = Self {
+        julian_day: -MAX_JULIAN_DAY,
+    };
+
+    /// The latest supported date, with Julian day number 2<sup>40</sup>.
+    pub const MAX: Self = Self {
+        julian_day: MAX_JULIAN_DAY,
+    };
+
+    /// Creates the date with the given Julian day number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the date would be outside the range from [`Date::MIN`] to
+    /// [`Date::MAX`].
+    #[must_use]
+    pub const fn from_julian_day(julian_day: i64) -> Self {
+        match Self::checked_from_julian_day(julian_day) {
+            Some(date) => date,
+            None => panic!("Julian day out of range"),
+        }
+    }
+
+    /// Creates the date with the given Julian day number, or returns `None` if it would
+    /// be outside the range from [`Date::MIN`] to [`Date::MAX`].
+    #[must_use]
+    pub const fn checked_from_julian_day(julian_day: i64) -> Option<Self> {
+        if julian_day.unsigned_abs() <= MAX_JULIAN_DAY.unsigned_abs() {
+            Some(Self { julian_day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the Julian day number of the date.
+    #[must_use]
+    pub const fn julian_day(self) -> i64 {
+        self.julian_day
+    }
+
+    /// Returns the date `days` days after this one, or before it if `days` is negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would be outside the range from [`Date::MIN`] to
+    /// [`Date::MAX`]; see [`checked_adding_days`](Self::checked_adding_days).
+    #[must_use]
+    pub const fn adding_days(self, days: i64) -> Self {
+        match self.checked_adding_days(days) {
+            Some(date) => date,
+            None => panic!("date out of range"),
+        }
+    }
+
+    /// Returns the date `days` days after this one, or before it if `days` is negative,
+    /// or `None` if that would be outside the range from [`Date::MIN`] to [`Date::MAX`].
+    #[must_use]
+    pub const fn checked_adding_days(self, days: i64) -> Option<Self> {
+        match self.julian_day.checked_add(days) {
+            Some(julian_day) => Self::checked_from_julian_day(julian_day),
+            None => None,
+        }
+    }
+
+    /// Returns the number of days from this date to `other`, negative if `other` is
+    /// earlier.
+    #[must_use]
+    pub const fn days_until(self, other: Self) -> i64 {
+        other.julian_day - self.julian_day
+    }
+
+    /// Returns the day of the week the date falls on.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn weekday(self) -> Weekday {
+        // Julian day 0 was a Monday.
+        Weekday::ALL[self.julian_day.rem_euclid(7) as usize]
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Weekday {
+    /// Monday, the first day of the ISO 8601 week.
+    Monday = 1,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday, the last day of the ISO 8601 week.
+    Sunday,
+}
+
+impl Weekday {
+    /// Every weekday, from Monday to Sunday.
+    pub const ALL: [Self; 7] = [
+        Self::Monday,
+        Self::Tuesday,
+        Self::Wednesday,
+        Self::Thursday,
+        Self::Friday,
+        Self::Saturday,
+        Self::Sunday,
+    ];
+
+    /// Returns the ISO 8601 number of the weekday, from 1 for Monday to 7 for Sunday.
+    #[must_use]
+    pub const fn number_from_monday(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the weekday with the given ISO 8601 number, from 1 for Monday to 7 for
+    /// Sunday.
+    #[must_use]
+    pub const fn from_number_from_monday(number: u8) -> Option<Self> {
+        match number {
+            1..=7 => Some(Self::ALL[number as usize - 1]),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Sunday => "Sunday",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// The fields of a date as named by a particular [`Calendar`].
+///
+/// Unset fields are `None`. [`Calendar::date_components`] fills in every field the
+/// calendar supports, and [`Calendar::date`] reads the fields it needs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DateComponents {
+    /// The year, with 0 for 1 BC and negative values before it.
+    pub year: Option<i64>,
+
+    /// The month of the year, starting at 1.
+    pub month: Option<u8>,
+
+    /// The day of the month, starting at 1.
+    pub day: Option<u8>,
+
+    /// The day of the week.
+    pub weekday: Option<Weekday>,
+
+    /// The ISO 8601 week of the year, from 1 to 53.
+    pub week_of_year: Option<u8>,
+
+    /// The ISO 8601 week-numbering year, which may differ from `year` around New Year.
+    pub year_for_week_of_year: Option<i64>,
+}
+
+impl DateComponents {
+    /// Creates components with only the year, month and day set.
+    #[must_use]
+    pub const fn ymd(year: i64, month: u8, day: u8) -> Self {
+        Self {
+            year: Some(year),
+            month: Some(month),
+            day: Some(day),
+            weekday: None,
+            week_of_year: None,
+            year_for_week_of_year: None,
+        }
+    }
+}
+
+/// The calendar systems a [`Calendar`] can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CalendarIdentifier {
+    /// The proleptic Gregorian calendar.
+    Gregorian,
+
+    /// The Gregorian calendar with ISO 8601 week numbering.
+    Iso8601,
+
+    /// The tabular (arithmetic) Islamic calendar.
+    #[cfg(feature = "islamic-calendar")]
+    IslamicTabular,
+}
+
+/// A system for naming [`Date`]s.
+///
+/// # Examples
+///
+/// ```
+/// use libx::calendar::{Calendar, DateComponents};
+///
+/// let calendar = Calendar::gregorian();
+///
+/// let date = calendar.date(&DateComponents::ymd(2000, 1, 1)).unwrap();
+/// assert_eq!(date.julian_day(), 2_451_545);
+///
+/// let components = calendar.date_components(date.adding_days(59));
+/// assert_eq!((components.month, components.day), (Some(2), Some(29)));
+///
+/// assert_eq!(calendar.date(&DateComponents::ymd(2001, 2, 29)), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Calendar {
+    identifier: CalendarIdentifier,
+}
+
+impl Calendar {
+    /// Creates a calendar for the given system.
+    #[must_use]
+    pub const fn new(identifier: CalendarIdentifier) -> Self {
+        Self { identifier }
+    }
+
+    /// Returns the proleptic Gregorian calendar.
+    #[must_use]
+    pub const fn gregorian() -> Self {
+        Self::new(CalendarIdentifier::Gregorian)
+    }
+
+    /// Returns the ISO 8601 calendar, which adds week-based fields to the Gregorian one.
+    #[must_use]
+    pub const fn iso8601() -> Self {
+        Self::new(CalendarIdentifier::Iso8601)
+    }
+
+    /// Returns the tabular Islamic calendar.
+    #[cfg(feature = "islamic-calendar")]
+    #[must_use]
+    pub const fn islamic_tabular() -> Self {
+        Self::new(CalendarIdentifier::IslamicTabular)
+    }
+
+    /// Returns the system this calendar uses.
+    #[must_use]
+    pub const fn identifier(&self) -> CalendarIdentifier {
+        self.identifier
+    }
+
+    /// Splits `date` into the components this calendar names it by.
+    ///
+    /// The year, month, day and weekday are always set. The ISO 8601 calendar also sets
+    /// the week of the year and its week-numbering year.
+    #[must_use]
+    pub const fn date_components(&self, date: Date) -> DateComponents {
+        let (year, month, day) = match self.identifier {
+            CalendarIdentifier::Gregorian | CalendarIdentifier::Iso8601 => {
+                gregorian::from_julian_day(date.julian_day)
+            }
+            #[cfg(feature = "islamic-calendar")]
+            CalendarIdentifier::IslamicTabular => islamic::from_julian_day(date.julian_day),
+        };
+
+        let mut components = DateComponents::ymd(year, month, day);
+        components.weekday = Some(date.weekday());
+
+        if matches!(self.identifier, CalendarIdentifier::Iso8601) {
+            let (week_year, week) = iso_week(date);
+            components.year_for_week_of_year = Some(week_year);
+            components.week_of_year = Some(week);
+        }
+
+        components
+    }
+
+    /// Builds the date named by `components`, or returns `None` if they do not name a
+    /// valid date or name one outside the range from [`Date::MIN`] to [`Date::MAX`].
+    ///
+    /// The ISO 8601 calendar uses the week-based fields when
+    /// [`year_for_week_of_year`](DateComponents::year_for_week_of_year),
+    /// [`week_of_year`](DateComponents::week_of_year) and
+    /// [`weekday`](DateComponents::weekday) are all set, and the year, month and day
+    /// otherwise. Fields the calendar does not read are ignored.
+    #[must_use]
+    pub const fn date(&self, components: &DateComponents) -> Option<Date> {
+        if let (CalendarIdentifier::Iso8601, Some(week_year), Some(week), Some(weekday)) = (
+            self.identifier,
+            components.year_for_week_of_year,
+            components.week_of_year,
+            components.weekday,
+        ) {
+            return iso_week_date(week_year, week, weekday);
+        }
+
+        let (Some(year), Some(month), Some(day)) =
+            (components.year, components.month, components.day)
+        else {
+            return None;
+        };
+
+        if year.unsigned_abs() > MAX_YEAR {
+            return None;
+        }
+
+        let days_in_month = match self.identifier {
+            CalendarIdentifier::Gregorian | CalendarIdentifier::Iso8601 => {
+                gregorian::days_in_month(year, month)
+            }
+            #[cfg(feature = "islamic-calendar")]
+            CalendarIdentifier::IslamicTabular => islamic::days_in_month(year, month),
+        };
+
+        if day == 0 || day > days_in_month {
+            return None;
+        }
+
+        let julian_day = match self.identifier {
+            CalendarIdentifier::Gregorian | CalendarIdentifier::Iso8601 => {
+                gregorian::to_julian_day(year, month, day)
+            }
+            #[cfg(feature = "islamic-calendar")]
+            CalendarIdentifier::IslamicTabular => islamic::to_julian_day(year, month, day),
+        };
+
+        Date::checked_from_julian_day(julian_day)
+    }
+
+    /// Returns the day of the year `date` falls on in this calendar, starting at 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::calendar::{Calendar, DateComponents};
+    ///
+    /// let calendar = Calendar::gregorian();
+    /// let date = calendar.date(&DateComponents::ymd(2024, 12, 31)).unwrap();
+    ///
+    /// assert_eq!(calendar.day_of_year(date), 366);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub const fn day_of_year(&self, date: Date) -> u16 {
+        let (year, _, _) = match self.identifier {
+            CalendarIdentifier::Gregorian | CalendarIdentifier::Iso8601 => {
+                gregorian::from_julian_day(date.julian_day)
+            }
+            #[cfg(feature = "islamic-calendar")]
+            CalendarIdentifier::IslamicTabular => islamic::from_julian_day(date.julian_day),
+        };
+
+        let new_year = match self.identifier {
+            CalendarIdentifier::Gregorian | CalendarIdentifier::Iso8601 => {
+                gregorian::to_julian_day(year, 1, 1)
+            }
+            #[cfg(feature = "islamic-calendar")]
+            CalendarIdentifier::IslamicTabular => islamic::to_julian_day(year, 1, 1),
+        };
+
+        // No supported calendar has more than 366 days in a year.
+        (date.julian_day - new_year + 1) as u16
+    }
+
+    /// Returns the ISO 8601 week number of `date`, from 1 to 53.
+    ///
+    /// Week numbers are defined on the Gregorian calendar, so the result is the same
+    /// whichever calendar is asked. Around New Year the week may belong to the
+    /// neighbouring year; [`date_components`](Self::date_components) on
+    /// [`Calendar::iso8601`] reports that year as well.
+    #[must_use]
+    pub const fn week_of_year(&self, date: Date) -> u8 {
+        iso_week(date).1
+    }
+
+    /// Returns `true` if `date` falls on the weekend observed in `locale`'s region.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::calendar::{Calendar, DateComponents};
+    /// use libx::locale::Locale;
+    ///
+    /// let calendar = Calendar::gregorian();
+    /// let friday = calendar.date(&DateComponents::ymd(2024, 3, 15)).unwrap();
+    ///
+    /// assert!(!calendar.is_weekend(friday, &Locale::new("en_US")));
+    /// assert!(calendar.is_weekend(friday, &Locale::new("ar_SA")));
+    /// ```
+    #[must_use]
+    pub fn is_weekend(&self, date: Date, locale: &Locale) -> bool {
+        Weekend::for_locale(locale).contains(date.weekday())
+    }
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self::gregorian()
+    }
+}
+
+/// Returns the ISO 8601 week-numbering year and week of `date`.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+const fn iso_week(date: Date) -> (i64, u8) {
+    // The week belongs to the year its Thursday falls in.
+    let thursday = date.julian_day - date.weekday().number_from_monday() as i64 + 4;
+    let (year, _, _) = gregorian::from_julian_day(thursday);
+    let day_of_year = thursday - gregorian::to_julian_day(year, 1, 1);
+
+    // `day_of_year` is below 366, so the week is in `1..=53`.
+    (year, (day_of_year / 7 + 1) as u8)
+}
+
+/// Returns the date of `weekday` in ISO 8601 week `week` of `week_year`.
+const fn iso_week_date(week_year: i64, week: u8, weekday: Weekday) -> Option<Date> {
+    if week_year.unsigned_abs() > MAX_YEAR {
+        return None;
+    }
+
+    // 4 January is always in week 1, and 28 December always in the last week. Either may
+    // be just past the supported range, which the arithmetic below still handles.
+    let january_4 = Date {
+        julian_day: gregorian::to_julian_day(week_year, 1, 4),
+    };
+    let december_28 = Date {
+        julian_day: gregorian::to_julian_day(week_year, 12, 28),
+    };
+    let (_, weeks_in_year) = iso_week(december_28);
+
+    if week == 0 || week > weeks_in_year {
+        return None;
+    }
+
+    let first_monday = january_4.julian_day - january_4.weekday().number_from_monday() as i64 + 1;
+    let offset = (week as i64 - 1) * 7 + weekday.number_from_monday() as i64 - 1;
+
+    Date::checked_from_julian_day(first_monday + offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weekday() {
+        let date = Calendar::gregorian().date(&DateComponents::ymd(2024, 2, 29));
+        assert_eq!(date.map(Date::weekday), Some(Weekday::Thursday));
+
+        assert_eq!(Weekday::from_number_from_monday(7), Some(Weekday::Sunday));
+        assert_eq!(Weekday::from_number_from_monday(0), None);
+    }
+
+    #[test]
+    fn test_iso_weeks() {
+        let calendar = Calendar::iso8601();
+        let week_of = |year, month, day| {
+            calendar
+                .date(&DateComponents::ymd(year, month, day))
+                .map(|date| calendar.date_components(date))
+                .and_then(|c| c.year_for_week_of_year.zip(c.week_of_year))
+        };
+
+        assert_eq!(week_of(2005, 1, 1), Some((2004, 53)));
+        assert_eq!(week_of(2008, 12, 29), Some((2009, 1)));
+        assert_eq!(week_of(2010, 1, 3), Some((2009, 53)));
+        assert_eq!(week_of(2024, 6, 15), Some((2024, 24)));
+
+        let missing_week = DateComponents {
+            year_for_week_of_year: Some(2024),
+            week_of_year: Some(53),
+            weekday: Some(Weekday::Monday),
+            ..DateComponents::default()
+        };
+        assert_eq!(calendar.date(&missing_week), None);
+    }
+
+    #[test]
+    fn test_components_round_trip() {
+        let calendars = [
+            Calendar::gregorian(),
+            Calendar::iso8601(),
+            #[cfg(feature = "islamic-calendar")]
+            Calendar::islamic_tabular(),
+        ];
+
+        for calendar in calendars {
+            for julian_day in (2_300_000..2_600_000).step_by(37) {
+                let date = Date::from_julian_day(julian_day);
+                let components = calendar.date_components(date);
+
+                assert_eq!(calendar.date(&components), Some(date));
+
+                let ymd = DateComponents::ymd(
+                    components.year.unwrap_or_default(),
+                    components.month.unwrap_or_default(),
+                    components.day.unwrap_or_default(),
+                );
+                assert_eq!(calendar.date(&ymd), Some(date));
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_components() {
+        let calendar = Calendar::gregorian();
+
+        assert_eq!(calendar.date(&DateComponents::ymd(2023, 13, 1)), None);
+        assert_eq!(calendar.date(&DateComponents::ymd(2023, 4, 31)), None);
+        assert_eq!(calendar.date(&DateComponents::ymd(2023, 1, 0)), None);
+        assert_eq!(calendar.date(&DateComponents::default()), None);
+        assert_eq!(calendar.date(&DateComponents::ymd(i64::MAX, 12, 31)), None);
+        assert_eq!(calendar.date(&DateComponents::ymd(i64::MIN, 1, 1)), None);
+        assert_eq!(
+            calendar.date(&DateComponents::ymd(4_000_000_000, 1, 1)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extreme_dates() {
+        let calendars = [
+            Calendar::gregorian(),
+            Calendar::iso8601(),
+            #[cfg(feature = "islamic-calendar")]
+            Calendar::islamic_tabular(),
+        ];
+
+        for calendar in calendars {
+            for date in [Date::MIN, Date::MAX] {
+                let components = calendar.date_components(date);
+
+                assert_eq!(calendar.date(&components), Some(date));
+                assert!((1..=366).contains(&calendar.day_of_year(date)));
+                assert!((1..=53).contains(&calendar.week_of_year(date)));
+            }
+        }
+
+        assert_eq!(Date::MAX.checked_adding_days(1), None);
+        assert_eq!(Date::MIN.checked_adding_days(i64::MIN), None);
+        assert_eq!(
+            Date::MIN.checked_adding_days(1),
+            Some(Date::MIN.adding_days(1))
+        );
+        assert_eq!(Date::checked_from_julian_day(i64::MAX), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "date out of range")]
+    fn test_adding_days_past_max() {
+        let _ = Date::MAX.adding_days(1);
+    }
+
+    #[cfg(feature = "islamic-calendar")]
+    #[test]
+    fn test_islamic_tabular() {
+        let gregorian = Calendar::gregorian();
+        let islamic = Calendar::islamic_tabular();
+
+        let date = islamic.date(&DateComponents::ymd(1445, 9, 1));
+        let components = date.map(|date| gregorian.date_components(date));
+
+        assert_eq!(
+            components.map(|c| (c.year, c.month, c.day)),
+            Some((Some(2024), Some(3), Some(11)))
+        );
+        assert_eq!(islamic.date(&DateComponents::ymd(1445, 2, 30)), None);
+    }
+}
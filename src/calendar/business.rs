@@ -0,0 +1,462 @@
+use alloc::collections::BTreeSet;
+
+use super::{Date, Weekday};
+use crate::locale::Locale;
+
+/// The longest run of consecutive non-business days that
+/// [`BusinessCalendar::adding_business_days`] steps through before giving up.
+const MAX_CLOSED_DAYS: u32 = 366;
+
+/// The days of the week that are not working days.
+///
+/// A weekend is a run of consecutive weekdays from `start` to `end`, wrapping around
+/// Sunday if needed. It always leaves at least one working day in the week.
+///
+/// # Examples
+///
+/// ```
+/// use libx::calendar::{Weekday, Weekend};
+/// use libx::locale::Locale;
+///
+/// let weekend = Weekend::for_locale(&Locale::new("he_IL"));
+///
+/// assert_eq!(weekend, Weekend::new(Weekday::Friday, Weekday::Saturday));
+/// assert!(weekend.contains(Weekday::Friday));
+/// assert!(!weekend.contains(Weekday::Sunday));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Weekend {
+    start: Weekday,
+    end: Weekday,
+}
+
+impl Weekend {
+    /// The Saturday and Sunday weekend used in most regions.
+    pub const SATURDAY_SUNDAY: Self = Self::new(Weekday::Saturday, Weekday::Sunday);
+
+    /// Creates a weekend running from `start` to `end`, inclusive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the weekend covers the whole week, such as Monday to Sunday or Tuesday
+    /// to Monday.
+    #[must_use]
+    pub const fn new(start: Weekday, end: Weekday) -> Self {
+        let weekend = Self { start, end };
+        assert!(
+            weekend.day_count() < 7,
+            "a weekend cannot cover the whole week"
+        );
+        weekend
+    }
+
+    /// Returns the weekend observed in `locale`'s region.
+    ///
+    /// Locales without a region, or with a region the crate has no data for, use
+    /// [`Weekend::SATURDAY_SUNDAY`].
+    #[must_use]
+    pub fn for_locale(locale: &Locale) -> Self {
+        const FRIDAY_SATURDAY: &[&str] = &[
+            "BH", "DZ", "EG", "IL", "IQ", "JO", "KW", "LY", "OM", "QA", "SA", "SD", "SY", "YE",
+        ];
+
+        let Some(region) = locale.region_code() else {
+            return Self::SATURDAY_SUNDAY;
+        };
+
+        match region {
+            "AF" => Self::new(Weekday::Thursday, Weekday::Friday),
+            "IR" => Self::new(Weekday::Friday, Weekday::Friday),
+            "IN" | "UG" => Self::new(Weekday::Sunday, Weekday::Sunday),
+            _ if FRIDAY_SATURDAY.contains(&region) => Self::new(Weekday::Friday, Weekday::Saturday),
+            _ => Self::SATURDAY_SUNDAY,
+        }
+    }
+
+    /// Returns the first day of the weekend.
+    #[must_use]
+    pub const fn start(&self) -> Weekday {
+        self.start
+    }
+
+    /// Returns the last day of the weekend.
+    #[must_use]
+    pub const fn end(&self) -> Weekday {
+        self.end
+    }
+
+    /// Returns `true` if `weekday` is part of the weekend.
+    #[must_use]
+    pub const fn contains(&self, weekday: Weekday) -> bool {
+        let (start, end) = (self.start as u8, self.end as u8);
+        let day = weekday as u8;
+
+        if start <= end {
+            start <= day && day <= end
+        } else {
+            day >= start || day <= end
+        }
+    }
+
+    /// Returns the number of days in the weekend.
+    const fn day_count(self) -> i64 {
+        (self.end as i64 - self.start as i64).rem_euclid(7) + 1
+    }
+}
+
+impl Default for Weekend {
+    fn default() -> Self {
+        Self::SATURDAY_SUNDAY
+    }
+}
+
+/// A set of dates that are not working days, such as public holidays.
+///
+/// The trait is implemented for sorted slices and `BTreeSet`s of dates, for `()` (no
+/// holidays), and for closures, so rule-based holidays can be computed on demand.
+pub trait HolidaySet {
+    /// Returns `true` if `date` is a holiday.
+    fn is_holiday(&self, date: Date) -> bool;
+
+    /// Returns the number of holidays from `start` up to, but not including, `end` that
+    /// do not fall on `weekend`.
+    ///
+    /// The default implementation checks every date in the range. Sets that store their
+    /// holidays override it to visit only those.
+    fn count_between(&self, start: Date, end: Date, weekend: Weekend) -> usize {
+        (start.julian_day()..end.julian_day())
+            .map(Date::from_julian_day)
+            .filter(|&date| !weekend.contains(date.weekday()) && self.is_holiday(date))
+            .count()
+    }
+}
+
+impl HolidaySet for () {
+    fn is_holiday(&self, _date: Date) -> bool {
+        false
+    }
+
+    fn count_between(&self, _start: Date, _end: Date, _weekend: Weekend) -> usize {
+        0
+    }
+}
+
+/// The slice must be sorted, as lookups use a binary search.
+impl HolidaySet for [Date] {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.binary_search(&date).is_ok()
+    }
+
+    fn count_between(&self, start: Date, end: Date, weekend: Weekend) -> usize {
+        if start >= end {
+            return 0;
+        }
+
+        let lower = self.partition_point(|&date| date < start);
+        let upper = self.partition_point(|&date| date < end);
+
+        // Repeated dates count once, as they do for `is_holiday`.
+        self[lower..upper]
+            .chunk_by(PartialEq::eq)
+            .filter(|run| !weekend.contains(run[0].weekday()))
+            .count()
+    }
+}
+
+impl<const N: usize> HolidaySet for [Date; N] {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.as_slice().is_holiday(date)
+    }
+
+    fn count_between(&self, start: Date, end: Date, weekend: Weekend) -> usize {
+        self.as_slice().count_between(start, end, weekend)
+    }
+}
+
+impl HolidaySet for BTreeSet<Date> {
+    fn is_holiday(&self, date: Date) -> bool {
+        self.contains(&date)
+    }
+
+    fn count_between(&self, start: Date, end: Date, weekend: Weekend) -> usize {
+        if start >= end {
+            return 0;
+        }
+
+        self.range(start..end)
+            .filter(|date| !weekend.contains(date.weekday()))
+            .count()
+    }
+}
+
+impl<F> HolidaySet for F
+where
+    F: Fn(Date) -> bool,
+{
+    fn is_holiday(&self, date: Date) -> bool {
+        self(date)
+    }
+}
+
+/// Business-day arithmetic over a [`Weekend`] and a [`HolidaySet`].
+///
+/// A business day is any date that is neither on the weekend nor a holiday.
+///
+/// # Examples
+///
+/// ```
+/// use libx::calendar::{BusinessCalendar, Calendar, DateComponents, Weekend};
+///
+/// let calendar = Calendar::gregorian();
+/// let date = |y, m, d| calendar.date(&DateComponents::ymd(y, m, d)).unwrap();
+///
+/// let christmas = [date(2024, 12, 25), date(2024, 12, 26)];
+/// let business = BusinessCalendar::new(Weekend::SATURDAY_SUNDAY, christmas);
+///
+/// // Friday 20 December plus three business days skips the weekend and both holidays.
+/// assert_eq!(business.adding_business_days(date(2024, 12, 20), 3), Some(date(2024, 12, 27)));
+/// assert_eq!(business.business_days_between(date(2024, 12, 20), date(2024, 12, 27)), 3);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct BusinessCalendar<H = ()> {
+    weekend: Weekend,
+    holidays: H,
+}
+
+impl<H: HolidaySet> BusinessCalendar<H> {
+    /// Creates a business calendar with the given weekend and holidays.
+    #[must_use]
+    pub const fn new(weekend: Weekend, holidays: H) -> Self {
+        Self { weekend, holidays }
+    }
+
+    /// Creates a business calendar with the weekend observed in `locale`'s region.
+    #[must_use]
+    pub fn for_locale(locale: &Locale, holidays: H) -> Self {
+        Self::new(Weekend::for_locale(locale), holidays)
+    }
+
+    /// Returns the weekend of the calendar.
+    #[must_use]
+    pub const fn weekend(&self) -> Weekend {
+        self.weekend
+    }
+
+    /// Returns the holidays of the calendar.
+    #[must_use]
+    pub const fn holidays(&self) -> &H {
+        &self.holidays
+    }
+
+    /// Returns `true` if `date` is neither on the weekend nor a holiday.
+    #[must_use]
+    pub fn is_business_day(&self, date: Date) -> bool {
+        !self.weekend.contains(date.weekday()) && !self.holidays.is_holiday(date)
+    }
+
+    /// Returns the date `days` business days after `date`, or before it if `days` is
+    /// negative.
+    ///
+    /// `date` itself is not counted, so adding one business day to a Friday gives the
+    /// following Monday on a Saturday and Sunday weekend. Adding zero days returns
+    /// `date` unchanged, even if it is not a business day.
+    ///
+    /// Returns `None` if the holidays close more than a year of consecutive days, so that
+    /// a holiday set with no business days left cannot stall the search, or if the
+    /// result would be past [`Date::MIN`] or [`Date::MAX`].
+    #[must_use]
+    pub fn adding_business_days(&self, date: Date, days: i64) -> Option<Date> {
+        let step = days.signum();
+        let mut remaining = days.unsigned_abs();
+        let mut current = date;
+        let mut closed = 0;
+
+        while remaining > 0 {
+            current = current.checked_adding_days(step)?;
+
+            if self.is_business_day(current) {
+                remaining -= 1;
+                closed = 0;
+            } else {
+                closed += 1;
+
+                if closed > MAX_CLOSED_DAYS {
+                    return None;
+                }
+            }
+        }
+
+        Some(current)
+    }
+
+    /// Returns the number of business days from `start` up to, but not including, `end`.
+    ///
+    /// The result is negative if `end` is before `start`.
+    ///
+    /// Whole weeks are counted arithmetically, so only the days past the last whole week
+    /// and the holidays in the range are visited.
+    #[must_use]
+    pub fn business_days_between(&self, start: Date, end: Date) -> i64 {
+        let (from, to, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+
+        let days = from.days_until(to);
+        let weeks = days / 7;
+        let tail = from.adding_days(weeks * 7);
+
+        let working_days = weeks * (7 - self.weekend.day_count())
+            + (0..days % 7)
+                .map(|offset| i64::from(!self.weekend.contains(tail.adding_days(offset).weekday())))
+                .sum::<i64>();
+        let holidays = self.holidays.count_between(from, to, self.weekend);
+
+        sign * (working_days - i64::try_from(holidays).unwrap_or(i64::MAX))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec::Vec};
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    const MONDAY: Date = Date::from_julian_day(2_460_381); // 2024-03-11
+
+    #[test]
+    fn test_weekend_wraps_around_sunday() {
+        let weekend = Weekend::new(Weekday::Saturday, Weekday::Monday);
+
+        assert!(weekend.contains(Weekday::Sunday));
+        assert!(weekend.contains(Weekday::Monday));
+        assert!(!weekend.contains(Weekday::Friday));
+
+        assert_eq!(Weekend::for_locale(&Locale::new("fr")), Weekend::default());
+        assert_eq!(
+            Weekend::for_locale(&Locale::new("hi_IN")).start(),
+            Weekday::Sunday
+        );
+    }
+
+    #[test]
+    fn test_adding_business_days() {
+        assert_eq!(MONDAY.weekday(), Weekday::Monday);
+
+        let business = BusinessCalendar::new(Weekend::SATURDAY_SUNDAY, ());
+        let friday = MONDAY.adding_days(4);
+
+        assert_eq!(
+            business.adding_business_days(friday, 1),
+            Some(MONDAY.adding_days(7))
+        );
+        assert_eq!(
+            business.adding_business_days(MONDAY, -1),
+            Some(friday.adding_days(-7))
+        );
+        assert_eq!(
+            business.adding_business_days(MONDAY, 10),
+            Some(MONDAY.adding_days(14))
+        );
+
+        let saturday = friday.adding_days(1);
+        assert_eq!(business.adding_business_days(saturday, 0), Some(saturday));
+    }
+
+    #[test]
+    fn test_business_days_between_with_holidays() {
+        let wednesday = MONDAY.adding_days(2);
+        let holidays = BTreeSet::from([wednesday]);
+        let business = BusinessCalendar::new(Weekend::SATURDAY_SUNDAY, holidays);
+
+        assert!(!business.is_business_day(wednesday));
+        assert_eq!(
+            business.business_days_between(MONDAY, MONDAY.adding_days(7)),
+            4
+        );
+        assert_eq!(
+            business.business_days_between(MONDAY.adding_days(7), MONDAY),
+            -4
+        );
+        assert_eq!(business.business_days_between(MONDAY, MONDAY), 0);
+
+        let first_of_month = |date: Date| date.julian_day() == MONDAY.julian_day() + 1;
+        let rule_based = BusinessCalendar::new(Weekend::SATURDAY_SUNDAY, first_of_month);
+        assert_eq!(rule_based.adding_business_days(MONDAY, 1), Some(wednesday));
+    }
+
+    #[test]
+    fn test_no_business_days_left() {
+        let closed = BusinessCalendar::new(Weekend::SATURDAY_SUNDAY, |_| true);
+
+        assert_eq!(closed.adding_business_days(MONDAY, 1), None);
+        assert_eq!(closed.adding_business_days(MONDAY, -3), None);
+        assert_eq!(
+            closed.business_days_between(MONDAY, MONDAY.adding_days(30)),
+            0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "a weekend cannot cover the whole week")]
+    fn test_weekend_covering_whole_week() {
+        let _ = Weekend::new(Weekday::Tuesday, Weekday::Monday);
+    }
+
+    #[test]
+    fn test_business_days_between_matches_walk() {
+        let result = check_property(0xb05, 200, |rng| {
+            #[allow(clippy::cast_possible_truncation)] // Bounded by 7.
+            let mut weekday = || Weekday::ALL[rng.next_bounded(7) as usize];
+            let (first, last) = (weekday(), weekday());
+            let covers_week = (last as u8 + 7 - first as u8) % 7 == 6;
+            let weekend = Weekend::new(first, if covers_week { first } else { last });
+
+            #[allow(clippy::cast_possible_wrap)] // Bounded by 400.
+            let offset = |rng: &mut _| RandomNumberGenerator::next_bounded(rng, 400) as i64;
+            let holidays: BTreeSet<Date> = (0..rng.next_bounded(40))
+                .map(|_| MONDAY.adding_days(offset(rng) - 50))
+                .collect();
+            // Every holiday twice, as a sorted slice may repeat dates.
+            let sorted: Vec<Date> = holidays.iter().flat_map(|&date| [date, date]).collect();
+            let (start, end) = (
+                MONDAY.adding_days(offset(rng)),
+                MONDAY.adding_days(offset(rng)),
+            );
+
+            let by_set = BusinessCalendar::new(weekend, holidays.clone());
+            let by_rule = BusinessCalendar::new(weekend, |date| holidays.contains(&date));
+
+            let (from, to) = (start.min(end), start.max(end));
+            let walked = (from.julian_day()..to.julian_day())
+                .filter(|&day| by_rule.is_business_day(Date::from_julian_day(day)))
+                .count();
+            let expected =
+                i64::try_from(walked).unwrap_or(i64::MAX) * if start <= end { 1 } else { -1 };
+
+            for (name, actual) in [
+                ("set", by_set.business_days_between(start, end)),
+                ("rule", by_rule.business_days_between(start, end)),
+            ] {
+                if actual != expected {
+                    return Err(format!("{name}: {actual} != {expected} for {weekend:?}").into());
+                }
+            }
+
+            let (in_set, in_slice) = (
+                holidays.count_between(from, to, weekend),
+                sorted.as_slice().count_between(from, to, weekend),
+            );
+
+            if in_slice != in_set {
+                return Err(format!("slice: {in_slice} != {in_set} for {weekend:?}").into());
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
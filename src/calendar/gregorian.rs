@@ -0,0 +1,80 @@
+//! Conversions between the proleptic Gregorian calendar and Julian day numbers.
+
+/// The Julian day number of 1970-01-01.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+/// Returns `true` if `year` is a Gregorian leap year.
+pub const fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days in `month` of `year`, or `0` for an invalid month.
+pub const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Returns the Julian day number of a valid Gregorian date.
+pub const fn to_julian_day(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+    let year = if month <= 2 { year - 1 } else { year };
+
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468 + UNIX_EPOCH_JULIAN_DAY
+}
+
+/// Returns the Gregorian `(year, month, day)` of a Julian day number.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn from_julian_day(julian_day: i64) -> (i64, u8, u8) {
+    let days = julian_day - UNIX_EPOCH_JULIAN_DAY + 719_468;
+
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    // `month` is in `1..=12` and `day` in `1..=31`.
+    (year, month as u8, day as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_julian_days() {
+        assert_eq!(to_julian_day(1970, 1, 1), 2_440_588);
+        assert_eq!(to_julian_day(2000, 1, 1), 2_451_545);
+        assert_eq!(to_julian_day(-4713, 11, 24), 0);
+        assert_eq!(from_julian_day(0), (-4713, 11, 24));
+        assert_eq!(from_julian_day(2_460_000), (2023, 2, 24));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for julian_day in 2_400_000..2_500_000 {
+            let (year, month, day) = from_julian_day(julian_day);
+            assert!(day >= 1 && day <= days_in_month(year, month));
+            assert_eq!(to_julian_day(year, month, day), julian_day);
+        }
+    }
+}
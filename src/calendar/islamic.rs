@@ -0,0 +1,73 @@
+//! The tabular Islamic calendar.
+//!
+//! This is the arithmetic (civil) variant, with the epoch on Friday, 16 July 622 (Julian)
+//! and leap years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26 and 29 of each 30-year cycle. It
+//! follows Reingold and Dershowitz, *Calendrical Calculations*; observational calendars
+//! may differ by a day or two.
+
+/// The Julian day number of 1 Muharram 1 AH.
+const EPOCH_JULIAN_DAY: i64 = 1_948_440;
+
+/// Returns `true` if `year` has 355 days.
+pub const fn is_leap_year(year: i64) -> bool {
+    (14 + 11 * year).rem_euclid(30) < 11
+}
+
+/// Returns the number of days in `month` of `year`, or `0` for an invalid month.
+pub const fn days_in_month(year: i64, month: u8) -> u8 {
+    match month {
+        12 if is_leap_year(year) => 30,
+        1..=12 if month % 2 == 1 => 30,
+        1..=12 => 29,
+        _ => 0,
+    }
+}
+
+/// Returns the Julian day number of a valid Islamic date.
+pub const fn to_julian_day(year: i64, month: u8, day: u8) -> i64 {
+    let (month, day) = (month as i64, day as i64);
+
+    EPOCH_JULIAN_DAY - 1
+        + (year - 1) * 354
+        + (3 + 11 * year).div_euclid(30)
+        + 29 * (month - 1)
+        + month / 2
+        + day
+}
+
+/// Returns the Islamic `(year, month, day)` of a Julian day number.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub const fn from_julian_day(julian_day: i64) -> (i64, u8, u8) {
+    let year = (30 * (julian_day - EPOCH_JULIAN_DAY) + 10_646).div_euclid(10_631);
+    let prior_days = julian_day - to_julian_day(year, 1, 1);
+    let month = (11 * prior_days + 330) / 325;
+    let day = julian_day - to_julian_day(year, month as u8, 1) + 1;
+
+    // `month` is in `1..=12` and `day` in `1..=30`.
+    (year, month as u8, day as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_dates() {
+        assert_eq!(from_julian_day(EPOCH_JULIAN_DAY), (1, 1, 1));
+        // 1 Muharram 1445 fell on 19 July 2023.
+        assert_eq!(
+            to_julian_day(1445, 1, 1),
+            super::super::gregorian::to_julian_day(2023, 7, 19)
+        );
+        assert!(is_leap_year(2) && !is_leap_year(3) && is_leap_year(29));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for julian_day in 2_400_000..2_500_000 {
+            let (year, month, day) = from_julian_day(julian_day);
+            assert!(day >= 1 && day <= days_in_month(year, month));
+            assert_eq!(to_julian_day(year, month, day), julian_day);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+//! Classical ciphers and codes.
+//!
+//! Each cipher is available as functions over whole inputs, and Morse code and XOR also
+//! as iterator adapters that produce their output lazily, one symbol at a time. The
+//! adapters keep only a few bytes of state, so they work on inputs of any size and
+//! without an allocator. None of these ciphers offers any real secrecy, and
+//! [`analysis`] has the statistics that break them.
+
+pub mod affine;
+pub mod analysis;
+pub mod baconian;
+pub mod hill;
+pub mod morse;
+pub mod stego;
+pub mod substitution;
+pub mod tap_code;
+pub mod xor;
+
+pub use morse::MorseExt;
+pub use xor::XorExt;
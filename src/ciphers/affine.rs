@@ -0,0 +1,136 @@
+//! The affine cipher.
+//!
+//! Each letter, numbered from 0 for `A` to 25 for `Z`, is encrypted as `(a * x + b) % 26`.
+//! Decryption multiplies by the inverse of `a` modulo 26, so `a` must not share a factor
+//! with 26. The Caesar cipher is the special case `a = 1`.
+//!
+//! Case is kept, and everything that is not an ASCII letter passes through unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::affine::AffineCipher;
+//!
+//! let cipher = AffineCipher::new(5, 8)?;
+//!
+//! assert_eq!(cipher.encrypt("Affine cipher"), "Ihhwvc swfrcp");
+//! assert_eq!(cipher.decrypt("Ihhwvc swfrcp"), "Affine cipher");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::string::String;
+
+use crate::{errors::CipherError, num::modular::mod_inverse};
+
+/// An affine cipher over the 26 letters of the Latin alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AffineCipher {
+    multiplier: u8,
+    shift: u8,
+    inverse: u8,
+}
+
+impl AffineCipher {
+    /// Creates a cipher that encrypts `x` as `(multiplier * x + shift) % 26`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::KeyNotInvertible`] if `multiplier` shares a factor with 26.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{ciphers::affine::AffineCipher, errors::CipherError};
+    ///
+    /// assert_eq!(
+    ///     AffineCipher::new(13, 1),
+    ///     Err(CipherError::KeyNotInvertible { determinant: 13 })
+    /// );
+    /// ```
+    pub fn new(multiplier: u8, shift: u8) -> Result<Self, CipherError> {
+        let multiplier = multiplier % 26;
+
+        let Some(inverse) = mod_inverse(u64::from(multiplier), 26) else {
+            return Err(CipherError::KeyNotInvertible {
+                determinant: multiplier,
+            });
+        };
+
+        #[allow(clippy::cast_possible_truncation)] // The inverse is below 26.
+        let inverse = inverse as u8;
+
+        Ok(Self {
+            multiplier,
+            shift: shift % 26,
+            inverse,
+        })
+    }
+
+    /// Returns the multiplier, reduced modulo 26.
+    #[must_use]
+    pub const fn multiplier(&self) -> u8 {
+        self.multiplier
+    }
+
+    /// Returns the shift, reduced modulo 26.
+    #[must_use]
+    pub const fn shift(&self) -> u8 {
+        self.shift
+    }
+
+    /// Replaces every letter of `text` with `map` of its number, keeping its case.
+    fn map_letters(text: &str, map: impl Fn(u16) -> u16) -> String {
+        text.chars()
+            .map(|character| {
+                let base = match character {
+                    'a'..='z' => b'a',
+                    'A'..='Z' => b'A',
+                    _ => return character,
+                };
+
+                #[allow(clippy::cast_possible_truncation)] // Letters are ASCII and map below 26.
+                let letter = map(u16::from(character as u8 - base)) as u8;
+                char::from(base + letter)
+            })
+            .collect()
+    }
+
+    /// Encrypts `plaintext`.
+    #[must_use]
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        let (multiplier, shift) = (u16::from(self.multiplier), u16::from(self.shift));
+        Self::map_letters(plaintext, |x| (multiplier * x + shift) % 26)
+    }
+
+    /// Decrypts `ciphertext`.
+    #[must_use]
+    pub fn decrypt(&self, ciphertext: &str) -> String {
+        let (inverse, shift) = (u16::from(self.inverse), u16::from(self.shift));
+        Self::map_letters(ciphertext, |y| inverse * (y + 26 - shift) % 26)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_valid_key_round_trips() {
+        let text = "The Quick Brown Fox, 1984!";
+
+        for multiplier in 0..26 {
+            match AffineCipher::new(multiplier, 7) {
+                Ok(cipher) => assert_eq!(cipher.decrypt(&cipher.encrypt(text)), text),
+                Err(error) => assert!(multiplier % 2 == 0 || multiplier == 13, "{error}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_caesar_special_case() {
+        let cipher = AffineCipher::new(27, 29).expect("1 is invertible");
+
+        assert_eq!((cipher.multiplier(), cipher.shift()), (1, 3));
+        assert_eq!(cipher.encrypt("xyz"), "abc");
+    }
+}
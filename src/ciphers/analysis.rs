@@ -0,0 +1,444 @@
+//! Frequency analysis of classical ciphertexts.
+//!
+//! The statistics here look only at the ASCII letters of a text and ignore case, since
+//! that is the alphabet the classical substitution ciphers work on. [`LetterCounts`]
+//! holds a letter histogram and scores it against English: a low chi-squared statistic
+//! means the text reads like English, and the index of coincidence tells a
+//! monoalphabetic substitution (about 0.067) from a polyalphabetic one (closer to
+//! 1/26, about 0.038).
+//!
+//! [`caesar_candidates`] tries every Caesar shift and ranks the results, and
+//! [`vigenere_key_lengths`] estimates the key length of a Vigenère ciphertext.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::analysis;
+//!
+//! let best = analysis::crack_caesar("Wkh txlfn eurzq ira mxpsv ryhu wkh odcb grj.");
+//!
+//! assert_eq!(best.map(|candidate| candidate.shift), Some(3));
+//! ```
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+/// The relative frequencies of the letters `A` to `Z` in English text.
+pub const ENGLISH_LETTER_FREQUENCIES: [f64; 26] = [
+    0.08167, 0.01492, 0.02782, 0.04253, 0.12702, 0.02228, 0.02015, 0.06094, 0.06966, 0.00153,
+    0.00772, 0.04025, 0.02406, 0.06749, 0.07507, 0.01929, 0.00095, 0.05987, 0.06327, 0.09056,
+    0.02758, 0.00978, 0.02360, 0.00150, 0.01974, 0.00074,
+];
+
+/// The index of coincidence of English text.
+pub const ENGLISH_INDEX_OF_COINCIDENCE: f64 = 0.0667;
+
+/// Returns the position of `character` in the alphabet, ignoring case, if it is an ASCII
+/// letter.
+fn letter_index(character: char) -> Option<usize> {
+    character
+        .is_ascii_alphabetic()
+        .then(|| usize::from(character.to_ascii_uppercase() as u8 - b'A'))
+}
+
+/// A histogram of the ASCII letters in a text, ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use libx::ciphers::analysis::LetterCounts;
+///
+/// let counts = LetterCounts::of("Hello, world!");
+///
+/// assert_eq!(counts.count('l'), 3);
+/// assert_eq!(counts.total(), 10);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct LetterCounts {
+    counts: [usize; 26],
+    total: usize,
+}
+
+impl LetterCounts {
+    /// Creates an empty histogram.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            counts: [0; 26],
+            total: 0,
+        }
+    }
+
+    /// Counts the letters of `text`.
+    #[must_use]
+    pub fn of(text: &str) -> Self {
+        text.chars().collect()
+    }
+
+    /// Counts `character` if it is an ASCII letter.
+    pub fn add(&mut self, character: char) {
+        if let Some(index) = letter_index(character) {
+            self.counts[index] += 1;
+            self.total += 1;
+        }
+    }
+
+    /// Returns the number of times `letter` occurs, ignoring case. Returns zero for
+    /// anything other than an ASCII letter.
+    #[must_use]
+    pub fn count(&self, letter: char) -> usize {
+        letter_index(letter).map_or(0, |index| self.counts[index])
+    }
+
+    /// Returns the counts of the letters `A` to `Z`.
+    #[must_use]
+    pub const fn counts(&self) -> &[usize; 26] {
+        &self.counts
+    }
+
+    /// Returns the number of letters counted.
+    #[must_use]
+    pub const fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Returns the share of the counted letters that are `letter`, or zero if nothing has
+    /// been counted.
+    #[must_use]
+    pub fn frequency(&self, letter: char) -> f64 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Texts are far shorter than 2^53 letters.
+        let frequency = self.count(letter) as f64 / self.total as f64;
+        frequency
+    }
+
+    /// Returns the chi-squared statistic of the counts against
+    /// [`ENGLISH_LETTER_FREQUENCIES`], or `None` if nothing has been counted.
+    ///
+    /// The lower the statistic, the more the letters are distributed like English.
+    #[must_use]
+    pub fn chi_squared(&self) -> Option<f64> {
+        if self.total == 0 {
+            return None;
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Texts are far shorter than 2^53 letters.
+        let total = self.total as f64;
+
+        let statistic = self
+            .counts
+            .iter()
+            .zip(ENGLISH_LETTER_FREQUENCIES)
+            .map(|(&count, frequency)| {
+                let expected = total * frequency;
+                #[allow(clippy::cast_precision_loss)]
+                let difference = count as f64 - expected;
+                difference * difference / expected
+            })
+            .sum();
+
+        Some(statistic)
+    }
+
+    /// Returns the probability that two letters drawn from the counts without
+    /// replacement are the same, or `None` if fewer than two letters have been counted.
+    #[must_use]
+    pub fn index_of_coincidence(&self) -> Option<f64> {
+        if self.total < 2 {
+            return None;
+        }
+
+        let pairs: usize = self
+            .counts
+            .iter()
+            .map(|&count| count * count.saturating_sub(1))
+            .sum();
+
+        #[allow(clippy::cast_precision_loss)] // Texts are far shorter than 2^26 letters.
+        let index = pairs as f64 / (self.total * (self.total - 1)) as f64;
+        Some(index)
+    }
+}
+
+impl Extend<char> for LetterCounts {
+    fn extend<I: IntoIterator<Item = char>>(&mut self, characters: I) {
+        for character in characters {
+            self.add(character);
+        }
+    }
+}
+
+impl FromIterator<char> for LetterCounts {
+    fn from_iter<I: IntoIterator<Item = char>>(characters: I) -> Self {
+        let mut counts = Self::new();
+        counts.extend(characters);
+        counts
+    }
+}
+
+/// Returns the chi-squared statistic of the letters of `text` against English, or `None`
+/// if it has no letters. See [`LetterCounts::chi_squared`].
+#[must_use]
+pub fn chi_squared(text: &str) -> Option<f64> {
+    LetterCounts::of(text).chi_squared()
+}
+
+/// Returns the index of coincidence of the letters of `text`, or `None` if it has fewer
+/// than two letters. See [`LetterCounts::index_of_coincidence`].
+#[must_use]
+pub fn index_of_coincidence(text: &str) -> Option<f64> {
+    LetterCounts::of(text).index_of_coincidence()
+}
+
+/// One way of decrypting a Caesar ciphertext.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaesarCandidate {
+    /// The number of places the letters were shifted forward when encrypting.
+    pub shift: u8,
+
+    /// The chi-squared statistic of the plaintext against English; lower is better.
+    pub score: f64,
+
+    /// The ciphertext with the letters shifted back.
+    pub plaintext: String,
+}
+
+/// Shifts the ASCII letters of `text` back by `shift` places, keeping their case.
+fn shift_back(text: &str, shift: u8) -> String {
+    text.chars()
+        .map(|character| {
+            let base = match character {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return character,
+            };
+            let offset = (character as u8 - base + 26 - shift) % 26;
+            char::from(base + offset)
+        })
+        .collect()
+}
+
+/// Decrypts `ciphertext` with all 26 Caesar shifts and returns the results ranked from
+/// the most to the least English-like. Returns nothing if the ciphertext has no letters.
+///
+/// # Examples
+///
+/// ```
+/// use libx::ciphers::analysis::caesar_candidates;
+///
+/// let candidates = caesar_candidates("Ymnx nx f xjhwjy rjxxflj");
+///
+/// assert_eq!(candidates.len(), 26);
+/// assert_eq!(candidates[0].plaintext, "This is a secret message");
+/// ```
+#[must_use]
+pub fn caesar_candidates(ciphertext: &str) -> Vec<CaesarCandidate> {
+    let mut candidates: Vec<_> = (0..26)
+        .filter_map(|shift| {
+            let plaintext = shift_back(ciphertext, shift);
+            let score = chi_squared(&plaintext)?;
+
+            Some(CaesarCandidate {
+                shift,
+                score,
+                plaintext,
+            })
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| a.score.total_cmp(&b.score));
+    candidates
+}
+
+/// Returns the most English-like Caesar decryption of `ciphertext`, or `None` if it has
+/// no letters.
+#[must_use]
+pub fn crack_caesar(ciphertext: &str) -> Option<CaesarCandidate> {
+    caesar_candidates(ciphertext).into_iter().next()
+}
+
+/// The evidence for one possible Vigenère key length.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyLengthEstimate {
+    /// The key length.
+    pub length: usize,
+
+    /// The average index of coincidence of the columns that every `length`-th letter
+    /// forms. Close to [`ENGLISH_INDEX_OF_COINCIDENCE`] when each column was encrypted
+    /// with a single shift.
+    pub index_of_coincidence: f64,
+
+    /// The number of spacings between repeated trigrams that are multiples of the
+    /// length, as in the Kasiski examination.
+    pub kasiski_votes: usize,
+}
+
+/// Estimates the key length of a Vigenère ciphertext, trying every length up to
+/// `max_length`.
+///
+/// The estimates are ranked from the highest index of coincidence down, since the columns
+/// of the right length each read like a monoalphabetic substitution. A multiple of the key
+/// length scores about as well as the key length itself, so the ranking starts with the
+/// shortest length whose index is within a tenth of the highest one. Lengths that would
+/// leave fewer than two letters per column are not tried.
+///
+/// # Examples
+///
+/// ```
+/// use libx::ciphers::analysis::vigenere_key_lengths;
+///
+/// // "Attack at dawn, the enemy sleeps at the bridge..." under the key "LEMON".
+/// let ciphertext = "LXFOPVEFRNHRFVRPRQALDPQSCDEFHUPFDWQRIMBQELQUH\
+///                   LVPGPSEZURLXYWQYMSVGDSISPCSEGGSIDWIPVIVRYXTSZ\
+///                   ZSZWFOSIBNYHFOXPXTSOCMPURMIRCEPXTSRYIYMJLOQG";
+///
+/// assert_eq!(vigenere_key_lengths(ciphertext, 8)[0].length, 5);
+/// ```
+#[must_use]
+pub fn vigenere_key_lengths(ciphertext: &str, max_length: usize) -> Vec<KeyLengthEstimate> {
+    #[allow(clippy::cast_possible_truncation)] // The letters are ASCII.
+    let letters: Vec<u8> = ciphertext
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|letter| letter.to_ascii_uppercase() as u8)
+        .collect();
+
+    let max_length = max_length.min(letters.len() / 2);
+    let mut votes = vec![0_usize; max_length + 1];
+    let mut last_seen = BTreeMap::new();
+
+    for (position, trigram) in letters.windows(3).enumerate() {
+        if let Some(previous) = last_seen.insert(trigram, position) {
+            let spacing = position - previous;
+
+            for (length, votes) in votes.iter_mut().enumerate().skip(1) {
+                if spacing % length == 0 {
+                    *votes += 1;
+                }
+            }
+        }
+    }
+
+    let mut estimates: Vec<_> = (1..=max_length)
+        .map(|length| {
+            let mut total = 0.0;
+
+            for column in 0..length {
+                let counts: LetterCounts = letters
+                    .iter()
+                    .skip(column)
+                    .step_by(length)
+                    .map(|&letter| char::from(letter))
+                    .collect();
+                total += counts.index_of_coincidence().unwrap_or(0.0);
+            }
+
+            #[allow(clippy::cast_precision_loss)] // Key lengths are small.
+            let index_of_coincidence = total / length as f64;
+
+            KeyLengthEstimate {
+                length,
+                index_of_coincidence,
+                kasiski_votes: votes[length],
+            }
+        })
+        .collect();
+
+    estimates.sort_by(|a, b| b.index_of_coincidence.total_cmp(&a.index_of_coincidence));
+
+    if let Some(best) = estimates
+        .first()
+        .map(|estimate| estimate.index_of_coincidence)
+    {
+        let shortest = (0..estimates.len())
+            .filter(|&i| estimates[i].index_of_coincidence >= best * 0.9)
+            .min_by_key(|&i| estimates[i].length);
+
+        if let Some(shortest) = shortest {
+            estimates[..=shortest].rotate_right(1);
+        }
+    }
+
+    estimates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encrypts `text` with the Vigenère cipher, dropping everything but letters.
+    fn vigenere(text: &str, key: &str) -> String {
+        text.chars()
+            .filter(char::is_ascii_alphabetic)
+            .zip(key.bytes().cycle())
+            .map(|(letter, key)| {
+                let letter = letter.to_ascii_uppercase() as u8 - b'A';
+                char::from(b'A' + (letter + key - b'A') % 26)
+            })
+            .collect()
+    }
+
+    const PLAINTEXT: &str = "It was the best of times, it was the worst of times, it was the \
+        age of wisdom, it was the age of foolishness, it was the epoch of belief, it was \
+        the epoch of incredulity, it was the season of Light, it was the season of \
+        Darkness, it was the spring of hope, it was the winter of despair, we had \
+        everything before us, we had nothing before us.";
+
+    #[test]
+    fn test_letter_counts() {
+        let counts = LetterCounts::of("aAb!");
+
+        assert_eq!(counts.count('A'), 2);
+        assert_eq!(counts.count('!'), 0);
+        assert_eq!(counts.total(), 3);
+        assert!((counts.frequency('b') - 1.0 / 3.0).abs() < 1e-12);
+        assert_eq!(counts.index_of_coincidence(), Some(1.0 / 3.0));
+
+        assert_eq!(LetterCounts::new().chi_squared(), None);
+        assert_eq!(index_of_coincidence("a"), None);
+    }
+
+    #[test]
+    fn test_english_scores_better_than_noise() {
+        let english = chi_squared(PLAINTEXT).unwrap_or(f64::INFINITY);
+        let noise = chi_squared("qzxj vkwq zzjx qqxk").unwrap_or(0.0);
+        assert!(english < noise);
+
+        let ioc = index_of_coincidence(PLAINTEXT).unwrap_or(0.0);
+        assert!((ioc - ENGLISH_INDEX_OF_COINCIDENCE).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_caesar_candidates() {
+        assert_eq!(shift_back("Zab, c!", 25), "Abc, d!");
+
+        let ciphertext = shift_back(PLAINTEXT, 26 - 11);
+        let candidates = caesar_candidates(&ciphertext);
+
+        assert_eq!(candidates.len(), 26);
+        assert_eq!(candidates[0].shift, 11);
+        assert_eq!(candidates[0].plaintext, PLAINTEXT);
+        assert!(
+            candidates
+                .windows(2)
+                .all(|pair| pair[0].score <= pair[1].score)
+        );
+
+        assert!(caesar_candidates("123").is_empty());
+    }
+
+    #[test]
+    fn test_vigenere_key_lengths() {
+        let ciphertext = vigenere(PLAINTEXT, "CIPHER");
+        let estimates = vigenere_key_lengths(&ciphertext, 12);
+
+        assert_eq!(estimates.len(), 12);
+        assert_eq!(estimates[0].length, 6);
+        assert!(estimates[0].kasiski_votes > 0);
+
+        assert!(vigenere_key_lengths("AB", 5).len() == 1);
+        assert!(vigenere_key_lengths("", 5).is_empty());
+    }
+}
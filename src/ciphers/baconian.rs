@@ -0,0 +1,281 @@
+//! Bacon's cipher.
+//!
+//! Every letter is written as a group of five `A`s and `B`s, the five bits of its
+//! position in the alphabet with `A` for 0 and `B` for 1, so `A` is `AAAAA` and `Z` is
+//! `BBAAB`. This is the 26-letter variant, in which `I`/`J` and `U`/`V` have groups of
+//! their own. Groups are separated by spaces and words by ` / `, as in
+//! [`morse`](super::morse).
+//!
+//! Bacon meant the two symbols to be hidden in an innocent-looking cover text rather
+//! than written out: [`conceal`] carries each symbol in the case of a cover letter, lower
+//! case for `A` and upper case for `B`, and [`reveal`] reads it back.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::baconian;
+//!
+//! assert_eq!(baconian::encode("Hi you")?, "AABBB ABAAA / BBAAA ABBBA BABAA");
+//! assert_eq!(baconian::decode("AABBB ABAAA / BBAAA ABBBA BABAA")?, "HI YOU");
+//!
+//! let hidden = baconian::conceal("hi", "meet me by the old mill at noon")?;
+//! assert_eq!(hidden, "meET Me By the old mill at noon");
+//! assert_eq!(&baconian::reveal(&hidden)?[..2], "HI");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::string::String;
+
+use crate::errors::CipherError;
+
+/// The number of symbols in a group.
+const GROUP_LENGTH: usize = 5;
+
+/// Returns the group of `letter` as five bits, most significant first, ignoring case.
+fn bits_for(letter: char) -> Option<u8> {
+    #[allow(clippy::cast_possible_truncation)] // The letter is ASCII.
+    letter
+        .is_ascii_alphabetic()
+        .then(|| letter.to_ascii_uppercase() as u8 - b'A')
+}
+
+/// Returns the letter whose group is `bits`.
+fn letter_for(bits: u8) -> Option<char> {
+    (bits < 26).then(|| char::from(b'A' + bits))
+}
+
+/// Encodes the letters of `text` as Bacon's cipher.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character other than an ASCII letter or
+/// whitespace.
+pub fn encode(text: &str) -> Result<String, CipherError> {
+    let mut code = String::with_capacity(text.len() * (GROUP_LENGTH + 1));
+    let mut word_break = false;
+
+    for (position, character) in text.char_indices() {
+        if character.is_whitespace() {
+            word_break = !code.is_empty();
+            continue;
+        }
+
+        let bits = bits_for(character).ok_or(CipherError::InvalidSymbol {
+            character,
+            position,
+        })?;
+
+        if word_break {
+            code.push_str(" / ");
+        } else if !code.is_empty() {
+            code.push(' ');
+        }
+        word_break = false;
+
+        for shift in (0..GROUP_LENGTH).rev() {
+            code.push(if bits >> shift & 1 == 0 { 'A' } else { 'B' });
+        }
+    }
+
+    Ok(code)
+}
+
+/// Decodes Bacon's cipher into upper-case text.
+///
+/// Symbols are read in groups of five whether or not spaces separate them, and either
+/// case is accepted.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character other than `A`, `B`, `/` and
+/// whitespace, [`CipherError::UnknownSequence`] for a group that stands for no letter,
+/// and [`CipherError::Truncated`] if a word ends in the middle of a group.
+pub fn decode(code: &str) -> Result<String, CipherError> {
+    let mut text = String::with_capacity(code.len() / GROUP_LENGTH);
+    let mut bits = 0;
+    let mut length = 0;
+    let mut start = 0;
+
+    for (position, character) in code.char_indices() {
+        let bit = match character {
+            'A' | 'a' => 0,
+            'B' | 'b' => 1,
+            '/' if length == 0 => {
+                text.push(' ');
+                continue;
+            }
+            '/' => return Err(CipherError::Truncated),
+            _ if character.is_whitespace() => continue,
+            _ => {
+                return Err(CipherError::InvalidSymbol {
+                    character,
+                    position,
+                });
+            }
+        };
+
+        if length == 0 {
+            start = position;
+        }
+
+        bits = bits << 1 | bit;
+        length += 1;
+
+        if length == GROUP_LENGTH {
+            text.push(letter_for(bits).ok_or(CipherError::UnknownSequence { position: start })?);
+            bits = 0;
+            length = 0;
+        }
+    }
+
+    if length > 0 {
+        return Err(CipherError::Truncated);
+    }
+
+    Ok(text)
+}
+
+/// Hides the letters of `message` in the case of the letters of `cover`.
+///
+/// Each letter of the message takes five letters of the cover, which are written in
+/// lower case for `A` and upper case for `B`. Whitespace in the message is dropped, the
+/// cover letters left over are written in lower case, and everything else in the cover
+/// is copied as it is.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character in `message` other than an
+/// ASCII letter or whitespace, and [`CipherError::CoverTooShort`] if `cover` has too few
+/// letters to carry the message.
+pub fn conceal(message: &str, cover: &str) -> Result<String, CipherError> {
+    let mut symbols = alloc::vec::Vec::with_capacity(message.len() * GROUP_LENGTH);
+
+    for (position, character) in message.char_indices() {
+        if character.is_whitespace() {
+            continue;
+        }
+
+        let bits = bits_for(character).ok_or(CipherError::InvalidSymbol {
+            character,
+            position,
+        })?;
+        symbols.extend((0..GROUP_LENGTH).rev().map(|shift| bits >> shift & 1 == 1));
+    }
+
+    let available = cover.chars().filter(char::is_ascii_alphabetic).count();
+
+    if available < symbols.len() {
+        return Err(CipherError::CoverTooShort {
+            letters_needed: symbols.len(),
+        });
+    }
+
+    let mut symbols = symbols.into_iter();
+
+    Ok(cover
+        .chars()
+        .map(|character| {
+            if !character.is_ascii_alphabetic() {
+                character
+            } else if symbols.next() == Some(true) {
+                character.to_ascii_uppercase()
+            } else {
+                character.to_ascii_lowercase()
+            }
+        })
+        .collect())
+}
+
+/// Reads the message that [`conceal`] hid in the case of the letters of `cover`.
+///
+/// Every complete group of five cover letters is decoded, so a cover that is longer
+/// than the message it carries reveals trailing `A`s; a final incomplete group is
+/// ignored.
+///
+/// # Errors
+///
+/// Returns [`CipherError::UnknownSequence`] for a group of letters whose case pattern
+/// stands for no letter.
+pub fn reveal(cover: &str) -> Result<String, CipherError> {
+    let mut text = String::new();
+    let mut bits = 0;
+    let mut length = 0;
+    let mut start = 0;
+
+    for (position, character) in cover.char_indices() {
+        if !character.is_ascii_alphabetic() {
+            continue;
+        }
+
+        if length == 0 {
+            start = position;
+        }
+
+        bits = bits << 1 | u8::from(character.is_ascii_uppercase());
+        length += 1;
+
+        if length == GROUP_LENGTH {
+            text.push(letter_for(bits).ok_or(CipherError::UnknownSequence { position: start })?);
+            bits = 0;
+            length = 0;
+        }
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let code = encode("  The quick  fox ").unwrap_or_default();
+
+        assert!(code.starts_with("BAABB AABBB AABAA / BAAAA"));
+        assert_eq!(decode(&code), Ok(String::from("THE QUICK FOX")));
+        assert_eq!(decode("abbbaaabba"), Ok(String::from("OG")));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            encode("a1"),
+            Err(CipherError::InvalidSymbol {
+                character: '1',
+                position: 1
+            })
+        );
+        assert_eq!(
+            decode("AAAAA BBBBB"),
+            Err(CipherError::UnknownSequence { position: 6 })
+        );
+        assert_eq!(decode("AAAA / AAAAA"), Err(CipherError::Truncated));
+        assert_eq!(decode("AAAAA AB"), Err(CipherError::Truncated));
+        assert_eq!(
+            decode("AAAAC"),
+            Err(CipherError::InvalidSymbol {
+                character: 'C',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_conceal_and_reveal() {
+        let cover = "Nothing to see here, move along please.";
+        let hidden = conceal("Run", cover).unwrap_or_default();
+
+        assert!(hidden.eq_ignore_ascii_case(cover));
+        assert_eq!(reveal(&hidden), Ok(String::from("RUNAAA")));
+
+        assert_eq!(
+            conceal("abc", "too short"),
+            Err(CipherError::CoverTooShort { letters_needed: 15 })
+        );
+        assert_eq!(
+            reveal("ZZZZZ"),
+            Err(CipherError::UnknownSequence { position: 0 })
+        );
+    }
+}
@@ -0,0 +1,277 @@
+//! The Hill cipher.
+//!
+//! Letters, numbered from 0 for `A` to 25 for `Z`, are encrypted `N` at a time by
+//! multiplying them as a column vector by an `N`x`N` key matrix modulo 26. Decryption
+//! multiplies by the inverse of the key, which exists only if the determinant of the key
+//! shares no factor with 26. Keys of size 2 and 3 are the classical choices; larger ones
+//! work too, but take `N!` steps to invert.
+//!
+//! Only letters are encrypted. Everything else is dropped, the output is in upper case,
+//! and the last block is padded with `X`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::hill::HillCipher;
+//!
+//! let cipher = HillCipher::<3>::from_key("GYBNQKURP")?;
+//!
+//! assert_eq!(cipher.encrypt("act"), "POH");
+//! assert_eq!(cipher.decrypt("POH")?, "ACT");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{errors::CipherError, num::modular::mod_inverse};
+
+/// The letter that pads the last block, numbered from `A`.
+const PADDING: u8 = b'X' - b'A';
+
+/// Returns the determinant of the submatrix of `matrix` made of `rows` and `columns`, by
+/// expanding along its first row.
+fn determinant_of<const N: usize>(matrix: &[[u8; N]; N], rows: &[usize], columns: &[usize]) -> i64 {
+    let Some((&row, rows)) = rows.split_first() else {
+        return 1;
+    };
+
+    let mut total = 0;
+
+    for (position, &column) in columns.iter().enumerate() {
+        let rest: Vec<usize> = columns.iter().copied().filter(|&c| c != column).collect();
+        let term = i64::from(matrix[row][column]) * determinant_of(matrix, rows, &rest);
+
+        total += if position % 2 == 0 { term } else { -term };
+    }
+
+    total
+}
+
+/// A Hill cipher with an `N`x`N` key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HillCipher<const N: usize> {
+    key: [[u8; N]; N],
+    inverse: [[u8; N]; N],
+}
+
+impl<const N: usize> HillCipher<N> {
+    /// Creates a cipher from a key matrix, whose entries are reduced modulo 26.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::KeyNotInvertible`] if the determinant of the key shares a
+    /// factor with 26.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{ciphers::hill::HillCipher, errors::CipherError};
+    ///
+    /// let cipher = HillCipher::new([[3, 3], [2, 5]])?;
+    /// assert_eq!(cipher.decrypt(&cipher.encrypt("help"))?, "HELP");
+    ///
+    /// assert_eq!(
+    ///     HillCipher::new([[2, 4], [1, 3]]),
+    ///     Err(CipherError::KeyNotInvertible { determinant: 2 })
+    /// );
+    /// # Ok::<(), CipherError>(())
+    /// ```
+    pub fn new(key: [[u8; N]; N]) -> Result<Self, CipherError> {
+        const { assert!(N > 0, "the key must have at least one row") };
+
+        let key = key.map(|row| row.map(|entry| entry % 26));
+        let all: Vec<usize> = (0..N).collect();
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        // The Euclidean remainder lies in `0..26`.
+        let determinant = determinant_of(&key, &all, &all).rem_euclid(26) as u8;
+
+        let Some(determinant_inverse) = mod_inverse(u64::from(determinant), 26) else {
+            return Err(CipherError::KeyNotInvertible { determinant });
+        };
+
+        // The inverse is the adjugate, the transposed matrix of cofactors, divided by the
+        // determinant.
+        let mut inverse = [[0; N]; N];
+
+        for (row, inverse_row) in inverse.iter_mut().enumerate() {
+            for (column, entry) in inverse_row.iter_mut().enumerate() {
+                let rows: Vec<usize> = (0..N).filter(|&r| r != column).collect();
+                let columns: Vec<usize> = (0..N).filter(|&c| c != row).collect();
+                let minor = determinant_of(&key, &rows, &columns);
+                let cofactor = if (row + column) % 2 == 0 {
+                    minor
+                } else {
+                    -minor
+                };
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                // The Euclidean remainder lies in `0..26`.
+                let cofactor = cofactor.rem_euclid(26) as u64;
+
+                #[allow(clippy::cast_possible_truncation)] // The product is reduced below 26.
+                let value = (cofactor * determinant_inverse % 26) as u8;
+                *entry = value;
+            }
+        }
+
+        Ok(Self { key, inverse })
+    }
+
+    /// Creates a cipher from the `N * N` letters of `key`, read row by row with `A` as 0.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::InvalidSymbol`] for a character that is not an ASCII letter,
+    /// [`CipherError::KeyLength`] if there are not `N * N` letters, and
+    /// [`CipherError::KeyNotInvertible`] as [`new`](Self::new) does.
+    pub fn from_key(key: &str) -> Result<Self, CipherError> {
+        let mut matrix = [[0; N]; N];
+        let mut length = 0;
+
+        for (position, character) in key.char_indices() {
+            if !character.is_ascii_alphabetic() {
+                return Err(CipherError::InvalidSymbol {
+                    character,
+                    position,
+                });
+            }
+
+            if let Some(entry) = matrix.as_flattened_mut().get_mut(length) {
+                #[allow(clippy::cast_possible_truncation)] // The letter is ASCII.
+                let letter = character.to_ascii_uppercase() as u8 - b'A';
+                *entry = letter;
+            }
+
+            length += 1;
+        }
+
+        if length != N * N {
+            return Err(CipherError::KeyLength {
+                expected: N * N,
+                actual: length,
+            });
+        }
+
+        Self::new(matrix)
+    }
+
+    /// Returns the key matrix, reduced modulo 26.
+    #[must_use]
+    pub const fn key(&self) -> &[[u8; N]; N] {
+        &self.key
+    }
+
+    /// Returns the inverse of the key matrix modulo 26, which decrypts.
+    #[must_use]
+    pub const fn inverse(&self) -> &[[u8; N]; N] {
+        &self.inverse
+    }
+
+    /// Multiplies each block of `N` letters by `matrix` and writes the results as
+    /// upper-case letters.
+    fn transform(matrix: &[[u8; N]; N], letters: &[u8]) -> String {
+        let mut output = String::with_capacity(letters.len());
+
+        for block in letters.chunks_exact(N) {
+            for row in matrix {
+                let sum: u32 = row
+                    .iter()
+                    .zip(block)
+                    .map(|(&entry, &letter)| u32::from(entry) * u32::from(letter))
+                    .sum();
+
+                #[allow(clippy::cast_possible_truncation)] // The sum is reduced below 26.
+                let letter = (sum % 26) as u8;
+                output.push(char::from(b'A' + letter));
+            }
+        }
+
+        output
+    }
+
+    /// Returns the letters of `text` numbered from `A`, dropping everything else.
+    fn letters(text: &str) -> Vec<u8> {
+        #[allow(clippy::cast_possible_truncation)] // The letters are ASCII.
+        text.chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|letter| letter.to_ascii_uppercase() as u8 - b'A')
+            .collect()
+    }
+
+    /// Encrypts the letters of `plaintext`, padding the last block with `X`.
+    #[must_use]
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        let mut letters = Self::letters(plaintext);
+        letters.resize(letters.len().next_multiple_of(N), PADDING);
+
+        Self::transform(&self.key, &letters)
+    }
+
+    /// Decrypts the letters of `ciphertext`. Any padding added by
+    /// [`encrypt`](Self::encrypt) is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::Truncated`] if the number of letters is not a multiple of
+    /// `N`.
+    pub fn decrypt(&self, ciphertext: &str) -> Result<String, CipherError> {
+        let letters = Self::letters(ciphertext);
+
+        if letters.len() % N != 0 {
+            return Err(CipherError::Truncated);
+        }
+
+        Ok(Self::transform(&self.inverse, &letters))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_vectors() {
+        let cipher = HillCipher::<2>::from_key("HILL").expect("invertible key");
+
+        assert_eq!(cipher.key(), &[[7, 8], [11, 11]]);
+        assert_eq!(cipher.inverse(), &[[25, 22], [1, 23]]);
+        assert_eq!(cipher.encrypt("short example"), "APADJTFTWLFJ");
+        assert_eq!(
+            cipher.decrypt("APADJTFTWLFJ"),
+            Ok(String::from("SHORTEXAMPLE"))
+        );
+    }
+
+    #[test]
+    fn test_padding_and_truncation() {
+        let cipher = HillCipher::<3>::from_key("gybnqkurp").expect("invertible key");
+
+        assert_eq!(cipher.encrypt("Hi!").len(), 3);
+        assert_eq!(
+            cipher.decrypt(&cipher.encrypt("Hi!")),
+            Ok(String::from("HIX"))
+        );
+        assert_eq!(cipher.decrypt("POHP"), Err(CipherError::Truncated));
+    }
+
+    #[test]
+    fn test_key_errors() {
+        assert_eq!(
+            HillCipher::<2>::from_key("ABC"),
+            Err(CipherError::KeyLength {
+                expected: 4,
+                actual: 3
+            })
+        );
+        assert_eq!(
+            HillCipher::<3>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]),
+            Err(CipherError::KeyNotInvertible { determinant: 0 })
+        );
+        assert_eq!(
+            HillCipher::<2>::new([[13, 0], [0, 1]]),
+            Err(CipherError::KeyNotInvertible { determinant: 13 })
+        );
+        assert!(HillCipher::<1>::new([[7]]).is_ok());
+    }
+}
@@ -0,0 +1,374 @@
+//! International Morse code.
+//!
+//! Letters are written as dots and dashes separated by single spaces, and words are
+//! separated by ` / `. Encoding ignores case and collapses runs of whitespace; decoding
+//! produces upper-case letters.
+//!
+//! The [`MorseExt`] adapters encode and decode a stream of characters lazily, yielding
+//! `Result<char, CipherError>` so that output can be consumed before the whole input has
+//! been read. Both stop after the first error.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::{MorseExt, morse};
+//!
+//! assert_eq!(morse::encode("SOS now")?, "... --- ... / -. --- .--");
+//! assert_eq!(morse::decode("... --- ... / -. --- .--")?, "SOS NOW");
+//!
+//! let first: String = "hello".chars().morse_encoded().take(5).collect::<Result<_, _>>()?;
+//! assert_eq!(first, ".... ");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::string::String;
+
+use crate::errors::CipherError;
+
+/// The characters Morse code can express, with their codes.
+const TABLE: [(char, &str); 54] = [
+    ('A', ".-"),
+    ('B', "-..."),
+    ('C', "-.-."),
+    ('D', "-.."),
+    ('E', "."),
+    ('F', "..-."),
+    ('G', "--."),
+    ('H', "...."),
+    ('I', ".."),
+    ('J', ".---"),
+    ('K', "-.-"),
+    ('L', ".-.."),
+    ('M', "--"),
+    ('N', "-."),
+    ('O', "---"),
+    ('P', ".--."),
+    ('Q', "--.-"),
+    ('R', ".-."),
+    ('S', "..."),
+    ('T', "-"),
+    ('U', "..-"),
+    ('V', "...-"),
+    ('W', ".--"),
+    ('X', "-..-"),
+    ('Y', "-.--"),
+    ('Z', "--.."),
+    ('0', "-----"),
+    ('1', ".----"),
+    ('2', "..---"),
+    ('3', "...--"),
+    ('4', "....-"),
+    ('5', "....."),
+    ('6', "-...."),
+    ('7', "--..."),
+    ('8', "---.."),
+    ('9', "----."),
+    ('.', ".-.-.-"),
+    (',', "--..--"),
+    ('?', "..--.."),
+    ('\'', ".----."),
+    ('!', "-.-.--"),
+    ('/', "-..-."),
+    ('(', "-.--."),
+    (')', "-.--.-"),
+    ('&', ".-..."),
+    (':', "---..."),
+    (';', "-.-.-."),
+    ('=', "-...-"),
+    ('+', ".-.-."),
+    ('-', "-....-"),
+    ('_', "..--.-"),
+    ('"', ".-..-."),
+    ('$', "...-..-"),
+    ('@', ".--.-."),
+];
+
+/// The length of the longest code in [`TABLE`].
+const MAX_CODE_LENGTH: usize = 7;
+
+/// Returns the code of `character`, ignoring case.
+fn code_for(character: char) -> Option<&'static str> {
+    let character = character.to_ascii_uppercase();
+
+    TABLE
+        .iter()
+        .find(|&&(candidate, _)| candidate == character)
+        .map(|&(_, code)| code)
+}
+
+/// Returns the character whose code is `code`.
+fn character_for(code: &[u8]) -> Option<char> {
+    TABLE
+        .iter()
+        .find(|&&(_, candidate)| candidate.as_bytes() == code)
+        .map(|&(character, _)| character)
+}
+
+/// Encodes `text` as Morse code.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character that has no Morse code.
+pub fn encode(text: &str) -> Result<String, CipherError> {
+    text.chars().morse_encoded().collect()
+}
+
+/// Decodes Morse code into upper-case text.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character other than `.`, `-`, `/` and
+/// whitespace, and [`CipherError::UnknownSequence`] for a group of dots and dashes that is
+/// not a code.
+pub fn decode(code: &str) -> Result<String, CipherError> {
+    code.chars().morse_decoded().collect()
+}
+
+/// Morse adapters for iterators over characters.
+pub trait MorseExt: Iterator<Item = char> + Sized {
+    /// Returns an iterator over the Morse code of the characters, as [`encode`] writes it.
+    fn morse_encoded(self) -> MorseEncoded<Self>;
+
+    /// Returns an iterator over the text that the Morse code in the characters spells, as
+    /// [`decode`] reads it.
+    fn morse_decoded(self) -> MorseDecoded<Self>;
+}
+
+impl<I: Iterator<Item = char>> MorseExt for I {
+    fn morse_encoded(self) -> MorseEncoded<Self> {
+        MorseEncoded {
+            chars: self,
+            separator: b"",
+            code: b"",
+            position: 0,
+            started: false,
+            word_break: false,
+            failed: false,
+        }
+    }
+
+    fn morse_decoded(self) -> MorseDecoded<Self> {
+        MorseDecoded {
+            chars: self,
+            code: [0; MAX_CODE_LENGTH],
+            length: 0,
+            start: 0,
+            position: 0,
+            pending_space: false,
+            failed: false,
+        }
+    }
+}
+
+/// An iterator that encodes characters as Morse code, created by
+/// [`MorseExt::morse_encoded`].
+#[derive(Debug, Clone)]
+pub struct MorseEncoded<I> {
+    chars: I,
+    separator: &'static [u8],
+    code: &'static [u8],
+    position: usize,
+    started: bool,
+    word_break: bool,
+    failed: bool,
+}
+
+impl<I: Iterator<Item = char>> Iterator for MorseEncoded<I> {
+    type Item = Result<char, CipherError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            for pending in [&mut self.separator, &mut self.code] {
+                if let Some((&symbol, rest)) = pending.split_first() {
+                    *pending = rest;
+                    return Some(Ok(char::from(symbol)));
+                }
+            }
+
+            if self.failed {
+                return None;
+            }
+
+            let character = self.chars.next()?;
+            let position = self.position;
+            self.position += character.len_utf8();
+
+            if character.is_whitespace() {
+                self.word_break = self.started;
+                continue;
+            }
+
+            let Some(code) = code_for(character) else {
+                self.failed = true;
+                return Some(Err(CipherError::InvalidSymbol {
+                    character,
+                    position,
+                }));
+            };
+
+            self.separator = match (self.started, self.word_break) {
+                (false, _) => b"",
+                (true, false) => b" ",
+                (true, true) => b" / ",
+            };
+            self.code = code.as_bytes();
+            self.started = true;
+            self.word_break = false;
+        }
+    }
+}
+
+/// An iterator that decodes Morse code into characters, created by
+/// [`MorseExt::morse_decoded`].
+#[derive(Debug, Clone)]
+pub struct MorseDecoded<I> {
+    chars: I,
+    code: [u8; MAX_CODE_LENGTH],
+    length: usize,
+    start: usize,
+    position: usize,
+    pending_space: bool,
+    failed: bool,
+}
+
+impl<I: Iterator<Item = char>> MorseDecoded<I> {
+    /// Decodes the buffered dots and dashes and empties the buffer.
+    fn take_code(&mut self) -> Result<char, CipherError> {
+        let code = &self.code[..self.length];
+        self.length = 0;
+
+        character_for(code).ok_or(CipherError::UnknownSequence {
+            position: self.start,
+        })
+    }
+
+    /// Stops decoding after `error`.
+    const fn fail(&mut self, error: CipherError) -> Result<char, CipherError> {
+        self.failed = true;
+        Err(error)
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for MorseDecoded<I> {
+    type Item = Result<char, CipherError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pending_space {
+            self.pending_space = false;
+            return Some(Ok(' '));
+        }
+
+        loop {
+            if self.failed {
+                return None;
+            }
+
+            let character = self.chars.next();
+            let position = self.position;
+            self.position += character.map_or(0, char::len_utf8);
+
+            match character {
+                Some(symbol @ ('.' | '-')) => {
+                    if self.length == 0 {
+                        self.start = position;
+                    }
+
+                    if self.length == MAX_CODE_LENGTH {
+                        let start = self.start;
+                        return Some(self.fail(CipherError::UnknownSequence { position: start }));
+                    }
+
+                    #[allow(clippy::cast_possible_truncation)] // The symbol is ASCII.
+                    let symbol = symbol as u8;
+                    self.code[self.length] = symbol;
+                    self.length += 1;
+                }
+                Some(separator) if separator == '/' || separator.is_whitespace() => {
+                    let word_break = separator == '/';
+
+                    if self.length > 0 {
+                        let decoded = self.take_code();
+                        self.pending_space = word_break && decoded.is_ok();
+                        self.failed = decoded.is_err();
+                        return Some(decoded);
+                    }
+
+                    if word_break {
+                        return Some(Ok(' '));
+                    }
+                }
+                Some(character) => {
+                    return Some(self.fail(CipherError::InvalidSymbol {
+                        character,
+                        position,
+                    }));
+                }
+                None if self.length > 0 => {
+                    let decoded = self.take_code();
+                    self.failed = true;
+                    return Some(decoded);
+                }
+                None => return None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_consistent() {
+        for &(character, code) in &TABLE {
+            assert!(code.len() <= MAX_CODE_LENGTH);
+            assert_eq!(character_for(code.as_bytes()), Some(character));
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let text = "  The QUICK fox, 1 + 1 = 2!  ";
+        let code = encode(text).unwrap_or_default();
+
+        assert!(code.starts_with("- .... . / --.- ..- .. -.-. -.- /"));
+        assert_eq!(decode(&code), Ok(String::from("THE QUICK FOX, 1 + 1 = 2!")));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            encode("a#"),
+            Err(CipherError::InvalidSymbol {
+                character: '#',
+                position: 1
+            })
+        );
+        assert_eq!(
+            decode(".- ........"),
+            Err(CipherError::UnknownSequence { position: 3 })
+        );
+        assert_eq!(
+            decode("... ..--"),
+            Err(CipherError::UnknownSequence { position: 4 })
+        );
+        assert_eq!(
+            decode(".-x"),
+            Err(CipherError::InvalidSymbol {
+                character: 'x',
+                position: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_decoding_is_lazy() {
+        let mut decoded = ".- / -... #".chars().morse_decoded();
+
+        assert_eq!(decoded.next(), Some(Ok('A')));
+        assert_eq!(decoded.next(), Some(Ok(' ')));
+        assert_eq!(decoded.next(), Some(Ok('B')));
+        assert!(matches!(decoded.next(), Some(Err(_))));
+        assert_eq!(decoded.next(), None);
+    }
+}
@@ -0,0 +1,287 @@
+//! Hiding data in the least significant bits of other data.
+//!
+//! [`Lsb`] writes a payload into the low bits of every byte of a carrier, such as the
+//! samples of an uncompressed image or sound, where the change is too small to notice.
+//! It works on plain byte slices and knows nothing about file formats, so callers pass
+//! in only the bytes that are safe to change.
+//!
+//! By default the payload is preceded by a header holding its length and CRC-32, so
+//! that [`Lsb::extract`] can find where it ends and tell whether the carrier really
+//! holds one. Bits are written most significant first.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::stego::Lsb;
+//!
+//! let mut pixels = vec![0x80_u8; 200];
+//! let lsb = Lsb::new().with_bits_per_byte(2);
+//!
+//! lsb.embed(&mut pixels, b"hidden")?;
+//! assert!(pixels.iter().all(|&pixel| pixel & 0xfc == 0x80));
+//! assert_eq!(lsb.extract(&pixels)?, b"hidden");
+//! # Ok::<(), libx::errors::EncodingError>(())
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{errors::EncodingError, hashing::crc::CRC_32_ISO_HDLC};
+
+/// The length of the header: the payload length and its CRC-32, both little-endian.
+const HEADER_LENGTH: usize = 8;
+
+/// Least-significant-bit embedding settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Lsb {
+    bits_per_byte: u8,
+    header: bool,
+}
+
+impl Lsb {
+    /// Creates settings that use one bit of every carrier byte and write a header.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bits_per_byte: 1,
+            header: true,
+        }
+    }
+
+    /// Uses the low `bits_per_byte` bits of every carrier byte. More bits hold more data
+    /// but change the carrier more.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits_per_byte` is not between 1 and 8.
+    #[must_use]
+    pub const fn with_bits_per_byte(mut self, bits_per_byte: u8) -> Self {
+        assert!(
+            bits_per_byte >= 1 && bits_per_byte <= 8,
+            "bits per byte must be between 1 and 8"
+        );
+
+        self.bits_per_byte = bits_per_byte;
+        self
+    }
+
+    /// Sets whether the payload is preceded by its length and CRC-32. Without the header
+    /// the payload uses all of the capacity, but [`extract`](Self::extract) cannot tell
+    /// where it ends.
+    #[must_use]
+    pub const fn with_header(mut self, header: bool) -> Self {
+        self.header = header;
+        self
+    }
+
+    /// Returns the number of low bits used in every carrier byte.
+    #[must_use]
+    pub const fn bits_per_byte(&self) -> u8 {
+        self.bits_per_byte
+    }
+
+    /// Returns whether the payload is preceded by a header.
+    #[must_use]
+    pub const fn header(&self) -> bool {
+        self.header
+    }
+
+    /// Returns the number of payload bytes a carrier of `carrier_len` bytes can hold.
+    #[must_use]
+    pub const fn capacity(&self, carrier_len: usize) -> usize {
+        let bytes = carrier_len / 8 * self.bits_per_byte as usize
+            + carrier_len % 8 * self.bits_per_byte as usize / 8;
+
+        if self.header {
+            bytes.saturating_sub(HEADER_LENGTH)
+        } else {
+            bytes
+        }
+    }
+
+    /// Writes `payload` into the low bits of `carrier`, after the header if there is one.
+    ///
+    /// Only as many carrier bytes as the data needs are changed, and only in their low
+    /// bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::BufferTooSmall`] with the [capacity](Self::capacity) of
+    /// the carrier if the payload does not fit, leaving the carrier unchanged.
+    pub fn embed(&self, carrier: &mut [u8], payload: &[u8]) -> Result<(), EncodingError> {
+        let capacity = self.capacity(carrier.len());
+
+        if payload.len() > capacity {
+            return Err(EncodingError::BufferTooSmall { capacity });
+        }
+
+        let mut header = Vec::new();
+
+        if self.header {
+            let length = u32::try_from(payload.len())
+                .map_err(|_| EncodingError::BufferTooSmall { capacity })?;
+
+            header.extend_from_slice(&length.to_le_bytes());
+            header.extend_from_slice(&CRC_32_ISO_HDLC.checksum(payload).to_le_bytes());
+        }
+
+        let bits = usize::from(self.bits_per_byte);
+        let bit_count = (header.len() + payload.len()) * 8;
+        let bit_at = |index: usize| {
+            let byte = header
+                .get(index / 8)
+                .or_else(|| payload.get(index / 8 - header.len()))
+                .copied()
+                .unwrap_or(0);
+
+            byte >> (7 - index % 8) & 1
+        };
+
+        let mask = u8::MAX >> (8 - bits);
+
+        for (position, byte) in carrier
+            .iter_mut()
+            .take(bit_count.div_ceil(bits))
+            .enumerate()
+        {
+            let chunk = (0..bits).fold(0, |chunk, bit| chunk << 1 | bit_at(position * bits + bit));
+            *byte = *byte & !mask | chunk;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the `index`-th byte that the low bits of `carrier` spell.
+    fn read_byte(self, carrier: &[u8], index: usize) -> u8 {
+        let bits = usize::from(self.bits_per_byte);
+
+        (index * 8..index * 8 + 8).fold(0, |byte, bit| {
+            let carrier_byte = carrier[bit / bits];
+            byte << 1 | carrier_byte >> (bits - 1 - bit % bits) & 1
+        })
+    }
+
+    /// Reads the payload from the low bits of `carrier`.
+    ///
+    /// With a header, the payload is checked against its CRC-32. Without one, every whole
+    /// byte the carrier can hold is returned, and the caller has to know how much of it
+    /// is the payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::UnexpectedEnd`] if the carrier is too short for the header
+    /// or the length it records, and [`EncodingError::ChecksumMismatch`] if the payload
+    /// does not match its CRC-32, which is also what a carrier without a payload usually
+    /// produces.
+    pub fn extract(&self, carrier: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let capacity = self.capacity(carrier.len());
+
+        if !self.header {
+            return Ok((0..capacity)
+                .map(|index| self.read_byte(carrier, index))
+                .collect());
+        }
+
+        if self.with_header(false).capacity(carrier.len()) < HEADER_LENGTH {
+            return Err(EncodingError::UnexpectedEnd);
+        }
+
+        let mut header = [0; HEADER_LENGTH];
+
+        for (index, byte) in header.iter_mut().enumerate() {
+            *byte = self.read_byte(carrier, index);
+        }
+
+        let [l0, l1, l2, l3, c0, c1, c2, c3] = header;
+        let length = usize::try_from(u32::from_le_bytes([l0, l1, l2, l3]))
+            .map_err(|_| EncodingError::UnexpectedEnd)?;
+
+        if length > capacity {
+            return Err(EncodingError::UnexpectedEnd);
+        }
+
+        let payload: Vec<u8> = (HEADER_LENGTH..HEADER_LENGTH + length)
+            .map(|index| self.read_byte(carrier, index))
+            .collect();
+
+        if CRC_32_ISO_HDLC.checksum(&payload) != u32::from_le_bytes([c0, c1, c2, c3]) {
+            return Err(EncodingError::ChecksumMismatch);
+        }
+
+        Ok(payload)
+    }
+}
+
+impl Default for Lsb {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_round_trip_at_every_width() {
+        let payload = b"The quick brown fox";
+
+        for bits in 1..=8 {
+            let lsb = Lsb::new().with_bits_per_byte(bits);
+            let mut carrier: Vec<u8> = (0..=255).collect();
+            let original = carrier.clone();
+
+            assert_eq!(lsb.embed(&mut carrier, payload), Ok(()));
+            assert_eq!(lsb.extract(&carrier).as_deref(), Ok(&payload[..]));
+
+            let mask = u8::MAX >> (8 - bits);
+            assert!(
+                carrier
+                    .iter()
+                    .zip(&original)
+                    .all(|(a, b)| a & !mask == b & !mask)
+            );
+        }
+    }
+
+    #[test]
+    fn test_capacity() {
+        let lsb = Lsb::new();
+
+        assert_eq!(lsb.capacity(80), 2);
+        assert_eq!(lsb.capacity(60), 0);
+        assert_eq!(lsb.with_bits_per_byte(3).capacity(11), 0);
+        assert_eq!(lsb.with_bits_per_byte(3).with_header(false).capacity(11), 4);
+
+        let mut carrier = [0; 80];
+        assert_eq!(
+            lsb.embed(&mut carrier, b"abc"),
+            Err(EncodingError::BufferTooSmall { capacity: 2 })
+        );
+        assert_eq!(carrier, [0; 80]);
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        let lsb = Lsb::new();
+
+        assert_eq!(lsb.extract(&[0; 10]), Err(EncodingError::UnexpectedEnd));
+        assert_eq!(lsb.extract(&[0xff; 200]), Err(EncodingError::UnexpectedEnd));
+
+        let mut carrier = vec![0; 200];
+        assert_eq!(lsb.embed(&mut carrier, b"data"), Ok(()));
+        carrier[90] ^= 1;
+        assert_eq!(lsb.extract(&carrier), Err(EncodingError::ChecksumMismatch));
+    }
+
+    #[test]
+    fn test_headerless() {
+        let lsb = Lsb::new().with_bits_per_byte(4).with_header(false);
+        let mut carrier = [0xaa; 5];
+
+        assert_eq!(lsb.embed(&mut carrier, b"\x12\x34"), Ok(()));
+        assert_eq!(carrier, [0xa1, 0xa2, 0xa3, 0xa4, 0xaa]);
+        assert_eq!(lsb.extract(&carrier), Ok(vec![0x12, 0x34]));
+    }
+}
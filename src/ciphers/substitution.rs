@@ -0,0 +1,305 @@
+//! The simple substitution cipher.
+//!
+//! A [`SubstitutionCipher`] replaces every letter of the alphabet with another one, as
+//! given by a cipher alphabet: a permutation of `A` to `Z` in which the first letter
+//! stands for `A`, the second for `B`, and so on. The cipher alphabet can be written out
+//! in full, derived from a keyword, or shuffled by a [`RandomNumberGenerator`].
+//!
+//! Case is kept, and everything that is not an ASCII letter passes through unchanged.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::substitution::SubstitutionCipher;
+//!
+//! let cipher = SubstitutionCipher::from_keyword("zebras")?;
+//! assert_eq!(cipher.alphabet(), "ZEBRASCDFGHIJKLMNOPQTUVWXY");
+//!
+//! let secret = cipher.encrypt("Flee at once. We are discovered!");
+//! assert_eq!(secret, "Siaa zq lkba. Va zoa rfpbluaoar!");
+//! assert_eq!(cipher.decrypt(&secret), "Flee at once. We are discovered!");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::string::String;
+use core::{fmt, str};
+
+use crate::{errors::CipherError, random::RandomNumberGenerator};
+
+/// The plain alphabet, which the identity cipher uses.
+const PLAIN: [u8; 26] = *b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A simple substitution cipher over the 26 letters of the Latin alphabet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubstitutionCipher {
+    encryption: [u8; 26],
+    decryption: [u8; 26],
+}
+
+impl SubstitutionCipher {
+    /// Creates the cipher that leaves every letter as it is.
+    #[must_use]
+    pub const fn identity() -> Self {
+        Self {
+            encryption: PLAIN,
+            decryption: PLAIN,
+        }
+    }
+
+    /// Creates a cipher from the letters that `A` to `Z` encrypt to.
+    fn from_encryption(encryption: [u8; 26]) -> Self {
+        let mut decryption = [0; 26];
+
+        for (plain, &cipher) in PLAIN.iter().zip(&encryption) {
+            decryption[usize::from(cipher - b'A')] = *plain;
+        }
+
+        Self {
+            encryption,
+            decryption,
+        }
+    }
+
+    /// Creates a cipher from a cipher alphabet of 26 distinct letters, in either case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::InvalidSymbol`] for a character that is not an ASCII letter,
+    /// [`CipherError::DuplicateLetter`] for a letter that appears twice, and
+    /// [`CipherError::KeyLength`] if the alphabet does not have 26 letters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{ciphers::substitution::SubstitutionCipher, errors::CipherError};
+    ///
+    /// let reversed = SubstitutionCipher::from_alphabet("zyxwvutsrqponmlkjihgfedcba")?;
+    /// assert_eq!(reversed.encrypt("Wizard"), "Draziw");
+    ///
+    /// assert_eq!(
+    ///     SubstitutionCipher::from_alphabet("ABCDEFGHIJKLMNOPQRSTUVWXYA"),
+    ///     Err(CipherError::DuplicateLetter { letter: 'A' })
+    /// );
+    /// # Ok::<(), CipherError>(())
+    /// ```
+    pub fn from_alphabet(alphabet: &str) -> Result<Self, CipherError> {
+        let mut encryption = [0; 26];
+        let mut seen = [false; 26];
+        let mut length = 0;
+
+        for (position, character) in alphabet.char_indices() {
+            if !character.is_ascii_alphabetic() {
+                return Err(CipherError::InvalidSymbol {
+                    character,
+                    position,
+                });
+            }
+
+            #[allow(clippy::cast_possible_truncation)] // The letter is ASCII.
+            let letter = character.to_ascii_uppercase() as u8;
+            let index = usize::from(letter - b'A');
+
+            if seen[index] {
+                return Err(CipherError::DuplicateLetter {
+                    letter: char::from(letter),
+                });
+            }
+
+            seen[index] = true;
+            encryption[length] = letter;
+            length += 1;
+        }
+
+        if length != 26 {
+            return Err(CipherError::KeyLength {
+                expected: 26,
+                actual: length,
+            });
+        }
+
+        Ok(Self::from_encryption(encryption))
+    }
+
+    /// Creates a cipher whose alphabet is the distinct letters of `keyword` followed by
+    /// the rest of the alphabet in order. Whitespace in the keyword is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CipherError::InvalidSymbol`] for a character in `keyword` other than an
+    /// ASCII letter or whitespace.
+    pub fn from_keyword(keyword: &str) -> Result<Self, CipherError> {
+        let mut encryption = [0; 26];
+        let mut seen = [false; 26];
+        let mut length = 0;
+
+        let letters = keyword
+            .char_indices()
+            .filter(|&(_, character)| !character.is_whitespace())
+            .map(|(position, character)| {
+                character.is_ascii_alphabetic().then_some(character).ok_or(
+                    CipherError::InvalidSymbol {
+                        character,
+                        position,
+                    },
+                )
+            })
+            .chain(PLAIN.iter().map(|&letter| Ok(char::from(letter))));
+
+        for letter in letters {
+            #[allow(clippy::cast_possible_truncation)] // The letter is ASCII.
+            let letter = letter?.to_ascii_uppercase() as u8;
+            let index = usize::from(letter - b'A');
+
+            if !seen[index] {
+                seen[index] = true;
+                encryption[length] = letter;
+                length += 1;
+            }
+        }
+
+        Ok(Self::from_encryption(encryption))
+    }
+
+    /// Creates a cipher with a cipher alphabet shuffled by `rng`.
+    ///
+    /// Every one of the 26! alphabets is equally likely, but the key is only as
+    /// unpredictable as the generator.
+    #[must_use]
+    pub fn random<R: RandomNumberGenerator + ?Sized>(rng: &mut R) -> Self {
+        let mut encryption = PLAIN;
+
+        for last in (1..encryption.len()).rev() {
+            #[allow(clippy::cast_possible_truncation)] // The bound is at most 26.
+            let other = rng.next_bounded(last as u64 + 1) as usize;
+            encryption.swap(last, other);
+        }
+
+        Self::from_encryption(encryption)
+    }
+
+    /// Returns the cipher alphabet in upper case.
+    #[must_use]
+    pub const fn alphabet(&self) -> &str {
+        // SAFETY: the alphabet only ever holds ASCII letters.
+        unsafe { str::from_utf8_unchecked(&self.encryption) }
+    }
+
+    /// Returns the cipher that undoes this one.
+    #[must_use]
+    pub const fn inverse(&self) -> Self {
+        Self {
+            encryption: self.decryption,
+            decryption: self.encryption,
+        }
+    }
+
+    /// Replaces every letter of `text` using `table`, keeping its case.
+    fn substitute(table: &[u8; 26], text: &str) -> String {
+        text.chars()
+            .map(|character| {
+                if !character.is_ascii_alphabetic() {
+                    return character;
+                }
+
+                #[allow(clippy::cast_possible_truncation)] // The letter is ASCII.
+                let index = usize::from(character.to_ascii_uppercase() as u8 - b'A');
+                let replacement = char::from(table[index]);
+
+                if character.is_ascii_lowercase() {
+                    replacement.to_ascii_lowercase()
+                } else {
+                    replacement
+                }
+            })
+            .collect()
+    }
+
+    /// Encrypts `plaintext`.
+    #[must_use]
+    pub fn encrypt(&self, plaintext: &str) -> String {
+        Self::substitute(&self.encryption, plaintext)
+    }
+
+    /// Decrypts `ciphertext`.
+    #[must_use]
+    pub fn decrypt(&self, ciphertext: &str) -> String {
+        Self::substitute(&self.decryption, ciphertext)
+    }
+}
+
+impl Default for SubstitutionCipher {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for SubstitutionCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.alphabet())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn test_alphabet_validation() {
+        assert_eq!(
+            SubstitutionCipher::from_alphabet("QWERTY"),
+            Err(CipherError::KeyLength {
+                expected: 26,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            SubstitutionCipher::from_alphabet("QWERTYUIOPASDFGHJKLZXCVBNMQ"),
+            Err(CipherError::DuplicateLetter { letter: 'Q' })
+        );
+        assert_eq!(
+            SubstitutionCipher::from_alphabet("QWERTYUIOPASDFGHJKLZXCVBN1"),
+            Err(CipherError::InvalidSymbol {
+                character: '1',
+                position: 25
+            })
+        );
+
+        let cipher = SubstitutionCipher::from_alphabet("qwertyuiopasdfghjklzxcvbnm");
+        assert_eq!(
+            cipher.map(|cipher| cipher.to_string()),
+            Ok(String::from("QWERTYUIOPASDFGHJKLZXCVBNM"))
+        );
+    }
+
+    #[test]
+    fn test_keyword() {
+        let cipher = SubstitutionCipher::from_keyword("Secret Key").unwrap_or_default();
+
+        assert_eq!(cipher.alphabet(), "SECRTKYABDFGHIJLMNOPQUVWXZ");
+        assert_eq!(
+            SubstitutionCipher::from_keyword("no-way"),
+            Err(CipherError::InvalidSymbol {
+                character: '-',
+                position: 2
+            })
+        );
+        assert_eq!(
+            SubstitutionCipher::from_keyword(""),
+            Ok(SubstitutionCipher::identity())
+        );
+    }
+
+    #[test]
+    fn test_random_round_trip() {
+        let cipher = SubstitutionCipher::random(&mut SplitMix64::new(5));
+        let text = "Pack my box with five dozen liquor jugs.";
+
+        assert!(SubstitutionCipher::from_alphabet(cipher.alphabet()).is_ok());
+        assert_ne!(cipher.encrypt(text), text);
+        assert_eq!(cipher.decrypt(&cipher.encrypt(text)), text);
+        assert_eq!(cipher.inverse().encrypt(&cipher.encrypt(text)), text);
+    }
+}
@@ -0,0 +1,210 @@
+//! The tap code.
+//!
+//! Letters are laid out in a 5x5 grid, with `C` standing in for `K`:
+//!
+//! ```text
+//!     1 2 3 4 5
+//! 1   A B C D E
+//! 2   F G H I J
+//! 3   L M N O P
+//! 4   Q R S T U
+//! 5   V W X Y Z
+//! ```
+//!
+//! A letter is sent as two groups of taps, the row and then the column. In writing, each
+//! tap is a `.`, the two groups of a letter are separated by a space, letters by two
+//! spaces and words by ` / `, as in [`morse`](super::morse).
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::tap_code;
+//!
+//! assert_eq!(tap_code::encode("Hi all")?, ".. ...  .. .... / . .  ... .  ... .");
+//! assert_eq!(tap_code::decode(".. ...  .. .... / . .  ... .  ... .")?, "HI ALL");
+//! assert_eq!(tap_code::decode(tap_code::encode("kick")?.as_str())?, "CICC");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::string::String;
+
+use crate::errors::CipherError;
+
+/// The letters of the grid, row by row.
+const GRID: &[u8; 25] = b"ABCDEFGHIJLMNOPQRSTUVWXYZ";
+
+/// The number of rows and columns in [`GRID`].
+const SIZE: usize = 5;
+
+/// Returns the row and column of `letter`, counting from one and ignoring case.
+fn taps_for(letter: char) -> Option<(usize, usize)> {
+    let letter = match letter.to_ascii_uppercase() {
+        'K' => 'C',
+        letter => letter,
+    };
+
+    let index = GRID.iter().position(|&cell| char::from(cell) == letter)?;
+    Some((index / SIZE + 1, index % SIZE + 1))
+}
+
+/// Finishes a group of `taps` that began at `start`, returning the letter it completes.
+fn end_group(
+    row: &mut Option<usize>,
+    taps: &mut usize,
+    start: usize,
+) -> Result<Option<char>, CipherError> {
+    if *taps == 0 {
+        return Ok(None);
+    }
+
+    if *taps > SIZE {
+        return Err(CipherError::UnknownSequence { position: start });
+    }
+
+    let column = core::mem::take(taps);
+
+    if let Some(row) = row.take() {
+        return Ok(Some(char::from(GRID[(row - 1) * SIZE + column - 1])));
+    }
+
+    *row = Some(column);
+    Ok(None)
+}
+
+/// Encodes the letters of `text` as the tap code.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character other than an ASCII letter or
+/// whitespace.
+pub fn encode(text: &str) -> Result<String, CipherError> {
+    let mut code = String::with_capacity(text.len() * 8);
+    let mut word_break = false;
+
+    for (position, character) in text.char_indices() {
+        if character.is_whitespace() {
+            word_break = !code.is_empty();
+            continue;
+        }
+
+        let (row, column) = taps_for(character).ok_or(CipherError::InvalidSymbol {
+            character,
+            position,
+        })?;
+
+        if word_break {
+            code.push_str(" / ");
+        } else if !code.is_empty() {
+            code.push_str("  ");
+        }
+        word_break = false;
+
+        code.extend(core::iter::repeat_n('.', row));
+        code.push(' ');
+        code.extend(core::iter::repeat_n('.', column));
+    }
+
+    Ok(code)
+}
+
+/// Decodes the tap code into upper-case text.
+///
+/// Groups of taps are paired up in order, so any amount of whitespace may separate them.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidSymbol`] for a character other than `.`, `/` and
+/// whitespace, [`CipherError::UnknownSequence`] for a group of more than five taps, and
+/// [`CipherError::Truncated`] if a word ends with a row that has no column.
+pub fn decode(code: &str) -> Result<String, CipherError> {
+    let mut text = String::with_capacity(code.len() / 4);
+    let mut row = None;
+    let mut taps = 0;
+    let mut start = 0;
+
+    // A trailing space flushes the last group.
+    for (position, character) in code.char_indices().chain([(code.len(), ' ')]) {
+        match character {
+            '.' => {
+                if taps == 0 {
+                    start = position;
+                }
+
+                taps += 1;
+            }
+            separator if separator == '/' || separator.is_whitespace() => {
+                if let Some(letter) = end_group(&mut row, &mut taps, start)? {
+                    text.push(letter);
+                }
+
+                if separator == '/' {
+                    if row.is_some() {
+                        return Err(CipherError::Truncated);
+                    }
+
+                    text.push(' ');
+                }
+            }
+            _ => {
+                return Err(CipherError::InvalidSymbol {
+                    character,
+                    position,
+                });
+            }
+        }
+    }
+
+    if row.is_some() {
+        return Err(CipherError::Truncated);
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_covers_alphabet() {
+        for letter in 'A'..='Z' {
+            let (row, column) = taps_for(letter).unwrap_or_default();
+            let expected = if letter == 'K' { 'C' } else { letter };
+
+            assert_eq!(char::from(GRID[(row - 1) * SIZE + column - 1]), expected);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let code = encode(" Water  works ").unwrap_or_default();
+
+        assert!(code.starts_with("..... ..  . .  .... ....  . .....  .... .. / "));
+        assert_eq!(decode(&code), Ok(String::from("WATER WORCS")));
+        assert_eq!(decode(". .\n..... .....  "), Ok(String::from("AZ")));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            encode("a-b"),
+            Err(CipherError::InvalidSymbol {
+                character: '-',
+                position: 1
+            })
+        );
+        assert_eq!(
+            decode(". ...... "),
+            Err(CipherError::UnknownSequence { position: 2 })
+        );
+        assert_eq!(decode(". / . ."), Err(CipherError::Truncated));
+        assert_eq!(decode(". .  .."), Err(CipherError::Truncated));
+        assert_eq!(
+            decode(". x"),
+            Err(CipherError::InvalidSymbol {
+                character: 'x',
+                position: 2
+            })
+        );
+    }
+}
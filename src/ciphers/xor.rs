@@ -0,0 +1,129 @@
+//! The repeating-key XOR cipher.
+//!
+//! Every byte of the input is combined with the next byte of the key by exclusive or,
+//! starting over at the beginning of the key when it runs out. The cipher is its own
+//! inverse, so the same key both encrypts and decrypts.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::ciphers::{XorExt, xor};
+//!
+//! let encrypted = xor::apply(b"attack at dawn", b"key")?;
+//! assert_eq!(xor::apply(&encrypted, b"key")?, b"attack at dawn");
+//!
+//! let lazy: Vec<u8> = encrypted.iter().copied().xor_cipher(b"key").collect();
+//! assert_eq!(lazy, b"attack at dawn");
+//! # Ok::<(), libx::errors::CipherError>(())
+//! ```
+
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::errors::CipherError;
+
+/// Returns `data` combined with the repeating `key`.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidKey`] if `key` is empty.
+pub fn apply(data: &[u8], key: &[u8]) -> Result<Vec<u8>, CipherError> {
+    if key.is_empty() {
+        return Err(CipherError::InvalidKey);
+    }
+
+    Ok(data.iter().copied().xor_cipher(key).collect())
+}
+
+/// Combines `data` with the repeating `key` in place.
+///
+/// # Errors
+///
+/// Returns [`CipherError::InvalidKey`] if `key` is empty, leaving `data` unchanged.
+pub fn apply_in_place(data: &mut [u8], key: &[u8]) -> Result<(), CipherError> {
+    if key.is_empty() {
+        return Err(CipherError::InvalidKey);
+    }
+
+    for (byte, key_byte) in data.iter_mut().zip(key.iter().cycle()) {
+        *byte ^= key_byte;
+    }
+
+    Ok(())
+}
+
+/// The XOR adapter for iterators over bytes.
+pub trait XorExt: Iterator<Item = u8> + Sized {
+    /// Returns an iterator over the bytes combined with the repeating `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` is empty.
+    fn xor_cipher(self, key: &[u8]) -> XorCipher<'_, Self>;
+}
+
+impl<I: Iterator<Item = u8>> XorExt for I {
+    fn xor_cipher(self, key: &[u8]) -> XorCipher<'_, Self> {
+        assert!(!key.is_empty(), "key must not be empty");
+
+        XorCipher {
+            bytes: self,
+            key,
+            position: 0,
+        }
+    }
+}
+
+/// An iterator that applies the XOR cipher, created by [`XorExt::xor_cipher`].
+#[derive(Debug, Clone)]
+pub struct XorCipher<'a, I> {
+    bytes: I,
+    key: &'a [u8],
+    position: usize,
+}
+
+impl<I: Iterator<Item = u8>> Iterator for XorCipher<'_, I> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.bytes.next()?;
+        let key_byte = self.key[self.position];
+
+        self.position = (self.position + 1) % self.key.len();
+        Some(byte ^ key_byte)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bytes.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator<Item = u8>> ExactSizeIterator for XorCipher<'_, I> {}
+
+impl<I: FusedIterator<Item = u8>> FusedIterator for XorCipher<'_, I> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_vector() {
+        let encrypted = apply(b"Burning 'em", b"ICE").unwrap_or_default();
+        assert_eq!(
+            encrypted,
+            [
+                0x0b, 0x36, 0x37, 0x27, 0x2a, 0x2b, 0x2e, 0x63, 0x62, 0x2c, 0x2e
+            ]
+        );
+
+        let mut in_place = *b"Burning 'em";
+        assert_eq!(apply_in_place(&mut in_place, b"ICE"), Ok(()));
+        assert_eq!(in_place.as_slice(), encrypted);
+    }
+
+    #[test]
+    fn test_empty_key() {
+        assert_eq!(apply(b"data", b""), Err(CipherError::InvalidKey));
+        assert_eq!(apply_in_place(&mut [1], b""), Err(CipherError::InvalidKey));
+    }
+}
@@ -0,0 +1,865 @@
+//! A small declarative command-line parser.
+//!
+//! A [`Command`] lists its [`Arg`]s and subcommands; [`Command::parse`] turns the
+//! program's arguments into [`Matches`], from which values are read as strings or
+//! converted with [`FromStr`]. `-h` and `--help` are handled automatically and return
+//! [`CliError::HelpRequested`] with the generated help text.
+//!
+//! The syntax follows common Unix conventions: `--long value`, `--long=value`, clustered
+//! short flags (`-vvx`), `-ovalue`, and `--` to end option parsing. A lone `-` and
+//! negative numbers such as `-5` are treated as values.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::cli::{Arg, Command};
+//!
+//! let command = Command::new("fetch")
+//!     .with_about("Downloads a file")
+//!     .with_arg(Arg::flag("verbose").with_short('v').with_help("Print progress"))
+//!     .with_arg(Arg::option("retries").with_default("3").with_help("Attempts before giving up"))
+//!     .with_arg(Arg::positional("url").with_required(true));
+//!
+//! let matches = command
+//!     .parse(["-v", "--retries=5", "https://example.com"])
+//!     .unwrap();
+//!
+//! assert!(matches.flag("verbose"));
+//! assert_eq!(matches.get::<u32>("retries"), Ok(Some(5)));
+//! assert_eq!(matches.value("url"), Some("https://example.com"));
+//! ```
+
+use alloc::{
+    borrow::Cow,
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt::Write, str::FromStr};
+
+use crate::{
+    errors::{CliError, FormatError},
+    formatting::TableFormatter,
+};
+
+/// What an [`Arg`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArgKind {
+    /// A switch such as `--verbose`, which takes no value and may be repeated.
+    Flag,
+
+    /// A named argument with a value, such as `--port 8080`.
+    Option,
+
+    /// A value identified by its position, such as an input file.
+    Positional,
+}
+
+/// An argument a [`Command`] accepts.
+///
+/// Flags and options get a long name equal to their name, which
+/// [`with_long`](Self::with_long) can change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arg {
+    name: Cow<'static, str>,
+    kind: ArgKind,
+    short: Option<char>,
+    long: Option<Cow<'static, str>>,
+    help: Cow<'static, str>,
+    value_name: Option<Cow<'static, str>>,
+    default: Option<Cow<'static, str>>,
+    is_required: bool,
+    takes_multiple: bool,
+}
+
+impl Arg {
+    /// Creates a flag, given as `--name`.
+    #[must_use]
+    pub fn flag(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(name.into(), ArgKind::Flag)
+    }
+
+    /// Creates an option, given as `--name value` or `--name=value`.
+    #[must_use]
+    pub fn option(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(name.into(), ArgKind::Option)
+    }
+
+    /// Creates a positional argument.
+    ///
+    /// Positional arguments are filled in the order they are added to the command.
+    #[must_use]
+    pub fn positional(name: impl Into<Cow<'static, str>>) -> Self {
+        Self::new(name.into(), ArgKind::Positional)
+    }
+
+    fn new(name: Cow<'static, str>, kind: ArgKind) -> Self {
+        Self {
+            long: (kind != ArgKind::Positional).then(|| name.clone()),
+            name,
+            kind,
+            short: None,
+            help: Cow::Borrowed(""),
+            value_name: None,
+            default: None,
+            is_required: false,
+            takes_multiple: false,
+        }
+    }
+
+    /// Sets the single-character name, given as `-c`.
+    #[must_use]
+    pub const fn with_short(mut self, short: char) -> Self {
+        self.short = Some(short);
+        self
+    }
+
+    /// Sets the long name, given as `--long`.
+    #[must_use]
+    pub fn with_long(mut self, long: impl Into<Cow<'static, str>>) -> Self {
+        self.long = Some(long.into());
+        self
+    }
+
+    /// Sets the description shown in the help text.
+    #[must_use]
+    pub fn with_help(mut self, help: impl Into<Cow<'static, str>>) -> Self {
+        self.help = help.into();
+        self
+    }
+
+    /// Sets the placeholder for the value in the help text; the default is the name in
+    /// capitals.
+    #[must_use]
+    pub fn with_value_name(mut self, value_name: impl Into<Cow<'static, str>>) -> Self {
+        self.value_name = Some(value_name.into());
+        self
+    }
+
+    /// Sets the value used when the argument is not given.
+    #[must_use]
+    pub fn with_default(mut self, default: impl Into<Cow<'static, str>>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Sets whether parsing fails when the argument is not given.
+    #[must_use]
+    pub const fn with_required(mut self, is_required: bool) -> Self {
+        self.is_required = is_required;
+        self
+    }
+
+    /// Sets whether a positional argument takes every remaining value; it must then be
+    /// the last positional argument.
+    #[must_use]
+    pub const fn with_multiple(mut self, takes_multiple: bool) -> Self {
+        self.takes_multiple = takes_multiple;
+        self
+    }
+
+    /// Returns the name values are looked up by in [`Matches`].
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns what the argument accepts.
+    #[must_use]
+    pub const fn kind(&self) -> ArgKind {
+        self.kind
+    }
+
+    /// Returns the single-character name.
+    #[must_use]
+    pub const fn short(&self) -> Option<char> {
+        self.short
+    }
+
+    /// Returns the long name.
+    #[must_use]
+    pub fn long(&self) -> Option<&str> {
+        self.long.as_deref()
+    }
+
+    /// Returns the description shown in the help text.
+    #[must_use]
+    pub fn help(&self) -> &str {
+        &self.help
+    }
+
+    /// Returns the value used when the argument is not given.
+    #[must_use]
+    pub fn default_value(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Returns `true` if parsing fails when the argument is not given.
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        self.is_required
+    }
+
+    /// Returns `true` if a positional argument takes every remaining value.
+    #[must_use]
+    pub const fn takes_multiple(&self) -> bool {
+        self.takes_multiple
+    }
+
+    /// Returns the argument as it is written in messages: `--long`, `-s` or `<VALUE>`.
+    fn display_name(&self) -> String {
+        match (&self.long, self.short) {
+            (Some(long), _) if self.kind != ArgKind::Positional => alloc::format!("--{long}"),
+            (_, Some(short)) if self.kind != ArgKind::Positional => alloc::format!("-{short}"),
+            _ => alloc::format!("<{}>", self.value_name()),
+        }
+    }
+
+    fn value_name(&self) -> String {
+        self.value_name
+            .as_deref()
+            .map_or_else(|| self.name.to_ascii_uppercase(), String::from)
+    }
+}
+
+/// A program or subcommand and the arguments it accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Command {
+    name: Cow<'static, str>,
+    about: Cow<'static, str>,
+    args: Vec<Arg>,
+    subcommands: Vec<Self>,
+}
+
+impl Command {
+    /// Creates a command without arguments.
+    #[must_use]
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            about: Cow::Borrowed(""),
+            args: Vec::new(),
+            subcommands: Vec::new(),
+        }
+    }
+
+    /// Sets the description shown at the top of the help text and in the parent's list
+    /// of commands.
+    #[must_use]
+    pub fn with_about(mut self, about: impl Into<Cow<'static, str>>) -> Self {
+        self.about = about.into();
+        self
+    }
+
+    /// Adds an argument.
+    #[must_use]
+    pub fn with_arg(mut self, arg: Arg) -> Self {
+        self.args.push(arg);
+        self
+    }
+
+    /// Adds a subcommand, selected by its name as the next positional value.
+    #[must_use]
+    pub fn with_subcommand(mut self, subcommand: Self) -> Self {
+        self.subcommands.push(subcommand);
+        self
+    }
+
+    /// Returns the name of the command.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description of the command.
+    #[must_use]
+    pub fn about(&self) -> &str {
+        &self.about
+    }
+
+    /// Returns the arguments of the command.
+    #[must_use]
+    pub fn args(&self) -> &[Arg] {
+        &self.args
+    }
+
+    /// Returns the subcommands of the command.
+    #[must_use]
+    pub fn subcommands(&self) -> &[Self] {
+        &self.subcommands
+    }
+
+    /// Parses `arguments`, which do not include the program name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CliError`] describing the first problem found, or
+    /// [`CliError::HelpRequested`] if help was asked for.
+    pub fn parse<I>(&self, arguments: I) -> Result<Matches, CliError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let arguments: Vec<String> = arguments
+            .into_iter()
+            .map(|argument| argument.as_ref().to_string())
+            .collect();
+
+        self.parse_arguments(&arguments)
+    }
+
+    /// Parses the arguments of the current process.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CliError`] as [`parse`](Self::parse) does.
+    #[cfg(feature = "std")]
+    pub fn parse_env(&self) -> Result<Matches, CliError> {
+        self.parse(std::env::args().skip(1))
+    }
+
+    /// Returns the help text, with lines separated by `\n` and no trailing newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::cli::{Arg, Command};
+    ///
+    /// let command = Command::new("greet")
+    ///     .with_about("Says hello")
+    ///     .with_arg(Arg::option("name").with_short('n').with_help("Who to greet"))
+    ///     .with_arg(Arg::positional("times"));
+    ///
+    /// assert_eq!(
+    ///     command.help(),
+    ///     "Says hello\n\
+    ///      \n\
+    ///      Usage: greet [OPTIONS] [TIMES]\n\
+    ///      \n\
+    ///      Arguments:\n  \
+    ///        [TIMES]\n\
+    ///      \n\
+    ///      Options:\n  \
+    ///        -n, --name <NAME>  Who to greet\n  \
+    ///        -h, --help         Print help"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write_help(&mut output);
+        output
+    }
+
+    /// Writes the help text into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write_help<W: Write>(&self, sink: &mut W) -> Result<(), FormatError> {
+        if !self.about.is_empty() {
+            write!(sink, "{}\n\n", self.about)?;
+        }
+
+        write!(sink, "Usage: {} [OPTIONS]", self.name)?;
+
+        let positionals: Vec<_> = self.positionals().collect();
+
+        for arg in &positionals {
+            write!(sink, " {}", positional_usage(arg))?;
+        }
+
+        if !self.subcommands.is_empty() {
+            sink.write_str(" <COMMAND>")?;
+        }
+
+        let table = TableFormatter::new();
+
+        if !positionals.is_empty() {
+            let rows: Vec<[String; 2]> = positionals
+                .iter()
+                .map(|arg| [positional_usage(arg), described(arg)])
+                .collect();
+            write_section("Arguments", &table.format(&rows), sink)?;
+        }
+
+        let mut rows: Vec<[String; 2]> = self
+            .args
+            .iter()
+            .filter(|arg| arg.kind != ArgKind::Positional)
+            .map(|arg| [option_usage(arg), described(arg)])
+            .collect();
+
+        if self.handles_help() {
+            rows.push(["-h, --help".into(), "Print help".into()]);
+        }
+
+        write_section("Options", &table.format(&rows), sink)?;
+
+        if !self.subcommands.is_empty() {
+            let rows: Vec<[&str; 2]> = self
+                .subcommands
+                .iter()
+                .map(|command| [command.name(), command.about()])
+                .collect();
+            write_section("Commands", &table.format(&rows), sink)?;
+        }
+
+        Ok(())
+    }
+
+    fn parse_arguments(&self, arguments: &[String]) -> Result<Matches, CliError> {
+        let mut matches = Matches::default();
+        let mut positionals = self.positionals();
+        let mut positional = positionals.next();
+        let mut index = 0;
+        let mut options_ended = false;
+
+        while let Some(argument) = arguments.get(index) {
+            index += 1;
+
+            if !options_ended && argument == "--" {
+                options_ended = true;
+                continue;
+            }
+
+            if !options_ended && let Some(long) = argument.strip_prefix("--") {
+                self.parse_long(long, arguments, &mut index, &mut matches)?;
+                continue;
+            }
+
+            if !options_ended
+                && let Some(shorts) = argument.strip_prefix('-')
+                && !shorts.is_empty()
+                && !self.is_negative_number(argument)
+            {
+                self.parse_shorts(shorts, arguments, &mut index, &mut matches)?;
+                continue;
+            }
+
+            if !options_ended
+                && let Some(subcommand) = self
+                    .subcommands
+                    .iter()
+                    .find(|command| command.name == *argument)
+            {
+                let subcommand_matches = subcommand.parse_arguments(&arguments[index..])?;
+                matches.subcommand = Some((argument.clone(), Box::new(subcommand_matches)));
+                break;
+            }
+
+            let arg = positional.ok_or_else(|| CliError::UnknownArgument(argument.clone()))?;
+            matches.add_value(arg, argument);
+
+            if !arg.takes_multiple {
+                positional = positionals.next();
+            }
+        }
+
+        for arg in &self.args {
+            if matches.count(&arg.name) > 0 {
+                continue;
+            }
+
+            if let Some(default) = &arg.default {
+                matches
+                    .values
+                    .insert(arg.name.to_string(), alloc::vec![default.to_string()]);
+            } else if arg.is_required {
+                return Err(CliError::MissingArgument(arg.display_name()));
+            }
+        }
+
+        Ok(matches)
+    }
+
+    /// Parses `--long`, `--long=value` or `--long value`, with `index` pointing past it.
+    fn parse_long(
+        &self,
+        long: &str,
+        arguments: &[String],
+        index: &mut usize,
+        matches: &mut Matches,
+    ) -> Result<(), CliError> {
+        let (name, inline) = match long.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (long, None),
+        };
+
+        if name == "help" && self.handles_help() {
+            return Err(CliError::HelpRequested(self.help()));
+        }
+
+        let written = || alloc::format!("--{name}");
+        let arg = self
+            .args
+            .iter()
+            .find(|arg| arg.kind != ArgKind::Positional && arg.long() == Some(name))
+            .ok_or_else(|| CliError::UnknownArgument(written()))?;
+
+        match (arg.kind, inline) {
+            (ArgKind::Flag, Some(_)) => return Err(CliError::UnexpectedValue(written())),
+            (ArgKind::Flag, None) => matches.add_occurrence(arg),
+            (_, Some(value)) => matches.add_value(arg, value),
+            (_, None) => matches.add_value(arg, take_value(arguments, index, written)?),
+        }
+
+        Ok(())
+    }
+
+    /// Parses clustered short flags, ending at the first option, whose value is the rest
+    /// of the cluster or the next argument.
+    fn parse_shorts(
+        &self,
+        shorts: &str,
+        arguments: &[String],
+        index: &mut usize,
+        matches: &mut Matches,
+    ) -> Result<(), CliError> {
+        for (offset, short) in shorts.char_indices() {
+            if short == 'h' && self.handles_help() {
+                return Err(CliError::HelpRequested(self.help()));
+            }
+
+            let written = || alloc::format!("-{short}");
+            let arg = self
+                .args
+                .iter()
+                .find(|arg| arg.kind != ArgKind::Positional && arg.short == Some(short))
+                .ok_or_else(|| CliError::UnknownArgument(written()))?;
+
+            if arg.kind == ArgKind::Flag {
+                matches.add_occurrence(arg);
+                continue;
+            }
+
+            let rest = &shorts[offset + short.len_utf8()..];
+
+            if rest.is_empty() {
+                matches.add_value(arg, take_value(arguments, index, written)?);
+            } else {
+                matches.add_value(arg, rest);
+            }
+
+            break;
+        }
+
+        Ok(())
+    }
+
+    fn positionals(&self) -> impl Iterator<Item = &Arg> {
+        self.args
+            .iter()
+            .filter(|arg| arg.kind == ArgKind::Positional)
+    }
+
+    /// Returns `true` if `-h` and `--help` are not taken by the command's own arguments.
+    fn handles_help(&self) -> bool {
+        !self.args.iter().any(|arg| {
+            arg.kind != ArgKind::Positional
+                && (arg.short == Some('h') || arg.long() == Some("help"))
+        })
+    }
+
+    /// Returns `true` if `argument` is a negative number rather than short flags.
+    fn is_negative_number(&self, argument: &str) -> bool {
+        let digit_flags = self
+            .args
+            .iter()
+            .any(|arg| arg.short.is_some_and(|short| short.is_ascii_digit()));
+
+        !digit_flags && argument.parse::<f64>().is_ok()
+    }
+}
+
+/// The result of parsing arguments with a [`Command`].
+///
+/// Values are looked up by the [`Arg::name`] of their argument. Defaults are included,
+/// but do not count as occurrences.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Matches {
+    occurrences: BTreeMap<String, usize>,
+    values: BTreeMap<String, Vec<String>>,
+    subcommand: Option<(String, Box<Self>)>,
+}
+
+impl Matches {
+    /// Returns `true` if the argument `name` was given.
+    #[must_use]
+    pub fn flag(&self, name: &str) -> bool {
+        self.count(name) > 0
+    }
+
+    /// Returns how many times the argument `name` was given, as with `-vvv`.
+    #[must_use]
+    pub fn count(&self, name: &str) -> usize {
+        self.occurrences.get(name).copied().unwrap_or(0)
+    }
+
+    /// Returns the value of the argument `name`, or its default. If it was given more
+    /// than once, the last value wins.
+    #[must_use]
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.values.get(name)?.last().map(String::as_str)
+    }
+
+    /// Returns every value given for the argument `name`, in order.
+    pub fn values(&self, name: &str) -> impl Iterator<Item = &str> {
+        self.values
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(String::as_str)
+    }
+
+    /// Returns the value of the argument `name` converted to `T`.
+    ///
+    /// Any [`FromStr`] type works, including [`Number`](crate::num::Number) for values
+    /// that may be integers or floats.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CliError::InvalidValue`] if the value does not parse.
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, CliError> {
+        self.value(name)
+            .map(|value| {
+                value.parse().map_err(|_| CliError::InvalidValue {
+                    argument: name.to_string(),
+                    value: value.to_string(),
+                })
+            })
+            .transpose()
+    }
+
+    /// Returns every value of the argument `name` converted to `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CliError::InvalidValue`] for the first value that does not parse.
+    pub fn get_all<T: FromStr>(&self, name: &str) -> Result<Vec<T>, CliError> {
+        self.values(name)
+            .map(|value| {
+                value.parse().map_err(|_| CliError::InvalidValue {
+                    argument: name.to_string(),
+                    value: value.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the name and matches of the subcommand that was given.
+    #[must_use]
+    pub fn subcommand(&self) -> Option<(&str, &Self)> {
+        self.subcommand
+            .as_ref()
+            .map(|(name, matches)| (name.as_str(), &**matches))
+    }
+
+    fn add_occurrence(&mut self, arg: &Arg) {
+        *self.occurrences.entry(arg.name.to_string()).or_default() += 1;
+    }
+
+    fn add_value(&mut self, arg: &Arg, value: &str) {
+        self.add_occurrence(arg);
+        self.values
+            .entry(arg.name.to_string())
+            .or_default()
+            .push(value.to_string());
+    }
+}
+
+/// Returns the argument at `index` as an option's value and moves past it.
+fn take_value<'a>(
+    arguments: &'a [String],
+    index: &mut usize,
+    written: impl FnOnce() -> String,
+) -> Result<&'a str, CliError> {
+    let value = arguments
+        .get(*index)
+        .ok_or_else(|| CliError::MissingValue(written()))?;
+    *index += 1;
+    Ok(value)
+}
+
+fn positional_usage(arg: &Arg) -> String {
+    let ellipsis = if arg.takes_multiple { "..." } else { "" };
+
+    if arg.is_required {
+        alloc::format!("<{}>{ellipsis}", arg.value_name())
+    } else {
+        alloc::format!("[{}]{ellipsis}", arg.value_name())
+    }
+}
+
+fn option_usage(arg: &Arg) -> String {
+    let mut usage = match (arg.short, arg.long()) {
+        (Some(short), Some(long)) => alloc::format!("-{short}, --{long}"),
+        (Some(short), None) => alloc::format!("-{short}"),
+        (None, Some(long)) => alloc::format!("    --{long}"),
+        (None, None) => String::new(),
+    };
+
+    if arg.kind == ArgKind::Option {
+        let _ = write!(usage, " <{}>", arg.value_name());
+    }
+
+    usage
+}
+
+fn described(arg: &Arg) -> String {
+    match &arg.default {
+        Some(default) if arg.help.is_empty() => alloc::format!("[default: {default}]"),
+        Some(default) => alloc::format!("{} [default: {default}]", arg.help),
+        None => arg.help.to_string(),
+    }
+}
+
+fn write_section<W: Write>(title: &str, table: &str, sink: &mut W) -> Result<(), FormatError> {
+    write!(sink, "\n\n{title}:")?;
+
+    for line in table.lines() {
+        write!(sink, "\n  {line}")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::num::Number;
+
+    fn tool() -> Command {
+        Command::new("tool")
+            .with_arg(Arg::flag("verbose").with_short('v'))
+            .with_arg(Arg::flag("dry-run").with_short('n'))
+            .with_arg(Arg::option("output").with_short('o'))
+            .with_arg(Arg::option("offset"))
+            .with_arg(Arg::positional("input").with_required(true))
+            .with_arg(Arg::positional("rest").with_multiple(true))
+            .with_subcommand(
+                Command::new("serve")
+                    .with_about("Starts the server")
+                    .with_arg(Arg::option("port").with_short('p').with_default("8080")),
+            )
+    }
+
+    #[test]
+    fn test_flags_and_options() {
+        let matches = tool()
+            .parse(["-vvn", "-oout.txt", "--offset", "-2.5", "in.txt", "a", "b"])
+            .expect("valid arguments");
+
+        assert_eq!(matches.count("verbose"), 2);
+        assert!(matches.flag("dry-run"));
+        assert_eq!(matches.value("output"), Some("out.txt"));
+        assert_eq!(
+            matches.get::<Number>("offset"),
+            Ok(Some(Number::Float(-2.5)))
+        );
+        assert_eq!(matches.value("input"), Some("in.txt"));
+        assert_eq!(matches.values("rest").collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(matches.subcommand(), None);
+    }
+
+    #[test]
+    fn test_positional_values() {
+        let matches = tool()
+            .parse(["-5", "--", "--verbose", "-"])
+            .expect("valid arguments");
+
+        assert_eq!(matches.get::<i32>("input"), Ok(Some(-5)));
+        assert_eq!(
+            matches.values("rest").collect::<Vec<_>>(),
+            ["--verbose", "-"]
+        );
+        assert!(!matches.flag("verbose"));
+        assert_eq!(
+            matches.get_all::<i32>("rest"),
+            Err(CliError::InvalidValue {
+                argument: "rest".into(),
+                value: "--verbose".into()
+            })
+        );
+    }
+
+    #[test]
+    fn test_subcommands() {
+        let matches = tool()
+            .parse(["-v", "in", "serve", "-p", "9000"])
+            .expect("valid arguments");
+        let (name, serve) = matches.subcommand().expect("a subcommand");
+
+        assert_eq!(name, "serve");
+        assert_eq!(serve.get::<u16>("port"), Ok(Some(9000)));
+        assert!(matches.flag("verbose"));
+
+        let (_, serve) = tool()
+            .parse(["in", "serve"])
+            .expect("valid arguments")
+            .subcommand
+            .expect("a subcommand");
+        assert_eq!(serve.value("port"), Some("8080"));
+        assert!(!serve.flag("port"));
+    }
+
+    #[test]
+    fn test_errors() {
+        let tool = tool();
+        let error = |arguments: &[&str]| tool.parse(arguments).expect_err("invalid arguments");
+
+        assert_eq!(error(&[]), CliError::MissingArgument("<INPUT>".into()));
+        assert_eq!(
+            error(&["in", "--color"]),
+            CliError::UnknownArgument("--color".into())
+        );
+        assert_eq!(error(&["-x"]), CliError::UnknownArgument("-x".into()));
+        assert_eq!(error(&["in", "-o"]), CliError::MissingValue("-o".into()));
+        assert_eq!(
+            error(&["--output"]),
+            CliError::MissingValue("--output".into())
+        );
+        assert_eq!(
+            error(&["--verbose=yes"]),
+            CliError::UnexpectedValue("--verbose".into())
+        );
+        assert_eq!(
+            error(&["in", "serve", "--bogus"]),
+            CliError::UnknownArgument("--bogus".into())
+        );
+
+        let Err(CliError::HelpRequested(help)) = tool.parse(["in", "serve", "-h"]) else {
+            panic!("expected help");
+        };
+        assert!(help.starts_with("Starts the server\n\nUsage: serve [OPTIONS]"));
+    }
+
+    #[test]
+    fn test_help() {
+        assert_eq!(
+            tool().help(),
+            "Usage: tool [OPTIONS] <INPUT> [REST]... <COMMAND>\n\
+             \n\
+             Arguments:\n  \
+               <INPUT>\n  \
+               [REST]...\n\
+             \n\
+             Options:\n  \
+               -v, --verbose\n  \
+               -n, --dry-run\n  \
+               -o, --output <OUTPUT>\n      \
+               --offset <OFFSET>\n  \
+               -h, --help             Print help\n\
+             \n\
+             Commands:\n  \
+               serve  Starts the server"
+        );
+
+        let custom_help = Command::new("x").with_arg(Arg::flag("help").with_short('h'));
+        let matches = custom_help.parse(["-h"]).expect("a user-defined flag");
+        assert!(matches.flag("help"));
+        assert!(!custom_help.help().contains("Print help"));
+    }
+}
@@ -0,0 +1,314 @@
+//! A string stored inline with a fixed capacity.
+//!
+//! [`ArrayString`] keeps up to `N` bytes of UTF-8 in an array, so it needs no allocator.
+//! It implements [`core::fmt::Write`], which makes it a sink for `write!` and for the
+//! `write` methods of the formatters in [`crate::formatting`] on heapless targets.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::fmt::Write;
+//!
+//! use libx::collections::array_string::ArrayString;
+//!
+//! let mut text = ArrayString::<8>::new();
+//! write!(text, "{}-{}", 12, 34)?;
+//! assert_eq!(text, "12-34");
+//!
+//! // Output that does not fit is rejected as a whole.
+//! assert!(write!(text, "{}", "long").is_err());
+//! assert_eq!(text, "12-34");
+//! # Ok::<(), core::fmt::Error>(())
+//! ```
+
+use core::{borrow::Borrow, fmt, hash::Hash, ops::Deref, str};
+
+use crate::{errors::CollectionError, security::Zeroize};
+
+/// A UTF-8 string of at most `N` bytes, stored inline.
+#[derive(Clone, Copy)]
+pub struct ArrayString<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ArrayString<N> {
+    /// Creates an empty string.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum length of the string in bytes.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the length of the string in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the string holds no text.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of bytes that can still be appended.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns the contents as a string slice.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: only whole `&str`s and encoded `char`s are copied in, and truncation
+        // checks for character boundaries, so the first `len` bytes are valid UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.len]) }
+    }
+
+    /// Appends `character`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the character does not fit.
+    pub fn push(&mut self, character: char) {
+        self.try_push(character).expect("capacity exceeded");
+    }
+
+    /// Appends `character`, or leaves the string unchanged if it does not fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::CapacityExceeded`] if the character does not fit.
+    pub fn try_push(&mut self, character: char) -> Result<(), CollectionError> {
+        self.try_push_str(character.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Appends `text`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the text does not fit.
+    pub fn push_str(&mut self, text: &str) {
+        self.try_push_str(text).expect("capacity exceeded");
+    }
+
+    /// Appends `text`, or leaves the string unchanged if it does not fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::CapacityExceeded`] if the text does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::array_string::ArrayString, errors::CollectionError};
+    ///
+    /// let mut text = ArrayString::<4>::new();
+    ///
+    /// assert_eq!(text.try_push_str("ab"), Ok(()));
+    /// assert_eq!(
+    ///     text.try_push_str("cde"),
+    ///     Err(CollectionError::CapacityExceeded { capacity: 4 })
+    /// );
+    /// assert_eq!(text, "ab");
+    /// ```
+    pub fn try_push_str(&mut self, text: &str) -> Result<(), CollectionError> {
+        let end = self.len + text.len();
+
+        if end > N {
+            return Err(CollectionError::CapacityExceeded { capacity: N });
+        }
+
+        self.bytes[self.len..end].copy_from_slice(text.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+
+    /// Removes and returns the last character.
+    pub fn pop(&mut self) -> Option<char> {
+        let character = self.as_str().chars().next_back()?;
+        self.len -= character.len_utf8();
+        Some(character)
+    }
+
+    /// Shortens the string to `new_len` bytes. Does nothing if the string is already
+    /// shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is not on a character boundary.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len < self.len {
+            assert!(
+                self.as_str().is_char_boundary(new_len),
+                "new length is not on a character boundary"
+            );
+            self.len = new_len;
+        }
+    }
+
+    /// Removes all text.
+    pub const fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Default for ArrayString<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Zeroize for ArrayString<N> {
+    /// Zeroizes the whole buffer, not only the bytes in use, and empties the string.
+    fn zeroize(&mut self) {
+        self.bytes.zeroize();
+        self.len = 0;
+    }
+}
+
+impl<const N: usize> Deref for ArrayString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> Borrow<str> for ArrayString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for ArrayString<N> {
+    type Error = CollectionError;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let mut string = Self::new();
+        string.try_push_str(text)?;
+        Ok(string)
+    }
+}
+
+impl<const N: usize> fmt::Write for ArrayString<N> {
+    /// Fails without writing anything if `text` does not fit.
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.try_push_str(text).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<ArrayString<M>> for ArrayString<N> {
+    fn eq(&self, other: &ArrayString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayString<N> {}
+
+impl<const N: usize> PartialEq<str> for ArrayString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ArrayString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialOrd for ArrayString<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for ArrayString<N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl<const N: usize> Hash for ArrayString<N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_pop() {
+        let mut text = ArrayString::<5>::new();
+
+        text.push('a');
+        text.push('é');
+        assert_eq!(text.len(), 3);
+        assert_eq!(
+            text.try_push('€'),
+            Err(CollectionError::CapacityExceeded { capacity: 5 })
+        );
+
+        assert_eq!(text.pop(), Some('é'));
+        assert_eq!(text.remaining_capacity(), 4);
+        assert_eq!(text.pop(), Some('a'));
+        assert_eq!(text.pop(), None);
+    }
+
+    #[test]
+    #[should_panic = "new length is not on a character boundary"]
+    fn test_truncate_inside_character() {
+        let mut text = ArrayString::<4>::try_from("é").unwrap_or_default();
+        text.truncate(1);
+    }
+
+    #[test]
+    fn test_formatting_into_array_string() {
+        use crate::formatting::{ByteCountFormatter, NumberFormatter};
+
+        let mut text = ArrayString::<16>::new();
+        let sink: &mut dyn fmt::Write = &mut text;
+
+        assert_eq!(
+            NumberFormatter::new()
+                .with_uses_grouping_separator(true)
+                .write(1_234_567, sink),
+            Ok(())
+        );
+        assert_eq!(text, "1,234,567");
+
+        text.clear();
+        assert_eq!(ByteCountFormatter::new().write(2048, &mut text), Ok(()));
+        assert_eq!(text, "2 kB");
+    }
+}
@@ -0,0 +1,742 @@
+//! A vector stored inline with a fixed capacity.
+//!
+//! [`ArrayVec`] keeps up to `N` elements in an array, so like
+//! [`ArrayString`](super::array_string::ArrayString) it needs no allocator at all. It
+//! dereferences to a slice, which provides indexing, slicing, sorting and iteration.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{collections::array_vec::ArrayVec, errors::CollectionError};
+//!
+//! let mut readings = ArrayVec::<u16, 4>::new();
+//! readings.extend([310, 295, 302]);
+//! readings.push(288);
+//!
+//! assert!(readings.is_full());
+//! assert_eq!(
+//!     readings.try_push(300),
+//!     Err(CollectionError::CapacityExceeded { capacity: 4 })
+//! );
+//!
+//! readings.sort_unstable();
+//! assert_eq!(readings[..2], [288, 295]);
+//! assert_eq!(readings.iter().max(), Some(&310));
+//! ```
+
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt,
+    hash::Hash,
+    iter::FusedIterator,
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr, slice,
+};
+
+use crate::{errors::CollectionError, security::Zeroize};
+
+/// A vector of at most `N` elements, stored inline.
+pub struct ArrayVec<T, const N: usize> {
+    elements: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayVec<T, N> {
+    /// Creates an empty vector.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            elements: [const { MaybeUninit::uninit() }; N],
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector holds no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if no more elements fit.
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Returns the number of elements that can still be added.
+    #[must_use]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.len
+    }
+
+    /// Returns the elements as a slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: the first `len` elements are initialized.
+        unsafe { slice::from_raw_parts(self.elements.as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the elements as a mutable slice.
+    #[must_use]
+    pub const fn as_mut_slice(&mut self) -> &mut [T] {
+        // SAFETY: the first `len` elements are initialized.
+        unsafe { slice::from_raw_parts_mut(self.elements.as_mut_ptr().cast(), self.len) }
+    }
+
+    /// Appends `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vector is full.
+    pub fn push(&mut self, value: T) {
+        self.try_push(value).expect("capacity exceeded");
+    }
+
+    /// Appends `value`, or drops it and leaves the vector unchanged if the vector is
+    /// full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::CapacityExceeded`] if the vector is full.
+    pub fn try_push(&mut self, value: T) -> Result<(), CollectionError> {
+        if self.is_full() {
+            return Err(CollectionError::CapacityExceeded { capacity: N });
+        }
+
+        self.elements[self.len].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the last element.
+    pub const fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+
+        // SAFETY: the element was initialized and is now outside the length, so it is
+        // read exactly once.
+        Some(unsafe { self.elements[self.len].assume_init_read() })
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it to the right.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length or the vector is full.
+    pub fn insert(&mut self, index: usize, value: T) {
+        if let Err(error) = self.try_insert(index, value) {
+            panic!("{error}");
+        }
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it to the right, or
+    /// drops it and leaves the vector unchanged if the vector is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::IndexOutOfBounds`] if `index` is greater than the
+    /// length, or [`CollectionError::CapacityExceeded`] if the vector is full.
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), CollectionError> {
+        if index > self.len {
+            return Err(CollectionError::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+
+        if self.is_full() {
+            return Err(CollectionError::CapacityExceeded { capacity: N });
+        }
+
+        // SAFETY: `index <= len < N`, so both ranges lie within the array; the elements
+        // are moved up by one and the gap is filled before the length grows.
+        unsafe {
+            let gap = self.elements.as_mut_ptr().add(index);
+            ptr::copy(gap, gap.add(1), self.len - index);
+            (*gap).write(value);
+        }
+
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting the elements after it to
+    /// the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for length {}",
+            self.len
+        );
+
+        // SAFETY: `index < len`, so the element is initialized and read exactly once
+        // before the elements after it are moved down over it.
+        unsafe {
+            let hole = self.elements.as_mut_ptr().add(index);
+            let value = (*hole).assume_init_read();
+            ptr::copy(hole.add(1), hole, self.len - index - 1);
+            self.len -= 1;
+            value
+        }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        assert!(
+            index < self.len,
+            "index {index} out of bounds for length {}",
+            self.len
+        );
+
+        let last = self.len - 1;
+        self.as_mut_slice().swap(index, last);
+        self.pop().expect("the vector is not empty")
+    }
+
+    /// Shortens the vector to `new_len` elements, dropping the rest. Does nothing if
+    /// the vector is already shorter.
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.len {
+            return;
+        }
+
+        let tail: *mut [T] = &raw mut self.as_mut_slice()[new_len..];
+
+        // The length shrinks first, so a panicking destructor cannot cause a double
+        // drop.
+        self.len = new_len;
+
+        // SAFETY: the tail was initialized and is no longer part of the vector.
+        unsafe { ptr::drop_in_place(tail) };
+    }
+
+    /// Removes all elements.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Keeps only the elements for which `keep` returns `true`, in order.
+    ///
+    /// If `keep` panics, the elements not yet visited are kept, and none are leaked.
+    pub fn retain<F: FnMut(&mut T) -> bool>(&mut self, mut keep: F) {
+        /// Closes the gap left by removed elements, even if `keep` or a destructor
+        /// panics.
+        struct Compactor<'a, T, const N: usize> {
+            vec: &'a mut ArrayVec<T, N>,
+            original_len: usize,
+            processed: usize,
+            removed: usize,
+        }
+
+        impl<T, const N: usize> Drop for Compactor<'_, T, N> {
+            fn drop(&mut self) {
+                let base = self.vec.elements.as_mut_ptr().cast::<T>();
+
+                if self.removed > 0 {
+                    // SAFETY: the unvisited elements are initialized, and the destination
+                    // is the gap left by the removed ones, within the array.
+                    unsafe {
+                        ptr::copy(
+                            base.add(self.processed),
+                            base.add(self.processed - self.removed),
+                            self.original_len - self.processed,
+                        );
+                    }
+                }
+
+                self.vec.len = self.original_len - self.removed;
+            }
+        }
+
+        let original_len = self.len;
+
+        // The vector is empty until the compactor restores its length, so a panic can
+        // never expose a dropped or duplicated element.
+        self.len = 0;
+
+        let mut compactor = Compactor {
+            vec: self,
+            original_len,
+            processed: 0,
+            removed: 0,
+        };
+
+        while compactor.processed < original_len {
+            let index = compactor.processed;
+
+            // SAFETY: elements from `processed` on have not been moved or dropped.
+            let current = unsafe { compactor.vec.elements[index].assume_init_mut() };
+
+            if keep(current) {
+                if compactor.removed > 0 {
+                    let base = compactor.vec.elements.as_mut_ptr().cast::<T>();
+
+                    // SAFETY: the destination slot was vacated by a removed element.
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            base.add(index),
+                            base.add(index - compactor.removed),
+                            1,
+                        );
+                    }
+                }
+
+                compactor.processed += 1;
+            } else {
+                // Counted before dropping, so a panicking destructor is not dropped twice.
+                compactor.processed += 1;
+                compactor.removed += 1;
+
+                // SAFETY: the element is initialized and is now outside the kept range.
+                unsafe { ptr::drop_in_place(current) };
+            }
+        }
+    }
+
+    /// Appends clones of every element of `values`, or leaves the vector unchanged if
+    /// they do not all fit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::CapacityExceeded`] if the elements do not fit.
+    pub fn try_extend_from_slice(&mut self, values: &[T]) -> Result<(), CollectionError>
+    where
+        T: Clone,
+    {
+        if values.len() > self.remaining_capacity() {
+            return Err(CollectionError::CapacityExceeded { capacity: N });
+        }
+
+        for value in values {
+            self.push(value.clone());
+        }
+
+        Ok(())
+    }
+
+    /// Returns the elements as an array if the vector is full, or itself otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns the vector unchanged if it is not full.
+    pub fn into_inner(self) -> Result<[T; N], Self> {
+        if !self.is_full() {
+            return Err(self);
+        }
+
+        let this = ManuallyDrop::new(self);
+
+        // SAFETY: every element is initialized, and `this` is never dropped, so each is
+        // moved out exactly once.
+        Ok(unsafe { ptr::from_ref(&this.elements).cast::<[T; N]>().read() })
+    }
+}
+
+impl<T, const N: usize> Drop for ArrayVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Default for ArrayVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Zeroize, const N: usize> Zeroize for ArrayVec<T, N> {
+    /// Zeroizes every element in place, then removes them all.
+    fn zeroize(&mut self) {
+        for value in self.as_mut_slice() {
+            value.zeroize();
+        }
+
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Deref for ArrayVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for ArrayVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for ArrayVec<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for ArrayVec<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for ArrayVec<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for ArrayVec<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for ArrayVec<T, N> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ArrayVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<ArrayVec<T, M>> for ArrayVec<T, N> {
+    fn eq(&self, other: &ArrayVec<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T]> for ArrayVec<T, N> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const N: usize, const M: usize> PartialEq<[T; M]> for ArrayVec<T, N> {
+    fn eq(&self, other: &[T; M]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for ArrayVec<T, N> {}
+
+impl<T: PartialOrd, const N: usize> PartialOrd for ArrayVec<T, N> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const N: usize> Ord for ArrayVec<T, N> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const N: usize> Hash for ArrayVec<T, N> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for ArrayVec<T, N> {
+    fn from(array: [T; N]) -> Self {
+        let array = ManuallyDrop::new(array);
+
+        Self {
+            // SAFETY: `MaybeUninit<T>` has the layout of `T`, and the array is never
+            // dropped, so each element is moved exactly once.
+            elements: unsafe { ptr::from_ref(&*array).cast::<[MaybeUninit<T>; N]>().read() },
+            len: N,
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> TryFrom<&[T]> for ArrayVec<T, N> {
+    type Error = CollectionError;
+
+    fn try_from(values: &[T]) -> Result<Self, Self::Error> {
+        let mut vec = Self::new();
+        vec.try_extend_from_slice(values)?;
+        Ok(vec)
+    }
+}
+
+impl<T, const N: usize> Extend<T> for ArrayVec<T, N> {
+    /// Appends every element of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the elements do not fit.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for ArrayVec<T, N> {
+    /// Collects the elements of `iter`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than `N` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a ArrayVec<T, N> {
+    type Item = &'a T;
+
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a mut ArrayVec<T, N> {
+    type Item = &'a mut T;
+
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ArrayVec<T, N> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let end = self.len;
+        let this = ManuallyDrop::new(self);
+
+        IntoIter {
+            // SAFETY: `this` is never dropped, so the elements are moved out of it
+            // exactly once, into the iterator.
+            elements: unsafe { ptr::from_ref(&this.elements).read() },
+            start: 0,
+            end,
+        }
+    }
+}
+
+/// An owning iterator over the elements of an [`ArrayVec`].
+///
+/// Created by the [`IntoIterator`] implementation for [`ArrayVec`].
+pub struct IntoIter<T, const N: usize> {
+    elements: [MaybeUninit<T>; N],
+    start: usize,
+    end: usize,
+}
+
+impl<T, const N: usize> IntoIter<T, N> {
+    /// Returns the elements not yet yielded as a slice.
+    #[must_use]
+    pub const fn as_slice(&self) -> &[T] {
+        // SAFETY: the elements in `start..end` are initialized and not yet yielded.
+        unsafe {
+            slice::from_raw_parts(
+                self.elements.as_ptr().add(self.start).cast(),
+                self.end - self.start,
+            )
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.start += 1;
+
+        // SAFETY: the element was initialized and is now outside `start..end`, so it is
+        // read exactly once.
+        Some(unsafe { self.elements[self.start - 1].assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+
+        // SAFETY: as in `next`.
+        Some(unsafe { self.elements[self.end].assume_init_read() })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> FusedIterator for IntoIter<T, N> {}
+
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        // SAFETY: the elements in `start..end` are initialized and never yielded, so
+        // they are dropped exactly once.
+        unsafe {
+            let rest = self.elements.as_mut_ptr().add(self.start).cast::<T>();
+            ptr::drop_in_place(ptr::slice_from_raw_parts_mut(rest, self.end - self.start));
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for IntoIter<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::{rc::Rc, vec::Vec};
+
+    use super::*;
+
+    #[test]
+    fn test_push_insert_and_remove() {
+        let mut vec = ArrayVec::<i32, 4>::new();
+
+        vec.push(1);
+        vec.push(3);
+        vec.insert(1, 2);
+        assert_eq!(vec, [1, 2, 3]);
+        assert_eq!(
+            vec.try_insert(5, 0),
+            Err(CollectionError::IndexOutOfBounds { index: 5, len: 3 })
+        );
+
+        vec.insert(0, 0);
+        assert_eq!(
+            vec.try_insert(0, 9),
+            Err(CollectionError::CapacityExceeded { capacity: 4 })
+        );
+        assert_eq!(vec.remove(1), 1);
+        assert_eq!(vec.swap_remove(0), 0);
+        assert_eq!(vec, [3, 2]);
+        assert_eq!(vec.pop(), Some(2));
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.pop(), None);
+
+        assert_eq!(
+            ArrayVec::<i32, 2>::try_from(&[1, 2, 3][..]),
+            Err(CollectionError::CapacityExceeded { capacity: 2 })
+        );
+        assert_eq!(ArrayVec::from([1, 2]).into_inner(), Ok([1, 2]));
+        assert_eq!(
+            ArrayVec::<i32, 2>::from_iter([1]).into_inner(),
+            Err(ArrayVec::from_iter([1]))
+        );
+    }
+
+    #[test]
+    fn test_elements_are_dropped_once() {
+        let value = Rc::new(());
+        let mut vec: ArrayVec<_, 8> = (0..6).map(|_| Rc::clone(&value)).collect();
+
+        vec.truncate(5);
+        vec.retain({
+            let mut keep = false;
+            move |_| {
+                keep = !keep;
+                keep
+            }
+        });
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        let copy = vec.clone();
+        let mut iter = vec.into_iter();
+        iter.next();
+        iter.next_back();
+        assert_eq!(Rc::strong_count(&value), 5);
+
+        drop(iter);
+        drop(copy);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_retain_survives_panic() {
+        let value = Rc::new(());
+        let mut vec: ArrayVec<_, 6> = (0..6).map(|n| (n, Rc::clone(&value))).collect();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.retain(|&mut (n, _)| {
+                assert!(n != 3, "unlucky");
+                n % 2 == 0
+            });
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(
+            vec.iter().map(|&(n, _)| n).collect::<Vec<_>>(),
+            [0, 2, 3, 4, 5]
+        );
+        assert_eq!(Rc::strong_count(&value), 6);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_iteration() {
+        let mut vec = ArrayVec::<_, 5>::from_iter([5, 1, 4]);
+
+        for value in &mut vec {
+            *value *= 10;
+        }
+        vec.sort_unstable();
+
+        assert_eq!(vec.iter().sum::<i32>(), 100);
+        assert_eq!(vec.into_iter().rev().collect::<Vec<_>>(), [50, 40, 10]);
+    }
+}
@@ -0,0 +1,631 @@
+//! Sets of small non-negative integers stored as bits.
+//!
+//! A bit set holds `usize` members as the set bits of a run of `u64` words, so
+//! membership tests are a shift and a mask and set algebra works a word at a time.
+//! [`BitSet`] grows its words as members are inserted; [`FixedBitSet`] keeps a fixed
+//! number of words inline and never allocates.
+//!
+//! Both support union (`|`), intersection (`&`), difference (`-`) and symmetric
+//! difference (`^`), and iterate over their members in ascending order.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::bitset::BitSet;
+//!
+//! let primes: BitSet = [2, 3, 5, 7, 11, 13].into_iter().collect();
+//! let odds: BitSet = (1..14).step_by(2).collect();
+//!
+//! assert_eq!((&primes & &odds).iter().collect::<Vec<_>>(), [3, 5, 7, 11, 13]);
+//! assert_eq!((&primes - &odds).iter().collect::<Vec<_>>(), [2]);
+//! assert_eq!((&primes | &odds).count_ones(), 8);
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::FusedIterator,
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign},
+};
+
+use crate::num::traits::{BinaryInteger, FixedWidthInteger};
+
+/// The number of members each word holds.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Returns the word holding `index` and the mask of its bit in that word.
+const fn locate(index: usize) -> (usize, u64) {
+    (index / WORD_BITS, 1 << (index % WORD_BITS))
+}
+
+fn count_ones(words: &[u64]) -> usize {
+    words.iter().map(FixedWidthInteger::nonzero_bit_count).sum()
+}
+
+fn is_subset(words: &[u64], other: &[u64]) -> bool {
+    words
+        .iter()
+        .enumerate()
+        .all(|(index, &word)| word & !other.get(index).copied().unwrap_or(0) == 0)
+}
+
+fn is_disjoint(words: &[u64], other: &[u64]) -> bool {
+    words.iter().zip(other).all(|(&a, &b)| a & b == 0)
+}
+
+/// Returns `words` without its trailing empty words.
+fn trimmed(words: &[u64]) -> &[u64] {
+    let len = words
+        .iter()
+        .rposition(|&word| word != 0)
+        .map_or(0, |last| last + 1);
+    &words[..len]
+}
+
+/// A growable set of `usize` members stored as bits.
+///
+/// The set uses one bit per integer up to its largest member, so it suits dense members
+/// with a modest maximum.
+#[derive(Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { words: Vec::new() }
+    }
+
+    /// Creates an empty set with room for the members `0..bits` without reallocating.
+    #[must_use]
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            words: Vec::with_capacity(bits.div_ceil(WORD_BITS)),
+        }
+    }
+
+    /// Adds `index` to the set and returns whether it was newly added.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, mask) = locate(index);
+
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        added
+    }
+
+    /// Removes `index` from the set and returns whether it was a member.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, mask) = locate(index);
+
+        self.words.get_mut(word).is_some_and(|word| {
+            let removed = *word & mask != 0;
+            *word &= !mask;
+            removed
+        })
+    }
+
+    /// Returns whether `index` is a member.
+    #[must_use]
+    pub fn contains(&self, index: usize) -> bool {
+        let (word, mask) = locate(index);
+        self.words.get(word).is_some_and(|&word| word & mask != 0)
+    }
+
+    /// Returns the number of members.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        count_ones(&self.words)
+    }
+
+    /// Returns whether the set has no members.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Removes every member.
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
+    /// Returns whether every member of `self` is also in `other`.
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        is_subset(&self.words, &other.words)
+    }
+
+    /// Returns whether every member of `other` is also in `self`.
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns whether `self` and `other` share no members.
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        is_disjoint(&self.words, &other.words)
+    }
+
+    /// Returns an iterator over the members in ascending order.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words)
+    }
+
+    /// Shrinks the storage to the largest member.
+    pub fn shrink_to_fit(&mut self) {
+        let len = trimmed(&self.words).len();
+        self.words.truncate(len);
+        self.words.shrink_to_fit();
+    }
+
+    /// Combines the words of `other` into `self` with `op`, treating missing words as
+    /// empty.
+    fn combine(&mut self, other: &Self, op: impl Fn(u64, u64) -> u64) {
+        if self.words.len() < other.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+
+        for (index, word) in self.words.iter_mut().enumerate() {
+            *word = op(*word, other.words.get(index).copied().unwrap_or(0));
+        }
+    }
+}
+
+impl fmt::Debug for BitSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Two sets are equal when they have the same members, however much storage they hold.
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        trimmed(&self.words) == trimmed(&other.words)
+    }
+}
+
+impl Eq for BitSet {}
+
+impl Hash for BitSet {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        trimmed(&self.words).hash(state);
+    }
+}
+
+impl Extend<usize> for BitSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a BitSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Implements a set operator for `BitSet` references and its assigning form for
+/// `BitSet`, from the word operation `$op`.
+macro_rules! bitset_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:expr) => {
+        impl $assign_trait<&BitSet> for BitSet {
+            fn $assign_method(&mut self, rhs: &BitSet) {
+                self.combine(rhs, $op);
+            }
+        }
+
+        impl $trait for &BitSet {
+            type Output = BitSet;
+
+            fn $method(self, rhs: &BitSet) -> BitSet {
+                let mut result = self.clone();
+                result.combine(rhs, $op);
+                result
+            }
+        }
+    };
+}
+
+bitset_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+bitset_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+bitset_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+bitset_operator!(Sub, sub, SubAssign, sub_assign, |a, b| a & !b);
+
+/// A set of the members `0..WORDS * 64` stored inline as `WORDS` words.
+///
+/// The set is `Copy` and never allocates. Its capacity is a whole number of words because
+/// the word count cannot be derived from a bit count on stable Rust.
+///
+/// # Examples
+///
+/// ```
+/// use libx::collections::bitset::FixedBitSet;
+///
+/// let mut weekdays = FixedBitSet::<1>::new();
+/// weekdays.extend(0..5);
+///
+/// let weekend = !weekdays & FixedBitSet::from_iter(0..7);
+/// assert_eq!(weekend.iter().collect::<Vec<_>>(), [5, 6]);
+/// assert_eq!(FixedBitSet::<1>::CAPACITY, 64);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedBitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> FixedBitSet<WORDS> {
+    /// The number of members the set can hold.
+    pub const CAPACITY: usize = WORDS * WORD_BITS;
+
+    /// Creates an empty set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { words: [0; WORDS] }
+    }
+
+    /// Creates the set of every member it can hold.
+    #[must_use]
+    pub const fn full() -> Self {
+        Self {
+            words: [u64::MAX; WORDS],
+        }
+    }
+
+    /// Adds `index` to the set and returns whether it was newly added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not below [`CAPACITY`](Self::CAPACITY).
+    pub const fn insert(&mut self, index: usize) -> bool {
+        assert!(index < Self::CAPACITY, "bit index out of range");
+
+        let (word, mask) = locate(index);
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        added
+    }
+
+    /// Removes `index` from the set and returns whether it was a member.
+    pub const fn remove(&mut self, index: usize) -> bool {
+        if index >= Self::CAPACITY {
+            return false;
+        }
+
+        let (word, mask) = locate(index);
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    /// Returns whether `index` is a member.
+    #[must_use]
+    pub const fn contains(&self, index: usize) -> bool {
+        let (word, mask) = locate(index);
+        index < Self::CAPACITY && self.words[word] & mask != 0
+    }
+
+    /// Returns the number of members.
+    #[must_use]
+    pub fn count_ones(&self) -> usize {
+        count_ones(&self.words)
+    }
+
+    /// Returns whether the set has no members.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// Removes every member.
+    pub const fn clear(&mut self) {
+        self.words = [0; WORDS];
+    }
+
+    /// Returns whether every member of `self` is also in `other`.
+    #[must_use]
+    pub fn is_subset(&self, other: &Self) -> bool {
+        is_subset(&self.words, &other.words)
+    }
+
+    /// Returns whether every member of `other` is also in `self`.
+    #[must_use]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns whether `self` and `other` share no members.
+    #[must_use]
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        is_disjoint(&self.words, &other.words)
+    }
+
+    /// Returns an iterator over the members in ascending order.
+    #[must_use]
+    pub const fn iter(&self) -> Iter<'_> {
+        Iter::new(&self.words)
+    }
+
+    fn combine(mut self, other: Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        for (word, other) in self.words.iter_mut().zip(other.words) {
+            *word = op(*word, other);
+        }
+
+        self
+    }
+}
+
+impl<const WORDS: usize> Default for FixedBitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> fmt::Debug for FixedBitSet<WORDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<const WORDS: usize> Extend<usize> for FixedBitSet<WORDS> {
+    /// Adds every index to the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is not below [`CAPACITY`](Self::CAPACITY).
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for index in iter {
+            self.insert(index);
+        }
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for FixedBitSet<WORDS> {
+    /// Creates the set of every index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is not below [`CAPACITY`](Self::CAPACITY).
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a, const WORDS: usize> IntoIterator for &'a FixedBitSet<WORDS> {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// The complement of the set within its capacity.
+impl<const WORDS: usize> Not for FixedBitSet<WORDS> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self::full() - self
+    }
+}
+
+/// Implements a set operator for `FixedBitSet` and its assigning form, from the word
+/// operation `$op`.
+macro_rules! fixed_bitset_operator {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:expr) => {
+        impl<const WORDS: usize> $trait for FixedBitSet<WORDS> {
+            type Output = Self;
+
+            fn $method(self, rhs: Self) -> Self {
+                self.combine(rhs, $op)
+            }
+        }
+
+        impl<const WORDS: usize> $assign_trait for FixedBitSet<WORDS> {
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = self.combine(rhs, $op);
+            }
+        }
+    };
+}
+
+fixed_bitset_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+fixed_bitset_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+fixed_bitset_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+fixed_bitset_operator!(Sub, sub, SubAssign, sub_assign, |a, b| a & !b);
+
+/// An iterator over the members of a [`BitSet`] or [`FixedBitSet`] in ascending order.
+///
+/// Created by [`BitSet::iter`] and [`FixedBitSet::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    words: &'a [u64],
+    /// The index of the first member of `word`.
+    base: usize,
+    /// The members of the current word not yet yielded.
+    word: u64,
+}
+
+impl<'a> Iter<'a> {
+    const fn new(words: &'a [u64]) -> Self {
+        match words.split_first() {
+            Some((&word, rest)) => Self {
+                words: rest,
+                base: 0,
+                word,
+            },
+            None => Self {
+                words,
+                base: 0,
+                word: 0,
+            },
+        }
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.word == 0 {
+            let (&word, rest) = self.words.split_first()?;
+            self.words = rest;
+            self.base += WORD_BITS;
+            self.word = word;
+        }
+
+        let index = self.base + self.word.trailing_zero_bit_count();
+        // Clear the lowest set bit.
+        self.word &= self.word - 1;
+        Some(index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.word.nonzero_bit_count() + count_ones(self.words);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter<'_> {}
+
+impl FusedIterator for Iter<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{collections::BTreeSet, format, vec::Vec};
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    #[test]
+    fn test_insert_remove_contains() {
+        let mut set = BitSet::new();
+
+        assert!(set.insert(3));
+        assert!(set.insert(130));
+        assert!(!set.insert(3));
+        assert!(set.contains(130));
+        assert!(!set.contains(64));
+        assert!(!set.contains(usize::MAX));
+        assert_eq!(set.count_ones(), 2);
+
+        assert!(set.remove(130));
+        assert!(!set.remove(130));
+        assert!(!set.remove(10_000));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [3]);
+        assert_eq!(set, BitSet::from_iter([3]));
+
+        set.shrink_to_fit();
+        assert_eq!(set.words.len(), 1);
+        set.clear();
+        assert!(set.is_empty());
+        assert_eq!(format!("{set:?}"), "{}");
+    }
+
+    #[test]
+    fn test_set_algebra() {
+        let a: BitSet = [1, 64, 65, 200].into_iter().collect();
+        let b: BitSet = [1, 65, 100].into_iter().collect();
+
+        assert_eq!((&a | &b).iter().collect::<Vec<_>>(), [1, 64, 65, 100, 200]);
+        assert_eq!((&a & &b).iter().collect::<Vec<_>>(), [1, 65]);
+        assert_eq!((&a - &b).iter().collect::<Vec<_>>(), [64, 200]);
+        assert_eq!((&a ^ &b).iter().collect::<Vec<_>>(), [64, 100, 200]);
+
+        let mut c = a.clone();
+        c &= &b;
+        assert!(c.is_subset(&a) && c.is_subset(&b));
+        assert!(a.is_superset(&c));
+        assert!((&a - &b).is_disjoint(&b));
+        assert!(!a.is_subset(&b));
+    }
+
+    #[test]
+    fn test_fixed() {
+        let mut set = FixedBitSet::<2>::new();
+
+        assert!(set.insert(0));
+        assert!(set.insert(127));
+        assert!(!set.contains(128));
+        assert!(!set.remove(128));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0, 127]);
+        assert_eq!((!set).count_ones(), 126);
+        assert_eq!(FixedBitSet::<2>::full().count_ones(), 128);
+
+        let other = FixedBitSet::from_iter([0, 5]);
+        assert_eq!((set | other).iter().collect::<Vec<_>>(), [0, 5, 127]);
+        assert_eq!((set & other).iter().collect::<Vec<_>>(), [0]);
+        assert_eq!((set - other).iter().collect::<Vec<_>>(), [127]);
+        assert_eq!((set ^ other).iter().collect::<Vec<_>>(), [5, 127]);
+
+        set -= other;
+        assert!(set.is_disjoint(&other));
+        assert_eq!(format!("{set:?}"), "{127}");
+    }
+
+    #[test]
+    #[should_panic(expected = "bit index out of range")]
+    fn test_fixed_insert_out_of_range() {
+        FixedBitSet::<1>::new().insert(64);
+    }
+
+    #[test]
+    fn test_matches_model() {
+        let result = check_property(0xb175, 200, |rng| {
+            let mut set = BitSet::new();
+            let mut fixed = FixedBitSet::<4>::new();
+            let mut model = BTreeSet::new();
+
+            for step in 0..64 {
+                #[allow(clippy::cast_possible_truncation)] // Bounded by 256.
+                let index = rng.next_bounded(256) as usize;
+
+                let (a, b, expected) = if rng.next_bounded(3) == 0 {
+                    (set.remove(index), fixed.remove(index), model.remove(&index))
+                } else {
+                    (set.insert(index), fixed.insert(index), model.insert(index))
+                };
+
+                if a != expected || b != expected {
+                    return Err(format!("update of {index} disagreed at step {step}").into());
+                }
+
+                let members: Vec<usize> = model.iter().copied().collect();
+
+                if set.iter().collect::<Vec<_>>() != members
+                    || fixed.iter().collect::<Vec<_>>() != members
+                    || set.count_ones() != members.len()
+                    || fixed.iter().len() != members.len()
+                {
+                    return Err(format!("members disagreed at step {step}").into());
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
@@ -0,0 +1,786 @@
+//! A least-recently-used cache.
+//!
+//! [`LruCache`] holds at most a fixed number of entries. Reading or writing an entry
+//! makes it the most recently used, and inserting into a full cache evicts the least
+//! recently used one. Entries live in one vector threaded by a doubly linked recency
+//! list, with a [`hashbrown::HashTable`] of indices for lookups, so every operation is
+//! O(1) and the cache does not allocate per entry.
+//!
+//! With [`with_ttl`](LruCache::with_ttl) the cache also forgets entries a fixed time
+//! after they were written, as measured on a [`MonotonicClock`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::cache::LruCache;
+//!
+//! let mut cache = LruCache::new(2);
+//! cache.put("a", 1);
+//! cache.put("b", 2);
+//!
+//! assert_eq!(cache.get("a"), Some(&1));
+//! cache.put("c", 3);
+//!
+//! assert_eq!(cache.peek("b"), None);
+//! assert_eq!(cache.iter().collect::<Vec<_>>(), [(&"c", &3), (&"a", &1)]);
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    mem,
+    time::Duration,
+};
+
+use hashbrown::{DefaultHashBuilder, HashTable};
+
+use crate::time::MonotonicClock;
+
+/// The link of the first or last entry of the recency list.
+const NIL: usize = usize::MAX;
+
+/// The clock of a cache without a time to live. It never advances.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Untimed;
+
+impl MonotonicClock for Untimed {
+    fn now(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Entry<K, V> {
+    key: K,
+    value: V,
+    /// The next more recently used entry.
+    newer: usize,
+    /// The next less recently used entry.
+    older: usize,
+    /// The clock reading at which the entry expires.
+    expires_at: Duration,
+}
+
+/// A map that holds at most `capacity` entries and evicts the least recently used one
+/// to make room.
+///
+/// Keys are hashed with `S`, and entry lifetimes are measured with `C` once a time to
+/// live is set.
+#[derive(Clone)]
+pub struct LruCache<K, V, S = DefaultHashBuilder, C = Untimed> {
+    /// Indices into `entries`.
+    table: HashTable<usize>,
+    entries: Vec<Entry<K, V>>,
+    /// The most recently used entry.
+    newest: usize,
+    /// The least recently used entry.
+    oldest: usize,
+    capacity: usize,
+    hasher: S,
+    clock: C,
+    ttl: Option<Duration>,
+}
+
+impl<K: Hash + Eq, V> LruCache<K, V> {
+    /// Creates an empty cache that holds at most `capacity` entries.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self::with_hasher(capacity, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> LruCache<K, V, S> {
+    /// Creates an empty cache that holds at most `capacity` entries and hashes keys with
+    /// `hasher`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn with_hasher(capacity: usize, hasher: S) -> Self {
+        assert!(
+            capacity > 0,
+            "an LRU cache needs room for at least one entry"
+        );
+
+        Self {
+            table: HashTable::new(),
+            entries: Vec::new(),
+            newest: NIL,
+            oldest: NIL,
+            capacity,
+            hasher,
+            clock: Untimed,
+            ttl: None,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, C: MonotonicClock> LruCache<K, V, S, C> {
+    /// Makes entries expire `ttl` after they were last written, as read from `clock`.
+    ///
+    /// Reading an entry does not extend its life. Entries already in the cache expire
+    /// `ttl` after this call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::{cell::Cell, time::Duration};
+    ///
+    /// use libx::{collections::cache::LruCache, time::MonotonicClock};
+    ///
+    /// struct ManualClock(Cell<Duration>);
+    ///
+    /// impl MonotonicClock for ManualClock {
+    ///     fn now(&self) -> Duration {
+    ///         self.0.get()
+    ///     }
+    /// }
+    ///
+    /// let clock = ManualClock(Cell::new(Duration::ZERO));
+    /// let mut cache = LruCache::new(8).with_ttl(Duration::from_secs(30), &clock);
+    /// cache.put("session", 7);
+    ///
+    /// clock.0.set(Duration::from_secs(29));
+    /// assert_eq!(cache.get("session"), Some(&7));
+    ///
+    /// clock.0.set(Duration::from_secs(30));
+    /// assert_eq!(cache.get("session"), None);
+    /// assert!(cache.is_empty());
+    /// ```
+    #[must_use]
+    pub fn with_ttl<D: MonotonicClock>(self, ttl: Duration, clock: D) -> LruCache<K, V, S, D> {
+        let expires_at = clock.now().saturating_add(ttl);
+        let mut entries = self.entries;
+
+        for entry in &mut entries {
+            entry.expires_at = expires_at;
+        }
+
+        LruCache {
+            table: self.table,
+            entries,
+            newest: self.newest,
+            oldest: self.oldest,
+            capacity: self.capacity,
+            hasher: self.hasher,
+            clock,
+            ttl: Some(ttl),
+        }
+    }
+
+    /// Returns the most entries the cache holds.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of entries, counting expired entries that have not been
+    /// removed yet.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the cache holds no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns how long entries live after they are written, if they expire.
+    #[must_use]
+    pub const fn ttl(&self) -> Option<Duration> {
+        self.ttl
+    }
+
+    /// Inserts `value` under `key` as the most recently used entry, and returns the
+    /// unexpired value it replaced.
+    ///
+    /// If `key` is new and the cache is full, the least recently used entry is evicted.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hash(&key);
+
+        let Some(index) = self.find(hash, &key) else {
+            self.insert(hash, key, value);
+            return None;
+        };
+
+        let expired = self.is_expired(&self.entries[index], self.clock.now());
+        let (_, old) = self.replace(index, key, value);
+
+        (!expired).then_some(old)
+    }
+
+    /// Inserts `value` under `key` as the most recently used entry.
+    ///
+    /// Returns the entry that made way for it: the old entry of `key` if there was one,
+    /// expired or not, or else the least recently used entry if the cache was full.
+    pub fn push(&mut self, key: K, value: V) -> Option<(K, V)> {
+        let hash = self.hash(&key);
+
+        match self.find(hash, &key) {
+            Some(index) => Some(self.replace(index, key, value)),
+            None => self.insert(hash, key, value),
+        }
+    }
+
+    /// Returns the value of `key` and makes it the most recently used entry.
+    ///
+    /// An expired entry is removed and reported as missing.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_mut(key).map(|value| &*value)
+    }
+
+    /// Returns the value of `key` mutably and makes it the most recently used entry.
+    ///
+    /// An expired entry is removed and reported as missing.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hash(key), key)?;
+
+        if self.is_expired(&self.entries[index], self.clock.now()) {
+            self.remove_index(index);
+            return None;
+        }
+
+        self.touch(index);
+        Some(&mut self.entries[index].value)
+    }
+
+    /// Returns the value of `key` without changing how recently it was used.
+    #[must_use]
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = &self.entries[self.find(self.hash(key), key)?];
+
+        (!self.is_expired(entry, self.clock.now())).then_some(&entry.value)
+    }
+
+    /// Returns whether the cache holds an unexpired entry for `key`, without changing how
+    /// recently it was used.
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.peek(key).is_some()
+    }
+
+    /// Removes the entry of `key` and returns its value, if it has not expired.
+    pub fn pop<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hash(key), key)?;
+        let expired = self.is_expired(&self.entries[index], self.clock.now());
+
+        self.remove_index(index)
+            .filter(|_| !expired)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the least recently used entry that has not expired, without changing how
+    /// recently it was used.
+    #[must_use]
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        self.iter().next_back()
+    }
+
+    /// Removes the least recently used entry that has not expired and returns it.
+    /// Expired entries older than it are removed as well.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let now = self.clock.now();
+
+        while self.oldest != NIL {
+            let expired = self.is_expired(&self.entries[self.oldest], now);
+            let entry = self.remove_index(self.oldest);
+
+            if !expired {
+                return entry;
+            }
+        }
+
+        None
+    }
+
+    /// Removes every expired entry.
+    pub fn remove_expired(&mut self) {
+        let now = self.clock.now();
+        let mut index = self.oldest;
+
+        while index != NIL {
+            let newer = self.entries[index].newer;
+
+            if self.is_expired(&self.entries[index], now) {
+                self.remove_index(index);
+                // Removal moves the last entry into the freed slot, so the next entry may
+                // have moved there.
+                index = if newer == self.entries.len() {
+                    index
+                } else {
+                    newer
+                };
+            } else {
+                index = newer;
+            }
+        }
+    }
+
+    /// Changes the most entries the cache holds, evicting the least recently used
+    /// entries that no longer fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        assert!(
+            capacity > 0,
+            "an LRU cache needs room for at least one entry"
+        );
+
+        while self.entries.len() > capacity {
+            self.remove_index(self.oldest);
+        }
+
+        self.capacity = capacity;
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.entries.clear();
+        self.newest = NIL;
+        self.oldest = NIL;
+    }
+
+    /// Returns an iterator over the unexpired entries, from the most to the least
+    /// recently used.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: &self.entries,
+            newest: self.newest,
+            oldest: self.oldest,
+            now: self.ttl.map(|_| self.clock.now()),
+        }
+    }
+
+    /// Overwrites the entry at `index`, makes it the most recently used and returns its
+    /// old key and value.
+    fn replace(&mut self, index: usize, key: K, value: V) -> (K, V) {
+        let expires_at = self.expiry();
+        self.touch(index);
+
+        let entry = &mut self.entries[index];
+        entry.expires_at = expires_at;
+
+        (
+            mem::replace(&mut entry.key, key),
+            mem::replace(&mut entry.value, value),
+        )
+    }
+
+    /// Adds a new entry as the most recently used, evicting the least recently used one
+    /// if the cache is full, and returns the evicted entry.
+    fn insert(&mut self, hash: u64, key: K, value: V) -> Option<(K, V)> {
+        let evicted = if self.entries.len() == self.capacity {
+            self.remove_index(self.oldest)
+        } else {
+            None
+        };
+
+        let index = self.entries.len();
+        self.entries.push(Entry {
+            key,
+            value,
+            newer: NIL,
+            older: NIL,
+            expires_at: self.expiry(),
+        });
+        self.link_newest(index);
+
+        let (entries, hasher) = (&self.entries, &self.hasher);
+        self.table
+            .insert_unique(hash, index, |&index| hasher.hash_one(&entries[index].key));
+
+        evicted
+    }
+
+    fn hash<Q: Hash + ?Sized>(&self, key: &Q) -> u64 {
+        self.hasher.hash_one(key)
+    }
+
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.table
+            .find(hash, |&index| self.entries[index].key.borrow() == key)
+            .copied()
+    }
+
+    fn expiry(&self) -> Duration {
+        self.ttl
+            .map_or(Duration::MAX, |ttl| self.clock.now().saturating_add(ttl))
+    }
+
+    fn is_expired(&self, entry: &Entry<K, V>, now: Duration) -> bool {
+        self.ttl.is_some() && now >= entry.expires_at
+    }
+
+    /// Makes the entry at `index` the most recently used.
+    fn touch(&mut self, index: usize) {
+        if index != self.newest {
+            self.unlink(index);
+            self.link_newest(index);
+        }
+    }
+
+    fn link_newest(&mut self, index: usize) {
+        self.entries[index].newer = NIL;
+        self.entries[index].older = self.newest;
+
+        match self.entries.get_mut(self.newest) {
+            Some(newest) => newest.newer = index,
+            None => self.oldest = index,
+        }
+
+        self.newest = index;
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let Entry { newer, older, .. } = self.entries[index];
+
+        match self.entries.get_mut(newer) {
+            Some(entry) => entry.older = older,
+            None => self.newest = older,
+        }
+
+        match self.entries.get_mut(older) {
+            Some(entry) => entry.newer = newer,
+            None => self.oldest = newer,
+        }
+    }
+
+    /// Removes the entry at `index`, moving the last entry into its slot.
+    fn remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        if index >= self.entries.len() {
+            return None;
+        }
+
+        let hash = self.hash(&self.entries[index].key);
+        if let Ok(found) = self.table.find_entry(hash, |&candidate| candidate == index) {
+            found.remove();
+        }
+
+        self.unlink(index);
+        let removed = self.entries.swap_remove(index);
+        let moved = self.entries.len();
+
+        if index < moved {
+            // The entry that was last now lives at `index`; repoint its neighbours and its
+            // slot in the table.
+            let Entry { newer, older, .. } = self.entries[index];
+
+            match self.entries.get_mut(newer) {
+                Some(entry) => entry.older = index,
+                None => self.newest = index,
+            }
+
+            match self.entries.get_mut(older) {
+                Some(entry) => entry.newer = index,
+                None => self.oldest = index,
+            }
+
+            let hash = self.hash(&self.entries[index].key);
+            if let Some(slot) = self.table.find_mut(hash, |&candidate| candidate == moved) {
+                *slot = index;
+            }
+        }
+
+        Some((removed.key, removed.value))
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S, C> fmt::Debug for LruCache<K, V, S, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries = Iter {
+            entries: &self.entries,
+            newest: self.newest,
+            oldest: self.oldest,
+            now: None,
+        };
+
+        f.debug_map().entries(entries).finish()
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher, C: MonotonicClock> Extend<(K, V)> for LruCache<K, V, S, C> {
+    /// Puts every pair in order, so the last ones end up the most recently used.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.put(key, value);
+        }
+    }
+}
+
+impl<'a, K: Hash + Eq, V, S: BuildHasher, C: MonotonicClock> IntoIterator
+    for &'a LruCache<K, V, S, C>
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an [`LruCache`], from the most to the least recently
+/// used.
+///
+/// Created by [`LruCache::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, K, V> {
+    entries: &'a [Entry<K, V>],
+    newest: usize,
+    oldest: usize,
+    /// The time entries are checked against, if they expire.
+    now: Option<Duration>,
+}
+
+impl<K, V> Iter<'_, K, V> {
+    fn is_live(&self, entry: &Entry<K, V>) -> bool {
+        self.now.is_none_or(|now| now < entry.expires_at)
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<(&'a K, &'a V)> {
+        while self.newest != NIL {
+            let entry = &self.entries[self.newest];
+
+            if self.newest == self.oldest {
+                self.newest = NIL;
+                self.oldest = NIL;
+            } else {
+                self.newest = entry.older;
+            }
+
+            if self.is_live(entry) {
+                return Some((&entry.key, &entry.value));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.entries.len()))
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.oldest != NIL {
+            let entry = &self.entries[self.oldest];
+
+            if self.newest == self.oldest {
+                self.newest = NIL;
+                self.oldest = NIL;
+            } else {
+                self.oldest = entry.newer;
+            }
+
+            if self.is_live(entry) {
+                return Some((&entry.key, &entry.value));
+            }
+        }
+
+        None
+    }
+}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+impl<K, V> Clone for Iter<'_, K, V> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec::Vec};
+
+    use super::*;
+    use crate::{
+        hashing::SipHashState,
+        random::RandomNumberGenerator,
+        testing::{ManualClock, check_property},
+    };
+
+    fn contents<S: BuildHasher, C: MonotonicClock>(
+        cache: &LruCache<u32, u32, S, C>,
+    ) -> Vec<(u32, u32)> {
+        cache.iter().map(|(&key, &value)| (key, value)).collect()
+    }
+
+    #[test]
+    fn test_recency_order_and_eviction() {
+        let mut cache = LruCache::new(3);
+
+        for key in 1..=3 {
+            assert_eq!(cache.put(key, key * 10), None);
+        }
+
+        assert_eq!(cache.get(&1), Some(&10));
+        assert_eq!(cache.peek(&2), Some(&20));
+        assert_eq!(contents(&cache), [(1, 10), (3, 30), (2, 20)]);
+        assert_eq!(cache.iter().next_back(), Some((&2, &20)));
+
+        assert_eq!(cache.push(4, 40), Some((2, 20)));
+        assert_eq!(cache.put(3, 33), Some(30));
+        assert_eq!(cache.push(1, 11), Some((1, 10)));
+        assert_eq!(contents(&cache), [(1, 11), (3, 33), (4, 40)]);
+        assert_eq!(format!("{cache:?}"), "{1: 11, 3: 33, 4: 40}");
+
+        if let Some(value) = cache.get_mut(&4) {
+            *value += 1;
+        }
+        assert_eq!(cache.peek_lru(), Some((&3, &33)));
+        assert_eq!(cache.pop_lru(), Some((3, 33)));
+        assert_eq!(cache.pop(&1), Some(11));
+        assert_eq!(cache.pop(&1), None);
+        assert_eq!(contents(&cache), [(4, 41)]);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.pop_lru(), None);
+    }
+
+    #[test]
+    fn test_set_capacity_evicts_oldest() {
+        let mut cache = LruCache::with_hasher(4, SipHashState::<1, 3>::with_keys(1, 2));
+        cache.extend([(1, 1), (2, 2), (3, 3), (4, 4)]);
+
+        cache.set_capacity(2);
+        assert_eq!(cache.capacity(), 2);
+        assert_eq!(contents(&cache), [(4, 4), (3, 3)]);
+    }
+
+    #[test]
+    fn test_ttl() {
+        let clock = ManualClock::new(Duration::ZERO);
+        let mut cache = LruCache::new(4).with_ttl(Duration::from_secs(10), &clock);
+
+        cache.put(1, 1);
+        clock.advance(Duration::from_secs(5));
+        cache.put(2, 2);
+        cache.put(3, 3);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.ttl(), Some(Duration::from_secs(10)));
+        assert_eq!(cache.peek(&1), None);
+        assert!(!cache.contains_key(&1));
+        assert_eq!(cache.len(), 3);
+        assert_eq!(contents(&cache), [(3, 3), (2, 2)]);
+        assert_eq!(cache.put(1, 10), None);
+
+        // Writing refreshes an entry; reading does not.
+        assert_eq!(cache.get(&2), Some(&2));
+        cache.put(3, 30);
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(contents(&cache), [(3, 30), (1, 10)]);
+        assert_eq!(cache.get(&2), None);
+        assert_eq!(cache.len(), 2);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(cache.pop_lru(), None);
+        assert!(cache.is_empty());
+
+        cache.put(5, 5);
+        cache.put(6, 6);
+        clock.advance(Duration::from_secs(10));
+        cache.put(7, 7);
+        cache.remove_expired();
+        assert_eq!(contents(&cache), [(7, 7)]);
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn test_matches_model() {
+        let result = check_property(0x1e0, 200, |rng| {
+            #[allow(clippy::cast_possible_truncation)] // Bounded by 8.
+            let capacity = rng.next_bounded(8) as usize + 1;
+            let mut cache = LruCache::new(capacity);
+            // Entries from the most to the least recently used.
+            let mut model: Vec<(u32, u32)> = Vec::new();
+
+            for step in 0..64 {
+                #[allow(clippy::cast_possible_truncation)] // Bounded by 12.
+                let key = rng.next_bounded(12) as u32;
+                let position = model.iter().position(|&(k, _)| k == key);
+
+                match rng.next_bounded(4) {
+                    0 | 1 => {
+                        let old = position.map(|index| model.remove(index).1);
+                        model.insert(0, (key, step));
+                        model.truncate(capacity);
+
+                        if cache.put(key, step) != old {
+                            return Err(format!("put({key}) disagreed at step {step}").into());
+                        }
+                    }
+                    2 => {
+                        let expected = position.map(|index| {
+                            let entry = model.remove(index);
+                            model.insert(0, entry);
+                            entry.1
+                        });
+
+                        if cache.get(&key).copied() != expected {
+                            return Err(format!("get({key}) disagreed at step {step}").into());
+                        }
+                    }
+                    _ => {
+                        let expected = position.map(|index| model.remove(index).1);
+
+                        if cache.pop(&key) != expected {
+                            return Err(format!("pop({key}) disagreed at step {step}").into());
+                        }
+                    }
+                }
+
+                if contents(&cache) != model {
+                    return Err(format!("contents disagreed at step {step}").into());
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
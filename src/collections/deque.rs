@@ -0,0 +1,824 @@
+//! A double-ended queue backed by a growable ring buffer.
+//!
+//! [`Deque`] stores its elements in one allocation that wraps around at the end, so
+//! pushing and popping at either end is amortized O(1) and never allocates per element.
+//! Prefer it to [`List`](crate::collections::list::doubly_linked::List) for queue
+//! workloads; the list remains the better fit when elements are inserted or removed in
+//! the middle through a cursor.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::deque::Deque;
+//!
+//! let mut queue = Deque::new();
+//! queue.push_back(1);
+//! queue.push_back(2);
+//! queue.push_front(0);
+//!
+//! assert_eq!(queue.pop_front(), Some(0));
+//! assert_eq!(queue.iter().copied().collect::<Vec<_>>(), [1, 2]);
+//! ```
+
+use core::{
+    fmt,
+    hash::{Hash, Hasher},
+    iter::{Chain, FusedIterator},
+    mem::MaybeUninit,
+    ops::{Bound, Index, IndexMut, RangeBounds},
+    slice,
+};
+
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    vec::Vec,
+};
+
+/// A double-ended queue stored in a ring buffer.
+///
+/// Indices count from the front. The buffer doubles when full and never shrinks on its
+/// own.
+pub struct Deque<T, A: Allocator = Global> {
+    /// Every slot of the ring; the buffer's length is the deque's capacity.
+    buffer: Vec<MaybeUninit<T>, A>,
+    /// The slot of the front element.
+    head: usize,
+    len: usize,
+}
+
+impl<T> Deque<T> {
+    /// Creates an empty deque without allocating.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// Creates an empty deque with room for `capacity` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::deque::Deque;
+    ///
+    /// let deque: Deque<u8> = Deque::with_capacity(10);
+    /// assert_eq!(deque.capacity(), 10);
+    /// ```
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> Deque<T, A> {
+    /// Creates an empty deque whose buffer is allocated with `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use libx::collections::deque::Deque;
+    ///
+    /// let mut deque = Deque::new_in(Global);
+    /// deque.push_back(1);
+    /// assert_eq!(deque.len(), 1);
+    /// ```
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            buffer: Vec::new_in(alloc),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Creates an empty deque with room for `capacity` elements, whose buffer is
+    /// allocated with `alloc`.
+    #[must_use]
+    pub fn with_capacity_in(capacity: usize, alloc: A) -> Self {
+        let mut buffer = Vec::with_capacity_in(capacity, alloc);
+        buffer.resize_with(capacity, MaybeUninit::uninit);
+
+        Self {
+            buffer,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns a reference to the allocator backing this deque.
+    #[must_use]
+    pub fn allocator(&self) -> &A {
+        self.buffer.allocator()
+    }
+
+    /// Returns the number of elements.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the deque holds no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the deque can hold before it reallocates.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+
+    /// Appends `value` to the back.
+    pub fn push_back(&mut self, value: T) {
+        self.grow_if_full();
+        let slot = self.slot(self.len);
+        self.buffer[slot].write(value);
+        self.len += 1;
+    }
+
+    /// Prepends `value` to the front.
+    pub fn push_front(&mut self, value: T) {
+        self.grow_if_full();
+        self.head = self.slot(self.capacity() - 1);
+        self.buffer[self.head].write(value);
+        self.len += 1;
+    }
+
+    /// Removes and returns the front element, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let slot = self.head;
+        self.head = self.slot(1);
+        self.len -= 1;
+
+        // SAFETY: the slot held the front element, and moving the head past it means it is
+        // never read again.
+        Some(unsafe { self.buffer[slot].assume_init_read() })
+    }
+
+    /// Removes and returns the back element, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let slot = self.slot(self.len);
+
+        // SAFETY: the slot held the back element, and shrinking the length means it is
+        // never read again.
+        Some(unsafe { self.buffer[slot].assume_init_read() })
+    }
+
+    /// Returns a reference to the front element.
+    #[must_use]
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    /// Returns a reference to the back element.
+    #[must_use]
+    pub fn back(&self) -> Option<&T> {
+        self.len.checked_sub(1).and_then(|index| self.get(index))
+    }
+
+    /// Returns a mutable reference to the front element.
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    /// Returns a mutable reference to the back element.
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.len
+            .checked_sub(1)
+            .and_then(|index| self.get_mut(index))
+    }
+
+    /// Returns a reference to the element at `index` from the front.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+
+        // SAFETY: slots for indices below `len` are initialized.
+        Some(unsafe { self.buffer[self.slot(index)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index` from the front.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let slot = self.slot(index);
+
+        // SAFETY: slots for indices below `len` are initialized.
+        Some(unsafe { self.buffer[slot].assume_init_mut() })
+    }
+
+    /// Removes every element, keeping the buffer.
+    pub fn clear(&mut self) {
+        while self.pop_back().is_some() {}
+        self.head = 0;
+    }
+
+    /// Returns the elements as two slices, front first, whose concatenation is the
+    /// deque in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::deque::Deque;
+    ///
+    /// let mut deque = Deque::with_capacity(3);
+    /// deque.push_back(2);
+    /// deque.push_back(3);
+    /// deque.push_front(1);
+    ///
+    /// assert_eq!(deque.as_slices(), (&[1][..], &[2, 3][..]));
+    /// ```
+    #[must_use]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let (front, back) = self.ranges();
+
+        // SAFETY: both ranges cover exactly the initialized slots.
+        unsafe {
+            (
+                assume_init(&self.buffer[front]),
+                assume_init(&self.buffer[back]),
+            )
+        }
+    }
+
+    /// Returns the elements as two mutable slices, front first, whose concatenation is
+    /// the deque in order.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (front, back) = self.ranges();
+        let (wrapped, rest) = self.buffer.split_at_mut(front.start);
+
+        // SAFETY: both ranges cover exactly the initialized slots, and they do not
+        // overlap because `back` ends before `front` starts.
+        unsafe {
+            (
+                assume_init_mut(&mut rest[..front.len()]),
+                assume_init_mut(&mut wrapped[back]),
+            )
+        }
+    }
+
+    /// Returns an iterator over references to the elements, front to back.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (front, back) = self.as_slices();
+
+        Iter {
+            inner: front.iter().chain(back),
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (front, back) = self.as_mut_slices();
+
+        IterMut {
+            inner: front.iter_mut().chain(back),
+        }
+    }
+
+    /// Rearranges the buffer so the elements are stored in order in one slice, and
+    /// returns it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::deque::Deque;
+    ///
+    /// let mut deque = Deque::with_capacity(3);
+    /// deque.push_back(2);
+    /// deque.push_front(1);
+    ///
+    /// assert_eq!(deque.make_contiguous(), [1, 2]);
+    /// assert_eq!(deque.as_slices().1, []);
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        // Moving uninitialized slots around is harmless, so the whole ring can rotate.
+        self.buffer.rotate_left(self.head);
+        self.head = 0;
+        self.as_mut_slices().0
+    }
+
+    /// Rotates the deque `n` places to the left, so the element at index `n` becomes
+    /// the front.
+    ///
+    /// This moves at most `min(n, len - n)` elements, or none if the buffer is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::deque::Deque;
+    ///
+    /// let mut deque: Deque<u8> = (1..=5).collect();
+    /// deque.rotate_left(2);
+    ///
+    /// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [3, 4, 5, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation {n} out of bounds for length {}",
+            self.len
+        );
+
+        if self.len == self.capacity() {
+            self.head = self.slot(n);
+        } else if n <= self.len / 2 {
+            for _ in 0..n {
+                self.move_front_to_back();
+            }
+        } else {
+            for _ in n..self.len {
+                self.move_back_to_front();
+            }
+        }
+    }
+
+    /// Rotates the deque `n` places to the right, so the element at index `len - n`
+    /// becomes the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is greater than the length.
+    pub fn rotate_right(&mut self, n: usize) {
+        assert!(
+            n <= self.len,
+            "rotation {n} out of bounds for length {}",
+            self.len
+        );
+        self.rotate_left(self.len - n);
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator, front to back.
+    ///
+    /// The elements are moved out before this returns, so dropping the iterator early
+    /// still leaves them removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends after the length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::deque::Deque;
+    ///
+    /// let mut deque: Deque<u8> = (1..=5).collect();
+    /// let middle: Vec<u8> = deque.drain(1..4).collect();
+    ///
+    /// assert_eq!(middle, [2, 3, 4]);
+    /// assert_eq!(deque.iter().copied().collect::<Vec<_>>(), [1, 5]);
+    /// ```
+    pub fn drain<R>(&mut self, range: R) -> IntoIter<T, A>
+    where
+        R: RangeBounds<usize>,
+        A: Clone,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        assert!(
+            start <= end && end <= self.len,
+            "range {start}..{end} out of bounds for length {}",
+            self.len
+        );
+
+        let mut drained = Self::with_capacity_in(end - start, self.allocator().clone());
+
+        self.rotate_left(start);
+
+        for _ in start..end {
+            if let Some(value) = self.pop_front() {
+                drained.push_back(value);
+            }
+        }
+
+        self.rotate_right(start);
+        drained.into_iter()
+    }
+
+    /// Returns the slot of the element `index` places from the front, which may be past
+    /// the back as long as it is within one lap of the ring.
+    fn slot(&self, index: usize) -> usize {
+        let slot = self.head + index;
+        let capacity = self.buffer.len();
+
+        if slot >= capacity {
+            slot - capacity
+        } else {
+            slot
+        }
+    }
+
+    /// Returns the slot ranges of the front and wrapped-around parts of the elements.
+    fn ranges(&self) -> (core::ops::Range<usize>, core::ops::Range<usize>) {
+        let capacity = self.capacity();
+
+        if self.head + self.len <= capacity {
+            (self.head..self.head + self.len, 0..0)
+        } else {
+            (self.head..capacity, 0..self.head + self.len - capacity)
+        }
+    }
+
+    fn grow_if_full(&mut self) {
+        let capacity = self.capacity();
+
+        if self.len < capacity {
+            return;
+        }
+
+        self.make_contiguous();
+        self.buffer
+            .resize_with((capacity * 2).max(4), MaybeUninit::uninit);
+    }
+
+    fn move_front_to_back(&mut self) {
+        if let Some(value) = self.pop_front() {
+            self.push_back(value);
+        }
+    }
+
+    fn move_back_to_front(&mut self) {
+        if let Some(value) = self.pop_back() {
+            self.push_front(value);
+        }
+    }
+}
+
+/// Views initialized slots as values.
+///
+/// # Safety
+///
+/// Every slot in `slots` must be initialized.
+const unsafe fn assume_init<T>(slots: &[MaybeUninit<T>]) -> &[T] {
+    // SAFETY: `MaybeUninit<T>` has the layout of `T`, and the caller guarantees the
+    // values are initialized.
+    unsafe { &*(core::ptr::from_ref(slots) as *const [T]) }
+}
+
+/// Views initialized slots as mutable values.
+///
+/// # Safety
+///
+/// Every slot in `slots` must be initialized.
+const unsafe fn assume_init_mut<T>(slots: &mut [MaybeUninit<T>]) -> &mut [T] {
+    // SAFETY: as for `assume_init`.
+    unsafe { &mut *(core::ptr::from_mut(slots) as *mut [T]) }
+}
+
+impl<T, A: Allocator> Drop for Deque<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for Deque<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for Deque<T, A> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index)
+            .unwrap_or_else(|| panic!("index {index} out of bounds for length {len}"))
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for Deque<T, A> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("index {index} out of bounds for length {len}"))
+    }
+}
+
+impl<T, A> Clone for Deque<T, A>
+where
+    T: Clone,
+    A: Allocator + Clone,
+{
+    fn clone(&self) -> Self {
+        let mut deque = Self::with_capacity_in(self.len, self.allocator().clone());
+        deque.extend(self.iter().cloned());
+        deque
+    }
+}
+
+impl<T, A, B> PartialEq<Deque<T, B>> for Deque<T, A>
+where
+    T: PartialEq,
+    A: Allocator,
+    B: Allocator,
+{
+    fn eq(&self, other: &Deque<T, B>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for Deque<T, A> {}
+
+impl<T: Hash, A: Allocator> Hash for Deque<T, A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Deque<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Deque<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push_back(value);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for Deque<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque = Self::new();
+        deque.extend(iter);
+        deque
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for Deque<T, A> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, A>;
+
+    fn into_iter(self) -> IntoIter<T, A> {
+        IntoIter { deque: self }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a Deque<T, A> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Deque<T, A> {
+    type Item = &'a mut T;
+
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// An iterator over references to the elements of a [`Deque`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, T> {
+    inner: Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+/// An iterator over mutable references to the elements of a [`Deque`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    inner: Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+/// An owning iterator over the elements of a [`Deque`], front to back.
+#[derive(Debug, Clone)]
+pub struct IntoIter<T, A: Allocator = Global> {
+    deque: Deque<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.deque.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.deque.len, Some(self.deque.len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.deque.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{rc::Rc, vec::Vec as StdVec};
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    #[test]
+    fn test_matches_vec_model() {
+        let result = check_property(0xDE0E, 200, |rng| {
+            let mut deque = Deque::new();
+            let mut model = StdVec::new();
+
+            for step in 0..200u32 {
+                match rng.next_bounded(7) {
+                    0 => {
+                        deque.push_back(step);
+                        model.push(step);
+                    }
+                    1 => {
+                        deque.push_front(step);
+                        model.insert(0, step);
+                    }
+                    2 => {
+                        let expected = (!model.is_empty()).then(|| model.remove(0));
+                        if deque.pop_front() != expected {
+                            return Err("pop_front".into());
+                        }
+                    }
+                    3 => {
+                        if deque.pop_back() != model.pop() {
+                            return Err("pop_back".into());
+                        }
+                    }
+                    4 if !model.is_empty() => {
+                        #[allow(clippy::cast_possible_truncation)]
+                        let n = rng.next_bounded(model.len() as u64 + 1) as usize;
+                        deque.rotate_left(n);
+                        model.rotate_left(n);
+                    }
+                    5 if !model.is_empty() => {
+                        #[allow(clippy::cast_possible_truncation)]
+                        let start = rng.next_bounded(model.len() as u64) as usize;
+                        let end = (start + 3).min(model.len());
+                        let drained: StdVec<u32> = deque.drain(start..end).collect();
+                        if drained != model.drain(start..end).collect::<StdVec<_>>() {
+                            return Err("drain".into());
+                        }
+                    }
+                    _ => {
+                        if let Some(value) = deque.back_mut() {
+                            *value += 1;
+                        }
+                        if let Some(value) = model.last_mut() {
+                            *value += 1;
+                        }
+                    }
+                }
+
+                if !deque.iter().eq(model.iter()) || deque.len() != model.len() {
+                    return Err(alloc::format!("{deque:?} != {model:?}").into());
+                }
+            }
+
+            if deque.make_contiguous() != model.as_slice() {
+                return Err("make_contiguous".into());
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn test_wrapped_access() {
+        let mut deque = Deque::with_capacity(4);
+        deque.extend([3, 4]);
+        deque.push_front(2);
+        deque.push_front(1);
+
+        assert_eq!(deque.capacity(), 4);
+        assert_eq!(deque.as_slices(), (&[1, 2][..], &[3, 4][..]));
+        assert_eq!(deque[3], 4);
+        assert_eq!(
+            deque.iter().rev().copied().collect::<StdVec<_>>(),
+            [4, 3, 2, 1]
+        );
+
+        for value in &mut deque {
+            *value *= 10;
+        }
+        deque[0] += 1;
+        assert_eq!(deque.front(), Some(&11));
+        assert_eq!(deque.back(), Some(&40));
+
+        deque.rotate_right(1);
+        assert_eq!(
+            deque.clone().into_iter().collect::<StdVec<_>>(),
+            [40, 11, 20, 30]
+        );
+        assert_eq!(deque, deque.clone());
+        assert_eq!(alloc::format!("{deque:?}"), "[40, 11, 20, 30]");
+    }
+
+    #[test]
+    fn test_drops_every_element_once() {
+        let counter = Rc::new(());
+        let mut deque = Deque::with_capacity(2);
+
+        for _ in 0..5 {
+            deque.push_front(Rc::clone(&counter));
+        }
+
+        drop(deque.drain(..2));
+        assert_eq!(Rc::strong_count(&counter), 4);
+
+        let mut iter = deque.into_iter();
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&counter), 3);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&counter), 1);
+
+        let empty: Deque<u8> = Deque::new();
+        assert_eq!(empty.capacity(), 0);
+        assert_eq!(empty.front(), None);
+        assert_eq!(empty.iter().len(), 0);
+    }
+}
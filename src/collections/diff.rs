@@ -0,0 +1,277 @@
+//! Edit scripts between two sequences.
+//!
+//! [`diff`] finds a longest common subsequence of two sequences with Myers' algorithm
+//! and describes everything outside it as [`Edit`]s. Elements that disappear in one
+//! place and reappear in another are reported as moves rather than as a removal and an
+//! insertion, so a consumer such as a list view can animate them.
+//!
+//! The script is a batch, not a sequence of steps: removals and move sources are
+//! positions in the old sequence, while insertions and move destinations are positions in
+//! the new one. [`List::apply_diff`](super::list::doubly_linked::List::apply_diff)
+//! replays a script onto a list.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::{
+//!     diff::{Edit, diff},
+//!     list::doubly_linked::list,
+//! };
+//!
+//! let old = ['a', 'b', 'c', 'd'];
+//! let new = ['b', 'c', 'a', 'e'];
+//!
+//! let edits = diff(&old, &new);
+//! assert_eq!(
+//!     edits,
+//!     [
+//!         Edit::Remove { index: 3 },
+//!         Edit::Move { from: 0, to: 2 },
+//!         Edit::Insert { index: 3, value: 'e' },
+//!     ]
+//! );
+//!
+//! let mut list = list!['a', 'b', 'c', 'd'];
+//! list.apply_diff(edits);
+//! assert_eq!(list, list!['b', 'c', 'a', 'e']);
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+/// A single change in an edit script produced by [`diff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edit<T> {
+    /// The element at `index` of the old sequence was removed.
+    Remove {
+        /// The position in the old sequence.
+        index: usize,
+    },
+
+    /// `value` was inserted at `index` of the new sequence.
+    Insert {
+        /// The position in the new sequence.
+        index: usize,
+
+        /// The inserted element.
+        value: T,
+    },
+
+    /// The element at `from` of the old sequence moved to `to` of the new sequence.
+    Move {
+        /// The position in the old sequence.
+        from: usize,
+
+        /// The position in the new sequence.
+        to: usize,
+    },
+}
+
+/// Returns an edit script that turns `old` into `new`.
+///
+/// The script lists removals in ascending order, then moves by their destination, then
+/// insertions in ascending order. It is minimal in the number of elements it touches.
+/// A removed element is paired with an equal inserted element to form a move, in order
+/// of appearance; pairing compares every removed element with every inserted one, so it
+/// is quadratic in the size of the change rather than in the length of the sequences.
+///
+/// # Examples
+///
+/// ```
+/// use libx::collections::diff::{Edit, diff};
+///
+/// assert_eq!(diff(b"abc", b"abc"), []);
+/// assert_eq!(
+///     diff(b"ab", b"axb"),
+///     [Edit::Insert { index: 1, value: b'x' }]
+/// );
+/// ```
+#[must_use]
+pub fn diff<T>(old: &[T], new: &[T]) -> Vec<Edit<T>>
+where
+    T: PartialEq + Clone,
+{
+    let mut kept_old = vec![false; old.len()];
+    let mut kept_new = vec![false; new.len()];
+
+    for (x, y) in common_subsequence(old, new) {
+        kept_old[x] = true;
+        kept_new[y] = true;
+    }
+
+    let removed: Vec<usize> = (0..old.len()).filter(|&x| !kept_old[x]).collect();
+    let mut inserted: Vec<Option<usize>> =
+        (0..new.len()).filter(|&y| !kept_new[y]).map(Some).collect();
+
+    let mut removals = Vec::new();
+    let mut moves = Vec::new();
+
+    for from in removed {
+        let partner = inserted
+            .iter_mut()
+            .find(|slot| slot.is_some_and(|to| new[to] == old[from]));
+
+        match partner.and_then(Option::take) {
+            Some(to) => moves.push(Edit::Move { from, to }),
+            None => removals.push(Edit::Remove { index: from }),
+        }
+    }
+
+    moves.sort_unstable_by_key(|edit| match edit {
+        Edit::Move { to, .. } => *to,
+        _ => 0,
+    });
+
+    let insertions = inserted.into_iter().flatten().map(|index| Edit::Insert {
+        index,
+        value: new[index].clone(),
+    });
+
+    removals
+        .into_iter()
+        .chain(moves)
+        .chain(insertions)
+        .collect()
+}
+
+/// Returns the index pairs of a longest common subsequence of `old` and `new`, in order.
+///
+/// This is the greedy forward search of Myers' "An O(ND) Difference Algorithm and Its
+/// Variations", keeping the furthest reaching path of every round to backtrack through.
+pub(crate) fn common_subsequence<T: PartialEq>(old: &[T], new: &[T]) -> Vec<(usize, usize)> {
+    let max = old.len() + new.len();
+
+    // `furthest[k + max]` is the furthest `x` reached on diagonal `k = x - y`.
+    let mut furthest = vec![0_usize; 2 * max + 2];
+    let mut trace = Vec::new();
+    let diagonal = |k: isize| (k + max.cast_signed()).cast_unsigned();
+
+    'search: for d in 0..=max.cast_signed() {
+        trace.push(furthest.clone());
+
+        for k in (-d..=d).step_by(2) {
+            let mut x =
+                if k == -d || (k != d && furthest[diagonal(k - 1)] < furthest[diagonal(k + 1)]) {
+                    furthest[diagonal(k + 1)]
+                } else {
+                    furthest[diagonal(k - 1)] + 1
+                };
+            let mut y = (x.cast_signed() - k).cast_unsigned();
+
+            while let (Some(a), Some(b)) = (old.get(x), new.get(y))
+                && a == b
+            {
+                x += 1;
+                y += 1;
+            }
+
+            furthest[diagonal(k)] = x;
+
+            if x >= old.len() && y >= new.len() {
+                break 'search;
+            }
+        }
+    }
+
+    let mut pairs = Vec::new();
+    let (mut x, mut y) = (old.len(), new.len());
+
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let (d, k) = (d.cast_signed(), x.cast_signed() - y.cast_signed());
+
+        let (previous_x, previous_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let previous_k =
+                if k == -d || (k != d && furthest[diagonal(k - 1)] < furthest[diagonal(k + 1)]) {
+                    k + 1
+                } else {
+                    k - 1
+                };
+            let previous_x = furthest[diagonal(previous_k)];
+            (
+                previous_x,
+                (previous_x.cast_signed() - previous_k).cast_unsigned(),
+            )
+        };
+
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            pairs.push((x, y));
+        }
+
+        (x, y) = (previous_x, previous_y);
+    }
+
+    pairs.reverse();
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        collections::list::doubly_linked::List, random::RandomNumberGenerator,
+        testing::check_property,
+    };
+
+    #[test]
+    fn test_common_subsequence() {
+        let pairs = common_subsequence(b"ABCABBA", b"CBABAC");
+        assert_eq!(pairs.len(), 4);
+        assert!(pairs.iter().all(|&(x, y)| b"ABCABBA"[x] == b"CBABAC"[y]));
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 && w[0].1 < w[1].1));
+
+        assert_eq!(common_subsequence::<u8>(&[], &[]), []);
+        assert_eq!(common_subsequence(b"ab", b""), []);
+    }
+
+    #[test]
+    fn test_diff_edge_cases() {
+        assert_eq!(diff::<u8>(&[], &[]), []);
+        assert_eq!(
+            diff(b"", b"ab"),
+            [
+                Edit::Insert {
+                    index: 0,
+                    value: b'a'
+                },
+                Edit::Insert {
+                    index: 1,
+                    value: b'b'
+                },
+            ]
+        );
+        assert_eq!(
+            diff(b"ab", b""),
+            [Edit::Remove { index: 0 }, Edit::Remove { index: 1 }]
+        );
+        assert_eq!(diff(b"abc", b"cab"), [Edit::Move { from: 2, to: 0 }]);
+    }
+
+    #[test]
+    fn test_apply_diff_reproduces_new() {
+        let result = check_property(0xD1FF, 300, |rng| {
+            let sequence = |rng: &mut crate::random::SplitMix64| -> Vec<u64> {
+                let length = rng.next_bounded(12);
+                (0..length).map(|_| rng.next_bounded(4)).collect()
+            };
+
+            let old = sequence(rng);
+            let new = sequence(rng);
+
+            let mut list = List::new();
+            list.extend(old.iter().copied());
+            list.apply_diff(diff(&old, &new));
+
+            let applied: Vec<u64> = list.iter().copied().collect();
+            if applied == new {
+                Ok(())
+            } else {
+                Err(alloc::format!("{old:?} -> {new:?} produced {applied:?}").into())
+            }
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+}
@@ -1,32 +1,36 @@
-use core::{ops::Index, ptr};
+use core::{cmp::Ordering, hash::Hash, marker::PhantomData, ops::Index, ptr::NonNull};
 
-use alloc::{
-    boxed::Box,
-    fmt,
-    string::{String, ToString},
-    vec::Vec,
+use alloc::{alloc::handle_alloc_error, collections::BTreeMap, fmt, vec::Vec};
+use allocator_api2::alloc::{Allocator, Global, Layout};
+
+use crate::{
+    collections::diff::Edit, errors::CollectionError, keypath::KeyPath, security::Zeroize,
+    text::natural::natural_cmp,
 };
 
-mod iter;
+pub mod iter;
+#[cfg(feature = "nightly")]
+mod macros;
+
+type Link<T> = Option<NonNull<Node<T>>>;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 struct Node<T> {
     value: T,
-    prev: *mut Node<T>,
-    next: *mut Node<T>,
+    prev: Link<T>,
+    next: Link<T>,
 }
 
 impl<T> Node<T> {
     const fn new(value: T) -> Self {
         Self {
             value,
-            prev: ptr::null_mut(),
-            next: ptr::null_mut(),
+            prev: None,
+            next: None,
         }
     }
 }
 
-/// A doubly-linked list implementation with reference-counted nodes.
+/// A doubly-linked list implementation with individually allocated nodes.
 ///
 /// This list allows adding and removing elements efficiently at both ends.
 ///
@@ -61,12 +65,13 @@ impl<T> Node<T> {
 /// assert_eq!(list.pop_back(), Some(4));
 /// assert_eq!(list.pop_back(), Some(3));
 /// ```
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct List<T> {
-    head: Option<*mut Node<T>>,
-    tail: Option<*mut Node<T>>,
+pub struct List<T, A: Allocator = Global> {
+    head: Link<T>,
+    tail: Link<T>,
     length: usize,
     capacity: usize,
+    alloc: A,
+    marker: PhantomData<T>,
 }
 
 impl<T> List<T> {
@@ -82,12 +87,7 @@ impl<T> List<T> {
     /// ```
     #[must_use]
     pub const fn new() -> Self {
-        Self {
-            head: None,
-            tail: None,
-            length: 0,
-            capacity: 0,
-        }
+        Self::new_in(Global)
     }
 
     /// Creates a new empty list with the specified capacity.
@@ -105,14 +105,58 @@ impl<T> List<T> {
     /// ```
     #[must_use]
     pub const fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_in(capacity, Global)
+    }
+}
+
+impl<T, A: Allocator> List<T, A> {
+    /// Creates a new empty list whose nodes are allocated with `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use libx::collections::list::doubly_linked::List;
+    ///
+    /// let mut list = List::new_in(Global);
+    /// list.push_back(1);
+    /// assert_eq!(list.len(), 1);
+    /// ```
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        Self::with_capacity_in(0, alloc)
+    }
+
+    /// Creates a new empty list with the specified capacity, whose nodes are allocated
+    /// with `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use libx::collections::list::doubly_linked::List;
+    ///
+    /// let list: List<u32, _> = List::with_capacity_in(10, Global);
+    /// assert_eq!(list.capacity(), 10);
+    /// ```
+    #[must_use]
+    pub const fn with_capacity_in(capacity: usize, alloc: A) -> Self {
         Self {
             head: None,
             tail: None,
             length: 0,
             capacity,
+            alloc,
+            marker: PhantomData,
         }
     }
 
+    /// Returns a reference to the allocator backing this list.
+    #[must_use]
+    pub const fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
     /// Pushes an element to the front of the list.
     ///
     /// If the length of the list exceeds its capacity, the list will be resized.
@@ -132,27 +176,41 @@ impl<T> List<T> {
     /// assert_eq!(list.pop_front(), Some(2));
     /// ```
     pub fn push_front(&mut self, value: T) {
-        let new_node = Box::into_raw(Box::new(Node::new(value)));
+        let new_node = self.allocate_node(value);
+        self.grow_for_push_front();
+        self.link_front(new_node);
+    }
+
+    /// Pushes an element to the front of the list, reporting allocation failure instead of
+    /// aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::AllocationFailed`] if the allocator cannot provide memory
+    /// for a new node. The list is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::List;
+    ///
+    /// let mut list = List::new();
+    /// assert_eq!(list.try_push_front(1), Ok(()));
+    /// assert_eq!(list.front(), Some(1));
+    /// ```
+    pub fn try_push_front(&mut self, value: T) -> Result<(), CollectionError> {
+        let new_node = self.try_allocate_node(value)?;
+        self.grow_for_push_front();
+        self.link_front(new_node);
+        Ok(())
+    }
 
+    const fn grow_for_push_front(&mut self) {
         if self.length >= self.capacity {
             // Perform resizing or handle capacity overflow error
             // For simplicity, let's double the capacity if it's reached
             self.capacity *= 2;
         }
-
-        if let Some(old_head) = self.head.take() {
-            unsafe {
-                (*old_head).prev = new_node;
-
-                (*new_node).next = old_head;
-            }
-            self.head = Some(new_node);
-        } else {
-            self.head = Some(new_node);
-            self.tail = Some(new_node);
-        }
-
-        self.length += 1;
     }
 
     /// Pushes an element to the back of the list.
@@ -174,8 +232,36 @@ impl<T> List<T> {
     /// assert_eq!(list.pop_back(), Some(2));
     /// ```
     pub fn push_back(&mut self, value: T) {
-        let new_node = Box::into_raw(Box::new(Node::new(value)));
+        let new_node = self.allocate_node(value);
+        self.grow_for_push_back();
+        self.link_back(new_node);
+    }
+
+    /// Pushes an element to the back of the list, reporting allocation failure instead of
+    /// aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::AllocationFailed`] if the allocator cannot provide memory
+    /// for a new node. The list is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::List;
+    ///
+    /// let mut list = List::new();
+    /// assert_eq!(list.try_push_back(1), Ok(()));
+    /// assert_eq!(list.back(), Some(1));
+    /// ```
+    pub fn try_push_back(&mut self, value: T) -> Result<(), CollectionError> {
+        let new_node = self.try_allocate_node(value)?;
+        self.grow_for_push_back();
+        self.link_back(new_node);
+        Ok(())
+    }
 
+    const fn grow_for_push_back(&mut self) {
         if self.capacity == 0 {
             self.capacity = 4;
         }
@@ -185,19 +271,6 @@ impl<T> List<T> {
             // For simplicity, let's double the capacity if it's reached
             self.capacity *= 2;
         }
-
-        if let Some(old_tail) = self.tail.take() {
-            unsafe {
-                (*old_tail).next = new_node;
-                (*new_node).prev = old_tail;
-            }
-        } else {
-            self.head = Some(new_node);
-        }
-
-        self.tail = Some(new_node);
-
-        self.length += 1;
     }
 
     /// Pushes an element to the front of the list if the capacity is not reached.
@@ -208,38 +281,31 @@ impl<T> List<T> {
     ///
     /// # Errors
     ///
-    /// Returns an `Err` if the maximum capacity is reached.
+    /// Returns [`CollectionError::CapacityExceeded`] if the maximum capacity is reached, or
+    /// [`CollectionError::AllocationFailed`] if the allocator cannot provide a new node.
     ///
     /// # Examples
     ///
     /// ```
-    /// use libx::collections::list::doubly_linked::List;
+    /// use libx::{collections::list::doubly_linked::List, errors::CollectionError};
     ///
     /// let mut list = List::with_capacity(2);
     /// assert_eq!(list.push_front_within_capacity(1), Ok(()));
     /// assert_eq!(list.push_front_within_capacity(2), Ok(()));
-    /// assert_eq!(list.push_front_within_capacity(3), Err("Maximum capacity reached".to_string()));
+    /// assert_eq!(
+    ///     list.push_front_within_capacity(3),
+    ///     Err(CollectionError::CapacityExceeded { capacity: 2 })
+    /// );
     /// ```
-    pub fn push_front_within_capacity(&mut self, value: T) -> Result<(), String> {
+    pub fn push_front_within_capacity(&mut self, value: T) -> Result<(), CollectionError> {
         if self.length >= self.capacity {
-            return Err("Maximum capacity reached".to_string());
+            return Err(CollectionError::CapacityExceeded {
+                capacity: self.capacity,
+            });
         }
 
-        let new_node = Box::into_raw(Box::new(Node::new(value)));
-
-        if let Some(old_head) = self.head.take() {
-            unsafe {
-                (*old_head).prev = new_node;
-                (*new_node).next = old_head;
-            }
-
-            self.head = Some(new_node);
-        } else {
-            self.head = Some(new_node);
-            self.tail = Some(new_node);
-        }
-
-        self.length += 1;
+        let new_node = self.try_allocate_node(value)?;
+        self.link_front(new_node);
         Ok(())
     }
 
@@ -251,37 +317,31 @@ impl<T> List<T> {
     ///
     /// # Errors
     ///
-    /// Returns an `Err` if the maximum capacity is reached.
+    /// Returns [`CollectionError::CapacityExceeded`] if the maximum capacity is reached, or
+    /// [`CollectionError::AllocationFailed`] if the allocator cannot provide a new node.
     ///
     /// # Examples
     ///
     /// ```
-    /// use libx::collections::list::doubly_linked::List;
+    /// use libx::{collections::list::doubly_linked::List, errors::CollectionError};
     ///
     /// let mut list = List::with_capacity(2);
     /// assert_eq!(list.push_back_within_capacity(1), Ok(()));
     /// assert_eq!(list.push_back_within_capacity(2), Ok(()));
-    /// assert_eq!(list.push_back_within_capacity(3), Err("Maximum capacity reached".to_string()));
+    /// assert_eq!(
+    ///     list.push_back_within_capacity(3),
+    ///     Err(CollectionError::CapacityExceeded { capacity: 2 })
+    /// );
     /// ```
-    pub fn push_back_within_capacity(&mut self, value: T) -> Result<(), String> {
+    pub fn push_back_within_capacity(&mut self, value: T) -> Result<(), CollectionError> {
         if self.length >= self.capacity {
-            return Err("Maximum capacity reached".to_string());
-        }
-
-        let new_node = Box::into_raw(Box::new(Node::new(value)));
-
-        if let Some(old_tail) = self.tail.take() {
-            unsafe {
-                (*old_tail).next = new_node;
-                (*new_node).prev = old_tail;
-            }
-        } else {
-            self.head = Some(new_node);
+            return Err(CollectionError::CapacityExceeded {
+                capacity: self.capacity,
+            });
         }
 
-        self.tail = Some(new_node);
-
-        self.length += 1;
+        let new_node = self.try_allocate_node(value)?;
+        self.link_back(new_node);
         Ok(())
     }
 
@@ -309,22 +369,7 @@ impl<T> List<T> {
     /// assert_eq!(list.pop_front(), None);
     /// ```
     pub fn pop_front(&mut self) -> Option<T> {
-        match self.head.take() {
-            Some(old_head) => {
-                if unsafe { !(*old_head).next.is_null() } {
-                    unsafe {
-                        (*(*old_head).next).prev = ptr::null_mut();
-                        self.head = Some((*old_head).next);
-                    }
-                } else {
-                    self.tail = None;
-                }
-
-                self.length -= 1;
-                unsafe { Some(Box::from_raw(old_head).value) }
-            }
-            None => None,
-        }
+        self.head.map(|head| self.unlink_node(head))
     }
 
     /// Removes and returns the element from the back of the list.
@@ -351,22 +396,7 @@ impl<T> List<T> {
     /// assert_eq!(list.pop_back(), None);
     /// ```
     pub fn pop_back(&mut self) -> Option<T> {
-        match self.tail.take() {
-            Some(old_tail) => {
-                if unsafe { !(*old_tail).prev.is_null() } {
-                    unsafe {
-                        (*(*old_tail).prev).next = ptr::null_mut();
-                        self.tail = Some((*old_tail).prev);
-                    }
-                } else {
-                    self.head = None;
-                }
-
-                self.length -= 1;
-                unsafe { Some(Box::from_raw(old_tail).value) }
-            }
-            None => None,
-        }
+        self.tail.map(|tail| self.unlink_node(tail))
     }
 
     /// Returns the number of elements in the list.
@@ -468,7 +498,7 @@ impl<T> List<T> {
     where
         T: Clone,
     {
-        self.head.map(|head| unsafe { (*head).value.clone() })
+        self.head.map(|head| self.value(head).clone())
     }
 
     /// Returns the value of the element at the back of the list, without removing it.
@@ -497,7 +527,7 @@ impl<T> List<T> {
     where
         T: Clone,
     {
-        self.tail.map(|tail| unsafe { (*tail).value.clone() })
+        self.tail.map(|tail| self.value(tail).clone())
     }
 
     /// Removes all elements from the list.
@@ -562,29 +592,10 @@ impl<T> List<T> {
         } else if index == self.length {
             self.push_back(value);
         } else {
-            let new_node = Box::into_raw(Box::new(Node::new(value)));
-
-            let mut current_index = 0;
-            let mut current_node = self.head.expect("head is None");
-
-            while current_index < index {
-                unsafe {
-                    current_node = (*current_node).next;
-                    current_index += 1;
-                }
-            }
-
-            unsafe {
-                let prev_node = (*current_node).prev;
-
-                (*new_node).prev = prev_node;
-                (*new_node).next = current_node;
-
-                (*prev_node).next = new_node;
-                (*current_node).prev = new_node;
-            }
+            let current_node = self.node_at(index);
+            let new_node = self.allocate_node(value);
 
-            self.length += 1;
+            self.link_before(new_node, current_node);
         }
     }
 
@@ -635,31 +646,13 @@ impl<T> List<T> {
                 self.push_back(value);
             }
         } else {
-            let mut current_index = 0;
-            let mut current_node = self.head.expect("head is None");
-
-            while current_index < index {
-                unsafe {
-                    current_node = (*current_node).next;
-                    current_index += 1;
-                }
-            }
+            let mut current_node = self.node_at(index);
 
             for value in iter.into_iter().rev() {
-                let new_node = Box::into_raw(Box::new(Node::new(value)));
-
-                unsafe {
-                    let prev_node = (*current_node).prev;
-
-                    (*new_node).prev = prev_node;
-                    (*new_node).next = current_node;
-
-                    (*prev_node).next = new_node;
-                    (*current_node).prev = new_node;
-                }
+                let new_node = self.allocate_node(value);
 
+                self.link_before(new_node, current_node);
                 current_node = new_node;
-                self.length += 1;
             }
         }
     }
@@ -693,25 +686,11 @@ impl<T> List<T> {
     pub fn remove_by_index(&mut self, index: usize) -> Option<T> {
         assert!(index < self.length, "Index out of bounds");
 
-        let mut current_node = self.head;
-        let mut current_index = 0;
-
-        while let Some(node) = current_node {
-            let next_node = unsafe { (*node).next };
-
-            if current_index == index {
-                let removed_element = self.unlink_node(node);
-                return Some(removed_element);
-            }
-
-            current_node = Some(next_node);
-            current_index += 1;
-        }
-
-        None
+        let node = self.node_at(index);
+        Some(self.unlink_node(node))
     }
 
-    /// Removes elements from the list within the specified range and returns them as a slice.
+    /// Removes elements from the list within the specified range and returns them in order.
     ///
     /// # Arguments
     ///
@@ -719,65 +698,166 @@ impl<T> List<T> {
     ///
     /// # Returns
     ///
-    /// Returns a slice containing the removed elements.
+    /// Returns a vector containing the removed elements.
     ///
     /// # Panics
     ///
     /// This function panics if the range is out of bounds.
-    pub fn remove_by_range<'a>(&mut self, range: core::ops::Range<usize>) -> &'a [T]
-    where
-        T: Clone,
-    {
-        let start = range.start;
-        let end = range.end;
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let mut list = list![10, 20, 30, 40, 50];
+    /// assert_eq!(list.remove_by_range(1..4), vec![20, 30, 40]);
+    /// assert_eq!(list.len(), 2);
+    /// ```
+    pub fn remove_by_range(&mut self, range: core::ops::Range<usize>) -> Vec<T> {
+        let core::ops::Range { start, end } = range;
 
         // Check if the range is within bounds
         assert!(start <= end && end <= self.len(), "range out of bounds");
 
-        // Find the starting node to remove
-        let mut current_index = 0;
-        let mut current_node = self.head;
+        self.drain_nodes(start, end - start)
+    }
 
-        while current_index < start {
-            if let Some(node) = current_node {
-                unsafe {
-                    current_node = Some((*node).next);
-                }
-                current_index += 1;
-            } else {
-                break;
-            }
+    /// Inserts an element at the specified index, without panicking.
+    ///
+    /// This is the fallible counterpart of [`insert`](Self::insert).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::IndexOutOfBounds`] if `index` is greater than the length
+    /// of the list. The list is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, errors::CollectionError};
+    ///
+    /// let mut list = list![1, 3];
+    /// assert_eq!(list.try_insert(1, 2), Ok(()));
+    /// assert_eq!(
+    ///     list.try_insert(5, 4),
+    ///     Err(CollectionError::IndexOutOfBounds { index: 5, len: 3 })
+    /// );
+    /// ```
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), CollectionError> {
+        if index > self.length {
+            return Err(CollectionError::IndexOutOfBounds {
+                index,
+                len: self.length,
+            });
         }
 
-        // Remove the elements within the range
-        let mut removed_elements = Vec::new();
+        self.insert(index, value);
+        Ok(())
+    }
 
-        while current_index < end {
-            if let Some(node) = current_node {
-                let next_node;
+    /// Removes and returns the element at the given index, without panicking.
+    ///
+    /// This is the fallible counterpart of [`remove_by_index`](Self::remove_by_index).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::IndexOutOfBounds`] if `index` is not less than the length
+    /// of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, errors::CollectionError};
+    ///
+    /// let mut list = list![1, 2, 3];
+    /// assert_eq!(list.try_remove_by_index(1), Ok(2));
+    /// assert_eq!(
+    ///     list.try_remove_by_index(2),
+    ///     Err(CollectionError::IndexOutOfBounds { index: 2, len: 2 })
+    /// );
+    /// ```
+    pub fn try_remove_by_index(&mut self, index: usize) -> Result<T, CollectionError> {
+        let error = CollectionError::IndexOutOfBounds {
+            index,
+            len: self.length,
+        };
+
+        if index >= self.length {
+            return Err(error);
+        }
 
-                unsafe {
-                    next_node = (*node).next;
-                    let elements = self.unlink_node(node);
-                    removed_elements.push(elements);
-                }
+        self.remove_by_index(index).ok_or(error)
+    }
 
-                current_node = Some(next_node);
-                current_index += 1;
-            } else {
-                break;
-            }
+    /// Returns a reference to the element at the given index, without panicking.
+    ///
+    /// This is the fallible counterpart of indexing with `list[index]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::IndexOutOfBounds`] if `index` is not less than the length
+    /// of the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, errors::CollectionError};
+    ///
+    /// let list = list![10, 20];
+    /// assert_eq!(list.try_get(1), Ok(&20));
+    /// assert_eq!(
+    ///     list.try_get(2),
+    ///     Err(CollectionError::IndexOutOfBounds { index: 2, len: 2 })
+    /// );
+    /// ```
+    pub fn try_get(&self, index: usize) -> Result<&T, CollectionError> {
+        if index >= self.length {
+            return Err(CollectionError::IndexOutOfBounds {
+                index,
+                len: self.length,
+            });
         }
 
-        // Update the head and tail pointers
-        if start == 0 {
-            self.head = current_node;
-        }
-        if end == self.length {
-            self.tail = current_node;
+        Ok(&self[index])
+    }
+
+    /// Removes the elements within `range` and returns them in order, without panicking.
+    ///
+    /// This is the non-panicking counterpart of [`remove_by_range`](Self::remove_by_range).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::RangeOutOfBounds`] if the range is reversed or extends
+    /// past the end of the list. The list is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, errors::CollectionError};
+    ///
+    /// let mut list = list![1, 2, 3, 4, 5];
+    /// assert_eq!(list.try_drain_range(1..3), Ok(vec![2, 3]));
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!(
+    ///     list.try_drain_range(2..4),
+    ///     Err(CollectionError::RangeOutOfBounds { start: 2, end: 4, len: 3 })
+    /// );
+    /// ```
+    pub fn try_drain_range(
+        &mut self,
+        range: core::ops::Range<usize>,
+    ) -> Result<Vec<T>, CollectionError> {
+        let core::ops::Range { start, end } = range;
+
+        if start > end || end > self.length {
+            return Err(CollectionError::RangeOutOfBounds {
+                start,
+                end,
+                len: self.length,
+            });
         }
 
-        removed_elements.leak()
+        Ok(self.drain_nodes(start, end - start))
     }
 
     /// Returns an iterator over the elements of the list.
@@ -797,90 +877,555 @@ impl<T> List<T> {
     ///
     /// let mut iterator = list.iter();
     ///
-    /// assert_eq!(iterator.next(), Some(1));
-    /// assert_eq!(iterator.next(), Some(2));
-    /// assert_eq!(iterator.next(), Some(3));
+    /// assert_eq!(iterator.next(), Some(&1));
+    /// assert_eq!(iterator.next_back(), Some(&3));
+    /// assert_eq!(iterator.next(), Some(&2));
     /// assert_eq!(iterator.next(), None);
     /// ```
     #[must_use]
-    pub const fn iter(&self) -> iter::Iter<'_, T>
-    where
-        T: Clone,
-    {
+    pub const fn iter(&self) -> iter::Iter<'_, T> {
         iter::Iter {
-            stack: self,
-            index: 0,
+            head: self.head,
+            tail: self.tail,
+            len: self.length,
+            marker: PhantomData,
         }
     }
 
-    fn unlink_node(&mut self, node: *mut Node<T>) -> T {
-        let prev_node = unsafe { (*node).prev };
-        let next_node = unsafe { (*node).next };
-
-        if prev_node.is_null() {
-            self.head = Some(next_node);
-        } else {
-            unsafe {
-                (*prev_node).next = next_node;
-            }
-        }
-
-        if next_node.is_null() {
-            self.tail = Some(prev_node);
-        } else {
-            unsafe {
-                (*next_node).prev = prev_node;
-            }
+    /// Returns an iterator over mutable references to the elements, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let mut list = list![1, 2, 3];
+    ///
+    /// for value in list.iter_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(list, list![10, 20, 30]);
+    /// ```
+    pub const fn iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        iter::IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.length,
+            marker: PhantomData,
         }
-
-        self.length -= 1;
-
-        unsafe { Box::from_raw(node).value }
-    }
-}
-
-impl<T> Drop for List<T> {
-    fn drop(&mut self) {
-        self.clear();
     }
-}
 
-impl<T> Default for List<T>
-where
-    T: Clone,
-{
-    fn default() -> Self {
-        Self::new()
+    /// Returns a vector holding clones of the elements, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let list = list![1, 2, 3];
+    /// assert_eq!(list.to_vec(), [1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
     }
-}
-
-impl<T> Index<usize> for List<T> {
-    type Output = T;
-
-    fn index(&self, index: usize) -> &Self::Output {
-        // Check if the index is within bounds
-        assert!(index < self.length, "Index out of bounds");
 
-        // Traverse the list to find the node at the specified index
-        let mut current = self.head.expect("head is None");
-        for _ in 0..index {
-            unsafe {
-                let next = (*current).next;
+    /// Moves the elements into a contiguous vector, front to back, without cloning them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let list = list![String::from("a"), String::from("b")];
+    /// assert_eq!(list.make_contiguous(), ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub fn make_contiguous(mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.length);
 
-                current = next;
-            }
+        while let Some(value) = self.pop_front() {
+            values.push(value);
         }
 
-        // Return a reference to the value inside the node
-        unsafe { &(*current).value }
+        values
     }
-}
-
-impl<'a, T> IntoIterator for &'a List<T>
-where
-    T: Clone,
-{
-    type Item = T;
+
+    /// Sorts the list in place with the comparator `compare`, keeping equal elements in
+    /// their original order.
+    ///
+    /// Nodes are relinked rather than reallocated, so no element is moved or cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let mut list = list![3, 1, 2];
+    /// list.sort_by(|a, b| b.cmp(a));
+    ///
+    /// assert_eq!(list, list![3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        self.sort_nodes_by(compare);
+    }
+
+    /// Sorts the list of strings in place in natural order, so that `file2` comes before
+    /// `file10`, keeping equal elements in their original order.
+    ///
+    /// See [`natural_cmp`] for the ordering; use
+    /// [`sort_by`](Self::sort_by) with [`NaturalOrder`](crate::text::NaturalOrder) for
+    /// case-insensitive or locale-aware variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let mut files = list!["img12.png", "img10.png", "img2.png"];
+    /// files.sort_naturally();
+    ///
+    /// assert_eq!(files, list!["img2.png", "img10.png", "img12.png"]);
+    /// ```
+    pub fn sort_naturally(&mut self)
+    where
+        T: AsRef<str>,
+    {
+        self.sort_nodes_by(|a, b| natural_cmp(a.as_ref(), b.as_ref()));
+    }
+
+    /// Sorts the list in place by the value at `key_path`, keeping equal elements in their
+    /// original order.
+    ///
+    /// Nodes are relinked rather than reallocated, so no element is moved or cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, keypath};
+    ///
+    /// struct Person {
+    ///     name: &'static str,
+    ///     age: u32,
+    /// }
+    ///
+    /// let mut people = list![
+    ///     Person { name: "Grace", age: 85 },
+    ///     Person { name: "Ada", age: 36 },
+    /// ];
+    ///
+    /// people.sort_by_keypath(&keypath!(Person.age));
+    /// assert_eq!(people[0].name, "Ada");
+    /// ```
+    pub fn sort_by_keypath<V>(&mut self, key_path: &KeyPath<T, V>)
+    where
+        V: Ord + ?Sized,
+    {
+        self.sort_nodes_by(|a, b| key_path.get(a).cmp(key_path.get(b)));
+    }
+
+    /// Returns a copy of the list sorted by the value at `key_path`, keeping equal elements
+    /// in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, keypath};
+    ///
+    /// #[derive(Clone)]
+    /// struct Person {
+    ///     name: &'static str,
+    ///     age: u32,
+    /// }
+    ///
+    /// let people = list![
+    ///     Person { name: "Grace", age: 85 },
+    ///     Person { name: "Ada", age: 36 },
+    /// ];
+    ///
+    /// let by_age = people.sorted_by_keypath(&keypath!(Person.age));
+    /// assert_eq!(by_age[0].name, "Ada");
+    /// assert_eq!(people[0].name, "Grace");
+    /// ```
+    #[must_use]
+    pub fn sorted_by_keypath<V>(&self, key_path: &KeyPath<T, V>) -> Self
+    where
+        T: Clone,
+        A: Clone,
+        V: Ord + ?Sized,
+    {
+        let mut sorted = self.clone();
+        sorted.sort_by_keypath(key_path);
+        sorted
+    }
+
+    /// Groups clones of the elements by the value at `key_path`, preserving their order
+    /// within each group.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::list::doubly_linked::list, keypath};
+    ///
+    /// #[derive(Clone)]
+    /// struct Person {
+    ///     name: &'static str,
+    ///     city: &'static str,
+    /// }
+    ///
+    /// let people = list![
+    ///     Person { name: "Ada", city: "London" },
+    ///     Person { name: "Grace", city: "New York" },
+    ///     Person { name: "Alan", city: "London" },
+    /// ];
+    ///
+    /// let groups = people.grouped_by_keypath(&keypath!(Person.city));
+    /// assert_eq!(groups["London"].len(), 2);
+    /// assert_eq!(groups["New York"][0].name, "Grace");
+    /// ```
+    #[must_use]
+    pub fn grouped_by_keypath<V>(&self, key_path: &KeyPath<T, V>) -> BTreeMap<V, List<T>>
+    where
+        T: Clone,
+        V: Ord + Clone,
+    {
+        let mut groups: BTreeMap<V, List<T>> = BTreeMap::new();
+
+        for value in self {
+            groups
+                .entry(key_path.get(value).clone())
+                .or_default()
+                .push_back(value.clone());
+        }
+
+        groups
+    }
+
+    /// Applies an edit script produced by [`diff`](crate::collections::diff::diff), turning
+    /// the list from the script's old sequence into its new one.
+    ///
+    /// Removals and move sources are taken out from the back first, then insertions and
+    /// move destinations are put in from the front, so every index in the script refers to
+    /// the sequence it was computed against.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds, which happens when the script was computed
+    /// against a different list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::{diff::diff, list::doubly_linked::list};
+    ///
+    /// let mut list = list![1, 2, 3, 4];
+    /// list.apply_diff(diff(&[1, 2, 3, 4], &[4, 1, 3, 5]));
+    ///
+    /// assert_eq!(list, list![4, 1, 3, 5]);
+    /// ```
+    pub fn apply_diff<I>(&mut self, edits: I)
+    where
+        I: IntoIterator<Item = Edit<T>>,
+    {
+        let mut sources = Vec::new();
+        let mut insertions = Vec::new();
+
+        for edit in edits {
+            match edit {
+                Edit::Remove { index } => sources.push((index, None)),
+                Edit::Move { from, to } => sources.push((from, Some(to))),
+                Edit::Insert { index, value } => insertions.push((index, value)),
+            }
+        }
+
+        sources.sort_unstable_by_key(|&(index, _)| core::cmp::Reverse(index));
+
+        for (index, destination) in sources {
+            let value = self.remove_by_index(index);
+
+            if let (Some(destination), Some(value)) = (destination, value) {
+                insertions.push((destination, value));
+            }
+        }
+
+        insertions.sort_by_key(|&(index, _)| index);
+
+        for (index, value) in insertions {
+            self.insert(index, value);
+        }
+    }
+
+    // The unsafe core of the list. Every raw pointer access lives below this point, and
+    // each helper relies on the same invariant: `head`, `tail`, and every `prev`/`next`
+    // link point to nodes allocated by `self.alloc` for this list, which stay alive
+    // until `unlink_node` frees them, and `length` counts exactly those nodes.
+
+    /// Allocates an unlinked node holding `value`, aborting on allocation failure.
+    fn allocate_node(&self, value: T) -> NonNull<Node<T>> {
+        self.try_allocate_node(value)
+            .unwrap_or_else(|_| handle_alloc_error(Layout::new::<Node<T>>()))
+    }
+
+    /// Allocates an unlinked node holding `value`.
+    fn try_allocate_node(&self, value: T) -> Result<NonNull<Node<T>>, CollectionError> {
+        let node = self
+            .alloc
+            .allocate(Layout::new::<Node<T>>())
+            .map_err(|_| CollectionError::AllocationFailed)?
+            .cast::<Node<T>>();
+
+        // SAFETY: the allocator returned a block that is valid for writes and laid out
+        // for `Node<T>`.
+        unsafe { node.as_ptr().write(Node::new(value)) };
+
+        #[cfg(feature = "stats")]
+        crate::stats::record_node_allocation();
+
+        Ok(node)
+    }
+
+    /// Links a freshly allocated node in front of the current head.
+    fn link_front(&mut self, node: NonNull<Node<T>>) {
+        // SAFETY: `node` is unlinked and owned by this list, and `head` is live by the
+        // list invariant, so both may be written through.
+        unsafe {
+            (*node.as_ptr()).prev = None;
+            (*node.as_ptr()).next = self.head;
+
+            match self.head {
+                Some(head) => (*head.as_ptr()).prev = Some(node),
+                None => self.tail = Some(node),
+            }
+        }
+
+        self.head = Some(node);
+        self.length += 1;
+    }
+
+    /// Links a freshly allocated node after the current tail.
+    fn link_back(&mut self, node: NonNull<Node<T>>) {
+        // SAFETY: `node` is unlinked and owned by this list, and `tail` is live by the
+        // list invariant, so both may be written through.
+        unsafe {
+            (*node.as_ptr()).next = None;
+            (*node.as_ptr()).prev = self.tail;
+
+            match self.tail {
+                Some(tail) => (*tail.as_ptr()).next = Some(node),
+                None => self.head = Some(node),
+            }
+        }
+
+        self.tail = Some(node);
+        self.length += 1;
+    }
+
+    /// Links a freshly allocated node directly before `next`, which must be in this list.
+    fn link_before(&mut self, node: NonNull<Node<T>>, next: NonNull<Node<T>>) {
+        // SAFETY: `node` is unlinked and owned by this list; `next` and its predecessor
+        // are live by the list invariant.
+        unsafe {
+            let prev = (*next.as_ptr()).prev;
+
+            (*node.as_ptr()).prev = prev;
+            (*node.as_ptr()).next = Some(next);
+            (*next.as_ptr()).prev = Some(node);
+
+            match prev {
+                Some(prev) => (*prev.as_ptr()).next = Some(node),
+                None => self.head = Some(node),
+            }
+        }
+
+        self.length += 1;
+    }
+
+    /// Unlinks `node`, which must be in this list, frees it, and returns its value.
+    fn unlink_node(&mut self, node: NonNull<Node<T>>) -> T {
+        // SAFETY: `node` and its neighbours are live by the list invariant.
+        let (prev, next) = unsafe { ((*node.as_ptr()).prev, (*node.as_ptr()).next) };
+
+        // SAFETY: as above; the neighbours are relinked around `node` before it is freed.
+        unsafe {
+            match prev {
+                Some(prev) => (*prev.as_ptr()).next = next,
+                None => self.head = next,
+            }
+
+            match next {
+                Some(next) => (*next.as_ptr()).prev = prev,
+                None => self.tail = prev,
+            }
+        }
+
+        self.length -= 1;
+
+        // SAFETY: `node` came from `allocate_node`, is now unreachable from the list, and
+        // is not used again.
+        unsafe { self.free_node(node) }
+    }
+
+    /// Unlinks `count` nodes starting at `start` and returns their values in order.
+    fn drain_nodes(&mut self, start: usize, count: usize) -> Vec<T> {
+        let mut drained = Vec::with_capacity(count);
+
+        if count == 0 {
+            return drained;
+        }
+
+        let mut current = Some(self.node_at(start));
+
+        for _ in 0..count {
+            let Some(node) = current else {
+                break;
+            };
+
+            // SAFETY: `node` is live by the list invariant until it is unlinked below.
+            current = unsafe { (*node.as_ptr()).next };
+            drained.push(self.unlink_node(node));
+        }
+
+        drained
+    }
+
+    /// Moves the value out of `node` and releases its memory.
+    ///
+    /// # Safety
+    ///
+    /// `node` must have been returned by [`allocate_node`](Self::allocate_node) or
+    /// [`try_allocate_node`](Self::try_allocate_node) on this list, must already be
+    /// unlinked, and must not be used afterwards.
+    unsafe fn free_node(&self, node: NonNull<Node<T>>) -> T {
+        // SAFETY: the caller guarantees `node` is a live, initialized node that nothing
+        // else refers to, so its contents can be moved out exactly once.
+        let Node { value, .. } = unsafe { node.as_ptr().read() };
+
+        // SAFETY: the block was allocated by `self.alloc` with this layout.
+        unsafe { self.alloc.deallocate(node.cast(), Layout::new::<Node<T>>()) };
+
+        #[cfg(feature = "stats")]
+        crate::stats::record_node_deallocation();
+
+        value
+    }
+
+    /// Stably sorts the nodes by `compare` and relinks them in that order.
+    fn sort_nodes_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering,
+    {
+        let mut nodes = Vec::with_capacity(self.length);
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            nodes.push(node);
+            // SAFETY: `node` is live by the list invariant.
+            current = unsafe { (*node.as_ptr()).next };
+        }
+
+        nodes.sort_by(|&a, &b| compare(self.value(a), self.value(b)));
+
+        self.head = None;
+        self.tail = None;
+        self.length = 0;
+
+        for node in nodes {
+            self.link_back(node);
+        }
+    }
+
+    /// Returns the node at `index`, walking from whichever end of the list is closer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    fn node_at(&self, index: usize) -> NonNull<Node<T>> {
+        assert!(index < self.length, "Index out of bounds");
+
+        let (mut current, steps, forward) = if index <= self.length / 2 {
+            (self.head, index, true)
+        } else {
+            (self.tail, self.length - 1 - index, false)
+        };
+
+        for _ in 0..steps {
+            let Some(node) = current else {
+                break;
+            };
+
+            // SAFETY: `node` is live by the list invariant.
+            current = unsafe {
+                if forward {
+                    (*node.as_ptr()).next
+                } else {
+                    (*node.as_ptr()).prev
+                }
+            };
+        }
+
+        current.unwrap_or_else(|| unreachable!("list links are shorter than its length"))
+    }
+
+    /// Returns a reference to the value stored in `node`, which must be in this list.
+    ///
+    /// `self` is only used to tie the returned reference to a borrow of the list.
+    #[allow(clippy::unused_self)]
+    fn value(&self, node: NonNull<Node<T>>) -> &T {
+        // SAFETY: `node` is live by the list invariant, and the returned borrow of `self`
+        // prevents it from being unlinked or mutated while the reference exists.
+        unsafe { &(*node.as_ptr()).value }
+    }
+}
+
+impl<T, A: Allocator> Drop for List<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Zeroize, A: Allocator> Zeroize for List<T, A> {
+    /// Zeroizes every element in place, then removes them all.
+    fn zeroize(&mut self) {
+        let mut current = self.head;
+
+        while let Some(node) = current {
+            // SAFETY: `node` is live by the list invariant, and `self` is borrowed mutably.
+            unsafe {
+                (*node.as_ptr()).value.zeroize();
+                current = (*node.as_ptr()).next;
+            }
+        }
+
+        self.clear();
+    }
+}
+
+impl<T> Default for List<T>
+where
+    T: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for List<T, A> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.value(self.node_at(index))
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a List<T, A> {
+    type Item = &'a T;
 
     type IntoIter = iter::Iter<'a, T>;
 
@@ -889,19 +1434,152 @@ where
     }
 }
 
-unsafe impl<T> Send for List<T> {}
-unsafe impl<T> Sync for List<T> {}
+impl<'a, T, A: Allocator> IntoIterator for &'a mut List<T, A> {
+    type Item = &'a mut T;
+
+    type IntoIter = iter::IterMut<'a, T>;
 
-impl<T> fmt::Debug for List<T>
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for List<T, A> {
+    type Item = T;
+
+    type IntoIter = iter::IntoIter<T, A>;
+
+    /// Returns an iterator that moves the elements out of the list, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let names = list![String::from("ada"), String::from("grace")];
+    /// let upper: Vec<String> = names.into_iter().map(|name| name.to_uppercase()).collect();
+    ///
+    /// assert_eq!(upper, ["ADA", "GRACE"]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        iter::IntoIter { list: self }
+    }
+}
+
+// SAFETY: the list uniquely owns its nodes, so sending or sharing it is equivalent to
+// sending or sharing the values and the allocator.
+unsafe impl<T: Send, A: Allocator + Send> Send for List<T, A> {}
+// SAFETY: see above; `&List` only hands out `&T` and `&A`.
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for List<T, A> {}
+
+impl<T, A> Clone for List<T, A>
+where
+    T: Clone,
+    A: Allocator + Clone,
+{
+    /// Returns a deep copy of the list, allocated with a clone of its allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let original = list![1, 2];
+    /// let mut copy = original.clone();
+    /// copy.push_back(3);
+    ///
+    /// assert_eq!(original.len(), 2);
+    /// assert_eq!(copy.len(), 3);
+    /// ```
+    fn clone(&self) -> Self {
+        let mut list = Self::with_capacity_in(self.capacity, self.alloc.clone());
+
+        for value in self {
+            list.push_back(value.clone());
+        }
+
+        list.capacity = self.capacity;
+        list
+    }
+}
+
+impl<T, A, B> PartialEq<List<T, B>> for List<T, A>
+where
+    T: PartialEq,
+    A: Allocator,
+    B: Allocator,
+{
+    fn eq(&self, other: &List<T, B>) -> bool {
+        self.length == other.length && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for List<T, A> {}
+
+impl<T, A> PartialOrd for List<T, A>
+where
+    T: PartialOrd,
+    A: Allocator,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord, A: Allocator> Ord for List<T, A> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<T: Hash, A: Allocator> Hash for List<T, A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.length);
+
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T, A: Allocator> fmt::Debug for List<T, A>
 where
-    T: fmt::Debug + Clone,
+    T: fmt::Debug,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_list().entries(self.iter()).finish()
     }
 }
 
-impl<T> Extend<T> for List<T> {
+impl<T, A: Allocator> fmt::Display for List<T, A>
+where
+    T: fmt::Display,
+{
+    /// Formats the list as its elements in order, enclosed in brackets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// assert_eq!(list![1, 2, 3].to_string(), "[1, 2, 3]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+
+        for (index, value) in self.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            fmt::Display::fmt(value, f)?;
+        }
+
+        f.write_str("]")
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for List<T, A> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for item in iter {
             self.push_back(item);
@@ -909,29 +1587,172 @@ impl<T> Extend<T> for List<T> {
     }
 }
 
-pub macro list {
-    () => {
-        $crate::list::doubly_linked::List::new()
-    },
-
-    ($($x:expr),*) => {
-        {
-            let mut temp_list = $crate::collections::list::doubly_linked::List::new();
-            $(
-                temp_list.push_back($x);
-            )*
-            temp_list
+#[cfg(feature = "std")]
+impl<T> From<std::collections::LinkedList<T>> for List<T> {
+    /// Converts a `std` linked list into a [`List`], preserving element order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::LinkedList;
+    ///
+    /// use libx::collections::list::doubly_linked::List;
+    ///
+    /// let linked: LinkedList<_> = [1, 2, 3].into_iter().collect();
+    /// let mut list = List::from(linked);
+    ///
+    /// assert_eq!(list.pop_front(), Some(1));
+    /// assert_eq!(list.pop_back(), Some(3));
+    /// ```
+    fn from(linked: std::collections::LinkedList<T>) -> Self {
+        let mut list = Self::new();
+        list.extend(linked);
+        list
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, A: Allocator> From<List<T, A>> for std::collections::LinkedList<T> {
+    /// Converts a [`List`] into a `std` linked list, preserving element order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::LinkedList;
+    ///
+    /// use libx::collections::list::doubly_linked::list;
+    ///
+    /// let linked = LinkedList::from(list![1, 2, 3]);
+    ///
+    /// assert_eq!(linked.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+    /// ```
+    fn from(mut list: List<T, A>) -> Self {
+        let mut linked = Self::new();
+
+        while let Some(value) = list.pop_front() {
+            linked.push_back(value);
         }
+
+        linked
     }
 }
 
+#[cfg(feature = "nightly")]
+pub use macros::list;
+
+// Stable toolchains lack `decl_macro`, so the same macro is exported through
+// `macro_rules!` and re-exported at the path the nightly macro lives at.
+#[cfg(not(feature = "nightly"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __list {
+    () => {
+        $crate::collections::list::doubly_linked::List::new()
+    };
+
+    ($($x:expr),* $(,)?) => {{
+        let mut temp_list = $crate::collections::list::doubly_linked::List::new();
+        $(
+            temp_list.push_back($x);
+        )*
+        temp_list
+    }};
+}
+
+/// Creates a [`List`] containing the given elements, front to back.
+///
+/// # Examples
+///
+/// ```
+/// use libx::collections::list::doubly_linked::list;
+///
+/// let list = list![1, 2, 3];
+/// assert_eq!(list.to_string(), "[1, 2, 3]");
+/// ```
+#[cfg(not(feature = "nightly"))]
+#[doc(inline)]
+pub use crate::__list as list;
+
 #[cfg(test)]
 mod tests {
 
-    use alloc::vec;
+    use alloc::{rc::Rc, vec};
+    use allocator_api2::alloc::AllocError;
+    use core::{cell::Cell, ptr::NonNull};
 
     use super::*;
 
+    #[derive(Clone, Default)]
+    struct CountingAlloc(Rc<Cell<usize>>);
+
+    unsafe impl Allocator for CountingAlloc {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            self.0.set(self.0.get() + 1);
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            self.0.set(self.0.get() - 1);
+            unsafe { Global.deallocate(ptr, layout) };
+        }
+    }
+
+    struct FailingAlloc;
+
+    unsafe impl Allocator for FailingAlloc {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    }
+
+    #[test]
+    fn test_new_in_uses_allocator() {
+        let alloc = CountingAlloc::default();
+        let mut list = List::new_in(alloc.clone());
+
+        list.push_back(1);
+        list.push_front(0);
+        list.insert(1, 5);
+        assert_eq!(alloc.0.get(), 3);
+
+        assert_eq!(list.pop_back(), Some(1));
+        assert_eq!(alloc.0.get(), 2);
+
+        drop(list);
+        assert_eq!(alloc.0.get(), 0);
+    }
+
+    #[test]
+    fn test_try_push_reports_allocation_failure() {
+        let mut list = List::new_in(FailingAlloc);
+
+        assert_eq!(
+            list.try_push_back(1),
+            Err(CollectionError::AllocationFailed)
+        );
+        assert_eq!(
+            list.try_push_front(1),
+            Err(CollectionError::AllocationFailed)
+        );
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_clone_is_deep() {
+        let original: List<i32> = list![1, 2, 3];
+        let mut copy = original.clone();
+
+        copy.push_back(4);
+        assert_eq!(copy.pop_front(), Some(1));
+
+        assert_eq!(original.len(), 3);
+        assert_eq!(original[0], 1);
+        assert_ne!(original, copy);
+        assert_eq!(original, original.clone());
+    }
+
     #[test]
     fn test_new_list_is_empty() {
         let list: List<u32> = List::new();
@@ -967,7 +1788,7 @@ mod tests {
         assert_eq!(list.push_front_within_capacity(2), Ok(()));
         assert_eq!(
             list.push_front_within_capacity(3),
-            Err("Maximum capacity reached".to_string())
+            Err(CollectionError::CapacityExceeded { capacity: 2 })
         );
     }
 
@@ -978,7 +1799,7 @@ mod tests {
         assert_eq!(list.push_back_within_capacity(2), Ok(()));
         assert_eq!(
             list.push_back_within_capacity(3),
-            Err("Maximum capacity reached".to_string())
+            Err(CollectionError::CapacityExceeded { capacity: 2 })
         );
     }
 
@@ -1120,18 +1941,173 @@ mod tests {
     }
 
     #[test]
-    #[cfg_attr(miri, ignore)]
+    fn test_display() {
+        let list: List<i32> = List::new();
+        assert_eq!(alloc::format!("{list}"), "[]");
+
+        let list = list!["a", "b"];
+        assert_eq!(alloc::format!("{list}"), "[a, b]");
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut list = list![1, 2];
+        assert_eq!(list.try_insert(2, 3), Ok(()));
+        assert_eq!(
+            list.try_insert(4, 4),
+            Err(CollectionError::IndexOutOfBounds { index: 4, len: 3 })
+        );
+        assert_eq!(list.len(), 3);
+        assert_eq!(list[2], 3);
+    }
+
+    #[test]
+    fn test_try_remove_by_index_empty_list() {
+        let mut list: List<i32> = List::new();
+        assert_eq!(
+            list.try_remove_by_index(0),
+            Err(CollectionError::IndexOutOfBounds { index: 0, len: 0 })
+        );
+    }
+
+    #[test]
+    fn test_try_remove_by_index_then_push() {
+        let mut list = list![1];
+        assert_eq!(list.try_remove_by_index(0), Ok(1));
+
+        list.push_front(2);
+        list.push_back(3);
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+    }
+
+    #[test]
+    fn test_try_get() {
+        let list = list![1, 2, 3];
+        assert_eq!(list.try_get(0), Ok(&1));
+        assert_eq!(list.try_get(2), Ok(&3));
+        assert_eq!(
+            list.try_get(3),
+            Err(CollectionError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
+    #[test]
+    fn test_try_drain_range() {
+        let mut list = list![1, 2, 3, 4];
+        assert_eq!(list.try_drain_range(0..2), Ok(vec![1, 2]));
+        assert_eq!(list.try_drain_range(1..1), Ok(vec![]));
+        assert_eq!(list.try_drain_range(0..2), Ok(vec![3, 4]));
+        assert!(list.is_empty());
+
+        list.push_back(5);
+        assert_eq!(list.front(), Some(5));
+        assert_eq!(list.back(), Some(5));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_try_drain_range_out_of_bounds() {
+        let mut list = list![1, 2, 3];
+        assert_eq!(
+            list.try_drain_range(2..1),
+            Err(CollectionError::RangeOutOfBounds {
+                start: 2,
+                end: 1,
+                len: 3
+            })
+        );
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_std_linked_list_round_trip() {
+        let linked = std::collections::LinkedList::from(list![1, 2, 3]);
+        assert_eq!(linked.len(), 3);
+
+        let mut list = List::from(linked);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+        assert_eq!(list.pop_front(), Some(3));
+    }
+
+    #[test]
     fn test_remove_by_range() {
         let mut list: List<i32> = list![10, 20, 30, 40, 50];
 
         // Remove elements by range
         let range = list.remove_by_range(1..4); // Remove elements at indices 1, 2, 3
-
-        unsafe { Vec::from_raw_parts(range.as_ptr().cast_mut(), range.len(), range.len()) };
+        assert_eq!(range, vec![20, 30, 40]);
 
         // Validate the list after removal
         assert_eq!(list.len(), 2);
         assert_eq!(list[0], 10);
         assert_eq!(list[1], 50);
     }
+
+    #[test]
+    fn test_links_stay_consistent_from_both_ends() {
+        let mut list: List<i32> = list![0, 1, 2, 3, 4, 5];
+
+        list.insert(4, 9);
+        assert_eq!(list.remove_by_index(5), Some(4));
+        assert_eq!(list.remove_by_range(0..2), vec![0, 1]);
+
+        assert_eq!(list[2], 9);
+        assert_eq!(list.front(), Some(2));
+        assert_eq!(list.back(), Some(5));
+        assert_eq!(list.remove_by_range(0..4), vec![2, 3, 9, 5]);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back(7);
+        assert_eq!(list.pop_front(), Some(7));
+    }
+
+    #[test]
+    fn test_borrowing_iterators_meet_in_the_middle() {
+        struct Token(u8);
+
+        let mut list = List::new();
+        list.extend((1..=4).map(Token));
+
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next().map(|token| token.0), Some(1));
+        assert_eq!(iter.next_back().map(|token| token.0), Some(4));
+        assert_eq!(iter.clone().count(), 2);
+        assert_eq!(iter.next_back().map(|token| token.0), Some(3));
+        assert_eq!(iter.next().map(|token| token.0), Some(2));
+        assert!(iter.next().is_none() && iter.next_back().is_none());
+
+        for token in list.iter_mut().rev().take(2) {
+            token.0 *= 10;
+        }
+
+        let mut values = list.into_iter().map(|token| token.0);
+        assert_eq!(values.next_back(), Some(40));
+        assert_eq!(values.collect::<Vec<_>>(), [1, 2, 30]);
+    }
+
+    #[test]
+    fn test_sort_by_keypath_is_stable() {
+        type Pair = (u8, char);
+
+        let mut list: List<Pair> = list![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        let key = crate::keypath!(Pair.0);
+
+        list.sort_by_keypath(&key);
+        assert_eq!(list, list![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+        assert_eq!(list.back(), Some((2, 'c')));
+
+        list.push_front((0, 'z'));
+        assert_eq!(list.front(), Some((0, 'z')));
+
+        let groups = list.grouped_by_keypath(&key);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[&2], list![(2, 'a'), (2, 'c')]);
+    }
 }
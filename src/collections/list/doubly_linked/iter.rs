@@ -1,29 +1,176 @@
-use super::List;
+use core::{iter::FusedIterator, marker::PhantomData};
 
+use allocator_api2::alloc::{Allocator, Global};
+
+use super::{Link, List};
+
+/// An iterator over references to the elements of a [`List`], front to back.
+///
+/// Created by [`List::iter`].
 #[derive(Debug)]
-pub struct Iter<'a, T>
-where
-    T: Clone,
-{
-    pub(super) stack: &'a List<T>,
-    pub(super) index: usize,
-}
-
-impl<T> Iterator for Iter<'_, T>
-where
-    T: Clone,
-{
-    type Item = T;
+pub struct Iter<'a, T> {
+    pub(super) head: Link<T>,
+    pub(super) tail: Link<T>,
+    pub(super) len: usize,
+    pub(super) marker: PhantomData<&'a T>,
+}
 
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.index >= self.stack.len() {
-            None
-        } else {
-            let item = self.stack[self.index].clone();
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
 
-            self.index += 1;
+    fn next(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| {
+            self.len -= 1;
+
+            // SAFETY: the list outlives `'a` and is borrowed immutably for it, so `node`
+            // stays live and unmodified.
+            unsafe {
+                let node = &*node.as_ptr();
+                self.head = node.next;
+                &node.value
+            }
+        })
+    }
 
-            Some(item)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        if self.len == 0 {
+            return None;
         }
+
+        self.tail.map(|node| {
+            self.len -= 1;
+
+            // SAFETY: as in `next`.
+            unsafe {
+                let node = &*node.as_ptr();
+                self.tail = node.prev;
+                &node.value
+            }
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+// SAFETY: `Iter` only hands out `&T`, like `&List`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: see above.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+/// An iterator over mutable references to the elements of a [`List`], front to back.
+///
+/// Created by [`List::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    pub(super) head: Link<T>,
+    pub(super) tail: Link<T>,
+    pub(super) len: usize,
+    pub(super) marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.head.map(|node| {
+            self.len -= 1;
+
+            // SAFETY: the list is borrowed mutably for `'a`, and the shrinking length
+            // keeps the two ends from yielding the same node twice.
+            unsafe {
+                let node = &mut *node.as_ptr();
+                self.head = node.next;
+                &mut node.value
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.tail.map(|node| {
+            self.len -= 1;
+
+            // SAFETY: as in `next`.
+            unsafe {
+                let node = &mut *node.as_ptr();
+                self.tail = node.prev;
+                &mut node.value
+            }
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+// SAFETY: `IterMut` hands out `&mut T`, like `&mut List`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: see above.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+/// An owning iterator over the elements of a [`List`], front to back.
+///
+/// Created by the [`IntoIterator`] implementation for [`List`].
+pub struct IntoIter<T, A: Allocator = Global> {
+    pub(super) list: List<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T: core::fmt::Debug, A: Allocator> core::fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.list).finish()
     }
 }
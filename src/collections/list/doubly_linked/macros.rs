@@ -0,0 +1,25 @@
+/// Creates a [`List`](super::List) containing the given elements, front to back.
+///
+/// # Examples
+///
+/// ```
+/// use libx::collections::list::doubly_linked::list;
+///
+/// let list = list![1, 2, 3];
+/// assert_eq!(list.to_string(), "[1, 2, 3]");
+/// ```
+pub macro list {
+    () => {
+        $crate::collections::list::doubly_linked::List::new()
+    },
+
+    ($($x:expr),* $(,)?) => {
+        {
+            let mut temp_list = $crate::collections::list::doubly_linked::List::new();
+            $(
+                temp_list.push_back($x);
+            )*
+            temp_list
+        }
+    }
+}
@@ -1,2 +1,11 @@
+pub mod array_string;
+pub mod array_vec;
+pub mod bitset;
+pub mod cache;
+pub mod deque;
+pub mod diff;
 pub mod list;
+pub mod ordered_map;
+pub mod queue;
+pub mod slice;
 pub mod stack;
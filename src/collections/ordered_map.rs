@@ -0,0 +1,744 @@
+//! A hash map that remembers insertion order.
+//!
+//! [`OrderedMap`] keeps its entries in one vector in the order they were first inserted,
+//! with a [`hashbrown::HashTable`] of indices into it for lookups. Entries can be read by
+//! key in O(1) like a hash map, or by position like a vector, and iteration always
+//! follows the vector.
+//!
+//! Removing an entry either shifts the entries after it down a place, keeping the order
+//! ([`shift_remove`](OrderedMap::shift_remove), O(n)), or moves the last entry into its
+//! slot ([`swap_remove`](OrderedMap::swap_remove), O(1)).
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::ordered_map::OrderedMap;
+//!
+//! let mut map = OrderedMap::new();
+//! map.insert("zebra", 3);
+//! map.insert("apple", 1);
+//! map.insert("mango", 2);
+//!
+//! assert_eq!(map.get("apple"), Some(&1));
+//! assert_eq!(map.get_index(0), Some((&"zebra", &3)));
+//!
+//! map.shift_remove("zebra");
+//! assert_eq!(map.keys().copied().collect::<Vec<_>>(), ["apple", "mango"]);
+//! ```
+
+use alloc::vec::{self, Vec};
+use core::{
+    borrow::Borrow,
+    fmt,
+    hash::{BuildHasher, Hash},
+    iter::FusedIterator,
+    mem, slice,
+};
+
+use hashbrown::{DefaultHashBuilder, HashTable};
+
+#[derive(Debug, Clone)]
+struct Bucket<K, V> {
+    /// The hash of `key`, kept so the table can be rebuilt without rehashing.
+    hash: u64,
+    key: K,
+    value: V,
+}
+
+/// A hash map whose entries keep the order they were inserted in.
+///
+/// Keys are hashed with `S`. Replacing the value of an existing key keeps its position.
+#[derive(Clone)]
+pub struct OrderedMap<K, V, S = DefaultHashBuilder> {
+    /// Indices into `entries`.
+    table: HashTable<usize>,
+    entries: Vec<Bucket<K, V>>,
+    hasher: S,
+}
+
+impl<K, V> OrderedMap<K, V> {
+    /// Creates an empty map.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_hasher(DefaultHashBuilder::default())
+    }
+
+    /// Creates an empty map with room for at least `capacity` entries.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, DefaultHashBuilder::default())
+    }
+}
+
+impl<K, V, S> OrderedMap<K, V, S> {
+    /// Creates an empty map that hashes keys with `hasher`.
+    pub const fn with_hasher(hasher: S) -> Self {
+        Self {
+            table: HashTable::new(),
+            entries: Vec::new(),
+            hasher,
+        }
+    }
+
+    /// Creates an empty map with room for at least `capacity` entries that hashes keys
+    /// with `hasher`.
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        Self {
+            table: HashTable::with_capacity(capacity),
+            entries: Vec::with_capacity(capacity),
+            hasher,
+        }
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the map holds no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the entry at position `index`.
+    #[must_use]
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries
+            .get(index)
+            .map(|bucket| (&bucket.key, &bucket.value))
+    }
+
+    /// Returns the entry at position `index`, with its value mutable.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries
+            .get_mut(index)
+            .map(|bucket| (&bucket.key, &mut bucket.value))
+    }
+
+    /// Returns the first entry.
+    #[must_use]
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.get_index(0)
+    }
+
+    /// Returns the last entry.
+    #[must_use]
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.get_index(self.len().wrapping_sub(1))
+    }
+
+    /// Removes and returns the last entry.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        let bucket = self.entries.pop()?;
+        let index = self.entries.len();
+
+        if let Ok(found) = self
+            .table
+            .find_entry(bucket.hash, |&candidate| candidate == index)
+        {
+            found.remove();
+        }
+
+        Some((bucket.key, bucket.value))
+    }
+
+    /// Removes every entry.
+    pub fn clear(&mut self) {
+        self.table.clear();
+        self.entries.clear();
+    }
+
+    /// Removes the entry at position `index` and shifts every later entry down a place,
+    /// keeping their order.
+    ///
+    /// This takes time proportional to the number of later entries.
+    pub fn shift_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.forget(hash, index);
+
+        let bucket = self.entries.remove(index);
+
+        for (moved, later) in self.entries[index..].iter().enumerate() {
+            let old = index + moved + 1;
+
+            if let Some(slot) = self
+                .table
+                .find_mut(later.hash, |&candidate| candidate == old)
+            {
+                *slot = old - 1;
+            }
+        }
+
+        Some((bucket.key, bucket.value))
+    }
+
+    /// Removes the entry at position `index` and moves the last entry into its place.
+    pub fn swap_remove_index(&mut self, index: usize) -> Option<(K, V)> {
+        let hash = self.entries.get(index)?.hash;
+        self.forget(hash, index);
+
+        let bucket = self.entries.swap_remove(index);
+        let moved = self.entries.len();
+
+        if let Some(last) = self.entries.get(index)
+            && let Some(slot) = self
+                .table
+                .find_mut(last.hash, |&candidate| candidate == moved)
+        {
+            *slot = index;
+        }
+
+        Some((bucket.key, bucket.value))
+    }
+
+    /// Sorts the entries with `compare`, which sees each entry as two key-value pairs.
+    ///
+    /// The sort is stable.
+    pub fn sort_by<F>(&mut self, mut compare: F)
+    where
+        F: FnMut(&K, &V, &K, &V) -> core::cmp::Ordering,
+    {
+        self.entries
+            .sort_by(|a, b| compare(&a.key, &a.value, &b.key, &b.value));
+        self.reindex();
+    }
+
+    /// Sorts the entries by key.
+    pub fn sort_keys(&mut self)
+    where
+        K: Ord,
+    {
+        self.entries.sort_by(|a, b| a.key.cmp(&b.key));
+        self.reindex();
+    }
+
+    /// Reverses the order of the entries.
+    pub fn reverse(&mut self) {
+        self.entries.reverse();
+        self.reindex();
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`, in their order.
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&K, &mut V) -> bool,
+    {
+        let len = self.entries.len();
+        self.entries
+            .retain_mut(|bucket| keep(&bucket.key, &mut bucket.value));
+
+        if self.entries.len() != len {
+            self.reindex();
+        }
+    }
+
+    /// Returns an iterator over the entries in order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Returns an iterator over the entries in order, with their values mutable.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.entries.iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the keys in order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Returns an iterator over the values in order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values {
+            inner: self.entries.iter(),
+        }
+    }
+
+    /// Removes the table slot that points at `index`.
+    fn forget(&mut self, hash: u64, index: usize) {
+        if let Ok(found) = self.table.find_entry(hash, |&candidate| candidate == index) {
+            found.remove();
+        }
+    }
+
+    /// Rebuilds the table after the entries were reordered.
+    fn reindex(&mut self) {
+        let entries = &self.entries;
+        self.table.clear();
+
+        for (index, bucket) in entries.iter().enumerate() {
+            self.table
+                .insert_unique(bucket.hash, index, |&index| entries[index].hash);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher> OrderedMap<K, V, S> {
+    /// Inserts `value` under `key` and returns the value it replaced.
+    ///
+    /// A new key goes after every existing entry; an existing key keeps its position and
+    /// its original key.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.insert_full(key, value).1
+    }
+
+    /// Inserts `value` under `key` and returns the position of the entry along with the
+    /// value it replaced.
+    pub fn insert_full(&mut self, key: K, value: V) -> (usize, Option<V>) {
+        let hash = self.hasher.hash_one(&key);
+
+        if let Some(index) = self.find(hash, &key) {
+            let old = mem::replace(&mut self.entries[index].value, value);
+            return (index, Some(old));
+        }
+
+        let index = self.entries.len();
+        let entries = &self.entries;
+        self.table
+            .insert_unique(hash, index, |&index| entries[index].hash);
+        self.entries.push(Bucket { hash, key, value });
+
+        (index, None)
+    }
+
+    /// Returns the value of `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(&self.entries[index].value)
+    }
+
+    /// Returns the value of `key` mutably.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        Some(&mut self.entries[index].value)
+    }
+
+    /// Returns the position of `key`.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.find(self.hasher.hash_one(key), key)
+    }
+
+    /// Returns whether the map holds `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Removes `key` and returns its value, shifting every later entry down a place.
+    ///
+    /// This keeps the order of the remaining entries but takes time proportional to the
+    /// number of later entries.
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        self.shift_remove_index(index).map(|(_, value)| value)
+    }
+
+    /// Removes `key` and returns its value, moving the last entry into its place.
+    ///
+    /// This takes constant time but changes the order of the remaining entries.
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+        self.swap_remove_index(index).map(|(_, value)| value)
+    }
+
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.table
+            .find(hash, |&index| self.entries[index].key.borrow() == key)
+            .copied()
+    }
+}
+
+impl<K, V, S: Default> Default for OrderedMap<K, V, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for OrderedMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Two maps are equal when they hold the same entries in the same order.
+impl<K: PartialEq, V: PartialEq, S> PartialEq for OrderedMap<K, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq, S> Eq for OrderedMap<K, V, S> {}
+
+impl<K: Hash + Eq, V, S: BuildHasher> Extend<(K, V)> for OrderedMap<K, V, S> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Hash + Eq, V, S: BuildHasher + Default> FromIterator<(K, V)> for OrderedMap<K, V, S> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::default();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V, S: BuildHasher + Default, H> From<std::collections::HashMap<K, V, H>>
+    for OrderedMap<K, V, S>
+{
+    /// Converts a `std` hash map into an [`OrderedMap`], in the hash map's iteration
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use libx::collections::ordered_map::OrderedMap;
+    ///
+    /// let hash_map = HashMap::from([("a", 1), ("b", 2)]);
+    /// let map: OrderedMap<_, _> = OrderedMap::from(hash_map);
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get("b"), Some(&2));
+    /// ```
+    fn from(hash_map: std::collections::HashMap<K, V, H>) -> Self {
+        hash_map.into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Hash + Eq, V, S, H: BuildHasher + Default> From<OrderedMap<K, V, S>>
+    for std::collections::HashMap<K, V, H>
+{
+    /// Converts an [`OrderedMap`] into a `std` hash map, dropping the insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    ///
+    /// use libx::collections::ordered_map::OrderedMap;
+    ///
+    /// let map: OrderedMap<_, _> = [("a", 1), ("b", 2)].into_iter().collect();
+    /// let hash_map: HashMap<_, _> = HashMap::from(map);
+    ///
+    /// assert_eq!(hash_map["a"], 1);
+    /// ```
+    fn from(map: OrderedMap<K, V, S>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a OrderedMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut OrderedMap<K, V, S> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+impl<K, V, S> IntoIterator for OrderedMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    fn into_iter(self) -> IntoIter<K, V> {
+        IntoIter {
+            inner: self.entries.into_iter(),
+        }
+    }
+}
+
+/// Implements the iterator traits for a wrapper around a vector or slice iterator of
+/// buckets, mapping each bucket with `$map`.
+macro_rules! bucket_iterator {
+    ($name:ident $(<$lt:lifetime>)?, $item:ty, $map:expr) => {
+        impl<$($lt,)? K, V> Iterator for $name<$($lt,)? K, V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.inner.next().map($map)
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.inner.size_hint()
+            }
+
+            fn nth(&mut self, n: usize) -> Option<Self::Item> {
+                self.inner.nth(n).map($map)
+            }
+        }
+
+        impl<$($lt,)? K, V> DoubleEndedIterator for $name<$($lt,)? K, V> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                self.inner.next_back().map($map)
+            }
+        }
+
+        impl<$($lt,)? K, V> ExactSizeIterator for $name<$($lt,)? K, V> {}
+
+        impl<$($lt,)? K, V> FusedIterator for $name<$($lt,)? K, V> {}
+    };
+}
+
+/// An iterator over the entries of an [`OrderedMap`] in order.
+///
+/// Created by [`OrderedMap::iter`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a, K, V> {
+    inner: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Iter<'a>, (&'a K, &'a V), |bucket| (
+    &bucket.key,
+    &bucket.value
+));
+
+/// An iterator over the entries of an [`OrderedMap`] in order, with their values
+/// mutable.
+///
+/// Created by [`OrderedMap::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, K, V> {
+    inner: slice::IterMut<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(IterMut<'a>, (&'a K, &'a mut V), |bucket| (
+    &bucket.key,
+    &mut bucket.value
+));
+
+/// An iterator over the keys of an [`OrderedMap`] in order.
+///
+/// Created by [`OrderedMap::keys`].
+#[derive(Debug, Clone)]
+pub struct Keys<'a, K, V> {
+    inner: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Keys<'a>, &'a K, |bucket| &bucket.key);
+
+/// An iterator over the values of an [`OrderedMap`] in order.
+///
+/// Created by [`OrderedMap::values`].
+#[derive(Debug, Clone)]
+pub struct Values<'a, K, V> {
+    inner: slice::Iter<'a, Bucket<K, V>>,
+}
+
+bucket_iterator!(Values<'a>, &'a V, |bucket| &bucket.value);
+
+/// An owning iterator over the entries of an [`OrderedMap`] in order.
+///
+/// Created by the [`IntoIterator`] implementation of [`OrderedMap`].
+#[derive(Debug)]
+pub struct IntoIter<K, V> {
+    inner: vec::IntoIter<Bucket<K, V>>,
+}
+
+bucket_iterator!(IntoIter, (K, V), |bucket| (bucket.key, bucket.value));
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec::Vec};
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    fn contents<S>(map: &OrderedMap<u32, u32, S>) -> Vec<(u32, u32)> {
+        map.iter().map(|(&key, &value)| (key, value)).collect()
+    }
+
+    #[test]
+    fn test_insertion_order_and_positions() {
+        let mut map = OrderedMap::new();
+
+        for key in [5, 1, 4, 2] {
+            assert_eq!(map.insert(key, key * 10), None);
+        }
+
+        assert_eq!(map.insert_full(1, 11), (1, Some(10)));
+        assert_eq!(map.insert_full(3, 30), (4, None));
+        assert_eq!(
+            contents(&map),
+            [(5, 50), (1, 11), (4, 40), (2, 20), (3, 30)]
+        );
+
+        assert_eq!(map.get_index(2), Some((&4, &40)));
+        assert_eq!(map.get_index(5), None);
+        assert_eq!(map.get_index_of(&2), Some(3));
+        assert_eq!(map.first(), Some((&5, &50)));
+        assert_eq!(map.last(), Some((&3, &30)));
+        assert_eq!(map.iter().rev().nth(1), Some((&2, &20)));
+
+        if let Some((_, value)) = map.get_index_mut(0) {
+            *value += 5;
+        }
+        assert_eq!(map.get(&5), Some(&55));
+    }
+
+    #[test]
+    fn test_removals() {
+        let mut map: OrderedMap<u32, u32> = (1..=6).map(|key| (key, key * 10)).collect();
+
+        assert_eq!(map.shift_remove(&2), Some(20));
+        assert_eq!(
+            contents(&map),
+            [(1, 10), (3, 30), (4, 40), (5, 50), (6, 60)]
+        );
+        assert_eq!(map.get_index_of(&6), Some(4));
+
+        assert_eq!(map.swap_remove(&3), Some(30));
+        assert_eq!(contents(&map), [(1, 10), (6, 60), (4, 40), (5, 50)]);
+        assert_eq!(map.get(&6), Some(&60));
+
+        assert_eq!(map.pop(), Some((5, 50)));
+        assert_eq!(map.shift_remove(&5), None);
+        assert_eq!(map.swap_remove_index(9), None);
+
+        map.retain(|&key, _| key != 6);
+        assert_eq!(contents(&map), [(1, 10), (4, 40)]);
+        assert_eq!(map.get(&4), Some(&40));
+    }
+
+    #[test]
+    fn test_sorting() {
+        let mut map: OrderedMap<u32, u32> = [(3, 1), (1, 3), (2, 2)].into_iter().collect();
+
+        map.sort_keys();
+        assert_eq!(contents(&map), [(1, 3), (2, 2), (3, 1)]);
+        assert_eq!(map.get_index_of(&3), Some(2));
+
+        map.sort_by(|_, a, _, b| a.cmp(b));
+        assert_eq!(contents(&map), [(3, 1), (2, 2), (1, 3)]);
+
+        map.reverse();
+        assert_eq!(map.keys().copied().collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(map.get(&1), Some(&3));
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_std_hash_map_round_trip() {
+        let hash_map: std::collections::HashMap<u32, u32> = (0..10).map(|n| (n, n * n)).collect();
+
+        let mut map: OrderedMap<u32, u32> = OrderedMap::from(hash_map.clone());
+        assert_eq!(map.len(), 10);
+        assert_eq!(map.get(&3), Some(&9));
+
+        map.sort_keys();
+        assert_eq!(
+            contents(&map),
+            (0..10).map(|n| (n, n * n)).collect::<Vec<_>>()
+        );
+        assert_eq!(std::collections::HashMap::from(map), hash_map);
+    }
+
+    #[test]
+    fn test_matches_model() {
+        let result = check_property(0x04de, 200, |rng| {
+            let mut map = OrderedMap::new();
+            // Entries in insertion order.
+            let mut model: Vec<(u32, u32)> = Vec::new();
+
+            for step in 0..64 {
+                #[allow(clippy::cast_possible_truncation)] // Bounded by 12.
+                let key = rng.next_bounded(12) as u32;
+                let position = model.iter().position(|&(k, _)| k == key);
+
+                match rng.next_bounded(4) {
+                    0 | 1 => {
+                        let old = position.map(|index| mem::replace(&mut model[index].1, step));
+                        if old.is_none() {
+                            model.push((key, step));
+                        }
+
+                        if map.insert(key, step) != old {
+                            return Err(format!("insert({key}) disagreed at step {step}").into());
+                        }
+                    }
+                    2 => {
+                        let expected = position.map(|index| model.remove(index).1);
+
+                        if map.shift_remove(&key) != expected {
+                            return Err(
+                                format!("shift_remove({key}) disagreed at step {step}").into()
+                            );
+                        }
+                    }
+                    _ => {
+                        let expected = position.map(|index| model.swap_remove(index).1);
+
+                        if map.swap_remove(&key) != expected {
+                            return Err(
+                                format!("swap_remove({key}) disagreed at step {step}").into()
+                            );
+                        }
+                    }
+                }
+
+                if contents(&map) != model {
+                    return Err(format!("contents disagreed at step {step}").into());
+                }
+
+                for (index, &(key, _)) in model.iter().enumerate() {
+                    if map.get_index_of(&key) != Some(index) {
+                        return Err(format!("get_index_of({key}) disagreed at step {step}").into());
+                    }
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
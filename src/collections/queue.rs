@@ -0,0 +1,426 @@
+//! A first-in, first-out queue of individually allocated nodes.
+//!
+//! [`Queue`] is the FIFO counterpart to [`Stack`](super::stack::linked_list::Stack): a
+//! singly linked list that also remembers its last node, so that both
+//! [`enqueue`](Queue::enqueue) and [`dequeue`](Queue::dequeue) take constant time and
+//! never move existing elements.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::queue::Queue;
+//!
+//! let mut queue = Queue::new();
+//! queue.enqueue("first");
+//! queue.enqueue("second");
+//! queue.extend(["third"]);
+//!
+//! assert_eq!(queue.peek_front(), Some(&"first"));
+//! assert_eq!(queue.peek_back(), Some(&"third"));
+//! assert_eq!(queue.dequeue(), Some("first"));
+//! assert_eq!(queue.iter().copied().collect::<Vec<_>>(), ["second", "third"]);
+//! ```
+
+use core::{fmt, hash::Hash, marker::PhantomData, ptr::NonNull};
+
+use alloc::alloc::handle_alloc_error;
+use allocator_api2::alloc::{Allocator, Global, Layout};
+
+use crate::{errors::CollectionError, security::Zeroize};
+
+pub mod iter;
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    value: T,
+    next: Link<T>,
+}
+
+/// A FIFO queue backed by a singly linked list.
+///
+/// Elements are enqueued at the back and dequeued from the front.
+pub struct Queue<T, A: Allocator = Global> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    alloc: A,
+    marker: PhantomData<T>,
+}
+
+impl<T> Queue<T> {
+    /// Creates an empty queue.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator> Queue<T, A> {
+    /// Creates an empty queue whose nodes are allocated with `alloc`.
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            head: None,
+            tail: None,
+            len: 0,
+            alloc,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the allocator backing this queue.
+    #[must_use]
+    pub const fn allocator(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Returns the number of elements in the queue.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the queue holds no elements.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Adds `value` to the back of the queue.
+    pub fn enqueue(&mut self, value: T) {
+        let node = self
+            .try_allocate_node(value)
+            .unwrap_or_else(|_| handle_alloc_error(Layout::new::<Node<T>>()));
+
+        self.link_back(node);
+    }
+
+    /// Adds `value` to the back of the queue, reporting allocation failure instead of
+    /// aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::AllocationFailed`] if the allocator cannot provide memory
+    /// for a new node. The queue is left unchanged.
+    pub fn try_enqueue(&mut self, value: T) -> Result<(), CollectionError> {
+        let node = self.try_allocate_node(value)?;
+        self.link_back(node);
+
+        Ok(())
+    }
+
+    /// Removes and returns the element at the front of the queue, or `None` if it is
+    /// empty.
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.head.map(|head| {
+            // SAFETY: `head` is live by the queue invariant.
+            self.head = unsafe { (*head.as_ptr()).next };
+
+            if self.head.is_none() {
+                self.tail = None;
+            }
+
+            self.len -= 1;
+
+            // SAFETY: `head` came from `try_allocate_node`, is now unreachable from the
+            // queue, and is not used again.
+            unsafe { self.free_node(head) }
+        })
+    }
+
+    /// Returns a reference to the element at the front of the queue, which
+    /// [`dequeue`](Self::dequeue) removes next.
+    #[must_use]
+    pub fn peek_front(&self) -> Option<&T> {
+        // SAFETY: `head` is live by the queue invariant and borrowed with `self`.
+        self.head.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns a mutable reference to the element at the front of the queue.
+    #[must_use]
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `head` is live by the queue invariant and borrowed mutably with `self`.
+        self.head.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Returns a reference to the element at the back of the queue, which was enqueued
+    /// last.
+    #[must_use]
+    pub fn peek_back(&self) -> Option<&T> {
+        // SAFETY: `tail` is live by the queue invariant and borrowed with `self`.
+        self.tail.map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns a mutable reference to the element at the back of the queue.
+    #[must_use]
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        // SAFETY: `tail` is live by the queue invariant and borrowed mutably with `self`.
+        self.tail.map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Removes all elements from the queue.
+    pub fn clear(&mut self) {
+        while self.dequeue().is_some() {}
+    }
+
+    /// Returns an iterator over references to the elements, front to back.
+    #[must_use]
+    pub const fn iter(&self) -> iter::Iter<'_, T> {
+        iter::Iter {
+            next: self.head,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to the elements, front to back.
+    #[must_use]
+    pub const fn iter_mut(&mut self) -> iter::IterMut<'_, T> {
+        iter::IterMut {
+            next: self.head,
+            len: self.len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Allocates an unlinked node holding `value`.
+    fn try_allocate_node(&self, value: T) -> Result<NonNull<Node<T>>, CollectionError> {
+        let node = self
+            .alloc
+            .allocate(Layout::new::<Node<T>>())
+            .map_err(|_| CollectionError::AllocationFailed)?
+            .cast::<Node<T>>();
+
+        // SAFETY: the allocator returned a block that is valid for writes and laid out
+        // for `Node<T>`.
+        unsafe { node.as_ptr().write(Node { value, next: None }) };
+
+        #[cfg(feature = "stats")]
+        crate::stats::record_node_allocation();
+
+        Ok(node)
+    }
+
+    /// Links a freshly allocated node after the current tail.
+    fn link_back(&mut self, node: NonNull<Node<T>>) {
+        match self.tail {
+            // SAFETY: `tail` is live by the queue invariant.
+            Some(tail) => unsafe { (*tail.as_ptr()).next = Some(node) },
+            None => self.head = Some(node),
+        }
+
+        self.tail = Some(node);
+        self.len += 1;
+    }
+
+    /// Moves the value out of `node` and frees it.
+    ///
+    /// # Safety
+    ///
+    /// `node` must have come from `try_allocate_node`, be initialized, and no longer be
+    /// reachable from the queue.
+    unsafe fn free_node(&self, node: NonNull<Node<T>>) -> T {
+        // SAFETY: the caller guarantees `node` is a live, initialized node that nothing
+        // else refers to, so its contents can be moved out exactly once.
+        let Node { value, .. } = unsafe { node.as_ptr().read() };
+
+        // SAFETY: the block was allocated by `self.alloc` with this layout.
+        unsafe { self.alloc.deallocate(node.cast(), Layout::new::<Node<T>>()) };
+
+        #[cfg(feature = "stats")]
+        crate::stats::record_node_deallocation();
+
+        value
+    }
+}
+
+impl<T, A: Allocator> Drop for Queue<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Zeroize, A: Allocator> Zeroize for Queue<T, A> {
+    /// Zeroizes every element in place, then removes them all.
+    fn zeroize(&mut self) {
+        for value in self.iter_mut() {
+            value.zeroize();
+        }
+
+        self.clear();
+    }
+}
+
+impl<T> Default for Queue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, A: Allocator + Clone> Clone for Queue<T, A> {
+    fn clone(&self) -> Self {
+        let mut queue = Self::new_in(self.alloc.clone());
+        queue.extend(self.iter().cloned());
+        queue
+    }
+}
+
+impl<T: PartialEq, A: Allocator> PartialEq for Queue<T, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq, A: Allocator> Eq for Queue<T, A> {}
+
+impl<T: Hash, A: Allocator> Hash for Queue<T, A> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len);
+
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for Queue<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, A: Allocator> Extend<T> for Queue<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.enqueue(value);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, A: Allocator> Extend<&'a T> for Queue<T, A> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> FromIterator<T> for Queue<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue = Self::new();
+        queue.extend(iter);
+        queue
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a Queue<T, A> {
+    type Item = &'a T;
+
+    type IntoIter = iter::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut Queue<T, A> {
+    type Item = &'a mut T;
+
+    type IntoIter = iter::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for Queue<T, A> {
+    type Item = T;
+
+    type IntoIter = iter::IntoIter<T, A>;
+
+    /// Returns an iterator that dequeues every element, front to back.
+    fn into_iter(self) -> Self::IntoIter {
+        iter::IntoIter { queue: self }
+    }
+}
+
+// SAFETY: the queue uniquely owns its nodes, so sending or sharing it is equivalent to
+// sending or sharing the values and the allocator.
+unsafe impl<T: Send, A: Allocator + Send> Send for Queue<T, A> {}
+// SAFETY: see above; `&Queue` only hands out `&T` and `&A`.
+unsafe impl<T: Sync, A: Allocator + Sync> Sync for Queue<T, A> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, rc::Rc, vec::Vec};
+    use allocator_api2::alloc::AllocError;
+
+    use super::*;
+
+    #[derive(Clone)]
+    struct FailingAlloc;
+
+    unsafe impl Allocator for FailingAlloc {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    }
+
+    #[test]
+    fn test_queue_is_fifo() {
+        let mut queue = Queue::new();
+        assert_eq!(queue.dequeue(), None);
+        assert_eq!(queue.peek_back(), None);
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+        assert_eq!(queue.dequeue(), Some(1));
+
+        // Emptying and refilling must reset the tail as well as the head.
+        assert_eq!(queue.dequeue(), Some(2));
+        assert!(queue.is_empty());
+        queue.extend(&[3, 4]);
+        queue.enqueue(5);
+
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek_front(), Some(&3));
+        assert_eq!(queue.peek_back(), Some(&5));
+        assert_eq!(format!("{queue:?}"), "[3, 4, 5]");
+
+        *queue.peek_front_mut().expect("the queue is not empty") += 10;
+        *queue.peek_back_mut().expect("the queue is not empty") += 20;
+        for value in &mut queue {
+            *value *= 2;
+        }
+
+        assert_eq!(queue.iter().len(), 3);
+        assert_eq!(queue.clone().into_iter().collect::<Vec<_>>(), [26, 8, 50]);
+        assert_eq!(queue, [26, 8, 50].into_iter().collect());
+    }
+
+    #[test]
+    fn test_queue_drops_remaining_values() {
+        let value = Rc::new(());
+        let mut queue: Queue<_> = (0..4).map(|_| Rc::clone(&value)).collect();
+
+        queue.dequeue();
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        let mut iter = queue.into_iter();
+        iter.next();
+        assert_eq!(Rc::strong_count(&value), 3);
+
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_queue_try_enqueue_reports_allocation_failure() {
+        let mut queue = Queue::new_in(FailingAlloc);
+
+        assert_eq!(queue.try_enqueue(1), Err(CollectionError::AllocationFailed));
+        assert!(queue.is_empty());
+        assert_eq!(queue.peek_back(), None);
+    }
+}
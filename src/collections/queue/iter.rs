@@ -0,0 +1,122 @@
+use core::{fmt, iter::FusedIterator, marker::PhantomData};
+
+use allocator_api2::alloc::{Allocator, Global};
+
+use super::{Link, Queue};
+
+/// An iterator over references to the elements of a [`Queue`], front to back.
+///
+/// Created by [`Queue::iter`].
+#[derive(Debug)]
+pub struct Iter<'a, T> {
+    pub(super) next: Link<T>,
+    pub(super) len: usize,
+    pub(super) marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.next.map(|node| {
+            self.len -= 1;
+
+            // SAFETY: the queue outlives `'a` and is borrowed immutably for it, so `node`
+            // stays live and unmodified.
+            unsafe {
+                let node = &*node.as_ptr();
+                self.next = node.next;
+                &node.value
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+// SAFETY: `Iter` only hands out `&T`, like `&Queue`.
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+// SAFETY: see above.
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+
+/// An iterator over mutable references to the elements of a [`Queue`], front to back.
+///
+/// Created by [`Queue::iter_mut`].
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    pub(super) next: Link<T>,
+    pub(super) len: usize,
+    pub(super) marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.next.map(|node| {
+            self.len -= 1;
+
+            // SAFETY: the queue is borrowed mutably for `'a`, and each node is visited
+            // once.
+            unsafe {
+                let node = &mut *node.as_ptr();
+                self.next = node.next;
+                &mut node.value
+            }
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+// SAFETY: `IterMut` hands out `&mut T`, like `&mut Queue`.
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+// SAFETY: see above.
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
+
+/// An owning iterator over the elements of a [`Queue`], front to back.
+///
+/// Created by the [`IntoIterator`] implementation for [`Queue`].
+pub struct IntoIter<T, A: Allocator = Global> {
+    pub(super) queue: Queue<T, A>,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.queue.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.queue.len(), Some(self.queue.len()))
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {}
+
+impl<T, A: Allocator> FusedIterator for IntoIter<T, A> {}
+
+impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.queue).finish()
+    }
+}
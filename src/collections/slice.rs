@@ -0,0 +1,139 @@
+//! Conversions from slices into the crate's node-based collections.
+//!
+//! [`SliceExt`] clones the elements of a slice into a [`List`] or a [`Stack`] in one
+//! call, and splits a slice into lists of chunks or overlapping windows. The reverse
+//! direction is [`List::to_vec`] and [`List::make_contiguous`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::collections::slice::SliceExt;
+//!
+//! let readings = [3, 1, 4, 1, 5];
+//!
+//! let list = readings.as_list();
+//! assert_eq!(list.to_vec(), readings);
+//!
+//! let mut stack = readings.as_stack();
+//! assert_eq!(stack.pop(), Some(5));
+//!
+//! let chunks = readings.chunked_lists(2).unwrap();
+//! assert_eq!(chunks.len(), 3);
+//! assert_eq!(chunks[2].to_vec(), [5]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{
+    collections::{list::doubly_linked::List, stack::linked_list::Stack},
+    errors::CollectionError,
+};
+
+/// Extension methods that clone the elements of a slice into crate collections.
+pub trait SliceExt<T: Clone> {
+    /// Returns a list holding clones of the elements, in order.
+    #[must_use]
+    fn as_list(&self) -> List<T>;
+
+    /// Returns a stack holding clones of the elements, pushed in order so the last element
+    /// is on top.
+    #[must_use]
+    fn as_stack(&self) -> Stack<T>;
+
+    /// Splits the slice into lists of `size` elements, the last of which may be shorter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::ZeroSize`] if `size` is zero.
+    fn chunked_lists(&self, size: usize) -> Result<Vec<List<T>>, CollectionError>;
+
+    /// Returns a list for every run of `size` consecutive elements, overlapping by all
+    /// but one. A slice shorter than `size` has none.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::ZeroSize`] if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::slice::SliceExt;
+    ///
+    /// let windows = [1, 2, 3].windowed_lists(2).unwrap();
+    /// let windows: Vec<_> = windows.iter().map(|window| window.to_vec()).collect();
+    /// assert_eq!(windows, [[1, 2], [2, 3]]);
+    /// ```
+    fn windowed_lists(&self, size: usize) -> Result<Vec<List<T>>, CollectionError>;
+}
+
+impl<T: Clone> SliceExt<T> for [T] {
+    fn as_list(&self) -> List<T> {
+        let mut list = List::new();
+        list.extend(self.iter().cloned());
+        list
+    }
+
+    fn as_stack(&self) -> Stack<T> {
+        let mut stack = Stack::new();
+
+        for item in self {
+            stack.push(item.clone());
+        }
+
+        stack
+    }
+
+    fn chunked_lists(&self, size: usize) -> Result<Vec<List<T>>, CollectionError> {
+        if size == 0 {
+            return Err(CollectionError::ZeroSize);
+        }
+
+        Ok(self.chunks(size).map(Self::as_list).collect())
+    }
+
+    fn windowed_lists(&self, size: usize) -> Result<Vec<List<T>>, CollectionError> {
+        if size == 0 {
+            return Err(CollectionError::ZeroSize);
+        }
+
+        Ok(self.windows(size).map(Self::as_list).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        let empty: [u8; 0] = [];
+        assert!(empty.as_list().is_empty());
+        assert!(empty.as_stack().is_empty());
+
+        let words = ["a", "b", "c"];
+        let mut stack = words.as_stack();
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.pop(), Some("c"));
+        assert_eq!(stack.pop(), Some("b"));
+
+        let list = words.as_list();
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.make_contiguous(), words);
+    }
+
+    #[test]
+    fn test_chunks_and_windows() {
+        let values = [1, 2, 3, 4, 5];
+        let lengths = |lists: Vec<List<i32>>| lists.iter().map(List::len).collect::<Vec<_>>();
+
+        assert_eq!(values.chunked_lists(2).map(lengths), Ok(vec![2, 2, 1]));
+        assert_eq!(values.chunked_lists(9).map(lengths), Ok(vec![5]));
+        assert_eq!(values.windowed_lists(3).map(lengths), Ok(vec![3, 3, 3]));
+        assert_eq!(values.windowed_lists(6).map(lengths), Ok(vec![]));
+
+        assert_eq!(values.chunked_lists(0), Err(CollectionError::ZeroSize));
+        assert_eq!(values.windowed_lists(0), Err(CollectionError::ZeroSize));
+    }
+}
@@ -1,13 +1,18 @@
 use core::ops::Index;
 
-use alloc::boxed::Box;
+use allocator_api2::{
+    alloc::{Allocator, Global},
+    boxed::Box,
+};
+
+use crate::{errors::CollectionError, security::Zeroize};
 
 pub mod iter;
 
 #[derive(Debug, Clone)]
-struct Node<T> {
+struct Node<T, A: Allocator = Global> {
     data: T,
-    next: Option<Box<Node<T>>>,
+    next: Option<Box<Self, A>>,
 }
 
 impl<T> Default for Node<T>
@@ -43,16 +48,46 @@ where
 ///
 /// ```
 #[derive(Default, Clone)]
-pub struct Stack<T> {
-    top: Option<Box<Node<T>>>,
+pub struct Stack<T, A: Allocator + Clone = Global> {
+    top: Option<Box<Node<T, A>, A>>,
     len: usize,
+    alloc: A,
 }
 
 impl<T> Stack<T> {
     /// Creates an empty stack.
     #[must_use]
     pub const fn new() -> Self {
-        Self { top: None, len: 0 }
+        Self::new_in(Global)
+    }
+}
+
+impl<T, A: Allocator + Clone> Stack<T, A> {
+    /// Creates an empty stack whose nodes are allocated with `alloc`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use allocator_api2::alloc::Global;
+    /// use libx::collections::stack::linked_list::Stack;
+    ///
+    /// let mut stack = Stack::new_in(Global);
+    /// stack.push(1);
+    /// assert_eq!(stack.peek(), Some(&1));
+    /// ```
+    #[must_use]
+    pub const fn new_in(alloc: A) -> Self {
+        Self {
+            top: None,
+            len: 0,
+            alloc,
+        }
+    }
+
+    /// Returns a reference to the allocator backing this stack.
+    #[must_use]
+    pub const fn allocator(&self) -> &A {
+        &self.alloc
     }
 
     /// Pushes a value onto the top of the stack.
@@ -66,8 +101,42 @@ impl<T> Stack<T> {
             next: self.top.take(),
         };
 
-        self.top = Some(Box::new(node));
+        self.top = Some(Box::new_in(node, self.alloc.clone()));
         self.len += 1;
+
+        #[cfg(feature = "stats")]
+        crate::stats::record_node_allocation();
+    }
+
+    /// Pushes a value onto the top of the stack, reporting allocation failure instead of
+    /// aborting.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::AllocationFailed`] if the allocator cannot provide memory
+    /// for a new node. The stack is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::stack::linked_list::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// assert_eq!(stack.try_push(1), Ok(()));
+    /// assert_eq!(stack.peek(), Some(&1));
+    /// ```
+    pub fn try_push(&mut self, data: T) -> Result<(), CollectionError> {
+        let mut node = Box::try_new_in(Node { data, next: None }, self.alloc.clone())
+            .map_err(|_| CollectionError::AllocationFailed)?;
+
+        node.next = self.top.take();
+        self.top = Some(node);
+        self.len += 1;
+
+        #[cfg(feature = "stats")]
+        crate::stats::record_node_allocation();
+
+        Ok(())
     }
 
     /// Removes and returns the top element from the stack.
@@ -76,10 +145,15 @@ impl<T> Stack<T> {
     pub fn pop(&mut self) -> Option<T> {
         let top = self.top.take();
 
-        top.map(|mut node| {
-            self.top = node.next.take();
+        top.map(|node| {
+            let Node { data, next } = Box::into_inner(node);
+            self.top = next;
             self.len -= 1;
-            node.data
+
+            #[cfg(feature = "stats")]
+            crate::stats::record_node_deallocation();
+
+            data
         })
     }
 
@@ -109,6 +183,42 @@ impl<T> Stack<T> {
         self.top.as_ref().map(|node| &node.data)
     }
 
+    /// Returns a reference to the element at the given index, without panicking.
+    ///
+    /// Indices count from the bottom of the stack, so the top element has index
+    /// `len() - 1`, exactly as with `stack[index]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollectionError::IndexOutOfBounds`] if `index` is not less than the length
+    /// of the stack.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{collections::stack::linked_list::Stack, errors::CollectionError};
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push("bottom");
+    /// stack.push("top");
+    ///
+    /// assert_eq!(stack.try_index(1), Ok(&"top"));
+    /// assert_eq!(
+    ///     stack.try_index(2),
+    ///     Err(CollectionError::IndexOutOfBounds { index: 2, len: 2 })
+    /// );
+    /// ```
+    pub fn try_index(&self, index: usize) -> Result<&T, CollectionError> {
+        if index >= self.len {
+            return Err(CollectionError::IndexOutOfBounds {
+                index,
+                len: self.len,
+            });
+        }
+
+        Ok(&self[index])
+    }
+
     /// Removes all elements from the stack.
     pub fn clear(&mut self) {
         for _ in 0..self.len() {
@@ -122,7 +232,7 @@ impl<T> Stack<T> {
     ///
     /// An iterator that yields references to the elements in the stack in LIFO order.
     #[must_use]
-    pub const fn iter(&self) -> iter::Iter<'_, T>
+    pub const fn iter(&self) -> iter::Iter<'_, T, A>
     where
         T: Clone,
     {
@@ -133,12 +243,30 @@ impl<T> Stack<T> {
     }
 }
 
-unsafe impl<T> Send for Stack<T> where T: Send {}
-unsafe impl<T> Sync for Stack<T> {}
+impl<T, A: Allocator + Clone> Drop for Stack<T, A> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Zeroize, A: Allocator + Clone> Zeroize for Stack<T, A> {
+    /// Zeroizes every element in place, then removes them all.
+    fn zeroize(&mut self) {
+        let mut current = self.top.as_deref_mut();
+
+        while let Some(node) = current {
+            node.data.zeroize();
+            current = node.next.as_deref_mut();
+        }
+
+        self.clear();
+    }
+}
 
-impl<T> core::fmt::Debug for Stack<T>
+impl<T, A> core::fmt::Debug for Stack<T, A>
 where
     T: Clone + core::fmt::Debug,
+    A: Allocator + Clone,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
         let mut first = true;
@@ -156,7 +284,44 @@ where
     }
 }
 
-impl<T> Index<usize> for Stack<T> {
+impl<T, A> core::fmt::Display for Stack<T, A>
+where
+    T: core::fmt::Display,
+    A: Allocator + Clone,
+{
+    /// Formats the stack from top to bottom, separating elements with arrows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::collections::stack::linked_list::Stack;
+    ///
+    /// let mut stack = Stack::new();
+    /// stack.push("bottom");
+    /// stack.push("top");
+    ///
+    /// assert_eq!(stack.to_string(), "top -> bottom");
+    /// ```
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> Result<(), core::fmt::Error> {
+        let mut current = self.top.as_deref();
+        let mut first = true;
+
+        while let Some(node) = current {
+            if !first {
+                f.write_str(" -> ")?;
+            }
+
+            core::fmt::Display::fmt(&node.data, f)?;
+            first = false;
+
+            current = node.next.as_deref();
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, A: Allocator + Clone> Index<usize> for Stack<T, A> {
     type Output = T;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -177,13 +342,14 @@ impl<T> Index<usize> for Stack<T> {
     }
 }
 
-impl<'a, T> IntoIterator for &'a Stack<T>
+impl<'a, T, A> IntoIterator for &'a Stack<T, A>
 where
     T: Clone,
+    A: Allocator + Clone,
 {
     type Item = T;
 
-    type IntoIter = iter::Iter<'a, T>;
+    type IntoIter = iter::Iter<'a, T, A>;
 
     fn into_iter(self) -> Self::IntoIter {
         self.iter()
@@ -193,9 +359,38 @@ where
 #[cfg(test)]
 mod tests {
     use alloc::{format, string::String};
+    use allocator_api2::alloc::{AllocError, Layout};
+    use core::ptr::NonNull;
 
     use super::*;
 
+    #[derive(Clone)]
+    struct FailingAlloc;
+
+    unsafe impl Allocator for FailingAlloc {
+        fn allocate(&self, _layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Err(AllocError)
+        }
+
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {}
+    }
+
+    #[test]
+    fn test_stack_new_in() {
+        let mut stack = Stack::new_in(Global);
+        stack.push(1);
+        assert_eq!(stack.try_push(2), Ok(()));
+        assert_eq!(stack.pop(), Some(2));
+        assert_eq!(stack.pop(), Some(1));
+    }
+
+    #[test]
+    fn test_stack_try_push_reports_allocation_failure() {
+        let mut stack = Stack::new_in(FailingAlloc);
+        assert_eq!(stack.try_push(1), Err(CollectionError::AllocationFailed));
+        assert!(stack.is_empty());
+    }
+
     #[test]
     fn test_stack_push() {
         let mut stack = super::Stack::new();
@@ -268,6 +463,14 @@ mod tests {
         assert_eq!(format!("{stack:?}"), "1");
     }
 
+    #[test]
+    fn test_stack_display_trait() {
+        let mut stack = super::Stack::new();
+        stack.push("a");
+        stack.push("b");
+        assert_eq!(format!("{stack}"), "b -> a");
+    }
+
     #[test]
     fn test_stack_peak() {
         let mut stack = super::Stack::new();
@@ -283,6 +486,21 @@ mod tests {
         assert_eq!(stack.peek(), None);
     }
 
+    #[test]
+    fn test_stack_try_index() {
+        let mut stack = Stack::new();
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert_eq!(stack.try_index(0), Ok(&1));
+        assert_eq!(stack.try_index(2), Ok(&3));
+        assert_eq!(
+            stack.try_index(3),
+            Err(CollectionError::IndexOutOfBounds { index: 3, len: 3 })
+        );
+    }
+
     #[test]
     fn test_stack_clear() {
         let mut stack = Stack::new();
@@ -1,17 +1,21 @@
+use allocator_api2::alloc::{Allocator, Global};
+
 use super::Stack;
 
 #[derive(Debug)]
-pub struct Iter<'a, T>
+pub struct Iter<'a, T, A = Global>
 where
     T: Clone,
+    A: Allocator + Clone,
 {
-    pub(super) stack: &'a Stack<T>,
+    pub(super) stack: &'a Stack<T, A>,
     pub(super) index: usize,
 }
 
-impl<T> Iterator for Iter<'_, T>
+impl<T, A> Iterator for Iter<'_, T, A>
 where
     T: Clone,
+    A: Allocator + Clone,
 {
     type Item = T;
 
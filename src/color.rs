@@ -0,0 +1,565 @@
+//! RGBA colors with CSS-style parsing and HSL/HSV conversions.
+//!
+//! [`Color`] stores 8-bit sRGB channels and straight (non-premultiplied) alpha. It
+//! parses the hexadecimal, `rgb()` and `hsl()` notations used in CSS and configuration
+//! files, and converts to and from [`Hsl`] and [`Hsv`] for color manipulation.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::color::Color;
+//!
+//! let accent: Color = "hsl(210, 100%, 50%)".parse().unwrap();
+//! assert_eq!(accent, Color::rgb(0, 128, 255));
+//! assert_eq!(accent.to_string(), "#0080ff");
+//!
+//! let faded = accent.with_alpha(128).blend_over(Color::WHITE);
+//! assert_eq!(faded, Color::rgb(127, 191, 255));
+//! ```
+
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use crate::{errors::ParseError, num::traits::FloatingPoint};
+
+/// An sRGB color with 8-bit channels and straight alpha.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// The red channel.
+    pub red: u8,
+
+    /// The green channel.
+    pub green: u8,
+
+    /// The blue channel.
+    pub blue: u8,
+
+    /// The opacity, from `0` (transparent) to `255` (opaque).
+    pub alpha: u8,
+}
+
+/// A color as hue, saturation and lightness.
+///
+/// The hue is in degrees in `0.0..360.0`; saturation, lightness and alpha are in
+/// `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Hsl {
+    /// The hue in degrees.
+    pub hue: f64,
+
+    /// The saturation.
+    pub saturation: f64,
+
+    /// The lightness.
+    pub lightness: f64,
+
+    /// The opacity.
+    pub alpha: f64,
+}
+
+/// A color as hue, saturation and value.
+///
+/// The hue is in degrees in `0.0..360.0`; saturation, value and alpha are in
+/// `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Hsv {
+    /// The hue in degrees.
+    pub hue: f64,
+
+    /// The saturation.
+    pub saturation: f64,
+
+    /// The value, or brightness.
+    pub value: f64,
+
+    /// The opacity.
+    pub alpha: f64,
+}
+
+impl Color {
+    /// Opaque black.
+    pub const BLACK: Self = Self::rgb(0, 0, 0);
+
+    /// Opaque white.
+    pub const WHITE: Self = Self::rgb(255, 255, 255);
+
+    /// Fully transparent black.
+    pub const TRANSPARENT: Self = Self::rgba(0, 0, 0, 0);
+
+    /// Creates an opaque color.
+    #[must_use]
+    pub const fn rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self::rgba(red, green, blue, 255)
+    }
+
+    /// Creates a color with the given opacity.
+    #[must_use]
+    pub const fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        Self {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Returns the color with its alpha replaced.
+    #[must_use]
+    pub const fn with_alpha(self, alpha: u8) -> Self {
+        Self { alpha, ..self }
+    }
+
+    /// Returns `true` if the color is fully opaque.
+    #[must_use]
+    pub const fn is_opaque(&self) -> bool {
+        self.alpha == 255
+    }
+
+    /// Creates a color from hue, saturation and lightness.
+    #[must_use]
+    pub fn from_hsl(hsl: Hsl) -> Self {
+        let saturation = hsl.saturation.clamp(0.0, 1.0);
+        let lightness = hsl.lightness.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+        from_hue_chroma(hsl.hue, chroma, lightness - chroma / 2.0, hsl.alpha)
+    }
+
+    /// Creates a color from hue, saturation and value.
+    #[must_use]
+    pub fn from_hsv(hsv: Hsv) -> Self {
+        let saturation = hsv.saturation.clamp(0.0, 1.0);
+        let value = hsv.value.clamp(0.0, 1.0);
+        let chroma = value * saturation;
+
+        from_hue_chroma(hsv.hue, chroma, value - chroma, hsv.alpha)
+    }
+
+    /// Converts the color to hue, saturation and lightness.
+    #[must_use]
+    pub fn to_hsl(self) -> Hsl {
+        let (hue, max, min) = self.hue_max_min();
+        let lightness = f64::midpoint(max, min);
+        let chroma = max - min;
+        let saturation = if chroma == 0.0 {
+            0.0
+        } else {
+            chroma / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+
+        Hsl {
+            hue,
+            saturation,
+            lightness,
+            alpha: channel_to_unit(self.alpha),
+        }
+    }
+
+    /// Converts the color to hue, saturation and value.
+    #[must_use]
+    pub fn to_hsv(self) -> Hsv {
+        let (hue, max, min) = self.hue_max_min();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+
+        Hsv {
+            hue,
+            saturation,
+            value: max,
+            alpha: channel_to_unit(self.alpha),
+        }
+    }
+
+    /// Interpolates linearly between `self` and `other`, channel by channel, alpha
+    /// included.
+    ///
+    /// `t` is clamped to `0.0..=1.0`; `0.0` gives `self` and `1.0` gives `other`.
+    #[must_use]
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |from: u8, to: u8| {
+            let (from, to) = (f64::from(from), f64::from(to));
+            unit_to_channel((from + (to - from) * t) / 255.0)
+        };
+
+        Self::rgba(
+            mix(self.red, other.red),
+            mix(self.green, other.green),
+            mix(self.blue, other.blue),
+            mix(self.alpha, other.alpha),
+        )
+    }
+
+    /// Composites `self` over `background` with the "source over" operator.
+    #[must_use]
+    pub fn blend_over(self, background: Self) -> Self {
+        let source_alpha = channel_to_unit(self.alpha);
+        let background_alpha = channel_to_unit(background.alpha) * (1.0 - source_alpha);
+        let alpha = source_alpha + background_alpha;
+
+        if alpha == 0.0 {
+            return Self::TRANSPARENT;
+        }
+
+        let mix = |source: u8, background: u8| {
+            let source = channel_to_unit(source) * source_alpha;
+            let background = channel_to_unit(background) * background_alpha;
+            unit_to_channel((source + background) / alpha)
+        };
+
+        Self::rgba(
+            mix(self.red, background.red),
+            mix(self.green, background.green),
+            mix(self.blue, background.blue),
+            unit_to_channel(alpha),
+        )
+    }
+
+    /// Returns the color as `#rrggbb`, or `#rrggbbaa` if it is not opaque.
+    #[must_use]
+    pub fn to_hex(&self) -> String {
+        if self.is_opaque() {
+            alloc::format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+        } else {
+            alloc::format!(
+                "#{:02x}{:02x}{:02x}{:02x}",
+                self.red,
+                self.green,
+                self.blue,
+                self.alpha
+            )
+        }
+    }
+
+    /// Returns the hue in degrees and the largest and smallest channels in `0.0..=1.0`.
+    // `max` is one of the channels, so comparing it exactly finds which.
+    #[allow(clippy::float_cmp)]
+    fn hue_max_min(self) -> (f64, f64, f64) {
+        let (red, green, blue) = (
+            channel_to_unit(self.red),
+            channel_to_unit(self.green),
+            channel_to_unit(self.blue),
+        );
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let chroma = max - min;
+
+        let sector = if chroma == 0.0 {
+            0.0
+        } else if max == red {
+            ((green - blue) / chroma).truncating_remainder(6.0)
+        } else if max == green {
+            (blue - red) / chroma + 2.0
+        } else {
+            (red - green) / chroma + 4.0
+        };
+
+        let hue = (sector * 60.0 + 360.0).truncating_remainder(360.0);
+        (hue, max, min)
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        Self::from_hsl(hsl)
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(hsv: Hsv) -> Self {
+        Self::from_hsv(hsv)
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        color.to_hsl()
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(color: Color) -> Self {
+        color.to_hsv()
+    }
+}
+
+/// Parses `#rgb`, `#rgba`, `#rrggbb`, `#rrggbbaa`, `rgb()`, `rgba()`, `hsl()` and
+/// `hsla()`.
+///
+/// Functional notations accept arguments separated by commas or by spaces with the
+/// alpha after a `/`, as in `rgb(255 0 0 / 50%)`. Color channels may be numbers in
+/// `0..=255` or percentages, and alpha may be a number in `0..=1` or a percentage.
+impl FromStr for Color {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+
+        let (name, arguments) = s
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .ok_or(ParseError::InvalidSyntax)?;
+        let mut components = [Component::default(); 4];
+        let count = split_arguments(arguments, &mut components)?;
+
+        if count < 3 {
+            return Err(ParseError::OutOfRange);
+        }
+
+        let alpha = if count == 4 {
+            components[3].fraction(1.0)
+        } else {
+            1.0
+        };
+
+        match name.trim().to_ascii_lowercase().as_str() {
+            "rgb" | "rgba" => Ok(Self::rgba(
+                unit_to_channel(components[0].fraction(255.0)),
+                unit_to_channel(components[1].fraction(255.0)),
+                unit_to_channel(components[2].fraction(255.0)),
+                unit_to_channel(alpha),
+            )),
+            "hsl" | "hsla" => Ok(Self::from_hsl(Hsl {
+                hue: components[0].value,
+                saturation: components[1].fraction(100.0),
+                lightness: components[2].fraction(100.0),
+                alpha,
+            })),
+            _ => Err(ParseError::InvalidSyntax),
+        }
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Color, ParseError> {
+    if !hex.is_ascii() {
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    let digit = |position: usize| {
+        let character = char::from(hex.as_bytes()[position]);
+
+        #[allow(clippy::cast_possible_truncation)] // Hexadecimal digits are below 16.
+        character
+            .to_digit(16)
+            .map(|digit| digit as u8)
+            .ok_or(ParseError::InvalidCharacter {
+                character,
+                // Count the leading `#`.
+                position: position + 1,
+            })
+    };
+    let short = |position| digit(position).map(|digit| digit * 17);
+    let long = |position| Ok(digit(position)? * 16 + digit(position + 1)?);
+
+    match hex.len() {
+        3 => Ok(Color::rgb(short(0)?, short(1)?, short(2)?)),
+        4 => Ok(Color::rgba(short(0)?, short(1)?, short(2)?, short(3)?)),
+        6 => Ok(Color::rgb(long(0)?, long(2)?, long(4)?)),
+        8 => Ok(Color::rgba(long(0)?, long(2)?, long(4)?, long(6)?)),
+        _ => Err(ParseError::OutOfRange),
+    }
+}
+
+/// An argument of a functional notation, such as `255`, `50%` or `120deg`.
+#[derive(Clone, Copy, Default)]
+struct Component {
+    value: f64,
+    percent: bool,
+}
+
+impl Component {
+    /// Returns the component as a fraction, dividing plain numbers by `full`.
+    fn fraction(self, full: f64) -> f64 {
+        if self.percent {
+            self.value / 100.0
+        } else {
+            self.value / full
+        }
+    }
+}
+
+/// Parses the arguments of a functional notation into `components`, returning how
+/// many there were.
+fn split_arguments(arguments: &str, components: &mut [Component; 4]) -> Result<usize, ParseError> {
+    let (main, alpha) = match arguments.split_once('/') {
+        Some((main, alpha)) => (main, Some(alpha)),
+        None => (arguments, None),
+    };
+
+    let mut count = 0;
+
+    for token in main
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|token| !token.is_empty())
+        .chain(alpha.map(str::trim))
+    {
+        if count == components.len() {
+            return Err(ParseError::OutOfRange);
+        }
+
+        components[count] = parse_component(token)?;
+        count += 1;
+    }
+
+    Ok(count)
+}
+
+fn parse_component(token: &str) -> Result<Component, ParseError> {
+    let percent = token.ends_with('%');
+    let number = token
+        .strip_suffix('%')
+        .or_else(|| token.strip_suffix("deg"))
+        .unwrap_or(token);
+
+    let value = number
+        .parse::<f64>()
+        .map_err(|_| ParseError::InvalidSyntax)?;
+
+    if !value.is_finite() {
+        return Err(ParseError::OutOfRange);
+    }
+
+    Ok(Component { value, percent })
+}
+
+fn from_hue_chroma(hue: f64, chroma: f64, offset: f64, alpha: f64) -> Color {
+    let hue = (hue.truncating_remainder(360.0) + 360.0).truncating_remainder(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue.truncating_remainder(2.0) - 1.0).abs());
+
+    let (red, green, blue) = match hue.floor() {
+        0.0 => (chroma, x, 0.0),
+        1.0 => (x, chroma, 0.0),
+        2.0 => (0.0, chroma, x),
+        3.0 => (0.0, x, chroma),
+        4.0 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::rgba(
+        unit_to_channel(red + offset),
+        unit_to_channel(green + offset),
+        unit_to_channel(blue + offset),
+        unit_to_channel(alpha),
+    )
+}
+
+fn channel_to_unit(channel: u8) -> f64 {
+    f64::from(channel) / 255.0
+}
+
+fn unit_to_channel(unit: f64) -> u8 {
+    // The value is clamped to 0..=255 before the cast, so nothing is lost.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let channel = (unit.clamp(0.0, 1.0) * 255.0).rounded() as u8;
+    channel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_parse_hex() {
+        assert_eq!("#f80".parse(), Ok(Color::rgb(255, 136, 0)));
+        assert_eq!("#f808".parse(), Ok(Color::rgba(255, 136, 0, 136)));
+        assert_eq!("#1E90FF".parse(), Ok(Color::rgb(30, 144, 255)));
+        assert_eq!("#1e90ff80".parse(), Ok(Color::rgba(30, 144, 255, 128)));
+        assert_eq!("".parse::<Color>(), Err(ParseError::Empty));
+        assert_eq!("#12345".parse::<Color>(), Err(ParseError::OutOfRange));
+        assert_eq!(
+            "#12g".parse::<Color>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'g',
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_functions() {
+        assert_eq!("rgb(255, 0, 0)".parse(), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!(
+            "rgba(0, 0, 255, 0.5)".parse(),
+            Ok(Color::rgba(0, 0, 255, 128))
+        );
+        assert_eq!(
+            "rgb(100% 50% 0% / 25%)".parse(),
+            Ok(Color::rgba(255, 128, 0, 64))
+        );
+        assert_eq!("hsl(120deg 100% 25%)".parse(), Ok(Color::rgb(0, 128, 0)));
+        assert_eq!(
+            "hsla(0, 0%, 100%, 0)".parse(),
+            Ok(Color::rgba(255, 255, 255, 0))
+        );
+        assert_eq!("rgb(1, 2)".parse::<Color>(), Err(ParseError::OutOfRange));
+        assert_eq!(
+            "cmyk(0, 0, 0, 0)".parse::<Color>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "rgb(red, 0, 0)".parse::<Color>(),
+            Err(ParseError::InvalidSyntax)
+        );
+    }
+
+    #[test]
+    fn test_hsl_and_hsv_round_trip() {
+        for color in [
+            Color::BLACK,
+            Color::WHITE,
+            Color::rgb(255, 0, 0),
+            Color::rgb(30, 144, 255),
+            Color::rgba(200, 100, 50, 10),
+            Color::rgb(12, 200, 180),
+        ] {
+            assert_eq!(Color::from_hsl(color.to_hsl()), color);
+            assert_eq!(Color::from_hsv(color.to_hsv()), color);
+        }
+
+        let hsv = Color::rgb(255, 0, 255).to_hsv();
+        assert!((hsv.hue - 300.0).abs() < 1e-9);
+        assert!((hsv.saturation - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lerp_and_blend() {
+        let red = Color::rgb(255, 0, 0);
+        let blue = Color::rgb(0, 0, 255);
+
+        assert_eq!(red.lerp(blue, 0.0), red);
+        assert_eq!(red.lerp(blue, 0.5), Color::rgb(128, 0, 128));
+        assert_eq!(red.lerp(blue, 2.0), blue);
+
+        assert_eq!(red.blend_over(blue), red);
+        assert_eq!(Color::TRANSPARENT.blend_over(blue), blue);
+        assert_eq!(
+            Color::TRANSPARENT.blend_over(Color::TRANSPARENT),
+            Color::TRANSPARENT
+        );
+        assert_eq!(
+            red.with_alpha(128).blend_over(blue.with_alpha(128)),
+            Color::rgba(170, 0, 85, 192)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Color::rgb(30, 144, 255).to_string(), "#1e90ff");
+        assert_eq!(Color::rgba(30, 144, 255, 0).to_string(), "#1e90ff00");
+        assert_eq!(Color::TRANSPARENT.to_hex().parse(), Ok(Color::TRANSPARENT));
+    }
+}
@@ -0,0 +1,30 @@
+//! Lightweight compression for `no_std` targets.
+//!
+//! Both codecs trade ratio for size and speed: [`rle`] collapses runs of repeated bytes,
+//! and [`lz`] replaces repeated sequences with back-references into a 4 KiB window. There
+//! is no entropy coding stage, so neither competes with DEFLATE on ratio, but both run
+//! in a few kilobytes of state.
+//!
+//! With the `inflate` feature, the `inflate` module also decompresses standard DEFLATE
+//! data, raw or in zlib or gzip framing, using the same streaming interface.
+//!
+//! Each codec has an encoder and a decoder that accept input in chunks of any size and
+//! write to any [`ByteWriter`](crate::encoding::ByteWriter), so output can go to a
+//! `Vec<u8>` or a fixed buffer through a [`SliceWriter`](crate::encoding::SliceWriter).
+//! The `compress` and `decompress` functions handle the common one-shot case.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::compression::lz;
+//!
+//! let text = b"to be or not to be, that is the question; to be or not to be";
+//! let compressed = lz::compress(text);
+//! assert!(compressed.len() < text.len());
+//! assert_eq!(lz::decompress(&compressed).unwrap(), text);
+//! ```
+
+#[cfg(feature = "inflate")]
+pub mod inflate;
+pub mod lz;
+pub mod rle;
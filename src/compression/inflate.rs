@@ -0,0 +1,804 @@
+//! A DEFLATE decompressor for raw, zlib and gzip streams.
+//!
+//! Every block type of RFC 1951 is supported, along with the zlib wrapper of RFC 1950
+//! (without a preset dictionary) and single-member gzip files from RFC 1952. The
+//! checksums in zlib and gzip trailers are verified; the optional gzip header checksum
+//! is skipped.
+//!
+//! The decoder keeps the last 32 KiB of output to resolve back-references, so output can
+//! go to a fixed buffer as well as a vector, and it buffers only the input it has not
+//! decoded yet.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::compression::inflate::{self, Format};
+//!
+//! let compressed = [
+//!     0x78, 0x9c, 0xab, 0xca, 0xc9, 0x4c, 0x52, 0x48, 0x49, 0x2c, 0x49, 0x04, 0x00, 0x11,
+//!     0x7a, 0x03, 0x6c,
+//! ];
+//!
+//! assert_eq!(inflate::decompress(&compressed, Format::Zlib).unwrap(), b"zlib data");
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    encoding::{ByteWriter, SliceWriter},
+    errors::EncodingError,
+    hashing::crc::{CRC_32_ISO_HDLC, Crc},
+};
+
+/// How far back a match can reach.
+const WINDOW: usize = 32 * 1024;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// The order in which a dynamic block lists the lengths of the code length code.
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+/// The framing around a DEFLATE stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// A bare DEFLATE stream.
+    Raw,
+
+    /// A DEFLATE stream with a zlib header and an Adler-32 trailer.
+    Zlib,
+
+    /// A DEFLATE stream with a gzip header and a CRC-32 trailer.
+    Gzip,
+}
+
+/// Why decoding stopped before the end of the input.
+enum Stop {
+    /// More input is needed to decode the next item.
+    Stall,
+    Error(EncodingError),
+}
+
+impl From<EncodingError> for Stop {
+    fn from(error: EncodingError) -> Self {
+        Self::Error(error)
+    }
+}
+
+/// The input not yet decoded, read least significant bit first.
+#[derive(Debug, Clone)]
+struct BitReader {
+    input: Vec<u8>,
+    bit: usize,
+}
+
+impl BitReader {
+    fn bits(&mut self, count: u32) -> Result<u32, Stop> {
+        let mut value = 0;
+
+        for index in 0..count {
+            let byte = *self.input.get(self.bit / 8).ok_or(Stop::Stall)?;
+            value |= u32::from(byte >> (self.bit % 8) & 1) << index;
+            self.bit += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn byte(&mut self) -> Result<u8, Stop> {
+        #[allow(clippy::cast_possible_truncation)] // Eight bits.
+        self.bits(8).map(|bits| bits as u8)
+    }
+
+    const fn align(&mut self) {
+        self.bit = self.bit.next_multiple_of(8);
+    }
+
+    /// Returns the number of whole bytes left, once aligned.
+    const fn remaining(&self) -> usize {
+        self.input.len() - self.bit.div_ceil(8)
+    }
+
+    fn discard_consumed(&mut self) {
+        self.input.drain(..self.bit / 8);
+        self.bit %= 8;
+    }
+}
+
+/// A canonical Huffman code, decoded one bit at a time.
+#[derive(Debug, Clone)]
+struct Huffman {
+    /// The number of codes of each length.
+    counts: [u16; 16],
+    /// The symbols ordered by code.
+    symbols: [u16; 288],
+}
+
+impl Huffman {
+    const fn empty() -> Self {
+        Self {
+            counts: [0; 16],
+            symbols: [0; 288],
+        }
+    }
+
+    /// Builds the code from the code length of each symbol. Incomplete codes are
+    /// accepted; reading one of their unused codes fails.
+    fn new(lengths: &[u8]) -> Result<Self, EncodingError> {
+        let mut counts = [0_u16; 16];
+
+        for &length in lengths {
+            counts[usize::from(length)] += 1;
+        }
+
+        counts[0] = 0;
+        let mut left = 1_i32;
+
+        for &count in &counts[1..] {
+            left = left * 2 - i32::from(count);
+
+            if left < 0 {
+                return Err(EncodingError::InvalidData);
+            }
+        }
+
+        let mut offsets = [0_u16; 16];
+
+        for length in 1..15 {
+            offsets[length + 1] = offsets[length] + counts[length];
+        }
+
+        let mut symbols = [0; 288];
+
+        for (symbol, &length) in lengths.iter().enumerate() {
+            if length != 0 {
+                let offset = &mut offsets[usize::from(length)];
+                #[allow(clippy::cast_possible_truncation)] // At most 288 symbols.
+                let symbol = symbol as u16;
+                symbols[usize::from(*offset)] = symbol;
+                *offset += 1;
+            }
+        }
+
+        Ok(Self { counts, symbols })
+    }
+
+    /// Returns the codes of fixed Huffman blocks.
+    fn fixed() -> (Self, Self) {
+        let mut lengths = [8; 288];
+        lengths[144..256].fill(9);
+        lengths[256..280].fill(7);
+
+        // Both tables are complete, so they cannot fail to build.
+        let literals = Self::new(&lengths).unwrap_or_else(|_| Self::empty());
+        let distances = Self::new(&[5; 30]).unwrap_or_else(|_| Self::empty());
+        (literals, distances)
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, Stop> {
+        let (mut code, mut first, mut index) = (0_u32, 0_u32, 0_u32);
+
+        for &count in &self.counts[1..] {
+            code |= reader.bits(1)?;
+            let count = u32::from(count);
+
+            if code < first + count {
+                return Ok(self.symbols[(index + code - first) as usize]);
+            }
+
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+
+        Err(EncodingError::InvalidData.into())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Header,
+    Block,
+    Stored { remaining: usize },
+    Codes,
+    Trailer,
+    Done,
+}
+
+/// A streaming DEFLATE decoder.
+///
+/// Input can be split into chunks anywhere. Call [`finish`](Self::finish) after the last
+/// chunk to check that the stream was complete. If writing fails, the output so far is
+/// incomplete and the decoder should be discarded.
+///
+/// # Examples
+///
+/// ```
+/// use libx::compression::inflate::{Format, InflateDecoder};
+///
+/// let compressed = [0xf3, 0x48, 0xcd, 0xc9, 0xc9, 0xd7, 0x51, 0xc8, 0x40, 0xa2, 0x14, 0x01];
+/// let mut decoder = InflateDecoder::new(Format::Raw);
+/// let mut output = Vec::new();
+///
+/// for chunk in compressed.chunks(5) {
+///     decoder.write(chunk, &mut output).unwrap();
+/// }
+///
+/// assert!(decoder.is_finished());
+/// decoder.finish().unwrap();
+/// assert_eq!(output, b"Hello, hello, hello!");
+/// ```
+#[derive(Debug, Clone)]
+pub struct InflateDecoder {
+    format: Format,
+    state: State,
+    last_block: bool,
+    reader: BitReader,
+    literals: Huffman,
+    distances: Huffman,
+    history: Vec<u8>,
+    /// Where the next output byte goes in `history`.
+    cursor: usize,
+    /// How much of `history` holds output, up to the whole window.
+    filled: usize,
+    /// The running Adler-32 of the output, for zlib.
+    adler: u32,
+    /// The running CRC-32 of the output, for gzip.
+    crc: Crc<u32>,
+    /// The length of the output modulo 2^32, as gzip records it.
+    length: u32,
+}
+
+impl InflateDecoder {
+    /// Creates a decoder expecting the start of a stream in `format`.
+    #[must_use]
+    pub fn new(format: Format) -> Self {
+        Self {
+            format,
+            state: State::Header,
+            last_block: false,
+            reader: BitReader {
+                input: Vec::new(),
+                bit: 0,
+            },
+            literals: Huffman::empty(),
+            distances: Huffman::empty(),
+            history: vec![0; WINDOW],
+            cursor: 0,
+            filled: 0,
+            adler: 1,
+            crc: Crc::<u32>::new(CRC_32_ISO_HDLC),
+            length: 0,
+        }
+    }
+
+    /// Returns the framing this decoder expects.
+    #[must_use]
+    pub const fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns `true` once the end of the stream, including any trailer, has been read.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.state == State::Done
+    }
+
+    /// Decodes `input`, writing the decompressed bytes to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::InvalidData`] if the input is malformed or continues past
+    /// the end of the stream, [`EncodingError::ChecksumMismatch`] if the trailer does not
+    /// match the output, or another [`EncodingError`] if `writer` cannot take the output.
+    pub fn write<W: ByteWriter + ?Sized>(
+        &mut self,
+        input: &[u8],
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        self.reader.input.extend_from_slice(input);
+
+        loop {
+            if self.state == State::Done {
+                if self.reader.remaining() > 0 {
+                    return Err(EncodingError::InvalidData);
+                }
+
+                break;
+            }
+
+            let checkpoint = self.reader.bit;
+
+            match self.step(writer) {
+                Ok(()) => {}
+                Err(Stop::Stall) => {
+                    self.reader.bit = checkpoint;
+                    break;
+                }
+                Err(Stop::Error(error)) => return Err(error),
+            }
+        }
+
+        self.reader.discard_consumed();
+        Ok(())
+    }
+
+    /// Checks that the whole stream was read and resets the decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::UnexpectedEnd`] if the input ended before the end of the
+    /// stream.
+    pub fn finish(&mut self) -> Result<(), EncodingError> {
+        let finished = self.is_finished();
+
+        self.state = State::Header;
+        self.last_block = false;
+        self.reader.input.clear();
+        self.reader.bit = 0;
+        self.cursor = 0;
+        self.filled = 0;
+        self.adler = 1;
+        self.crc = Crc::<u32>::new(CRC_32_ISO_HDLC);
+        self.length = 0;
+
+        if finished {
+            Ok(())
+        } else {
+            Err(EncodingError::UnexpectedEnd)
+        }
+    }
+
+    /// Decodes one item, such as a header or a symbol. Fields only change once the whole
+    /// item has been read, so a stalled step can be retried with more input.
+    fn step<W: ByteWriter + ?Sized>(&mut self, writer: &mut W) -> Result<(), Stop> {
+        match self.state {
+            State::Header => self.read_header(),
+            State::Block => self.read_block_header(),
+            State::Stored { remaining } => self.copy_stored(remaining, writer),
+            State::Codes => self.decode_symbol(writer),
+            State::Trailer => self.read_trailer(),
+            State::Done => Ok(()),
+        }
+    }
+
+    fn read_header(&mut self) -> Result<(), Stop> {
+        match self.format {
+            Format::Raw => {}
+            Format::Zlib => {
+                let method = self.reader.bits(8)?;
+                let flags = self.reader.bits(8)?;
+
+                if method & 0x0f != 8
+                    || method >> 4 > 7
+                    || (method << 8 | flags) % 31 != 0
+                    || flags & 0x20 != 0
+                {
+                    return Err(EncodingError::InvalidData.into());
+                }
+            }
+            Format::Gzip => {
+                let mut fixed = [0; 10];
+
+                for byte in &mut fixed {
+                    *byte = self.reader.byte()?;
+                }
+
+                let flags = fixed[3];
+
+                if fixed[..3] != [0x1f, 0x8b, 8] || flags & 0xe0 != 0 {
+                    return Err(EncodingError::InvalidData.into());
+                }
+
+                if flags & 0x04 != 0 {
+                    let extra = self.reader.bits(16)?;
+
+                    for _ in 0..extra {
+                        self.reader.byte()?;
+                    }
+                }
+
+                // The file name and comment are zero-terminated.
+                for flag in [0x08, 0x10] {
+                    if flags & flag != 0 {
+                        while self.reader.byte()? != 0 {}
+                    }
+                }
+
+                if flags & 0x02 != 0 {
+                    self.reader.bits(16)?;
+                }
+            }
+        }
+
+        self.state = State::Block;
+        Ok(())
+    }
+
+    fn read_block_header(&mut self) -> Result<(), Stop> {
+        let header = self.reader.bits(3)?;
+
+        self.state = match header >> 1 {
+            0 => {
+                self.reader.align();
+                let length = self.reader.bits(16)?;
+                let complement = self.reader.bits(16)?;
+
+                if length != !complement & 0xffff {
+                    return Err(EncodingError::InvalidData.into());
+                }
+
+                State::Stored {
+                    remaining: length as usize,
+                }
+            }
+            1 => {
+                (self.literals, self.distances) = Huffman::fixed();
+                State::Codes
+            }
+            2 => {
+                self.read_dynamic_codes()?;
+                State::Codes
+            }
+            _ => return Err(EncodingError::InvalidData.into()),
+        };
+
+        self.last_block = header & 1 == 1;
+        Ok(())
+    }
+
+    fn read_dynamic_codes(&mut self) -> Result<(), Stop> {
+        let literal_count = self.reader.bits(5)? as usize + 257;
+        let distance_count = self.reader.bits(5)? as usize + 1;
+        let code_length_count = self.reader.bits(4)? as usize + 4;
+
+        if literal_count > 286 || distance_count > 30 {
+            return Err(EncodingError::InvalidData.into());
+        }
+
+        let mut lengths = [0_u8; 19];
+
+        for &symbol in &CODE_LENGTH_ORDER[..code_length_count] {
+            #[allow(clippy::cast_possible_truncation)] // Three bits.
+            let length = self.reader.bits(3)? as u8;
+            lengths[symbol] = length;
+        }
+
+        let code_lengths = Huffman::new(&lengths)?;
+        let total = literal_count + distance_count;
+        let mut lengths = [0_u8; 286 + 30];
+        let mut index = 0;
+
+        while index < total {
+            let (length, repeat) = match code_lengths.decode(&mut self.reader)? {
+                16 => {
+                    let previous = index.checked_sub(1).ok_or(EncodingError::InvalidData)?;
+                    (lengths[previous], 3 + self.reader.bits(2)?)
+                }
+                17 => (0, 3 + self.reader.bits(3)?),
+                18 => (0, 11 + self.reader.bits(7)?),
+                #[allow(clippy::cast_possible_truncation)] // Code lengths are below 16.
+                length => (length as u8, 1),
+            };
+
+            let end = index + repeat as usize;
+
+            if end > total {
+                return Err(EncodingError::InvalidData.into());
+            }
+
+            lengths[index..end].fill(length);
+            index = end;
+        }
+
+        if lengths[256] == 0 {
+            return Err(EncodingError::InvalidData.into());
+        }
+
+        let literals = Huffman::new(&lengths[..literal_count])?;
+        let distances = Huffman::new(&lengths[literal_count..total])?;
+        self.literals = literals;
+        self.distances = distances;
+        Ok(())
+    }
+
+    fn copy_stored<W: ByteWriter + ?Sized>(
+        &mut self,
+        remaining: usize,
+        writer: &mut W,
+    ) -> Result<(), Stop> {
+        if remaining == 0 {
+            self.state = self.after_block();
+            return Ok(());
+        }
+
+        let count = remaining.min(self.reader.remaining());
+
+        if count == 0 {
+            return Err(Stop::Stall);
+        }
+
+        let start = self.reader.bit / 8;
+        self.reader.bit += count * 8;
+
+        for index in start..start + count {
+            self.push(self.reader.input[index], writer)?;
+        }
+
+        self.state = State::Stored {
+            remaining: remaining - count,
+        };
+        Ok(())
+    }
+
+    fn decode_symbol<W: ByteWriter + ?Sized>(&mut self, writer: &mut W) -> Result<(), Stop> {
+        let symbol = self.literals.decode(&mut self.reader)?;
+
+        if let Ok(byte) = u8::try_from(symbol) {
+            self.push(byte, writer)?;
+            return Ok(());
+        }
+
+        if symbol == 256 {
+            self.state = self.after_block();
+            return Ok(());
+        }
+
+        let index = usize::from(symbol - 257);
+        let base = *LENGTH_BASE.get(index).ok_or(EncodingError::InvalidData)?;
+        let length = usize::from(base) + self.reader.bits(u32::from(LENGTH_EXTRA[index]))? as usize;
+
+        let index = usize::from(self.distances.decode(&mut self.reader)?);
+        let base = *DISTANCE_BASE.get(index).ok_or(EncodingError::InvalidData)?;
+        let distance =
+            usize::from(base) + self.reader.bits(u32::from(DISTANCE_EXTRA[index]))? as usize;
+
+        if distance > self.filled {
+            return Err(EncodingError::InvalidData.into());
+        }
+
+        let mut source = (self.cursor + WINDOW - distance) % WINDOW;
+
+        for _ in 0..length {
+            self.push(self.history[source], writer)?;
+            source = (source + 1) % WINDOW;
+        }
+
+        Ok(())
+    }
+
+    fn read_trailer(&mut self) -> Result<(), Stop> {
+        self.reader.align();
+
+        let matches = match self.format {
+            Format::Raw => true,
+            Format::Zlib => {
+                let mut expected = 0;
+
+                for _ in 0..4 {
+                    expected = expected << 8 | self.reader.bits(8)?;
+                }
+
+                expected == self.adler
+            }
+            Format::Gzip => {
+                let crc = self.reader.bits(16)? | self.reader.bits(16)? << 16;
+                let length = self.reader.bits(16)? | self.reader.bits(16)? << 16;
+                crc == self.crc.finish() && length == self.length
+            }
+        };
+
+        if !matches {
+            return Err(EncodingError::ChecksumMismatch.into());
+        }
+
+        self.state = State::Done;
+        Ok(())
+    }
+
+    const fn after_block(&self) -> State {
+        if self.last_block {
+            State::Trailer
+        } else {
+            State::Block
+        }
+    }
+
+    fn push<W: ByteWriter + ?Sized>(
+        &mut self,
+        byte: u8,
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        writer.write_byte(byte)?;
+        self.history[self.cursor] = byte;
+        self.cursor = (self.cursor + 1) % WINDOW;
+        self.filled = (self.filled + 1).min(WINDOW);
+        self.length = self.length.wrapping_add(1);
+
+        match self.format {
+            Format::Raw => {}
+            Format::Zlib => {
+                let low = ((self.adler & 0xffff) + u32::from(byte)) % 65521;
+                let high = ((self.adler >> 16) + low) % 65521;
+                self.adler = high << 16 | low;
+            }
+            Format::Gzip => self.crc.update(&[byte]),
+        }
+
+        Ok(())
+    }
+}
+
+/// Decompresses `input` in `format` into a new vector.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::UnexpectedEnd`] if `input` ends before the stream does, or the
+/// errors of [`InflateDecoder::write`] for malformed input.
+pub fn decompress(input: &[u8], format: Format) -> Result<Vec<u8>, EncodingError> {
+    let mut output = Vec::new();
+    let mut decoder = InflateDecoder::new(format);
+    decoder.write(input, &mut output)?;
+    decoder.finish()?;
+    Ok(output)
+}
+
+/// Decompresses `input` in `format` into the start of `buffer` and returns the
+/// decompressed length.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::BufferTooSmall`] if the output does not fit in `buffer`, or
+/// the errors of [`decompress`].
+pub fn decompress_into(
+    input: &[u8],
+    format: Format,
+    buffer: &mut [u8],
+) -> Result<usize, EncodingError> {
+    let mut writer = SliceWriter::new(buffer);
+    let mut decoder = InflateDecoder::new(format);
+    decoder.write(input, &mut writer)?;
+    decoder.finish()?;
+    Ok(writer.position())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    /// Twenty numbered pangram lines, compressed by zlib into one dynamic block.
+    const DYNAMIC: [u8; 113] = [
+        0x9d, 0xd2, 0x5d, 0x16, 0x42, 0x50, 0x18, 0x85, 0xe1, 0x7b, 0xa3, 0xf8, 0x86, 0x60, 0x4b,
+        0x3f, 0x9a, 0x8d, 0x38, 0x4a, 0x0e, 0x27, 0x0a, 0x65, 0xf4, 0x96, 0x66, 0xe0, 0xbd, 0xde,
+        0xeb, 0xbd, 0xda, 0x8f, 0xaf, 0x3b, 0x67, 0xf1, 0xd5, 0x3e, 0x0f, 0x67, 0xfd, 0x58, 0x17,
+        0x8d, 0xdd, 0x86, 0x30, 0x77, 0x56, 0x85, 0xaf, 0x3d, 0xc7, 0xf6, 0xf5, 0xb6, 0x30, 0xb9,
+        0xe1, 0x3f, 0xfb, 0x7c, 0xf9, 0x59, 0x19, 0xee, 0x91, 0xdf, 0x1a, 0x81, 0x26, 0x01, 0xcd,
+        0x01, 0x34, 0x29, 0x68, 0x8e, 0xa0, 0x39, 0x81, 0xe6, 0x0c, 0x9a, 0x0b, 0x68, 0x32, 0xf2,
+        0x29, 0x82, 0x40, 0x24, 0x88, 0x50, 0x10, 0xb1, 0x20, 0x82, 0x41, 0x44, 0x83, 0x08, 0x07,
+        0x11, 0x0f, 0x22, 0x20, 0xb4, 0x53, 0xc4, 0x0a,
+    ];
+
+    const GZIP: [u8; 29] = [
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x4b, 0xaf, 0xca, 0x2c, 0x50,
+        0x48, 0x49, 0x2c, 0x49, 0x04, 0x00, 0x17, 0x72, 0xe1, 0xbb, 0x09, 0x00, 0x00, 0x00,
+    ];
+
+    fn pangrams() -> Vec<u8> {
+        (0..20)
+            .flat_map(|line| {
+                format!("line {line}: the quick brown fox jumps over the lazy dog\n").into_bytes()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_block_types() {
+        let stored = [
+            0x01, 0x07, 0x00, 0xf8, 0xff, b's', b't', b'o', b'r', b'e', b'd', b'!',
+        ];
+        assert_eq!(decompress(&stored, Format::Raw), Ok(b"stored!".to_vec()));
+        assert_eq!(decompress(&DYNAMIC, Format::Raw), Ok(pangrams()));
+
+        let mut buffer = [0; 1050];
+        assert_eq!(
+            decompress_into(&DYNAMIC, Format::Raw, &mut buffer),
+            Ok(1050)
+        );
+        assert_eq!(
+            decompress_into(&DYNAMIC, Format::Raw, &mut buffer[..1000]),
+            Err(EncodingError::BufferTooSmall { capacity: 1000 })
+        );
+    }
+
+    #[test]
+    fn test_byte_at_a_time() {
+        let mut decoder = InflateDecoder::new(Format::Raw);
+        let mut output = Vec::new();
+
+        for &byte in &DYNAMIC {
+            assert!(!decoder.is_finished());
+            decoder.write(&[byte], &mut output).expect("valid input");
+        }
+
+        assert_eq!(decoder.finish(), Ok(()));
+        assert_eq!(output, pangrams());
+    }
+
+    #[test]
+    fn test_framing() {
+        assert_eq!(decompress(&GZIP, Format::Gzip), Ok(b"gzip data".to_vec()));
+
+        let mut named = GZIP[..10].to_vec();
+        named[3] = 0x08;
+        named.extend_from_slice(b"data.txt\0");
+        named.extend_from_slice(&GZIP[10..]);
+        assert_eq!(decompress(&named, Format::Gzip), Ok(b"gzip data".to_vec()));
+
+        let mut corrupt = GZIP;
+        corrupt[21] ^= 1;
+        assert_eq!(
+            decompress(&corrupt, Format::Gzip),
+            Err(EncodingError::ChecksumMismatch)
+        );
+        assert_eq!(
+            decompress(&GZIP, Format::Zlib),
+            Err(EncodingError::InvalidData)
+        );
+
+        let zlib = [
+            0x78, 0x9c, 0xab, 0xca, 0xc9, 0x4c, 0x52, 0x48, 0x49, 0x2c, 0x49, 0x04, 0x00, 0x11,
+            0x7a, 0x03, 0x6d,
+        ];
+        assert_eq!(
+            decompress(&zlib, Format::Zlib),
+            Err(EncodingError::ChecksumMismatch)
+        );
+    }
+
+    #[test]
+    fn test_malformed_streams() {
+        assert_eq!(
+            decompress(&[], Format::Raw),
+            Err(EncodingError::UnexpectedEnd)
+        );
+        assert_eq!(
+            decompress(&DYNAMIC[..60], Format::Raw),
+            Err(EncodingError::UnexpectedEnd)
+        );
+        assert_eq!(
+            decompress(&[0x07], Format::Raw),
+            Err(EncodingError::InvalidData)
+        );
+        assert_eq!(
+            decompress(&[0x01, 0x01, 0x00, 0x00, 0x00, 0x00], Format::Raw),
+            Err(EncodingError::InvalidData)
+        );
+
+        let mut trailing = GZIP.to_vec();
+        trailing.push(0);
+        assert_eq!(
+            decompress(&trailing, Format::Gzip),
+            Err(EncodingError::InvalidData)
+        );
+    }
+}
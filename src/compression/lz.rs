@@ -0,0 +1,571 @@
+//! An LZSS-style compressor with a 4 KiB window and no entropy stage.
+//!
+//! The output is a sequence of groups. Each group starts with a flag byte followed by
+//! up to eight tokens, one per flag bit from least to most significant:
+//!
+//! - A clear bit is a literal: one byte copied as it is.
+//! - A set bit is a match: two bytes `[d >> 4, (d & 0xf) << 4 | (l - 3)]`, where `d` is
+//!   the distance back minus one (so 1 to 4096 bytes) and `l` the length, from 3 to 18
+//!   bytes. A match may overlap the bytes it produces.
+//!
+//! The last group may hold fewer than eight tokens; its unused flag bits are clear.
+//! Incompressible data grows by one byte per eight.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::compression::lz;
+//!
+//! let compressed = lz::compress(b"abcabcabc");
+//! assert_eq!(compressed, [0b1000, b'a', b'b', b'c', 0x00, 0x23]);
+//! assert_eq!(lz::decompress(&compressed).unwrap(), b"abcabcabc");
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+use crate::{
+    encoding::{ByteWriter, SliceWriter},
+    errors::EncodingError,
+};
+
+/// How far back a match can reach.
+const WINDOW: usize = 4096;
+
+const MIN_MATCH: usize = 3;
+
+const MAX_MATCH: usize = 18;
+
+/// The input the encoder holds back so every position inside the longest match can be
+/// hashed.
+const LOOKAHEAD: usize = MAX_MATCH + MIN_MATCH - 1;
+
+const HASH_BITS: u32 = 12;
+
+/// How many earlier positions the encoder compares before settling for the best match
+/// so far.
+const MAX_CHAIN: usize = 64;
+
+/// Returns the largest possible compressed length of `input_length` bytes.
+#[must_use]
+pub const fn max_compressed_length(input_length: usize) -> usize {
+    input_length + input_length.div_ceil(8)
+}
+
+fn hash(bytes: &[u8]) -> usize {
+    let key = u32::from(bytes[0]) | u32::from(bytes[1]) << 8 | u32::from(bytes[2]) << 16;
+    (key.wrapping_mul(0x9e37_79b1) >> (32 - HASH_BITS)) as usize
+}
+
+/// A streaming LZ encoder.
+///
+/// Input can be split into chunks anywhere; the output is the same as for the whole
+/// input at once. The encoder holds back a few bytes of input and up to one group of
+/// tokens, so call [`finish`](Self::finish) after the last chunk. If writing fails,
+/// the output so far is incomplete and the encoder should be discarded.
+///
+/// # Examples
+///
+/// ```
+/// use libx::compression::lz::{self, LzEncoder};
+///
+/// let mut encoder = LzEncoder::new();
+/// let mut output = Vec::new();
+///
+/// for line in ["one fish, ", "two fish, ", "red fish, ", "blue fish"] {
+///     encoder.write(line.as_bytes(), &mut output).unwrap();
+/// }
+///
+/// encoder.finish(&mut output).unwrap();
+/// assert_eq!(lz::decompress(&output).unwrap(), b"one fish, two fish, red fish, blue fish");
+/// ```
+#[derive(Debug, Clone)]
+pub struct LzEncoder {
+    /// The input from absolute position `base` onwards, holding at least a window of
+    /// history before `position` once that much has been encoded.
+    data: Vec<u8>,
+    base: usize,
+    position: usize,
+    /// The latest position plus one for each hash, or zero.
+    head: Vec<usize>,
+    /// For each position modulo the window, the previous position plus one with the same
+    /// hash, or zero.
+    prev: Vec<usize>,
+    group: [u8; 1 + 8 * 2],
+    group_length: usize,
+    tokens: usize,
+}
+
+impl LzEncoder {
+    /// Creates an encoder with no buffered input.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            data: Vec::new(),
+            base: 0,
+            position: 0,
+            head: vec![0; 1 << HASH_BITS],
+            prev: vec![0; WINDOW],
+            group: [0; 1 + 8 * 2],
+            group_length: 1,
+            tokens: 0,
+        }
+    }
+
+    /// Encodes `input`, writing complete groups to `writer` and buffering the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if `writer` cannot take the output.
+    pub fn write<W: ByteWriter + ?Sized>(
+        &mut self,
+        input: &[u8],
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        for piece in input.chunks(WINDOW) {
+            self.data.extend_from_slice(piece);
+            self.encode(LOOKAHEAD, writer)?;
+
+            let history = self.position - self.base;
+
+            if history > 2 * WINDOW {
+                let stale = history - WINDOW;
+                self.data.drain(..stale);
+                self.base += stale;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes the buffered input and resets the encoder for a new stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if `writer` cannot take the output.
+    pub fn finish<W: ByteWriter + ?Sized>(&mut self, writer: &mut W) -> Result<(), EncodingError> {
+        self.encode(1, writer)?;
+        self.flush_group(writer)?;
+
+        self.data.clear();
+        self.base = 0;
+        self.position = 0;
+        self.head.fill(0);
+        Ok(())
+    }
+
+    /// Encodes tokens while at least `lookahead` bytes of input are buffered.
+    fn encode<W: ByteWriter + ?Sized>(
+        &mut self,
+        lookahead: usize,
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        while self.base + self.data.len() - self.position >= lookahead {
+            let (distance, length) = self.find_match();
+            self.insert(self.position);
+
+            if length >= MIN_MATCH {
+                for position in self.position + 1..self.position + length {
+                    self.insert(position);
+                }
+
+                let code = distance - 1;
+                #[allow(clippy::cast_possible_truncation)] // 12 bits of distance, 4 of length.
+                let token = [
+                    (code >> 4) as u8,
+                    ((code & 0xf) << 4 | (length - MIN_MATCH)) as u8,
+                ];
+                self.push_token(&token, true, writer)?;
+                self.position += length;
+            } else {
+                let byte = self.data[self.position - self.base];
+                self.push_token(&[byte], false, writer)?;
+                self.position += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the distance and length of the longest match found for the input at the
+    /// current position.
+    fn find_match(&self) -> (usize, usize) {
+        let ahead = &self.data[self.position - self.base..];
+        let limit = ahead.len().min(MAX_MATCH);
+
+        if limit < MIN_MATCH {
+            return (0, 0);
+        }
+
+        let mut best = (0, 0);
+        let mut link = self.head[hash(ahead)];
+
+        for _ in 0..MAX_CHAIN {
+            let Some(candidate) = link.checked_sub(1) else {
+                break;
+            };
+
+            let distance = self.position - candidate;
+
+            if distance > WINDOW {
+                break;
+            }
+
+            let length = self.data[candidate - self.base..]
+                .iter()
+                .zip(&ahead[..limit])
+                .take_while(|(earlier, later)| earlier == later)
+                .count();
+
+            if length > best.1 {
+                best = (distance, length);
+
+                if length == limit {
+                    break;
+                }
+            }
+
+            let next = self.prev[candidate % WINDOW];
+
+            // Chains only run backwards; anything else is a slot reused by a newer
+            // position.
+            if next >= link {
+                break;
+            }
+
+            link = next;
+        }
+
+        best
+    }
+
+    /// Adds `position` to the hash chains if three bytes are available there.
+    fn insert(&mut self, position: usize) {
+        let offset = position - self.base;
+
+        if let Some(bytes) = self.data.get(offset..offset + MIN_MATCH) {
+            let hash = hash(bytes);
+            self.prev[position % WINDOW] = self.head[hash];
+            self.head[hash] = position + 1;
+        }
+    }
+
+    fn push_token<W: ByteWriter + ?Sized>(
+        &mut self,
+        token: &[u8],
+        is_match: bool,
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        if is_match {
+            self.group[0] |= 1 << self.tokens;
+        }
+
+        self.group[self.group_length..self.group_length + token.len()].copy_from_slice(token);
+        self.group_length += token.len();
+        self.tokens += 1;
+
+        if self.tokens == 8 {
+            self.flush_group(writer)?;
+        }
+
+        Ok(())
+    }
+
+    fn flush_group<W: ByteWriter + ?Sized>(&mut self, writer: &mut W) -> Result<(), EncodingError> {
+        let result = if self.tokens > 0 {
+            writer.write_bytes(&self.group[..self.group_length])
+        } else {
+            Ok(())
+        };
+
+        self.group[0] = 0;
+        self.group_length = 1;
+        self.tokens = 0;
+        result
+    }
+}
+
+impl Default for LzEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A streaming LZ decoder.
+///
+/// Input can be split into chunks anywhere, including inside a match. The decoder keeps
+/// the last 4 KiB of output to resolve matches, so it works the same whether the output
+/// goes to a vector or a fixed buffer. Call [`finish`](Self::finish) after the last
+/// chunk to check that the input did not end in the middle of a match.
+#[derive(Debug, Clone)]
+pub struct LzDecoder {
+    history: Vec<u8>,
+    /// Where the next output byte goes in `history`.
+    cursor: usize,
+    /// How much of `history` holds output, up to the whole window.
+    filled: usize,
+    flags: u8,
+    /// The tokens left in the current group; zero when a flag byte is expected.
+    tokens: u8,
+    /// The first byte of a match whose second byte has not arrived yet.
+    pending: Option<u8>,
+}
+
+impl LzDecoder {
+    /// Creates a decoder expecting the start of a stream.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            history: vec![0; WINDOW],
+            cursor: 0,
+            filled: 0,
+            flags: 0,
+            tokens: 0,
+            pending: None,
+        }
+    }
+
+    /// Decodes `input`, writing the decompressed bytes to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::InvalidData`] if a match reaches back before the start of
+    /// the output, or another [`EncodingError`] if `writer` cannot take the output.
+    pub fn write<W: ByteWriter + ?Sized>(
+        &mut self,
+        input: &[u8],
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        for &byte in input {
+            if self.tokens == 0 {
+                self.flags = byte;
+                self.tokens = 8;
+                continue;
+            }
+
+            if let Some(first) = self.pending.take() {
+                self.copy_match(first, byte, writer)?;
+            } else if self.flags & 1 == 1 {
+                self.pending = Some(byte);
+                continue;
+            } else {
+                self.push(byte, writer)?;
+            }
+
+            self.flags >>= 1;
+            self.tokens -= 1;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the input did not end inside a match and resets the decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::UnexpectedEnd`] if the input ended inside a match.
+    pub const fn finish(&mut self) -> Result<(), EncodingError> {
+        let pending = self.pending.take();
+
+        self.cursor = 0;
+        self.filled = 0;
+        self.flags = 0;
+        self.tokens = 0;
+
+        match pending {
+            Some(_) => Err(EncodingError::UnexpectedEnd),
+            None => Ok(()),
+        }
+    }
+
+    fn copy_match<W: ByteWriter + ?Sized>(
+        &mut self,
+        first: u8,
+        second: u8,
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        let distance = (usize::from(first) << 4 | usize::from(second >> 4)) + 1;
+        let length = usize::from(second & 0xf) + MIN_MATCH;
+
+        if distance > self.filled {
+            return Err(EncodingError::InvalidData);
+        }
+
+        let mut source = (self.cursor + WINDOW - distance) % WINDOW;
+
+        for _ in 0..length {
+            self.push(self.history[source], writer)?;
+            source = (source + 1) % WINDOW;
+        }
+
+        Ok(())
+    }
+
+    fn push<W: ByteWriter + ?Sized>(
+        &mut self,
+        byte: u8,
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        writer.write_byte(byte)?;
+        self.history[self.cursor] = byte;
+        self.cursor = (self.cursor + 1) % WINDOW;
+        self.filled = (self.filled + 1).min(WINDOW);
+        Ok(())
+    }
+}
+
+impl Default for LzDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compresses `input` into a new vector.
+#[must_use]
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::new();
+    let mut encoder = LzEncoder::new();
+
+    // Writing into a `Vec` cannot fail.
+    let _ = encoder
+        .write(input, &mut output)
+        .and_then(|()| encoder.finish(&mut output));
+
+    output
+}
+
+/// Compresses `input` into the start of `buffer` and returns the compressed length.
+///
+/// A buffer of [`max_compressed_length`] bytes is always large enough.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::BufferTooSmall`] if the output does not fit in `buffer`.
+pub fn compress_into(input: &[u8], buffer: &mut [u8]) -> Result<usize, EncodingError> {
+    let mut writer = SliceWriter::new(buffer);
+    let mut encoder = LzEncoder::new();
+    encoder.write(input, &mut writer)?;
+    encoder.finish(&mut writer)?;
+    Ok(writer.position())
+}
+
+/// Decompresses `input` into a new vector.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::InvalidData`] if a match reaches back before the start of
+/// the output, or [`EncodingError::UnexpectedEnd`] if `input` ends inside a match.
+pub fn decompress(input: &[u8]) -> Result<Vec<u8>, EncodingError> {
+    let mut output = Vec::new();
+    let mut decoder = LzDecoder::new();
+    decoder.write(input, &mut output)?;
+    decoder.finish()?;
+    Ok(output)
+}
+
+/// Decompresses `input` into the start of `buffer` and returns the decompressed length.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::BufferTooSmall`] if the output does not fit in `buffer`, or
+/// the errors of [`decompress`] for malformed input.
+pub fn decompress_into(input: &[u8], buffer: &mut [u8]) -> Result<usize, EncodingError> {
+    let mut writer = SliceWriter::new(buffer);
+    let mut decoder = LzDecoder::new();
+    decoder.write(input, &mut writer)?;
+    decoder.finish()?;
+    Ok(writer.position())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<u8> {
+        let mut state = 0x2545_f491_u32;
+        let mut data = Vec::new();
+
+        for round in 0..400 {
+            data.extend_from_slice(b"the quick brown fox jumps over the lazy dog. ");
+
+            for _ in 0..round % 7 {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                data.push(state.to_le_bytes()[0]);
+            }
+        }
+
+        data
+    }
+
+    #[test]
+    fn test_tokens() {
+        assert_eq!(compress(b""), []);
+        assert_eq!(compress(b"ab"), [0, b'a', b'b']);
+        assert_eq!(compress(&[5; 19]), [0b10, 5, 0x00, 0x0f]);
+        assert_eq!(decompress(&[0b10, 5, 0x00, 0x0f]), Ok([5; 19].to_vec()));
+        assert_eq!(max_compressed_length(17), 20);
+    }
+
+    #[test]
+    fn test_round_trip_in_chunks() {
+        let input = sample();
+        let expected = compress(&input);
+
+        assert!(input.len() > 3 * WINDOW);
+        assert!(expected.len() < input.len() / 2);
+        assert_eq!(decompress(&expected).as_ref(), Ok(&input));
+
+        for chunk_size in [1, 2, 17, 1000, 5000] {
+            let mut encoder = LzEncoder::new();
+            let mut output = Vec::new();
+
+            for chunk in input.chunks(chunk_size) {
+                encoder.write(chunk, &mut output).expect("vector output");
+            }
+
+            encoder.finish(&mut output).expect("vector output");
+            assert_eq!(output, expected);
+
+            let mut decoder = LzDecoder::new();
+            let mut restored = Vec::new();
+
+            for chunk in output.chunks(chunk_size) {
+                decoder.write(chunk, &mut restored).expect("valid input");
+            }
+
+            assert_eq!(decoder.finish(), Ok(()));
+            assert_eq!(restored, input);
+        }
+    }
+
+    #[test]
+    fn test_incompressible_input_fits_the_bound() {
+        let input: Vec<u8> = (0..=255).collect();
+        let mut buffer = vec![0; max_compressed_length(input.len())];
+
+        assert_eq!(compress_into(&input, &mut buffer), Ok(buffer.len()));
+        assert_eq!(
+            compress_into(&input, &mut buffer[..100]),
+            Err(EncodingError::BufferTooSmall { capacity: 100 })
+        );
+
+        let mut output = [0; 256];
+        assert_eq!(decompress_into(&buffer, &mut output), Ok(256));
+        assert_eq!(output[..], input[..]);
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(decompress(&[1, 0, 0]), Err(EncodingError::InvalidData));
+        assert_eq!(
+            decompress(&[0b10, b'a', 0, 0x10]),
+            Err(EncodingError::InvalidData)
+        );
+        assert_eq!(
+            decompress(&[0b10, b'a', 0]),
+            Err(EncodingError::UnexpectedEnd)
+        );
+        assert_eq!(decompress(&[0b10, b'a']), Ok(b"a".to_vec()));
+    }
+}
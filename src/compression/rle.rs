@@ -0,0 +1,372 @@
+//! Run-length encoding in the `PackBits` format.
+//!
+//! The output is a sequence of packets, each starting with a header byte `n`:
+//!
+//! - `0..=127`: the next `n + 1` bytes are copied as they are.
+//! - `129..=255`: the next byte is repeated `257 - n` times, from 2 to 128 times.
+//! - `128`: no operation; decoders skip it.
+//!
+//! Runs of three or more equal bytes shrink to two bytes per 128, while data without
+//! runs grows by at most one byte per 128.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::compression::rle;
+//!
+//! let compressed = rle::compress(b"aaaaaaaaaabcd");
+//! assert_eq!(compressed, [0xf7, b'a', 2, b'b', b'c', b'd']);
+//! assert_eq!(rle::decompress(&compressed).unwrap(), b"aaaaaaaaaabcd");
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{
+    encoding::{ByteWriter, SliceWriter},
+    errors::EncodingError,
+};
+
+/// The most bytes a single packet can describe.
+const MAX_PACKET: usize = 128;
+
+/// Returns the largest possible compressed length of `input_length` bytes.
+#[must_use]
+pub const fn max_compressed_length(input_length: usize) -> usize {
+    input_length + input_length.div_ceil(MAX_PACKET)
+}
+
+/// A streaming run-length encoder.
+///
+/// Input can be split into chunks anywhere; the output is the same as for the whole
+/// input at once. Call [`finish`](Self::finish) after the last chunk to write the
+/// buffered bytes. If writing fails, the output so far is incomplete and the encoder
+/// should be discarded.
+///
+/// # Examples
+///
+/// ```
+/// use libx::compression::rle::{self, RleEncoder};
+///
+/// let mut encoder = RleEncoder::new();
+/// let mut output = Vec::new();
+///
+/// encoder.write(b"xxxx", &mut output).unwrap();
+/// encoder.write(b"xxyz", &mut output).unwrap();
+/// encoder.finish(&mut output).unwrap();
+///
+/// assert_eq!(output, rle::compress(b"xxxxxxyz"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RleEncoder {
+    literals: [u8; MAX_PACKET],
+    literal_length: usize,
+    run_byte: u8,
+    run_length: usize,
+}
+
+impl RleEncoder {
+    /// Creates an encoder with no buffered input.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            literals: [0; MAX_PACKET],
+            literal_length: 0,
+            run_byte: 0,
+            run_length: 0,
+        }
+    }
+
+    /// Encodes `input`, writing complete packets to `writer` and buffering the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if `writer` cannot take the output.
+    pub fn write<W: ByteWriter + ?Sized>(
+        &mut self,
+        input: &[u8],
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        for &byte in input {
+            if self.run_length > 0 && byte == self.run_byte && self.run_length < MAX_PACKET {
+                self.run_length += 1;
+                continue;
+            }
+
+            self.settle_run(writer)?;
+            self.run_byte = byte;
+            self.run_length = 1;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the buffered input and resets the encoder for a new stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if `writer` cannot take the output.
+    pub fn finish<W: ByteWriter + ?Sized>(&mut self, writer: &mut W) -> Result<(), EncodingError> {
+        self.settle_run(writer)?;
+        self.flush_literals(writer)
+    }
+
+    /// Writes the current run as a repeat packet if that saves space, or moves it into
+    /// the pending literals otherwise.
+    fn settle_run<W: ByteWriter + ?Sized>(&mut self, writer: &mut W) -> Result<(), EncodingError> {
+        if self.run_length >= 3 || (self.run_length == 2 && self.literal_length == 0) {
+            self.flush_literals(writer)?;
+            #[allow(clippy::cast_possible_truncation)] // Runs are 2 to 128 bytes long.
+            let header = (257 - self.run_length) as u8;
+            writer.write_bytes(&[header, self.run_byte])?;
+        } else {
+            for _ in 0..self.run_length {
+                self.literals[self.literal_length] = self.run_byte;
+                self.literal_length += 1;
+
+                if self.literal_length == MAX_PACKET {
+                    self.flush_literals(writer)?;
+                }
+            }
+        }
+
+        self.run_length = 0;
+        Ok(())
+    }
+
+    fn flush_literals<W: ByteWriter + ?Sized>(
+        &mut self,
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        if self.literal_length > 0 {
+            #[allow(clippy::cast_possible_truncation)] // At most 128 literals.
+            writer.write_byte((self.literal_length - 1) as u8)?;
+            writer.write_bytes(&self.literals[..self.literal_length])?;
+            self.literal_length = 0;
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for RleEncoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Header,
+    Literal { remaining: usize },
+    Repeat { count: usize },
+}
+
+/// A streaming run-length decoder.
+///
+/// Input can be split into chunks anywhere, including inside a packet. Call
+/// [`finish`](Self::finish) after the last chunk to check that the input did not end in
+/// the middle of a packet.
+#[derive(Debug, Clone)]
+pub struct RleDecoder {
+    state: State,
+}
+
+impl RleDecoder {
+    /// Creates a decoder expecting the start of a packet.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: State::Header,
+        }
+    }
+
+    /// Decodes `input`, writing the decompressed bytes to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if `writer` cannot take the output.
+    pub fn write<W: ByteWriter + ?Sized>(
+        &mut self,
+        input: &[u8],
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        for &byte in input {
+            self.state = match self.state {
+                State::Header => match byte {
+                    0..=127 => State::Literal {
+                        remaining: usize::from(byte) + 1,
+                    },
+                    128 => State::Header,
+                    _ => State::Repeat {
+                        count: 257 - usize::from(byte),
+                    },
+                },
+                State::Literal { remaining } => {
+                    writer.write_byte(byte)?;
+
+                    if remaining > 1 {
+                        State::Literal {
+                            remaining: remaining - 1,
+                        }
+                    } else {
+                        State::Header
+                    }
+                }
+                State::Repeat { count } => {
+                    writer.write_bytes(&[byte; MAX_PACKET][..count])?;
+                    State::Header
+                }
+            };
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the input ended between packets and resets the decoder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::UnexpectedEnd`] if the input ended inside a packet.
+    pub fn finish(&mut self) -> Result<(), EncodingError> {
+        let state = core::mem::replace(&mut self.state, State::Header);
+
+        if state == State::Header {
+            Ok(())
+        } else {
+            Err(EncodingError::UnexpectedEnd)
+        }
+    }
+}
+
+impl Default for RleDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Compresses `input` into a new vector.
+#[must_use]
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(max_compressed_length(input.len()));
+    let mut encoder = RleEncoder::new();
+
+    // Writing into a `Vec` cannot fail.
+    let _ = encoder
+        .write(input, &mut output)
+        .and_then(|()| encoder.finish(&mut output));
+
+    output
+}
+
+/// Compresses `input` into the start of `buffer` and returns the compressed length.
+///
+/// A buffer of [`max_compressed_length`] bytes is always large enough.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::BufferTooSmall`] if the output does not fit in `buffer`.
+pub fn compress_into(input: &[u8], buffer: &mut [u8]) -> Result<usize, EncodingError> {
+    let mut writer = SliceWriter::new(buffer);
+    let mut encoder = RleEncoder::new();
+    encoder.write(input, &mut writer)?;
+    encoder.finish(&mut writer)?;
+    Ok(writer.position())
+}
+
+/// Decompresses `input` into a new vector.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::UnexpectedEnd`] if `input` ends inside a packet.
+pub fn decompress(input: &[u8]) -> Result<Vec<u8>, EncodingError> {
+    let mut output = Vec::new();
+    let mut decoder = RleDecoder::new();
+    decoder.write(input, &mut output)?;
+    decoder.finish()?;
+    Ok(output)
+}
+
+/// Decompresses `input` into the start of `buffer` and returns the decompressed length.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::BufferTooSmall`] if the output does not fit in `buffer`, or
+/// [`EncodingError::UnexpectedEnd`] if `input` ends inside a packet.
+pub fn decompress_into(input: &[u8], buffer: &mut [u8]) -> Result<usize, EncodingError> {
+    let mut writer = SliceWriter::new(buffer);
+    let mut decoder = RleDecoder::new();
+    decoder.write(input, &mut writer)?;
+    decoder.finish()?;
+    Ok(writer.position())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_packets() {
+        assert_eq!(compress(b""), []);
+        assert_eq!(compress(b"a"), [0, b'a']);
+        assert_eq!(compress(b"aa"), [0xff, b'a']);
+        assert_eq!(compress(b"abba"), [3, b'a', b'b', b'b', b'a']);
+        assert_eq!(compress(b"abbba"), [0, b'a', 0xfe, b'b', 0, b'a']);
+        assert_eq!(compress(&[7; 130]), [0x81, 7, 0xff, 7]);
+
+        let literals: Vec<u8> = (0..=255).collect();
+        let compressed = compress(&literals);
+        assert_eq!(compressed.len(), max_compressed_length(literals.len()));
+        assert_eq!((compressed[0], compressed[129]), (127, 127));
+    }
+
+    #[test]
+    fn test_round_trip_in_chunks() {
+        let mut input = vec![0_u8; 300];
+        input.extend((0..200).map(|index: u32| (index * 7 % 5) as u8));
+        input.extend(b"xyzzzzzzz");
+
+        let expected = compress(&input);
+
+        for chunk_size in [1, 2, 3, 127, 1000] {
+            let mut encoder = RleEncoder::new();
+            let mut output = Vec::new();
+
+            for chunk in input.chunks(chunk_size) {
+                encoder.write(chunk, &mut output).expect("vector output");
+            }
+
+            encoder.finish(&mut output).expect("vector output");
+            assert_eq!(output, expected);
+
+            let mut decoder = RleDecoder::new();
+            let mut restored = Vec::new();
+
+            for chunk in output.chunks(chunk_size) {
+                decoder.write(chunk, &mut restored).expect("vector output");
+            }
+
+            assert_eq!(decoder.finish(), Ok(()));
+            assert_eq!(restored, input);
+        }
+    }
+
+    #[test]
+    fn test_errors_and_buffers() {
+        assert_eq!(decompress(&[2, b'a']), Err(EncodingError::UnexpectedEnd));
+        assert_eq!(decompress(&[0xfe]), Err(EncodingError::UnexpectedEnd));
+        assert_eq!(decompress(&[128, 0, b'a']), Ok(vec![b'a']));
+
+        let mut buffer = [0; 8];
+        assert_eq!(compress_into(&[1; 50], &mut buffer), Ok(2));
+        assert_eq!(
+            decompress_into(&buffer[..2], &mut buffer.clone()),
+            Err(EncodingError::BufferTooSmall { capacity: 8 })
+        );
+        assert_eq!(
+            compress_into(b"abcdefgh", &mut buffer),
+            Err(EncodingError::BufferTooSmall { capacity: 8 })
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! Reading and writing configuration files.
+//!
+//! The [`ini`] module parses and writes INI and properties files.
+
+pub mod ini;
+
+pub use ini::{Ini, IniParser};
@@ -0,0 +1,592 @@
+//! INI and properties files.
+//!
+//! An [`Ini`] is a list of [`Section`]s of key-value pairs, kept in the order they were
+//! read or added, so a file that is parsed, changed and written back keeps its layout.
+//! Keys before the first section header belong to the unnamed global section.
+//!
+//! [`IniParser`] reads the common dialect:
+//!
+//! - `[name]` starts a section. A section that appears twice is continued.
+//! - `key = value` and `key: value` add a pair. Whitespace around keys and values is
+//!   removed.
+//! - Lines starting with `;` or `#` are comments, as is the rest of a line from a `;` or
+//!   `#` that follows whitespace, unless inline comments are turned off.
+//! - A value in double quotes keeps its whitespace and comment characters, and may use
+//!   C escape sequences such as `\n` and `\"`.
+//! - An unquoted value that ends with a backslash continues on the next line.
+//!
+//! What happens to a key that appears twice in a section is chosen with
+//! [`DuplicateKeys`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::config::Ini;
+//!
+//! let mut ini: Ini = "name = demo\n\n[server]\nport = 8080 ; default\n"
+//!     .parse()
+//!     .expect("the file is valid");
+//!
+//! assert_eq!(ini.get("", "name"), Some("demo"));
+//! assert_eq!(ini.get("server", "port"), Some("8080"));
+//!
+//! ini.set("server", "host", " localhost ");
+//! assert_eq!(
+//!     ini.to_string(),
+//!     "name = demo\n\n[server]\nport = 8080\nhost = \" localhost \"\n"
+//! );
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use crate::{
+    errors::IniError,
+    text::escape::{Mode, escape_c, unescape_c},
+};
+
+/// What a parser does with a key that appears twice in a section.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum DuplicateKeys {
+    /// A repeated key is an error.
+    Error,
+
+    /// The first value is kept and later ones are ignored.
+    KeepFirst,
+
+    /// The last value is kept, in the position of the first.
+    #[default]
+    KeepLast,
+
+    /// Every value is kept, as separate entries.
+    KeepAll,
+}
+
+/// A section of an INI file: a name and its key-value pairs, in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Section {
+    name: String,
+    entries: Vec<(String, String)>,
+}
+
+impl Section {
+    /// Creates an empty section named `name`.
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Returns the name of the section, which is empty for the global section.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the number of entries.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether the section has no entries.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the first value of `key`.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.position(key)
+            .map(|index| self.entries[index].1.as_str())
+    }
+
+    /// Returns every value of `key`, in order.
+    pub fn get_all<'s>(&'s self, key: &'s str) -> impl Iterator<Item = &'s str> {
+        self.entries
+            .iter()
+            .filter(move |(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the entries, in order.
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+    }
+
+    /// Sets `key` to `value`, replacing the first value and removing any others, or adds
+    /// it at the end.
+    pub fn set(&mut self, key: &str, value: &str) {
+        match self.position(key) {
+            Some(index) => {
+                self.entries[index].1 = value.to_string();
+
+                let mut seen = 0;
+                self.entries.retain(|(name, _)| {
+                    seen += usize::from(name == key);
+                    name != key || seen == 1
+                });
+            }
+            None => self.append(key, value),
+        }
+    }
+
+    /// Adds `key` with `value` at the end, even if the key is already present.
+    pub fn append(&mut self, key: &str, value: &str) {
+        self.entries.push((key.to_string(), value.to_string()));
+    }
+
+    /// Removes every value of `key`, returning the first.
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        let first = self.position(key).map(|index| self.entries.remove(index).1);
+        self.entries.retain(|(name, _)| name != key);
+        first
+    }
+
+    /// Returns the index of the first entry of `key`.
+    fn position(&self, key: &str) -> Option<usize> {
+        self.entries.iter().position(|(name, _)| name == key)
+    }
+}
+
+/// The contents of an INI file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Ini {
+    /// The global section, followed by the named sections.
+    sections: Vec<Section>,
+}
+
+impl Ini {
+    /// Creates an empty file.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            sections: alloc::vec![Section::default()],
+        }
+    }
+
+    /// Parses `text` with the default [`IniParser`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IniError`] for the first line that cannot be parsed.
+    pub fn parse(text: &str) -> Result<Self, IniError> {
+        IniParser::new().parse(text)
+    }
+
+    /// Returns the global section, which holds the keys before any section header.
+    #[must_use]
+    pub fn global(&self) -> &Section {
+        &self.sections[0]
+    }
+
+    /// Returns the section `name`, or the global section if `name` is empty.
+    #[must_use]
+    pub fn section(&self, name: &str) -> Option<&Section> {
+        self.sections.iter().find(|section| section.name == name)
+    }
+
+    /// Returns the section `name`, adding it at the end if there is none.
+    pub fn section_mut(&mut self, name: &str) -> &mut Section {
+        let index = self.section_index(name);
+        &mut self.sections[index]
+    }
+
+    /// Returns the named sections, in order.
+    pub fn sections(&self) -> impl Iterator<Item = &Section> {
+        self.sections[1..].iter()
+    }
+
+    /// Removes the named section `name` and returns it.
+    pub fn remove_section(&mut self, name: &str) -> Option<Section> {
+        let index = self.sections[1..]
+            .iter()
+            .position(|section| section.name == name)?;
+
+        Some(self.sections.remove(index + 1))
+    }
+
+    /// Returns the first value of `key` in `section`.
+    #[must_use]
+    pub fn get(&self, section: &str, key: &str) -> Option<&str> {
+        self.section(section)?.get(key)
+    }
+
+    /// Sets `key` to `value` in `section`, adding the section if there is none.
+    ///
+    /// See [`Section::set`].
+    pub fn set(&mut self, section: &str, key: &str, value: &str) {
+        self.section_mut(section).set(key, value);
+    }
+
+    /// Returns the index of the section `name`, adding it if there is none.
+    fn section_index(&mut self, name: &str) -> usize {
+        self.sections
+            .iter()
+            .position(|section| section.name == name)
+            .unwrap_or_else(|| {
+                self.sections.push(Section::new(name));
+                self.sections.len() - 1
+            })
+    }
+}
+
+impl Default for Ini {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FromStr for Ini {
+    type Err = IniError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl fmt::Display for Ini {
+    /// Writes the file with one `key = value` line per entry and a blank line before
+    /// every section header. Values that would not read back the same are quoted.
+    /// Section names and keys are written as they are.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+
+        for section in &self.sections {
+            if !section.name.is_empty() {
+                if !first {
+                    f.write_str("\n")?;
+                }
+
+                writeln!(f, "[{}]", section.name)?;
+            } else if section.is_empty() {
+                continue;
+            }
+
+            for (key, value) in &section.entries {
+                if needs_quotes(value) {
+                    writeln!(f, "{key} = \"{}\"", escape_c(value))?;
+                } else {
+                    writeln!(f, "{key} = {value}")?;
+                }
+            }
+
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns whether `value` must be quoted to be read back unchanged.
+fn needs_quotes(value: &str) -> bool {
+    value.trim() != value
+        || value.starts_with('"')
+        || value.ends_with('\\')
+        || value.contains([';', '#'])
+        || value.contains(char::is_control)
+}
+
+/// A parser of INI files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IniParser {
+    duplicate_keys: DuplicateKeys,
+    inline_comments: bool,
+}
+
+impl IniParser {
+    /// Creates a parser that keeps the last value of a repeated key and allows inline
+    /// comments.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            duplicate_keys: DuplicateKeys::KeepLast,
+            inline_comments: true,
+        }
+    }
+
+    /// Sets what happens to a key that appears twice in a section.
+    #[must_use]
+    pub const fn with_duplicate_keys(mut self, duplicate_keys: DuplicateKeys) -> Self {
+        self.duplicate_keys = duplicate_keys;
+        self
+    }
+
+    /// Sets whether a `;` or `#` after whitespace starts a comment in an unquoted value.
+    #[must_use]
+    pub const fn with_inline_comments(mut self, inline_comments: bool) -> Self {
+        self.inline_comments = inline_comments;
+        self
+    }
+
+    /// Returns what happens to a key that appears twice in a section.
+    #[must_use]
+    pub const fn duplicate_keys(&self) -> DuplicateKeys {
+        self.duplicate_keys
+    }
+
+    /// Returns whether inline comments are allowed.
+    #[must_use]
+    pub const fn inline_comments(&self) -> bool {
+        self.inline_comments
+    }
+
+    /// Parses `text`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IniError`] with the number of the first line that cannot be parsed,
+    /// or that repeats a key when [`DuplicateKeys::Error`] is chosen.
+    pub fn parse(&self, text: &str) -> Result<Ini, IniError> {
+        let mut ini = Ini::new();
+        let mut current = 0;
+        let mut lines = text.lines().zip(1..);
+
+        while let Some((line, number)) = lines.next() {
+            let line = line.trim();
+
+            if is_blank(line) {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let (name, rest) = header
+                    .split_once(']')
+                    .ok_or(IniError::Syntax { line: number })?;
+
+                if !is_blank(rest.trim_start()) {
+                    return Err(IniError::Syntax { line: number });
+                }
+
+                current = ini.section_index(name.trim());
+                continue;
+            }
+
+            let separator = line
+                .find(['=', ':'])
+                .ok_or(IniError::Syntax { line: number })?;
+            let key = line[..separator].trim_end();
+
+            if key.is_empty() {
+                return Err(IniError::Syntax { line: number });
+            }
+
+            let (mut value, mut continues) =
+                self.value(line[separator + 1..].trim_start(), number)?;
+
+            while continues && let Some((line, _)) = lines.next() {
+                let (rest, more) = self.unquoted(line.trim_start());
+                value.push_str(rest);
+                continues = more;
+            }
+
+            let section = &mut ini.sections[current];
+
+            match (self.duplicate_keys, section.position(key)) {
+                (DuplicateKeys::Error, Some(_)) => {
+                    return Err(IniError::DuplicateKey { line: number });
+                }
+                (DuplicateKeys::KeepFirst, Some(_)) => {}
+                (DuplicateKeys::KeepLast, Some(index)) => section.entries[index].1 = value,
+                (DuplicateKeys::KeepAll, _) | (_, None) => {
+                    section.entries.push((key.to_string(), value));
+                }
+            }
+        }
+
+        Ok(ini)
+    }
+
+    /// Reads the value that starts `text`, on line `line`, and returns it with whether
+    /// it continues on the next line.
+    fn value(self, text: &str, line: usize) -> Result<(String, bool), IniError> {
+        let Some(quoted) = text.strip_prefix('"') else {
+            let (value, continues) = self.unquoted(text);
+            return Ok((value.to_string(), continues));
+        };
+
+        let mut escaped = false;
+        let end = quoted
+            .char_indices()
+            .find(|&(_, character)| {
+                let is_end = character == '"' && !escaped;
+                escaped = character == '\\' && !escaped;
+                is_end
+            })
+            .map(|(index, _)| index)
+            .ok_or(IniError::UnterminatedQuote { line })?;
+
+        if !is_blank(quoted[end + 1..].trim_start()) {
+            return Err(IniError::Syntax { line });
+        }
+
+        unescape_c(&quoted[..end], Mode::Strict)
+            .map(|value| (value.into_owned(), false))
+            .map_err(|_| IniError::InvalidEscape { line })
+    }
+
+    /// Returns an unquoted value without its comment and trailing whitespace, and
+    /// whether it ends with a backslash that continues it on the next line.
+    fn unquoted(self, text: &str) -> (&str, bool) {
+        let comment = text
+            .char_indices()
+            .zip(text.chars().skip(1))
+            .find(|&((_, before), after)| before.is_whitespace() && matches!(after, ';' | '#'))
+            .filter(|_| self.inline_comments)
+            .map_or(text.len(), |((index, _), _)| index);
+        let value = text[..comment].trim_end();
+
+        value
+            .strip_suffix('\\')
+            .map_or((value, false), |value| (value, true))
+    }
+}
+
+impl Default for IniParser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns whether `line`, without leading whitespace, is empty or a comment.
+fn is_blank(line: &str) -> bool {
+    line.is_empty() || line.starts_with([';', '#'])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let text = "; global settings\n\
+                    name=demo\n\
+                    [paths]\n\
+                    home : C:\\Users\\me   # inline\n\
+                    motd = \"  hi; there\\n\" ; after\n\
+                    list = a, \\\n   b, \\\n   c\n\
+                    [ empty ]\n\
+                    [paths]\n\
+                    tmp = /tmp\n";
+        let ini = Ini::parse(text).expect("the file is valid");
+
+        assert_eq!(
+            ini.global().entries().collect::<Vec<_>>(),
+            [("name", "demo")]
+        );
+        assert_eq!(
+            ini.sections().map(Section::name).collect::<Vec<_>>(),
+            ["paths", "empty"]
+        );
+
+        let paths = ini.section("paths").expect("the section exists");
+        assert_eq!(
+            paths.entries().collect::<Vec<_>>(),
+            [
+                ("home", "C:\\Users\\me"),
+                ("motd", "  hi; there\n"),
+                ("list", "a, b, c"),
+                ("tmp", "/tmp"),
+            ]
+        );
+
+        let parser = IniParser::new().with_inline_comments(false);
+        let ini = parser
+            .parse("color = #fff ; red\n")
+            .expect("the file is valid");
+        assert_eq!(ini.get("", "color"), Some("#fff ; red"));
+    }
+
+    #[test]
+    fn test_duplicate_keys_and_errors() {
+        let text = "a = 1\nb = 2\na = 3\n";
+        let parse = |duplicate_keys| {
+            IniParser::new()
+                .with_duplicate_keys(duplicate_keys)
+                .parse(text)
+        };
+
+        let first = parse(DuplicateKeys::KeepFirst).expect("duplicates are allowed");
+        assert_eq!(
+            first.global().entries().collect::<Vec<_>>(),
+            [("a", "1"), ("b", "2")]
+        );
+
+        let last = parse(DuplicateKeys::KeepLast).expect("duplicates are allowed");
+        assert_eq!(
+            last.global().entries().collect::<Vec<_>>(),
+            [("a", "3"), ("b", "2")]
+        );
+
+        let all = parse(DuplicateKeys::KeepAll).expect("duplicates are allowed");
+        assert_eq!(all.global().get_all("a").collect::<Vec<_>>(), ["1", "3"]);
+
+        assert_eq!(
+            parse(DuplicateKeys::Error),
+            Err(IniError::DuplicateKey { line: 3 })
+        );
+
+        assert_eq!(Ini::parse("[open\n"), Err(IniError::Syntax { line: 1 }));
+        assert_eq!(
+            Ini::parse("\n\nno separator\n"),
+            Err(IniError::Syntax { line: 3 })
+        );
+        assert_eq!(Ini::parse("= value\n"), Err(IniError::Syntax { line: 1 }));
+        assert_eq!(
+            Ini::parse("a = \"open\n"),
+            Err(IniError::UnterminatedQuote { line: 1 })
+        );
+        assert_eq!(
+            Ini::parse("a = \"\\q\"\n"),
+            Err(IniError::InvalidEscape { line: 1 })
+        );
+        assert_eq!(
+            Ini::parse("a = \"x\" y\n"),
+            Err(IniError::Syntax { line: 1 })
+        );
+    }
+
+    #[test]
+    fn test_edit_and_write() {
+        let mut ini = Ini::new();
+        ini.set("", "version", "2");
+        ini.set("server", "port", "8080");
+        ini.set("server", "motd", "say \"hi\"; bye\\");
+        ini.section_mut("server").append("alias", "a");
+        ini.section_mut("server").append("alias", "b");
+        ini.set("client", "retries", "3");
+
+        let text = ini.to_string();
+        assert_eq!(
+            text,
+            "version = 2\n\n\
+             [server]\nport = 8080\nmotd = \"say \\\"hi\\\"; bye\\\\\"\nalias = a\nalias = b\n\n\
+             [client]\nretries = 3\n"
+        );
+
+        let parsed = IniParser::new()
+            .with_duplicate_keys(DuplicateKeys::KeepAll)
+            .parse(&text)
+            .expect("written files parse");
+        assert_eq!(parsed, ini);
+
+        ini.set("server", "alias", "c");
+        assert_eq!(ini.get("server", "alias"), Some("c"));
+        assert_eq!(
+            ini.section_mut("server").remove("alias"),
+            Some("c".to_string())
+        );
+        assert_eq!(ini.section("server").map(Section::len), Some(2));
+        assert!(ini.remove_section("client").is_some());
+        assert_eq!(ini.section("client"), None);
+        assert_eq!(Ini::new().to_string(), "");
+    }
+}
@@ -0,0 +1,430 @@
+//! Binary encodings and the byte sources and sinks they work over.
+//!
+//! Codecs read from a [`ByteReader`] and write to a [`ByteWriter`]. A `&[u8]` is a reader
+//! that advances past the bytes it yields, a `Vec<u8>` is a writer that grows as needed,
+//! and a [`SliceWriter`] writes into a fixed buffer without allocating.
+//!
+//! With the `std` feature, `StdReader` and `StdWriter` let codecs work over `std::io`
+//! streams, and `ByteIo` lets `std::io` code work over byte sources and sinks.
+
+pub mod framing;
+pub mod varint;
+
+use alloc::vec::Vec;
+
+use crate::errors::EncodingError;
+
+/// A source of bytes, read one at a time.
+pub trait ByteReader {
+    /// Returns the next byte, or `None` at the end of the input.
+    fn read_byte(&mut self) -> Option<u8>;
+}
+
+impl ByteReader for &[u8] {
+    fn read_byte(&mut self) -> Option<u8> {
+        let (&first, rest) = self.split_first()?;
+        *self = rest;
+        Some(first)
+    }
+}
+
+/// A sink for bytes.
+pub trait ByteWriter {
+    /// Writes one byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if the sink cannot take another byte.
+    fn write_byte(&mut self, byte: u8) -> Result<(), EncodingError>;
+
+    /// Writes every byte of `bytes`, stopping at the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if the sink cannot take all of the bytes. Bytes
+    /// before the failing one may already have been written.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodingError> {
+        bytes.iter().try_for_each(|&byte| self.write_byte(byte))
+    }
+}
+
+impl ByteWriter for Vec<u8> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), EncodingError> {
+        self.push(byte);
+        Ok(())
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodingError> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// A [`ByteWriter`] that fills a borrowed buffer from the start.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{
+///     encoding::{ByteWriter, SliceWriter},
+///     errors::EncodingError,
+/// };
+///
+/// let mut buffer = [0; 3];
+/// let mut writer = SliceWriter::new(&mut buffer);
+///
+/// writer.write_bytes(b"ab").unwrap();
+/// assert_eq!(writer.written(), b"ab");
+/// assert_eq!(
+///     writer.write_bytes(b"cd"),
+///     Err(EncodingError::BufferTooSmall { capacity: 3 })
+/// );
+/// ```
+#[derive(Debug)]
+pub struct SliceWriter<'a> {
+    buffer: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    /// Creates a writer that starts at the beginning of `buffer`.
+    #[must_use]
+    pub const fn new(buffer: &'a mut [u8]) -> Self {
+        Self {
+            buffer,
+            position: 0,
+        }
+    }
+
+    /// Returns the number of bytes written so far.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes that can still be written.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
+
+    /// Returns the bytes written so far.
+    #[must_use]
+    pub fn written(&self) -> &[u8] {
+        &self.buffer[..self.position]
+    }
+
+    /// Consumes the writer and returns the bytes written, borrowed for the buffer's
+    /// lifetime.
+    #[must_use]
+    pub fn into_written(self) -> &'a [u8] {
+        &self.buffer[..self.position]
+    }
+}
+
+impl ByteWriter for SliceWriter<'_> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), EncodingError> {
+        let capacity = self.buffer.len();
+        let slot = self
+            .buffer
+            .get_mut(self.position)
+            .ok_or(EncodingError::BufferTooSmall { capacity })?;
+
+        *slot = byte;
+        self.position += 1;
+        Ok(())
+    }
+}
+
+/// A [`ByteReader`] over a [`std::io::Read`] stream.
+///
+/// The stream is read a byte at a time, so wrap unbuffered sources in a
+/// [`std::io::BufReader`]. A read error ends the input; it is kept so the caller can tell
+/// an error from the end of the stream.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Cursor;
+///
+/// use libx::encoding::{ByteReader, StdReader};
+///
+/// let mut reader = StdReader::new(Cursor::new([1, 2]));
+///
+/// assert_eq!(reader.read_byte(), Some(1));
+/// assert_eq!(reader.read_byte(), Some(2));
+/// assert_eq!(reader.read_byte(), None);
+/// assert!(reader.take_error().is_none());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct StdReader<R> {
+    inner: R,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<R> StdReader<R> {
+    /// Creates a reader over `inner`.
+    #[must_use]
+    pub const fn new(inner: R) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Returns the error that ended the input, if any, and clears it.
+    pub const fn take_error(&mut self) -> Option<std::io::Error> {
+        self.error.take()
+    }
+
+    /// Consumes the reader and returns the stream.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> ByteReader for StdReader<R> {
+    fn read_byte(&mut self) -> Option<u8> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        let mut byte = 0;
+
+        loop {
+            match self.inner.read(core::slice::from_mut(&mut byte)) {
+                Ok(0) => return None,
+                Ok(_) => return Some(byte),
+                Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(error) => {
+                    self.error = Some(error);
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// A [`ByteWriter`] over a [`std::io::Write`] stream.
+///
+/// A write error is reported as [`EncodingError::WriteFailed`] and kept so the caller can
+/// inspect it.
+///
+/// # Examples
+///
+/// ```
+/// use libx::encoding::{ByteWriter, StdWriter};
+///
+/// let mut writer = StdWriter::new(Vec::new());
+/// writer.write_bytes(b"ok").unwrap();
+///
+/// assert_eq!(writer.into_inner(), b"ok");
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct StdWriter<W> {
+    inner: W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W> StdWriter<W> {
+    /// Creates a writer over `inner`.
+    #[must_use]
+    pub const fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// Returns the last write error, if any, and clears it.
+    pub const fn take_error(&mut self) -> Option<std::io::Error> {
+        self.error.take()
+    }
+
+    /// Consumes the writer and returns the stream.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> ByteWriter for StdWriter<W> {
+    fn write_byte(&mut self, byte: u8) -> Result<(), EncodingError> {
+        self.write_bytes(&[byte])
+    }
+
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EncodingError> {
+        self.inner.write_all(bytes).map_err(|error| {
+            self.error = Some(error);
+            EncodingError::WriteFailed
+        })
+    }
+}
+
+/// A [`std::io::Read`] and [`std::io::Write`] stream over a [`ByteReader`] or
+/// [`ByteWriter`].
+///
+/// Errors from the writer are reported as [`std::io::ErrorKind::Other`] errors wrapping
+/// the [`EncodingError`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{Read, Write};
+///
+/// use libx::encoding::{ByteIo, SliceWriter};
+///
+/// let mut text = String::new();
+/// ByteIo::new(&b"hello"[..]).read_to_string(&mut text).unwrap();
+/// assert_eq!(text, "hello");
+///
+/// let mut buffer = [0; 2];
+/// let mut writer = ByteIo::new(SliceWriter::new(&mut buffer));
+/// assert_eq!(writer.write(b"abc").unwrap(), 2);
+/// assert!(writer.write(b"c").is_err());
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ByteIo<T>(T);
+
+#[cfg(feature = "std")]
+impl<T> ByteIo<T> {
+    /// Creates a stream over `inner`.
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self(inner)
+    }
+
+    /// Consumes the stream and returns the byte source or sink.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ByteReader> std::io::Read for ByteIo<T> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut count = 0;
+
+        for slot in buf {
+            let Some(byte) = self.0.read_byte() else {
+                break;
+            };
+
+            *slot = byte;
+            count += 1;
+        }
+
+        Ok(count)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: ByteWriter> std::io::Write for ByteIo<T> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for (count, &byte) in buf.iter().enumerate() {
+            if let Err(error) = self.0.write_byte(byte) {
+                // Report the bytes already taken; the next call surfaces the error.
+                return if count == 0 {
+                    Err(std::io::Error::other(error))
+                } else {
+                    Ok(count)
+                };
+            }
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice_reader_advances() {
+        let mut input: &[u8] = &[1, 2];
+
+        assert_eq!(input.read_byte(), Some(1));
+        assert_eq!(input, [2]);
+        assert_eq!(input.read_byte(), Some(2));
+        assert_eq!(input.read_byte(), None);
+    }
+
+    #[test]
+    fn test_writers() {
+        let mut buffer = [0; 4];
+        let mut writer = SliceWriter::new(&mut buffer);
+
+        assert_eq!(writer.write_bytes(&[1, 2, 3]), Ok(()));
+        assert_eq!(writer.remaining(), 1);
+        assert_eq!(
+            writer.write_bytes(&[4, 5]),
+            Err(EncodingError::BufferTooSmall { capacity: 4 })
+        );
+        assert_eq!(writer.position(), 4);
+        assert_eq!(writer.into_written(), [1, 2, 3, 4]);
+
+        let mut vector = Vec::new();
+        assert_eq!(vector.write_bytes(b"ok"), Ok(()));
+        assert_eq!(vector, b"ok");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_std_io_adapters() {
+        use std::io::{self, Read, Write};
+
+        use crate::encoding::varint::Leb128;
+
+        struct Failing;
+
+        impl Read for Failing {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+        }
+
+        impl Write for Failing {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = StdWriter::new(Vec::new());
+        assert_eq!(300_u32.encode(&mut writer), Ok(2));
+
+        let mut reader = StdReader::new(io::Cursor::new(writer.into_inner()));
+        assert_eq!(u32::decode(&mut reader), Ok(300));
+        assert_eq!(reader.read_byte(), None);
+        assert!(reader.take_error().is_none());
+
+        let mut failing = StdReader::new(Failing);
+        assert_eq!(failing.read_byte(), None);
+        assert!(failing.take_error().is_some());
+
+        let mut failing = StdWriter::new(Failing);
+        assert_eq!(failing.write_byte(0), Err(EncodingError::WriteFailed));
+        assert!(failing.take_error().is_some());
+
+        let mut bytes = Vec::new();
+        assert_eq!(
+            ByteIo::new(&[1, 2, 3][..]).read_to_end(&mut bytes).ok(),
+            Some(3)
+        );
+        assert_eq!(bytes, [1, 2, 3]);
+
+        let mut buffer = [0; 3];
+        let mut stream = ByteIo::new(SliceWriter::new(&mut buffer));
+        assert!(stream.write_all(b"abc").is_ok());
+        assert!(stream.write_all(b"d").is_err());
+        assert_eq!(stream.into_inner().written(), b"abc");
+    }
+}
@@ -0,0 +1,540 @@
+//! Packet framing for byte streams such as serial links.
+//!
+//! A [`FrameFormat`] combines a byte-stuffing scheme with an optional CRC trailer:
+//!
+//! - [COBS](Framing::Cobs) removes every zero byte from the frame and ends it with a single
+//!   zero, adding one byte per 254 bytes of payload.
+//! - [SLIP](Framing::Slip) (RFC 1055) surrounds the frame with `0xc0` and escapes that
+//!   byte and `0xdb` inside it, adding up to one byte per payload byte.
+//!
+//! The [`Trailer`] CRC is computed over the payload and appended to it in little-endian
+//! order before stuffing. A [`FrameDecoder`] takes the stream a byte at a time and hands
+//! back each frame as its delimiter arrives, so partial input, noise between frames and
+//! corrupt frames only cost the frames they touch.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{
+//!     encoding::framing::{FrameDecoder, FrameFormat, Framing, Trailer},
+//!     hashing::crc::CRC_16_IBM_3740,
+//! };
+//!
+//! let format = FrameFormat::new(Framing::Cobs).with_trailer(Trailer::Crc16(CRC_16_IBM_3740));
+//!
+//! let mut stream = Vec::new();
+//! format.encode(b"\x00hello", &mut stream).unwrap();
+//! format.encode(b"world", &mut stream).unwrap();
+//! assert_eq!(stream.iter().filter(|&&byte| byte == 0).count(), 2);
+//!
+//! let mut decoder = FrameDecoder::new(format, 64);
+//! let mut frames = Vec::new();
+//!
+//! for &byte in &stream {
+//!     if let Some(frame) = decoder.push(byte) {
+//!         frames.push(frame.unwrap().to_vec());
+//!     }
+//! }
+//!
+//! assert_eq!(frames, [b"\x00hello".to_vec(), b"world".to_vec()]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{encoding::ByteWriter, errors::EncodingError, hashing::crc::Algorithm};
+
+const SLIP_END: u8 = 0xc0;
+const SLIP_ESC: u8 = 0xdb;
+const SLIP_ESC_END: u8 = 0xdc;
+const SLIP_ESC_ESC: u8 = 0xdd;
+
+/// The longest run of non-zero bytes a COBS block can hold.
+const COBS_BLOCK: usize = 254;
+
+/// How frames are delimited in the byte stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Framing {
+    /// Consistent Overhead Byte Stuffing, with a zero byte after each frame.
+    Cobs,
+
+    /// Serial Line Internet Protocol framing, with an END byte before and after each frame.
+    Slip,
+}
+
+/// The integrity check appended to each frame's payload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Trailer {
+    /// No check.
+    None,
+
+    /// A 16-bit CRC.
+    Crc16(Algorithm<u16>),
+
+    /// A 32-bit CRC.
+    Crc32(Algorithm<u32>),
+}
+
+impl Trailer {
+    /// Returns the number of bytes the trailer adds to the payload.
+    #[must_use]
+    pub const fn length(self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Crc16(_) => 2,
+            Self::Crc32(_) => 4,
+        }
+    }
+
+    /// Returns the trailer bytes for `payload`, in the first [`length`](Self::length)
+    /// bytes of the array.
+    fn compute(self, payload: &[u8]) -> [u8; 4] {
+        let mut trailer = [0; 4];
+
+        match self {
+            Self::None => {}
+            Self::Crc16(algorithm) => {
+                trailer[..2].copy_from_slice(&algorithm.checksum(payload).to_le_bytes());
+            }
+            Self::Crc32(algorithm) => trailer = algorithm.checksum(payload).to_le_bytes(),
+        }
+
+        trailer
+    }
+}
+
+/// A framing scheme and trailer, used to encode frames and to configure a
+/// [`FrameDecoder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameFormat {
+    framing: Framing,
+    trailer: Trailer,
+}
+
+impl FrameFormat {
+    /// Creates a format using `framing` with no trailer.
+    #[must_use]
+    pub const fn new(framing: Framing) -> Self {
+        Self {
+            framing,
+            trailer: Trailer::None,
+        }
+    }
+
+    /// Sets the trailer appended to each payload.
+    #[must_use]
+    pub const fn with_trailer(mut self, trailer: Trailer) -> Self {
+        self.trailer = trailer;
+        self
+    }
+
+    /// Returns the framing scheme.
+    #[must_use]
+    pub const fn framing(&self) -> Framing {
+        self.framing
+    }
+
+    /// Returns the trailer.
+    #[must_use]
+    pub const fn trailer(&self) -> Trailer {
+        self.trailer
+    }
+
+    /// Returns the largest possible encoded length of a payload of `payload_length`
+    /// bytes, including delimiters.
+    #[must_use]
+    pub const fn max_encoded_length(&self, payload_length: usize) -> usize {
+        let length = payload_length + self.trailer.length();
+
+        match self.framing {
+            Framing::Cobs => length + length / COBS_BLOCK + 2,
+            Framing::Slip => 2 * length + 2,
+        }
+    }
+
+    /// Writes `payload` as one complete frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if `writer` cannot take the frame.
+    pub fn encode<W: ByteWriter + ?Sized>(
+        &self,
+        payload: &[u8],
+        writer: &mut W,
+    ) -> Result<(), EncodingError> {
+        let trailer = self.trailer.compute(payload);
+        let bytes = payload.iter().chain(&trailer[..self.trailer.length()]);
+
+        match self.framing {
+            Framing::Cobs => {
+                let mut block = [0; COBS_BLOCK];
+                let mut length = 0;
+
+                for &byte in bytes {
+                    if byte != 0 {
+                        block[length] = byte;
+                        length += 1;
+
+                        if length < COBS_BLOCK {
+                            continue;
+                        }
+                    }
+
+                    write_cobs_block(&block[..length], writer)?;
+                    length = 0;
+                }
+
+                write_cobs_block(&block[..length], writer)?;
+                writer.write_byte(0)
+            }
+            Framing::Slip => {
+                writer.write_byte(SLIP_END)?;
+
+                for &byte in bytes {
+                    match byte {
+                        SLIP_END => writer.write_bytes(&[SLIP_ESC, SLIP_ESC_END])?,
+                        SLIP_ESC => writer.write_bytes(&[SLIP_ESC, SLIP_ESC_ESC])?,
+                        _ => writer.write_byte(byte)?,
+                    }
+                }
+
+                writer.write_byte(SLIP_END)
+            }
+        }
+    }
+
+    /// Decodes the first frame in `input` and returns its payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::UnexpectedEnd`] if `input` holds no complete frame, or
+    /// the error of [`FrameDecoder::push`] for a malformed frame.
+    pub fn decode(&self, input: &[u8]) -> Result<Vec<u8>, EncodingError> {
+        let mut decoder = FrameDecoder::new(*self, input.len());
+
+        for &byte in input {
+            if let Some(frame) = decoder.push(byte) {
+                return frame.map(<[u8]>::to_vec);
+            }
+        }
+
+        Err(EncodingError::UnexpectedEnd)
+    }
+}
+
+/// Writes one COBS block: a code byte one greater than the block length, then the block.
+fn write_cobs_block<W: ByteWriter + ?Sized>(
+    block: &[u8],
+    writer: &mut W,
+) -> Result<(), EncodingError> {
+    #[allow(clippy::cast_possible_truncation)] // Blocks hold at most 254 bytes.
+    writer.write_byte(block.len() as u8 + 1)?;
+    writer.write_bytes(block)
+}
+
+/// A streaming frame decoder.
+///
+/// Bytes are pushed one at a time, and each delimiter completes a frame: its payload, or
+/// the reason it was rejected. Delimiters with nothing between them are skipped, so
+/// SLIP's leading END bytes and repeated COBS zeros cost nothing. After a rejected frame
+/// the decoder carries on with the next one.
+#[derive(Debug, Clone)]
+pub struct FrameDecoder {
+    format: FrameFormat,
+    max_length: usize,
+    frame: Vec<u8>,
+    /// Whether any byte of the current frame has arrived.
+    started: bool,
+    /// The first error in the current frame, reported at its delimiter.
+    error: Option<EncodingError>,
+    /// The code byte of the current COBS block, or zero before the first one.
+    code: u8,
+    /// The data bytes left in the current COBS block.
+    remaining: u8,
+    /// Whether the last SLIP byte was an escape.
+    escaped: bool,
+}
+
+impl FrameDecoder {
+    /// Creates a decoder for `format` that rejects payloads longer than `max_length`
+    /// bytes.
+    #[must_use]
+    pub const fn new(format: FrameFormat, max_length: usize) -> Self {
+        Self {
+            format,
+            max_length,
+            frame: Vec::new(),
+            started: false,
+            error: None,
+            code: 0,
+            remaining: 0,
+            escaped: false,
+        }
+    }
+
+    /// Returns the format being decoded.
+    #[must_use]
+    pub const fn format(&self) -> FrameFormat {
+        self.format
+    }
+
+    /// Returns `true` if part of a frame has arrived but not its delimiter.
+    #[must_use]
+    pub const fn in_frame(&self) -> bool {
+        self.started
+    }
+
+    /// Takes the next byte of the stream and returns the frame it completes, if any.
+    ///
+    /// # Errors
+    ///
+    /// A completed frame is an error instead of a payload if it is malformed:
+    ///
+    /// - [`EncodingError::InvalidData`] if the stuffing is invalid, such as a COBS block
+    ///   cut short by a delimiter or an unknown SLIP escape.
+    /// - [`EncodingError::TooLong`] if the payload is longer than the maximum.
+    /// - [`EncodingError::UnexpectedEnd`] if the frame is too short to hold its trailer.
+    /// - [`EncodingError::ChecksumMismatch`] if the trailer does not match the payload.
+    pub fn push(&mut self, byte: u8) -> Option<Result<&[u8], EncodingError>> {
+        let delimiter = match self.format.framing {
+            Framing::Cobs => 0,
+            Framing::Slip => SLIP_END,
+        };
+
+        if byte == delimiter {
+            return self.end_frame();
+        }
+
+        if !self.started {
+            self.started = true;
+            self.frame.clear();
+        }
+
+        if self.error.is_none() {
+            let result = match self.format.framing {
+                Framing::Cobs => self.push_cobs(byte),
+                Framing::Slip => self.push_slip(byte),
+            };
+
+            self.error = result.err();
+        }
+
+        None
+    }
+
+    /// Discards any partial frame.
+    pub fn reset(&mut self) {
+        self.frame.clear();
+        self.started = false;
+        self.error = None;
+        self.code = 0;
+        self.remaining = 0;
+        self.escaped = false;
+    }
+
+    fn push_cobs(&mut self, byte: u8) -> Result<(), EncodingError> {
+        if self.remaining > 0 {
+            self.remaining -= 1;
+            return self.store(byte);
+        }
+
+        // Every block but the last and the full ones stands for a zero after its data.
+        if self.code != 0 && usize::from(self.code) <= COBS_BLOCK {
+            self.store(0)?;
+        }
+
+        self.code = byte;
+        self.remaining = byte - 1;
+        Ok(())
+    }
+
+    fn push_slip(&mut self, byte: u8) -> Result<(), EncodingError> {
+        if self.escaped {
+            self.escaped = false;
+
+            match byte {
+                SLIP_ESC_END => self.store(SLIP_END),
+                SLIP_ESC_ESC => self.store(SLIP_ESC),
+                _ => Err(EncodingError::InvalidData),
+            }
+        } else if byte == SLIP_ESC {
+            self.escaped = true;
+            Ok(())
+        } else {
+            self.store(byte)
+        }
+    }
+
+    fn store(&mut self, byte: u8) -> Result<(), EncodingError> {
+        if self.frame.len() >= self.max_length.saturating_add(self.format.trailer.length()) {
+            return Err(EncodingError::TooLong {
+                max_length: self.max_length,
+            });
+        }
+
+        self.frame.push(byte);
+        Ok(())
+    }
+
+    fn end_frame(&mut self) -> Option<Result<&[u8], EncodingError>> {
+        let started = core::mem::take(&mut self.started);
+        let error = self.error.take();
+        let cut_short = self.remaining > 0 || self.escaped;
+
+        self.code = 0;
+        self.remaining = 0;
+        self.escaped = false;
+
+        if !started {
+            return None;
+        }
+
+        if let Some(error) = error {
+            return Some(Err(error));
+        }
+
+        if cut_short {
+            return Some(Err(EncodingError::InvalidData));
+        }
+
+        let trailer = self.format.trailer;
+        let Some(split) = self.frame.len().checked_sub(trailer.length()) else {
+            return Some(Err(EncodingError::UnexpectedEnd));
+        };
+
+        let (payload, received) = self.frame.split_at(split);
+
+        if trailer.compute(payload)[..trailer.length()] != *received {
+            return Some(Err(EncodingError::ChecksumMismatch));
+        }
+
+        Some(Ok(payload))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::hashing::crc::{CRC_16_IBM_3740, CRC_32_ISO_HDLC};
+
+    fn decode_all(decoder: &mut FrameDecoder, input: &[u8]) -> Vec<Result<Vec<u8>, EncodingError>> {
+        input
+            .iter()
+            .filter_map(|&byte| decoder.push(byte).map(|frame| frame.map(<[u8]>::to_vec)))
+            .collect()
+    }
+
+    #[test]
+    fn test_cobs_encoding() {
+        let format = FrameFormat::new(Framing::Cobs);
+        let encode = |payload: &[u8]| {
+            let mut output = Vec::new();
+            format.encode(payload, &mut output).expect("vector output");
+            output
+        };
+
+        assert_eq!(encode(b""), [0x01, 0x00]);
+        assert_eq!(encode(&[0x00]), [0x01, 0x01, 0x00]);
+        assert_eq!(
+            encode(&[0x11, 0x22, 0x00, 0x33]),
+            [0x03, 0x11, 0x22, 0x02, 0x33, 0x00]
+        );
+
+        let long: Vec<u8> = (1..=255).collect();
+        let encoded = encode(&long);
+        assert_eq!((encoded[0], encoded[255], encoded.len()), (0xff, 0x02, 258));
+        assert!(encoded.len() <= format.max_encoded_length(long.len()));
+
+        for payload in [
+            &b""[..],
+            &[0, 0],
+            &[0x11, 0x22, 0x00, 0x33],
+            &long,
+            &[0; 300],
+        ] {
+            assert_eq!(format.decode(&encode(payload)).as_deref(), Ok(payload));
+        }
+    }
+
+    #[test]
+    fn test_slip_encoding() {
+        let format = FrameFormat::new(Framing::Slip).with_trailer(Trailer::Crc32(CRC_32_ISO_HDLC));
+        let payload = [0x01, SLIP_END, SLIP_ESC, 0x02];
+        let mut output = Vec::new();
+
+        format.encode(&payload, &mut output).expect("vector output");
+        assert_eq!(
+            output[..7],
+            [
+                SLIP_END,
+                0x01,
+                SLIP_ESC,
+                SLIP_ESC_END,
+                SLIP_ESC,
+                SLIP_ESC_ESC,
+                0x02
+            ]
+        );
+        assert_eq!(format.decode(&output), Ok(payload.to_vec()));
+    }
+
+    #[test]
+    fn test_decoder_recovers_from_bad_frames() {
+        let format = FrameFormat::new(Framing::Cobs).with_trailer(Trailer::Crc16(CRC_16_IBM_3740));
+        let mut stream = vec![0, 0x37, 0x42, 0];
+
+        format.encode(b"first", &mut stream).expect("vector output");
+        let corrupt = stream.len() + 2;
+        format
+            .encode(b"second", &mut stream)
+            .expect("vector output");
+        stream[corrupt] ^= 0x01;
+        format.encode(&[7; 40], &mut stream).expect("vector output");
+        format.encode(b"last", &mut stream).expect("vector output");
+
+        let mut decoder = FrameDecoder::new(format, 32);
+        let (head, tail) = stream.split_at(9);
+        let mut frames = decode_all(&mut decoder, head);
+        assert!(decoder.in_frame());
+        frames.extend(decode_all(&mut decoder, tail));
+
+        assert_eq!(
+            frames,
+            [
+                Err(EncodingError::InvalidData),
+                Ok(b"first".to_vec()),
+                Err(EncodingError::ChecksumMismatch),
+                Err(EncodingError::TooLong { max_length: 32 }),
+                Ok(b"last".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_malformed_slip_frames() {
+        let format = FrameFormat::new(Framing::Slip).with_trailer(Trailer::Crc16(CRC_16_IBM_3740));
+        let mut decoder = FrameDecoder::new(format, 8);
+
+        assert_eq!(
+            decode_all(&mut decoder, &[SLIP_END, SLIP_END, 0x01, SLIP_END]),
+            [Err(EncodingError::UnexpectedEnd)]
+        );
+        assert_eq!(
+            decode_all(&mut decoder, &[SLIP_ESC, 0x01, SLIP_END]),
+            [Err(EncodingError::InvalidData)]
+        );
+        assert_eq!(
+            decode_all(&mut decoder, &[0x01, SLIP_ESC, SLIP_END]),
+            [Err(EncodingError::InvalidData)]
+        );
+        assert_eq!(
+            format.decode(&[SLIP_END, 0x01]),
+            Err(EncodingError::UnexpectedEnd)
+        );
+
+        decoder.push(0x01);
+        decoder.reset();
+        assert!(!decoder.in_frame());
+    }
+}
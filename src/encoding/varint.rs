@@ -0,0 +1,408 @@
+//! LEB128 variable-length integers and zigzag encoding.
+//!
+//! [`Leb128`] writes unsigned integers as unsigned LEB128 and signed integers as signed
+//! LEB128, seven bits per byte with the high bit marking that more bytes follow. This is
+//! the format of DWARF, WebAssembly and, for unsigned values, Protocol Buffers varints.
+//!
+//! Protocol Buffers encodes signed `sint` fields by first mapping them to unsigned
+//! values with [`ZigZag`], so that numbers close to zero stay short whatever their sign.
+//!
+//! Decoding rejects encodings longer than [`Leb128::MAX_LENGTH`] bytes and values that do
+//! not fit in the requested type, so malformed input cannot make a decoder read forever.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::encoding::varint::{self, Leb128, ZigZag};
+//!
+//! let mut buffer = Vec::new();
+//! 300_u32.encode(&mut buffer).unwrap();
+//! (-2_i64).zigzag_encode().encode(&mut buffer).unwrap();
+//! assert_eq!(buffer, [0xac, 0x02, 0x03]);
+//!
+//! let mut input = buffer.as_slice();
+//! assert_eq!(u32::decode(&mut input), Ok(300));
+//! assert_eq!(i64::zigzag_decode(u64::decode(&mut input).unwrap()), -2);
+//!
+//! assert_eq!(varint::decode::<i32>(&[0x7e]), Ok((-2, 1)));
+//! ```
+
+use super::{ByteReader, ByteWriter, SliceWriter};
+use crate::errors::EncodingError;
+
+/// An integer with a LEB128 encoding.
+pub trait Leb128: Sized {
+    /// The maximum number of bytes in an encoding of this type.
+    const MAX_LENGTH: usize;
+
+    /// Writes the value to `writer` and returns the number of bytes written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodingError`] if the writer cannot take the encoded bytes.
+    fn encode<W: ByteWriter + ?Sized>(self, writer: &mut W) -> Result<usize, EncodingError>;
+
+    /// Reads a value from `reader`, consuming exactly the bytes of its encoding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodingError::UnexpectedEnd`] if the input ends before the value does,
+    /// [`EncodingError::TooLong`] if the encoding has more than
+    /// [`MAX_LENGTH`](Self::MAX_LENGTH) bytes and [`EncodingError::Overflow`] if the
+    /// value does not fit in the type.
+    fn decode<R: ByteReader + ?Sized>(reader: &mut R) -> Result<Self, EncodingError>;
+
+    /// Returns the number of bytes in the encoding of the value.
+    fn encoded_length(self) -> usize;
+}
+
+/// A [`ByteWriter`] that only counts bytes.
+struct LengthCounter(usize);
+
+impl ByteWriter for LengthCounter {
+    fn write_byte(&mut self, _byte: u8) -> Result<(), EncodingError> {
+        self.0 += 1;
+        Ok(())
+    }
+}
+
+/// Returns the next byte of an encoding, failing if the input has ended.
+fn next_byte<R: ByteReader + ?Sized>(reader: &mut R) -> Result<u8, EncodingError> {
+    reader.read_byte().ok_or(EncodingError::UnexpectedEnd)
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {
+        $(
+            impl Leb128 for $ty {
+                const MAX_LENGTH: usize = <$ty>::BITS.div_ceil(7) as usize;
+
+                fn encode<W: ByteWriter + ?Sized>(
+                    self,
+                    writer: &mut W,
+                ) -> Result<usize, EncodingError> {
+                    let mut value = self;
+                    let mut length = 0;
+
+                    loop {
+                        #[allow(clippy::cast_possible_truncation)] // Masked to seven bits.
+                        let byte = (value & 0x7f) as u8;
+                        value >>= 7;
+                        length += 1;
+
+                        if value == 0 {
+                            writer.write_byte(byte)?;
+                            return Ok(length);
+                        }
+
+                        writer.write_byte(byte | 0x80)?;
+                    }
+                }
+
+                fn decode<R: ByteReader + ?Sized>(reader: &mut R) -> Result<Self, EncodingError> {
+                    let mut value: Self = 0;
+
+                    for index in 0..Self::MAX_LENGTH {
+                        let byte = next_byte(reader)?;
+                        let group = Self::from(byte & 0x7f);
+                        #[allow(clippy::cast_possible_truncation)] // At most 126.
+                        let shift = (index * 7) as u32;
+
+                        // The last group may only use the bits the type has left.
+                        if shift + 7 > Self::BITS && (byte & 0x7f) >> (Self::BITS - shift) != 0 {
+                            return Err(EncodingError::Overflow);
+                        }
+
+                        value |= group << shift;
+
+                        if byte & 0x80 == 0 {
+                            return Ok(value);
+                        }
+                    }
+
+                    Err(EncodingError::TooLong {
+                        max_length: Self::MAX_LENGTH,
+                    })
+                }
+
+                fn encoded_length(self) -> usize {
+                    (<$ty>::BITS - self.leading_zeros()).div_ceil(7).max(1) as usize
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {
+        $(
+            impl Leb128 for $ty {
+                const MAX_LENGTH: usize = <$ty>::BITS.div_ceil(7) as usize;
+
+                fn encode<W: ByteWriter + ?Sized>(
+                    self,
+                    writer: &mut W,
+                ) -> Result<usize, EncodingError> {
+                    let mut value = self;
+                    let mut length = 0;
+
+                    loop {
+                        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                        let byte = (value & 0x7f) as u8;
+                        value >>= 7;
+                        length += 1;
+
+                        // Stop once the remaining bits only repeat the sign of this byte.
+                        let sign_bit_set = byte & 0x40 != 0;
+
+                        if (value == 0 && !sign_bit_set) || (value == -1 && sign_bit_set) {
+                            writer.write_byte(byte)?;
+                            return Ok(length);
+                        }
+
+                        writer.write_byte(byte | 0x80)?;
+                    }
+                }
+
+                fn decode<R: ByteReader + ?Sized>(reader: &mut R) -> Result<Self, EncodingError> {
+                    let mut value: Self = 0;
+
+                    for index in 0..Self::MAX_LENGTH {
+                        let byte = next_byte(reader)?;
+                        // Seven bits fit in every signed type.
+                        #[allow(clippy::cast_possible_wrap, clippy::cast_lossless)]
+                        let group = (byte & 0x7f) as Self;
+                        #[allow(clippy::cast_possible_truncation)] // At most 126.
+                        let shift = (index * 7) as u32;
+
+                        // Bits past the type's width must repeat its sign bit.
+                        if shift + 7 > Self::BITS {
+                            let excess = (byte & 0x7f) >> (Self::BITS - shift - 1);
+
+                            if excess != 0 && excess != 0x7f >> (Self::BITS - shift - 1) {
+                                return Err(EncodingError::Overflow);
+                            }
+                        }
+
+                        value |= group << shift;
+
+                        if byte & 0x80 == 0 {
+                            if shift + 7 < Self::BITS && byte & 0x40 != 0 {
+                                value |= -1 << (shift + 7);
+                            }
+
+                            return Ok(value);
+                        }
+                    }
+
+                    Err(EncodingError::TooLong {
+                        max_length: Self::MAX_LENGTH,
+                    })
+                }
+
+                fn encoded_length(self) -> usize {
+                    let mut counter = LengthCounter(0);
+                    let _ = self.encode(&mut counter);
+                    counter.0
+                }
+            }
+        )*
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_signed!(i8, i16, i32, i64, i128, isize);
+
+/// A signed integer with a zigzag mapping to an unsigned integer of the same width.
+///
+/// Zigzag encoding interleaves positive and negative values, mapping 0, -1, 1, -2, 2 to
+/// 0, 1, 2, 3, 4, so small magnitudes of either sign become small unsigned values.
+pub trait ZigZag: Sized {
+    /// The unsigned integer type of the same width.
+    type Unsigned;
+
+    /// Maps the value to its zigzag-encoded unsigned form.
+    fn zigzag_encode(self) -> Self::Unsigned;
+
+    /// Maps a zigzag-encoded value back to the signed value.
+    fn zigzag_decode(value: Self::Unsigned) -> Self;
+}
+
+macro_rules! impl_zigzag {
+    ($($signed:ty => $unsigned:ty),*) => {
+        $(
+            impl ZigZag for $signed {
+                type Unsigned = $unsigned;
+
+                #[allow(clippy::cast_sign_loss)] // Reinterprets the bits.
+                fn zigzag_encode(self) -> $unsigned {
+                    ((self << 1) ^ (self >> (<$signed>::BITS - 1))) as $unsigned
+                }
+
+                #[allow(clippy::cast_possible_wrap)] // Reinterprets the bits.
+                fn zigzag_decode(value: $unsigned) -> Self {
+                    ((value >> 1) as Self) ^ -((value & 1) as Self)
+                }
+            }
+        )*
+    };
+}
+
+impl_zigzag!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    i128 => u128,
+    isize => usize
+);
+
+/// Encodes `value` into the start of `buffer` and returns the number of bytes written.
+///
+/// # Errors
+///
+/// Returns [`EncodingError::BufferTooSmall`] if the encoding does not fit in `buffer`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::encoding::varint;
+///
+/// let mut buffer = [0; 10];
+/// let length = varint::encode(624_485_u64, &mut buffer).unwrap();
+/// assert_eq!(&buffer[..length], [0xe5, 0x8e, 0x26]);
+/// ```
+pub fn encode<T: Leb128>(value: T, buffer: &mut [u8]) -> Result<usize, EncodingError> {
+    value.encode(&mut SliceWriter::new(buffer))
+}
+
+/// Decodes a value from the start of `bytes` and returns it with the number of bytes it
+/// took.
+///
+/// # Errors
+///
+/// Returns an [`EncodingError`] under the same conditions as [`Leb128::decode`].
+pub fn decode<T: Leb128>(bytes: &[u8]) -> Result<(T, usize), EncodingError> {
+    let mut input = bytes;
+    let value = T::decode(&mut input)?;
+    Ok((value, bytes.len() - input.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn encoded<T: Leb128>(value: T) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        value.encode(&mut bytes).expect("a vector grows as needed");
+        bytes
+    }
+
+    #[test]
+    fn test_unsigned() {
+        assert_eq!(encoded(0_u32), [0x00]);
+        assert_eq!(encoded(127_u32), [0x7f]);
+        assert_eq!(encoded(128_u32), [0x80, 0x01]);
+        assert_eq!(encoded(u64::MAX).len(), u64::MAX_LENGTH);
+        assert_eq!(
+            encoded(u128::MAX)[18],
+            0x03,
+            "the last group of a u128 holds two bits"
+        );
+
+        for value in [
+            0,
+            1,
+            127,
+            128,
+            16_383,
+            16_384,
+            u64::from(u32::MAX),
+            u64::MAX,
+        ] {
+            let bytes = encoded(value);
+            assert_eq!(value.encoded_length(), bytes.len());
+            assert_eq!(decode::<u64>(&bytes), Ok((value, bytes.len())));
+        }
+
+        assert_eq!(decode::<u8>(&[0xff, 0x01]), Ok((255, 2)));
+        assert_eq!(decode::<u8>(&[0x80, 0x02]), Err(EncodingError::Overflow));
+        assert_eq!(
+            decode::<u32>(&[0x80, 0x80, 0x80, 0x80, 0x10]),
+            Err(EncodingError::Overflow)
+        );
+        assert_eq!(decode::<u128>(&encoded(u128::MAX)), Ok((u128::MAX, 19)));
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(encoded(0_i32), [0x00]);
+        assert_eq!(encoded(-1_i32), [0x7f]);
+        assert_eq!(encoded(63_i32), [0x3f]);
+        assert_eq!(encoded(64_i32), [0xc0, 0x00]);
+        assert_eq!(encoded(-64_i32), [0x40]);
+        assert_eq!(encoded(-123_456_i64), [0xc0, 0xbb, 0x78]);
+
+        for value in [0, 1, -1, 63, 64, -64, -65, i64::MIN, i64::MAX] {
+            let bytes = encoded(value);
+            assert_eq!(value.encoded_length(), bytes.len());
+            assert_eq!(decode::<i64>(&bytes), Ok((value, bytes.len())));
+        }
+
+        for value in [i8::MIN, -1, 0, 1, i8::MAX] {
+            assert_eq!(
+                decode::<i8>(&encoded(value)),
+                Ok((value, value.encoded_length()))
+            );
+        }
+
+        for value in [i128::MIN, i128::MAX] {
+            assert_eq!(decode::<i128>(&encoded(value)), Ok((value, 19)));
+        }
+
+        assert_eq!(
+            decode::<i8>(&encoded(128_i32)),
+            Err(EncodingError::Overflow)
+        );
+        assert_eq!(
+            decode::<i8>(&encoded(-129_i32)),
+            Err(EncodingError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(decode::<u64>(&[]), Err(EncodingError::UnexpectedEnd));
+        assert_eq!(
+            decode::<u64>(&[0x80, 0x80]),
+            Err(EncodingError::UnexpectedEnd)
+        );
+        assert_eq!(decode::<i32>(&[0xff]), Err(EncodingError::UnexpectedEnd));
+        assert_eq!(
+            decode::<u16>(&[0x80, 0x80, 0x80, 0x00]),
+            Err(EncodingError::TooLong { max_length: 3 })
+        );
+        assert_eq!(decode::<u16>(&[0x80, 0x80, 0x00]), Ok((0, 3)));
+
+        let mut buffer = [0; 2];
+        assert_eq!(
+            encode(u32::MAX, &mut buffer),
+            Err(EncodingError::BufferTooSmall { capacity: 2 })
+        );
+    }
+
+    #[test]
+    fn test_zigzag() {
+        let mapped: Vec<u32> = [0, -1, 1, -2, 2, i32::MAX, i32::MIN]
+            .into_iter()
+            .map(ZigZag::zigzag_encode)
+            .collect();
+
+        assert_eq!(mapped, [0, 1, 2, 3, 4, u32::MAX - 1, u32::MAX]);
+        assert_eq!(i32::zigzag_decode(u32::MAX), i32::MIN);
+        assert_eq!(i64::zigzag_decode((-300_i64).zigzag_encode()), -300);
+        assert_eq!(encoded((-64_i64).zigzag_encode()), [0x7f]);
+    }
+}
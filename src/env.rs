@@ -0,0 +1,408 @@
+//! Reading environment variables, with typed parsing and a mock for tests.
+//!
+//! The [`Environment`] trait abstracts over where variables come from. On Unix,
+//! [`SystemEnvironment`] reads the process environment, and the free functions in this
+//! module are shortcuts for it. [`MockEnvironment`] keeps variables in memory, so code
+//! that takes an `impl Environment` can be tested without touching the process.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::env::{Environment, MockEnvironment};
+//!
+//! let env = MockEnvironment::new()
+//!     .with_var("APP_PORT", "8080")
+//!     .with_var("APP_VERBOSE", "yes")
+//!     .with_var("HOME", "/home/libx");
+//!
+//! let app = env.with_prefix("APP_");
+//!
+//! assert_eq!(app.get_parsed::<u16>("PORT"), Ok(Some(8080)));
+//! assert_eq!(app.get_bool("VERBOSE"), Ok(Some(true)));
+//! assert_eq!(app.get_bool("COLOR"), Ok(None));
+//! assert_eq!(app.vars().len(), 2);
+//! ```
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use crate::errors::ParseError;
+
+/// A source of environment variables.
+pub trait Environment {
+    /// Returns the value of the variable `name`, or `None` if it is unset.
+    fn var(&self, name: &str) -> Option<String>;
+
+    /// Returns every variable as `(name, value)` pairs.
+    fn vars(&self) -> Vec<(String, String)>;
+
+    /// Returns the variable `name` parsed as `T`, or `None` if it is unset.
+    ///
+    /// Leading and trailing whitespace is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error of `T` if the variable is set but does not parse.
+    fn get_parsed<T: FromStr>(&self, name: &str) -> Result<Option<T>, T::Err>
+    where
+        Self: Sized,
+    {
+        self.var(name).map(|value| value.trim().parse()).transpose()
+    }
+
+    /// Returns the variable `name` as a boolean, or `None` if it is unset.
+    ///
+    /// See [`parse_bool`] for the accepted values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidSyntax`] if the variable is set but is not a boolean.
+    fn get_bool(&self, name: &str) -> Result<Option<bool>, ParseError>
+    where
+        Self: Sized,
+    {
+        self.var(name).map(|value| parse_bool(&value)).transpose()
+    }
+
+    /// Returns a view of the variables whose names start with `prefix`, with the prefix
+    /// removed from their names.
+    fn with_prefix<'a>(&'a self, prefix: &'a str) -> Prefixed<'a, Self>
+    where
+        Self: Sized,
+    {
+        Prefixed {
+            environment: self,
+            prefix,
+        }
+    }
+}
+
+/// Parses a boolean the way configuration variables usually spell them.
+///
+/// `1`, `true`, `yes`, `on` and `y` are `true`; `0`, `false`, `no`, `off`, `n` and the
+/// empty string are `false`. Case and surrounding whitespace are ignored.
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidSyntax`] for any other value.
+///
+/// # Examples
+///
+/// ```
+/// use libx::env::parse_bool;
+///
+/// assert_eq!(parse_bool(" Yes "), Ok(true));
+/// assert_eq!(parse_bool("OFF"), Ok(false));
+/// assert!(parse_bool("maybe").is_err());
+/// ```
+pub fn parse_bool(value: &str) -> Result<bool, ParseError> {
+    const TRUE: [&str; 5] = ["1", "true", "yes", "on", "y"];
+    const FALSE: [&str; 6] = ["0", "false", "no", "off", "n", ""];
+
+    let value = value.trim();
+
+    if TRUE.iter().any(|word| word.eq_ignore_ascii_case(value)) {
+        Ok(true)
+    } else if FALSE.iter().any(|word| word.eq_ignore_ascii_case(value)) {
+        Ok(false)
+    } else {
+        Err(ParseError::InvalidSyntax)
+    }
+}
+
+/// The variables of an [`Environment`] that share a prefix, created by
+/// [`Environment::with_prefix`].
+#[derive(Debug, Clone, Copy)]
+pub struct Prefixed<'a, E> {
+    environment: &'a E,
+    prefix: &'a str,
+}
+
+impl<E> Prefixed<'_, E> {
+    /// Returns the prefix that is prepended to names.
+    #[must_use]
+    pub const fn prefix(&self) -> &str {
+        self.prefix
+    }
+}
+
+impl<E: Environment> Environment for Prefixed<'_, E> {
+    fn var(&self, name: &str) -> Option<String> {
+        let mut full_name = String::with_capacity(self.prefix.len() + name.len());
+        full_name.push_str(self.prefix);
+        full_name.push_str(name);
+        self.environment.var(&full_name)
+    }
+
+    fn vars(&self) -> Vec<(String, String)> {
+        self.environment
+            .vars()
+            .into_iter()
+            .filter_map(|(name, value)| {
+                let name = name.strip_prefix(self.prefix)?.to_string();
+                Some((name, value))
+            })
+            .collect()
+    }
+}
+
+/// Variables kept in memory, for tests and sandboxed configuration.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MockEnvironment {
+    vars: BTreeMap<String, String>,
+}
+
+impl MockEnvironment {
+    /// Creates an environment without variables.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            vars: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the environment with the variable `name` set to `value`.
+    #[must_use]
+    pub fn with_var(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set_var(name, value);
+        self
+    }
+
+    /// Sets the variable `name` to `value`, returning its previous value.
+    pub fn set_var(&mut self, name: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.vars.insert(name.into(), value.into())
+    }
+
+    /// Unsets the variable `name`, returning its value.
+    pub fn remove_var(&mut self, name: &str) -> Option<String> {
+        self.vars.remove(name)
+    }
+}
+
+impl Environment for MockEnvironment {
+    fn var(&self, name: &str) -> Option<String> {
+        self.vars.get(name).cloned()
+    }
+
+    /// Returns the variables sorted by name.
+    fn vars(&self) -> Vec<(String, String)> {
+        self.vars
+            .iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    }
+}
+
+impl<N: Into<String>, V: Into<String>> FromIterator<(N, V)> for MockEnvironment {
+    fn from_iter<I: IntoIterator<Item = (N, V)>>(iter: I) -> Self {
+        Self {
+            vars: iter
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(unix)]
+pub use system::{SystemEnvironment, get_bool, get_parsed, var, vars, with_prefix};
+
+#[cfg(unix)]
+mod system {
+    use alloc::{ffi::CString, string::String, vec::Vec};
+    use core::{
+        ffi::{CStr, c_char},
+        str::FromStr,
+    };
+
+    use super::{Environment, Prefixed};
+    use crate::errors::ParseError;
+
+    unsafe extern "C" {
+        static environ: *const *const c_char;
+    }
+
+    /// The environment of the current process.
+    ///
+    /// Variables whose name or value is not valid UTF-8 are treated as unset. Like the C
+    /// functions it is built on, reading the environment is not synchronized with
+    /// threads that modify it.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct SystemEnvironment;
+
+    static SYSTEM: SystemEnvironment = SystemEnvironment;
+
+    impl Environment for SystemEnvironment {
+        /// Returns `None` for names that cannot be variable names: empty names and names
+        /// containing `=` or NUL.
+        fn var(&self, name: &str) -> Option<String> {
+            if name.is_empty() || name.contains('=') {
+                return None;
+            }
+
+            let name = CString::new(name).ok()?;
+            // SAFETY: `name` is a valid C string, and the returned pointer is either null
+            // or points to a C string that is copied before returning.
+            let value = unsafe { libc::getenv(name.as_ptr()) };
+
+            if value.is_null() {
+                return None;
+            }
+
+            // SAFETY: `getenv` returned a non-null pointer to a C string.
+            let value = unsafe { CStr::from_ptr(value) };
+            value.to_str().ok().map(String::from)
+        }
+
+        fn vars(&self) -> Vec<(String, String)> {
+            let mut vars = Vec::new();
+            // SAFETY: `environ` is a null-terminated array of C strings maintained by the
+            // C library.
+            let mut entry = unsafe { environ };
+
+            if entry.is_null() {
+                return vars;
+            }
+
+            // SAFETY: the array is null-terminated, so every pointer up to and including
+            // the terminator may be read.
+            while let Some(pair) = unsafe { (*entry).as_ref() } {
+                // SAFETY: each entry is a C string.
+                let pair = unsafe { CStr::from_ptr(pair) };
+
+                // A leading `=` belongs to the name, as in `=C:` on some systems.
+                if let Ok(pair) = pair.to_str()
+                    && let Some(index) = pair.get(1..).and_then(|rest| rest.find('='))
+                {
+                    let (name, value) = pair.split_at(index + 1);
+                    vars.push((String::from(name), String::from(&value[1..])));
+                }
+
+                // SAFETY: the current entry was not the terminator.
+                entry = unsafe { entry.add(1) };
+            }
+
+            vars
+        }
+    }
+
+    /// Returns the process variable `name`, or `None` if it is unset.
+    #[must_use]
+    pub fn var(name: &str) -> Option<String> {
+        SYSTEM.var(name)
+    }
+
+    /// Returns every process variable as `(name, value)` pairs.
+    #[must_use]
+    pub fn vars() -> Vec<(String, String)> {
+        SYSTEM.vars()
+    }
+
+    /// Returns the process variable `name` parsed as `T`, or `None` if it is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns the parse error of `T` if the variable is set but does not parse.
+    pub fn get_parsed<T: FromStr>(name: &str) -> Result<Option<T>, T::Err> {
+        SYSTEM.get_parsed(name)
+    }
+
+    /// Returns the process variable `name` as a boolean, or `None` if it is unset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::InvalidSyntax`] if the variable is set but is not a boolean.
+    pub fn get_bool(name: &str) -> Result<Option<bool>, ParseError> {
+        SYSTEM.get_bool(name)
+    }
+
+    /// Returns a view of the process variables whose names start with `prefix`.
+    #[must_use]
+    pub fn with_prefix(prefix: &str) -> Prefixed<'_, SystemEnvironment> {
+        SYSTEM.with_prefix(prefix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bool() {
+        for value in ["1", "TRUE", "Yes", " on ", "y"] {
+            assert_eq!(parse_bool(value), Ok(true), "{value:?}");
+        }
+
+        for value in ["0", "False", "NO", "off", "n", "", "  "] {
+            assert_eq!(parse_bool(value), Ok(false), "{value:?}");
+        }
+
+        assert_eq!(parse_bool("2"), Err(ParseError::InvalidSyntax));
+        assert_eq!(parse_bool("yess"), Err(ParseError::InvalidSyntax));
+    }
+
+    #[test]
+    fn test_mock_environment() {
+        let mut env: MockEnvironment = [("B", "2"), ("A", " 1 ")].into_iter().collect();
+
+        assert_eq!(env.var("A").as_deref(), Some(" 1 "));
+        assert_eq!(env.get_parsed::<i32>("A"), Ok(Some(1)));
+        assert!(
+            env.get_parsed::<i32>("B=")
+                .is_ok_and(|value| value.is_none())
+        );
+        assert_eq!(
+            env.vars(),
+            [("A".into(), " 1 ".into()), ("B".into(), "2".into())]
+        );
+
+        assert_eq!(env.set_var("B", "x"), Some("2".into()));
+        assert!(env.get_parsed::<i32>("B").is_err());
+        assert_eq!(env.remove_var("B"), Some("x".into()));
+        assert_eq!(env.var("B"), None);
+    }
+
+    #[test]
+    fn test_prefixed_view() {
+        let env = MockEnvironment::new()
+            .with_var("APP_NAME", "libx")
+            .with_var("APP_DEBUG", "off")
+            .with_var("APPLE", "fruit");
+        let app = env.with_prefix("APP_");
+
+        assert_eq!(app.prefix(), "APP_");
+        assert_eq!(app.var("NAME").as_deref(), Some("libx"));
+        assert_eq!(app.get_bool("DEBUG"), Ok(Some(false)));
+        assert_eq!(app.var("LE"), None);
+        assert_eq!(
+            app.vars(),
+            [
+                ("DEBUG".into(), "off".into()),
+                ("NAME".into(), "libx".into())
+            ]
+        );
+
+        let nested = app.with_prefix("NA");
+        assert_eq!(nested.var("ME").as_deref(), Some("libx"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_system_environment() {
+        let all = vars();
+
+        for (name, value) in all.iter().take(10) {
+            assert!(!name.contains('='));
+            assert_eq!(var(name).as_ref(), Some(value));
+        }
+
+        assert_eq!(var(""), None);
+        assert_eq!(var("PATH=/bin"), None);
+        assert_eq!(var("NUL\0"), None);
+        assert_eq!(with_prefix("").vars().len(), all.len());
+    }
+}
@@ -0,0 +1,1450 @@
+//! Error types shared across the crate.
+//!
+//! Each subsystem reports failures through its own enum so callers can match on the
+//! precise cause. All of them implement [`Display`](core::fmt::Display) and
+//! [`core::error::Error`].
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, time::Duration};
+
+/// An error produced by a collection operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollectionError {
+    /// The collection is full and cannot grow past its capacity.
+    CapacityExceeded {
+        /// The capacity of the collection.
+        capacity: usize,
+    },
+
+    /// An index was outside the bounds of the collection.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: usize,
+
+        /// The length of the collection.
+        len: usize,
+    },
+
+    /// A range was reversed or extended past the end of the collection.
+    RangeOutOfBounds {
+        /// The start of the offending range.
+        start: usize,
+
+        /// The end of the offending range.
+        end: usize,
+
+        /// The length of the collection.
+        len: usize,
+    },
+
+    /// The operation requires a non-empty collection.
+    Empty,
+
+    /// The allocator could not provide the memory the operation needed.
+    AllocationFailed,
+
+    /// A chunk or window size of zero was given.
+    ZeroSize,
+}
+
+impl fmt::Display for CollectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CapacityExceeded { capacity } => {
+                write!(f, "maximum capacity of {capacity} reached")
+            }
+            Self::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} out of bounds for length {len}")
+            }
+            Self::RangeOutOfBounds { start, end, len } => {
+                write!(f, "range {start}..{end} out of bounds for length {len}")
+            }
+            Self::Empty => f.write_str("collection is empty"),
+            Self::AllocationFailed => f.write_str("memory allocation failed"),
+            Self::ZeroSize => f.write_str("chunk or window size is zero"),
+        }
+    }
+}
+
+impl core::error::Error for CollectionError {}
+
+/// An error produced while parsing text into a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+
+    /// The input contained a character that is not valid at its position.
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+
+        /// The byte offset of the character in the input.
+        position: usize,
+    },
+
+    /// The input did not match the expected syntax.
+    InvalidSyntax,
+
+    /// The input described a value that cannot be represented.
+    OutOfRange,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("cannot parse a value from empty input"),
+            Self::InvalidCharacter {
+                character,
+                position,
+            } => write!(f, "invalid character {character:?} at position {position}"),
+            Self::InvalidSyntax => f.write_str("invalid syntax"),
+            Self::OutOfRange => f.write_str("value out of range"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// An error produced while formatting a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FormatError {
+    /// The output buffer was too small to hold the formatted value.
+    BufferTooSmall {
+        /// The number of bytes the buffer could hold.
+        capacity: usize,
+    },
+
+    /// The formatter configuration cannot represent the value.
+    Unsupported,
+
+    /// Writing to the underlying [`fmt::Write`] sink failed.
+    Write,
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall { capacity } => {
+                write!(f, "output does not fit in a buffer of {capacity} bytes")
+            }
+            Self::Unsupported => f.write_str("value cannot be formatted with this configuration"),
+            Self::Write => f.write_str("failed to write formatted output"),
+        }
+    }
+}
+
+impl core::error::Error for FormatError {}
+
+impl From<fmt::Error> for FormatError {
+    fn from(_: fmt::Error) -> Self {
+        Self::Write
+    }
+}
+
+/// An error produced while formatting a message template.
+///
+/// Positions are byte offsets into the template.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemplateError {
+    /// The template is not well formed.
+    InvalidSyntax {
+        /// The byte offset where parsing failed.
+        position: usize,
+    },
+
+    /// A placeholder names an argument that was not supplied.
+    MissingArgument {
+        /// The byte offset of the placeholder.
+        position: usize,
+    },
+
+    /// A placeholder uses an argument of the wrong kind, such as text in a plural.
+    MismatchedArgument {
+        /// The byte offset of the placeholder.
+        position: usize,
+    },
+
+    /// Writing to the underlying [`fmt::Write`] sink failed.
+    Write,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax { position } => {
+                write!(f, "invalid template syntax at position {position}")
+            }
+            Self::MissingArgument { position } => {
+                write!(f, "missing argument for placeholder at position {position}")
+            }
+            Self::MismatchedArgument { position } => {
+                write!(
+                    f,
+                    "wrong kind of argument for placeholder at position {position}"
+                )
+            }
+            Self::Write => f.write_str("failed to write formatted output"),
+        }
+    }
+}
+
+impl core::error::Error for TemplateError {}
+
+impl From<fmt::Error> for TemplateError {
+    fn from(_: fmt::Error) -> Self {
+        Self::Write
+    }
+}
+
+/// An error produced by a cipher or encoding scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipherError {
+    /// The key cannot be used with this cipher.
+    InvalidKey,
+
+    /// The input contained a symbol the cipher cannot process.
+    InvalidSymbol {
+        /// The offending character.
+        character: char,
+
+        /// The byte offset of the character in the input.
+        position: usize,
+    },
+
+    /// The input ended in the middle of an encoded unit.
+    Truncated,
+
+    /// A group of valid symbols did not encode anything.
+    UnknownSequence {
+        /// The byte offset of the group in the input.
+        position: usize,
+    },
+
+    /// A cover text had too few letters to hide a message in.
+    CoverTooShort {
+        /// The number of letters the message needs.
+        letters_needed: usize,
+    },
+
+    /// A key had the wrong number of letters.
+    KeyLength {
+        /// The number of letters the cipher needs.
+        expected: usize,
+
+        /// The number of letters in the key.
+        actual: usize,
+    },
+
+    /// A letter appeared twice in a key that must use every letter once.
+    DuplicateLetter {
+        /// The repeated letter.
+        letter: char,
+    },
+
+    /// A key cannot be inverted modulo the size of the alphabet, so it could not decrypt.
+    KeyNotInvertible {
+        /// The determinant of the key matrix, or the multiplier of an affine key, reduced
+        /// modulo the size of the alphabet.
+        determinant: u8,
+    },
+}
+
+impl fmt::Display for CipherError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidKey => f.write_str("invalid cipher key"),
+            Self::InvalidSymbol {
+                character,
+                position,
+            } => write!(f, "invalid symbol {character:?} at position {position}"),
+            Self::Truncated => f.write_str("input ended in the middle of an encoded unit"),
+            Self::UnknownSequence { position } => {
+                write!(f, "unknown code at position {position}")
+            }
+            Self::CoverTooShort { letters_needed } => {
+                write!(f, "cover text needs at least {letters_needed} letters")
+            }
+            Self::KeyLength { expected, actual } => {
+                write!(f, "key has {actual} letters, expected {expected}")
+            }
+            Self::DuplicateLetter { letter } => write!(f, "key repeats the letter {letter:?}"),
+            Self::KeyNotInvertible { determinant } => {
+                write!(f, "key with determinant {determinant} is not invertible")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CipherError {}
+
+/// An error produced by a binary encoding such as LEB128.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodingError {
+    /// The input ended in the middle of an encoded value.
+    UnexpectedEnd,
+
+    /// The input is not a valid encoding, such as a back-reference to data that was never
+    /// produced.
+    InvalidData,
+
+    /// A checksum stored with the data does not match the decoded data.
+    ChecksumMismatch,
+
+    /// The decoded value does not fit in the requested type.
+    Overflow,
+
+    /// The encoded value is longer than any valid encoding of the requested type.
+    TooLong {
+        /// The maximum number of bytes in a valid encoding.
+        max_length: usize,
+    },
+
+    /// The output buffer was too small to hold the encoded value.
+    BufferTooSmall {
+        /// The number of bytes the buffer could hold.
+        capacity: usize,
+    },
+
+    /// The sink failed to take the bytes for a reason of its own, such as an I/O error.
+    WriteFailed,
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => f.write_str("input ended in the middle of an encoded value"),
+            Self::InvalidData => f.write_str("input is not a valid encoding"),
+            Self::ChecksumMismatch => f.write_str("checksum does not match the decoded data"),
+            Self::Overflow => f.write_str("decoded value does not fit in the requested type"),
+            Self::TooLong { max_length } => {
+                write!(
+                    f,
+                    "encoded value is longer than the maximum of {max_length} bytes"
+                )
+            }
+            Self::BufferTooSmall { capacity } => {
+                write!(f, "output does not fit in a buffer of {capacity} bytes")
+            }
+            Self::WriteFailed => f.write_str("sink failed to take the bytes"),
+        }
+    }
+}
+
+impl core::error::Error for EncodingError {}
+
+/// An error produced while configuring the global logger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LoggingError {
+    /// A logger has already been registered.
+    AlreadyInitialized,
+}
+
+impl fmt::Display for LoggingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AlreadyInitialized => f.write_str("a logger has already been registered"),
+        }
+    }
+}
+
+impl core::error::Error for LoggingError {}
+
+/// An error produced while parsing command-line arguments.
+///
+/// Arguments are named as they were written, such as `--port` or `-p`, and positional
+/// arguments by their value name, such as `<INPUT>`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CliError {
+    /// An argument matched no flag, option, positional argument or subcommand.
+    UnknownArgument(String),
+
+    /// An option was given without its value.
+    MissingValue(String),
+
+    /// A flag was given a value, as in `--verbose=yes`.
+    UnexpectedValue(String),
+
+    /// A required argument was not given.
+    MissingArgument(String),
+
+    /// A value could not be converted to the requested type.
+    InvalidValue {
+        /// The name of the argument.
+        argument: String,
+
+        /// The value as it was given.
+        value: String,
+    },
+
+    /// `-h` or `--help` was given; the error holds the help text to print.
+    HelpRequested(String),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownArgument(argument) => write!(f, "unexpected argument '{argument}'"),
+            Self::MissingValue(argument) => write!(f, "a value is required for '{argument}'"),
+            Self::UnexpectedValue(argument) => {
+                write!(f, "'{argument}' does not take a value")
+            }
+            Self::MissingArgument(argument) => {
+                write!(f, "the argument '{argument}' is required")
+            }
+            Self::InvalidValue { argument, value } => {
+                write!(f, "invalid value '{value}' for '{argument}'")
+            }
+            Self::HelpRequested(help) => f.write_str(help),
+        }
+    }
+}
+
+impl core::error::Error for CliError {}
+
+/// An error produced while reading or writing a packed binary layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PackingError {
+    /// The input ended before the whole layout could be read.
+    UnexpectedEnd {
+        /// The number of bytes the layout needs.
+        required: usize,
+
+        /// The number of bytes the input holds.
+        available: usize,
+    },
+
+    /// The output buffer was too small to hold the layout.
+    BufferTooSmall {
+        /// The number of bytes the layout needs.
+        required: usize,
+
+        /// The number of bytes the buffer can hold.
+        available: usize,
+    },
+}
+
+impl fmt::Display for PackingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd {
+                required,
+                available,
+            } => write!(
+                f,
+                "expected {required} bytes of input but only {available} are available"
+            ),
+            Self::BufferTooSmall {
+                required,
+                available,
+            } => write!(
+                f,
+                "a buffer of {available} bytes cannot hold the {required} bytes required"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for PackingError {}
+
+/// An error produced while registering a metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MetricsError {
+    /// The name is empty or contains characters other than ASCII letters, digits, `_`
+    /// and `:`, or starts with a digit.
+    InvalidName(&'static str),
+
+    /// A metric with the same name is already registered.
+    DuplicateName(&'static str),
+}
+
+impl fmt::Display for MetricsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName(name) => write!(f, "{name:?} is not a valid metric name"),
+            Self::DuplicateName(name) => write!(f, "a metric named {name:?} is already registered"),
+        }
+    }
+}
+
+impl core::error::Error for MetricsError {}
+
+/// An error produced while parsing or evaluating an arithmetic expression.
+///
+/// Every position is the byte offset in the source of the token at fault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExpressionError {
+    /// The source contained a character that starts no token.
+    UnexpectedCharacter {
+        /// The offending character.
+        character: char,
+
+        /// The byte offset of the character.
+        position: usize,
+    },
+
+    /// A token appeared where the grammar does not allow it.
+    UnexpectedToken {
+        /// The byte offset of the token.
+        position: usize,
+    },
+
+    /// The source ended in the middle of an expression.
+    UnexpectedEnd,
+
+    /// A number literal cannot be represented at the chosen precision, such as a fraction
+    /// in integer arithmetic.
+    InvalidNumber {
+        /// The byte offset of the literal.
+        position: usize,
+    },
+
+    /// A variable has no value.
+    UnknownVariable {
+        /// The byte offset of the variable name.
+        position: usize,
+    },
+
+    /// A function name is not known.
+    UnknownFunction {
+        /// The byte offset of the function name.
+        position: usize,
+    },
+
+    /// A function was called with the wrong number of arguments.
+    ArgumentCount {
+        /// The byte offset of the function name.
+        position: usize,
+
+        /// The number of arguments the function takes.
+        expected: usize,
+
+        /// The number of arguments it was given.
+        actual: usize,
+    },
+
+    /// A division or remainder had a zero divisor.
+    DivisionByZero {
+        /// The byte offset of the operator.
+        position: usize,
+    },
+
+    /// A result does not fit in the chosen precision.
+    Overflow {
+        /// The byte offset of the operator or function.
+        position: usize,
+    },
+
+    /// An operation is not defined for its operands, such as the square root of a
+    /// negative number.
+    Domain {
+        /// The byte offset of the operator or function.
+        position: usize,
+    },
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "unexpected character {character:?} at position {position}"
+            ),
+            Self::UnexpectedToken { position } => {
+                write!(f, "unexpected token at position {position}")
+            }
+            Self::UnexpectedEnd => f.write_str("expression ended unexpectedly"),
+            Self::InvalidNumber { position } => write!(f, "invalid number at position {position}"),
+            Self::UnknownVariable { position } => {
+                write!(f, "unknown variable at position {position}")
+            }
+            Self::UnknownFunction { position } => {
+                write!(f, "unknown function at position {position}")
+            }
+            Self::ArgumentCount {
+                position,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "function at position {position} takes {expected} arguments, got {actual}"
+            ),
+            Self::DivisionByZero { position } => {
+                write!(f, "division by zero at position {position}")
+            }
+            Self::Overflow { position } => write!(f, "overflow at position {position}"),
+            Self::Domain { position } => {
+                write!(
+                    f,
+                    "operation at position {position} is undefined for its operands"
+                )
+            }
+        }
+    }
+}
+
+impl core::error::Error for ExpressionError {}
+
+/// An error produced while parsing or applying a unified diff.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PatchError {
+    /// A line of the patch is not valid unified diff syntax.
+    Malformed {
+        /// The number of the line, counting from one.
+        line: usize,
+    },
+
+    /// A hunk does not match the text, even with the allowed fuzz.
+    HunkMismatch {
+        /// The index of the hunk, counting from zero.
+        hunk: usize,
+    },
+}
+
+impl fmt::Display for PatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed { line } => write!(f, "malformed patch at line {line}"),
+            Self::HunkMismatch { hunk } => write!(f, "hunk {hunk} does not match the text"),
+        }
+    }
+}
+
+impl core::error::Error for PatchError {}
+
+/// An error produced when escaped or quoted text cannot be decoded, or text cannot be
+/// represented in an escaped form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EscapeError {
+    /// An escape sequence is unknown, incomplete or names no character.
+    InvalidEscape {
+        /// The byte offset of the start of the escape sequence.
+        position: usize,
+    },
+
+    /// A character is not allowed unescaped, or cannot be represented at all.
+    InvalidCharacter {
+        /// The byte offset of the character.
+        position: usize,
+    },
+
+    /// A quote is not closed.
+    UnterminatedQuote {
+        /// The byte offset of the opening quote.
+        position: usize,
+    },
+}
+
+impl fmt::Display for EscapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidEscape { position } => {
+                write!(f, "invalid escape sequence at byte {position}")
+            }
+            Self::InvalidCharacter { position } => {
+                write!(f, "invalid character at byte {position}")
+            }
+            Self::UnterminatedQuote { position } => {
+                write!(f, "unterminated quote at byte {position}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for EscapeError {}
+
+/// An error produced while reading an XML document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum XmlError {
+    /// The markup at the position is not well-formed.
+    Syntax {
+        /// The byte offset of the markup.
+        position: usize,
+    },
+
+    /// The document ends inside markup or with elements still open.
+    UnexpectedEnd,
+
+    /// An end tag does not match the open element.
+    MismatchedTag {
+        /// The byte offset of the end tag.
+        position: usize,
+    },
+
+    /// An entity or character reference is unknown or names no character.
+    InvalidReference {
+        /// The byte offset of the reference.
+        position: usize,
+    },
+
+    /// A namespace prefix is not declared.
+    UnboundPrefix {
+        /// The byte offset of the prefixed name.
+        position: usize,
+    },
+
+    /// An element has two attributes with the same name.
+    DuplicateAttribute {
+        /// The byte offset of the second attribute.
+        position: usize,
+    },
+}
+
+impl fmt::Display for XmlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { position } => write!(f, "malformed XML at byte {position}"),
+            Self::UnexpectedEnd => f.write_str("unexpected end of XML document"),
+            Self::MismatchedTag { position } => {
+                write!(
+                    f,
+                    "end tag at byte {position} does not match the open element"
+                )
+            }
+            Self::InvalidReference { position } => {
+                write!(f, "invalid reference at byte {position}")
+            }
+            Self::UnboundPrefix { position } => {
+                write!(f, "undeclared namespace prefix at byte {position}")
+            }
+            Self::DuplicateAttribute { position } => {
+                write!(f, "duplicate attribute at byte {position}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for XmlError {}
+
+/// An error produced while parsing an INI file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IniError {
+    /// A line is neither a section header, a key-value pair nor a comment.
+    Syntax {
+        /// The number of the line, counting from one.
+        line: usize,
+    },
+
+    /// A quoted value has no closing quote.
+    UnterminatedQuote {
+        /// The number of the line, counting from one.
+        line: usize,
+    },
+
+    /// A quoted value contains an unknown or incomplete escape sequence.
+    InvalidEscape {
+        /// The number of the line, counting from one.
+        line: usize,
+    },
+
+    /// A key appears twice in a section and duplicates are not allowed.
+    DuplicateKey {
+        /// The number of the line of the second occurrence, counting from one.
+        line: usize,
+    },
+}
+
+impl fmt::Display for IniError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { line } => write!(f, "malformed INI at line {line}"),
+            Self::UnterminatedQuote { line } => write!(f, "unterminated quote at line {line}"),
+            Self::InvalidEscape { line } => write!(f, "invalid escape sequence at line {line}"),
+            Self::DuplicateKey { line } => write!(f, "duplicate key at line {line}"),
+        }
+    }
+}
+
+impl core::error::Error for IniError {}
+
+/// An error produced while converting an internationalized domain name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdnaError {
+    /// A Punycode label is malformed or decodes to an out-of-range value.
+    InvalidPunycode,
+
+    /// A label is empty.
+    EmptyLabel,
+
+    /// A label is longer than 63 bytes in its ASCII form.
+    LabelTooLong,
+
+    /// The domain is longer than 253 bytes in its ASCII form.
+    DomainTooLong,
+
+    /// A label starts or ends with a hyphen, or has hyphens in its third and fourth
+    /// positions.
+    InvalidHyphen,
+
+    /// A label contains a character that is not allowed in a domain name.
+    DisallowedCharacter(char),
+}
+
+impl fmt::Display for IdnaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPunycode => f.write_str("invalid Punycode label"),
+            Self::EmptyLabel => f.write_str("empty domain label"),
+            Self::LabelTooLong => f.write_str("domain label longer than 63 bytes"),
+            Self::DomainTooLong => f.write_str("domain name longer than 253 bytes"),
+            Self::InvalidHyphen => f.write_str("misplaced hyphen in domain label"),
+            Self::DisallowedCharacter(character) => {
+                write!(f, "character {character:?} is not allowed in a domain name")
+            }
+        }
+    }
+}
+
+impl core::error::Error for IdnaError {}
+
+/// An error produced while parsing an email address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EmailError {
+    /// The input was empty.
+    Empty,
+
+    /// The address has no `@` between its local part and domain.
+    MissingAt,
+
+    /// The local part is empty, has a misplaced dot or holds a character that must be
+    /// quoted.
+    InvalidLocalPart,
+
+    /// The local part is longer than 64 bytes.
+    LocalPartTooLong,
+
+    /// The domain is neither a valid host name nor a bracketed IP address.
+    InvalidDomain,
+
+    /// The address is longer than 254 bytes.
+    TooLong,
+
+    /// A quoted local part or display name has no closing quote.
+    UnterminatedQuote,
+
+    /// The text around an address in angle brackets is malformed.
+    InvalidDisplayName,
+}
+
+impl fmt::Display for EmailError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "empty email address",
+            Self::MissingAt => "email address has no @",
+            Self::InvalidLocalPart => "invalid local part in email address",
+            Self::LocalPartTooLong => "local part of email address longer than 64 bytes",
+            Self::InvalidDomain => "invalid domain in email address",
+            Self::TooLong => "email address longer than 254 bytes",
+            Self::UnterminatedQuote => "unterminated quote in email address",
+            Self::InvalidDisplayName => "invalid display name in email address",
+        })
+    }
+}
+
+impl core::error::Error for EmailError {}
+
+/// An error produced while parsing a phone number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PhoneError {
+    /// The input held no digits.
+    Empty,
+
+    /// The input holds a character that is neither a digit nor punctuation used in
+    /// phone numbers.
+    InvalidCharacter {
+        /// The byte offset of the character.
+        position: usize,
+    },
+
+    /// The number starts with `+` but no country calling code is assigned to its first
+    /// digits.
+    UnknownCallingCode,
+
+    /// The number has no `+` and no default region to take the calling code from.
+    MissingRegion,
+
+    /// The national number has fewer than four digits.
+    TooShort,
+
+    /// The number has more than the 15 digits allowed by E.164.
+    TooLong,
+}
+
+impl fmt::Display for PhoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty phone number"),
+            Self::InvalidCharacter { position } => {
+                write!(f, "invalid character in phone number at byte {position}")
+            }
+            Self::UnknownCallingCode => f.write_str("unknown country calling code"),
+            Self::MissingRegion => f.write_str("phone number has no country calling code"),
+            Self::TooShort => f.write_str("phone number too short"),
+            Self::TooLong => f.write_str("phone number longer than 15 digits"),
+        }
+    }
+}
+
+impl core::error::Error for PhoneError {}
+
+/// An error produced when a postal address lacks a field its country requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressError {
+    /// The address has no street lines.
+    MissingStreet,
+
+    /// The address has no city or town.
+    MissingLocality,
+
+    /// The country divides addresses by state, province or prefecture but the address
+    /// names none.
+    MissingAdministrativeArea,
+
+    /// The country uses postal codes but the address has none.
+    MissingPostalCode,
+
+    /// The postal code does not have the shape the country uses.
+    InvalidPostalCode,
+}
+
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::MissingStreet => "address has no street",
+            Self::MissingLocality => "address has no city",
+            Self::MissingAdministrativeArea => "address has no state or province",
+            Self::MissingPostalCode => "address has no postal code",
+            Self::InvalidPostalCode => "invalid postal code for the country",
+        })
+    }
+}
+
+impl core::error::Error for AddressError {}
+
+/// An error produced when weights cannot describe a probability distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeightError {
+    /// No weights were given.
+    Empty,
+
+    /// A weight is negative, infinite or NaN.
+    InvalidWeight {
+        /// The index of the weight.
+        index: usize,
+    },
+
+    /// Every weight is zero, or their sum overflows.
+    InvalidTotal,
+
+    /// The number of weights differs from the number of items.
+    LengthMismatch {
+        /// The number of items.
+        items: usize,
+
+        /// The number of weights.
+        weights: usize,
+    },
+}
+
+impl fmt::Display for WeightError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("no weights given"),
+            Self::InvalidWeight { index } => write!(f, "invalid weight at index {index}"),
+            Self::InvalidTotal => f.write_str("weights must have a positive, finite sum"),
+            Self::LengthMismatch { items, weights } => {
+                write!(f, "{weights} weights given for {items} items")
+            }
+        }
+    }
+}
+
+impl core::error::Error for WeightError {}
+
+/// An error produced when a fast Fourier transform is given a buffer it cannot handle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FftError {
+    /// The length is not a power of two.
+    LengthNotPowerOfTwo {
+        /// The length of the buffer.
+        len: usize,
+    },
+}
+
+impl fmt::Display for FftError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LengthNotPowerOfTwo { len } => {
+                write!(f, "length {len} is not a power of two")
+            }
+        }
+    }
+}
+
+impl core::error::Error for FftError {}
+
+/// An error produced when a coordinate or geohash is invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GeoError {
+    /// The latitude is outside `-90..=90` degrees or is not finite.
+    InvalidLatitude,
+
+    /// The longitude is outside `-180..=180` degrees or is not finite.
+    InvalidLongitude,
+
+    /// The geohash precision is outside `1..=12` characters.
+    InvalidPrecision {
+        /// The requested number of characters.
+        precision: usize,
+    },
+
+    /// A geohash contains a character outside its base-32 alphabet.
+    InvalidCharacter {
+        /// The invalid character.
+        character: char,
+
+        /// The byte offset of the character.
+        position: usize,
+    },
+}
+
+impl fmt::Display for GeoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidLatitude => f.write_str("latitude must be between -90 and 90 degrees"),
+            Self::InvalidLongitude => f.write_str("longitude must be between -180 and 180 degrees"),
+            Self::InvalidPrecision { precision } => {
+                write!(f, "geohash precision {precision} is not between 1 and 12")
+            }
+            Self::InvalidCharacter {
+                character,
+                position,
+            } => write!(
+                f,
+                "invalid geohash character {character:?} at position {position}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for GeoError {}
+
+/// An error produced by a matrix operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LinearError {
+    /// The operation needs a square matrix.
+    NotSquare {
+        /// The number of rows.
+        rows: usize,
+
+        /// The number of columns.
+        columns: usize,
+    },
+
+    /// A decomposition needs at least as many rows as columns.
+    Underdetermined {
+        /// The number of rows.
+        rows: usize,
+
+        /// The number of columns.
+        columns: usize,
+    },
+
+    /// The sizes of the operands do not agree.
+    DimensionMismatch {
+        /// The size the operation needs.
+        expected: usize,
+
+        /// The size it was given.
+        found: usize,
+    },
+
+    /// The matrix is singular, or too close to singular for a reliable solution.
+    Singular,
+}
+
+impl fmt::Display for LinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotSquare { rows, columns } => {
+                write!(f, "{rows}x{columns} matrix is not square")
+            }
+            Self::Underdetermined { rows, columns } => {
+                write!(f, "{rows}x{columns} matrix has fewer rows than columns")
+            }
+            Self::DimensionMismatch { expected, found } => {
+                write!(f, "expected size {expected}, found {found}")
+            }
+            Self::Singular => f.write_str("matrix is singular"),
+        }
+    }
+}
+
+impl core::error::Error for LinearError {}
+
+/// An error produced when converting between units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitError {
+    /// The units measure different quantities.
+    Incompatible {
+        /// The symbol of the unit converted from.
+        from: &'static str,
+
+        /// The symbol of the unit converted to.
+        to: &'static str,
+    },
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Incompatible { from, to } => write!(f, "cannot convert {from} to {to}"),
+        }
+    }
+}
+
+impl core::error::Error for UnitError {}
+
+/// An error produced by arithmetic on amounts of money.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoneyError {
+    /// The amounts are in different currencies.
+    CurrencyMismatch {
+        /// The code of the currency of the left-hand amount.
+        expected: &'static str,
+
+        /// The code of the currency of the right-hand amount.
+        found: &'static str,
+    },
+
+    /// The amount has digits below the minor unit of the currency.
+    PrecisionLoss {
+        /// The code of the currency.
+        currency: &'static str,
+    },
+
+    /// The result does not fit in the amount type.
+    Overflow,
+
+    /// The ratios to allocate by are empty or all zero.
+    InvalidRatios,
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CurrencyMismatch { expected, found } => {
+                write!(f, "expected an amount in {expected}, found one in {found}")
+            }
+            Self::PrecisionLoss { currency } => {
+                write!(
+                    f,
+                    "amount is more precise than the minor unit of {currency}"
+                )
+            }
+            Self::Overflow => f.write_str("amount overflowed"),
+            Self::InvalidRatios => f.write_str("ratios must not be empty or all zero"),
+        }
+    }
+}
+
+impl core::error::Error for MoneyError {}
+
+/// An error produced when converting money between currencies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExchangeError {
+    /// No rate is set for the currency.
+    MissingRate {
+        /// The code of the currency.
+        currency: &'static str,
+    },
+
+    /// The rate for the currency is older than the table allows.
+    StaleRate {
+        /// The code of the currency.
+        currency: &'static str,
+
+        /// How long ago the rate was set.
+        age: Duration,
+    },
+
+    /// The rate is not positive, or is quoted for the base currency itself.
+    InvalidRate {
+        /// The code of the currency.
+        currency: &'static str,
+    },
+
+    /// The converted amount does not fit in the amount type.
+    Overflow,
+}
+
+impl fmt::Display for ExchangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingRate { currency } => write!(f, "no exchange rate for {currency}"),
+            Self::StaleRate { currency, age } => {
+                write!(f, "exchange rate for {currency} is stale ({age:?} old)")
+            }
+            Self::InvalidRate { currency } => write!(f, "invalid exchange rate for {currency}"),
+            Self::Overflow => f.write_str("converted amount overflowed"),
+        }
+    }
+}
+
+impl core::error::Error for ExchangeError {}
+
+/// An invariant reported as broken by one of the `libx::testing` checks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CheckError {
+    /// A list operation returned a different value from the reference model.
+    ResultMismatch {
+        /// The zero-based index of the operation.
+        step: usize,
+
+        /// The operation, as debug-formatted text.
+        operation: String,
+
+        /// The value the list returned, as debug-formatted text.
+        actual: String,
+
+        /// The value the model returned, as debug-formatted text.
+        expected: String,
+    },
+
+    /// A list operation left the list with different contents from the reference model.
+    StateMismatch {
+        /// The zero-based index of the operation.
+        step: usize,
+
+        /// The operation, as debug-formatted text.
+        operation: String,
+
+        /// The contents of the list, as debug-formatted text.
+        actual: String,
+
+        /// The contents of the model, as debug-formatted text.
+        expected: String,
+    },
+
+    /// Formatted number text could not be parsed again.
+    Unparsable {
+        /// The text that failed to parse.
+        text: String,
+
+        /// The reason it failed.
+        error: ParseError,
+    },
+
+    /// Number or Morse text kept changing after its first normalising round trip.
+    Unstable {
+        /// The original input.
+        input: String,
+
+        /// The text after one round trip.
+        normalized: String,
+
+        /// The text after a second round trip.
+        reformatted: String,
+    },
+
+    /// A property-specific invariant, described in prose.
+    Violated(String),
+}
+
+impl fmt::Display for CheckError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ResultMismatch {
+                step,
+                operation,
+                actual,
+                expected,
+            } => write!(
+                f,
+                "step {step} ({operation}) returned {actual}, model returned {expected}"
+            ),
+            Self::StateMismatch {
+                step,
+                operation,
+                actual,
+                expected,
+            } => write!(
+                f,
+                "step {step} ({operation}) left list {actual}, model is {expected}"
+            ),
+            Self::Unparsable { text, error } => {
+                write!(f, "{text:?} no longer parses: {error}")
+            }
+            Self::Unstable {
+                input,
+                normalized,
+                reformatted,
+            } => write!(
+                f,
+                "{input:?} normalized to {normalized:?} but then formatted as {reformatted:?}"
+            ),
+            Self::Violated(message) => f.write_str(message),
+        }
+    }
+}
+
+impl core::error::Error for CheckError {}
+
+impl From<String> for CheckError {
+    fn from(message: String) -> Self {
+        Self::Violated(message)
+    }
+}
+
+impl From<&str> for CheckError {
+    fn from(message: &str) -> Self {
+        Self::Violated(message.into())
+    }
+}
+
+/// A failed property check, carrying everything needed to reproduce it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyFailure {
+    /// The seed the property was run with.
+    pub seed: u64,
+
+    /// The zero-based index of the failing case.
+    pub case: usize,
+
+    /// The invariant the case violated.
+    pub error: CheckError,
+}
+
+impl fmt::Display for PropertyFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "property failed at case {} (seed {:#x}): {}",
+            self.case, self.seed, self.error
+        )
+    }
+}
+
+impl core::error::Error for PropertyFailure {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An error produced when items cannot be ordered because their dependencies form a
+/// cycle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CycleError<T> {
+    /// The items of the cycle, each depending on the next and the last on the first.
+    pub cycle: Vec<T>,
+}
+
+impl<T: fmt::Debug> fmt::Display for CycleError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dependency cycle: ")?;
+
+        for item in &self.cycle {
+            write!(f, "{item:?} -> ")?;
+        }
+
+        match self.cycle.first() {
+            Some(first) => write!(f, "{first:?}"),
+            None => f.write_str("(empty)"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for CycleError<T> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use super::*;
+
+    #[test]
+    fn test_collection_error_display() {
+        assert_eq!(
+            CollectionError::CapacityExceeded { capacity: 4 }.to_string(),
+            "maximum capacity of 4 reached"
+        );
+        assert_eq!(
+            CollectionError::IndexOutOfBounds { index: 5, len: 2 }.to_string(),
+            "index 5 out of bounds for length 2"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(
+            ParseError::InvalidCharacter {
+                character: 'x',
+                position: 3
+            }
+            .to_string(),
+            "invalid character 'x' at position 3"
+        );
+    }
+
+    #[test]
+    fn test_format_error_from_fmt_error() {
+        assert_eq!(FormatError::from(fmt::Error), FormatError::Write);
+    }
+
+    #[test]
+    fn test_cycle_error_display() {
+        let error = CycleError {
+            cycle: vec!["app", "lib"],
+        };
+
+        assert_eq!(
+            error.to_string(),
+            r#"dependency cycle: "app" -> "lib" -> "app""#
+        );
+    }
+
+    #[test]
+    fn test_errors_are_core_errors() {
+        fn assert_error<E: core::error::Error>(_: &E) {}
+
+        assert_error(&CollectionError::Empty);
+        assert_error(&ParseError::Empty);
+        assert_error(&FormatError::Unsupported);
+        assert_error(&TemplateError::Write);
+        assert_error(&CipherError::InvalidKey);
+        assert_error(&EncodingError::UnexpectedEnd);
+        assert_error(&LoggingError::AlreadyInitialized);
+        assert_error(&CliError::MissingValue("--port".into()));
+        assert_error(&PackingError::UnexpectedEnd {
+            required: 4,
+            available: 2,
+        });
+        assert_error(&MetricsError::DuplicateName("requests_total"));
+        assert_error(&ExpressionError::UnexpectedEnd);
+        assert_error(&PatchError::HunkMismatch { hunk: 0 });
+        assert_error(&EscapeError::InvalidEscape { position: 0 });
+        assert_error(&XmlError::UnexpectedEnd);
+        assert_error(&IniError::Syntax { line: 1 });
+        assert_error(&IdnaError::EmptyLabel);
+        assert_error(&EmailError::MissingAt);
+        assert_error(&PhoneError::TooShort);
+        assert_error(&AddressError::InvalidPostalCode);
+        assert_error(&WeightError::InvalidTotal);
+        assert_error(&FftError::LengthNotPowerOfTwo { len: 3 });
+        assert_error(&LinearError::Singular);
+        assert_error(&GeoError::InvalidLatitude);
+        assert_error(&UnitError::Incompatible { from: "m", to: "s" });
+        assert_error(&MoneyError::Overflow);
+        assert_error(&ExchangeError::Overflow);
+        assert_error(&CheckError::Violated("broken".into()));
+        assert_error(&PropertyFailure {
+            seed: 0,
+            case: 0,
+            error: CheckError::Violated("broken".into()),
+        });
+        assert_error(&CycleError { cycle: vec!["a"] });
+    }
+}
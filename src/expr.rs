@@ -0,0 +1,868 @@
+//! Arithmetic expressions.
+//!
+//! [`Expression::parse`] reads an expression such as `2 * (3.5 + x)` once, and
+//! [`Expression::evaluate`] computes it in an [`Environment`] that holds the values of
+//! its variables and the [`Precision`] of the arithmetic. Values go in and come out as
+//! [`Number`]s.
+//!
+//! The grammar has the usual operators with the usual precedence: `^` binds tightest and
+//! groups to the right, then unary `-` and `+`, then `*`, `/` and `%`, then binary `+`
+//! and `-`. Parentheses group, and the functions `abs`, `sqrt`, `floor`, `ceil`,
+//! `trunc`, `round`, `min` and `max` are computed with the crate's [`FloatingPoint`]
+//! operations. The constants `pi` and `e` are predefined variables.
+//!
+//! Every error carries the byte offset of the token at fault, so that it can be shown
+//! under the source.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{
+//!     expr::{Environment, Expression, Precision, evaluate},
+//!     num::Number,
+//! };
+//!
+//! assert_eq!(evaluate("2 * (3.5 + 1)"), Ok(Number::Double(9.0)));
+//!
+//! let area = Expression::parse("pi * r ^ 2")?;
+//! let environment = Environment::new().with_variable("r", 2.0);
+//! assert_eq!(area.evaluate(&environment)?.double(), core::f64::consts::PI * 4.0);
+//!
+//! let environment = Environment::new().with_precision(Precision::Integer);
+//! assert_eq!(Expression::parse("7 / 2")?.evaluate(&environment), Ok(Number::Int(3)));
+//! # Ok::<(), libx::errors::ExpressionError>(())
+//! ```
+
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::FromStr;
+
+use crate::{
+    errors::ExpressionError,
+    num::{Number, traits::FloatingPoint},
+};
+
+/// The arithmetic an expression is evaluated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Precision {
+    /// `isize` arithmetic, producing [`Number::Int`]. Division truncates toward zero,
+    /// number literals must be whole, and results that overflow are errors.
+    Integer,
+
+    /// `f32` arithmetic, producing [`Number::Float`].
+    Single,
+
+    /// `f64` arithmetic, producing [`Number::Double`].
+    #[default]
+    Double,
+}
+
+/// The variables and precision an expression is evaluated with.
+#[derive(Debug)]
+pub struct Environment {
+    variables: BTreeMap<String, Number>,
+    precision: Precision,
+}
+
+impl Environment {
+    /// Creates an environment with double precision and the variables `pi` and `e`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            variables: BTreeMap::new(),
+            precision: Precision::Double,
+        }
+        .with_variable("pi", core::f64::consts::PI)
+        .with_variable("e", core::f64::consts::E)
+    }
+
+    /// Evaluates with `precision`.
+    #[must_use]
+    pub const fn with_precision(mut self, precision: Precision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Sets the variable `name` to `value`.
+    #[must_use]
+    pub fn with_variable(mut self, name: &str, value: impl Into<Number>) -> Self {
+        self.set_variable(name, value);
+        self
+    }
+
+    /// Returns the precision of the arithmetic.
+    #[must_use]
+    pub const fn precision(&self) -> Precision {
+        self.precision
+    }
+
+    /// Sets the variable `name` to `value`, replacing any earlier value.
+    ///
+    /// The value is converted to the precision when it is used, as the accessors of
+    /// [`Number`] convert it, so a fraction is truncated in integer arithmetic.
+    pub fn set_variable(&mut self, name: &str, value: impl Into<Number>) {
+        self.variables.insert(name.to_string(), value.into());
+    }
+
+    /// Returns the value of the variable `name`.
+    #[must_use]
+    pub fn variable(&self, name: &str) -> Option<&Number> {
+        self.variables.get(name)
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The kinds of token in an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenKind<'a> {
+    Number(&'a str),
+    Identifier(&'a str),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LeftParenthesis,
+    RightParenthesis,
+    Comma,
+}
+
+/// A token and the byte offset where it starts.
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    kind: TokenKind<'a>,
+    position: usize,
+}
+
+/// Returns the byte offset just past the run of ASCII digits starting at `start`.
+fn skip_digits(bytes: &[u8], start: usize) -> usize {
+    start
+        + bytes[start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count()
+}
+
+/// Splits `source` into tokens.
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>, ExpressionError> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut position = 0;
+
+    while let Some(character) = source[position..].chars().next() {
+        let start = position;
+        position += character.len_utf8();
+
+        let kind = match character {
+            ' ' | '\t' | '\n' | '\r' => continue,
+            '+' => TokenKind::Plus,
+            '-' => TokenKind::Minus,
+            '*' => TokenKind::Star,
+            '/' => TokenKind::Slash,
+            '%' => TokenKind::Percent,
+            '^' => TokenKind::Caret,
+            '(' => TokenKind::LeftParenthesis,
+            ')' => TokenKind::RightParenthesis,
+            ',' => TokenKind::Comma,
+            '0'..='9' | '.' => {
+                position = skip_digits(bytes, start);
+
+                if bytes.get(position) == Some(&b'.') {
+                    position = skip_digits(bytes, position + 1);
+                }
+
+                // An exponent needs at least one digit, optionally after a sign.
+                if matches!(bytes.get(position), Some(b'e' | b'E')) {
+                    let digits = match bytes.get(position + 1) {
+                        Some(b'+' | b'-') => position + 2,
+                        _ => position + 1,
+                    };
+
+                    if bytes.get(digits).is_some_and(u8::is_ascii_digit) {
+                        position = skip_digits(bytes, digits);
+                    }
+                }
+
+                TokenKind::Number(&source[start..position])
+            }
+            'a'..='z' | 'A'..='Z' | '_' => {
+                position = start
+                    + bytes[start..]
+                        .iter()
+                        .take_while(|byte| byte.is_ascii_alphanumeric() || **byte == b'_')
+                        .count();
+
+                TokenKind::Identifier(&source[start..position])
+            }
+            _ => {
+                return Err(ExpressionError::UnexpectedCharacter {
+                    character,
+                    position: start,
+                });
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            position: start,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// A binary operator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+}
+
+impl Operator {
+    /// Returns the operator a token stands for, with its left and right binding power.
+    const fn from_token(kind: TokenKind<'_>) -> Option<(Self, u8, u8)> {
+        match kind {
+            TokenKind::Plus => Some((Self::Add, 1, 2)),
+            TokenKind::Minus => Some((Self::Subtract, 1, 2)),
+            TokenKind::Star => Some((Self::Multiply, 3, 4)),
+            TokenKind::Slash => Some((Self::Divide, 3, 4)),
+            TokenKind::Percent => Some((Self::Remainder, 3, 4)),
+            // Higher on the left than on the right, so that `^` groups to the right.
+            TokenKind::Caret => Some((Self::Power, 8, 7)),
+            _ => None,
+        }
+    }
+}
+
+/// The binding power of unary `-` and `+`, between `*` and `^`.
+const PREFIX_POWER: u8 = 5;
+
+/// A built-in function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Function {
+    Abs,
+    Sqrt,
+    Floor,
+    Ceil,
+    Trunc,
+    Round,
+    Min,
+    Max,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "abs" => Self::Abs,
+            "sqrt" => Self::Sqrt,
+            "floor" => Self::Floor,
+            "ceil" => Self::Ceil,
+            "trunc" => Self::Trunc,
+            "round" => Self::Round,
+            "min" => Self::Min,
+            "max" => Self::Max,
+            _ => return None,
+        })
+    }
+
+    /// Returns the number of arguments the function takes.
+    const fn arity(self) -> usize {
+        match self {
+            Self::Min | Self::Max => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// A node of the syntax tree, with the byte offset of the token it came from.
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Literal {
+        value: f64,
+        integer: Option<isize>,
+        position: usize,
+    },
+    Variable {
+        name: String,
+        position: usize,
+    },
+    Negate {
+        operand: Box<Self>,
+        position: usize,
+    },
+    Binary {
+        operator: Operator,
+        left: Box<Self>,
+        right: Box<Self>,
+        position: usize,
+    },
+    Call {
+        function: Function,
+        arguments: Vec<Self>,
+        position: usize,
+    },
+}
+
+/// A Pratt parser over a list of tokens.
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    index: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.index).copied()
+    }
+
+    fn next(&mut self) -> Result<Token<'a>, ExpressionError> {
+        let token = self.peek().ok_or(ExpressionError::UnexpectedEnd)?;
+        self.index += 1;
+        Ok(token)
+    }
+
+    fn expect(&mut self, kind: TokenKind<'_>) -> Result<(), ExpressionError> {
+        let token = self.next()?;
+
+        if token.kind == kind {
+            Ok(())
+        } else {
+            Err(ExpressionError::UnexpectedToken {
+                position: token.position,
+            })
+        }
+    }
+
+    /// Parses an expression whose operators all bind at least as tightly as `min_power`.
+    fn expression(&mut self, min_power: u8) -> Result<Node, ExpressionError> {
+        let token = self.next()?;
+        let position = token.position;
+
+        let mut left = match token.kind {
+            TokenKind::Number(text) => Node::Literal {
+                value: text
+                    .parse()
+                    .map_err(|_| ExpressionError::InvalidNumber { position })?,
+                integer: text.parse().ok(),
+                position,
+            },
+            TokenKind::Identifier(name) => {
+                if self.peek().map(|token| token.kind) == Some(TokenKind::LeftParenthesis) {
+                    self.call(name, position)?
+                } else {
+                    Node::Variable {
+                        name: name.to_string(),
+                        position,
+                    }
+                }
+            }
+            TokenKind::Minus => Node::Negate {
+                operand: Box::new(self.expression(PREFIX_POWER)?),
+                position,
+            },
+            TokenKind::Plus => self.expression(PREFIX_POWER)?,
+            TokenKind::LeftParenthesis => {
+                let inner = self.expression(0)?;
+                self.expect(TokenKind::RightParenthesis)?;
+                inner
+            }
+            _ => return Err(ExpressionError::UnexpectedToken { position }),
+        };
+
+        while let Some(token) = self.peek() {
+            let Some((operator, left_power, right_power)) = Operator::from_token(token.kind) else {
+                break;
+            };
+
+            if left_power < min_power {
+                break;
+            }
+
+            self.index += 1;
+
+            left = Node::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(self.expression(right_power)?),
+                position: token.position,
+            };
+        }
+
+        Ok(left)
+    }
+
+    /// Parses the parenthesised arguments of a call to `name`.
+    fn call(&mut self, name: &str, position: usize) -> Result<Node, ExpressionError> {
+        let function =
+            Function::from_name(name).ok_or(ExpressionError::UnknownFunction { position })?;
+
+        self.expect(TokenKind::LeftParenthesis)?;
+        let mut arguments = Vec::new();
+
+        if self.peek().map(|token| token.kind) == Some(TokenKind::RightParenthesis) {
+            self.index += 1;
+        } else {
+            loop {
+                arguments.push(self.expression(0)?);
+                let token = self.next()?;
+
+                match token.kind {
+                    TokenKind::Comma => {}
+                    TokenKind::RightParenthesis => break,
+                    _ => {
+                        return Err(ExpressionError::UnexpectedToken {
+                            position: token.position,
+                        });
+                    }
+                }
+            }
+        }
+
+        if arguments.len() != function.arity() {
+            return Err(ExpressionError::ArgumentCount {
+                position,
+                expected: function.arity(),
+                actual: arguments.len(),
+            });
+        }
+
+        Ok(Node::Call {
+            function,
+            arguments,
+            position,
+        })
+    }
+}
+
+/// A value during evaluation: an integer in integer arithmetic, and otherwise an `f64`
+/// that is rounded to `f32` after every operation in single precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Scalar {
+    Integer(isize),
+    Real(f64),
+}
+
+/// Raises `base` to the whole power `exponent` by repeated squaring.
+fn real_power(base: f64, exponent: f64) -> f64 {
+    // Larger exponents overflow or vanish anyway.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let mut remaining = exponent.abs() as u64;
+    let mut square = base;
+    let mut result = 1.0;
+
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            result *= square;
+        }
+
+        square *= square;
+        remaining >>= 1;
+    }
+
+    if exponent < 0.0 { 1.0 / result } else { result }
+}
+
+/// A parsed arithmetic expression.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression {
+    root: Node,
+}
+
+impl Expression {
+    /// Parses `source`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpressionError::UnexpectedCharacter`], [`UnexpectedToken`] or
+    /// [`UnexpectedEnd`] if `source` is not a well-formed expression,
+    /// [`ExpressionError::UnknownFunction`] for a call to a function that does not exist,
+    /// and [`ExpressionError::ArgumentCount`] if a call has the wrong number of
+    /// arguments.
+    ///
+    /// [`UnexpectedToken`]: ExpressionError::UnexpectedToken
+    /// [`UnexpectedEnd`]: ExpressionError::UnexpectedEnd
+    pub fn parse(source: &str) -> Result<Self, ExpressionError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            index: 0,
+        };
+
+        let root = parser.expression(0)?;
+
+        if let Some(token) = parser.peek() {
+            return Err(ExpressionError::UnexpectedToken {
+                position: token.position,
+            });
+        }
+
+        Ok(Self { root })
+    }
+
+    /// Evaluates the expression with the variables and precision of `environment`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExpressionError::UnknownVariable`] for a variable without a value,
+    /// [`ExpressionError::InvalidNumber`] for a fraction in integer arithmetic, and
+    /// [`DivisionByZero`], [`Overflow`] or [`Domain`] if an operation fails.
+    ///
+    /// [`DivisionByZero`]: ExpressionError::DivisionByZero
+    /// [`Overflow`]: ExpressionError::Overflow
+    /// [`Domain`]: ExpressionError::Domain
+    pub fn evaluate(&self, environment: &Environment) -> Result<Number, ExpressionError> {
+        let value = Self::evaluate_node(&self.root, environment)?;
+
+        #[allow(clippy::cast_possible_truncation)] // Single precision values fit in `f32`.
+        Ok(match (value, environment.precision) {
+            (Scalar::Integer(value), _) => Number::Int(value),
+            (Scalar::Real(value), Precision::Single) => Number::Float(value as f32),
+            (Scalar::Real(value), _) => Number::Double(value),
+        })
+    }
+
+    /// Rounds `value` to the precision of `environment`, failing if it overflowed there.
+    fn real(
+        value: f64,
+        environment: &Environment,
+        position: usize,
+    ) -> Result<Scalar, ExpressionError> {
+        #[allow(clippy::cast_possible_truncation)] // Rounding to `f32` is the point.
+        let value = match environment.precision {
+            Precision::Single => f64::from(value as f32),
+            _ => value,
+        };
+
+        if value.is_finite() {
+            Ok(Scalar::Real(value))
+        } else {
+            Err(ExpressionError::Overflow { position })
+        }
+    }
+
+    fn evaluate_node(node: &Node, environment: &Environment) -> Result<Scalar, ExpressionError> {
+        match node {
+            Node::Literal {
+                value,
+                integer,
+                position,
+            } => match environment.precision {
+                Precision::Integer => {
+                    integer
+                        .map(Scalar::Integer)
+                        .ok_or(ExpressionError::InvalidNumber {
+                            position: *position,
+                        })
+                }
+                _ => Self::real(*value, environment, *position),
+            },
+            Node::Variable { name, position } => {
+                let value = environment
+                    .variable(name)
+                    .ok_or(ExpressionError::UnknownVariable {
+                        position: *position,
+                    })?;
+
+                match environment.precision {
+                    Precision::Integer => Ok(Scalar::Integer(value.int())),
+                    _ => Self::real(value.double(), environment, *position),
+                }
+            }
+            Node::Negate { operand, position } => {
+                match Self::evaluate_node(operand, environment)? {
+                    Scalar::Integer(value) => {
+                        value
+                            .checked_neg()
+                            .map(Scalar::Integer)
+                            .ok_or(ExpressionError::Overflow {
+                                position: *position,
+                            })
+                    }
+                    Scalar::Real(value) => Ok(Scalar::Real(-value)),
+                }
+            }
+            Node::Binary {
+                operator,
+                left,
+                right,
+                position,
+            } => {
+                let left = Self::evaluate_node(left, environment)?;
+                let right = Self::evaluate_node(right, environment)?;
+                Self::binary(*operator, left, right, environment, *position)
+            }
+            Node::Call {
+                function,
+                arguments,
+                position,
+            } => {
+                let arguments = arguments
+                    .iter()
+                    .map(|argument| Self::evaluate_node(argument, environment))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Self::call(*function, &arguments, environment, *position)
+            }
+        }
+    }
+
+    fn binary(
+        operator: Operator,
+        left: Scalar,
+        right: Scalar,
+        environment: &Environment,
+        position: usize,
+    ) -> Result<Scalar, ExpressionError> {
+        let overflow = ExpressionError::Overflow { position };
+
+        match (left, right) {
+            (Scalar::Integer(left), Scalar::Integer(right)) => {
+                if matches!(operator, Operator::Divide | Operator::Remainder) && right == 0 {
+                    return Err(ExpressionError::DivisionByZero { position });
+                }
+
+                let result = match operator {
+                    Operator::Add => left.checked_add(right),
+                    Operator::Subtract => left.checked_sub(right),
+                    Operator::Multiply => left.checked_mul(right),
+                    Operator::Divide => left.checked_div(right),
+                    Operator::Remainder => left.checked_rem(right),
+                    Operator::Power => {
+                        if right < 0 {
+                            return Err(ExpressionError::Domain { position });
+                        }
+
+                        u32::try_from(right)
+                            .ok()
+                            .and_then(|exponent| left.checked_pow(exponent))
+                    }
+                };
+
+                result.map(Scalar::Integer).ok_or(overflow)
+            }
+            (Scalar::Real(left), Scalar::Real(right)) => {
+                let result = match operator {
+                    Operator::Add => left + right,
+                    Operator::Subtract => left - right,
+                    Operator::Multiply => left * right,
+                    Operator::Divide | Operator::Remainder if right == 0.0 => {
+                        return Err(ExpressionError::DivisionByZero { position });
+                    }
+                    Operator::Divide => left / right,
+                    Operator::Remainder => left % right,
+                    Operator::Power => {
+                        if FloatingPoint::fract(right) != 0.0 {
+                            return Err(ExpressionError::Domain { position });
+                        }
+
+                        if left == 0.0 && right < 0.0 {
+                            return Err(ExpressionError::DivisionByZero { position });
+                        }
+
+                        real_power(left, right)
+                    }
+                };
+
+                Self::real(result, environment, position)
+            }
+            _ => unreachable!("every value of an evaluation has the same precision"),
+        }
+    }
+
+    fn call(
+        function: Function,
+        arguments: &[Scalar],
+        environment: &Environment,
+        position: usize,
+    ) -> Result<Scalar, ExpressionError> {
+        match (function, arguments) {
+            (_, &[Scalar::Integer(value)]) => match function {
+                Function::Abs => value
+                    .checked_abs()
+                    .map(Scalar::Integer)
+                    .ok_or(ExpressionError::Overflow { position }),
+                Function::Sqrt if value < 0 => Err(ExpressionError::Domain { position }),
+                Function::Sqrt => Ok(Scalar::Integer(value.isqrt())),
+                _ => Ok(Scalar::Integer(value)),
+            },
+            (_, &[Scalar::Real(value)]) => {
+                let result = match function {
+                    Function::Abs => value.abs(),
+                    Function::Sqrt if value < 0.0 => {
+                        return Err(ExpressionError::Domain { position });
+                    }
+                    Function::Sqrt => FloatingPoint::square_root(value),
+                    Function::Floor => FloatingPoint::floor(value),
+                    Function::Ceil => FloatingPoint::ceil(value),
+                    Function::Trunc => FloatingPoint::trunc(value),
+                    _ => FloatingPoint::rounded(value),
+                };
+
+                Self::real(result, environment, position)
+            }
+            (Function::Min, &[Scalar::Integer(left), Scalar::Integer(right)]) => {
+                Ok(Scalar::Integer(left.min(right)))
+            }
+            (_, &[Scalar::Integer(left), Scalar::Integer(right)]) => {
+                Ok(Scalar::Integer(left.max(right)))
+            }
+            (Function::Min, &[Scalar::Real(left), Scalar::Real(right)]) => {
+                Ok(Scalar::Real(left.min(right)))
+            }
+            (_, &[Scalar::Real(left), Scalar::Real(right)]) => Ok(Scalar::Real(left.max(right))),
+            _ => unreachable!("the parser checks the number of arguments"),
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ExpressionError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::parse(source)
+    }
+}
+
+/// Parses and evaluates `source` in the default [`Environment`], with double precision
+/// and only the variables `pi` and `e`.
+///
+/// # Errors
+///
+/// Returns the errors of [`Expression::parse`] and [`Expression::evaluate`].
+pub fn evaluate(source: &str) -> Result<Number, ExpressionError> {
+    Expression::parse(source)?.evaluate(&Environment::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate_in(source: &str, environment: &Environment) -> Result<Number, ExpressionError> {
+        Expression::parse(source).and_then(|expression| expression.evaluate(environment))
+    }
+
+    #[test]
+    fn test_precedence_and_associativity() {
+        for (source, value) in [
+            ("1 + 2 * 3", 7.0),
+            ("(1 + 2) * 3", 9.0),
+            ("10 - 4 - 3", 3.0),
+            ("2 ^ 3 ^ 2", 512.0),
+            ("-2 ^ 2", -4.0),
+            ("2 ^ -1", 0.5),
+            ("- -3 + +1", 4.0),
+            ("7 % 4 * 2", 6.0),
+            ("1.5e2 + .5", 150.5),
+        ] {
+            assert_eq!(evaluate(source), Ok(Number::Double(value)), "{source}");
+        }
+    }
+
+    #[test]
+    fn test_functions_and_variables() {
+        assert_eq!(
+            evaluate("floor(2.7) + ceil(2.2) + trunc(-2.7) + round(2.5) + abs(-2)"),
+            Ok(Number::Double(8.0))
+        );
+        assert_eq!(evaluate("max(1, min(5, 3))"), Ok(Number::Double(3.0)));
+        assert_eq!(
+            evaluate("2 * pi"),
+            Ok(Number::Double(core::f64::consts::TAU))
+        );
+
+        let root = evaluate("sqrt(2)").expect("the root exists").double();
+        assert!((root - core::f64::consts::SQRT_2).abs() < 1e-9);
+
+        let mut environment = Environment::new()
+            .with_variable("x", 4_i32)
+            .with_variable("rate", 0.25);
+        let expression =
+            Expression::parse("2 * (3.5 + x) * rate").expect("the expression is valid");
+
+        assert_eq!(expression.evaluate(&environment), Ok(Number::Double(3.75)));
+        assert_eq!(
+            evaluate_in("x + y", &environment),
+            Err(ExpressionError::UnknownVariable { position: 4 })
+        );
+
+        environment.set_variable("x", 0.5);
+        assert_eq!(expression.evaluate(&environment), Ok(Number::Double(2.0)));
+    }
+
+    #[test]
+    fn test_precisions() {
+        let integer = Environment::new().with_precision(Precision::Integer);
+        let single = Environment::new().with_precision(Precision::Single);
+
+        assert_eq!(evaluate_in("-7 / 2", &integer), Ok(Number::Int(-3)));
+        assert_eq!(evaluate_in("2 ^ 10 % 1000", &integer), Ok(Number::Int(24)));
+        assert_eq!(evaluate_in("sqrt(17)", &integer), Ok(Number::Int(4)));
+        assert_eq!(evaluate_in("pi", &integer), Ok(Number::Int(3)));
+        assert_eq!(
+            evaluate_in("1.5 + 1", &integer),
+            Err(ExpressionError::InvalidNumber { position: 0 })
+        );
+        assert_eq!(
+            evaluate_in("2 ^ 70", &integer),
+            Err(ExpressionError::Overflow { position: 2 })
+        );
+
+        assert_eq!(evaluate_in("0.1 + 0.2", &single), Ok(Number::Float(0.3)));
+        assert_eq!(
+            evaluate_in("1e30 * 1e30", &single),
+            Err(ExpressionError::Overflow { position: 5 })
+        );
+    }
+
+    #[test]
+    fn test_error_positions() {
+        assert_eq!(
+            evaluate("1 + $"),
+            Err(ExpressionError::UnexpectedCharacter {
+                character: '$',
+                position: 4
+            })
+        );
+        assert_eq!(evaluate("(1 + 2"), Err(ExpressionError::UnexpectedEnd));
+        assert_eq!(
+            evaluate("1 2"),
+            Err(ExpressionError::UnexpectedToken { position: 2 })
+        );
+        assert_eq!(
+            evaluate("2 * foo(1)"),
+            Err(ExpressionError::UnknownFunction { position: 4 })
+        );
+        assert_eq!(
+            evaluate("min(1)"),
+            Err(ExpressionError::ArgumentCount {
+                position: 0,
+                expected: 2,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            evaluate("1 / (2 - 2)"),
+            Err(ExpressionError::DivisionByZero { position: 2 })
+        );
+        assert_eq!(
+            evaluate("sqrt(-1)"),
+            Err(ExpressionError::Domain { position: 0 })
+        );
+        assert_eq!(
+            evaluate("2 ^ 0.5"),
+            Err(ExpressionError::Domain { position: 2 })
+        );
+    }
+}
@@ -0,0 +1,27 @@
+//! Formatters that turn values into human-readable text.
+//!
+//! Formatters are plain values configured with `with_*` methods and then reused for any
+//! number of values. They produce a `String`, or write into any [`core::fmt::Write`]
+//! sink, including a `&mut dyn Write` and a fixed-capacity
+//! [`ArrayString`](crate::collections::array_string::ArrayString) on targets without a
+//! heap for the output.
+
+pub mod address;
+pub mod breakdown;
+pub mod bytes;
+pub mod numbers;
+pub mod person_name;
+pub mod phone;
+pub mod radix;
+pub mod table;
+pub mod template;
+
+pub use address::{Address, AddressFormatter};
+pub use breakdown::BreakdownFormatter;
+pub use bytes::{ByteCountFormatter, ByteCountFormatterUnits, ByteCountStyle};
+pub use numbers::{NumberFormatter, NumberStyle};
+pub use person_name::{PersonNameComponents, PersonNameFormatter};
+pub use phone::{PhoneNumber, PhoneNumberFormatter};
+pub use radix::{RadixFormatter, RadixInteger};
+pub use table::TableFormatter;
+pub use template::{TemplateFormatter, format_template};
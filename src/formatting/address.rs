@@ -0,0 +1,724 @@
+//! Postal addresses laid out the way each country writes them.
+//!
+//! An [`Address`] holds the components of a postal address. [`AddressFormatter`] arranges
+//! them into lines with a per-country template, so a German address puts the postal code
+//! before the city while an American one puts it after the state, and a Japanese address
+//! starts with the postal code and ends with the recipient. Lines and separators around
+//! missing components are dropped. The country is written last, in capitals, unless it
+//! is the formatter's home region.
+//!
+//! Templates use the field codes of Google's address data: `%N` recipient,
+//! `%O` organization, `%A` street lines, `%D` dependent locality, `%C` city, `%S` state
+//! or province, `%Z` postal code and `%n` for a line break.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::formatting::address::{Address, AddressFormatter};
+//! use libx::locale::{Locale, Region};
+//!
+//! let address = Address::new()
+//!     .with_recipient("Erika Mustermann")
+//!     .with_street_line("Heidestraße 17")
+//!     .with_locality("Köln")
+//!     .with_postal_code("51147")
+//!     .with_country(Region::from_code("DE").unwrap());
+//!
+//! let formatter = AddressFormatter::new().with_locale(&Locale::new("de_DE"));
+//! assert_eq!(formatter.format(&address), "Erika Mustermann\nHeidestraße 17\n51147 Köln");
+//!
+//! let abroad = AddressFormatter::new().with_locale(&Locale::new("en_US"));
+//! assert_eq!(
+//!     abroad.format(&address),
+//!     "Erika Mustermann\nHeidestraße 17\n51147 Köln\nGERMANY"
+//! );
+//! ```
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::fmt::Write;
+
+use crate::{
+    errors::{AddressError, FormatError},
+    locale::{Locale, Region},
+};
+
+/// The template used for countries without one of their own.
+const DEFAULT_TEMPLATE: &str = "%N%n%O%n%A%n%C";
+
+/// The components of a postal address.
+///
+/// Components that are not set are empty.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Address {
+    recipient: String,
+    organization: String,
+    street_lines: Vec<String>,
+    dependent_locality: String,
+    locality: String,
+    administrative_area: String,
+    postal_code: String,
+    country: Option<Region>,
+}
+
+impl Address {
+    /// Creates an address with no components.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            recipient: String::new(),
+            organization: String::new(),
+            street_lines: Vec::new(),
+            dependent_locality: String::new(),
+            locality: String::new(),
+            administrative_area: String::new(),
+            postal_code: String::new(),
+            country: None,
+        }
+    }
+
+    /// Sets the name of the person the mail is for.
+    #[must_use]
+    pub fn with_recipient(mut self, recipient: impl Into<String>) -> Self {
+        self.recipient = recipient.into();
+        self
+    }
+
+    /// Sets the company or organization.
+    #[must_use]
+    pub fn with_organization(mut self, organization: impl Into<String>) -> Self {
+        self.organization = organization.into();
+        self
+    }
+
+    /// Adds a line of the street address, after any added before.
+    #[must_use]
+    pub fn with_street_line(mut self, line: impl Into<String>) -> Self {
+        self.street_lines.push(line.into());
+        self
+    }
+
+    /// Sets the neighbourhood or district within the city, such as a Brazilian
+    /// _bairro_.
+    #[must_use]
+    pub fn with_dependent_locality(mut self, dependent_locality: impl Into<String>) -> Self {
+        self.dependent_locality = dependent_locality.into();
+        self
+    }
+
+    /// Sets the city or town.
+    #[must_use]
+    pub fn with_locality(mut self, locality: impl Into<String>) -> Self {
+        self.locality = locality.into();
+        self
+    }
+
+    /// Sets the state, province, prefecture or county.
+    #[must_use]
+    pub fn with_administrative_area(mut self, administrative_area: impl Into<String>) -> Self {
+        self.administrative_area = administrative_area.into();
+        self
+    }
+
+    /// Sets the postal code.
+    #[must_use]
+    pub fn with_postal_code(mut self, postal_code: impl Into<String>) -> Self {
+        self.postal_code = postal_code.into();
+        self
+    }
+
+    /// Sets the country, which picks the template the address is formatted with.
+    #[must_use]
+    pub const fn with_country(mut self, country: Region) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    /// Returns the name of the person the mail is for.
+    #[must_use]
+    pub fn recipient(&self) -> &str {
+        &self.recipient
+    }
+
+    /// Returns the company or organization.
+    #[must_use]
+    pub fn organization(&self) -> &str {
+        &self.organization
+    }
+
+    /// Returns the lines of the street address.
+    #[must_use]
+    pub fn street_lines(&self) -> &[String] {
+        &self.street_lines
+    }
+
+    /// Returns the neighbourhood or district within the city.
+    #[must_use]
+    pub fn dependent_locality(&self) -> &str {
+        &self.dependent_locality
+    }
+
+    /// Returns the city or town.
+    #[must_use]
+    pub fn locality(&self) -> &str {
+        &self.locality
+    }
+
+    /// Returns the state, province, prefecture or county.
+    #[must_use]
+    pub fn administrative_area(&self) -> &str {
+        &self.administrative_area
+    }
+
+    /// Returns the postal code.
+    #[must_use]
+    pub fn postal_code(&self) -> &str {
+        &self.postal_code
+    }
+
+    /// Returns the country.
+    #[must_use]
+    pub const fn country(&self) -> Option<Region> {
+        self.country
+    }
+
+    /// Checks that the address has the components its country requires and that its
+    /// postal code has the country's shape.
+    ///
+    /// Countries outside the built-in set only require a street and a city.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first [`AddressError`] found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::errors::AddressError;
+    /// use libx::formatting::address::Address;
+    /// use libx::locale::Region;
+    ///
+    /// let address = Address::new()
+    ///     .with_street_line("24 Sussex Drive")
+    ///     .with_locality("Ottawa")
+    ///     .with_administrative_area("ON")
+    ///     .with_postal_code("K1M 1M4")
+    ///     .with_country(Region::from_code("CA").unwrap());
+    /// assert_eq!(address.validate(), Ok(()));
+    ///
+    /// let address = address.with_postal_code("K1M1M4X");
+    /// assert_eq!(address.validate(), Err(AddressError::InvalidPostalCode));
+    /// ```
+    pub fn validate(&self) -> Result<(), AddressError> {
+        let format = self.country.and_then(built_in_format);
+        let required = format.map_or("AC", |format| format.required);
+        let checks = [
+            ('A', AddressError::MissingStreet),
+            ('C', AddressError::MissingLocality),
+            ('S', AddressError::MissingAdministrativeArea),
+            ('Z', AddressError::MissingPostalCode),
+        ];
+
+        for (code, error) in checks {
+            if required.contains(code) && field(self, code).is_empty() {
+                return Err(error);
+            }
+        }
+
+        let valid = self.postal_code.trim().is_empty()
+            || self
+                .country
+                .and_then(|country| is_valid_postal_code(country, &self.postal_code))
+                .unwrap_or(true);
+
+        if valid {
+            Ok(())
+        } else {
+            Err(AddressError::InvalidPostalCode)
+        }
+    }
+}
+
+/// Returns whether `postal_code` has the shape used in `country`, or `None` if the
+/// country's postal codes are not known.
+///
+/// Letters are matched case-insensitively and surrounding whitespace is ignored.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::address::is_valid_postal_code;
+/// use libx::locale::Region;
+///
+/// let gb = Region::from_code("GB").unwrap();
+/// assert_eq!(is_valid_postal_code(gb, "SW1A 1AA"), Some(true));
+/// assert_eq!(is_valid_postal_code(gb, "SW1A1AA"), Some(false));
+///
+/// let us = Region::from_code("US").unwrap();
+/// assert_eq!(is_valid_postal_code(us, "20500-0003"), Some(true));
+///
+/// let fj = Region::from_code("FJ").unwrap();
+/// assert_eq!(is_valid_postal_code(fj, "anything"), None);
+/// ```
+#[must_use]
+pub fn is_valid_postal_code(country: Region, postal_code: &str) -> Option<bool> {
+    let shapes = built_in_format(country)?.postal_codes;
+    let postal_code = postal_code.trim();
+
+    Some(shapes.split('|').any(|shape| {
+        shape.len() == postal_code.len()
+            && shape
+                .bytes()
+                .zip(postal_code.bytes())
+                .all(|(expected, byte)| match expected {
+                    b'9' => byte.is_ascii_digit(),
+                    b'A' => byte.is_ascii_alphabetic(),
+                    _ => byte == expected,
+                })
+    }))
+}
+
+/// Formats [`Address`]es as lines of text in the order their country uses.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::address::{Address, AddressFormatter};
+/// use libx::locale::Region;
+///
+/// let address = Address::new()
+///     .with_recipient("Jane Doe")
+///     .with_street_line("1600 Pennsylvania Ave NW")
+///     .with_locality("Washington")
+///     .with_administrative_area("DC")
+///     .with_postal_code("20500")
+///     .with_country(Region::from_code("US").unwrap());
+///
+/// let formatter = AddressFormatter::new().with_home_region(Region::from_code("US"));
+/// assert_eq!(
+///     formatter.format(&address),
+///     "Jane Doe\n1600 Pennsylvania Ave NW\nWashington, DC 20500"
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddressFormatter {
+    home_region: Option<Region>,
+    templates: Vec<(Region, Cow<'static, str>)>,
+}
+
+impl AddressFormatter {
+    /// Creates a formatter that writes the country of every address that has one.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            home_region: None,
+            templates: Vec::new(),
+        }
+    }
+
+    /// Takes the home region from the region of `locale`.
+    #[must_use]
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.home_region = locale.region();
+        self
+    }
+
+    /// Sets the region whose name is left out of addresses within it.
+    #[must_use]
+    pub const fn with_home_region(mut self, home_region: Option<Region>) -> Self {
+        self.home_region = home_region;
+        self
+    }
+
+    /// Sets the template for addresses in `country`, replacing the built-in one.
+    ///
+    /// The template uses the field codes described in the [module
+    /// documentation](self).
+    #[must_use]
+    pub fn with_template(
+        mut self,
+        country: Region,
+        template: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.templates.retain(|(region, _)| *region != country);
+        self.templates.push((country, template.into()));
+        self
+    }
+
+    /// Returns the region whose name is left out of addresses within it.
+    #[must_use]
+    pub const fn home_region(&self) -> Option<Region> {
+        self.home_region
+    }
+
+    /// Returns the template used for addresses in `country`.
+    #[must_use]
+    pub fn template(&self, country: Option<Region>) -> &str {
+        let Some(country) = country else {
+            return DEFAULT_TEMPLATE;
+        };
+
+        self.templates
+            .iter()
+            .find(|(region, _)| *region == country)
+            .map(|(_, template)| template.as_ref())
+            .or_else(|| built_in_format(country).map(|format| format.template))
+            .unwrap_or(DEFAULT_TEMPLATE)
+    }
+
+    /// Formats `address` as a string, with lines separated by `\n`.
+    #[must_use]
+    pub fn format(&self, address: &Address) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(address, &mut output);
+        output
+    }
+
+    /// Formats `address` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        address: &Address,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        let mut first = true;
+        let mut line = String::new();
+
+        for template in self.template(address.country).split("%n") {
+            line.clear();
+            render_line(template, address, &mut line);
+
+            if !line.is_empty() {
+                if !first {
+                    sink.write_char('\n')?;
+                }
+
+                sink.write_str(&line)?;
+                first = false;
+            }
+        }
+
+        if let Some(country) = address
+            .country
+            .filter(|&country| Some(country) != self.home_region)
+        {
+            if !first {
+                sink.write_char('\n')?;
+            }
+
+            for character in country.name().chars().flat_map(char::to_uppercase) {
+                sink.write_char(character)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders one line of a template into `line`.
+///
+/// Text before the first field is kept only if that field is present, and text after a
+/// present field is kept only if another present field follows it on the line.
+fn render_line(template: &str, address: &Address, line: &mut String) {
+    let mut literal = String::new();
+    let mut seen_field = false;
+    let mut pending: Option<String> = None;
+    let mut characters = template.chars();
+
+    while let Some(character) = characters.next() {
+        if character != '%' {
+            literal.push(character);
+            continue;
+        }
+
+        let code = characters.next().unwrap_or('%');
+
+        if code == '%' {
+            literal.push('%');
+            continue;
+        }
+
+        if !seen_field || (!line.is_empty() && pending.is_none()) {
+            pending = Some(core::mem::take(&mut literal));
+        } else {
+            literal.clear();
+        }
+
+        let value = field(address, code);
+
+        if value.is_empty() {
+            if line.is_empty() {
+                pending = None;
+            }
+        } else {
+            if let Some(separator) = pending.take() {
+                line.push_str(&separator);
+            }
+
+            line.push_str(&value);
+        }
+
+        seen_field = true;
+    }
+}
+
+/// Returns the value of the field with template code `code`.
+fn field(address: &Address, code: char) -> Cow<'_, str> {
+    let value = match code {
+        'N' => &address.recipient,
+        'O' => &address.organization,
+        'A' => {
+            let lines: Vec<_> = address
+                .street_lines
+                .iter()
+                .map(|line| line.trim())
+                .filter(|line| !line.is_empty())
+                .collect();
+            return Cow::Owned(lines.join("\n"));
+        }
+        'D' => &address.dependent_locality,
+        'C' => &address.locality,
+        'S' => &address.administrative_area,
+        'Z' => &address.postal_code,
+        _ => return Cow::Borrowed(""),
+    };
+
+    Cow::Borrowed(value.trim())
+}
+
+/// The template, required fields and postal code shapes of a country in the built-in
+/// set.
+#[derive(Debug)]
+struct CountryFormat {
+    country: &'static str,
+    template: &'static str,
+    required: &'static str,
+    postal_codes: &'static str,
+}
+
+fn built_in_format(country: Region) -> Option<&'static CountryFormat> {
+    let code = country.alpha2_code()?;
+
+    FORMATS
+        .binary_search_by_key(&code, |format| format.country)
+        .ok()
+        .map(|index| &FORMATS[index])
+}
+
+/// Templates after Google's address data, with postal code shapes written with `9` for
+/// a digit and `A` for a letter, alternatives separated by `|`. Sorted by country.
+static FORMATS: &[CountryFormat] = &[
+    CountryFormat {
+        country: "AU",
+        template: "%O%n%N%n%A%n%C %S %Z",
+        required: "ACSZ",
+        postal_codes: "9999",
+    },
+    CountryFormat {
+        country: "BR",
+        template: "%O%n%N%n%A%n%D%n%C-%S%n%Z",
+        required: "ACSZ",
+        postal_codes: "99999-999",
+    },
+    CountryFormat {
+        country: "CA",
+        template: "%N%n%O%n%A%n%C %S %Z",
+        required: "ACSZ",
+        postal_codes: "A9A 9A9",
+    },
+    CountryFormat {
+        country: "CH",
+        template: "%O%n%N%n%A%n%Z %C",
+        required: "ACZ",
+        postal_codes: "9999",
+    },
+    CountryFormat {
+        country: "CN",
+        template: "%Z%n%S%C%D%n%A%n%O%n%N",
+        required: "ACS",
+        postal_codes: "999999",
+    },
+    CountryFormat {
+        country: "DE",
+        template: "%N%n%O%n%A%n%Z %C",
+        required: "ACZ",
+        postal_codes: "99999",
+    },
+    CountryFormat {
+        country: "ES",
+        template: "%N%n%O%n%A%n%Z %C %S",
+        required: "ACSZ",
+        postal_codes: "99999",
+    },
+    CountryFormat {
+        country: "FR",
+        template: "%O%n%N%n%A%n%Z %C",
+        required: "ACZ",
+        postal_codes: "99999",
+    },
+    CountryFormat {
+        country: "GB",
+        template: "%N%n%O%n%A%n%C%n%Z",
+        required: "ACZ",
+        postal_codes: "A9 9AA|A99 9AA|A9A 9AA|AA9 9AA|AA99 9AA|AA9A 9AA",
+    },
+    CountryFormat {
+        country: "IN",
+        template: "%N%n%O%n%A%n%D%n%C %Z%n%S",
+        required: "ACSZ",
+        postal_codes: "999999",
+    },
+    CountryFormat {
+        country: "IT",
+        template: "%N%n%O%n%A%n%Z %C %S",
+        required: "ACSZ",
+        postal_codes: "99999",
+    },
+    CountryFormat {
+        country: "JP",
+        template: "〒%Z%n%S%C%n%A%n%O%n%N",
+        required: "ASZ",
+        postal_codes: "999-9999",
+    },
+    CountryFormat {
+        country: "MX",
+        template: "%N%n%O%n%A%n%D%n%Z %C, %S",
+        required: "ACZ",
+        postal_codes: "99999",
+    },
+    CountryFormat {
+        country: "NL",
+        template: "%O%n%N%n%A%n%Z %C",
+        required: "ACZ",
+        postal_codes: "9999 AA",
+    },
+    CountryFormat {
+        country: "RU",
+        template: "%N%n%O%n%A%n%C%n%S%n%Z",
+        required: "ACSZ",
+        postal_codes: "999999",
+    },
+    CountryFormat {
+        country: "SE",
+        template: "%O%n%N%n%A%n%Z %C",
+        required: "ACZ",
+        postal_codes: "999 99",
+    },
+    CountryFormat {
+        country: "US",
+        template: "%N%n%O%n%A%n%C, %S %Z",
+        required: "ACSZ",
+        postal_codes: "99999|99999-9999",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn country(code: &str) -> Region {
+        Region::from_code(code).expect("a known region")
+    }
+
+    #[test]
+    fn test_templates() {
+        assert!(FORMATS.is_sorted_by_key(|format| format.country));
+
+        let formatter = AddressFormatter::new().with_home_region(Some(country("JP")));
+        let address = Address::new()
+            .with_recipient("山田太郎")
+            .with_street_line("丸の内1-1")
+            .with_locality("千代田区")
+            .with_administrative_area("東京都")
+            .with_postal_code("100-0005")
+            .with_country(country("JP"));
+        assert_eq!(
+            formatter.format(&address),
+            "〒100-0005\n東京都千代田区\n丸の内1-1\n山田太郎"
+        );
+
+        let address = Address::new()
+            .with_organization("Ministério da Saúde")
+            .with_street_line("Esplanada dos Ministérios, Bloco G")
+            .with_dependent_locality("Zona Cívico-Administrativa")
+            .with_locality("Brasília")
+            .with_administrative_area("DF")
+            .with_postal_code("70058-900")
+            .with_country(country("BR"));
+        assert_eq!(
+            formatter.format(&address),
+            "Ministério da Saúde\nEsplanada dos Ministérios, Bloco G\n\
+             Zona Cívico-Administrativa\nBrasília-DF\n70058-900\nBRAZIL"
+        );
+
+        let unknown = Address::new()
+            .with_recipient("Ana")
+            .with_street_line(" 1 Main St ")
+            .with_street_line("")
+            .with_locality("Suva")
+            .with_country(country("FJ"));
+        assert_eq!(formatter.format(&unknown), "Ana\n1 Main St\nSuva\nFIJI");
+        assert_eq!(formatter.format(&Address::new()), "");
+    }
+
+    #[test]
+    fn test_missing_fields_drop_separators() {
+        let formatter = AddressFormatter::new().with_home_region(Some(country("US")));
+        let address = Address::new()
+            .with_street_line("1 Infinite Loop")
+            .with_locality("Cupertino")
+            .with_country(country("US"));
+
+        assert_eq!(formatter.format(&address), "1 Infinite Loop\nCupertino");
+        assert_eq!(
+            formatter.format(&address.clone().with_postal_code("95014")),
+            "1 Infinite Loop\nCupertino, 95014"
+        );
+        assert_eq!(
+            formatter.format(&address.with_locality("").with_administrative_area("CA")),
+            "1 Infinite Loop\nCA"
+        );
+
+        let custom = formatter.with_template(country("US"), "%A%n%Z %C");
+        let address = Address::new()
+            .with_street_line("1 Infinite Loop")
+            .with_locality("Cupertino")
+            .with_postal_code("95014")
+            .with_country(country("US"));
+        assert_eq!(custom.format(&address), "1 Infinite Loop\n95014 Cupertino");
+    }
+
+    #[test]
+    fn test_validate() {
+        let address = Address::new()
+            .with_street_line("10 Downing Street")
+            .with_locality("London")
+            .with_country(country("GB"));
+        assert_eq!(address.validate(), Err(AddressError::MissingPostalCode));
+
+        let address = address.with_postal_code("sw1a 2aa");
+        assert_eq!(address.validate(), Ok(()));
+        assert_eq!(
+            address.with_postal_code("SW1 2A").validate(),
+            Err(AddressError::InvalidPostalCode)
+        );
+
+        let address = Address::new()
+            .with_street_line("1 Infinite Loop")
+            .with_locality("Cupertino")
+            .with_postal_code("95014")
+            .with_country(country("US"));
+        assert_eq!(
+            address.validate(),
+            Err(AddressError::MissingAdministrativeArea)
+        );
+        assert_eq!(Address::new().validate(), Err(AddressError::MissingStreet));
+        assert_eq!(
+            Address::new().with_street_line("Main St").validate(),
+            Err(AddressError::MissingLocality)
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Summaries of how a whole splits into labeled parts, such as
+//! `CPU 42.1%, IO 31.0%, idle 26.9%`.
+//!
+//! Rounding each share on its own can make the shown percentages add up to 99.9% or
+//! 100.1%. [`BreakdownFormatter`] rounds with [`largest_remainder`] instead, so the
+//! shares always total exactly 100%.
+
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+use core::fmt::Write;
+
+use super::numbers::{NumberFormatter, NumberStyle, Value};
+use crate::{errors::FormatError, locale::Locale, num::decimal::Decimal};
+
+/// The most fraction digits a [`BreakdownFormatter`] shows.
+pub const MAX_FRACTION_DIGITS: u8 = 9;
+
+/// Splits `total` units between `weights` in proportion, so that the parts add up to
+/// exactly `total`.
+///
+/// Every part first gets the whole units of its exact share. The units left over go one
+/// each to the parts with the largest fractional remainders, the earlier part first
+/// among equals. This is the largest remainder, or Hamilton, method.
+///
+/// Returns `None` if a weight is negative or not finite. If every weight is zero, so is
+/// every part.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::breakdown::largest_remainder;
+///
+/// assert_eq!(largest_remainder(&[1.0, 1.0, 1.0], 100), Some(vec![34, 33, 33]));
+/// assert_eq!(largest_remainder(&[2.0, 0.0, 1.0], 10), Some(vec![7, 0, 3]));
+/// assert_eq!(largest_remainder(&[1.0, -1.0], 10), None);
+/// ```
+#[must_use]
+pub fn largest_remainder(weights: &[f64], total: u64) -> Option<Vec<u64>> {
+    if weights
+        .iter()
+        .any(|weight| !weight.is_finite() || *weight < 0.0)
+    {
+        return None;
+    }
+
+    // Dividing by the largest weight first keeps the sum finite.
+    let largest = weights.iter().copied().fold(0.0, f64::max);
+
+    if largest <= 0.0 {
+        return Some(vec![0; weights.len()]);
+    }
+
+    let sum: f64 = weights.iter().map(|weight| weight / largest).sum();
+    #[allow(clippy::cast_precision_loss)] // Totals are counts of display units.
+    let scale = total as f64 / sum;
+
+    let mut parts = Vec::with_capacity(weights.len());
+    let mut remainders = Vec::with_capacity(weights.len());
+
+    for weight in weights {
+        let exact = weight / largest * scale;
+        // `exact` is between zero and `total`, so truncating it is its floor.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let whole = (exact as u64).min(total);
+
+        parts.push(whole);
+        #[allow(clippy::cast_precision_loss)]
+        remainders.push(exact - whole as f64);
+    }
+
+    let mut order: Vec<usize> = (0..weights.len()).collect();
+    order.sort_by(|&a, &b| remainders[b].total_cmp(&remainders[a]));
+
+    let assigned: u64 = parts.iter().sum();
+
+    if assigned <= total {
+        let leftover = usize::try_from(total - assigned).unwrap_or(usize::MAX);
+
+        for &index in order.iter().cycle().take(leftover) {
+            parts[index] += 1;
+        }
+    } else {
+        // Floating-point error gave out too much; take it back from the parts that were
+        // rounded down the least.
+        let mut excess = assigned - total;
+
+        for &index in order.iter().rev().cycle() {
+            if excess == 0 {
+                break;
+            }
+
+            if parts[index] > 0 {
+                parts[index] -= 1;
+                excess -= 1;
+            }
+        }
+    }
+
+    Some(parts)
+}
+
+/// Formats labeled parts as their percentages of the whole, such as
+/// `CPU 42.1%, IO 31.0%, idle 26.9%`.
+///
+/// The percentages are rounded with [`largest_remainder`], so they always add up to
+/// exactly 100%. Every percentage is shown with the same number of fraction digits,
+/// one by default.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::breakdown::BreakdownFormatter;
+///
+/// let formatter = BreakdownFormatter::new();
+/// let parts = [("CPU", 421.0), ("IO", 310.0), ("idle", 269.0)];
+/// assert_eq!(formatter.format(&parts), "CPU 42.1%, IO 31.0%, idle 26.9%");
+///
+/// // Rounded on their own, these would show 33.3% three times.
+/// let thirds = [("a", 1.0), ("b", 1.0), ("c", 1.0)];
+/// assert_eq!(formatter.format(&thirds), "a 33.4%, b 33.3%, c 33.3%");
+///
+/// let whole = formatter.with_fraction_digits(0).with_separator(" / ");
+/// assert_eq!(whole.format(&thirds), "a 34% / b 33% / c 33%");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BreakdownFormatter {
+    number_formatter: NumberFormatter,
+    fraction_digits: u8,
+    separator: Cow<'static, str>,
+    label_separator: Cow<'static, str>,
+}
+
+impl BreakdownFormatter {
+    /// Creates a formatter that shows one fraction digit and separates parts with
+    /// `", "`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            number_formatter: NumberFormatter::new()
+                .with_style(NumberStyle::Percent)
+                .with_minimum_fraction_digits(1)
+                .with_maximum_fraction_digits(1),
+            fraction_digits: 1,
+            separator: Cow::Borrowed(", "),
+            label_separator: Cow::Borrowed(" "),
+        }
+    }
+
+    /// Takes the separators, percent sign and digits of the percentages from `locale`.
+    #[must_use]
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.number_formatter = self.number_formatter.with_locale(locale);
+        self
+    }
+
+    /// Sets the number of fraction digits of every percentage, at most
+    /// [`MAX_FRACTION_DIGITS`].
+    #[must_use]
+    pub fn with_fraction_digits(mut self, digits: u8) -> Self {
+        let digits = digits.min(MAX_FRACTION_DIGITS);
+
+        self.fraction_digits = digits;
+        self.number_formatter = self
+            .number_formatter
+            .with_maximum_fraction_digits(usize::from(digits))
+            .with_minimum_fraction_digits(usize::from(digits));
+        self
+    }
+
+    /// Sets the text written between parts.
+    #[must_use]
+    pub fn with_separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the text written between a label and its percentage.
+    #[must_use]
+    pub fn with_label_separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.label_separator = separator.into();
+        self
+    }
+
+    /// Returns the number of fraction digits of every percentage.
+    #[must_use]
+    pub const fn fraction_digits(&self) -> u8 {
+        self.fraction_digits
+    }
+
+    /// Returns the text written between parts.
+    #[must_use]
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+
+    /// Returns the text written between a label and its percentage.
+    #[must_use]
+    pub fn label_separator(&self) -> &str {
+        &self.label_separator
+    }
+
+    /// Returns the rounded percentage of each value, which add up to exactly 100 unless
+    /// every value is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Unsupported`] if a value is negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::formatting::breakdown::BreakdownFormatter;
+    /// use libx::num::decimal::Decimal;
+    ///
+    /// let shares = BreakdownFormatter::new().percentages(&[2.0, 1.0])?;
+    /// assert_eq!(shares, [Decimal::new(667, 1), Decimal::new(333, 1)]);
+    /// # Ok::<(), libx::errors::FormatError>(())
+    /// ```
+    pub fn percentages(&self, values: &[f64]) -> Result<Vec<Decimal>, FormatError> {
+        self.units(values).map(|units| {
+            units
+                .into_iter()
+                .map(|units| Decimal::new(i128::from(units), self.fraction_digits))
+                .collect()
+        })
+    }
+
+    /// Formats `parts` into a new string.
+    ///
+    /// Parts that cannot be formatted, because a value is negative or not finite, give an
+    /// empty string.
+    #[must_use]
+    pub fn format(&self, parts: &[(&str, f64)]) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(parts, &mut output);
+        output
+    }
+
+    /// Formats `parts` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Unsupported`] if a value is negative or not finite, and
+    /// [`FormatError::Write`] if the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        parts: &[(&str, f64)],
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        let values: Vec<f64> = parts.iter().map(|&(_, value)| value).collect();
+        let units = self.units(&values)?;
+
+        for (index, (&(label, _), units)) in parts.iter().zip(units).enumerate() {
+            if index > 0 {
+                sink.write_str(&self.separator)?;
+            }
+
+            sink.write_str(label)?;
+            sink.write_str(&self.label_separator)?;
+
+            // The percent style multiplies by 100, so the share is written as a fraction
+            // of one.
+            let share = Decimal::new(i128::from(units), self.fraction_digits + 2);
+            self.number_formatter
+                .write_value(Value::Decimal(share), sink)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns each share in units of the last shown digit of a percentage.
+    fn units(&self, values: &[f64]) -> Result<Vec<u64>, FormatError> {
+        let total = 100 * 10_u64.pow(u32::from(self.fraction_digits));
+
+        largest_remainder(values, total).ok_or(FormatError::Unsupported)
+    }
+}
+
+impl Default for BreakdownFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    #[test]
+    fn test_format() {
+        let formatter = BreakdownFormatter::new().with_label_separator(": ");
+        let parts = [("reads", 1.0), ("writes", 2.0), ("idle", 0.0)];
+
+        assert_eq!(
+            formatter.format(&parts),
+            "reads: 33.3%, writes: 66.7%, idle: 0.0%"
+        );
+        assert_eq!(formatter.format(&[]), "");
+        assert_eq!(formatter.format(&[("none", 0.0)]), "none: 0.0%");
+        assert_eq!(formatter.format(&[("bad", f64::NAN)]), "");
+
+        let mut output = String::new();
+        assert_eq!(
+            formatter.write(&[("bad", -1.0)], &mut output),
+            Err(FormatError::Unsupported)
+        );
+
+        let german = BreakdownFormatter::new()
+            .with_locale(&Locale::new("de_DE"))
+            .with_fraction_digits(2);
+        assert_eq!(
+            german.format(&[("a", 1.0), ("b", 2.0)]),
+            "a 33,33%, b 66,67%"
+        );
+        assert_eq!(german.fraction_digits(), 2);
+        assert_eq!(
+            BreakdownFormatter::new()
+                .with_fraction_digits(40)
+                .fraction_digits(),
+            MAX_FRACTION_DIGITS
+        );
+    }
+
+    #[test]
+    fn test_largest_remainder_ties_and_extremes() {
+        assert_eq!(
+            largest_remainder(&[1.0; 6], 100),
+            Some(vec![17, 17, 17, 17, 16, 16])
+        );
+        assert_eq!(
+            largest_remainder(&[f64::MAX, f64::MAX], 3),
+            Some(vec![2, 1])
+        );
+        assert_eq!(largest_remainder(&[1e-300, 1.0], 1000), Some(vec![0, 1000]));
+        assert_eq!(largest_remainder(&[], 100), Some(vec![]));
+        assert_eq!(largest_remainder(&[f64::INFINITY], 100), None);
+    }
+
+    #[test]
+    fn test_largest_remainder_totals_exactly() {
+        const SCALES: [f64; 6] = [1.0, 10.0, 100.0, 1e3, 1e4, 1e5];
+
+        let result = check_property(0x00b4_ea4d, 500, |rng| {
+            #[allow(clippy::cast_possible_truncation)] // Bounded by 12.
+            let count = rng.next_bounded(12) as usize + 1;
+            #[allow(clippy::cast_possible_truncation)] // Bounded by 6.
+            let weights: Vec<f64> = (0..count)
+                .map(|_| rng.next_f64() * SCALES[rng.next_bounded(6) as usize])
+                .collect();
+            let total = rng.next_bounded(100_000);
+            let sum: f64 = weights.iter().sum();
+
+            let parts = largest_remainder(&weights, total)
+                .ok_or_else(|| alloc::format!("{weights:?} were rejected"))?;
+
+            if parts.iter().sum::<u64>() != total {
+                return Err(alloc::format!("{weights:?} split {total} into {parts:?}").into());
+            }
+
+            for (part, weight) in parts.iter().zip(&weights) {
+                #[allow(clippy::cast_precision_loss)]
+                let exact = weight / sum * total as f64;
+                #[allow(clippy::cast_precision_loss)]
+                let error = (*part as f64 - exact).abs();
+
+                if error >= 1.0 + 1e-6 {
+                    return Err(alloc::format!("{weights:?} gave {part} for {exact}").into());
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
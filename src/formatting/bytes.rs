@@ -0,0 +1,306 @@
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::numbers::{NumberFormatter, Value};
+use crate::{bitmask, errors::FormatError, locale::Locale, option_set::OptionSet};
+
+bitmask! {
+    /// The units a [`ByteCountFormatter`] may choose from.
+    pub struct ByteCountFormatterUnits: u8 {
+        /// Plain bytes.
+        const BYTES = 1 << 0;
+        /// Kilobytes or kibibytes.
+        const KB = 1 << 1;
+        /// Megabytes or mebibytes.
+        const MB = 1 << 2;
+        /// Gigabytes or gibibytes.
+        const GB = 1 << 3;
+        /// Terabytes or tebibytes.
+        const TB = 1 << 4;
+        /// Petabytes or pebibytes.
+        const PB = 1 << 5;
+        /// Exabytes or exbibytes.
+        const EB = 1 << 6;
+    }
+}
+
+/// How a [`ByteCountFormatter`] scales byte counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ByteCountStyle {
+    /// Powers of 1000 with SI symbols (`kB`, `MB`), as file sizes are usually shown.
+    #[default]
+    Decimal,
+
+    /// Powers of 1024 with IEC symbols (`KiB`, `MiB`), as memory sizes are usually shown.
+    Binary,
+}
+
+impl ByteCountStyle {
+    const fn base(self) -> f64 {
+        match self {
+            Self::Decimal => 1000.0,
+            Self::Binary => 1024.0,
+        }
+    }
+
+    const fn symbols(self) -> [&'static str; 6] {
+        match self {
+            Self::Decimal => ["kB", "MB", "GB", "TB", "PB", "EB"],
+            Self::Binary => ["KiB", "MiB", "GiB", "TiB", "PiB", "EiB"],
+        }
+    }
+}
+
+/// Formats byte counts in the largest allowed unit, such as `1.5 MB`.
+///
+/// Kilobytes are shown without fractions, megabytes with up to one fraction digit and
+/// larger units with up to two. The unit is picked after rounding, so `999,999` bytes
+/// is `1 MB` rather than `1,000 kB`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::bytes::{ByteCountFormatter, ByteCountFormatterUnits, ByteCountStyle};
+///
+/// let formatter = ByteCountFormatter::new();
+///
+/// assert_eq!(formatter.format(1), "1 byte");
+/// assert_eq!(formatter.format(1_536_000), "1.5 MB");
+/// assert_eq!(formatter.format(999_999), "1 MB");
+///
+/// let memory = formatter.with_style(ByteCountStyle::Binary);
+/// assert_eq!(memory.format(3 * 1024 * 1024 * 1024), "3 GiB");
+///
+/// let kilobytes = ByteCountFormatter::new().with_allowed_units(ByteCountFormatterUnits::KB);
+/// assert_eq!(kilobytes.format(12_345_678), "12,346 kB");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ByteCountFormatter {
+    style: ByteCountStyle,
+    allowed_units: ByteCountFormatterUnits,
+    includes_unit: bool,
+    includes_count: bool,
+    number_formatter: NumberFormatter,
+}
+
+impl ByteCountFormatter {
+    /// Creates a decimal formatter that may use any unit.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: ByteCountStyle::Decimal,
+            allowed_units: ByteCountFormatterUnits::from_raw_value(0x7F),
+            includes_unit: true,
+            includes_count: true,
+            number_formatter: NumberFormatter::new().with_uses_grouping_separator(true),
+        }
+    }
+
+    /// Takes the separators and digits of the count from `locale`.
+    #[must_use]
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.number_formatter = self.number_formatter.with_locale(locale);
+        self
+    }
+
+    /// Sets how counts are scaled.
+    #[must_use]
+    pub const fn with_style(mut self, style: ByteCountStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the units the formatter may choose from; an empty set allows every unit.
+    #[must_use]
+    pub const fn with_allowed_units(mut self, units: ByteCountFormatterUnits) -> Self {
+        self.allowed_units = units;
+        self
+    }
+
+    /// Sets whether the unit is written.
+    #[must_use]
+    pub const fn with_includes_unit(mut self, includes_unit: bool) -> Self {
+        self.includes_unit = includes_unit;
+        self
+    }
+
+    /// Sets whether the count is written.
+    #[must_use]
+    pub const fn with_includes_count(mut self, includes_count: bool) -> Self {
+        self.includes_count = includes_count;
+        self
+    }
+
+    /// Returns how counts are scaled.
+    #[must_use]
+    pub const fn style(&self) -> ByteCountStyle {
+        self.style
+    }
+
+    /// Returns the units the formatter may choose from.
+    #[must_use]
+    pub const fn allowed_units(&self) -> ByteCountFormatterUnits {
+        self.allowed_units
+    }
+
+    /// Returns `true` if the unit is written.
+    #[must_use]
+    pub const fn includes_unit(&self) -> bool {
+        self.includes_unit
+    }
+
+    /// Returns `true` if the count is written.
+    #[must_use]
+    pub const fn includes_count(&self) -> bool {
+        self.includes_count
+    }
+
+    /// Formats `byte_count` as a string.
+    #[must_use]
+    pub fn format(&self, byte_count: i64) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(byte_count, &mut output);
+        output
+    }
+
+    /// Formats `byte_count` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        byte_count: i64,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        #[allow(clippy::cast_precision_loss)] // At most two fraction digits are shown.
+        let magnitude = byte_count.unsigned_abs() as f64;
+
+        let (unit, scaled) = self.unit(magnitude);
+        let formatter = self
+            .number_formatter
+            .clone()
+            .with_maximum_fraction_digits(fraction_digits(unit));
+
+        if self.includes_count {
+            let value = if unit == 0 {
+                Value::Integer(i128::from(byte_count))
+            } else {
+                Value::Float(if byte_count < 0 { -scaled } else { scaled })
+            };
+
+            formatter.write_value(value, sink)?;
+
+            if self.includes_unit {
+                sink.write_char(' ')?;
+            }
+        }
+
+        if self.includes_unit {
+            match unit {
+                0 if byte_count.unsigned_abs() == 1 => sink.write_str("byte")?,
+                0 => sink.write_str("bytes")?,
+                _ => sink.write_str(self.style.symbols()[unit - 1])?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the index of the unit `magnitude` is shown in, zero for bytes, and the
+    /// magnitude scaled to it.
+    fn unit(&self, magnitude: f64) -> (usize, f64) {
+        let allowed = if self.allowed_units.is_empty() {
+            ByteCountFormatterUnits::all()
+        } else {
+            self.allowed_units
+        };
+        let mut units = ByteCountFormatterUnits::FLAGS
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(_, flag))| allowed.contains(flag))
+            .map(|(index, _)| index);
+
+        let base = self.style.base();
+        let scale = |unit: usize| (0..unit).fold(1.0, |scale, _| scale * base);
+
+        let mut unit = units.next().unwrap_or(0);
+
+        for candidate in units {
+            let scaled = magnitude / scale(unit);
+            let digits = fraction_digits(unit);
+            let rounded = alloc::format!("{scaled:.digits$}")
+                .parse::<f64>()
+                .unwrap_or(scaled);
+
+            if rounded * scale(unit) < scale(candidate) {
+                break;
+            }
+
+            unit = candidate;
+        }
+
+        (unit, magnitude / scale(unit))
+    }
+}
+
+impl Default for ByteCountFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Returns the most fraction digits shown for the unit at `index`.
+const fn fraction_digits(index: usize) -> usize {
+    match index {
+        0 | 1 => 0,
+        2 => 1,
+        _ => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_and_binary_units() {
+        let formatter = ByteCountFormatter::new();
+
+        assert_eq!(formatter.format(0), "0 bytes");
+        assert_eq!(formatter.format(999), "999 bytes");
+        assert_eq!(formatter.format(1000), "1 kB");
+        assert_eq!(formatter.format(-2_500_000), "-2.5 MB");
+        assert_eq!(formatter.format(1_234_567_890), "1.23 GB");
+        assert_eq!(formatter.format(i64::MAX), "9.22 EB");
+
+        let binary = formatter.with_style(ByteCountStyle::Binary);
+        assert_eq!(binary.format(1023), "1,023 bytes");
+        assert_eq!(binary.format(1024), "1 KiB");
+        assert_eq!(binary.format(1_048_575), "1 MiB");
+    }
+
+    #[test]
+    fn test_allowed_units_and_parts() {
+        let megabytes = ByteCountFormatter::new()
+            .with_allowed_units(ByteCountFormatterUnits::MB | ByteCountFormatterUnits::GB);
+
+        assert_eq!(megabytes.format(1_000), "0 MB");
+        assert_eq!(megabytes.format(2_000_000_000_000), "2,000 GB");
+
+        let count_only = ByteCountFormatter::new().with_includes_unit(false);
+        assert_eq!(count_only.format(1_500_000), "1.5");
+
+        let unit_only = ByteCountFormatter::new().with_includes_count(false);
+        assert_eq!(unit_only.format(1_500_000), "MB");
+    }
+
+    #[test]
+    fn test_locale() {
+        let german = ByteCountFormatter::new().with_locale(&Locale::new("de_DE"));
+
+        assert_eq!(german.format(1_536_000), "1,5 MB");
+        assert_eq!(german.format(2048), "2 kB");
+    }
+}
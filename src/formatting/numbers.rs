@@ -0,0 +1,1097 @@
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+};
+use core::fmt::{self, Write};
+
+use crate::{
+    errors::FormatError,
+    locale::{CompactUnit, Currency, Locale, NumberSymbols, NumberingSystem, TextDirection},
+    num::{
+        Number,
+        decimal::{self, Decimal, RoundingMode},
+    },
+};
+
+/// How a [`NumberFormatter`] presents a value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumberStyle {
+    /// A plain decimal number, such as `1,234.5`.
+    #[default]
+    Decimal,
+
+    /// A percentage, where `1.0` is formatted as `100%`.
+    Percent,
+
+    /// A per-mille value, where `1.0` is formatted as `1000‰`.
+    PerMille,
+
+    /// A compact number with an abbreviated unit, such as `1.2K`.
+    CompactShort,
+
+    /// A compact number with a spelled-out unit, such as `1.2 thousand`.
+    CompactLong,
+
+    /// An amount of money with a currency symbol before it, such as `$1,234.50`.
+    ///
+    /// [`with_currency`](NumberFormatter::with_currency) sets the symbol and the
+    /// fraction digits together.
+    Currency,
+}
+
+/// A value prepared for formatting: integers and decimals are kept exact, everything
+/// else goes through `f64`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Value {
+    Integer(i128),
+    Float(f64),
+    Decimal(Decimal),
+}
+
+impl Value {
+    /// Returns the value as the nearest `f64`, which may lose precision.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn to_f64(self) -> f64 {
+        match self {
+            Self::Integer(value) => value as f64,
+            Self::Float(value) => value,
+            Self::Decimal(value) => value.mantissa() as f64 / power_of_ten(value.scale()),
+        }
+    }
+}
+
+impl From<Number> for Value {
+    fn from(number: Number) -> Self {
+        match number {
+            Number::Bool(value) => Self::Integer(i128::from(value)),
+            Number::Int(value) => Self::Integer(value as i128),
+            Number::Int8(value) => Self::Integer(i128::from(value)),
+            Number::Int16(value) => Self::Integer(i128::from(value)),
+            Number::Int32(value) => Self::Integer(i128::from(value)),
+            Number::UInt(value) => Self::Integer(value as i128),
+            Number::UInt8(value) => Self::Integer(i128::from(value)),
+            Number::UInt16(value) => Self::Integer(i128::from(value)),
+            Number::UInt32(value) => Self::Integer(i128::from(value)),
+            Number::Float(value) => Self::Float(f64::from(value)),
+            Number::Double(value) => Self::Float(value),
+        }
+    }
+}
+
+/// Formats numbers as text with configurable precision, grouping and symbols.
+///
+/// Special floating-point values never fall through to Rust's `NaN` and `inf`: they are
+/// written with [`nan_symbol`](Self::nan_symbol) and the infinity symbols, and negative
+/// zero is written as zero unless
+/// [`with_treats_negative_zero_as_zero(false)`](Self::with_treats_negative_zero_as_zero)
+/// is set.
+///
+/// [`with_locale`](Self::with_locale) takes the separators, signs and digits from a
+/// [`Locale`]. When the locale is written right to left and
+/// [`with_uses_directionality_marks(true)`](Self::with_uses_directionality_marks) is
+/// set, the output is wrapped in right-to-left marks (U+200F) so that signs and
+/// symbols stay attached to the number when it is embedded in right-to-left text.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::{NumberFormatter, NumberStyle};
+///
+/// let formatter = NumberFormatter::new()
+///     .with_uses_grouping_separator(true)
+///     .with_maximum_fraction_digits(2);
+///
+/// assert_eq!(formatter.format(1234567.891), "1,234,567.89");
+/// assert_eq!(formatter.format(-42), "-42");
+/// assert_eq!(formatter.format(f64::INFINITY), "∞");
+///
+/// let percent = NumberFormatter::new().with_style(NumberStyle::Percent);
+/// assert_eq!(percent.format(0.256), "25.6%");
+/// ```
+///
+/// The compact styles pick the largest unit of the locale that fits the value and keep
+/// two significant digits, or every integer digit when there are more. Rounding happens
+/// before the unit is final, so a value that rounds up to the next unit is written with
+/// it:
+///
+/// ```
+/// use libx::formatting::{NumberFormatter, NumberStyle};
+///
+/// let compact = NumberFormatter::new().with_style(NumberStyle::CompactShort);
+///
+/// assert_eq!(compact.format(1234), "1.2K");
+/// assert_eq!(compact.format(123_456), "123K");
+/// assert_eq!(compact.format(999_950), "1M");
+///
+/// let long = NumberFormatter::new().with_style(NumberStyle::CompactLong);
+/// assert_eq!(long.format(2_500_000), "2.5 million");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormatter {
+    style: NumberStyle,
+    minimum_integer_digits: usize,
+    minimum_fraction_digits: usize,
+    maximum_fraction_digits: usize,
+    uses_grouping_separator: bool,
+    grouping_separator: Cow<'static, str>,
+    decimal_separator: Cow<'static, str>,
+    minus_sign: Cow<'static, str>,
+    percent_symbol: Cow<'static, str>,
+    per_mille_symbol: Cow<'static, str>,
+    currency_symbol: Cow<'static, str>,
+    numbering_system: NumberingSystem,
+    compact_units: &'static [CompactUnit],
+    text_direction: TextDirection,
+    uses_directionality_marks: bool,
+    nan_symbol: Cow<'static, str>,
+    positive_infinity_symbol: Cow<'static, str>,
+    negative_infinity_symbol: Cow<'static, str>,
+    zero_symbol: Option<Cow<'static, str>>,
+    treats_negative_zero_as_zero: bool,
+}
+
+impl NumberFormatter {
+    /// Creates a decimal formatter with up to three fraction digits and no grouping.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: NumberStyle::Decimal,
+            minimum_integer_digits: 1,
+            minimum_fraction_digits: 0,
+            maximum_fraction_digits: 3,
+            uses_grouping_separator: false,
+            grouping_separator: Cow::Borrowed(","),
+            decimal_separator: Cow::Borrowed("."),
+            minus_sign: Cow::Borrowed("-"),
+            percent_symbol: Cow::Borrowed("%"),
+            per_mille_symbol: Cow::Borrowed("‰"),
+            currency_symbol: Cow::Borrowed("¤"),
+            numbering_system: NumberingSystem::Latin,
+            compact_units: NumberSymbols::ENGLISH.compact_units,
+            text_direction: TextDirection::LeftToRight,
+            uses_directionality_marks: false,
+            nan_symbol: Cow::Borrowed("NaN"),
+            positive_infinity_symbol: Cow::Borrowed("∞"),
+            negative_infinity_symbol: Cow::Borrowed("-∞"),
+            zero_symbol: None,
+            treats_negative_zero_as_zero: true,
+        }
+    }
+
+    /// Takes the separators, signs, digits and text direction from `locale`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::formatting::{NumberFormatter, NumberStyle};
+    /// use libx::locale::Locale;
+    ///
+    /// let german = NumberFormatter::new()
+    ///     .with_locale(&Locale::new("de_DE"))
+    ///     .with_uses_grouping_separator(true);
+    /// assert_eq!(german.format(1234.5), "1.234,5");
+    ///
+    /// let arabic = NumberFormatter::new().with_locale(&Locale::new("ar_EG"));
+    /// assert_eq!(arabic.format(12.5), "١٢٫٥");
+    /// ```
+    #[must_use]
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        let symbols = NumberSymbols::for_locale(locale);
+
+        self.decimal_separator = Cow::Borrowed(symbols.decimal_separator);
+        self.grouping_separator = Cow::Borrowed(symbols.grouping_separator);
+        self.minus_sign = Cow::Borrowed(symbols.minus_sign);
+        self.percent_symbol = Cow::Borrowed(symbols.percent_sign);
+        self.per_mille_symbol = Cow::Borrowed(symbols.per_mille_sign);
+        self.numbering_system = symbols.numbering_system;
+        self.compact_units = symbols.compact_units;
+        self.text_direction = locale.text_direction();
+        self
+    }
+
+    /// Sets the style of the formatter.
+    #[must_use]
+    pub const fn with_style(mut self, style: NumberStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets the minimum number of integer digits, padding with leading zeros.
+    #[must_use]
+    pub const fn with_minimum_integer_digits(mut self, digits: usize) -> Self {
+        self.minimum_integer_digits = digits;
+        self
+    }
+
+    /// Sets the minimum number of fraction digits, padding with trailing zeros.
+    ///
+    /// The maximum is raised to match if it is smaller.
+    #[must_use]
+    pub const fn with_minimum_fraction_digits(mut self, digits: usize) -> Self {
+        self.minimum_fraction_digits = digits;
+
+        if self.maximum_fraction_digits < digits {
+            self.maximum_fraction_digits = digits;
+        }
+
+        self
+    }
+
+    /// Sets the maximum number of fraction digits; values are rounded half to even.
+    ///
+    /// The minimum is lowered to match if it is larger.
+    #[must_use]
+    pub const fn with_maximum_fraction_digits(mut self, digits: usize) -> Self {
+        self.maximum_fraction_digits = digits;
+
+        if self.minimum_fraction_digits > digits {
+            self.minimum_fraction_digits = digits;
+        }
+
+        self
+    }
+
+    /// Sets whether the integer part is split into groups of three digits.
+    #[must_use]
+    pub const fn with_uses_grouping_separator(mut self, uses_grouping_separator: bool) -> Self {
+        self.uses_grouping_separator = uses_grouping_separator;
+        self
+    }
+
+    /// Sets the separator placed between digit groups.
+    #[must_use]
+    pub fn with_grouping_separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.grouping_separator = separator.into();
+        self
+    }
+
+    /// Sets the separator placed between the integer and fraction parts.
+    #[must_use]
+    pub fn with_decimal_separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.decimal_separator = separator.into();
+        self
+    }
+
+    /// Sets the symbol written before negative values.
+    #[must_use]
+    pub fn with_minus_sign(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.minus_sign = symbol.into();
+        self
+    }
+
+    /// Sets the symbol written after values in the [`Percent`](NumberStyle::Percent)
+    /// style.
+    #[must_use]
+    pub fn with_percent_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.percent_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the symbol written after values in the [`PerMille`](NumberStyle::PerMille)
+    /// style.
+    #[must_use]
+    pub fn with_per_mille_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.per_mille_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the symbol written before values in the [`Currency`](NumberStyle::Currency)
+    /// style.
+    #[must_use]
+    pub fn with_currency_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.currency_symbol = symbol.into();
+        self
+    }
+
+    /// Switches to the [`Currency`](NumberStyle::Currency) style for amounts of
+    /// `currency`: its symbol, and exactly as many fraction digits as its minor units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::formatting::NumberFormatter;
+    /// use libx::locale::Currency;
+    ///
+    /// let dollars = Currency::from_code("USD").unwrap();
+    /// let formatter = NumberFormatter::new()
+    ///     .with_uses_grouping_separator(true)
+    ///     .with_currency(&dollars);
+    ///
+    /// assert_eq!(formatter.format(1234.5), "$1,234.50");
+    /// assert_eq!(formatter.format(-3), "-$3.00");
+    /// ```
+    #[must_use]
+    pub fn with_currency(self, currency: &Currency) -> Self {
+        let digits = usize::from(currency.minor_units());
+
+        self.with_style(NumberStyle::Currency)
+            .with_currency_symbol(currency.symbol())
+            .with_maximum_fraction_digits(digits)
+            .with_minimum_fraction_digits(digits)
+    }
+
+    /// Sets the digits numbers are written with.
+    #[must_use]
+    pub const fn with_numbering_system(mut self, numbering_system: NumberingSystem) -> Self {
+        self.numbering_system = numbering_system;
+        self
+    }
+
+    /// Sets the units used by the compact styles, ordered from the smallest to the
+    /// largest exponent.
+    #[must_use]
+    pub const fn with_compact_units(mut self, units: &'static [CompactUnit]) -> Self {
+        self.compact_units = units;
+        self
+    }
+
+    /// Sets the direction of the text the output is embedded in.
+    #[must_use]
+    pub const fn with_text_direction(mut self, text_direction: TextDirection) -> Self {
+        self.text_direction = text_direction;
+        self
+    }
+
+    /// Sets whether output in a right-to-left context is wrapped in right-to-left marks.
+    #[must_use]
+    pub const fn with_uses_directionality_marks(mut self, uses_marks: bool) -> Self {
+        self.uses_directionality_marks = uses_marks;
+        self
+    }
+
+    /// Sets the text written for NaN.
+    #[must_use]
+    pub fn with_nan_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.nan_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the text written for positive infinity.
+    #[must_use]
+    pub fn with_positive_infinity_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.positive_infinity_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the text written for negative infinity.
+    #[must_use]
+    pub fn with_negative_infinity_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.negative_infinity_symbol = symbol.into();
+        self
+    }
+
+    /// Sets the text written for values equal to zero, instead of formatting the digits.
+    #[must_use]
+    pub fn with_zero_symbol(mut self, symbol: impl Into<Cow<'static, str>>) -> Self {
+        self.zero_symbol = Some(symbol.into());
+        self
+    }
+
+    /// Sets whether negative zero, including negative values that round to zero, is
+    /// written without a minus sign.
+    #[must_use]
+    pub const fn with_treats_negative_zero_as_zero(mut self, treats_as_zero: bool) -> Self {
+        self.treats_negative_zero_as_zero = treats_as_zero;
+        self
+    }
+
+    /// Returns the style of the formatter.
+    #[must_use]
+    pub const fn style(&self) -> NumberStyle {
+        self.style
+    }
+
+    /// Returns the minimum number of integer digits.
+    #[must_use]
+    pub const fn minimum_integer_digits(&self) -> usize {
+        self.minimum_integer_digits
+    }
+
+    /// Returns the minimum number of fraction digits.
+    #[must_use]
+    pub const fn minimum_fraction_digits(&self) -> usize {
+        self.minimum_fraction_digits
+    }
+
+    /// Returns the maximum number of fraction digits.
+    #[must_use]
+    pub const fn maximum_fraction_digits(&self) -> usize {
+        self.maximum_fraction_digits
+    }
+
+    /// Returns whether digit groups are separated.
+    #[must_use]
+    pub const fn uses_grouping_separator(&self) -> bool {
+        self.uses_grouping_separator
+    }
+
+    /// Returns the separator placed between digit groups.
+    #[must_use]
+    pub fn grouping_separator(&self) -> &str {
+        &self.grouping_separator
+    }
+
+    /// Returns the separator placed between the integer and fraction parts.
+    #[must_use]
+    pub fn decimal_separator(&self) -> &str {
+        &self.decimal_separator
+    }
+
+    /// Returns the symbol written before negative values.
+    #[must_use]
+    pub fn minus_sign(&self) -> &str {
+        &self.minus_sign
+    }
+
+    /// Returns the symbol written after percentages.
+    #[must_use]
+    pub fn percent_symbol(&self) -> &str {
+        &self.percent_symbol
+    }
+
+    /// Returns the symbol written after per-mille values.
+    #[must_use]
+    pub fn per_mille_symbol(&self) -> &str {
+        &self.per_mille_symbol
+    }
+
+    /// Returns the symbol written before amounts of money.
+    #[must_use]
+    pub fn currency_symbol(&self) -> &str {
+        &self.currency_symbol
+    }
+
+    /// Returns the digits numbers are written with.
+    #[must_use]
+    pub const fn numbering_system(&self) -> NumberingSystem {
+        self.numbering_system
+    }
+
+    /// Returns the units used by the compact styles.
+    #[must_use]
+    pub const fn compact_units(&self) -> &'static [CompactUnit] {
+        self.compact_units
+    }
+
+    /// Returns the direction of the text the output is embedded in.
+    #[must_use]
+    pub const fn text_direction(&self) -> TextDirection {
+        self.text_direction
+    }
+
+    /// Returns whether output in a right-to-left context is wrapped in right-to-left
+    /// marks.
+    #[must_use]
+    pub const fn uses_directionality_marks(&self) -> bool {
+        self.uses_directionality_marks
+    }
+
+    /// Returns the text written for NaN.
+    #[must_use]
+    pub fn nan_symbol(&self) -> &str {
+        &self.nan_symbol
+    }
+
+    /// Returns the text written for positive infinity.
+    #[must_use]
+    pub fn positive_infinity_symbol(&self) -> &str {
+        &self.positive_infinity_symbol
+    }
+
+    /// Returns the text written for negative infinity.
+    #[must_use]
+    pub fn negative_infinity_symbol(&self) -> &str {
+        &self.negative_infinity_symbol
+    }
+
+    /// Returns the text written for zero, if one is set.
+    #[must_use]
+    pub fn zero_symbol(&self) -> Option<&str> {
+        self.zero_symbol.as_deref()
+    }
+
+    /// Returns whether negative zero is written without a minus sign.
+    #[must_use]
+    pub const fn treats_negative_zero_as_zero(&self) -> bool {
+        self.treats_negative_zero_as_zero
+    }
+
+    /// Formats `value` into a new string.
+    #[must_use]
+    pub fn format(&self, value: impl Into<Number>) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(value, &mut output);
+        output
+    }
+
+    /// Formats `value` into `sink`.
+    ///
+    /// The sink may be a trait object or a fixed-capacity string, so formatting needs no
+    /// heap for its output.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::fmt::Write;
+    ///
+    /// use libx::{collections::array_string::ArrayString, formatting::NumberFormatter};
+    ///
+    /// let mut text = ArrayString::<8>::new();
+    /// let sink: &mut dyn Write = &mut text;
+    ///
+    /// NumberFormatter::new().with_maximum_fraction_digits(1).write(2.25, sink)?;
+    /// assert_eq!(text, "2.2");
+    /// # Ok::<(), libx::errors::FormatError>(())
+    /// ```
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        value: impl Into<Number>,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        self.write_value(Value::from(value.into()), sink)
+            .map(|_| ())
+            .map_err(|fmt::Error| FormatError::Write)
+    }
+
+    /// Formats `value` right-aligned in a field of at least `width` characters.
+    ///
+    /// Values with fewer fraction digits than the formatter allows are padded on the
+    /// right as well, so the decimal separators of a column of values formatted with the
+    /// same formatter and width line up. Output wider than `width` is not truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::formatting::NumberFormatter;
+    ///
+    /// let formatter = NumberFormatter::new()
+    ///     .with_uses_grouping_separator(true)
+    ///     .with_maximum_fraction_digits(2);
+    ///
+    /// assert_eq!(formatter.formatted_fixed_width(1234.5, 10), "  1,234.5 ");
+    /// assert_eq!(formatter.formatted_fixed_width(-7.25, 10), "     -7.25");
+    /// assert_eq!(formatter.formatted_fixed_width(12, 10), "     12   ");
+    /// ```
+    #[must_use]
+    pub fn formatted_fixed_width(&self, value: impl Into<Number>, width: usize) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write_fixed_width(value, width, &mut output);
+        output
+    }
+
+    /// Formats `value` into `sink` right-aligned in a field of at least `width`
+    /// characters, as [`formatted_fixed_width`](Self::formatted_fixed_width) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write_fixed_width<W: Write + ?Sized>(
+        &self,
+        value: impl Into<Number>,
+        width: usize,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        let mut body = String::new();
+        let fraction_digits = self
+            .write_value(Value::from(value.into()), &mut body)
+            .unwrap_or(0);
+
+        let maximum_fraction_digits = match self.style {
+            NumberStyle::CompactShort | NumberStyle::CompactLong => 1,
+            _ => self.maximum_fraction_digits,
+        };
+        let trailing = if fraction_digits == 0 && maximum_fraction_digits > 0 {
+            maximum_fraction_digits + self.decimal_separator.chars().count()
+        } else {
+            maximum_fraction_digits.saturating_sub(fraction_digits)
+        };
+        // Directionality marks take up no space on screen.
+        let visible = body
+            .chars()
+            .filter(|c| !matches!(c, '\u{200E}' | '\u{200F}' | '\u{61C}'))
+            .count();
+        let leading = width.saturating_sub(visible + trailing);
+
+        (0..leading)
+            .try_for_each(|_| sink.write_char(' '))
+            .and_then(|()| sink.write_str(&body))
+            .and_then(|()| (0..trailing).try_for_each(|_| sink.write_char(' ')))
+            .map_err(|fmt::Error| FormatError::Write)
+    }
+
+    /// Writes `value` and returns the number of fraction digits written.
+    pub(crate) fn write_value<W: Write + ?Sized>(
+        &self,
+        value: Value,
+        sink: &mut W,
+    ) -> Result<usize, fmt::Error> {
+        const RIGHT_TO_LEFT_MARK: char = '\u{200F}';
+
+        let marks =
+            self.uses_directionality_marks && self.text_direction == TextDirection::RightToLeft;
+
+        if marks {
+            sink.write_char(RIGHT_TO_LEFT_MARK)?;
+        }
+
+        let fraction_digits = if let Some(special) = self.special_symbol(value) {
+            sink.write_str(special)?;
+            0
+        } else {
+            self.write_number(value, sink)?
+        };
+
+        if marks {
+            sink.write_char(RIGHT_TO_LEFT_MARK)?;
+        }
+
+        Ok(fraction_digits)
+    }
+
+    fn write_number<W: Write + ?Sized>(
+        &self,
+        value: Value,
+        sink: &mut W,
+    ) -> Result<usize, fmt::Error> {
+        let (negative, integer, fraction) = match self.style {
+            NumberStyle::Decimal => self.digits(value, 1),
+            NumberStyle::Currency => {
+                let (negative, integer, fraction) = self.digits(value, 1);
+
+                if negative {
+                    sink.write_str(&self.minus_sign)?;
+                }
+
+                sink.write_str(&self.currency_symbol)?;
+                self.write_parts(false, &integer, &fraction, sink)?;
+
+                return Ok(fraction.len());
+            }
+            NumberStyle::Percent => self.digits(value, 100),
+            NumberStyle::PerMille => self.digits(value, 1000),
+            NumberStyle::CompactShort | NumberStyle::CompactLong => {
+                return self.write_compact(value, sink);
+            }
+        };
+
+        self.write_parts(negative, &integer, &fraction, sink)?;
+
+        match self.style {
+            NumberStyle::Percent => sink.write_str(&self.percent_symbol)?,
+            NumberStyle::PerMille => sink.write_str(&self.per_mille_symbol)?,
+            _ => {}
+        }
+
+        Ok(fraction.len())
+    }
+
+    fn write_compact<W: Write + ?Sized>(
+        &self,
+        value: Value,
+        sink: &mut W,
+    ) -> Result<usize, fmt::Error> {
+        // Compact output keeps few significant digits.
+        let value = value.to_f64();
+
+        let (scaled, unit) = self.compact_unit(value.abs());
+        let fraction_digits = usize::from(scaled < 10.0);
+        let (negative, integer, fraction) =
+            self.rounded_digits(Value::Float(scaled.copysign(value)), 1, 0, fraction_digits);
+
+        self.write_parts(negative, &integer, &fraction, sink)?;
+
+        if let Some(unit) = unit {
+            match self.style {
+                NumberStyle::CompactShort => sink.write_str(unit.short)?,
+                _ if integer == "1" && fraction.is_empty() => sink.write_str(unit.long_one)?,
+                _ => sink.write_str(unit.long_other)?,
+            }
+        }
+
+        Ok(fraction.len())
+    }
+
+    /// Returns `magnitude` divided by the largest compact unit it reaches after rounding,
+    /// along with that unit.
+    fn compact_unit(&self, magnitude: f64) -> (f64, Option<&'static CompactUnit>) {
+        let mut unit: Option<&'static CompactUnit> = None;
+
+        for candidate in self.compact_units {
+            let divisor = unit.map_or(1.0, |unit| power_of_ten(unit.exponent));
+            let scaled = magnitude / divisor;
+            let fraction_digits = usize::from(scaled < 10.0);
+            let rounded = alloc::format!("{scaled:.fraction_digits$}")
+                .parse::<f64>()
+                .unwrap_or(scaled);
+
+            if rounded * divisor < power_of_ten(candidate.exponent) {
+                break;
+            }
+
+            unit = Some(candidate);
+        }
+
+        let divisor = unit.map_or(1.0, |unit| power_of_ten(unit.exponent));
+        (magnitude / divisor, unit)
+    }
+
+    fn write_parts<W: Write + ?Sized>(
+        &self,
+        negative: bool,
+        integer: &str,
+        fraction: &str,
+        sink: &mut W,
+    ) -> fmt::Result {
+        if negative {
+            sink.write_str(&self.minus_sign)?;
+        }
+
+        self.write_integer(integer, sink)?;
+
+        if !fraction.is_empty() {
+            sink.write_str(&self.decimal_separator)?;
+            self.write_digits(fraction, sink)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the symbol that replaces the digits of `value`, if any.
+    fn special_symbol(&self, value: Value) -> Option<&str> {
+        match value {
+            Value::Float(value) if value.is_nan() => Some(&self.nan_symbol),
+            Value::Float(value) if value == f64::INFINITY => Some(&self.positive_infinity_symbol),
+            Value::Float(value) if value == f64::NEG_INFINITY => {
+                Some(&self.negative_infinity_symbol)
+            }
+            Value::Float(0.0) | Value::Integer(0) => self.zero_symbol.as_deref(),
+            Value::Decimal(value) if value.is_zero() => self.zero_symbol.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the sign, integer digits and fraction digits of a finite value, after
+    /// scaling and rounding.
+    fn digits(&self, value: Value, scale: u16) -> (bool, String, String) {
+        self.rounded_digits(
+            value,
+            scale,
+            self.minimum_fraction_digits,
+            self.maximum_fraction_digits,
+        )
+    }
+
+    fn rounded_digits(
+        &self,
+        value: Value,
+        scale: u16,
+        minimum_fraction_digits: usize,
+        maximum_fraction_digits: usize,
+    ) -> (bool, String, String) {
+        let (negative, mut integer, mut fraction) = match value {
+            Value::Integer(value) => {
+                let scaled = value.saturating_mul(i128::from(scale));
+                (scaled < 0, scaled.unsigned_abs().to_string(), String::new())
+            }
+            Value::Float(value) => {
+                let scaled = value * f64::from(scale);
+                let text = alloc::format!("{:.*}", maximum_fraction_digits, scaled.abs());
+
+                let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+                let fraction = fraction.trim_end_matches('0');
+
+                (
+                    scaled.is_sign_negative(),
+                    String::from(integer),
+                    String::from(fraction),
+                )
+            }
+            Value::Decimal(value) => {
+                let saturated = if value.is_negative() {
+                    Decimal::MIN
+                } else {
+                    Decimal::MAX
+                };
+                let scaled = value.checked_mul(Decimal::from(scale)).unwrap_or(saturated);
+                #[allow(clippy::cast_possible_truncation)] // At most `MAX_SCALE`.
+                let digits = maximum_fraction_digits.min(usize::from(decimal::MAX_SCALE)) as u8;
+                let text = scaled.round(digits, RoundingMode::HalfEven).to_string();
+
+                let text = text.trim_start_matches('-');
+                let (integer, fraction) = text.split_once('.').unwrap_or((text, ""));
+                let fraction = fraction.trim_end_matches('0');
+
+                (
+                    scaled.is_negative(),
+                    String::from(integer),
+                    String::from(fraction),
+                )
+            }
+        };
+
+        while fraction.len() < minimum_fraction_digits {
+            fraction.push('0');
+        }
+
+        while integer.len() < self.minimum_integer_digits {
+            integer.insert(0, '0');
+        }
+
+        let is_zero = integer.bytes().chain(fraction.bytes()).all(|b| b == b'0');
+        let negative = negative && !(is_zero && self.treats_negative_zero_as_zero);
+
+        (negative, integer, fraction)
+    }
+
+    fn write_integer<W: Write + ?Sized>(&self, digits: &str, sink: &mut W) -> fmt::Result {
+        if !self.uses_grouping_separator {
+            return self.write_digits(digits, sink);
+        }
+
+        for (index, digit) in digits.char_indices() {
+            if index > 0 && (digits.len() - index).is_multiple_of(3) {
+                sink.write_str(&self.grouping_separator)?;
+            }
+
+            sink.write_char(self.numbering_system.localize(digit))?;
+        }
+
+        Ok(())
+    }
+
+    fn write_digits<W: Write + ?Sized>(&self, digits: &str, sink: &mut W) -> fmt::Result {
+        if self.numbering_system == NumberingSystem::Latin {
+            return sink.write_str(digits);
+        }
+
+        digits
+            .chars()
+            .try_for_each(|digit| sink.write_char(self.numbering_system.localize(digit)))
+    }
+}
+
+/// Returns `10^exponent`, which is exact for the exponents compact units use.
+fn power_of_ten(exponent: u8) -> f64 {
+    (0..exponent).fold(1.0, |power, _| power * 10.0)
+}
+
+impl Default for NumberFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_digits() {
+        let formatter = NumberFormatter::new()
+            .with_minimum_fraction_digits(2)
+            .with_maximum_fraction_digits(4);
+
+        assert_eq!(formatter.format(1.5), "1.50");
+        assert_eq!(formatter.format(0.123_456), "0.1235");
+        assert_eq!(formatter.format(7_u8), "7.00");
+        assert_eq!(formatter.with_minimum_integer_digits(3).format(7), "007.00");
+    }
+
+    #[test]
+    fn test_grouping_and_separators() {
+        let formatter = NumberFormatter::new()
+            .with_uses_grouping_separator(true)
+            .with_grouping_separator(".")
+            .with_decimal_separator(",");
+
+        assert_eq!(formatter.format(1_234_567.25), "1.234.567,25");
+        assert_eq!(formatter.format(-999), "-999");
+        assert_eq!(formatter.format(usize::MAX), "18.446.744.073.709.551.615");
+    }
+
+    #[test]
+    fn test_special_values() {
+        let formatter = NumberFormatter::new();
+
+        assert_eq!(formatter.format(Number::Float(f32::NAN)), "NaN");
+        assert_eq!(formatter.format(Number::Float(f32::INFINITY)), "∞");
+        assert_eq!(formatter.format(Number::Double(f64::NEG_INFINITY)), "-∞");
+
+        let custom = NumberFormatter::new()
+            .with_nan_symbol("n/a")
+            .with_positive_infinity_symbol("+inf")
+            .with_negative_infinity_symbol("-inf");
+
+        assert_eq!(custom.format(f64::NAN), "n/a");
+        assert_eq!(custom.format(f32::INFINITY), "+inf");
+        assert_eq!(custom.format(f32::NEG_INFINITY), "-inf");
+    }
+
+    #[test]
+    fn test_negative_zero() {
+        let formatter = NumberFormatter::new().with_maximum_fraction_digits(1);
+
+        assert_eq!(formatter.format(-0.0), "0");
+        assert_eq!(formatter.format(-0.01), "0");
+
+        let signed = formatter.with_treats_negative_zero_as_zero(false);
+        assert_eq!(signed.format(-0.0), "-0");
+        assert_eq!(signed.format(-0.01), "-0");
+    }
+
+    #[test]
+    fn test_zero_symbol_and_percent() {
+        let formatter = NumberFormatter::new().with_zero_symbol("—");
+
+        assert_eq!(formatter.format(0), "—");
+        assert_eq!(formatter.format(-0.0), "—");
+        assert_eq!(formatter.format(0.001), "0.001");
+
+        let percent = NumberFormatter::new().with_style(NumberStyle::Percent);
+        assert_eq!(percent.format(1), "100%");
+        assert_eq!(percent.format(-0.5), "-50%");
+        assert_eq!(percent.format(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn test_locale_symbols_and_digits() {
+        let egyptian = NumberFormatter::new()
+            .with_locale(&Locale::new("ar_EG"))
+            .with_uses_grouping_separator(true);
+
+        assert_eq!(egyptian.format(1234.5), "١٬٢٣٤٫٥");
+        assert_eq!(
+            egyptian.with_style(NumberStyle::Percent).format(0.5),
+            "٥٠٪\u{61C}"
+        );
+
+        let persian = NumberFormatter::new().with_locale(&Locale::new("fa_IR"));
+        assert_eq!(persian.format(-3), "\u{200E}−۳");
+
+        let per_mille = NumberFormatter::new().with_style(NumberStyle::PerMille);
+        assert_eq!(per_mille.format(0.0125), "12.5‰");
+    }
+
+    #[test]
+    fn test_directionality_marks() {
+        let hebrew = NumberFormatter::new()
+            .with_locale(&Locale::new("he_IL"))
+            .with_style(NumberStyle::Percent);
+
+        assert_eq!(hebrew.format(-0.25), "\u{200E}-25%");
+
+        let marked = hebrew.with_uses_directionality_marks(true);
+        assert_eq!(marked.format(-0.25), "\u{200F}\u{200E}-25%\u{200F}");
+        assert_eq!(marked.format(f64::NAN), "\u{200F}NaN\u{200F}");
+
+        let english = NumberFormatter::new().with_uses_directionality_marks(true);
+        assert_eq!(english.format(1), "1");
+    }
+
+    #[test]
+    fn test_compact_rounding_at_boundaries() {
+        let short = NumberFormatter::new().with_style(NumberStyle::CompactShort);
+
+        assert_eq!(short.format(0), "0");
+        assert_eq!(short.format(1.25), "1.2");
+        assert_eq!(short.format(999), "999");
+        assert_eq!(short.format(999.9), "1K");
+        assert_eq!(short.format(9_960), "10K");
+        assert_eq!(short.format(99_960), "100K");
+        assert_eq!(short.format(999_499), "999K");
+        assert_eq!(short.format(999_500), "1M");
+        assert_eq!(short.format(-1_500_000_000), "-1.5B");
+        assert_eq!(short.format(4.2e15), "4200T");
+        assert_eq!(short.format(f64::NAN), "NaN");
+    }
+
+    #[test]
+    fn test_compact_locales() {
+        let german = NumberFormatter::new()
+            .with_locale(&Locale::new("de_DE"))
+            .with_style(NumberStyle::CompactLong);
+
+        assert_eq!(german.format(1_000_000), "1 Million");
+        assert_eq!(german.format(1_200_000), "1,2 Millionen");
+        assert_eq!(
+            german.with_style(NumberStyle::CompactShort).format(45_000),
+            "45\u{A0}Tsd."
+        );
+
+        let japanese = NumberFormatter::new()
+            .with_locale(&Locale::new("ja_JP"))
+            .with_style(NumberStyle::CompactShort);
+
+        assert_eq!(japanese.format(1234), "1234");
+        assert_eq!(japanese.format(12_345), "1.2万");
+        assert_eq!(japanese.format(99_999_999), "1億");
+
+        let arabic = NumberFormatter::new()
+            .with_locale(&Locale::new("ar_EG"))
+            .with_style(NumberStyle::CompactShort);
+        assert_eq!(arabic.format(2500), "٢٫٥K");
+    }
+
+    #[test]
+    fn test_fixed_width_column_alignment() {
+        let formatter = NumberFormatter::new()
+            .with_uses_grouping_separator(true)
+            .with_maximum_fraction_digits(2);
+
+        let column = [1_234_567.891, -0.5, 42.0, f64::NAN]
+            .map(|value| formatter.formatted_fixed_width(value, 14));
+
+        assert_eq!(
+            column,
+            [
+                "  1,234,567.89",
+                "         -0.5 ",
+                "         42   ",
+                "        NaN   "
+            ]
+        );
+
+        let narrow = NumberFormatter::new().with_maximum_fraction_digits(0);
+        assert_eq!(narrow.formatted_fixed_width(123_456, 3), "123456");
+        assert_eq!(narrow.formatted_fixed_width(7, 3), "  7");
+
+        let compact = NumberFormatter::new().with_style(NumberStyle::CompactShort);
+        assert_eq!(compact.formatted_fixed_width(1_500, 6), "  1.5K");
+        assert_eq!(compact.formatted_fixed_width(15_000, 6), " 15K  ");
+    }
+
+    #[test]
+    fn test_write_into_sink() {
+        let mut output = String::from("total: ");
+
+        assert_eq!(NumberFormatter::new().write(12.5, &mut output), Ok(()));
+        assert_eq!(output, "total: 12.5");
+    }
+
+    #[test]
+    fn test_currency_style() {
+        let dinar = Currency::from_code("KWD").expect("KWD is an ISO 4217 currency");
+        let formatter = NumberFormatter::new()
+            .with_locale(&Locale::new("de_DE"))
+            .with_uses_grouping_separator(true)
+            .with_currency(&dinar);
+
+        assert_eq!(formatter.format(1234.5), "KWD1.234,500");
+        assert_eq!(formatter.format(-0.0004), "KWD0,000");
+
+        let mut output = String::new();
+        let amount = Value::Decimal(Decimal::new(-123_456_789_012_345_678_901_234_567, 4));
+        assert_eq!(formatter.write_value(amount, &mut output), Ok(3));
+        assert_eq!(output, "-KWD12.345.678.901.234.567.890.123,457");
+
+        let plain = NumberFormatter::new().with_style(NumberStyle::Currency);
+        assert_eq!(plain.format(2), "¤2");
+    }
+}
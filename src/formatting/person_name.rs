@@ -0,0 +1,439 @@
+//! Personal names, written in the order and length a locale expects.
+//!
+//! [`PersonNameComponents`] holds the parts of a name and [`PersonNameFormatter`]
+//! assembles them in one of four [`PersonNameStyle`]s, after Foundation's
+//! `PersonNameComponentsFormatter`. Locales whose languages put the family name first,
+//! such as Japanese, Chinese, Korean, Hungarian and Vietnamese, reverse the order of the
+//! given and family names. Names written entirely in Chinese, Japanese or Korean script
+//! always put the family name first and are joined without a space.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::formatting::person_name::{
+//!     PersonNameComponents, PersonNameFormatter, PersonNameStyle,
+//! };
+//! use libx::locale::Locale;
+//!
+//! let name = PersonNameComponents::new()
+//!     .with_given_name("Ferenc")
+//!     .with_family_name("Puskás");
+//!
+//! let formatter = PersonNameFormatter::new();
+//! assert_eq!(formatter.format(&name), "Ferenc Puskás");
+//!
+//! let hungarian = formatter.with_locale(&Locale::new("hu_HU"));
+//! assert_eq!(hungarian.format(&name), "Puskás Ferenc");
+//! assert_eq!(hungarian.with_style(PersonNameStyle::Abbreviated).format(&name), "PF");
+//! ```
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::{errors::FormatError, locale::Locale};
+
+/// Languages that write the family name before the given name.
+const FAMILY_NAME_FIRST_LANGUAGES: &[&str] = &["hu", "ja", "ko", "vi", "zh"];
+
+/// The parts of a person's name.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::person_name::PersonNameComponents;
+///
+/// let name = PersonNameComponents::new()
+///     .with_prefix("Dr.")
+///     .with_given_name("Martin")
+///     .with_middle_name("Luther")
+///     .with_family_name("King")
+///     .with_suffix("Jr.");
+///
+/// assert_eq!(name.given_name(), Some("Martin"));
+/// assert_eq!(name.nickname(), None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct PersonNameComponents {
+    prefix: Option<String>,
+    given_name: Option<String>,
+    middle_name: Option<String>,
+    family_name: Option<String>,
+    suffix: Option<String>,
+    nickname: Option<String>,
+}
+
+impl PersonNameComponents {
+    /// Creates a name with no parts.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            prefix: None,
+            given_name: None,
+            middle_name: None,
+            family_name: None,
+            suffix: None,
+            nickname: None,
+        }
+    }
+
+    /// Sets the title or honorific before the name, such as `Dr.`.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Sets the given name, the name a person is usually addressed by.
+    #[must_use]
+    pub fn with_given_name(mut self, given_name: impl Into<String>) -> Self {
+        self.given_name = Some(given_name.into());
+        self
+    }
+
+    /// Sets the middle name.
+    #[must_use]
+    pub fn with_middle_name(mut self, middle_name: impl Into<String>) -> Self {
+        self.middle_name = Some(middle_name.into());
+        self
+    }
+
+    /// Sets the family name.
+    #[must_use]
+    pub fn with_family_name(mut self, family_name: impl Into<String>) -> Self {
+        self.family_name = Some(family_name.into());
+        self
+    }
+
+    /// Sets the designation after the name, such as `Jr.` or `PhD`.
+    #[must_use]
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Sets the informal name the person goes by.
+    #[must_use]
+    pub fn with_nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.nickname = Some(nickname.into());
+        self
+    }
+
+    /// Returns the title or honorific before the name.
+    #[must_use]
+    pub fn prefix(&self) -> Option<&str> {
+        self.prefix.as_deref()
+    }
+
+    /// Returns the given name.
+    #[must_use]
+    pub fn given_name(&self) -> Option<&str> {
+        self.given_name.as_deref()
+    }
+
+    /// Returns the middle name.
+    #[must_use]
+    pub fn middle_name(&self) -> Option<&str> {
+        self.middle_name.as_deref()
+    }
+
+    /// Returns the family name.
+    #[must_use]
+    pub fn family_name(&self) -> Option<&str> {
+        self.family_name.as_deref()
+    }
+
+    /// Returns the designation after the name.
+    #[must_use]
+    pub fn suffix(&self) -> Option<&str> {
+        self.suffix.as_deref()
+    }
+
+    /// Returns the informal name the person goes by.
+    #[must_use]
+    pub fn nickname(&self) -> Option<&str> {
+        self.nickname.as_deref()
+    }
+}
+
+/// How much of a name a [`PersonNameFormatter`] writes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PersonNameStyle {
+    /// The nickname, or else the given name: `Johnny`.
+    Short,
+
+    /// The given and family names: `John Appleseed`.
+    #[default]
+    Medium,
+
+    /// Every part but the nickname: `Dr. John Ronald Appleseed Jr.`.
+    Long,
+
+    /// The initials of the given and family names, as for a monogram: `JA`.
+    Abbreviated,
+}
+
+/// Formats [`PersonNameComponents`] in a [`PersonNameStyle`].
+///
+/// Missing parts are skipped, and a style whose parts are all missing falls back to the
+/// parts that are present, so any name with a part produces some text.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::person_name::{
+///     PersonNameComponents, PersonNameFormatter, PersonNameStyle,
+/// };
+///
+/// let name = PersonNameComponents::new()
+///     .with_prefix("Dr.")
+///     .with_given_name("John")
+///     .with_middle_name("Ronald")
+///     .with_family_name("Appleseed")
+///     .with_suffix("Jr.")
+///     .with_nickname("Johnny");
+/// let formatter = PersonNameFormatter::new();
+///
+/// assert_eq!(formatter.with_style(PersonNameStyle::Short).format(&name), "Johnny");
+/// assert_eq!(formatter.format(&name), "John Appleseed");
+/// assert_eq!(
+///     formatter.with_style(PersonNameStyle::Long).format(&name),
+///     "Dr. John Ronald Appleseed Jr."
+/// );
+/// assert_eq!(formatter.with_style(PersonNameStyle::Abbreviated).format(&name), "JA");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PersonNameFormatter {
+    style: PersonNameStyle,
+    family_name_first: bool,
+}
+
+impl PersonNameFormatter {
+    /// Creates a formatter for the medium style with the given name first.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            style: PersonNameStyle::Medium,
+            family_name_first: false,
+        }
+    }
+
+    /// Takes the order of the given and family names from the language of `locale`.
+    #[must_use]
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.family_name_first = FAMILY_NAME_FIRST_LANGUAGES
+            .iter()
+            .any(|language| language.eq_ignore_ascii_case(locale.language_code()));
+        self
+    }
+
+    /// Sets how much of a name is written.
+    #[must_use]
+    pub const fn with_style(mut self, style: PersonNameStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Sets whether the family name is written before the given name.
+    #[must_use]
+    pub const fn with_family_name_first(mut self, family_name_first: bool) -> Self {
+        self.family_name_first = family_name_first;
+        self
+    }
+
+    /// Returns how much of a name is written.
+    #[must_use]
+    pub const fn style(&self) -> PersonNameStyle {
+        self.style
+    }
+
+    /// Returns `true` if the family name is written before the given name.
+    #[must_use]
+    pub const fn family_name_first(&self) -> bool {
+        self.family_name_first
+    }
+
+    /// Formats `name` as a string.
+    #[must_use]
+    pub fn format(&self, name: &PersonNameComponents) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(name, &mut output);
+        output
+    }
+
+    /// Formats `name` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        name: &PersonNameComponents,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        let given = present(name.given_name());
+        let middle = present(name.middle_name());
+        let family = present(name.family_name());
+        let cjk = (given.is_some() || family.is_some())
+            && [given, middle, family]
+                .into_iter()
+                .flatten()
+                .all(|part| part.chars().all(is_cjk));
+        let family_first = self.family_name_first || cjk;
+
+        let names = match (self.style, family_first) {
+            (PersonNameStyle::Short, _) => {
+                [present(name.nickname()).or(given).or(family), None, None]
+            }
+            (PersonNameStyle::Long, true) => [family, middle, given],
+            (PersonNameStyle::Long, false) => [given, middle, family],
+            (_, true) => [family, given, None],
+            (_, false) => [given, family, None],
+        };
+
+        if self.style == PersonNameStyle::Abbreviated {
+            for initial in names
+                .into_iter()
+                .flatten()
+                .filter_map(|part| part.chars().next())
+                .flat_map(char::to_uppercase)
+            {
+                sink.write_char(initial)?;
+            }
+
+            return Ok(());
+        }
+
+        let (prefix, suffix) = if self.style == PersonNameStyle::Long {
+            (present(name.prefix()), present(name.suffix()))
+        } else {
+            (None, None)
+        };
+        let separator = if cjk { "" } else { " " };
+
+        if let Some(prefix) = prefix {
+            sink.write_str(prefix)?;
+        }
+
+        let mut written = prefix.is_some();
+
+        for (index, name) in names.into_iter().flatten().enumerate() {
+            if index > 0 {
+                sink.write_str(separator)?;
+            } else if written {
+                sink.write_char(' ')?;
+            }
+
+            sink.write_str(name)?;
+            written = true;
+        }
+
+        if let Some(suffix) = suffix {
+            if written {
+                sink.write_char(' ')?;
+            }
+
+            sink.write_str(suffix)?;
+        } else if !written && let Some(nickname) = present(name.nickname()) {
+            sink.write_str(nickname)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `part` with surrounding whitespace removed, or `None` if nothing is left.
+fn present(part: Option<&str>) -> Option<&str> {
+    part.map(str::trim).filter(|part| !part.is_empty())
+}
+
+/// Returns `true` for Han ideographs, kana and Hangul syllables, which names are
+/// written in without spaces.
+const fn is_cjk(character: char) -> bool {
+    matches!(
+        character,
+        '\u{3040}'..='\u{30FF}'
+            | '\u{3400}'..='\u{4DBF}'
+            | '\u{4E00}'..='\u{9FFF}'
+            | '\u{AC00}'..='\u{D7AF}'
+            | '\u{F900}'..='\u{FAFF}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_styles() {
+        let formatter = PersonNameFormatter::new();
+        let name = PersonNameComponents::new()
+            .with_given_name(" Grace ")
+            .with_middle_name("Brewster")
+            .with_family_name("Hopper")
+            .with_prefix("Rear Admiral");
+
+        let format = |style| formatter.with_style(style).format(&name);
+        assert_eq!(format(PersonNameStyle::Short), "Grace");
+        assert_eq!(format(PersonNameStyle::Medium), "Grace Hopper");
+        assert_eq!(
+            format(PersonNameStyle::Long),
+            "Rear Admiral Grace Brewster Hopper"
+        );
+        assert_eq!(format(PersonNameStyle::Abbreviated), "GH");
+
+        let family_only = PersonNameComponents::new().with_family_name("Hopper");
+        assert_eq!(formatter.format(&family_only), "Hopper");
+        assert_eq!(
+            formatter
+                .with_style(PersonNameStyle::Short)
+                .format(&family_only),
+            "Hopper"
+        );
+
+        let nickname_only = PersonNameComponents::new().with_nickname("Amazing Grace");
+        assert_eq!(formatter.format(&nickname_only), "Amazing Grace");
+        assert_eq!(formatter.format(&PersonNameComponents::new()), "");
+    }
+
+    #[test]
+    fn test_family_name_first() {
+        let name = PersonNameComponents::new()
+            .with_given_name("An")
+            .with_middle_name("Văn")
+            .with_family_name("Nguyễn");
+        let vietnamese = PersonNameFormatter::new().with_locale(&Locale::new("vi-VN"));
+        assert!(vietnamese.family_name_first());
+        assert_eq!(vietnamese.format(&name), "Nguyễn An");
+        assert_eq!(
+            vietnamese.with_style(PersonNameStyle::Long).format(&name),
+            "Nguyễn Văn An"
+        );
+
+        let name = PersonNameComponents::new()
+            .with_given_name("太郎")
+            .with_family_name("山田");
+        let english = PersonNameFormatter::new().with_locale(&Locale::new("en_US"));
+        assert!(!english.family_name_first());
+        assert_eq!(english.format(&name), "山田太郎");
+        assert_eq!(
+            english
+                .with_style(PersonNameStyle::Abbreviated)
+                .format(&name),
+            "山太"
+        );
+        assert_eq!(
+            english
+                .with_style(PersonNameStyle::Long)
+                .format(&name.with_suffix("様")),
+            "山田太郎 様"
+        );
+
+        let name = PersonNameComponents::new()
+            .with_given_name("Taro")
+            .with_family_name("Yamada");
+        let japanese = PersonNameFormatter::new().with_locale(&Locale::new("ja_JP"));
+        assert_eq!(japanese.format(&name), "Yamada Taro");
+        assert_eq!(english.format(&name), "Taro Yamada");
+    }
+}
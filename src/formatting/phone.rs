@@ -0,0 +1,956 @@
+//! Phone numbers in E.164 form, with national and international pretty-printing.
+//!
+//! A [`PhoneNumber`] is a country calling code and a national significant number, parsed
+//! from text such as `+44 20 7946 0958` or, given a default [`Region`], from a national
+//! form such as `(201) 555-0123`. [`PhoneNumberFormatter`] writes numbers back out using
+//! per-region [`PhonePattern`] tables: a starter set for the United States, Canada, the
+//! United Kingdom, France, Germany, Japan, Australia and India is built in, and further
+//! patterns can be added for any region. Numbers without a matching pattern are written
+//! as undivided digits.
+//!
+//! Only the shape of a number is checked, not whether it is assigned.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::formatting::phone::{PhoneFormat, PhoneNumber, PhoneNumberFormatter};
+//! use libx::locale::Region;
+//!
+//! let us = Region::from_code("US");
+//! let number = PhoneNumber::parse("(201) 555-0123", us).unwrap();
+//! assert_eq!(number.to_string(), "+12015550123");
+//!
+//! let formatter = PhoneNumberFormatter::new();
+//! assert_eq!(formatter.format(&number), "+1 201-555-0123");
+//!
+//! let national = formatter.with_format(PhoneFormat::National);
+//! assert_eq!(national.format(&number), "(201) 555-0123");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{fmt, fmt::Write, str::FromStr};
+
+use crate::{
+    errors::{FormatError, PhoneError},
+    locale::Region,
+};
+
+/// The most digits an E.164 number may have, calling code included.
+const MAX_DIGITS: usize = 15;
+
+/// The fewest digits accepted in a national significant number.
+const MIN_NATIONAL_DIGITS: usize = 4;
+
+/// Returns the country calling code of `region`, such as 44 for the United Kingdom.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::phone::calling_code;
+/// use libx::locale::Region;
+///
+/// assert_eq!(Region::from_code("GB").and_then(calling_code), Some(44));
+/// assert_eq!(Region::from_code("BV").and_then(calling_code), None);
+/// ```
+#[must_use]
+pub fn calling_code(region: Region) -> Option<u16> {
+    let code = region.alpha2_code()?;
+
+    CALLING_CODES
+        .binary_search_by_key(&code, |&(alpha2, _)| alpha2)
+        .ok()
+        .map(|index| CALLING_CODES[index].1)
+}
+
+/// Returns the regions that share the country calling code `code`, in order of their
+/// alpha-2 codes.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::phone::regions_for_calling_code;
+///
+/// let codes: Vec<_> = regions_for_calling_code(7).map(|r| r.identifier()).collect();
+/// assert_eq!(codes, ["KZ", "RU"]);
+/// ```
+pub fn regions_for_calling_code(code: u16) -> impl Iterator<Item = Region> {
+    CALLING_CODES
+        .iter()
+        .filter(move |&&(_, calling_code)| calling_code == code)
+        .filter_map(|&(alpha2, _)| Region::from_code(alpha2))
+}
+
+/// Returns the region a number with calling code `code` is attributed to when no better
+/// guess is available.
+fn main_region(code: u16) -> Option<Region> {
+    MAIN_REGIONS
+        .iter()
+        .find(|&&(calling_code, _)| calling_code == code)
+        .and_then(|&(_, alpha2)| Region::from_code(alpha2))
+        .or_else(|| regions_for_calling_code(code).next())
+}
+
+/// A phone number: a country calling code and a national significant number.
+///
+/// The number also remembers the region it is attributed to, which picks the patterns
+/// it is formatted with. Regions that share a calling code, such as the members of the
+/// North American Numbering Plan, are told apart only by the default region given when
+/// parsing.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::phone::PhoneNumber;
+///
+/// let number: PhoneNumber = "+33 1 23 45 67 89".parse().unwrap();
+/// assert_eq!(number.calling_code(), 33);
+/// assert_eq!(number.national_number(), "123456789");
+/// assert_eq!(number.region().map(|r| r.identifier()), Some("FR"));
+/// assert_eq!(number.to_string(), "+33123456789");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PhoneNumber {
+    calling_code: u16,
+    national_number: String,
+    region: Option<Region>,
+}
+
+impl PhoneNumber {
+    /// Parses a number in international form, starting with `+`, or in the national
+    /// form of `default_region`.
+    ///
+    /// Spaces, hyphens, dots, slashes and parentheses between digits are ignored. A
+    /// national number may start with its region's trunk prefix, such as the `0` of
+    /// `020 7946 0958`, when the region is one of the built-in starter set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PhoneError`] if the text holds other characters, the calling code is
+    /// unknown or missing, or the number has too few or too many digits.
+    pub fn parse(text: &str, default_region: Option<Region>) -> Result<Self, PhoneError> {
+        let text = text.trim();
+        let (international, rest) = text
+            .strip_prefix('+')
+            .map_or((false, text), |rest| (true, rest));
+        let offset = text.len() - rest.len();
+        let mut digits = String::with_capacity(rest.len());
+
+        for (index, character) in rest.char_indices() {
+            match character {
+                '0'..='9' => digits.push(character),
+                ' ' | '-' | '.' | '/' | '(' | ')' | '\u{a0}' => {}
+                _ => {
+                    return Err(PhoneError::InvalidCharacter {
+                        position: offset + index,
+                    });
+                }
+            }
+        }
+
+        if digits.is_empty() {
+            return Err(PhoneError::Empty);
+        }
+
+        if international {
+            return Self::from_digits(&digits, default_region);
+        }
+
+        let region = default_region.ok_or(PhoneError::MissingRegion)?;
+        let code = calling_code(region).ok_or(PhoneError::MissingRegion)?;
+        let national = built_in_format(region)
+            .and_then(|format| digits.strip_prefix(format.trunk_prefix))
+            .filter(|national| national.len() >= MIN_NATIONAL_DIGITS)
+            .unwrap_or(&digits);
+
+        Self::new(code, national, Some(region))
+    }
+
+    /// Parses a number in strict E.164 form: `+` followed by up to 15 digits and nothing
+    /// else.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PhoneError`] if the text is not in that form or its calling code is
+    /// unknown.
+    pub fn from_e164(text: &str) -> Result<Self, PhoneError> {
+        let digits = text.strip_prefix('+').ok_or(if text.is_empty() {
+            PhoneError::Empty
+        } else {
+            PhoneError::InvalidCharacter { position: 0 }
+        })?;
+
+        if let Some(index) = digits.bytes().position(|byte| !byte.is_ascii_digit()) {
+            return Err(PhoneError::InvalidCharacter {
+                position: index + 1,
+            });
+        }
+
+        if digits.is_empty() {
+            return Err(PhoneError::Empty);
+        }
+
+        Self::from_digits(digits, None)
+    }
+
+    /// Splits the digits of an international number into calling code and national
+    /// number. Calling codes are prefix-free, so at most one split matches.
+    fn from_digits(digits: &str, default_region: Option<Region>) -> Result<Self, PhoneError> {
+        let (code, national) = (1..=3.min(digits.len()))
+            .find_map(|length| {
+                let code = digits[..length].parse().ok()?;
+                regions_for_calling_code(code)
+                    .next()
+                    .map(|_| (code, &digits[length..]))
+            })
+            .ok_or(PhoneError::UnknownCallingCode)?;
+
+        let region = default_region
+            .filter(|&region| calling_code(region) == Some(code))
+            .or_else(|| main_region(code));
+
+        Self::new(code, national, region)
+    }
+
+    fn new(
+        calling_code: u16,
+        national_number: &str,
+        region: Option<Region>,
+    ) -> Result<Self, PhoneError> {
+        if national_number.len() < MIN_NATIONAL_DIGITS {
+            return Err(PhoneError::TooShort);
+        }
+
+        if calling_code.ilog10() as usize + 1 + national_number.len() > MAX_DIGITS {
+            return Err(PhoneError::TooLong);
+        }
+
+        Ok(Self {
+            calling_code,
+            national_number: national_number.into(),
+            region,
+        })
+    }
+
+    /// Returns the country calling code.
+    #[must_use]
+    pub const fn calling_code(&self) -> u16 {
+        self.calling_code
+    }
+
+    /// Returns the digits of the national significant number, without any trunk prefix.
+    #[must_use]
+    pub fn national_number(&self) -> &str {
+        &self.national_number
+    }
+
+    /// Returns the region the number is attributed to.
+    #[must_use]
+    pub const fn region(&self) -> Option<Region> {
+        self.region
+    }
+
+    /// Returns the number in E.164 form, such as `+442079460958`.
+    #[must_use]
+    pub fn e164(&self) -> String {
+        alloc::format!("+{}{}", self.calling_code, self.national_number)
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    /// Writes the number in E.164 form.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "+{}{}", self.calling_code, self.national_number)
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = PhoneError;
+
+    /// Parses a number in international form; see [`PhoneNumber::parse`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+/// How a [`PhoneNumberFormatter`] writes numbers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PhoneFormat {
+    /// Digits only, after a `+`: `+442079460958`.
+    E164,
+
+    /// Grouped digits after the calling code: `+44 20 7946 0958`.
+    #[default]
+    International,
+
+    /// Grouped digits as dialled within the region: `020 7946 0958`.
+    National,
+}
+
+/// How the numbers of one region are grouped.
+///
+/// Each template writes the digits of the national number in place of its `#`
+/// characters and copies every other character. A pattern applies to national numbers
+/// that start with its leading digits and have as many digits as its national template
+/// has `#`s. The national template includes the trunk prefix, if the region dials one.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::phone::{PhoneNumber, PhoneNumberFormatter, PhonePattern};
+/// use libx::locale::Region;
+///
+/// let nl = Region::from_code("NL").unwrap();
+/// let mobile = PhonePattern::new("0# ########", "# ########").with_leading_digits("6");
+/// let formatter = PhoneNumberFormatter::new().with_patterns(nl, [mobile]);
+///
+/// let number: PhoneNumber = "+31612345678".parse().unwrap();
+/// assert_eq!(formatter.format(&number), "+31 6 12345678");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhonePattern {
+    leading_digits: &'static str,
+    national: &'static str,
+    international: &'static str,
+}
+
+impl PhonePattern {
+    /// Creates a pattern from its national and international templates.
+    #[must_use]
+    pub const fn new(national: &'static str, international: &'static str) -> Self {
+        Self {
+            leading_digits: "",
+            national,
+            international,
+        }
+    }
+
+    /// Limits the pattern to national numbers starting with `digits`.
+    #[must_use]
+    pub const fn with_leading_digits(mut self, digits: &'static str) -> Self {
+        self.leading_digits = digits;
+        self
+    }
+
+    /// Returns the digits that national numbers must start with.
+    #[must_use]
+    pub const fn leading_digits(&self) -> &'static str {
+        self.leading_digits
+    }
+
+    /// Returns the template used for the national format.
+    #[must_use]
+    pub const fn national(&self) -> &'static str {
+        self.national
+    }
+
+    /// Returns the template used after the calling code in the international format.
+    #[must_use]
+    pub const fn international(&self) -> &'static str {
+        self.international
+    }
+
+    /// Returns `true` if the pattern applies to `national_number`.
+    #[must_use]
+    pub fn matches(&self, national_number: &str) -> bool {
+        national_number.starts_with(self.leading_digits)
+            && self.national.bytes().filter(|&byte| byte == b'#').count() == national_number.len()
+    }
+}
+
+/// Formats [`PhoneNumber`]s for display.
+///
+/// Patterns added with [`with_patterns`](Self::with_patterns) are tried before the
+/// built-in ones for the same region.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::phone::{PhoneFormat, PhoneNumber, PhoneNumberFormatter};
+///
+/// let number: PhoneNumber = "+81 3-1234-5678".parse().unwrap();
+/// let formatter = PhoneNumberFormatter::new();
+///
+/// assert_eq!(formatter.format(&number), "+81 3-1234-5678");
+/// assert_eq!(formatter.with_format(PhoneFormat::National).format(&number), "03-1234-5678");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PhoneNumberFormatter {
+    format: PhoneFormat,
+    patterns: Vec<(Region, Vec<PhonePattern>)>,
+}
+
+impl PhoneNumberFormatter {
+    /// Creates a formatter that writes numbers in international format.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            format: PhoneFormat::International,
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Sets how numbers are written.
+    #[must_use]
+    pub const fn with_format(mut self, format: PhoneFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Adds patterns for numbers attributed to `region`, tried in order before any
+    /// added earlier and before the built-in ones.
+    #[must_use]
+    pub fn with_patterns(
+        mut self,
+        region: Region,
+        patterns: impl IntoIterator<Item = PhonePattern>,
+    ) -> Self {
+        self.patterns
+            .insert(0, (region, patterns.into_iter().collect()));
+        self
+    }
+
+    /// Returns how numbers are written.
+    #[must_use]
+    pub const fn phone_format(&self) -> PhoneFormat {
+        self.format
+    }
+
+    /// Formats `number` as a string.
+    #[must_use]
+    pub fn format(&self, number: &PhoneNumber) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(number, &mut output);
+        output
+    }
+
+    /// Formats `number` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        number: &PhoneNumber,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        let national_number = number.national_number();
+        let pattern = self.pattern(number);
+
+        match self.format {
+            PhoneFormat::E164 => write!(sink, "{number}")?,
+            PhoneFormat::International => {
+                write!(sink, "+{} ", number.calling_code)?;
+
+                match pattern {
+                    Some(pattern) => fill(pattern.international, national_number, sink)?,
+                    None => sink.write_str(national_number)?,
+                }
+            }
+            PhoneFormat::National => {
+                if let Some(pattern) = pattern {
+                    fill(pattern.national, national_number, sink)?;
+                } else {
+                    let format = number.region.and_then(built_in_format);
+                    sink.write_str(format.map_or("", |format| format.trunk_prefix))?;
+                    sink.write_str(national_number)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the first pattern that applies to `number`.
+    fn pattern(&self, number: &PhoneNumber) -> Option<&PhonePattern> {
+        let region = number.region?;
+        let added = self
+            .patterns
+            .iter()
+            .filter(|(candidate, _)| *candidate == region)
+            .flat_map(|(_, patterns)| patterns);
+        let built_in = built_in_format(region)
+            .into_iter()
+            .flat_map(|format| format.patterns);
+
+        added
+            .chain(built_in)
+            .find(|pattern| pattern.matches(&number.national_number))
+    }
+}
+
+/// Writes `template` with its `#`s replaced by the digits of `digits`.
+fn fill<W: Write + ?Sized>(template: &str, digits: &str, sink: &mut W) -> fmt::Result {
+    let mut digits = digits.chars();
+
+    for character in template.chars() {
+        match character {
+            '#' => sink.write_char(digits.next().unwrap_or('#'))?,
+            _ => sink.write_char(character)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// The trunk prefix and patterns of a region in the starter set.
+#[derive(Debug)]
+struct RegionFormat {
+    region: &'static str,
+    trunk_prefix: &'static str,
+    patterns: &'static [PhonePattern],
+}
+
+fn built_in_format(region: Region) -> Option<&'static RegionFormat> {
+    FORMATS
+        .iter()
+        .find(|format| Some(format.region) == region.alpha2_code())
+}
+
+static NANP: &[PhonePattern] = &[PhonePattern::new("(###) ###-####", "###-###-####")];
+
+static FORMATS: &[RegionFormat] = &[
+    RegionFormat {
+        region: "AU",
+        trunk_prefix: "0",
+        patterns: &[
+            PhonePattern::new("0### ### ###", "### ### ###").with_leading_digits("4"),
+            PhonePattern::new("0# #### ####", "# #### ####"),
+        ],
+    },
+    RegionFormat {
+        region: "CA",
+        trunk_prefix: "1",
+        patterns: NANP,
+    },
+    RegionFormat {
+        region: "DE",
+        trunk_prefix: "0",
+        patterns: &[
+            PhonePattern::new("0## ########", "## ########").with_leading_digits("30"),
+            PhonePattern::new("0## ########", "## ########").with_leading_digits("40"),
+            PhonePattern::new("0## ########", "## ########").with_leading_digits("69"),
+            PhonePattern::new("0## ########", "## ########").with_leading_digits("89"),
+            PhonePattern::new("0#### #######", "#### #######").with_leading_digits("1"),
+            PhonePattern::new("0### #######", "### #######").with_leading_digits("1"),
+        ],
+    },
+    RegionFormat {
+        region: "FR",
+        trunk_prefix: "0",
+        patterns: &[PhonePattern::new("0# ## ## ## ##", "# ## ## ## ##")],
+    },
+    RegionFormat {
+        region: "GB",
+        trunk_prefix: "0",
+        patterns: &[
+            PhonePattern::new("0## #### ####", "## #### ####").with_leading_digits("2"),
+            PhonePattern::new("0### ### ####", "### ### ####").with_leading_digits("3"),
+            PhonePattern::new("0### ### ####", "### ### ####").with_leading_digits("8"),
+            PhonePattern::new("0#### ######", "#### ######"),
+        ],
+    },
+    RegionFormat {
+        region: "IN",
+        trunk_prefix: "0",
+        patterns: &[PhonePattern::new("0##### #####", "##### #####")],
+    },
+    RegionFormat {
+        region: "JP",
+        trunk_prefix: "0",
+        patterns: &[
+            PhonePattern::new("0##-####-####", "##-####-####"),
+            PhonePattern::new("0#-####-####", "#-####-####").with_leading_digits("3"),
+            PhonePattern::new("0#-####-####", "#-####-####").with_leading_digits("6"),
+            PhonePattern::new("0##-###-####", "##-###-####"),
+        ],
+    },
+    RegionFormat {
+        region: "US",
+        trunk_prefix: "1",
+        patterns: NANP,
+    },
+];
+
+/// The region numbers are attributed to for calling codes that several regions share.
+static MAIN_REGIONS: &[(u16, &str)] = &[
+    (1, "US"),
+    (7, "RU"),
+    (39, "IT"),
+    (44, "GB"),
+    (47, "NO"),
+    (61, "AU"),
+    (64, "NZ"),
+    (212, "MA"),
+    (262, "RE"),
+    (358, "FI"),
+    (500, "FK"),
+    (590, "GP"),
+    (599, "CW"),
+    (672, "NF"),
+];
+
+/// ITU-T E.164 country calling codes, sorted by alpha-2 code. Regions without a code of
+/// their own are left out.
+static CALLING_CODES: &[(&str, u16)] = &[
+    ("AD", 376),
+    ("AE", 971),
+    ("AF", 93),
+    ("AG", 1),
+    ("AI", 1),
+    ("AL", 355),
+    ("AM", 374),
+    ("AO", 244),
+    ("AQ", 672),
+    ("AR", 54),
+    ("AS", 1),
+    ("AT", 43),
+    ("AU", 61),
+    ("AW", 297),
+    ("AX", 358),
+    ("AZ", 994),
+    ("BA", 387),
+    ("BB", 1),
+    ("BD", 880),
+    ("BE", 32),
+    ("BF", 226),
+    ("BG", 359),
+    ("BH", 973),
+    ("BI", 257),
+    ("BJ", 229),
+    ("BL", 590),
+    ("BM", 1),
+    ("BN", 673),
+    ("BO", 591),
+    ("BQ", 599),
+    ("BR", 55),
+    ("BS", 1),
+    ("BT", 975),
+    ("BW", 267),
+    ("BY", 375),
+    ("BZ", 501),
+    ("CA", 1),
+    ("CC", 61),
+    ("CD", 243),
+    ("CF", 236),
+    ("CG", 242),
+    ("CH", 41),
+    ("CI", 225),
+    ("CK", 682),
+    ("CL", 56),
+    ("CM", 237),
+    ("CN", 86),
+    ("CO", 57),
+    ("CR", 506),
+    ("CU", 53),
+    ("CV", 238),
+    ("CW", 599),
+    ("CX", 61),
+    ("CY", 357),
+    ("CZ", 420),
+    ("DE", 49),
+    ("DJ", 253),
+    ("DK", 45),
+    ("DM", 1),
+    ("DO", 1),
+    ("DZ", 213),
+    ("EC", 593),
+    ("EE", 372),
+    ("EG", 20),
+    ("EH", 212),
+    ("ER", 291),
+    ("ES", 34),
+    ("ET", 251),
+    ("FI", 358),
+    ("FJ", 679),
+    ("FK", 500),
+    ("FM", 691),
+    ("FO", 298),
+    ("FR", 33),
+    ("GA", 241),
+    ("GB", 44),
+    ("GD", 1),
+    ("GE", 995),
+    ("GF", 594),
+    ("GG", 44),
+    ("GH", 233),
+    ("GI", 350),
+    ("GL", 299),
+    ("GM", 220),
+    ("GN", 224),
+    ("GP", 590),
+    ("GQ", 240),
+    ("GR", 30),
+    ("GS", 500),
+    ("GT", 502),
+    ("GU", 1),
+    ("GW", 245),
+    ("GY", 592),
+    ("HK", 852),
+    ("HN", 504),
+    ("HR", 385),
+    ("HT", 509),
+    ("HU", 36),
+    ("ID", 62),
+    ("IE", 353),
+    ("IL", 972),
+    ("IM", 44),
+    ("IN", 91),
+    ("IO", 246),
+    ("IQ", 964),
+    ("IR", 98),
+    ("IS", 354),
+    ("IT", 39),
+    ("JE", 44),
+    ("JM", 1),
+    ("JO", 962),
+    ("JP", 81),
+    ("KE", 254),
+    ("KG", 996),
+    ("KH", 855),
+    ("KI", 686),
+    ("KM", 269),
+    ("KN", 1),
+    ("KP", 850),
+    ("KR", 82),
+    ("KW", 965),
+    ("KY", 1),
+    ("KZ", 7),
+    ("LA", 856),
+    ("LB", 961),
+    ("LC", 1),
+    ("LI", 423),
+    ("LK", 94),
+    ("LR", 231),
+    ("LS", 266),
+    ("LT", 370),
+    ("LU", 352),
+    ("LV", 371),
+    ("LY", 218),
+    ("MA", 212),
+    ("MC", 377),
+    ("MD", 373),
+    ("ME", 382),
+    ("MF", 590),
+    ("MG", 261),
+    ("MH", 692),
+    ("MK", 389),
+    ("ML", 223),
+    ("MM", 95),
+    ("MN", 976),
+    ("MO", 853),
+    ("MP", 1),
+    ("MQ", 596),
+    ("MR", 222),
+    ("MS", 1),
+    ("MT", 356),
+    ("MU", 230),
+    ("MV", 960),
+    ("MW", 265),
+    ("MX", 52),
+    ("MY", 60),
+    ("MZ", 258),
+    ("NA", 264),
+    ("NC", 687),
+    ("NE", 227),
+    ("NF", 672),
+    ("NG", 234),
+    ("NI", 505),
+    ("NL", 31),
+    ("NO", 47),
+    ("NP", 977),
+    ("NR", 674),
+    ("NU", 683),
+    ("NZ", 64),
+    ("OM", 968),
+    ("PA", 507),
+    ("PE", 51),
+    ("PF", 689),
+    ("PG", 675),
+    ("PH", 63),
+    ("PK", 92),
+    ("PL", 48),
+    ("PM", 508),
+    ("PN", 64),
+    ("PR", 1),
+    ("PS", 970),
+    ("PT", 351),
+    ("PW", 680),
+    ("PY", 595),
+    ("QA", 974),
+    ("RE", 262),
+    ("RO", 40),
+    ("RS", 381),
+    ("RU", 7),
+    ("RW", 250),
+    ("SA", 966),
+    ("SB", 677),
+    ("SC", 248),
+    ("SD", 249),
+    ("SE", 46),
+    ("SG", 65),
+    ("SH", 290),
+    ("SI", 386),
+    ("SJ", 47),
+    ("SK", 421),
+    ("SL", 232),
+    ("SM", 378),
+    ("SN", 221),
+    ("SO", 252),
+    ("SR", 597),
+    ("SS", 211),
+    ("ST", 239),
+    ("SV", 503),
+    ("SX", 1),
+    ("SY", 963),
+    ("SZ", 268),
+    ("TC", 1),
+    ("TD", 235),
+    ("TG", 228),
+    ("TH", 66),
+    ("TJ", 992),
+    ("TK", 690),
+    ("TL", 670),
+    ("TM", 993),
+    ("TN", 216),
+    ("TO", 676),
+    ("TR", 90),
+    ("TT", 1),
+    ("TV", 688),
+    ("TW", 886),
+    ("TZ", 255),
+    ("UA", 380),
+    ("UG", 256),
+    ("US", 1),
+    ("UY", 598),
+    ("UZ", 998),
+    ("VA", 39),
+    ("VC", 1),
+    ("VE", 58),
+    ("VG", 1),
+    ("VI", 1),
+    ("VN", 84),
+    ("VU", 678),
+    ("WF", 681),
+    ("WS", 685),
+    ("YE", 967),
+    ("YT", 262),
+    ("ZA", 27),
+    ("ZM", 260),
+    ("ZW", 263),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn region(code: &str) -> Option<Region> {
+        Region::from_code(code)
+    }
+
+    #[test]
+    fn test_tables_are_consistent() {
+        assert!(CALLING_CODES.is_sorted_by_key(|&(alpha2, _)| alpha2));
+
+        for &(alpha2, code) in CALLING_CODES {
+            let region = Region::from_code(alpha2).expect("a known region");
+            assert_eq!(calling_code(region), Some(code));
+            assert!(main_region(code).is_some_and(|main| calling_code(main) == Some(code)));
+        }
+
+        for &(code, alpha2) in MAIN_REGIONS {
+            assert_eq!(region(alpha2).and_then(calling_code), Some(code));
+        }
+
+        for format in FORMATS {
+            assert!(region(format.region).and_then(calling_code).is_some());
+
+            for pattern in format.patterns {
+                let count = |template: &str| template.matches('#').count();
+                assert_eq!(count(pattern.national), count(pattern.international));
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let number = PhoneNumber::parse("+44 20 7946 0958", None);
+        assert_eq!(
+            number.as_ref().map(PhoneNumber::national_number),
+            Ok("2079460958")
+        );
+
+        let number = PhoneNumber::parse("020 7946 0958", region("GB")).expect("a UK number");
+        assert_eq!(number.e164(), "+442079460958");
+        assert_eq!(number.region(), region("GB"));
+
+        let number = PhoneNumber::parse("1-416-555-0199", region("CA")).expect("a NANP number");
+        assert_eq!(number.to_string(), "+14165550199");
+        assert_eq!(number.region(), region("CA"));
+        assert_eq!(
+            PhoneNumber::parse("+14165550199", None).map(|n| n.region()),
+            Ok(region("US"))
+        );
+
+        assert_eq!(
+            PhoneNumber::from_e164("+4930123456").map(|n| n.calling_code()),
+            Ok(49)
+        );
+        assert_eq!(
+            PhoneNumber::from_e164("+49 30123456"),
+            Err(PhoneError::InvalidCharacter { position: 3 })
+        );
+        assert_eq!(PhoneNumber::parse(" ", None), Err(PhoneError::Empty));
+        assert_eq!(
+            PhoneNumber::parse("555-0123 ext", None),
+            Err(PhoneError::InvalidCharacter { position: 9 })
+        );
+        assert_eq!(
+            PhoneNumber::parse("5550123", None),
+            Err(PhoneError::MissingRegion)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+999 1234", None),
+            Err(PhoneError::UnknownCallingCode)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+33 123", None),
+            Err(PhoneError::TooShort)
+        );
+        assert_eq!(
+            PhoneNumber::parse("+1 2345678901234567", None),
+            Err(PhoneError::TooLong)
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let international = PhoneNumberFormatter::new();
+        let national = PhoneNumberFormatter::new().with_format(PhoneFormat::National);
+        let cases = [
+            ("+12015550123", "+1 201-555-0123", "(201) 555-0123"),
+            ("+442079460958", "+44 20 7946 0958", "020 7946 0958"),
+            ("+447400123456", "+44 7400 123456", "07400 123456"),
+            ("+33123456789", "+33 1 23 45 67 89", "01 23 45 67 89"),
+            ("+493012345678", "+49 30 12345678", "030 12345678"),
+            ("+4915123456789", "+49 1512 3456789", "01512 3456789"),
+            ("+819012345678", "+81 90-1234-5678", "090-1234-5678"),
+            ("+61412345678", "+61 412 345 678", "0412 345 678"),
+            ("+61212345678", "+61 2 1234 5678", "02 1234 5678"),
+            ("+919876543210", "+91 98765 43210", "098765 43210"),
+            ("+34612345678", "+34 612345678", "612345678"),
+        ];
+
+        for (text, expected_international, expected_national) in cases {
+            let number = PhoneNumber::from_e164(text).expect("a valid number");
+            assert_eq!(international.format(&number), expected_international);
+            assert_eq!(national.format(&number), expected_national);
+        }
+
+        let number = PhoneNumber::from_e164("+442079460958").expect("a valid number");
+        let e164 = PhoneNumberFormatter::new().with_format(PhoneFormat::E164);
+        assert_eq!(e164.format(&number), "+442079460958");
+    }
+}
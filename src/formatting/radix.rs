@@ -0,0 +1,431 @@
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Write};
+
+use crate::errors::{FormatError, ParseError};
+
+/// An integer that a [`RadixFormatter`] can write and parse.
+///
+/// Values are handled as a sign and a magnitude, so negative numbers are written with a
+/// minus sign rather than as their two's complement bits. Format the unsigned type of
+/// the same width, such as `-1_i8 as u8`, to see the bits.
+pub trait RadixInteger: Copy {
+    /// Returns whether the value is negative, and its absolute value.
+    fn to_sign_magnitude(self) -> (bool, u128);
+
+    /// Builds a value from a sign and an absolute value, or returns `None` if it does not
+    /// fit.
+    fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self>;
+}
+
+macro_rules! impl_radix_integer {
+    (unsigned: $($unsigned:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl RadixInteger for $unsigned {
+                #[allow(clippy::cast_lossless)] // `usize` has no `From` conversion.
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (false, self as u128)
+                }
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    if negative && magnitude != 0 {
+                        return None;
+                    }
+
+                    Self::try_from(magnitude).ok()
+                }
+            }
+        )*
+
+        $(
+            impl RadixInteger for $signed {
+                #[allow(clippy::cast_lossless)] // `usize` has no `From` conversion.
+                fn to_sign_magnitude(self) -> (bool, u128) {
+                    (self < 0, self.unsigned_abs() as u128)
+                }
+
+                fn from_sign_magnitude(negative: bool, magnitude: u128) -> Option<Self> {
+                    let value = if negative {
+                        0_i128.checked_sub_unsigned(magnitude)?
+                    } else {
+                        i128::try_from(magnitude).ok()?
+                    };
+
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_radix_integer!(
+    unsigned: u8, u16, u32, u64, u128, usize;
+    signed: i8, i16, i32, i64, i128, isize
+);
+
+/// Writes integers in any radix from 2 to 36, and parses them back.
+///
+/// Digits can be split into groups counted from the least significant digit, padded
+/// with zeros to a minimum number of digits, and preceded by the conventional `0b`, `0o`
+/// or `0x` prefix for binary, octal and hexadecimal.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::RadixFormatter;
+///
+/// let binary = RadixFormatter::new(2)
+///     .with_group_size(4)
+///     .with_minimum_digits(8);
+/// assert_eq!(binary.format(0b1010_1010_u8), "1010_1010");
+/// assert_eq!(binary.format(5_u8), "0000_0101");
+///
+/// let hex = RadixFormatter::new(16)
+///     .with_uppercase(true)
+///     .with_group_size(2)
+///     .with_group_separator(" ");
+/// assert_eq!(hex.format(0xffff_u16), "FF FF");
+/// assert_eq!(hex.parse::<u16>("ff ff"), Ok(0xffff));
+///
+/// let prefixed = RadixFormatter::new(16).with_uses_prefix(true);
+/// assert_eq!(prefixed.format(-255), "-0xff");
+/// assert_eq!(prefixed.parse::<i32>("-0xff"), Ok(-255));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RadixFormatter {
+    radix: u32,
+    uppercase: bool,
+    uses_prefix: bool,
+    group_size: usize,
+    group_separator: Cow<'static, str>,
+    minimum_digits: usize,
+}
+
+impl RadixFormatter {
+    /// Creates a formatter for `radix` with lowercase digits, no prefix, no grouping and
+    /// no padding. The group separator is `_`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36.
+    #[must_use]
+    pub const fn new(radix: u32) -> Self {
+        assert!(radix >= 2 && radix <= 36, "radix must be between 2 and 36");
+
+        Self {
+            radix,
+            uppercase: false,
+            uses_prefix: false,
+            group_size: 0,
+            group_separator: Cow::Borrowed("_"),
+            minimum_digits: 1,
+        }
+    }
+
+    /// Sets the radix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not between 2 and 36.
+    #[must_use]
+    pub const fn with_radix(mut self, radix: u32) -> Self {
+        assert!(radix >= 2 && radix <= 36, "radix must be between 2 and 36");
+        self.radix = radix;
+        self
+    }
+
+    /// Sets whether digits above 9 are written in uppercase.
+    #[must_use]
+    pub const fn with_uppercase(mut self, uppercase: bool) -> Self {
+        self.uppercase = uppercase;
+        self
+    }
+
+    /// Sets whether the `0b`, `0o` or `0x` prefix is written. Other radixes have no
+    /// prefix.
+    #[must_use]
+    pub const fn with_uses_prefix(mut self, uses_prefix: bool) -> Self {
+        self.uses_prefix = uses_prefix;
+        self
+    }
+
+    /// Sets how many digits make up a group; zero turns grouping off.
+    #[must_use]
+    pub const fn with_group_size(mut self, group_size: usize) -> Self {
+        self.group_size = group_size;
+        self
+    }
+
+    /// Sets the text written between groups.
+    #[must_use]
+    pub fn with_group_separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.group_separator = separator.into();
+        self
+    }
+
+    /// Sets the minimum number of digits, padding with leading zeros.
+    #[must_use]
+    pub const fn with_minimum_digits(mut self, digits: usize) -> Self {
+        self.minimum_digits = digits;
+        self
+    }
+
+    /// Returns the radix.
+    #[must_use]
+    pub const fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    /// Returns whether digits above 9 are written in uppercase.
+    #[must_use]
+    pub const fn uppercase(&self) -> bool {
+        self.uppercase
+    }
+
+    /// Returns whether the radix prefix is written.
+    #[must_use]
+    pub const fn uses_prefix(&self) -> bool {
+        self.uses_prefix
+    }
+
+    /// Returns the number of digits in a group, or zero if grouping is off.
+    #[must_use]
+    pub const fn group_size(&self) -> usize {
+        self.group_size
+    }
+
+    /// Returns the text written between groups.
+    #[must_use]
+    pub fn group_separator(&self) -> &str {
+        &self.group_separator
+    }
+
+    /// Returns the minimum number of digits.
+    #[must_use]
+    pub const fn minimum_digits(&self) -> usize {
+        self.minimum_digits
+    }
+
+    /// Returns the prefix of the radix, or an empty string if it has none.
+    #[must_use]
+    pub const fn prefix(&self) -> &'static str {
+        match self.radix {
+            2 => "0b",
+            8 => "0o",
+            16 => "0x",
+            _ => "",
+        }
+    }
+
+    /// Formats `value` into a new string.
+    #[must_use]
+    pub fn format(&self, value: impl RadixInteger) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(value, &mut output);
+        output
+    }
+
+    /// Formats `value` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        value: impl RadixInteger,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        self.write_value(value.to_sign_magnitude(), sink)
+            .map_err(|fmt::Error| FormatError::Write)
+    }
+
+    fn write_value<W: Write + ?Sized>(
+        &self,
+        (negative, mut magnitude): (bool, u128),
+        sink: &mut W,
+    ) -> fmt::Result {
+        // Enough for `u128::MAX` in binary.
+        let mut digits = [0_u8; 128];
+        let mut length = 0;
+
+        loop {
+            #[allow(clippy::cast_possible_truncation)] // The remainder is below 36.
+            let digit = (magnitude % u128::from(self.radix)) as u8;
+            digits[length] = digit;
+            length += 1;
+            magnitude /= u128::from(self.radix);
+
+            if magnitude == 0 {
+                break;
+            }
+        }
+
+        if negative {
+            sink.write_char('-')?;
+        }
+
+        if self.uses_prefix {
+            sink.write_str(self.prefix())?;
+        }
+
+        let total = length.max(self.minimum_digits);
+
+        for index in (0..total).rev() {
+            let digit = if index < length { digits[index] } else { 0 };
+            let character = char::from_digit(u32::from(digit), self.radix).unwrap_or('0');
+
+            sink.write_char(if self.uppercase {
+                character.to_ascii_uppercase()
+            } else {
+                character
+            })?;
+
+            if self.group_size > 0 && index > 0 && index % self.group_size == 0 {
+                sink.write_str(&self.group_separator)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses an integer written in this formatter's radix.
+    ///
+    /// A leading `+` or `-` sign, the radix prefix and group separators between digits
+    /// are accepted whether or not the formatter writes them, and digits are accepted in
+    /// either case.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Empty`] for empty input, [`ParseError::InvalidSyntax`] if
+    /// there are no digits, [`ParseError::InvalidCharacter`] for a character that is not
+    /// a digit of the radix, or [`ParseError::OutOfRange`] if the value does not fit in
+    /// `T`.
+    pub fn parse<T: RadixInteger>(&self, text: &str) -> Result<T, ParseError> {
+        if text.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (negative, mut position) = match text.as_bytes()[0] {
+            b'-' => (true, 1),
+            b'+' => (false, 1),
+            _ => (false, 0),
+        };
+
+        let prefix = self.prefix();
+
+        if !prefix.is_empty()
+            && text
+                .get(position..position + prefix.len())
+                .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+        {
+            position += prefix.len();
+        }
+
+        let mut magnitude = 0_u128;
+        let mut has_digits = false;
+
+        while let Some(character) = text[position..].chars().next() {
+            if has_digits
+                && !self.group_separator.is_empty()
+                && text[position..].starts_with(&*self.group_separator)
+            {
+                position += self.group_separator.len();
+                continue;
+            }
+
+            let digit = character
+                .to_digit(self.radix)
+                .ok_or(ParseError::InvalidCharacter {
+                    character,
+                    position,
+                })?;
+
+            magnitude = magnitude
+                .checked_mul(u128::from(self.radix))
+                .and_then(|shifted| shifted.checked_add(u128::from(digit)))
+                .ok_or(ParseError::OutOfRange)?;
+            has_digits = true;
+            position += character.len_utf8();
+        }
+
+        if !has_digits {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        T::from_sign_magnitude(negative, magnitude).ok_or(ParseError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format() {
+        let decimal = RadixFormatter::new(10)
+            .with_group_size(3)
+            .with_group_separator(",");
+        assert_eq!(decimal.format(1_234_567), "1,234,567");
+        assert_eq!(decimal.format(0_u8), "0");
+        assert_eq!(decimal.format(i64::MIN), "-9,223,372,036,854,775,808");
+
+        let binary = RadixFormatter::new(2).with_uses_prefix(true);
+        assert_eq!(binary.format(u128::MAX).len(), 130);
+        assert_eq!(binary.format(-2_i8), "-0b10");
+
+        let base36 = RadixFormatter::new(36)
+            .with_uppercase(true)
+            .with_uses_prefix(true);
+        assert_eq!(base36.format(35_u8), "Z");
+        assert_eq!(base36.with_radix(8).format(8_u8), "0o10");
+
+        let padded = RadixFormatter::new(16)
+            .with_minimum_digits(4)
+            .with_group_size(2);
+        assert_eq!(padded.format(0xa_u8), "00_0a");
+        assert_eq!(padded.format(0x12345_u32), "1_23_45");
+    }
+
+    #[test]
+    fn test_parse() {
+        let hex = RadixFormatter::new(16).with_group_size(4);
+
+        assert_eq!(hex.parse::<u32>("dead_BEEF"), Ok(0xdead_beef));
+        assert_eq!(hex.parse::<u32>("0XFF"), Ok(0xff));
+        assert_eq!(hex.parse::<i8>("-80"), Ok(i8::MIN));
+        assert_eq!(
+            hex.parse::<i128>("-80000000000000000000000000000000"),
+            Ok(i128::MIN)
+        );
+        assert_eq!(hex.parse::<u8>("-0"), Ok(0));
+        assert_eq!(hex.parse::<i8>("80"), Err(ParseError::OutOfRange));
+        assert_eq!(hex.parse::<u8>("-1"), Err(ParseError::OutOfRange));
+        assert_eq!(
+            hex.parse::<u128>(&"f".repeat(33)),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!(hex.parse::<u8>(""), Err(ParseError::Empty));
+        assert_eq!(hex.parse::<u8>("0x"), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            hex.parse::<u8>("_1"),
+            Err(ParseError::InvalidCharacter {
+                character: '_',
+                position: 0
+            })
+        );
+        assert_eq!(
+            hex.parse::<u8>("1g"),
+            Err(ParseError::InvalidCharacter {
+                character: 'g',
+                position: 1
+            })
+        );
+
+        let grouped = RadixFormatter::new(2)
+            .with_group_size(4)
+            .with_minimum_digits(16);
+        let text = grouped.format(0xbeef_u16);
+        assert_eq!(text, "1011_1110_1110_1111");
+        assert_eq!(grouped.parse::<u16>(&text), Ok(0xbeef));
+    }
+}
@@ -0,0 +1,580 @@
+//! Plain-text tables for terminals and logs.
+
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+use core::fmt::{self, Write};
+
+use crate::{
+    errors::FormatError,
+    terminal::{self, Segment},
+    text::Wrapper,
+};
+
+/// How the text of a cell is placed within its column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    /// Text starts at the left edge of the column.
+    #[default]
+    Left,
+
+    /// Text ends at the right edge of the column, as numbers usually are.
+    Right,
+
+    /// Text is centered, with any odd space on the right.
+    Center,
+}
+
+/// The lines drawn around and between the cells of a table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TableBorder {
+    /// No borders: columns are separated by two spaces and the header is underlined.
+    #[default]
+    None,
+
+    /// Borders drawn with `+`, `-` and `|`, for terminals without Unicode.
+    Ascii,
+
+    /// Borders drawn with Unicode box-drawing characters.
+    Unicode,
+}
+
+/// The characters a border is drawn with: horizontal, vertical, then the corners and
+/// junctions of the top, middle and bottom rules, each as left, middle and right.
+struct BorderChars {
+    horizontal: char,
+    vertical: char,
+    top: [char; 3],
+    middle: [char; 3],
+    bottom: [char; 3],
+}
+
+impl TableBorder {
+    const fn chars(self) -> Option<BorderChars> {
+        match self {
+            Self::None => None,
+            Self::Ascii => Some(BorderChars {
+                horizontal: '-',
+                vertical: '|',
+                top: ['+', '+', '+'],
+                middle: ['+', '+', '+'],
+                bottom: ['+', '+', '+'],
+            }),
+            Self::Unicode => Some(BorderChars {
+                horizontal: '─',
+                vertical: '│',
+                top: ['┌', '┬', '┐'],
+                middle: ['├', '┼', '┤'],
+                bottom: ['└', '┴', '┘'],
+            }),
+        }
+    }
+}
+
+/// Renders rows of text as a table with aligned columns.
+///
+/// Column widths are sized to the widest cell, counted in `char`s, and capped by
+/// [`with_maximum_column_width`](Self::with_maximum_column_width); longer cells are cut
+/// short and end with the ellipsis, or are wrapped onto several lines with
+/// [`with_wraps_cells`](Self::with_wraps_cells). ANSI escape sequences, such as those written by
+/// [`terminal::Style`], take no width and are kept when a cell is cut short. Rows may
+/// have different lengths, and missing cells are left empty.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::table::{Alignment, TableBorder, TableFormatter};
+///
+/// let table = TableFormatter::new()
+///     .with_border(TableBorder::Ascii)
+///     .with_header(true)
+///     .with_alignments([Alignment::Left, Alignment::Right]);
+///
+/// let rows = [["Fruit", "Count"], ["apple", "3"], ["kiwi", "12"]];
+///
+/// assert_eq!(
+///     table.format(&rows),
+///     "+-------+-------+\n\
+///      | Fruit | Count |\n\
+///      +-------+-------+\n\
+///      | apple |     3 |\n\
+///      | kiwi  |    12 |\n\
+///      +-------+-------+"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableFormatter {
+    border: TableBorder,
+    has_header: bool,
+    alignments: Vec<Alignment>,
+    maximum_column_width: Option<usize>,
+    wraps_cells: bool,
+    ellipsis: Cow<'static, str>,
+}
+
+impl TableFormatter {
+    /// Creates a borderless formatter with left-aligned columns and no header.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            border: TableBorder::None,
+            has_header: false,
+            alignments: Vec::new(),
+            maximum_column_width: None,
+            wraps_cells: false,
+            ellipsis: Cow::Borrowed("…"),
+        }
+    }
+
+    /// Sets the borders drawn around and between cells.
+    #[must_use]
+    pub const fn with_border(mut self, border: TableBorder) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Sets whether the first row is a header, separated from the others by a rule.
+    #[must_use]
+    pub const fn with_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+
+    /// Sets the alignment of each column, from the first; columns without one are
+    /// left-aligned.
+    #[must_use]
+    pub fn with_alignments(mut self, alignments: impl IntoIterator<Item = Alignment>) -> Self {
+        self.alignments = alignments.into_iter().collect();
+        self
+    }
+
+    /// Sets the widest a column may be; `None` lets columns grow to fit every cell.
+    #[must_use]
+    pub const fn with_maximum_column_width(mut self, width: Option<usize>) -> Self {
+        self.maximum_column_width = width;
+        self
+    }
+
+    /// Sets whether cells wider than the maximum column width are wrapped between words
+    /// onto several lines, rather than cut short.
+    #[must_use]
+    pub const fn with_wraps_cells(mut self, wraps_cells: bool) -> Self {
+        self.wraps_cells = wraps_cells;
+        self
+    }
+
+    /// Sets the text that ends truncated cells, `…` by default.
+    #[must_use]
+    pub fn with_ellipsis(mut self, ellipsis: impl Into<Cow<'static, str>>) -> Self {
+        self.ellipsis = ellipsis.into();
+        self
+    }
+
+    /// Returns the borders drawn around and between cells.
+    #[must_use]
+    pub const fn border(&self) -> TableBorder {
+        self.border
+    }
+
+    /// Returns `true` if the first row is a header.
+    #[must_use]
+    pub const fn has_header(&self) -> bool {
+        self.has_header
+    }
+
+    /// Returns the alignment of each column.
+    #[must_use]
+    pub fn alignments(&self) -> &[Alignment] {
+        &self.alignments
+    }
+
+    /// Returns the widest a column may be.
+    #[must_use]
+    pub const fn maximum_column_width(&self) -> Option<usize> {
+        self.maximum_column_width
+    }
+
+    /// Returns `true` if wide cells are wrapped rather than cut short.
+    #[must_use]
+    pub const fn wraps_cells(&self) -> bool {
+        self.wraps_cells
+    }
+
+    /// Returns the text that ends truncated cells.
+    #[must_use]
+    pub fn ellipsis(&self) -> &str {
+        &self.ellipsis
+    }
+
+    /// Renders `rows` as a table, with lines separated by `\n` and no trailing newline.
+    #[must_use]
+    pub fn format<R, C>(&self, rows: &[R]) -> String
+    where
+        R: AsRef<[C]>,
+        C: AsRef<str>,
+    {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(rows, &mut output);
+        output
+    }
+
+    /// Renders `rows` as a table into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<R, C, W>(&self, rows: &[R], sink: &mut W) -> Result<(), FormatError>
+    where
+        R: AsRef<[C]>,
+        C: AsRef<str>,
+        W: Write + ?Sized,
+    {
+        let columns = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+        let mut widths = vec![0; columns];
+
+        for row in rows {
+            for (width, cell) in widths.iter_mut().zip(row.as_ref()) {
+                *width = (*width).max(terminal::visible_width(cell.as_ref()));
+            }
+        }
+
+        if let Some(maximum) = self.maximum_column_width {
+            for width in &mut widths {
+                *width = (*width).min(maximum);
+            }
+        }
+
+        let border = self.border.chars();
+        let mut lines = 0;
+        let mut line = |sink: &mut W| {
+            lines += 1;
+            if lines > 1 {
+                sink.write_char('\n')
+            } else {
+                Ok(())
+            }
+        };
+
+        if let Some(border) = &border {
+            line(sink)?;
+            write_rule(&widths, border, border.top, sink)?;
+        }
+
+        for (index, row) in rows.iter().enumerate() {
+            let row = row.as_ref();
+
+            if self.wraps_cells && self.maximum_column_width.is_some() {
+                let cells: Vec<Vec<String>> = row
+                    .iter()
+                    .zip(&widths)
+                    .map(|(cell, &width)| Wrapper::new(width).wrap(cell.as_ref()))
+                    .collect();
+                let height = cells.iter().map(Vec::len).max().unwrap_or(1);
+
+                for line_index in 0..height {
+                    let cells: Vec<&str> = cells
+                        .iter()
+                        .map(|lines| lines.get(line_index).map_or("", String::as_str))
+                        .collect();
+
+                    line(sink)?;
+                    self.write_row(&cells, &widths, border.as_ref(), sink)?;
+                }
+            } else {
+                line(sink)?;
+                self.write_row(row, &widths, border.as_ref(), sink)?;
+            }
+
+            if self.has_header && index == 0 && rows.len() > 1 {
+                line(sink)?;
+
+                if let Some(border) = &border {
+                    write_rule(&widths, border, border.middle, sink)?;
+                } else {
+                    write_underline(&widths, sink)?;
+                }
+            }
+        }
+
+        if let Some(border) = &border {
+            line(sink)?;
+            write_rule(&widths, border, border.bottom, sink)?;
+        }
+
+        Ok(())
+    }
+
+    fn write_row<C, W>(
+        &self,
+        row: &[C],
+        widths: &[usize],
+        border: Option<&BorderChars>,
+        sink: &mut W,
+    ) -> fmt::Result
+    where
+        C: AsRef<str>,
+        W: Write + ?Sized,
+    {
+        let mut text = String::new();
+
+        for (column, &width) in widths.iter().enumerate() {
+            match border {
+                Some(border) => {
+                    text.push(border.vertical);
+                    text.push(' ');
+                }
+                None if column > 0 => text.push_str("  "),
+                None => {}
+            }
+
+            let cell = row.get(column).map_or("", AsRef::as_ref);
+            let alignment = self.alignments.get(column).copied().unwrap_or_default();
+            self.write_cell(cell, width, alignment, &mut text)?;
+
+            if border.is_some() {
+                text.push(' ');
+            }
+        }
+
+        match border {
+            Some(border) => {
+                text.push(border.vertical);
+                sink.write_str(&text)
+            }
+            None => sink.write_str(text.trim_end()),
+        }
+    }
+
+    fn write_cell<W: Write + ?Sized>(
+        &self,
+        cell: &str,
+        width: usize,
+        alignment: Alignment,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let length = terminal::visible_width(cell);
+
+        if length > width {
+            let ellipsis_length = self.ellipsis.chars().count();
+
+            if width < ellipsis_length {
+                return write_truncated(cell, width, "", sink);
+            }
+
+            return write_truncated(cell, width - ellipsis_length, &self.ellipsis, sink);
+        }
+
+        let padding = width - length;
+        let (before, after) = match alignment {
+            Alignment::Left => (0, padding),
+            Alignment::Right => (padding, 0),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+        };
+
+        write_repeated(' ', before, sink)?;
+        sink.write_str(cell)?;
+        write_repeated(' ', after, sink)
+    }
+}
+
+impl Default for TableFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn write_rule<W: Write + ?Sized>(
+    widths: &[usize],
+    border: &BorderChars,
+    [left, middle, right]: [char; 3],
+    sink: &mut W,
+) -> fmt::Result {
+    sink.write_char(left)?;
+
+    for (column, &width) in widths.iter().enumerate() {
+        if column > 0 {
+            sink.write_char(middle)?;
+        }
+
+        write_repeated(border.horizontal, width + 2, sink)?;
+    }
+
+    sink.write_char(right)
+}
+
+fn write_underline<W: Write + ?Sized>(widths: &[usize], sink: &mut W) -> fmt::Result {
+    for (column, &width) in widths.iter().enumerate() {
+        if column > 0 {
+            sink.write_str("  ")?;
+        }
+
+        write_repeated('-', width, sink)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the first `width` visible `char`s of `cell` followed by `ellipsis`.
+///
+/// Escape sequences before the cut are kept, and a reset follows if there were any, so
+/// the style of a cut cell does not leak into the rest of the line.
+fn write_truncated<W: Write + ?Sized>(
+    cell: &str,
+    width: usize,
+    ellipsis: &str,
+    sink: &mut W,
+) -> fmt::Result {
+    let mut remaining = width;
+    let mut styled = false;
+
+    for segment in terminal::segments(cell) {
+        match segment {
+            Segment::Escape(escape) => {
+                styled = true;
+                sink.write_str(escape)?;
+            }
+            Segment::Text(text) => {
+                for character in text.chars() {
+                    if remaining == 0 {
+                        break;
+                    }
+
+                    remaining -= 1;
+                    sink.write_char(character)?;
+                }
+            }
+        }
+
+        if remaining == 0 {
+            break;
+        }
+    }
+
+    sink.write_str(ellipsis)?;
+
+    if styled {
+        sink.write_str(terminal::RESET)?;
+    }
+
+    Ok(())
+}
+
+fn write_repeated<W: Write + ?Sized>(character: char, count: usize, sink: &mut W) -> fmt::Result {
+    (0..count).try_for_each(|_| sink.write_char(character))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_plain_table() {
+        let table = TableFormatter::new().with_header(true).with_alignments([
+            Alignment::Left,
+            Alignment::Center,
+            Alignment::Right,
+        ]);
+
+        let rows = [
+            vec!["Name", "Kind", "Size"],
+            vec!["notes.txt", "file", "1.2 KB"],
+            vec!["src"],
+        ];
+
+        assert_eq!(
+            table.format(&rows),
+            "Name       Kind    Size\n\
+             ---------  ----  ------\n\
+             notes.txt  file  1.2 KB\n\
+             src"
+        );
+    }
+
+    #[test]
+    fn test_unicode_border_and_truncation() {
+        let table = TableFormatter::new()
+            .with_border(TableBorder::Unicode)
+            .with_maximum_column_width(Some(6));
+
+        assert_eq!(
+            table.format(&[["a", "description"], ["bb", "short"]]),
+            "┌────┬────────┐\n\
+             │ a  │ descr… │\n\
+             │ bb │ short  │\n\
+             └────┴────────┘"
+        );
+
+        let ascii = table.with_border(TableBorder::Ascii).with_ellipsis("...");
+        assert_eq!(
+            ascii.format(&[["description"]]),
+            "+--------+\n\
+             | des... |\n\
+             +--------+"
+        );
+    }
+
+    #[test]
+    fn test_wrapped_cells() {
+        let table = TableFormatter::new()
+            .with_border(TableBorder::Ascii)
+            .with_maximum_column_width(Some(10))
+            .with_wraps_cells(true)
+            .with_alignments([Alignment::Right]);
+
+        let rows = [
+            ["1", "A cell that is too long for one line"],
+            ["22", "short"],
+        ];
+
+        assert_eq!(
+            table.format(&rows),
+            "+----+------------+\n\
+             |  1 | A cell     |\n\
+             |    | that is    |\n\
+             |    | too long   |\n\
+             |    | for one    |\n\
+             |    | line       |\n\
+             | 22 | short      |\n\
+             +----+------------+"
+        );
+    }
+
+    #[test]
+    fn test_styled_cells() {
+        use crate::terminal::{Style, TerminalColor};
+
+        let red = Style::new().with_foreground(TerminalColor::Red);
+        let status = red.styled("failed").to_string();
+        let table = TableFormatter::new().with_border(TableBorder::Ascii);
+
+        assert_eq!(
+            table.format(&[["build", status.as_str()], ["test", "ok"]]),
+            "+-------+--------+\n\
+             | build | \x1b[31mfailed\x1b[0m |\n\
+             | test  | ok     |\n\
+             +-------+--------+"
+        );
+
+        let narrow = table.with_maximum_column_width(Some(4));
+        assert_eq!(
+            narrow.format(&[[status.as_str()]]),
+            "+------+\n\
+             | \x1b[31mfai…\x1b[0m |\n\
+             +------+"
+        );
+    }
+
+    #[test]
+    fn test_empty_table() {
+        let rows: [[&str; 0]; 0] = [];
+
+        assert_eq!(TableFormatter::new().format(&rows), "");
+        assert_eq!(
+            TableFormatter::new()
+                .with_border(TableBorder::Ascii)
+                .format(&rows),
+            "++\n++"
+        );
+    }
+}
@@ -0,0 +1,791 @@
+//! Message templates with named arguments, in a subset of ICU `MessageFormat`.
+//!
+//! A template is literal text with placeholders in braces:
+//!
+//! - `{name}` writes the argument `name`. Numbers go through the template's
+//!   [`NumberFormatter`], dates are written in ISO 8601 form and text is copied as is.
+//! - `{name, number}` requires a number and accepts the styles `integer`, `percent`,
+//!   `compact-short` and `compact-long`, as in `{ratio, number, percent}`.
+//! - `{name, date}` requires a [`Date`] and accepts the style `iso`.
+//! - `{name, plural, one{...} other{...}}` picks a sub-message by the plural category of
+//!   a number, or by an exact value written as `=0`. Inside it, `#` stands for the
+//!   formatted number.
+//! - `{name, select, female{...} other{...}}` picks a sub-message by text.
+//!
+//! Plural and select placeholders must have an `other` sub-message. An apostrophe
+//! quotes a following brace or `#` up to the next apostrophe, and `''` writes a single
+//! apostrophe.
+
+use alloc::{string::String, vec::Vec};
+use core::fmt::Write;
+
+use super::numbers::{NumberFormatter, NumberStyle, Value};
+use crate::{
+    calendar::{Calendar, Date},
+    errors::TemplateError,
+    locale::{Locale, PluralRules},
+    num::Number,
+};
+
+/// A value passed to a template.
+///
+/// Arguments are created with `From` from numbers, strings and [`Date`]s.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Argument<'a> {
+    kind: Kind<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Kind<'a> {
+    Number(Value),
+    Text(&'a str),
+    Date(Date),
+}
+
+macro_rules! impl_from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for Argument<'_> {
+                fn from(value: $ty) -> Self {
+                    Self {
+                        kind: Kind::Number(Value::Integer(i128::from(value))),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl From<isize> for Argument<'_> {
+    fn from(value: isize) -> Self {
+        Self::from(Number::Int(value))
+    }
+}
+
+impl From<usize> for Argument<'_> {
+    fn from(value: usize) -> Self {
+        Self::from(Number::UInt(value))
+    }
+}
+
+impl From<f32> for Argument<'_> {
+    fn from(value: f32) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl From<f64> for Argument<'_> {
+    fn from(value: f64) -> Self {
+        Self {
+            kind: Kind::Number(Value::Float(value)),
+        }
+    }
+}
+
+impl From<Number> for Argument<'_> {
+    fn from(number: Number) -> Self {
+        Self {
+            kind: Kind::Number(Value::from(number)),
+        }
+    }
+}
+
+impl<'a> From<&'a str> for Argument<'a> {
+    fn from(text: &'a str) -> Self {
+        Self {
+            kind: Kind::Text(text),
+        }
+    }
+}
+
+impl<'a> From<&'a String> for Argument<'a> {
+    fn from(text: &'a String) -> Self {
+        Self::from(text.as_str())
+    }
+}
+
+impl From<Date> for Argument<'_> {
+    fn from(date: Date) -> Self {
+        Self {
+            kind: Kind::Date(date),
+        }
+    }
+}
+
+/// Formats message templates with a locale's number formatting and plural rules.
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::template::TemplateFormatter;
+/// use libx::locale::Locale;
+///
+/// let formatter = TemplateFormatter::new().with_locale(&Locale::new("de_DE"));
+/// let message = formatter.format(
+///     "{count, plural, one{# Datei} other{# Dateien}} gelöscht",
+///     &[("count", 1200.into())],
+/// );
+///
+/// assert_eq!(message.as_deref(), Ok("1.200 Dateien gelöscht"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateFormatter {
+    number_formatter: NumberFormatter,
+    plural_rules: PluralRules,
+}
+
+impl TemplateFormatter {
+    /// Creates a formatter with English plural rules and grouped English numbers.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            number_formatter: NumberFormatter::new().with_uses_grouping_separator(true),
+            plural_rules: PluralRules::ENGLISH,
+        }
+    }
+
+    /// Takes the number formatting and plural rules from `locale`.
+    #[must_use]
+    pub fn with_locale(self, locale: &Locale) -> Self {
+        Self {
+            number_formatter: self.number_formatter.with_locale(locale),
+            plural_rules: PluralRules::for_locale(locale),
+        }
+    }
+
+    /// Sets the formatter used for number arguments and `#` in plural sub-messages.
+    #[must_use]
+    pub fn with_number_formatter(mut self, number_formatter: NumberFormatter) -> Self {
+        self.number_formatter = number_formatter;
+        self
+    }
+
+    /// Sets the rules that pick plural sub-messages.
+    #[must_use]
+    pub const fn with_plural_rules(mut self, plural_rules: PluralRules) -> Self {
+        self.plural_rules = plural_rules;
+        self
+    }
+
+    /// Returns the formatter used for number arguments.
+    #[must_use]
+    pub const fn number_formatter(&self) -> &NumberFormatter {
+        &self.number_formatter
+    }
+
+    /// Returns the rules that pick plural sub-messages.
+    #[must_use]
+    pub const fn plural_rules(&self) -> PluralRules {
+        self.plural_rules
+    }
+
+    /// Formats `template` with the named `arguments`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TemplateError::InvalidSyntax`] if the template is malformed,
+    /// [`TemplateError::MissingArgument`] if a placeholder that is written names no
+    /// argument, and [`TemplateError::MismatchedArgument`] if an argument has the wrong
+    /// kind for its placeholder.
+    pub fn format(
+        &self,
+        template: &str,
+        arguments: &[(&str, Argument<'_>)],
+    ) -> Result<String, TemplateError> {
+        let mut output = String::new();
+        self.write(template, arguments, &mut output)?;
+        Ok(output)
+    }
+
+    /// Formats `template` with the named `arguments` into `sink`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`format`](Self::format), and [`TemplateError::Write`] if
+    /// the sink fails.
+    pub fn write<W: Write + ?Sized>(
+        &self,
+        template: &str,
+        arguments: &[(&str, Argument<'_>)],
+        sink: &mut W,
+    ) -> Result<(), TemplateError> {
+        let mut parser = Parser {
+            formatter: self,
+            template,
+            arguments,
+            position: 0,
+        };
+
+        parser.message(sink, true, None, false)
+    }
+}
+
+impl Default for TemplateFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Formats `template` with the named `arguments`, using English number formatting and
+/// plural rules.
+///
+/// # Errors
+///
+/// Returns the errors of [`TemplateFormatter::format`].
+///
+/// # Examples
+///
+/// ```
+/// use libx::formatting::template::format_template;
+///
+/// let message = format_template(
+///     "Hello {name}, you have {count, plural, =0{no items} one{# item} other{# items}}",
+///     &[("name", "Ada".into()), ("count", 3.into())],
+/// );
+///
+/// assert_eq!(message.as_deref(), Ok("Hello Ada, you have 3 items"));
+/// ```
+pub fn format_template(
+    template: &str,
+    arguments: &[(&str, Argument<'_>)],
+) -> Result<String, TemplateError> {
+    TemplateFormatter::new().format(template, arguments)
+}
+
+/// A single pass over a template that writes its output as it goes.
+///
+/// Sub-messages of plural and select placeholders are first parsed without output to
+/// find their bounds, then the selected one is parsed again with output.
+struct Parser<'a> {
+    formatter: &'a TemplateFormatter,
+    template: &'a str,
+    arguments: &'a [(&'a str, Argument<'a>)],
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a message up to the end of the template, or up to the closing brace of a
+    /// sub-message when `nested` is set. `pound` is the number `#` stands for.
+    fn message<W: Write + ?Sized>(
+        &mut self,
+        sink: &mut W,
+        emit: bool,
+        pound: Option<Value>,
+        nested: bool,
+    ) -> Result<(), TemplateError> {
+        loop {
+            let Some(character) = self.peek() else {
+                return if nested {
+                    Err(self.syntax_error())
+                } else {
+                    Ok(())
+                };
+            };
+
+            match character {
+                '}' if nested => {
+                    self.position += 1;
+                    return Ok(());
+                }
+                '}' => return Err(self.syntax_error()),
+                '{' => self.placeholder(sink, emit, pound)?,
+                '#' if pound.is_some() => {
+                    self.position += 1;
+
+                    if let (true, Some(value)) = (emit, pound) {
+                        self.formatter.number_formatter.write_value(value, sink)?;
+                    }
+                }
+                '\'' => self.quoted(sink, emit)?,
+                _ => {
+                    self.position += character.len_utf8();
+
+                    if emit {
+                        sink.write_char(character)?;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parses an apostrophe and the text it quotes.
+    fn quoted<W: Write + ?Sized>(&mut self, sink: &mut W, emit: bool) -> Result<(), TemplateError> {
+        let start = self.position;
+        self.position += 1;
+
+        match self.peek() {
+            Some('\'') => {
+                self.position += 1;
+
+                if emit {
+                    sink.write_char('\'')?;
+                }
+            }
+            Some('{' | '}' | '#') => loop {
+                let Some(character) = self.peek() else {
+                    return Err(TemplateError::InvalidSyntax { position: start });
+                };
+
+                self.position += character.len_utf8();
+
+                if character == '\'' {
+                    if self.peek() != Some('\'') {
+                        break;
+                    }
+
+                    self.position += 1;
+                }
+
+                if emit {
+                    sink.write_char(character)?;
+                }
+            },
+            _ => {
+                if emit {
+                    sink.write_char('\'')?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn placeholder<W: Write + ?Sized>(
+        &mut self,
+        sink: &mut W,
+        emit: bool,
+        pound: Option<Value>,
+    ) -> Result<(), TemplateError> {
+        let start = self.position;
+        self.position += 1;
+
+        let name = self.identifier()?;
+
+        match self.next_token()? {
+            '}' => {
+                if emit {
+                    self.write_argument(self.argument(name, start)?, sink)?;
+                }
+
+                return Ok(());
+            }
+            ',' => {}
+            _ => return Err(self.syntax_error()),
+        }
+
+        self.skip_whitespace();
+        let kind_position = self.position;
+        let kind = self.identifier()?;
+
+        match kind {
+            "number" | "date" => {
+                let style = match self.next_token()? {
+                    '}' => None,
+                    ',' => {
+                        self.skip_whitespace();
+                        let style = (self.position, self.identifier()?);
+
+                        if self.next_token()? != '}' {
+                            return Err(self.syntax_error());
+                        }
+
+                        Some(style)
+                    }
+                    _ => return Err(self.syntax_error()),
+                };
+
+                if emit {
+                    let argument = self.argument(name, start)?;
+
+                    if kind == "number" {
+                        self.write_number(argument, style, start, sink)?;
+                    } else {
+                        Self::write_date(argument, style, start, sink)?;
+                    }
+                }
+
+                Ok(())
+            }
+            "plural" | "select" => {
+                if self.next_token()? != ',' {
+                    return Err(self.syntax_error());
+                }
+
+                let argument = if emit {
+                    Some(self.argument(name, start)?)
+                } else {
+                    None
+                };
+                let value = match (kind, argument) {
+                    (
+                        "plural",
+                        Some(Argument {
+                            kind: Kind::Number(value),
+                        }),
+                    ) => Some(value),
+                    ("plural", Some(_))
+                    | (
+                        "select",
+                        Some(Argument {
+                            kind: Kind::Number(_) | Kind::Date(_),
+                        }),
+                    ) => {
+                        return Err(TemplateError::MismatchedArgument { position: start });
+                    }
+                    _ => None,
+                };
+
+                let branches = self.branches(start, value.or(pound))?;
+                let selected = match (value, argument) {
+                    (Some(value), _) => self.select_plural(&branches, value),
+                    (
+                        None,
+                        Some(Argument {
+                            kind: Kind::Text(text),
+                        }),
+                    ) => select(&branches, text),
+                    _ => return Ok(()),
+                };
+
+                let end = self.position;
+                self.position = selected;
+                self.message(sink, true, value.or(pound), true)?;
+                self.position = end;
+
+                Ok(())
+            }
+            _ => Err(TemplateError::InvalidSyntax {
+                position: kind_position,
+            }),
+        }
+    }
+
+    /// Parses the `selector{sub-message}` pairs of a plural or select placeholder up to
+    /// its closing brace, returning each selector and the start of its sub-message.
+    fn branches(
+        &mut self,
+        start: usize,
+        pound: Option<Value>,
+    ) -> Result<Vec<(&'a str, usize)>, TemplateError> {
+        let mut branches = Vec::new();
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                Some('}') => {
+                    self.position += 1;
+                    break;
+                }
+                Some('{') | None => return Err(self.syntax_error()),
+                Some(_) => {}
+            }
+
+            let template = self.template;
+            let selector_start = self.position;
+            let rest = &template[selector_start..];
+            let length = rest
+                .find(|c: char| c.is_whitespace() || c == '{' || c == '}')
+                .unwrap_or(rest.len());
+            self.position += length;
+            let selector = &template[selector_start..self.position];
+
+            if self.next_token()? != '{' {
+                return Err(self.syntax_error());
+            }
+
+            let body = self.position;
+            self.message(&mut Discard, false, pound, true)?;
+            branches.push((selector, body));
+        }
+
+        if branches.iter().any(|&(selector, _)| selector == "other") {
+            Ok(branches)
+        } else {
+            Err(TemplateError::InvalidSyntax { position: start })
+        }
+    }
+
+    /// Returns the start of the sub-message for `value`: an exact `=N` match first, then
+    /// its plural category, then `other`.
+    fn select_plural(&self, branches: &[(&str, usize)], value: Value) -> usize {
+        // Exact selectors are small integers, which `f64` holds exactly.
+        let number = value.to_f64();
+        let category = match value {
+            Value::Integer(value) => self.formatter.plural_rules.category_of_integer(value),
+            Value::Float(_) | Value::Decimal(_) => {
+                self.formatter.plural_rules.category_of_float(number)
+            }
+        };
+
+        branches
+            .iter()
+            .find(|(selector, _)| {
+                selector
+                    .strip_prefix('=')
+                    .and_then(|exact| exact.parse::<f64>().ok())
+                    .is_some_and(|exact| {
+                        #[allow(clippy::float_cmp)] // Exact selectors match exact values only.
+                        let matches = exact == number;
+                        matches
+                    })
+            })
+            .map_or_else(|| select(branches, category.keyword()), |&(_, body)| body)
+    }
+
+    fn write_argument<W: Write + ?Sized>(
+        &self,
+        argument: Argument<'_>,
+        sink: &mut W,
+    ) -> Result<(), TemplateError> {
+        match argument.kind {
+            Kind::Number(value) => {
+                self.formatter.number_formatter.write_value(value, sink)?;
+            }
+            Kind::Text(text) => sink.write_str(text)?,
+            Kind::Date(date) => write_iso_date(date, sink)?,
+        }
+
+        Ok(())
+    }
+
+    fn write_number<W: Write + ?Sized>(
+        &self,
+        argument: Argument<'_>,
+        style: Option<(usize, &str)>,
+        start: usize,
+        sink: &mut W,
+    ) -> Result<(), TemplateError> {
+        let Kind::Number(value) = argument.kind else {
+            return Err(TemplateError::MismatchedArgument { position: start });
+        };
+
+        let formatter = self.formatter.number_formatter.clone();
+        let formatter = match style {
+            None => formatter,
+            Some((_, "integer")) => formatter.with_maximum_fraction_digits(0),
+            Some((_, "percent")) => formatter.with_style(NumberStyle::Percent),
+            Some((_, "compact-short")) => formatter.with_style(NumberStyle::CompactShort),
+            Some((_, "compact-long")) => formatter.with_style(NumberStyle::CompactLong),
+            Some((position, _)) => return Err(TemplateError::InvalidSyntax { position }),
+        };
+
+        formatter.write_value(value, sink)?;
+        Ok(())
+    }
+
+    fn write_date<W: Write + ?Sized>(
+        argument: Argument<'_>,
+        style: Option<(usize, &str)>,
+        start: usize,
+        sink: &mut W,
+    ) -> Result<(), TemplateError> {
+        let Kind::Date(date) = argument.kind else {
+            return Err(TemplateError::MismatchedArgument { position: start });
+        };
+
+        match style {
+            None | Some((_, "iso")) => write_iso_date(date, sink),
+            Some((position, _)) => Err(TemplateError::InvalidSyntax { position }),
+        }
+    }
+
+    fn argument(&self, name: &str, start: usize) -> Result<Argument<'a>, TemplateError> {
+        self.arguments
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|&(_, argument)| argument)
+            .ok_or(TemplateError::MissingArgument { position: start })
+    }
+
+    /// Parses a name made of letters, digits, `_` and `-`, surrounded by whitespace.
+    fn identifier(&mut self) -> Result<&'a str, TemplateError> {
+        self.skip_whitespace();
+
+        let template = self.template;
+        let start = self.position;
+        let rest = &template[start..];
+        let length = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+            .unwrap_or(rest.len());
+
+        if length == 0 {
+            return Err(self.syntax_error());
+        }
+
+        self.position += length;
+        let identifier = &template[start..self.position];
+        self.skip_whitespace();
+
+        Ok(identifier)
+    }
+
+    /// Skips whitespace and consumes the next character.
+    fn next_token(&mut self) -> Result<char, TemplateError> {
+        self.skip_whitespace();
+
+        let character = self.peek().ok_or_else(|| self.syntax_error())?;
+        self.position += character.len_utf8();
+
+        Ok(character)
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.template[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.template[self.position..].chars().next()
+    }
+
+    const fn syntax_error(&self) -> TemplateError {
+        TemplateError::InvalidSyntax {
+            position: self.position,
+        }
+    }
+}
+
+/// Returns the start of the sub-message with `selector`, or of `other`.
+fn select(branches: &[(&str, usize)], selector: &str) -> usize {
+    branches
+        .iter()
+        .find(|&&(key, _)| key == selector)
+        .or_else(|| branches.iter().find(|&&(key, _)| key == "other"))
+        .map_or(0, |&(_, body)| body)
+}
+
+fn write_iso_date<W: Write + ?Sized>(date: Date, sink: &mut W) -> Result<(), TemplateError> {
+    let components = Calendar::gregorian().date_components(date);
+
+    write!(
+        sink,
+        "{:04}-{:02}-{:02}",
+        components.year.unwrap_or_default(),
+        components.month.unwrap_or_default(),
+        components.day.unwrap_or_default()
+    )?;
+
+    Ok(())
+}
+
+/// A sink for sub-messages that are parsed but not written.
+struct Discard;
+
+impl Write for Discard {
+    fn write_str(&mut self, _: &str) -> core::fmt::Result {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::DateComponents;
+
+    #[test]
+    fn test_simple_arguments() {
+        let date = Calendar::gregorian()
+            .date(&DateComponents::ymd(2024, 3, 9))
+            .expect("valid date");
+
+        assert_eq!(
+            format_template(
+                "{who} paid {amount} on {when}",
+                &[
+                    ("who", "Ada".into()),
+                    ("amount", 1234.5.into()),
+                    ("when", date.into()),
+                ],
+            ),
+            Ok(String::from("Ada paid 1,234.5 on 2024-03-09"))
+        );
+        assert_eq!(
+            format_template(
+                "{ratio, number, percent} / {n, number, integer}",
+                &[("ratio", 0.25.into()), ("n", 2.7.into()),]
+            ),
+            Ok(String::from("25% / 3"))
+        );
+    }
+
+    #[test]
+    fn test_plural_and_select() {
+        let template = "{host, select, female{{guests, plural, =0{She has no guests} one{She invited one guest} other{She invited # guests}}} other{They invited {guests, plural, one{# guest} other{# guests}}}}";
+
+        let format = |host: &str, guests: i32| {
+            format_template(
+                template,
+                &[("host", host.into()), ("guests", guests.into())],
+            )
+        };
+
+        assert_eq!(format("female", 0).as_deref(), Ok("She has no guests"));
+        assert_eq!(format("female", 1).as_deref(), Ok("She invited one guest"));
+        assert_eq!(
+            format("female", 1500).as_deref(),
+            Ok("She invited 1,500 guests")
+        );
+        assert_eq!(format("male", 1).as_deref(), Ok("They invited 1 guest"));
+    }
+
+    #[test]
+    fn test_locale_plural_rules() {
+        let polish = TemplateFormatter::new().with_locale(&Locale::new("pl_PL"));
+        let template = "{n, plural, one{# plik} few{# pliki} many{# plików} other{# pliku}}";
+
+        let format = |n: Argument<'_>| polish.format(template, &[("n", n)]);
+
+        assert_eq!(format(1.into()).as_deref(), Ok("1 plik"));
+        assert_eq!(format(3.into()).as_deref(), Ok("3 pliki"));
+        assert_eq!(format(12.into()).as_deref(), Ok("12 plików"));
+        assert_eq!(format(1.5.into()).as_deref(), Ok("1,5 pliku"));
+    }
+
+    #[test]
+    fn test_quoting() {
+        assert_eq!(
+            format_template(
+                "It''s '{literal}' and '#' {n, plural, other{'#'#}}",
+                &[("n", 2.into())]
+            ),
+            Ok(String::from("It's {literal} and # #2"))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            format_template("Hello {name", &[("name", "Ada".into())]),
+            Err(TemplateError::InvalidSyntax { position: 11 })
+        );
+        assert_eq!(
+            format_template("Hello {name}", &[]),
+            Err(TemplateError::MissingArgument { position: 6 })
+        );
+        assert_eq!(
+            format_template("{n, plural, one{x}}", &[("n", 1.into())]),
+            Err(TemplateError::InvalidSyntax { position: 0 })
+        );
+        assert_eq!(
+            format_template("{n, plural, other{#}}", &[("n", "many".into())]),
+            Err(TemplateError::MismatchedArgument { position: 0 })
+        );
+        assert_eq!(
+            format_template("{n, spell}", &[("n", 1.into())]),
+            Err(TemplateError::InvalidSyntax { position: 4 })
+        );
+        assert_eq!(
+            format_template("a } b", &[]),
+            Err(TemplateError::InvalidSyntax { position: 2 })
+        );
+
+        // Arguments of sub-messages that are not selected are never looked up.
+        assert_eq!(
+            format_template("{n, plural, one{{missing}} other{ok}}", &[("n", 2.into())]),
+            Ok(String::from("ok"))
+        );
+    }
+}
@@ -0,0 +1,439 @@
+//! Functional conveniences: [`Either`] and extension traits for `Option` and `Result`.
+//!
+//! These are the small combinators that otherwise get rewritten in every downstream
+//! crate: side-effecting taps that pass the value through, zipping two results with a
+//! function, and collecting an iterator of results while keeping *every* error instead of
+//! stopping at the first one.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A value of one of two types.
+///
+/// Unlike `Result`, neither side implies failure. When both sides are iterators over the
+/// same item type, `Either` is itself an iterator, which lets a function return one of
+/// two iterator types without boxing.
+///
+/// # Examples
+///
+/// ```
+/// use libx::func::Either;
+///
+/// fn evens_or_all(only_evens: bool) -> impl Iterator<Item = u32> {
+///     if only_evens {
+///         Either::Left((0..10).filter(|n| n % 2 == 0))
+///     } else {
+///         Either::Right(0..10)
+///     }
+/// }
+///
+/// assert_eq!(evens_or_all(true).count(), 5);
+/// assert_eq!(evens_or_all(false).count(), 10);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Either<L, R> {
+    /// A value of the left type.
+    Left(L),
+
+    /// A value of the right type.
+    Right(R),
+}
+
+impl<L, R> Either<L, R> {
+    /// Returns `true` if this is a `Left` value.
+    #[must_use]
+    pub const fn is_left(&self) -> bool {
+        matches!(self, Self::Left(_))
+    }
+
+    /// Returns `true` if this is a `Right` value.
+    #[must_use]
+    pub const fn is_right(&self) -> bool {
+        matches!(self, Self::Right(_))
+    }
+
+    /// Returns the left value, if any.
+    #[must_use]
+    pub fn left(self) -> Option<L> {
+        match self {
+            Self::Left(left) => Some(left),
+            Self::Right(_) => None,
+        }
+    }
+
+    /// Returns the right value, if any.
+    #[must_use]
+    pub fn right(self) -> Option<R> {
+        match self {
+            Self::Left(_) => None,
+            Self::Right(right) => Some(right),
+        }
+    }
+
+    /// Converts from `&Either<L, R>` to `Either<&L, &R>`.
+    #[must_use]
+    pub const fn as_ref(&self) -> Either<&L, &R> {
+        match self {
+            Self::Left(left) => Either::Left(left),
+            Self::Right(right) => Either::Right(right),
+        }
+    }
+
+    /// Converts from `&mut Either<L, R>` to `Either<&mut L, &mut R>`.
+    #[must_use]
+    pub const fn as_mut(&mut self) -> Either<&mut L, &mut R> {
+        match self {
+            Self::Left(left) => Either::Left(left),
+            Self::Right(right) => Either::Right(right),
+        }
+    }
+
+    /// Swaps the sides.
+    #[must_use]
+    pub fn flip(self) -> Either<R, L> {
+        match self {
+            Self::Left(left) => Either::Right(left),
+            Self::Right(right) => Either::Left(right),
+        }
+    }
+
+    /// Applies `f` to a left value, leaving a right value unchanged.
+    #[must_use]
+    pub fn map_left<T, F>(self, f: F) -> Either<T, R>
+    where
+        F: FnOnce(L) -> T,
+    {
+        match self {
+            Self::Left(left) => Either::Left(f(left)),
+            Self::Right(right) => Either::Right(right),
+        }
+    }
+
+    /// Applies `f` to a right value, leaving a left value unchanged.
+    #[must_use]
+    pub fn map_right<T, F>(self, f: F) -> Either<L, T>
+    where
+        F: FnOnce(R) -> T,
+    {
+        match self {
+            Self::Left(left) => Either::Left(left),
+            Self::Right(right) => Either::Right(f(right)),
+        }
+    }
+
+    /// Collapses either side into a single value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::func::Either;
+    ///
+    /// let value: Either<u8, &str> = Either::Right("four");
+    /// assert_eq!(value.either(|n| n as usize, str::len), 4);
+    /// ```
+    pub fn either<T, F, G>(self, f: F, g: G) -> T
+    where
+        F: FnOnce(L) -> T,
+        G: FnOnce(R) -> T,
+    {
+        match self {
+            Self::Left(left) => f(left),
+            Self::Right(right) => g(right),
+        }
+    }
+}
+
+impl<T> Either<T, T> {
+    /// Returns the value from whichever side is present.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        match self {
+            Self::Left(value) | Self::Right(value) => value,
+        }
+    }
+}
+
+impl<T, E> From<Result<T, E>> for Either<E, T> {
+    /// Converts `Err` to `Left` and `Ok` to `Right`.
+    fn from(result: Result<T, E>) -> Self {
+        match result {
+            Ok(value) => Self::Right(value),
+            Err(error) => Self::Left(error),
+        }
+    }
+}
+
+impl<L, R> fmt::Display for Either<L, R>
+where
+    L: fmt::Display,
+    R: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Left(left) => left.fmt(f),
+            Self::Right(right) => right.fmt(f),
+        }
+    }
+}
+
+impl<L, R> Iterator for Either<L, R>
+where
+    L: Iterator,
+    R: Iterator<Item = L::Item>,
+{
+    type Item = L::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Left(left) => left.next(),
+            Self::Right(right) => right.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Left(left) => left.size_hint(),
+            Self::Right(right) => right.size_hint(),
+        }
+    }
+}
+
+impl<L, R> DoubleEndedIterator for Either<L, R>
+where
+    L: DoubleEndedIterator,
+    R: DoubleEndedIterator<Item = L::Item>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Left(left) => left.next_back(),
+            Self::Right(right) => right.next_back(),
+        }
+    }
+}
+
+impl<L, R> ExactSizeIterator for Either<L, R>
+where
+    L: ExactSizeIterator,
+    R: ExactSizeIterator<Item = L::Item>,
+{
+}
+
+impl<L, R> core::iter::FusedIterator for Either<L, R>
+where
+    L: core::iter::FusedIterator,
+    R: core::iter::FusedIterator<Item = L::Item>,
+{
+}
+
+/// Extension methods for `Option`.
+///
+/// `Option` already has an unstable inherent `zip_with`, so the zipping helper lives only
+/// on [`ResultExt`]; for options use `a.zip(b).map(|(a, b)| f(a, b))`.
+pub trait OptionExt<T> {
+    /// Calls `f` with a reference to the contained value, if any, and returns the option
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::func::OptionExt;
+    ///
+    /// let mut seen = None;
+    /// let value = Some(3).tap(|n| seen = Some(*n));
+    ///
+    /// assert_eq!(value, Some(3));
+    /// assert_eq!(seen, Some(3));
+    /// ```
+    #[must_use]
+    fn tap<F: FnOnce(&T)>(self, f: F) -> Self;
+
+    /// Calls `f` if the option is `None`, and returns the option unchanged.
+    #[must_use]
+    fn tap_none<F: FnOnce()>(self, f: F) -> Self;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    fn tap<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Some(value) = &self {
+            f(value);
+        }
+
+        self
+    }
+
+    fn tap_none<F: FnOnce()>(self, f: F) -> Self {
+        if self.is_none() {
+            f();
+        }
+
+        self
+    }
+}
+
+/// Extension methods for `Result`.
+pub trait ResultExt<T, E> {
+    /// Calls `f` with a reference to the success value, if any, and returns the result
+    /// unchanged.
+    #[must_use]
+    fn tap<F: FnOnce(&T)>(self, f: F) -> Self;
+
+    /// Calls `f` with a reference to the error, if any, and returns the result unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::func::ResultExt;
+    ///
+    /// let mut logged = 0;
+    /// let result: Result<u8, &str> = Err("bad input").tap_err(|_| logged += 1);
+    ///
+    /// assert_eq!(result, Err("bad input"));
+    /// assert_eq!(logged, 1);
+    /// ```
+    #[must_use]
+    fn tap_err<F: FnOnce(&E)>(self, f: F) -> Self;
+
+    /// Combines two successful results with `f`, or returns the first error.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `self` if it failed, otherwise the error of `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::func::ResultExt;
+    ///
+    /// let width: Result<u32, &str> = Ok(3);
+    /// let height: Result<u32, &str> = Ok(4);
+    /// assert_eq!(width.zip_with(height, |w, h| w * h), Ok(12));
+    ///
+    /// let missing: Result<u32, &str> = Err("no height");
+    /// assert_eq!(width.zip_with(missing, |w, h| w * h), Err("no height"));
+    /// ```
+    fn zip_with<U, R, F>(self, other: Result<U, E>, f: F) -> Result<R, E>
+    where
+        F: FnOnce(T, U) -> R;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    fn tap<F: FnOnce(&T)>(self, f: F) -> Self {
+        if let Ok(value) = &self {
+            f(value);
+        }
+
+        self
+    }
+
+    fn tap_err<F: FnOnce(&E)>(self, f: F) -> Self {
+        if let Err(error) = &self {
+            f(error);
+        }
+
+        self
+    }
+
+    fn zip_with<U, R, F>(self, other: Result<U, E>, f: F) -> Result<R, E>
+    where
+        F: FnOnce(T, U) -> R,
+    {
+        Ok(f(self?, other?))
+    }
+}
+
+/// Extension methods for iterators over `Result`s.
+pub trait ResultIteratorExt<T, E>: Iterator<Item = Result<T, E>> + Sized {
+    /// Collects every success value, or every error if there was at least one.
+    ///
+    /// Unlike collecting into `Result<Vec<T>, E>`, this consumes the whole iterator, so
+    /// callers can report all problems at once.
+    ///
+    /// # Errors
+    ///
+    /// Returns all errors, in order, if any item was an `Err`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::func::ResultIteratorExt;
+    ///
+    /// let parsed = ["1", "x", "3", "y"].iter().map(|s| s.parse::<u8>()).collect_results();
+    /// assert_eq!(parsed.map_err(|errors| errors.len()), Err(2));
+    ///
+    /// let parsed = ["1", "2"].iter().map(|s| s.parse::<u8>()).collect_results();
+    /// assert_eq!(parsed, Ok(vec![1, 2]));
+    /// ```
+    fn collect_results(self) -> Result<Vec<T>, Vec<E>> {
+        let mut values = Vec::new();
+        let mut errors = Vec::new();
+
+        for item in self {
+            match item {
+                Ok(value) if errors.is_empty() => values.push(value),
+                Ok(_) => {}
+                Err(error) => errors.push(error),
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(values)
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+impl<I, T, E> ResultIteratorExt<T, E> for I where I: Iterator<Item = Result<T, E>> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec};
+
+    use super::*;
+
+    #[test]
+    fn test_either_accessors() {
+        let left: Either<u8, &str> = Either::Left(1);
+        let right: Either<u8, &str> = Either::Right("one");
+
+        assert!(left.is_left());
+        assert!(right.is_right());
+        assert_eq!(left.left(), Some(1));
+        assert_eq!(right.left(), None);
+        assert_eq!(right.flip(), Either::Left("one"));
+        assert_eq!(left.map_left(|n| n + 1), Either::Left(2));
+        assert_eq!(right.map_right(str::len).right(), Some(3));
+        assert_eq!(format!("{right}"), "one");
+        assert_eq!(Either::<u8, u8>::Right(5).into_inner(), 5);
+        assert_eq!(Either::from(Err::<u8, &str>("e")), Either::Left("e"));
+    }
+
+    #[test]
+    fn test_either_iterates() {
+        let mut iter: Either<_, core::iter::Empty<u8>> = Either::Left([1, 2, 3].into_iter());
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_taps() {
+        let mut calls = 0;
+
+        let _ = Some(1).tap(|_| calls += 1).tap_none(|| calls += 10);
+        let _ = None::<u8>.tap(|_| calls += 100).tap_none(|| calls += 1000);
+        let _ = Ok::<u8, u8>(1).tap(|_| calls += 1).tap_err(|_| calls += 10);
+
+        assert_eq!(calls, 1002);
+    }
+
+    #[test]
+    fn test_collect_results_keeps_all_errors() {
+        let results = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+        assert_eq!(results.into_iter().collect_results(), Err(vec!["a", "b"]));
+
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+        assert_eq!(results.into_iter().collect_results(), Ok(vec![1, 2]));
+    }
+}
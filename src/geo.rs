@@ -0,0 +1,569 @@
+//! Geographic coordinates, great-circle distances and geohashes.
+//!
+//! [`Coordinate`] holds a validated latitude and longitude in degrees. Distances treat
+//! the Earth as a sphere of radius [`EARTH_RADIUS`], which is within about half a
+//! percent of the true ellipsoidal distance: [`Coordinate::haversine_distance`] is the
+//! classic formula, and [`Coordinate::vincenty_distance`] is Vincenty's formula
+//! specialized to a sphere, which stays accurate for nearly antipodal points.
+//!
+//! [`Geohash`] encodes a coordinate as up to twelve base-32 characters naming a cell of
+//! a grid, so that nearby points usually share a prefix. It is stored inline and never
+//! allocates.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::geo::{Coordinate, Geohash};
+//!
+//! let paris = Coordinate::new(48.8566, 2.3522).unwrap();
+//! let london = Coordinate::new(51.5074, -0.1278).unwrap();
+//!
+//! let kilometers = paris.haversine_distance(london) / 1000.0;
+//! assert!((kilometers - 343.56).abs() < 0.01);
+//!
+//! let hash = Geohash::encode(paris, 6).unwrap();
+//! assert_eq!(hash.as_str(), "u09tvw");
+//! assert!(hash.bounds().contains(paris));
+//! ```
+
+use core::{fmt, str, str::FromStr};
+
+use crate::{
+    errors::GeoError,
+    num::{atan2, sin_cos, sqrt},
+};
+
+/// The mean radius of the Earth in meters.
+pub const EARTH_RADIUS: f64 = 6_371_008.8;
+
+/// A point on the Earth given by its latitude and longitude in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coordinate {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl Coordinate {
+    /// Creates a coordinate, with north and east positive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoError::InvalidLatitude`] if `latitude` is not within `-90..=90`, or
+    /// [`GeoError::InvalidLongitude`] if `longitude` is not within `-180..=180`.
+    pub fn new(latitude: f64, longitude: f64) -> Result<Self, GeoError> {
+        if !(-90.0..=90.0).contains(&latitude) {
+            return Err(GeoError::InvalidLatitude);
+        }
+
+        if !(-180.0..=180.0).contains(&longitude) {
+            return Err(GeoError::InvalidLongitude);
+        }
+
+        Ok(Self {
+            latitude,
+            longitude,
+        })
+    }
+
+    /// Returns the latitude in degrees.
+    #[must_use]
+    pub const fn latitude(self) -> f64 {
+        self.latitude
+    }
+
+    /// Returns the longitude in degrees.
+    #[must_use]
+    pub const fn longitude(self) -> f64 {
+        self.longitude
+    }
+
+    /// Returns the great-circle distance to `other` in meters by the haversine formula.
+    #[must_use]
+    pub fn haversine_distance(self, other: Self) -> f64 {
+        let (sin_latitude, _) = sin_cos((other.latitude - self.latitude).to_radians() / 2.0);
+        let (sin_longitude, _) = sin_cos((other.longitude - self.longitude).to_radians() / 2.0);
+        let (_, cos_from) = sin_cos(self.latitude.to_radians());
+        let (_, cos_to) = sin_cos(other.latitude.to_radians());
+
+        let haversine = (sin_latitude * sin_latitude
+            + cos_from * cos_to * sin_longitude * sin_longitude)
+            .min(1.0);
+
+        2.0 * EARTH_RADIUS * atan2(sqrt(haversine), sqrt(1.0 - haversine))
+    }
+
+    /// Returns the great-circle distance to `other` in meters by Vincenty's formula on
+    /// a sphere.
+    ///
+    /// This costs a little more than [`haversine_distance`](Self::haversine_distance)
+    /// but keeps full precision for points on opposite sides of the Earth.
+    #[must_use]
+    pub fn vincenty_distance(self, other: Self) -> f64 {
+        let (x, y, z) = self.great_circle(other);
+
+        EARTH_RADIUS * atan2(sqrt(x * x + y * y), z)
+    }
+
+    /// Returns the initial bearing of the great circle to `other`, in degrees clockwise
+    /// from north within `0.0..360.0`.
+    ///
+    /// The bearing from a point to itself is zero.
+    #[must_use]
+    pub fn initial_bearing(self, other: Self) -> f64 {
+        let (x, y, _) = self.great_circle(other);
+
+        (atan2(x, y).to_degrees() + 360.0) % 360.0
+    }
+
+    /// Returns the east, north and radial components of the direction to `other`, which
+    /// the distance and bearing formulas share.
+    fn great_circle(self, other: Self) -> (f64, f64, f64) {
+        let (sin_from, cos_from) = sin_cos(self.latitude.to_radians());
+        let (sin_to, cos_to) = sin_cos(other.latitude.to_radians());
+        let (sin_longitude, cos_longitude) =
+            sin_cos((other.longitude - self.longitude).to_radians());
+
+        (
+            cos_to * sin_longitude,
+            cos_from * sin_to - sin_from * cos_to * cos_longitude,
+            sin_from * sin_to + cos_from * cos_to * cos_longitude,
+        )
+    }
+}
+
+/// A latitude and longitude range, such as the cell a [`Geohash`] names.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bounds {
+    /// The southern edge in degrees of latitude.
+    pub south: f64,
+
+    /// The western edge in degrees of longitude.
+    pub west: f64,
+
+    /// The northern edge in degrees of latitude.
+    pub north: f64,
+
+    /// The eastern edge in degrees of longitude.
+    pub east: f64,
+}
+
+impl Bounds {
+    /// Returns the point halfway between the edges.
+    #[must_use]
+    pub const fn center(&self) -> Coordinate {
+        Coordinate {
+            latitude: f64::midpoint(self.south, self.north),
+            longitude: f64::midpoint(self.west, self.east),
+        }
+    }
+
+    /// Returns `true` if `coordinate` lies within the edges, inclusive.
+    #[must_use]
+    pub fn contains(&self, coordinate: Coordinate) -> bool {
+        (self.south..=self.north).contains(&coordinate.latitude)
+            && (self.west..=self.east).contains(&coordinate.longitude)
+    }
+}
+
+/// One of the eight directions to a neighboring [`Geohash`] cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Returns the steps north and east, each -1, 0 or 1, in this direction.
+    const fn offset(self) -> (f64, f64) {
+        match self {
+            Self::North => (1.0, 0.0),
+            Self::NorthEast => (1.0, 1.0),
+            Self::East => (0.0, 1.0),
+            Self::SouthEast => (-1.0, 1.0),
+            Self::South => (-1.0, 0.0),
+            Self::SouthWest => (-1.0, -1.0),
+            Self::West => (0.0, -1.0),
+            Self::NorthWest => (1.0, -1.0),
+        }
+    }
+}
+
+/// The characters of a geohash, each of which encodes five bits.
+const ALPHABET: &[u8; 32] = b"0123456789bcdefghjkmnpqrstuvwxyz";
+
+/// The most characters a [`Geohash`] holds, which narrows a cell to a few centimeters.
+pub const MAX_PRECISION: usize = 12;
+
+/// A geohash of one to [`MAX_PRECISION`] characters.
+///
+/// Each character halves the cell five times, alternately in longitude and latitude,
+/// starting with longitude. Parsing accepts either case; the hash is stored and
+/// displayed in lower case.
+///
+/// # Examples
+///
+/// ```
+/// use libx::geo::{Direction, Geohash};
+///
+/// let hash: Geohash = "gbsuv".parse().unwrap();
+///
+/// assert_eq!(hash.neighbor(Direction::North).unwrap().as_str(), "gbsvj");
+/// assert_eq!(hash.neighbor(Direction::West).unwrap().as_str(), "gbsuu");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Geohash {
+    bytes: [u8; MAX_PRECISION],
+    len: u8,
+}
+
+impl Geohash {
+    /// Returns the geohash of `precision` characters whose cell contains `coordinate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeoError::InvalidPrecision`] if `precision` is not within
+    /// `1..=MAX_PRECISION`.
+    pub fn encode(coordinate: Coordinate, precision: usize) -> Result<Self, GeoError> {
+        if !(1..=MAX_PRECISION).contains(&precision) {
+            return Err(GeoError::InvalidPrecision { precision });
+        }
+
+        let mut bytes = [0; MAX_PRECISION];
+        let mut latitude = (-90.0, 90.0);
+        let mut longitude = (-180.0, 180.0);
+        let mut is_longitude = true;
+
+        for byte in &mut bytes[..precision] {
+            let mut index = 0;
+
+            for _ in 0..5 {
+                let (range, value) = if is_longitude {
+                    (&mut longitude, coordinate.longitude)
+                } else {
+                    (&mut latitude, coordinate.latitude)
+                };
+                let middle = f64::midpoint(range.0, range.1);
+
+                index <<= 1;
+
+                if value >= middle {
+                    index |= 1;
+                    range.0 = middle;
+                } else {
+                    range.1 = middle;
+                }
+
+                is_longitude = !is_longitude;
+            }
+
+            *byte = ALPHABET[index];
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // The precision is at most 12.
+        let len = precision as u8;
+
+        Ok(Self { bytes, len })
+    }
+
+    /// Returns the hash as a string.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: the bytes only ever hold characters of `ALPHABET`, which are ASCII.
+        unsafe { str::from_utf8_unchecked(&self.bytes[..self.precision()]) }
+    }
+
+    /// Returns the number of characters.
+    #[must_use]
+    pub const fn precision(&self) -> usize {
+        self.len as usize
+    }
+
+    /// Returns the cell the hash names.
+    #[must_use]
+    pub fn bounds(&self) -> Bounds {
+        let mut latitude = (-90.0, 90.0);
+        let mut longitude = (-180.0, 180.0);
+        let mut is_longitude = true;
+
+        for &byte in self.as_str().as_bytes() {
+            let index = ALPHABET.iter().position(|&c| c == byte).unwrap_or_default();
+
+            for bit in (0..5).rev() {
+                let range = if is_longitude {
+                    &mut longitude
+                } else {
+                    &mut latitude
+                };
+                let middle = f64::midpoint(range.0, range.1);
+
+                if index >> bit & 1 == 1 {
+                    range.0 = middle;
+                } else {
+                    range.1 = middle;
+                }
+
+                is_longitude = !is_longitude;
+            }
+        }
+
+        Bounds {
+            south: latitude.0,
+            west: longitude.0,
+            north: latitude.1,
+            east: longitude.1,
+        }
+    }
+
+    /// Returns the center of the cell the hash names.
+    #[must_use]
+    pub fn center(&self) -> Coordinate {
+        self.bounds().center()
+    }
+
+    /// Returns the hash of the same precision for the adjacent cell in `direction`, or
+    /// `None` if that cell would lie beyond a pole. Cells wrap around the antimeridian.
+    #[must_use]
+    pub fn neighbor(&self, direction: Direction) -> Option<Self> {
+        let bounds = self.bounds();
+        let center = bounds.center();
+        let (north, east) = direction.offset();
+
+        let latitude = center.latitude + north * (bounds.north - bounds.south);
+        let mut longitude = center.longitude + east * (bounds.east - bounds.west);
+
+        if !(-90.0..=90.0).contains(&latitude) {
+            return None;
+        }
+
+        if longitude > 180.0 {
+            longitude -= 360.0;
+        } else if longitude < -180.0 {
+            longitude += 360.0;
+        }
+
+        Self::encode(
+            Coordinate {
+                latitude,
+                longitude,
+            },
+            self.precision(),
+        )
+        .ok()
+    }
+
+    /// Returns the neighbors in the order of [`Direction::ALL`].
+    #[must_use]
+    pub fn neighbors(&self) -> [Option<Self>; 8] {
+        Direction::ALL.map(|direction| self.neighbor(direction))
+    }
+}
+
+impl fmt::Debug for Geohash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Geohash").field(&self.as_str()).finish()
+    }
+}
+
+impl fmt::Display for Geohash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Geohash {
+    type Err = GeoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = [0; MAX_PRECISION];
+
+        for (position, character) in s.char_indices() {
+            let lower = character.to_ascii_lowercase();
+
+            if !lower.is_ascii() || !ALPHABET.contains(&(lower as u8)) {
+                return Err(GeoError::InvalidCharacter {
+                    character,
+                    position,
+                });
+            }
+
+            if let Some(byte) = bytes.get_mut(position) {
+                *byte = lower as u8;
+            }
+        }
+
+        if !(1..=MAX_PRECISION).contains(&s.len()) {
+            return Err(GeoError::InvalidPrecision { precision: s.len() });
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // The length is at most 12.
+        let len = s.len() as u8;
+
+        Ok(Self { bytes, len })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    fn coordinate(latitude: f64, longitude: f64) -> Coordinate {
+        Coordinate::new(latitude, longitude).expect("the coordinate is valid")
+    }
+
+    fn hash(s: &str) -> Geohash {
+        s.parse().expect("the geohash is valid")
+    }
+
+    #[test]
+    fn test_distances_and_bearings() {
+        let origin = coordinate(0.0, 0.0);
+        let quarter = EARTH_RADIUS * core::f64::consts::FRAC_PI_2;
+
+        assert!((origin.haversine_distance(coordinate(0.0, 90.0)) - quarter).abs() < 1e-6);
+        assert!((origin.vincenty_distance(coordinate(90.0, 0.0)) - quarter).abs() < 1e-6);
+        assert!((origin.vincenty_distance(coordinate(0.0, 180.0)) - 2.0 * quarter).abs() < 1e-6);
+        assert!(origin.haversine_distance(origin).abs() < 1e-9);
+
+        for (target, bearing) in [
+            (coordinate(10.0, 0.0), 0.0),
+            (coordinate(0.0, 10.0), 90.0),
+            (coordinate(-10.0, 0.0), 180.0),
+            (coordinate(0.0, -10.0), 270.0),
+            (origin, 0.0),
+        ] {
+            assert!((origin.initial_bearing(target) - bearing).abs() < 1e-9);
+        }
+
+        // The flight from London heads west-north-west towards New York.
+        let bearing = coordinate(51.5074, -0.1278).initial_bearing(coordinate(40.7128, -74.006));
+        assert!((bearing - 288.3).abs() < 0.1, "{bearing}");
+    }
+
+    #[test]
+    fn test_formulas_agree() {
+        let result = check_property(0x2004, 500, |rng| {
+            let mut point = || {
+                Coordinate::new(
+                    rng.next_f64() * 180.0 - 90.0,
+                    rng.next_f64() * 360.0 - 180.0,
+                )
+                .map_err(|error| alloc::format!("{error}"))
+            };
+            let (a, b) = (point()?, point()?);
+            let (haversine, vincenty) = (a.haversine_distance(b), a.vincenty_distance(b));
+
+            if (haversine - vincenty).abs() > 1.0
+                || (a.vincenty_distance(b) - b.vincenty_distance(a)).abs() > 1e-6
+            {
+                return Err(alloc::format!("{a:?} to {b:?}: {haversine} and {vincenty}").into());
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn test_geohash_round_trip() {
+        let point = coordinate(57.649_11, 10.407_44);
+        let encoded = Geohash::encode(point, 11).expect("the precision is valid");
+
+        assert_eq!(encoded.as_str(), "u4pruydqqvj");
+        assert_eq!(hash("U4PRUYDQQVJ"), encoded);
+        assert!(encoded.bounds().contains(point));
+
+        let bounds = hash("ezs42").bounds();
+        assert_eq!(
+            bounds,
+            Bounds {
+                south: 42.583_007_812_5,
+                west: -5.625,
+                north: 42.626_953_125,
+                east: -5.581_054_687_5,
+            }
+        );
+        assert_eq!(Geohash::encode(bounds.center(), 5), Ok(hash("ezs42")));
+    }
+
+    #[test]
+    fn test_geohash_neighbors() {
+        let neighbors = hash("gbsuv")
+            .neighbors()
+            .map(|neighbor| neighbor.map(|h| h.to_string()));
+
+        assert_eq!(
+            neighbors.each_ref().map(|neighbor| neighbor.as_deref()),
+            [
+                Some("gbsvj"),
+                Some("gbsvn"),
+                Some("gbsuy"),
+                Some("gbsuw"),
+                Some("gbsut"),
+                Some("gbsus"),
+                Some("gbsuu"),
+                Some("gbsvh"),
+            ]
+        );
+
+        assert_eq!(hash("b").neighbor(Direction::West), Some(hash("z")));
+        assert_eq!(hash("zzz").neighbor(Direction::East), Some(hash("bpb")));
+        assert_eq!(hash("zzz").neighbor(Direction::North), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Coordinate::new(90.5, 0.0), Err(GeoError::InvalidLatitude));
+        assert_eq!(
+            Coordinate::new(f64::NAN, 0.0),
+            Err(GeoError::InvalidLatitude)
+        );
+        assert_eq!(
+            Coordinate::new(0.0, -180.5),
+            Err(GeoError::InvalidLongitude)
+        );
+        assert_eq!(
+            Geohash::encode(coordinate(0.0, 0.0), 13),
+            Err(GeoError::InvalidPrecision { precision: 13 })
+        );
+        assert_eq!(
+            "".parse::<Geohash>(),
+            Err(GeoError::InvalidPrecision { precision: 0 })
+        );
+        assert_eq!(
+            "ezsa2".parse::<Geohash>(),
+            Err(GeoError::InvalidCharacter {
+                character: 'a',
+                position: 3
+            })
+        );
+        assert_eq!(
+            "ez€".parse::<Geohash>(),
+            Err(GeoError::InvalidCharacter {
+                character: '€',
+                position: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Checksums and hash functions over byte slices.
+//!
+//! Every hasher can be fed its input in pieces with `update` and produces the same result
+//! as hashing the whole input at once. The cryptographic hash functions implement
+//! [`Digest`], so that constructions such as [`hmac::Hmac`], [`merkle::MerkleTree`] and
+//! the key derivation functions in [`security`](crate::security) work with any of them.
+//! [`SipHash13`] and [`SipHash24`] are keyed hashers for hash tables instead,
+//! implementing [`Hasher`], and [`ConsistentHashRing`] uses them to spread keys over a
+//! changing set of nodes.
+//!
+//! With the `legacy-hashes` feature, the `md5` and `sha1` modules add the MD5 and SHA-1
+//! digests. Both are broken and are only for interoperating with formats that still
+//! require them.
+//!
+//! [`Hasher`]: core::hash::Hasher
+
+use core::{fmt, hash::Hash};
+
+pub mod crc;
+pub mod hmac;
+#[cfg(feature = "legacy-hashes")]
+pub mod md5;
+pub mod merkle;
+pub mod ring;
+#[cfg(feature = "legacy-hashes")]
+pub mod sha1;
+pub mod sha2;
+pub mod siphash;
+
+pub use ring::{ConsistentHashRing, RingStatistics};
+pub use siphash::{SipHash13, SipHash24, SipHashState};
+
+/// A cryptographic hash function that can be fed its input in pieces.
+///
+/// # Examples
+///
+/// ```
+/// use libx::hashing::{Digest, sha2::Sha256};
+///
+/// fn fingerprint<D: Digest>(parts: &[&[u8]]) -> D::Output {
+///     let mut digest = D::new();
+///
+///     for part in parts {
+///         digest.update(part);
+///     }
+///
+///     digest.finish()
+/// }
+///
+/// assert_eq!(fingerprint::<Sha256>(&[b"ab", b"c"]), Sha256::digest(b"abc"));
+/// ```
+pub trait Digest: Clone + fmt::Debug {
+    /// The number of bytes the function compresses at a time.
+    const BLOCK_SIZE: usize;
+
+    /// The length of the digest in bytes.
+    const OUTPUT_SIZE: usize;
+
+    /// The digest, an array of [`OUTPUT_SIZE`](Self::OUTPUT_SIZE) bytes.
+    type Output: AsRef<[u8]> + AsMut<[u8]> + Copy + Eq + Hash + fmt::Debug;
+
+    /// Starts a computation over no input.
+    fn new() -> Self;
+
+    /// Adds `bytes` to the input.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the digest of the input so far. More input can still be added.
+    fn finish(&self) -> Self::Output;
+
+    /// Returns the digest of `bytes`.
+    #[must_use]
+    fn digest(bytes: &[u8]) -> Self::Output {
+        let mut digest = Self::new();
+        digest.update(bytes);
+        digest.finish()
+    }
+}
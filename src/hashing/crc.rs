@@ -0,0 +1,186 @@
+//! Cyclic redundancy checks of 16 and 32 bits.
+//!
+//! An [`Algorithm`] describes a CRC by its polynomial, initial value, bit order and final
+//! XOR, following the catalogue of parametrised CRC algorithms. The common variants are
+//! provided as constants, and [`Crc`] computes any of them bit by bit, without lookup
+//! tables.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::crc::{CRC_32_ISO_HDLC, Crc};
+//!
+//! assert_eq!(CRC_32_ISO_HDLC.checksum(b"123456789"), 0xcbf4_3926);
+//!
+//! let mut crc = Crc::<u32>::new(CRC_32_ISO_HDLC);
+//! crc.update(b"1234");
+//! crc.update(b"56789");
+//! assert_eq!(crc.finish(), 0xcbf4_3926);
+//! ```
+
+/// The parameters of a CRC algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Algorithm<T> {
+    /// The generator polynomial without its leading term, most significant bit first.
+    pub polynomial: T,
+
+    /// The register value before any input, most significant bit first.
+    pub initial: T,
+
+    /// Whether input bytes and the result are processed least significant bit first.
+    pub reflected: bool,
+
+    /// The value combined with the register by exclusive or to produce the result.
+    pub final_xor: T,
+}
+
+/// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE.
+pub const CRC_16_IBM_3740: Algorithm<u16> = Algorithm {
+    polynomial: 0x1021,
+    initial: 0xffff,
+    reflected: false,
+    final_xor: 0,
+};
+
+/// CRC-16/KERMIT, also known as CRC-16/CCITT.
+pub const CRC_16_KERMIT: Algorithm<u16> = Algorithm {
+    polynomial: 0x1021,
+    initial: 0,
+    reflected: true,
+    final_xor: 0,
+};
+
+/// CRC-16/MODBUS.
+pub const CRC_16_MODBUS: Algorithm<u16> = Algorithm {
+    polynomial: 0x8005,
+    initial: 0xffff,
+    reflected: true,
+    final_xor: 0,
+};
+
+/// CRC-32/ISO-HDLC, the CRC of Ethernet, gzip and PNG.
+pub const CRC_32_ISO_HDLC: Algorithm<u32> = Algorithm {
+    polynomial: 0x04c1_1db7,
+    initial: 0xffff_ffff,
+    reflected: true,
+    final_xor: 0xffff_ffff,
+};
+
+/// CRC-32/ISCSI, also known as CRC-32C (Castagnoli).
+pub const CRC_32_ISCSI: Algorithm<u32> = Algorithm {
+    polynomial: 0x1edc_6f41,
+    initial: 0xffff_ffff,
+    reflected: true,
+    final_xor: 0xffff_ffff,
+};
+
+/// A running CRC computation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc<T> {
+    algorithm: Algorithm<T>,
+    register: T,
+}
+
+macro_rules! impl_crc {
+    ($($ty:ty),*) => {
+        $(
+            impl Algorithm<$ty> {
+                /// Returns the CRC of `bytes`.
+                #[must_use]
+                pub fn checksum(&self, bytes: &[u8]) -> $ty {
+                    let mut crc = Crc::<$ty>::new(*self);
+                    crc.update(bytes);
+                    crc.finish()
+                }
+            }
+
+            impl Crc<$ty> {
+                /// Starts a computation of `algorithm` over no input.
+                #[must_use]
+                pub const fn new(algorithm: Algorithm<$ty>) -> Self {
+                    let register = if algorithm.reflected {
+                        algorithm.initial.reverse_bits()
+                    } else {
+                        algorithm.initial
+                    };
+
+                    Self { algorithm, register }
+                }
+
+                /// Returns the algorithm being computed.
+                #[must_use]
+                pub const fn algorithm(&self) -> Algorithm<$ty> {
+                    self.algorithm
+                }
+
+                /// Adds `bytes` to the input.
+                pub fn update(&mut self, bytes: &[u8]) {
+                    if self.algorithm.reflected {
+                        let polynomial = self.algorithm.polynomial.reverse_bits();
+
+                        for &byte in bytes {
+                            self.register ^= <$ty>::from(byte);
+
+                            for _ in 0..8 {
+                                let carry = self.register & 1 != 0;
+                                self.register >>= 1;
+
+                                if carry {
+                                    self.register ^= polynomial;
+                                }
+                            }
+                        }
+                    } else {
+                        for &byte in bytes {
+                            self.register ^= <$ty>::from(byte) << (<$ty>::BITS - 8);
+
+                            for _ in 0..8 {
+                                let carry = self.register >> (<$ty>::BITS - 1) != 0;
+                                self.register <<= 1;
+
+                                if carry {
+                                    self.register ^= self.algorithm.polynomial;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                /// Returns the CRC of the input so far. More input can still be added.
+                #[must_use]
+                pub const fn finish(&self) -> $ty {
+                    self.register ^ self.algorithm.final_xor
+                }
+            }
+        )*
+    };
+}
+
+impl_crc!(u16, u32);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_values() {
+        assert_eq!(CRC_16_IBM_3740.checksum(b"123456789"), 0x29b1);
+        assert_eq!(CRC_16_KERMIT.checksum(b"123456789"), 0x2189);
+        assert_eq!(CRC_16_MODBUS.checksum(b"123456789"), 0x4b37);
+        assert_eq!(CRC_32_ISO_HDLC.checksum(b"123456789"), 0xcbf4_3926);
+        assert_eq!(CRC_32_ISCSI.checksum(b"123456789"), 0xe306_9283);
+        assert_eq!(CRC_32_ISO_HDLC.checksum(b""), 0);
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let mut crc = Crc::<u16>::new(CRC_16_IBM_3740);
+
+        for byte in b"123456789" {
+            crc.update(&[*byte]);
+        }
+
+        assert_eq!(crc.finish(), 0x29b1);
+        assert_eq!(crc.algorithm(), CRC_16_IBM_3740);
+    }
+}
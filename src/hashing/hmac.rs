@@ -0,0 +1,164 @@
+//! Keyed-hash message authentication codes, as specified in RFC 2104.
+//!
+//! [`Hmac`] turns any [`Digest`] into a message authentication code: only someone who
+//! knows the key can produce the tag of a message, so a matching tag shows that the
+//! message came from a holder of the key and was not changed on the way.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::{hmac::Hmac, sha2::Sha256};
+//!
+//! let tag = Hmac::<Sha256>::mac(b"key", b"The quick brown fox jumps over the lazy dog");
+//! assert_eq!(tag[..4], [0xf7, 0xbc, 0x83, 0xf4]);
+//!
+//! let mut hmac = Hmac::<Sha256>::new(b"key");
+//! hmac.update(b"The quick brown fox ");
+//! hmac.update(b"jumps over the lazy dog");
+//! assert!(hmac.verify(&tag));
+//! ```
+
+use alloc::vec;
+
+use super::Digest;
+use crate::security::{Zeroize, ct_eq};
+
+/// The byte that the key is combined with for the inner hash.
+const INNER_PAD: u8 = 0x36;
+
+/// The byte that the key is combined with for the outer hash.
+const OUTER_PAD: u8 = 0x5c;
+
+/// A running HMAC computation with the digest `D`.
+///
+/// The key is absorbed when the computation is created, so a keyed computation can be
+/// cloned to authenticate many messages without processing the key again.
+#[derive(Debug, Clone)]
+pub struct Hmac<D: Digest> {
+    inner: D,
+    outer: D,
+}
+
+impl<D: Digest> Hmac<D> {
+    /// Starts a computation keyed with `key`. Keys longer than a block of `D` are hashed
+    /// first, as the specification requires.
+    #[must_use]
+    pub fn new(key: &[u8]) -> Self {
+        let mut block = vec![0; D::BLOCK_SIZE];
+
+        if key.len() > D::BLOCK_SIZE {
+            let mut hashed = D::digest(key);
+            block[..D::OUTPUT_SIZE].copy_from_slice(hashed.as_ref());
+            hashed.as_mut().zeroize();
+        } else {
+            block[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = D::new();
+        let mut outer = D::new();
+
+        for byte in &mut block {
+            *byte ^= INNER_PAD;
+        }
+
+        inner.update(&block);
+
+        // Swap the inner padding for the outer one.
+        for byte in &mut block {
+            *byte ^= INNER_PAD ^ OUTER_PAD;
+        }
+
+        outer.update(&block);
+        block.zeroize();
+
+        Self { inner, outer }
+    }
+
+    /// Returns the tag of `message` under `key`.
+    #[must_use]
+    pub fn mac(key: &[u8], message: &[u8]) -> D::Output {
+        let mut hmac = Self::new(key);
+        hmac.update(message);
+        hmac.finish()
+    }
+
+    /// Adds `bytes` to the message.
+    pub fn update(&mut self, bytes: &[u8]) {
+        self.inner.update(bytes);
+    }
+
+    /// Returns the tag of the message so far. More of the message can still be added.
+    #[must_use]
+    pub fn finish(&self) -> D::Output {
+        let mut outer = self.outer.clone();
+        outer.update(self.inner.finish().as_ref());
+        outer.finish()
+    }
+
+    /// Returns whether `tag` is the tag of the message so far, comparing in constant time.
+    ///
+    /// A `tag` shorter than the digest never matches; truncate the result of
+    /// [`finish`](Self::finish) and compare it with [`ct_eq`] to check a truncated tag.
+    #[must_use]
+    pub fn verify(&self, tag: &[u8]) -> bool {
+        ct_eq(self.finish().as_ref(), tag)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::hashing::sha2::{Sha256, Sha384, Sha512};
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&text[index..index + 2], 16).unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn test_rfc_4231_vectors() {
+        let key = [0x0b; 20];
+        assert_eq!(
+            Hmac::<Sha256>::mac(&key, b"Hi There")[..],
+            hex("b0344c61d8db38535ca8afceaf0bf12b881dc200c9833da726e9376c2e32cff7")
+        );
+
+        assert_eq!(
+            Hmac::<Sha256>::mac(b"Jefe", b"what do ya want for nothing?")[..],
+            hex("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        );
+
+        // A key longer than the block is hashed first.
+        let key = [0xaa; 131];
+        let message = b"Test Using Larger Than Block-Size Key - Hash Key First";
+        assert_eq!(
+            Hmac::<Sha256>::mac(&key, message)[..],
+            hex("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+        );
+        assert_eq!(
+            Hmac::<Sha384>::mac(&key, message)[..16],
+            hex("4ece084485813e9088d2c63a041bc5b4")
+        );
+        assert_eq!(
+            Hmac::<Sha512>::mac(&key, message)[..16],
+            hex("80b24263c7c1a3ebb71493c1dd7be8b4")
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let mut hmac = Hmac::<Sha256>::new(b"secret");
+        hmac.update(b"message");
+        let tag = hmac.finish();
+
+        assert!(hmac.verify(&tag));
+        assert!(!hmac.verify(&tag[..16]));
+
+        hmac.update(b"!");
+        assert!(!hmac.verify(&tag));
+    }
+}
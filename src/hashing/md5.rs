@@ -0,0 +1,267 @@
+//! The MD5 message digest, as specified in RFC 1321.
+//!
+//! **MD5 is broken.** Collisions can be produced in seconds on ordinary hardware, so it
+//! must not be used where an attacker may choose the input: not for signatures,
+//! certificates, passwords or integrity checks against tampering. It is here only
+//! because file formats and protocols still carry MD5 checksums that have to be computed
+//! to interoperate with them. Use [`Sha256`](super::sha2::Sha256) for anything new.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::{Digest, md5::Md5};
+//!
+//! assert_eq!(Md5::digest(b"abc")[..4], [0x90, 0x01, 0x50, 0x98]);
+//! ```
+
+use super::Digest;
+
+/// The integer parts of `abs(sin(i + 1)) * 2^32`, one for every step.
+const CONSTANTS: [u32; 64] = [
+    0xd76a_a478,
+    0xe8c7_b756,
+    0x2420_70db,
+    0xc1bd_ceee,
+    0xf57c_0faf,
+    0x4787_c62a,
+    0xa830_4613,
+    0xfd46_9501,
+    0x6980_98d8,
+    0x8b44_f7af,
+    0xffff_5bb1,
+    0x895c_d7be,
+    0x6b90_1122,
+    0xfd98_7193,
+    0xa679_438e,
+    0x49b4_0821,
+    0xf61e_2562,
+    0xc040_b340,
+    0x265e_5a51,
+    0xe9b6_c7aa,
+    0xd62f_105d,
+    0x0244_1453,
+    0xd8a1_e681,
+    0xe7d3_fbc8,
+    0x21e1_cde6,
+    0xc337_07d6,
+    0xf4d5_0d87,
+    0x455a_14ed,
+    0xa9e3_e905,
+    0xfcef_a3f8,
+    0x676f_02d9,
+    0x8d2a_4c8a,
+    0xfffa_3942,
+    0x8771_f681,
+    0x6d9d_6122,
+    0xfde5_380c,
+    0xa4be_ea44,
+    0x4bde_cfa9,
+    0xf6bb_4b60,
+    0xbebf_bc70,
+    0x289b_7ec6,
+    0xeaa1_27fa,
+    0xd4ef_3085,
+    0x0488_1d05,
+    0xd9d4_d039,
+    0xe6db_99e5,
+    0x1fa2_7cf8,
+    0xc4ac_5665,
+    0xf429_2244,
+    0x432a_ff97,
+    0xab94_23a7,
+    0xfc93_a039,
+    0x655b_59c3,
+    0x8f0c_cc92,
+    0xffef_f47d,
+    0x8584_5dd1,
+    0x6fa8_7e4f,
+    0xfe2c_e6e0,
+    0xa301_4314,
+    0x4e08_11a1,
+    0xf753_7e82,
+    0xbd3a_f235,
+    0x2ad7_d2bb,
+    0xeb86_d391,
+];
+
+/// The rotation amounts of the four rounds, each used for four steps in turn.
+const SHIFTS: [[u32; 4]; 4] = [
+    [7, 12, 17, 22],
+    [5, 9, 14, 20],
+    [4, 11, 16, 23],
+    [6, 10, 15, 21],
+];
+
+/// The state before any input.
+const INITIAL: [u32; 4] = [0x6745_2301, 0xefcd_ab89, 0x98ba_dcfe, 0x1032_5476];
+
+/// Mixes one block into `state`.
+#[allow(clippy::many_single_char_names)] // The names of RFC 1321.
+fn compress(state: &mut [u32; 4], block: &[u8]) {
+    let mut words = [0; 16];
+
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = bytes
+            .iter()
+            .rev()
+            .fold(0, |word, &byte| word << 8 | u32::from(byte));
+    }
+
+    let [mut a, mut b, mut c, mut d] = *state;
+
+    for (step, &constant) in CONSTANTS.iter().enumerate() {
+        let (mix, index) = match step / 16 {
+            0 => ((b & c) | (!b & d), step),
+            1 => ((d & b) | (!d & c), (5 * step + 1) % 16),
+            2 => (b ^ c ^ d, (3 * step + 5) % 16),
+            _ => (c ^ (b | !d), 7 * step % 16),
+        };
+
+        let rotated = a
+            .wrapping_add(mix)
+            .wrapping_add(constant)
+            .wrapping_add(words[index])
+            .rotate_left(SHIFTS[step / 16][step % 4]);
+
+        (a, b, c, d) = (d, b.wrapping_add(rotated), b, c);
+    }
+
+    for (word, value) in state.iter_mut().zip([a, b, c, d]) {
+        *word = word.wrapping_add(value);
+    }
+}
+
+/// A running MD5 computation.
+#[derive(Debug, Clone, Copy)]
+pub struct Md5 {
+    state: [u32; 4],
+    buffer: [u8; 64],
+    buffered: usize,
+    length: u64,
+}
+
+impl Digest for Md5 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 16;
+
+    type Output = [u8; 16];
+
+    fn new() -> Self {
+        Self {
+            state: INITIAL,
+            buffer: [0; 64],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    fn update(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        if self.buffered > 0 {
+            let taken = bytes.len().min(64 - self.buffered);
+            self.buffer[self.buffered..self.buffered + taken].copy_from_slice(&bytes[..taken]);
+            self.buffered += taken;
+            bytes = &bytes[taken..];
+
+            if self.buffered < 64 {
+                return;
+            }
+
+            compress(&mut self.state, &self.buffer);
+            self.buffered = 0;
+        }
+
+        let mut blocks = bytes.chunks_exact(64);
+
+        for block in &mut blocks {
+            compress(&mut self.state, block);
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    fn finish(&self) -> [u8; 16] {
+        let mut state = self.state;
+        let mut buffer = self.buffer;
+
+        // The message is followed by a one bit, zeros, and its length in bits.
+        buffer[self.buffered] = 0x80;
+        buffer[self.buffered + 1..].fill(0);
+
+        if self.buffered >= 56 {
+            compress(&mut state, &buffer);
+            buffer.fill(0);
+        }
+
+        buffer[56..].copy_from_slice(&self.length.wrapping_mul(8).to_le_bytes());
+        compress(&mut state, &buffer);
+
+        let mut output = [0; 16];
+
+        for (bytes, word) in output.chunks_exact_mut(4).zip(state) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+
+        output
+    }
+}
+
+impl Default for Md5 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec};
+    use core::fmt::Write;
+
+    use super::*;
+    use crate::hashing::hmac::Hmac;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut text, byte| {
+            let _ = write!(text, "{byte:02x}");
+            text
+        })
+    }
+
+    #[test]
+    fn test_rfc_1321_vectors() {
+        assert_eq!(hex(&Md5::digest(b"")), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            hex(&Md5::digest(b"abc")),
+            "900150983cd24fb0d6963f7d28e17f72"
+        );
+        assert_eq!(
+            hex(&Md5::digest(b"message digest")),
+            "f96b697d7cb7938d525a2f31aaf161d0"
+        );
+        assert_eq!(
+            hex(&Md5::digest(
+                b"12345678901234567890123456789012345678901234567890123456789012345678901234567890"
+            )),
+            "57edf4a22be3c955ac49da2e2107b67a"
+        );
+    }
+
+    #[test]
+    fn test_incremental_update_and_hmac() {
+        let message = vec![b'a'; 1000];
+        let mut md5 = Md5::new();
+
+        for chunk in message.chunks(37) {
+            md5.update(chunk);
+        }
+
+        assert_eq!(hex(&md5.finish()), "cabe45dcc9ae5b66ba86600cca6b8ba8");
+        assert_eq!(
+            hex(&Hmac::<Md5>::mac(&[0x0b; 16], b"Hi There")),
+            "9294727a3638bb1c13f48ef8158bfc9d"
+        );
+    }
+}
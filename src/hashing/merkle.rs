@@ -0,0 +1,275 @@
+//! Merkle trees and inclusion proofs.
+//!
+//! A [`MerkleTree`] hashes a sequence of leaves pairwise, level by level, up to a single
+//! root. Whoever knows the root can check that a leaf belongs to the tree with a
+//! [`MerkleProof`] of only one hash per level, without seeing the other leaves. This is
+//! how chunked downloads are verified piece by piece against one trusted hash.
+//!
+//! The tree follows RFC 6962: leaves are hashed as `H(0x00 || leaf)` and interior nodes
+//! as `H(0x01 || left || right)`, so that a leaf can never pass for a node. A level with
+//! an odd number of nodes promotes the last one unchanged, and the root of an empty tree
+//! is the hash of no input.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::{merkle::MerkleTree, sha2::Sha256};
+//!
+//! let chunks = [&b"first chunk"[..], b"second chunk", b"third chunk"];
+//! let tree = MerkleTree::<Sha256>::new(chunks);
+//! let root = tree.root();
+//!
+//! let proof = tree.proof(1).unwrap();
+//! assert!(proof.verify(&root, b"second chunk"));
+//! assert!(!proof.verify(&root, b"forged chunk"));
+//! ```
+
+use alloc::vec::Vec;
+
+use super::Digest;
+
+/// The prefix of a hashed leaf.
+const LEAF_PREFIX: u8 = 0x00;
+
+/// The prefix of a hashed pair of nodes.
+const NODE_PREFIX: u8 = 0x01;
+
+/// Returns the hash of the leaf `data`.
+fn leaf_hash<D: Digest>(data: &[u8]) -> D::Output {
+    let mut digest = D::new();
+    digest.update(&[LEAF_PREFIX]);
+    digest.update(data);
+    digest.finish()
+}
+
+/// Returns the hash of the nodes `left` and `right`.
+fn node_hash<D: Digest>(left: &D::Output, right: &D::Output) -> D::Output {
+    let mut digest = D::new();
+    digest.update(&[NODE_PREFIX]);
+    digest.update(left.as_ref());
+    digest.update(right.as_ref());
+    digest.finish()
+}
+
+/// A Merkle tree over the digest `D`, keeping every level so that proofs are cheap.
+#[derive(Debug, Clone)]
+pub struct MerkleTree<D: Digest> {
+    levels: Vec<Vec<D::Output>>,
+}
+
+impl<D: Digest> MerkleTree<D> {
+    /// Builds the tree over `leaves`, in order.
+    pub fn new<I>(leaves: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let leaves: Vec<D::Output> = leaves
+            .into_iter()
+            .map(|leaf| leaf_hash::<D>(leaf.as_ref()))
+            .collect();
+
+        let mut levels = Vec::new();
+        let mut level = leaves;
+
+        while level.len() > 1 {
+            let next = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash::<D>(left, right),
+                    [single] => *single,
+                    _ => unreachable!("chunks of two hold one or two nodes"),
+                })
+                .collect();
+
+            levels.push(level);
+            level = next;
+        }
+
+        levels.push(level);
+        Self { levels }
+    }
+
+    /// Returns the number of leaves.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.levels[0].len()
+    }
+
+    /// Returns whether the tree has no leaves.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.levels[0].is_empty()
+    }
+
+    /// Returns the hashes of the leaves, in order.
+    #[must_use]
+    pub fn leaf_hashes(&self) -> &[D::Output] {
+        &self.levels[0]
+    }
+
+    /// Returns the root hash, which is the hash of no input for an empty tree.
+    #[must_use]
+    pub fn root(&self) -> D::Output {
+        self.levels
+            .last()
+            .and_then(|level| level.first())
+            .copied()
+            .unwrap_or_else(|| D::digest(&[]))
+    }
+
+    /// Returns the proof that the leaf at `index` is in the tree, or `None` if there is
+    /// no such leaf.
+    #[must_use]
+    pub fn proof(&self, index: usize) -> Option<MerkleProof<D>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let mut siblings = Vec::new();
+        let mut position = index;
+
+        for level in &self.levels[..self.levels.len() - 1] {
+            if let Some(sibling) = level.get(position ^ 1) {
+                siblings.push(*sibling);
+            }
+
+            position /= 2;
+        }
+
+        Some(MerkleProof {
+            index,
+            leaf_count: self.len(),
+            siblings,
+        })
+    }
+}
+
+/// The hashes needed to recompute the root of a [`MerkleTree`] from one of its leaves.
+#[derive(Debug, Clone)]
+pub struct MerkleProof<D: Digest> {
+    index: usize,
+    leaf_count: usize,
+    siblings: Vec<D::Output>,
+}
+
+impl<D: Digest> MerkleProof<D> {
+    /// Returns the index of the leaf this proof is for.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the number of leaves in the tree the proof came from.
+    #[must_use]
+    pub const fn leaf_count(&self) -> usize {
+        self.leaf_count
+    }
+
+    /// Returns the sibling hashes from the leaf level up.
+    #[must_use]
+    pub fn siblings(&self) -> &[D::Output] {
+        &self.siblings
+    }
+
+    /// Returns whether `leaf` is the leaf at [`index`](Self::index) of the tree with
+    /// `root`.
+    #[must_use]
+    pub fn verify(&self, root: &D::Output, leaf: &[u8]) -> bool {
+        if self.index >= self.leaf_count {
+            return false;
+        }
+
+        let mut hash = leaf_hash::<D>(leaf);
+        let mut siblings = self.siblings.iter();
+        let (mut position, mut count) = (self.index, self.leaf_count);
+
+        while count > 1 {
+            if position % 2 == 1 {
+                let Some(left) = siblings.next() else {
+                    return false;
+                };
+
+                hash = node_hash::<D>(left, &hash);
+            } else if position + 1 < count {
+                let Some(right) = siblings.next() else {
+                    return false;
+                };
+
+                hash = node_hash::<D>(&hash, right);
+            }
+
+            position /= 2;
+            count = count.div_ceil(2);
+        }
+
+        siblings.next().is_none() && hash == *root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::*;
+    use crate::hashing::sha2::Sha256;
+
+    #[test]
+    fn test_every_proof_verifies() {
+        for leaf_count in 1..=17 {
+            let leaves: Vec<String> = (0..leaf_count).map(|i| format!("leaf {i}")).collect();
+            let tree = MerkleTree::<Sha256>::new(&leaves);
+            let root = tree.root();
+
+            assert_eq!(tree.len(), leaf_count);
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = tree.proof(index).expect("the leaf exists");
+
+                assert!(proof.verify(&root, leaf.as_bytes()));
+                assert!(!proof.verify(&root, b"another leaf"));
+            }
+
+            assert!(tree.proof(leaf_count).is_none());
+        }
+    }
+
+    #[test]
+    fn test_structure() {
+        let empty = MerkleTree::<Sha256>::new::<[&[u8]; 0]>([]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.root(), Sha256::digest(&[]));
+
+        let tree = MerkleTree::<Sha256>::new([b"a", b"b", b"c"]);
+        let [a, b, c] = [b"a", b"b", b"c"].map(|leaf| leaf_hash::<Sha256>(leaf));
+        assert_eq!(tree.leaf_hashes(), [a, b, c]);
+        assert_eq!(
+            tree.root(),
+            node_hash::<Sha256>(&node_hash::<Sha256>(&a, &b), &c)
+        );
+
+        // A leaf must not be accepted in place of an interior node.
+        let proof = tree.proof(2).expect("the leaf exists");
+        assert_eq!(proof.siblings(), [node_hash::<Sha256>(&a, &b)]);
+        assert!(!proof.verify(&tree.root(), &[a, b].concat()));
+    }
+
+    #[test]
+    fn test_tampered_proofs_fail() {
+        let tree = MerkleTree::<Sha256>::new([b"a", b"b", b"c", b"d", b"e"]);
+        let root = tree.root();
+        let proof = tree.proof(3).expect("the leaf exists");
+
+        let mut moved = proof.clone();
+        moved.index = 2;
+        assert!(!moved.verify(&root, b"d"));
+
+        let mut shortened = proof.clone();
+        shortened.siblings.pop();
+        assert!(!shortened.verify(&root, b"d"));
+
+        let mut lengthened = proof;
+        lengthened.siblings.push(root);
+        assert!(!lengthened.verify(&root, b"d"));
+    }
+}
@@ -0,0 +1,302 @@
+//! Consistent hashing.
+//!
+//! A [`ConsistentHashRing`] assigns keys to nodes, such as servers or shards, so that
+//! adding or removing a node moves only the keys that have to move: those the new node
+//! takes over, or those the removed node owned. Hashing keys modulo the number of nodes
+//! would instead move almost every key.
+//!
+//! Each node is placed on a ring of 64-bit hashes at several points, its virtual nodes,
+//! and a key belongs to the first point at or after its own hash. More virtual nodes
+//! spread the key space more evenly, which [`ConsistentHashRing::statistics`] measures.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::ConsistentHashRing;
+//!
+//! let mut ring = ConsistentHashRing::new(64);
+//! ring.add_node("cache-a");
+//! ring.add_node("cache-b");
+//!
+//! let owner = *ring.node_for("user:42").unwrap();
+//!
+//! // Adding a node only moves keys to that node.
+//! ring.add_node("cache-c");
+//! let new_owner = *ring.node_for("user:42").unwrap();
+//! assert!(new_owner == owner || new_owner == "cache-c");
+//! ```
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::hash::{BuildHasher, Hash};
+
+use super::SipHashState;
+
+/// The number of points on the ring, as a float.
+const RING_SIZE: f64 = 18_446_744_073_709_551_616.0;
+
+/// A ring that assigns keys to nodes of type `N` by consistent hashing with the hasher
+/// builder `S`.
+#[derive(Debug, Clone)]
+pub struct ConsistentHashRing<N, S = SipHashState> {
+    nodes: Vec<N>,
+    points: BTreeMap<u64, usize>,
+    virtual_nodes: usize,
+    hasher: S,
+}
+
+/// How evenly a [`ConsistentHashRing`] divides the key space between its nodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RingStatistics {
+    /// The number of nodes.
+    pub nodes: usize,
+
+    /// The number of points on the ring, which is at most the number of nodes times the
+    /// virtual nodes of each.
+    pub points: usize,
+
+    /// The smallest fraction of the key space that a node owns.
+    pub min_share: f64,
+
+    /// The largest fraction of the key space that a node owns.
+    pub max_share: f64,
+
+    /// The largest share divided by the mean share, which is 1 for a perfect balance.
+    pub imbalance: f64,
+}
+
+impl<N: Hash + Eq> ConsistentHashRing<N> {
+    /// Creates an empty ring that places every node at `virtual_nodes` points.
+    ///
+    /// The points are hashed with SipHash-1-3 under a fixed key, so every ring built with
+    /// the same nodes assigns keys the same way, across runs and processes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `virtual_nodes` is zero.
+    #[must_use]
+    pub fn new(virtual_nodes: usize) -> Self {
+        Self::with_hasher(virtual_nodes, SipHashState::with_keys(0, 0))
+    }
+}
+
+impl<N: Hash + Eq, S: BuildHasher> ConsistentHashRing<N, S> {
+    /// Creates an empty ring that places every node at `virtual_nodes` points hashed with
+    /// `hasher`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `virtual_nodes` is zero.
+    pub fn with_hasher(virtual_nodes: usize, hasher: S) -> Self {
+        assert!(virtual_nodes > 0, "every node needs at least one point");
+
+        Self {
+            nodes: Vec::new(),
+            points: BTreeMap::new(),
+            virtual_nodes,
+            hasher,
+        }
+    }
+
+    /// Returns the number of points at which every node is placed.
+    #[must_use]
+    pub const fn virtual_nodes(&self) -> usize {
+        self.virtual_nodes
+    }
+
+    /// Returns the nodes, in the order they were added.
+    #[must_use]
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Returns the number of nodes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns whether the ring has no nodes.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Adds `node` to the ring, returning `false` if it was already there.
+    ///
+    /// The keys that move are exactly those the node now owns, a fraction of the key
+    /// space that [`share`](Self::share) reports.
+    pub fn add_node(&mut self, node: N) -> bool {
+        if self.nodes.contains(&node) {
+            return false;
+        }
+
+        let index = self.nodes.len();
+
+        for replica in 0..self.virtual_nodes {
+            // On the rare collision the point stays with the node that had it first.
+            self.points
+                .entry(self.hasher.hash_one((&node, replica)))
+                .or_insert(index);
+        }
+
+        self.nodes.push(node);
+        true
+    }
+
+    /// Removes `node` from the ring, returning `false` if it was not there.
+    ///
+    /// The keys that move are exactly those the node owned; measure them with
+    /// [`share`](Self::share) before removing it.
+    pub fn remove_node(&mut self, node: &N) -> bool {
+        let Some(index) = self.nodes.iter().position(|other| other == node) else {
+            return false;
+        };
+
+        self.nodes.remove(index);
+        self.points.retain(|_, owner| *owner != index);
+
+        for owner in self.points.values_mut() {
+            if *owner > index {
+                *owner -= 1;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the node that owns `key`, or `None` if the ring is empty.
+    pub fn node_for<K: Hash + ?Sized>(&self, key: &K) -> Option<&N> {
+        let hash = self.hasher.hash_one(key);
+
+        self.points
+            .range(hash..)
+            .next()
+            .or_else(|| self.points.iter().next())
+            .map(|(_, &owner)| &self.nodes[owner])
+    }
+
+    /// Returns the fraction of the key space owned by each node, in the order of
+    /// [`nodes`](Self::nodes).
+    #[must_use]
+    pub fn shares(&self) -> Vec<f64> {
+        if self.points.len() == 1 {
+            return vec![1.0];
+        }
+
+        let mut owned = vec![0_u128; self.nodes.len()];
+        let mut previous = self.points.last_key_value().map_or(0, |(&point, _)| point);
+
+        for (&point, &owner) in &self.points {
+            owned[owner] += u128::from(point.wrapping_sub(previous));
+            previous = point;
+        }
+
+        #[allow(clippy::cast_precision_loss)] // A share only needs a few digits.
+        owned
+            .into_iter()
+            .map(|arc| arc as f64 / RING_SIZE)
+            .collect()
+    }
+
+    /// Returns the fraction of the key space owned by `node`, which is zero if it is not
+    /// in the ring.
+    #[must_use]
+    pub fn share(&self, node: &N) -> f64 {
+        self.nodes
+            .iter()
+            .position(|other| other == node)
+            .map_or(0.0, |index| self.shares()[index])
+    }
+
+    /// Returns how evenly the key space is divided, or `None` if the ring is empty.
+    #[must_use]
+    pub fn statistics(&self) -> Option<RingStatistics> {
+        let shares = self.shares();
+        let min_share = shares.iter().copied().reduce(f64::min)?;
+        let max_share = shares.iter().copied().reduce(f64::max)?;
+
+        #[allow(clippy::cast_precision_loss)] // The number of nodes is small.
+        let imbalance = max_share * shares.len() as f64;
+
+        Some(RingStatistics {
+            nodes: self.nodes.len(),
+            points: self.points.len(),
+            min_share,
+            max_share,
+            imbalance,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::String};
+
+    use super::*;
+
+    fn keys() -> Vec<String> {
+        (0..2000).map(|i| format!("key {i}")).collect()
+    }
+
+    #[test]
+    fn test_adding_a_node_only_moves_keys_to_it() {
+        let mut ring = ConsistentHashRing::new(100);
+
+        for node in 0..8_u32 {
+            assert!(ring.add_node(node));
+        }
+
+        assert!(!ring.add_node(3));
+
+        let before: Vec<u32> = keys()
+            .iter()
+            .map(|key| *ring.node_for(key).unwrap_or(&u32::MAX))
+            .collect();
+
+        ring.add_node(8);
+        let mut moved = 0;
+
+        for (key, &owner) in keys().iter().zip(&before) {
+            let new_owner = *ring.node_for(key).unwrap_or(&u32::MAX);
+
+            if new_owner != owner {
+                assert_eq!(new_owner, 8);
+                moved += 1;
+            }
+        }
+
+        let share = ring.share(&8);
+        assert!((0.05..0.2).contains(&share), "{share}");
+        assert!((0.05..0.2).contains(&(f64::from(moved) / 2000.0)));
+
+        assert!(ring.remove_node(&8));
+        assert!(!ring.remove_node(&8));
+
+        for (key, &owner) in keys().iter().zip(&before) {
+            assert_eq!(ring.node_for(key), Some(&owner));
+        }
+    }
+
+    #[test]
+    fn test_statistics() {
+        let mut ring: ConsistentHashRing<&str> = ConsistentHashRing::new(200);
+        assert_eq!(ring.statistics(), None);
+        assert_eq!(ring.node_for("key"), None);
+
+        ring.add_node("only");
+        assert_eq!(ring.shares(), [1.0]);
+        assert_eq!(ring.node_for("key"), Some(&"only"));
+
+        for node in ["a", "b", "c"] {
+            ring.add_node(node);
+        }
+
+        let statistics = ring.statistics().expect("the ring has nodes");
+
+        assert_eq!(statistics.nodes, 4);
+        assert_eq!(statistics.points, 800);
+        assert!((ring.shares().iter().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(statistics.min_share > 0.15 && statistics.max_share < 0.35);
+        assert!(statistics.imbalance < 1.4);
+    }
+}
@@ -0,0 +1,218 @@
+//! The SHA-1 hash function, as specified in FIPS 180-4.
+//!
+//! **SHA-1 is broken.** Chosen-prefix collisions have been demonstrated at a cost within
+//! reach of a well-funded attacker, so it must not be used for signatures, certificates
+//! or any check against deliberate tampering. It is here because Git object names,
+//! WebSocket handshakes, older TLS and many file formats still require it. HMAC-SHA-1 and
+//! PBKDF2 with SHA-1 are not affected by collisions and remain acceptable where a
+//! protocol demands them. Use [`Sha256`](super::sha2::Sha256) for anything new.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::{Digest, sha1::Sha1};
+//!
+//! assert_eq!(Sha1::digest(b"abc")[..4], [0xa9, 0x99, 0x3e, 0x36]);
+//! ```
+
+use super::Digest;
+
+/// The round constants, each used for twenty steps.
+const CONSTANTS: [u32; 4] = [0x5a82_7999, 0x6ed9_eba1, 0x8f1b_bcdc, 0xca62_c1d6];
+
+/// The state before any input.
+const INITIAL: [u32; 5] = [
+    0x6745_2301,
+    0xefcd_ab89,
+    0x98ba_dcfe,
+    0x1032_5476,
+    0xc3d2_e1f0,
+];
+
+/// Mixes one block into `state`.
+#[allow(clippy::many_single_char_names)] // The names of FIPS 180-4.
+fn compress(state: &mut [u32; 5], block: &[u8]) {
+    let mut schedule = [0; 80];
+
+    for (word, bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+        *word = bytes
+            .iter()
+            .fold(0, |word, &byte| word << 8 | u32::from(byte));
+    }
+
+    for index in 16..schedule.len() {
+        schedule[index] = (schedule[index - 3]
+            ^ schedule[index - 8]
+            ^ schedule[index - 14]
+            ^ schedule[index - 16])
+            .rotate_left(1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e] = *state;
+
+    for (step, word) in schedule.into_iter().enumerate() {
+        let mix = match step / 20 {
+            0 => (b & c) | (!b & d),
+            2 => (b & c) | (b & d) | (c & d),
+            _ => b ^ c ^ d,
+        };
+
+        let temporary = a
+            .rotate_left(5)
+            .wrapping_add(mix)
+            .wrapping_add(e)
+            .wrapping_add(CONSTANTS[step / 20])
+            .wrapping_add(word);
+
+        (e, d, c, b, a) = (d, c, b.rotate_left(30), a, temporary);
+    }
+
+    for (word, value) in state.iter_mut().zip([a, b, c, d, e]) {
+        *word = word.wrapping_add(value);
+    }
+}
+
+/// A running SHA-1 computation.
+#[derive(Debug, Clone, Copy)]
+pub struct Sha1 {
+    state: [u32; 5],
+    buffer: [u8; 64],
+    buffered: usize,
+    length: u64,
+}
+
+impl Digest for Sha1 {
+    const BLOCK_SIZE: usize = 64;
+    const OUTPUT_SIZE: usize = 20;
+
+    type Output = [u8; 20];
+
+    fn new() -> Self {
+        Self {
+            state: INITIAL,
+            buffer: [0; 64],
+            buffered: 0,
+            length: 0,
+        }
+    }
+
+    fn update(&mut self, mut bytes: &[u8]) {
+        self.length = self.length.wrapping_add(bytes.len() as u64);
+
+        if self.buffered > 0 {
+            let taken = bytes.len().min(64 - self.buffered);
+            self.buffer[self.buffered..self.buffered + taken].copy_from_slice(&bytes[..taken]);
+            self.buffered += taken;
+            bytes = &bytes[taken..];
+
+            if self.buffered < 64 {
+                return;
+            }
+
+            compress(&mut self.state, &self.buffer);
+            self.buffered = 0;
+        }
+
+        let mut blocks = bytes.chunks_exact(64);
+
+        for block in &mut blocks {
+            compress(&mut self.state, block);
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffered = rest.len();
+    }
+
+    fn finish(&self) -> [u8; 20] {
+        let mut state = self.state;
+        let mut buffer = self.buffer;
+
+        // The message is followed by a one bit, zeros, and its length in bits.
+        buffer[self.buffered] = 0x80;
+        buffer[self.buffered + 1..].fill(0);
+
+        if self.buffered >= 56 {
+            compress(&mut state, &buffer);
+            buffer.fill(0);
+        }
+
+        buffer[56..].copy_from_slice(&self.length.wrapping_mul(8).to_be_bytes());
+        compress(&mut state, &buffer);
+
+        let mut output = [0; 20];
+
+        for (bytes, word) in output.chunks_exact_mut(4).zip(state) {
+            bytes.copy_from_slice(&word.to_be_bytes());
+        }
+
+        output
+    }
+}
+
+impl Default for Sha1 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec};
+    use core::fmt::Write;
+
+    use super::*;
+    use crate::{hashing::hmac::Hmac, security::pbkdf2};
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut text, byte| {
+            let _ = write!(text, "{byte:02x}");
+            text
+        })
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        assert_eq!(
+            hex(&Sha1::digest(b"")),
+            "da39a3ee5e6b4b0d3255bfef95601890afd80709"
+        );
+        assert_eq!(
+            hex(&Sha1::digest(b"abc")),
+            "a9993e364706816aba3e25717850c26c9cd0d89d"
+        );
+        assert_eq!(
+            hex(&Sha1::digest(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "84983e441c3bd26ebaae4aa1f95129e5e54670f1"
+        );
+
+        let message = vec![b'a'; 1000];
+        let mut sha1 = Sha1::new();
+
+        for chunk in message.chunks(37) {
+            sha1.update(chunk);
+        }
+
+        assert_eq!(
+            hex(&sha1.finish()),
+            "291e9a6c66994949b57ba5e650361e98fc36b1ba"
+        );
+    }
+
+    #[test]
+    fn test_hmac_and_pbkdf2_vectors() {
+        assert_eq!(
+            hex(&Hmac::<Sha1>::mac(&[0x0b; 20], b"Hi There")),
+            "b617318655057264e28bc0b6fb378c8ef146be00"
+        );
+
+        // RFC 6070.
+        let mut key = [0; 20];
+        pbkdf2::<Sha1>(b"password", b"salt", 1, &mut key);
+        assert_eq!(hex(&key), "0c60c80f961f0e71f3a9b524af6012062fe037a6");
+        pbkdf2::<Sha1>(b"password", b"salt", 4096, &mut key);
+        assert_eq!(hex(&key), "4b007901b765489abead49d926f721d065a429c1");
+    }
+}
@@ -0,0 +1,531 @@
+//! The SHA-2 family of hash functions, as specified in FIPS 180-4.
+//!
+//! [`Sha256`] and [`Sha512`] work on 32-bit and 64-bit words respectively. [`Sha224`] and
+//! [`Sha384`] are the same functions started from a different state and cut short, and
+//! are used where a shorter digest is wanted. All of them implement [`Digest`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::hashing::{Digest, sha2::Sha256};
+//!
+//! let mut sha = Sha256::new();
+//! sha.update(b"a");
+//! sha.update(b"bc");
+//!
+//! assert_eq!(sha.finish(), Sha256::digest(b"abc"));
+//! assert_eq!(sha.finish()[..4], [0xba, 0x78, 0x16, 0xbf]);
+//! ```
+
+use super::Digest;
+
+/// The first 32 bits of the fractional parts of the cube roots of the first 64 primes.
+const ROUND_CONSTANTS_256: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+/// The first 64 bits of the fractional parts of the cube roots of the first 80 primes.
+const ROUND_CONSTANTS_512: [u64; 80] = [
+    0x428a_2f98_d728_ae22,
+    0x7137_4491_23ef_65cd,
+    0xb5c0_fbcf_ec4d_3b2f,
+    0xe9b5_dba5_8189_dbbc,
+    0x3956_c25b_f348_b538,
+    0x59f1_11f1_b605_d019,
+    0x923f_82a4_af19_4f9b,
+    0xab1c_5ed5_da6d_8118,
+    0xd807_aa98_a303_0242,
+    0x1283_5b01_4570_6fbe,
+    0x2431_85be_4ee4_b28c,
+    0x550c_7dc3_d5ff_b4e2,
+    0x72be_5d74_f27b_896f,
+    0x80de_b1fe_3b16_96b1,
+    0x9bdc_06a7_25c7_1235,
+    0xc19b_f174_cf69_2694,
+    0xe49b_69c1_9ef1_4ad2,
+    0xefbe_4786_384f_25e3,
+    0x0fc1_9dc6_8b8c_d5b5,
+    0x240c_a1cc_77ac_9c65,
+    0x2de9_2c6f_592b_0275,
+    0x4a74_84aa_6ea6_e483,
+    0x5cb0_a9dc_bd41_fbd4,
+    0x76f9_88da_8311_53b5,
+    0x983e_5152_ee66_dfab,
+    0xa831_c66d_2db4_3210,
+    0xb003_27c8_98fb_213f,
+    0xbf59_7fc7_beef_0ee4,
+    0xc6e0_0bf3_3da8_8fc2,
+    0xd5a7_9147_930a_a725,
+    0x06ca_6351_e003_826f,
+    0x1429_2967_0a0e_6e70,
+    0x27b7_0a85_46d2_2ffc,
+    0x2e1b_2138_5c26_c926,
+    0x4d2c_6dfc_5ac4_2aed,
+    0x5338_0d13_9d95_b3df,
+    0x650a_7354_8baf_63de,
+    0x766a_0abb_3c77_b2a8,
+    0x81c2_c92e_47ed_aee6,
+    0x9272_2c85_1482_353b,
+    0xa2bf_e8a1_4cf1_0364,
+    0xa81a_664b_bc42_3001,
+    0xc24b_8b70_d0f8_9791,
+    0xc76c_51a3_0654_be30,
+    0xd192_e819_d6ef_5218,
+    0xd699_0624_5565_a910,
+    0xf40e_3585_5771_202a,
+    0x106a_a070_32bb_d1b8,
+    0x19a4_c116_b8d2_d0c8,
+    0x1e37_6c08_5141_ab53,
+    0x2748_774c_df8e_eb99,
+    0x34b0_bcb5_e19b_48a8,
+    0x391c_0cb3_c5c9_5a63,
+    0x4ed8_aa4a_e341_8acb,
+    0x5b9c_ca4f_7763_e373,
+    0x682e_6ff3_d6b2_b8a3,
+    0x748f_82ee_5def_b2fc,
+    0x78a5_636f_4317_2f60,
+    0x84c8_7814_a1f0_ab72,
+    0x8cc7_0208_1a64_39ec,
+    0x90be_fffa_2363_1e28,
+    0xa450_6ceb_de82_bde9,
+    0xbef9_a3f7_b2c6_7915,
+    0xc671_78f2_e372_532b,
+    0xca27_3ece_ea26_619c,
+    0xd186_b8c7_21c0_c207,
+    0xeada_7dd6_cde0_eb1e,
+    0xf57d_4f7f_ee6e_d178,
+    0x06f0_67aa_7217_6fba,
+    0x0a63_7dc5_a2c8_98a6,
+    0x113f_9804_bef9_0dae,
+    0x1b71_0b35_131c_471b,
+    0x28db_77f5_2304_7d84,
+    0x32ca_ab7b_40c7_2493,
+    0x3c9e_be0a_15c9_bebc,
+    0x431d_67c4_9c10_0d4c,
+    0x4cc5_d4be_cb3e_42b6,
+    0x597f_299c_fc65_7e2a,
+    0x5fcb_6fab_3ad6_faec,
+    0x6c44_198c_4a47_5817,
+];
+
+/// The initial state of SHA-224, the second 32 bits of the fractional parts of the
+/// square roots of the 9th to 16th primes.
+const INITIAL_224: [u32; 8] = [
+    0xc105_9ed8,
+    0x367c_d507,
+    0x3070_dd17,
+    0xf70e_5939,
+    0xffc0_0b31,
+    0x6858_1511,
+    0x64f9_8fa7,
+    0xbefa_4fa4,
+];
+
+/// The initial state of SHA-256, the first 32 bits of the fractional parts of the
+/// square roots of the first 8 primes.
+const INITIAL_256: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+/// The initial state of SHA-384, the first 64 bits of the fractional parts of the
+/// square roots of the 9th to 16th primes.
+const INITIAL_384: [u64; 8] = [
+    0xcbbb_9d5d_c105_9ed8,
+    0x629a_292a_367c_d507,
+    0x9159_015a_3070_dd17,
+    0x152f_ecd8_f70e_5939,
+    0x6733_2667_ffc0_0b31,
+    0x8eb4_4a87_6858_1511,
+    0xdb0c_2e0d_64f9_8fa7,
+    0x47b5_481d_befa_4fa4,
+];
+
+/// The initial state of SHA-512, the first 64 bits of the fractional parts of the
+/// square roots of the first 8 primes.
+const INITIAL_512: [u64; 8] = [
+    0x6a09_e667_f3bc_c908,
+    0xbb67_ae85_84ca_a73b,
+    0x3c6e_f372_fe94_f82b,
+    0xa54f_f53a_5f1d_36f1,
+    0x510e_527f_ade6_82d1,
+    0x9b05_688c_2b3e_6c1f,
+    0x1f83_d9ab_fb41_bd6b,
+    0x5be0_cd19_137e_2179,
+];
+
+macro_rules! impl_compress {
+    (
+        $name:ident, $word:ty, $constants:ident,
+        sigma: [$s0:literal, $s1:literal, $s2:literal], [$s3:literal, $s4:literal, $s5:literal],
+        schedule: [$m0:literal, $m1:literal, $m2:literal], [$m3:literal, $m4:literal, $m5:literal]
+    ) => {
+        /// Mixes one block into `state`.
+        #[allow(clippy::many_single_char_names)] // The names of FIPS 180-4.
+        fn $name(state: &mut [$word; 8], block: &[u8]) {
+            let mut schedule = [0; $constants.len()];
+
+            for (word, bytes) in schedule
+                .iter_mut()
+                .zip(block.chunks_exact(size_of::<$word>()))
+            {
+                *word = bytes
+                    .iter()
+                    .fold(0, |word, &byte| word << 8 | <$word>::from(byte));
+            }
+
+            for index in 16..schedule.len() {
+                let (early, late) = (schedule[index - 15], schedule[index - 2]);
+                let sigma0 = early.rotate_right($m0) ^ early.rotate_right($m1) ^ early >> $m2;
+                let sigma1 = late.rotate_right($m3) ^ late.rotate_right($m4) ^ late >> $m5;
+
+                schedule[index] = schedule[index - 16]
+                    .wrapping_add(sigma0)
+                    .wrapping_add(schedule[index - 7])
+                    .wrapping_add(sigma1);
+            }
+
+            let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+            for (constant, word) in $constants.iter().zip(schedule) {
+                let sigma1 = e.rotate_right($s3) ^ e.rotate_right($s4) ^ e.rotate_right($s5);
+                let choice = (e & f) ^ (!e & g);
+                let temporary1 = h
+                    .wrapping_add(sigma1)
+                    .wrapping_add(choice)
+                    .wrapping_add(*constant)
+                    .wrapping_add(word);
+
+                let sigma0 = a.rotate_right($s0) ^ a.rotate_right($s1) ^ a.rotate_right($s2);
+                let majority = (a & b) ^ (a & c) ^ (b & c);
+                let temporary2 = sigma0.wrapping_add(majority);
+
+                (h, g, f, e) = (g, f, e, d.wrapping_add(temporary1));
+                (d, c, b, a) = (c, b, a, temporary1.wrapping_add(temporary2));
+            }
+
+            for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+                *word = word.wrapping_add(value);
+            }
+        }
+    };
+}
+
+impl_compress!(
+    compress_256, u32, ROUND_CONSTANTS_256,
+    sigma: [2, 13, 22], [6, 11, 25],
+    schedule: [7, 18, 3], [17, 19, 10]
+);
+
+impl_compress!(
+    compress_512, u64, ROUND_CONSTANTS_512,
+    sigma: [28, 34, 39], [14, 18, 41],
+    schedule: [1, 8, 7], [19, 61, 6]
+);
+
+macro_rules! impl_sha2 {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident: $word:ty, $initial:ident, $compress:ident, $block:literal, $output:literal;
+    )*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name {
+                state: [$word; 8],
+                buffer: [u8; $block],
+                buffered: usize,
+                length: u128,
+            }
+
+            impl Digest for $name {
+                const BLOCK_SIZE: usize = $block;
+                const OUTPUT_SIZE: usize = $output;
+
+                type Output = [u8; $output];
+
+                fn new() -> Self {
+                    Self {
+                        state: $initial,
+                        buffer: [0; $block],
+                        buffered: 0,
+                        length: 0,
+                    }
+                }
+
+                fn update(&mut self, mut bytes: &[u8]) {
+                    self.length += bytes.len() as u128;
+
+                    if self.buffered > 0 {
+                        let taken = bytes.len().min($block - self.buffered);
+                        self.buffer[self.buffered..self.buffered + taken]
+                            .copy_from_slice(&bytes[..taken]);
+                        self.buffered += taken;
+                        bytes = &bytes[taken..];
+
+                        if self.buffered < $block {
+                            return;
+                        }
+
+                        $compress(&mut self.state, &self.buffer);
+                        self.buffered = 0;
+                    }
+
+                    let mut blocks = bytes.chunks_exact($block);
+
+                    for block in &mut blocks {
+                        $compress(&mut self.state, block);
+                    }
+
+                    let rest = blocks.remainder();
+                    self.buffer[..rest.len()].copy_from_slice(rest);
+                    self.buffered = rest.len();
+                }
+
+                fn finish(&self) -> [u8; $output] {
+                    // The message is followed by a one bit, zeros, and its length in bits,
+                    // which takes the last eighth of the final block.
+                    const LENGTH_BYTES: usize = $block / 8;
+
+                    let mut state = self.state;
+                    let mut buffer = self.buffer;
+
+                    buffer[self.buffered] = 0x80;
+                    buffer[self.buffered + 1..].fill(0);
+
+                    if self.buffered + 1 > $block - LENGTH_BYTES {
+                        $compress(&mut state, &buffer);
+                        buffer.fill(0);
+                    }
+
+                    let length = self.length.wrapping_mul(8).to_be_bytes();
+                    buffer[$block - LENGTH_BYTES..]
+                        .copy_from_slice(&length[length.len() - LENGTH_BYTES..]);
+                    $compress(&mut state, &buffer);
+
+                    let mut output = [0; $output];
+
+                    for (byte, value) in output
+                        .iter_mut()
+                        .zip(state.iter().flat_map(|word| word.to_be_bytes()))
+                    {
+                        *byte = value;
+                    }
+
+                    output
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        )*
+    };
+}
+
+impl_sha2! {
+    /// SHA-224, a 224-bit digest computed like SHA-256.
+    Sha224: u32, INITIAL_224, compress_256, 64, 28;
+
+    /// SHA-256, a 256-bit digest.
+    Sha256: u32, INITIAL_256, compress_256, 64, 32;
+
+    /// SHA-384, a 384-bit digest computed like SHA-512.
+    Sha384: u64, INITIAL_384, compress_512, 128, 48;
+
+    /// SHA-512, a 512-bit digest.
+    Sha512: u64, INITIAL_512, compress_512, 128, 64;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec};
+    use core::fmt::Write;
+
+    use super::*;
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().fold(String::new(), |mut text, byte| {
+            let _ = write!(text, "{byte:02x}");
+            text
+        })
+    }
+
+    #[test]
+    fn test_known_vectors() {
+        assert_eq!(
+            hex(&Sha224::digest(b"")),
+            "d14a028c2a3a2bc9476102bb288234c415a2b01f828ea62ac5b3e42f"
+        );
+        assert_eq!(
+            hex(&Sha224::digest(b"abc")),
+            "23097d223405d8228642a477bda255b32aadbce4bda0b3f7e36c9da7"
+        );
+        assert_eq!(
+            hex(&Sha224::digest(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "75388b16512776cc5dba5da1fd890150b0c6455cb4f58b1952522525"
+        );
+        assert_eq!(
+            hex(&Sha256::digest(b"")),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex(&Sha256::digest(b"abc")),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            hex(&Sha256::digest(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+        );
+        assert_eq!(
+            hex(&Sha384::digest(b"")),
+            concat!(
+                "38b060a751ac96384cd9327eb1b1e36a21fdb71114be07434c0cc7bf63f6e1da",
+                "274edebfe76f65fbd51ad2f14898b95b"
+            )
+        );
+        assert_eq!(
+            hex(&Sha384::digest(b"abc")),
+            concat!(
+                "cb00753f45a35e8bb5a03d699ac65007272c32ab0eded1631a8b605a43ff5bed",
+                "8086072ba1e7cc2358baeca134c825a7"
+            )
+        );
+        assert_eq!(
+            hex(&Sha384::digest(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            concat!(
+                "3391fdddfc8dc7393707a65b1b4709397cf8b1d162af05abfe8f450de5f36bc6",
+                "b0455a8520bc4e6f5fe95b1fe3c8452b"
+            )
+        );
+        assert_eq!(
+            hex(&Sha512::digest(b"")),
+            concat!(
+                "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce",
+                "47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e"
+            )
+        );
+        assert_eq!(
+            hex(&Sha512::digest(b"abc")),
+            concat!(
+                "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a",
+                "2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+            )
+        );
+        assert_eq!(
+            hex(&Sha512::digest(
+                b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq"
+            )),
+            concat!(
+                "204a8fc6dda82f0a0ced7beb8e08a41657c16ef468b228a8279be331a703c335",
+                "96fd15c13b1b07f9aa1d3bea57789ca031ad85c7a71dd70354ec631238ca3445"
+            )
+        );
+    }
+
+    #[test]
+    fn test_updates_across_block_boundaries() {
+        let message = vec![b'a'; 1000];
+
+        for split in [1, 55, 56, 63, 64, 111, 112, 127, 128, 999] {
+            let mut sha256 = Sha256::new();
+            sha256.update(&message[..split]);
+            sha256.update(&message[split..]);
+            assert_eq!(sha256.finish(), Sha256::digest(&message));
+
+            let mut sha512 = Sha512::new();
+            sha512.update(&message[..split]);
+            sha512.update(&message[split..]);
+            assert_eq!(sha512.finish(), Sha512::digest(&message));
+        }
+
+        assert_eq!(
+            hex(&Sha256::digest(&message)),
+            "41edece42d63e8d9bf515a9ba6932e1c20cbc9f5a5d134645adb5db1b9737ea3"
+        );
+        assert_eq!(
+            hex(&Sha512::digest(&message)),
+            concat!(
+                "67ba5535a46e3f86dbfbed8cbbaf0125c76ed549ff8b0b9e03e0c88cf90fa634",
+                "fa7b12b47d77b694de488ace8d9a65967dc96df599727d3292a8d9d447709c97"
+            )
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! `SipHash`, a keyed hash function for hash tables.
+//!
+//! A hash table that uses a fixed hash function can be flooded with keys chosen to
+//! collide, turning every lookup into a linear scan. `SipHash` mixes a secret 128-bit key
+//! into the hash, so an attacker who does not know the key cannot predict collisions.
+//! [`SipHash24`] is the variant of the original paper, and [`SipHash13`] the faster one
+//! that the standard library's hash maps use.
+//!
+//! [`SipHashState`] draws a key from a [`RandomNumberGenerator`] and builds hashers with
+//! it, for use as the [`BuildHasher`] of a map.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::hash::{BuildHasher, Hasher};
+//!
+//! use hashbrown::HashMap;
+//! use libx::{
+//!     hashing::{SipHash24, SipHashState},
+//!     random::SplitMix64,
+//! };
+//!
+//! let mut hasher = SipHash24::with_keys(0x0706_0504_0302_0100, 0x0f0e_0d0c_0b0a_0908);
+//! hasher.write(b"");
+//! assert_eq!(hasher.finish(), 0x726f_db47_dd0e_0e31);
+//!
+//! let state: SipHashState = SipHashState::from_rng(&mut SplitMix64::new(7));
+//! let mut map = HashMap::with_hasher(state);
+//! map.insert("key", 1);
+//! assert_eq!(map.get("key"), Some(&1));
+//! assert_eq!(state.hash_one("key"), state.hash_one("key"));
+//! ```
+
+use core::hash::{BuildHasher, Hasher};
+
+use crate::random::RandomNumberGenerator;
+
+/// `SipHash` with 1 compression round and 3 finalization rounds.
+pub type SipHash13 = SipHasher<1, 3>;
+
+/// `SipHash` with 2 compression rounds and 4 finalization rounds.
+pub type SipHash24 = SipHasher<2, 4>;
+
+/// Mixes the four state words once.
+const fn sip_round(state: &mut [u64; 4]) {
+    let [v0, v1, v2, v3] = state;
+
+    *v0 = v0.wrapping_add(*v1);
+    *v1 = v1.rotate_left(13) ^ *v0;
+    *v0 = v0.rotate_left(32);
+    *v2 = v2.wrapping_add(*v3);
+    *v3 = v3.rotate_left(16) ^ *v2;
+    *v0 = v0.wrapping_add(*v3);
+    *v3 = v3.rotate_left(21) ^ *v0;
+    *v2 = v2.wrapping_add(*v1);
+    *v1 = v1.rotate_left(17) ^ *v2;
+    *v2 = v2.rotate_left(32);
+}
+
+/// `SipHash` with `C` compression rounds and `D` finalization rounds.
+///
+/// Use the aliases [`SipHash13`] and [`SipHash24`] rather than naming the round counts.
+#[derive(Debug, Clone, Copy)]
+pub struct SipHasher<const C: usize, const D: usize> {
+    state: [u64; 4],
+    tail: u64,
+    tail_length: usize,
+    length: u8,
+}
+
+impl<const C: usize, const D: usize> SipHasher<C, D> {
+    /// Creates a hasher keyed with the 128-bit key `key0`, `key1`, where `key0` holds the
+    /// first eight bytes of the key read as a little-endian number.
+    #[must_use]
+    pub const fn with_keys(key0: u64, key1: u64) -> Self {
+        Self {
+            state: [
+                key0 ^ 0x736f_6d65_7073_6575,
+                key1 ^ 0x646f_7261_6e64_6f6d,
+                key0 ^ 0x6c79_6765_6e65_7261,
+                key1 ^ 0x7465_6462_7974_6573,
+            ],
+            tail: 0,
+            tail_length: 0,
+            length: 0,
+        }
+    }
+
+    /// Creates a hasher with the all-zero key, which offers no protection against chosen
+    /// collisions.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_keys(0, 0)
+    }
+
+    /// Mixes one little-endian word of input into the state.
+    fn compress(&mut self, word: u64) {
+        self.state[3] ^= word;
+
+        for _ in 0..C {
+            sip_round(&mut self.state);
+        }
+
+        self.state[0] ^= word;
+    }
+}
+
+impl<const C: usize, const D: usize> Default for SipHasher<C, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const C: usize, const D: usize> Hasher for SipHasher<C, D> {
+    fn write(&mut self, mut bytes: &[u8]) {
+        #[allow(clippy::cast_possible_truncation)] // Only the length modulo 256 is hashed.
+        let length = bytes.len() as u8;
+        self.length = self.length.wrapping_add(length);
+
+        if self.tail_length > 0 {
+            let taken = bytes.len().min(8 - self.tail_length);
+
+            for (index, &byte) in bytes[..taken].iter().enumerate() {
+                self.tail |= u64::from(byte) << (8 * (self.tail_length + index));
+            }
+
+            self.tail_length += taken;
+            bytes = &bytes[taken..];
+
+            if self.tail_length < 8 {
+                return;
+            }
+
+            self.compress(self.tail);
+            self.tail = 0;
+            self.tail_length = 0;
+        }
+
+        let mut words = bytes.chunks_exact(8);
+
+        for word in &mut words {
+            self.compress(
+                word.iter()
+                    .rev()
+                    .fold(0, |word, &byte| word << 8 | u64::from(byte)),
+            );
+        }
+
+        for (index, &byte) in words.remainder().iter().enumerate() {
+            self.tail |= u64::from(byte) << (8 * index);
+        }
+
+        self.tail_length = words.remainder().len();
+    }
+
+    fn finish(&self) -> u64 {
+        let mut hasher = *self;
+        hasher.compress(u64::from(self.length) << 56 | self.tail);
+        hasher.state[2] ^= 0xff;
+
+        for _ in 0..D {
+            sip_round(&mut hasher.state);
+        }
+
+        let [v0, v1, v2, v3] = hasher.state;
+        v0 ^ v1 ^ v2 ^ v3
+    }
+}
+
+/// Builds `SipHash` hashers that share a random key, like the standard library's
+/// `RandomState`.
+///
+/// Every map should get its own state, so that learning how one map orders its keys
+/// tells an attacker nothing about another. The key is only as secret as the generator
+/// it was drawn from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SipHashState<const C: usize = 1, const D: usize = 3> {
+    key0: u64,
+    key1: u64,
+}
+
+impl<const C: usize, const D: usize> SipHashState<C, D> {
+    /// Creates a state with the given key.
+    #[must_use]
+    pub const fn with_keys(key0: u64, key1: u64) -> Self {
+        Self { key0, key1 }
+    }
+
+    /// Creates a state with a key drawn from `rng`.
+    pub fn from_rng<R>(rng: &mut R) -> Self
+    where
+        R: RandomNumberGenerator + ?Sized,
+    {
+        Self::with_keys(rng.next_u64(), rng.next_u64())
+    }
+
+    /// Returns the key of the hashers this state builds.
+    #[must_use]
+    pub const fn keys(&self) -> (u64, u64) {
+        (self.key0, self.key1)
+    }
+}
+
+impl<const C: usize, const D: usize> BuildHasher for SipHashState<C, D> {
+    type Hasher = SipHasher<C, D>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SipHasher::with_keys(self.key0, self.key1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::random::SplitMix64;
+
+    const KEY0: u64 = 0x0706_0504_0302_0100;
+    const KEY1: u64 = 0x0f0e_0d0c_0b0a_0908;
+
+    fn hash<H: Hasher>(mut hasher: H, bytes: &[u8]) -> u64 {
+        hasher.write(bytes);
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_reference_vectors() {
+        let message: Vec<u8> = (0..64).collect();
+
+        assert_eq!(
+            hash(SipHash24::with_keys(KEY0, KEY1), &message[..15]),
+            0xa129_ca61_49be_45e5
+        );
+        assert_eq!(
+            hash(SipHash24::with_keys(KEY0, KEY1), &message),
+            0xacd2_c40b_8502_cad8
+        );
+        assert_eq!(
+            hash(SipHash13::with_keys(KEY0, KEY1), &[]),
+            0xabac_0158_050f_c4dc
+        );
+        assert_eq!(
+            hash(SipHash13::with_keys(KEY0, KEY1), &message[..15]),
+            0xd320_d86d_2a51_9956
+        );
+        assert_eq!(
+            hash(SipHash13::with_keys(KEY0, KEY1), &message),
+            0xf179_97ec_4b4a_6065
+        );
+    }
+
+    #[test]
+    fn test_split_writes() {
+        let message: Vec<u8> = (0..64).collect();
+        let whole = hash(SipHash24::with_keys(KEY0, KEY1), &message);
+
+        for split in [1, 3, 7, 8, 9, 15, 16, 63] {
+            let mut hasher = SipHash24::with_keys(KEY0, KEY1);
+            hasher.write(&message[..split]);
+            hasher.write(&message[split..]);
+            assert_eq!(hasher.finish(), whole);
+        }
+    }
+
+    #[test]
+    fn test_state() {
+        let state = SipHashState::<2, 4>::from_rng(&mut SplitMix64::new(1));
+        let other = SipHashState::<2, 4>::from_rng(&mut SplitMix64::new(2));
+        let (key0, key1) = state.keys();
+
+        assert_eq!(
+            hash(state.build_hasher(), b"abc"),
+            hash(SipHash24::with_keys(key0, key1), b"abc")
+        );
+        assert_ne!(state.hash_one(42_u32), other.hash_one(42_u32));
+    }
+}
@@ -0,0 +1,618 @@
+//! One-call helpers that turn values into friendly text for a locale, and back.
+//!
+//! Each function builds the matching formatter from [`libx::formatting`](crate::formatting)
+//! for the given [`Locale`], so application code that only needs the common
+//! presentation does not have to configure formatters itself. [`parse_bytes`] and
+//! [`parse_duration`] read the same kind of text, as people type it, back into values.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::time::Duration;
+//!
+//! use libx::humanize;
+//! use libx::locale::Locale;
+//!
+//! let english = Locale::new("en_US");
+//!
+//! assert_eq!(humanize::bytes(1_536_000, &english), "1.5 MB");
+//! assert_eq!(humanize::count(12_345, &english), "12K");
+//! assert_eq!(humanize::duration(Duration::from_secs(5400), &english), "1 h 30 min");
+//! assert_eq!(humanize::ordinal(22, &english), "22nd");
+//! assert_eq!(humanize::truncate_middle("libx-0.1.0-x86_64.tar.gz", 13), "libx-0…tar.gz");
+//! ```
+
+use alloc::{borrow::Cow, string::String};
+use core::time::Duration;
+
+use crate::{
+    errors::ParseError,
+    formatting::{ByteCountFormatter, NumberFormatter, NumberStyle, numbers::Value},
+    locale::{Locale, NumberSymbols},
+    num::Number,
+    text::digits,
+};
+
+/// Formats a byte count as a file size, such as `1.5 MB`.
+#[must_use]
+pub fn bytes(byte_count: i64, locale: &Locale) -> String {
+    ByteCountFormatter::new()
+        .with_locale(locale)
+        .format(byte_count)
+}
+
+/// Formats a number in compact notation, such as `1.2K`.
+#[must_use]
+pub fn count(value: impl Into<Number>, locale: &Locale) -> String {
+    NumberFormatter::new()
+        .with_locale(locale)
+        .with_style(NumberStyle::CompactShort)
+        .format(value)
+}
+
+/// Formats a duration with its two largest units, such as `1 h 30 min`.
+///
+/// Durations under a minute use a single unit: seconds with up to one fraction digit,
+/// milliseconds or microseconds. Unit symbols are the international ones, which read
+/// the same in every language.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::humanize;
+/// use libx::locale::Locale;
+///
+/// let german = Locale::new("de_DE");
+///
+/// assert_eq!(humanize::duration(Duration::from_millis(2_500), &german), "2,5 s");
+/// assert_eq!(humanize::duration(Duration::from_secs(90_061), &german), "1 d 1 h");
+/// ```
+#[must_use]
+pub fn duration(duration: Duration, locale: &Locale) -> String {
+    const UNITS: [(u128, &str); 4] = [(86_400, "d"), (3_600, "h"), (60, "min"), (1, "s")];
+
+    let formatter = NumberFormatter::new()
+        .with_locale(locale)
+        .with_uses_grouping_separator(true)
+        .with_maximum_fraction_digits(1);
+    let mut output = String::new();
+    let mut write = |value: Value, unit: &str| {
+        if !output.is_empty() {
+            output.push(' ');
+        }
+
+        // Writing into a `String` cannot fail.
+        let _ = formatter.write_value(value, &mut output);
+        output.push(' ');
+        output.push_str(unit);
+    };
+
+    let milliseconds = duration.as_millis();
+
+    // Compare rounded values, so that 59.96 seconds is written as a minute.
+    if (milliseconds + 50) / 100 >= 600 {
+        let mut remaining = (milliseconds + 500) / 1000;
+        let mut parts = UNITS
+            .iter()
+            .map(|&(size, unit)| {
+                let amount = remaining / size;
+                remaining %= size;
+                (amount, unit)
+            })
+            .skip_while(|&(amount, _)| amount == 0);
+
+        if let Some((amount, unit)) = parts.next() {
+            write(Value::Integer(amount.try_into().unwrap_or(i128::MAX)), unit);
+        }
+
+        if let Some((amount, unit)) = parts.next().filter(|&(amount, _)| amount > 0) {
+            write(Value::Integer(amount.try_into().unwrap_or(i128::MAX)), unit);
+        }
+    } else if milliseconds >= 1000 || duration.is_zero() {
+        write(Value::Float(duration.as_secs_f64()), "s");
+    } else if milliseconds > 0 {
+        write(Value::Integer(i128::from(duration.subsec_millis())), "ms");
+    } else {
+        write(Value::Integer(i128::from(duration.subsec_micros())), "µs");
+    }
+
+    output
+}
+
+/// Formats an integer as an ordinal, such as `2nd` in English or `2.` in German.
+///
+/// Languages with grammatical gender use the masculine form. Languages without ordinal
+/// data use the English suffixes.
+///
+/// # Examples
+///
+/// ```
+/// use libx::humanize;
+/// use libx::locale::Locale;
+///
+/// assert_eq!(humanize::ordinal(1, &Locale::new("fr_FR")), "1er");
+/// assert_eq!(humanize::ordinal(3, &Locale::new("es_ES")), "3.º");
+/// assert_eq!(humanize::ordinal(113, &Locale::new("en_GB")), "113th");
+/// ```
+#[must_use]
+pub fn ordinal(value: i64, locale: &Locale) -> String {
+    let formatter = NumberFormatter::new()
+        .with_locale(locale)
+        .with_uses_grouping_separator(true);
+    let mut digits = String::new();
+    // Writing into a `String` cannot fail.
+    let _ = formatter.write_value(Value::Integer(i128::from(value)), &mut digits);
+
+    let last = value.unsigned_abs() % 10;
+    let last_two = value.unsigned_abs() % 100;
+
+    let (prefix, suffix) = match locale.language_code() {
+        "fr" if value == 1 => ("", "er"),
+        "fr" | "nl" => ("", "e"),
+        "de" | "da" | "nb" | "no" | "nn" | "fi" | "cs" | "sk" | "pl" | "hu" | "tr" | "et"
+        | "lv" | "sl" | "hr" | "sr" => ("", "."),
+        "es" | "pt" | "gl" => ("", ".º"),
+        "it" => ("", "º"),
+        "sv" if matches!(last, 1 | 2) && !matches!(last_two, 11 | 12) => ("", ":a"),
+        "sv" => ("", ":e"),
+        "ru" | "uk" => ("", "-й"),
+        "ja" | "zh" => ("第", ""),
+        _ => match (last, last_two) {
+            (_, 11..=13) => ("", "th"),
+            (1, _) => ("", "st"),
+            (2, _) => ("", "nd"),
+            (3, _) => ("", "rd"),
+            _ => ("", "th"),
+        },
+    };
+
+    alloc::format!("{prefix}{digits}{suffix}")
+}
+
+/// Shortens `text` to at most `max_len` characters by replacing its middle with `…`.
+///
+/// The start and end of the text are kept, which suits file names and identifiers whose
+/// ends are the most telling. Text that already fits is returned unchanged.
+#[must_use]
+pub fn truncate_middle(text: &str, max_len: usize) -> Cow<'_, str> {
+    let length = text.chars().count();
+
+    if length <= max_len {
+        return Cow::Borrowed(text);
+    }
+
+    if max_len == 0 {
+        return Cow::Borrowed("");
+    }
+
+    let kept = max_len - 1;
+    let head = kept.div_ceil(2);
+    let tail = kept / 2;
+
+    let mut output: String = text.chars().take(head).collect();
+    output.push('…');
+    output.extend(text.chars().skip(length - tail));
+
+    Cow::Owned(output)
+}
+
+/// A decimal number read from the start of some text.
+struct Decimal {
+    integer: u128,
+    fraction: u128,
+    /// The power of ten the fraction is a numerator of.
+    scale: u128,
+    /// The byte offset just past the number.
+    end: usize,
+}
+
+impl Decimal {
+    /// The fraction digits that are kept; the rest cannot change a byte count or a
+    /// number of nanoseconds, and keeping them could overflow.
+    const MAX_SCALE: u128 = 1_000_000_000_000;
+
+    /// Reads a number written with `symbols` from `text`, starting at `start`.
+    fn read(text: &str, start: usize, symbols: &NumberSymbols) -> Result<Self, ParseError> {
+        let mut decimal = Self {
+            integer: 0,
+            fraction: 0,
+            scale: 1,
+            end: start,
+        };
+        let mut rest = &text[start..];
+        let mut has_digits = false;
+        let mut in_fraction = false;
+
+        loop {
+            let next = rest.chars().next();
+
+            if let Some(value) = next.and_then(digits::value) {
+                let value = u128::from(value);
+
+                if !in_fraction {
+                    decimal.integer = decimal
+                        .integer
+                        .checked_mul(10)
+                        .and_then(|integer| integer.checked_add(value))
+                        .ok_or(ParseError::OutOfRange)?;
+                } else if decimal.scale < Self::MAX_SCALE {
+                    decimal.fraction = decimal.fraction * 10 + value;
+                    decimal.scale *= 10;
+                }
+
+                has_digits = true;
+                rest = &rest[next.map_or(0, char::len_utf8)..];
+            } else if let Some(after) = rest
+                .strip_prefix(symbols.decimal_separator)
+                .filter(|_| has_digits && !in_fraction)
+            {
+                in_fraction = true;
+                rest = after;
+            } else if let Some(after) =
+                rest.strip_prefix(symbols.grouping_separator)
+                    .filter(|after| {
+                        has_digits
+                            && !in_fraction
+                            && after.chars().next().and_then(digits::value).is_some()
+                    })
+            {
+                rest = after;
+            } else {
+                break;
+            }
+        }
+
+        if !has_digits {
+            return Err(rest
+                .chars()
+                .next()
+                .map_or(ParseError::InvalidSyntax, |character| {
+                    ParseError::InvalidCharacter {
+                        character,
+                        position: start,
+                    }
+                }));
+        }
+
+        decimal.end = text.len() - rest.len();
+        Ok(decimal)
+    }
+
+    /// Returns the number times `unit`, rounded to the nearest integer.
+    fn times(&self, unit: u128) -> Result<u128, ParseError> {
+        let fraction = (self.fraction * unit + self.scale / 2) / self.scale;
+
+        self.integer
+            .checked_mul(unit)
+            .and_then(|whole| whole.checked_add(fraction))
+            .ok_or(ParseError::OutOfRange)
+    }
+}
+
+/// Returns the byte offset of the first character at or after `start` that is not
+/// whitespace.
+fn skip_whitespace(text: &str, start: usize) -> usize {
+    text.len() - text[start..].trim_start().len()
+}
+
+/// Returns the byte offset just past the run of letters starting at `start`.
+fn skip_letters(text: &str, start: usize) -> usize {
+    text[start..]
+        .find(|character: char| !character.is_alphabetic())
+        .map_or(text.len(), |length| start + length)
+}
+
+/// Returns an error for the character at `position`, or for missing input at the end.
+fn unexpected(text: &str, position: usize) -> ParseError {
+    text[position..]
+        .chars()
+        .next()
+        .map_or(ParseError::InvalidSyntax, |character| {
+            ParseError::InvalidCharacter {
+                character,
+                position,
+            }
+        })
+}
+
+/// Parses a file size such as `10 MiB` or `1.5 GB` into a number of bytes, the inverse of
+/// [`bytes`].
+///
+/// The number is written with the decimal and grouping separators of `locale`, in the
+/// digits of any [`NumberingSystem`](crate::locale::NumberingSystem). The unit is matched
+/// without regard to case: `B`, `byte` or `bytes`, or nothing, for bytes; `kB` to `YB`
+/// for powers of 1000; and `KiB` to `YiB` for powers of 1024. The result is rounded to a
+/// whole byte.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Empty`] for blank text, [`ParseError::InvalidCharacter`] at an
+/// unknown unit or an unexpected character, and [`ParseError::OutOfRange`] if the size
+/// does not fit in a `u128`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::humanize;
+/// use libx::locale::Locale;
+///
+/// let english = Locale::new("en_US");
+///
+/// assert_eq!(humanize::parse_bytes("10 MiB", &english), Ok(10 * 1024 * 1024));
+/// assert_eq!(humanize::parse_bytes("1.5kb", &english), Ok(1_500));
+/// assert_eq!(humanize::parse_bytes("2,5 GB", &Locale::new("de")), Ok(2_500_000_000));
+/// ```
+pub fn parse_bytes(text: &str, locale: &Locale) -> Result<u128, ParseError> {
+    let start = skip_whitespace(text, 0);
+
+    if start == text.len() {
+        return Err(ParseError::Empty);
+    }
+
+    let number = Decimal::read(text, start, &NumberSymbols::for_locale(locale))?;
+    let unit_start = skip_whitespace(text, number.end);
+    let unit_end = skip_letters(text, unit_start);
+    let unit = text[unit_start..unit_end].to_ascii_lowercase();
+
+    let multiplier = match unit.as_str() {
+        "" | "b" | "byte" | "bytes" => 1,
+        _ => {
+            let mut characters = unit.chars();
+            let power = characters
+                .next()
+                .and_then(|prefix| "kmgtpezy".find(prefix))
+                .and_then(|index| u32::try_from(index + 1).ok());
+            let base = match characters.as_str() {
+                "" | "b" => Some(1000_u128),
+                "i" | "ib" => Some(1024),
+                _ => None,
+            };
+
+            match (power, base) {
+                (Some(power), Some(base)) => base.pow(power),
+                _ => return Err(unexpected(text, unit_start)),
+            }
+        }
+    };
+
+    let end = skip_whitespace(text, unit_end);
+
+    if end < text.len() {
+        return Err(unexpected(text, end));
+    }
+
+    number.times(multiplier)
+}
+
+/// Parses a duration such as `1h30m15s` or `2,5 s` into a [`Duration`], the inverse of
+/// [`duration`].
+///
+/// The duration is a sequence of numbers, each followed by its unit, which are added up.
+/// Numbers are written with the separators of `locale`, in any digits, and may have a
+/// fraction. Spaces are allowed between and within the parts. The units are `d`, `h`,
+/// `m` or `min`, `s`, `ms`, `µs` or `us`, and `ns`, as well as the English words for
+/// days, hours, minutes and seconds, all without regard to case.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Empty`] for blank text, [`ParseError::InvalidCharacter`] at an
+/// unknown unit or an unexpected character, [`ParseError::InvalidSyntax`] if the last
+/// number has no unit, and [`ParseError::OutOfRange`] if the duration does not fit in a
+/// [`Duration`].
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::humanize;
+/// use libx::locale::Locale;
+///
+/// let english = Locale::new("en_US");
+///
+/// assert_eq!(humanize::parse_duration("1h30m15s", &english), Ok(Duration::from_secs(5_415)));
+/// assert_eq!(humanize::parse_duration("1 h 30 min", &english), Ok(Duration::from_secs(5_400)));
+/// assert_eq!(
+///     humanize::parse_duration("2,5 s", &Locale::new("de_DE")),
+///     Ok(Duration::from_millis(2_500))
+/// );
+/// ```
+pub fn parse_duration(text: &str, locale: &Locale) -> Result<Duration, ParseError> {
+    const NANOSECONDS_PER_SECOND: u128 = 1_000_000_000;
+
+    let symbols = NumberSymbols::for_locale(locale);
+    let mut position = skip_whitespace(text, 0);
+    let mut nanoseconds: u128 = 0;
+
+    if position == text.len() {
+        return Err(ParseError::Empty);
+    }
+
+    while position < text.len() {
+        let number = Decimal::read(text, position, &symbols)?;
+        let unit_start = skip_whitespace(text, number.end);
+        let unit_end = skip_letters(text, unit_start);
+
+        let unit = match text[unit_start..unit_end].to_lowercase().as_str() {
+            "d" | "day" | "days" => 86_400 * NANOSECONDS_PER_SECOND,
+            "h" | "hr" | "hrs" | "hour" | "hours" => 3_600 * NANOSECONDS_PER_SECOND,
+            "m" | "min" | "mins" | "minute" | "minutes" => 60 * NANOSECONDS_PER_SECOND,
+            "s" | "sec" | "secs" | "second" | "seconds" => NANOSECONDS_PER_SECOND,
+            "ms" => 1_000_000,
+            "µs" | "μs" | "us" => 1_000,
+            "ns" => 1,
+            _ => return Err(unexpected(text, unit_start)),
+        };
+
+        nanoseconds = nanoseconds
+            .checked_add(number.times(unit)?)
+            .ok_or(ParseError::OutOfRange)?;
+        position = skip_whitespace(text, unit_end);
+    }
+
+    let seconds =
+        u64::try_from(nanoseconds / NANOSECONDS_PER_SECOND).map_err(|_| ParseError::OutOfRange)?;
+
+    #[allow(clippy::cast_possible_truncation)] // The remainder is under a billion.
+    Ok(Duration::new(
+        seconds,
+        (nanoseconds % NANOSECONDS_PER_SECOND) as u32,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duration_units() {
+        let english = Locale::new("en");
+
+        assert_eq!(duration(Duration::ZERO, &english), "0 s");
+        assert_eq!(duration(Duration::from_micros(250), &english), "250 µs");
+        assert_eq!(duration(Duration::from_millis(999), &english), "999 ms");
+        assert_eq!(duration(Duration::from_millis(59_940), &english), "59.9 s");
+        assert_eq!(duration(Duration::from_millis(59_960), &english), "1 min");
+        assert_eq!(duration(Duration::from_secs(3_605), &english), "1 h");
+        assert_eq!(duration(Duration::from_secs(3_725), &english), "1 h 2 min");
+        assert_eq!(duration(Duration::from_hours(400 * 24), &english), "400 d");
+    }
+
+    #[test]
+    fn test_ordinals() {
+        let english = Locale::new("en_US");
+        let suffixes: [String; 8] =
+            [1, 2, 3, 4, 11, 12, 21, 1_001].map(|value| ordinal(value, &english));
+
+        assert_eq!(
+            suffixes,
+            [
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "21st", "1,001st"
+            ]
+        );
+        assert_eq!(ordinal(2, &Locale::new("fr")), "2e");
+        assert_eq!(ordinal(5, &Locale::new("de")), "5.");
+        assert_eq!(ordinal(2, &Locale::new("sv")), "2:a");
+        assert_eq!(ordinal(12, &Locale::new("sv")), "12:e");
+        assert_eq!(ordinal(3, &Locale::new("ja")), "第3");
+        assert_eq!(ordinal(7, &Locale::new("ar_EG")), "٧th");
+    }
+
+    #[test]
+    fn test_truncate_middle() {
+        assert_eq!(truncate_middle("short", 10), "short");
+        assert_eq!(truncate_middle("abcdefghij", 7), "abc…hij");
+        assert_eq!(truncate_middle("abcdefghij", 6), "abc…ij");
+        assert_eq!(truncate_middle("abcdefghij", 1), "…");
+        assert_eq!(truncate_middle("abcdefghij", 0), "");
+        assert!(matches!(truncate_middle("fits", 4), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_bytes_and_count_use_locale() {
+        let french = Locale::new("fr_FR");
+
+        assert_eq!(bytes(2_500_000, &french), "2,5 MB");
+        assert_eq!(count(2_500_000, &french), "2,5\u{A0}M");
+    }
+
+    #[test]
+    fn test_parse_bytes() {
+        let english = Locale::new("en_US");
+
+        assert_eq!(parse_bytes("512", &english), Ok(512));
+        assert_eq!(parse_bytes(" 1.5 KiB ", &english), Ok(1_536));
+        assert_eq!(parse_bytes("1,536 KB", &english), Ok(1_536_000));
+        assert_eq!(parse_bytes("3 bytes", &english), Ok(3));
+        assert_eq!(parse_bytes("1 EiB", &english), Ok(1 << 60));
+        assert_eq!(parse_bytes("0.3333 B", &english), Ok(0));
+        assert_eq!(parse_bytes("٢٫٥ KB", &Locale::new("ar_EG")), Ok(2_500));
+        assert_eq!(
+            parse_bytes(&bytes(2_500_000, &english), &english),
+            Ok(2_500_000)
+        );
+
+        assert_eq!(parse_bytes("  ", &english), Err(ParseError::Empty));
+        assert_eq!(
+            parse_bytes("10 XB", &english),
+            Err(ParseError::InvalidCharacter {
+                character: 'X',
+                position: 3
+            })
+        );
+        assert_eq!(
+            parse_bytes("-1 B", &english),
+            Err(ParseError::InvalidCharacter {
+                character: '-',
+                position: 0
+            })
+        );
+        assert_eq!(
+            parse_bytes("1 MB 2", &english),
+            Err(ParseError::InvalidCharacter {
+                character: '2',
+                position: 5
+            })
+        );
+        assert_eq!(
+            parse_bytes("1000000000000000 YB", &english),
+            Err(ParseError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        let english = Locale::new("en");
+
+        assert_eq!(
+            parse_duration("3h20m", &english),
+            Ok(Duration::from_mins(200))
+        );
+        assert_eq!(
+            parse_duration("1.5 hours", &english),
+            Ok(Duration::from_mins(90))
+        );
+        assert_eq!(
+            parse_duration("2 d 250ms 3 µs", &english),
+            Ok(Duration::new(172_800, 250_003_000))
+        );
+        assert_eq!(
+            parse_duration("0.000000001s", &english),
+            Ok(Duration::from_nanos(1))
+        );
+
+        for seconds in [0, 59, 3_720, 90_000] {
+            let text = duration(Duration::from_secs(seconds), &english);
+            assert_eq!(
+                parse_duration(&text, &english),
+                Ok(Duration::from_secs(seconds))
+            );
+        }
+
+        assert_eq!(parse_duration("", &english), Err(ParseError::Empty));
+        assert_eq!(
+            parse_duration("1h 30", &english),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            parse_duration("5 fortnights", &english),
+            Err(ParseError::InvalidCharacter {
+                character: 'f',
+                position: 2
+            })
+        );
+        assert_eq!(
+            parse_duration("1h,5m", &english),
+            Err(ParseError::InvalidCharacter {
+                character: ',',
+                position: 2
+            })
+        );
+        assert_eq!(
+            parse_duration("1000000000000000 d", &english),
+            Err(ParseError::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Stable identities for values and objects.
+//!
+//! [`Identifiable`] lets a type name the key it should be tracked by, so observer
+//! registries and caches can be keyed consistently. [`UniqueId`] hands out process-wide
+//! unique identifiers from an atomic counter, and [`ObjectIdentifier`] identifies a value
+//! by its address, like Swift's `ObjectIdentifier`.
+
+use alloc::{rc::Rc, sync::Arc};
+use core::{
+    fmt,
+    hash::Hash,
+    num::NonZeroUsize,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// A type whose values carry a stable identity.
+///
+/// Two values with the same `id` are considered the same entity, even if their other
+/// fields differ.
+///
+/// # Examples
+///
+/// ```
+/// use libx::identity::{Identifiable, UniqueId};
+///
+/// struct Subscriber {
+///     id: UniqueId,
+///     name: &'static str,
+/// }
+///
+/// impl Identifiable for Subscriber {
+///     type Id = UniqueId;
+///
+///     fn id(&self) -> UniqueId {
+///         self.id
+///     }
+/// }
+///
+/// let first = Subscriber { id: UniqueId::new(), name: "first" };
+/// let second = Subscriber { id: UniqueId::new(), name: "second" };
+/// assert_ne!(first.id(), second.id());
+/// ```
+pub trait Identifiable {
+    /// The type of the identity.
+    type Id: Eq + Hash;
+
+    /// Returns the identity of this value.
+    fn id(&self) -> Self::Id;
+}
+
+/// A source of identifiers that are unique for the lifetime of the generator.
+///
+/// Generators are lock-free and usable from `static` items, so they work in `no_std`
+/// environments with atomic pointer-width integers.
+///
+/// # Examples
+///
+/// ```
+/// use libx::identity::UniqueIdGenerator;
+///
+/// static IDS: UniqueIdGenerator = UniqueIdGenerator::new();
+///
+/// let a = IDS.next_id();
+/// let b = IDS.next_id();
+/// assert!(a < b);
+/// ```
+#[derive(Debug)]
+pub struct UniqueIdGenerator {
+    next: AtomicUsize,
+}
+
+impl UniqueIdGenerator {
+    /// Creates a generator whose first identifier is `1`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            next: AtomicUsize::new(1),
+        }
+    }
+
+    /// Returns the next identifier.
+    ///
+    /// # Panics
+    ///
+    /// Panics once the generator has handed out `usize::MAX - 1` identifiers. Exhaustion
+    /// is permanent: the counter never wraps, so every later call panics too.
+    pub fn next_id(&self) -> UniqueId {
+        let raw = self
+            .next
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |next| {
+                next.checked_add(1)
+            })
+            .ok()
+            .and_then(NonZeroUsize::new);
+
+        let Some(raw) = raw else {
+            panic!("unique identifiers exhausted");
+        };
+
+        UniqueId(raw)
+    }
+}
+
+impl Default for UniqueIdGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+static GLOBAL_IDS: UniqueIdGenerator = UniqueIdGenerator::new();
+
+/// A process-wide unique identifier.
+///
+/// Identifiers are ordered by creation time within a generator, and `Option<UniqueId>`
+/// is the same size as `UniqueId`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UniqueId(NonZeroUsize);
+
+impl UniqueId {
+    /// Returns a new identifier from the global generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the global generator is exhausted.
+    #[must_use]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        GLOBAL_IDS.next_id()
+    }
+
+    /// Returns the raw value of the identifier.
+    #[must_use]
+    pub const fn get(self) -> usize {
+        self.0.get()
+    }
+}
+
+impl fmt::Display for UniqueId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+impl Identifiable for UniqueId {
+    type Id = Self;
+
+    fn id(&self) -> Self {
+        *self
+    }
+}
+
+/// Identifies a value by its address.
+///
+/// Two identifiers are equal exactly when they were created from the same place in
+/// memory, which makes them suitable for keying shared objects such as `Rc` and `Arc`
+/// allocations. An identifier does not keep its object alive, so it may be reused once
+/// the object is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use std::rc::Rc;
+///
+/// use libx::identity::ObjectIdentifier;
+///
+/// let shared = Rc::new(5);
+/// let alias = Rc::clone(&shared);
+/// let other = Rc::new(5);
+///
+/// assert_eq!(ObjectIdentifier::new(&*shared), ObjectIdentifier::new(&*alias));
+/// assert_ne!(ObjectIdentifier::new(&*shared), ObjectIdentifier::new(&*other));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ObjectIdentifier(usize);
+
+impl ObjectIdentifier {
+    /// Creates the identifier for the value behind `object`.
+    #[must_use]
+    pub fn new<T: ?Sized>(object: &T) -> Self {
+        Self(core::ptr::from_ref(object).cast::<()>().addr())
+    }
+
+    /// Returns `true` if `a` and `b` refer to the same value.
+    #[must_use]
+    pub fn same<T: ?Sized>(a: &T, b: &T) -> bool {
+        Self::new(a) == Self::new(b)
+    }
+}
+
+impl<T: ?Sized> Identifiable for Rc<T> {
+    type Id = ObjectIdentifier;
+
+    fn id(&self) -> ObjectIdentifier {
+        ObjectIdentifier::new(&**self)
+    }
+}
+
+impl<T: ?Sized> Identifiable for Arc<T> {
+    type Id = ObjectIdentifier;
+
+    fn id(&self) -> ObjectIdentifier {
+        ObjectIdentifier::new(&**self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_generator_is_monotonic() {
+        let generator = UniqueIdGenerator::new();
+
+        let first = generator.next_id();
+        let second = generator.next_id();
+
+        assert_eq!(first.get(), 1);
+        assert_eq!(second.get(), 2);
+        assert_eq!(format!("{second}"), "#2");
+    }
+
+    #[test]
+    fn test_generator_exhaustion_is_sticky() {
+        let generator = UniqueIdGenerator {
+            next: AtomicUsize::new(usize::MAX - 1),
+        };
+
+        assert_eq!(generator.next_id().get(), usize::MAX - 1);
+
+        for _ in 0..2 {
+            let result = std::panic::catch_unwind(|| generator.next_id());
+            assert!(result.is_err());
+        }
+    }
+
+    #[test]
+    fn test_global_ids_are_unique() {
+        let a = UniqueId::new();
+        let b = UniqueId::new();
+
+        assert_ne!(a, b);
+        assert_eq!(a.id(), a);
+        assert_eq!(size_of::<Option<UniqueId>>(), size_of::<UniqueId>());
+    }
+
+    #[test]
+    fn test_shared_pointer_identity() {
+        let shared = Rc::new([1, 2, 3]);
+        let alias = Rc::clone(&shared);
+        let copy = Rc::new([1, 2, 3]);
+
+        assert_eq!(shared.id(), alias.id());
+        assert_ne!(shared.id(), copy.id());
+
+        let atomic = Arc::new(0_u8);
+        assert_eq!(atomic.id(), ObjectIdentifier::new(&*atomic));
+        assert!(ObjectIdentifier::same(&*shared, &*alias));
+    }
+}
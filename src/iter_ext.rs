@@ -0,0 +1,9 @@
+//! Iterator adapters beyond those in `core`.
+//!
+//! The [`combinatorics`] module enumerates permutations, combinations, cartesian products
+//! and power sets lazily, and counts them with overflow-checked [`factorial`] and
+//! [`binomial`].
+
+pub mod combinatorics;
+
+pub use combinatorics::{CombinatoricsExt, binomial, factorial};
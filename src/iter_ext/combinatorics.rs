@@ -0,0 +1,560 @@
+//! Lazy permutations, combinations, cartesian products and power sets.
+//!
+//! The adapters on [`CombinatoricsExt`] read their source only when first advanced, then
+//! keep its items in a buffer and yield each arrangement as a `Vec` of clones. Items
+//! are told apart by position, not by value, so a source with repeated items yields
+//! repeated arrangements. Arrangements come in lexicographic order of the positions.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::iter_ext::{CombinatoricsExt, binomial};
+//!
+//! let pairs: Vec<Vec<char>> = "abcd".chars().combinations(2).collect();
+//! assert_eq!(pairs.len(), 6);
+//! assert_eq!(pairs[0], ['a', 'b']);
+//! assert_eq!(binomial(4u32, 2), Some(6));
+//!
+//! let grid: Vec<(u8, char)> = (1..=2).cartesian_product(['x', 'y']).collect();
+//! assert_eq!(grid, [(1, 'x'), (1, 'y'), (2, 'x'), (2, 'y')]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::num::traits::FixedWidthInteger;
+
+/// Combinatoric adapters for any iterator over cloneable items.
+pub trait CombinatoricsExt: Iterator + Sized
+where
+    Self::Item: Clone,
+{
+    /// Returns every ordering of every `k` distinct positions.
+    ///
+    /// A source of `n` items yields `n! / (n - k)!` permutations; none if `k > n`, and a
+    /// single empty one if `k` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::iter_ext::CombinatoricsExt;
+    ///
+    /// let orders: Vec<Vec<u8>> = [1, 2, 3].into_iter().permutations(2).collect();
+    /// assert_eq!(orders, [[1, 2], [1, 3], [2, 1], [2, 3], [3, 1], [3, 2]]);
+    /// ```
+    fn permutations(self, k: usize) -> Permutations<Self> {
+        Permutations {
+            pool: Pool::new(self),
+            k,
+            indices: Vec::new(),
+            cycles: Vec::new(),
+            started: false,
+        }
+    }
+
+    /// Returns every choice of `k` distinct positions, in their original order.
+    ///
+    /// A source of `n` items yields `binomial(n, k)` combinations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::iter_ext::CombinatoricsExt;
+    ///
+    /// let hands: Vec<Vec<u8>> = [1, 2, 3].into_iter().combinations(2).collect();
+    /// assert_eq!(hands, [[1, 2], [1, 3], [2, 3]]);
+    /// ```
+    fn combinations(self, k: usize) -> Combinations<Self> {
+        Combinations {
+            pool: Pool::new(self),
+            indices: (0..k).collect(),
+            started: false,
+        }
+    }
+
+    /// Returns every choice of `k` positions where a position may be chosen more than
+    /// once, in their original order.
+    ///
+    /// A source of `n` items yields `binomial(n + k - 1, k)` combinations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::iter_ext::CombinatoricsExt;
+    ///
+    /// let scoops = ['v', 'c'].into_iter().combinations_with_replacement(2);
+    /// assert_eq!(scoops.collect::<Vec<_>>(), [['v', 'v'], ['v', 'c'], ['c', 'c']]);
+    /// ```
+    fn combinations_with_replacement(self, k: usize) -> CombinationsWithReplacement<Self> {
+        CombinationsWithReplacement {
+            pool: Pool::new(self),
+            indices: alloc::vec![0; k],
+            started: false,
+        }
+    }
+
+    /// Returns every pair of an item from `self` and an item from `other`, varying the
+    /// second fastest.
+    ///
+    /// `other` is restarted from a clone for each item of `self`. If `other` is empty,
+    /// `self` is still drained before the iterator ends.
+    fn cartesian_product<J>(self, other: J) -> CartesianProduct<Self, J::IntoIter>
+    where
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        let other = other.into_iter();
+
+        CartesianProduct {
+            first: self,
+            current: None,
+            second: other.clone(),
+            original: other,
+        }
+    }
+
+    /// Returns every subset of the positions, smallest first.
+    ///
+    /// A source of `n` items yields `2^n` subsets, starting with the empty one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::iter_ext::CombinatoricsExt;
+    ///
+    /// let subsets: Vec<Vec<u8>> = [1, 2, 3].into_iter().powerset().collect();
+    /// assert_eq!(subsets.len(), 8);
+    /// assert_eq!(subsets[..5], [vec![], vec![1], vec![2], vec![3], vec![1, 2]]);
+    /// ```
+    fn powerset(self) -> Powerset<Self> {
+        Powerset {
+            pool: Pool::new(self),
+            indices: Vec::new(),
+            started: false,
+        }
+    }
+}
+
+impl<I> CombinatoricsExt for I
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+}
+
+/// The items of a source, read in full on first use.
+#[derive(Debug, Clone)]
+struct Pool<I, T> {
+    source: Option<I>,
+    items: Vec<T>,
+}
+
+impl<I, T> Pool<I, T>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    const fn new(source: I) -> Self {
+        Self {
+            source: Some(source),
+            items: Vec::new(),
+        }
+    }
+
+    fn items(&mut self) -> &[T] {
+        if let Some(source) = self.source.take() {
+            self.items.extend(source);
+        }
+
+        &self.items
+    }
+
+    fn pick(&self, indices: &[usize]) -> Vec<T> {
+        indices
+            .iter()
+            .map(|&index| self.items[index].clone())
+            .collect()
+    }
+}
+
+/// Advances `indices`, strictly increasing positions below `n`, to the next combination
+/// of the same size, returning `false` after the last.
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+    let Some(position) = (0..k).rev().find(|&i| indices[i] != i + n - k) else {
+        return false;
+    };
+
+    indices[position] += 1;
+
+    for i in position + 1..k {
+        indices[i] = indices[i - 1] + 1;
+    }
+
+    true
+}
+
+/// The iterator returned by [`CombinatoricsExt::permutations`].
+#[derive(Debug, Clone)]
+pub struct Permutations<I: Iterator> {
+    pool: Pool<I, I::Item>,
+    k: usize,
+    indices: Vec<usize>,
+    cycles: Vec<usize>,
+    started: bool,
+}
+
+impl<I: Iterator> Iterator for Permutations<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let n = self.pool.items().len();
+        let k = self.k;
+
+        if k > n {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            self.indices = (0..n).collect();
+            self.cycles = (n - k + 1..=n).rev().collect();
+            return Some(self.pool.pick(&self.indices[..k]));
+        }
+
+        // Each cycle counts down the choices left for its position; when one runs out,
+        // that position's tail is rotated back into order and the position before moves.
+        for i in (0..k).rev() {
+            self.cycles[i] -= 1;
+
+            if self.cycles[i] == 0 {
+                self.indices[i..].rotate_left(1);
+                self.cycles[i] = n - i;
+            } else {
+                let j = n - self.cycles[i];
+                self.indices.swap(i, j);
+                return Some(self.pool.pick(&self.indices[..k]));
+            }
+        }
+
+        self.k = n + 1;
+        None
+    }
+}
+
+/// The iterator returned by [`CombinatoricsExt::combinations`].
+#[derive(Debug, Clone)]
+pub struct Combinations<I: Iterator> {
+    pool: Pool<I, I::Item>,
+    indices: Vec<usize>,
+    started: bool,
+}
+
+impl<I: Iterator> Iterator for Combinations<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let n = self.pool.items().len();
+
+        if self.indices.len() > n {
+            return None;
+        }
+
+        if self.started {
+            if !next_combination(&mut self.indices, n) {
+                self.indices = (0..=n).collect();
+                return None;
+            }
+        } else {
+            self.started = true;
+        }
+
+        Some(self.pool.pick(&self.indices))
+    }
+}
+
+/// The iterator returned by [`CombinatoricsExt::combinations_with_replacement`].
+#[derive(Debug, Clone)]
+pub struct CombinationsWithReplacement<I: Iterator> {
+    pool: Pool<I, I::Item>,
+    indices: Vec<usize>,
+    started: bool,
+}
+
+impl<I: Iterator> Iterator for CombinationsWithReplacement<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let n = self.pool.items().len();
+
+        if n == 0 && !self.indices.is_empty() {
+            return None;
+        }
+
+        if !self.started {
+            self.started = true;
+            return Some(self.pool.pick(&self.indices));
+        }
+
+        let position = self.indices.iter().rposition(|&index| index + 1 < n);
+
+        let Some(position) = position else {
+            // A single index at the end can never advance, so the iterator stays finished.
+            self.indices = alloc::vec![n];
+            return None;
+        };
+
+        let next = self.indices[position] + 1;
+        self.indices[position..].fill(next);
+
+        Some(self.pool.pick(&self.indices))
+    }
+}
+
+/// The iterator returned by [`CombinatoricsExt::cartesian_product`].
+#[derive(Debug, Clone)]
+pub struct CartesianProduct<I: Iterator, J> {
+    first: I,
+    current: Option<I::Item>,
+    second: J,
+    original: J,
+}
+
+impl<I, J> Iterator for CartesianProduct<I, J>
+where
+    I: Iterator,
+    I::Item: Clone,
+    J: Iterator + Clone,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<(I::Item, J::Item)> {
+        loop {
+            if self.current.is_none() {
+                self.current = Some(self.first.next()?);
+            }
+
+            if let (Some(current), Some(item)) = (&self.current, self.second.next()) {
+                return Some((current.clone(), item));
+            }
+
+            self.current = None;
+            self.second = self.original.clone();
+        }
+    }
+}
+
+/// The iterator returned by [`CombinatoricsExt::powerset`].
+#[derive(Debug, Clone)]
+pub struct Powerset<I: Iterator> {
+    pool: Pool<I, I::Item>,
+    indices: Vec<usize>,
+    started: bool,
+}
+
+impl<I: Iterator> Iterator for Powerset<I>
+where
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>> {
+        let n = self.pool.items().len();
+
+        if self.indices.len() > n {
+            return None;
+        }
+
+        if self.started {
+            if !next_combination(&mut self.indices, n) {
+                self.indices = (0..=self.indices.len()).collect();
+
+                if self.indices.len() > n {
+                    return None;
+                }
+            }
+        } else {
+            self.started = true;
+        }
+
+        Some(self.pool.pick(&self.indices))
+    }
+}
+
+/// Returns `n!`, or `None` if `n` is negative or the result overflows `T`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::iter_ext::factorial;
+///
+/// assert_eq!(factorial(5u32), Some(120));
+/// assert_eq!(factorial(0u8), Some(1));
+/// assert_eq!(factorial(6u8), None);
+/// assert_eq!(factorial(-1i32), None);
+/// ```
+pub fn factorial<T: FixedWidthInteger>(n: T) -> Option<T> {
+    if n < T::ZERO {
+        return None;
+    }
+
+    let mut result = T::ONE;
+    let mut factor = T::ONE;
+
+    while factor < n {
+        factor += T::ONE;
+        result = checked(result.multiplied_reporting_overflow(factor))?;
+    }
+
+    Some(result)
+}
+
+/// Returns the number of ways to choose `k` of `n` items, or `None` if either is
+/// negative or the result overflows `T`.
+///
+/// Intermediate products are reduced as they go, so the result is found whenever it
+/// fits in `T`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::iter_ext::binomial;
+///
+/// assert_eq!(binomial(52u64, 5), Some(2_598_960));
+/// assert_eq!(binomial(3u8, 5), Some(0));
+/// assert_eq!(binomial(67u64, 33), Some(14_226_520_737_620_288_370));
+/// assert_eq!(binomial(68u64, 34), None);
+/// ```
+pub fn binomial<T: FixedWidthInteger>(n: T, k: T) -> Option<T> {
+    if n < T::ZERO || k < T::ZERO {
+        return None;
+    }
+
+    if k > n {
+        return Some(T::ZERO);
+    }
+
+    let k = if n - k < k { n - k } else { k };
+    let mut result = T::ONE;
+    let mut step = T::ZERO;
+
+    // After each step `result` is binomial(n - k + step, step), and the division below
+    // is exact because `step` divides `result * (n - k + step)`.
+    while step < k {
+        step += T::ONE;
+        let divisor = gcd(result, step);
+        let factor = (n - k + step) / (step / divisor);
+        result = checked((result / divisor).multiplied_reporting_overflow(factor))?;
+    }
+
+    Some(result)
+}
+
+fn checked<T>((value, overflow): (T, bool)) -> Option<T> {
+    (!overflow).then_some(value)
+}
+
+fn gcd<T: FixedWidthInteger>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        let remainder = a % b;
+        a = b;
+        b = remainder;
+    }
+
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+
+    use super::*;
+
+    fn count<I: Iterator>(iter: I) -> u64 {
+        iter.count() as u64
+    }
+
+    #[test]
+    fn test_counts_match_formulas() {
+        for n in 0..6u64 {
+            let items = || 0..n;
+
+            assert_eq!(count(items().powerset()), 1 << n);
+
+            for k in 0..7u64 {
+                let ordered = factorial(n).zip(factorial(n.saturating_sub(k)));
+                let expected = if k > n {
+                    0
+                } else {
+                    ordered.map_or(0, |(a, b)| a / b)
+                };
+                let k_usize = usize::try_from(k).expect("small");
+
+                assert_eq!(count(items().permutations(k_usize)), expected);
+                assert_eq!(
+                    count(items().combinations(k_usize)),
+                    binomial(n, k).unwrap_or(0)
+                );
+
+                let with_replacement = match (n, k) {
+                    (_, 0) => 1,
+                    (0, _) => 0,
+                    _ => binomial(n + k - 1, k).unwrap_or(0),
+                };
+                assert_eq!(
+                    count(items().combinations_with_replacement(k_usize)),
+                    with_replacement,
+                    "{n} {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_adapters_stay_finished_and_are_lazy() {
+        let mut permutations = [1, 2].into_iter().permutations(2);
+        assert_eq!(permutations.by_ref().count(), 2);
+        assert_eq!(permutations.next(), None);
+
+        let mut combinations = [1, 2].into_iter().combinations_with_replacement(1);
+        assert_eq!(combinations.by_ref().count(), 2);
+        assert_eq!(combinations.next(), None);
+
+        let mut reads = 0;
+        let source = [1, 2, 3].into_iter().inspect(|_| reads += 1);
+        let mut combinations = source.combinations(3);
+        assert_eq!(combinations.next(), Some(vec![1, 2, 3]));
+        assert_eq!(combinations.next(), None);
+        drop(combinations);
+        assert_eq!(reads, 3);
+
+        let mut empty = core::iter::empty::<u8>().cartesian_product([1, 2]);
+        assert_eq!(empty.next(), None);
+        assert_eq!((1..3).cartesian_product(Vec::<u8>::new()).count(), 0);
+    }
+
+    #[test]
+    fn test_factorial_and_binomial() {
+        assert_eq!(factorial(20u64), Some(2_432_902_008_176_640_000));
+        assert_eq!(factorial(21u64), None);
+        assert_eq!(factorial(5i8), Some(120));
+        assert_eq!(factorial(6i8), None);
+        assert_eq!(binomial(10i32, 0), Some(1));
+        assert_eq!(binomial(10i32, 10), Some(1));
+        assert_eq!(binomial(-3i32, 1), None);
+        assert_eq!(binomial(255u8, 1), Some(255));
+        assert_eq!(binomial(255u8, 2), None);
+        assert_eq!(
+            binomial(100u128, 50),
+            Some(100_891_344_545_564_193_334_812_497_256)
+        );
+    }
+}
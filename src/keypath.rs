@@ -0,0 +1,199 @@
+//! Key paths: first-class references to a field of a type.
+//!
+//! A [`KeyPath<Root, Value>`] reads and writes a `Value` inside any `Root`, which lets
+//! APIs such as [`List::sorted_by_keypath`](crate::collections::list::doubly_linked::List::sorted_by_keypath)
+//! take "the field to use" as an argument. Key paths are usually written with the
+//! [`keypath!`](macro@crate::keypath) macro and can be chained with
+//! [`appending`](KeyPath::appending).
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::keypath;
+//!
+//! struct Address {
+//!     city: &'static str,
+//! }
+//!
+//! struct Person {
+//!     name: &'static str,
+//!     address: Address,
+//! }
+//!
+//! let mut person = Person { name: "Ada", address: Address { city: "London" } };
+//!
+//! let city = keypath!(Person.address).appending(keypath!(Address.city));
+//! assert_eq!(*city.get(&person), "London");
+//!
+//! city.set(&mut person, "Paris");
+//! assert_eq!(person.address.city, "Paris");
+//! ```
+
+use alloc::rc::Rc;
+use core::fmt;
+
+type Getter<Root, Value> = dyn Fn(&Root) -> &Value;
+type MutGetter<Root, Value> = dyn Fn(&mut Root) -> &mut Value;
+
+/// A readable and writable path from a `Root` to one of its `Value`s.
+///
+/// Cloning a key path is cheap: the accessors are shared.
+pub struct KeyPath<Root: ?Sized, Value: ?Sized> {
+    get: Rc<Getter<Root, Value>>,
+    get_mut: Rc<MutGetter<Root, Value>>,
+}
+
+impl<Root: ?Sized, Value: ?Sized> KeyPath<Root, Value> {
+    /// Creates a key path from a pair of accessors.
+    ///
+    /// Both closures must project to the same place; the [`keypath!`](macro@crate::keypath)
+    /// macro generates them from a field path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::keypath::KeyPath;
+    ///
+    /// let first = KeyPath::<(u8, char), u8>::new(|pair| &pair.0, |pair| &mut pair.0);
+    /// assert_eq!(*first.get(&(7, 'x')), 7);
+    /// ```
+    pub fn new<G, M>(get: G, get_mut: M) -> Self
+    where
+        G: Fn(&Root) -> &Value + 'static,
+        M: Fn(&mut Root) -> &mut Value + 'static,
+    {
+        Self {
+            get: Rc::new(get),
+            get_mut: Rc::new(get_mut),
+        }
+    }
+
+    /// Returns a reference to the value at this path in `root`.
+    pub fn get<'a>(&self, root: &'a Root) -> &'a Value {
+        (self.get)(root)
+    }
+
+    /// Returns a mutable reference to the value at this path in `root`.
+    pub fn get_mut<'a>(&self, root: &'a mut Root) -> &'a mut Value {
+        (self.get_mut)(root)
+    }
+
+    /// Replaces the value at this path in `root`, returning the previous value.
+    pub fn set(&self, root: &mut Root, value: Value) -> Value
+    where
+        Value: Sized,
+    {
+        core::mem::replace(self.get_mut(root), value)
+    }
+
+    /// Returns a key path that follows `self` and then `next`.
+    #[must_use]
+    pub fn appending<Next>(self, next: KeyPath<Value, Next>) -> KeyPath<Root, Next>
+    where
+        Root: 'static,
+        Value: 'static,
+        Next: ?Sized + 'static,
+    {
+        let (get, get_mut) = (self.get, self.get_mut);
+        let (next_get, next_get_mut) = (next.get, next.get_mut);
+
+        KeyPath {
+            get: Rc::new(move |root| next_get(get(root))),
+            get_mut: Rc::new(move |root| next_get_mut(get_mut(root))),
+        }
+    }
+}
+
+impl<Root: ?Sized, Value: ?Sized> Clone for KeyPath<Root, Value> {
+    fn clone(&self) -> Self {
+        Self {
+            get: Rc::clone(&self.get),
+            get_mut: Rc::clone(&self.get_mut),
+        }
+    }
+}
+
+impl<Root: ?Sized, Value: ?Sized> fmt::Debug for KeyPath<Root, Value> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "KeyPath<{}, {}>",
+            core::any::type_name::<Root>(),
+            core::any::type_name::<Value>()
+        )
+    }
+}
+
+/// Creates a [`KeyPath`] from a type name and a chain of fields.
+///
+/// Tuple fields are written by index, as in `keypath!(Pair.0)`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::keypath;
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let y = keypath!(Point.y);
+/// let mut point = Point { x: 1, y: 2 };
+///
+/// *y.get_mut(&mut point) += 10;
+/// assert_eq!(point.y, 12);
+/// ```
+#[macro_export]
+macro_rules! keypath {
+    ($root:ident $(. $field:tt)+) => {
+        $crate::keypath::KeyPath::<$root, _>::new(
+            |root| &root $(.$field)+,
+            |root| &mut root $(.$field)+,
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Inner {
+        value: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Outer {
+        inner: Inner,
+        pair: (u8, u8),
+    }
+
+    #[test]
+    fn test_get_and_set() {
+        let mut outer = Outer {
+            inner: Inner { value: 1 },
+            pair: (2, 3),
+        };
+
+        let second = crate::keypath!(Outer.pair.1);
+        assert_eq!(*second.get(&outer), 3);
+        assert_eq!(second.set(&mut outer, 9), 3);
+        assert_eq!(outer.pair, (2, 9));
+    }
+
+    #[test]
+    fn test_appending() {
+        let mut outer = Outer {
+            inner: Inner { value: 5 },
+            pair: (0, 0),
+        };
+
+        let value = crate::keypath!(Outer.inner).appending(crate::keypath!(Inner.value));
+        let copy = value.clone();
+
+        *value.get_mut(&mut outer) *= 2;
+        assert_eq!(*copy.get(&outer), 10);
+        assert!(format!("{value:?}").starts_with("KeyPath<"));
+    }
+}
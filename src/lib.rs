@@ -1,5 +1,9 @@
 #![no_std]
-#![feature(const_trait_impl, decl_macro, portable_simd)]
+#![cfg_attr(
+    feature = "nightly",
+    feature(const_trait_impl, decl_macro, portable_simd)
+)]
+#![deny(unsafe_op_in_unsafe_fn)]
 #![warn(
     clippy::pedantic,
     clippy::nursery,
@@ -9,6 +13,55 @@
 
 extern crate alloc;
 extern crate core;
+#[cfg(feature = "std")]
+extern crate std;
 
+pub mod calendar;
+pub mod ciphers;
+pub mod cli;
 pub mod collections;
+pub mod color;
+pub mod compression;
+pub mod config;
+pub mod encoding;
+pub mod env;
+pub mod errors;
+pub mod expr;
+pub mod formatting;
+pub mod func;
+pub mod geo;
+pub mod hashing;
+pub mod humanize;
+pub mod identity;
+pub mod iter_ext;
+pub mod keypath;
+pub mod locale;
+pub mod logging;
+#[cfg(target_has_atomic = "64")]
+pub mod metrics;
+pub mod mime;
+pub mod money;
+pub mod net;
 pub mod num;
+pub mod option_set;
+pub mod packing;
+#[cfg(feature = "threads")]
+pub mod parallel;
+pub mod path;
+pub mod random;
+pub mod retry;
+pub mod security;
+pub mod sort;
+#[cfg(feature = "stats")]
+pub mod stats;
+pub mod sync;
+pub mod terminal;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod text;
+pub mod time;
+pub mod units;
+pub mod url;
+pub mod uuid;
+pub mod version;
+pub mod xml;
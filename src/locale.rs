@@ -0,0 +1,242 @@
+//! Locale identifiers and the static tables that describe them.
+//!
+//! A [`Locale`] is a lightweight wrapper around an identifier such as `en_US` or
+//! `pt-BR`. Reference data about currencies, languages and regions is compiled into the
+//! crate as `&'static` tables, so looking it up never allocates.
+
+pub mod currency;
+pub mod language;
+pub mod numbers;
+pub mod plural;
+pub mod region;
+
+pub use currency::Currency;
+pub use language::{Language, TextDirection};
+pub use numbers::{CompactUnit, NumberSymbols, NumberingSystem};
+pub use plural::{PluralCategory, PluralRules};
+pub use region::Region;
+
+use alloc::string::String;
+use core::fmt;
+
+use crate::env::Environment;
+#[cfg(unix)]
+use crate::env::SystemEnvironment;
+
+/// A locale identifier made of a language code and optional script and region subtags.
+///
+/// Both `_` and `-` are accepted as separators; the identifier is stored with `_`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::Locale;
+///
+/// let locale = Locale::new("zh-Hant-TW");
+///
+/// assert_eq!(locale.identifier(), "zh_Hant_TW");
+/// assert_eq!(locale.language_code(), "zh");
+/// assert_eq!(locale.script_code(), Some("Hant"));
+/// assert_eq!(locale.region_code(), Some("TW"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    identifier: String,
+}
+
+impl Locale {
+    /// Creates a locale from an identifier such as `en_US` or `fr-CA`.
+    #[must_use]
+    pub fn new(identifier: &str) -> Self {
+        Self {
+            identifier: identifier.replace('-', "_"),
+        }
+    }
+
+    /// Returns the locale named by the `LC_ALL`, `LC_MESSAGES` or `LANG` variable of
+    /// `env`, in that order of precedence.
+    ///
+    /// Encodings and modifiers such as `.UTF-8` and `@euro` are dropped. Variables that
+    /// are empty or name the `C` or `POSIX` locale are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::{env::MockEnvironment, locale::Locale};
+    ///
+    /// let env = MockEnvironment::new()
+    ///     .with_var("LANG", "de_DE.UTF-8")
+    ///     .with_var("LC_ALL", "C");
+    ///
+    /// assert_eq!(Locale::from_environment(&env), Some(Locale::new("de_DE")));
+    /// assert_eq!(Locale::from_environment(&MockEnvironment::new()), None);
+    /// ```
+    #[must_use]
+    pub fn from_environment<E: Environment + ?Sized>(env: &E) -> Option<Self> {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .find_map(|name| {
+                let value = env.var(name)?;
+                let identifier = value.split(['.', '@']).next().unwrap_or_default().trim();
+
+                (!matches!(identifier, "" | "C" | "POSIX")).then(|| Self::new(identifier))
+            })
+    }
+
+    /// Returns the locale of the current process, as read by
+    /// [`from_environment`](Self::from_environment) from the process environment.
+    #[cfg(unix)]
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        Self::from_environment(&SystemEnvironment)
+    }
+
+    /// Returns the identifier of the locale, using `_` as the separator.
+    #[must_use]
+    pub fn identifier(&self) -> &str {
+        &self.identifier
+    }
+
+    /// Returns the language code, the first subtag of the identifier.
+    #[must_use]
+    pub fn language_code(&self) -> &str {
+        self.subtags().next().unwrap_or_default()
+    }
+
+    /// Returns the language of the locale, if its language code is known.
+    #[must_use]
+    pub fn language(&self) -> Option<Language> {
+        Language::from_code(self.language_code())
+    }
+
+    /// Returns the direction text in this locale is laid out in.
+    ///
+    /// An explicit script subtag takes precedence over the language's usual script, and
+    /// unknown locales fall back to left-to-right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::{Locale, TextDirection};
+    ///
+    /// assert_eq!(Locale::new("ar_EG").text_direction(), TextDirection::RightToLeft);
+    /// assert_eq!(Locale::new("pa_Arab_PK").text_direction(), TextDirection::RightToLeft);
+    /// assert_eq!(Locale::new("ku_Latn").text_direction(), TextDirection::LeftToRight);
+    /// ```
+    #[must_use]
+    pub fn text_direction(&self) -> TextDirection {
+        self.script_code()
+            .and_then(TextDirection::for_script)
+            .or_else(|| self.language().map(|language| language.text_direction()))
+            .unwrap_or(TextDirection::LeftToRight)
+    }
+
+    /// Returns the four-letter script code, if the identifier has one.
+    #[must_use]
+    pub fn script_code(&self) -> Option<&str> {
+        self.subtags()
+            .skip(1)
+            .find(|tag| tag.len() == 4 && tag.bytes().all(|b| b.is_ascii_alphabetic()))
+    }
+
+    /// Returns the region code, if the identifier has one.
+    ///
+    /// Region subtags are either two letters (ISO 3166-1) or three digits (UN M49).
+    #[must_use]
+    pub fn region_code(&self) -> Option<&str> {
+        self.subtags().skip(1).find(|tag| {
+            (tag.len() == 2 && tag.bytes().all(|b| b.is_ascii_alphabetic()))
+                || (tag.len() == 3 && tag.bytes().all(|b| b.is_ascii_digit()))
+        })
+    }
+
+    /// Returns the region of the locale, if its region code is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::Locale;
+    ///
+    /// let region = Locale::new("fr_CA").region();
+    ///
+    /// assert_eq!(region.map(|r| r.name()), Some("Canada"));
+    /// assert_eq!(Locale::new("es_419").region().map(|r| r.name()), Some("Latin America and the Caribbean"));
+    /// ```
+    #[must_use]
+    pub fn region(&self) -> Option<Region> {
+        self.region_code().and_then(Region::from_code)
+    }
+
+    /// Returns the ISO 4217 codes of the currencies in common use.
+    ///
+    /// The list is sorted and static, so calling this never allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::Locale;
+    ///
+    /// let codes = Locale::common_iso_currency_codes();
+    ///
+    /// assert!(codes.contains(&"EUR"));
+    /// assert!(codes.is_sorted());
+    /// ```
+    #[must_use]
+    pub const fn common_iso_currency_codes() -> &'static [&'static str] {
+        currency::COMMON_ISO_CURRENCY_CODES
+    }
+
+    fn subtags(&self) -> impl Iterator<Item = &str> {
+        self.identifier.split('_').filter(|tag| !tag.is_empty())
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.identifier)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subtags() {
+        let locale = Locale::new("en-US");
+
+        assert_eq!(locale.identifier(), "en_US");
+        assert_eq!(locale.language_code(), "en");
+        assert_eq!(locale.script_code(), None);
+        assert_eq!(locale.region_code(), Some("US"));
+
+        assert_eq!(Locale::new("es_419").region_code(), Some("419"));
+        assert_eq!(Locale::new("fr").region_code(), None);
+        assert_eq!(Locale::new("").language_code(), "");
+    }
+
+    #[test]
+    fn test_common_currency_codes_are_static() {
+        let first = Locale::common_iso_currency_codes();
+        let second = Locale::common_iso_currency_codes();
+
+        assert!(core::ptr::eq(first, second));
+        assert!(first.is_sorted());
+    }
+
+    #[test]
+    fn test_from_environment_precedence() {
+        use crate::env::MockEnvironment;
+
+        let env = MockEnvironment::new()
+            .with_var("LANG", "en_US.UTF-8")
+            .with_var("LC_MESSAGES", "fr_CA@euro");
+        assert_eq!(Locale::from_environment(&env), Some(Locale::new("fr_CA")));
+
+        let env = env.with_var("LC_ALL", "pt-BR");
+        assert_eq!(Locale::from_environment(&env), Some(Locale::new("pt_BR")));
+
+        let env = MockEnvironment::new().with_var("LANG", "POSIX");
+        assert_eq!(Locale::from_environment(&env), None);
+    }
+}
@@ -0,0 +1,365 @@
+//! ISO 4217 currencies and their metadata.
+//!
+//! Every active currency is described by a [`Currency`] in a single static table, which
+//! backs both the code list returned by
+//! [`Locale::common_iso_currency_codes`](super::Locale::common_iso_currency_codes) and
+//! the lookups below.
+
+use core::fmt;
+
+/// An ISO 4217 currency.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::currency::Currency;
+///
+/// let yen = Currency::from_code("JPY").unwrap();
+///
+/// assert_eq!(yen.name(), "Yen");
+/// assert_eq!(yen.symbol(), "¥");
+/// assert_eq!(yen.numeric_code(), 392);
+/// assert_eq!(yen.minor_units(), 0);
+/// assert_eq!(Currency::from_numeric_code(392), Some(yen));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    numeric_code: u16,
+    minor_units: u8,
+    name: &'static str,
+    symbol: &'static str,
+}
+
+impl Currency {
+    const fn new(
+        code: &'static str,
+        numeric_code: u16,
+        minor_units: u8,
+        name: &'static str,
+        symbol: &'static str,
+    ) -> Self {
+        Self {
+            code,
+            numeric_code,
+            minor_units,
+            name,
+            symbol,
+        }
+    }
+
+    /// Returns every currency in active use, sorted by code.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        CURRENCIES
+    }
+
+    /// Looks up a currency by its three-letter code.
+    ///
+    /// The comparison is case-sensitive, as ISO 4217 codes are upper case.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        CURRENCIES
+            .binary_search_by(|currency| currency.code.cmp(code))
+            .ok()
+            .map(|index| CURRENCIES[index])
+    }
+
+    /// Looks up a currency by its three-digit numeric code.
+    #[must_use]
+    pub fn from_numeric_code(numeric_code: u16) -> Option<Self> {
+        CURRENCIES
+            .iter()
+            .find(|currency| currency.numeric_code == numeric_code)
+            .copied()
+    }
+
+    /// Returns the three-letter code, such as `"EUR"`.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the three-digit numeric code, such as `978` for the euro.
+    #[must_use]
+    pub const fn numeric_code(&self) -> u16 {
+        self.numeric_code
+    }
+
+    /// Returns the number of digits after the decimal separator in amounts of this
+    /// currency.
+    #[must_use]
+    pub const fn minor_units(&self) -> u8 {
+        self.minor_units
+    }
+
+    /// Returns the English name of the currency as published in ISO 4217.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the symbol used for the currency in English-language locales.
+    ///
+    /// Currencies without a widely recognised symbol use their code.
+    #[must_use]
+    pub const fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+static CURRENCIES: &[Currency] = &[
+    Currency::new("AED", 784, 2, "UAE Dirham", "AED"),
+    Currency::new("AFN", 971, 2, "Afghani", "AFN"),
+    Currency::new("ALL", 8, 2, "Lek", "ALL"),
+    Currency::new("AMD", 51, 2, "Armenian Dram", "AMD"),
+    Currency::new("AOA", 973, 2, "Kwanza", "AOA"),
+    Currency::new("ARS", 32, 2, "Argentine Peso", "ARS"),
+    Currency::new("AUD", 36, 2, "Australian Dollar", "A$"),
+    Currency::new("AWG", 533, 2, "Aruban Florin", "AWG"),
+    Currency::new("AZN", 944, 2, "Azerbaijan Manat", "AZN"),
+    Currency::new("BAM", 977, 2, "Convertible Mark", "BAM"),
+    Currency::new("BBD", 52, 2, "Barbados Dollar", "BBD"),
+    Currency::new("BDT", 50, 2, "Taka", "BDT"),
+    Currency::new("BGN", 975, 2, "Bulgarian Lev", "BGN"),
+    Currency::new("BHD", 48, 3, "Bahraini Dinar", "BHD"),
+    Currency::new("BIF", 108, 0, "Burundi Franc", "BIF"),
+    Currency::new("BMD", 60, 2, "Bermudian Dollar", "BMD"),
+    Currency::new("BND", 96, 2, "Brunei Dollar", "BND"),
+    Currency::new("BOB", 68, 2, "Boliviano", "BOB"),
+    Currency::new("BRL", 986, 2, "Brazilian Real", "R$"),
+    Currency::new("BSD", 44, 2, "Bahamian Dollar", "BSD"),
+    Currency::new("BTN", 64, 2, "Ngultrum", "BTN"),
+    Currency::new("BWP", 72, 2, "Pula", "BWP"),
+    Currency::new("BYN", 933, 2, "Belarusian Ruble", "BYN"),
+    Currency::new("BZD", 84, 2, "Belize Dollar", "BZD"),
+    Currency::new("CAD", 124, 2, "Canadian Dollar", "CA$"),
+    Currency::new("CDF", 976, 2, "Congolese Franc", "CDF"),
+    Currency::new("CHF", 756, 2, "Swiss Franc", "CHF"),
+    Currency::new("CLP", 152, 0, "Chilean Peso", "CLP"),
+    Currency::new("CNY", 156, 2, "Yuan Renminbi", "CN¥"),
+    Currency::new("COP", 170, 2, "Colombian Peso", "COP"),
+    Currency::new("CRC", 188, 2, "Costa Rican Colon", "CRC"),
+    Currency::new("CUP", 192, 2, "Cuban Peso", "CUP"),
+    Currency::new("CVE", 132, 2, "Cabo Verde Escudo", "CVE"),
+    Currency::new("CZK", 203, 2, "Czech Koruna", "CZK"),
+    Currency::new("DJF", 262, 0, "Djibouti Franc", "DJF"),
+    Currency::new("DKK", 208, 2, "Danish Krone", "DKK"),
+    Currency::new("DOP", 214, 2, "Dominican Peso", "DOP"),
+    Currency::new("DZD", 12, 2, "Algerian Dinar", "DZD"),
+    Currency::new("EGP", 818, 2, "Egyptian Pound", "EGP"),
+    Currency::new("ERN", 232, 2, "Nakfa", "ERN"),
+    Currency::new("ETB", 230, 2, "Ethiopian Birr", "ETB"),
+    Currency::new("EUR", 978, 2, "Euro", "€"),
+    Currency::new("FJD", 242, 2, "Fiji Dollar", "FJD"),
+    Currency::new("FKP", 238, 2, "Falkland Islands Pound", "FKP"),
+    Currency::new("GBP", 826, 2, "Pound Sterling", "£"),
+    Currency::new("GEL", 981, 2, "Lari", "GEL"),
+    Currency::new("GHS", 936, 2, "Ghana Cedi", "GHS"),
+    Currency::new("GIP", 292, 2, "Gibraltar Pound", "GIP"),
+    Currency::new("GMD", 270, 2, "Dalasi", "GMD"),
+    Currency::new("GNF", 324, 0, "Guinean Franc", "GNF"),
+    Currency::new("GTQ", 320, 2, "Quetzal", "GTQ"),
+    Currency::new("GYD", 328, 2, "Guyana Dollar", "GYD"),
+    Currency::new("HKD", 344, 2, "Hong Kong Dollar", "HK$"),
+    Currency::new("HNL", 340, 2, "Lempira", "HNL"),
+    Currency::new("HTG", 332, 2, "Gourde", "HTG"),
+    Currency::new("HUF", 348, 2, "Forint", "HUF"),
+    Currency::new("IDR", 360, 2, "Rupiah", "IDR"),
+    Currency::new("ILS", 376, 2, "New Israeli Sheqel", "₪"),
+    Currency::new("INR", 356, 2, "Indian Rupee", "₹"),
+    Currency::new("IQD", 368, 3, "Iraqi Dinar", "IQD"),
+    Currency::new("IRR", 364, 2, "Iranian Rial", "IRR"),
+    Currency::new("ISK", 352, 0, "Iceland Krona", "ISK"),
+    Currency::new("JMD", 388, 2, "Jamaican Dollar", "JMD"),
+    Currency::new("JOD", 400, 3, "Jordanian Dinar", "JOD"),
+    Currency::new("JPY", 392, 0, "Yen", "¥"),
+    Currency::new("KES", 404, 2, "Kenyan Shilling", "KES"),
+    Currency::new("KGS", 417, 2, "Som", "KGS"),
+    Currency::new("KHR", 116, 2, "Riel", "KHR"),
+    Currency::new("KMF", 174, 0, "Comorian Franc", "KMF"),
+    Currency::new("KPW", 408, 2, "North Korean Won", "KPW"),
+    Currency::new("KRW", 410, 0, "Won", "₩"),
+    Currency::new("KWD", 414, 3, "Kuwaiti Dinar", "KWD"),
+    Currency::new("KYD", 136, 2, "Cayman Islands Dollar", "KYD"),
+    Currency::new("KZT", 398, 2, "Tenge", "KZT"),
+    Currency::new("LAK", 418, 2, "Lao Kip", "LAK"),
+    Currency::new("LBP", 422, 2, "Lebanese Pound", "LBP"),
+    Currency::new("LKR", 144, 2, "Sri Lanka Rupee", "LKR"),
+    Currency::new("LRD", 430, 2, "Liberian Dollar", "LRD"),
+    Currency::new("LSL", 426, 2, "Loti", "LSL"),
+    Currency::new("LYD", 434, 3, "Libyan Dinar", "LYD"),
+    Currency::new("MAD", 504, 2, "Moroccan Dirham", "MAD"),
+    Currency::new("MDL", 498, 2, "Moldovan Leu", "MDL"),
+    Currency::new("MGA", 969, 2, "Malagasy Ariary", "MGA"),
+    Currency::new("MKD", 807, 2, "Denar", "MKD"),
+    Currency::new("MMK", 104, 2, "Kyat", "MMK"),
+    Currency::new("MNT", 496, 2, "Tugrik", "MNT"),
+    Currency::new("MOP", 446, 2, "Pataca", "MOP"),
+    Currency::new("MRU", 929, 2, "Ouguiya", "MRU"),
+    Currency::new("MUR", 480, 2, "Mauritius Rupee", "MUR"),
+    Currency::new("MVR", 462, 2, "Rufiyaa", "MVR"),
+    Currency::new("MWK", 454, 2, "Malawi Kwacha", "MWK"),
+    Currency::new("MXN", 484, 2, "Mexican Peso", "MX$"),
+    Currency::new("MYR", 458, 2, "Malaysian Ringgit", "MYR"),
+    Currency::new("MZN", 943, 2, "Mozambique Metical", "MZN"),
+    Currency::new("NAD", 516, 2, "Namibia Dollar", "NAD"),
+    Currency::new("NGN", 566, 2, "Naira", "NGN"),
+    Currency::new("NIO", 558, 2, "Cordoba Oro", "NIO"),
+    Currency::new("NOK", 578, 2, "Norwegian Krone", "NOK"),
+    Currency::new("NPR", 524, 2, "Nepalese Rupee", "NPR"),
+    Currency::new("NZD", 554, 2, "New Zealand Dollar", "NZ$"),
+    Currency::new("OMR", 512, 3, "Rial Omani", "OMR"),
+    Currency::new("PAB", 590, 2, "Balboa", "PAB"),
+    Currency::new("PEN", 604, 2, "Sol", "PEN"),
+    Currency::new("PGK", 598, 2, "Kina", "PGK"),
+    Currency::new("PHP", 608, 2, "Philippine Peso", "₱"),
+    Currency::new("PKR", 586, 2, "Pakistan Rupee", "PKR"),
+    Currency::new("PLN", 985, 2, "Zloty", "PLN"),
+    Currency::new("PYG", 600, 0, "Guarani", "PYG"),
+    Currency::new("QAR", 634, 2, "Qatari Rial", "QAR"),
+    Currency::new("RON", 946, 2, "Romanian Leu", "RON"),
+    Currency::new("RSD", 941, 2, "Serbian Dinar", "RSD"),
+    Currency::new("RUB", 643, 2, "Russian Ruble", "RUB"),
+    Currency::new("RWF", 646, 0, "Rwanda Franc", "RWF"),
+    Currency::new("SAR", 682, 2, "Saudi Riyal", "SAR"),
+    Currency::new("SBD", 90, 2, "Solomon Islands Dollar", "SBD"),
+    Currency::new("SCR", 690, 2, "Seychelles Rupee", "SCR"),
+    Currency::new("SDG", 938, 2, "Sudanese Pound", "SDG"),
+    Currency::new("SEK", 752, 2, "Swedish Krona", "SEK"),
+    Currency::new("SGD", 702, 2, "Singapore Dollar", "SGD"),
+    Currency::new("SHP", 654, 2, "Saint Helena Pound", "SHP"),
+    Currency::new("SLE", 925, 2, "Leone", "SLE"),
+    Currency::new("SOS", 706, 2, "Somali Shilling", "SOS"),
+    Currency::new("SRD", 968, 2, "Surinam Dollar", "SRD"),
+    Currency::new("SSP", 728, 2, "South Sudanese Pound", "SSP"),
+    Currency::new("STN", 930, 2, "Dobra", "STN"),
+    Currency::new("SVC", 222, 2, "El Salvador Colon", "SVC"),
+    Currency::new("SYP", 760, 2, "Syrian Pound", "SYP"),
+    Currency::new("SZL", 748, 2, "Lilangeni", "SZL"),
+    Currency::new("THB", 764, 2, "Baht", "THB"),
+    Currency::new("TJS", 972, 2, "Somoni", "TJS"),
+    Currency::new("TMT", 934, 2, "Turkmenistan New Manat", "TMT"),
+    Currency::new("TND", 788, 3, "Tunisian Dinar", "TND"),
+    Currency::new("TOP", 776, 2, "Pa'anga", "TOP"),
+    Currency::new("TRY", 949, 2, "Turkish Lira", "TRY"),
+    Currency::new("TTD", 780, 2, "Trinidad and Tobago Dollar", "TTD"),
+    Currency::new("TWD", 901, 2, "New Taiwan Dollar", "NT$"),
+    Currency::new("TZS", 834, 2, "Tanzanian Shilling", "TZS"),
+    Currency::new("UAH", 980, 2, "Hryvnia", "UAH"),
+    Currency::new("UGX", 800, 0, "Uganda Shilling", "UGX"),
+    Currency::new("USD", 840, 2, "US Dollar", "$"),
+    Currency::new("UYU", 858, 2, "Peso Uruguayo", "UYU"),
+    Currency::new("UZS", 860, 2, "Uzbekistan Sum", "UZS"),
+    Currency::new("VED", 926, 2, "Bolívar Soberano", "VED"),
+    Currency::new("VES", 928, 2, "Bolívar Soberano", "VES"),
+    Currency::new("VND", 704, 0, "Dong", "₫"),
+    Currency::new("VUV", 548, 0, "Vatu", "VUV"),
+    Currency::new("WST", 882, 2, "Tala", "WST"),
+    Currency::new("XAF", 950, 0, "CFA Franc BEAC", "FCFA"),
+    Currency::new("XCD", 951, 2, "East Caribbean Dollar", "EC$"),
+    Currency::new("XCG", 532, 2, "Caribbean Guilder", "XCG"),
+    Currency::new("XOF", 952, 0, "CFA Franc BCEAO", "F CFA"),
+    Currency::new("XPF", 953, 0, "CFP Franc", "CFPF"),
+    Currency::new("YER", 886, 2, "Yemeni Rial", "YER"),
+    Currency::new("ZAR", 710, 2, "Rand", "ZAR"),
+    Currency::new("ZMW", 967, 2, "Zambian Kwacha", "ZMW"),
+    Currency::new("ZWG", 924, 2, "Zimbabwe Gold", "ZWG"),
+];
+
+const CODES: [&str; CURRENCIES.len()] = {
+    let mut codes = [""; CURRENCIES.len()];
+    let mut index = 0;
+
+    while index < codes.len() {
+        codes[index] = CURRENCIES[index].code;
+        index += 1;
+    }
+
+    codes
+};
+
+/// The ISO 4217 codes of currencies in active use, sorted alphabetically.
+///
+/// Funds codes, precious metals and testing codes (such as `XAU` and `XTS`) are not
+/// included.
+pub static COMMON_ISO_CURRENCY_CODES: &[&str] = &CODES;
+
+/// Returns `true` if `code` is a currency code in [`COMMON_ISO_CURRENCY_CODES`].
+///
+/// The comparison is case-sensitive, as ISO 4217 codes are upper case.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::currency::is_valid_currency_code;
+///
+/// assert!(is_valid_currency_code("JPY"));
+/// assert!(!is_valid_currency_code("jpy"));
+/// assert!(!is_valid_currency_code("XYZ"));
+/// ```
+#[must_use]
+pub fn is_valid_currency_code(code: &str) -> bool {
+    Currency::from_code(code).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn test_codes_are_sorted_and_well_formed() {
+        assert!(COMMON_ISO_CURRENCY_CODES.is_sorted());
+        assert!(COMMON_ISO_CURRENCY_CODES.windows(2).all(|w| w[0] != w[1]));
+        assert!(
+            COMMON_ISO_CURRENCY_CODES
+                .iter()
+                .all(|code| code.len() == 3 && code.bytes().all(|b| b.is_ascii_uppercase()))
+        );
+    }
+
+    #[test]
+    fn test_is_valid_currency_code() {
+        assert!(is_valid_currency_code("AED"));
+        assert!(is_valid_currency_code("ZWG"));
+        assert!(!is_valid_currency_code(""));
+        assert!(!is_valid_currency_code("XTS"));
+    }
+
+    #[test]
+    fn test_numeric_codes_are_unique() {
+        for (index, currency) in CURRENCIES.iter().enumerate() {
+            assert!(currency.numeric_code < 1000);
+            assert!(
+                CURRENCIES[index + 1..]
+                    .iter()
+                    .all(|other| other.numeric_code != currency.numeric_code)
+            );
+            assert_eq!(
+                Currency::from_numeric_code(currency.numeric_code),
+                Some(*currency)
+            );
+        }
+    }
+
+    #[test]
+    fn test_metadata() {
+        let dinar = Currency::from_code("KWD");
+        assert_eq!(dinar.map(|c| c.minor_units()), Some(3));
+
+        let euro = Currency::from_numeric_code(978);
+        assert_eq!(euro.map(|c| (c.code(), c.symbol())), Some(("EUR", "€")));
+        assert_eq!(euro.map(|c| format!("{c}")).as_deref(), Some("EUR"));
+
+        assert_eq!(Currency::from_code("usd"), None);
+        assert_eq!(Currency::from_numeric_code(0), None);
+    }
+}
@@ -0,0 +1,369 @@
+//! ISO 639 languages and their writing direction.
+
+use core::fmt;
+
+use TextDirection::{LeftToRight, RightToLeft};
+
+/// The direction in which a language's text is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextDirection {
+    /// Text runs from left to right, as in English.
+    LeftToRight,
+
+    /// Text runs from right to left, as in Arabic and Hebrew.
+    RightToLeft,
+}
+
+impl TextDirection {
+    /// Returns the direction of text written in the given ISO 15924 script.
+    ///
+    /// Returns `None` for scripts the crate does not know the direction of.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::language::TextDirection;
+    ///
+    /// assert_eq!(TextDirection::for_script("Arab"), Some(TextDirection::RightToLeft));
+    /// assert_eq!(TextDirection::for_script("Cyrl"), Some(TextDirection::LeftToRight));
+    /// ```
+    #[must_use]
+    pub fn for_script(script: &str) -> Option<Self> {
+        const RIGHT_TO_LEFT: &[&str] = &["Adlm", "Arab", "Hebr", "Nkoo", "Rohg", "Syrc", "Thaa"];
+        const LEFT_TO_RIGHT: &[&str] = &[
+            "Armn", "Beng", "Cyrl", "Deva", "Ethi", "Geor", "Grek", "Gujr", "Guru", "Hang", "Hani",
+            "Hans", "Hant", "Hira", "Jpan", "Kana", "Khmr", "Knda", "Kore", "Laoo", "Latn", "Mlym",
+            "Mong", "Mymr", "Orya", "Sinh", "Taml", "Telu", "Thai", "Tibt",
+        ];
+
+        if RIGHT_TO_LEFT.iter().any(|s| s.eq_ignore_ascii_case(script)) {
+            Some(RightToLeft)
+        } else if LEFT_TO_RIGHT.iter().any(|s| s.eq_ignore_ascii_case(script)) {
+            Some(LeftToRight)
+        } else {
+            None
+        }
+    }
+}
+
+/// A language with an ISO 639-1 code.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::language::{Language, TextDirection};
+///
+/// let hebrew = Language::from_code("he").unwrap();
+///
+/// assert_eq!(hebrew.alpha3_code(), "heb");
+/// assert_eq!(hebrew.name(), "Hebrew");
+/// assert_eq!(hebrew.text_direction(), TextDirection::RightToLeft);
+/// assert_eq!(Language::from_code("heb"), Some(hebrew));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Language {
+    alpha2_code: &'static str,
+    alpha3_code: &'static str,
+    name: &'static str,
+    text_direction: TextDirection,
+}
+
+impl Language {
+    const fn new(
+        alpha2_code: &'static str,
+        alpha3_code: &'static str,
+        name: &'static str,
+        text_direction: TextDirection,
+    ) -> Self {
+        Self {
+            alpha2_code,
+            alpha3_code,
+            name,
+            text_direction,
+        }
+    }
+
+    /// Returns every language with an ISO 639-1 code, sorted by that code.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        LANGUAGES
+    }
+
+    /// Looks up a language by its ISO 639-1 or ISO 639-3 code.
+    ///
+    /// Codes are matched case-insensitively.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let bytes = code.as_bytes();
+
+        match bytes.len() {
+            2 => LANGUAGES
+                .binary_search_by(|language| {
+                    language
+                        .alpha2_code
+                        .bytes()
+                        .cmp(bytes.iter().map(u8::to_ascii_lowercase))
+                })
+                .ok()
+                .map(|index| LANGUAGES[index]),
+            3 => LANGUAGES
+                .iter()
+                .find(|language| language.alpha3_code.eq_ignore_ascii_case(code))
+                .copied(),
+            _ => None,
+        }
+    }
+
+    /// Returns the two-letter ISO 639-1 code, such as `"en"`.
+    #[must_use]
+    pub const fn alpha2_code(&self) -> &'static str {
+        self.alpha2_code
+    }
+
+    /// Returns the three-letter ISO 639-3 code, such as `"eng"`.
+    #[must_use]
+    pub const fn alpha3_code(&self) -> &'static str {
+        self.alpha3_code
+    }
+
+    /// Returns the English name of the language.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the direction the language is usually written in.
+    ///
+    /// Languages written in several scripts report the direction of their most common
+    /// one; [`Locale::text_direction`](super::Locale::text_direction) also takes the
+    /// script subtag into account.
+    #[must_use]
+    pub const fn text_direction(&self) -> TextDirection {
+        self.text_direction
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.alpha2_code)
+    }
+}
+
+static LANGUAGES: &[Language] = &[
+    Language::new("aa", "aar", "Afar", LeftToRight),
+    Language::new("ab", "abk", "Abkhazian", LeftToRight),
+    Language::new("ae", "ave", "Avestan", LeftToRight),
+    Language::new("af", "afr", "Afrikaans", LeftToRight),
+    Language::new("ak", "aka", "Akan", LeftToRight),
+    Language::new("am", "amh", "Amharic", LeftToRight),
+    Language::new("an", "arg", "Aragonese", LeftToRight),
+    Language::new("ar", "ara", "Arabic", RightToLeft),
+    Language::new("as", "asm", "Assamese", LeftToRight),
+    Language::new("av", "ava", "Avaric", LeftToRight),
+    Language::new("ay", "aym", "Aymara", LeftToRight),
+    Language::new("az", "aze", "Azerbaijani", LeftToRight),
+    Language::new("ba", "bak", "Bashkir", LeftToRight),
+    Language::new("be", "bel", "Belarusian", LeftToRight),
+    Language::new("bg", "bul", "Bulgarian", LeftToRight),
+    Language::new("bi", "bis", "Bislama", LeftToRight),
+    Language::new("bm", "bam", "Bambara", LeftToRight),
+    Language::new("bn", "ben", "Bangla", LeftToRight),
+    Language::new("bo", "bod", "Tibetan", LeftToRight),
+    Language::new("br", "bre", "Breton", LeftToRight),
+    Language::new("bs", "bos", "Bosnian", LeftToRight),
+    Language::new("ca", "cat", "Catalan", LeftToRight),
+    Language::new("ce", "che", "Chechen", LeftToRight),
+    Language::new("ch", "cha", "Chamorro", LeftToRight),
+    Language::new("co", "cos", "Corsican", LeftToRight),
+    Language::new("cr", "cre", "Cree", LeftToRight),
+    Language::new("cs", "ces", "Czech", LeftToRight),
+    Language::new("cu", "chu", "Church Slavic", LeftToRight),
+    Language::new("cv", "chv", "Chuvash", LeftToRight),
+    Language::new("cy", "cym", "Welsh", LeftToRight),
+    Language::new("da", "dan", "Danish", LeftToRight),
+    Language::new("de", "deu", "German", LeftToRight),
+    Language::new("dv", "div", "Dhivehi", RightToLeft),
+    Language::new("dz", "dzo", "Dzongkha", LeftToRight),
+    Language::new("ee", "ewe", "Ewe", LeftToRight),
+    Language::new("el", "ell", "Greek", LeftToRight),
+    Language::new("en", "eng", "English", LeftToRight),
+    Language::new("eo", "epo", "Esperanto", LeftToRight),
+    Language::new("es", "spa", "Spanish", LeftToRight),
+    Language::new("et", "est", "Estonian", LeftToRight),
+    Language::new("eu", "eus", "Basque", LeftToRight),
+    Language::new("fa", "fas", "Persian", RightToLeft),
+    Language::new("ff", "ful", "Fulah", LeftToRight),
+    Language::new("fi", "fin", "Finnish", LeftToRight),
+    Language::new("fj", "fij", "Fijian", LeftToRight),
+    Language::new("fo", "fao", "Faroese", LeftToRight),
+    Language::new("fr", "fra", "French", LeftToRight),
+    Language::new("fy", "fry", "Western Frisian", LeftToRight),
+    Language::new("ga", "gle", "Irish", LeftToRight),
+    Language::new("gd", "gla", "Scottish Gaelic", LeftToRight),
+    Language::new("gl", "glg", "Galician", LeftToRight),
+    Language::new("gn", "grn", "Guarani", LeftToRight),
+    Language::new("gu", "guj", "Gujarati", LeftToRight),
+    Language::new("gv", "glv", "Manx", LeftToRight),
+    Language::new("ha", "hau", "Hausa", LeftToRight),
+    Language::new("he", "heb", "Hebrew", RightToLeft),
+    Language::new("hi", "hin", "Hindi", LeftToRight),
+    Language::new("ho", "hmo", "Hiri Motu", LeftToRight),
+    Language::new("hr", "hrv", "Croatian", LeftToRight),
+    Language::new("ht", "hat", "Haitian", LeftToRight),
+    Language::new("hu", "hun", "Hungarian", LeftToRight),
+    Language::new("hy", "hye", "Armenian", LeftToRight),
+    Language::new("hz", "her", "Herero", LeftToRight),
+    Language::new("ia", "ina", "Interlingua", LeftToRight),
+    Language::new("id", "ind", "Indonesian", LeftToRight),
+    Language::new("ie", "ile", "Interlingue", LeftToRight),
+    Language::new("ig", "ibo", "Igbo", LeftToRight),
+    Language::new("ii", "iii", "Sichuan Yi", LeftToRight),
+    Language::new("ik", "ipk", "Inupiaq", LeftToRight),
+    Language::new("io", "ido", "Ido", LeftToRight),
+    Language::new("is", "isl", "Icelandic", LeftToRight),
+    Language::new("it", "ita", "Italian", LeftToRight),
+    Language::new("iu", "iku", "Inuktitut", LeftToRight),
+    Language::new("ja", "jpn", "Japanese", LeftToRight),
+    Language::new("jv", "jav", "Javanese", LeftToRight),
+    Language::new("ka", "kat", "Georgian", LeftToRight),
+    Language::new("kg", "kon", "Kongo", LeftToRight),
+    Language::new("ki", "kik", "Kikuyu", LeftToRight),
+    Language::new("kj", "kua", "Kuanyama", LeftToRight),
+    Language::new("kk", "kaz", "Kazakh", LeftToRight),
+    Language::new("kl", "kal", "Kalaallisut", LeftToRight),
+    Language::new("km", "khm", "Khmer", LeftToRight),
+    Language::new("kn", "kan", "Kannada", LeftToRight),
+    Language::new("ko", "kor", "Korean", LeftToRight),
+    Language::new("kr", "kau", "Kanuri", LeftToRight),
+    Language::new("ks", "kas", "Kashmiri", RightToLeft),
+    Language::new("ku", "kur", "Kurdish", LeftToRight),
+    Language::new("kv", "kom", "Komi", LeftToRight),
+    Language::new("kw", "cor", "Cornish", LeftToRight),
+    Language::new("ky", "kir", "Kirghiz", LeftToRight),
+    Language::new("la", "lat", "Latin", LeftToRight),
+    Language::new("lb", "ltz", "Luxembourgish", LeftToRight),
+    Language::new("lg", "lug", "Ganda", LeftToRight),
+    Language::new("li", "lim", "Limburgan", LeftToRight),
+    Language::new("ln", "lin", "Lingala", LeftToRight),
+    Language::new("lo", "lao", "Lao", LeftToRight),
+    Language::new("lt", "lit", "Lithuanian", LeftToRight),
+    Language::new("lu", "lub", "Luba-Katanga", LeftToRight),
+    Language::new("lv", "lav", "Latvian", LeftToRight),
+    Language::new("mg", "mlg", "Malagasy", LeftToRight),
+    Language::new("mh", "mah", "Marshallese", LeftToRight),
+    Language::new("mi", "mri", "Maori", LeftToRight),
+    Language::new("mk", "mkd", "Macedonian", LeftToRight),
+    Language::new("ml", "mal", "Malayalam", LeftToRight),
+    Language::new("mn", "mon", "Mongolian", LeftToRight),
+    Language::new("mr", "mar", "Marathi", LeftToRight),
+    Language::new("ms", "msa", "Malay", LeftToRight),
+    Language::new("mt", "mlt", "Maltese", LeftToRight),
+    Language::new("my", "mya", "Burmese", LeftToRight),
+    Language::new("na", "nau", "Nauru", LeftToRight),
+    Language::new("nb", "nob", "Norwegian Bokmål", LeftToRight),
+    Language::new("nd", "nde", "North Ndebele", LeftToRight),
+    Language::new("ne", "nep", "Nepali", LeftToRight),
+    Language::new("ng", "ndo", "Ndonga", LeftToRight),
+    Language::new("nl", "nld", "Dutch", LeftToRight),
+    Language::new("nn", "nno", "Norwegian Nynorsk", LeftToRight),
+    Language::new("no", "nor", "Norwegian", LeftToRight),
+    Language::new("nr", "nbl", "South Ndebele", LeftToRight),
+    Language::new("nv", "nav", "Navajo", LeftToRight),
+    Language::new("ny", "nya", "Nyanja", LeftToRight),
+    Language::new("oc", "oci", "Occitan", LeftToRight),
+    Language::new("oj", "oji", "Ojibwa", LeftToRight),
+    Language::new("om", "orm", "Oromo", LeftToRight),
+    Language::new("or", "ori", "Odia", LeftToRight),
+    Language::new("os", "oss", "Ossetian", LeftToRight),
+    Language::new("pa", "pan", "Panjabi", LeftToRight),
+    Language::new("pi", "pli", "Pali", LeftToRight),
+    Language::new("pl", "pol", "Polish", LeftToRight),
+    Language::new("ps", "pus", "Pushto", RightToLeft),
+    Language::new("pt", "por", "Portuguese", LeftToRight),
+    Language::new("qu", "que", "Quechua", LeftToRight),
+    Language::new("rm", "roh", "Romansh", LeftToRight),
+    Language::new("rn", "run", "Rundi", LeftToRight),
+    Language::new("ro", "ron", "Romanian", LeftToRight),
+    Language::new("ru", "rus", "Russian", LeftToRight),
+    Language::new("rw", "kin", "Kinyarwanda", LeftToRight),
+    Language::new("sa", "san", "Sanskrit", LeftToRight),
+    Language::new("sc", "srd", "Sardinian", LeftToRight),
+    Language::new("sd", "snd", "Sindhi", RightToLeft),
+    Language::new("se", "sme", "Northern Sami", LeftToRight),
+    Language::new("sg", "sag", "Sango", LeftToRight),
+    Language::new("sh", "hbs", "Serbo-Croatian", LeftToRight),
+    Language::new("si", "sin", "Sinhala", LeftToRight),
+    Language::new("sk", "slk", "Slovak", LeftToRight),
+    Language::new("sl", "slv", "Slovenian", LeftToRight),
+    Language::new("sm", "smo", "Samoan", LeftToRight),
+    Language::new("sn", "sna", "Shona", LeftToRight),
+    Language::new("so", "som", "Somali", LeftToRight),
+    Language::new("sq", "sqi", "Albanian", LeftToRight),
+    Language::new("sr", "srp", "Serbian", LeftToRight),
+    Language::new("ss", "ssw", "Swati", LeftToRight),
+    Language::new("st", "sot", "Southern Sotho", LeftToRight),
+    Language::new("su", "sun", "Sundanese", LeftToRight),
+    Language::new("sv", "swe", "Swedish", LeftToRight),
+    Language::new("sw", "swa", "Swahili", LeftToRight),
+    Language::new("ta", "tam", "Tamil", LeftToRight),
+    Language::new("te", "tel", "Telugu", LeftToRight),
+    Language::new("tg", "tgk", "Tajik", LeftToRight),
+    Language::new("th", "tha", "Thai", LeftToRight),
+    Language::new("ti", "tir", "Tigrinya", LeftToRight),
+    Language::new("tk", "tuk", "Turkmen", LeftToRight),
+    Language::new("tl", "tgl", "Tagalog", LeftToRight),
+    Language::new("tn", "tsn", "Tswana", LeftToRight),
+    Language::new("to", "ton", "Tongan", LeftToRight),
+    Language::new("tr", "tur", "Turkish", LeftToRight),
+    Language::new("ts", "tso", "Tsonga", LeftToRight),
+    Language::new("tt", "tat", "Tatar", LeftToRight),
+    Language::new("tw", "twi", "Twi", LeftToRight),
+    Language::new("ty", "tah", "Tahitian", LeftToRight),
+    Language::new("ug", "uig", "Uighur", RightToLeft),
+    Language::new("uk", "ukr", "Ukrainian", LeftToRight),
+    Language::new("ur", "urd", "Urdu", RightToLeft),
+    Language::new("uz", "uzb", "Uzbek", LeftToRight),
+    Language::new("ve", "ven", "Venda", LeftToRight),
+    Language::new("vi", "vie", "Vietnamese", LeftToRight),
+    Language::new("vo", "vol", "Volapük", LeftToRight),
+    Language::new("wa", "wln", "Walloon", LeftToRight),
+    Language::new("wo", "wol", "Wolof", LeftToRight),
+    Language::new("xh", "xho", "Xhosa", LeftToRight),
+    Language::new("yi", "yid", "Yiddish", RightToLeft),
+    Language::new("yo", "yor", "Yoruba", LeftToRight),
+    Language::new("za", "zha", "Zhuang", LeftToRight),
+    Language::new("zh", "zho", "Chinese", LeftToRight),
+    Language::new("zu", "zul", "Zulu", LeftToRight),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_consistent() {
+        assert!(LANGUAGES.is_sorted_by_key(|language| language.alpha2_code));
+
+        for language in LANGUAGES {
+            assert_eq!(Language::from_code(language.alpha2_code), Some(*language));
+            assert_eq!(Language::from_code(language.alpha3_code), Some(*language));
+        }
+    }
+
+    #[test]
+    fn test_lookup_and_direction() {
+        let arabic = Language::from_code("AR");
+        assert_eq!(arabic.map(|l| l.alpha3_code()), Some("ara"));
+        assert_eq!(arabic.map(|l| l.text_direction()), Some(RightToLeft));
+
+        assert_eq!(
+            Language::from_code("eng").map(|l| l.text_direction()),
+            Some(LeftToRight)
+        );
+        assert_eq!(Language::from_code("xx"), None);
+        assert_eq!(Language::from_code("english"), None);
+
+        assert_eq!(TextDirection::for_script("latn"), Some(LeftToRight));
+        assert_eq!(TextDirection::for_script("Zzzz"), None);
+    }
+}
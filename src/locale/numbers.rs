@@ -0,0 +1,351 @@
+//! Number symbols and digit sets used by each locale.
+//!
+//! The data follows the defaults published in the Unicode CLDR for the most widely
+//! used languages; other locales fall back to the English symbols.
+
+use super::Locale;
+
+/// A set of decimal digits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumberingSystem {
+    /// The ASCII digits `0123456789`.
+    #[default]
+    Latin,
+
+    /// The Arabic-Indic digits `٠١٢٣٤٥٦٧٨٩`, used with Arabic.
+    ArabicIndic,
+
+    /// The extended Arabic-Indic digits `۰۱۲۳۴۵۶۷۸۹`, used with Persian and Urdu.
+    ExtendedArabicIndic,
+
+    /// The Bengali digits `০১২৩৪৫৬৭৮৯`.
+    Bengali,
+
+    /// The Devanagari digits `०१२३४५६७८९`, used with Marathi and Nepali.
+    Devanagari,
+
+    /// The Thai digits `๐๑๒๓๔๕๖๗๘๙`.
+    Thai,
+
+    /// The fullwidth digits `０１２３４５６７８９`, used in East Asian typesetting.
+    Fullwidth,
+}
+
+impl NumberingSystem {
+    /// Returns the ten digits of the system, from zero to nine.
+    #[must_use]
+    pub const fn digits(self) -> [char; 10] {
+        match self {
+            Self::Latin => ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'],
+            Self::ArabicIndic => ['٠', '١', '٢', '٣', '٤', '٥', '٦', '٧', '٨', '٩'],
+            Self::ExtendedArabicIndic => ['۰', '۱', '۲', '۳', '۴', '۵', '۶', '۷', '۸', '۹'],
+            Self::Bengali => ['০', '১', '২', '৩', '৪', '৫', '৬', '৭', '৮', '৯'],
+            Self::Devanagari => ['०', '१', '२', '३', '४', '५', '६', '७', '८', '९'],
+            Self::Thai => ['๐', '๑', '๒', '๓', '๔', '๕', '๖', '๗', '๘', '๙'],
+            Self::Fullwidth => ['０', '１', '２', '３', '４', '５', '６', '７', '８', '９'],
+        }
+    }
+
+    /// Returns the digit of the system for an ASCII digit, and any other character
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::NumberingSystem;
+    ///
+    /// assert_eq!(NumberingSystem::ArabicIndic.localize('7'), '٧');
+    /// assert_eq!(NumberingSystem::ArabicIndic.localize('.'), '.');
+    /// ```
+    #[must_use]
+    pub const fn localize(self, character: char) -> char {
+        if character.is_ascii_digit() {
+            self.digits()[(character as u8 - b'0') as usize]
+        } else {
+            character
+        }
+    }
+}
+
+/// A power of ten that compact numbers are written in, such as thousands in `1.2K`.
+///
+/// Suffixes include any space that separates them from the number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CompactUnit {
+    /// The power of ten the unit stands for, such as `3` for thousands.
+    pub exponent: u8,
+
+    /// The abbreviated suffix, such as `K`.
+    pub short: &'static str,
+
+    /// The spelled-out suffix used when the number is exactly one, such as ` million`.
+    pub long_one: &'static str,
+
+    /// The spelled-out suffix used for every other number, such as ` millions`.
+    pub long_other: &'static str,
+}
+
+impl CompactUnit {
+    const fn new(
+        exponent: u8,
+        short: &'static str,
+        long_one: &'static str,
+        long_other: &'static str,
+    ) -> Self {
+        Self {
+            exponent,
+            short,
+            long_one,
+            long_other,
+        }
+    }
+}
+
+const ENGLISH_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(3, "K", " thousand", " thousand"),
+    CompactUnit::new(6, "M", " million", " million"),
+    CompactUnit::new(9, "B", " billion", " billion"),
+    CompactUnit::new(12, "T", " trillion", " trillion"),
+];
+
+const GERMAN_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(3, "\u{A0}Tsd.", " Tausend", " Tausend"),
+    CompactUnit::new(6, "\u{A0}Mio.", " Million", " Millionen"),
+    CompactUnit::new(9, "\u{A0}Mrd.", " Milliarde", " Milliarden"),
+    CompactUnit::new(12, "\u{A0}Bio.", " Billion", " Billionen"),
+];
+
+const FRENCH_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(3, "\u{A0}k", " mille", " mille"),
+    CompactUnit::new(6, "\u{A0}M", " million", " millions"),
+    CompactUnit::new(9, "\u{A0}Md", " milliard", " milliards"),
+    CompactUnit::new(12, "\u{A0}Bn", " billion", " billions"),
+];
+
+const SPANISH_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(3, "\u{A0}mil", " mil", " mil"),
+    CompactUnit::new(6, "\u{A0}M", " millón", " millones"),
+    CompactUnit::new(9, "\u{A0}mil\u{A0}M", " mil millones", " mil millones"),
+    CompactUnit::new(12, "\u{A0}B", " billón", " billones"),
+];
+
+const ITALIAN_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(3, "K", " mila", " mila"),
+    CompactUnit::new(6, "\u{A0}Mln", " milione", " milioni"),
+    CompactUnit::new(9, "\u{A0}Mrd", " miliardo", " miliardi"),
+    CompactUnit::new(12, "\u{A0}Bln", " bilione", " bilioni"),
+];
+
+const PORTUGUESE_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(3, "\u{A0}mil", " mil", " mil"),
+    CompactUnit::new(6, "\u{A0}mi", " milhão", " milhões"),
+    CompactUnit::new(9, "\u{A0}bi", " bilhão", " bilhões"),
+    CompactUnit::new(12, "\u{A0}tri", " trilhão", " trilhões"),
+];
+
+// Japanese and Chinese group digits in myriads, so the units step by four digits.
+const JAPANESE_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(4, "万", "万", "万"),
+    CompactUnit::new(8, "億", "億", "億"),
+    CompactUnit::new(12, "兆", "兆", "兆"),
+];
+
+const CHINESE_UNITS: &[CompactUnit] = &[
+    CompactUnit::new(4, "万", "万", "万"),
+    CompactUnit::new(8, "亿", "亿", "亿"),
+    CompactUnit::new(12, "万亿", "万亿", "万亿"),
+];
+
+/// The symbols a locale writes numbers with.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::{Locale, NumberSymbols};
+///
+/// let german = NumberSymbols::for_locale(&Locale::new("de_DE"));
+///
+/// assert_eq!(german.decimal_separator, ",");
+/// assert_eq!(german.grouping_separator, ".");
+/// assert_eq!(german.per_mille_sign, "‰");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NumberSymbols {
+    /// The separator between the integer and fraction parts.
+    pub decimal_separator: &'static str,
+
+    /// The separator between digit groups.
+    pub grouping_separator: &'static str,
+
+    /// The percent sign.
+    pub percent_sign: &'static str,
+
+    /// The per-mille sign.
+    pub per_mille_sign: &'static str,
+
+    /// The sign written before negative numbers.
+    pub minus_sign: &'static str,
+
+    /// The digits numbers are written with.
+    pub numbering_system: NumberingSystem,
+
+    /// The units of compact numbers, from the smallest to the largest.
+    pub compact_units: &'static [CompactUnit],
+}
+
+impl NumberSymbols {
+    /// The symbols used by English and most locales without their own data.
+    pub const ENGLISH: Self = Self::new(".", ",", "-");
+
+    const fn new(
+        decimal_separator: &'static str,
+        grouping_separator: &'static str,
+        minus_sign: &'static str,
+    ) -> Self {
+        Self {
+            decimal_separator,
+            grouping_separator,
+            percent_sign: "%",
+            per_mille_sign: "‰",
+            minus_sign,
+            numbering_system: NumberingSystem::Latin,
+            compact_units: ENGLISH_UNITS,
+        }
+    }
+
+    /// Returns the default number symbols of `locale`.
+    #[must_use]
+    pub fn for_locale(locale: &Locale) -> Self {
+        const ARABIC: NumberSymbols = NumberSymbols {
+            decimal_separator: "٫",
+            grouping_separator: "٬",
+            percent_sign: "٪\u{61C}",
+            per_mille_sign: "؉",
+            minus_sign: "\u{61C}-",
+            numbering_system: NumberingSystem::ArabicIndic,
+            compact_units: ENGLISH_UNITS,
+        };
+        const PERSIAN: NumberSymbols = NumberSymbols {
+            decimal_separator: "٫",
+            grouping_separator: "٬",
+            percent_sign: "٪",
+            per_mille_sign: "؉",
+            minus_sign: "\u{200E}−",
+            numbering_system: NumberingSystem::ExtendedArabicIndic,
+            compact_units: ENGLISH_UNITS,
+        };
+        // Arabic-speaking regions whose default digits are Latin.
+        const LATIN_ARABIC_REGIONS: &[&str] = &["AE", "DZ", "EH", "LY", "MA", "TN"];
+
+        let region = locale.region_code().unwrap_or_default();
+
+        match locale.language_code() {
+            "ar" if LATIN_ARABIC_REGIONS.contains(&region) => Self {
+                minus_sign: "\u{200E}-",
+                percent_sign: "\u{200E}%\u{200E}",
+                ..Self::new(",", ".", "-")
+            },
+            "ar" => ARABIC,
+            "fa" => PERSIAN,
+            "bn" => Self {
+                numbering_system: NumberingSystem::Bengali,
+                ..Self::ENGLISH
+            },
+            "mr" | "ne" => Self {
+                numbering_system: NumberingSystem::Devanagari,
+                ..Self::ENGLISH
+            },
+            "he" => Self::new(".", ",", "\u{200E}-"),
+            "de" if matches!(region, "CH" | "LI") => Self {
+                compact_units: GERMAN_UNITS,
+                ..Self::new(".", "’", "-")
+            },
+            "de" => Self {
+                compact_units: GERMAN_UNITS,
+                ..Self::new(",", ".", "-")
+            },
+            "es" => Self {
+                compact_units: SPANISH_UNITS,
+                ..Self::new(",", ".", "-")
+            },
+            "it" => Self {
+                compact_units: ITALIAN_UNITS,
+                ..Self::new(",", ".", "-")
+            },
+            "pt" => Self {
+                compact_units: PORTUGUESE_UNITS,
+                ..Self::new(",", ".", "-")
+            },
+            "id" | "nl" | "tr" | "da" | "el" | "ro" | "hr" | "sl" | "sr" | "vi" => {
+                Self::new(",", ".", "-")
+            }
+            "fr" => Self {
+                compact_units: FRENCH_UNITS,
+                ..Self::new(",", "\u{202F}", "-")
+            },
+            "ja" => Self {
+                compact_units: JAPANESE_UNITS,
+                ..Self::ENGLISH
+            },
+            "zh" => Self {
+                compact_units: CHINESE_UNITS,
+                ..Self::ENGLISH
+            },
+            "cs" | "pl" | "ru" | "uk" | "sk" | "hu" | "bg" | "kk" | "be" | "lv" => {
+                Self::new(",", "\u{A0}", "-")
+            }
+            "fi" | "nb" | "no" | "nn" | "sv" | "lt" | "et" => Self::new(",", "\u{A0}", "−"),
+            _ => Self::ENGLISH,
+        }
+    }
+}
+
+impl Default for NumberSymbols {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_sets() {
+        for system in [
+            NumberingSystem::Latin,
+            NumberingSystem::ArabicIndic,
+            NumberingSystem::ExtendedArabicIndic,
+            NumberingSystem::Bengali,
+            NumberingSystem::Devanagari,
+        ] {
+            let digits = system.digits();
+
+            assert!(digits.iter().all(|digit| digit.is_numeric()));
+            assert_eq!(system.localize('0'), digits[0]);
+            assert_eq!(system.localize('9'), digits[9]);
+        }
+    }
+
+    #[test]
+    fn test_symbols_for_locale() {
+        let egyptian = NumberSymbols::for_locale(&Locale::new("ar_EG"));
+        assert_eq!(egyptian.numbering_system, NumberingSystem::ArabicIndic);
+
+        let moroccan = NumberSymbols::for_locale(&Locale::new("ar_MA"));
+        assert_eq!(moroccan.numbering_system, NumberingSystem::Latin);
+
+        let swiss = NumberSymbols::for_locale(&Locale::new("de_CH"));
+        assert_eq!(swiss.grouping_separator, "’");
+        assert_eq!(swiss.compact_units[1].short, "\u{A0}Mio.");
+
+        let japanese = NumberSymbols::for_locale(&Locale::new("ja_JP"));
+        assert_eq!(japanese.compact_units[0].exponent, 4);
+
+        assert_eq!(
+            NumberSymbols::for_locale(&Locale::new("xx")),
+            NumberSymbols::ENGLISH
+        );
+    }
+}
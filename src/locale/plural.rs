@@ -0,0 +1,300 @@
+//! Cardinal plural rules.
+//!
+//! The rules follow the Unicode CLDR cardinal plural rules for the most widely used
+//! languages; other languages use the English rule. A number's category depends on how
+//! it is written, so `1` and `1.5` can fall into different categories.
+
+use alloc::string::ToString;
+use core::fmt;
+
+use super::Locale;
+use crate::num::Number;
+
+/// The plural category a number falls into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PluralCategory {
+    /// Used for zero in languages such as Arabic.
+    Zero,
+
+    /// Used for one, and for other numbers in some languages.
+    One,
+
+    /// Used for two in languages such as Arabic and Hebrew.
+    Two,
+
+    /// Used for small numbers in languages such as Russian and Czech.
+    Few,
+
+    /// Used for larger numbers or fractions in languages such as Polish and Arabic.
+    Many,
+
+    /// Used for every number no other category covers.
+    Other,
+}
+
+impl PluralCategory {
+    /// Returns the CLDR keyword of the category, such as `"one"`.
+    #[must_use]
+    pub const fn keyword(self) -> &'static str {
+        match self {
+            Self::Zero => "zero",
+            Self::One => "one",
+            Self::Two => "two",
+            Self::Few => "few",
+            Self::Many => "many",
+            Self::Other => "other",
+        }
+    }
+
+    /// Returns the category with the CLDR keyword `keyword`.
+    #[must_use]
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword {
+            "zero" => Some(Self::Zero),
+            "one" => Some(Self::One),
+            "two" => Some(Self::Two),
+            "few" => Some(Self::Few),
+            "many" => Some(Self::Many),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.keyword())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Rule {
+    /// Every number is `other`, as in Japanese or Chinese.
+    Other,
+    /// `one` for the integer 1, as in English or German.
+    IntegerOne,
+    /// `one` for 0 and 1 with any fraction, as in French.
+    ZeroOne,
+    /// `one` for 0 and the number 1, as in Hindi.
+    ZeroOrOne,
+    EastSlavic,
+    Polish,
+    Czech,
+    Arabic,
+    Hebrew,
+}
+
+/// The operands of a number that plural rules look at.
+#[derive(Clone, Copy)]
+struct Operands {
+    /// The integer digits.
+    i: u128,
+    /// The number of visible fraction digits.
+    v: usize,
+}
+
+/// The cardinal plural rules of a language.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::{Locale, PluralCategory, PluralRules};
+///
+/// let russian = PluralRules::for_locale(&Locale::new("ru_RU"));
+///
+/// assert_eq!(russian.category(1), PluralCategory::One);
+/// assert_eq!(russian.category(3), PluralCategory::Few);
+/// assert_eq!(russian.category(11), PluralCategory::Many);
+/// assert_eq!(russian.category(1.5), PluralCategory::Other);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PluralRules {
+    rule: Rule,
+}
+
+impl PluralRules {
+    /// The English rules: `one` for the integer 1 and `other` for everything else.
+    pub const ENGLISH: Self = Self {
+        rule: Rule::IntegerOne,
+    };
+
+    /// Returns the plural rules of `locale`'s language.
+    #[must_use]
+    pub fn for_locale(locale: &Locale) -> Self {
+        let rule = match locale.language_code() {
+            "ja" | "zh" | "ko" | "th" | "vi" | "id" | "ms" | "my" | "lo" | "km" => Rule::Other,
+            "fr" | "pt" => Rule::ZeroOne,
+            "hi" | "bn" | "fa" | "gu" | "kn" | "mr" | "zu" | "am" => Rule::ZeroOrOne,
+            "ru" | "uk" | "be" => Rule::EastSlavic,
+            "pl" => Rule::Polish,
+            "cs" | "sk" => Rule::Czech,
+            "ar" => Rule::Arabic,
+            "he" => Rule::Hebrew,
+            _ => Rule::IntegerOne,
+        };
+
+        Self { rule }
+    }
+
+    /// Returns the category of `value` written with its shortest decimal representation.
+    #[must_use]
+    pub fn category(&self, value: impl Into<Number>) -> PluralCategory {
+        match value.into() {
+            Number::Float(value) => self.category_of_float(f64::from(value)),
+            Number::Double(value) => self.category_of_float(value),
+            Number::Bool(value) => self.category_of_integer(i128::from(value)),
+            Number::Int(value) => self.category_of_integer(value as i128),
+            Number::Int8(value) => self.category_of_integer(i128::from(value)),
+            Number::Int16(value) => self.category_of_integer(i128::from(value)),
+            Number::Int32(value) => self.category_of_integer(i128::from(value)),
+            Number::UInt(value) => self.category_of_integer(value as i128),
+            Number::UInt8(value) => self.category_of_integer(i128::from(value)),
+            Number::UInt16(value) => self.category_of_integer(i128::from(value)),
+            Number::UInt32(value) => self.category_of_integer(i128::from(value)),
+        }
+    }
+
+    pub(crate) fn category_of_integer(self, value: i128) -> PluralCategory {
+        self.category_of(Operands {
+            i: value.unsigned_abs(),
+            v: 0,
+        })
+    }
+
+    pub(crate) fn category_of_float(self, value: f64) -> PluralCategory {
+        if !value.is_finite() {
+            return PluralCategory::Other;
+        }
+
+        let text = value.abs().to_string();
+        let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+
+        self.category_of(Operands {
+            i: integer.parse().unwrap_or(u128::MAX),
+            v: fraction.len(),
+        })
+    }
+
+    fn category_of(self, operands: Operands) -> PluralCategory {
+        let Operands { i, v } = operands;
+        let integral = v == 0;
+
+        match self.rule {
+            Rule::IntegerOne if i == 1 && integral => PluralCategory::One,
+            Rule::ZeroOne if i <= 1 => PluralCategory::One,
+            Rule::ZeroOrOne if i == 0 || (i == 1 && integral) => PluralCategory::One,
+            Rule::EastSlavic if integral => match (i % 10, i % 100) {
+                (1, rem) if rem != 11 => PluralCategory::One,
+                (2..=4, rem) if !(12..=14).contains(&rem) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            Rule::Polish if integral => match (i % 10, i % 100) {
+                _ if i == 1 => PluralCategory::One,
+                (2..=4, rem) if !(12..=14).contains(&rem) => PluralCategory::Few,
+                _ => PluralCategory::Many,
+            },
+            Rule::Czech if !integral => PluralCategory::Many,
+            Rule::Czech => match i {
+                1 => PluralCategory::One,
+                2..=4 => PluralCategory::Few,
+                _ => PluralCategory::Other,
+            },
+            Rule::Arabic if integral => match (i, i % 100) {
+                (0, _) => PluralCategory::Zero,
+                (1, _) => PluralCategory::One,
+                (2, _) => PluralCategory::Two,
+                (_, 3..=10) => PluralCategory::Few,
+                (_, 11..=99) => PluralCategory::Many,
+                _ => PluralCategory::Other,
+            },
+            Rule::Hebrew if (i == 1 && integral) || (i == 0 && !integral) => PluralCategory::One,
+            Rule::Hebrew if i == 2 && integral => PluralCategory::Two,
+            _ => PluralCategory::Other,
+        }
+    }
+}
+
+impl Default for PluralRules {
+    fn default() -> Self {
+        Self::ENGLISH
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(identifier: &str) -> PluralRules {
+        PluralRules::for_locale(&Locale::new(identifier))
+    }
+
+    #[test]
+    fn test_english_and_french() {
+        let english = rules("en_US");
+
+        assert_eq!(english.category(1), PluralCategory::One);
+        assert_eq!(english.category(0), PluralCategory::Other);
+        assert_eq!(english.category(1.5), PluralCategory::Other);
+        assert_eq!(english.category(-1), PluralCategory::One);
+
+        let french = rules("fr_FR");
+        assert_eq!(french.category(0), PluralCategory::One);
+        assert_eq!(french.category(1.5), PluralCategory::One);
+        assert_eq!(french.category(2), PluralCategory::Other);
+
+        assert_eq!(rules("ja").category(1), PluralCategory::Other);
+    }
+
+    #[test]
+    fn test_slavic_rules() {
+        let polish = rules("pl");
+
+        assert_eq!(polish.category(1), PluralCategory::One);
+        assert_eq!(polish.category(22), PluralCategory::Few);
+        assert_eq!(polish.category(12), PluralCategory::Many);
+        assert_eq!(polish.category(21), PluralCategory::Many);
+        assert_eq!(polish.category(0.5), PluralCategory::Other);
+
+        let russian = rules("uk_UA");
+        assert_eq!(russian.category(21), PluralCategory::One);
+        assert_eq!(russian.category(111), PluralCategory::Many);
+
+        let czech = rules("cs");
+        assert_eq!(czech.category(4), PluralCategory::Few);
+        assert_eq!(czech.category(2.5), PluralCategory::Many);
+    }
+
+    #[test]
+    fn test_arabic_and_hebrew() {
+        let arabic = rules("ar_EG");
+
+        assert_eq!(arabic.category(0), PluralCategory::Zero);
+        assert_eq!(arabic.category(2), PluralCategory::Two);
+        assert_eq!(arabic.category(103), PluralCategory::Few);
+        assert_eq!(arabic.category(111), PluralCategory::Many);
+        assert_eq!(arabic.category(100), PluralCategory::Other);
+
+        let hebrew = rules("he");
+        assert_eq!(hebrew.category(2), PluralCategory::Two);
+        assert_eq!(hebrew.category(0.5), PluralCategory::One);
+    }
+
+    #[test]
+    fn test_keywords_round_trip() {
+        for category in [
+            PluralCategory::Zero,
+            PluralCategory::One,
+            PluralCategory::Two,
+            PluralCategory::Few,
+            PluralCategory::Many,
+            PluralCategory::Other,
+        ] {
+            assert_eq!(
+                PluralCategory::from_keyword(category.keyword()),
+                Some(category)
+            );
+        }
+
+        assert_eq!(PluralCategory::from_keyword("several"), None);
+    }
+}
@@ -0,0 +1,595 @@
+//! ISO 3166-1 countries and the UN M49 regions that contain them.
+//!
+//! A [`Region`] is either a country or territory with ISO 3166-1 codes, or one of the
+//! M49 groupings (continents, subregions and intermediate regions) identified by a
+//! three-digit code. Regions form a tree rooted at the world (`001`), so every country
+//! can report the subregion and continent it belongs to.
+
+use core::fmt;
+
+const NONE: u16 = 0;
+const WORLD: u16 = 1;
+
+/// A country, territory or UN M49 geographic region.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::region::Region;
+///
+/// let japan = Region::from_code("JP").unwrap();
+///
+/// assert_eq!(japan.name(), "Japan");
+/// assert_eq!(japan.alpha3_code(), Some("JPN"));
+/// assert_eq!(japan.numeric_code(), 392);
+/// assert_eq!(japan.containing_region().map(|r| r.name()), Some("Eastern Asia"));
+/// assert_eq!(japan.continent().map(|r| r.name()), Some("Asia"));
+///
+/// assert_eq!(Region::from_code("JPN"), Some(japan));
+/// assert_eq!(Region::from_code("392"), Some(japan));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Region {
+    code: &'static str,
+    alpha3_code: Option<&'static str>,
+    numeric_code: u16,
+    name: &'static str,
+    parent: u16,
+}
+
+impl Region {
+    const fn country(
+        alpha2_code: &'static str,
+        alpha3_code: &'static str,
+        numeric_code: u16,
+        name: &'static str,
+        parent: u16,
+    ) -> Self {
+        Self {
+            code: alpha2_code,
+            alpha3_code: Some(alpha3_code),
+            numeric_code,
+            name,
+            parent,
+        }
+    }
+
+    const fn grouping(
+        code: &'static str,
+        numeric_code: u16,
+        name: &'static str,
+        parent: u16,
+    ) -> Self {
+        Self {
+            code,
+            alpha3_code: None,
+            numeric_code,
+            name,
+            parent,
+        }
+    }
+
+    /// Returns every country and territory with an ISO 3166-1 code, sorted by alpha-2
+    /// code.
+    #[must_use]
+    pub const fn iso_regions() -> &'static [Self] {
+        COUNTRIES
+    }
+
+    /// Returns the world region, `001`, which contains every other region.
+    #[must_use]
+    pub const fn world() -> Self {
+        GROUPINGS[0]
+    }
+
+    /// Looks up a region by an alpha-2 code, an alpha-3 code or a three-digit numeric
+    /// code.
+    ///
+    /// Letter codes are matched case-insensitively.
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Self> {
+        let bytes = code.as_bytes();
+
+        if bytes.len() == 3 && bytes.iter().all(u8::is_ascii_digit) {
+            return code.parse().ok().and_then(Self::from_numeric_code);
+        }
+
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return None;
+        }
+
+        match bytes.len() {
+            2 => COUNTRIES
+                .binary_search_by(|region| {
+                    region
+                        .code
+                        .bytes()
+                        .cmp(bytes.iter().map(u8::to_ascii_uppercase))
+                })
+                .ok()
+                .map(|index| COUNTRIES[index]),
+            3 => COUNTRIES
+                .iter()
+                .find(|region| {
+                    region
+                        .alpha3_code
+                        .is_some_and(|alpha3| alpha3.eq_ignore_ascii_case(code))
+                })
+                .copied(),
+            _ => None,
+        }
+    }
+
+    /// Looks up a country or M49 grouping by its numeric code.
+    #[must_use]
+    pub fn from_numeric_code(numeric_code: u16) -> Option<Self> {
+        GROUPINGS
+            .iter()
+            .chain(COUNTRIES)
+            .find(|region| region.numeric_code == numeric_code)
+            .copied()
+    }
+
+    /// Returns the code that identifies the region: the alpha-2 code for countries and
+    /// the three-digit M49 code for groupings.
+    #[must_use]
+    pub const fn identifier(&self) -> &'static str {
+        self.code
+    }
+
+    /// Returns the ISO 3166-1 alpha-2 code, or `None` for M49 groupings.
+    #[must_use]
+    pub const fn alpha2_code(&self) -> Option<&'static str> {
+        if self.is_iso_region() {
+            Some(self.code)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the ISO 3166-1 alpha-3 code, or `None` for M49 groupings.
+    #[must_use]
+    pub const fn alpha3_code(&self) -> Option<&'static str> {
+        self.alpha3_code
+    }
+
+    /// Returns the ISO 3166-1 or M49 numeric code.
+    #[must_use]
+    pub const fn numeric_code(&self) -> u16 {
+        self.numeric_code
+    }
+
+    /// Returns the English name of the region.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns `true` if the region is a country or territory with ISO 3166-1 codes.
+    #[must_use]
+    pub const fn is_iso_region(&self) -> bool {
+        self.alpha3_code.is_some()
+    }
+
+    /// Returns the smallest M49 region containing this one, or `None` for the world.
+    #[must_use]
+    pub fn containing_region(&self) -> Option<Self> {
+        if self.parent == NONE {
+            None
+        } else {
+            Self::from_numeric_code(self.parent)
+        }
+    }
+
+    /// Returns the continent containing this region.
+    ///
+    /// Continents themselves, the world and regions outside any continent (such as
+    /// Antarctica) have none.
+    #[must_use]
+    pub fn continent(&self) -> Option<Self> {
+        let mut region = self.containing_region()?;
+
+        while region.parent != WORLD {
+            region = region.containing_region()?;
+        }
+
+        Some(region)
+    }
+
+    /// Returns the regions directly contained in this one.
+    pub fn sub_regions(&self) -> impl Iterator<Item = Self> + use<> {
+        let numeric_code = self.numeric_code;
+
+        GROUPINGS
+            .iter()
+            .chain(COUNTRIES)
+            .filter(move |region| region.parent == numeric_code)
+            .copied()
+    }
+
+    /// Returns `true` if `other` lies within this region, directly or indirectly.
+    ///
+    /// A region does not contain itself.
+    #[must_use]
+    pub fn contains(&self, other: &Self) -> bool {
+        let mut current = other.containing_region();
+
+        while let Some(region) = current {
+            if region == *self {
+                return true;
+            }
+
+            current = region.containing_region();
+        }
+
+        false
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+/// UN M49 groupings, sorted by numeric code.
+static GROUPINGS: &[Region] = &[
+    Region::grouping("001", 1, "World", 0),
+    Region::grouping("002", 2, "Africa", 1),
+    Region::grouping("005", 5, "South America", 419),
+    Region::grouping("009", 9, "Oceania", 1),
+    Region::grouping("011", 11, "Western Africa", 202),
+    Region::grouping("013", 13, "Central America", 419),
+    Region::grouping("014", 14, "Eastern Africa", 202),
+    Region::grouping("015", 15, "Northern Africa", 2),
+    Region::grouping("017", 17, "Middle Africa", 202),
+    Region::grouping("018", 18, "Southern Africa", 202),
+    Region::grouping("019", 19, "Americas", 1),
+    Region::grouping("021", 21, "Northern America", 19),
+    Region::grouping("029", 29, "Caribbean", 419),
+    Region::grouping("030", 30, "Eastern Asia", 142),
+    Region::grouping("034", 34, "Southern Asia", 142),
+    Region::grouping("035", 35, "South-eastern Asia", 142),
+    Region::grouping("039", 39, "Southern Europe", 150),
+    Region::grouping("053", 53, "Australia and New Zealand", 9),
+    Region::grouping("054", 54, "Melanesia", 9),
+    Region::grouping("057", 57, "Micronesia", 9),
+    Region::grouping("061", 61, "Polynesia", 9),
+    Region::grouping("142", 142, "Asia", 1),
+    Region::grouping("143", 143, "Central Asia", 142),
+    Region::grouping("145", 145, "Western Asia", 142),
+    Region::grouping("150", 150, "Europe", 1),
+    Region::grouping("151", 151, "Eastern Europe", 150),
+    Region::grouping("154", 154, "Northern Europe", 150),
+    Region::grouping("155", 155, "Western Europe", 150),
+    Region::grouping("202", 202, "Sub-Saharan Africa", 2),
+    Region::grouping("419", 419, "Latin America and the Caribbean", 19),
+];
+
+/// ISO 3166-1 countries and territories, sorted by alpha-2 code.
+static COUNTRIES: &[Region] = &[
+    Region::country("AD", "AND", 20, "Andorra", 39),
+    Region::country("AE", "ARE", 784, "United Arab Emirates", 145),
+    Region::country("AF", "AFG", 4, "Afghanistan", 34),
+    Region::country("AG", "ATG", 28, "Antigua and Barbuda", 29),
+    Region::country("AI", "AIA", 660, "Anguilla", 29),
+    Region::country("AL", "ALB", 8, "Albania", 39),
+    Region::country("AM", "ARM", 51, "Armenia", 145),
+    Region::country("AO", "AGO", 24, "Angola", 17),
+    Region::country("AQ", "ATA", 10, "Antarctica", 1),
+    Region::country("AR", "ARG", 32, "Argentina", 5),
+    Region::country("AS", "ASM", 16, "American Samoa", 61),
+    Region::country("AT", "AUT", 40, "Austria", 155),
+    Region::country("AU", "AUS", 36, "Australia", 53),
+    Region::country("AW", "ABW", 533, "Aruba", 29),
+    Region::country("AX", "ALA", 248, "Åland Islands", 154),
+    Region::country("AZ", "AZE", 31, "Azerbaijan", 145),
+    Region::country("BA", "BIH", 70, "Bosnia and Herzegovina", 39),
+    Region::country("BB", "BRB", 52, "Barbados", 29),
+    Region::country("BD", "BGD", 50, "Bangladesh", 34),
+    Region::country("BE", "BEL", 56, "Belgium", 155),
+    Region::country("BF", "BFA", 854, "Burkina Faso", 11),
+    Region::country("BG", "BGR", 100, "Bulgaria", 151),
+    Region::country("BH", "BHR", 48, "Bahrain", 145),
+    Region::country("BI", "BDI", 108, "Burundi", 14),
+    Region::country("BJ", "BEN", 204, "Benin", 11),
+    Region::country("BL", "BLM", 652, "Saint Barthélemy", 29),
+    Region::country("BM", "BMU", 60, "Bermuda", 21),
+    Region::country("BN", "BRN", 96, "Brunei Darussalam", 35),
+    Region::country("BO", "BOL", 68, "Bolivia", 5),
+    Region::country("BQ", "BES", 535, "Caribbean Netherlands", 29),
+    Region::country("BR", "BRA", 76, "Brazil", 5),
+    Region::country("BS", "BHS", 44, "Bahamas", 29),
+    Region::country("BT", "BTN", 64, "Bhutan", 34),
+    Region::country("BV", "BVT", 74, "Bouvet Island", 5),
+    Region::country("BW", "BWA", 72, "Botswana", 18),
+    Region::country("BY", "BLR", 112, "Belarus", 151),
+    Region::country("BZ", "BLZ", 84, "Belize", 13),
+    Region::country("CA", "CAN", 124, "Canada", 21),
+    Region::country("CC", "CCK", 166, "Cocos (Keeling) Islands", 53),
+    Region::country("CD", "COD", 180, "Democratic Republic of the Congo", 17),
+    Region::country("CF", "CAF", 140, "Central African Republic", 17),
+    Region::country("CG", "COG", 178, "Congo", 17),
+    Region::country("CH", "CHE", 756, "Switzerland", 155),
+    Region::country("CI", "CIV", 384, "Côte d'Ivoire", 11),
+    Region::country("CK", "COK", 184, "Cook Islands", 61),
+    Region::country("CL", "CHL", 152, "Chile", 5),
+    Region::country("CM", "CMR", 120, "Cameroon", 17),
+    Region::country("CN", "CHN", 156, "China", 30),
+    Region::country("CO", "COL", 170, "Colombia", 5),
+    Region::country("CR", "CRI", 188, "Costa Rica", 13),
+    Region::country("CU", "CUB", 192, "Cuba", 29),
+    Region::country("CV", "CPV", 132, "Cabo Verde", 11),
+    Region::country("CW", "CUW", 531, "Curaçao", 29),
+    Region::country("CX", "CXR", 162, "Christmas Island", 53),
+    Region::country("CY", "CYP", 196, "Cyprus", 145),
+    Region::country("CZ", "CZE", 203, "Czechia", 151),
+    Region::country("DE", "DEU", 276, "Germany", 155),
+    Region::country("DJ", "DJI", 262, "Djibouti", 14),
+    Region::country("DK", "DNK", 208, "Denmark", 154),
+    Region::country("DM", "DMA", 212, "Dominica", 29),
+    Region::country("DO", "DOM", 214, "Dominican Republic", 29),
+    Region::country("DZ", "DZA", 12, "Algeria", 15),
+    Region::country("EC", "ECU", 218, "Ecuador", 5),
+    Region::country("EE", "EST", 233, "Estonia", 154),
+    Region::country("EG", "EGY", 818, "Egypt", 15),
+    Region::country("EH", "ESH", 732, "Western Sahara", 15),
+    Region::country("ER", "ERI", 232, "Eritrea", 14),
+    Region::country("ES", "ESP", 724, "Spain", 39),
+    Region::country("ET", "ETH", 231, "Ethiopia", 14),
+    Region::country("FI", "FIN", 246, "Finland", 154),
+    Region::country("FJ", "FJI", 242, "Fiji", 54),
+    Region::country("FK", "FLK", 238, "Falkland Islands", 5),
+    Region::country("FM", "FSM", 583, "Micronesia", 57),
+    Region::country("FO", "FRO", 234, "Faroe Islands", 154),
+    Region::country("FR", "FRA", 250, "France", 155),
+    Region::country("GA", "GAB", 266, "Gabon", 17),
+    Region::country("GB", "GBR", 826, "United Kingdom", 154),
+    Region::country("GD", "GRD", 308, "Grenada", 29),
+    Region::country("GE", "GEO", 268, "Georgia", 145),
+    Region::country("GF", "GUF", 254, "French Guiana", 5),
+    Region::country("GG", "GGY", 831, "Guernsey", 154),
+    Region::country("GH", "GHA", 288, "Ghana", 11),
+    Region::country("GI", "GIB", 292, "Gibraltar", 39),
+    Region::country("GL", "GRL", 304, "Greenland", 21),
+    Region::country("GM", "GMB", 270, "Gambia", 11),
+    Region::country("GN", "GIN", 324, "Guinea", 11),
+    Region::country("GP", "GLP", 312, "Guadeloupe", 29),
+    Region::country("GQ", "GNQ", 226, "Equatorial Guinea", 17),
+    Region::country("GR", "GRC", 300, "Greece", 39),
+    Region::country(
+        "GS",
+        "SGS",
+        239,
+        "South Georgia and the South Sandwich Islands",
+        5,
+    ),
+    Region::country("GT", "GTM", 320, "Guatemala", 13),
+    Region::country("GU", "GUM", 316, "Guam", 57),
+    Region::country("GW", "GNB", 624, "Guinea-Bissau", 11),
+    Region::country("GY", "GUY", 328, "Guyana", 5),
+    Region::country("HK", "HKG", 344, "Hong Kong", 30),
+    Region::country("HM", "HMD", 334, "Heard Island and McDonald Islands", 53),
+    Region::country("HN", "HND", 340, "Honduras", 13),
+    Region::country("HR", "HRV", 191, "Croatia", 39),
+    Region::country("HT", "HTI", 332, "Haiti", 29),
+    Region::country("HU", "HUN", 348, "Hungary", 151),
+    Region::country("ID", "IDN", 360, "Indonesia", 35),
+    Region::country("IE", "IRL", 372, "Ireland", 154),
+    Region::country("IL", "ISR", 376, "Israel", 145),
+    Region::country("IM", "IMN", 833, "Isle of Man", 154),
+    Region::country("IN", "IND", 356, "India", 34),
+    Region::country("IO", "IOT", 86, "British Indian Ocean Territory", 14),
+    Region::country("IQ", "IRQ", 368, "Iraq", 145),
+    Region::country("IR", "IRN", 364, "Iran", 34),
+    Region::country("IS", "ISL", 352, "Iceland", 154),
+    Region::country("IT", "ITA", 380, "Italy", 39),
+    Region::country("JE", "JEY", 832, "Jersey", 154),
+    Region::country("JM", "JAM", 388, "Jamaica", 29),
+    Region::country("JO", "JOR", 400, "Jordan", 145),
+    Region::country("JP", "JPN", 392, "Japan", 30),
+    Region::country("KE", "KEN", 404, "Kenya", 14),
+    Region::country("KG", "KGZ", 417, "Kyrgyzstan", 143),
+    Region::country("KH", "KHM", 116, "Cambodia", 35),
+    Region::country("KI", "KIR", 296, "Kiribati", 57),
+    Region::country("KM", "COM", 174, "Comoros", 14),
+    Region::country("KN", "KNA", 659, "Saint Kitts and Nevis", 29),
+    Region::country("KP", "PRK", 408, "North Korea", 30),
+    Region::country("KR", "KOR", 410, "South Korea", 30),
+    Region::country("KW", "KWT", 414, "Kuwait", 145),
+    Region::country("KY", "CYM", 136, "Cayman Islands", 29),
+    Region::country("KZ", "KAZ", 398, "Kazakhstan", 143),
+    Region::country("LA", "LAO", 418, "Laos", 35),
+    Region::country("LB", "LBN", 422, "Lebanon", 145),
+    Region::country("LC", "LCA", 662, "Saint Lucia", 29),
+    Region::country("LI", "LIE", 438, "Liechtenstein", 155),
+    Region::country("LK", "LKA", 144, "Sri Lanka", 34),
+    Region::country("LR", "LBR", 430, "Liberia", 11),
+    Region::country("LS", "LSO", 426, "Lesotho", 18),
+    Region::country("LT", "LTU", 440, "Lithuania", 154),
+    Region::country("LU", "LUX", 442, "Luxembourg", 155),
+    Region::country("LV", "LVA", 428, "Latvia", 154),
+    Region::country("LY", "LBY", 434, "Libya", 15),
+    Region::country("MA", "MAR", 504, "Morocco", 15),
+    Region::country("MC", "MCO", 492, "Monaco", 155),
+    Region::country("MD", "MDA", 498, "Moldova", 151),
+    Region::country("ME", "MNE", 499, "Montenegro", 39),
+    Region::country("MF", "MAF", 663, "Saint Martin (French part)", 29),
+    Region::country("MG", "MDG", 450, "Madagascar", 14),
+    Region::country("MH", "MHL", 584, "Marshall Islands", 57),
+    Region::country("MK", "MKD", 807, "North Macedonia", 39),
+    Region::country("ML", "MLI", 466, "Mali", 11),
+    Region::country("MM", "MMR", 104, "Myanmar", 35),
+    Region::country("MN", "MNG", 496, "Mongolia", 30),
+    Region::country("MO", "MAC", 446, "Macao", 30),
+    Region::country("MP", "MNP", 580, "Northern Mariana Islands", 57),
+    Region::country("MQ", "MTQ", 474, "Martinique", 29),
+    Region::country("MR", "MRT", 478, "Mauritania", 11),
+    Region::country("MS", "MSR", 500, "Montserrat", 29),
+    Region::country("MT", "MLT", 470, "Malta", 39),
+    Region::country("MU", "MUS", 480, "Mauritius", 14),
+    Region::country("MV", "MDV", 462, "Maldives", 34),
+    Region::country("MW", "MWI", 454, "Malawi", 14),
+    Region::country("MX", "MEX", 484, "Mexico", 13),
+    Region::country("MY", "MYS", 458, "Malaysia", 35),
+    Region::country("MZ", "MOZ", 508, "Mozambique", 14),
+    Region::country("NA", "NAM", 516, "Namibia", 18),
+    Region::country("NC", "NCL", 540, "New Caledonia", 54),
+    Region::country("NE", "NER", 562, "Niger", 11),
+    Region::country("NF", "NFK", 574, "Norfolk Island", 53),
+    Region::country("NG", "NGA", 566, "Nigeria", 11),
+    Region::country("NI", "NIC", 558, "Nicaragua", 13),
+    Region::country("NL", "NLD", 528, "Netherlands", 155),
+    Region::country("NO", "NOR", 578, "Norway", 154),
+    Region::country("NP", "NPL", 524, "Nepal", 34),
+    Region::country("NR", "NRU", 520, "Nauru", 57),
+    Region::country("NU", "NIU", 570, "Niue", 61),
+    Region::country("NZ", "NZL", 554, "New Zealand", 53),
+    Region::country("OM", "OMN", 512, "Oman", 145),
+    Region::country("PA", "PAN", 591, "Panama", 13),
+    Region::country("PE", "PER", 604, "Peru", 5),
+    Region::country("PF", "PYF", 258, "French Polynesia", 61),
+    Region::country("PG", "PNG", 598, "Papua New Guinea", 54),
+    Region::country("PH", "PHL", 608, "Philippines", 35),
+    Region::country("PK", "PAK", 586, "Pakistan", 34),
+    Region::country("PL", "POL", 616, "Poland", 151),
+    Region::country("PM", "SPM", 666, "Saint Pierre and Miquelon", 21),
+    Region::country("PN", "PCN", 612, "Pitcairn", 61),
+    Region::country("PR", "PRI", 630, "Puerto Rico", 29),
+    Region::country("PS", "PSE", 275, "Palestine", 145),
+    Region::country("PT", "PRT", 620, "Portugal", 39),
+    Region::country("PW", "PLW", 585, "Palau", 57),
+    Region::country("PY", "PRY", 600, "Paraguay", 5),
+    Region::country("QA", "QAT", 634, "Qatar", 145),
+    Region::country("RE", "REU", 638, "Réunion", 14),
+    Region::country("RO", "ROU", 642, "Romania", 151),
+    Region::country("RS", "SRB", 688, "Serbia", 39),
+    Region::country("RU", "RUS", 643, "Russian Federation", 151),
+    Region::country("RW", "RWA", 646, "Rwanda", 14),
+    Region::country("SA", "SAU", 682, "Saudi Arabia", 145),
+    Region::country("SB", "SLB", 90, "Solomon Islands", 54),
+    Region::country("SC", "SYC", 690, "Seychelles", 14),
+    Region::country("SD", "SDN", 729, "Sudan", 15),
+    Region::country("SE", "SWE", 752, "Sweden", 154),
+    Region::country("SG", "SGP", 702, "Singapore", 35),
+    Region::country(
+        "SH",
+        "SHN",
+        654,
+        "Saint Helena, Ascension and Tristan da Cunha",
+        11,
+    ),
+    Region::country("SI", "SVN", 705, "Slovenia", 39),
+    Region::country("SJ", "SJM", 744, "Svalbard and Jan Mayen", 154),
+    Region::country("SK", "SVK", 703, "Slovakia", 151),
+    Region::country("SL", "SLE", 694, "Sierra Leone", 11),
+    Region::country("SM", "SMR", 674, "San Marino", 39),
+    Region::country("SN", "SEN", 686, "Senegal", 11),
+    Region::country("SO", "SOM", 706, "Somalia", 14),
+    Region::country("SR", "SUR", 740, "Suriname", 5),
+    Region::country("SS", "SSD", 728, "South Sudan", 14),
+    Region::country("ST", "STP", 678, "Sao Tome and Principe", 17),
+    Region::country("SV", "SLV", 222, "El Salvador", 13),
+    Region::country("SX", "SXM", 534, "Sint Maarten (Dutch part)", 29),
+    Region::country("SY", "SYR", 760, "Syria", 145),
+    Region::country("SZ", "SWZ", 748, "Eswatini", 18),
+    Region::country("TC", "TCA", 796, "Turks and Caicos Islands", 29),
+    Region::country("TD", "TCD", 148, "Chad", 17),
+    Region::country("TF", "ATF", 260, "French Southern Territories", 14),
+    Region::country("TG", "TGO", 768, "Togo", 11),
+    Region::country("TH", "THA", 764, "Thailand", 35),
+    Region::country("TJ", "TJK", 762, "Tajikistan", 143),
+    Region::country("TK", "TKL", 772, "Tokelau", 61),
+    Region::country("TL", "TLS", 626, "Timor-Leste", 35),
+    Region::country("TM", "TKM", 795, "Turkmenistan", 143),
+    Region::country("TN", "TUN", 788, "Tunisia", 15),
+    Region::country("TO", "TON", 776, "Tonga", 61),
+    Region::country("TR", "TUR", 792, "Türkiye", 145),
+    Region::country("TT", "TTO", 780, "Trinidad and Tobago", 29),
+    Region::country("TV", "TUV", 798, "Tuvalu", 61),
+    Region::country("TW", "TWN", 158, "Taiwan", 30),
+    Region::country("TZ", "TZA", 834, "Tanzania", 14),
+    Region::country("UA", "UKR", 804, "Ukraine", 151),
+    Region::country("UG", "UGA", 800, "Uganda", 14),
+    Region::country("UM", "UMI", 581, "United States Minor Outlying Islands", 57),
+    Region::country("US", "USA", 840, "United States", 21),
+    Region::country("UY", "URY", 858, "Uruguay", 5),
+    Region::country("UZ", "UZB", 860, "Uzbekistan", 143),
+    Region::country("VA", "VAT", 336, "Vatican City", 39),
+    Region::country("VC", "VCT", 670, "Saint Vincent and the Grenadines", 29),
+    Region::country("VE", "VEN", 862, "Venezuela", 5),
+    Region::country("VG", "VGB", 92, "British Virgin Islands", 29),
+    Region::country("VI", "VIR", 850, "U.S. Virgin Islands", 29),
+    Region::country("VN", "VNM", 704, "Vietnam", 35),
+    Region::country("VU", "VUT", 548, "Vanuatu", 54),
+    Region::country("WF", "WLF", 876, "Wallis and Futuna", 61),
+    Region::country("WS", "WSM", 882, "Samoa", 61),
+    Region::country("YE", "YEM", 887, "Yemen", 145),
+    Region::country("YT", "MYT", 175, "Mayotte", 14),
+    Region::country("ZA", "ZAF", 710, "South Africa", 18),
+    Region::country("ZM", "ZMB", 894, "Zambia", 14),
+    Region::country("ZW", "ZWE", 716, "Zimbabwe", 14),
+];
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn test_tables_are_consistent() {
+        assert!(COUNTRIES.is_sorted_by_key(|region| region.code));
+        assert!(GROUPINGS.is_sorted_by_key(|region| region.numeric_code));
+
+        for region in GROUPINGS.iter().chain(COUNTRIES) {
+            assert_eq!(
+                Region::from_numeric_code(region.numeric_code),
+                Some(*region)
+            );
+            assert_eq!(Region::from_code(region.code), Some(*region));
+
+            if region.parent != NONE {
+                assert!(Region::world().contains(region), "{}", region.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_code_conversion() {
+        let germany = Region::from_code("de");
+        assert_eq!(germany.and_then(|r| r.alpha3_code()), Some("DEU"));
+        assert_eq!(germany.map(|r| r.numeric_code()), Some(276));
+        assert_eq!(Region::from_code("deu"), germany);
+        assert_eq!(Region::from_code("276"), germany);
+
+        assert_eq!(Region::from_code("ZZ"), None);
+        assert_eq!(Region::from_code("D1"), None);
+        assert_eq!(Region::from_code(""), None);
+    }
+
+    #[test]
+    fn test_containment() {
+        let brazil = Region::from_code("BR");
+        let south_america = Region::from_code("005");
+        let americas = Region::from_code("019");
+
+        assert_eq!(brazil.and_then(|r| r.containing_region()), south_america);
+        assert_eq!(brazil.and_then(|r| r.continent()), americas);
+        assert!(americas.zip(brazil).is_some_and(|(a, b)| a.contains(&b)));
+        assert!(brazil.is_some_and(|b| !b.contains(&b)));
+
+        assert_eq!(americas.and_then(|r| r.continent()), None);
+        assert_eq!(Region::from_code("AQ").and_then(|r| r.continent()), None);
+
+        let continents: Vec<_> = Region::world()
+            .sub_regions()
+            .filter(|region| !region.is_iso_region())
+            .map(|region| region.name())
+            .collect();
+        assert_eq!(
+            continents,
+            ["Africa", "Oceania", "Americas", "Asia", "Europe"]
+        );
+    }
+}
@@ -0,0 +1,627 @@
+//! A minimal logging facade for `no_std` environments.
+//!
+//! Code emits records with the [`error!`](crate::error), [`warn!`](crate::warn),
+//! [`info!`](crate::info), [`debug!`](crate::debug) and [`trace!`](crate::trace) macros.
+//! Records below the [minimum level](set_minimum_level) are discarded before their
+//! message is formatted; the rest go to the [`Logger`] registered once with
+//! [`set_logger`]. Until a logger is registered, records are discarded.
+//!
+//! [`FormatSink`] is a ready-made logger that turns records into lines of text and
+//! passes them to a function, such as one that writes to a serial port.
+//!
+//! # Examples
+//!
+//! ```
+//! use core::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! use libx::logging::{self, Level, Logger, Record};
+//!
+//! struct Counter(AtomicUsize);
+//!
+//! impl Logger for Counter {
+//!     fn log(&self, record: &Record<'_>) {
+//!         self.0.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! }
+//!
+//! static COUNTER: Counter = Counter(AtomicUsize::new(0));
+//!
+//! logging::set_logger(&COUNTER).unwrap();
+//! logging::set_minimum_level(Some(Level::Info));
+//!
+//! libx::info!("started in {} ms", 12);
+//! libx::debug!("discarded");
+//!
+//! assert_eq!(COUNTER.0.load(Ordering::Relaxed), 1);
+//! ```
+
+use alloc::string::String;
+use core::{
+    fmt::{self, Write},
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::{
+    errors::LoggingError,
+    formatting::{ByteCountFormatter, NumberFormatter},
+    sync::OnceLock,
+    terminal::{Style, TerminalColor},
+    time::MonotonicClock,
+};
+
+/// How important a record is, from the most verbose to the most severe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(u8)]
+pub enum Level {
+    /// Very detailed tracing of the program's flow.
+    Trace,
+
+    /// Information useful while debugging.
+    Debug,
+
+    /// Notable events in normal operation.
+    Info,
+
+    /// Unexpected events the program recovered from.
+    Warn,
+
+    /// Failures.
+    Error,
+}
+
+impl Level {
+    const ALL: [Self; 5] = [
+        Self::Trace,
+        Self::Debug,
+        Self::Info,
+        Self::Warn,
+        Self::Error,
+    ];
+
+    /// Returns the name of the level in capitals, such as `"WARN"`.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Trace => "TRACE",
+            Self::Debug => "DEBUG",
+            Self::Info => "INFO",
+            Self::Warn => "WARN",
+            Self::Error => "ERROR",
+        }
+    }
+
+    fn style(self) -> Style {
+        match self {
+            Self::Trace => Style::new().dim(),
+            Self::Debug => Style::new().with_foreground(TerminalColor::Blue),
+            Self::Info => Style::new().with_foreground(TerminalColor::Green),
+            Self::Warn => Style::new().with_foreground(TerminalColor::Yellow),
+            Self::Error => Style::new().with_foreground(TerminalColor::Red).bold(),
+        }
+    }
+}
+
+/// Pads like a string, so `{:<5}` lines up the level names.
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A log message together with where it came from.
+#[derive(Debug, Clone, Copy)]
+pub struct Record<'a> {
+    level: Level,
+    target: &'a str,
+    arguments: fmt::Arguments<'a>,
+    file: &'static str,
+    line: u32,
+}
+
+impl<'a> Record<'a> {
+    /// Creates a record without a source location.
+    #[must_use]
+    pub const fn new(level: Level, target: &'a str, arguments: fmt::Arguments<'a>) -> Self {
+        Self {
+            level,
+            target,
+            arguments,
+            file: "",
+            line: 0,
+        }
+    }
+
+    /// Sets the source file and line the record was emitted from.
+    #[must_use]
+    pub const fn with_location(mut self, file: &'static str, line: u32) -> Self {
+        self.file = file;
+        self.line = line;
+        self
+    }
+
+    /// Returns the level of the record.
+    #[must_use]
+    pub const fn level(&self) -> Level {
+        self.level
+    }
+
+    /// Returns the part of the program the record is about, by default its module path.
+    #[must_use]
+    pub const fn target(&self) -> &'a str {
+        self.target
+    }
+
+    /// Returns the message, ready to be written with `{}`.
+    #[must_use]
+    pub const fn arguments(&self) -> &fmt::Arguments<'a> {
+        &self.arguments
+    }
+
+    /// Returns the source file the record was emitted from, or an empty string.
+    #[must_use]
+    pub const fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Returns the source line the record was emitted from, or zero.
+    #[must_use]
+    pub const fn line(&self) -> u32 {
+        self.line
+    }
+}
+
+/// A destination for log records.
+///
+/// Loggers are shared between threads through [`set_logger`], so they must be
+/// [`Sync`] and use interior mutability for any state.
+pub trait Logger: Sync {
+    /// Returns `true` if records with `level` and `target` should be logged.
+    ///
+    /// This is checked, after the global [minimum level](minimum_level), before a
+    /// message is formatted. The default accepts every record.
+    fn enabled(&self, level: Level, target: &str) -> bool {
+        let _ = (level, target);
+        true
+    }
+
+    /// Logs `record`.
+    fn log(&self, record: &Record<'_>);
+
+    /// Writes out any buffered records. The default does nothing.
+    fn flush(&self) {}
+}
+
+static LOGGER: OnceLock<&'static dyn Logger> = OnceLock::new();
+
+/// The minimum level as a `Level` discriminant, or `OFF`.
+static MINIMUM_LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+const OFF: u8 = u8::MAX;
+
+/// Registers the logger every record is sent to.
+///
+/// # Errors
+///
+/// Returns [`LoggingError::AlreadyInitialized`] if a logger was already registered.
+pub fn set_logger(logger: &'static dyn Logger) -> Result<(), LoggingError> {
+    LOGGER
+        .set(logger)
+        .map_err(|_| LoggingError::AlreadyInitialized)
+}
+
+/// Returns the registered logger.
+#[must_use]
+pub fn logger() -> Option<&'static dyn Logger> {
+    LOGGER.get().copied()
+}
+
+/// Sets the least severe level that is logged; `None` turns logging off.
+///
+/// The default is [`Level::Info`].
+pub fn set_minimum_level(level: Option<Level>) {
+    MINIMUM_LEVEL.store(level.map_or(OFF, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Returns the least severe level that is logged, or `None` if logging is off.
+#[must_use]
+pub fn minimum_level() -> Option<Level> {
+    let raw = MINIMUM_LEVEL.load(Ordering::Relaxed);
+    Level::ALL.into_iter().find(|&level| level as u8 == raw)
+}
+
+/// Returns `true` if a record with `level` and `target` would be logged.
+#[must_use]
+pub fn enabled(level: Level, target: &str) -> bool {
+    // No level reaches `OFF`, so this also handles logging being turned off.
+    level as u8 >= MINIMUM_LEVEL.load(Ordering::Relaxed)
+        && logger().is_some_and(|logger| logger.enabled(level, target))
+}
+
+/// Sends `record` to the registered logger if it is [`enabled`].
+///
+/// The logging macros call this; it is rarely needed directly.
+pub fn log(record: &Record<'_>) {
+    if let Some(logger) = logger()
+        && enabled(record.level, record.target)
+    {
+        logger.log(record);
+    }
+}
+
+/// Flushes the registered logger.
+pub fn flush() {
+    if let Some(logger) = logger() {
+        logger.flush();
+    }
+}
+
+/// A byte count that displays as a file size, such as `1.5 MB`, for use in log
+/// messages.
+///
+/// # Examples
+///
+/// ```
+/// use libx::logging::ByteCount;
+///
+/// assert_eq!(format!("read {}", ByteCount(1_536_000)), "read 1.5 MB");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ByteCount(pub i64);
+
+impl fmt::Display for ByteCount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        ByteCountFormatter::new()
+            .write(self.0, f)
+            .map_err(|_| fmt::Error)
+    }
+}
+
+/// A logger that writes each record as a line of text.
+///
+/// A line reads `[    12.345] WARN  target: message`: the time since the clock's
+/// origin in seconds, if a clock was given, then the level, target and message. Each
+/// line is passed to the output function without a trailing newline.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::logging::{FormatSink, Level, Record};
+/// use libx::time::MonotonicClock;
+///
+/// struct Uptime;
+///
+/// impl MonotonicClock for Uptime {
+///     fn now(&self) -> Duration {
+///         Duration::from_millis(12_345)
+///     }
+/// }
+///
+/// static UPTIME: Uptime = Uptime;
+///
+/// let sink = FormatSink::new(|line| {
+///     // Write `line` to a console or serial port here.
+/// })
+/// .with_clock(&UPTIME);
+///
+/// let record = Record::new(Level::Warn, "net", format_args!("retrying"));
+/// assert_eq!(sink.format(&record), "[    12.345] WARN  net: retrying");
+/// ```
+pub struct FormatSink {
+    output: fn(&str),
+    clock: Option<&'static (dyn MonotonicClock + Sync)>,
+    timestamp_formatter: NumberFormatter,
+    minimum_level: Level,
+    uses_colors: bool,
+}
+
+impl FormatSink {
+    /// Creates a sink that passes lines to `output`, accepts every level and writes no
+    /// timestamps or colors.
+    #[must_use]
+    pub const fn new(output: fn(&str)) -> Self {
+        Self {
+            output,
+            clock: None,
+            timestamp_formatter: NumberFormatter::new()
+                .with_minimum_fraction_digits(3)
+                .with_maximum_fraction_digits(3),
+            minimum_level: Level::Trace,
+            uses_colors: false,
+        }
+    }
+
+    /// Sets the clock timestamps are read from.
+    #[must_use]
+    pub const fn with_clock(mut self, clock: &'static (dyn MonotonicClock + Sync)) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Sets the least severe level the sink accepts, on top of the global minimum.
+    #[must_use]
+    pub const fn with_minimum_level(mut self, level: Level) -> Self {
+        self.minimum_level = level;
+        self
+    }
+
+    /// Sets whether level names are colored with ANSI escape sequences.
+    #[must_use]
+    pub const fn with_uses_colors(mut self, uses_colors: bool) -> Self {
+        self.uses_colors = uses_colors;
+        self
+    }
+
+    /// Returns the least severe level the sink accepts.
+    #[must_use]
+    pub const fn minimum_level(&self) -> Level {
+        self.minimum_level
+    }
+
+    /// Returns `true` if level names are colored.
+    #[must_use]
+    pub const fn uses_colors(&self) -> bool {
+        self.uses_colors
+    }
+
+    /// Formats `record` as the line the sink would output.
+    #[must_use]
+    pub fn format(&self, record: &Record<'_>) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(record, &mut output);
+        output
+    }
+
+    fn write<W: Write>(&self, record: &Record<'_>, sink: &mut W) -> fmt::Result {
+        if let Some(clock) = self.clock {
+            sink.write_char('[')?;
+            self.timestamp_formatter
+                .write_fixed_width(clock.now().as_secs_f64(), 10, sink)
+                .map_err(|_| fmt::Error)?;
+            sink.write_str("] ")?;
+        }
+
+        if self.uses_colors {
+            write!(sink, "{}", record.level.style().styled(record.level))?;
+            // Pad outside the escape sequences so colored lines stay aligned.
+            for _ in record.level.as_str().len()..5 {
+                sink.write_char(' ')?;
+            }
+        } else {
+            write!(sink, "{:<5}", record.level)?;
+        }
+
+        write!(sink, " {}: {}", record.target, record.arguments)
+    }
+}
+
+impl Logger for FormatSink {
+    fn enabled(&self, level: Level, _target: &str) -> bool {
+        level >= self.minimum_level
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        (self.output)(&self.format(record));
+    }
+}
+
+impl fmt::Debug for FormatSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormatSink")
+            .field("has_clock", &self.clock.is_some())
+            .field("minimum_level", &self.minimum_level)
+            .field("uses_colors", &self.uses_colors)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Logs a message at a given [`Level`].
+///
+/// The target defaults to the calling module's path and can be set with
+/// `target: "name",` before the level. The message takes [`format!`](alloc::format)
+/// arguments and is only formatted if the record is [enabled](crate::logging::enabled).
+///
+/// # Examples
+///
+/// ```
+/// use libx::logging::Level;
+///
+/// let attempt = 3;
+/// libx::log!(Level::Warn, "retrying, attempt {attempt}");
+/// libx::log!(target: "net", Level::Error, "connection lost");
+/// ```
+#[macro_export]
+macro_rules! log {
+    (target: $target:expr, $level:expr, $($argument:tt)+) => {{
+        let level = $level;
+        let target = $target;
+
+        if $crate::logging::enabled(level, target) {
+            $crate::logging::log(
+                &$crate::logging::Record::new(level, target, ::core::format_args!($($argument)+))
+                    .with_location(::core::file!(), ::core::line!()),
+            );
+        }
+    }};
+    ($level:expr, $($argument:tt)+) => {
+        $crate::log!(target: ::core::module_path!(), $level, $($argument)+)
+    };
+}
+
+/// Logs a message at [`Level::Error`](crate::logging::Level::Error), as
+/// [`log!`](crate::log) does.
+#[macro_export]
+macro_rules! error {
+    (target: $target:expr, $($argument:tt)+) => {
+        $crate::log!(target: $target, $crate::logging::Level::Error, $($argument)+)
+    };
+    ($($argument:tt)+) => {
+        $crate::log!($crate::logging::Level::Error, $($argument)+)
+    };
+}
+
+/// Logs a message at [`Level::Warn`](crate::logging::Level::Warn), as
+/// [`log!`](crate::log) does.
+#[macro_export]
+macro_rules! warn {
+    (target: $target:expr, $($argument:tt)+) => {
+        $crate::log!(target: $target, $crate::logging::Level::Warn, $($argument)+)
+    };
+    ($($argument:tt)+) => {
+        $crate::log!($crate::logging::Level::Warn, $($argument)+)
+    };
+}
+
+/// Logs a message at [`Level::Info`](crate::logging::Level::Info), as
+/// [`log!`](crate::log) does.
+#[macro_export]
+macro_rules! info {
+    (target: $target:expr, $($argument:tt)+) => {
+        $crate::log!(target: $target, $crate::logging::Level::Info, $($argument)+)
+    };
+    ($($argument:tt)+) => {
+        $crate::log!($crate::logging::Level::Info, $($argument)+)
+    };
+}
+
+/// Logs a message at [`Level::Debug`](crate::logging::Level::Debug), as
+/// [`log!`](crate::log) does.
+#[macro_export]
+macro_rules! debug {
+    (target: $target:expr, $($argument:tt)+) => {
+        $crate::log!(target: $target, $crate::logging::Level::Debug, $($argument)+)
+    };
+    ($($argument:tt)+) => {
+        $crate::log!($crate::logging::Level::Debug, $($argument)+)
+    };
+}
+
+/// Logs a message at [`Level::Trace`](crate::logging::Level::Trace), as
+/// [`log!`](crate::log) does.
+#[macro_export]
+macro_rules! trace {
+    (target: $target:expr, $($argument:tt)+) => {
+        $crate::log!(target: $target, $crate::logging::Level::Trace, $($argument)+)
+    };
+    ($($argument:tt)+) => {
+        $crate::log!($crate::logging::Level::Trace, $($argument)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use core::{sync::atomic::AtomicUsize, time::Duration};
+
+    use super::*;
+
+    /// Counts the records it receives per level and remembers the last target.
+    struct Recorder {
+        counts: [AtomicUsize; 5],
+        last_line: AtomicUsize,
+    }
+
+    impl Logger for Recorder {
+        fn enabled(&self, _level: Level, target: &str) -> bool {
+            target != "muted"
+        }
+
+        fn log(&self, record: &Record<'_>) {
+            self.counts[record.level() as usize].fetch_add(1, Ordering::Relaxed);
+            self.last_line
+                .store(record.line() as usize, Ordering::Relaxed);
+        }
+    }
+
+    static RECORDER: Recorder = Recorder {
+        counts: [const { AtomicUsize::new(0) }; 5],
+        last_line: AtomicUsize::new(0),
+    };
+
+    struct FixedClock;
+
+    impl MonotonicClock for FixedClock {
+        fn now(&self) -> Duration {
+            Duration::from_millis(3_250)
+        }
+    }
+
+    static CLOCK: FixedClock = FixedClock;
+
+    // The global logger can only be set once, so one test covers it.
+    #[test]
+    fn test_global_logger_and_macros() {
+        assert!(!enabled(Level::Error, "app"));
+
+        set_logger(&RECORDER).expect("no logger registered yet");
+        assert_eq!(
+            set_logger(&RECORDER).map_err(|error| error.to_string()),
+            Err("a logger has already been registered".into())
+        );
+
+        let count = |level: Level| RECORDER.counts[level as usize].load(Ordering::Relaxed);
+
+        assert_eq!(minimum_level(), Some(Level::Info));
+        crate::info!("one");
+        crate::warn!(target: "app", "two {}", 2);
+        let warned_at = line!() - 1;
+        crate::debug!("filtered");
+        crate::error!(target: "muted", "rejected by the logger");
+        assert_eq!((count(Level::Info), count(Level::Warn)), (1, 1));
+        assert_eq!((count(Level::Debug), count(Level::Error)), (0, 0));
+        assert_eq!(
+            RECORDER.last_line.load(Ordering::Relaxed),
+            warned_at as usize
+        );
+
+        set_minimum_level(Some(Level::Trace));
+        crate::trace!("traced");
+        assert_eq!(count(Level::Trace), 1);
+
+        set_minimum_level(None);
+        assert_eq!(minimum_level(), None);
+        crate::error!("off");
+        assert_eq!(count(Level::Error), 0);
+
+        set_minimum_level(Some(Level::Info));
+        flush();
+    }
+
+    #[test]
+    fn test_format_sink() {
+        let sink = FormatSink::new(|_| {}).with_minimum_level(Level::Warn);
+        let free = ByteCount(2_048);
+
+        assert_eq!(
+            sink.format(&Record::new(
+                Level::Error,
+                "disk",
+                format_args!("{free} free")
+            )),
+            "ERROR disk: 2 kB free"
+        );
+        assert!(!sink.enabled(Level::Info, "disk"));
+        assert!(sink.enabled(Level::Warn, "disk"));
+
+        let timed = FormatSink::new(|_| {})
+            .with_clock(&CLOCK)
+            .with_uses_colors(true);
+        let record = Record::new(Level::Info, "boot", format_args!("ready"));
+
+        assert_eq!(
+            timed.format(&record),
+            "[     3.250] \x1b[32mINFO\x1b[0m  boot: ready"
+        );
+    }
+
+    #[test]
+    fn test_level_display() {
+        use alloc::format;
+
+        assert_eq!(format!("[{:<5}]", Level::Warn), "[WARN ]");
+        assert_eq!(Level::Error.to_string(), "ERROR");
+        assert!(Level::Trace < Level::Error);
+    }
+}
@@ -0,0 +1,529 @@
+//! Lock-free counters, gauges and histograms for instrumenting services.
+//!
+//! Every metric is a handful of relaxed atomics with a `const` constructor, so metrics
+//! are usually `static` items updated from anywhere without locking. A [`Registry`]
+//! collects references to them under `'static` names and renders them in the Prometheus
+//! text exposition format through its [`Display`](fmt::Display) implementation.
+//!
+//! The module is only available on targets with 64-bit atomics.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::metrics::{Counter, Gauge, HistogramMetric, Registry};
+//!
+//! static REQUESTS: Counter = Counter::new();
+//! static CONNECTIONS: Gauge = Gauge::new();
+//! static LATENCY_MS: HistogramMetric<3> = HistogramMetric::new([10, 100, 1_000]);
+//!
+//! REQUESTS.increment();
+//! CONNECTIONS.add(2);
+//! LATENCY_MS.observe(42);
+//!
+//! let mut registry = Registry::new();
+//! registry.register_counter("requests_total", "Requests served.", &REQUESTS)?;
+//! registry.register_gauge("connections", "Open connections.", &CONNECTIONS)?;
+//! registry.register_histogram("latency_ms", "", &LATENCY_MS)?;
+//!
+//! let text = registry.to_string();
+//! assert!(text.contains("# TYPE requests_total counter\nrequests_total 1\n"));
+//! assert!(text.contains("latency_ms_bucket{le=\"100\"} 1\n"));
+//! # Ok::<(), libx::errors::MetricsError>(())
+//! ```
+
+use alloc::collections::{BTreeMap, btree_map::Entry};
+use core::{
+    fmt,
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
+};
+
+use crate::errors::MetricsError;
+
+/// A count that only goes up, such as the number of requests served.
+#[derive(Debug, Default)]
+pub struct Counter {
+    value: AtomicU64,
+}
+
+impl Counter {
+    /// Creates a counter at zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: AtomicU64::new(0),
+        }
+    }
+
+    /// Adds one to the counter.
+    pub fn increment(&self) {
+        self.add(1);
+    }
+
+    /// Adds `amount` to the counter, wrapping around on overflow.
+    pub fn add(&self, amount: u64) {
+        self.value.fetch_add(amount, Ordering::Relaxed);
+    }
+
+    /// Returns the current count.
+    #[must_use]
+    pub fn get(&self) -> u64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// A value that can go up and down, such as the number of open connections.
+#[derive(Debug, Default)]
+pub struct Gauge {
+    value: AtomicI64,
+}
+
+impl Gauge {
+    /// Creates a gauge at zero.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            value: AtomicI64::new(0),
+        }
+    }
+
+    /// Replaces the value of the gauge.
+    pub fn set(&self, value: i64) {
+        self.value.store(value, Ordering::Relaxed);
+    }
+
+    /// Adds one to the gauge.
+    pub fn increment(&self) {
+        self.add(1);
+    }
+
+    /// Subtracts one from the gauge.
+    pub fn decrement(&self) {
+        self.add(-1);
+    }
+
+    /// Adds `amount`, which may be negative, to the gauge, wrapping around on overflow.
+    pub fn add(&self, amount: i64) {
+        self.value.fetch_add(amount, Ordering::Relaxed);
+    }
+
+    /// Returns the current value.
+    #[must_use]
+    pub fn get(&self) -> i64 {
+        self.value.load(Ordering::Relaxed)
+    }
+}
+
+/// A distribution of observed values, counted in `N` buckets with fixed upper bounds.
+///
+/// A value falls in the first bucket whose bound is at least the value. Values above
+/// every bound are counted in an implicit overflow bucket, so [`count`](Self::count)
+/// includes them.
+///
+/// # Examples
+///
+/// ```
+/// use libx::metrics::HistogramMetric;
+///
+/// let histogram = HistogramMetric::new([1, 5]);
+///
+/// for value in [0, 1, 3, 9] {
+///     histogram.observe(value);
+/// }
+///
+/// assert_eq!(histogram.bucket_counts(), [2, 1]);
+/// assert_eq!(histogram.count(), 4);
+/// assert_eq!(histogram.sum(), 13);
+/// ```
+#[derive(Debug)]
+pub struct HistogramMetric<const N: usize> {
+    bounds: [u64; N],
+    buckets: [AtomicU64; N],
+    overflow: AtomicU64,
+    sum: AtomicU64,
+}
+
+impl<const N: usize> HistogramMetric<N> {
+    /// Creates an empty histogram with the given bucket bounds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bounds` is not strictly increasing.
+    #[must_use]
+    pub const fn new(bounds: [u64; N]) -> Self {
+        let mut index = 1;
+
+        while index < N {
+            assert!(
+                bounds[index - 1] < bounds[index],
+                "histogram bounds must be strictly increasing"
+            );
+            index += 1;
+        }
+
+        Self {
+            bounds,
+            buckets: [const { AtomicU64::new(0) }; N],
+            overflow: AtomicU64::new(0),
+            sum: AtomicU64::new(0),
+        }
+    }
+
+    /// Records one observation of `value`.
+    pub fn observe(&self, value: u64) {
+        let index = self.bounds.partition_point(|&bound| bound < value);
+        let bucket = self.buckets.get(index).unwrap_or(&self.overflow);
+
+        bucket.fetch_add(1, Ordering::Relaxed);
+        self.sum.fetch_add(value, Ordering::Relaxed);
+    }
+
+    /// Returns the upper bounds of the buckets.
+    #[must_use]
+    pub const fn bounds(&self) -> &[u64; N] {
+        &self.bounds
+    }
+
+    /// Returns the number of observations in each bucket, excluding the overflow bucket.
+    #[must_use]
+    pub fn bucket_counts(&self) -> [u64; N] {
+        core::array::from_fn(|index| self.buckets[index].load(Ordering::Relaxed))
+    }
+
+    /// Returns the total number of observations.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        total_count(&self.buckets, &self.overflow)
+    }
+
+    /// Returns the sum of every observed value, wrapping around on overflow.
+    #[must_use]
+    pub fn sum(&self) -> u64 {
+        self.sum.load(Ordering::Relaxed)
+    }
+}
+
+fn total_count(buckets: &[AtomicU64], overflow: &AtomicU64) -> u64 {
+    buckets.iter().chain([overflow]).fold(0, |total, bucket| {
+        total.wrapping_add(bucket.load(Ordering::Relaxed))
+    })
+}
+
+/// A histogram of any bucket count, seen through slices.
+#[derive(Debug, Clone, Copy)]
+struct HistogramView<'a> {
+    bounds: &'a [u64],
+    buckets: &'a [AtomicU64],
+    overflow: &'a AtomicU64,
+    sum: &'a AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Metric<'a> {
+    Counter(&'a Counter),
+    Gauge(&'a Gauge),
+    Histogram(HistogramView<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Registration<'a> {
+    help: &'static str,
+    metric: Metric<'a>,
+}
+
+/// A set of named metrics that can be rendered as text.
+///
+/// Metrics are rendered in the order of their names, each preceded by a `# HELP` line
+/// unless its help text is empty and by a `# TYPE` line. Histograms follow the
+/// Prometheus convention of cumulative `_bucket` series with an `le` label, followed by
+/// `_sum` and `_count`.
+#[derive(Debug, Clone, Default)]
+pub struct Registry<'a> {
+    metrics: BTreeMap<&'static str, Registration<'a>>,
+}
+
+impl<'a> Registry<'a> {
+    /// Creates an empty registry.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            metrics: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `counter` under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MetricsError::InvalidName`] if `name` is not a valid metric name, and
+    /// [`MetricsError::DuplicateName`] if it is already taken.
+    pub fn register_counter(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        counter: &'a Counter,
+    ) -> Result<(), MetricsError> {
+        self.register(name, help, Metric::Counter(counter))
+    }
+
+    /// Registers `gauge` under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MetricsError::InvalidName`] if `name` is not a valid metric name, and
+    /// [`MetricsError::DuplicateName`] if it is already taken.
+    pub fn register_gauge(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        gauge: &'a Gauge,
+    ) -> Result<(), MetricsError> {
+        self.register(name, help, Metric::Gauge(gauge))
+    }
+
+    /// Registers `histogram` under `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MetricsError::InvalidName`] if `name` is not a valid metric name, and
+    /// [`MetricsError::DuplicateName`] if it is already taken.
+    pub fn register_histogram<const N: usize>(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        histogram: &'a HistogramMetric<N>,
+    ) -> Result<(), MetricsError> {
+        let view = HistogramView {
+            bounds: &histogram.bounds,
+            buckets: &histogram.buckets,
+            overflow: &histogram.overflow,
+            sum: &histogram.sum,
+        };
+
+        self.register(name, help, Metric::Histogram(view))
+    }
+
+    fn register(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        metric: Metric<'a>,
+    ) -> Result<(), MetricsError> {
+        if !is_valid_name(name) {
+            return Err(MetricsError::InvalidName(name));
+        }
+
+        match self.metrics.entry(name) {
+            Entry::Occupied(_) => Err(MetricsError::DuplicateName(name)),
+            Entry::Vacant(entry) => {
+                entry.insert(Registration { help, metric });
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the counter registered under `name`.
+    #[must_use]
+    pub fn counter(&self, name: &str) -> Option<&'a Counter> {
+        match self.metrics.get(name)?.metric {
+            Metric::Counter(counter) => Some(counter),
+            _ => None,
+        }
+    }
+
+    /// Returns the gauge registered under `name`.
+    #[must_use]
+    pub fn gauge(&self, name: &str) -> Option<&'a Gauge> {
+        match self.metrics.get(name)?.metric {
+            Metric::Gauge(gauge) => Some(gauge),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if a metric of any kind is registered under `name`.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.metrics.contains_key(name)
+    }
+
+    /// Returns the registered names in order.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.metrics.keys().copied()
+    }
+
+    /// Returns the number of registered metrics.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.metrics.len()
+    }
+
+    /// Returns `true` if no metrics are registered.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.metrics.is_empty()
+    }
+}
+
+impl fmt::Display for Registry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (name, registration) in &self.metrics {
+            if !registration.help.is_empty() {
+                write!(f, "# HELP {name} ")?;
+                write_escaped_help(f, registration.help)?;
+                f.write_str("\n")?;
+            }
+
+            match registration.metric {
+                Metric::Counter(counter) => {
+                    writeln!(f, "# TYPE {name} counter\n{name} {}", counter.get())?;
+                }
+                Metric::Gauge(gauge) => {
+                    writeln!(f, "# TYPE {name} gauge\n{name} {}", gauge.get())?;
+                }
+                Metric::Histogram(view) => {
+                    writeln!(f, "# TYPE {name} histogram")?;
+
+                    let mut cumulative = 0_u64;
+
+                    for (bound, bucket) in view.bounds.iter().zip(view.buckets) {
+                        cumulative = cumulative.wrapping_add(bucket.load(Ordering::Relaxed));
+                        writeln!(f, "{name}_bucket{{le=\"{bound}\"}} {cumulative}")?;
+                    }
+
+                    let count = total_count(view.buckets, view.overflow);
+                    let sum = view.sum.load(Ordering::Relaxed);
+
+                    writeln!(f, "{name}_bucket{{le=\"+Inf\"}} {count}")?;
+                    writeln!(f, "{name}_sum {sum}\n{name}_count {count}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn is_valid_name(name: &str) -> bool {
+    let mut bytes = name.bytes();
+
+    bytes
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || matches!(first, b'_' | b':'))
+        && bytes.all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b':'))
+}
+
+/// Writes `help` with backslashes and line feeds escaped, as the text format requires.
+fn write_escaped_help(f: &mut fmt::Formatter<'_>, help: &str) -> fmt::Result {
+    for character in help.chars() {
+        match character {
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            _ => fmt::Write::write_char(f, character)?,
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn test_counter_and_gauge() {
+        let counter = Counter::new();
+        counter.increment();
+        counter.add(4);
+        assert_eq!(counter.get(), 5);
+
+        let gauge = Gauge::new();
+        gauge.set(3);
+        gauge.decrement();
+        gauge.add(-5);
+        assert_eq!(gauge.get(), -3);
+    }
+
+    #[test]
+    fn test_histogram_buckets() {
+        let histogram = HistogramMetric::new([10, 20]);
+
+        for value in [10, 11, 20, 21, 0] {
+            histogram.observe(value);
+        }
+
+        assert_eq!(histogram.bucket_counts(), [2, 2]);
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.sum(), 62);
+    }
+
+    #[test]
+    #[should_panic = "histogram bounds must be strictly increasing"]
+    fn test_histogram_unsorted_bounds() {
+        let _ = HistogramMetric::new([5, 5]);
+    }
+
+    #[test]
+    fn test_registry_rejects_bad_names() {
+        let counter = Counter::new();
+        let mut registry = Registry::new();
+
+        assert_eq!(
+            registry.register_counter("jobs_total", "", &counter),
+            Ok(())
+        );
+        assert_eq!(
+            registry.register_counter("jobs_total", "", &counter),
+            Err(MetricsError::DuplicateName("jobs_total"))
+        );
+        assert_eq!(
+            registry.register_counter("2xx", "", &counter),
+            Err(MetricsError::InvalidName("2xx"))
+        );
+        assert_eq!(
+            registry.register_counter("", "", &counter),
+            Err(MetricsError::InvalidName(""))
+        );
+
+        assert!(
+            registry
+                .counter("jobs_total")
+                .is_some_and(|found| core::ptr::eq(found, &raw const counter))
+        );
+        assert!(registry.gauge("jobs_total").is_none());
+        assert_eq!(registry.len(), 1);
+    }
+
+    #[test]
+    fn test_text_exposition() {
+        let counter = Counter::new();
+        let gauge = Gauge::new();
+        let histogram = HistogramMetric::new([1, 2]);
+
+        counter.add(7);
+        gauge.set(-2);
+        histogram.observe(1);
+        histogram.observe(5);
+
+        let mut registry = Registry::new();
+        let _ = registry.register_histogram("b_seconds", "", &histogram);
+        let _ = registry.register_gauge("a_depth", "Queue depth.\nIn items.", &gauge);
+        let _ = registry.register_counter("c_total", "Paths like C:\\", &counter);
+
+        assert_eq!(
+            registry.to_string(),
+            "# HELP a_depth Queue depth.\\nIn items.\n\
+             # TYPE a_depth gauge\n\
+             a_depth -2\n\
+             # TYPE b_seconds histogram\n\
+             b_seconds_bucket{le=\"1\"} 1\n\
+             b_seconds_bucket{le=\"2\"} 1\n\
+             b_seconds_bucket{le=\"+Inf\"} 2\n\
+             b_seconds_sum 6\n\
+             b_seconds_count 2\n\
+             # HELP c_total Paths like C:\\\\\n\
+             # TYPE c_total counter\n\
+             c_total 7\n"
+        );
+    }
+}
@@ -0,0 +1,590 @@
+//! Media types (MIME types) and file-signature detection.
+//!
+//! [`MediaType`] parses and formats values such as `text/html; charset=utf-8`, follows the
+//! case rules of RFC 9110, and offers constants for the most common types. It can also be
+//! looked up from a file extension, and guessed from the first bytes of a file with
+//! [`MediaType::sniff`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::mime::MediaType;
+//!
+//! let media: MediaType = "Text/HTML; Charset=\"UTF-8\"".parse().unwrap();
+//! assert_eq!(media.essence(), "text/html");
+//! assert_eq!(media.parameter("charset"), Some("UTF-8"));
+//! assert_eq!(media.to_string(), "text/html; charset=UTF-8");
+//!
+//! assert_eq!(MediaType::from_extension("PNG"), Some(MediaType::IMAGE_PNG));
+//! assert_eq!(MediaType::sniff(b"%PDF-1.7\n"), Some(MediaType::APPLICATION_PDF));
+//! ```
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use core::{fmt, str::FromStr};
+
+use crate::{errors::ParseError, path::PathStyle};
+
+/// A media type: a top-level type, a subtype and optional parameters.
+///
+/// The type, subtype and parameter names are case-insensitive and stored in lowercase.
+/// Parameter values are kept as written, without the quotes of a quoted string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MediaType {
+    top_level: Cow<'static, str>,
+    subtype: Cow<'static, str>,
+    parameters: Vec<(String, String)>,
+}
+
+macro_rules! media_types {
+    ($($(#[$meta:meta])* $name:ident = $top_level:literal / $subtype:literal;)*) => {
+        impl MediaType {
+            $(
+                $(#[$meta])*
+                pub const $name: Self = Self::from_static($top_level, $subtype);
+            )*
+        }
+    };
+}
+
+media_types! {
+    /// `text/plain`
+    TEXT_PLAIN = "text" / "plain";
+    /// `text/html`
+    TEXT_HTML = "text" / "html";
+    /// `text/css`
+    TEXT_CSS = "text" / "css";
+    /// `text/csv`
+    TEXT_CSV = "text" / "csv";
+    /// `text/javascript`
+    TEXT_JAVASCRIPT = "text" / "javascript";
+    /// `text/markdown`
+    TEXT_MARKDOWN = "text" / "markdown";
+    /// `application/json`
+    APPLICATION_JSON = "application" / "json";
+    /// `application/xml`
+    APPLICATION_XML = "application" / "xml";
+    /// `application/octet-stream`, for arbitrary binary data.
+    APPLICATION_OCTET_STREAM = "application" / "octet-stream";
+    /// `application/pdf`
+    APPLICATION_PDF = "application" / "pdf";
+    /// `application/zip`
+    APPLICATION_ZIP = "application" / "zip";
+    /// `application/gzip`
+    APPLICATION_GZIP = "application" / "gzip";
+    /// `application/wasm`
+    APPLICATION_WASM = "application" / "wasm";
+    /// `application/x-www-form-urlencoded`
+    APPLICATION_FORM_URLENCODED = "application" / "x-www-form-urlencoded";
+    /// `multipart/form-data`
+    MULTIPART_FORM_DATA = "multipart" / "form-data";
+    /// `image/png`
+    IMAGE_PNG = "image" / "png";
+    /// `image/jpeg`
+    IMAGE_JPEG = "image" / "jpeg";
+    /// `image/gif`
+    IMAGE_GIF = "image" / "gif";
+    /// `image/webp`
+    IMAGE_WEBP = "image" / "webp";
+    /// `image/bmp`
+    IMAGE_BMP = "image" / "bmp";
+    /// `image/svg+xml`
+    IMAGE_SVG = "image" / "svg+xml";
+    /// `image/x-icon`
+    IMAGE_ICON = "image" / "x-icon";
+    /// `audio/mpeg`
+    AUDIO_MPEG = "audio" / "mpeg";
+    /// `audio/ogg`
+    AUDIO_OGG = "audio" / "ogg";
+    /// `audio/wav`
+    AUDIO_WAV = "audio" / "wav";
+    /// `audio/flac`
+    AUDIO_FLAC = "audio" / "flac";
+    /// `video/mp4`
+    VIDEO_MP4 = "video" / "mp4";
+    /// `font/woff`
+    FONT_WOFF = "font" / "woff";
+    /// `font/woff2`
+    FONT_WOFF2 = "font" / "woff2";
+}
+
+/// File extensions and their media types. The first extension listed for a media type is
+/// its preferred one.
+static EXTENSIONS: [(&str, MediaType); 34] = [
+    ("txt", MediaType::TEXT_PLAIN),
+    ("text", MediaType::TEXT_PLAIN),
+    ("html", MediaType::TEXT_HTML),
+    ("htm", MediaType::TEXT_HTML),
+    ("css", MediaType::TEXT_CSS),
+    ("csv", MediaType::TEXT_CSV),
+    ("js", MediaType::TEXT_JAVASCRIPT),
+    ("mjs", MediaType::TEXT_JAVASCRIPT),
+    ("md", MediaType::TEXT_MARKDOWN),
+    ("markdown", MediaType::TEXT_MARKDOWN),
+    ("json", MediaType::APPLICATION_JSON),
+    ("xml", MediaType::APPLICATION_XML),
+    ("bin", MediaType::APPLICATION_OCTET_STREAM),
+    ("pdf", MediaType::APPLICATION_PDF),
+    ("zip", MediaType::APPLICATION_ZIP),
+    ("gz", MediaType::APPLICATION_GZIP),
+    ("wasm", MediaType::APPLICATION_WASM),
+    ("png", MediaType::IMAGE_PNG),
+    ("jpg", MediaType::IMAGE_JPEG),
+    ("jpeg", MediaType::IMAGE_JPEG),
+    ("gif", MediaType::IMAGE_GIF),
+    ("webp", MediaType::IMAGE_WEBP),
+    ("bmp", MediaType::IMAGE_BMP),
+    ("svg", MediaType::IMAGE_SVG),
+    ("ico", MediaType::IMAGE_ICON),
+    ("mp3", MediaType::AUDIO_MPEG),
+    ("ogg", MediaType::AUDIO_OGG),
+    ("oga", MediaType::AUDIO_OGG),
+    ("wav", MediaType::AUDIO_WAV),
+    ("flac", MediaType::AUDIO_FLAC),
+    ("mp4", MediaType::VIDEO_MP4),
+    ("m4v", MediaType::VIDEO_MP4),
+    ("woff", MediaType::FONT_WOFF),
+    ("woff2", MediaType::FONT_WOFF2),
+];
+
+/// Bytes expected at an offset from the start of a file.
+type Magic = (usize, &'static [u8]);
+
+/// File signatures: every [`Magic`] must match for the media type to apply.
+static SIGNATURES: [(&[Magic], MediaType); 18] = [
+    (&[(0, b"\x89PNG\r\n\x1a\n")], MediaType::IMAGE_PNG),
+    (&[(0, b"\xff\xd8\xff")], MediaType::IMAGE_JPEG),
+    (&[(0, b"GIF87a")], MediaType::IMAGE_GIF),
+    (&[(0, b"GIF89a")], MediaType::IMAGE_GIF),
+    (&[(0, b"RIFF"), (8, b"WEBP")], MediaType::IMAGE_WEBP),
+    (&[(0, b"BM")], MediaType::IMAGE_BMP),
+    (&[(0, b"\0\0\x01\0")], MediaType::IMAGE_ICON),
+    (&[(0, b"%PDF-")], MediaType::APPLICATION_PDF),
+    (&[(0, b"PK\x03\x04")], MediaType::APPLICATION_ZIP),
+    (&[(0, b"\x1f\x8b")], MediaType::APPLICATION_GZIP),
+    (&[(0, b"\0asm")], MediaType::APPLICATION_WASM),
+    (&[(0, b"ID3")], MediaType::AUDIO_MPEG),
+    (&[(0, b"OggS")], MediaType::AUDIO_OGG),
+    (&[(0, b"RIFF"), (8, b"WAVE")], MediaType::AUDIO_WAV),
+    (&[(0, b"fLaC")], MediaType::AUDIO_FLAC),
+    (&[(4, b"ftyp")], MediaType::VIDEO_MP4),
+    (&[(0, b"wOFF")], MediaType::FONT_WOFF),
+    (&[(0, b"wOF2")], MediaType::FONT_WOFF2),
+];
+
+impl MediaType {
+    const fn from_static(top_level: &'static str, subtype: &'static str) -> Self {
+        Self {
+            top_level: Cow::Borrowed(top_level),
+            subtype: Cow::Borrowed(subtype),
+            parameters: Vec::new(),
+        }
+    }
+
+    /// Adds a parameter, replacing any existing parameter with the same name.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `name` is not a valid token.
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Result<Self, ParseError> {
+        validate_token(name, 0)?;
+        let name = name.to_ascii_lowercase();
+        self.parameters.retain(|(existing, _)| *existing != name);
+        self.parameters.push((name, String::from(value)));
+        Ok(self)
+    }
+
+    /// Returns the top-level type, such as `text`.
+    #[must_use]
+    pub fn top_level(&self) -> &str {
+        &self.top_level
+    }
+
+    /// Returns the subtype, such as `html` or `svg+xml`.
+    #[must_use]
+    pub fn subtype(&self) -> &str {
+        &self.subtype
+    }
+
+    /// Returns the structured syntax suffix of the subtype, such as `xml` for
+    /// `image/svg+xml`.
+    #[must_use]
+    pub fn suffix(&self) -> Option<&str> {
+        self.subtype.rsplit_once('+').map(|(_, suffix)| suffix)
+    }
+
+    /// Returns the type and subtype without parameters, such as `text/html`.
+    #[must_use]
+    pub fn essence(&self) -> String {
+        let mut essence = String::with_capacity(self.top_level.len() + self.subtype.len() + 1);
+        essence.push_str(&self.top_level);
+        essence.push('/');
+        essence.push_str(&self.subtype);
+        essence
+    }
+
+    /// Returns the value of the parameter `name`, compared case-insensitively.
+    #[must_use]
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(existing, _)| existing.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the parameters in the order they were given.
+    pub fn parameters(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.parameters
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Returns `true` if both media types have the same type and subtype, ignoring
+    /// parameters.
+    #[must_use]
+    pub fn essence_eq(&self, other: &Self) -> bool {
+        self.top_level == other.top_level && self.subtype == other.subtype
+    }
+
+    /// Returns the media type for a file extension, with or without its leading dot.
+    ///
+    /// Extensions are compared case-insensitively.
+    #[must_use]
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        let extension = extension.strip_prefix('.').unwrap_or(extension);
+
+        EXTENSIONS
+            .iter()
+            .find(|(known, _)| known.eq_ignore_ascii_case(extension))
+            .map(|(_, media)| media.clone())
+    }
+
+    /// Returns the media type for the extension of a native file path.
+    #[must_use]
+    pub fn from_path(path: &str) -> Option<Self> {
+        PathStyle::NATIVE
+            .extension(path)
+            .and_then(Self::from_extension)
+    }
+
+    /// Returns the preferred file extension for the media type, ignoring parameters.
+    #[must_use]
+    pub fn extension(&self) -> Option<&'static str> {
+        EXTENSIONS
+            .iter()
+            .find(|(_, media)| media.essence_eq(self))
+            .map(|(extension, _)| *extension)
+    }
+
+    /// Guesses the media type of a file from its first bytes.
+    ///
+    /// Only formats with a distinctive signature are recognized; text formats such as
+    /// HTML or JSON are not. A prefix of 16 bytes is enough for every known signature.
+    #[must_use]
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        SIGNATURES
+            .iter()
+            .find(|(parts, _)| {
+                parts.iter().all(|&(offset, magic)| {
+                    bytes
+                        .get(offset..)
+                        .is_some_and(|rest| rest.starts_with(magic))
+                })
+            })
+            .map(|(_, media)| media.clone())
+    }
+}
+
+impl fmt::Display for MediaType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.top_level, self.subtype)?;
+
+        for (name, value) in &self.parameters {
+            write!(f, "; {name}=")?;
+
+            if !value.is_empty() && value.chars().all(is_token_char) {
+                f.write_str(value)?;
+                continue;
+            }
+
+            f.write_str("\"")?;
+
+            for character in value.chars() {
+                if matches!(character, '"' | '\\') {
+                    f.write_str("\\")?;
+                }
+
+                write!(f, "{character}")?;
+            }
+
+            f.write_str("\"")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for MediaType {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (essence, parameters) = s.split_once(';').unwrap_or((s, ""));
+        let essence = essence.trim_end();
+        let (top_level, subtype) = essence.split_once('/').ok_or(ParseError::InvalidSyntax)?;
+        validate_token(top_level, 0)?;
+        validate_token(subtype, top_level.len() + 1)?;
+
+        Ok(Self {
+            top_level: Cow::Owned(top_level.to_ascii_lowercase()),
+            subtype: Cow::Owned(subtype.to_ascii_lowercase()),
+            parameters: parse_parameters(parameters, s.len() - parameters.len())?,
+        })
+    }
+}
+
+/// Parses `name=value` pairs separated by semicolons, starting at byte `offset` of the
+/// input.
+fn parse_parameters(input: &str, offset: usize) -> Result<Vec<(String, String)>, ParseError> {
+    let mut parameters: Vec<(String, String)> = Vec::new();
+    let mut position = 0;
+
+    while position < input.len() {
+        let rest = &input[position..];
+        let trimmed = rest.trim_start();
+        position += rest.len() - trimmed.len();
+
+        // Tolerate empty parameters, as in `text/plain;` or `a/b;; c=d`.
+        if trimmed.is_empty() || trimmed.starts_with(';') {
+            position += usize::from(!trimmed.is_empty());
+            continue;
+        }
+
+        let (name, after_name) = trimmed.split_once('=').ok_or(ParseError::InvalidSyntax)?;
+        validate_token(name, offset + position)?;
+        position += name.len() + 1;
+
+        let (value, length) = if after_name.starts_with('"') {
+            parse_quoted(after_name, offset + position)?
+        } else {
+            let value = after_name.split(';').next().unwrap_or_default().trim_end();
+            validate_token(value, offset + position)?;
+            (String::from(value), value.len())
+        };
+
+        position += length;
+        let rest = &input[position..];
+        let trimmed = rest.trim_start();
+
+        if !trimmed.is_empty() && !trimmed.starts_with(';') {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        position += rest.len() - trimmed.len();
+        let name = name.to_ascii_lowercase();
+
+        // Like most parsers, keep the first occurrence of a repeated parameter.
+        if !parameters.iter().any(|(existing, _)| *existing == name) {
+            parameters.push((name, value));
+        }
+    }
+
+    Ok(parameters)
+}
+
+/// Parses a quoted string at the start of `input`, returning its unescaped value and the
+/// number of bytes it spans.
+fn parse_quoted(input: &str, offset: usize) -> Result<(String, usize), ParseError> {
+    let mut value = String::new();
+    let mut characters = input.char_indices().skip(1);
+
+    while let Some((index, character)) = characters.next() {
+        match character {
+            '"' => return Ok((value, index + 1)),
+            '\\' => {
+                let (_, escaped) = characters.next().ok_or(ParseError::InvalidSyntax)?;
+                value.push(escaped);
+            }
+            '\t' | ' '..='~' => value.push(character),
+            _ if !character.is_ascii() => value.push(character),
+            _ => {
+                return Err(ParseError::InvalidCharacter {
+                    character,
+                    position: offset + index,
+                });
+            }
+        }
+    }
+
+    Err(ParseError::InvalidSyntax)
+}
+
+/// Returns `true` for the characters RFC 9110 allows in a token.
+const fn is_token_char(character: char) -> bool {
+    character.is_ascii_alphanumeric()
+        || matches!(
+            character,
+            '!' | '#'
+                | '$'
+                | '%'
+                | '&'
+                | '\''
+                | '*'
+                | '+'
+                | '-'
+                | '.'
+                | '^'
+                | '_'
+                | '`'
+                | '|'
+                | '~'
+        )
+}
+
+/// Checks that `token` is a non-empty token starting at byte `position` of the input.
+fn validate_token(token: &str, position: usize) -> Result<(), ParseError> {
+    if token.is_empty() {
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    match token.char_indices().find(|&(_, c)| !is_token_char(c)) {
+        Some((index, character)) => Err(ParseError::InvalidCharacter {
+            character,
+            position: position + index,
+        }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn media(text: &str) -> MediaType {
+        text.parse().expect("a valid media type")
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let parsed = media("Multipart/Form-Data; Boundary=abc123 ;charset=\"utf-8\"");
+
+        assert_eq!(parsed.top_level(), "multipart");
+        assert_eq!(parsed.subtype(), "form-data");
+        assert_eq!(parsed.parameter("BOUNDARY"), Some("abc123"));
+        assert_eq!(parsed.parameter("charset"), Some("utf-8"));
+        assert_eq!(
+            parsed.to_string(),
+            "multipart/form-data; boundary=abc123; charset=utf-8"
+        );
+        assert!(parsed.essence_eq(&MediaType::MULTIPART_FORM_DATA));
+        assert_ne!(parsed, MediaType::MULTIPART_FORM_DATA);
+
+        assert_eq!(media("text/plain;"), MediaType::TEXT_PLAIN);
+        assert_eq!(media("image/svg+xml").suffix(), Some("xml"));
+        assert_eq!(MediaType::TEXT_CSS.suffix(), None);
+        assert_eq!(
+            media("a/b; x=1; x=2").parameters().collect::<Vec<_>>(),
+            [("x", "1")]
+        );
+    }
+
+    #[test]
+    fn test_quoted_values() {
+        let parsed = media(r#"text/plain; title="a \"quoted\" value; ok"; empty="""#);
+
+        assert_eq!(parsed.parameter("title"), Some("a \"quoted\" value; ok"));
+        assert_eq!(parsed.parameter("empty"), Some(""));
+        assert_eq!(
+            parsed.to_string(),
+            r#"text/plain; title="a \"quoted\" value; ok"; empty="""#
+        );
+
+        let built = MediaType::TEXT_PLAIN
+            .with_parameter("Charset", "us-ascii")
+            .and_then(|media| media.with_parameter("charset", "utf-8"))
+            .expect("a valid parameter name");
+        assert_eq!(built.to_string(), "text/plain; charset=utf-8");
+        assert!(MediaType::TEXT_PLAIN.with_parameter("a b", "c").is_err());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!("".parse::<MediaType>(), Err(ParseError::Empty));
+        assert_eq!("text".parse::<MediaType>(), Err(ParseError::InvalidSyntax));
+        assert_eq!("text/".parse::<MediaType>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "text/pl ain".parse::<MediaType>(),
+            Err(ParseError::InvalidCharacter {
+                character: ' ',
+                position: 7
+            })
+        );
+        assert_eq!(
+            "text/plain; charset".parse::<MediaType>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "text/plain; a=\"open".parse::<MediaType>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "text/plain; a=\"b\"c".parse::<MediaType>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "text/plain; a=b@c".parse::<MediaType>(),
+            Err(ParseError::InvalidCharacter {
+                character: '@',
+                position: 15
+            })
+        );
+    }
+
+    #[test]
+    fn test_extensions() {
+        assert_eq!(
+            MediaType::from_extension("JPG"),
+            Some(MediaType::IMAGE_JPEG)
+        );
+        assert_eq!(
+            MediaType::from_extension(".mjs"),
+            Some(MediaType::TEXT_JAVASCRIPT)
+        );
+        assert_eq!(MediaType::from_extension("unknown"), None);
+        assert_eq!(
+            MediaType::from_path("site/index.html"),
+            Some(MediaType::TEXT_HTML)
+        );
+        assert_eq!(MediaType::from_path("Makefile"), None);
+
+        assert_eq!(MediaType::IMAGE_JPEG.extension(), Some("jpg"));
+        assert_eq!(media("text/html; charset=utf-8").extension(), Some("html"));
+        assert_eq!(media("application/x-unknown").extension(), None);
+    }
+
+    #[test]
+    fn test_sniff() {
+        let cases: [(&[u8], MediaType); 8] = [
+            (b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR", MediaType::IMAGE_PNG),
+            (b"\xff\xd8\xff\xe0\0\x10JFIF", MediaType::IMAGE_JPEG),
+            (b"GIF89a\x01\0", MediaType::IMAGE_GIF),
+            (b"RIFF\x24\x08\0\0WEBPVP8 ", MediaType::IMAGE_WEBP),
+            (b"RIFF\x24\x08\0\0WAVEfmt ", MediaType::AUDIO_WAV),
+            (b"PK\x03\x04\x14\0", MediaType::APPLICATION_ZIP),
+            (b"\0\0\0\x20ftypisom", MediaType::VIDEO_MP4),
+            (b"\0asm\x01\0\0\0", MediaType::APPLICATION_WASM),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(MediaType::sniff(bytes), Some(expected));
+        }
+
+        assert_eq!(MediaType::sniff(b"RIFF\0\0\0\0AVI "), None);
+        assert_eq!(MediaType::sniff(b"<!doctype html>"), None);
+        assert_eq!(MediaType::sniff(b""), None);
+    }
+}
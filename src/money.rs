@@ -0,0 +1,540 @@
+//! Amounts of money in a specific currency.
+//!
+//! A [`Money`] pairs a [`Decimal`] amount with a [`Currency`], and always holds exactly
+//! as many fraction digits as the currency has minor units, so `Money` in US dollars is
+//! a whole number of cents. Arithmetic is only defined between amounts of the same
+//! currency; mixing currencies is an error rather than a silent conversion.
+//!
+//! Dividing money rarely comes out even. [`Money::allocate`] splits an amount by ratios
+//! with banker's rounding and then hands out the leftover minor units, so the parts
+//! always add up to the whole.
+//!
+//! Converting between currencies goes through an [`ExchangeRates`] table that the
+//! caller fills in.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::locale::Currency;
+//! use libx::money::Money;
+//!
+//! let euro = Currency::from_code("EUR").unwrap();
+//! let bill = Money::from_minor_units(10_000, euro);
+//!
+//! let shares = bill.allocate(&[1, 1, 1])?;
+//! let cents: Vec<i128> = shares.iter().map(|share| share.minor_units()).collect();
+//! assert_eq!(cents, [3334, 3333, 3333]);
+//!
+//! let tip = Money::from_minor_units(1_500, euro);
+//! assert_eq!(bill.try_add(tip)?.to_string(), "115.00 EUR");
+//! # Ok::<(), libx::errors::MoneyError>(())
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{cmp::Ordering, fmt, ops::Neg};
+
+pub use self::rates::{ExchangeRate, ExchangeRates};
+use crate::{
+    errors::{FormatError, MoneyError},
+    formatting::{NumberFormatter, numbers::Value},
+    locale::Currency,
+    num::decimal::{Decimal, RoundingMode},
+};
+
+pub mod rates;
+
+/// An amount of money: a [`Decimal`] with the scale of its [`Currency`].
+///
+/// Amounts of different currencies are never equal, and do not compare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Returns zero in `currency`.
+    #[must_use]
+    pub const fn zero(currency: Currency) -> Self {
+        Self::from_minor_units(0, currency)
+    }
+
+    /// Creates an amount from a count of the currency's minor units, such as cents.
+    #[must_use]
+    pub const fn from_minor_units(minor_units: i128, currency: Currency) -> Self {
+        Self {
+            amount: Decimal::new(minor_units, currency.minor_units()),
+            currency,
+        }
+    }
+
+    /// Creates an amount of `currency` from `amount`, which must not have digits below the
+    /// currency's minor unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::PrecisionLoss`] if `amount` has nonzero digits the currency
+    /// cannot hold, and [`MoneyError::Overflow`] if padding it to the currency's scale
+    /// overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::errors::MoneyError;
+    /// use libx::locale::Currency;
+    /// use libx::money::Money;
+    /// use libx::num::decimal::Decimal;
+    ///
+    /// let yen = Currency::from_code("JPY").unwrap();
+    ///
+    /// assert_eq!(Money::new(Decimal::new(1500, 2), yen)?.minor_units(), 15);
+    /// assert_eq!(
+    ///     Money::new(Decimal::new(1550, 2), yen),
+    ///     Err(MoneyError::PrecisionLoss { currency: "JPY" })
+    /// );
+    /// # Ok::<(), MoneyError>(())
+    /// ```
+    pub const fn new(amount: Decimal, currency: Currency) -> Result<Self, MoneyError> {
+        let scale = currency.minor_units();
+
+        match amount.rescale(scale) {
+            Some(amount) => Ok(Self { amount, currency }),
+            None if amount.scale() > scale => Err(MoneyError::PrecisionLoss {
+                currency: currency.code(),
+            }),
+            None => Err(MoneyError::Overflow),
+        }
+    }
+
+    /// Creates an amount of `currency` from `amount`, rounding away digits below the
+    /// currency's minor unit with `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if padding `amount` to the currency's scale
+    /// overflows.
+    pub const fn rounded(
+        amount: Decimal,
+        currency: Currency,
+        mode: RoundingMode,
+    ) -> Result<Self, MoneyError> {
+        Self::new(amount.round(currency.minor_units(), mode), currency)
+    }
+
+    /// Returns the amount, with as many fraction digits as the currency has minor units.
+    #[must_use]
+    pub const fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Returns the currency.
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Returns the amount as a count of minor units, such as `1234` for `$12.34`.
+    #[must_use]
+    pub const fn minor_units(&self) -> i128 {
+        self.amount.mantissa()
+    }
+
+    /// Returns the whole major units of the amount, such as `12` for `$12.34`. Negative
+    /// amounts are truncated toward zero.
+    #[must_use]
+    pub const fn major_units(&self) -> i128 {
+        self.minor_units() / self.minor_units_per_major_unit()
+    }
+
+    /// Returns the minor units beyond the whole major units, such as `34` for `$12.34`.
+    /// The sign is that of the amount.
+    #[must_use]
+    pub const fn minor_part(&self) -> i128 {
+        self.minor_units() % self.minor_units_per_major_unit()
+    }
+
+    const fn minor_units_per_major_unit(&self) -> i128 {
+        let mut unit = 1;
+        let mut digits = 0;
+
+        while digits < self.currency.minor_units() {
+            unit *= 10;
+            digits += 1;
+        }
+
+        unit
+    }
+
+    /// Returns whether the amount is zero.
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.amount.is_zero()
+    }
+
+    /// Returns whether the amount is less than zero.
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.amount.is_negative()
+    }
+
+    /// Returns whether the amount is greater than zero.
+    #[must_use]
+    pub const fn is_positive(&self) -> bool {
+        self.amount.is_positive()
+    }
+
+    /// Returns `self + other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ, and
+    /// [`MoneyError::Overflow`] if the sum overflows.
+    pub fn try_add(self, other: Self) -> Result<Self, MoneyError> {
+        self.check_currency(other)?;
+
+        self.minor_units()
+            .checked_add(other.minor_units())
+            .map(|sum| Self::from_minor_units(sum, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Returns `self - other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::CurrencyMismatch`] if the currencies differ, and
+    /// [`MoneyError::Overflow`] if the difference overflows.
+    pub fn try_sub(self, other: Self) -> Result<Self, MoneyError> {
+        self.check_currency(other)?;
+
+        self.minor_units()
+            .checked_sub(other.minor_units())
+            .map(|difference| Self::from_minor_units(difference, self.currency))
+            .ok_or(MoneyError::Overflow)
+    }
+
+    /// Returns the amount multiplied by `factor` and rounded to the currency's minor
+    /// unit with `mode`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::Overflow`] if the product overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::Currency;
+    /// use libx::money::Money;
+    /// use libx::num::decimal::{Decimal, RoundingMode};
+    ///
+    /// let dollars = Currency::from_code("USD").unwrap();
+    /// let price = Money::from_minor_units(1999, dollars);
+    /// let tax = price.try_multiply(Decimal::new(825, 4), RoundingMode::HalfEven)?;
+    ///
+    /// assert_eq!(tax.minor_units(), 165);
+    /// # Ok::<(), libx::errors::MoneyError>(())
+    /// ```
+    pub fn try_multiply(self, factor: Decimal, mode: RoundingMode) -> Result<Self, MoneyError> {
+        let product = self
+            .amount
+            .checked_mul(factor)
+            .ok_or(MoneyError::Overflow)?;
+
+        Self::rounded(product, self.currency, mode)
+    }
+
+    /// Splits the amount into parts proportional to `ratios`, which add up to exactly the
+    /// amount.
+    ///
+    /// Each part is first rounded half to even to a whole minor unit. The minor units
+    /// that rounding gained or lost are then taken from or given to the parts that were
+    /// rounded furthest in the other direction, the earliest first among equals. A part
+    /// with a ratio of zero is always zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MoneyError::InvalidRatios`] if `ratios` is empty or all zero, and
+    /// [`MoneyError::Overflow`] if the amount times a ratio overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::locale::Currency;
+    /// use libx::money::Money;
+    ///
+    /// let dollars = Currency::from_code("USD").unwrap();
+    /// let parts = Money::from_minor_units(5, dollars).allocate(&[1, 1, 2])?;
+    /// let cents: Vec<i128> = parts.iter().map(|part| part.minor_units()).collect();
+    ///
+    /// assert_eq!(cents, [1, 1, 3]);
+    /// # Ok::<(), libx::errors::MoneyError>(())
+    /// ```
+    pub fn allocate(&self, ratios: &[u32]) -> Result<Vec<Self>, MoneyError> {
+        let total: i128 = ratios.iter().map(|&ratio| i128::from(ratio)).sum();
+
+        if total == 0 {
+            return Err(MoneyError::InvalidRatios);
+        }
+
+        let amount = self.minor_units();
+        // Each part and how far it was rounded up, in units of `1 / total` minor units.
+        let mut parts = ratios
+            .iter()
+            .map(|&ratio| {
+                let exact = amount
+                    .checked_mul(i128::from(ratio))
+                    .ok_or(MoneyError::Overflow)?;
+                let part = RoundingMode::HalfEven.divide(exact, total);
+
+                Ok((part, part * total - exact))
+            })
+            .collect::<Result<Vec<_>, MoneyError>>()?;
+
+        let mut leftover = amount - parts.iter().map(|&(part, _)| part).sum::<i128>();
+
+        while leftover != 0 {
+            let step = leftover.signum();
+            // The part whose rounding most favoured the other direction; `min_by_key`
+            // keeps the earliest of equals.
+            let (part, rounded_by) = parts
+                .iter_mut()
+                .min_by_key(|(_, rounded_by)| *rounded_by * step)
+                .ok_or(MoneyError::InvalidRatios)?;
+
+            *part += step;
+            *rounded_by += step * total;
+            leftover -= step;
+        }
+
+        Ok(parts
+            .into_iter()
+            .map(|(part, _)| Self::from_minor_units(part, self.currency))
+            .collect())
+    }
+
+    /// Formats the amount with `formatter` in the currency style of its currency, as set
+    /// by [`NumberFormatter::with_currency`].
+    ///
+    /// The amount is formatted exactly, without going through `f64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::formatting::NumberFormatter;
+    /// use libx::locale::{Currency, Locale};
+    /// use libx::money::Money;
+    ///
+    /// let euro = Currency::from_code("EUR").unwrap();
+    /// let rent = Money::from_minor_units(-123_456, euro);
+    ///
+    /// let formatter = NumberFormatter::new().with_uses_grouping_separator(true);
+    /// assert_eq!(rent.format(&formatter), "-€1,234.56");
+    ///
+    /// let german = formatter.with_locale(&Locale::new("de_DE"));
+    /// assert_eq!(rent.format(&german), "-€1.234,56");
+    /// ```
+    #[must_use]
+    pub fn format(&self, formatter: &NumberFormatter) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write(formatter, &mut output);
+        output
+    }
+
+    /// Formats the amount into `sink`, as [`format`](Self::format) does.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FormatError::Write`] if the sink fails.
+    pub fn write<W: fmt::Write + ?Sized>(
+        &self,
+        formatter: &NumberFormatter,
+        sink: &mut W,
+    ) -> Result<(), FormatError> {
+        formatter
+            .clone()
+            .with_currency(&self.currency)
+            .write_value(Value::Decimal(self.amount), sink)
+            .map(|_| ())
+            .map_err(|fmt::Error| FormatError::Write)
+    }
+
+    fn check_currency(self, other: Self) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                expected: self.currency.code(),
+                found: other.currency.code(),
+            })
+        }
+    }
+}
+
+impl PartialOrd for Money {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (self.currency == other.currency).then(|| self.amount.cmp(&other.amount))
+    }
+}
+
+impl Neg for Money {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            amount: -self.amount,
+            currency: self.currency,
+        }
+    }
+}
+
+/// Writes the amount and the currency code, such as `12.50 USD`.
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec};
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    fn currency(code: &str) -> Currency {
+        Currency::from_code(code).expect("the code is an ISO 4217 currency")
+    }
+
+    #[test]
+    fn test_minor_units() {
+        let dinar = currency("BHD");
+        let amount = Money::new(Decimal::new(-12_345, 3), dinar).expect("three digits fit");
+
+        assert_eq!(amount.minor_units(), -12_345);
+        assert_eq!(amount.major_units(), -12);
+        assert_eq!(amount.minor_part(), -345);
+        assert_eq!(amount.to_string(), "-12.345 BHD");
+
+        let yen = Money::from_minor_units(500, currency("JPY"));
+        assert_eq!((yen.major_units(), yen.minor_part()), (500, 0));
+
+        let padded = Money::new(Decimal::from(7), currency("USD")).expect("7 is whole");
+        assert_eq!(padded.amount().to_string(), "7.00");
+        assert_eq!(
+            Money::new(Decimal::MAX, currency("USD")),
+            Err(MoneyError::Overflow)
+        );
+        assert_eq!(
+            Money::rounded(
+                Decimal::new(1005, 3),
+                currency("USD"),
+                RoundingMode::HalfEven
+            )
+            .map(|money| money.minor_units()),
+            Ok(100)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic_requires_one_currency() {
+        let dollars = Money::from_minor_units(1_000, currency("USD"));
+        let euros = Money::from_minor_units(1_000, currency("EUR"));
+
+        assert_eq!(
+            dollars.try_add(euros),
+            Err(MoneyError::CurrencyMismatch {
+                expected: "USD",
+                found: "EUR"
+            })
+        );
+        assert!(dollars.try_sub(euros).is_err());
+        assert_ne!(dollars, euros);
+        assert_eq!(dollars.partial_cmp(&euros), None);
+
+        let change = dollars
+            .try_sub(Money::from_minor_units(1_250, currency("USD")))
+            .expect("same currency");
+        assert_eq!(change.to_string(), "-2.50 USD");
+        assert!(change < dollars);
+        assert_eq!(-change, Money::from_minor_units(250, currency("USD")));
+
+        let most = Money::from_minor_units(i128::MAX, currency("USD"));
+        assert_eq!(most.try_add(dollars), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn test_allocate() {
+        let usd = currency("USD");
+        let cents = |amount: i128, ratios: &[u32]| {
+            Money::from_minor_units(amount, usd)
+                .allocate(ratios)
+                .map(|parts| parts.iter().map(Money::minor_units).collect::<Vec<_>>())
+        };
+
+        assert_eq!(cents(10_000, &[1, 1, 2]), Ok(vec![2_500, 2_500, 5_000]));
+        assert_eq!(cents(5, &[1, 1]), Ok(vec![3, 2]));
+        assert_eq!(cents(-5, &[1, 1]), Ok(vec![-3, -2]));
+        assert_eq!(cents(100, &[1, 1, 1]), Ok(vec![34, 33, 33]));
+        assert_eq!(cents(2, &[0, 1, 1, 1]), Ok(vec![0, 0, 1, 1]));
+        assert_eq!(cents(3, &[1, 1, 1, 1, 1, 1]), Ok(vec![1, 1, 1, 0, 0, 0]));
+        assert_eq!(cents(1, &[]), Err(MoneyError::InvalidRatios));
+        assert_eq!(cents(1, &[0, 0]), Err(MoneyError::InvalidRatios));
+        assert_eq!(cents(i128::MAX, &[2, 1]), Err(MoneyError::Overflow));
+    }
+
+    #[test]
+    fn test_allocate_preserves_total() {
+        let usd = currency("USD");
+
+        let result = check_property(0x0a11_0c47, 300, |rng| {
+            #[allow(clippy::cast_possible_truncation)] // At most 8 ratios.
+            let count = rng.next_bounded(8) as usize + 1;
+            #[allow(clippy::cast_possible_truncation)] // Bounded by 10.
+            let ratios: Vec<u32> = (0..count).map(|_| rng.next_bounded(10) as u32).collect();
+            let amount = i128::from(rng.next_u32()) - i128::from(u32::MAX / 2);
+            let total: u32 = ratios.iter().sum();
+
+            let Ok(parts) = Money::from_minor_units(amount, usd).allocate(&ratios) else {
+                return if total == 0 {
+                    Ok(())
+                } else {
+                    Err(alloc::format!("allocating {amount} by {ratios:?} failed").into())
+                };
+            };
+
+            let sum: i128 = parts.iter().map(Money::minor_units).sum();
+            if sum != amount {
+                return Err(alloc::format!("{ratios:?} split {amount} into {sum}").into());
+            }
+
+            for (part, &ratio) in parts.iter().zip(&ratios) {
+                // Each part is within one minor unit of its exact share.
+                let exact = amount * i128::from(ratio);
+                let error = (part.minor_units() * i128::from(total) - exact).abs();
+
+                if error >= i128::from(total) {
+                    return Err(alloc::format!("{ratios:?} gave {amount} a part of {part}").into());
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn test_format() {
+        let yen = Money::from_minor_units(1_234_567, currency("JPY"));
+        let formatter = NumberFormatter::new()
+            .with_uses_grouping_separator(true)
+            .with_maximum_fraction_digits(5);
+
+        assert_eq!(yen.format(&formatter), "¥1,234,567");
+        assert_eq!(
+            Money::zero(currency("GBP")).format(&formatter.with_zero_symbol("free")),
+            "free"
+        );
+    }
+}
@@ -0,0 +1,414 @@
+//! Exchange rates between currencies.
+//!
+//! An [`ExchangeRates`] table quotes every currency against one base currency, so a
+//! conversion between two other currencies triangulates through the base. Each rate
+//! records when it was set, measured on a [`WallClock`], and the table can refuse rates
+//! older than a maximum age.
+
+use alloc::collections::BTreeMap;
+use core::time::Duration;
+
+use super::Money;
+use crate::{
+    errors::ExchangeError,
+    locale::Currency,
+    num::decimal::{Decimal, RoundingMode},
+    time::WallClock,
+};
+
+/// A rate in an [`ExchangeRates`] table: how many units of a currency one unit of the
+/// base currency buys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExchangeRate {
+    rate: Decimal,
+    updated_at: Duration,
+}
+
+impl ExchangeRate {
+    /// Returns the units of the quoted currency that one unit of the base currency buys.
+    #[must_use]
+    pub const fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns when the rate was set, as time since the Unix epoch.
+    #[must_use]
+    pub const fn updated_at(&self) -> Duration {
+        self.updated_at
+    }
+}
+
+/// A table of exchange rates against a base currency, populated by the caller.
+///
+/// Converted amounts are computed exactly with [`Decimal`] arithmetic and rounded once,
+/// to the minor unit of the target currency, with the table's
+/// [`rounding`](Self::rounding) mode.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::locale::Currency;
+/// use libx::money::{ExchangeRates, Money};
+/// use libx::num::decimal::Decimal;
+/// use libx::time::WallClock;
+///
+/// struct Noon;
+///
+/// impl WallClock for Noon {
+///     fn since_unix_epoch(&self) -> Duration {
+///         Duration::from_secs(1_700_000_000)
+///     }
+/// }
+///
+/// let [usd, eur, jpy] = ["USD", "EUR", "JPY"].map(|code| Currency::from_code(code).unwrap());
+///
+/// let mut rates = ExchangeRates::new(usd, Noon).with_max_age(Duration::from_secs(3_600));
+/// rates.set_rate(eur, Decimal::new(92, 2))?;
+/// rates.set_rate(jpy, Decimal::new(1495, 1))?;
+///
+/// let price = Money::from_minor_units(10_000, eur);
+/// assert_eq!(rates.convert(price, usd)?.to_string(), "108.70 USD");
+/// assert_eq!(rates.convert(price, jpy)?.to_string(), "16250 JPY");
+/// # Ok::<(), libx::errors::ExchangeError>(())
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExchangeRates<C> {
+    base: Currency,
+    clock: C,
+    rates: BTreeMap<&'static str, ExchangeRate>,
+    rounding: RoundingMode,
+    max_age: Option<Duration>,
+}
+
+impl<C> ExchangeRates<C>
+where
+    C: WallClock,
+{
+    /// Creates an empty table quoted against `base`, timestamped with `clock`.
+    ///
+    /// Rates never go stale and conversions round half to even until configured
+    /// otherwise.
+    #[must_use]
+    pub const fn new(base: Currency, clock: C) -> Self {
+        Self {
+            base,
+            clock,
+            rates: BTreeMap::new(),
+            rounding: RoundingMode::HalfEven,
+            max_age: None,
+        }
+    }
+
+    /// Sets how converted amounts are rounded to the minor unit of their currency.
+    #[must_use]
+    pub const fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Sets the age past which a rate is stale and no longer used for conversions.
+    #[must_use]
+    pub const fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = Some(max_age);
+        self
+    }
+
+    /// Returns the currency every rate is quoted against.
+    #[must_use]
+    pub const fn base(&self) -> Currency {
+        self.base
+    }
+
+    /// Returns how converted amounts are rounded.
+    #[must_use]
+    pub const fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    /// Returns the age past which a rate is stale, if there is one.
+    #[must_use]
+    pub const fn max_age(&self) -> Option<Duration> {
+        self.max_age
+    }
+
+    /// Returns the number of quoted currencies, not counting the base.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Returns whether no rates have been set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Sets the rate of `currency` to `rate` units per unit of the base currency, as of
+    /// now.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExchangeError::InvalidRate`] if `rate` is not positive or `currency` is
+    /// the base currency.
+    pub fn set_rate(&mut self, currency: Currency, rate: Decimal) -> Result<(), ExchangeError> {
+        let now = self.clock.since_unix_epoch();
+        self.set_rate_at(currency, rate, now)
+    }
+
+    /// Sets the rate of `currency` to `rate` units per unit of the base currency, as of
+    /// `updated_at` since the Unix epoch.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExchangeError::InvalidRate`] if `rate` is not positive or `currency` is
+    /// the base currency.
+    pub fn set_rate_at(
+        &mut self,
+        currency: Currency,
+        rate: Decimal,
+        updated_at: Duration,
+    ) -> Result<(), ExchangeError> {
+        if !rate.is_positive() || currency == self.base {
+            return Err(ExchangeError::InvalidRate {
+                currency: currency.code(),
+            });
+        }
+
+        self.rates
+            .insert(currency.code(), ExchangeRate { rate, updated_at });
+        Ok(())
+    }
+
+    /// Returns the rate of `currency`, stale or not, if one has been set.
+    #[must_use]
+    pub fn rate(&self, currency: Currency) -> Option<ExchangeRate> {
+        self.rates.get(currency.code()).copied()
+    }
+
+    /// Removes the rate of `currency` and returns it.
+    pub fn remove_rate(&mut self, currency: Currency) -> Option<ExchangeRate> {
+        self.rates.remove(currency.code())
+    }
+
+    /// Returns whether the rate of `currency` is older than the maximum age. Missing rates
+    /// are not stale, and the base currency never is.
+    #[must_use]
+    pub fn is_stale(&self, currency: Currency) -> bool {
+        self.rate(currency)
+            .is_some_and(|rate| self.check_age(currency, rate).is_err())
+    }
+
+    /// Removes every rate older than the maximum age.
+    pub fn remove_stale(&mut self) {
+        let Some(max_age) = self.max_age else {
+            return;
+        };
+        let now = self.clock.since_unix_epoch();
+
+        self.rates
+            .retain(|_, rate| now.saturating_sub(rate.updated_at) <= max_age);
+    }
+
+    /// Converts `money` to `to`, going through the base currency when neither is the
+    /// base.
+    ///
+    /// The amount is multiplied and divided exactly, then rounded once to the minor unit
+    /// of `to`. Converting to the same currency returns `money` unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ExchangeError::MissingRate`] if either currency has no rate,
+    /// [`ExchangeError::StaleRate`] if either rate is older than the maximum age, and
+    /// [`ExchangeError::Overflow`] if the converted amount overflows.
+    pub fn convert(&self, money: Money, to: Currency) -> Result<Money, ExchangeError> {
+        let from = money.currency();
+
+        if from == to {
+            return Ok(money);
+        }
+
+        let (from_rate, to_rate) = (self.usable_rate(from)?, self.usable_rate(to)?);
+
+        money
+            .amount()
+            .checked_mul(to_rate)
+            .and_then(|scaled| scaled.checked_div(from_rate, to.minor_units(), self.rounding))
+            .and_then(|amount| Money::new(amount, to).ok())
+            .ok_or(ExchangeError::Overflow)
+    }
+
+    /// Returns the rate of `currency` against the base, if there is a fresh one.
+    fn usable_rate(&self, currency: Currency) -> Result<Decimal, ExchangeError> {
+        if currency == self.base {
+            return Ok(Decimal::ONE);
+        }
+
+        let rate = self
+            .rate(currency)
+            .ok_or_else(|| ExchangeError::MissingRate {
+                currency: currency.code(),
+            })?;
+        self.check_age(currency, rate)?;
+
+        Ok(rate.rate)
+    }
+
+    fn check_age(&self, currency: Currency, rate: ExchangeRate) -> Result<(), ExchangeError> {
+        let age = self
+            .clock
+            .since_unix_epoch()
+            .saturating_sub(rate.updated_at);
+
+        match self.max_age {
+            Some(max_age) if age > max_age => Err(ExchangeError::StaleRate {
+                currency: currency.code(),
+                age,
+            }),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::testing::ManualClock;
+
+    fn currency(code: &str) -> Currency {
+        Currency::from_code(code).expect("the code is an ISO 4217 currency")
+    }
+
+    fn decimal(text: &str) -> Decimal {
+        text.parse().expect("the literal is a valid decimal")
+    }
+
+    #[test]
+    fn test_convert_triangulates_through_base() {
+        let clock = ManualClock::new(Duration::from_secs(1_000));
+        let mut rates = ExchangeRates::new(currency("EUR"), &clock);
+
+        rates
+            .set_rate(currency("GBP"), decimal("0.8612"))
+            .expect("the rate is positive");
+        rates
+            .set_rate(currency("KWD"), decimal("0.3345"))
+            .expect("the rate is positive");
+
+        let pounds = Money::from_minor_units(12_345, currency("GBP"));
+        let dinars = rates
+            .convert(pounds, currency("KWD"))
+            .expect("both rates are set");
+
+        // 123.45 × 0.3345 / 0.8612 = 47.9494...
+        assert_eq!(dinars.to_string(), "47.949 KWD");
+
+        let euros = rates
+            .convert(pounds, currency("EUR"))
+            .expect("GBP is quoted");
+        assert_eq!(euros.to_string(), "143.35 EUR");
+        assert_eq!(rates.convert(euros, currency("EUR")), Ok(euros));
+
+        assert_eq!(
+            rates.convert(pounds, currency("CHF")),
+            Err(ExchangeError::MissingRate { currency: "CHF" })
+        );
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        let clock = ManualClock::new(Duration::ZERO);
+        let mut rates = ExchangeRates::new(currency("USD"), &clock);
+        rates
+            .set_rate(currency("JPY"), decimal("150.5"))
+            .expect("the rate is positive");
+
+        let dollar = Money::from_minor_units(100, currency("USD"));
+        let down = rates.clone().with_rounding(RoundingMode::Floor);
+
+        assert_eq!(
+            rates
+                .convert(dollar, currency("JPY"))
+                .map(|m| m.minor_units()),
+            Ok(150)
+        );
+        assert_eq!(
+            rates
+                .with_rounding(RoundingMode::HalfUp)
+                .convert(dollar, currency("JPY"))
+                .map(|m| m.minor_units()),
+            Ok(151)
+        );
+        assert_eq!(
+            down.convert(-dollar, currency("JPY"))
+                .map(|m| m.minor_units()),
+            Ok(-151)
+        );
+    }
+
+    #[test]
+    fn test_staleness() {
+        let clock = ManualClock::new(Duration::from_secs(10_000));
+        let mut rates =
+            ExchangeRates::new(currency("USD"), &clock).with_max_age(Duration::from_mins(1));
+
+        rates
+            .set_rate(currency("EUR"), decimal("0.9"))
+            .expect("the rate is positive");
+        rates
+            .set_rate_at(currency("CAD"), decimal("1.35"), Duration::from_mins(150))
+            .expect("the rate is positive");
+
+        let dollars = Money::from_minor_units(1_000, currency("USD"));
+        assert!(rates.convert(dollars, currency("EUR")).is_ok());
+        assert!(rates.is_stale(currency("CAD")));
+        assert_eq!(
+            rates.convert(dollars, currency("CAD")),
+            Err(ExchangeError::StaleRate {
+                currency: "CAD",
+                age: Duration::from_secs(1_000)
+            })
+        );
+
+        clock.advance(Duration::from_secs(61));
+        assert!(rates.is_stale(currency("EUR")));
+        assert!(!rates.is_stale(currency("USD")));
+
+        rates.remove_stale();
+        assert!(rates.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_rates() {
+        let clock = ManualClock::new(Duration::ZERO);
+        let mut rates = ExchangeRates::new(currency("USD"), &clock);
+
+        assert_eq!(
+            rates.set_rate(currency("EUR"), Decimal::ZERO),
+            Err(ExchangeError::InvalidRate { currency: "EUR" })
+        );
+        assert_eq!(
+            rates.set_rate(currency("USD"), Decimal::ONE),
+            Err(ExchangeError::InvalidRate { currency: "USD" })
+        );
+
+        rates
+            .set_rate(currency("EUR"), Decimal::MAX)
+            .expect("the rate is positive");
+        assert_eq!(
+            rates.convert(
+                Money::from_minor_units(100, currency("USD")),
+                currency("EUR")
+            ),
+            Err(ExchangeError::Overflow)
+        );
+        assert_eq!(
+            rates.remove_rate(currency("EUR")).map(|rate| rate.rate()),
+            Some(Decimal::MAX)
+        );
+        assert_eq!(rates.len(), 0);
+    }
+}
@@ -0,0 +1,1580 @@
+//! IP addresses, CIDR networks, MAC addresses and email addresses that work without `std`.
+//!
+//! [`Ipv4Addr`], [`Ipv6Addr`] and [`IpAddr`] parse and format the usual textual forms,
+//! with IPv6 addresses written in the canonical form of RFC 5952. They convert to and
+//! from the address types in [`core::net`]. [`Cidr`] describes a network such as
+//! `192.168.0.0/16`, tests membership, iterates over hosts and splits into subnets.
+//! [`EmailAddress`] parses addresses such as `Jane Doe <jane@example.com>`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::net::{Cidr, IpAddr, Ipv4Addr};
+//!
+//! let network: Cidr = "10.1.0.0/30".parse().unwrap();
+//! assert!(network.contains("10.1.0.2".parse::<IpAddr>().unwrap()));
+//!
+//! let hosts: Vec<_> = network.hosts().map(|host| host.to_string()).collect();
+//! assert_eq!(hosts, ["10.1.0.1", "10.1.0.2"]);
+//!
+//! let address: IpAddr = "2001:DB8:0:0:0:0:0:1".parse().unwrap();
+//! assert_eq!(address.to_string(), "2001:db8::1");
+//! assert_eq!(Ipv4Addr::LOCALHOST.to_string(), "127.0.0.1");
+//! ```
+
+use alloc::string::{String, ToString};
+use core::{fmt, str::FromStr};
+
+use crate::errors::{EmailError, ParseError};
+
+/// An IPv4 address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ipv4Addr {
+    octets: [u8; 4],
+}
+
+impl Ipv4Addr {
+    /// The loopback address, `127.0.0.1`.
+    pub const LOCALHOST: Self = Self::new(127, 0, 0, 1);
+
+    /// The unspecified address, `0.0.0.0`.
+    pub const UNSPECIFIED: Self = Self::new(0, 0, 0, 0);
+
+    /// The limited broadcast address, `255.255.255.255`.
+    pub const BROADCAST: Self = Self::new(255, 255, 255, 255);
+
+    /// Creates an address from its four octets.
+    #[must_use]
+    pub const fn new(a: u8, b: u8, c: u8, d: u8) -> Self {
+        Self {
+            octets: [a, b, c, d],
+        }
+    }
+
+    /// Creates an address from its big-endian integer value.
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            octets: bits.to_be_bytes(),
+        }
+    }
+
+    /// Returns the address as a big-endian integer.
+    #[must_use]
+    pub const fn to_bits(self) -> u32 {
+        u32::from_be_bytes(self.octets)
+    }
+
+    /// Returns the four octets of the address.
+    #[must_use]
+    pub const fn octets(self) -> [u8; 4] {
+        self.octets
+    }
+
+    /// Returns `true` for `0.0.0.0`.
+    #[must_use]
+    pub const fn is_unspecified(self) -> bool {
+        self.to_bits() == 0
+    }
+
+    /// Returns `true` for addresses in `127.0.0.0/8`.
+    #[must_use]
+    pub const fn is_loopback(self) -> bool {
+        self.octets[0] == 127
+    }
+
+    /// Returns `true` for the private ranges of RFC 1918: `10.0.0.0/8`, `172.16.0.0/12` and
+    /// `192.168.0.0/16`.
+    #[must_use]
+    pub const fn is_private(self) -> bool {
+        matches!(self.octets, [10, ..] | [172, 16..=31, ..] | [192, 168, ..])
+    }
+
+    /// Returns `true` for addresses in `169.254.0.0/16`.
+    #[must_use]
+    pub const fn is_link_local(self) -> bool {
+        matches!(self.octets, [169, 254, ..])
+    }
+
+    /// Returns `true` for addresses in `224.0.0.0/4`.
+    #[must_use]
+    pub const fn is_multicast(self) -> bool {
+        self.octets[0] >= 224 && self.octets[0] <= 239
+    }
+
+    /// Returns `true` for `255.255.255.255`.
+    #[must_use]
+    pub const fn is_broadcast(self) -> bool {
+        self.to_bits() == u32::MAX
+    }
+
+    /// Returns the IPv4-mapped IPv6 address, `::ffff:a.b.c.d`.
+    #[must_use]
+    pub const fn to_ipv6_mapped(self) -> Ipv6Addr {
+        let [a, b, c, d] = self.octets;
+        Ipv6Addr::new(
+            0,
+            0,
+            0,
+            0,
+            0,
+            0xffff,
+            u16::from_be_bytes([a, b]),
+            u16::from_be_bytes([c, d]),
+        )
+    }
+}
+
+impl fmt::Display for Ipv4Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [first, second, third, fourth] = self.octets;
+        write!(f, "{first}.{second}.{third}.{fourth}")
+    }
+}
+
+impl FromStr for Ipv4Addr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_ipv4(s, 0)
+    }
+}
+
+impl From<[u8; 4]> for Ipv4Addr {
+    fn from(octets: [u8; 4]) -> Self {
+        Self { octets }
+    }
+}
+
+impl From<core::net::Ipv4Addr> for Ipv4Addr {
+    fn from(address: core::net::Ipv4Addr) -> Self {
+        Self::from(address.octets())
+    }
+}
+
+impl From<Ipv4Addr> for core::net::Ipv4Addr {
+    fn from(address: Ipv4Addr) -> Self {
+        Self::from(address.octets)
+    }
+}
+
+/// An IPv6 address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ipv6Addr {
+    segments: [u16; 8],
+}
+
+impl Ipv6Addr {
+    /// The loopback address, `::1`.
+    pub const LOCALHOST: Self = Self::new(0, 0, 0, 0, 0, 0, 0, 1);
+
+    /// The unspecified address, `::`.
+    pub const UNSPECIFIED: Self = Self::new(0, 0, 0, 0, 0, 0, 0, 0);
+
+    /// Creates an address from its eight 16-bit segments.
+    #[allow(clippy::too_many_arguments, clippy::many_single_char_names)] // As in `core::net`.
+    #[must_use]
+    pub const fn new(a: u16, b: u16, c: u16, d: u16, e: u16, f: u16, g: u16, h: u16) -> Self {
+        Self {
+            segments: [a, b, c, d, e, f, g, h],
+        }
+    }
+
+    /// Creates an address from its big-endian integer value.
+    #[must_use]
+    pub const fn from_bits(bits: u128) -> Self {
+        let mut segments = [0; 8];
+        let mut index = 0;
+
+        while index < 8 {
+            #[allow(clippy::cast_possible_truncation)] // Each segment takes 16 bits.
+            let segment = (bits >> (112 - 16 * index)) as u16;
+            segments[index] = segment;
+            index += 1;
+        }
+
+        Self { segments }
+    }
+
+    /// Returns the address as a big-endian integer.
+    #[must_use]
+    pub const fn to_bits(self) -> u128 {
+        let mut bits = 0;
+        let mut index = 0;
+
+        while index < 8 {
+            bits = (bits << 16) | self.segments[index] as u128;
+            index += 1;
+        }
+
+        bits
+    }
+
+    /// Returns the eight segments of the address.
+    #[must_use]
+    pub const fn segments(self) -> [u16; 8] {
+        self.segments
+    }
+
+    /// Returns the sixteen octets of the address.
+    #[must_use]
+    pub const fn octets(self) -> [u8; 16] {
+        self.to_bits().to_be_bytes()
+    }
+
+    /// Returns `true` for `::`.
+    #[must_use]
+    pub const fn is_unspecified(self) -> bool {
+        self.to_bits() == 0
+    }
+
+    /// Returns `true` for `::1`.
+    #[must_use]
+    pub const fn is_loopback(self) -> bool {
+        self.to_bits() == 1
+    }
+
+    /// Returns `true` for addresses in `ff00::/8`.
+    #[must_use]
+    pub const fn is_multicast(self) -> bool {
+        self.segments[0] & 0xff00 == 0xff00
+    }
+
+    /// Returns `true` for unique local addresses in `fc00::/7`.
+    #[must_use]
+    pub const fn is_unique_local(self) -> bool {
+        self.segments[0] & 0xfe00 == 0xfc00
+    }
+
+    /// Returns `true` for link-local unicast addresses in `fe80::/10`.
+    #[must_use]
+    pub const fn is_link_local(self) -> bool {
+        self.segments[0] & 0xffc0 == 0xfe80
+    }
+
+    /// Returns the IPv4 address of an IPv4-mapped address, `::ffff:a.b.c.d`.
+    #[must_use]
+    pub const fn to_ipv4_mapped(self) -> Option<Ipv4Addr> {
+        match self.segments {
+            [0, 0, 0, 0, 0, 0xffff, high, low] => {
+                let [a, b] = high.to_be_bytes();
+                let [c, d] = low.to_be_bytes();
+                Some(Ipv4Addr::new(a, b, c, d))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the start and length of the run of zero segments that `::` replaces, if
+    /// any: the longest run of at least two, preferring the first on ties.
+    fn compressed_run(self) -> Option<(usize, usize)> {
+        let mut longest: Option<(usize, usize)> = None;
+        let mut index = 0;
+
+        while index < 8 {
+            let length = self.segments[index..]
+                .iter()
+                .take_while(|&&segment| segment == 0)
+                .count();
+
+            if length >= 2 && longest.is_none_or(|(_, best)| length > best) {
+                longest = Some((index, length));
+            }
+
+            index += length.max(1);
+        }
+
+        longest
+    }
+}
+
+impl fmt::Display for Ipv6Addr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ipv4) = self.to_ipv4_mapped() {
+            return write!(f, "::ffff:{ipv4}");
+        }
+
+        let write_segments = |f: &mut fmt::Formatter<'_>, segments: &[u16]| {
+            for (index, segment) in segments.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(":")?;
+                }
+
+                write!(f, "{segment:x}")?;
+            }
+
+            Ok(())
+        };
+
+        match self.compressed_run() {
+            Some((start, length)) => {
+                write_segments(f, &self.segments[..start])?;
+                f.write_str("::")?;
+                write_segments(f, &self.segments[start + length..])
+            }
+            None => write_segments(f, &self.segments),
+        }
+    }
+}
+
+impl FromStr for Ipv6Addr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut segments = [0; 8];
+
+        let Some(split) = s.find("::") else {
+            if parse_segments(s, 0, &mut segments)? != 8 {
+                return Err(ParseError::InvalidSyntax);
+            }
+
+            return Ok(Self { segments });
+        };
+
+        let (head, tail) = (&s[..split], &s[split + 2..]);
+
+        if tail.contains("::") {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        // The head cannot end in an embedded IPv4 address, since more segments follow.
+        if head.contains('.') {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        let mut tail_segments = [0; 8];
+        let head_count = parse_segments(head, 0, &mut segments)?;
+        let tail_count = parse_segments(tail, split + 2, &mut tail_segments)?;
+
+        // `::` stands for at least one zero segment.
+        if head_count + tail_count > 7 {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        segments[8 - tail_count..].copy_from_slice(&tail_segments[..tail_count]);
+        Ok(Self { segments })
+    }
+}
+
+impl From<[u16; 8]> for Ipv6Addr {
+    fn from(segments: [u16; 8]) -> Self {
+        Self { segments }
+    }
+}
+
+impl From<[u8; 16]> for Ipv6Addr {
+    fn from(octets: [u8; 16]) -> Self {
+        Self::from_bits(u128::from_be_bytes(octets))
+    }
+}
+
+impl From<core::net::Ipv6Addr> for Ipv6Addr {
+    fn from(address: core::net::Ipv6Addr) -> Self {
+        Self::from(address.segments())
+    }
+}
+
+impl From<Ipv6Addr> for core::net::Ipv6Addr {
+    fn from(address: Ipv6Addr) -> Self {
+        Self::from(address.segments)
+    }
+}
+
+/// An IPv4 or IPv6 address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum IpAddr {
+    /// An IPv4 address.
+    V4(Ipv4Addr),
+
+    /// An IPv6 address.
+    V6(Ipv6Addr),
+}
+
+impl IpAddr {
+    /// Returns `true` for an IPv4 address.
+    #[must_use]
+    pub const fn is_ipv4(self) -> bool {
+        matches!(self, Self::V4(_))
+    }
+
+    /// Returns `true` for an IPv6 address.
+    #[must_use]
+    pub const fn is_ipv6(self) -> bool {
+        matches!(self, Self::V6(_))
+    }
+
+    /// Returns `true` for the unspecified address of either family.
+    #[must_use]
+    pub const fn is_unspecified(self) -> bool {
+        match self {
+            Self::V4(address) => address.is_unspecified(),
+            Self::V6(address) => address.is_unspecified(),
+        }
+    }
+
+    /// Returns `true` for a loopback address of either family.
+    #[must_use]
+    pub const fn is_loopback(self) -> bool {
+        match self {
+            Self::V4(address) => address.is_loopback(),
+            Self::V6(address) => address.is_loopback(),
+        }
+    }
+
+    /// Returns `true` for a multicast address of either family.
+    #[must_use]
+    pub const fn is_multicast(self) -> bool {
+        match self {
+            Self::V4(address) => address.is_multicast(),
+            Self::V6(address) => address.is_multicast(),
+        }
+    }
+
+    /// Returns the number of bits in the address: 32 or 128.
+    const fn width(self) -> u8 {
+        match self {
+            Self::V4(_) => 32,
+            Self::V6(_) => 128,
+        }
+    }
+
+    const fn to_bits(self) -> u128 {
+        match self {
+            Self::V4(address) => address.to_bits() as u128,
+            Self::V6(address) => address.to_bits(),
+        }
+    }
+
+    /// Returns an address of the same family as `self` with the given bits.
+    #[allow(clippy::cast_possible_truncation)] // IPv4 bits always fit in 32 bits.
+    const fn with_bits(self, bits: u128) -> Self {
+        match self {
+            Self::V4(_) => Self::V4(Ipv4Addr::from_bits(bits as u32)),
+            Self::V6(_) => Self::V6(Ipv6Addr::from_bits(bits)),
+        }
+    }
+}
+
+impl fmt::Display for IpAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::V4(address) => address.fmt(f),
+            Self::V6(address) => address.fmt(f),
+        }
+    }
+}
+
+impl FromStr for IpAddr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.contains(':') {
+            s.parse().map(Self::V6)
+        } else {
+            s.parse().map(Self::V4)
+        }
+    }
+}
+
+impl From<Ipv4Addr> for IpAddr {
+    fn from(address: Ipv4Addr) -> Self {
+        Self::V4(address)
+    }
+}
+
+impl From<Ipv6Addr> for IpAddr {
+    fn from(address: Ipv6Addr) -> Self {
+        Self::V6(address)
+    }
+}
+
+impl From<core::net::IpAddr> for IpAddr {
+    fn from(address: core::net::IpAddr) -> Self {
+        match address {
+            core::net::IpAddr::V4(address) => Self::V4(address.into()),
+            core::net::IpAddr::V6(address) => Self::V6(address.into()),
+        }
+    }
+}
+
+impl From<IpAddr> for core::net::IpAddr {
+    fn from(address: IpAddr) -> Self {
+        match address {
+            IpAddr::V4(address) => Self::V4(address.into()),
+            IpAddr::V6(address) => Self::V6(address.into()),
+        }
+    }
+}
+
+/// An IP network in CIDR notation, such as `192.168.0.0/16` or `2001:db8::/32`.
+///
+/// The network address always has its host bits cleared, so `10.0.0.7/24` is the same
+/// network as `10.0.0.0/24`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cidr {
+    network: IpAddr,
+    prefix_length: u8,
+}
+
+impl Cidr {
+    /// Creates the network of `address` with a prefix of `prefix_length` bits, or returns
+    /// `None` if the prefix is longer than the address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::net::{Cidr, Ipv4Addr};
+    ///
+    /// let network = Cidr::new(Ipv4Addr::new(172, 16, 5, 4), 12).unwrap();
+    /// assert_eq!(network.to_string(), "172.16.0.0/12");
+    /// assert_eq!(Cidr::new(Ipv4Addr::LOCALHOST, 33), None);
+    /// ```
+    #[must_use]
+    pub fn new(address: impl Into<IpAddr>, prefix_length: u8) -> Option<Self> {
+        let address = address.into();
+
+        if prefix_length > address.width() {
+            return None;
+        }
+
+        let mask = network_mask(address.width(), prefix_length);
+
+        Some(Self {
+            network: address.with_bits(address.to_bits() & mask),
+            prefix_length,
+        })
+    }
+
+    /// Returns the network address, the first address of the network.
+    #[must_use]
+    pub const fn network(self) -> IpAddr {
+        self.network
+    }
+
+    /// Returns the number of leading bits shared by every address in the network.
+    #[must_use]
+    pub const fn prefix_length(self) -> u8 {
+        self.prefix_length
+    }
+
+    /// Returns the netmask, such as `255.255.255.0` for a `/24`.
+    #[must_use]
+    pub const fn netmask(self) -> IpAddr {
+        self.network
+            .with_bits(network_mask(self.network.width(), self.prefix_length))
+    }
+
+    /// Returns the host mask, such as `0.0.0.255` for a `/24`.
+    #[must_use]
+    pub const fn hostmask(self) -> IpAddr {
+        self.network.with_bits(self.host_bits())
+    }
+
+    /// Returns the last address of the network, the broadcast address for IPv4.
+    #[must_use]
+    pub const fn last(self) -> IpAddr {
+        self.network
+            .with_bits(self.network.to_bits() | self.host_bits())
+    }
+
+    /// Returns `true` if `address` belongs to the network. Addresses of the other family
+    /// never do.
+    #[must_use]
+    pub fn contains(self, address: impl Into<IpAddr>) -> bool {
+        let address = address.into();
+
+        address.width() == self.network.width()
+            && address.to_bits() & !self.host_bits() == self.network.to_bits()
+    }
+
+    /// Returns `true` if every address of `other` belongs to this network.
+    #[must_use]
+    pub fn contains_network(self, other: Self) -> bool {
+        other.prefix_length >= self.prefix_length && self.contains(other.network)
+    }
+
+    /// Returns the usable host addresses of the network.
+    ///
+    /// For IPv4 networks larger than `/31`, this leaves out the network and broadcast
+    /// addresses. `/31` and `/32` networks, and all IPv6 networks, yield every address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::net::Cidr;
+    ///
+    /// let network: Cidr = "192.168.1.0/24".parse().unwrap();
+    /// assert_eq!(network.hosts().count(), 254);
+    /// assert_eq!(network.hosts().next().unwrap().to_string(), "192.168.1.1");
+    /// ```
+    #[must_use]
+    pub const fn hosts(self) -> Hosts {
+        let first = self.network.to_bits();
+        let last = first | self.host_bits();
+
+        let (first, last) = if self.network.is_ipv4() && self.prefix_length < 31 {
+            (first + 1, last - 1)
+        } else {
+            (first, last)
+        };
+
+        Hosts {
+            family: self.network,
+            next: Some(first),
+            last,
+        }
+    }
+
+    /// Splits the network into subnets with a prefix of `prefix_length` bits, or returns
+    /// `None` if that prefix is shorter than this network's or longer than the address.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::net::Cidr;
+    ///
+    /// let network: Cidr = "10.0.0.0/23".parse().unwrap();
+    /// let subnets: Vec<_> = network.subnets(24).unwrap().map(|net| net.to_string()).collect();
+    /// assert_eq!(subnets, ["10.0.0.0/24", "10.0.1.0/24"]);
+    /// ```
+    #[must_use]
+    pub const fn subnets(self, prefix_length: u8) -> Option<Subnets> {
+        if prefix_length < self.prefix_length || prefix_length > self.network.width() {
+            return None;
+        }
+
+        let subnet_host_bits = !network_mask(self.network.width(), prefix_length)
+            & network_mask(self.network.width(), self.network.width());
+
+        Some(Subnets {
+            family: self.network,
+            prefix_length,
+            next: Some(self.network.to_bits()),
+            last: (self.network.to_bits() | self.host_bits()) & !subnet_host_bits,
+        })
+    }
+
+    /// Returns the network one bit shorter that contains this one, or `None` for a `/0`.
+    #[must_use]
+    pub fn supernet(self) -> Option<Self> {
+        let prefix_length = self.prefix_length.checked_sub(1)?;
+        Self::new(self.network, prefix_length)
+    }
+
+    /// Returns the bits that vary between addresses of the network.
+    const fn host_bits(self) -> u128 {
+        let width = self.network.width();
+        network_mask(width, width) & !network_mask(width, self.prefix_length)
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.network, self.prefix_length)
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (address, prefix_length) = s.split_once('/').ok_or(ParseError::InvalidSyntax)?;
+        let offset = address.len() + 1;
+        let address: IpAddr = address.parse()?;
+
+        if prefix_length.is_empty() || prefix_length.len() > 3 {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        if let Some((index, character)) = prefix_length
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit())
+        {
+            return Err(ParseError::InvalidCharacter {
+                character,
+                position: offset + index,
+            });
+        }
+
+        let prefix_length = prefix_length.parse().map_err(|_| ParseError::OutOfRange)?;
+        Self::new(address, prefix_length).ok_or(ParseError::OutOfRange)
+    }
+}
+
+/// An iterator over the host addresses of a [`Cidr`], created by [`Cidr::hosts`].
+#[derive(Debug, Clone)]
+pub struct Hosts {
+    family: IpAddr,
+    next: Option<u128>,
+    last: u128,
+}
+
+impl Iterator for Hosts {
+    type Item = IpAddr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = (current < self.last).then(|| current + 1);
+        Some(self.family.with_bits(current))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining_hint(self.next, self.last, 1)
+    }
+}
+
+/// An iterator over the subnets of a [`Cidr`], created by [`Cidr::subnets`].
+#[derive(Debug, Clone)]
+pub struct Subnets {
+    family: IpAddr,
+    prefix_length: u8,
+    next: Option<u128>,
+    last: u128,
+}
+
+impl Iterator for Subnets {
+    type Item = Cidr;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = (current < self.last).then(|| current + self.step());
+
+        Some(Cidr {
+            network: self.family.with_bits(current),
+            prefix_length: self.prefix_length,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        remaining_hint(self.next, self.last, self.step())
+    }
+}
+
+impl Subnets {
+    /// Returns the distance between consecutive subnets. It saturates for a `/0`, which
+    /// has no next subnet anyway.
+    const fn step(&self) -> u128 {
+        let width = self.family.width();
+        (network_mask(width, width) & !network_mask(width, self.prefix_length)).saturating_add(1)
+    }
+}
+
+/// A MAC-48 hardware address.
+///
+/// Parsing accepts six colon- or hyphen-separated octets (`00:1a:2b:3c:4d:5e`) and the
+/// dotted form `001a.2b3c.4d5e`; formatting uses lowercase with colons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MacAddr {
+    octets: [u8; 6],
+}
+
+impl MacAddr {
+    /// The broadcast address, `ff:ff:ff:ff:ff:ff`.
+    pub const BROADCAST: Self = Self::new([0xff; 6]);
+
+    /// Creates an address from its six octets.
+    #[must_use]
+    pub const fn new(octets: [u8; 6]) -> Self {
+        Self { octets }
+    }
+
+    /// Returns the six octets of the address.
+    #[must_use]
+    pub const fn octets(self) -> [u8; 6] {
+        self.octets
+    }
+
+    /// Returns `true` if the address identifies a group rather than a single interface.
+    #[must_use]
+    pub const fn is_multicast(self) -> bool {
+        self.octets[0] & 0x01 != 0
+    }
+
+    /// Returns `true` if the address identifies a single interface.
+    #[must_use]
+    pub const fn is_unicast(self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Returns `true` if the address was assigned locally rather than by the vendor.
+    #[must_use]
+    pub const fn is_locally_administered(self) -> bool {
+        self.octets[0] & 0x02 != 0
+    }
+
+    /// Returns `true` for `ff:ff:ff:ff:ff:ff`.
+    #[must_use]
+    pub fn is_broadcast(self) -> bool {
+        self == Self::BROADCAST
+    }
+}
+
+impl fmt::Display for MacAddr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, octet) in self.octets.iter().enumerate() {
+            if index > 0 {
+                f.write_str(":")?;
+            }
+
+            write!(f, "{octet:02x}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for MacAddr {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (separator, group_length) = match s.len() {
+            0 => return Err(ParseError::Empty),
+            17 if s.as_bytes()[2] == b'-' => (b'-', 2),
+            17 => (b':', 2),
+            14 => (b'.', 4),
+            _ => return Err(ParseError::InvalidSyntax),
+        };
+
+        let mut octets = [0; 6];
+        let mut digits = 0;
+
+        for (position, character) in s.char_indices() {
+            if (position + 1) % (group_length + 1) == 0 {
+                if character != char::from(separator) {
+                    return Err(ParseError::InvalidCharacter {
+                        character,
+                        position,
+                    });
+                }
+
+                continue;
+            }
+
+            let value = character.to_digit(16).ok_or(ParseError::InvalidCharacter {
+                character,
+                position,
+            })?;
+
+            #[allow(clippy::cast_possible_truncation)] // A hex digit fits in a byte.
+            let value = value as u8;
+            octets[digits / 2] = (octets[digits / 2] << 4) | value;
+            digits += 1;
+        }
+
+        Ok(Self { octets })
+    }
+}
+
+impl From<[u8; 6]> for MacAddr {
+    fn from(octets: [u8; 6]) -> Self {
+        Self { octets }
+    }
+}
+
+/// An email address, with an optional display name.
+///
+/// Parsing follows RFC 5322 pragmatically: the local part is a dot-atom or a quoted
+/// string, the domain is a host name or an IP address in brackets, and an address may be
+/// written as `Name <local@domain>`. Comments and obsolete syntax are not accepted.
+/// Non-ASCII characters are allowed in the local part, the display name and, checked by
+/// [`idna::to_ascii`](crate::url::idna::to_ascii), the domain, as RFC 6531 allows.
+///
+/// A quoted local part or display name is stored without its quotes and escapes, and
+/// quoted again when the address is written only if it needs to be.
+///
+/// # Examples
+///
+/// ```
+/// use libx::net::EmailAddress;
+///
+/// let address: EmailAddress = "\"Doe, Jane\" <Jane.Doe+news@Example.COM>".parse().unwrap();
+/// assert_eq!(address.display_name(), Some("Doe, Jane"));
+/// assert_eq!(address.local_part(), "Jane.Doe+news");
+/// assert_eq!(address.tag(), Some("news"));
+///
+/// let normalized = address.normalized().without_tag();
+/// assert_eq!(normalized.address(), "Jane.Doe@example.com");
+/// assert_eq!(normalized.to_string(), "\"Doe, Jane\" <Jane.Doe@example.com>");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EmailAddress {
+    display_name: Option<String>,
+    local_part: String,
+    domain: String,
+}
+
+impl EmailAddress {
+    /// Creates an address from its local part, unquoted, and its domain.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmailError`] if either part is invalid or the address is too long.
+    pub fn new(local_part: &str, domain: &str) -> Result<Self, EmailError> {
+        if local_part.is_empty() {
+            return Err(EmailError::InvalidLocalPart);
+        }
+
+        if local_part.chars().any(char::is_control) {
+            return Err(EmailError::InvalidLocalPart);
+        }
+
+        let address = Self {
+            display_name: None,
+            local_part: local_part.to_string(),
+            domain: domain.to_string(),
+        };
+        address.validate()?;
+        Ok(address)
+    }
+
+    /// Parses an address, either bare or as `Name <local@domain>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmailError`] describing the first problem found.
+    pub fn parse(text: &str) -> Result<Self, EmailError> {
+        let text = text.trim();
+
+        if text.is_empty() {
+            return Err(EmailError::Empty);
+        }
+
+        let Some(open) = find_unquoted(text, '<') else {
+            return Self::parse_address(text);
+        };
+
+        let address = text[open + 1..]
+            .strip_suffix('>')
+            .ok_or(EmailError::InvalidDisplayName)?;
+        let name = text[..open].trim();
+        let mut parsed = Self::parse_address(address.trim())?;
+
+        parsed.display_name = match name.strip_prefix('"') {
+            Some(quoted) => {
+                let quoted = quoted
+                    .strip_suffix('"')
+                    .ok_or(EmailError::UnterminatedQuote)?;
+                Some(unquote(quoted).ok_or(EmailError::InvalidDisplayName)?)
+            }
+            None if name.contains('"') => return Err(EmailError::InvalidDisplayName),
+            None => (!name.is_empty()).then(|| name.to_string()),
+        };
+
+        Ok(parsed)
+    }
+
+    /// Parses a bare `local@domain`.
+    fn parse_address(text: &str) -> Result<Self, EmailError> {
+        let at = text.rfind('@').ok_or(EmailError::MissingAt)?;
+        let (local_part, domain) = (&text[..at], &text[at + 1..]);
+
+        let local_part = match local_part.strip_prefix('"') {
+            Some(quoted) => {
+                let quoted = quoted
+                    .strip_suffix('"')
+                    .ok_or(EmailError::UnterminatedQuote)?;
+                unquote(quoted).ok_or(EmailError::InvalidLocalPart)?
+            }
+            None if is_dot_atom(local_part) => local_part.to_string(),
+            None => return Err(EmailError::InvalidLocalPart),
+        };
+
+        Self::new(&local_part, domain)
+    }
+
+    /// Checks the domain and the lengths.
+    fn validate(&self) -> Result<(), EmailError> {
+        let domain = self.domain.as_str();
+        let valid_domain = domain.strip_prefix('[').map_or_else(
+            || !domain.ends_with('.') && crate::url::idna::to_ascii(domain).is_ok(),
+            is_address_literal,
+        );
+
+        if !valid_domain {
+            return Err(EmailError::InvalidDomain);
+        }
+
+        let local_length = self.quoted_local_part().len();
+
+        if local_length > 64 {
+            return Err(EmailError::LocalPartTooLong);
+        }
+
+        if local_length + 1 + domain.len() > 254 {
+            return Err(EmailError::TooLong);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the display name, if there is one.
+    #[must_use]
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    /// Returns the local part, without quotes.
+    #[must_use]
+    pub fn local_part(&self) -> &str {
+        &self.local_part
+    }
+
+    /// Returns the domain, as written.
+    #[must_use]
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// Sets the display name.
+    #[must_use]
+    pub fn with_display_name(mut self, display_name: Option<&str>) -> Self {
+        self.display_name = display_name.map(ToString::to_string);
+        self
+    }
+
+    /// Returns the subaddress after the first `+` of the local part, as in
+    /// `user+tag@example.com`.
+    #[must_use]
+    pub fn tag(&self) -> Option<&str> {
+        self.local_part
+            .split_once('+')
+            .filter(|(user, _)| !user.is_empty())
+            .map(|(_, tag)| tag)
+    }
+
+    /// Returns the address without the subaddress of [`tag`](Self::tag).
+    #[must_use]
+    pub fn without_tag(mut self) -> Self {
+        if let Some(plus) = self.tag().map(|tag| self.local_part.len() - tag.len() - 1) {
+            self.local_part.truncate(plus);
+        }
+
+        self
+    }
+
+    /// Returns the address with its domain lowercased.
+    ///
+    /// The local part is kept as it is, since RFC 5321 leaves its case to the receiving
+    /// server.
+    #[must_use]
+    pub fn normalized(mut self) -> Self {
+        self.domain = self.domain.to_lowercase();
+        self
+    }
+
+    /// Returns the address as `local@domain`, without the display name, with the local
+    /// part quoted if needed.
+    #[must_use]
+    pub fn address(&self) -> String {
+        let mut address = self.quoted_local_part();
+        address.push('@');
+        address.push_str(&self.domain);
+        address
+    }
+
+    /// Returns the local part, quoted if it is not a dot-atom.
+    fn quoted_local_part(&self) -> String {
+        if is_dot_atom(&self.local_part) {
+            self.local_part.clone()
+        } else {
+            quote(&self.local_part)
+        }
+    }
+}
+
+impl fmt::Display for EmailAddress {
+    /// Writes the address, as `Name <local@domain>` if it has a display name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.display_name {
+            Some(name) if name.contains(|c: char| "()<>[]:;@\\,.\"".contains(c)) => {
+                write!(f, "{} <{}>", quote(name), self.address())
+            }
+            Some(name) => write!(f, "{name} <{}>", self.address()),
+            None => f.write_str(&self.address()),
+        }
+    }
+}
+
+impl FromStr for EmailAddress {
+    type Err = EmailError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Returns whether `text` is a dot-atom: atoms of letters, digits, non-ASCII characters
+/// and ``!#$%&'*+-/=?^_`{|}~``, joined by single dots.
+fn is_dot_atom(text: &str) -> bool {
+    text.split('.').all(|atom| {
+        !atom.is_empty()
+            && atom.chars().all(|c| {
+                c.is_ascii_alphanumeric()
+                    || (!c.is_ascii() && !c.is_control() && !c.is_whitespace())
+                    || "!#$%&'*+-/=?^_`{|}~".contains(c)
+            })
+    })
+}
+
+/// Returns whether `text` is the inside of a domain literal such as `[192.0.2.1]` or
+/// `[IPv6:2001:db8::1]`, after the opening bracket.
+fn is_address_literal(text: &str) -> bool {
+    text.strip_suffix(']').is_some_and(|literal| {
+        literal.strip_prefix("IPv6:").map_or_else(
+            || literal.parse::<Ipv4Addr>().is_ok(),
+            |address| address.parse::<Ipv6Addr>().is_ok(),
+        )
+    })
+}
+
+/// Returns `text` in double quotes, with quotes and backslashes escaped.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+
+    for character in text.chars() {
+        if matches!(character, '"' | '\\') {
+            quoted.push('\\');
+        }
+
+        quoted.push(character);
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+/// Removes the escapes of the inside of a quoted string, or returns `None` if it holds an
+/// unescaped quote, a control character or a trailing backslash.
+fn unquote(text: &str) -> Option<String> {
+    let mut unquoted = String::with_capacity(text.len());
+    let mut characters = text.chars();
+
+    while let Some(character) = characters.next() {
+        let character = match character {
+            '\\' => characters.next()?,
+            '"' => return None,
+            _ => character,
+        };
+
+        if character.is_control() && character != '\t' {
+            return None;
+        }
+
+        unquoted.push(character);
+    }
+
+    Some(unquoted)
+}
+
+/// Returns the byte offset of the first `target` outside double quotes.
+fn find_unquoted(text: &str, target: char) -> Option<usize> {
+    let mut quoted = false;
+    let mut escaped = false;
+
+    text.char_indices().find_map(|(index, character)| {
+        if escaped {
+            escaped = false;
+        } else if quoted && character == '\\' {
+            escaped = true;
+        } else if character == '"' {
+            quoted = !quoted;
+        } else if !quoted && character == target {
+            return Some(index);
+        }
+
+        None
+    })
+}
+
+/// Returns a mask with the top `prefix_length` bits of a `width`-bit address set.
+const fn network_mask(width: u8, prefix_length: u8) -> u128 {
+    let full = if width == 128 {
+        u128::MAX
+    } else {
+        (1 << width) - 1
+    };
+
+    match full.checked_shl((width - prefix_length) as u32) {
+        Some(mask) => mask & full,
+        None => 0,
+    }
+}
+
+/// Returns the size hint for an iterator that steps from `next` to `last` inclusive.
+fn remaining_hint(next: Option<u128>, last: u128, step: u128) -> (usize, Option<usize>) {
+    next.map_or(Some(0), |next| ((last - next) / step).checked_add(1))
+        .and_then(|remaining| usize::try_from(remaining).ok())
+        .map_or((usize::MAX, None), |remaining| (remaining, Some(remaining)))
+}
+
+/// Parses a dotted-quad IPv4 address starting at byte `offset` of the input.
+fn parse_ipv4(s: &str, offset: usize) -> Result<Ipv4Addr, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut octets = [0; 4];
+    let mut count = 0;
+    let mut position = offset;
+
+    for part in s.split('.') {
+        if count == 4 || part.is_empty() || part.len() > 3 {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        if let Some((index, character)) = part.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(ParseError::InvalidCharacter {
+                character,
+                position: position + index,
+            });
+        }
+
+        // Leading zeros are rejected, since some parsers read them as octal.
+        if part.len() > 1 && part.starts_with('0') {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        octets[count] = part.parse().map_err(|_| ParseError::OutOfRange)?;
+        count += 1;
+        position += part.len() + 1;
+    }
+
+    if count != 4 {
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    Ok(Ipv4Addr { octets })
+}
+
+/// Parses colon-separated hexadecimal segments into `segments`, returning how many were
+/// written. The last segment may be an embedded IPv4 address, which takes two.
+fn parse_segments(s: &str, offset: usize, segments: &mut [u16; 8]) -> Result<usize, ParseError> {
+    if s.is_empty() {
+        return Ok(0);
+    }
+
+    let mut count = 0;
+    let mut position = offset;
+    let mut parts = s.split(':').peekable();
+
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() && part.contains('.') {
+            if count > 6 {
+                return Err(ParseError::InvalidSyntax);
+            }
+
+            let [first, second, third, fourth] = parse_ipv4(part, position)?.octets;
+            segments[count] = u16::from_be_bytes([first, second]);
+            segments[count + 1] = u16::from_be_bytes([third, fourth]);
+            return Ok(count + 2);
+        }
+
+        if count == 8 || part.is_empty() || part.len() > 4 {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        if let Some((index, character)) = part.char_indices().find(|(_, c)| !c.is_ascii_hexdigit())
+        {
+            return Err(ParseError::InvalidCharacter {
+                character,
+                position: position + index,
+            });
+        }
+
+        segments[count] = u16::from_str_radix(part, 16).map_err(|_| ParseError::InvalidSyntax)?;
+        count += 1;
+        position += part.len() + 1;
+    }
+
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use super::*;
+
+    fn ip(text: &str) -> IpAddr {
+        text.parse().expect("a valid address")
+    }
+
+    fn cidr(text: &str) -> Cidr {
+        text.parse().expect("a valid network")
+    }
+
+    #[test]
+    fn test_ipv4() {
+        let address: Ipv4Addr = "192.168.0.10".parse().expect("a valid address");
+
+        assert_eq!(address.octets(), [192, 168, 0, 10]);
+        assert_eq!(address.to_bits(), 0xc0a8_000a);
+        assert_eq!(Ipv4Addr::from_bits(0xc0a8_000a), address);
+        assert!(address.is_private());
+        assert!(!Ipv4Addr::new(172, 32, 0, 1).is_private());
+        assert!(Ipv4Addr::new(224, 0, 0, 251).is_multicast());
+        assert_eq!(address.to_ipv6_mapped().to_string(), "::ffff:192.168.0.10");
+        assert_eq!(
+            core::net::Ipv4Addr::from(address),
+            core::net::Ipv4Addr::new(192, 168, 0, 10)
+        );
+
+        assert_eq!("".parse::<Ipv4Addr>(), Err(ParseError::Empty));
+        assert_eq!("1.2.3".parse::<Ipv4Addr>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "1.2.3.4.5".parse::<Ipv4Addr>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "1.2.03.4".parse::<Ipv4Addr>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!("1.2.3.256".parse::<Ipv4Addr>(), Err(ParseError::OutOfRange));
+        assert_eq!(
+            "1.2.x.4".parse::<Ipv4Addr>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'x',
+                position: 4
+            })
+        );
+    }
+
+    #[test]
+    fn test_ipv6_formatting() {
+        let cases = [
+            (
+                "2001:0db8:0000:0000:0000:ff00:0042:8329",
+                "2001:db8::ff00:42:8329",
+            ),
+            ("0:0:0:0:0:0:0:1", "::1"),
+            ("::", "::"),
+            ("1::", "1::"),
+            ("2001:db8:0:1:1:1:1:1", "2001:db8:0:1:1:1:1:1"),
+            ("2001:0:0:1:0:0:0:1", "2001:0:0:1::1"),
+            ("2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1"),
+            ("::ffff:10.0.0.1", "::ffff:10.0.0.1"),
+            ("64:ff9b::192.0.2.33", "64:ff9b::c000:221"),
+        ];
+
+        for (input, expected) in cases {
+            let address: Ipv6Addr = input.parse().expect("a valid address");
+            assert_eq!(address.to_string(), expected, "{input}");
+            assert_eq!(
+                address,
+                Ipv6Addr::from(input.parse::<core::net::Ipv6Addr>().expect("valid"))
+            );
+        }
+    }
+
+    #[test]
+    fn test_ipv6_parse_errors() {
+        let invalid = [
+            ":::",
+            "1:2:3:4:5:6:7",
+            "1:2:3:4:5:6:7:8:9",
+            "1::2::3",
+            "1:2:3:4:5:6:7::8",
+            "12345::",
+            ":1:2:3:4:5:6:7",
+            "1:2:3:4:5:6:7:",
+            "1.2.3.4::",
+            "::1.2.3",
+        ];
+
+        for input in invalid {
+            assert!(input.parse::<Ipv6Addr>().is_err(), "{input}");
+        }
+
+        assert_eq!(
+            "fe80::g".parse::<Ipv6Addr>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'g',
+                position: 6
+            })
+        );
+
+        let address: Ipv6Addr = "fe80::1".parse().expect("a valid address");
+        assert!(address.is_link_local());
+        assert!(Ipv6Addr::from_bits(0xfd00 << 112).is_unique_local());
+        assert_eq!(Ipv6Addr::from(address.octets()), address);
+    }
+
+    #[test]
+    fn test_cidr() {
+        let network = cidr("192.168.13.77/20");
+
+        assert_eq!(network.to_string(), "192.168.0.0/20");
+        assert_eq!(network.netmask(), ip("255.255.240.0"));
+        assert_eq!(network.hostmask(), ip("0.0.15.255"));
+        assert_eq!(network.last(), ip("192.168.15.255"));
+        assert!(network.contains(ip("192.168.15.1")));
+        assert!(!network.contains(ip("192.168.16.1")));
+        assert!(!network.contains(ip("::ffff:192.168.0.1")));
+        assert!(network.contains_network(cidr("192.168.4.0/24")));
+        assert!(!network.contains_network(cidr("192.168.0.0/16")));
+        assert_eq!(network.supernet(), Some(cidr("192.168.0.0/19")));
+        assert_eq!(cidr("0.0.0.0/0").supernet(), None);
+        assert!(cidr("0.0.0.0/0").contains(Ipv4Addr::BROADCAST));
+
+        let v6 = cidr("2001:db8:abcd::1/48");
+        assert_eq!(v6.to_string(), "2001:db8:abcd::/48");
+        assert_eq!(v6.last(), ip("2001:db8:abcd:ffff:ffff:ffff:ffff:ffff"));
+        assert!(cidr("::/0").contains(ip("ffff::1")));
+
+        assert_eq!("10.0.0.0/33".parse::<Cidr>(), Err(ParseError::OutOfRange));
+        assert_eq!("10.0.0.0".parse::<Cidr>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "10.0.0.0/2a".parse::<Cidr>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'a',
+                position: 10
+            })
+        );
+    }
+
+    #[test]
+    fn test_hosts_and_subnets() {
+        assert_eq!(cidr("10.0.0.0/24").hosts().size_hint(), (254, Some(254)));
+        assert_eq!(
+            cidr("10.0.0.0/31").hosts().collect::<Vec<_>>(),
+            [ip("10.0.0.0"), ip("10.0.0.1")]
+        );
+        assert_eq!(
+            cidr("10.0.0.9/32").hosts().collect::<Vec<_>>(),
+            [ip("10.0.0.9")]
+        );
+        assert_eq!(cidr("::/127").hosts().count(), 2);
+        assert_eq!(cidr("::/0").hosts().size_hint(), (usize::MAX, None));
+
+        let subnets: Vec<_> = cidr("10.0.0.0/22")
+            .subnets(24)
+            .expect("a longer prefix")
+            .map(|subnet| subnet.to_string())
+            .collect();
+        assert_eq!(
+            subnets,
+            ["10.0.0.0/24", "10.0.1.0/24", "10.0.2.0/24", "10.0.3.0/24"]
+        );
+        assert_eq!(
+            cidr("255.255.255.252/30").subnets(32).map(Iterator::count),
+            Some(4)
+        );
+        assert_eq!(cidr("::/0").subnets(0).map(Iterator::count), Some(1));
+        assert!(cidr("10.0.0.0/24").subnets(16).is_none());
+        assert!(cidr("10.0.0.0/24").subnets(33).is_none());
+    }
+
+    #[test]
+    fn test_mac_address() {
+        let address: MacAddr = "00:1A:2b:3c:4d:5e".parse().expect("a valid address");
+
+        assert_eq!(address.octets(), [0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+        assert_eq!(address.to_string(), "00:1a:2b:3c:4d:5e");
+        assert_eq!("00-1a-2b-3c-4d-5e".parse(), Ok(address));
+        assert_eq!("001a.2b3c.4d5e".parse(), Ok(address));
+        assert!(address.is_unicast());
+        assert!(!address.is_locally_administered());
+        assert!(MacAddr::BROADCAST.is_broadcast());
+        assert!(MacAddr::new([0x03, 0, 0, 0, 0, 1]).is_multicast());
+
+        assert_eq!("".parse::<MacAddr>(), Err(ParseError::Empty));
+        assert_eq!(
+            "00:1a:2b:3c:4d:5".parse::<MacAddr>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "00:1a-2b:3c:4d:5e".parse::<MacAddr>(),
+            Err(ParseError::InvalidCharacter {
+                character: '-',
+                position: 5
+            })
+        );
+        assert_eq!(
+            "00:1a:2b:3c:4d:5z".parse::<MacAddr>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'z',
+                position: 16
+            })
+        );
+    }
+
+    #[test]
+    fn test_email_address() {
+        let address = EmailAddress::parse("jane.doe@example.com").expect("valid address");
+        assert_eq!(address.local_part(), "jane.doe");
+        assert_eq!(address.domain(), "example.com");
+        assert_eq!(address.display_name(), None);
+        assert_eq!(address.tag(), None);
+
+        let address = EmailAddress::parse("  Jane Doe <jane+work@Example.org>  ")
+            .expect("valid address with a name");
+        assert_eq!(address.display_name(), Some("Jane Doe"));
+        assert_eq!(address.tag(), Some("work"));
+        assert_eq!(address.to_string(), "Jane Doe <jane+work@Example.org>");
+        assert_eq!(
+            address.normalized().without_tag().to_string(),
+            "Jane Doe <jane@example.org>"
+        );
+
+        let address =
+            EmailAddress::parse(r#""john \"j\" smith"@[192.0.2.1]"#).expect("quoted local part");
+        assert_eq!(address.local_part(), "john \"j\" smith");
+        assert_eq!(address.address(), r#""john \"j\" smith"@[192.0.2.1]"#);
+
+        let address = EmailAddress::parse("<\"a@b\"@[IPv6:2001:db8::1]>").expect("bracketed");
+        assert_eq!(address.local_part(), "a@b");
+        assert_eq!(address.domain(), "[IPv6:2001:db8::1]");
+
+        let address = EmailAddress::new("user", "bücher.example")
+            .expect("internationalized domain")
+            .with_display_name(Some("Doe, J."));
+        assert_eq!(address.to_string(), "\"Doe, J.\" <user@bücher.example>");
+        assert_eq!(address.to_string().parse::<EmailAddress>(), Ok(address));
+    }
+
+    #[test]
+    fn test_email_errors() {
+        let cases = [
+            ("", EmailError::Empty),
+            ("example.com", EmailError::MissingAt),
+            ("@example.com", EmailError::InvalidLocalPart),
+            ("a..b@example.com", EmailError::InvalidLocalPart),
+            ("a b@example.com", EmailError::InvalidLocalPart),
+            ("\"ab@example.com", EmailError::UnterminatedQuote),
+            ("user@", EmailError::InvalidDomain),
+            ("user@example..com", EmailError::InvalidDomain),
+            ("user@example.com.", EmailError::InvalidDomain),
+            ("user@[300.0.0.1]", EmailError::InvalidDomain),
+            ("Jane <user@example.com", EmailError::InvalidDisplayName),
+            (
+                "Ja\"ne\" <user@example.com>",
+                EmailError::InvalidDisplayName,
+            ),
+        ];
+
+        for (text, error) in cases {
+            assert_eq!(EmailAddress::parse(text), Err(error), "{text:?}");
+        }
+
+        let long_local = "a".repeat(65);
+        assert_eq!(
+            EmailAddress::new(&long_local, "example.com"),
+            Err(EmailError::LocalPartTooLong)
+        );
+
+        let long_domain = ["a".repeat(60).as_str(); 4].join(".");
+        assert_eq!(
+            EmailAddress::new(&"a".repeat(20), &long_domain),
+            Err(EmailError::TooLong)
+        );
+    }
+}
@@ -1,7 +1,18 @@
-use core::{fmt, str::FromStr};
+use core::{
+    f64::consts::{FRAC_PI_2, FRAC_PI_6, PI},
+    fmt,
+    str::FromStr,
+};
 
-use alloc::string::String;
+use self::traits::FloatingPoint;
+use crate::errors::ParseError;
 
+pub mod complex;
+pub mod decimal;
+pub mod fft;
+pub mod linear;
+pub mod modular;
+pub mod primes;
 pub mod traits;
 
 #[derive(Debug, PartialEq, PartialOrd)]
@@ -227,9 +238,13 @@ impl fmt::Display for Number {
 }
 
 impl FromStr for Number {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
         let bool_result = s.parse::<bool>().map(Number::Bool);
         let int_result = s.parse::<isize>().map(Number::Int);
         let int8_result = s.parse::<i8>().map(Number::Int8);
@@ -253,7 +268,7 @@ impl FromStr for Number {
             .or(uint32_result)
             .or(float_result)
             .or(double_result)
-            .map_err(|e| alloc::format!("{e}"))
+            .map_err(|_| ParseError::InvalidSyntax)
     }
 }
 
@@ -268,3 +283,185 @@ impl From<isize> for Number {
         Self::Int(value)
     }
 }
+
+impl From<i8> for Number {
+    fn from(value: i8) -> Self {
+        Self::Int8(value)
+    }
+}
+
+impl From<i16> for Number {
+    fn from(value: i16) -> Self {
+        Self::Int16(value)
+    }
+}
+
+impl From<i32> for Number {
+    fn from(value: i32) -> Self {
+        Self::Int32(value)
+    }
+}
+
+impl From<usize> for Number {
+    fn from(value: usize) -> Self {
+        Self::UInt(value)
+    }
+}
+
+impl From<u8> for Number {
+    fn from(value: u8) -> Self {
+        Self::UInt8(value)
+    }
+}
+
+impl From<u16> for Number {
+    fn from(value: u16) -> Self {
+        Self::UInt16(value)
+    }
+}
+
+impl From<u32> for Number {
+    fn from(value: u32) -> Self {
+        Self::UInt32(value)
+    }
+}
+
+impl From<f32> for Number {
+    fn from(value: f32) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Self::Double(value)
+    }
+}
+
+/// Returns the square root of `x`, correctly rounded, from the integer square root of
+/// its significand.
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    if x <= 0.0 || !x.is_finite() {
+        return if x == 0.0 || x == f64::INFINITY {
+            x
+        } else {
+            f64::NAN
+        };
+    }
+
+    // Write `x` as `significand · 2^exponent` with an integer significand.
+    let bits = x.to_bits();
+    let biased = (bits >> 52) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mut significand, mut exponent) = if biased == 0 {
+        let shift = fraction.leading_zeros() - 11;
+        (u128::from(fraction << shift), -1074 - shift as i32)
+    } else {
+        (u128::from(fraction | 1 << 52), biased - 1075)
+    };
+
+    if exponent % 2 != 0 {
+        significand <<= 1;
+        exponent -= 1;
+    }
+
+    // The scaled significand lies in [2^104, 2^106), so its root has 53 bits. The true
+    // root exceeds `root + 1/2` exactly when the remainder exceeds `root`.
+    let scaled = significand << 52;
+    let mut root = scaled.isqrt();
+    if scaled - root * root > root {
+        root += 1;
+    }
+
+    // The root of any positive double is a normal number, so the power of two is exact.
+    let power = f64::from_bits((((exponent - 52) / 2 + 1023) as u64) << 52);
+    root as f64 * power
+}
+
+/// Returns the cosine and sine of `x` radians, for `|x| <= π/4`, from their Taylor
+/// series evaluated in Horner form.
+pub(crate) fn cos_sin_kernel(x: f64) -> (f64, f64) {
+    let square = x * x;
+    let (mut cos, mut sin) = (1.0, 1.0);
+
+    for n in (1..=9u8).rev() {
+        let n = f64::from(n);
+        sin = 1.0 - square / ((2.0 * n) * (2.0 * n + 1.0)) * sin;
+        cos = 1.0 - square / ((2.0 * n - 1.0) * (2.0 * n)) * cos;
+    }
+
+    (cos, x * sin)
+}
+
+/// Returns the sine and cosine of `x` radians.
+///
+/// The argument is reduced by `π/2` split into two parts, which keeps the result within
+/// a few ulps for arguments up to about a million.
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) fn sin_cos(x: f64) -> (f64, f64) {
+    // The high part has 33 significant bits, so its product with the quadrant is exact.
+    const FRAC_PI_2_HIGH: f64 = 1.570_796_326_734_125_6;
+    const FRAC_PI_2_LOW: f64 = 6.077_100_506_506_192e-11;
+
+    let quadrant = FloatingPoint::floor(x / FRAC_PI_2 + 0.5);
+    let reduced = (x - quadrant * FRAC_PI_2_HIGH) - quadrant * FRAC_PI_2_LOW;
+    let (cos, sin) = cos_sin_kernel(reduced);
+
+    match quadrant as i64 & 3 {
+        0 => (sin, cos),
+        1 => (cos, -sin),
+        2 => (-sin, -cos),
+        _ => (-cos, sin),
+    }
+}
+
+/// Returns the angle in radians, in `-π..=π`, between the positive x axis and the point
+/// `(x, y)`.
+pub(crate) fn atan2(y: f64, x: f64) -> f64 {
+    if y.is_nan() || x.is_nan() {
+        return f64::NAN;
+    }
+
+    let (rise, run) = (y.abs(), x.abs());
+    let angle = if rise == 0.0 {
+        0.0
+    } else if rise <= run {
+        atan_unit(rise / run)
+    } else {
+        FRAC_PI_2 - atan_unit(run / rise)
+    };
+    let angle = if x.is_sign_negative() {
+        PI - angle
+    } else {
+        angle
+    };
+
+    if y.is_sign_negative() { -angle } else { angle }
+}
+
+/// Returns the arctangent of `t` in `0.0..=1.0`.
+fn atan_unit(t: f64) -> f64 {
+    const SQRT_3: f64 = 1.732_050_807_568_877_2;
+    const TAN_FRAC_PI_12: f64 = 0.267_949_192_431_122_7;
+
+    // atan(t) = π/6 + atan((t√3 − 1)/(t + √3)) brings the argument within tan(π/12).
+    let (offset, t) = if t > TAN_FRAC_PI_12 {
+        (FRAC_PI_6, (t * SQRT_3 - 1.0) / (t + SQRT_3))
+    } else {
+        (0.0, t)
+    };
+    let square = t * t;
+    let mut series = 0.0;
+
+    for k in (0..15u8).rev() {
+        series = 1.0 / f64::from(2 * k + 1) - square * series;
+    }
+
+    offset + t * series
+}
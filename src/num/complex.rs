@@ -0,0 +1,184 @@
+//! Complex numbers.
+//!
+//! [`Complex`] is a pair of `f64`s with the arithmetic operators, which is what
+//! [`fft`](super::fft) and other signal-processing code need. It does not try to be a
+//! general numeric tower: there is no generic component type and no transcendental
+//! functions beyond what the crate can compute in `core`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::num::complex::Complex;
+//!
+//! let z = Complex::new(3.0, 4.0);
+//!
+//! assert_eq!(z * Complex::I, Complex::new(-4.0, 3.0));
+//! assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+//! assert_eq!(z.norm(), 5.0);
+//! assert_eq!(z.to_string(), "3+4i");
+//! ```
+
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+/// A complex number with `f64` real and imaginary parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex {
+    /// The real part.
+    pub re: f64,
+
+    /// The imaginary part.
+    pub im: f64,
+}
+
+impl Complex {
+    /// Zero.
+    pub const ZERO: Self = Self::new(0.0, 0.0);
+
+    /// One.
+    pub const ONE: Self = Self::new(1.0, 0.0);
+
+    /// The imaginary unit.
+    pub const I: Self = Self::new(0.0, 1.0);
+
+    /// Creates the number `re + im·i`.
+    #[must_use]
+    pub const fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Returns the complex conjugate, `re - im·i`.
+    #[must_use]
+    pub const fn conj(self) -> Self {
+        Self::new(self.re, -self.im)
+    }
+
+    /// Returns the squared magnitude, `re² + im²`.
+    #[must_use]
+    pub const fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the magnitude, correctly rounded for values whose squared magnitude neither
+    /// overflows nor underflows.
+    #[must_use]
+    pub fn norm(self) -> f64 {
+        super::sqrt(self.norm_sqr())
+    }
+
+    /// Multiplies both parts by `factor`.
+    #[must_use]
+    pub const fn scale(self, factor: f64) -> Self {
+        Self::new(self.re * factor, self.im * factor)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Self::new(re, 0.0)
+    }
+}
+
+impl Add for Complex {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        (self * rhs.conj()).scale(1.0 / rhs.norm_sqr())
+    }
+}
+
+impl Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.re, -self.im)
+    }
+}
+
+impl AddAssign for Complex {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for Complex {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl MulAssign for Complex {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl fmt::Display for Complex {
+    /// Formats the number as `a+bi` or `a-bi`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.im.is_sign_negative() { '-' } else { '+' };
+        write!(f, "{}{sign}{}i", self.re, self.im.abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!((a * b) / b, a);
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+        assert_eq!(Complex::I * Complex::I, -Complex::ONE);
+        assert_eq!(Complex::from(2.5).to_string(), "2.5+0i");
+        assert_eq!(b.to_string(), "3-1i");
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_norm_is_correctly_rounded() {
+        assert_eq!(Complex::new(5.0, 12.0).norm(), 13.0);
+        assert_eq!(Complex::new(1.0, 1.0).norm(), core::f64::consts::SQRT_2);
+        assert_eq!(Complex::ZERO.norm(), 0.0);
+        assert_eq!(Complex::new(1e-150, 0.0).norm(), 1e-150);
+        assert!(Complex::new(f64::NAN, 0.0).norm().is_nan());
+    }
+}
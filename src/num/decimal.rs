@@ -0,0 +1,705 @@
+//! Exact decimal numbers.
+//!
+//! [`Decimal`] is an `i128` mantissa with a power-of-ten scale, so `Decimal::new(1050, 2)`
+//! is exactly `10.50`. It is meant for amounts that must not pick up binary
+//! floating-point error, such as money. The `checked_*` methods return `None` on
+//! overflow and the operators panic, as the integer operators do. Division and rounding
+//! take an explicit [`RoundingMode`].
+//!
+//! Values compare by what they denote, not by representation: `1.50` and `1.5` are
+//! equal and hash alike, but keep their own scale when printed.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::num::decimal::{Decimal, RoundingMode};
+//!
+//! let price: Decimal = "19.99".parse()?;
+//! let total = price * Decimal::from(3);
+//!
+//! assert_eq!(total.to_string(), "59.97");
+//! assert_eq!(Decimal::new(150, 2), Decimal::new(15, 1));
+//!
+//! let half = Decimal::new(25, 1);
+//! assert_eq!(half.round(0, RoundingMode::HalfEven), Decimal::from(2));
+//! assert_eq!(half.round(0, RoundingMode::HalfUp), Decimal::from(3));
+//!
+//! let third = Decimal::ONE.checked_div(Decimal::from(3), 4, RoundingMode::HalfEven);
+//! assert_eq!(third, Some(Decimal::new(3333, 4)));
+//! # Ok::<(), libx::errors::ParseError>(())
+//! ```
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Write},
+    hash::{Hash, Hasher},
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::errors::ParseError;
+
+/// The largest number of fraction digits a [`Decimal`] keeps.
+pub const MAX_SCALE: u8 = 28;
+
+/// `10^0` through `10^38`, every power of ten that fits in an `i128`.
+const POWERS_OF_TEN: [i128; 39] = {
+    let mut powers = [1; 39];
+    let mut index = 1;
+
+    while index < powers.len() {
+        powers[index] = powers[index - 1] * 10;
+        index += 1;
+    }
+
+    powers
+};
+
+const fn power_of_ten(exponent: u8) -> i128 {
+    POWERS_OF_TEN[exponent as usize]
+}
+
+/// How a value that falls between two representable values is rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// To the nearest value, and ties to the even one. Also called banker's rounding.
+    #[default]
+    HalfEven,
+
+    /// To the nearest value, and ties away from zero.
+    HalfUp,
+
+    /// To the nearest value, and ties toward zero.
+    HalfDown,
+
+    /// Away from zero.
+    Up,
+
+    /// Toward zero, which truncates.
+    Down,
+
+    /// Toward positive infinity.
+    Ceiling,
+
+    /// Toward negative infinity.
+    Floor,
+}
+
+impl RoundingMode {
+    /// Returns `numerator / denominator` rounded to an integer. `denominator` must be
+    /// positive.
+    pub(crate) const fn divide(self, numerator: i128, denominator: i128) -> i128 {
+        let quotient = numerator / denominator;
+        let remainder = (numerator % denominator).unsigned_abs();
+
+        if remainder == 0 {
+            return quotient;
+        }
+
+        let rest = denominator.unsigned_abs() - remainder;
+        let away = match self {
+            Self::HalfEven => remainder > rest || (remainder == rest && quotient % 2 != 0),
+            Self::HalfUp => remainder >= rest,
+            Self::HalfDown => remainder > rest,
+            Self::Up => true,
+            Self::Down => false,
+            Self::Ceiling => numerator > 0,
+            Self::Floor => numerator < 0,
+        };
+
+        // A nonzero remainder means the denominator is at least two, so the quotient is
+        // at most half of `i128::MAX` and stepping it cannot overflow.
+        if away {
+            quotient + numerator.signum()
+        } else {
+            quotient
+        }
+    }
+}
+
+/// A decimal number: an `i128` mantissa divided by `10^scale`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u8,
+}
+
+impl Decimal {
+    /// Zero.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// One.
+    pub const ONE: Self = Self::new(1, 0);
+
+    /// The largest value.
+    pub const MAX: Self = Self::new(i128::MAX, 0);
+
+    /// The smallest value.
+    pub const MIN: Self = Self::new(i128::MIN, 0);
+
+    /// Creates the number `mantissa / 10^scale`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is greater than [`MAX_SCALE`].
+    #[must_use]
+    pub const fn new(mantissa: i128, scale: u8) -> Self {
+        assert!(scale <= MAX_SCALE, "decimal scale exceeds MAX_SCALE");
+
+        Self { mantissa, scale }
+    }
+
+    /// Returns the mantissa, the value times `10^scale`.
+    #[must_use]
+    pub const fn mantissa(self) -> i128 {
+        self.mantissa
+    }
+
+    /// Returns the number of fraction digits.
+    #[must_use]
+    pub const fn scale(self) -> u8 {
+        self.scale
+    }
+
+    /// Returns whether the value is zero.
+    #[must_use]
+    pub const fn is_zero(self) -> bool {
+        self.mantissa == 0
+    }
+
+    /// Returns whether the value is less than zero.
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Returns whether the value is greater than zero.
+    #[must_use]
+    pub const fn is_positive(self) -> bool {
+        self.mantissa > 0
+    }
+
+    /// Returns the negated value, or `None` if it overflows.
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.mantissa.checked_neg() {
+            Some(mantissa) => Some(Self::new(mantissa, self.scale)),
+            None => None,
+        }
+    }
+
+    /// Returns the absolute value, or `None` if it overflows.
+    #[must_use]
+    pub const fn checked_abs(self) -> Option<Self> {
+        match self.mantissa.checked_abs() {
+            Some(mantissa) => Some(Self::new(mantissa, self.scale)),
+            None => None,
+        }
+    }
+
+    /// Returns the same value with trailing fraction zeros removed.
+    #[must_use]
+    pub const fn normalize(self) -> Self {
+        let mut normalized = self;
+
+        while normalized.scale > 0 && normalized.mantissa % 10 == 0 {
+            normalized.mantissa /= 10;
+            normalized.scale -= 1;
+        }
+
+        normalized
+    }
+
+    /// Returns the value rounded to at most `scale` fraction digits.
+    ///
+    /// Values that already have `scale` fraction digits or fewer are returned unchanged.
+    #[must_use]
+    pub const fn round(self, scale: u8, mode: RoundingMode) -> Self {
+        if scale >= self.scale {
+            return self;
+        }
+
+        let divisor = power_of_ten(self.scale - scale);
+        Self::new(mode.divide(self.mantissa, divisor), scale)
+    }
+
+    /// Returns the same value written with exactly `scale` fraction digits.
+    ///
+    /// Returns `None` if `scale` is greater than [`MAX_SCALE`], if the mantissa would
+    /// overflow, or if dropping fraction digits would change the value. Use
+    /// [`round`](Self::round) to drop digits that are not zero.
+    #[must_use]
+    pub const fn rescale(self, scale: u8) -> Option<Self> {
+        if scale > MAX_SCALE {
+            return None;
+        }
+
+        if scale >= self.scale {
+            return match self.mantissa.checked_mul(power_of_ten(scale - self.scale)) {
+                Some(mantissa) => Some(Self::new(mantissa, scale)),
+                None => None,
+            };
+        }
+
+        let divisor = power_of_ten(self.scale - scale);
+
+        if self.mantissa % divisor == 0 {
+            Some(Self::new(self.mantissa / divisor, scale))
+        } else {
+            None
+        }
+    }
+
+    /// Returns `self + other`, or `None` if it overflows.
+    ///
+    /// The sum has the larger of the two scales.
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let (left, right) = (self.rescale(scale)?, other.rescale(scale)?);
+
+        left.mantissa
+            .checked_add(right.mantissa)
+            .map(|mantissa| Self::new(mantissa, scale))
+    }
+
+    /// Returns `self - other`, or `None` if it overflows.
+    ///
+    /// The difference has the larger of the two scales.
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let scale = self.scale.max(other.scale);
+        let (left, right) = (self.rescale(scale)?, other.rescale(scale)?);
+
+        left.mantissa
+            .checked_sub(right.mantissa)
+            .map(|mantissa| Self::new(mantissa, scale))
+    }
+
+    /// Returns `self * other`, or `None` if it overflows.
+    ///
+    /// The product has the sum of the two scales, rounded half to even down to
+    /// [`MAX_SCALE`] if it is larger.
+    #[must_use]
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let (left, right) = match self.mantissa.checked_mul(other.mantissa) {
+            Some(_) => (self, other),
+            None => (self.normalize(), other.normalize()),
+        };
+        let mantissa = left.mantissa.checked_mul(right.mantissa)?;
+        let scale = left.scale + right.scale;
+
+        if scale <= MAX_SCALE {
+            return Some(Self::new(mantissa, scale));
+        }
+
+        let divisor = power_of_ten(scale - MAX_SCALE);
+        Some(Self::new(
+            RoundingMode::HalfEven.divide(mantissa, divisor),
+            MAX_SCALE,
+        ))
+    }
+
+    /// Returns `self / divisor` rounded to `scale` fraction digits with `mode`.
+    ///
+    /// Returns `None` if `divisor` is zero, if `scale` is greater than [`MAX_SCALE`] or if
+    /// an intermediate product overflows.
+    #[must_use]
+    pub fn checked_div(self, divisor: Self, scale: u8, mode: RoundingMode) -> Option<Self> {
+        if divisor.is_zero() || scale > MAX_SCALE {
+            return None;
+        }
+
+        let (dividend, divisor) = (self.normalize(), divisor.normalize());
+        // `dividend / divisor * 10^scale`, written as a ratio of integers.
+        let shift = i32::from(divisor.scale) + i32::from(scale) - i32::from(dividend.scale);
+        let power = |exponent: i32| {
+            u8::try_from(exponent)
+                .ok()
+                .and_then(|exponent| POWERS_OF_TEN.get(usize::from(exponent)).copied())
+        };
+
+        let (mut numerator, mut denominator) = if shift >= 0 {
+            (
+                dividend.mantissa.checked_mul(power(shift)?)?,
+                divisor.mantissa,
+            )
+        } else {
+            (
+                dividend.mantissa,
+                divisor.mantissa.checked_mul(power(-shift)?)?,
+            )
+        };
+
+        if denominator < 0 {
+            numerator = numerator.checked_neg()?;
+            denominator = denominator.checked_neg()?;
+        }
+
+        Some(Self::new(mode.divide(numerator, denominator), scale))
+    }
+
+    /// Returns the integer part and the fraction at [`MAX_SCALE`], both truncated toward
+    /// zero, which order the same way as the values they come from.
+    const fn parts(self) -> (i128, i128) {
+        let unit = power_of_ten(self.scale);
+
+        (
+            self.mantissa / unit,
+            self.mantissa % unit * power_of_ten(MAX_SCALE - self.scale),
+        )
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.parts() == other.parts()
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.parts().cmp(&other.parts())
+    }
+}
+
+impl Hash for Decimal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let normalized = self.normalize();
+
+        normalized.mantissa.hash(state);
+        normalized.scale.hash(state);
+    }
+}
+
+macro_rules! from_integer {
+    ($($integer:ty),*) => {$(
+        impl From<$integer> for Decimal {
+            fn from(value: $integer) -> Self {
+                Self::new(i128::from(value), 0)
+            }
+        }
+    )*};
+}
+
+from_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64);
+
+impl Add for Decimal {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("attempt to add with overflow")
+    }
+}
+
+impl Sub for Decimal {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul for Decimal {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Neg for Decimal {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        self.checked_neg().expect("attempt to negate with overflow")
+    }
+}
+
+impl AddAssign for Decimal {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Decimal {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Decimal {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+/// Writes every fraction digit of the scale. A precision, as in `{:.2}`, rounds half to
+/// even or pads with zeros to that many digits.
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = usize::from(self.scale);
+        let (value, padding) = match f.precision() {
+            // The precision is below the scale, so it fits in a `u8`.
+            #[allow(clippy::cast_possible_truncation)]
+            Some(precision) if precision < scale => {
+                (self.round(precision as u8, RoundingMode::HalfEven), 0)
+            }
+            Some(precision) => (*self, precision - scale),
+            None => (*self, 0),
+        };
+
+        let unit = power_of_ten(value.scale).unsigned_abs();
+        let magnitude = value.mantissa.unsigned_abs();
+
+        if value.is_negative() {
+            f.write_char('-')?;
+        }
+
+        write!(f, "{}", magnitude / unit)?;
+
+        if value.scale > 0 {
+            let digits = usize::from(value.scale);
+            write!(f, ".{:0digits$}", magnitude % unit)?;
+        } else if padding > 0 {
+            f.write_char('.')?;
+        }
+
+        (0..padding).try_for_each(|_| f.write_char('0'))
+    }
+}
+
+/// Parses an optional sign, digits and an optional `.` followed by more digits, such as
+/// `-12.50`. The scale is the number of digits after the point.
+impl FromStr for Decimal {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, ParseError> {
+        let (negative, digits) = match text.as_bytes().first() {
+            None => return Err(ParseError::Empty),
+            Some(b'-') => (true, &text[1..]),
+            Some(b'+') => (false, &text[1..]),
+            Some(_) => (false, text),
+        };
+        let offset = text.len() - digits.len();
+
+        let mut mantissa: i128 = 0;
+        let mut scale = 0;
+        let mut has_digits = false;
+        let mut in_fraction = false;
+
+        for (index, character) in digits.char_indices() {
+            match character {
+                '0'..='9' => {
+                    let digit = i128::from(character as u8 - b'0');
+
+                    mantissa = mantissa
+                        .checked_mul(10)
+                        .and_then(|mantissa| {
+                            if negative {
+                                mantissa.checked_sub(digit)
+                            } else {
+                                mantissa.checked_add(digit)
+                            }
+                        })
+                        .ok_or(ParseError::OutOfRange)?;
+
+                    if in_fraction {
+                        scale += 1;
+
+                        if scale > MAX_SCALE {
+                            return Err(ParseError::OutOfRange);
+                        }
+                    }
+
+                    has_digits = true;
+                }
+                '.' if !in_fraction => in_fraction = true,
+                _ => {
+                    return Err(ParseError::InvalidCharacter {
+                        character,
+                        position: offset + index,
+                    });
+                }
+            }
+        }
+
+        if !has_digits {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        Ok(Self::new(mantissa, scale))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+    use core::hash::BuildHasher;
+
+    use super::*;
+    use crate::{hashing::SipHashState, random::RandomNumberGenerator, testing::check_property};
+
+    fn decimal(text: &str) -> Decimal {
+        text.parse().expect("the literal is a valid decimal")
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        for text in [
+            "0",
+            "-1.50",
+            "12.345",
+            "0.0001",
+            "170141183460469231731687303715884105727",
+        ] {
+            assert_eq!(decimal(text).to_string(), text);
+        }
+
+        assert_eq!(decimal("+.5").to_string(), "0.5");
+        assert_eq!(decimal("-0").to_string(), "0");
+        assert_eq!(alloc::format!("{:.2}", decimal("2.345")), "2.34");
+        assert_eq!(alloc::format!("{:.3}", decimal("7")), "7.000");
+
+        assert_eq!("".parse::<Decimal>(), Err(ParseError::Empty));
+        assert_eq!("-".parse::<Decimal>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "1.2.3".parse::<Decimal>(),
+            Err(ParseError::InvalidCharacter {
+                character: '.',
+                position: 3
+            })
+        );
+        assert_eq!(
+            "1e5".parse::<Decimal>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'e',
+                position: 1
+            })
+        );
+        assert_eq!(
+            "0.00000000000000000000000000001".parse::<Decimal>(),
+            Err(ParseError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_rounding_modes() {
+        use RoundingMode::{Ceiling, Down, Floor, HalfDown, HalfEven, HalfUp, Up};
+
+        let cases = [
+            ("2.5", [2, 3, 2, 3, 2, 3, 2]),
+            ("3.5", [4, 4, 3, 4, 3, 4, 3]),
+            ("-2.5", [-2, -3, -2, -3, -2, -2, -3]),
+            ("2.4", [2, 2, 2, 3, 2, 3, 2]),
+            ("-2.6", [-3, -3, -3, -3, -2, -2, -3]),
+            ("7", [7, 7, 7, 7, 7, 7, 7]),
+        ];
+        let modes = [HalfEven, HalfUp, HalfDown, Up, Down, Ceiling, Floor];
+
+        for (text, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(
+                    decimal(text).round(0, mode),
+                    Decimal::from(expected),
+                    "{text} rounded {mode:?}"
+                );
+            }
+        }
+
+        assert_eq!(decimal("1.2345").round(2, HalfEven).to_string(), "1.23");
+        assert_eq!(decimal("1.2").round(4, HalfEven).to_string(), "1.2");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        assert_eq!((decimal("0.1") + decimal("0.2")).to_string(), "0.3");
+        assert_eq!((decimal("1.50") - decimal("2")).to_string(), "-0.50");
+        assert_eq!((decimal("1.5") * decimal("-0.25")).to_string(), "-0.375");
+        assert_eq!(-decimal("4.2"), decimal("-4.2"));
+
+        assert_eq!(
+            decimal("10").checked_div(decimal("4"), 2, RoundingMode::HalfEven),
+            Some(decimal("2.5"))
+        );
+        assert_eq!(
+            decimal("2").checked_div(decimal("-3"), 3, RoundingMode::Floor),
+            Some(decimal("-0.667"))
+        );
+        assert_eq!(
+            decimal("1.25").checked_div(decimal("0.005"), 0, RoundingMode::Down),
+            Some(decimal("250"))
+        );
+        assert_eq!(
+            decimal("1").checked_div(Decimal::ZERO, 2, RoundingMode::HalfEven),
+            None
+        );
+
+        assert_eq!(Decimal::MAX.checked_add(Decimal::ONE), None);
+        assert_eq!(Decimal::MIN.checked_neg(), None);
+        assert_eq!(decimal("1.0").rescale(3), Some(decimal("1.000")));
+        assert_eq!(decimal("1.05").rescale(1), None);
+
+        let tiny = Decimal::new(1, MAX_SCALE);
+        assert_eq!(tiny * decimal("0.5"), Decimal::ZERO);
+        assert_eq!(tiny * decimal("1.5"), Decimal::new(2, MAX_SCALE));
+    }
+
+    #[test]
+    fn test_equality_ignores_scale() {
+        let hasher = SipHashState::<1, 3>::with_keys(0, 0);
+
+        assert_eq!(decimal("1.50"), decimal("1.5"));
+        assert_eq!(
+            hasher.hash_one(decimal("1.50")),
+            hasher.hash_one(decimal("1.5"))
+        );
+        assert_eq!(decimal("-0.00"), Decimal::ZERO);
+        assert!(decimal("-1.5") < decimal("-1.25"));
+        assert!(decimal("0.001") > decimal("-0.001"));
+        assert!(Decimal::MAX > Decimal::new(i128::MAX, MAX_SCALE));
+    }
+
+    #[test]
+    fn test_ordering_matches_integers() {
+        let result = check_property(0xdec1, 500, |rng| {
+            #[allow(clippy::cast_possible_wrap)] // Any bit pattern is a fine mantissa.
+            let mut pick = || {
+                // Small enough to rescale to `MAX_SCALE` without overflowing.
+                let mantissa = rng.next_u32() as i32;
+                let scale = rng.next_bounded(u64::from(MAX_SCALE) + 1);
+                #[allow(clippy::cast_possible_truncation)] // The scale is at most 28.
+                Decimal::new(i128::from(mantissa), scale as u8)
+            };
+            let (a, b) = (pick(), pick());
+
+            // At a common scale the order is the order of the mantissas.
+            let (Some(wide_a), Some(wide_b)) = (a.rescale(MAX_SCALE), b.rescale(MAX_SCALE)) else {
+                return Err(alloc::format!("{a} or {b} does not rescale").into());
+            };
+
+            if wide_a.mantissa.cmp(&wide_b.mantissa) != a.cmp(&b) {
+                return Err(alloc::format!("{a} and {b} compare as {:?}", a.cmp(&b)).into());
+            }
+
+            if a.checked_sub(b).map(Decimal::is_zero) != Some(a == b) {
+                return Err(alloc::format!("{a} - {b} disagrees with {a} == {b}").into());
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+}
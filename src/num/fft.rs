@@ -0,0 +1,374 @@
+//! Fast Fourier transforms and window functions.
+//!
+//! [`fft`] and [`ifft`] transform a buffer of [`Complex`] samples in place with the
+//! iterative radix-2 Cooley–Tukey algorithm, so the length must be a power of two.
+//! [`rfft`] transforms real samples at about half the cost by packing them into a
+//! complex buffer of half the length. [`Window`] tapers a block of samples before it is
+//! transformed to reduce spectral leakage.
+//!
+//! The sines and cosines are computed in `core` from their series, so the module works
+//! without `std` or a math library. With the `nightly` feature, windows are applied with
+//! portable SIMD.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::num::{complex::Complex, fft};
+//!
+//! // A cosine that completes two cycles in eight samples.
+//! let samples = [1.0, 0.0, -1.0, 0.0, 1.0, 0.0, -1.0, 0.0];
+//! let spectrum = fft::rfft(&samples).unwrap();
+//!
+//! assert_eq!(spectrum.len(), 5);
+//! assert!((spectrum[2] - Complex::new(4.0, 0.0)).norm() < 1e-12);
+//! assert!(spectrum[1].norm() < 1e-12);
+//! ```
+
+use alloc::vec::Vec;
+use core::f64::consts::FRAC_PI_2;
+
+use super::{complex::Complex, traits::FloatingPoint};
+use crate::errors::FftError;
+
+/// Replaces `buffer` with its discrete Fourier transform.
+///
+/// Bin `k` of the result is `Σ x[n]·e^(−2πi·kn/N)`, without normalization.
+///
+/// # Errors
+///
+/// Returns [`FftError::LengthNotPowerOfTwo`] if the length of `buffer` is not a power
+/// of two; the buffer is left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::{complex::Complex, fft::fft};
+///
+/// let mut buffer = [Complex::ONE; 4];
+/// fft(&mut buffer).unwrap();
+///
+/// assert_eq!(buffer, [Complex::new(4.0, 0.0), Complex::ZERO, Complex::ZERO, Complex::ZERO]);
+/// ```
+pub fn fft(buffer: &mut [Complex]) -> Result<(), FftError> {
+    transform(buffer, false)
+}
+
+/// Replaces `buffer` with its inverse discrete Fourier transform, scaled by `1/N` so
+/// that it undoes [`fft`].
+///
+/// # Errors
+///
+/// Returns [`FftError::LengthNotPowerOfTwo`] if the length of `buffer` is not a power
+/// of two; the buffer is left unchanged.
+pub fn ifft(buffer: &mut [Complex]) -> Result<(), FftError> {
+    transform(buffer, true)?;
+
+    #[allow(clippy::cast_precision_loss)] // Powers of two are exact.
+    let scale = 1.0 / buffer.len() as f64;
+
+    for value in buffer {
+        *value = value.scale(scale);
+    }
+
+    Ok(())
+}
+
+/// Returns the first `N/2 + 1` bins of the discrete Fourier transform of real
+/// `samples`; the rest are the conjugates of these in reverse order.
+///
+/// # Errors
+///
+/// Returns [`FftError::LengthNotPowerOfTwo`] if the number of samples is not a power of
+/// two.
+pub fn rfft(samples: &[f64]) -> Result<Vec<Complex>, FftError> {
+    let n = samples.len();
+
+    if !n.is_power_of_two() {
+        return Err(FftError::LengthNotPowerOfTwo { len: n });
+    }
+
+    if n == 1 {
+        return Ok(alloc::vec![Complex::from(samples[0])]);
+    }
+
+    // Even samples become real parts and odd samples imaginary parts of a transform of
+    // half the length, whose bins are then separated and recombined.
+    let half = n / 2;
+    let mut packed: Vec<Complex> = samples
+        .chunks_exact(2)
+        .map(|pair| Complex::new(pair[0], pair[1]))
+        .collect();
+    fft(&mut packed)?;
+
+    Ok((0..=half)
+        .map(|k| {
+            let bin = packed[k % half];
+            let mirror = packed[(half - k) % half].conj();
+            let even = (bin + mirror).scale(0.5);
+            let odd = (bin - mirror) * Complex::new(0.0, -0.5);
+
+            even + twiddle(k, n, false) * odd
+        })
+        .collect())
+}
+
+fn transform(buffer: &mut [Complex], inverse: bool) -> Result<(), FftError> {
+    let n = buffer.len();
+
+    if !n.is_power_of_two() {
+        return Err(FftError::LengthNotPowerOfTwo { len: n });
+    }
+
+    if n == 1 {
+        return Ok(());
+    }
+
+    let shift = usize::BITS - n.trailing_zeros();
+
+    for index in 0..n {
+        let reversed = index.reverse_bits() >> shift;
+
+        if index < reversed {
+            buffer.swap(index, reversed);
+        }
+    }
+
+    let twiddles: Vec<Complex> = (0..n / 2).map(|k| twiddle(k, n, inverse)).collect();
+    let mut len = 2;
+
+    while len <= n {
+        let stride = n / len;
+
+        for block in buffer.chunks_exact_mut(len) {
+            let (low, high) = block.split_at_mut(len / 2);
+
+            for (k, (a, b)) in low.iter_mut().zip(high).enumerate() {
+                let product = *b * twiddles[k * stride];
+                *b = *a - product;
+                *a += product;
+            }
+        }
+
+        len *= 2;
+    }
+
+    Ok(())
+}
+
+/// Returns `e^(∓2πi·k/n)`, with the positive sign for the inverse transform.
+#[allow(clippy::cast_precision_loss)] // Indices and lengths are far below 2^53.
+fn twiddle(k: usize, n: usize, inverse: bool) -> Complex {
+    let (cos, sin) = cos_sin_turns(k as f64 / n as f64);
+    Complex::new(cos, if inverse { sin } else { -sin })
+}
+
+/// Returns the cosine and sine of `turns` full turns, for `turns` in `0.0..=1.0`.
+fn cos_sin_turns(turns: f64) -> (f64, f64) {
+    let quarters = turns * 4.0;
+    let quadrant = FloatingPoint::floor(quarters);
+    let fraction = quarters - quadrant;
+
+    // Staying within an eighth of a turn keeps the series short and accurate.
+    let (cos, sin) = if fraction <= 0.5 {
+        super::cos_sin_kernel(fraction * FRAC_PI_2)
+    } else {
+        let (cos, sin) = super::cos_sin_kernel((1.0 - fraction) * FRAC_PI_2);
+        (sin, cos)
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    match quadrant as u8 & 3 {
+        0 => (cos, sin),
+        1 => (-sin, cos),
+        2 => (-cos, -sin),
+        _ => (sin, -cos),
+    }
+}
+
+/// A window function that tapers a block of samples towards its ends.
+///
+/// The windows are symmetric: the first and last coefficients are equal.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::fft::Window;
+///
+/// let mut samples = [1.0; 5];
+/// Window::Hann.apply(&mut samples);
+///
+/// assert_eq!(samples[0], 0.0);
+/// assert_eq!(samples[2], 1.0);
+/// assert!((samples[1] - 0.5).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Window {
+    /// The Hann window, `0.5 − 0.5·cos(2πn/(N−1))`, which falls to zero at both ends.
+    Hann,
+
+    /// The Hamming window, `0.54 − 0.46·cos(2πn/(N−1))`, which lowers the nearest side
+    /// lobe at the cost of not reaching zero.
+    Hamming,
+}
+
+impl Window {
+    /// Returns the `len` coefficients of the window.
+    #[must_use]
+    pub fn coefficients(self, len: usize) -> Vec<f64> {
+        let (a, b) = match self {
+            Self::Hann => (0.5, 0.5),
+            Self::Hamming => (0.54, 0.46),
+        };
+
+        if len <= 1 {
+            return alloc::vec![1.0; len];
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Lengths are far below 2^53.
+        let last = (len - 1) as f64;
+
+        (0..len)
+            .map(|n| {
+                #[allow(clippy::cast_precision_loss)]
+                let (cos, _) = cos_sin_turns(n as f64 / last);
+                a - b * cos
+            })
+            .collect()
+    }
+
+    /// Multiplies `samples` by the window of the same length.
+    pub fn apply(self, samples: &mut [f64]) {
+        multiply(samples, &self.coefficients(samples.len()));
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+fn multiply(samples: &mut [f64], coefficients: &[f64]) {
+    for (sample, coefficient) in samples.iter_mut().zip(coefficients) {
+        *sample *= coefficient;
+    }
+}
+
+#[cfg(feature = "nightly")]
+fn multiply(samples: &mut [f64], coefficients: &[f64]) {
+    use core::simd::f64x4;
+
+    let mut samples = samples.chunks_exact_mut(4);
+    let mut coefficients = coefficients.chunks_exact(4);
+
+    for (chunk, factors) in samples.by_ref().zip(coefficients.by_ref()) {
+        let product = f64x4::from_slice(chunk) * f64x4::from_slice(factors);
+        product.copy_to_slice(chunk);
+    }
+
+    for (sample, coefficient) in samples
+        .into_remainder()
+        .iter_mut()
+        .zip(coefficients.remainder())
+    {
+        *sample *= coefficient;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    fn naive_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+
+        (0..n)
+            .map(|k| {
+                input
+                    .iter()
+                    .enumerate()
+                    .fold(Complex::ZERO, |sum, (j, &x)| {
+                        sum + x * twiddle(k * j % n, n, false)
+                    })
+            })
+            .collect()
+    }
+
+    fn close(a: &[Complex], b: &[Complex]) -> bool {
+        a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (*x - *y).norm() < 1e-9)
+    }
+
+    #[test]
+    fn test_transforms_match_naive_dft() {
+        let result = check_property(0xFF7, 50, |rng| {
+            for bits in 0..7 {
+                let n = 1 << bits;
+                let real: Vec<f64> = (0..n).map(|_| rng.next_f64() - 0.5).collect();
+                let input: Vec<Complex> = real
+                    .iter()
+                    .map(|&re| Complex::new(re, rng.next_f64() - 0.5))
+                    .collect();
+                let expected = naive_dft(&input);
+
+                let mut output = input.clone();
+                fft(&mut output).map_err(|error| error.to_string())?;
+                if !close(&output, &expected) {
+                    return Err(alloc::format!("fft of {n}").into());
+                }
+
+                ifft(&mut output).map_err(|error| error.to_string())?;
+                if !close(&output, &input) {
+                    return Err(alloc::format!("ifft of {n}").into());
+                }
+
+                let spectrum = rfft(&real).map_err(|error| error.to_string())?;
+                let full = naive_dft(&real.iter().map(|&re| Complex::from(re)).collect::<Vec<_>>());
+                if !close(&spectrum, &full[..=n / 2]) {
+                    return Err(alloc::format!("rfft of {n}").into());
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn test_lengths_must_be_powers_of_two() {
+        let mut buffer = [Complex::ONE; 6];
+
+        assert_eq!(
+            fft(&mut buffer),
+            Err(FftError::LengthNotPowerOfTwo { len: 6 })
+        );
+        assert_eq!(buffer, [Complex::ONE; 6]);
+        assert_eq!(ifft(&mut []), Err(FftError::LengthNotPowerOfTwo { len: 0 }));
+        assert_eq!(
+            rfft(&[1.0; 3]),
+            Err(FftError::LengthNotPowerOfTwo { len: 3 })
+        );
+        assert_eq!(rfft(&[2.0]), Ok(alloc::vec![Complex::new(2.0, 0.0)]));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_windows() {
+        for turns in [0.0, 0.1, 0.25, 0.3, 0.5, 0.7, 0.75, 0.9, 1.0] {
+            let (cos, sin) = cos_sin_turns(turns);
+            assert!((cos * cos + sin * sin - 1.0).abs() < 1e-15);
+        }
+        assert_eq!(cos_sin_turns(0.5), (-1.0, 0.0));
+
+        let hamming = Window::Hamming.coefficients(9);
+        assert!((hamming[0] - 0.08).abs() < 1e-15);
+        assert_eq!(hamming[0], hamming[8]);
+        assert!((hamming[4] - 1.0).abs() < 1e-15);
+
+        let mut samples = [2.0; 7];
+        Window::Hann.apply(&mut samples);
+        let expected = Window::Hann.coefficients(7);
+        assert!(samples.iter().zip(&expected).all(|(s, c)| *s == 2.0 * c));
+
+        assert_eq!(Window::Hann.coefficients(1), [1.0]);
+        assert!(Window::Hamming.coefficients(0).is_empty());
+    }
+}
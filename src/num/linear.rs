@@ -0,0 +1,788 @@
+//! Dense linear algebra on small matrices of `f64`.
+//!
+//! [`Matrix`] is a heap-allocated, row-major matrix whose size is chosen at run time.
+//! [`Matrix::lu`] factors a square matrix with partial pivoting, which gives
+//! [`solve`], [`Matrix::determinant`] and [`Matrix::inverse`]; [`Matrix::qr`] factors a
+//! tall matrix with Householder reflections, which solves overdetermined systems in
+//! the least-squares sense for curve fitting and calibration.
+//!
+//! The algorithms are the textbook ones and take cubic time, which suits matrices of
+//! up to a few dozen rows.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::num::linear::{Matrix, solve};
+//!
+//! let a = Matrix::from_rows(&[[2.0, 1.0], [1.0, 3.0]]);
+//! let x = solve(&a, &[3.0, 5.0]).unwrap();
+//!
+//! assert!((x[0] - 0.8).abs() < 1e-12 && (x[1] - 1.4).abs() < 1e-12);
+//! assert!((a.determinant().unwrap() - 5.0).abs() < 1e-12);
+//! ```
+
+use alloc::{vec, vec::Vec};
+use core::ops::{Index, IndexMut};
+
+use crate::errors::LinearError;
+
+/// A matrix of `f64` stored in row-major order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    columns: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// Creates a `rows`x`columns` matrix of zeros.
+    #[must_use]
+    pub fn new(rows: usize, columns: usize) -> Self {
+        Self {
+            rows,
+            columns,
+            data: vec![0.0; rows * columns],
+        }
+    }
+
+    /// Creates the `n`x`n` identity matrix.
+    #[must_use]
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::new(n, n);
+
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+
+        matrix
+    }
+
+    /// Creates a matrix from its rows.
+    #[must_use]
+    pub fn from_rows<const N: usize>(rows: &[[f64; N]]) -> Self {
+        Self {
+            rows: rows.len(),
+            columns: N,
+            data: rows.as_flattened().to_vec(),
+        }
+    }
+
+    /// Creates a `rows`x`columns` matrix from its entries in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::DimensionMismatch`] if there are not `rows * columns`
+    /// entries.
+    pub fn from_vec(rows: usize, columns: usize, data: Vec<f64>) -> Result<Self, LinearError> {
+        if data.len() != rows * columns {
+            return Err(LinearError::DimensionMismatch {
+                expected: rows * columns,
+                found: data.len(),
+            });
+        }
+
+        Ok(Self {
+            rows,
+            columns,
+            data,
+        })
+    }
+
+    /// Returns the number of rows.
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[must_use]
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns `true` if the matrix has as many rows as columns.
+    #[must_use]
+    pub const fn is_square(&self) -> bool {
+        self.rows == self.columns
+    }
+
+    /// Returns the entry at `row` and `column`, or `None` if either is out of bounds.
+    #[must_use]
+    pub fn get(&self, row: usize, column: usize) -> Option<f64> {
+        if row < self.rows && column < self.columns {
+            Some(self.data[row * self.columns + column])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the entries of `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    #[must_use]
+    pub fn row(&self, row: usize) -> &[f64] {
+        assert!(
+            row < self.rows,
+            "row {row} out of bounds for {} rows",
+            self.rows
+        );
+        &self.data[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// Returns the entries in row-major order.
+    #[must_use]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// Returns the transpose.
+    #[must_use]
+    pub fn transpose(&self) -> Self {
+        let mut transpose = Self::new(self.columns, self.rows);
+
+        for row in 0..self.rows {
+            for column in 0..self.columns {
+                transpose[(column, row)] = self[(row, column)];
+            }
+        }
+
+        transpose
+    }
+
+    /// Returns the product `self * other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::DimensionMismatch`] if `other` does not have as many rows
+    /// as `self` has columns.
+    pub fn multiply(&self, other: &Self) -> Result<Self, LinearError> {
+        if other.rows != self.columns {
+            return Err(LinearError::DimensionMismatch {
+                expected: self.columns,
+                found: other.rows,
+            });
+        }
+
+        let mut product = Self::new(self.rows, other.columns);
+
+        for row in 0..self.rows {
+            for (k, &factor) in self.row(row).iter().enumerate() {
+                for column in 0..other.columns {
+                    product[(row, column)] += factor * other[(k, column)];
+                }
+            }
+        }
+
+        Ok(product)
+    }
+
+    /// Returns the product of the matrix and the column vector `vector`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::DimensionMismatch`] if `vector` does not have one entry
+    /// per column.
+    pub fn multiply_vector(&self, vector: &[f64]) -> Result<Vec<f64>, LinearError> {
+        if vector.len() != self.columns {
+            return Err(LinearError::DimensionMismatch {
+                expected: self.columns,
+                found: vector.len(),
+            });
+        }
+
+        Ok((0..self.rows)
+            .map(|row| dot(self.row(row), vector))
+            .collect())
+    }
+
+    /// Returns the LU decomposition of the matrix with partial pivoting.
+    ///
+    /// The decomposition of a singular matrix succeeds; solving with it does not.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::NotSquare`] if the matrix is not square.
+    pub fn lu(&self) -> Result<Lu, LinearError> {
+        Lu::new(self)
+    }
+
+    /// Returns the determinant, which is zero for a singular matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::NotSquare`] if the matrix is not square.
+    pub fn determinant(&self) -> Result<f64, LinearError> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// Returns the inverse.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::NotSquare`] if the matrix is not square, or
+    /// [`LinearError::Singular`] if it has no inverse.
+    pub fn inverse(&self) -> Result<Self, LinearError> {
+        self.lu()?.inverse()
+    }
+
+    /// Returns the QR decomposition of the matrix by Householder reflections.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::Underdetermined`] if the matrix has fewer rows than
+    /// columns.
+    pub fn qr(&self) -> Result<Qr, LinearError> {
+        Qr::new(self)
+    }
+
+    /// Returns the largest absolute value of the entries, which scales the tolerance
+    /// for singularity.
+    fn max_magnitude(&self) -> f64 {
+        self.data
+            .iter()
+            .fold(0.0, |max, entry| entry.abs().max(max))
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    /// Returns the entry at `(row, column)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row or column is out of bounds.
+    fn index(&self, (row, column): (usize, usize)) -> &f64 {
+        assert!(
+            row < self.rows && column < self.columns,
+            "index ({row}, {column}) out of bounds for a {}x{} matrix",
+            self.rows,
+            self.columns
+        );
+        &self.data[row * self.columns + column]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut f64 {
+        assert!(
+            row < self.rows && column < self.columns,
+            "index ({row}, {column}) out of bounds for a {}x{} matrix",
+            self.rows,
+            self.columns
+        );
+        &mut self.data[row * self.columns + column]
+    }
+}
+
+/// Solves the square system `a * x = b` for `x`.
+///
+/// # Errors
+///
+/// Returns [`LinearError::NotSquare`] if `a` is not square,
+/// [`LinearError::DimensionMismatch`] if `b` does not have one entry per row, or
+/// [`LinearError::Singular`] if the system has no unique solution.
+pub fn solve(a: &Matrix, b: &[f64]) -> Result<Vec<f64>, LinearError> {
+    a.lu()?.solve(b)
+}
+
+/// The LU decomposition `P * A = L * U` of a square matrix, where `P` permutes rows,
+/// `L` is unit lower triangular and `U` is upper triangular.
+///
+/// Created by [`Matrix::lu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu {
+    /// `L` below the diagonal and `U` on and above it.
+    factors: Matrix,
+    permutation: Vec<usize>,
+    odd: bool,
+    tolerance: f64,
+}
+
+impl Lu {
+    fn new(matrix: &Matrix) -> Result<Self, LinearError> {
+        if !matrix.is_square() {
+            return Err(LinearError::NotSquare {
+                rows: matrix.rows,
+                columns: matrix.columns,
+            });
+        }
+
+        let n = matrix.rows;
+        let mut factors = matrix.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut odd = false;
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&a, &b| factors[(a, k)].abs().total_cmp(&factors[(b, k)].abs()))
+                .unwrap_or(k);
+
+            if pivot != k {
+                for column in 0..n {
+                    factors.data.swap(k * n + column, pivot * n + column);
+                }
+                permutation.swap(k, pivot);
+                odd = !odd;
+            }
+
+            let diagonal = factors[(k, k)];
+
+            // A zero column leaves nothing to eliminate; the zero pivot marks the matrix
+            // singular.
+            if diagonal == 0.0 {
+                continue;
+            }
+
+            for row in k + 1..n {
+                let multiplier = factors[(row, k)] / diagonal;
+                factors[(row, k)] = multiplier;
+
+                for column in k + 1..n {
+                    factors[(row, column)] -= multiplier * factors[(k, column)];
+                }
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Sizes are far below 2^53.
+        let tolerance = n as f64 * f64::EPSILON * matrix.max_magnitude();
+
+        Ok(Self {
+            factors,
+            permutation,
+            odd,
+            tolerance,
+        })
+    }
+
+    /// Returns the size of the decomposed matrix.
+    #[must_use]
+    pub const fn size(&self) -> usize {
+        self.factors.rows
+    }
+
+    /// Returns the unit lower triangular factor `L`.
+    #[must_use]
+    pub fn lower(&self) -> Matrix {
+        let mut lower = Matrix::identity(self.size());
+
+        for row in 0..self.size() {
+            for column in 0..row {
+                lower[(row, column)] = self.factors[(row, column)];
+            }
+        }
+
+        lower
+    }
+
+    /// Returns the upper triangular factor `U`.
+    #[must_use]
+    pub fn upper(&self) -> Matrix {
+        let mut upper = Matrix::new(self.size(), self.size());
+
+        for row in 0..self.size() {
+            for column in row..self.size() {
+                upper[(row, column)] = self.factors[(row, column)];
+            }
+        }
+
+        upper
+    }
+
+    /// Returns the row permutation: row `i` of `P * A` is row `permutation()[i]` of `A`.
+    #[must_use]
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns `true` if a pivot is too small, relative to the largest entry of the
+    /// matrix, for the system to be solved reliably.
+    #[must_use]
+    pub fn is_singular(&self) -> bool {
+        (0..self.size()).any(|k| self.factors[(k, k)].abs() <= self.tolerance)
+    }
+
+    /// Returns the determinant of the decomposed matrix.
+    #[must_use]
+    pub fn determinant(&self) -> f64 {
+        let product = (0..self.size()).fold(1.0, |product, k| product * self.factors[(k, k)]);
+
+        if self.odd { -product } else { product }
+    }
+
+    /// Solves `A * x = b` for `x`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::DimensionMismatch`] if `b` does not have one entry per
+    /// row, or [`LinearError::Singular`] if the matrix is singular.
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, LinearError> {
+        if b.len() != self.size() {
+            return Err(LinearError::DimensionMismatch {
+                expected: self.size(),
+                found: b.len(),
+            });
+        }
+
+        if self.is_singular() {
+            return Err(LinearError::Singular);
+        }
+
+        let mut x: Vec<f64> = self.permutation.iter().map(|&row| b[row]).collect();
+        self.substitute(&mut x);
+
+        Ok(x)
+    }
+
+    /// Returns the inverse of the decomposed matrix.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::Singular`] if the matrix is singular.
+    pub fn inverse(&self) -> Result<Matrix, LinearError> {
+        if self.is_singular() {
+            return Err(LinearError::Singular);
+        }
+
+        let n = self.size();
+        let mut inverse = Matrix::new(n, n);
+        let mut column = vec![0.0; n];
+
+        for j in 0..n {
+            for (entry, &row) in column.iter_mut().zip(&self.permutation) {
+                *entry = if row == j { 1.0 } else { 0.0 };
+            }
+            self.substitute(&mut column);
+
+            for (i, &entry) in column.iter().enumerate() {
+                inverse[(i, j)] = entry;
+            }
+        }
+
+        Ok(inverse)
+    }
+
+    /// Overwrites the permuted right-hand side `x` with the solution, by forward
+    /// substitution through `L` and back substitution through `U`.
+    fn substitute(&self, x: &mut [f64]) {
+        let n = self.size();
+
+        for row in 0..n {
+            let sum = dot(&self.factors.row(row)[..row], &x[..row]);
+            x[row] -= sum;
+        }
+
+        for row in (0..n).rev() {
+            let sum = dot(&self.factors.row(row)[row + 1..], &x[row + 1..]);
+            x[row] = (x[row] - sum) / self.factors[(row, row)];
+        }
+    }
+}
+
+/// The thin QR decomposition `A = Q * R` of an `m`x`n` matrix with `m >= n`, where `Q`
+/// has orthonormal columns and `R` is upper triangular.
+///
+/// Created by [`Matrix::qr`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr {
+    /// The unit Householder vectors, the `k`th of which acts on rows `k..m`.
+    reflectors: Vec<Vec<f64>>,
+    r: Matrix,
+    rows: usize,
+    tolerance: f64,
+}
+
+impl Qr {
+    fn new(matrix: &Matrix) -> Result<Self, LinearError> {
+        let (m, n) = (matrix.rows, matrix.columns);
+
+        if m < n {
+            return Err(LinearError::Underdetermined {
+                rows: m,
+                columns: n,
+            });
+        }
+
+        let mut work = matrix.clone();
+        let mut reflectors = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let mut v: Vec<f64> = (k..m).map(|row| work[(row, k)]).collect();
+            let norm = super::sqrt(dot(&v, &v));
+
+            // Reflecting onto the axis away from the first entry avoids cancellation.
+            let alpha = if v[0] >= 0.0 { -norm } else { norm };
+            v[0] -= alpha;
+            let length = super::sqrt(dot(&v, &v));
+
+            if length == 0.0 {
+                v.fill(0.0);
+            } else {
+                for entry in &mut v {
+                    *entry /= length;
+                }
+            }
+
+            for column in k..n {
+                let projection: f64 = (k..m).map(|row| v[row - k] * work[(row, column)]).sum();
+
+                for row in k..m {
+                    work[(row, column)] -= 2.0 * v[row - k] * projection;
+                }
+            }
+
+            reflectors.push(v);
+        }
+
+        let mut r = Matrix::new(n, n);
+
+        for row in 0..n {
+            for column in row..n {
+                r[(row, column)] = work[(row, column)];
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Sizes are far below 2^53.
+        let tolerance = m as f64 * f64::EPSILON * matrix.max_magnitude();
+
+        Ok(Self {
+            reflectors,
+            r,
+            rows: m,
+            tolerance,
+        })
+    }
+
+    /// Returns the `m`x`n` factor `Q`, whose columns are orthonormal.
+    #[must_use]
+    pub fn q(&self) -> Matrix {
+        let n = self.reflectors.len();
+        let mut q = Matrix::new(self.rows, n);
+
+        for column in 0..n {
+            let mut e = vec![0.0; self.rows];
+            e[column] = 1.0;
+
+            for k in (0..n).rev() {
+                self.reflect(k, &mut e);
+            }
+
+            for (row, &entry) in e.iter().enumerate() {
+                q[(row, column)] = entry;
+            }
+        }
+
+        q
+    }
+
+    /// Returns the `n`x`n` upper triangular factor `R`.
+    #[must_use]
+    pub const fn r(&self) -> &Matrix {
+        &self.r
+    }
+
+    /// Returns the `x` that minimizes the length of `A * x - b`, which solves the
+    /// system exactly if `A` is square.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LinearError::DimensionMismatch`] if `b` does not have one entry per
+    /// row, or [`LinearError::Singular`] if the columns of `A` are linearly dependent.
+    pub fn least_squares(&self, b: &[f64]) -> Result<Vec<f64>, LinearError> {
+        if b.len() != self.rows {
+            return Err(LinearError::DimensionMismatch {
+                expected: self.rows,
+                found: b.len(),
+            });
+        }
+
+        let n = self.reflectors.len();
+
+        if (0..n).any(|k| self.r[(k, k)].abs() <= self.tolerance) {
+            return Err(LinearError::Singular);
+        }
+
+        let mut y = b.to_vec();
+
+        for k in 0..n {
+            self.reflect(k, &mut y);
+        }
+
+        y.truncate(n);
+
+        for row in (0..n).rev() {
+            let sum = dot(&self.r.row(row)[row + 1..], &y[row + 1..]);
+            y[row] = (y[row] - sum) / self.r[(row, row)];
+        }
+
+        Ok(y)
+    }
+
+    /// Applies the `k`th Householder reflection to `x`.
+    fn reflect(&self, k: usize, x: &mut [f64]) {
+        let v = &self.reflectors[k];
+        let projection = dot(v, &x[k..]);
+
+        for (entry, &component) in x[k..].iter_mut().zip(v) {
+            *entry -= 2.0 * component * projection;
+        }
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    fn assert_close(a: &[f64], b: &[f64]) {
+        assert_eq!(a.len(), b.len());
+        assert!(
+            a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-9),
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn test_lu_solves_and_inverts() {
+        let a = Matrix::from_rows(&[[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [3.0, 0.0, 1.0]]);
+        let lu = a.lu().expect("the matrix is square");
+
+        let mut pa = Matrix::new(3, 3);
+        for (row, &source) in lu.permutation().iter().enumerate() {
+            for column in 0..3 {
+                pa[(row, column)] = a[(source, column)];
+            }
+        }
+        assert_close(
+            lu.lower()
+                .multiply(&lu.upper())
+                .expect("the factors are square")
+                .as_slice(),
+            pa.as_slice(),
+        );
+
+        assert!((lu.determinant() - -5.0).abs() < 1e-12);
+        assert_close(
+            &solve(&a, &[5.0, 3.0, 6.0]).expect("the matrix is invertible"),
+            &[1.4, 1.6, 1.8],
+        );
+
+        let inverse = a.inverse().expect("the matrix is invertible");
+        assert_close(
+            a.multiply(&inverse).expect("the sizes agree").as_slice(),
+            Matrix::identity(3).as_slice(),
+        );
+    }
+
+    #[test]
+    fn test_random_systems() {
+        let result = check_property(0x2003, 100, |rng| {
+            #[allow(clippy::cast_possible_truncation)] // The size is below 7.
+            let n = rng.next_bounded(6) as usize + 1;
+            let data = (0..n * n).map(|_| rng.next_f64() * 2.0 - 1.0).collect();
+            let a = Matrix::from_vec(n, n, data).map_err(|error| format!("{error}"))?;
+            let b: Vec<f64> = (0..n).map(|_| rng.next_f64()).collect();
+
+            let x = solve(&a, &b).map_err(|error| format!("{error}"))?;
+            let residual = a.multiply_vector(&x).map_err(|error| format!("{error}"))?;
+            let qr_x = a
+                .qr()
+                .and_then(|qr| qr.least_squares(&b))
+                .map_err(|error| format!("{error}"))?;
+
+            if residual.iter().zip(&b).any(|(r, b)| (r - b).abs() > 1e-8) {
+                return Err(format!("residual {residual:?} for {b:?}").into());
+            }
+
+            if x.iter().zip(&qr_x).any(|(x, y)| (x - y).abs() > 1e-6) {
+                return Err(format!("LU {x:?} and QR {qr_x:?} disagree").into());
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn test_qr_fits_a_line() {
+        let a = Matrix::from_rows(&[[1.0, 0.0], [1.0, 1.0], [1.0, 2.0], [1.0, 3.0]]);
+        let qr = a.qr().expect("the matrix is tall");
+
+        let q = qr.q();
+        assert_close(
+            q.transpose()
+                .multiply(&q)
+                .expect("the sizes agree")
+                .as_slice(),
+            Matrix::identity(2).as_slice(),
+        );
+        assert_close(
+            q.multiply(qr.r()).expect("the sizes agree").as_slice(),
+            a.as_slice(),
+        );
+
+        // y = 1 + 2x with symmetric noise.
+        let fit = qr
+            .least_squares(&[1.1, 2.9, 5.1, 6.9])
+            .expect("the columns are independent");
+        assert_close(&fit, &[1.06, 1.96]);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_errors() {
+        let singular = Matrix::from_rows(&[[1.0, 2.0], [2.0, 4.0]]);
+        assert_eq!(singular.determinant(), Ok(0.0));
+        assert_eq!(singular.inverse(), Err(LinearError::Singular));
+        assert_eq!(solve(&singular, &[1.0, 2.0]), Err(LinearError::Singular));
+        assert_eq!(
+            singular
+                .qr()
+                .expect("the matrix is square")
+                .least_squares(&[1.0, 2.0]),
+            Err(LinearError::Singular)
+        );
+
+        let wide = Matrix::new(2, 3);
+        assert_eq!(
+            wide.lu(),
+            Err(LinearError::NotSquare {
+                rows: 2,
+                columns: 3
+            })
+        );
+        assert_eq!(
+            wide.qr(),
+            Err(LinearError::Underdetermined {
+                rows: 2,
+                columns: 3
+            })
+        );
+        assert_eq!(
+            solve(&Matrix::identity(2), &[1.0]),
+            Err(LinearError::DimensionMismatch {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Matrix::from_vec(2, 2, vec![1.0]),
+            Err(LinearError::DimensionMismatch {
+                expected: 4,
+                found: 1
+            })
+        );
+        assert_eq!(Matrix::new(0, 0).determinant(), Ok(1.0));
+        assert_eq!(Matrix::identity(2).get(1, 1), Some(1.0));
+        assert_eq!(Matrix::identity(2).get(2, 0), None);
+    }
+}
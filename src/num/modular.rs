@@ -0,0 +1,162 @@
+//! Modular arithmetic on unsigned integers.
+//!
+//! These are the number-theory helpers that ciphers and hash functions build on. Products
+//! are widened to `u128` before they are reduced, so no intermediate result overflows for
+//! any modulus that fits in a `u64`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::num::modular::{gcd, mod_inverse, mod_pow};
+//!
+//! assert_eq!(gcd(84, 36), 12);
+//! assert_eq!(mod_inverse(7, 26), Some(15));
+//! assert_eq!(mod_inverse(13, 26), None);
+//! assert_eq!(mod_pow(4, 13, 497), 445);
+//! ```
+
+/// Returns the greatest common divisor of `a` and `b`, which is zero only if both are.
+#[must_use]
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`, or `None` if it does not fit in a
+/// `u64`. The least common multiple with zero is zero.
+#[must_use]
+pub const fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Returns `(g, x, y)` such that `g` is the greatest common divisor of `a` and `b` and
+/// `a * x + b * y == g`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::modular::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46);
+///
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// ```
+#[must_use]
+pub const fn extended_gcd(a: u64, b: u64) -> (u64, i128, i128) {
+    let (mut previous, mut remainder) = (a as i128, b as i128);
+    let (mut previous_x, mut coefficient_x) = (1, 0);
+    let (mut previous_y, mut coefficient_y) = (0, 1);
+
+    while remainder != 0 {
+        let quotient = previous / remainder;
+        (previous, remainder) = (remainder, previous - quotient * remainder);
+        (previous_x, coefficient_x) = (coefficient_x, previous_x - quotient * coefficient_x);
+        (previous_y, coefficient_y) = (coefficient_y, previous_y - quotient * coefficient_y);
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    // The remainders start non-negative and at most `u64::MAX`, and only shrink.
+    (previous as u64, previous_x, previous_y)
+}
+
+/// Returns the multiplicative inverse of `value` modulo `modulus`.
+///
+/// The inverse is the number `x` below `modulus` for which `value * x % modulus` is
+/// `1 % modulus`. Returns `None` if `value` and `modulus` share a factor, and for a zero
+/// modulus.
+#[must_use]
+pub const fn mod_inverse(value: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(value % modulus, modulus);
+
+    if g != 1 {
+        return None;
+    }
+
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    // The Euclidean remainder lies in `0..modulus`.
+    let inverse = x.rem_euclid(modulus as i128) as u64;
+    Some(inverse)
+}
+
+/// Returns `a * b % modulus` without overflowing.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+#[must_use]
+pub const fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    #[allow(clippy::cast_possible_truncation)] // The remainder is below `modulus`.
+    let product = (a as u128 * b as u128 % modulus as u128) as u64;
+    product
+}
+
+/// Returns `base` raised to `exponent`, modulo `modulus`, by repeated squaring.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+#[must_use]
+pub const fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_and_lcm() {
+        assert_eq!(gcd(0, 0), 0);
+        assert_eq!(gcd(0, 9), 9);
+        assert_eq!(gcd(17, 5), 1);
+        assert_eq!(lcm(4, 6), Some(12));
+        assert_eq!(lcm(0, 6), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn test_mod_inverse() {
+        for value in 1..26 {
+            match mod_inverse(value, 26) {
+                Some(inverse) => assert_eq!(value * inverse % 26, 1),
+                None => assert_ne!(gcd(value, 26), 1),
+            }
+        }
+
+        assert_eq!(mod_inverse(u64::MAX - 1, u64::MAX), Some(u64::MAX - 1));
+        assert_eq!(mod_inverse(3, 1), Some(0));
+        assert_eq!(mod_inverse(3, 0), None);
+    }
+
+    #[test]
+    fn test_mod_pow() {
+        assert_eq!(mod_pow(2, 64, u64::MAX), 1);
+        assert_eq!(mod_pow(0, 0, 7), 1);
+        assert_eq!(mod_pow(5, 3, 1), 0);
+        assert_eq!(mod_mul(u64::MAX, u64::MAX, 10), 5);
+    }
+}
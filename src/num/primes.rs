@@ -0,0 +1,463 @@
+//! Prime numbers: sieving, testing and factorization.
+//!
+//! [`Primes`] enumerates primes in order with a segmented Sieve of Eratosthenes, so it
+//! uses memory proportional to the square root of the largest prime reached rather than
+//! to the prime itself; past about 2.8 × 10^14 it tests each candidate instead.
+//! [`is_prime`] is a deterministic Miller–Rabin test, and [`factorize`] combines trial
+//! division with Pollard's rho method, so every `u64` factors in well under a
+//! millisecond.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::num::primes::{Primes, divisors, factorize, totient};
+//!
+//! let first: Vec<u64> = Primes::new().take(6).collect();
+//! assert_eq!(first, [2, 3, 5, 7, 11, 13]);
+//!
+//! assert_eq!(factorize(360), [(2, 3), (3, 2), (5, 1)]);
+//! assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+//! assert_eq!(totient(36), 12);
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+use super::modular::{gcd, mod_mul, mod_pow};
+
+/// The number of candidates sieved at a time.
+const SEGMENT: u64 = 1 << 15;
+
+/// Segments whose square root exceeds this are tested number by number, which keeps the
+/// table of sieving primes to a few megabytes.
+const SIEVE_ROOT_LIMIT: u64 = 1 << 24;
+
+/// The primes below this bound are found by trial division before Pollard's rho.
+const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+/// An endless iterator over the primes in increasing order, up to the largest `u64`
+/// prime.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::primes::Primes;
+///
+/// let near_a_million: Vec<u64> = Primes::starting_at(1_000_000).take(3).collect();
+/// assert_eq!(near_a_million, [1_000_003, 1_000_033, 1_000_037]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Primes {
+    /// The primes up to `base_limit`, which strike out composites in each segment.
+    base: Vec<u64>,
+    base_limit: u64,
+    /// The first number of the current segment, or `None` once the range is exhausted.
+    low: Option<u64>,
+    composite: Vec<bool>,
+    position: usize,
+}
+
+impl Primes {
+    /// Creates an iterator starting at two.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::starting_at(2)
+    }
+
+    /// Creates an iterator over the primes greater than or equal to `start`.
+    #[must_use]
+    pub const fn starting_at(start: u64) -> Self {
+        Self {
+            base: Vec::new(),
+            base_limit: 1,
+            low: Some(if start < 2 { 2 } else { start }),
+            composite: Vec::new(),
+            position: 0,
+        }
+    }
+
+    /// Sieves the segment starting at `low`.
+    fn sieve(&mut self, low: u64) {
+        let high = low.saturating_add(SEGMENT - 1);
+        let root = high.isqrt();
+
+        self.composite.clear();
+        self.position = 0;
+
+        if root > SIEVE_ROOT_LIMIT {
+            self.composite
+                .extend((low..=high).map(|candidate| !is_prime(candidate)));
+            return;
+        }
+
+        if root > self.base_limit {
+            self.base_limit = root.max(self.base_limit * 2).min(SIEVE_ROOT_LIMIT);
+            self.base = small_primes(self.base_limit);
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // A segment is far below `usize::MAX`.
+        let length = (high - low + 1) as usize;
+        self.composite.resize(length, false);
+
+        for &prime in self.base.iter().take_while(|&&prime| prime <= root) {
+            let first = (prime * prime).max(low.div_ceil(prime) * prime);
+            let mut multiple = first;
+
+            while multiple <= high {
+                #[allow(clippy::cast_possible_truncation)]
+                let index = (multiple - low) as usize;
+                self.composite[index] = true;
+
+                let Some(next) = multiple.checked_add(prime) else {
+                    break;
+                };
+                multiple = next;
+            }
+        }
+    }
+}
+
+impl Default for Primes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Primes {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            let low = self.low?;
+
+            if self.composite.is_empty() {
+                self.sieve(low);
+            }
+
+            if let Some(offset) = self.composite[self.position..]
+                .iter()
+                .position(|&composite| !composite)
+            {
+                self.position += offset + 1;
+                return Some(low + (self.position - 1) as u64);
+            }
+
+            self.low = low.checked_add(self.composite.len() as u64);
+            self.composite.clear();
+        }
+    }
+}
+
+/// Returns the primes up to and including `limit` with a plain sieve.
+fn small_primes(limit: u64) -> Vec<u64> {
+    #[allow(clippy::cast_possible_truncation)] // Only called with small limits.
+    let limit = limit as usize;
+    let mut composite = vec![false; limit + 1];
+    let mut primes = Vec::new();
+
+    for candidate in 2..=limit {
+        if composite[candidate] {
+            continue;
+        }
+
+        primes.push(candidate as u64);
+
+        for multiple in (candidate * candidate..=limit).step_by(candidate) {
+            composite[multiple] = true;
+        }
+    }
+
+    primes
+}
+
+/// Returns `true` if `n` is prime.
+///
+/// The Miller–Rabin bases used are known to give the right answer for every `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::primes::is_prime;
+///
+/// assert!(is_prime(2));
+/// assert!(!is_prime(1));
+/// assert!(is_prime(18_446_744_073_709_551_557));
+/// assert!(!is_prime(3_215_031_751));
+/// ```
+#[must_use]
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+
+    if let Some(&base) = BASES.iter().find(|&&base| n.is_multiple_of(base)) {
+        return n == base;
+    }
+
+    let shift = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> shift;
+
+    BASES.iter().all(|&base| {
+        let mut x = mod_pow(base, odd, n);
+
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        for _ in 1..shift {
+            x = mod_mul(x, x, n);
+
+            if x == n - 1 {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Returns the smallest prime greater than `n`, or `None` if it does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::primes::next_prime;
+///
+/// assert_eq!(next_prime(0), Some(2));
+/// assert_eq!(next_prime(13), Some(17));
+/// assert_eq!(next_prime(u64::MAX - 58), None);
+/// ```
+#[must_use]
+pub fn next_prime(n: u64) -> Option<u64> {
+    let mut candidate = n.checked_add(1)?;
+
+    while !is_prime(candidate) {
+        candidate = candidate.checked_add(1)?;
+    }
+
+    Some(candidate)
+}
+
+/// Returns the largest prime less than `n`, or `None` if `n` is two or less.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::primes::prev_prime;
+///
+/// assert_eq!(prev_prime(3), Some(2));
+/// assert_eq!(prev_prime(100), Some(97));
+/// assert_eq!(prev_prime(2), None);
+/// ```
+#[must_use]
+pub fn prev_prime(n: u64) -> Option<u64> {
+    (2..n).rev().find(|&candidate| is_prime(candidate))
+}
+
+/// Returns the prime factorization of `n` as `(prime, exponent)` pairs in increasing
+/// order of prime.
+///
+/// Zero and one have no prime factors, so both give an empty list.
+#[must_use]
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+
+    if n == 0 {
+        return factors;
+    }
+
+    for prime in small_primes(TRIAL_DIVISION_BOUND) {
+        if prime * prime > n {
+            break;
+        }
+
+        let mut exponent = 0;
+
+        while n.is_multiple_of(prime) {
+            n /= prime;
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((prime, exponent));
+        }
+    }
+
+    let mut pending = vec![n];
+    let mut large = Vec::new();
+
+    while let Some(m) = pending.pop() {
+        if m == 1 {
+            continue;
+        }
+
+        if is_prime(m) {
+            large.push(m);
+        } else {
+            let divisor = pollard_rho(m);
+            pending.push(divisor);
+            pending.push(m / divisor);
+        }
+    }
+
+    large.sort_unstable();
+
+    for prime in large {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == prime => *exponent += 1,
+            _ => factors.push((prime, 1)),
+        }
+    }
+
+    factors
+}
+
+/// Returns a non-trivial divisor of the odd composite `n` with Brent's variant of
+/// Pollard's rho method.
+fn pollard_rho(n: u64) -> u64 {
+    // Adds `c` modulo `n` without overflowing, since `c` is far below `n`.
+    let step = |x: u64, c: u64| {
+        let square = mod_mul(x, x, n);
+        if square >= n - c {
+            square - (n - c)
+        } else {
+            square + c
+        }
+    };
+
+    // A walk can fail by cycling without finding a factor; another constant then helps.
+    (1..n)
+        .find_map(|c| {
+            let (mut x, mut y) = (2, 2);
+            let mut divisor = 1;
+
+            while divisor == 1 {
+                x = step(x, c);
+                y = step(step(y, c), c);
+                divisor = gcd(x.abs_diff(y), n);
+            }
+
+            (divisor != n).then_some(divisor)
+        })
+        .unwrap_or(n)
+}
+
+/// Returns every positive divisor of `n` in increasing order; none for zero.
+#[must_use]
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut divisors = vec![1];
+
+    for (prime, exponent) in factorize(n) {
+        let existing = divisors.len();
+        let mut power = 1;
+
+        for _ in 0..exponent {
+            power *= prime;
+
+            for index in 0..existing {
+                divisors.push(divisors[index] * power);
+            }
+        }
+    }
+
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Returns Euler's totient of `n`: how many numbers in `1..=n` share no factor with it.
+///
+/// # Examples
+///
+/// ```
+/// use libx::num::primes::totient;
+///
+/// assert_eq!(totient(1), 1);
+/// assert_eq!(totient(97), 96);
+/// assert_eq!(totient(0), 0);
+/// ```
+#[must_use]
+pub fn totient(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .fold(n, |result, (prime, _)| result / prime * (prime - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sieve_matches_primality_test() {
+        let sieved: Vec<u64> = Primes::new().take_while(|&p| p < 200_000).collect();
+        let tested: Vec<u64> = (0..200_000).filter(|&n| is_prime(n)).collect();
+        assert_eq!(sieved, tested);
+
+        let last: Vec<u64> = Primes::starting_at(u64::MAX - 100).collect();
+        assert_eq!(
+            last,
+            [
+                18_446_744_073_709_551_521,
+                18_446_744_073_709_551_533,
+                18_446_744_073_709_551_557
+            ]
+        );
+        assert_eq!(Primes::starting_at(0).next(), Some(2));
+    }
+
+    #[test]
+    fn test_factorize_round_trips() {
+        let cases = [
+            1,
+            2,
+            1_024,
+            600_851_475_143,
+            18_446_744_073_709_551_557,
+            4_294_967_291 * 4_294_967_279,
+            1_000_003 * 1_000_003 * 17,
+            u64::MAX,
+        ];
+
+        for n in cases.into_iter().chain(1..2_000) {
+            let factors = factorize(n);
+            let product: u64 = factors.iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, n, "{factors:?}");
+            assert!(factors.iter().all(|&(p, _)| is_prime(p)));
+            assert!(factors.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        }
+
+        assert_eq!(factorize(0), []);
+        assert_eq!(
+            factorize(4_294_967_291 * 4_294_967_279),
+            [(4_294_967_279, 1), (4_294_967_291, 1)]
+        );
+    }
+
+    #[test]
+    fn test_divisors_and_totient() {
+        assert_eq!(divisors(1), [1]);
+        assert_eq!(divisors(0), []);
+        assert_eq!(divisors(97), [1, 97]);
+
+        for n in 1..300 {
+            let expected: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(divisors(n), expected);
+
+            let coprime = (1..=n).filter(|&k| gcd(k, n) == 1).count() as u64;
+            assert_eq!(totient(n), coprime);
+        }
+
+        assert_eq!(
+            prev_prime(18_446_744_073_709_551_557),
+            Some(18_446_744_073_709_551_533)
+        );
+        assert_eq!(
+            next_prime(18_446_744_073_709_551_533),
+            Some(18_446_744_073_709_551_557)
+        );
+    }
+}
@@ -0,0 +1,455 @@
+//! Swift-style option sets backed by an integer bit mask.
+//!
+//! An option set is a set of named flags stored in a single [`FixedWidthInteger`], so
+//! membership tests, unions, and intersections are single bitwise operations. The
+//! [`bitmask!`](crate::bitmask) macro declares the flag type together with its
+//! constants, operators, and a `Debug` implementation that prints flag names.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{bitmask, option_set::OptionSet};
+//!
+//! bitmask! {
+//!     /// Permissions granted on a file.
+//!     pub struct Permissions: u8 {
+//!         const READ = 1 << 0;
+//!         const WRITE = 1 << 1;
+//!         const EXECUTE = 1 << 2;
+//!     }
+//! }
+//!
+//! let mut granted = Permissions::READ | Permissions::WRITE;
+//! assert!(granted.contains(Permissions::READ));
+//! assert!(!granted.contains(Permissions::EXECUTE));
+//!
+//! granted.insert(Permissions::EXECUTE);
+//! assert_eq!(granted, Permissions::all());
+//! assert_eq!(format!("{:?}", Permissions::READ | Permissions::EXECUTE), "Permissions(READ | EXECUTE)");
+//! ```
+
+use core::{
+    fmt,
+    ops::{BitAnd, BitOr, BitXor, Not},
+};
+
+use crate::num::traits::{AdditiveArithmetic, FixedWidthInteger};
+
+/// A set of flags represented by the bits of an integer raw value.
+///
+/// Implementors only provide the conversion to and from the raw value and the list of
+/// named flags; every set operation is derived from those. Types declared with
+/// [`bitmask!`](crate::bitmask) implement this trait automatically.
+pub trait OptionSet: Copy + Eq + Sized + 'static {
+    /// The integer type holding the flag bits.
+    type RawValue: FixedWidthInteger
+        + Copy
+        + BitAnd<Output = Self::RawValue>
+        + BitOr<Output = Self::RawValue>
+        + BitXor<Output = Self::RawValue>
+        + Not<Output = Self::RawValue>;
+
+    /// Every named flag together with its name, in declaration order.
+    const FLAGS: &'static [(&'static str, Self)];
+
+    /// Returns the raw bits of the set.
+    fn raw_value(&self) -> Self::RawValue;
+
+    /// Creates a set from raw bits, keeping bits that do not correspond to a named flag.
+    fn from_raw_value(raw_value: Self::RawValue) -> Self;
+
+    /// Returns the set containing no flags.
+    #[must_use]
+    fn empty() -> Self {
+        Self::from_raw_value(Self::RawValue::ZERO)
+    }
+
+    /// Returns the set containing every named flag.
+    #[must_use]
+    fn all() -> Self {
+        Self::FLAGS
+            .iter()
+            .fold(Self::empty(), |set, &(_, flag)| set.union(flag))
+    }
+
+    /// Returns `true` if the set contains no flags.
+    fn is_empty(&self) -> bool {
+        self.raw_value() == Self::RawValue::ZERO
+    }
+
+    /// Returns `true` if every flag in `member` is also in `self`.
+    fn contains(&self, member: Self) -> bool {
+        self.raw_value() & member.raw_value() == member.raw_value()
+    }
+
+    /// Returns `true` if `self` and `other` share at least one flag.
+    fn intersects(&self, other: Self) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    /// Returns `true` if every flag in `self` is also in `other`.
+    fn is_subset(&self, other: Self) -> bool {
+        other.contains(*self)
+    }
+
+    /// Returns `true` if every flag in `other` is also in `self`.
+    fn is_superset(&self, other: Self) -> bool {
+        self.contains(other)
+    }
+
+    /// Returns `true` if `self` and `other` share no flags.
+    fn is_disjoint(&self, other: Self) -> bool {
+        !self.intersects(other)
+    }
+
+    /// Returns the flags in either set.
+    #[must_use]
+    fn union(self, other: Self) -> Self {
+        Self::from_raw_value(self.raw_value() | other.raw_value())
+    }
+
+    /// Returns the flags in both sets.
+    #[must_use]
+    fn intersection(self, other: Self) -> Self {
+        Self::from_raw_value(self.raw_value() & other.raw_value())
+    }
+
+    /// Returns the flags in exactly one of the sets.
+    #[must_use]
+    fn symmetric_difference(self, other: Self) -> Self {
+        Self::from_raw_value(self.raw_value() ^ other.raw_value())
+    }
+
+    /// Returns the flags in `self` that are not in `other`.
+    #[must_use]
+    fn subtracting(self, other: Self) -> Self {
+        Self::from_raw_value(self.raw_value() & !other.raw_value())
+    }
+
+    /// Adds the flags in `member`, returning `true` if any of them were newly inserted.
+    fn insert(&mut self, member: Self) -> bool {
+        let inserted = !self.contains(member);
+        *self = self.union(member);
+        inserted
+    }
+
+    /// Removes the flags in `member`, returning `true` if any of them were present.
+    fn remove(&mut self, member: Self) -> bool {
+        let removed = self.intersects(member);
+        *self = self.subtracting(member);
+        removed
+    }
+
+    /// Adds the flags in `member` that are absent and removes those that are present.
+    fn toggle(&mut self, member: Self) {
+        *self = self.symmetric_difference(member);
+    }
+
+    /// Returns an iterator over the named flags contained in the set, with their names.
+    fn iter_names(&self) -> impl Iterator<Item = (&'static str, Self)> {
+        let set = *self;
+        Self::FLAGS
+            .iter()
+            .copied()
+            .filter(move |&(_, flag)| !flag.is_empty() && set.contains(flag))
+    }
+}
+
+/// Writes `set` as `Name(FLAG | FLAG | 0x..)`, the format used by
+/// [`bitmask!`](crate::bitmask) for `Debug`.
+///
+/// Bits that do not belong to any named flag are printed as a hexadecimal remainder.
+///
+/// # Errors
+///
+/// Returns an error if writing to `f` fails.
+pub fn fmt_flags<S>(set: &S, name: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result
+where
+    S: OptionSet,
+    S::RawValue: fmt::LowerHex,
+{
+    write!(f, "{name}(")?;
+
+    let mut remaining = *set;
+    let mut first = true;
+
+    for (flag_name, flag) in set.iter_names() {
+        if !remaining.intersects(flag) {
+            continue;
+        }
+
+        if !first {
+            f.write_str(" | ")?;
+        }
+
+        f.write_str(flag_name)?;
+        remaining.remove(flag);
+        first = false;
+    }
+
+    if !remaining.is_empty() {
+        if !first {
+            f.write_str(" | ")?;
+        }
+
+        write!(f, "{:#x}", remaining.raw_value())?;
+    } else if first {
+        f.write_str("empty")?;
+    }
+
+    f.write_str(")")
+}
+
+/// Declares an [`OptionSet`] type with named flag constants.
+///
+/// The generated type is a newtype over the raw integer and derives `Clone`, `Copy`,
+/// `PartialEq`, `Eq`, `Hash`, and `Default` (the empty set). It implements [`OptionSet`],
+/// the `|`, `&`, `^`, `-`, and `!` operators with their assigning forms, `FromIterator`,
+/// `Extend`, and a `Debug` that prints flag names. `!` complements within the named
+/// flags, so it never sets undeclared bits.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{bitmask, option_set::OptionSet};
+///
+/// bitmask! {
+///     pub struct Weekdays: u8 {
+///         const MONDAY = 1 << 0;
+///         const TUESDAY = 1 << 1;
+///         const WEDNESDAY = 1 << 2;
+///         const THURSDAY = 1 << 3;
+///         const FRIDAY = 1 << 4;
+///         const SATURDAY = 1 << 5;
+///         const SUNDAY = 1 << 6;
+///         const WEEKEND = Self::SATURDAY.raw_value() | Self::SUNDAY.raw_value();
+///     }
+/// }
+///
+/// let workdays = !Weekdays::WEEKEND;
+/// assert!(workdays.contains(Weekdays::FRIDAY));
+/// assert!(workdays.is_disjoint(Weekdays::SUNDAY));
+/// assert_eq!(Weekdays::from_raw_value(0b11).raw_value(), 3);
+/// ```
+#[macro_export]
+macro_rules! bitmask {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident: $raw:ty {
+            $(
+                $(#[$flag_attr:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $name($raw);
+
+        #[allow(dead_code)]
+        impl $name {
+            $(
+                $(#[$flag_attr])*
+                pub const $flag: Self = Self($value);
+            )*
+
+            /// Returns the raw bits of the set.
+            #[must_use]
+            pub const fn raw_value(self) -> $raw {
+                self.0
+            }
+
+            /// Creates a set from raw bits, keeping bits that do not correspond to a named
+            /// flag.
+            #[must_use]
+            pub const fn from_raw_value(raw_value: $raw) -> Self {
+                Self(raw_value)
+            }
+        }
+
+        impl $crate::option_set::OptionSet for $name {
+            type RawValue = $raw;
+
+            const FLAGS: &'static [(&'static str, Self)] = &[
+                $((stringify!($flag), Self::$flag),)*
+            ];
+
+            fn raw_value(&self) -> $raw {
+                self.0
+            }
+
+            fn from_raw_value(raw_value: $raw) -> Self {
+                Self(raw_value)
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::option_set::fmt_flags(self, stringify!($name), f)
+            }
+        }
+
+        impl ::core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                $crate::option_set::OptionSet::union(self, rhs)
+            }
+        }
+
+        impl ::core::ops::BitOrAssign for $name {
+            fn bitor_assign(&mut self, rhs: Self) {
+                *self = *self | rhs;
+            }
+        }
+
+        impl ::core::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                $crate::option_set::OptionSet::intersection(self, rhs)
+            }
+        }
+
+        impl ::core::ops::BitAndAssign for $name {
+            fn bitand_assign(&mut self, rhs: Self) {
+                *self = *self & rhs;
+            }
+        }
+
+        impl ::core::ops::BitXor for $name {
+            type Output = Self;
+
+            fn bitxor(self, rhs: Self) -> Self {
+                $crate::option_set::OptionSet::symmetric_difference(self, rhs)
+            }
+        }
+
+        impl ::core::ops::BitXorAssign for $name {
+            fn bitxor_assign(&mut self, rhs: Self) {
+                *self = *self ^ rhs;
+            }
+        }
+
+        impl ::core::ops::Sub for $name {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                $crate::option_set::OptionSet::subtracting(self, rhs)
+            }
+        }
+
+        impl ::core::ops::SubAssign for $name {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl ::core::ops::Not for $name {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                <Self as $crate::option_set::OptionSet>::all() - self
+            }
+        }
+
+        impl ::core::iter::FromIterator<$name> for $name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = Self>>(iter: I) -> Self {
+                iter.into_iter().fold(
+                    <Self as $crate::option_set::OptionSet>::empty(),
+                    |set, flag| set | flag,
+                )
+            }
+        }
+
+        impl ::core::iter::Extend<$name> for $name {
+            fn extend<I: ::core::iter::IntoIterator<Item = Self>>(&mut self, iter: I) {
+                for flag in iter {
+                    *self |= flag;
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec::Vec};
+
+    use super::*;
+
+    crate::bitmask! {
+        struct Units: u16 {
+            const BYTES = 1 << 0;
+            const KB = 1 << 1;
+            const MB = 1 << 2;
+            const GB = 1 << 3;
+            const DECIMAL = Self::KB.0 | Self::MB.0 | Self::GB.0;
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let small = Units::BYTES | Units::KB;
+        let large = Units::MB | Units::GB;
+
+        assert!(small.contains(Units::KB));
+        assert!(!small.contains(Units::KB | Units::MB));
+        assert!(small.is_disjoint(large));
+        assert_eq!(small.union(large), Units::all());
+        assert_eq!(Units::DECIMAL & small, Units::KB);
+        assert_eq!(Units::DECIMAL ^ Units::KB, large);
+        assert_eq!(Units::all() - large, small);
+        assert!(Units::KB.is_subset(Units::DECIMAL));
+        assert!(Units::DECIMAL.is_superset(Units::GB));
+    }
+
+    #[test]
+    fn test_insert_remove_toggle() {
+        let mut units = Units::empty();
+        assert!(units.is_empty());
+
+        assert!(units.insert(Units::MB));
+        assert!(!units.insert(Units::MB));
+        assert!(units.remove(Units::MB | Units::GB));
+        assert!(!units.remove(Units::MB));
+
+        units.toggle(Units::BYTES | Units::KB);
+        assert_eq!(units, Units::BYTES | Units::KB);
+        units.toggle(Units::KB);
+        assert_eq!(units, Units::BYTES);
+    }
+
+    #[test]
+    fn test_not_stays_within_named_flags() {
+        assert_eq!(!Units::BYTES, Units::DECIMAL);
+        assert_eq!(!Units::all(), Units::empty());
+    }
+
+    #[test]
+    fn test_iterators() {
+        let units: Units = [Units::KB, Units::GB].into_iter().collect();
+        assert_eq!(units, Units::KB | Units::GB);
+
+        let names: Vec<_> = units.iter_names().map(|(name, _)| name).collect();
+        assert_eq!(names, ["KB", "GB"]);
+
+        let mut extended = Units::BYTES;
+        extended.extend([Units::MB]);
+        assert_eq!(extended, Units::BYTES | Units::MB);
+    }
+
+    #[test]
+    fn test_debug_prints_names() {
+        assert_eq!(format!("{:?}", Units::empty()), "Units(empty)");
+        assert_eq!(
+            format!("{:?}", Units::BYTES | Units::GB),
+            "Units(BYTES | GB)"
+        );
+        assert_eq!(format!("{:?}", Units::DECIMAL), "Units(KB | MB | GB)");
+        assert_eq!(
+            format!("{:?}", Units::from_raw_value(0b1_0001)),
+            "Units(BYTES | 0x10)"
+        );
+    }
+}
@@ -0,0 +1,644 @@
+//! Packed binary layouts for protocol headers and hardware registers.
+//!
+//! [`bitfield!`](crate::bitfield) declares a newtype over an integer whose fields are bit
+//! ranges, and [`packed_struct!`](crate::packed_struct) declares a struct stored byte by
+//! byte with a byte order for each field. Both build on [`Packable`], which converts
+//! integers to and from bytes through the byte-order methods of [`FixedWidthInteger`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{bitfield, packed_struct};
+//!
+//! bitfield! {
+//!     /// The flags byte of the header.
+//!     pub struct Flags: u8 {
+//!         pub compressed, set_compressed: bool [0];
+//!         pub priority, set_priority: u8 [4..8];
+//!     }
+//! }
+//!
+//! packed_struct! {
+//!     #[derive(Debug, PartialEq)]
+//!     pub struct Header {
+//!         pub magic: [u8; 2] => Big,
+//!         pub length: u16 => Big,
+//!         pub flags: Flags => Big,
+//!     }
+//! }
+//!
+//! let header = Header::from_bytes(&[b'L', b'X', 0x01, 0x00, 0x31]).unwrap();
+//! assert_eq!(header.length, 256);
+//! assert!(header.flags.compressed());
+//! assert_eq!(header.flags.priority(), 3);
+//! assert_eq!(header.to_bytes(), [b'L', b'X', 0x01, 0x00, 0x31]);
+//! ```
+
+use crate::num::traits::FixedWidthInteger;
+
+/// The order of the bytes of a multi-byte integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endianness {
+    /// The most significant byte comes first, as in network protocols.
+    Big,
+
+    /// The least significant byte comes first.
+    Little,
+}
+
+impl Endianness {
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Self = Self::Big;
+
+    /// The byte order of the target platform.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Self = Self::Little;
+}
+
+/// A value with a fixed-size byte representation.
+///
+/// Integers use the given byte order, byte arrays are copied as they are, and the types
+/// declared by [`bitfield!`](crate::bitfield) and [`packed_struct!`](crate::packed_struct)
+/// implement it too, so they can be nested.
+pub trait Packable: Sized {
+    /// The number of bytes in the representation.
+    const SIZE: usize;
+
+    /// Writes the value into the first [`SIZE`](Self::SIZE) bytes of `buffer`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `buffer` is shorter than [`SIZE`](Self::SIZE).
+    fn pack(&self, endianness: Endianness, buffer: &mut [u8]);
+
+    /// Reads a value from the first [`SIZE`](Self::SIZE) bytes of `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bytes` is shorter than [`SIZE`](Self::SIZE).
+    fn unpack(endianness: Endianness, bytes: &[u8]) -> Self;
+}
+
+/// Converts a value to the byte order `endianness`, or back, since the conversion is its
+/// own inverse.
+fn convert<T: FixedWidthInteger>(value: &T, endianness: Endianness) -> T {
+    match endianness {
+        Endianness::Big => value.big_endian(),
+        Endianness::Little => value.little_endian(),
+    }
+}
+
+macro_rules! impl_packable {
+    ($($ty:ty),*) => {
+        $(
+            impl Packable for $ty {
+                const SIZE: usize = size_of::<$ty>();
+
+                fn pack(&self, endianness: Endianness, buffer: &mut [u8]) {
+                    buffer[..Self::SIZE].copy_from_slice(&convert(self, endianness).to_ne_bytes());
+                }
+
+                fn unpack(endianness: Endianness, bytes: &[u8]) -> Self {
+                    let mut raw = [0; size_of::<$ty>()];
+                    raw.copy_from_slice(&bytes[..Self::SIZE]);
+                    convert(&Self::from_ne_bytes(raw), endianness)
+                }
+            }
+        )*
+    };
+}
+
+impl_packable!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<const N: usize> Packable for [u8; N] {
+    const SIZE: usize = N;
+
+    fn pack(&self, _endianness: Endianness, buffer: &mut [u8]) {
+        buffer[..N].copy_from_slice(self);
+    }
+
+    fn unpack(_endianness: Endianness, bytes: &[u8]) -> Self {
+        let mut array = [0; N];
+        array.copy_from_slice(&bytes[..N]);
+        array
+    }
+}
+
+/// A value that can be stored in a range of bits of a [`bitfield!`](crate::bitfield).
+pub trait BitfieldValue: Copy {
+    /// The number of bits in the value.
+    const BITS: u32;
+
+    /// Returns the bits of the value; signed values are sign-extended.
+    fn into_field_bits(self) -> u128;
+
+    /// Creates a value from the low `width` bits of `bits`. Signed values treat bit
+    /// `width - 1` as the sign bit.
+    fn from_field_bits(bits: u128, width: u32) -> Self;
+}
+
+impl BitfieldValue for bool {
+    const BITS: u32 = 1;
+
+    fn into_field_bits(self) -> u128 {
+        u128::from(self)
+    }
+
+    fn from_field_bits(bits: u128, _width: u32) -> Self {
+        bits & 1 != 0
+    }
+}
+
+macro_rules! impl_bitfield_value {
+    (unsigned: $($unsigned:ty),*; signed: $($signed:ty),*) => {
+        $(
+            impl BitfieldValue for $unsigned {
+                const BITS: u32 = <$unsigned>::BITS;
+
+                fn into_field_bits(self) -> u128 {
+                    u128::from(self)
+                }
+
+                #[allow(clippy::cast_possible_truncation)] // Keeping the low bits is the point.
+                fn from_field_bits(bits: u128, width: u32) -> Self {
+                    (bits & low_bits_mask(width)) as Self
+                }
+            }
+        )*
+
+        $(
+            impl BitfieldValue for $signed {
+                const BITS: u32 = <$signed>::BITS;
+
+                #[allow(clippy::cast_sign_loss)] // Reinterprets the sign-extended bits.
+                fn into_field_bits(self) -> u128 {
+                    i128::from(self) as u128
+                }
+
+                #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+                fn from_field_bits(bits: u128, width: u32) -> Self {
+                    let shift = 128 - width.clamp(1, 128);
+                    (((bits << shift) as i128) >> shift) as Self
+                }
+            }
+        )*
+    };
+}
+
+impl_bitfield_value!(unsigned: u8, u16, u32, u64, u128; signed: i8, i16, i32, i64, i128);
+
+/// Returns a mask with the low `width` bits set.
+#[must_use]
+pub const fn low_bits_mask(width: u32) -> u128 {
+    if width >= 128 {
+        u128::MAX
+    } else {
+        (1 << width) - 1
+    }
+}
+
+/// Declares a newtype over an integer whose fields are ranges of its bits.
+///
+/// Each field names a getter and a setter, a type and a bit position: `[3]` for a single
+/// bit or `[4..8]` for bits 4 to 7, counted from the least significant bit. Field types
+/// can be `bool` or any primitive integer; signed fields are sign-extended, and setters
+/// keep only the bits that fit in the field. Ranges that do not fit in the raw integer
+/// fail to compile.
+///
+/// The generated type derives `Clone`, `Copy`, `PartialEq`, `Eq`, `Hash`, and `Default`,
+/// has the same `raw_value`/`from_raw_value` pair as [`bitmask!`](crate::bitmask), reads
+/// and writes bytes with `from_bytes`/`to_bytes`, implements [`Packable`] and has a
+/// `Debug` that prints each field.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{bitfield, packing::Endianness};
+///
+/// bitfield! {
+///     pub struct Control: u16 {
+///         pub enabled, set_enabled: bool [0];
+///         pub mode, set_mode: u8 [1..4];
+///         pub offset, set_offset: i8 [4..8];
+///         pub divider, set_divider: u16 [8..16];
+///     }
+/// }
+///
+/// let mut control = Control::default();
+/// control.set_enabled(true);
+/// control.set_mode(0b101);
+/// control.set_offset(-2);
+/// control.set_divider(0x1ff);
+///
+/// assert_eq!(control.raw_value(), 0xffeb);
+/// assert_eq!(control.offset(), -2);
+/// assert_eq!(control.divider(), 0xff);
+/// assert_eq!(control.to_bytes(Endianness::Big), [0xff, 0xeb]);
+/// ```
+#[macro_export]
+macro_rules! bitfield {
+    (@high $low:literal) => {
+        $low + 1
+    };
+    (@high $low:literal, $high:literal) => {
+        $high
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident: $raw:ty {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $getter:ident, $setter:ident: $field_ty:ty
+                    [$low:literal $(.. $high:literal)?];
+            )*
+        }
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        $vis struct $name($raw);
+
+        $(
+            const _: () = {
+                let high: u32 = $crate::bitfield!(@high $low $(, $high)?);
+                ::core::assert!(
+                    $low < high && high <= <$raw as $crate::packing::BitfieldValue>::BITS,
+                    concat!(
+                        "invalid bit range for `", stringify!($name), "::", stringify!($getter), "`"
+                    ),
+                );
+            };
+        )*
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Returns the raw bits of the value.
+            #[must_use]
+            pub const fn raw_value(self) -> $raw {
+                self.0
+            }
+
+            /// Creates a value from raw bits.
+            #[must_use]
+            pub const fn from_raw_value(raw_value: $raw) -> Self {
+                Self(raw_value)
+            }
+
+            /// Reads the value from the start of `bytes`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`PackingError::UnexpectedEnd`]($crate::errors::PackingError) if
+            /// `bytes` is too short.
+            pub fn from_bytes(
+                bytes: &[u8],
+                endianness: $crate::packing::Endianness,
+            ) -> ::core::result::Result<Self, $crate::errors::PackingError> {
+                let required = <$raw as $crate::packing::Packable>::SIZE;
+
+                if bytes.len() < required {
+                    return ::core::result::Result::Err(
+                        $crate::errors::PackingError::UnexpectedEnd {
+                            required,
+                            available: bytes.len(),
+                        },
+                    );
+                }
+
+                ::core::result::Result::Ok(<Self as $crate::packing::Packable>::unpack(
+                    endianness, bytes,
+                ))
+            }
+
+            /// Returns the bytes of the value in the given byte order.
+            #[must_use]
+            pub fn to_bytes(
+                self,
+                endianness: $crate::packing::Endianness,
+            ) -> [u8; ::core::mem::size_of::<$raw>()] {
+                let mut bytes = [0; ::core::mem::size_of::<$raw>()];
+                $crate::packing::Packable::pack(&self, endianness, &mut bytes);
+                bytes
+            }
+
+            $(
+                $(#[$field_attr])*
+                #[must_use]
+                $field_vis fn $getter(&self) -> $field_ty {
+                    let low: u32 = $low;
+                    let width = $crate::bitfield!(@high $low $(, $high)?) - low;
+                    let bits = $crate::packing::BitfieldValue::into_field_bits(self.0) >> low;
+                    <$field_ty as $crate::packing::BitfieldValue>::from_field_bits(bits, width)
+                }
+
+                #[doc = concat!(
+                    "Sets the `", stringify!($getter), "` field, keeping only the bits that fit."
+                )]
+                $field_vis fn $setter(&mut self, value: $field_ty) {
+                    let low: u32 = $low;
+                    let width = $crate::bitfield!(@high $low $(, $high)?) - low;
+                    let mask = $crate::packing::low_bits_mask(width) << low;
+                    let value = $crate::packing::BitfieldValue::into_field_bits(value) << low;
+                    let raw = $crate::packing::BitfieldValue::into_field_bits(self.0);
+                    self.0 = <$raw as $crate::packing::BitfieldValue>::from_field_bits(
+                        (raw & !mask) | (value & mask),
+                        <$raw as $crate::packing::BitfieldValue>::BITS,
+                    );
+                }
+            )*
+        }
+
+        impl $crate::packing::Packable for $name {
+            const SIZE: usize = <$raw as $crate::packing::Packable>::SIZE;
+
+            fn pack(&self, endianness: $crate::packing::Endianness, buffer: &mut [u8]) {
+                $crate::packing::Packable::pack(&self.0, endianness, buffer);
+            }
+
+            fn unpack(endianness: $crate::packing::Endianness, bytes: &[u8]) -> Self {
+                Self($crate::packing::Packable::unpack(endianness, bytes))
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    $(.field(stringify!($getter), &self.$getter()))*
+                    .finish()
+            }
+        }
+    };
+}
+
+/// Declares a struct with a packed byte layout.
+///
+/// Fields are stored in declaration order without padding, each followed by `=> Big` or
+/// `=> Little` for its byte order. Field types must implement [`Packable`], which
+/// includes primitive integers, byte arrays and other packed types; byte arrays and
+/// nested packed structs ignore the byte order, since their layout is already fixed.
+///
+/// The generated struct gets a `SIZE` constant, `from_bytes`, `to_bytes` and `write_to`,
+/// and implements [`Packable`]. Attributes, including derives, are passed through.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{errors::PackingError, packed_struct};
+///
+/// packed_struct! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub struct Record {
+///         pub id: u32 => Little,
+///         pub temperature: i16 => Big,
+///     }
+/// }
+///
+/// let record = Record { id: 7, temperature: -40 };
+/// assert_eq!(Record::SIZE, 6);
+/// assert_eq!(record.to_bytes(), [7, 0, 0, 0, 0xff, 0xd8]);
+/// assert_eq!(Record::from_bytes(&record.to_bytes()), Ok(record));
+/// assert_eq!(
+///     Record::from_bytes(&[1, 2, 3]),
+///     Err(PackingError::UnexpectedEnd { required: 6, available: 3 })
+/// );
+/// ```
+#[macro_export]
+macro_rules! packed_struct {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_attr:meta])*
+                $field_vis:vis $field:ident: $field_ty:ty => $endianness:ident
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $(
+                $(#[$field_attr])*
+                $field_vis $field: $field_ty,
+            )*
+        }
+
+        // The struct may or may not be `Copy`, so it is always taken by reference.
+        #[allow(dead_code, clippy::trivially_copy_pass_by_ref, clippy::wrong_self_convention)]
+        impl $name {
+            /// The number of bytes in the packed layout.
+            pub const SIZE: usize = 0 $(+ <$field_ty as $crate::packing::Packable>::SIZE)*;
+
+            /// Reads the struct from the start of `bytes`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`PackingError::UnexpectedEnd`]($crate::errors::PackingError) if
+            /// `bytes` is shorter than [`SIZE`](Self::SIZE).
+            pub fn from_bytes(
+                bytes: &[u8],
+            ) -> ::core::result::Result<Self, $crate::errors::PackingError> {
+                if bytes.len() < Self::SIZE {
+                    return ::core::result::Result::Err(
+                        $crate::errors::PackingError::UnexpectedEnd {
+                            required: Self::SIZE,
+                            available: bytes.len(),
+                        },
+                    );
+                }
+
+                ::core::result::Result::Ok(<Self as $crate::packing::Packable>::unpack(
+                    $crate::packing::Endianness::Big,
+                    bytes,
+                ))
+            }
+
+            /// Returns the packed bytes of the struct.
+            #[must_use]
+            pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+                let mut bytes = [0; Self::SIZE];
+                $crate::packing::Packable::pack(self, $crate::packing::Endianness::Big, &mut bytes);
+                bytes
+            }
+
+            /// Writes the packed bytes of the struct to the start of `buffer`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`PackingError::BufferTooSmall`]($crate::errors::PackingError) if
+            /// `buffer` is shorter than [`SIZE`](Self::SIZE).
+            pub fn write_to(
+                &self,
+                buffer: &mut [u8],
+            ) -> ::core::result::Result<(), $crate::errors::PackingError> {
+                if buffer.len() < Self::SIZE {
+                    return ::core::result::Result::Err(
+                        $crate::errors::PackingError::BufferTooSmall {
+                            required: Self::SIZE,
+                            available: buffer.len(),
+                        },
+                    );
+                }
+
+                $crate::packing::Packable::pack(self, $crate::packing::Endianness::Big, buffer);
+                ::core::result::Result::Ok(())
+            }
+        }
+
+        impl $crate::packing::Packable for $name {
+            const SIZE: usize = $name::SIZE;
+
+            #[allow(unused_assignments, unused_variables, unused_mut)]
+            fn pack(&self, _endianness: $crate::packing::Endianness, buffer: &mut [u8]) {
+                let mut offset = 0;
+
+                $(
+                    $crate::packing::Packable::pack(
+                        &self.$field,
+                        $crate::packing::Endianness::$endianness,
+                        &mut buffer[offset..],
+                    );
+                    offset += <$field_ty as $crate::packing::Packable>::SIZE;
+                )*
+            }
+
+            #[allow(unused_assignments, unused_variables, unused_mut)]
+            fn unpack(_endianness: $crate::packing::Endianness, bytes: &[u8]) -> Self {
+                let mut offset = 0;
+
+                $(
+                    let $field = <$field_ty as $crate::packing::Packable>::unpack(
+                        $crate::packing::Endianness::$endianness,
+                        &bytes[offset..],
+                    );
+                    offset += <$field_ty as $crate::packing::Packable>::SIZE;
+                )*
+
+                Self { $($field),* }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::errors::PackingError;
+
+    crate::bitfield! {
+        struct Register: u32 {
+            ready, set_ready: bool [0];
+            channel, set_channel: u8 [1..5];
+            delta, set_delta: i16 [5..17];
+            tag, set_tag: u16 [17..32];
+        }
+    }
+
+    crate::packed_struct! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Frame {
+            version: u8 => Big,
+            register: Register => Little,
+            sequence: u64 => Big,
+            checksum: [u8; 2] => Big,
+        }
+    }
+
+    #[test]
+    fn test_integers() {
+        let mut buffer = [0; 4];
+
+        0x1234_5678_u32.pack(Endianness::Big, &mut buffer);
+        assert_eq!(buffer, [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(u32::unpack(Endianness::Little, &buffer), 0x7856_3412);
+
+        (-2_i16).pack(Endianness::Little, &mut buffer);
+        assert_eq!(buffer[..2], [0xfe, 0xff]);
+        assert_eq!(i16::unpack(Endianness::Little, &buffer), -2);
+        assert_eq!(
+            Endianness::NATIVE,
+            if cfg!(target_endian = "big") {
+                Endianness::Big
+            } else {
+                Endianness::Little
+            }
+        );
+    }
+
+    #[test]
+    fn test_bitfield() {
+        let mut register = Register::default();
+        register.set_ready(true);
+        register.set_channel(0x1f);
+        register.set_delta(-1000);
+        register.set_tag(0x7fff);
+
+        assert!(register.ready());
+        assert_eq!(register.channel(), 0xf);
+        assert_eq!(register.delta(), -1000);
+        assert_eq!(register.tag(), 0x7fff);
+
+        register.set_delta(2047);
+        assert_eq!(register.delta(), 2047);
+        register.set_delta(2048);
+        assert_eq!(register.delta(), -2048);
+        assert_eq!(register.tag(), 0x7fff);
+        assert!(register.ready());
+
+        let raw = Register::from_raw_value(0b1_0110);
+        assert_eq!((raw.ready(), raw.channel()), (false, 0b1011));
+        assert_eq!(
+            format!("{raw:?}"),
+            "Register { ready: false, channel: 11, delta: 0, tag: 0 }"
+        );
+        assert_eq!(
+            Register::from_bytes(&[0, 0, 0, 0x16], Endianness::Big),
+            Ok(raw)
+        );
+        assert_eq!(raw.to_bytes(Endianness::Little), [0x16, 0, 0, 0]);
+        assert_eq!(
+            Register::from_bytes(&[0x16], Endianness::Big),
+            Err(PackingError::UnexpectedEnd {
+                required: 4,
+                available: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_packed_struct() {
+        let frame = Frame {
+            version: 2,
+            register: Register::from_raw_value(0x0102_0304),
+            sequence: 0x0a0b,
+            checksum: [0xca, 0xfe],
+        };
+
+        assert_eq!(Frame::SIZE, 15);
+        let bytes = frame.to_bytes();
+        assert_eq!(
+            bytes,
+            [2, 4, 3, 2, 1, 0, 0, 0, 0, 0, 0, 0x0a, 0x0b, 0xca, 0xfe]
+        );
+        assert_eq!(Frame::from_bytes(&bytes), Ok(frame));
+
+        let mut buffer = [0xee; 16];
+        assert_eq!(frame.write_to(&mut buffer), Ok(()));
+        assert_eq!(buffer[..15], bytes);
+        assert_eq!(buffer[15], 0xee);
+        assert_eq!(
+            frame.write_to(&mut buffer[..8]),
+            Err(PackingError::BufferTooSmall {
+                required: 15,
+                available: 8
+            })
+        );
+        assert_eq!(
+            Frame::from_bytes(&bytes[..14]),
+            Err(PackingError::UnexpectedEnd {
+                required: 15,
+                available: 14
+            })
+        );
+    }
+}
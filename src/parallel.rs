@@ -0,0 +1,200 @@
+//! Chunked parallel map and reduce over slices.
+//!
+//! Enabled by the `threads` feature, which implies `std`. [`Parallel`] splits a slice
+//! into one contiguous chunk per thread, runs a kernel on every chunk in scoped threads,
+//! and hands the results back in chunk order, so a reduction that is associative gives
+//! the same answer as a single pass. Inputs too small to be worth a thread run on the
+//! calling thread, and code that does not enable the feature keeps its scalar path.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::parallel::Parallel;
+//!
+//! let values: Vec<u64> = (1..=10_000).collect();
+//!
+//! let sum = Parallel::new().map_reduce(&values, |chunk| chunk.iter().sum::<u64>(), |a, b| a + b);
+//! assert_eq!(sum, Some(50_005_000));
+//! ```
+
+use alloc::vec::Vec;
+use core::num::NonZeroUsize;
+
+/// How work is split across threads.
+///
+/// The default uses as many threads as [`std::thread::available_parallelism`] reports
+/// and gives every thread at least 4096 elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Parallel {
+    threads: NonZeroUsize,
+    min_chunk_len: usize,
+}
+
+impl Parallel {
+    /// Creates a configuration for the threads available to the process.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            threads: std::thread::available_parallelism().unwrap_or(NonZeroUsize::MIN),
+            min_chunk_len: 4096,
+        }
+    }
+
+    /// Uses at most `threads` threads, including the calling one.
+    #[must_use]
+    pub const fn with_threads(mut self, threads: NonZeroUsize) -> Self {
+        self.threads = threads;
+        self
+    }
+
+    /// Gives every thread at least `min_chunk_len` elements, so that small inputs are not
+    /// split into chunks that cost more to hand off than to process.
+    #[must_use]
+    pub const fn with_min_chunk_len(mut self, min_chunk_len: usize) -> Self {
+        self.min_chunk_len = min_chunk_len;
+        self
+    }
+
+    /// Returns the maximum number of threads.
+    #[must_use]
+    pub const fn threads(&self) -> NonZeroUsize {
+        self.threads
+    }
+
+    /// Returns the minimum number of elements per thread.
+    #[must_use]
+    pub const fn min_chunk_len(&self) -> usize {
+        self.min_chunk_len
+    }
+
+    /// Returns the length of the chunks `len` elements are split into.
+    fn chunk_len(&self, len: usize) -> usize {
+        let by_threads = len.div_ceil(self.threads.get());
+        by_threads.max(self.min_chunk_len).max(1)
+    }
+
+    /// Runs `map` on contiguous chunks of `data` and returns the results in order.
+    ///
+    /// The last chunk runs on the calling thread. An empty slice produces no chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `map` panics on any chunk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::num::NonZeroUsize;
+    ///
+    /// use libx::parallel::Parallel;
+    ///
+    /// let parallel = Parallel::new()
+    ///     .with_threads(NonZeroUsize::new(2).expect("non-zero"))
+    ///     .with_min_chunk_len(1);
+    ///
+    /// assert_eq!(parallel.map_chunks(&[1, 2, 3, 4], <[i32]>::len), [2, 2]);
+    /// ```
+    pub fn map_chunks<T, R, F>(&self, data: &[T], map: F) -> Vec<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&[T]) -> R + Sync,
+    {
+        let mut chunks = data.chunks(self.chunk_len(data.len()));
+        let Some(last) = chunks.next_back() else {
+            return Vec::new();
+        };
+
+        if chunks.len() == 0 {
+            return alloc::vec![map(last)];
+        }
+
+        let map = &map;
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = chunks
+                .map(|chunk| scope.spawn(move || map(chunk)))
+                .collect();
+
+            let last = map(last);
+            let mut results: Vec<R> = handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect();
+
+            results.push(last);
+            results
+        })
+    }
+
+    /// Runs `map` on contiguous chunks of `data` and folds the results with `reduce`,
+    /// in chunk order. Returns `None` if `data` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `map` panics on any chunk.
+    pub fn map_reduce<T, R, F, G>(&self, data: &[T], map: F, reduce: G) -> Option<R>
+    where
+        T: Sync,
+        R: Send,
+        F: Fn(&[T]) -> R + Sync,
+        G: FnMut(R, R) -> R,
+    {
+        self.map_chunks(data, map).into_iter().reduce(reduce)
+    }
+}
+
+impl Default for Parallel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_threads() -> Parallel {
+        Parallel::new()
+            .with_threads(NonZeroUsize::MIN.saturating_add(1))
+            .with_min_chunk_len(1)
+    }
+
+    #[test]
+    fn test_map_chunks_keeps_order() {
+        let data: Vec<u32> = (0..9).collect();
+        let parallel = two_threads().with_threads(NonZeroUsize::MIN.saturating_add(3));
+
+        let firsts = parallel.map_chunks(&data, |chunk| chunk[0]);
+        assert_eq!(firsts, [0, 3, 6]);
+
+        assert!(parallel.map_chunks(&[] as &[u32], <[u32]>::len).is_empty());
+    }
+
+    #[test]
+    fn test_small_inputs_stay_on_one_chunk() {
+        let parallel = two_threads().with_min_chunk_len(100);
+        assert_eq!(parallel.map_chunks(&[1, 2, 3], <[i32]>::len), [3]);
+    }
+
+    #[test]
+    fn test_map_reduce_matches_scalar() {
+        let data: Vec<f64> = (0..1000).map(f64::from).collect();
+
+        let max = two_threads().map_reduce(
+            &data,
+            |chunk| chunk.iter().copied().fold(f64::MIN, f64::max),
+            f64::max,
+        );
+
+        assert_eq!(max, Some(999.0));
+        assert_eq!(
+            two_threads().map_reduce(&[] as &[f64], |_| 0.0, f64::max),
+            None
+        );
+    }
+}
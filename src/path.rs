@@ -0,0 +1,560 @@
+//! Lexical path manipulation without `std::path`.
+//!
+//! Paths are plain `&str`s interpreted according to a [`PathStyle`]: POSIX paths use `/`,
+//! while Windows paths accept both `\` and `/`, may start with a drive (`C:`) or UNC
+//! (`\\server\share`) prefix, and are written back with `\`. Nothing here touches the
+//! file system, so symbolic links are not resolved and `a/../b` is simply `b`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::path::PathStyle;
+//!
+//! let posix = PathStyle::Posix;
+//!
+//! assert_eq!(posix.join("/srv/www", "site/index.html"), "/srv/www/site/index.html");
+//! assert_eq!(posix.extension("archive.tar.gz"), Some("gz"));
+//! assert_eq!(posix.normalize("/srv/./www/../logs/"), "/srv/logs");
+//! assert_eq!(posix.relative_to("/srv/logs/today", "/srv/www").as_deref(), Some("../logs/today"));
+//!
+//! let windows = PathStyle::Windows;
+//!
+//! assert_eq!(windows.join(r"C:\Users", "libx/notes.txt"), r"C:\Users\libx/notes.txt");
+//! assert_eq!(windows.normalize(r"C:\Users\libx\..\Public"), r"C:\Users\Public");
+//! ```
+
+use alloc::{string::String, vec::Vec};
+
+/// The rules a path is interpreted with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PathStyle {
+    /// `/`-separated paths, as on Unix.
+    #[default]
+    Posix,
+
+    /// `\`- or `/`-separated paths with optional drive or UNC prefixes, as on Windows.
+    Windows,
+}
+
+/// A piece of a path, as yielded by [`PathStyle::components`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component<'a> {
+    /// A Windows drive or UNC prefix, such as `C:` or `\\server\share`.
+    Prefix(&'a str),
+
+    /// The root separator of an absolute path.
+    RootDir,
+
+    /// A leading `.` in a relative path; `.` elsewhere is skipped.
+    CurDir,
+
+    /// A `..` component.
+    ParentDir,
+
+    /// A file or directory name.
+    Normal(&'a str),
+}
+
+impl<'a> Component<'a> {
+    /// Returns the component as text, writing the root with `style`'s separator.
+    #[must_use]
+    pub const fn as_str(&self, style: PathStyle) -> &'a str {
+        match self {
+            Self::Prefix(text) | Self::Normal(text) => text,
+            Self::RootDir => match style {
+                PathStyle::Posix => "/",
+                PathStyle::Windows => "\\",
+            },
+            Self::CurDir => ".",
+            Self::ParentDir => "..",
+        }
+    }
+}
+
+/// An iterator over the [`Component`]s of a path.
+#[derive(Debug, Clone)]
+pub struct Components<'a> {
+    style: PathStyle,
+    prefix: Option<&'a str>,
+    has_root: bool,
+    rest: &'a str,
+    is_first: bool,
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = Component<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(prefix) = self.prefix.take() {
+            return Some(Component::Prefix(prefix));
+        }
+
+        if self.has_root {
+            self.has_root = false;
+            self.is_first = false;
+            return Some(Component::RootDir);
+        }
+
+        loop {
+            let rest = self.rest.trim_start_matches(|c| self.style.is_separator(c));
+
+            if rest.is_empty() {
+                self.rest = rest;
+                return None;
+            }
+
+            let end = rest
+                .find(|c| self.style.is_separator(c))
+                .unwrap_or(rest.len());
+            let (segment, remainder) = rest.split_at(end);
+            let is_first = core::mem::replace(&mut self.is_first, false);
+            self.rest = remainder;
+
+            match segment {
+                "." if is_first => return Some(Component::CurDir),
+                "." => {}
+                ".." => return Some(Component::ParentDir),
+                _ => return Some(Component::Normal(segment)),
+            }
+        }
+    }
+}
+
+impl PathStyle {
+    /// The style of the target the crate is compiled for.
+    #[cfg(windows)]
+    pub const NATIVE: Self = Self::Windows;
+
+    /// The style of the target the crate is compiled for.
+    #[cfg(not(windows))]
+    pub const NATIVE: Self = Self::Posix;
+
+    /// Returns the separator used when writing paths.
+    #[must_use]
+    pub const fn separator(self) -> char {
+        match self {
+            Self::Posix => '/',
+            Self::Windows => '\\',
+        }
+    }
+
+    /// Returns `true` if `character` separates components.
+    #[must_use]
+    pub const fn is_separator(self, character: char) -> bool {
+        match self {
+            Self::Posix => character == '/',
+            Self::Windows => character == '/' || character == '\\',
+        }
+    }
+
+    /// Returns the components of `path`.
+    ///
+    /// Repeated separators and `.` components other than a leading one are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::path::{Component, PathStyle};
+    ///
+    /// let components: Vec<_> = PathStyle::Windows.components(r"C:\temp\..\a.txt").collect();
+    ///
+    /// assert_eq!(
+    ///     components,
+    ///     [
+    ///         Component::Prefix("C:"),
+    ///         Component::RootDir,
+    ///         Component::Normal("temp"),
+    ///         Component::ParentDir,
+    ///         Component::Normal("a.txt"),
+    ///     ]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn components(self, path: &str) -> Components<'_> {
+        let (prefix, has_root, rest) = self.split_root(path);
+
+        Components {
+            style: self,
+            prefix,
+            has_root,
+            rest,
+            is_first: prefix.is_none() && !has_root,
+        }
+    }
+
+    /// Returns `true` if `path` does not depend on a current directory.
+    ///
+    /// On Windows, this requires both a prefix and a root, so `\temp` and `C:temp` are
+    /// relative.
+    #[must_use]
+    pub fn is_absolute(self, path: &str) -> bool {
+        let (prefix, has_root, _) = self.split_root(path);
+
+        match self {
+            Self::Posix => has_root,
+            Self::Windows => prefix.is_some() && (has_root || prefix.is_some_and(is_unc)),
+        }
+    }
+
+    /// Appends `path` to `base` with a separator between them.
+    ///
+    /// If `path` has a root, it replaces `base`; on Windows, it keeps `base`'s prefix
+    /// unless `path` has its own.
+    #[must_use]
+    pub fn join(self, base: &str, path: &str) -> String {
+        let (prefix, has_root, _) = self.split_root(path);
+
+        if prefix.is_some() || (self == Self::Posix && has_root) || base.is_empty() {
+            return String::from(path);
+        }
+
+        if has_root {
+            let (base_prefix, _, _) = self.split_root(base);
+            let mut joined = String::from(base_prefix.unwrap_or(""));
+            joined.push_str(path);
+            return joined;
+        }
+
+        let mut joined = String::from(base);
+
+        if !base.ends_with(|c| self.is_separator(c)) && !self.is_bare_drive(base) {
+            joined.push(self.separator());
+        }
+
+        joined.push_str(path);
+        joined
+    }
+
+    /// Returns the last component of `path` if it is a name.
+    #[must_use]
+    pub fn file_name(self, path: &str) -> Option<&str> {
+        match self.components(path).last()? {
+            Component::Normal(name) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Returns the file name without its [extension](Self::extension).
+    #[must_use]
+    pub fn file_stem(self, path: &str) -> Option<&str> {
+        let name = self.file_name(path)?;
+        Some(split_extension(name).0)
+    }
+
+    /// Returns the text after the last `.` of the file name.
+    ///
+    /// Names that start with their only `.`, such as `.profile`, have no extension.
+    #[must_use]
+    pub fn extension(self, path: &str) -> Option<&str> {
+        split_extension(self.file_name(path)?).1
+    }
+
+    /// Returns `path` without its last component, or `None` if it has no parent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::path::PathStyle;
+    ///
+    /// assert_eq!(PathStyle::Posix.parent("/usr/lib/"), Some("/usr"));
+    /// assert_eq!(PathStyle::Posix.parent("/usr"), Some("/"));
+    /// assert_eq!(PathStyle::Posix.parent("usr"), Some(""));
+    /// assert_eq!(PathStyle::Posix.parent("/"), None);
+    /// ```
+    #[must_use]
+    pub fn parent(self, path: &str) -> Option<&str> {
+        let (_, _, rest) = self.split_root(path);
+        let rest = rest.trim_start_matches(|c| self.is_separator(c));
+        let root_length = path.len() - rest.len();
+        let rest = rest.trim_end_matches(|c| self.is_separator(c));
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let end = rest.rfind(|c| self.is_separator(c)).map_or(0, |index| {
+            rest[..index]
+                .trim_end_matches(|c| self.is_separator(c))
+                .len()
+        });
+
+        Some(&path[..root_length + end])
+    }
+
+    /// Removes `.` components and resolves `..` against the preceding name.
+    ///
+    /// `..` at the start of a relative path is kept, and `..` directly after the root
+    /// is dropped. An empty result is written as `.`.
+    #[must_use]
+    pub fn normalize(self, path: &str) -> String {
+        let mut prefix = None;
+        let mut has_root = false;
+        let mut names: Vec<Component<'_>> = Vec::new();
+
+        for component in self.components(path) {
+            match component {
+                Component::Prefix(text) => prefix = Some(text),
+                Component::RootDir => has_root = true,
+                Component::CurDir => {}
+                Component::ParentDir => match names.last() {
+                    Some(Component::Normal(_)) => {
+                        names.pop();
+                    }
+                    _ if has_root => {}
+                    _ => names.push(component),
+                },
+                Component::Normal(_) => names.push(component),
+            }
+        }
+
+        self.assemble(prefix, has_root, &names)
+    }
+
+    /// Returns the relative path that leads from `base` to `path`.
+    ///
+    /// Both paths are [normalized](Self::normalize) first. Windows names are compared
+    /// without regard to ASCII case. Returns `None` if no relative path exists: when one
+    /// path is absolute and the other is not, when the prefixes differ, or when `base`
+    /// climbs out of a directory with `..` that `path` does not.
+    #[must_use]
+    pub fn relative_to(self, path: &str, base: &str) -> Option<String> {
+        let path = self.normalize(path);
+        let base = self.normalize(base);
+        let mut path_components = self.components(&path).peekable();
+        let mut base_components = self.components(&base).peekable();
+
+        let (path_prefix, base_prefix) = (self.split_root(&path), self.split_root(&base));
+
+        if path_prefix.1 != base_prefix.1
+            || !self.names_match(path_prefix.0.unwrap_or(""), base_prefix.0.unwrap_or(""))
+        {
+            return None;
+        }
+
+        while let (Some(left), Some(right)) = (path_components.peek(), base_components.peek()) {
+            let same = match (left, right) {
+                (Component::Normal(left), Component::Normal(right))
+                | (Component::Prefix(left), Component::Prefix(right)) => {
+                    self.names_match(left, right)
+                }
+                _ => left == right,
+            };
+
+            if !same {
+                break;
+            }
+
+            path_components.next();
+            base_components.next();
+        }
+
+        let mut names = Vec::new();
+
+        for component in base_components {
+            match component {
+                Component::Normal(_) => names.push(Component::ParentDir),
+                Component::CurDir => {}
+                _ => return None,
+            }
+        }
+
+        names.extend(path_components.filter(|component| *component != Component::CurDir));
+
+        Some(self.assemble(None, false, &names))
+    }
+
+    /// Splits `path` into its prefix, whether it has a root, and the rest.
+    fn split_root(self, path: &str) -> (Option<&str>, bool, &str) {
+        let prefix_length = match self {
+            Self::Posix => 0,
+            Self::Windows => self.prefix_length(path),
+        };
+        let (prefix, rest) = path.split_at(prefix_length);
+        let has_root = rest.starts_with(|c| self.is_separator(c));
+
+        ((!prefix.is_empty()).then_some(prefix), has_root, rest)
+    }
+
+    /// Returns the length of a Windows drive or UNC prefix at the start of `path`.
+    fn prefix_length(self, path: &str) -> usize {
+        let bytes = path.as_bytes();
+
+        if bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+            return 2;
+        }
+
+        let is_separator = |byte: &u8| self.is_separator(char::from(*byte));
+
+        if bytes.len() > 2 && is_separator(&bytes[0]) && is_separator(&bytes[1]) {
+            // `\\server\share`: the server, a separator, then the share.
+            let server_end = bytes[2..]
+                .iter()
+                .position(is_separator)
+                .map_or(bytes.len(), |index| index + 2);
+
+            if server_end == 2 {
+                return 0;
+            }
+
+            return bytes
+                .get(server_end + 1..)
+                .and_then(|rest| rest.iter().position(is_separator))
+                .map_or(bytes.len(), |index| index + server_end + 1);
+        }
+
+        0
+    }
+
+    /// Returns `true` if `path` is a drive without a root, such as `C:`, which is joined
+    /// without a separator.
+    fn is_bare_drive(self, path: &str) -> bool {
+        self == Self::Windows && path.len() == 2 && self.prefix_length(path) == 2
+    }
+
+    fn names_match(self, left: &str, right: &str) -> bool {
+        match self {
+            Self::Posix => left == right,
+            Self::Windows => left.eq_ignore_ascii_case(right),
+        }
+    }
+
+    fn assemble(self, prefix: Option<&str>, has_root: bool, names: &[Component<'_>]) -> String {
+        let mut output = String::from(prefix.unwrap_or(""));
+
+        if has_root {
+            output.push(self.separator());
+        }
+
+        for (index, name) in names.iter().enumerate() {
+            if index > 0 {
+                output.push(self.separator());
+            }
+
+            output.push_str(name.as_str(self));
+        }
+
+        if output.is_empty() {
+            output.push('.');
+        }
+
+        output
+    }
+}
+
+const fn is_unc(prefix: &str) -> bool {
+    prefix.len() > 2
+}
+
+/// Splits a file name into its stem and extension.
+fn split_extension(name: &str) -> (&str, Option<&str>) {
+    match name.rfind('.') {
+        Some(0) | None => (name, None),
+        Some(index) => (&name[..index], Some(&name[index + 1..])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POSIX: PathStyle = PathStyle::Posix;
+    const WINDOWS: PathStyle = PathStyle::Windows;
+
+    #[test]
+    fn test_components() {
+        assert_eq!(
+            POSIX.components("./a//b/./..").collect::<Vec<_>>(),
+            [
+                Component::CurDir,
+                Component::Normal("a"),
+                Component::Normal("b"),
+                Component::ParentDir
+            ]
+        );
+        assert_eq!(
+            WINDOWS
+                .components(r"\\server\share\dir/file")
+                .collect::<Vec<_>>(),
+            [
+                Component::Prefix(r"\\server\share"),
+                Component::RootDir,
+                Component::Normal("dir"),
+                Component::Normal("file")
+            ]
+        );
+        assert_eq!(
+            POSIX.components(r"C:\x").collect::<Vec<_>>(),
+            [Component::Normal(r"C:\x")]
+        );
+        assert_eq!(POSIX.components("").count(), 0);
+    }
+
+    #[test]
+    fn test_absolute_and_join() {
+        assert!(POSIX.is_absolute("/etc"));
+        assert!(!POSIX.is_absolute("etc"));
+        assert!(WINDOWS.is_absolute(r"C:\Windows"));
+        assert!(WINDOWS.is_absolute(r"\\server\share"));
+        assert!(!WINDOWS.is_absolute(r"\Windows"));
+        assert!(!WINDOWS.is_absolute("C:Windows"));
+
+        assert_eq!(POSIX.join("a/", "b"), "a/b");
+        assert_eq!(POSIX.join("a", "/b"), "/b");
+        assert_eq!(POSIX.join("", "b"), "b");
+        assert_eq!(WINDOWS.join(r"C:\a", r"\b"), r"C:\b");
+        assert_eq!(WINDOWS.join(r"C:\a", r"D:\b"), r"D:\b");
+        assert_eq!(WINDOWS.join("C:", "b"), "C:b");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(POSIX.file_name("/a/b.txt/"), Some("b.txt"));
+        assert_eq!(POSIX.file_name("/a/.."), None);
+        assert_eq!(POSIX.file_name("/"), None);
+        assert_eq!(POSIX.file_stem("a/archive.tar.gz"), Some("archive.tar"));
+        assert_eq!(POSIX.file_stem(".profile"), Some(".profile"));
+        assert_eq!(POSIX.extension(".profile"), None);
+        assert_eq!(POSIX.extension("notes."), Some(""));
+        assert_eq!(WINDOWS.extension(r"C:\dir.d\file"), None);
+    }
+
+    #[test]
+    fn test_parent() {
+        assert_eq!(POSIX.parent("a//b"), Some("a"));
+        assert_eq!(POSIX.parent(""), None);
+        assert_eq!(WINDOWS.parent(r"C:\a"), Some(r"C:\"));
+        assert_eq!(WINDOWS.parent("C:a"), Some("C:"));
+        assert_eq!(WINDOWS.parent(r"C:\"), None);
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(POSIX.normalize("a/b/../../.."), "..");
+        assert_eq!(POSIX.normalize("/../a"), "/a");
+        assert_eq!(POSIX.normalize("./"), ".");
+        assert_eq!(POSIX.normalize("../a/./b/"), "../a/b");
+        assert_eq!(WINDOWS.normalize("C:/a/./b"), r"C:\a\b");
+        assert_eq!(WINDOWS.normalize(r"\\srv\share\..\x"), r"\\srv\share\x");
+    }
+
+    #[test]
+    fn test_relative_to() {
+        let relative = |path, base| POSIX.relative_to(path, base);
+
+        assert_eq!(relative("/a/b/c", "/a/b").as_deref(), Some("c"));
+        assert_eq!(relative("/a", "/a/b/c").as_deref(), Some("../.."));
+        assert_eq!(relative("/a/b", "/a/b").as_deref(), Some("."));
+        assert_eq!(relative("x/y", "x/z").as_deref(), Some("../y"));
+        assert_eq!(relative("../x", "y").as_deref(), Some("../../x"));
+        assert_eq!(relative("x", "../y"), None);
+        assert_eq!(relative("/a", "a"), None);
+
+        assert_eq!(
+            WINDOWS
+                .relative_to(r"c:\Users\Libx", r"C:\users")
+                .as_deref(),
+            Some("Libx")
+        );
+        assert_eq!(WINDOWS.relative_to(r"C:\a", r"D:\a"), None);
+    }
+}
@@ -0,0 +1,239 @@
+//! Pseudo-random number generation.
+//!
+//! The [`RandomNumberGenerator`] trait abstracts over sources of random bits, and
+//! [`SplitMix64`] provides a small, fast, seedable generator that works everywhere
+//! `core` does, and [`Xorshift64`] an even smaller one. Neither is cryptographically
+//! secure. The [`sampling`] module draws from weighted distributions and samples
+//! streams of unknown length, and [`noise`] generates coherent noise and
+//! low-discrepancy sequences.
+
+pub mod noise;
+pub mod sampling;
+
+pub use sampling::{WeightedIndex, choices, reservoir_sample};
+
+/// A source of uniformly distributed random bits.
+///
+/// Implementors only need to provide [`next_u64`](Self::next_u64); every other method is
+/// derived from it.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::{RandomNumberGenerator, SplitMix64};
+///
+/// let mut rng = SplitMix64::new(42);
+///
+/// let roll = rng.next_bounded(6) + 1;
+/// assert!((1..=6).contains(&roll));
+///
+/// let unit = rng.next_f64();
+/// assert!((0.0..1.0).contains(&unit));
+/// ```
+pub trait RandomNumberGenerator {
+    /// Returns the next 64 random bits.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns the next 32 random bits.
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    /// Returns a uniformly distributed value in `0..upper_bound`.
+    ///
+    /// This uses Lemire's multiply-and-reject method, so the result carries no modulo bias.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `upper_bound` is zero.
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_bounded(&mut self, upper_bound: u64) -> u64 {
+        assert!(upper_bound > 0, "upper bound must be non-zero");
+
+        let mut product = u128::from(self.next_u64()) * u128::from(upper_bound);
+        let mut low = product as u64;
+
+        if low < upper_bound {
+            let threshold = upper_bound.wrapping_neg() % upper_bound;
+
+            while low < threshold {
+                product = u128::from(self.next_u64()) * u128::from(upper_bound);
+                low = product as u64;
+            }
+        }
+
+        (product >> 64) as u64
+    }
+
+    /// Returns a uniformly distributed value in `0.0..1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    fn next_f64(&mut self) -> f64 {
+        // The top 53 bits fill the significand exactly.
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns `true` with probability one half.
+    fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// Fills `dest` with random bytes.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+impl<R> RandomNumberGenerator for &mut R
+where
+    R: RandomNumberGenerator + ?Sized,
+{
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// Sebastiano Vigna's `SplitMix64` generator.
+///
+/// Every seed, including zero, produces a full-period sequence of 2^64 values.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::{RandomNumberGenerator, SplitMix64};
+///
+/// let mut first = SplitMix64::new(7);
+/// let mut second = SplitMix64::new(7);
+///
+/// assert_eq!(first.next_u64(), second.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Creates a generator from `seed`.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RandomNumberGenerator for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// George Marsaglia's 64-bit xorshift generator, with shifts 13, 7 and 17.
+///
+/// Each step costs three shifts and three exclusive-ors, and the sequence has period
+/// 2^64 − 1. Its low bits fail some statistical tests that [`SplitMix64`] passes, so
+/// prefer that generator unless every cycle counts.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::{RandomNumberGenerator, Xorshift64};
+///
+/// let mut rng = Xorshift64::new(1);
+///
+/// assert_eq!(rng.next_u64(), 1_082_269_761);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Xorshift64 {
+    state: u64,
+}
+
+impl Xorshift64 {
+    /// Creates a generator from `seed`.
+    ///
+    /// The all-zero state never leaves zero, so a zero seed is replaced by a fixed
+    /// non-zero one.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            state: if seed == 0 {
+                0x9E37_79B9_7F4A_7C15
+            } else {
+                seed
+            },
+        }
+    }
+}
+
+impl RandomNumberGenerator for Xorshift64 {
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_mix_reference_values() {
+        let mut rng = SplitMix64::new(1_234_567);
+
+        assert_eq!(rng.next_u64(), 6_457_827_717_110_365_317);
+        assert_eq!(rng.next_u64(), 3_203_168_211_198_807_973);
+        assert_eq!(rng.next_u64(), 9_817_491_932_198_370_423);
+    }
+
+    #[test]
+    fn test_xorshift_zero_seed() {
+        let mut rng = Xorshift64::new(0);
+
+        assert!((0..1_000).all(|_| rng.next_u64() != 0));
+        assert_eq!(Xorshift64::new(0), Xorshift64::new(0x9E37_79B9_7F4A_7C15));
+    }
+
+    #[test]
+    fn test_next_bounded_stays_in_range() {
+        let mut rng = SplitMix64::new(0);
+
+        for upper_bound in 1..100 {
+            assert!(rng.next_bounded(upper_bound) < upper_bound);
+        }
+    }
+
+    #[test]
+    #[should_panic = "upper bound must be non-zero"]
+    fn test_next_bounded_zero() {
+        SplitMix64::new(0).next_bounded(0);
+    }
+
+    #[test]
+    fn test_next_f64_range() {
+        let mut rng = SplitMix64::new(99);
+
+        for _ in 0..1_000 {
+            let value = rng.next_f64();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_fill_bytes_partial_chunk() {
+        let mut rng = SplitMix64::new(5);
+        let mut buffer = [0u8; 11];
+
+        rng.fill_bytes(&mut buffer);
+        assert!(buffer.iter().any(|&byte| byte != 0));
+    }
+}
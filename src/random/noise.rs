@@ -0,0 +1,491 @@
+//! Coherent noise and low-discrepancy sequences.
+//!
+//! [`Perlin`] and [`ValueNoise`] map coordinates to smoothly varying values in
+//! `-1.0..=1.0`: nearby points get similar values, so they suit terrain, textures and
+//! animation jitter. Both are seeded from a [`RandomNumberGenerator`] and return the
+//! same value for the same coordinates every time.
+//!
+//! [`Halton`] and [`Sobol`] yield points in the unit interval or square that cover it
+//! more evenly than independent random draws, which makes sampling and numerical
+//! integration converge faster.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::random::SplitMix64;
+//! use libx::random::noise::{Perlin, Sobol};
+//!
+//! let perlin = Perlin::new(&mut SplitMix64::new(9));
+//! let height = perlin.noise2(12.5, 3.25);
+//! assert!((-1.0..=1.0).contains(&height));
+//!
+//! let points: Vec<(f64, f64)> = Sobol::new().take(4).collect();
+//! assert_eq!(points, [(0.0, 0.0), (0.5, 0.5), (0.75, 0.25), (0.25, 0.75)]);
+//! ```
+
+use super::RandomNumberGenerator;
+use crate::num::traits::FloatingPoint;
+
+/// Ken Perlin's gradient noise in one and two dimensions.
+///
+/// The noise is zero at every integer coordinate and varies smoothly in between.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::SplitMix64;
+/// use libx::random::noise::Perlin;
+///
+/// let perlin = Perlin::new(&mut SplitMix64::new(1));
+///
+/// assert_eq!(perlin.noise2(3.0, 4.0), 0.0);
+/// assert!((perlin.noise1(0.5) - perlin.noise1(0.501)).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Perlin {
+    permutation: Permutation,
+}
+
+impl Perlin {
+    /// Creates noise whose gradients are shuffled by `rng`.
+    pub fn new<R>(rng: &mut R) -> Self
+    where
+        R: RandomNumberGenerator + ?Sized,
+    {
+        Self {
+            permutation: Permutation::new(rng),
+        }
+    }
+
+    /// Returns the noise at `x`, in `-1.0..=1.0`.
+    #[must_use]
+    pub fn noise1(&self, x: f64) -> f64 {
+        let (cell, x) = split(x);
+        let gradient = |offset: usize, x: f64| {
+            if self.permutation.hash(cell + offset) & 1 == 0 {
+                x
+            } else {
+                -x
+            }
+        };
+
+        // A single cell spans at most half the range, so the result is doubled.
+        2.0 * lerp(fade(x), gradient(0, x), gradient(1, x - 1.0))
+    }
+
+    /// Returns the noise at `(x, y)`, in `-1.0..=1.0`.
+    #[must_use]
+    pub fn noise2(&self, x: f64, y: f64) -> f64 {
+        let (column, x) = split(x);
+        let (row, y) = split(y);
+        let gradient = |dx: u8, dy: u8| {
+            let hash = self
+                .permutation
+                .hash2(column + usize::from(dx), row + usize::from(dy));
+            gradient2(hash, x - f64::from(dx), y - f64::from(dy))
+        };
+
+        lerp(
+            fade(y),
+            lerp(fade(x), gradient(0, 0), gradient(1, 0)),
+            lerp(fade(x), gradient(0, 1), gradient(1, 1)),
+        )
+    }
+}
+
+/// Value noise in one and two dimensions.
+///
+/// Random values at the integer coordinates are blended smoothly in between. It is
+/// cheaper than [`Perlin`] noise but shows more of the grid it is built on.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::SplitMix64;
+/// use libx::random::noise::ValueNoise;
+///
+/// let noise = ValueNoise::new(&mut SplitMix64::new(4));
+///
+/// assert_eq!(noise.noise2(1.5, 2.5), noise.noise2(1.5, 2.5));
+/// assert!((-1.0..=1.0).contains(&noise.noise1(7.3)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValueNoise {
+    permutation: Permutation,
+    values: [f64; 256],
+}
+
+impl ValueNoise {
+    /// Creates noise whose lattice values are drawn from `rng`.
+    pub fn new<R>(rng: &mut R) -> Self
+    where
+        R: RandomNumberGenerator + ?Sized,
+    {
+        let mut values = [0.0; 256];
+
+        for value in &mut values {
+            *value = rng.next_f64() * 2.0 - 1.0;
+        }
+
+        Self {
+            permutation: Permutation::new(rng),
+            values,
+        }
+    }
+
+    /// Returns the noise at `x`, in `-1.0..=1.0`.
+    #[must_use]
+    pub fn noise1(&self, x: f64) -> f64 {
+        let (cell, x) = split(x);
+        let value = |offset: usize| self.values[self.permutation.hash(cell + offset)];
+
+        lerp(fade(x), value(0), value(1))
+    }
+
+    /// Returns the noise at `(x, y)`, in `-1.0..=1.0`.
+    #[must_use]
+    pub fn noise2(&self, x: f64, y: f64) -> f64 {
+        let (column, x) = split(x);
+        let (row, y) = split(y);
+        let value =
+            |dx: usize, dy: usize| self.values[self.permutation.hash2(column + dx, row + dy)];
+
+        lerp(
+            fade(y),
+            lerp(fade(x), value(0, 0), value(1, 0)),
+            lerp(fade(x), value(0, 1), value(1, 1)),
+        )
+    }
+}
+
+/// A shuffled table of the bytes, repeated so lookups never need to wrap.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Permutation {
+    table: [u8; 512],
+}
+
+impl Permutation {
+    fn new<R>(rng: &mut R) -> Self
+    where
+        R: RandomNumberGenerator + ?Sized,
+    {
+        let mut table = [0u8; 512];
+
+        for (slot, byte) in table.iter_mut().zip(0..=u8::MAX) {
+            *slot = byte;
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // The bound is at most 256.
+        for index in (1..256).rev() {
+            let other = rng.next_bounded(index as u64 + 1) as usize;
+            table.swap(index, other);
+        }
+
+        let (first, second) = table.split_at_mut(256);
+        second.copy_from_slice(first);
+
+        Self { table }
+    }
+
+    /// Hashes a lattice coordinate, already reduced to `0..257`.
+    const fn hash(&self, cell: usize) -> usize {
+        self.table[cell] as usize
+    }
+
+    /// Hashes a pair of lattice coordinates, each already reduced to `0..257`.
+    const fn hash2(&self, column: usize, row: usize) -> usize {
+        self.table[self.hash(column) + row] as usize
+    }
+}
+
+/// Splits `x` into its lattice cell, reduced to `0..256`, and the offset within it.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn split(x: f64) -> (usize, f64) {
+    let floor = FloatingPoint::floor(x);
+
+    // Out-of-range values saturate, which still lands on some cell.
+    ((floor as i64 & 255) as usize, x - floor)
+}
+
+/// Returns the gradient for `hash` dotted with the offset `(x, y)`.
+fn gradient2(hash: usize, x: f64, y: f64) -> f64 {
+    match hash & 7 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        3 => -x - y,
+        4 => x,
+        5 => -x,
+        6 => y,
+        _ => -y,
+    }
+}
+
+/// Eases `t` in `0.0..=1.0` so the noise has continuous first and second derivatives.
+fn fade(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(t: f64, a: f64, b: f64) -> f64 {
+    t * (b - a) + a
+}
+
+/// Returns the radical inverse of `index` in `base`: its digits mirrored about the
+/// radix point.
+///
+/// # Panics
+///
+/// Panics if `base` is less than two.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::noise::radical_inverse;
+///
+/// // 6 is 110 in binary, and 0.011 in binary is 0.375; 5 is 12 in ternary.
+/// assert_eq!(radical_inverse(6, 2), 0.375);
+/// assert!((radical_inverse(5, 3) - 7.0 / 9.0).abs() < 1e-15);
+/// ```
+#[must_use]
+#[allow(clippy::cast_precision_loss)] // Bases and digits are far below 2^53.
+pub fn radical_inverse(mut index: u64, base: u64) -> f64 {
+    assert!(base >= 2, "base must be at least two");
+
+    let inverse_base = 1.0 / base as f64;
+    let mut scale = inverse_base;
+    let mut result = 0.0;
+
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale *= inverse_base;
+    }
+
+    result
+}
+
+/// The Halton sequence: radical inverses of `1, 2, 3, …` in one base per dimension.
+///
+/// [`Halton::new`] yields numbers in one base; [`Halton::pair`] yields points in the
+/// unit square from two bases, which should be coprime, such as the default 2 and 3.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::noise::Halton;
+///
+/// let values: Vec<f64> = Halton::new(2).take(4).collect();
+/// assert_eq!(values, [0.5, 0.25, 0.75, 0.125]);
+///
+/// let (x, y) = Halton::pair(2, 3).nth(1).unwrap();
+/// assert_eq!((x, y), (0.25, 2.0 / 3.0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Halton<const N: usize> {
+    bases: [u64; N],
+    index: u64,
+}
+
+impl Halton<1> {
+    /// Creates the one-dimensional sequence in `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is less than two.
+    #[must_use]
+    pub fn new(base: u64) -> Self {
+        Self::with_bases([base])
+    }
+}
+
+impl Halton<2> {
+    /// Creates the two-dimensional sequence in bases `x` and `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either base is less than two.
+    #[must_use]
+    pub fn pair(x: u64, y: u64) -> Self {
+        Self::with_bases([x, y])
+    }
+}
+
+impl<const N: usize> Halton<N> {
+    fn with_bases(bases: [u64; N]) -> Self {
+        assert!(
+            bases.iter().all(|&base| base >= 2),
+            "base must be at least two"
+        );
+
+        Self { bases, index: 0 }
+    }
+
+    /// Returns the number of points yielded so far.
+    #[must_use]
+    pub const fn position(&self) -> u64 {
+        self.index
+    }
+
+    /// Skips ahead so the next point is the one after `position` points.
+    #[must_use]
+    pub const fn with_position(mut self, position: u64) -> Self {
+        self.index = position;
+        self
+    }
+
+    fn advance(&mut self) -> Option<u64> {
+        self.index = self.index.checked_add(1)?;
+        Some(self.index)
+    }
+}
+
+impl Iterator for Halton<1> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        let index = self.advance()?;
+        Some(radical_inverse(index, self.bases[0]))
+    }
+}
+
+impl Iterator for Halton<2> {
+    type Item = (f64, f64);
+
+    fn next(&mut self) -> Option<(f64, f64)> {
+        let index = self.advance()?;
+        Some((
+            radical_inverse(index, self.bases[0]),
+            radical_inverse(index, self.bases[1]),
+        ))
+    }
+}
+
+/// The first two dimensions of the Sobol sequence, in Gray-code order.
+///
+/// The sequence starts at the origin and yields 2^32 points. Every block of `2^k`
+/// points starting at a multiple of `2^k` puts exactly one point in each of the `2^k`
+/// equal strips along either axis.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Sobol {
+    index: u32,
+    point: (u32, u32),
+    done: bool,
+}
+
+impl Sobol {
+    /// Creates the sequence, starting at the origin.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            index: 0,
+            point: (0, 0),
+            done: false,
+        }
+    }
+
+    /// Returns an iterator over the first coordinate alone, which is the base-2 van der
+    /// Corput sequence in Gray-code order.
+    pub fn first_dimension(self) -> impl Iterator<Item = f64> {
+        self.map(|(x, _)| x)
+    }
+}
+
+impl Default for Sobol {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Iterator for Sobol {
+    type Item = (f64, f64);
+
+    fn next(&mut self) -> Option<(f64, f64)> {
+        if self.done {
+            return None;
+        }
+
+        let scale = 1.0 / 4_294_967_296.0;
+        let (x, y) = self.point;
+        let point = (f64::from(x) * scale, f64::from(y) * scale);
+
+        // The next point flips the direction number of the lowest zero bit of the index.
+        let bit = self.index.trailing_ones();
+        let first = 1u32.checked_shl(31 - bit.min(31)).unwrap_or_default();
+        let second = (0..bit.min(31)).fold(1u32 << 31, |v, _| v ^ (v >> 1));
+
+        self.point = (x ^ first, y ^ second);
+        self.index = self.index.wrapping_add(1);
+        self.done = self.index == 0;
+
+        Some(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{random::SplitMix64, testing::check_property};
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_noise_is_bounded_and_continuous() {
+        let perlin = Perlin::new(&mut SplitMix64::new(2));
+        let value = ValueNoise::new(&mut SplitMix64::new(3));
+
+        let result = check_property(0x5EED, 500, |rng| {
+            let x = rng.next_f64() * 2_000.0 - 1_000.0;
+            let y = rng.next_f64() * 2_000.0 - 1_000.0;
+            let step = 1e-6;
+
+            for (here, near) in [
+                (perlin.noise1(x), perlin.noise1(x + step)),
+                (perlin.noise2(x, y), perlin.noise2(x + step, y - step)),
+                (value.noise1(x), value.noise1(x + step)),
+                (value.noise2(x, y), value.noise2(x - step, y + step)),
+            ] {
+                if !(-1.0..=1.0).contains(&here) || (here - near).abs() > 1e-4 {
+                    return Err(alloc::format!("{x}, {y}: {here} then {near}").into());
+                }
+            }
+
+            Ok(())
+        });
+
+        assert!(result.is_ok(), "{result:?}");
+        assert_eq!(perlin.noise1(-7.0), 0.0);
+        assert_eq!(perlin.noise2(255.0, 256.0), 0.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_halton() {
+        assert_eq!(radical_inverse(0, 7), 0.0);
+
+        let thirds: alloc::vec::Vec<f64> = Halton::new(3).take(3).collect();
+        assert_eq!(thirds, [1.0 / 3.0, 2.0 / 3.0, 1.0 / 9.0]);
+
+        let mut halton = Halton::pair(2, 3).with_position(3);
+        assert_eq!(halton.next(), Some((0.125, 4.0 / 9.0)));
+        assert_eq!(halton.position(), 4);
+        assert_eq!(Halton::new(2).with_position(u64::MAX).next(), None);
+    }
+
+    #[test]
+    #[allow(
+        clippy::float_cmp,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn test_sobol_stratification() {
+        let points: alloc::vec::Vec<(f64, f64)> = Sobol::new().take(16).collect();
+
+        for strip in 0..16u8 {
+            let inside = |v: f64| (v * 16.0) as u8 == strip;
+            assert_eq!(points.iter().filter(|(x, _)| inside(*x)).count(), 1);
+            assert_eq!(points.iter().filter(|(_, y)| inside(*y)).count(), 1);
+        }
+
+        let first: alloc::vec::Vec<f64> = Sobol::new().first_dimension().take(4).collect();
+        assert_eq!(first, [0.0, 0.5, 0.75, 0.25]);
+    }
+}
@@ -0,0 +1,285 @@
+//! Weighted selection and sampling from streams.
+//!
+//! [`WeightedIndex`] draws indices in proportion to a list of weights in constant time
+//! per draw, using Vose's alias method. [`choices`] builds on it to pick items with
+//! replacement, and [`reservoir_sample`] picks items without replacement from an
+//! iterator whose length is not known in advance, holding only the sample in memory.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::random::{SplitMix64, choices, reservoir_sample};
+//!
+//! let mut rng = SplitMix64::new(7);
+//!
+//! let picks = choices(&["rare", "common"], &[1.0, 99.0], 5, &mut rng).unwrap();
+//! assert_eq!(picks.len(), 5);
+//!
+//! let sample = reservoir_sample(1..=1_000_000, 3, &mut rng);
+//! assert_eq!(sample.len(), 3);
+//! ```
+
+use alloc::vec::Vec;
+
+use super::RandomNumberGenerator;
+use crate::errors::WeightError;
+
+/// A distribution over the indices `0..n` with probabilities proportional to weights.
+///
+/// Building the table takes linear time; each draw then takes one bounded integer and
+/// one float from the generator, whatever the number of weights.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::{SplitMix64, WeightedIndex};
+///
+/// let die = WeightedIndex::new([1.0, 1.0, 1.0, 1.0, 1.0, 5.0]).unwrap();
+/// let mut rng = SplitMix64::new(1);
+///
+/// let sixes = (0..1_000).filter(|_| die.sample(&mut rng) == 5).count();
+/// assert!((400..600).contains(&sixes));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeightedIndex {
+    probabilities: Vec<f64>,
+    aliases: Vec<usize>,
+}
+
+impl WeightedIndex {
+    /// Builds the distribution for `weights`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WeightError`] if there are no weights, a weight is negative or not
+    /// finite, or the weights do not have a positive, finite sum.
+    pub fn new<I>(weights: I) -> Result<Self, WeightError>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let weights: Vec<f64> = weights.into_iter().collect();
+
+        if weights.is_empty() {
+            return Err(WeightError::Empty);
+        }
+
+        if let Some(index) = weights
+            .iter()
+            .position(|weight| !weight.is_finite() || *weight < 0.0)
+        {
+            return Err(WeightError::InvalidWeight { index });
+        }
+
+        let total: f64 = weights.iter().sum();
+
+        if !total.is_finite() || total <= 0.0 {
+            return Err(WeightError::InvalidTotal);
+        }
+
+        #[allow(clippy::cast_precision_loss)] // Counts beyond 2^53 lose nothing that matters.
+        let scale = weights.len() as f64 / total;
+        let mut probabilities: Vec<f64> = weights.iter().map(|weight| weight * scale).collect();
+        let mut aliases: Vec<usize> = (0..weights.len()).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..weights.len()).partition(|&index| probabilities[index] < 1.0);
+
+        while let (Some(&less), Some(&more)) = (small.last(), large.last()) {
+            small.pop();
+            aliases[less] = more;
+            probabilities[more] -= 1.0 - probabilities[less];
+
+            if probabilities[more] < 1.0 {
+                large.pop();
+                small.push(more);
+            }
+        }
+
+        // Whatever is left is one up to rounding error.
+        for index in small.into_iter().chain(large) {
+            probabilities[index] = 1.0;
+        }
+
+        Ok(Self {
+            probabilities,
+            aliases,
+        })
+    }
+
+    /// Returns the number of indices the distribution draws from.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.probabilities.len()
+    }
+
+    /// Returns `false`: a distribution always has at least one index.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Draws an index.
+    #[allow(clippy::cast_possible_truncation)] // The bound came from a `usize`.
+    pub fn sample<R>(&self, rng: &mut R) -> usize
+    where
+        R: RandomNumberGenerator + ?Sized,
+    {
+        let index = rng.next_bounded(self.len() as u64) as usize;
+
+        if rng.next_f64() < self.probabilities[index] {
+            index
+        } else {
+            self.aliases[index]
+        }
+    }
+}
+
+/// Picks `k` items with replacement, each with probability proportional to its weight.
+///
+/// # Errors
+///
+/// Returns [`WeightError::LengthMismatch`] if `items` and `weights` differ in length,
+/// and the errors of [`WeightedIndex::new`] for invalid weights.
+///
+/// # Examples
+///
+/// ```
+/// use libx::random::{SplitMix64, choices};
+///
+/// let mut rng = SplitMix64::new(3);
+/// let picks = choices(&['a', 'b', 'c'], &[0.0, 1.0, 0.0], 4, &mut rng).unwrap();
+///
+/// assert_eq!(picks, [&'b'; 4]);
+/// ```
+pub fn choices<'a, T, R>(
+    items: &'a [T],
+    weights: &[f64],
+    k: usize,
+    rng: &mut R,
+) -> Result<Vec<&'a T>, WeightError>
+where
+    R: RandomNumberGenerator + ?Sized,
+{
+    if items.len() != weights.len() {
+        return Err(WeightError::LengthMismatch {
+            items: items.len(),
+            weights: weights.len(),
+        });
+    }
+
+    let distribution = WeightedIndex::new(weights.iter().copied())?;
+
+    Ok((0..k).map(|_| &items[distribution.sample(rng)]).collect())
+}
+
+/// Picks `k` items uniformly without replacement from `items`, in one pass.
+///
+/// Every subset of `k` items is equally likely, and only the sample is kept in memory,
+/// so the iterator may be far longer than fits in memory. If it yields fewer than `k`
+/// items, all of them are returned. The order of the sample is not meaningful.
+pub fn reservoir_sample<I, R>(items: I, k: usize, rng: &mut R) -> Vec<I::Item>
+where
+    I: IntoIterator,
+    R: RandomNumberGenerator + ?Sized,
+{
+    let mut items = items.into_iter();
+    let mut reservoir: Vec<I::Item> = items.by_ref().take(k).collect();
+
+    if reservoir.len() < k || k == 0 {
+        return reservoir;
+    }
+
+    for (seen, item) in (k as u64 + 1..).zip(items) {
+        #[allow(clippy::cast_possible_truncation)] // The slot is below `k`.
+        let slot = rng.next_bounded(seen) as usize;
+
+        if slot < k {
+            reservoir[slot] = item;
+        }
+    }
+
+    reservoir
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn test_weighted_index_frequencies() {
+        let weights = [1.0, 0.0, 2.0, 7.0];
+        let distribution = WeightedIndex::new(weights).expect("valid weights");
+        let mut rng = SplitMix64::new(11);
+        let mut counts = [0usize; 4];
+
+        for _ in 0..100_000 {
+            counts[distribution.sample(&mut rng)] += 1;
+        }
+
+        assert_eq!(counts[1], 0);
+
+        for (count, weight) in counts.iter().zip(weights) {
+            #[allow(clippy::cast_precision_loss)]
+            let share = *count as f64 / 100_000.0;
+            assert!((share - weight / 10.0).abs() < 0.01, "{counts:?}");
+        }
+    }
+
+    #[test]
+    fn test_weight_errors() {
+        assert_eq!(WeightedIndex::new([]), Err(WeightError::Empty));
+        assert_eq!(
+            WeightedIndex::new([1.0, -1.0]),
+            Err(WeightError::InvalidWeight { index: 1 })
+        );
+        assert_eq!(
+            WeightedIndex::new([f64::NAN]),
+            Err(WeightError::InvalidWeight { index: 0 })
+        );
+        assert_eq!(
+            WeightedIndex::new([0.0, 0.0]),
+            Err(WeightError::InvalidTotal)
+        );
+        assert_eq!(
+            WeightedIndex::new([f64::MAX, f64::MAX]),
+            Err(WeightError::InvalidTotal)
+        );
+
+        let mut rng = SplitMix64::new(0);
+        assert_eq!(
+            choices(&[1, 2], &[1.0], 1, &mut rng),
+            Err(WeightError::LengthMismatch {
+                items: 2,
+                weights: 1
+            })
+        );
+        assert_eq!(choices(&[1], &[1.0], 0, &mut rng), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_reservoir_sample() {
+        let mut rng = SplitMix64::new(5);
+
+        assert_eq!(reservoir_sample(0..3, 5, &mut rng).len(), 3);
+        assert!(reservoir_sample(0..3, 0, &mut rng).is_empty());
+
+        let mut counts = [0usize; 10];
+
+        for _ in 0..20_000 {
+            let mut sample = reservoir_sample(0..10, 3, &mut rng);
+            sample.sort_unstable();
+            sample.dedup();
+            assert_eq!(sample.len(), 3);
+
+            for item in sample {
+                counts[item] += 1;
+            }
+        }
+
+        // Each item is picked with probability 3/10, so about 6,000 times.
+        assert!(
+            counts.iter().all(|&count| (5_700..6_300).contains(&count)),
+            "{counts:?}"
+        );
+    }
+}
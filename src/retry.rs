@@ -0,0 +1,362 @@
+//! Backoff schedules and a retry loop for fallible operations.
+//!
+//! A [`Backoff`] describes how long to wait between attempts and produces those delays
+//! through [`Backoff::delays`]. The [`retry`] helper drives a closure with a
+//! [`RetryPolicy`], which pairs such a schedule with a way of waiting.
+
+use core::time::Duration;
+
+use crate::random::RandomNumberGenerator;
+
+/// How randomness is applied to each computed delay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Jitter {
+    /// Delays are used exactly as computed.
+    #[default]
+    None,
+
+    /// Each delay is replaced by a uniformly random value between zero and the delay.
+    Full,
+
+    /// Each delay keeps half of its value and randomizes the other half.
+    Equal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Strategy {
+    Constant,
+    Linear { increment: Duration },
+    Exponential { multiplier: u32 },
+}
+
+/// A schedule of delays between successive attempts of an operation.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::{random::SplitMix64, retry::Backoff};
+///
+/// let backoff = Backoff::exponential(Duration::from_millis(100), 2)
+///     .with_max_delay(Duration::from_millis(500))
+///     .with_max_retries(5);
+///
+/// let delays: Vec<_> = backoff.delays(SplitMix64::new(0)).collect();
+///
+/// assert_eq!(
+///     delays,
+///     [100, 200, 400, 500, 500].map(Duration::from_millis)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Backoff {
+    strategy: Strategy,
+    initial: Duration,
+    max_delay: Option<Duration>,
+    max_retries: Option<usize>,
+    jitter: Jitter,
+}
+
+impl Backoff {
+    const fn with_strategy(strategy: Strategy, initial: Duration) -> Self {
+        Self {
+            strategy,
+            initial,
+            max_delay: None,
+            max_retries: None,
+            jitter: Jitter::None,
+        }
+    }
+
+    /// Creates a schedule that always waits `delay`.
+    #[must_use]
+    pub const fn constant(delay: Duration) -> Self {
+        Self::with_strategy(Strategy::Constant, delay)
+    }
+
+    /// Creates a schedule that starts at `initial` and grows by `increment` after every
+    /// attempt.
+    #[must_use]
+    pub const fn linear(initial: Duration, increment: Duration) -> Self {
+        Self::with_strategy(Strategy::Linear { increment }, initial)
+    }
+
+    /// Creates a schedule that starts at `initial` and is multiplied by `multiplier` after
+    /// every attempt.
+    #[must_use]
+    pub const fn exponential(initial: Duration, multiplier: u32) -> Self {
+        Self::with_strategy(Strategy::Exponential { multiplier }, initial)
+    }
+
+    /// Caps every delay at `max_delay`, before jitter is applied.
+    #[must_use]
+    pub const fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Limits the schedule to `max_retries` delays.
+    ///
+    /// Without a limit, the schedule never ends.
+    #[must_use]
+    pub const fn with_max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Applies `jitter` to every delay.
+    #[must_use]
+    pub const fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Returns the maximum number of delays, if the schedule is bounded.
+    #[must_use]
+    pub const fn max_retries(&self) -> Option<usize> {
+        self.max_retries
+    }
+
+    /// Returns the delay before retry number `retry` (starting at zero), before jitter.
+    #[must_use]
+    pub fn delay(&self, retry: usize) -> Duration {
+        let step = u32::try_from(retry).unwrap_or(u32::MAX);
+
+        let delay = match self.strategy {
+            Strategy::Constant => Some(self.initial),
+            Strategy::Linear { increment } => increment
+                .checked_mul(step)
+                .and_then(|growth| self.initial.checked_add(growth)),
+            Strategy::Exponential { multiplier } => multiplier
+                .checked_pow(step)
+                .and_then(|factor| self.initial.checked_mul(factor)),
+        }
+        .unwrap_or(Duration::MAX);
+
+        self.max_delay
+            .map_or(delay, |max_delay| delay.min(max_delay))
+    }
+
+    /// Returns an iterator over the delays of this schedule, using `rng` for jitter.
+    pub const fn delays<R>(&self, rng: R) -> Delays<R>
+    where
+        R: RandomNumberGenerator,
+    {
+        Delays {
+            backoff: *self,
+            rng,
+            retry: 0,
+        }
+    }
+}
+
+/// An iterator over the delays of a [`Backoff`] schedule.
+///
+/// This struct is created by [`Backoff::delays`].
+#[derive(Debug, Clone)]
+pub struct Delays<R> {
+    backoff: Backoff,
+    rng: R,
+    retry: usize,
+}
+
+impl<R> Iterator for Delays<R>
+where
+    R: RandomNumberGenerator,
+{
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self
+            .backoff
+            .max_retries
+            .is_some_and(|max_retries| self.retry >= max_retries)
+        {
+            return None;
+        }
+
+        let delay = self.backoff.delay(self.retry);
+        self.retry += 1;
+
+        Some(match self.backoff.jitter {
+            Jitter::None => delay,
+            Jitter::Full => random_up_to(&mut self.rng, delay),
+            Jitter::Equal => {
+                let half = delay / 2;
+                half + random_up_to(&mut self.rng, delay.saturating_sub(half))
+            }
+        })
+    }
+}
+
+fn random_up_to<R>(rng: &mut R, limit: Duration) -> Duration
+where
+    R: RandomNumberGenerator,
+{
+    let nanos = u64::try_from(limit.as_nanos()).unwrap_or(u64::MAX);
+
+    Duration::from_nanos(rng.next_bounded(nanos.saturating_add(1).max(1)))
+}
+
+/// Pairs a schedule of delays with a function that waits for each of them.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::retry::{Backoff, RetryPolicy, retry};
+/// use libx::random::SplitMix64;
+///
+/// let mut waited = Duration::ZERO;
+/// let policy = RetryPolicy::new(
+///     Backoff::constant(Duration::from_millis(10))
+///         .with_max_retries(3)
+///         .delays(SplitMix64::new(0)),
+///     |delay| waited += delay,
+/// );
+///
+/// let mut attempts = 0;
+/// let result: Result<u32, &str> = retry(policy, |_| {
+///     attempts += 1;
+///     if attempts < 3 { Err("busy") } else { Ok(attempts) }
+/// });
+///
+/// assert_eq!(result, Ok(3));
+/// assert_eq!(waited, Duration::from_millis(20));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy<I, S> {
+    delays: I,
+    sleep: S,
+}
+
+impl<I, S> RetryPolicy<I, S>
+where
+    I: Iterator<Item = Duration>,
+    S: FnMut(Duration),
+{
+    /// Creates a policy that waits for each delay in `delays` by calling `sleep`.
+    ///
+    /// The operation is attempted once more than the number of delays.
+    pub fn new<D>(delays: D, sleep: S) -> Self
+    where
+        D: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            delays: delays.into_iter(),
+            sleep,
+        }
+    }
+}
+
+/// Runs `op` until it succeeds or `policy` runs out of delays.
+///
+/// The closure receives the zero-based attempt number. Between failed attempts, the policy
+/// waits for the next delay of its schedule.
+///
+/// # Errors
+///
+/// Returns the error of the last attempt if every attempt fails.
+pub fn retry<T, E, I, S, F>(mut policy: RetryPolicy<I, S>, mut op: F) -> Result<T, E>
+where
+    I: Iterator<Item = Duration>,
+    S: FnMut(Duration),
+    F: FnMut(usize) -> Result<T, E>,
+{
+    let mut attempt = 0;
+
+    loop {
+        match op(attempt) {
+            Ok(value) => return Ok(value),
+            Err(error) => match policy.delays.next() {
+                Some(delay) => (policy.sleep)(delay),
+                None => return Err(error),
+            },
+        }
+
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn test_constant_backoff() {
+        let delays: Vec<_> = Backoff::constant(Duration::from_secs(1))
+            .with_max_retries(3)
+            .delays(SplitMix64::new(0))
+            .collect();
+
+        assert_eq!(delays, [Duration::from_secs(1); 3]);
+    }
+
+    #[test]
+    fn test_linear_backoff() {
+        let backoff = Backoff::linear(Duration::from_millis(100), Duration::from_millis(50));
+
+        assert_eq!(backoff.delay(0), Duration::from_millis(100));
+        assert_eq!(backoff.delay(4), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_exponential_backoff_saturates() {
+        let backoff = Backoff::exponential(Duration::from_secs(1), 10);
+
+        assert_eq!(backoff.delay(3), Duration::from_secs(1_000));
+        assert_eq!(backoff.delay(1_000), Duration::MAX);
+    }
+
+    #[test]
+    fn test_unbounded_schedule() {
+        let backoff = Backoff::constant(Duration::from_millis(1));
+
+        assert_eq!(backoff.max_retries(), None);
+        assert_eq!(backoff.delays(SplitMix64::new(0)).take(100).count(), 100);
+    }
+
+    #[test]
+    fn test_full_jitter_within_bounds() {
+        let backoff = Backoff::exponential(Duration::from_millis(100), 2)
+            .with_max_retries(8)
+            .with_jitter(Jitter::Full);
+
+        for (retry, delay) in backoff.delays(SplitMix64::new(3)).enumerate() {
+            assert!(delay <= backoff.delay(retry));
+        }
+    }
+
+    #[test]
+    fn test_equal_jitter_keeps_half() {
+        let backoff = Backoff::constant(Duration::from_millis(100))
+            .with_max_retries(50)
+            .with_jitter(Jitter::Equal);
+
+        for delay in backoff.delays(SplitMix64::new(11)) {
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_retry_returns_last_error() {
+        let mut sleeps = 0;
+        let policy = RetryPolicy::new(
+            Backoff::constant(Duration::ZERO)
+                .with_max_retries(2)
+                .delays(SplitMix64::new(0)),
+            |_| sleeps += 1,
+        );
+
+        let result: Result<(), usize> = retry(policy, Err);
+
+        assert_eq!(result, Err(2));
+        assert_eq!(sleeps, 2);
+    }
+}
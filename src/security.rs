@@ -0,0 +1,455 @@
+//! Password and passphrase generation, key derivation, and handling of secrets.
+//!
+//! [`generate_password`] draws characters uniformly from a chosen set of character
+//! classes, and [`generate_passphrase`] draws words from a [`Wordlist`], in the manner of
+//! diceware. Both take their randomness from a [`RandomNumberGenerator`], whose bounded
+//! draws use rejection sampling so that no character or word is favoured over another.
+//! A secret is only as unpredictable as the generator, so use one seeded from a secure
+//! source.
+//!
+//! The entropy functions report how many bits of randomness a generated secret holds,
+//! and [`estimate_entropy`] gives a rough upper bound for a secret chosen by a person.
+//!
+//! The [`kdf`] module derives keys from passwords and other secrets. Once a secret
+//! exists, [`ct_eq`] compares it without leaking where it differs through timing, and
+//! the [`zeroize`] module wipes it from memory when it is no longer needed.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{
+//!     random::SplitMix64,
+//!     security::{CharacterSets, Wordlist, generate_passphrase, generate_password},
+//! };
+//!
+//! let mut rng = SplitMix64::new(2024);
+//!
+//! let sets = CharacterSets::LOWERCASE | CharacterSets::DIGITS;
+//! let password = generate_password(16, sets, &mut rng);
+//! assert_eq!(password.len(), 16);
+//! assert!(password.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit()));
+//!
+//! let passphrase = generate_passphrase(&Wordlist::default(), 5, "-", &mut rng);
+//! assert_eq!(passphrase.split('-').count(), 5);
+//! ```
+
+pub mod kdf;
+pub mod zeroize;
+
+pub use kdf::{hkdf_expand, hkdf_extract, pbkdf2};
+pub use zeroize::{Zeroize, Zeroizing};
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{bitmask, errors::ParseError, option_set::OptionSet, random::RandomNumberGenerator};
+
+bitmask! {
+    /// The classes of ASCII characters a password is drawn from.
+    pub struct CharacterSets: u8 {
+        /// The letters `a` to `z`.
+        const LOWERCASE = 1 << 0;
+
+        /// The letters `A` to `Z`.
+        const UPPERCASE = 1 << 1;
+
+        /// The digits `0` to `9`.
+        const DIGITS = 1 << 2;
+
+        /// The 32 printable ASCII punctuation characters.
+        const SYMBOLS = 1 << 3;
+    }
+}
+
+/// The characters of each class, in the order of the flags.
+const CLASSES: [(CharacterSets, &str); 4] = [
+    (CharacterSets::LOWERCASE, "abcdefghijklmnopqrstuvwxyz"),
+    (CharacterSets::UPPERCASE, "ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+    (CharacterSets::DIGITS, "0123456789"),
+    (CharacterSets::SYMBOLS, "!\"#$%&'()*+,-./:;<=>?@[\\]^_`{|}~"),
+];
+
+impl CharacterSets {
+    /// Returns the characters of every class in the set.
+    #[must_use]
+    pub fn characters(self) -> String {
+        CLASSES
+            .iter()
+            .filter(|&&(class, _)| self.contains(class))
+            .map(|&(_, characters)| characters)
+            .collect()
+    }
+}
+
+/// Returns the base-2 logarithm of `value`, which must not be zero.
+fn log2(value: u64) -> f64 {
+    let whole = value.ilog2();
+
+    #[allow(clippy::cast_precision_loss)] // Only the top bits matter for the fraction.
+    let mut mantissa = value as f64 / (1_u64 << whole) as f64;
+    let mut result = f64::from(whole);
+    let mut bit = 0.5;
+
+    // Squaring the mantissa doubles its logarithm, so each step yields one more bit.
+    for _ in 0..f64::MANTISSA_DIGITS {
+        mantissa *= mantissa;
+
+        if mantissa >= 2.0 {
+            mantissa /= 2.0;
+            result += bit;
+        }
+
+        bit /= 2.0;
+    }
+
+    result
+}
+
+/// Returns the bits of entropy in `count` independent, uniform choices among `options`.
+fn entropy(count: usize, options: usize) -> f64 {
+    if options == 0 {
+        return 0.0;
+    }
+
+    #[allow(clippy::cast_precision_loss)] // Counts are far below 2^53.
+    let count = count as f64;
+    count * log2(options as u64)
+}
+
+/// Returns a password of `length` characters drawn uniformly and independently from
+/// `sets`.
+///
+/// Every character is drawn from the union of the classes, so a short password may
+/// happen to miss one of them.
+///
+/// # Panics
+///
+/// Panics if `sets` is empty.
+pub fn generate_password<R>(length: usize, sets: CharacterSets, rng: &mut R) -> String
+where
+    R: RandomNumberGenerator + ?Sized,
+{
+    let characters = sets.characters();
+    let characters = characters.as_bytes();
+    assert!(
+        !characters.is_empty(),
+        "at least one character set is needed"
+    );
+
+    (0..length)
+        .map(|_| {
+            #[allow(clippy::cast_possible_truncation)] // The index is below the set size.
+            let index = rng.next_bounded(characters.len() as u64) as usize;
+            char::from(characters[index])
+        })
+        .collect()
+}
+
+/// Returns the bits of entropy in a password that [`generate_password`] makes.
+///
+/// # Examples
+///
+/// ```
+/// use libx::security::{CharacterSets, password_entropy};
+///
+/// let bits = password_entropy(12, CharacterSets::LOWERCASE | CharacterSets::UPPERCASE);
+/// assert!((bits - 68.41).abs() < 0.01);
+/// ```
+#[must_use]
+pub fn password_entropy(length: usize, sets: CharacterSets) -> f64 {
+    entropy(length, sets.characters().len())
+}
+
+/// Returns a rough upper bound on the bits of entropy in `password`.
+///
+/// The estimate assumes every character was drawn uniformly from all of the classes the
+/// password uses, counting characters outside ASCII as a class of 128 more. People choose
+/// far less randomly than that, so the real strength of a memorable password is lower.
+///
+/// # Examples
+///
+/// ```
+/// use libx::security::estimate_entropy;
+///
+/// assert!(estimate_entropy("correct horse") < estimate_entropy("c0rrect H0rse!"));
+/// assert_eq!(estimate_entropy(""), 0.0);
+/// ```
+#[must_use]
+pub fn estimate_entropy(password: &str) -> f64 {
+    let mut sets = CharacterSets::empty();
+    let mut other = false;
+
+    for character in password.chars() {
+        let class = CLASSES
+            .iter()
+            .find(|(_, characters)| characters.contains(character))
+            .map(|&(class, _)| class);
+
+        match class {
+            Some(class) => {
+                sets.insert(class);
+            }
+            // A space is counted with the symbols.
+            None if character == ' ' => {
+                sets.insert(CharacterSets::SYMBOLS);
+            }
+            None => other = true,
+        }
+    }
+
+    let pool = sets.characters().len() + if other { 128 } else { 0 };
+    entropy(password.chars().count(), pool)
+}
+
+/// The words of the built-in [`Wordlist`]: 256 short, common English words.
+const WORDS: [&str; 256] = [
+    "acid", "actor", "adobe", "agent", "alarm", "album", "alley", "angle", "ankle", "apple",
+    "apron", "arena", "armor", "atlas", "attic", "audio", "award", "bacon", "bagel", "baker",
+    "bamboo", "banjo", "barn", "basin", "beach", "beard", "bench", "berry", "bison", "blade",
+    "blimp", "bloom", "board", "bonus", "boost", "brain", "bread", "brick", "broom", "brush",
+    "bucket", "buddy", "cabin", "cable", "cactus", "camel", "candy", "canoe", "cargo", "carpet",
+    "cedar", "chalk", "charm", "chess", "chorus", "cider", "cinema", "circus", "clamp", "cliff",
+    "clock", "cloud", "clover", "coach", "cobra", "comet", "coral", "couch", "cover", "crane",
+    "crayon", "crown", "cube", "daisy", "dance", "delta", "denim", "desert", "diary", "dingo",
+    "disco", "dock", "dragon", "drum", "eagle", "easel", "echo", "elbow", "ember", "engine",
+    "envoy", "fable", "falcon", "fence", "fiber", "field", "flag", "flame", "flute", "focus",
+    "fossil", "frost", "fudge", "galaxy", "garden", "gecko", "ginger", "glove", "goose", "grape",
+    "gravel", "guitar", "hammer", "harbor", "harp", "hazel", "helmet", "hinge", "honey", "hornet",
+    "hotel", "igloo", "index", "island", "ivory", "jacket", "jaguar", "jelly", "jewel", "kayak",
+    "kettle", "kiosk", "kitten", "koala", "ladder", "lemon", "lens", "lilac", "limb", "linen",
+    "lizard", "locket", "lotus", "lunar", "magnet", "mango", "marble", "meadow", "melon", "meteor",
+    "mitten", "monkey", "mosaic", "motor", "muffin", "museum", "napkin", "nectar", "nest",
+    "noodle", "nugget", "oasis", "ocean", "olive", "orbit", "orchid", "otter", "oven", "owl",
+    "paddle", "panda", "parrot", "pasta", "peach", "pebble", "pencil", "piano", "pickle", "pigeon",
+    "pillow", "pilot", "planet", "plum", "pocket", "polar", "pony", "potato", "puzzle", "quail",
+    "quartz", "quiver", "rabbit", "radar", "raft", "rain", "ranch", "raven", "reef", "ribbon",
+    "robin", "rocket", "rodeo", "saddle", "salad", "salmon", "satin", "scarf", "scout", "shadow",
+    "shell", "silver", "siren", "skate", "sketch", "slate", "sleigh", "socket", "sofa", "spider",
+    "spoon", "squid", "stamp", "stone", "storm", "sugar", "summit", "sunset", "swan", "tango",
+    "teapot", "temple", "tiger", "timber", "toast", "topaz", "torch", "tower", "tulip", "tundra",
+    "turtle", "valley", "velvet", "violin", "vortex", "wagon", "walrus", "wasp", "water", "whale",
+    "willow", "window", "wombat", "yacht", "yogurt", "zebra", "zephyr",
+];
+
+/// A list of distinct words for passphrases.
+///
+/// The [`Default`] list holds 256 short English words, giving 8 bits of entropy per word.
+/// Longer lists, such as the diceware lists of 7776 words, can be embedded with
+/// [`include_str!`] and read with [`parse`](Self::parse).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Wordlist<'a> {
+    words: Vec<&'a str>,
+}
+
+impl<'a> Wordlist<'a> {
+    /// Reads a wordlist with one word per line.
+    ///
+    /// Surrounding whitespace and blank lines are ignored, and so is a leading column of
+    /// dice rolls as in the diceware lists, like `11111\tabacus`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Empty`] if there are no words, and
+    /// [`ParseError::InvalidSyntax`] if a word appears twice, since a repeated word would
+    /// make some passphrases likelier than others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::security::Wordlist;
+    ///
+    /// let list = Wordlist::parse("11111\tabacus\n11112\tabdomen\n\n11113\tabdominal\n")?;
+    ///
+    /// assert_eq!(list.words(), ["abacus", "abdomen", "abdominal"]);
+    /// # Ok::<(), libx::errors::ParseError>(())
+    /// ```
+    pub fn parse(text: &'a str) -> Result<Self, ParseError> {
+        let mut words: Vec<&str> = text
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim();
+                let word = match line.split_once(char::is_whitespace) {
+                    Some((rolls, word)) if rolls.bytes().all(|b| b.is_ascii_digit()) => {
+                        word.trim_start()
+                    }
+                    _ => line,
+                };
+
+                (!word.is_empty()).then_some(word)
+            })
+            .collect();
+
+        if words.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut sorted = words.clone();
+        sorted.sort_unstable();
+
+        if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        words.shrink_to_fit();
+        Ok(Self { words })
+    }
+
+    /// Returns the words, in the order they were listed.
+    #[must_use]
+    pub fn words(&self) -> &[&'a str] {
+        &self.words
+    }
+
+    /// Returns the number of words.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns `true` if the list has no words, which a parsed list never is.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns the bits of entropy in a passphrase of `word_count` words from this list.
+    #[must_use]
+    pub fn entropy(&self, word_count: usize) -> f64 {
+        entropy(word_count, self.words.len())
+    }
+}
+
+impl Default for Wordlist<'static> {
+    fn default() -> Self {
+        Self {
+            words: WORDS.to_vec(),
+        }
+    }
+}
+
+/// Returns `word_count` words drawn uniformly and independently from `wordlist`, joined
+/// by `separator`.
+///
+/// # Panics
+///
+/// Panics if `wordlist` is empty.
+pub fn generate_passphrase<R>(
+    wordlist: &Wordlist<'_>,
+    word_count: usize,
+    separator: &str,
+    rng: &mut R,
+) -> String
+where
+    R: RandomNumberGenerator + ?Sized,
+{
+    assert!(!wordlist.is_empty(), "the wordlist has no words");
+
+    let mut passphrase = String::new();
+
+    for index in 0..word_count {
+        if index > 0 {
+            passphrase.push_str(separator);
+        }
+
+        #[allow(clippy::cast_possible_truncation)] // The index is below the list length.
+        let word = rng.next_bounded(wordlist.len() as u64) as usize;
+        passphrase.push_str(wordlist.words[word]);
+    }
+
+    passphrase
+}
+
+/// Returns whether `a` and `b` hold the same bytes, taking a time that depends only on
+/// their lengths.
+///
+/// Comparing a secret such as a MAC tag with `==` stops at the first difference, so the
+/// time it takes tells an attacker how many leading bytes of a guess are right. This
+/// function reads every byte regardless. Slices of different lengths are unequal, and
+/// the lengths are not kept secret.
+///
+/// # Examples
+///
+/// ```
+/// use libx::security::ct_eq;
+///
+/// assert!(ct_eq(b"tag", b"tag"));
+/// assert!(!ct_eq(b"tag", b"tab"));
+/// assert!(!ct_eq(b"tag", b"tags"));
+/// ```
+#[must_use]
+pub fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let difference = a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y));
+    core::hint::black_box(difference) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn test_log2() {
+        assert!(log2(1).abs() < 1e-12);
+        assert!((log2(1024) - 10.0).abs() < 1e-12);
+        assert!((log2(10) - core::f64::consts::LOG2_10).abs() < 1e-12);
+        assert!((log2(7776) - 12.924_812_503_605_78).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_generate_password_uses_every_selected_character() {
+        let mut rng = SplitMix64::new(11);
+        let password = generate_password(2000, CharacterSets::DIGITS, &mut rng);
+
+        assert!(password.bytes().all(|b| b.is_ascii_digit()));
+        assert!((b'0'..=b'9').all(|digit| password.as_bytes().contains(&digit)));
+
+        let all = CharacterSets::all().characters();
+        assert_eq!(all.len(), 94);
+        assert!(all.bytes().all(|b| b.is_ascii_graphic()));
+    }
+
+    #[test]
+    #[should_panic = "at least one character set is needed"]
+    fn test_generate_password_without_sets() {
+        let _ = generate_password(8, CharacterSets::empty(), &mut SplitMix64::new(0));
+    }
+
+    #[test]
+    fn test_entropy() {
+        assert!((password_entropy(10, CharacterSets::DIGITS) - 33.219).abs() < 0.001);
+        assert!((Wordlist::default().entropy(6) - 48.0).abs() < 1e-9);
+        assert!((estimate_entropy("abc") - 3.0 * log2(26)).abs() < 1e-9);
+        assert!((estimate_entropy("a b") - 3.0 * log2(58)).abs() < 1e-9);
+        assert!((estimate_entropy("é1") - 2.0 * log2(138)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(&[], &[]));
+        assert!(ct_eq(&[0, 255, 7], &[0, 255, 7]));
+        assert!(!ct_eq(&[0, 255, 7], &[0, 255, 6]));
+        assert!(!ct_eq(&[1, 255, 7], &[0, 255, 7]));
+        assert!(!ct_eq(&[0], &[0, 0]));
+    }
+
+    #[test]
+    fn test_wordlist() {
+        let default = Wordlist::default();
+        assert_eq!(default.len(), 256);
+        assert!(Wordlist::parse(&WORDS.join("\n")).is_ok());
+
+        assert_eq!(Wordlist::parse(" \n\n"), Err(ParseError::Empty));
+        assert_eq!(Wordlist::parse("a\nb\na"), Err(ParseError::InvalidSyntax));
+
+        let list = Wordlist::parse("one\ntwo").unwrap_or_default();
+        let passphrase = generate_passphrase(&list, 3, " ", &mut SplitMix64::new(3));
+        assert!(
+            passphrase
+                .split(' ')
+                .all(|word| word == "one" || word == "two")
+        );
+    }
+}
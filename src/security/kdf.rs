@@ -0,0 +1,204 @@
+//! Key derivation functions.
+//!
+//! [`pbkdf2`] stretches a password into a key, iterating HMAC so that every guess costs an
+//! attacker as much as it costs the legitimate user. [`hkdf_extract`] and [`hkdf_expand`]
+//! turn key material that is already strong but unevenly distributed, such as the
+//! result of a key exchange, into as many independent keys as are needed. Both work with
+//! any [`Digest`].
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{
+//!     hashing::sha2::Sha256,
+//!     security::{hkdf_expand, hkdf_extract, pbkdf2},
+//! };
+//!
+//! let mut key = [0; 32];
+//! pbkdf2::<Sha256>(b"correct horse", b"per-user salt", 10_000, &mut key);
+//!
+//! let pseudorandom_key = hkdf_extract::<Sha256>(b"salt", &key);
+//! let mut encryption_key = [0; 16];
+//! let mut signing_key = [0; 16];
+//! hkdf_expand::<Sha256>(&pseudorandom_key, b"encryption", &mut encryption_key);
+//! hkdf_expand::<Sha256>(&pseudorandom_key, b"signing", &mut signing_key);
+//!
+//! assert_ne!(encryption_key, signing_key);
+//! ```
+
+use super::Zeroize;
+use crate::hashing::{Digest, hmac::Hmac};
+
+/// Fills `out` with a key derived from `password` and `salt` by PBKDF2 (RFC 8018) with
+/// HMAC over `D`.
+///
+/// The salt should be unique to each password, and `iterations` as high as the slowest
+/// acceptable derivation allows; current guidance for SHA-256 is in the hundreds of
+/// thousands.
+///
+/// # Panics
+///
+/// Panics if `iterations` is zero.
+pub fn pbkdf2<D: Digest>(password: &[u8], salt: &[u8], iterations: u32, out: &mut [u8]) {
+    assert!(iterations > 0, "PBKDF2 needs at least one iteration");
+
+    let keyed = Hmac::<D>::new(password);
+
+    for (chunk, block_index) in out.chunks_mut(D::OUTPUT_SIZE).zip(1_u32..) {
+        let mut hmac = keyed.clone();
+        hmac.update(salt);
+        hmac.update(&block_index.to_be_bytes());
+
+        let mut link = hmac.finish();
+        let mut block = link;
+
+        for _ in 1..iterations {
+            let mut hmac = keyed.clone();
+            hmac.update(link.as_ref());
+            link = hmac.finish();
+
+            for (byte, link_byte) in block.as_mut().iter_mut().zip(link.as_ref()) {
+                *byte ^= link_byte;
+            }
+        }
+
+        chunk.copy_from_slice(&block.as_ref()[..chunk.len()]);
+        link.as_mut().zeroize();
+        block.as_mut().zeroize();
+    }
+}
+
+/// Returns the pseudorandom key that HKDF (RFC 5869) extracts from `input_key_material`
+/// with `salt`, using HMAC over `D`.
+///
+/// An empty salt is the same as a salt of [`OUTPUT_SIZE`](Digest::OUTPUT_SIZE) zeros.
+#[must_use]
+pub fn hkdf_extract<D: Digest>(salt: &[u8], input_key_material: &[u8]) -> D::Output {
+    Hmac::<D>::mac(salt, input_key_material)
+}
+
+/// Fills `out` with key material that HKDF (RFC 5869) expands from `pseudorandom_key` for
+/// the purpose described by `info`, using HMAC over `D`.
+///
+/// Different `info` give independent keys from the same pseudorandom key, which should
+/// come from [`hkdf_extract`] unless it is already uniformly random.
+///
+/// # Panics
+///
+/// Panics if `out` is longer than 255 digests of `D`.
+pub fn hkdf_expand<D: Digest>(pseudorandom_key: &[u8], info: &[u8], out: &mut [u8]) {
+    assert!(
+        out.len() <= 255 * D::OUTPUT_SIZE,
+        "HKDF produces at most 255 digests of output"
+    );
+
+    let keyed = Hmac::<D>::new(pseudorandom_key);
+    let mut previous: Option<D::Output> = None;
+
+    for (chunk, counter) in out.chunks_mut(D::OUTPUT_SIZE).zip(1_u8..) {
+        let mut hmac = keyed.clone();
+
+        if let Some(previous) = &previous {
+            hmac.update(previous.as_ref());
+        }
+
+        hmac.update(info);
+        hmac.update(&[counter]);
+
+        let block = hmac.finish();
+        chunk.copy_from_slice(&block.as_ref()[..chunk.len()]);
+        previous = Some(block);
+    }
+
+    if let Some(previous) = &mut previous {
+        previous.as_mut().zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::hashing::sha2::Sha256;
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&text[index..index + 2], 16).unwrap_or_default())
+            .collect()
+    }
+
+    #[test]
+    fn test_pbkdf2_rfc_7914_vectors() {
+        let mut key = [0; 64];
+
+        pbkdf2::<Sha256>(b"passwd", b"salt", 1, &mut key);
+        assert_eq!(
+            key[..],
+            hex(concat!(
+                "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc",
+                "49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783"
+            ))
+        );
+
+        let mut short = [0; 20];
+        pbkdf2::<Sha256>(b"passwd", b"salt", 1, &mut short);
+        assert_eq!(short, key[..20]);
+
+        pbkdf2::<Sha256>(b"Password", b"NaCl", 80_000, &mut key);
+        assert_eq!(
+            key[..],
+            hex(concat!(
+                "4ddcd8f60b98be21830cee5ef22701f9641a4418d04c0414aeff08876b34ab56",
+                "a1d425a1225833549adb841b51c9b3176a272bdebba1d078478f62b397f33c8d"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_hkdf_rfc_5869_vectors() {
+        let input_key_material = [0x0b; 22];
+        let salt: Vec<u8> = (0x00..=0x0c).collect();
+        let info: Vec<u8> = (0xf0..=0xf9).collect();
+
+        let pseudorandom_key = hkdf_extract::<Sha256>(&salt, &input_key_material);
+        assert_eq!(
+            pseudorandom_key[..],
+            hex("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5")
+        );
+
+        let mut okm = [0; 42];
+        hkdf_expand::<Sha256>(&pseudorandom_key, &info, &mut okm);
+        assert_eq!(
+            okm[..],
+            hex(concat!(
+                "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf",
+                "34007208d5b887185865"
+            ))
+        );
+
+        // Test case 3, with an empty salt and info.
+        let pseudorandom_key = hkdf_extract::<Sha256>(&[], &input_key_material);
+        assert_eq!(
+            pseudorandom_key[..],
+            hex("19ef24a32c717b167f33a91d6f648bdf96596776afdb6377ac434c1c293ccb04")
+        );
+
+        hkdf_expand::<Sha256>(&pseudorandom_key, &[], &mut okm);
+        assert_eq!(
+            okm[..],
+            hex(concat!(
+                "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d",
+                "9d201395faa4b61a96c8"
+            ))
+        );
+    }
+
+    #[test]
+    #[should_panic = "HKDF produces at most 255 digests of output"]
+    fn test_hkdf_expand_length_limit() {
+        let mut okm = [0; 255 * 32 + 1];
+        hkdf_expand::<Sha256>(&[0; 32], &[], &mut okm);
+    }
+}
@@ -0,0 +1,222 @@
+//! Wiping secrets from memory.
+//!
+//! Dropping a value or clearing a buffer leaves its bytes in memory until something else
+//! reuses it, and the compiler may skip a plain overwrite of memory that is never read
+//! again. [`Zeroize`] overwrites a value with volatile writes that are not optimised
+//! away, and [`Zeroizing`] does so automatically when it is dropped.
+//!
+//! The trait is implemented for the integer types, arrays, slices, [`Vec`], [`String`],
+//! [`Box`], and the collections of this crate. Copies of a secret that were made before
+//! it was wiped, such as by moving it to a new place or by growing a vector, are out of
+//! reach, so key material should be kept in a buffer of its final size.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::security::{Zeroize, Zeroizing};
+//!
+//! let mut key = [0x2a_u8; 32];
+//! key.zeroize();
+//! assert_eq!(key, [0; 32]);
+//!
+//! let password = Zeroizing::new(String::from("hunter2"));
+//! assert_eq!(password.len(), 7);
+//! // The text is wiped here, when `password` goes out of scope.
+//! ```
+
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::{
+    fmt,
+    mem::size_of_val,
+    ops::{Deref, DerefMut},
+    ptr,
+    sync::atomic::{Ordering, compiler_fence},
+};
+
+/// A value that can be overwritten with zeros in a way the compiler does not remove.
+pub trait Zeroize {
+    /// Overwrites the value with zeros. Collections are also left empty.
+    fn zeroize(&mut self);
+}
+
+macro_rules! impl_zeroize_for_integers {
+    ($($ty:ty),*) => {
+        $(
+            impl Zeroize for $ty {
+                fn zeroize(&mut self) {
+                    // SAFETY: `self` is a valid, aligned and exclusive reference.
+                    unsafe { ptr::write_volatile(self, 0) };
+                    compiler_fence(Ordering::SeqCst);
+                }
+            }
+        )*
+    };
+}
+
+impl_zeroize_for_integers!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl<T: Zeroize> Zeroize for [T] {
+    fn zeroize(&mut self) {
+        for value in self {
+            value.zeroize();
+        }
+    }
+}
+
+impl<T: Zeroize, const N: usize> Zeroize for [T; N] {
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+    }
+}
+
+impl<T: Zeroize> Zeroize for Vec<T> {
+    /// Zeroizes and drops the elements, then overwrites the whole allocation, including
+    /// the spare capacity that earlier elements may have used.
+    fn zeroize(&mut self) {
+        self.as_mut_slice().zeroize();
+        self.clear();
+
+        let spare = self.spare_capacity_mut();
+        let bytes = spare.as_mut_ptr().cast::<u8>();
+
+        for offset in 0..size_of_val(spare) {
+            // SAFETY: the offset is within the spare capacity, which the vector owns, and
+            // any bytes are valid for `MaybeUninit`.
+            unsafe { ptr::write_volatile(bytes.add(offset), 0) };
+        }
+
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl Zeroize for String {
+    fn zeroize(&mut self) {
+        // SAFETY: zeroizing leaves the vector empty, which is valid UTF-8.
+        unsafe { self.as_mut_vec() }.zeroize();
+    }
+}
+
+impl<T: Zeroize + ?Sized> Zeroize for Box<T> {
+    fn zeroize(&mut self) {
+        (**self).zeroize();
+    }
+}
+
+/// A value that is zeroized when it is dropped.
+///
+/// The wrapper dereferences to the value, and its [`Debug`](fmt::Debug) output does not
+/// show it.
+#[derive(Default, Clone)]
+pub struct Zeroizing<T: Zeroize>(T);
+
+impl<T: Zeroize> Zeroizing<T> {
+    /// Wraps `value` so that it is zeroized when dropped.
+    #[must_use]
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize> Deref for Zeroizing<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Zeroize> DerefMut for Zeroizing<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize> From<T> for Zeroizing<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Zeroize> Drop for Zeroizing<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Zeroizing<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Zeroizing(..)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, vec};
+    use core::cell::Cell;
+
+    use super::*;
+    use crate::collections::{
+        array_string::ArrayString, list::doubly_linked::List, stack::linked_list::Stack,
+    };
+
+    /// Counts how often values are zeroized.
+    #[derive(Clone)]
+    struct Tracked<'a>(&'a Cell<usize>);
+
+    impl Zeroize for Tracked<'_> {
+        fn zeroize(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_vec_wipes_spare_capacity() {
+        let mut bytes = vec![0xff_u8; 16];
+        bytes.truncate(4);
+        bytes.zeroize();
+
+        assert!(bytes.is_empty());
+        // SAFETY: zeroizing initialised every byte of the allocation.
+        let allocation = unsafe { core::slice::from_raw_parts(bytes.as_ptr(), 16) };
+        assert_eq!(allocation, [0; 16]);
+
+        let mut text = String::from("secret");
+        text.zeroize();
+        assert!(text.is_empty());
+    }
+
+    #[test]
+    fn test_collections_zeroize_every_element() {
+        let count = Cell::new(0);
+
+        let mut list = List::new();
+        list.extend([Tracked(&count), Tracked(&count)]);
+        list.zeroize();
+        assert!(list.is_empty());
+
+        let mut stack = Stack::new();
+        stack.push(Tracked(&count));
+        stack.push(Tracked(&count));
+        stack.push(Tracked(&count));
+        stack.zeroize();
+        assert!(stack.is_empty());
+
+        assert_eq!(count.get(), 5);
+
+        let mut text = ArrayString::<8>::try_from("key").unwrap_or_default();
+        text.zeroize();
+        assert!(text.is_empty());
+    }
+
+    #[test]
+    fn test_zeroizing_wipes_on_drop() {
+        let count = Cell::new(0);
+        let secret = Zeroizing::new(Tracked(&count));
+
+        assert_eq!(format!("{secret:?}"), "Zeroizing(..)");
+        drop(secret);
+        assert_eq!(count.get(), 1);
+    }
+}
@@ -0,0 +1,269 @@
+//! Multi-key comparisons and dependency ordering.
+//!
+//! The [`sort_by_keys!`](crate::sort_by_keys) macro builds a comparator from a list of
+//! key extractors, each ascending or descending, like an SQL `ORDER BY` clause. The
+//! comparator works with any API that takes one, such as [`slice::sort_by`] or
+//! [`List::sort_by`](crate::collections::list::doubly_linked::List::sort_by).
+//! [`topological_sort`] orders items so that every item comes after its dependencies, and
+//! reports a cycle when there is no such order.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{sort::topological_sort, sort_by_keys};
+//!
+//! let mut scores = [("ada", 3), ("alan", 5), ("grace", 5)];
+//! scores.sort_by(sort_by_keys![desc |s: &(&str, i32)| s.1, asc |s: &(&str, i32)| s.0]);
+//! assert_eq!(scores, [("alan", 5), ("grace", 5), ("ada", 3)]);
+//!
+//! let order = topological_sort(["app", "net", "core"], |crate_name| match *crate_name {
+//!     "app" => vec!["net", "core"],
+//!     "net" => vec!["core"],
+//!     _ => vec![],
+//! });
+//! assert_eq!(order, Ok(vec!["core", "net", "app"]));
+//! ```
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet, btree_map::Entry},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Ordering;
+
+use crate::errors::CycleError;
+
+/// The direction in which a key is sorted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SortOrder {
+    /// Smaller keys come first.
+    #[default]
+    Ascending,
+
+    /// Larger keys come first.
+    Descending,
+}
+
+impl SortOrder {
+    /// Applies the direction to an ascending `ordering`.
+    #[must_use]
+    pub const fn apply(self, ordering: Ordering) -> Ordering {
+        match self {
+            Self::Ascending => ordering,
+            Self::Descending => ordering.reverse(),
+        }
+    }
+}
+
+/// Compares `a` and `b` by the key that `key` extracts, in the given `order`.
+///
+/// This is the building block of [`sort_by_keys!`](crate::sort_by_keys).
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+///
+/// use libx::sort::{SortOrder, compare_by_key};
+///
+/// let length = |word: &&str| word.len();
+/// assert_eq!(compare_by_key(SortOrder::Descending, length, &"a", &"bb"), Ordering::Greater);
+/// ```
+pub fn compare_by_key<T, K, F>(order: SortOrder, key: F, a: &T, b: &T) -> Ordering
+where
+    T: ?Sized,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    order.apply(key(a).cmp(&key(b)))
+}
+
+/// Builds a comparator from key extractors, each preceded by `asc` or `desc`.
+///
+/// Keys are compared in the order they are listed; later keys only break ties left by
+/// earlier ones. Extractors return owned keys, so borrowed fields are best returned as
+/// references with a `'static` lifetime or as copies.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{collections::list::doubly_linked::list, sort_by_keys};
+///
+/// struct Task {
+///     priority: u8,
+///     id: u32,
+/// }
+///
+/// let mut tasks = list![
+///     Task { priority: 1, id: 7 },
+///     Task { priority: 2, id: 9 },
+///     Task { priority: 2, id: 3 },
+/// ];
+///
+/// tasks.sort_by(sort_by_keys![desc |t: &Task| t.priority, asc |t: &Task| t.id]);
+///
+/// let ids: Vec<u32> = (0..tasks.len()).map(|i| tasks[i].id).collect();
+/// assert_eq!(ids, [3, 9, 7]);
+/// ```
+#[macro_export]
+macro_rules! sort_by_keys {
+    (@order asc) => {
+        $crate::sort::SortOrder::Ascending
+    };
+    (@order desc) => {
+        $crate::sort::SortOrder::Descending
+    };
+    ($($order:ident $key:expr),+ $(,)?) => {
+        |a, b| {
+            ::core::cmp::Ordering::Equal
+                $(.then_with(|| {
+                    $crate::sort::compare_by_key($crate::sort_by_keys!(@order $order), $key, a, b)
+                }))+
+        }
+    };
+}
+
+/// Orders `items` so that each comes after the items that `dependencies` returns for it.
+///
+/// Among the items that are ready at any point, the one listed first goes first, so the
+/// result is deterministic and keeps the input order where the dependencies allow it.
+/// Duplicate items are kept once, and dependencies that are not among `items` are
+/// assumed to be satisfied already.
+///
+/// # Errors
+///
+/// Returns a [`CycleError`] holding one cycle of items that depend on each other.
+///
+/// # Examples
+///
+/// ```
+/// use libx::sort::topological_sort;
+///
+/// // 1 and 2 depend on each other, and 3 depends on 1.
+/// let result = topological_sort([1, 2, 3], |&n| [if n == 3 { 1 } else { n % 2 + 1 }]);
+///
+/// assert_eq!(result.map_err(|error| error.cycle), Err(vec![1, 2]));
+/// ```
+pub fn topological_sort<T, I, F, D>(items: I, mut dependencies: F) -> Result<Vec<T>, CycleError<T>>
+where
+    T: Ord + Clone,
+    I: IntoIterator<Item = T>,
+    F: FnMut(&T) -> D,
+    D: IntoIterator<Item = T>,
+{
+    let mut nodes = Vec::new();
+    let mut indices = BTreeMap::new();
+
+    for item in items {
+        if let Entry::Vacant(entry) = indices.entry(item.clone()) {
+            entry.insert(nodes.len());
+            nodes.push(item);
+        }
+    }
+
+    let mut edges = vec![Vec::new(); nodes.len()];
+    let mut dependents = vec![Vec::new(); nodes.len()];
+    let mut unmet = vec![0_usize; nodes.len()];
+
+    for (index, node) in nodes.iter().enumerate() {
+        for dependency in dependencies(node) {
+            if let Some(&dependency) = indices.get(&dependency)
+                && !edges[index].contains(&dependency)
+            {
+                edges[index].push(dependency);
+                dependents[dependency].push(index);
+                unmet[index] += 1;
+            }
+        }
+    }
+
+    let mut ready: BTreeSet<usize> = (0..nodes.len()).filter(|&i| unmet[i] == 0).collect();
+    let mut order = Vec::with_capacity(nodes.len());
+
+    while let Some(index) = ready.pop_first() {
+        order.push(index);
+
+        for &dependent in &dependents[index] {
+            unmet[dependent] -= 1;
+
+            if unmet[dependent] == 0 {
+                ready.insert(dependent);
+            }
+        }
+    }
+
+    if order.len() == nodes.len() {
+        return Ok(order
+            .into_iter()
+            .map(|index| nodes[index].clone())
+            .collect());
+    }
+
+    // Every item left over waits on another left-over item, so following those waits
+    // from any of them must eventually revisit one.
+    let mut path = Vec::new();
+    let mut position = vec![None; nodes.len()];
+    let mut current = (0..nodes.len()).find(|&i| unmet[i] > 0);
+
+    while let Some(index) = current {
+        if let Some(start) = position[index] {
+            path.drain(..start);
+            break;
+        }
+
+        position[index] = Some(path.len());
+        path.push(index);
+        current = edges[index].iter().copied().find(|&i| unmet[i] > 0);
+    }
+
+    Err(CycleError {
+        cycle: path.into_iter().map(|index| nodes[index].clone()).collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_sort_by_keys_breaks_ties() {
+        let mut values = [(1, 'b'), (2, 'a'), (1, 'a'), (2, 'b')];
+
+        values.sort_by(crate::sort_by_keys![
+            asc |v: &(i32, char)| v.0,
+            desc |v: &(i32, char)| v.1,
+        ]);
+        assert_eq!(values, [(1, 'b'), (1, 'a'), (2, 'b'), (2, 'a')]);
+    }
+
+    #[test]
+    fn test_topological_sort_is_stable() {
+        let order = topological_sort(['d', 'c', 'b', 'a', 'c'], |&item| {
+            if item == 'a' { vec!['b', 'z'] } else { vec![] }
+        });
+
+        assert_eq!(order, Ok(vec!['d', 'c', 'b', 'a']));
+    }
+
+    #[test]
+    fn test_topological_sort_reports_cycle() {
+        let result = topological_sort(0..5, |&n| match n {
+            1 => vec![2],
+            2 => vec![3],
+            3 => vec![1, 0],
+            _ => vec![],
+        });
+
+        assert_eq!(
+            result,
+            Err(CycleError {
+                cycle: vec![1, 2, 3]
+            })
+        );
+
+        let result = topological_sort([7], |&n| [n]);
+        assert_eq!(result, Err(CycleError { cycle: vec![7] }));
+    }
+}
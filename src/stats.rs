@@ -0,0 +1,114 @@
+//! Internal performance counters.
+//!
+//! Enabled by the `stats` feature, these counters record how often the collections touch
+//! the allocator so that benchmarks and regression tests can assert on allocation
+//! behaviour rather than only on wall-clock time. Counters are process-wide and updated
+//! with relaxed atomics, so they are cheap but only meaningful when read from a single
+//! benchmark or test at a time.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+static NODE_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static NODE_DEALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// A point-in-time copy of the collection counters.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{collections::list::doubly_linked::List, stats};
+///
+/// let before = stats::snapshot();
+///
+/// let mut list = List::new();
+/// list.push_back(1);
+/// list.push_back(2);
+///
+/// let delta = stats::snapshot().since(&before);
+/// assert!(delta.node_allocations >= 2);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Snapshot {
+    /// The number of linked-list nodes allocated.
+    pub node_allocations: usize,
+
+    /// The number of linked-list nodes released.
+    pub node_deallocations: usize,
+}
+
+impl Snapshot {
+    /// Returns the counter increments between `earlier` and `self`.
+    #[must_use]
+    pub const fn since(&self, earlier: &Self) -> Self {
+        Self {
+            node_allocations: self.node_allocations.wrapping_sub(earlier.node_allocations),
+            node_deallocations: self
+                .node_deallocations
+                .wrapping_sub(earlier.node_deallocations),
+        }
+    }
+
+    /// Returns the number of nodes allocated but not yet released.
+    #[must_use]
+    pub const fn live_nodes(&self) -> usize {
+        self.node_allocations
+            .saturating_sub(self.node_deallocations)
+    }
+}
+
+/// Reads the current value of every counter.
+#[must_use]
+pub fn snapshot() -> Snapshot {
+    Snapshot {
+        node_allocations: NODE_ALLOCATIONS.load(Ordering::Relaxed),
+        node_deallocations: NODE_DEALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+/// Resets every counter to zero.
+pub fn reset() {
+    NODE_ALLOCATIONS.store(0, Ordering::Relaxed);
+    NODE_DEALLOCATIONS.store(0, Ordering::Relaxed);
+}
+
+pub(crate) fn record_node_allocation() {
+    NODE_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_node_deallocation() {
+    NODE_DEALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_since() {
+        let earlier = Snapshot {
+            node_allocations: 3,
+            node_deallocations: 1,
+        };
+        let later = Snapshot {
+            node_allocations: 10,
+            node_deallocations: 4,
+        };
+
+        let delta = later.since(&earlier);
+        assert_eq!(delta.node_allocations, 7);
+        assert_eq!(delta.node_deallocations, 3);
+        assert_eq!(later.live_nodes(), 6);
+    }
+
+    #[test]
+    fn test_counters_advance() {
+        let before = snapshot();
+
+        record_node_allocation();
+        record_node_deallocation();
+
+        let delta = snapshot().since(&before);
+        assert!(delta.node_allocations >= 1);
+        assert!(delta.node_deallocations >= 1);
+    }
+}
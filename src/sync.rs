@@ -0,0 +1,298 @@
+//! One-time initialization primitives that work without `std`.
+//!
+//! [`OnceLock`] and [`LazyLock`] mirror their `std::sync` namesakes but synchronize with
+//! a spin loop over a single atomic byte, so they can back `static` tables on bare-metal
+//! targets. Initialization normally happens once at startup, so the spinning only
+//! matters when several threads race for the very first access. [`LazyCell`] is
+//! re-exported from `core` for single-threaded use.
+
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    mem::MaybeUninit,
+    ops::Deref,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+pub use core::cell::LazyCell;
+
+const INCOMPLETE: u8 = 0;
+const RUNNING: u8 = 1;
+const COMPLETE: u8 = 2;
+
+/// A cell that can be written to only once, safely shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use libx::sync::OnceLock;
+///
+/// static GREETING: OnceLock<&str> = OnceLock::new();
+///
+/// assert_eq!(GREETING.get(), None);
+/// assert_eq!(*GREETING.get_or_init(|| "hello"), "hello");
+/// assert_eq!(GREETING.set("bye"), Err("bye"));
+/// ```
+pub struct OnceLock<T> {
+    state: AtomicU8,
+    value: UnsafeCell<MaybeUninit<T>>,
+}
+
+impl<T> OnceLock<T> {
+    /// Creates an empty cell.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            state: AtomicU8::new(INCOMPLETE),
+            value: UnsafeCell::new(MaybeUninit::uninit()),
+        }
+    }
+
+    /// Returns the value if the cell has been initialized.
+    #[must_use]
+    pub fn get(&self) -> Option<&T> {
+        if self.state.load(Ordering::Acquire) == COMPLETE {
+            // SAFETY: `COMPLETE` is only stored after the value has been written, and the
+            // acquire load synchronizes with that release store.
+            Some(unsafe { (*self.value.get()).assume_init_ref() })
+        } else {
+            None
+        }
+    }
+
+    /// Initializes the cell with `value` if it is empty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `value` back if the cell was already initialized or is being initialized.
+    pub fn set(&self, value: T) -> Result<(), T> {
+        let mut value = Some(value);
+        self.get_or_init(|| value.take().unwrap_or_else(|| unreachable!()));
+        value.map_or(Ok(()), Err)
+    }
+
+    /// Returns the value, initializing it with `f` if the cell is empty.
+    ///
+    /// If several threads call this concurrently, exactly one runs `f` while the others
+    /// spin until it finishes. If `f` panics, the cell is left empty and a later call may
+    /// try again.
+    pub fn get_or_init<F>(&self, f: F) -> &T
+    where
+        F: FnOnce() -> T,
+    {
+        // Resets the state if `f` unwinds, so waiting threads do not spin forever.
+        struct Reset<'a>(&'a AtomicU8);
+
+        impl Drop for Reset<'_> {
+            fn drop(&mut self) {
+                self.0.store(INCOMPLETE, Ordering::Release);
+            }
+        }
+
+        loop {
+            if let Some(value) = self.get() {
+                return value;
+            }
+
+            if self
+                .state
+                .compare_exchange(INCOMPLETE, RUNNING, Ordering::Acquire, Ordering::Acquire)
+                .is_ok()
+            {
+                let reset = Reset(&self.state);
+                let value = f();
+                core::mem::forget(reset);
+
+                // SAFETY: winning the `INCOMPLETE -> RUNNING` exchange gives this thread
+                // exclusive access to the slot until `COMPLETE` is published.
+                unsafe { (*self.value.get()).write(value) };
+                self.state.store(COMPLETE, Ordering::Release);
+
+                // SAFETY: the value was written just above.
+                return unsafe { (*self.value.get()).assume_init_ref() };
+            }
+
+            // Another thread is initializing; wait for it to finish or give up.
+            while self.state.load(Ordering::Acquire) == RUNNING {
+                core::hint::spin_loop();
+            }
+        }
+    }
+
+    /// Consumes the cell, returning its value if it was initialized.
+    #[must_use]
+    pub fn into_inner(mut self) -> Option<T> {
+        self.take()
+    }
+
+    /// Takes the value out, leaving the cell empty.
+    pub fn take(&mut self) -> Option<T> {
+        if *self.state.get_mut() == COMPLETE {
+            *self.state.get_mut() = INCOMPLETE;
+            // SAFETY: the cell was complete and `&mut self` guarantees no outstanding
+            // references; resetting the state prevents a second read.
+            Some(unsafe { self.value.get_mut().assume_init_read() })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Default for OnceLock<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for OnceLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tuple = f.debug_tuple("OnceLock");
+
+        match self.get() {
+            Some(value) => tuple.field(value),
+            None => tuple.field(&format_args!("<uninit>")),
+        };
+
+        tuple.finish()
+    }
+}
+
+impl<T> Drop for OnceLock<T> {
+    fn drop(&mut self) {
+        drop(self.take());
+    }
+}
+
+// SAFETY: sharing a `OnceLock` lets any thread initialize it (sending a `T`) and read it
+// (sharing a `&T`).
+unsafe impl<T: Send + Sync> Sync for OnceLock<T> {}
+// SAFETY: the cell owns its value.
+unsafe impl<T: Send> Send for OnceLock<T> {}
+
+/// A value initialized on first access, safely shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// use libx::sync::LazyLock;
+///
+/// static SQUARES: LazyLock<[u32; 4]> = LazyLock::new(|| {
+///     let mut squares = [0; 4];
+///     for (i, square) in squares.iter_mut().enumerate() {
+///         *square = (i * i) as u32;
+///     }
+///     squares
+/// });
+///
+/// assert_eq!(SQUARES[3], 9);
+/// ```
+pub struct LazyLock<T, F = fn() -> T> {
+    once: OnceLock<T>,
+    init: UnsafeCell<Option<F>>,
+}
+
+impl<T, F: FnOnce() -> T> LazyLock<T, F> {
+    /// Creates a lazy value that is initialized by `f` on first access.
+    #[must_use]
+    pub const fn new(f: F) -> Self {
+        Self {
+            once: OnceLock::new(),
+            init: UnsafeCell::new(Some(f)),
+        }
+    }
+
+    /// Forces evaluation and returns a reference to the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the initializer panicked on an earlier access.
+    pub fn force(this: &Self) -> &T {
+        this.once.get_or_init(|| {
+            // SAFETY: `get_or_init` runs this closure on at most one thread at a time,
+            // and only that thread touches `init`.
+            let Some(init) = (unsafe { (*this.init.get()).take() }) else {
+                panic!("LazyLock instance has previously been poisoned");
+            };
+
+            init()
+        })
+    }
+}
+
+impl<T, F: FnOnce() -> T> Deref for LazyLock<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        Self::force(self)
+    }
+}
+
+impl<T: fmt::Debug, F> fmt::Debug for LazyLock<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyLock").field(&self.once).finish()
+    }
+}
+
+// SAFETY: the initializer runs on whichever thread first forces the value, so it must be
+// `Send`; the value itself is shared as with `OnceLock`.
+unsafe impl<T: Send + Sync, F: Send> Sync for LazyLock<T, F> {}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use alloc::{format, vec::Vec};
+    use core::sync::atomic::AtomicUsize;
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_once_lock_set_and_take() {
+        let mut cell = OnceLock::new();
+        assert_eq!(format!("{cell:?}"), "OnceLock(<uninit>)");
+
+        assert_eq!(cell.set(1), Ok(()));
+        assert_eq!(cell.set(2), Err(2));
+        assert_eq!(cell.get(), Some(&1));
+        assert_eq!(format!("{cell:?}"), "OnceLock(1)");
+
+        assert_eq!(cell.take(), Some(1));
+        assert_eq!(cell.get(), None);
+    }
+
+    #[test]
+    fn test_once_lock_retries_after_panic() {
+        let cell = OnceLock::new();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            cell.get_or_init(|| panic!("first attempt fails"));
+        }));
+        assert!(result.is_err());
+
+        assert_eq!(*cell.get_or_init(|| 7), 7);
+    }
+
+    #[test]
+    fn test_lazy_lock_runs_once_across_threads() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        static VALUE: LazyLock<usize> = LazyLock::new(|| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            42
+        });
+
+        let handles: Vec<_> = (0..8).map(|_| thread::spawn(|| *VALUE)).collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().ok(), Some(42));
+        }
+
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_lazy_cell_reexport() {
+        let cell = LazyCell::new(|| 5 * 5);
+        assert_eq!(*cell, 25);
+    }
+}
@@ -0,0 +1,558 @@
+//! ANSI escape sequences for styling terminal output.
+//!
+//! A [`Style`] collects colors and attributes and renders them as one SGR ("select
+//! graphic rendition") sequence. [`Style::styled`] wraps any [`Display`] value so that it
+//! is written with the style and followed by a reset. [`strip_ansi`] and
+//! [`visible_width`] undo the styling when text has to be measured, as the
+//! [`TableFormatter`](crate::formatting::TableFormatter) does when it sizes columns.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::terminal::{self, Style, TerminalColor};
+//!
+//! let warning = Style::new().with_foreground(TerminalColor::Yellow).bold();
+//! let text = warning.styled("careful").to_string();
+//!
+//! assert_eq!(text, "\x1b[1;33mcareful\x1b[0m");
+//! assert_eq!(terminal::strip_ansi(&text), "careful");
+//! assert_eq!(terminal::visible_width(&text), 7);
+//! ```
+
+use alloc::{borrow::Cow, string::String};
+use core::fmt::{self, Display, Write};
+
+use crate::{bitmask, color::Color, option_set::OptionSet};
+
+/// The sequence that turns every style off.
+pub const RESET: &str = "\x1b[0m";
+
+bitmask! {
+    /// Text attributes a terminal may render.
+    ///
+    /// Not every terminal supports every attribute; unsupported ones are ignored.
+    pub struct Attributes: u8 {
+        /// Bold or increased intensity.
+        const BOLD = 1 << 0;
+        /// Faint or decreased intensity.
+        const DIM = 1 << 1;
+        /// Italic text.
+        const ITALIC = 1 << 2;
+        /// Underlined text.
+        const UNDERLINE = 1 << 3;
+        /// Blinking text.
+        const BLINK = 1 << 4;
+        /// Swapped foreground and background colors.
+        const REVERSE = 1 << 5;
+        /// Invisible text.
+        const HIDDEN = 1 << 6;
+        /// Crossed-out text.
+        const STRIKETHROUGH = 1 << 7;
+    }
+}
+
+/// The SGR parameters of [`Attributes::FLAGS`], in order.
+const ATTRIBUTE_CODES: [u8; 8] = [1, 2, 3, 4, 5, 7, 8, 9];
+
+/// A foreground or background color.
+///
+/// The sixteen named colors follow the terminal's palette, so their exact shades depend
+/// on its theme. [`Indexed`](Self::Indexed) selects from the 256-color palette and
+/// [`Rgb`](Self::Rgb) asks for a 24-bit "truecolor", whose alpha is ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TerminalColor {
+    /// Palette color 0.
+    Black,
+    /// Palette color 1.
+    Red,
+    /// Palette color 2.
+    Green,
+    /// Palette color 3.
+    Yellow,
+    /// Palette color 4.
+    Blue,
+    /// Palette color 5.
+    Magenta,
+    /// Palette color 6.
+    Cyan,
+    /// Palette color 7.
+    White,
+    /// Palette color 8.
+    BrightBlack,
+    /// Palette color 9.
+    BrightRed,
+    /// Palette color 10.
+    BrightGreen,
+    /// Palette color 11.
+    BrightYellow,
+    /// Palette color 12.
+    BrightBlue,
+    /// Palette color 13.
+    BrightMagenta,
+    /// Palette color 14.
+    BrightCyan,
+    /// Palette color 15.
+    BrightWhite,
+    /// A color of the 256-color palette.
+    Indexed(u8),
+    /// A 24-bit color.
+    Rgb(Color),
+}
+
+impl TerminalColor {
+    /// Writes the SGR parameters of the color, with `base` 30 for the foreground and 40
+    /// for the background.
+    fn write_parameters<W: Write>(self, base: u8, sink: &mut W) -> fmt::Result {
+        let named = match self {
+            Self::Indexed(index) => return write!(sink, "{};5;{index}", base + 8),
+            Self::Rgb(color) => {
+                return write!(
+                    sink,
+                    "{};2;{};{};{}",
+                    base + 8,
+                    color.red,
+                    color.green,
+                    color.blue
+                );
+            }
+            Self::Black => 0,
+            Self::Red => 1,
+            Self::Green => 2,
+            Self::Yellow => 3,
+            Self::Blue => 4,
+            Self::Magenta => 5,
+            Self::Cyan => 6,
+            Self::White => 7,
+            Self::BrightBlack => 8,
+            Self::BrightRed => 9,
+            Self::BrightGreen => 10,
+            Self::BrightYellow => 11,
+            Self::BrightBlue => 12,
+            Self::BrightMagenta => 13,
+            Self::BrightCyan => 14,
+            Self::BrightWhite => 15,
+        };
+
+        if named < 8 {
+            write!(sink, "{}", base + named)
+        } else {
+            // Bright colors are 90–97 and 100–107.
+            write!(sink, "{}", base + 60 + named - 8)
+        }
+    }
+}
+
+impl From<Color> for TerminalColor {
+    fn from(color: Color) -> Self {
+        Self::Rgb(color)
+    }
+}
+
+/// Colors and attributes for a run of terminal text.
+///
+/// # Examples
+///
+/// ```
+/// use libx::color::Color;
+/// use libx::terminal::{Style, TerminalColor};
+///
+/// let style = Style::new()
+///     .with_foreground(Color::rgb(255, 128, 0))
+///     .with_background(TerminalColor::Indexed(236))
+///     .underline();
+///
+/// assert_eq!(style.prefix(), "\x1b[4;38;2;255;128;0;48;5;236m");
+/// assert_eq!(Style::new().prefix(), "");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    foreground: Option<TerminalColor>,
+    background: Option<TerminalColor>,
+    attributes: Attributes,
+}
+
+impl Style {
+    /// Creates a style that leaves text unchanged.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            foreground: None,
+            background: None,
+            attributes: Attributes::from_raw_value(0),
+        }
+    }
+
+    /// Sets the color of the text.
+    #[must_use]
+    pub fn with_foreground(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.foreground = Some(color.into());
+        self
+    }
+
+    /// Sets the color behind the text.
+    #[must_use]
+    pub fn with_background(mut self, color: impl Into<TerminalColor>) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Replaces the attributes of the style.
+    #[must_use]
+    pub const fn with_attributes(mut self, attributes: Attributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
+    /// Adds [`Attributes::BOLD`].
+    #[must_use]
+    pub const fn bold(self) -> Self {
+        self.adding(Attributes::BOLD)
+    }
+
+    /// Adds [`Attributes::DIM`].
+    #[must_use]
+    pub const fn dim(self) -> Self {
+        self.adding(Attributes::DIM)
+    }
+
+    /// Adds [`Attributes::ITALIC`].
+    #[must_use]
+    pub const fn italic(self) -> Self {
+        self.adding(Attributes::ITALIC)
+    }
+
+    /// Adds [`Attributes::UNDERLINE`].
+    #[must_use]
+    pub const fn underline(self) -> Self {
+        self.adding(Attributes::UNDERLINE)
+    }
+
+    /// Adds [`Attributes::REVERSE`].
+    #[must_use]
+    pub const fn reverse(self) -> Self {
+        self.adding(Attributes::REVERSE)
+    }
+
+    /// Adds [`Attributes::STRIKETHROUGH`].
+    #[must_use]
+    pub const fn strikethrough(self) -> Self {
+        self.adding(Attributes::STRIKETHROUGH)
+    }
+
+    /// Returns the color of the text.
+    #[must_use]
+    pub const fn foreground(&self) -> Option<TerminalColor> {
+        self.foreground
+    }
+
+    /// Returns the color behind the text.
+    #[must_use]
+    pub const fn background(&self) -> Option<TerminalColor> {
+        self.background
+    }
+
+    /// Returns the attributes of the style.
+    #[must_use]
+    pub const fn attributes(&self) -> Attributes {
+        self.attributes
+    }
+
+    /// Returns `true` if the style has no colors or attributes.
+    #[must_use]
+    pub fn is_plain(&self) -> bool {
+        self.foreground.is_none() && self.background.is_none() && self.attributes.is_empty()
+    }
+
+    /// Returns the SGR sequence that turns the style on, or an empty string for a plain
+    /// style.
+    #[must_use]
+    pub fn prefix(&self) -> String {
+        let mut output = String::new();
+        // Writing into a `String` cannot fail.
+        let _ = self.write_prefix(&mut output);
+        output
+    }
+
+    /// Writes the SGR sequence that turns the style on into `sink`; a plain style writes
+    /// nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn write_prefix<W: Write>(&self, sink: &mut W) -> fmt::Result {
+        if self.is_plain() {
+            return Ok(());
+        }
+
+        sink.write_str("\x1b[")?;
+        let mut first = true;
+        let mut separate = |sink: &mut W| {
+            if first {
+                first = false;
+                Ok(())
+            } else {
+                sink.write_char(';')
+            }
+        };
+
+        for (&(_, attribute), code) in Attributes::FLAGS.iter().zip(ATTRIBUTE_CODES) {
+            if self.attributes.contains(attribute) {
+                separate(sink)?;
+                write!(sink, "{code}")?;
+            }
+        }
+
+        if let Some(color) = self.foreground {
+            separate(sink)?;
+            color.write_parameters(30, sink)?;
+        }
+
+        if let Some(color) = self.background {
+            separate(sink)?;
+            color.write_parameters(40, sink)?;
+        }
+
+        sink.write_char('m')
+    }
+
+    /// Wraps `content` so that it is displayed with the style, followed by [`RESET`].
+    #[must_use]
+    pub const fn styled<T: Display>(self, content: T) -> Styled<T> {
+        Styled {
+            style: self,
+            content,
+        }
+    }
+
+    const fn adding(mut self, attribute: Attributes) -> Self {
+        self.attributes =
+            Attributes::from_raw_value(self.attributes.raw_value() | attribute.raw_value());
+        self
+    }
+}
+
+/// A value displayed with a [`Style`], created by [`Style::styled`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Styled<T> {
+    style: Style,
+    content: T,
+}
+
+impl<T> Styled<T> {
+    /// Returns the style the content is displayed with.
+    #[must_use]
+    pub const fn style(&self) -> &Style {
+        &self.style
+    }
+
+    /// Returns the styled content.
+    #[must_use]
+    pub const fn content(&self) -> &T {
+        &self.content
+    }
+}
+
+impl<T: Display> Display for Styled<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.style.is_plain() {
+            return self.content.fmt(f);
+        }
+
+        self.style.write_prefix(f)?;
+        self.content.fmt(f)?;
+        f.write_str(RESET)
+    }
+}
+
+/// Removes ANSI escape sequences from `text`.
+///
+/// Control sequences (`ESC [` … final byte), operating system commands (`ESC ]` …
+/// `BEL` or `ESC \`) and other escapes such as `ESC ( B` are removed; everything else
+/// is kept. Text without escapes is returned unchanged.
+#[must_use]
+pub fn strip_ansi(text: &str) -> Cow<'_, str> {
+    if !text.contains('\x1b') {
+        return Cow::Borrowed(text);
+    }
+
+    Cow::Owned(
+        segments(text)
+            .filter_map(|segment| match segment {
+                Segment::Text(text) => Some(text),
+                Segment::Escape(_) => None,
+            })
+            .collect(),
+    )
+}
+
+/// Returns the number of `char`s of `text` a terminal displays, ignoring ANSI escape
+/// sequences.
+///
+/// # Examples
+///
+/// ```
+/// use libx::terminal::visible_width;
+///
+/// assert_eq!(visible_width("\x1b[31mrød\x1b[0m"), 3);
+/// ```
+#[must_use]
+pub fn visible_width(text: &str) -> usize {
+    segments(text)
+        .map(|segment| match segment {
+            Segment::Text(text) => text.chars().count(),
+            Segment::Escape(_) => 0,
+        })
+        .sum()
+}
+
+/// A run of text or a single escape sequence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Segment<'a> {
+    Text(&'a str),
+    Escape(&'a str),
+}
+
+/// Splits `text` into runs of visible text and escape sequences.
+pub(crate) fn segments(text: &str) -> impl Iterator<Item = Segment<'_>> {
+    let mut rest = text;
+
+    core::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let length = escape_length(rest.as_bytes());
+        let (segment, remainder) = if length > 0 {
+            let (escape, remainder) = rest.split_at(length);
+            (Segment::Escape(escape), remainder)
+        } else {
+            let end = rest.find('\x1b').unwrap_or(rest.len());
+            let (text, remainder) = rest.split_at(end);
+            (Segment::Text(text), remainder)
+        };
+
+        rest = remainder;
+        Some(segment)
+    })
+}
+
+/// Returns the length in bytes of the escape sequence `bytes` starts with, or zero if it
+/// does not start with one.
+///
+/// Unterminated sequences run to the end of the text, and a control sequence ends early
+/// at the first byte that cannot belong to it, so the result always falls on a `char`
+/// boundary.
+fn escape_length(bytes: &[u8]) -> usize {
+    match bytes {
+        [0x1b, b'[', rest @ ..] => {
+            for (index, &byte) in rest.iter().enumerate() {
+                match byte {
+                    0x20..=0x3f => {}
+                    0x40..=0x7e => return index + 3,
+                    _ => return index + 2,
+                }
+            }
+
+            bytes.len()
+        }
+        [0x1b, b']', rest @ ..] => {
+            for index in 0..rest.len() {
+                if rest[index] == 0x07 {
+                    return index + 3;
+                }
+
+                if rest[index..].starts_with(b"\x1b\\") {
+                    return index + 4;
+                }
+            }
+
+            bytes.len()
+        }
+        [0x1b, rest @ ..] => {
+            // Intermediate bytes, as in the character set designation `ESC ( B`, then
+            // a final byte.
+            for (index, &byte) in rest.iter().enumerate() {
+                match byte {
+                    0x20..=0x2f => {}
+                    0x30..=0x7e => return index + 2,
+                    _ => return index + 1,
+                }
+            }
+
+            bytes.len()
+        }
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_prefixes() {
+        assert_eq!(
+            Style::new().with_foreground(TerminalColor::Red).prefix(),
+            "\x1b[31m"
+        );
+        assert_eq!(
+            Style::new()
+                .with_foreground(TerminalColor::BrightCyan)
+                .with_background(TerminalColor::BrightBlack)
+                .prefix(),
+            "\x1b[96;100m"
+        );
+        assert_eq!(
+            Style::new()
+                .with_background(TerminalColor::Indexed(208))
+                .prefix(),
+            "\x1b[48;5;208m"
+        );
+        assert_eq!(
+            Style::new().bold().dim().italic().strikethrough().prefix(),
+            "\x1b[1;2;3;9m"
+        );
+        assert_eq!(
+            Style::new()
+                .with_attributes(Attributes::REVERSE | Attributes::BLINK)
+                .prefix(),
+            "\x1b[5;7m"
+        );
+    }
+
+    #[test]
+    fn test_styled() {
+        let plain = Style::new().styled(42);
+        assert_eq!(plain.to_string(), "42");
+
+        let styled = Style::new().underline().styled(42);
+        assert_eq!(styled.to_string(), "\x1b[4m42\x1b[0m");
+        assert_eq!(*styled.content(), 42);
+        assert!(styled.style().attributes().contains(Attributes::UNDERLINE));
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert!(matches!(strip_ansi("plain"), Cow::Borrowed("plain")));
+        assert_eq!(strip_ansi("\x1b[1;31mbold red\x1b[0m!"), "bold red!");
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x07link\x1b]8;;\x1b\\"),
+            "link"
+        );
+        assert_eq!(strip_ansi("a\x1b(Bb"), "ab");
+        assert_eq!(strip_ansi("unterminated\x1b[31"), "unterminated");
+        assert_eq!(strip_ansi("\x1b[é"), "é");
+        assert_eq!(strip_ansi("end\x1b"), "end");
+    }
+
+    #[test]
+    fn test_visible_width() {
+        assert_eq!(visible_width(""), 0);
+        assert_eq!(visible_width("naïve"), 5);
+        assert_eq!(
+            visible_width(&Style::new().bold().styled("naïve").to_string()),
+            5
+        );
+    }
+}
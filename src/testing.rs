@@ -0,0 +1,571 @@
+//! Property-based and model-based testing helpers.
+//!
+//! These helpers are part of the public API (behind the `testing` feature) so that
+//! downstream crates can hold their own types to the same invariants libx checks
+//! internally, and so that fuzz targets in `fuzz/` share the exact harness used by the
+//! unit tests.
+//!
+//! Properties are driven by a seeded [`SplitMix64`], so every failure reports the seed
+//! and case number needed to replay it.
+//!
+//! The module also ships deterministic doubles for the seams through which libx reads the
+//! outside world: [`ManualClock`] for the clock traits, [`ScriptedRandom`] for
+//! [`RandomNumberGenerator`], and [`MockEnvironment`] for environment variables.
+
+use alloc::{format, string::String, vec::Vec};
+use core::{
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+    time::Duration,
+};
+
+pub use crate::env::MockEnvironment;
+pub use crate::errors::{CheckError, PropertyFailure};
+use crate::{
+    ciphers::morse,
+    collections::list::doubly_linked::List,
+    num::Number,
+    random::{RandomNumberGenerator, SplitMix64},
+    time::{MonotonicClock, WallClock},
+};
+
+/// A clock that only moves when told to.
+///
+/// The same reading is reported both as monotonic time and as time since the Unix
+/// epoch. The time is held in an atomic, so a clock shared with the code under test can
+/// be advanced through a shared reference.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::{testing::ManualClock, time::Deadline};
+///
+/// let clock = ManualClock::new(Duration::ZERO);
+/// let deadline = Deadline::after(&clock, Duration::from_secs(5));
+///
+/// clock.advance(Duration::from_secs(4));
+/// assert!(!deadline.has_expired());
+///
+/// clock.advance(Duration::from_secs(1));
+/// assert!(deadline.has_expired());
+/// ```
+#[derive(Debug, Default)]
+pub struct ManualClock {
+    nanos: AtomicU64,
+}
+
+impl ManualClock {
+    /// Creates a clock reading `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` does not fit in 64 bits of nanoseconds, about 584 years.
+    #[must_use]
+    pub fn new(start: Duration) -> Self {
+        Self {
+            nanos: AtomicU64::new(to_nanos(start)),
+        }
+    }
+
+    /// Returns the current reading.
+    #[must_use]
+    pub fn get(&self) -> Duration {
+        Duration::from_nanos(self.nanos.load(Ordering::SeqCst))
+    }
+
+    /// Sets the reading to `time`, which may be earlier than the current one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `time` does not fit in 64 bits of nanoseconds.
+    pub fn set(&self, time: Duration) {
+        self.nanos.store(to_nanos(time), Ordering::SeqCst);
+    }
+
+    /// Moves the reading forward by `step`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new reading does not fit in 64 bits of nanoseconds.
+    pub fn advance(&self, step: Duration) {
+        let step = to_nanos(step);
+        let previous = self.nanos.fetch_add(step, Ordering::SeqCst);
+        assert!(
+            previous.checked_add(step).is_some(),
+            "manual clock overflowed"
+        );
+    }
+}
+
+impl MonotonicClock for ManualClock {
+    fn now(&self) -> Duration {
+        self.get()
+    }
+}
+
+impl WallClock for ManualClock {
+    fn since_unix_epoch(&self) -> Duration {
+        self.get()
+    }
+}
+
+fn to_nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).expect("duration is too long for a manual clock")
+}
+
+/// A generator that replays a fixed sequence of values, starting over once it runs out.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{random::RandomNumberGenerator, testing::ScriptedRandom};
+///
+/// let mut rng = ScriptedRandom::new([1, 2]);
+///
+/// assert_eq!(rng.next_u64(), 1);
+/// assert_eq!(rng.next_u64(), 2);
+/// assert_eq!(rng.next_u64(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ScriptedRandom {
+    values: Vec<u64>,
+    position: usize,
+}
+
+impl ScriptedRandom {
+    /// Creates a generator returning `values` in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    #[must_use]
+    pub fn new(values: impl IntoIterator<Item = u64>) -> Self {
+        let values: Vec<_> = values.into_iter().collect();
+        assert!(!values.is_empty(), "scripted values must not be empty");
+
+        Self {
+            values,
+            position: 0,
+        }
+    }
+
+    /// Returns a generator whose every value is `value`.
+    ///
+    /// A value of one makes bounded draws return their lower end and [`u64::MAX`] makes
+    /// them return their upper end, which pins jitter to either extreme. Zero is rejected
+    /// by bounded draws whose bound is not a power of two, so they would never return.
+    #[must_use]
+    pub fn constant(value: u64) -> Self {
+        Self::new([value])
+    }
+}
+
+impl RandomNumberGenerator for ScriptedRandom {
+    fn next_u64(&mut self) -> u64 {
+        let value = self.values[self.position];
+        self.position = (self.position + 1) % self.values.len();
+        value
+    }
+}
+
+/// Runs `property` against `cases` independently generated inputs.
+///
+/// Each case receives its own generator, derived from `seed` and the case index, so a
+/// single failing case can be replayed without running the ones before it.
+///
+/// # Errors
+///
+/// Returns a [`PropertyFailure`] wrapping the first [`CheckError`] reported by
+/// `property`. Properties can describe a violation in prose with
+/// [`CheckError::Violated`], which strings convert into.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{random::RandomNumberGenerator, testing::check_property};
+///
+/// let result = check_property(7, 100, |rng| {
+///     let value = rng.next_bounded(10);
+///     if value < 10 { Ok(()) } else { Err("value out of range".into()) }
+/// });
+///
+/// assert!(result.is_ok());
+/// ```
+pub fn check_property<F>(seed: u64, cases: usize, mut property: F) -> Result<(), PropertyFailure>
+where
+    F: FnMut(&mut SplitMix64) -> Result<(), CheckError>,
+{
+    for case in 0..cases {
+        let mut rng = SplitMix64::new(seed ^ (case as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+
+        property(&mut rng).map_err(|error| PropertyFailure { seed, case, error })?;
+    }
+
+    Ok(())
+}
+
+/// A single mutation applied to a list and to its reference model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ListOperation<T> {
+    /// Calls `push_front`.
+    PushFront(T),
+
+    /// Calls `push_back`.
+    PushBack(T),
+
+    /// Calls `pop_front`.
+    PopFront,
+
+    /// Calls `pop_back`.
+    PopBack,
+
+    /// Calls `insert`, with the index reduced modulo `len() + 1`.
+    Insert(usize, T),
+
+    /// Calls `remove_by_index`, with the index reduced modulo `len()`.
+    RemoveByIndex(usize),
+
+    /// Calls `clear`.
+    Clear,
+}
+
+impl ListOperation<u8> {
+    /// Decodes a sequence of operations from arbitrary bytes.
+    ///
+    /// Every input decodes to some sequence, which makes this suitable for feeding fuzzer
+    /// output straight into [`check_list_model`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libx::testing::ListOperation;
+    ///
+    /// let ops = ListOperation::decode(&[1, 42, 2]);
+    /// assert_eq!(ops, [ListOperation::PushBack(42), ListOperation::PopFront]);
+    /// ```
+    #[must_use]
+    pub fn decode(data: &[u8]) -> Vec<Self> {
+        let mut bytes = data.iter().copied();
+        let mut ops = Vec::new();
+
+        while let Some(tag) = bytes.next() {
+            let mut operand = || bytes.next().unwrap_or_default();
+
+            ops.push(match tag % 7 {
+                0 => Self::PushFront(operand()),
+                1 => Self::PushBack(operand()),
+                2 => Self::PopFront,
+                3 => Self::PopBack,
+                4 => {
+                    let index = usize::from(operand());
+                    Self::Insert(index, operand())
+                }
+                5 => Self::RemoveByIndex(usize::from(operand())),
+                _ => Self::Clear,
+            });
+        }
+
+        ops
+    }
+}
+
+impl ListOperation<u64> {
+    /// Generates a random operation.
+    pub fn random<R: RandomNumberGenerator>(rng: &mut R) -> Self {
+        let index = usize::try_from(rng.next_bounded(64)).unwrap_or_default();
+
+        // Pushes are weighted so that generated lists tend to grow.
+        match rng.next_bounded(10) {
+            0 | 1 => Self::PushFront(rng.next_u64()),
+            2 | 3 => Self::PushBack(rng.next_u64()),
+            4 => Self::PopFront,
+            5 => Self::PopBack,
+            6 | 7 => Self::Insert(index, rng.next_u64()),
+            8 => Self::RemoveByIndex(index),
+            _ => Self::Clear,
+        }
+    }
+}
+
+/// Applies `ops` to both a [`List`] and a `Vec` model, checking after every step that the
+/// two agree on length, contents, and the values returned by each operation.
+///
+/// # Errors
+///
+/// Returns a [`CheckError`] describing the first divergence between the list and the model.
+///
+/// # Examples
+///
+/// ```
+/// use libx::testing::{ListOperation, check_list_model};
+///
+/// let ops = [
+///     ListOperation::PushBack(1),
+///     ListOperation::PushFront(0),
+///     ListOperation::Insert(1, 5),
+///     ListOperation::PopBack,
+/// ];
+///
+/// assert_eq!(check_list_model(&ops), Ok(()));
+/// ```
+pub fn check_list_model<T>(ops: &[ListOperation<T>]) -> Result<(), CheckError>
+where
+    T: Clone + PartialEq + fmt::Debug,
+{
+    let mut list = List::new();
+    let mut model = Vec::new();
+
+    for (step, op) in ops.iter().enumerate() {
+        let (actual, expected) = match op.clone() {
+            ListOperation::PushFront(value) => {
+                list.push_front(value.clone());
+                model.insert(0, value);
+                (None, None)
+            }
+            ListOperation::PushBack(value) => {
+                list.push_back(value.clone());
+                model.push(value);
+                (None, None)
+            }
+            ListOperation::PopFront => {
+                let expected = (!model.is_empty()).then(|| model.remove(0));
+                (list.pop_front(), expected)
+            }
+            ListOperation::PopBack => (list.pop_back(), model.pop()),
+            ListOperation::Insert(index, value) => {
+                let index = index % (model.len() + 1);
+                list.insert(index, value.clone());
+                model.insert(index, value);
+                (None, None)
+            }
+            ListOperation::RemoveByIndex(index) => {
+                if model.is_empty() {
+                    (None, None)
+                } else {
+                    let index = index % model.len();
+                    (list.remove_by_index(index), Some(model.remove(index)))
+                }
+            }
+            ListOperation::Clear => {
+                list.clear();
+                model.clear();
+                (None, None)
+            }
+        };
+
+        if actual != expected {
+            return Err(CheckError::ResultMismatch {
+                step,
+                operation: format!("{op:?}"),
+                actual: format!("{actual:?}"),
+                expected: format!("{expected:?}"),
+            });
+        }
+
+        if list.len() != model.len() || !list.iter().eq(model.iter()) {
+            return Err(CheckError::StateMismatch {
+                step,
+                operation: format!("{op:?}"),
+                actual: format!("{list:?}"),
+                expected: format!("{model:?}"),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that formatting a parsed [`Number`] settles on a stable text form: once the
+/// formatted text has been parsed and formatted again, further round trips must not
+/// change it.
+///
+/// One normalising round trip is allowed because `Number::from_str` picks the first type
+/// that accepts the text, so `"1e3"` parses as a float but its formatted form `"1000"`
+/// parses as an integer, and a float `-0` becomes the integer `0`.
+///
+/// Inputs that do not parse satisfy the property trivially.
+///
+/// # Errors
+///
+/// Returns a [`CheckError`] for the text that failed to parse or settle.
+///
+/// # Examples
+///
+/// ```
+/// use libx::testing::check_number_round_trip;
+///
+/// assert_eq!(check_number_round_trip("-12.5"), Ok(()));
+/// assert_eq!(check_number_round_trip("not a number"), Ok(()));
+/// ```
+pub fn check_number_round_trip(input: &str) -> Result<(), CheckError> {
+    fn round_trip(text: &str) -> Result<String, CheckError> {
+        text.parse::<Number>()
+            .map(|number| format!("{number}"))
+            .map_err(|error| CheckError::Unparsable {
+                text: text.into(),
+                error,
+            })
+    }
+
+    let Ok(number) = input.parse::<Number>() else {
+        return Ok(());
+    };
+
+    let normalized = round_trip(&format!("{number}"))?;
+    let reformatted = round_trip(&normalized)?;
+
+    if normalized == reformatted {
+        Ok(())
+    } else {
+        Err(CheckError::Unstable {
+            input: input.into(),
+            normalized,
+            reformatted,
+        })
+    }
+}
+
+/// Checks that decoding Morse code settles on a stable text form: once the decoded text
+/// has been encoded and decoded again, further round trips must not change it.
+///
+/// One normalising round trip is allowed because the decoder folds runs of word breaks
+/// and surrounding whitespace that the encoder never writes.
+///
+/// Inputs that do not decode satisfy the property trivially.
+///
+/// # Errors
+///
+/// Returns a [`CheckError`] if decoded text cannot be encoded and decoded again, or does
+/// not settle.
+///
+/// # Examples
+///
+/// ```
+/// use libx::testing::check_morse_round_trip;
+///
+/// assert_eq!(check_morse_round_trip("... --- ..."), Ok(()));
+/// assert_eq!(check_morse_round_trip("not morse"), Ok(()));
+/// ```
+pub fn check_morse_round_trip(input: &str) -> Result<(), CheckError> {
+    fn round_trip(text: &str) -> Result<String, CheckError> {
+        morse::encode(text)
+            .and_then(|code| morse::decode(&code))
+            .map_err(|error| format!("{text:?} no longer round trips: {error}").into())
+    }
+
+    let Ok(text) = morse::decode(input) else {
+        return Ok(());
+    };
+
+    let normalized = round_trip(&text)?;
+    let reformatted = round_trip(&normalized)?;
+
+    if normalized == reformatted {
+        Ok(())
+    } else {
+        Err(CheckError::Unstable {
+            input: input.into(),
+            normalized,
+            reformatted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_clock() {
+        let clock = ManualClock::new(Duration::from_secs(10));
+
+        clock.advance(Duration::from_millis(250));
+        assert_eq!(clock.now(), Duration::from_millis(10_250));
+
+        clock.set(Duration::from_secs(1));
+        assert_eq!(clock.since_unix_epoch(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_scripted_random_pins_jitter() {
+        use crate::retry::{Backoff, Jitter};
+
+        let backoff = Backoff::constant(Duration::from_secs(2)).with_jitter(Jitter::Full);
+
+        let low = backoff.delays(ScriptedRandom::constant(1)).next();
+        let high = backoff.delays(ScriptedRandom::constant(u64::MAX)).next();
+
+        assert_eq!(low, Some(Duration::ZERO));
+        assert!(high >= Some(Duration::from_millis(1_999)));
+    }
+
+    #[test]
+    fn test_list_matches_model() {
+        let result = check_property(0x5EED, 200, |rng| {
+            let length = usize::try_from(rng.next_bounded(50)).unwrap_or_default();
+            let ops: Vec<_> = (0..length).map(|_| ListOperation::random(rng)).collect();
+            check_list_model(&ops)
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_decoded_operations_match_model() {
+        let result = check_property(0xF022, 200, |rng| {
+            let mut bytes = [0; 64];
+            rng.fill_bytes(&mut bytes);
+            check_list_model(&ListOperation::decode(&bytes))
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_number_round_trip() {
+        let result = check_property(0xABCD, 500, |rng| {
+            let input = match rng.next_bounded(3) {
+                0 => format!("{}", rng.next_u64().cast_signed()),
+                1 => format!("{}", f64::from_bits(rng.next_u64())),
+                _ => format!("{}", rng.next_f64() * 1000.0),
+            };
+            check_number_round_trip(&input)
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_morse_round_trip() {
+        let result = check_property(0x3053, 500, |rng| {
+            let input: String = (0..rng.next_bounded(24))
+                .map(|_| match rng.next_bounded(5) {
+                    0 | 1 => '.',
+                    2 => '-',
+                    3 => '/',
+                    _ => ' ',
+                })
+                .collect();
+            check_morse_round_trip(&input)
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn test_property_failure_reports_case() {
+        let result = check_property(1, 10, |rng| {
+            if rng.next_bounded(2) == 0 {
+                Err("unlucky".into())
+            } else {
+                Ok(())
+            }
+        });
+
+        let failure = result.expect_err("some case should fail");
+        assert_eq!(failure.seed, 1);
+        assert_eq!(failure.error, CheckError::Violated("unlucky".into()));
+    }
+}
@@ -0,0 +1,23 @@
+//! Helpers for working with Unicode text.
+//!
+//! The [`diff`] module compares texts line by line and applies unified diffs, the
+//! [`digits`] module converts between the digits of different numbering systems, the
+//! [`escape`] module escapes and unescapes text for JSON, HTML, shells and C, the
+//! [`natural`] module orders strings with embedded numbers as people expect, the
+//! [`search`] module finds one or many patterns in text or bytes, the [`transliterate`]
+//! module turns Latin text into ASCII and builds URL slugs from it, and the [`wrap`](mod@wrap)
+//! module breaks text into lines of a given width.
+
+pub mod diff;
+pub mod digits;
+pub mod escape;
+pub mod natural;
+pub mod search;
+pub mod transliterate;
+pub mod wrap;
+
+pub use diff::{Patch, apply_patch};
+pub use natural::{NaturalOrder, natural_cmp, sort_naturally};
+pub use search::{AhoCorasick, Finder, find_all};
+pub use transliterate::{Transliterator, Unmapped, slugify};
+pub use wrap::{WrapAlgorithm, Wrapper, fill, wrap};
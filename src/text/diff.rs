@@ -0,0 +1,627 @@
+//! Line-based diffs of text in the unified format, and applying them.
+//!
+//! [`diff`] compares two texts line by line, with the longest common subsequence that
+//! [`collections::diff`](crate::collections::diff) finds, and groups the changed lines
+//! into [`Hunk`]s with some unchanged lines of context around them. A [`Patch`] displays
+//! as a unified diff, the format of `diff -u` and `git diff`, and parses back from one.
+//!
+//! [`apply_patch`] applies a patch to a text. Hunks are looked for first where the patch
+//! says they are, then further and further away, so a patch still applies after lines
+//! were added or removed elsewhere. With fuzz, the outermost lines of context may also
+//! differ.
+//!
+//! Lines keep their line feeds, so a text that does not end with one differs from one
+//! that does; the unified format marks such a last line with `\ No newline at end of
+//! file`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::text::diff::{apply_patch, diff};
+//!
+//! let old = "one\ntwo\nthree\n";
+//! let new = "one\n2\nthree\n";
+//!
+//! let patch = diff(old, new, 3);
+//! assert_eq!(
+//!     patch.to_string(),
+//!     "--- a\n+++ b\n@@ -1,3 +1,3 @@\n one\n-two\n+2\n three\n"
+//! );
+//!
+//! assert_eq!(apply_patch(old, &patch, 0).as_deref(), Ok(new));
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::FromStr};
+
+use crate::{collections::diff::common_subsequence, errors::PatchError};
+
+/// What a line of a hunk does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineKind {
+    /// The line is in both texts.
+    Context,
+
+    /// The line is only in the old text.
+    Removed,
+
+    /// The line is only in the new text.
+    Added,
+}
+
+impl LineKind {
+    /// Returns the character that starts the line in a unified diff.
+    const fn prefix(self) -> char {
+        match self {
+            Self::Context => ' ',
+            Self::Removed => '-',
+            Self::Added => '+',
+        }
+    }
+}
+
+/// A line of a hunk.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HunkLine {
+    /// What the line does.
+    pub kind: LineKind,
+
+    /// The text of the line, with its line feed unless it is the last line of a text
+    /// that does not end with one.
+    pub text: String,
+}
+
+/// A run of changed lines with the unchanged lines around them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Hunk {
+    /// The index of the first line of the hunk in the old text, counting from zero.
+    pub old_start: usize,
+
+    /// The index of the first line of the hunk in the new text, counting from zero.
+    pub new_start: usize,
+
+    /// The lines of the hunk, in order.
+    pub lines: Vec<HunkLine>,
+}
+
+impl Hunk {
+    /// Returns the number of lines the hunk covers in the old text.
+    #[must_use]
+    pub fn old_count(&self) -> usize {
+        self.count(LineKind::Added)
+    }
+
+    /// Returns the number of lines the hunk covers in the new text.
+    #[must_use]
+    pub fn new_count(&self) -> usize {
+        self.count(LineKind::Removed)
+    }
+
+    /// Returns the number of lines that are not of the kind `excluded`.
+    fn count(&self, excluded: LineKind) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.kind != excluded)
+            .count()
+    }
+
+    /// Returns the lines of one side, without the first `front` and last `back` lines,
+    /// which must be context.
+    fn side(&self, excluded: LineKind, front: usize, back: usize) -> Vec<&str> {
+        let lines = &self.lines[front..self.lines.len() - back];
+
+        lines
+            .iter()
+            .filter(|line| line.kind != excluded)
+            .map(|line| line.text.as_str())
+            .collect()
+    }
+
+    /// Returns the number of context lines before the first change and after the last.
+    fn context(&self) -> (usize, usize) {
+        let is_context = |line: &&HunkLine| line.kind == LineKind::Context;
+
+        (
+            self.lines.iter().take_while(is_context).count(),
+            self.lines.iter().rev().take_while(is_context).count(),
+        )
+    }
+}
+
+/// The differences between two texts, as a list of hunks.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Patch {
+    /// The name of the old text, shown after `---`.
+    pub old_name: String,
+
+    /// The name of the new text, shown after `+++`.
+    pub new_name: String,
+
+    /// The hunks, in the order of the text.
+    pub hunks: Vec<Hunk>,
+}
+
+impl Patch {
+    /// Sets the names of the texts shown in the header, `a` and `b` by default.
+    #[must_use]
+    pub fn with_names(mut self, old_name: &str, new_name: &str) -> Self {
+        self.old_name = old_name.to_string();
+        self.new_name = new_name.to_string();
+        self
+    }
+
+    /// Returns whether the texts are the same.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+
+    /// Parses a unified diff.
+    ///
+    /// Lines before the `---` header, such as `diff --git` lines, are ignored, as is a
+    /// timestamp after a tab in the header. Only the first file of a multi-file diff is
+    /// read.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PatchError::Malformed`] with the number of the first line that does not
+    /// follow the format, or of the last line if a hunk is cut short.
+    pub fn parse(text: &str) -> Result<Self, PatchError> {
+        let mut patch = Self {
+            old_name: String::new(),
+            new_name: String::new(),
+            hunks: Vec::new(),
+        };
+        let mut lines = text.split_inclusive('\n').zip(1..).peekable();
+
+        while let Some((line, number)) = lines.next() {
+            if let Some(name) = line.strip_prefix("--- ") {
+                if !patch.hunks.is_empty() {
+                    break;
+                }
+
+                patch.old_name = header_name(name);
+                let (line, number) = lines.next().ok_or(PatchError::Malformed { line: number })?;
+                let name = line
+                    .strip_prefix("+++ ")
+                    .ok_or(PatchError::Malformed { line: number })?;
+                patch.new_name = header_name(name);
+                continue;
+            }
+
+            let Some(header) = line.strip_prefix("@@ ") else {
+                if patch.hunks.is_empty() {
+                    continue;
+                }
+
+                return Err(PatchError::Malformed { line: number });
+            };
+
+            let malformed = PatchError::Malformed { line: number };
+            let (old_start, mut old_count, new_start, mut new_count) =
+                parse_hunk_header(header).ok_or(malformed)?;
+            let mut hunk = Hunk {
+                old_start,
+                new_start,
+                lines: Vec::new(),
+            };
+            let mut last = number;
+
+            while old_count > 0 || new_count > 0 {
+                let (line, number) = lines.next().ok_or(PatchError::Malformed { line: last })?;
+                let malformed = PatchError::Malformed { line: number };
+                last = number;
+
+                let (kind, text) = match line.split_at_checked(1) {
+                    Some((" ", text)) => (LineKind::Context, text),
+                    Some(("-", text)) => (LineKind::Removed, text),
+                    Some(("+", text)) => (LineKind::Added, text),
+                    // Some tools strip the space of an empty line of context.
+                    Some(("\n", _)) => (LineKind::Context, "\n"),
+                    _ => return Err(malformed),
+                };
+
+                if kind != LineKind::Added {
+                    old_count = old_count.checked_sub(1).ok_or(malformed)?;
+                }
+
+                if kind != LineKind::Removed {
+                    new_count = new_count.checked_sub(1).ok_or(malformed)?;
+                }
+
+                hunk.lines.push(HunkLine {
+                    kind,
+                    text: text.to_string(),
+                });
+
+                if let Some(&(marker, _)) = lines.peek()
+                    && marker.starts_with('\\')
+                {
+                    lines.next();
+
+                    if let Some(line) = hunk.lines.last_mut()
+                        && line.text.ends_with('\n')
+                    {
+                        line.text.pop();
+                    }
+                }
+            }
+
+            patch.hunks.push(hunk);
+        }
+
+        Ok(patch)
+    }
+}
+
+impl Default for Patch {
+    fn default() -> Self {
+        Self {
+            old_name: String::from("a"),
+            new_name: String::from("b"),
+            hunks: Vec::new(),
+        }
+    }
+}
+
+impl FromStr for Patch {
+    type Err = PatchError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse(text)
+    }
+}
+
+impl fmt::Display for Patch {
+    /// Writes the patch as a unified diff, or nothing if it has no hunks.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.hunks.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(f, "--- {}", self.old_name)?;
+        writeln!(f, "+++ {}", self.new_name)?;
+
+        for hunk in &self.hunks {
+            writeln!(
+                f,
+                "@@ -{} +{} @@",
+                Range(hunk.old_start, hunk.old_count()),
+                Range(hunk.new_start, hunk.new_count())
+            )?;
+
+            for line in &hunk.lines {
+                write!(f, "{}{}", line.kind.prefix(), line.text)?;
+
+                if !line.text.ends_with('\n') {
+                    f.write_str("\n\\ No newline at end of file\n")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The range of a hunk header: the start and the count, which is left out when it is one.
+struct Range(usize, usize);
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // An empty range is numbered by the line before it, so counting from one, by its
+        // index.
+        match self.1 {
+            0 => write!(f, "{},0", self.0),
+            1 => write!(f, "{}", self.0 + 1),
+            count => write!(f, "{},{count}", self.0 + 1),
+        }
+    }
+}
+
+/// Returns the name in a `---` or `+++` header, without a timestamp.
+fn header_name(text: &str) -> String {
+    let name = text.trim_end_matches(['\n', '\r']);
+    name.split_once('\t')
+        .map_or(name, |(name, _)| name)
+        .to_string()
+}
+
+/// Parses `-1,3 +1,4 @@` into the starts, as indices, and counts of both ranges.
+fn parse_hunk_header(header: &str) -> Option<(usize, usize, usize, usize)> {
+    let (ranges, _) = header.split_once(" @@")?;
+    let (old, new) = ranges.split_once(' ')?;
+
+    let range = |text: &str| -> Option<(usize, usize)> {
+        let (start, count) = match text.split_once(',') {
+            Some((start, count)) => (start.parse::<usize>().ok()?, count.parse().ok()?),
+            None => (text.parse().ok()?, 1),
+        };
+
+        Some((
+            if count == 0 {
+                start
+            } else {
+                start.checked_sub(1)?
+            },
+            count,
+        ))
+    };
+
+    let (old_start, old_count) = range(old.strip_prefix('-')?)?;
+    let (new_start, new_count) = range(new.strip_prefix('+')?)?;
+    Some((old_start, old_count, new_start, new_count))
+}
+
+/// Returns the patch that turns `old` into `new`, with `context` unchanged lines around
+/// every change.
+///
+/// Changes closer together than twice the context share a hunk.
+#[must_use]
+pub fn diff(old: &str, new: &str, context: usize) -> Patch {
+    let old: Vec<&str> = old.split_inclusive('\n').collect();
+    let new: Vec<&str> = new.split_inclusive('\n').collect();
+
+    // Every line of both texts in order, with its kind and its index in each text.
+    let mut changes: Vec<(LineKind, usize, usize)> = Vec::new();
+    let (mut x, mut y) = (0, 0);
+
+    for (common_x, common_y) in common_subsequence(&old, &new)
+        .into_iter()
+        .chain([(old.len(), new.len())])
+    {
+        changes.extend((x..common_x).map(|x| (LineKind::Removed, x, y)));
+        changes.extend((y..common_y).map(|y| (LineKind::Added, common_x, y)));
+
+        if common_x < old.len() {
+            changes.push((LineKind::Context, common_x, common_y));
+        }
+
+        (x, y) = (common_x + 1, common_y + 1);
+    }
+
+    let is_change = |index: usize| changes[index].0 != LineKind::Context;
+    let mut hunks = Vec::new();
+    let mut next = 0;
+
+    while let Some(first) = (next..changes.len()).find(|&index| is_change(index)) {
+        let start = first.saturating_sub(context);
+        let mut end = first + 1;
+
+        // Extend the hunk while the next change is within twice the context.
+        while let Some(change) = (end..changes.len()).find(|&index| is_change(index))
+            && change - end <= 2 * context
+        {
+            end = change + 1;
+        }
+
+        end = (end + context).min(changes.len());
+
+        let (_, old_start, new_start) = changes[start];
+        let lines = changes[start..end]
+            .iter()
+            .map(|&(kind, x, y)| HunkLine {
+                kind,
+                text: match kind {
+                    LineKind::Added => new[y],
+                    _ => old[x],
+                }
+                .to_string(),
+            })
+            .collect();
+
+        hunks.push(Hunk {
+            old_start,
+            new_start,
+            lines,
+        });
+        next = end;
+    }
+
+    Patch {
+        hunks,
+        ..Patch::default()
+    }
+}
+
+/// Applies `patch` to `text`, returning the new text.
+///
+/// Each hunk is looked for at the line the patch gives, shifted by how far the previous
+/// hunk was found from its own line, and then at lines further and further away, but
+/// never before the end of the previous hunk. If a hunk is found nowhere, up to `fuzz` of
+/// its first and last lines of context are ignored in turn, as `patch --fuzz` does.
+///
+/// # Errors
+///
+/// Returns [`PatchError::HunkMismatch`] for the first hunk that cannot be found.
+///
+/// # Examples
+///
+/// ```
+/// use libx::text::diff::{apply_patch, diff};
+///
+/// let patch = diff("a\nb\nc\nd\n", "a\nb\nC\nd\n", 1);
+///
+/// // The hunk is found two lines further down, and with fuzz its changed context.
+/// assert_eq!(
+///     apply_patch("new\nlines\na\nb\nc\nd\n", &patch, 0).as_deref(),
+///     Ok("new\nlines\na\nb\nC\nd\n")
+/// );
+/// assert!(apply_patch("a\nB\nc\nd\n", &patch, 0).is_err());
+/// assert_eq!(apply_patch("a\nB\nc\nd\n", &patch, 1).as_deref(), Ok("a\nB\nC\nd\n"));
+/// ```
+pub fn apply_patch(text: &str, patch: &Patch, fuzz: usize) -> Result<String, PatchError> {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let mut output = String::with_capacity(text.len());
+    let mut consumed = 0;
+    let mut offset = 0_isize;
+
+    for (index, hunk) in patch.hunks.iter().enumerate() {
+        let (leading, trailing) = hunk.context();
+
+        let (position, front, back) = (0..=fuzz)
+            .find_map(|fuzz| {
+                let front = fuzz.min(leading);
+                let back = fuzz.min(trailing);
+                let expected = hunk.side(LineKind::Added, front, back);
+                let preferred = (hunk.old_start + front).saturating_add_signed(offset);
+
+                find_near(&lines, &expected, preferred, consumed)
+                    .map(|position| (position, front, back))
+            })
+            .ok_or(PatchError::HunkMismatch { hunk: index })?;
+
+        let removed = hunk.side(LineKind::Added, front, back).len();
+
+        for line in &lines[consumed..position] {
+            output.push_str(line);
+        }
+
+        for line in hunk.side(LineKind::Removed, front, back) {
+            output.push_str(line);
+        }
+
+        offset = position.cast_signed() - (hunk.old_start + front).cast_signed();
+        consumed = position + removed;
+    }
+
+    for line in &lines[consumed..] {
+        output.push_str(line);
+    }
+
+    Ok(output)
+}
+
+/// Returns the position at or after `minimum` closest to `preferred` where `lines` holds
+/// `expected`, preferring the earlier of two equally close positions.
+fn find_near(lines: &[&str], expected: &[&str], preferred: usize, minimum: usize) -> Option<usize> {
+    let last = lines.len().checked_sub(expected.len())?;
+    let matches = |position: usize| lines[position..position + expected.len()] == *expected;
+    let preferred = preferred.clamp(minimum, last.max(minimum));
+
+    (0..=last.max(preferred)).find_map(|distance| {
+        let before = preferred
+            .checked_sub(distance)
+            .filter(|&position| position >= minimum && position <= last);
+        let after = Some(preferred + distance).filter(|&position| position <= last);
+
+        before
+            .filter(|&position| matches(position))
+            .or_else(|| after.filter(|&position| matches(position)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::format;
+
+    use super::*;
+    use crate::{random::RandomNumberGenerator, testing::check_property};
+
+    #[test]
+    fn test_unified_format() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "1\n2\nthree\n4\n5\n6\n7\n8\n9\n10\neleven";
+        let patch = diff(old, new, 2).with_names("old.txt", "new.txt");
+
+        let text = patch.to_string();
+        assert_eq!(
+            text,
+            "--- old.txt\n+++ new.txt\n\
+             @@ -1,5 +1,5 @@\n 1\n 2\n-3\n+three\n 4\n 5\n\
+             @@ -9,2 +9,3 @@\n 9\n 10\n+eleven\n\\ No newline at end of file\n"
+        );
+        assert_eq!(text.parse::<Patch>(), Ok(patch));
+
+        assert!(diff(old, old, 3).is_empty());
+        assert_eq!(diff(old, old, 3).to_string(), "");
+        assert_eq!(
+            diff("", "new\n", 3).to_string(),
+            "--- a\n+++ b\n@@ -0,0 +1 @@\n+new\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_and_foreign_headers() {
+        let git = "diff --git a/f b/f\nindex 1..2 100644\n--- a/f\t2024-01-01\n+++ b/f\n\
+                   @@ -1 +1 @@ fn main\n-x\n+y\n";
+        let patch = Patch::parse(git).expect("the patch is valid");
+
+        assert_eq!(patch.old_name, "a/f");
+        assert_eq!(apply_patch("x\n", &patch, 0).as_deref(), Ok("y\n"));
+
+        assert_eq!(
+            Patch::parse("--- a\n+++ b\n@@ -1,2 +1,2 @@\n-x\n+y\n"),
+            Err(PatchError::Malformed { line: 5 })
+        );
+        assert_eq!(
+            Patch::parse("--- a\n+++ b\n@@ -1 +1 @@\n*x\n"),
+            Err(PatchError::Malformed { line: 4 })
+        );
+        assert_eq!(
+            Patch::parse("--- a\n+++ b\n@@ nonsense\n"),
+            Err(PatchError::Malformed { line: 3 })
+        );
+    }
+
+    #[test]
+    fn test_apply_with_offsets_and_fuzz() {
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nb\nC\nd\ne\nf\nG\nh\n";
+        let patch = diff(old, new, 1);
+        assert_eq!(patch.hunks.len(), 2);
+
+        let shifted = format!("x\ny\n{old}");
+        assert_eq!(apply_patch(&shifted, &patch, 0), Ok(format!("x\ny\n{new}")));
+        assert_eq!(
+            apply_patch("a\nb\nc\nd\ne\nF\ng\nh\n", &patch, 0),
+            Err(PatchError::HunkMismatch { hunk: 1 })
+        );
+        assert_eq!(
+            apply_patch("a\nb\nc\nd\ne\nF\ng\nh\n", &patch, 1).as_deref(),
+            Ok("a\nb\nC\nd\ne\nF\nG\nh\n")
+        );
+        assert_eq!(
+            apply_patch("unrelated\n", &patch, 3),
+            Err(PatchError::HunkMismatch { hunk: 0 })
+        );
+    }
+
+    #[test]
+    fn test_diff_then_apply_reproduces_new() {
+        let result = check_property(0x1984, 300, |rng| {
+            let text = |rng: &mut crate::random::SplitMix64| -> String {
+                let mut text = String::new();
+
+                for _ in 0..rng.next_bounded(12) {
+                    let line = usize::try_from(rng.next_bounded(4)).unwrap_or(0);
+                    text.push_str(["0\n", "1\n", "2\n", "3\n"][line]);
+                }
+
+                if rng.next_bool() {
+                    text.pop();
+                }
+
+                text
+            };
+
+            let old = text(rng);
+            let new = text(rng);
+            let context = usize::try_from(rng.next_bounded(4)).unwrap_or(0);
+            let patch = diff(&old, &new, context);
+            let parsed: Patch = patch
+                .to_string()
+                .parse()
+                .map_err(|error| format!("{error}"))?;
+
+            match apply_patch(&old, &parsed, 0) {
+                Ok(applied) if applied == new => Ok(()),
+                other => Err(format!("{old:?} -> {new:?} produced {other:?}").into()),
+            }
+        });
+
+        assert_eq!(result, Ok(()));
+    }
+}
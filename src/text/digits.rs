@@ -0,0 +1,147 @@
+//! Conversion between ASCII digits and the digits of other numbering systems.
+//!
+//! Text from locales that do not write numbers with `0`–`9`, such as `٢٠٢٤` in Arabic or
+//! `२०२४` in Marathi, can be turned into ASCII with [`to_ascii`] before it is parsed, and
+//! ASCII output can be written in a [`NumberingSystem`] with [`localize`]. [`detect`]
+//! finds which system a text uses.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::{locale::NumberingSystem, text::digits};
+//!
+//! assert_eq!(digits::to_ascii("٢٠٢٤-٠٣-١٥"), "2024-03-15");
+//! assert_eq!(digits::localize("Room 42", NumberingSystem::Thai), "Room ๔๒");
+//! assert_eq!(digits::detect("१२ km"), Some(NumberingSystem::Devanagari));
+//! ```
+
+use alloc::{borrow::Cow, string::String};
+
+use crate::locale::NumberingSystem;
+
+/// Every numbering system, with Latin first.
+const SYSTEMS: [NumberingSystem; 7] = [
+    NumberingSystem::Latin,
+    NumberingSystem::ArabicIndic,
+    NumberingSystem::ExtendedArabicIndic,
+    NumberingSystem::Bengali,
+    NumberingSystem::Devanagari,
+    NumberingSystem::Thai,
+    NumberingSystem::Fullwidth,
+];
+
+/// Returns the numbering system and value of `character` if it is a decimal digit of a
+/// known system.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{locale::NumberingSystem, text::digits};
+///
+/// assert_eq!(digits::decode('٧'), Some((NumberingSystem::ArabicIndic, 7)));
+/// assert_eq!(digits::decode('x'), None);
+/// ```
+#[must_use]
+pub fn decode(character: char) -> Option<(NumberingSystem, u32)> {
+    SYSTEMS.into_iter().find_map(|system| {
+        // The digits of every system are consecutive code points.
+        let offset = u32::from(character).checked_sub(u32::from(system.digits()[0]))?;
+        (offset < 10).then_some((system, offset))
+    })
+}
+
+/// Returns the value of `character` if it is a decimal digit of any known system.
+#[must_use]
+pub fn value(character: char) -> Option<u32> {
+    decode(character).map(|(_, value)| value)
+}
+
+/// Returns the numbering system of the first digit in `text`, or `None` if it has no
+/// digits.
+#[must_use]
+pub fn detect(text: &str) -> Option<NumberingSystem> {
+    text.chars().find_map(decode).map(|(system, _)| system)
+}
+
+/// Replaces the digits of every known system in `text` with ASCII digits.
+///
+/// Text without such digits is returned unchanged, without allocating.
+#[must_use]
+pub fn to_ascii(text: &str) -> Cow<'_, str> {
+    convert(text, |character| {
+        decode(character)
+            .filter(|&(system, _)| system != NumberingSystem::Latin)
+            .map(|(_, value)| NumberingSystem::Latin.digits()[value as usize])
+    })
+}
+
+/// Replaces the ASCII digits in `text` with the digits of `system`.
+///
+/// Text without ASCII digits, and any text for [`NumberingSystem::Latin`], is returned
+/// unchanged, without allocating.
+#[must_use]
+pub fn localize(text: &str, system: NumberingSystem) -> Cow<'_, str> {
+    if system == NumberingSystem::Latin {
+        return Cow::Borrowed(text);
+    }
+
+    convert(text, |character| {
+        character
+            .is_ascii_digit()
+            .then(|| system.localize(character))
+    })
+}
+
+/// Replaces the characters of `text` that `replacement` maps to another character.
+fn convert(text: &str, replacement: impl Fn(char) -> Option<char>) -> Cow<'_, str> {
+    let Some(first) = text.find(|character| replacement(character).is_some()) else {
+        return Cow::Borrowed(text);
+    };
+
+    let mut output = String::with_capacity(text.len());
+    output.push_str(&text[..first]);
+    output.extend(
+        text[first..]
+            .chars()
+            .map(|character| replacement(character).unwrap_or(character)),
+    );
+
+    Cow::Owned(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip_through_every_system() {
+        for system in SYSTEMS {
+            let localized = localize("v1.23-456789", system);
+
+            assert_eq!(to_ascii(&localized), "v1.23-456789");
+            assert_eq!(detect(&localized), Some(system));
+
+            for (value, digit) in (0..).zip(system.digits()) {
+                assert_eq!(decode(digit), Some((system, value)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_text_without_digits_is_borrowed() {
+        assert!(matches!(to_ascii("no digits"), Cow::Borrowed(_)));
+        assert!(matches!(to_ascii("123"), Cow::Borrowed(_)));
+        assert!(matches!(
+            localize("123", NumberingSystem::Latin),
+            Cow::Borrowed(_)
+        ));
+        assert!(matches!(
+            localize("none", NumberingSystem::Thai),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(detect("none"), None);
+        assert_eq!(value('٠'), Some(0));
+        assert_eq!(value('Ⅷ'), None);
+        assert_eq!(to_ascii("１２３ and ٤٥"), "123 and 45");
+    }
+}
@@ -0,0 +1,656 @@
+//! Escaping and unescaping text for JSON strings, HTML, POSIX shells and C strings.
+//!
+//! Every format has a pair of functions: [`escape_json`] and [`unescape_json`],
+//! [`escape_html`] and [`unescape_html`], [`quote_shell`] and [`unquote_shell`], and
+//! [`escape_c`] and [`unescape_c`]. JSON and C strings are handled without their
+//! surrounding quotes. Text that needs no change is returned borrowed.
+//!
+//! Decoding, and quoting for a shell, take a [`Mode`]. In [`Mode::Strict`] anything the
+//! format does not allow is an error. In [`Mode::Lossy`] malformed escape sequences are
+//! kept as written, escapes of values that are not characters become U+FFFD, and
+//! characters that should have been escaped are kept, so decoding never fails.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::text::escape::{self, Mode};
+//!
+//! assert_eq!(escape::escape_json("say \"hi\"\n"), r#"say \"hi\"\n"#);
+//! assert_eq!(
+//!     escape::unescape_json(r"caf\u00e9", Mode::Strict).as_deref(),
+//!     Ok("café")
+//! );
+//!
+//! assert_eq!(escape::escape_html("<a href='x'>"), "&lt;a href=&#39;x&#39;&gt;");
+//! assert_eq!(
+//!     escape::unescape_html("&copy; 2024 &amp; on", Mode::Strict).as_deref(),
+//!     Ok("© 2024 & on")
+//! );
+//!
+//! assert_eq!(escape::quote_shell("it's", Mode::Strict).as_deref(), Ok(r"'it'\''s'"));
+//! assert_eq!(
+//!     escape::unquote_shell(r#""a b"'c'\ d"#, Mode::Strict).as_deref(),
+//!     Ok("a bc d")
+//! );
+//!
+//! assert_eq!(escape::escape_c("tab\there"), r"tab\there");
+//! assert_eq!(
+//!     escape::unescape_c(r"\x41\102\u00e9", Mode::Strict).as_deref(),
+//!     Ok("ABé")
+//! );
+//! ```
+
+use alloc::{borrow::Cow, string::String};
+use core::fmt::Write;
+
+use crate::errors::EscapeError;
+
+/// How decoding treats input that the format does not allow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Mode {
+    /// Input the format does not allow is an error.
+    #[default]
+    Strict,
+
+    /// Input the format does not allow is kept as written, or replaced with U+FFFD if it
+    /// is an escape of a value that is not a character.
+    Lossy,
+}
+
+impl Mode {
+    /// Returns `error` in strict mode and `fallback` in lossy mode.
+    fn fail<T>(self, error: EscapeError, fallback: T) -> Result<T, EscapeError> {
+        match self {
+            Self::Strict => Err(error),
+            Self::Lossy => Ok(fallback),
+        }
+    }
+}
+
+/// The named character references [`unescape_html`] knows.
+const ENTITIES: [(&str, char); 32] = [
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+    ("nbsp", '\u{a0}'),
+    ("iexcl", '¡'),
+    ("cent", '¢'),
+    ("pound", '£'),
+    ("yen", '¥'),
+    ("sect", '§'),
+    ("copy", '©'),
+    ("laquo", '«'),
+    ("reg", '®'),
+    ("deg", '°'),
+    ("plusmn", '±'),
+    ("para", '¶'),
+    ("middot", '·'),
+    ("raquo", '»'),
+    ("iquest", '¿'),
+    ("times", '×'),
+    ("divide", '÷'),
+    ("ndash", '–'),
+    ("mdash", '—'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("bull", '•'),
+    ("hellip", '…'),
+    ("euro", '€'),
+    ("trade", '™'),
+];
+
+/// Escapes `text` for use inside a JSON string.
+///
+/// Quotes, backslashes and control characters are escaped, with the short forms such as
+/// `\n` where JSON has one and `\u00XX` otherwise. Everything else is kept.
+#[must_use]
+pub fn escape_json(text: &str) -> Cow<'_, str> {
+    escape_with(
+        text,
+        |character| matches!(character, '"' | '\\') || character < ' ',
+        |output, character| match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{c}' => output.push_str("\\f"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            _ => {
+                let _ = write!(output, "\\u{:04x}", u32::from(character));
+            }
+        },
+    )
+}
+
+/// Decodes the escape sequences of the inside of a JSON string.
+///
+/// A UTF-16 surrogate pair written as two `\u` escapes is joined into one character.
+///
+/// # Errors
+///
+/// In strict mode, returns [`EscapeError::InvalidEscape`] for an unknown or incomplete
+/// escape, or a `\u` escape of a lone surrogate, and [`EscapeError::InvalidCharacter`]
+/// for an unescaped quote or control character.
+pub fn unescape_json(text: &str, mode: Mode) -> Result<Cow<'_, str>, EscapeError> {
+    unescape_with(
+        text,
+        mode,
+        '\\',
+        |character| character != '"' && character >= ' ',
+        |rest| {
+            let value = match rest.as_bytes().first()? {
+                b'"' => u32::from('"'),
+                b'\\' => u32::from('\\'),
+                b'/' => u32::from('/'),
+                b'b' => 0x8,
+                b'f' => 0xc,
+                b'n' => u32::from('\n'),
+                b'r' => u32::from('\r'),
+                b't' => u32::from('\t'),
+                b'u' => {
+                    let high = exact_digits(&rest[1..], 16, 4)?;
+
+                    if (0xd800..0xdc00).contains(&high)
+                        && let Some(low) = rest[5..]
+                            .strip_prefix("\\u")
+                            .and_then(|rest| exact_digits(rest, 16, 4))
+                            .filter(|low| (0xdc00..0xe000).contains(low))
+                    {
+                        return Some((0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00), 11));
+                    }
+
+                    return Some((high, 5));
+                }
+                _ => return None,
+            };
+
+            Some((value, 1))
+        },
+    )
+}
+
+/// Escapes `text` for use in HTML text or a quoted attribute value.
+///
+/// `&`, `<`, `>`, `"` and `'` are replaced with character references.
+#[must_use]
+pub fn escape_html(text: &str) -> Cow<'_, str> {
+    escape_with(
+        text,
+        |character| matches!(character, '&' | '<' | '>' | '"' | '\''),
+        |output, character| {
+            output.push_str(match character {
+                '&' => "&amp;",
+                '<' => "&lt;",
+                '>' => "&gt;",
+                '"' => "&quot;",
+                _ => "&#39;",
+            });
+        },
+    )
+}
+
+/// Decodes the character references of HTML text.
+///
+/// Decimal and hexadecimal references such as `&#233;` and `&#xe9;` are decoded, as are
+/// the named references of the markup characters and of common Latin-1 and typographic
+/// symbols, such as `&amp;`, `&nbsp;`, `&copy;` and `&mdash;`. A reference must end with
+/// a semicolon.
+///
+/// # Errors
+///
+/// In strict mode, returns [`EscapeError::InvalidEscape`] for an unknown or unterminated
+/// reference, and for a numeric reference to zero, a surrogate or a value beyond
+/// U+10FFFF.
+pub fn unescape_html(text: &str, mode: Mode) -> Result<Cow<'_, str>, EscapeError> {
+    unescape_with(
+        text,
+        mode,
+        '&',
+        |_| true,
+        |rest| {
+            if let Some(number) = rest.strip_prefix('#') {
+                let (radix, digits) = number
+                    .strip_prefix(['x', 'X'])
+                    .map_or((10, number), |digits| (16, digits));
+                let (value, length) = leading_digits(digits, radix, usize::MAX);
+
+                if length == 0 || !digits[length..].starts_with(';') {
+                    return None;
+                }
+
+                // HTML replaces a reference to zero like one to a surrogate.
+                let value = if value == 0 { u32::MAX } else { value };
+                return Some((value, rest.len() - digits.len() + length + 1));
+            }
+
+            let (name, _) = rest.split_once(';')?;
+            ENTITIES
+                .iter()
+                .find(|(entity, _)| *entity == name)
+                .map(|&(_, character)| (u32::from(character), name.len() + 1))
+        },
+    )
+}
+
+/// Quotes `text` as a single word for a POSIX shell.
+///
+/// Text made only of letters, digits and `_@%+=:,./-` is returned as it is. Anything else
+/// is wrapped in single quotes, with every single quote written as `'\''`.
+///
+/// # Errors
+///
+/// A shell cannot pass a NUL character in an argument. In strict mode, returns
+/// [`EscapeError::InvalidCharacter`] for the first one; in lossy mode, they are removed.
+pub fn quote_shell(text: &str, mode: Mode) -> Result<Cow<'_, str>, EscapeError> {
+    let is_safe =
+        |character: char| character.is_ascii_alphanumeric() || "_@%+=:,./-".contains(character);
+
+    if !text.is_empty() && text.chars().all(is_safe) {
+        return Ok(Cow::Borrowed(text));
+    }
+
+    let mut output = String::with_capacity(text.len() + 2);
+    output.push('\'');
+
+    for (position, character) in text.char_indices() {
+        match character {
+            '\'' => output.push_str("'\\''"),
+            '\0' => mode.fail(EscapeError::InvalidCharacter { position }, ())?,
+            _ => output.push(character),
+        }
+    }
+
+    output.push('\'');
+    Ok(Cow::Owned(output))
+}
+
+/// Removes the quoting of a single POSIX shell word.
+///
+/// Single quotes keep everything up to the next single quote. Double quotes keep
+/// everything up to the next double quote, except that a backslash escapes `$`, `` ` ``,
+/// `"`, `\` and a line feed. Outside quotes a backslash escapes any character. An
+/// escaped line feed is removed.
+///
+/// # Errors
+///
+/// In strict mode, returns [`EscapeError::UnterminatedQuote`] for a quote that is not
+/// closed, [`EscapeError::InvalidEscape`] for a trailing backslash, and
+/// [`EscapeError::InvalidCharacter`] for a character the shell would interpret rather
+/// than keep: unquoted whitespace or any of ``|&;<>()$`*?[``, or `$` or `` ` `` inside
+/// double quotes.
+pub fn unquote_shell(text: &str, mode: Mode) -> Result<Cow<'_, str>, EscapeError> {
+    let is_special =
+        |character: char| character.is_ascii_whitespace() || "|&;<>()$`*?[".contains(character);
+
+    if !text.contains(|character| is_special(character) || "\\'\"".contains(character)) {
+        return Ok(Cow::Borrowed(text));
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut characters = text.char_indices();
+
+    while let Some((position, character)) = characters.next() {
+        match character {
+            '\'' => loop {
+                match characters.next() {
+                    Some((_, '\'')) => break,
+                    Some((_, character)) => output.push(character),
+                    None => break mode.fail(EscapeError::UnterminatedQuote { position }, ())?,
+                }
+            },
+            '"' => loop {
+                match characters.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match characters.clone().next() {
+                        Some((_, '\n')) => {
+                            characters.next();
+                        }
+                        Some((_, escaped @ ('$' | '`' | '"' | '\\'))) => {
+                            characters.next();
+                            output.push(escaped);
+                        }
+                        _ => output.push('\\'),
+                    },
+                    Some((position, character @ ('$' | '`'))) => {
+                        mode.fail(EscapeError::InvalidCharacter { position }, ())?;
+                        output.push(character);
+                    }
+                    Some((_, character)) => output.push(character),
+                    None => break mode.fail(EscapeError::UnterminatedQuote { position }, ())?,
+                }
+            },
+            '\\' => match characters.next() {
+                Some((_, '\n')) => {}
+                Some((_, escaped)) => output.push(escaped),
+                None => {
+                    mode.fail(EscapeError::InvalidEscape { position }, ())?;
+                    output.push('\\');
+                }
+            },
+            _ => {
+                if is_special(character) {
+                    mode.fail(EscapeError::InvalidCharacter { position }, ())?;
+                }
+
+                output.push(character);
+            }
+        }
+    }
+
+    Ok(Cow::Owned(output))
+}
+
+/// Escapes `text` for use inside a C string literal.
+///
+/// Quotes, backslashes and control characters are escaped, with the short forms such as
+/// `\n` where C has one and three octal digits otherwise, so a following digit is never
+/// taken as part of the escape. Characters outside ASCII are kept.
+#[must_use]
+pub fn escape_c(text: &str) -> Cow<'_, str> {
+    escape_with(
+        text,
+        |character| matches!(character, '"' | '\\') || character.is_ascii_control(),
+        |output, character| match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\u{7}' => output.push_str("\\a"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{c}' => output.push_str("\\f"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{b}' => output.push_str("\\v"),
+            _ => {
+                let _ = write!(output, "\\{:03o}", u32::from(character));
+            }
+        },
+    )
+}
+
+/// Decodes the escape sequences of the inside of a C string literal.
+///
+/// The simple escapes such as `\n` and `\?`, octal escapes of one to three digits,
+/// hexadecimal `\x` escapes of any length and the universal character names `\u` and
+/// `\U` are decoded. The value of an octal or hexadecimal escape is taken as a Unicode
+/// scalar value rather than a byte, so `\xe9` is `é`.
+///
+/// # Errors
+///
+/// In strict mode, returns [`EscapeError::InvalidEscape`] for an unknown or incomplete
+/// escape or one whose value is not a character, and [`EscapeError::InvalidCharacter`]
+/// for an unescaped quote or line feed.
+pub fn unescape_c(text: &str, mode: Mode) -> Result<Cow<'_, str>, EscapeError> {
+    unescape_with(
+        text,
+        mode,
+        '\\',
+        |character| !matches!(character, '"' | '\n'),
+        |rest| {
+            let value = match rest.as_bytes().first()? {
+                byte @ (b'"' | b'\'' | b'?' | b'\\') => u32::from(*byte),
+                b'a' => 0x7,
+                b'b' => 0x8,
+                b'f' => 0xc,
+                b'n' => u32::from('\n'),
+                b'r' => u32::from('\r'),
+                b't' => u32::from('\t'),
+                b'v' => 0xb,
+                b'0'..=b'7' => return Some(leading_digits(rest, 8, 3)),
+                b'x' => {
+                    let (value, length) = leading_digits(&rest[1..], 16, usize::MAX);
+                    return (length > 0).then_some((value, length + 1));
+                }
+                b'u' => return exact_digits(&rest[1..], 16, 4).map(|value| (value, 5)),
+                b'U' => return exact_digits(&rest[1..], 16, 8).map(|value| (value, 9)),
+                _ => return None,
+            };
+
+            Some((value, 1))
+        },
+    )
+}
+
+/// Returns `text` with every character for which `needs_escape` holds replaced by what
+/// `write_escape` writes.
+fn escape_with(
+    text: &str,
+    needs_escape: impl Fn(char) -> bool,
+    write_escape: impl Fn(&mut String, char),
+) -> Cow<'_, str> {
+    if !text.contains(&needs_escape) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut output = String::with_capacity(text.len() + text.len() / 8);
+
+    for character in text.chars() {
+        if needs_escape(character) {
+            write_escape(&mut output, character);
+        } else {
+            output.push(character);
+        }
+    }
+
+    Cow::Owned(output)
+}
+
+/// Decodes the escapes of `text`, which start with `introducer`.
+///
+/// `decode` is given the text after an introducer and returns the value of the escape and
+/// the number of bytes it spans, or `None` if it is malformed. Characters for which
+/// `is_allowed` does not hold must not appear unescaped.
+fn unescape_with(
+    text: &str,
+    mode: Mode,
+    introducer: char,
+    is_allowed: impl Fn(char) -> bool,
+    decode: impl Fn(&str) -> Option<(u32, usize)>,
+) -> Result<Cow<'_, str>, EscapeError> {
+    let needs_work = |character: char| character == introducer || !is_allowed(character);
+
+    if !text.contains(needs_work) {
+        return Ok(Cow::Borrowed(text));
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while let Some((offset, character)) = text[index..]
+        .char_indices()
+        .find(|&(_, character)| needs_work(character))
+    {
+        let position = index + offset;
+        output.push_str(&text[index..position]);
+        index = position + character.len_utf8();
+
+        if character != introducer {
+            mode.fail(EscapeError::InvalidCharacter { position }, ())?;
+            output.push(character);
+            continue;
+        }
+
+        let error = EscapeError::InvalidEscape { position };
+
+        if let Some((value, length)) = decode(&text[index..]) {
+            index += length;
+            output.push(match char::from_u32(value) {
+                Some(character) => character,
+                None => mode.fail(error, char::REPLACEMENT_CHARACTER)?,
+            });
+        } else {
+            mode.fail(error, ())?;
+            output.push(introducer);
+        }
+    }
+
+    output.push_str(&text[index..]);
+    Ok(Cow::Owned(output))
+}
+
+/// Returns the value of the digits in `radix` at the start of `text`, at most `limit` of
+/// them, and how many there are. A value too large for a `u32` is `u32::MAX`, which is not
+/// a character.
+pub(crate) fn leading_digits(text: &str, radix: u32, limit: usize) -> (u32, usize) {
+    let mut value = 0_u32;
+    let mut length = 0;
+
+    for digit in text
+        .chars()
+        .take(limit)
+        .map_while(|digit| digit.to_digit(radix))
+    {
+        value = value
+            .checked_mul(radix)
+            .and_then(|value| value.checked_add(digit))
+            .unwrap_or(u32::MAX);
+        length += 1;
+    }
+
+    (value, length)
+}
+
+/// Returns the value of exactly `count` digits in `radix` at the start of `text`.
+fn exact_digits(text: &str, radix: u32, count: usize) -> Option<u32> {
+    let (value, length) = leading_digits(text, radix, count);
+    (length == count).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json() {
+        let text = "\"quoted\" \\ \u{1}\t\u{1f600}";
+        let escaped = escape_json(text);
+        assert_eq!(escaped, r#"\"quoted\" \\ \u0001\t😀"#);
+        assert_eq!(unescape_json(&escaped, Mode::Strict).as_deref(), Ok(text));
+
+        assert!(matches!(escape_json("plain"), Cow::Borrowed(_)));
+        assert_eq!(
+            unescape_json(r"\ud83d\ude00\/", Mode::Strict).as_deref(),
+            Ok("😀/")
+        );
+
+        assert_eq!(
+            unescape_json(r"a\q", Mode::Strict),
+            Err(EscapeError::InvalidEscape { position: 1 })
+        );
+        assert_eq!(
+            unescape_json("a\"", Mode::Strict),
+            Err(EscapeError::InvalidCharacter { position: 1 })
+        );
+        assert_eq!(
+            unescape_json(r"a\q\u12", Mode::Lossy).as_deref(),
+            Ok(r"a\q\u12")
+        );
+        assert_eq!(
+            unescape_json(r"\ud800x", Mode::Lossy).as_deref(),
+            Ok("\u{fffd}x")
+        );
+    }
+
+    #[test]
+    fn test_html() {
+        let text = "<p class=\"a\">Tom & Jerry's</p>";
+        let escaped = escape_html(text);
+        assert_eq!(
+            escaped,
+            "&lt;p class=&quot;a&quot;&gt;Tom &amp; Jerry&#39;s&lt;/p&gt;"
+        );
+        assert_eq!(unescape_html(&escaped, Mode::Strict).as_deref(), Ok(text));
+
+        assert_eq!(
+            unescape_html("&#233;&#xE9;&#X1F600;&hellip;", Mode::Strict).as_deref(),
+            Ok("éé😀…")
+        );
+        assert_eq!(
+            unescape_html("AT&T", Mode::Strict),
+            Err(EscapeError::InvalidEscape { position: 2 })
+        );
+        assert_eq!(
+            unescape_html("&#0;", Mode::Strict),
+            Err(EscapeError::InvalidEscape { position: 0 })
+        );
+        assert_eq!(
+            unescape_html("AT&T &unknown; &#; &#xD800; &#99999999999;", Mode::Lossy).as_deref(),
+            Ok("AT&T &unknown; &#; \u{fffd} \u{fffd}")
+        );
+    }
+
+    #[test]
+    fn test_shell() {
+        assert_eq!(
+            quote_shell("./file-1.txt", Mode::Strict).as_deref(),
+            Ok("./file-1.txt")
+        );
+        assert_eq!(quote_shell("", Mode::Strict).as_deref(), Ok("''"));
+        assert_eq!(quote_shell("a b", Mode::Strict).as_deref(), Ok("'a b'"));
+        assert_eq!(
+            quote_shell("a\0b", Mode::Strict),
+            Err(EscapeError::InvalidCharacter { position: 1 })
+        );
+        assert_eq!(quote_shell("a\0b", Mode::Lossy).as_deref(), Ok("'ab'"));
+
+        for text in ["", "it's", "$HOME `pwd` \"x\" \\", "new\nline"] {
+            let quoted = quote_shell(text, Mode::Strict).expect("the text has no NUL");
+            assert_eq!(unquote_shell(&quoted, Mode::Strict).as_deref(), Ok(text));
+        }
+
+        assert_eq!(
+            unquote_shell(r#""\$\a\"" \\"#, Mode::Lossy).as_deref(),
+            Ok(r#"$\a" \"#)
+        );
+        assert_eq!(
+            unquote_shell("'open", Mode::Strict),
+            Err(EscapeError::UnterminatedQuote { position: 0 })
+        );
+        assert_eq!(
+            unquote_shell("\"$HOME\"", Mode::Strict),
+            Err(EscapeError::InvalidCharacter { position: 1 })
+        );
+        assert_eq!(
+            unquote_shell("a b", Mode::Strict),
+            Err(EscapeError::InvalidCharacter { position: 1 })
+        );
+        assert_eq!(
+            unquote_shell("a\\", Mode::Strict),
+            Err(EscapeError::InvalidEscape { position: 1 })
+        );
+        assert_eq!(unquote_shell("'open", Mode::Lossy).as_deref(), Ok("open"));
+    }
+
+    #[test]
+    fn test_c() {
+        let text = "\"bell\"\u{7}\u{1b}1\\ é";
+        let escaped = escape_c(text);
+        assert_eq!(escaped, r#"\"bell\"\a\0331\\ é"#);
+        assert_eq!(unescape_c(&escaped, Mode::Strict).as_deref(), Ok(text));
+
+        assert_eq!(
+            unescape_c(r"\0\7\101\x4a\U0001F600\?\'", Mode::Strict).as_deref(),
+            Ok("\0\u{7}AJ😀?'")
+        );
+        assert_eq!(
+            unescape_c(r"\q", Mode::Strict),
+            Err(EscapeError::InvalidEscape { position: 0 })
+        );
+        assert_eq!(
+            unescape_c(r"\x", Mode::Strict),
+            Err(EscapeError::InvalidEscape { position: 0 })
+        );
+        assert_eq!(
+            unescape_c("line\n", Mode::Strict),
+            Err(EscapeError::InvalidCharacter { position: 4 })
+        );
+        assert_eq!(
+            unescape_c(r"\q\xffffffffff", Mode::Lossy).as_deref(),
+            Ok("\\q\u{fffd}")
+        );
+    }
+}
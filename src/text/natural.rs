@@ -0,0 +1,347 @@
+//! Natural ordering of strings with embedded numbers.
+//!
+//! [`natural_cmp`] compares runs of ASCII digits by their numeric value and everything
+//! else character by character, so `file2` sorts before `file10` as a person would
+//! expect. [`NaturalOrder`] adds case-insensitive comparison and a collation for a
+//! locale, which sorts accented letters with their base letters and places the letters
+//! that some languages treat as separate, such as Swedish `ä`, where those languages
+//! put them.
+//!
+//! Numbers of any length are compared exactly. Numbers that differ only in leading zeros
+//! compare equal until every other difference is exhausted, and then the one with fewer
+//! zeros comes first.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::text::natural::{natural_cmp, sort_naturally};
+//!
+//! assert!(natural_cmp("file2", "file10").is_lt());
+//!
+//! let mut files = ["track10.mp3", "track9.mp3", "Track1.mp3", "track01.mp3"];
+//! sort_naturally(&mut files);
+//! assert_eq!(files, ["Track1.mp3", "track01.mp3", "track9.mp3", "track10.mp3"]);
+//! ```
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use super::transliterate;
+use crate::locale::Locale;
+
+/// Compares `a` and `b` with numbers ordered by value and other characters by code
+/// point.
+#[must_use]
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    NaturalOrder::new().compare(a, b)
+}
+
+/// Compares `a` and `b` like [`natural_cmp`], ignoring case.
+///
+/// # Examples
+///
+/// ```
+/// use libx::text::natural::natural_cmp_ignore_case;
+///
+/// assert!(natural_cmp_ignore_case("IMG_9.png", "img_12.png").is_lt());
+/// assert!(natural_cmp_ignore_case("README", "readme").is_eq());
+/// ```
+#[must_use]
+pub fn natural_cmp_ignore_case(a: &str, b: &str) -> Ordering {
+    NaturalOrder::new().with_ignores_case(true).compare(a, b)
+}
+
+/// Sorts `items` in natural order, keeping equal items in their original order.
+pub fn sort_naturally<S: AsRef<str>>(items: &mut [S]) {
+    NaturalOrder::new().sort(items);
+}
+
+/// A configurable natural ordering of strings.
+///
+/// # Examples
+///
+/// ```
+/// use libx::locale::Locale;
+/// use libx::text::natural::NaturalOrder;
+///
+/// let mut names = ["Öberg 2", "Zander", "Olsson", "Öberg 10", "Åkesson"];
+///
+/// NaturalOrder::new().with_locale(&Locale::new("de_DE")).sort(&mut names);
+/// assert_eq!(names, ["Åkesson", "Öberg 2", "Öberg 10", "Olsson", "Zander"]);
+///
+/// NaturalOrder::new().with_locale(&Locale::new("sv_SE")).sort(&mut names);
+/// assert_eq!(names, ["Olsson", "Zander", "Åkesson", "Öberg 2", "Öberg 10"]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NaturalOrder {
+    ignores_case: bool,
+    collation: Option<Collation>,
+}
+
+/// The letters a language sorts apart from their base letters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Collation {
+    tailoring: &'static [(char, u32)],
+}
+
+/// A unit of comparison: a run of digits or the weight of one character.
+#[derive(Debug, Clone, Copy)]
+enum Key<'a> {
+    Number { digits: &'a str, zeros: usize },
+    Weight(u32),
+}
+
+impl NaturalOrder {
+    /// Creates an ordering that compares characters other than digits by code point and
+    /// respects case.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            ignores_case: false,
+            collation: None,
+        }
+    }
+
+    /// Sets whether letters that differ only in case compare equal.
+    #[must_use]
+    pub const fn with_ignores_case(mut self, ignores_case: bool) -> Self {
+        self.ignores_case = ignores_case;
+        self
+    }
+
+    /// Compares characters by the collation of `locale`'s language instead of by code
+    /// point.
+    ///
+    /// Letters are compared first without accents or case, then by accent, then with
+    /// lowercase before uppercase. Danish, Norwegian, Swedish, Finnish and Spanish move
+    /// the letters they treat as separate to their place in those alphabets.
+    #[must_use]
+    pub fn with_locale(mut self, locale: &Locale) -> Self {
+        self.collation = Some(Collation {
+            tailoring: tailoring(locale.language_code()),
+        });
+        self
+    }
+
+    /// Returns `true` if letters that differ only in case compare equal.
+    #[must_use]
+    pub const fn ignores_case(&self) -> bool {
+        self.ignores_case
+    }
+
+    /// Compares `a` and `b`.
+    #[must_use]
+    pub fn compare(&self, a: &str, b: &str) -> Ordering {
+        let (a_keys, b_keys) = (self.keys(a), self.keys(b));
+        let zero = self.weights('0').next().unwrap_or(0);
+
+        let primary = a_keys
+            .iter()
+            .zip(&b_keys)
+            .map(|pair| match pair {
+                (Key::Number { digits: x, .. }, Key::Number { digits: y, .. }) => {
+                    x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+                }
+                (Key::Number { .. }, Key::Weight(weight)) => zero.cmp(weight),
+                (Key::Weight(weight), Key::Number { .. }) => weight.cmp(&zero),
+                (Key::Weight(x), Key::Weight(y)) => x.cmp(y),
+            })
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a_keys.len().cmp(&b_keys.len()));
+
+        let zeros = || {
+            a_keys
+                .iter()
+                .zip(&b_keys)
+                .filter_map(|pair| match pair {
+                    (Key::Number { zeros: x, .. }, Key::Number { zeros: y, .. }) => Some(x.cmp(y)),
+                    _ => None,
+                })
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(Ordering::Equal)
+        };
+
+        let ordering = primary.then_with(zeros);
+
+        if self.collation.is_none() {
+            return ordering;
+        }
+
+        let accents = || {
+            let fold = |text: &str| {
+                text.chars()
+                    .flat_map(char::to_lowercase)
+                    .collect::<Vec<_>>()
+            };
+            fold(a).cmp(&fold(b))
+        };
+        let case = || {
+            if self.ignores_case {
+                Ordering::Equal
+            } else {
+                a.chars()
+                    .map(char::is_uppercase)
+                    .cmp(b.chars().map(char::is_uppercase))
+            }
+        };
+
+        ordering.then_with(accents).then_with(case)
+    }
+
+    /// Sorts `items`, keeping equal items in their original order.
+    pub fn sort<S: AsRef<str>>(&self, items: &mut [S]) {
+        items.sort_by(|a, b| self.compare(a.as_ref(), b.as_ref()));
+    }
+
+    /// Splits `text` into digit runs and character weights.
+    fn keys<'a>(&self, text: &'a str) -> Vec<Key<'a>> {
+        let mut keys = Vec::with_capacity(text.len());
+        let mut characters = text.char_indices().peekable();
+
+        while let Some((start, character)) = characters.next() {
+            if character.is_ascii_digit() {
+                let mut end = start + 1;
+
+                while let Some((index, _)) = characters.next_if(|(_, c)| c.is_ascii_digit()) {
+                    end = index + 1;
+                }
+
+                let run = &text[start..end];
+                let digits = run.trim_start_matches('0');
+                keys.push(Key::Number {
+                    digits,
+                    zeros: run.len() - digits.len(),
+                });
+            } else {
+                keys.extend(self.weights(character).map(Key::Weight));
+            }
+        }
+
+        keys
+    }
+
+    /// Returns the primary weights of `character`: its code point, lowercased if case is
+    /// ignored, or under a collation its tailored weight or those of its unaccented
+    /// lowercase spelling.
+    fn weights(&self, character: char) -> impl Iterator<Item = u32> {
+        let Some(collation) = self.collation else {
+            let weights: Vec<u32> = if self.ignores_case {
+                character.to_lowercase().map(u32::from).collect()
+            } else {
+                alloc::vec![u32::from(character)]
+            };
+            return weights.into_iter();
+        };
+
+        let mut weights = Vec::new();
+
+        for lower in character.to_lowercase() {
+            if let Some(&(_, weight)) = collation
+                .tailoring
+                .iter()
+                .find(|&&(letter, _)| letter == lower)
+            {
+                weights.push(weight);
+            } else if let Some(spelling) = transliterate::builtin(lower) {
+                weights.extend(
+                    spelling
+                        .chars()
+                        .map(|c| u32::from(c.to_ascii_lowercase()) * 4),
+                );
+            } else {
+                weights.push(u32::from(lower) * 4);
+            }
+        }
+
+        weights.into_iter()
+    }
+}
+
+/// Returns the letters `language` sorts apart from their base letters, with weights on
+/// the scale of four per code point so they can fall between or after ASCII letters.
+fn tailoring(language: &str) -> &'static [(char, u32)] {
+    const AFTER_Z: u32 = 'z' as u32 * 4;
+    const AFTER_N: u32 = 'n' as u32 * 4;
+
+    match language.to_ascii_lowercase().as_str() {
+        "da" | "nb" | "nn" | "no" => &[
+            ('æ', AFTER_Z + 1),
+            ('ä', AFTER_Z + 1),
+            ('ø', AFTER_Z + 2),
+            ('ö', AFTER_Z + 2),
+            ('å', AFTER_Z + 3),
+        ],
+        "fi" | "sv" => &[
+            ('å', AFTER_Z + 1),
+            ('ä', AFTER_Z + 2),
+            ('æ', AFTER_Z + 2),
+            ('ö', AFTER_Z + 3),
+            ('ø', AFTER_Z + 3),
+        ],
+        "es" => &[('ñ', AFTER_N + 1)],
+        _ => &[],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        let cases = [
+            ("a2", "a10", Ordering::Less),
+            ("a10", "a10", Ordering::Equal),
+            ("a", "a1", Ordering::Less),
+            ("1.9", "1.10", Ordering::Less),
+            (
+                "x99999999999999999999999",
+                "x100000000000000000000000",
+                Ordering::Less,
+            ),
+            ("a01", "a1", Ordering::Greater),
+            ("a01b", "a1c", Ordering::Less),
+            ("a 1", "a1", Ordering::Less),
+            ("B2", "a10", Ordering::Less),
+            ("", "0", Ordering::Less),
+        ];
+
+        for (a, b, expected) in cases {
+            assert_eq!(natural_cmp(a, b), expected, "{a:?} {b:?}");
+            assert_eq!(natural_cmp(b, a), expected.reverse(), "{b:?} {a:?}");
+        }
+
+        assert_eq!(natural_cmp_ignore_case("B2", "a10"), Ordering::Greater);
+        assert_eq!(natural_cmp_ignore_case("Abc", "aBC"), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_collation() {
+        let order = NaturalOrder::new().with_locale(&Locale::new("en_US"));
+        let mut words = [
+            "peach", "Péché", "péché", "pêche", "Pêche", "peche2", "peche10",
+        ];
+        order.sort(&mut words);
+        assert_eq!(
+            words,
+            [
+                "peach", "péché", "Péché", "pêche", "Pêche", "peche2", "peche10"
+            ]
+        );
+
+        let mut words = ["Straße", "Strasse", "strasse", "Strand"];
+        order.sort(&mut words);
+        assert_eq!(words, ["Strand", "strasse", "Strasse", "Straße"]);
+
+        let mut words = ["ñu", "nube", "oso"];
+        NaturalOrder::new()
+            .with_locale(&Locale::new("es"))
+            .sort(&mut words);
+        assert_eq!(words, ["nube", "ñu", "oso"]);
+
+        let ignoring_case = order.with_ignores_case(true);
+        assert!(ignoring_case.ignores_case());
+        assert_eq!(ignoring_case.compare("Pêche", "pêche"), Ordering::Equal);
+        assert_eq!(ignoring_case.compare("Peche", "pêche"), Ordering::Less);
+    }
+}
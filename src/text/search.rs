@@ -0,0 +1,599 @@
+//! Substring search for one pattern or many.
+//!
+//! A [`Finder`] looks for one needle with the Two-Way algorithm of Crochemore and Perrin,
+//! which takes linear time in the worst case and no memory beyond the needle. It also
+//! skips ahead by a whole needle length, in the manner of Boyer–Moore, when the last
+//! byte under the needle does not occur in it. [`find_all`] is the one-call form.
+//!
+//! An [`AhoCorasick`] automaton looks for many patterns at once in a single pass over the
+//! haystack, however many patterns there are. It reports either non-overlapping matches
+//! or every match, including those that overlap.
+//!
+//! Both work on bytes, and accept anything that is [`AsRef<[u8]>`], such as `&str`,
+//! `&[u8]` and `Vec<u8>`. Positions are byte offsets; a match of a `&str` needle or
+//! pattern in a `&str` haystack always starts and ends on character boundaries.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::text::search::{AhoCorasick, find_all};
+//!
+//! let positions: Vec<usize> = find_all("one fish two fish", "fish").collect();
+//! assert_eq!(positions, [4, 13]);
+//!
+//! let automaton = AhoCorasick::new(["he", "she", "his", "hers"]);
+//! let matches: Vec<(usize, usize)> = automaton
+//!     .find_overlapping_iter("ushers")
+//!     .map(|found| (found.pattern, found.start))
+//!     .collect();
+//! assert_eq!(matches, [(1, 1), (0, 2), (3, 2)]);
+//! ```
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::ops::Range;
+
+/// Returns the start and period of the maximal suffix of `needle`, under the byte order,
+/// or under the reverse order if `reversed` is set.
+fn maximal_suffix(needle: &[u8], reversed: bool) -> (usize, usize) {
+    let mut start = 0;
+    let mut candidate = 1;
+    let mut offset = 0;
+    let mut period = 1;
+
+    while let Some(&next) = needle.get(candidate + offset) {
+        let current = needle[start + offset];
+
+        if (next < current) != reversed && next != current {
+            // The candidate suffix is smaller, so the period is everything up to it.
+            candidate += offset + 1;
+            offset = 0;
+            period = candidate - start;
+        } else if next == current {
+            if offset + 1 == period {
+                candidate += period;
+                offset = 0;
+            } else {
+                offset += 1;
+            }
+        } else {
+            start = candidate;
+            candidate += 1;
+            offset = 0;
+            period = 1;
+        }
+    }
+
+    (start, period)
+}
+
+/// A needle prepared for searching with the Two-Way algorithm.
+#[derive(Debug, Clone, Copy)]
+pub struct Finder<'n> {
+    needle: &'n [u8],
+    /// Where the needle splits into a left and a right half.
+    critical: usize,
+    /// How far to shift after a mismatch in the left half.
+    period: usize,
+    /// Whether the needle is periodic with `period`, so that the prefix that matched
+    /// before a shift is known to match after it.
+    periodic: bool,
+    /// The bytes of the needle, by their low six bits.
+    byteset: u64,
+}
+
+impl<'n> Finder<'n> {
+    /// Prepares `needle` for searching.
+    pub fn new<N: AsRef<[u8]> + ?Sized>(needle: &'n N) -> Self {
+        let needle = needle.as_ref();
+        let forward = maximal_suffix(needle, false);
+        let backward = maximal_suffix(needle, true);
+        let (critical, period) = if forward.0 > backward.0 {
+            forward
+        } else {
+            backward
+        };
+
+        let periodic = period + critical <= needle.len()
+            && needle[..critical] == needle[period..period + critical];
+
+        Self {
+            needle,
+            critical,
+            period: if periodic {
+                period
+            } else {
+                critical.max(needle.len() - critical) + 1
+            },
+            periodic,
+            byteset: needle
+                .iter()
+                .fold(0, |byteset, &byte| byteset | 1 << (byte & 63)),
+        }
+    }
+
+    /// Returns the needle.
+    #[must_use]
+    pub const fn needle(&self) -> &'n [u8] {
+        self.needle
+    }
+
+    /// Returns the position of the first occurrence of the needle in `haystack`.
+    pub fn find<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> Option<usize> {
+        self.find_from(haystack.as_ref(), 0)
+    }
+
+    /// Returns an iterator over the positions of the non-overlapping occurrences of the
+    /// needle in `haystack`, from left to right.
+    pub fn find_all<'h, H: AsRef<[u8]> + ?Sized>(self, haystack: &'h H) -> FindAll<'h, 'n> {
+        FindAll {
+            finder: self,
+            haystack: haystack.as_ref(),
+            position: 0,
+        }
+    }
+
+    /// Returns the position of the first occurrence at or after `position`.
+    fn find_from(&self, haystack: &[u8], mut position: usize) -> Option<usize> {
+        let needle = self.needle;
+
+        let Some(last) = needle.len().checked_sub(1) else {
+            return (position <= haystack.len()).then_some(position);
+        };
+
+        // How much of the left half is known to match after a shift by the period.
+        let mut memory = 0;
+
+        while let Some(&tail) = haystack.get(position + last) {
+            if self.byteset & 1 << (tail & 63) == 0 {
+                position += needle.len();
+                memory = 0;
+                continue;
+            }
+
+            let window = &haystack[position..=position + last];
+            let right = self.critical.max(memory);
+
+            if let Some(mismatch) = (right..needle.len()).find(|&i| needle[i] != window[i]) {
+                position += mismatch - self.critical + 1;
+                memory = 0;
+                continue;
+            }
+
+            let left = if self.periodic { memory } else { 0 };
+
+            if (left..self.critical).all(|i| needle[i] == window[i]) {
+                return Some(position);
+            }
+
+            position += self.period;
+
+            if self.periodic {
+                memory = needle.len() - self.period;
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the positions of a needle in a haystack, from [`find_all`].
+#[derive(Debug, Clone)]
+pub struct FindAll<'h, 'n> {
+    finder: Finder<'n>,
+    haystack: &'h [u8],
+    position: usize,
+}
+
+impl Iterator for FindAll<'_, '_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let found = self.finder.find_from(self.haystack, self.position)?;
+        // An empty needle occurs at every position, so step past the one just found.
+        self.position = found + self.finder.needle.len().max(1);
+        Some(found)
+    }
+}
+
+/// Returns an iterator over the positions of the non-overlapping occurrences of `needle`
+/// in `haystack`, from left to right.
+///
+/// An empty needle occurs at every byte offset, from zero to the length of the haystack.
+/// To search for the same needle repeatedly, prepare it once with [`Finder::new`].
+pub fn find_all<'h, 'n, H, N>(haystack: &'h H, needle: &'n N) -> FindAll<'h, 'n>
+where
+    H: AsRef<[u8]> + ?Sized,
+    N: AsRef<[u8]> + ?Sized,
+{
+    Finder::new(needle).find_all(haystack)
+}
+
+/// An occurrence of a pattern of an [`AhoCorasick`] automaton.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// The index of the pattern, in the order the patterns were given.
+    pub pattern: usize,
+
+    /// The byte offset where the occurrence starts.
+    pub start: usize,
+
+    /// The byte offset just past the occurrence.
+    pub end: usize,
+}
+
+impl Match {
+    /// Returns the byte range of the occurrence.
+    #[must_use]
+    pub const fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// A state of the automaton: a prefix of one or more patterns.
+#[derive(Debug, Clone, Default)]
+struct State {
+    /// The transitions of the trie, sorted by byte.
+    transitions: Vec<(u8, usize)>,
+    /// The state for the longest proper suffix of this prefix that is also a prefix.
+    failure: usize,
+    /// The patterns that end here, longest first.
+    outputs: Vec<usize>,
+}
+
+/// An automaton that finds many patterns in one pass, after Aho and Corasick.
+#[derive(Debug, Clone)]
+pub struct AhoCorasick {
+    states: Vec<State>,
+    /// The transitions of the start state for every byte, which are the most used.
+    start: [usize; 256],
+    lengths: Vec<usize>,
+}
+
+impl AhoCorasick {
+    /// Builds the automaton for `patterns`, which are numbered from zero in order.
+    pub fn new<I>(patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut states = vec![State::default()];
+        let mut lengths = Vec::new();
+
+        for (index, pattern) in patterns.into_iter().enumerate() {
+            let pattern = pattern.as_ref();
+            let mut state = 0;
+
+            for &byte in pattern {
+                let transitions = &states[state].transitions;
+
+                state = match transitions.binary_search_by_key(&byte, |&(byte, _)| byte) {
+                    Ok(found) => transitions[found].1,
+                    Err(insert) => {
+                        let next = states.len();
+                        states[state].transitions.insert(insert, (byte, next));
+                        states.push(State::default());
+                        next
+                    }
+                };
+            }
+
+            states[state].outputs.push(index);
+            lengths.push(pattern.len());
+        }
+
+        let mut start = [0; 256];
+
+        for &(byte, next) in &states[0].transitions {
+            start[usize::from(byte)] = next;
+        }
+
+        let mut automaton = Self {
+            states,
+            start,
+            lengths,
+        };
+
+        // Breadth first, so that every failure target is complete before it is used.
+        let mut queue: VecDeque<usize> = automaton.states[0]
+            .transitions
+            .iter()
+            .map(|&(_, next)| next)
+            .collect();
+
+        while let Some(state) = queue.pop_front() {
+            for index in 0..automaton.states[state].transitions.len() {
+                let (byte, next) = automaton.states[state].transitions[index];
+                let failure = if state == 0 {
+                    0
+                } else {
+                    automaton.next_state(automaton.states[state].failure, byte)
+                };
+
+                let inherited = automaton.states[failure].outputs.clone();
+                automaton.states[next].failure = failure;
+                automaton.states[next].outputs.extend(inherited);
+                queue.push_back(next);
+            }
+        }
+
+        automaton
+    }
+
+    /// Returns the number of patterns.
+    #[must_use]
+    pub const fn pattern_count(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Returns the state after reading `byte` in `state`.
+    fn next_state(&self, mut state: usize, byte: u8) -> usize {
+        loop {
+            if state == 0 {
+                return self.start[usize::from(byte)];
+            }
+
+            let transitions = &self.states[state].transitions;
+
+            if let Ok(found) = transitions.binary_search_by_key(&byte, |&(byte, _)| byte) {
+                return transitions[found].1;
+            }
+
+            state = self.states[state].failure;
+        }
+    }
+
+    /// Returns the match of `pattern` that ends at `end`.
+    fn match_at(&self, pattern: usize, end: usize) -> Match {
+        Match {
+            pattern,
+            start: end - self.lengths[pattern],
+            end,
+        }
+    }
+
+    /// Returns the first match in `haystack`, as [`find_iter`](Self::find_iter) would.
+    pub fn find<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> Option<Match> {
+        self.find_iter(haystack).next()
+    }
+
+    /// Returns whether any pattern occurs in `haystack`.
+    pub fn is_match<H: AsRef<[u8]> + ?Sized>(&self, haystack: &H) -> bool {
+        self.find(haystack).is_some()
+    }
+
+    /// Returns an iterator over non-overlapping matches in `haystack`.
+    ///
+    /// The haystack is scanned from left to right, and each match is the one that ends
+    /// first, or the longest of those that end at the same position. Scanning resumes
+    /// after its end.
+    pub fn find_iter<'a, 'h, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'h H,
+    ) -> FindIter<'a, 'h> {
+        FindIter {
+            automaton: self,
+            haystack: haystack.as_ref(),
+            position: 0,
+            state: 0,
+            checked: false,
+        }
+    }
+
+    /// Returns an iterator over every match in `haystack`, including those that overlap,
+    /// ordered by their end and then from the longest to the shortest.
+    pub fn find_overlapping_iter<'a, 'h, H: AsRef<[u8]> + ?Sized>(
+        &'a self,
+        haystack: &'h H,
+    ) -> FindOverlappingIter<'a, 'h> {
+        FindOverlappingIter {
+            automaton: self,
+            haystack: haystack.as_ref(),
+            position: 0,
+            state: 0,
+            output: 0,
+        }
+    }
+}
+
+/// An iterator over non-overlapping matches, from [`AhoCorasick::find_iter`].
+#[derive(Debug, Clone)]
+pub struct FindIter<'a, 'h> {
+    automaton: &'a AhoCorasick,
+    haystack: &'h [u8],
+    position: usize,
+    state: usize,
+    /// Whether the state has been checked for matches since the last byte.
+    checked: bool,
+}
+
+impl Iterator for FindIter<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            if !self.checked {
+                self.checked = true;
+
+                if let Some(&pattern) = self.automaton.states[self.state].outputs.first() {
+                    self.state = 0;
+                    return Some(self.automaton.match_at(pattern, self.position));
+                }
+            }
+
+            let &byte = self.haystack.get(self.position)?;
+            self.position += 1;
+            self.state = self.automaton.next_state(self.state, byte);
+            self.checked = false;
+        }
+    }
+}
+
+/// An iterator over every match, from [`AhoCorasick::find_overlapping_iter`].
+#[derive(Debug, Clone)]
+pub struct FindOverlappingIter<'a, 'h> {
+    automaton: &'a AhoCorasick,
+    haystack: &'h [u8],
+    position: usize,
+    state: usize,
+    /// The next output of the state to report.
+    output: usize,
+}
+
+impl Iterator for FindOverlappingIter<'_, '_> {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        loop {
+            let outputs = &self.automaton.states[self.state].outputs;
+
+            if let Some(&pattern) = outputs.get(self.output) {
+                self.output += 1;
+                return Some(self.automaton.match_at(pattern, self.position));
+            }
+
+            let &byte = self.haystack.get(self.position)?;
+            self.position += 1;
+            self.state = self.automaton.next_state(self.state, byte);
+            self.output = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::{RandomNumberGenerator, SplitMix64};
+
+    fn naive(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
+        let mut positions = Vec::new();
+        let mut position = 0;
+
+        while position + needle.len() <= haystack.len() {
+            if haystack[position..].starts_with(needle) {
+                positions.push(position);
+                position += needle.len().max(1);
+            } else {
+                position += 1;
+            }
+        }
+
+        positions
+    }
+
+    #[test]
+    fn test_find_all_matches_naive_search() {
+        let mut rng = SplitMix64::new(1982);
+
+        for _ in 0..2000 {
+            // A small alphabet makes periodic needles and near misses common.
+            let alphabet = 1 + rng.next_bounded(3);
+            let mut random = |length: u64| -> Vec<u8> {
+                (0..rng.next_bounded(length))
+                    .map(|_| b'a' + u8::try_from(rng.next_bounded(alphabet)).unwrap_or(0))
+                    .collect()
+            };
+            let haystack = random(60);
+            let needle = random(8);
+
+            if needle.is_empty() {
+                continue;
+            }
+
+            let found: Vec<usize> = find_all(&haystack, &needle).collect();
+            assert_eq!(found, naive(&haystack, &needle), "{haystack:?} {needle:?}");
+        }
+    }
+
+    #[test]
+    fn test_find_all_edge_cases() {
+        assert_eq!(find_all("aaaa", "aa").collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(find_all("abc", "").collect::<Vec<_>>(), [0, 1, 2, 3]);
+        assert_eq!(find_all("ab", "abc").next(), None);
+        assert_eq!(Finder::new("").find("abc"), Some(0));
+        assert_eq!(find_all("", "a").next(), None);
+        assert_eq!(Finder::new("ünï").find("naïve ünïcode"), Some(7));
+        assert_eq!(
+            Finder::new(&[0xFF, 0x00][..]).find(&[1, 0xFF, 0xFF, 0x00][..]),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_aho_corasick() {
+        let automaton = AhoCorasick::new(["he", "she", "his", "hers"]);
+        let matches: Vec<Match> = automaton.find_overlapping_iter("ushers").collect();
+
+        assert_eq!(
+            matches,
+            [
+                Match {
+                    pattern: 1,
+                    start: 1,
+                    end: 4
+                },
+                Match {
+                    pattern: 0,
+                    start: 2,
+                    end: 4
+                },
+                Match {
+                    pattern: 3,
+                    start: 2,
+                    end: 6
+                },
+            ]
+        );
+
+        let ranges: Vec<Range<usize>> = automaton
+            .find_iter("she said his hershey")
+            .map(|found| found.range())
+            .collect();
+        assert_eq!(ranges, [0..3, 9..12, 13..15, 16..19]);
+
+        assert_eq!(automaton.pattern_count(), 4);
+        assert!(!automaton.is_match("nothing to see"));
+    }
+
+    #[test]
+    fn test_aho_corasick_matches_naive_search() {
+        let mut rng = SplitMix64::new(42);
+        let patterns: Vec<Vec<u8>> = (0..12)
+            .map(|_| {
+                (0..=rng.next_bounded(4))
+                    .map(|_| b'a' + u8::try_from(rng.next_bounded(3)).unwrap_or(0))
+                    .collect()
+            })
+            .collect();
+        let automaton = AhoCorasick::new(&patterns);
+        let haystack: Vec<u8> = (0..500)
+            .map(|_| b'a' + u8::try_from(rng.next_bounded(3)).unwrap_or(0))
+            .collect();
+
+        let mut expected: Vec<(usize, usize)> = Vec::new();
+
+        for (index, pattern) in patterns.iter().enumerate() {
+            for start in 0..=haystack.len() - pattern.len() {
+                if haystack[start..].starts_with(pattern) {
+                    expected.push((start, index));
+                }
+            }
+        }
+
+        let mut found: Vec<(usize, usize)> = automaton
+            .find_overlapping_iter(&haystack)
+            .map(|found| (found.start, found.pattern))
+            .collect();
+
+        expected.sort_unstable();
+        found.sort_unstable();
+        assert_eq!(found, expected);
+
+        let mut end = 0;
+
+        for found in automaton.find_iter(&haystack) {
+            assert!(found.start >= end);
+            assert_eq!(&haystack[found.range()], patterns[found.pattern]);
+            end = found.end;
+        }
+    }
+}
@@ -0,0 +1,287 @@
+//! Transliteration of Latin text to ASCII.
+//!
+//! [`transliterate`] removes accents and spells out letters that ASCII lacks, so that
+//! `Crème brûlée` becomes `Creme brulee` and `Straße` becomes `Strasse`. The built-in
+//! table covers the Latin-1 Supplement and Latin Extended-A blocks, the Romanian
+//! comma-below letters, combining accents, and common typographic punctuation such as
+//! curly quotes and dashes. A [`Transliterator`] adds custom mappings on top of it, such
+//! as `ä` to `ae` for German, and chooses what happens to characters it cannot map.
+//!
+//! [`slugify`] builds on it to turn titles into URL-safe identifiers.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::text::transliterate::{Transliterator, slugify, transliterate};
+//!
+//! assert_eq!(transliterate("Ærøskøbing – Ålborg"), "AEroskobing - Alborg");
+//! assert_eq!(slugify("Crème Brûlée: A Story!"), "creme-brulee-a-story");
+//!
+//! let german = Transliterator::new().with_mappings(&[('ä', "ae"), ('ö', "oe"), ('ü', "ue")]);
+//! assert_eq!(german.transliterate("Müller über Köln"), "Mueller ueber Koeln");
+//! ```
+
+use alloc::{borrow::Cow, collections::BTreeMap, string::String};
+
+/// The ASCII letters of U+00C0 to U+00FF, with `*` for those spelled with several.
+const LATIN_1: &str = "AAAAAA*CEEEEIIIIDNOOOOOxOUUUUY**aaaaaa*ceeeeiiiidnooooo/ouuuuy*y";
+
+/// The ASCII letters of U+0100 to U+017F, with `*` for those spelled with several.
+const LATIN_EXTENDED_A: &str = concat!(
+    "AaAaAaCcCcCcCcDdDdEeEeEeEeEeGgGgGgGgHhHhIiIiIiIiIi**JjKkqLlLlLlL",
+    "lLlNnNnNn*NnOoOoOo**RrRrRrSsSsSsSsTtTtTtUuUuUuUuUuUuWwYyYZzZzZzs",
+);
+
+/// Returns the ASCII spelling of `character` in the built-in table, or `None` if it has
+/// none. Combining accents map to nothing.
+pub(crate) fn builtin(character: char) -> Option<&'static str> {
+    let code = u32::from(character);
+
+    let (table, start) = match code {
+        0xC0..=0xFF => (LATIN_1, 0xC0),
+        0x100..=0x17F => (LATIN_EXTENDED_A, 0x100),
+        _ => ("", 0),
+    };
+
+    // Every entry is one ASCII byte, so it is also a string of one letter.
+    let index = (code - start) as usize;
+    let letter = table.get(index..=index).filter(|&letter| letter != "*");
+
+    if letter.is_some() {
+        return letter;
+    }
+
+    Some(match character {
+        '\u{300}'..='\u{36F}' => "",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Þ' => "TH",
+        'þ' => "th",
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'Ĳ' => "IJ",
+        'ĳ' => "ij",
+        'ŉ' => "'n",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'ƒ' => "f",
+        'Ș' => "S",
+        'ș' => "s",
+        'Ț' => "T",
+        'ț' => "t",
+        '\u{A0}' | '\u{2007}' | '\u{202F}' => " ",
+        '‘' | '’' | '‚' | '‛' | '′' => "'",
+        '“' | '”' | '„' | '‟' | '″' => "\"",
+        '‐' | '‑' | '‒' | '–' | '—' | '−' => "-",
+        '…' => "...",
+        '‹' => "<",
+        '›' => ">",
+        '«' => "<<",
+        '»' => ">>",
+        '•' => "*",
+        '©' => "(C)",
+        '®' => "(R)",
+        '™' => "TM",
+        _ => return None,
+    })
+}
+
+/// What a [`Transliterator`] does with a non-ASCII character it has no mapping for.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Unmapped {
+    /// Keep the character.
+    #[default]
+    Keep,
+
+    /// Leave the character out.
+    Remove,
+
+    /// Write the given character instead.
+    Replace(char),
+}
+
+/// Transliterates text to ASCII with the built-in table and any custom mappings.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Transliterator {
+    mappings: BTreeMap<char, String>,
+    unmapped: Unmapped,
+}
+
+impl Transliterator {
+    /// Creates a transliterator with the built-in table, which keeps characters it
+    /// cannot map.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            mappings: BTreeMap::new(),
+            unmapped: Unmapped::Keep,
+        }
+    }
+
+    /// Maps `character` to `replacement`, before the built-in table.
+    #[must_use]
+    pub fn with_mapping(mut self, character: char, replacement: &str) -> Self {
+        self.mappings.insert(character, String::from(replacement));
+        self
+    }
+
+    /// Adds a table of mappings, as [`with_mapping`](Self::with_mapping) does for each.
+    #[must_use]
+    pub fn with_mappings(mut self, mappings: &[(char, &str)]) -> Self {
+        for &(character, replacement) in mappings {
+            self.mappings.insert(character, String::from(replacement));
+        }
+
+        self
+    }
+
+    /// Sets what happens to non-ASCII characters without a mapping.
+    #[must_use]
+    pub const fn with_unmapped(mut self, unmapped: Unmapped) -> Self {
+        self.unmapped = unmapped;
+        self
+    }
+
+    /// Returns what happens to non-ASCII characters without a mapping.
+    #[must_use]
+    pub const fn unmapped(&self) -> Unmapped {
+        self.unmapped
+    }
+
+    /// Transliterates `text`.
+    ///
+    /// ASCII text without custom mappings is returned unchanged, without allocating.
+    #[must_use]
+    pub fn transliterate<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let changes =
+            |character: char| !character.is_ascii() || self.mappings.contains_key(&character);
+
+        let Some(first) = text.find(changes) else {
+            return Cow::Borrowed(text);
+        };
+
+        let mut output = String::with_capacity(text.len());
+        output.push_str(&text[..first]);
+
+        for character in text[first..].chars() {
+            if let Some(replacement) = self.mappings.get(&character) {
+                output.push_str(replacement);
+            } else if character.is_ascii() {
+                output.push(character);
+            } else if let Some(replacement) = builtin(character) {
+                output.push_str(replacement);
+            } else {
+                match self.unmapped {
+                    Unmapped::Keep => output.push(character),
+                    Unmapped::Remove => {}
+                    Unmapped::Replace(replacement) => output.push(replacement),
+                }
+            }
+        }
+
+        Cow::Owned(output)
+    }
+}
+
+/// Transliterates `text` to ASCII with the built-in table, keeping characters it cannot
+/// map.
+#[must_use]
+pub fn transliterate(text: &str) -> Cow<'_, str> {
+    Transliterator::new().transliterate(text)
+}
+
+/// Turns `text` into a URL-safe identifier of lowercase ASCII letters and digits joined
+/// by single hyphens.
+///
+/// The text is transliterated first and characters that cannot be are dropped, as are
+/// apostrophes, so that `Don't Stop` becomes `dont-stop`.
+///
+/// # Examples
+///
+/// ```
+/// use libx::text::transliterate::slugify;
+///
+/// assert_eq!(slugify("  Hello, World!  "), "hello-world");
+/// assert_eq!(slugify("Ünïcödé — 2024 edition"), "unicode-2024-edition");
+/// assert_eq!(slugify("日本語"), "");
+/// ```
+#[must_use]
+pub fn slugify(text: &str) -> String {
+    let ascii = Transliterator::new()
+        .with_unmapped(Unmapped::Remove)
+        .transliterate(text);
+    let mut slug = String::with_capacity(ascii.len());
+    let mut separated = false;
+
+    for character in ascii.chars().filter(|&character| character != '\'') {
+        if character.is_ascii_alphanumeric() {
+            if separated && !slug.is_empty() {
+                slug.push('-');
+            }
+
+            slug.push(character.to_ascii_lowercase());
+            separated = false;
+        } else {
+            separated = true;
+        }
+    }
+
+    slug
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_table() {
+        assert_eq!(
+            transliterate("ÀÁÂÃÄÅÆÇÈÉÊËÌÍÎÏÐÑÒÓÔÕÖ×ØÙÚÛÜÝÞß"),
+            "AAAAAAAECEEEEIIIIDNOOOOOxOUUUUYTHss"
+        );
+        assert_eq!(
+            transliterate("àáâãäåæçèéêëìíîïðñòóôõö÷øùúûüýþÿ"),
+            "aaaaaaaeceeeeiiiidnooooo/ouuuuythy"
+        );
+        assert_eq!(
+            transliterate("Łódź, Dvořák, Ĳssel, œuvre"),
+            "Lodz, Dvorak, IJssel, oeuvre"
+        );
+        assert_eq!(transliterate("București, Şişli"), "Bucuresti, Sisli");
+        assert_eq!(transliterate("e\u{301}te\u{301}"), "ete");
+        assert_eq!(
+            transliterate("“Quoted” — it’s… «fine»"),
+            "\"Quoted\" - it's... <<fine>>"
+        );
+
+        assert!(matches!(transliterate("plain ASCII"), Cow::Borrowed(_)));
+        assert_eq!(transliterate("Ωμέγα"), "Ωμέγα");
+    }
+
+    #[test]
+    fn test_custom_mappings_and_unmapped_characters() {
+        let transliterator = Transliterator::new()
+            .with_mapping('ø', "oe")
+            .with_mapping('&', "and")
+            .with_unmapped(Unmapped::Replace('?'));
+
+        assert_eq!(
+            transliterator.transliterate("Søren & Ωmega"),
+            "Soeren and ?mega"
+        );
+        assert_eq!(
+            Transliterator::new()
+                .with_unmapped(Unmapped::Remove)
+                .transliterate("a→b"),
+            "ab"
+        );
+    }
+
+    #[test]
+    fn test_slugify() {
+        assert_eq!(slugify("Don't Stop Me Now"), "dont-stop-me-now");
+        assert_eq!(slugify("--Rust & C++ -- 2024--"), "rust-c-2024");
+        assert_eq!(slugify("Straße in Zürich"), "strasse-in-zurich");
+        assert_eq!(slugify(""), "");
+    }
+}
@@ -0,0 +1,503 @@
+//! Wrapping text into lines of a given width.
+//!
+//! A [`Wrapper`] breaks text between words so that no line is wider than its width,
+//! counted as [`terminal::visible_width`] does. The greedy algorithm fills each line as
+//! far as it goes; the optimal fit chooses all the breaks of a paragraph together so that
+//! the lines are as even as possible, in the manner of Knuth and Plass without their
+//! stretchable spaces. Lines can be indented, justified to the full width, and broken
+//! inside words at points chosen by a hyphenation function.
+//!
+//! Every line of the input is wrapped as a paragraph of its own, so explicit line breaks
+//! and blank lines are kept.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::text::wrap::{WrapAlgorithm, Wrapper, fill};
+//!
+//! assert_eq!(
+//!     fill("The quick brown fox jumps over the lazy dog.", 16),
+//!     "The quick brown\nfox jumps over\nthe lazy dog."
+//! );
+//!
+//! let wrapper = Wrapper::new(20)
+//!     .with_initial_indent("  * ")
+//!     .with_subsequent_indent("    ")
+//!     .with_justified(true);
+//!
+//! assert_eq!(
+//!     wrapper.wrap("Wrapped and justified text, with a hanging indent."),
+//!     ["  * Wrapped      and", "    justified  text,", "    with  a  hanging", "    indent."]
+//! );
+//! ```
+
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use crate::terminal;
+
+/// How a [`Wrapper`] chooses where to break lines.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WrapAlgorithm {
+    /// Put as many words as fit on each line before starting the next. This is fast and
+    /// predictable, but can leave a short line before a long word.
+    #[default]
+    Greedy,
+
+    /// Choose the breaks of a paragraph together, minimizing the sum of the squares of
+    /// the space left at the end of every line but the last.
+    OptimalFit,
+}
+
+/// A function that returns the byte offsets inside a word where it may be broken with a
+/// hyphen, such as `[2, 6, 7]` for `hyphenation`.
+pub type Hyphenator = fn(&str) -> Vec<usize>;
+
+/// A piece of a paragraph that is never broken: a word, or part of one.
+#[derive(Debug, Clone, Copy)]
+struct Fragment<'a> {
+    text: &'a str,
+    width: usize,
+    /// Whether a space follows, rather than the next part of the same word.
+    space_after: bool,
+    /// Whether a hyphen is written when a line ends after this fragment.
+    hyphen: bool,
+}
+
+/// Breaks text into lines.
+#[derive(Debug, Clone)]
+pub struct Wrapper {
+    width: usize,
+    algorithm: WrapAlgorithm,
+    initial_indent: Cow<'static, str>,
+    subsequent_indent: Cow<'static, str>,
+    justified: bool,
+    breaks_words: bool,
+    hyphenator: Option<Hyphenator>,
+}
+
+impl Wrapper {
+    /// Creates a greedy wrapper for lines of `width` columns, without indentation, that
+    /// breaks words wider than a line.
+    #[must_use]
+    pub const fn new(width: usize) -> Self {
+        Self {
+            width,
+            algorithm: WrapAlgorithm::Greedy,
+            initial_indent: Cow::Borrowed(""),
+            subsequent_indent: Cow::Borrowed(""),
+            justified: false,
+            breaks_words: true,
+            hyphenator: None,
+        }
+    }
+
+    /// Sets the width of the lines, including their indentation.
+    #[must_use]
+    pub const fn with_width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets how breaks are chosen.
+    #[must_use]
+    pub const fn with_algorithm(mut self, algorithm: WrapAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Sets the text written before the first line of every paragraph.
+    #[must_use]
+    pub fn with_initial_indent(mut self, indent: impl Into<Cow<'static, str>>) -> Self {
+        self.initial_indent = indent.into();
+        self
+    }
+
+    /// Sets the text written before every other line.
+    #[must_use]
+    pub fn with_subsequent_indent(mut self, indent: impl Into<Cow<'static, str>>) -> Self {
+        self.subsequent_indent = indent.into();
+        self
+    }
+
+    /// Sets whether the spaces of every line but the last of a paragraph are widened so
+    /// that the line fills the width.
+    #[must_use]
+    pub const fn with_justified(mut self, justified: bool) -> Self {
+        self.justified = justified;
+        self
+    }
+
+    /// Sets whether words wider than a line are broken wherever the line ends. Otherwise
+    /// they overflow the line.
+    #[must_use]
+    pub const fn with_breaks_words(mut self, breaks_words: bool) -> Self {
+        self.breaks_words = breaks_words;
+        self
+    }
+
+    /// Sets the function that finds where words may be hyphenated. Words are also broken
+    /// after the hyphens they already contain.
+    #[must_use]
+    pub const fn with_hyphenator(mut self, hyphenator: Option<Hyphenator>) -> Self {
+        self.hyphenator = hyphenator;
+        self
+    }
+
+    /// Returns the width of the lines.
+    #[must_use]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns how breaks are chosen.
+    #[must_use]
+    pub const fn algorithm(&self) -> WrapAlgorithm {
+        self.algorithm
+    }
+
+    /// Returns whether lines are justified.
+    #[must_use]
+    pub const fn justified(&self) -> bool {
+        self.justified
+    }
+
+    /// Wraps `text` into lines, without line breaks or trailing spaces.
+    #[must_use]
+    pub fn wrap(&self, text: &str) -> Vec<String> {
+        let first_width = self.available(&self.initial_indent);
+        let other_width = self.available(&self.subsequent_indent);
+        let mut lines = Vec::new();
+
+        for paragraph in text.split('\n') {
+            let fragments = self.fragments(paragraph, first_width.min(other_width));
+
+            if fragments.is_empty() {
+                lines.push(String::new());
+                continue;
+            }
+
+            let breaks = match self.algorithm {
+                WrapAlgorithm::Greedy => greedy(&fragments, first_width, other_width),
+                WrapAlgorithm::OptimalFit => optimal_fit(&fragments, first_width, other_width),
+            };
+
+            let mut start = 0;
+
+            for (index, &end) in breaks.iter().enumerate() {
+                let (indent, width) = if index == 0 {
+                    (&self.initial_indent, first_width)
+                } else {
+                    (&self.subsequent_indent, other_width)
+                };
+
+                let last = index + 1 == breaks.len();
+                let extra = if self.justified && !last {
+                    width.saturating_sub(line_width(&fragments[start..end]))
+                } else {
+                    0
+                };
+
+                lines.push(write_line(indent, &fragments[start..end], extra));
+                start = end;
+            }
+        }
+
+        lines
+    }
+
+    /// Wraps `text` into lines joined by `\n`.
+    #[must_use]
+    pub fn fill(&self, text: &str) -> String {
+        self.wrap(text).join("\n")
+    }
+
+    /// Returns the width left for text after `indent`, which is at least one column.
+    fn available(&self, indent: &str) -> usize {
+        self.width
+            .saturating_sub(terminal::visible_width(indent))
+            .max(1)
+    }
+
+    /// Splits a paragraph into fragments, breaking words at hyphens, at the points the
+    /// hyphenator allows, and where they are wider than `limit`.
+    fn fragments<'a>(&self, paragraph: &'a str, limit: usize) -> Vec<Fragment<'a>> {
+        let mut fragments = Vec::new();
+
+        for word in paragraph.split_whitespace() {
+            let mut points: Vec<(usize, bool)> = word
+                .match_indices('-')
+                .map(|(index, _)| (index + 1, false))
+                .filter(|&(index, _)| index > 1 && index < word.len())
+                .collect();
+
+            if let Some(hyphenator) = self.hyphenator {
+                points.extend(
+                    hyphenator(word)
+                        .into_iter()
+                        .filter(|&index| {
+                            index > 0 && index < word.len() && word.is_char_boundary(index)
+                        })
+                        .filter(|&index| !word[..index].ends_with('-'))
+                        .map(|index| (index, true)),
+                );
+            }
+
+            points.sort_unstable();
+            points.dedup_by_key(|point| point.0);
+            points.push((word.len(), false));
+
+            let mut start = 0;
+
+            for (end, hyphen) in points {
+                let piece = &word[start..end];
+                let last = end == word.len();
+
+                if self.breaks_words && terminal::visible_width(piece) > limit {
+                    split_wide(piece, limit, last, hyphen, &mut fragments);
+                } else {
+                    fragments.push(Fragment {
+                        text: piece,
+                        width: terminal::visible_width(piece),
+                        space_after: last,
+                        hyphen,
+                    });
+                }
+
+                start = end;
+            }
+        }
+
+        fragments
+    }
+}
+
+/// Splits `piece` into fragments of at most `limit` characters, the last of which ends
+/// the word if `last` is set and takes a hyphen if `hyphen` is.
+fn split_wide<'a>(
+    piece: &'a str,
+    limit: usize,
+    last: bool,
+    hyphen: bool,
+    fragments: &mut Vec<Fragment<'a>>,
+) {
+    let mut rest = piece;
+
+    while !rest.is_empty() {
+        let end = rest
+            .char_indices()
+            .nth(limit)
+            .map_or(rest.len(), |(index, _)| index);
+        let (text, remainder) = rest.split_at(end);
+        let final_piece = remainder.is_empty();
+
+        fragments.push(Fragment {
+            text,
+            width: terminal::visible_width(text),
+            space_after: final_piece && last,
+            hyphen: final_piece && hyphen,
+        });
+
+        rest = remainder;
+    }
+}
+
+/// Returns the width of a line of `fragments`, including the hyphen it may end with.
+fn line_width(fragments: &[Fragment<'_>]) -> usize {
+    let spaces = fragments[..fragments.len() - 1]
+        .iter()
+        .filter(|fragment| fragment.space_after)
+        .count();
+    let hyphen = fragments.last().is_some_and(|fragment| fragment.hyphen);
+
+    fragments
+        .iter()
+        .map(|fragment| fragment.width)
+        .sum::<usize>()
+        + spaces
+        + usize::from(hyphen)
+}
+
+/// Writes a line of `fragments` after `indent`, with `extra` spaces spread between its
+/// words from the left.
+fn write_line(indent: &str, fragments: &[Fragment<'_>], extra: usize) -> String {
+    let gaps = fragments[..fragments.len() - 1]
+        .iter()
+        .filter(|fragment| fragment.space_after)
+        .count();
+    let mut line = String::from(indent);
+    let mut gap = 0;
+
+    for (index, fragment) in fragments.iter().enumerate() {
+        line.push_str(fragment.text);
+
+        if index + 1 == fragments.len() {
+            if fragment.hyphen {
+                line.push('-');
+            }
+        } else if fragment.space_after {
+            let widened = extra / gaps + usize::from(gap < extra % gaps);
+            line.extend(core::iter::repeat_n(' ', 1 + widened));
+            gap += 1;
+        }
+    }
+
+    line
+}
+
+/// Returns the end of every line, filling each line as far as it goes.
+fn greedy(fragments: &[Fragment<'_>], first_width: usize, other_width: usize) -> Vec<usize> {
+    let mut breaks = Vec::new();
+    let mut start = 0;
+
+    for end in 1..fragments.len() {
+        let width = if breaks.is_empty() {
+            first_width
+        } else {
+            other_width
+        };
+
+        if line_width(&fragments[start..=end]) > width {
+            breaks.push(end);
+            start = end;
+        }
+    }
+
+    breaks.push(fragments.len());
+    breaks
+}
+
+/// Returns the end of every line, minimizing the squares of the space left on every line
+/// but the last.
+fn optimal_fit(fragments: &[Fragment<'_>], first_width: usize, other_width: usize) -> Vec<usize> {
+    // The cost of a line that overflows, which only a single wide fragment does.
+    const OVERFLOW: u64 = 1 << 40;
+    // The cost of ending a line inside a word, as much as three columns of space.
+    const HYPHEN: u64 = 9;
+
+    // `best[end]` is the cost of the best breaks of the first `end` fragments, and the
+    // start of the last of their lines.
+    let mut best: Vec<(u64, usize)> = Vec::with_capacity(fragments.len() + 1);
+    best.push((0, 0));
+
+    for end in 1..=fragments.len() {
+        let mut choice = (u64::MAX, 0);
+
+        for start in (0..end).rev() {
+            let width = if start == 0 { first_width } else { other_width };
+            let used = line_width(&fragments[start..end]);
+
+            if used > width && start + 1 < end {
+                break;
+            }
+
+            let cost = if used > width {
+                OVERFLOW
+            } else if end == fragments.len() {
+                0
+            } else {
+                let slack = (width - used) as u64;
+                slack * slack + if fragments[end - 1].hyphen { HYPHEN } else { 0 }
+            };
+
+            let total = best[start].0.saturating_add(cost);
+
+            if total < choice.0 {
+                choice = (total, start);
+            }
+        }
+
+        best.push(choice);
+    }
+
+    let mut breaks = Vec::new();
+    let mut end = fragments.len();
+
+    while end > 0 {
+        breaks.push(end);
+        end = best[end].1;
+    }
+
+    breaks.reverse();
+    breaks
+}
+
+/// Wraps `text` greedily into lines of at most `width` columns.
+#[must_use]
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    Wrapper::new(width).wrap(text)
+}
+
+/// Wraps `text` greedily into lines of at most `width` columns, joined by `\n`.
+#[must_use]
+pub fn fill(text: &str, width: usize) -> String {
+    Wrapper::new(width).fill(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn test_greedy_and_optimal_fit() {
+        let text = "aaa bb cc ddddd";
+
+        assert_eq!(wrap(text, 6), ["aaa bb", "cc", "ddddd"]);
+        assert_eq!(
+            Wrapper::new(6)
+                .with_algorithm(WrapAlgorithm::OptimalFit)
+                .wrap(text),
+            ["aaa", "bb cc", "ddddd"]
+        );
+
+        let text = "The quick brown fox jumps over the lazy dog and keeps running far away.";
+
+        for algorithm in [WrapAlgorithm::Greedy, WrapAlgorithm::OptimalFit] {
+            let lines = Wrapper::new(20).with_algorithm(algorithm).wrap(text);
+
+            assert!(lines.iter().all(|line| line.chars().count() <= 20));
+            assert_eq!(lines.join(" "), text);
+        }
+    }
+
+    #[test]
+    fn test_paragraphs_and_long_words() {
+        assert_eq!(wrap("one two\n\nthree", 80), ["one two", "", "three"]);
+        assert_eq!(wrap("", 10), [""]);
+        assert_eq!(wrap("abcdefghij xy", 4), ["abcd", "efgh", "ij", "xy"]);
+        assert_eq!(
+            Wrapper::new(4)
+                .with_breaks_words(false)
+                .wrap("abcdefghij xy"),
+            ["abcdefghij", "xy"]
+        );
+        assert_eq!(wrap("well-known fact", 8), ["well-", "known", "fact"]);
+        assert_eq!(
+            wrap("\x1b[1mbold\x1b[0m text", 9),
+            ["\x1b[1mbold\x1b[0m text"]
+        );
+    }
+
+    #[test]
+    fn test_hyphenation_and_justification() {
+        fn syllables(word: &str) -> Vec<usize> {
+            match word {
+                "hyphenation" => vec![2, 6, 7],
+                _ => Vec::new(),
+            }
+        }
+
+        let wrapper = Wrapper::new(10).with_hyphenator(Some(syllables));
+        assert_eq!(
+            wrapper.wrap("automatic hyphenation"),
+            ["automatic", "hyphena-", "tion"]
+        );
+        assert_eq!(wrapper.wrap("a hyphenation"), ["a hyphena-", "tion"]);
+
+        let justified = Wrapper::new(12)
+            .with_justified(true)
+            .wrap("a b c d e f g h");
+        assert_eq!(justified, ["a  b c d e f", "g h"]);
+    }
+}
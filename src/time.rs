@@ -0,0 +1,22 @@
+//! Monotonic time measurement for `no_std` environments.
+//!
+//! Everything in this module is layered over the [`MonotonicClock`] trait, which
+//! reports the time elapsed since an arbitrary but fixed origin. Hosted targets can
+//! use [`SystemClock`] (backed by `CLOCK_MONOTONIC`), while bare-metal targets can
+//! plug in their own tick counter through [`TickClock`]. Calendar time comes from the
+//! separate [`WallClock`] trait, which [`SystemClock`] also implements.
+
+pub mod clock;
+pub mod deadline;
+pub mod rate_limiter;
+pub mod stopwatch;
+
+pub use clock::{MonotonicClock, TickClock, WallClock};
+
+#[cfg(feature = "std")]
+pub use clock::StdClock;
+#[cfg(unix)]
+pub use clock::SystemClock;
+pub use deadline::Deadline;
+pub use rate_limiter::{RateLimitAlgorithm, RateLimiter};
+pub use stopwatch::Stopwatch;
@@ -0,0 +1,281 @@
+use core::time::Duration;
+
+/// A source of monotonically non-decreasing time.
+///
+/// Implementations report the time elapsed since an arbitrary origin that stays fixed
+/// for the lifetime of the clock. Only differences between two readings are meaningful.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::time::MonotonicClock;
+///
+/// struct FrozenClock;
+///
+/// impl MonotonicClock for FrozenClock {
+///     fn now(&self) -> Duration {
+///         Duration::from_secs(42)
+///     }
+/// }
+///
+/// assert_eq!(FrozenClock.now(), Duration::from_secs(42));
+/// ```
+pub trait MonotonicClock {
+    /// Returns the time elapsed since the clock's origin.
+    fn now(&self) -> Duration;
+}
+
+impl<C> MonotonicClock for &C
+where
+    C: MonotonicClock + ?Sized,
+{
+    fn now(&self) -> Duration {
+        (**self).now()
+    }
+}
+
+/// A source of calendar time, measured from the Unix epoch.
+///
+/// Unlike a [`MonotonicClock`], a wall clock follows adjustments to the system time, so
+/// two readings may go backwards. Code that needs the current date should take a wall
+/// clock rather than asking the operating system, so that tests can fix the time.
+///
+/// # Examples
+///
+/// ```
+/// use core::time::Duration;
+///
+/// use libx::time::WallClock;
+///
+/// struct Y2K;
+///
+/// impl WallClock for Y2K {
+///     fn since_unix_epoch(&self) -> Duration {
+///         Duration::from_secs(946_684_800)
+///     }
+/// }
+///
+/// assert_eq!(Y2K.since_unix_epoch().as_secs() / 86_400, 10_957);
+/// ```
+pub trait WallClock {
+    /// Returns the time elapsed since 1970-01-01T00:00:00Z.
+    fn since_unix_epoch(&self) -> Duration;
+}
+
+impl<C> WallClock for &C
+where
+    C: WallClock + ?Sized,
+{
+    fn since_unix_epoch(&self) -> Duration {
+        (**self).since_unix_epoch()
+    }
+}
+
+/// The operating system's clocks: `CLOCK_MONOTONIC` as a [`MonotonicClock`] and
+/// `CLOCK_REALTIME` as a [`WallClock`].
+///
+/// # Examples
+///
+/// ```
+/// use libx::time::{MonotonicClock, SystemClock, WallClock};
+///
+/// let first = SystemClock.now();
+/// let second = SystemClock.now();
+/// assert!(second >= first);
+///
+/// assert!(SystemClock.since_unix_epoch().as_secs() > 1_000_000_000);
+/// ```
+#[cfg(unix)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[cfg(unix)]
+impl MonotonicClock for SystemClock {
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn now(&self) -> Duration {
+        let mut time = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+
+        let result = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &raw mut time) };
+        assert_eq!(result, 0, "CLOCK_MONOTONIC is unavailable");
+
+        // `CLOCK_MONOTONIC` never reports negative values.
+        Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
+    }
+}
+
+#[cfg(unix)]
+impl WallClock for SystemClock {
+    /// Returns zero if the system time is set before 1970.
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    fn since_unix_epoch(&self) -> Duration {
+        let mut time = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+
+        let result = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME, &raw mut time) };
+        assert_eq!(result, 0, "CLOCK_REALTIME is unavailable");
+
+        if time.tv_sec < 0 {
+            return Duration::ZERO;
+        }
+
+        Duration::new(time.tv_sec as u64, time.tv_nsec as u32)
+    }
+}
+
+/// A clock backed by [`std::time::Instant`], measuring time since its creation.
+///
+/// # Examples
+///
+/// ```
+/// use libx::time::{MonotonicClock, StdClock};
+///
+/// let clock = StdClock::new();
+/// let first = clock.now();
+/// assert!(clock.now() >= first);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StdClock {
+    origin: std::time::Instant,
+}
+
+#[cfg(feature = "std")]
+impl StdClock {
+    /// Creates a clock whose origin is the current instant.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            origin: std::time::Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for StdClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl MonotonicClock for StdClock {
+    fn now(&self) -> Duration {
+        self.origin.elapsed()
+    }
+}
+
+/// A clock driven by a user-supplied tick counter.
+///
+/// This is intended for bare-metal targets where time comes from a hardware timer,
+/// a `SysTick` interrupt counter, or a cycle counter. The tick source must never go
+/// backwards.
+///
+/// # Examples
+///
+/// ```
+/// use core::{sync::atomic::{AtomicU64, Ordering}, time::Duration};
+///
+/// use libx::time::{MonotonicClock, TickClock};
+///
+/// static TICKS: AtomicU64 = AtomicU64::new(0);
+///
+/// fn ticks() -> u64 {
+///     TICKS.load(Ordering::Relaxed)
+/// }
+///
+/// let clock = TickClock::new(ticks, 1_000);
+///
+/// TICKS.store(1_500, Ordering::Relaxed);
+/// assert_eq!(clock.now(), Duration::from_millis(1_500));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TickClock {
+    source: fn() -> u64,
+    ticks_per_second: u64,
+}
+
+impl TickClock {
+    /// Creates a clock reading `source`, which advances `ticks_per_second` times per second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks_per_second` is zero.
+    #[must_use]
+    pub const fn new(source: fn() -> u64, ticks_per_second: u64) -> Self {
+        assert!(ticks_per_second > 0, "tick rate must be non-zero");
+
+        Self {
+            source,
+            ticks_per_second,
+        }
+    }
+
+    /// Returns the number of ticks per second of the underlying source.
+    #[must_use]
+    pub const fn ticks_per_second(&self) -> u64 {
+        self.ticks_per_second
+    }
+
+    /// Returns the raw tick count of the underlying source.
+    #[must_use]
+    pub fn ticks(&self) -> u64 {
+        (self.source)()
+    }
+}
+
+impl MonotonicClock for TickClock {
+    #[allow(clippy::cast_possible_truncation)]
+    fn now(&self) -> Duration {
+        let ticks = self.ticks();
+
+        let seconds = ticks / self.ticks_per_second;
+        let remainder = u128::from(ticks % self.ticks_per_second);
+
+        // The remainder is below `ticks_per_second`, so the result is below one second.
+        let nanos = remainder * 1_000_000_000 / u128::from(self.ticks_per_second);
+
+        Duration::new(seconds, nanos as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicU64, Ordering};
+
+    use super::*;
+
+    static TICKS: AtomicU64 = AtomicU64::new(0);
+
+    fn ticks() -> u64 {
+        TICKS.load(Ordering::Relaxed)
+    }
+
+    #[test]
+    fn test_tick_clock_conversion() {
+        let clock = TickClock::new(ticks, 32_768);
+
+        TICKS.store(32_768 * 3 + 16_384, Ordering::Relaxed);
+        assert_eq!(clock.now(), Duration::from_millis(3_500));
+    }
+
+    #[test]
+    #[should_panic = "tick rate must be non-zero"]
+    fn test_tick_clock_zero_rate() {
+        let _ = TickClock::new(ticks, 0);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_system_clock_is_monotonic() {
+        let first = SystemClock.now();
+        let second = SystemClock.now();
+        assert!(second >= first);
+    }
+}
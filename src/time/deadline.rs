@@ -0,0 +1,127 @@
+use core::time::Duration;
+
+use super::MonotonicClock;
+
+/// A point in time, measured on a [`MonotonicClock`], after which an operation should give up.
+///
+/// # Examples
+///
+/// ```
+/// use core::{cell::Cell, time::Duration};
+///
+/// use libx::time::{Deadline, MonotonicClock};
+///
+/// struct ManualClock(Cell<Duration>);
+///
+/// impl MonotonicClock for ManualClock {
+///     fn now(&self) -> Duration {
+///         self.0.get()
+///     }
+/// }
+///
+/// let clock = ManualClock(Cell::new(Duration::ZERO));
+/// let deadline = Deadline::after(&clock, Duration::from_secs(5));
+///
+/// clock.0.set(Duration::from_secs(2));
+/// assert_eq!(deadline.remaining(), Duration::from_secs(3));
+/// assert!(!deadline.has_expired());
+///
+/// clock.0.set(Duration::from_secs(5));
+/// assert!(deadline.has_expired());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Deadline<C> {
+    clock: C,
+    expires_at: Duration,
+}
+
+impl<C> Deadline<C>
+where
+    C: MonotonicClock,
+{
+    /// Creates a deadline that expires `timeout` from now.
+    ///
+    /// A timeout that would overflow the clock produces a deadline that never expires.
+    #[must_use]
+    pub fn after(clock: C, timeout: Duration) -> Self {
+        let expires_at = clock.now().saturating_add(timeout);
+        Self { clock, expires_at }
+    }
+
+    /// Creates a deadline that expires when the clock reaches `expires_at`.
+    #[must_use]
+    pub const fn at(clock: C, expires_at: Duration) -> Self {
+        Self { clock, expires_at }
+    }
+
+    /// Returns the clock reading at which the deadline expires.
+    #[must_use]
+    pub const fn expires_at(&self) -> Duration {
+        self.expires_at
+    }
+
+    /// Returns the time left before the deadline expires, or zero if it already has.
+    #[must_use]
+    pub fn remaining(&self) -> Duration {
+        self.expires_at.saturating_sub(self.clock.now())
+    }
+
+    /// Returns `true` once the clock has reached the deadline.
+    #[must_use]
+    pub fn has_expired(&self) -> bool {
+        self.clock.now() >= self.expires_at
+    }
+
+    /// Moves the deadline `timeout` past the current time.
+    pub fn extend(&mut self, timeout: Duration) {
+        self.expires_at = self.clock.now().saturating_add(timeout);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct ManualClock(Cell<Duration>);
+
+    impl MonotonicClock for ManualClock {
+        fn now(&self) -> Duration {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn test_remaining_saturates() {
+        let clock = ManualClock(Cell::new(Duration::from_secs(1)));
+        let deadline = Deadline::after(&clock, Duration::from_secs(1));
+
+        assert_eq!(deadline.expires_at(), Duration::from_secs(2));
+
+        clock.0.set(Duration::from_secs(3));
+        assert_eq!(deadline.remaining(), Duration::ZERO);
+        assert!(deadline.has_expired());
+    }
+
+    #[test]
+    fn test_overflowing_timeout_never_expires() {
+        let clock = ManualClock(Cell::new(Duration::from_secs(1)));
+        let deadline = Deadline::after(&clock, Duration::MAX);
+
+        assert_eq!(deadline.expires_at(), Duration::MAX);
+        assert!(!deadline.has_expired());
+    }
+
+    #[test]
+    fn test_extend() {
+        let clock = ManualClock(Cell::new(Duration::ZERO));
+        let mut deadline = Deadline::at(&clock, Duration::from_secs(1));
+
+        clock.0.set(Duration::from_secs(1));
+        assert!(deadline.has_expired());
+
+        deadline.extend(Duration::from_secs(2));
+        assert_eq!(deadline.remaining(), Duration::from_secs(2));
+    }
+}
@@ -0,0 +1,423 @@
+use core::time::Duration;
+
+use super::MonotonicClock;
+
+/// The algorithm a [`RateLimiter`] uses to admit permits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitAlgorithm {
+    /// Permits are stored as tokens that are replenished in whole quanta at the end of
+    /// every refill period, up to the burst size.
+    TokenBucket,
+
+    /// Acquired permits fill a bucket that drains continuously at the configured rate;
+    /// an acquisition is admitted only if it does not overflow the bucket.
+    LeakyBucket,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum State {
+    TokenBucket { tokens: u64, refilled_at: Duration },
+    LeakyBucket { level: u128, leaked_at: Duration },
+}
+
+/// Limits how often an operation may be performed, measured on a [`MonotonicClock`].
+///
+/// A limiter admits `rate` permits per `period` on average and allows short bursts of up
+/// to `burst` permits.
+///
+/// # Examples
+///
+/// ```
+/// use core::{cell::Cell, time::Duration};
+///
+/// use libx::time::{MonotonicClock, RateLimiter};
+///
+/// struct ManualClock(Cell<Duration>);
+///
+/// impl MonotonicClock for ManualClock {
+///     fn now(&self) -> Duration {
+///         self.0.get()
+///     }
+/// }
+///
+/// let clock = ManualClock(Cell::new(Duration::ZERO));
+///
+/// // Ten requests per second, with bursts of up to five.
+/// let mut limiter = RateLimiter::token_bucket(&clock, 10, Duration::from_secs(1), 5);
+///
+/// assert!(limiter.try_acquire(5));
+/// assert!(!limiter.try_acquire(1));
+/// assert_eq!(limiter.time_until_available(1), Some(Duration::from_secs(1)));
+///
+/// clock.0.set(Duration::from_secs(1));
+/// assert!(limiter.try_acquire(1));
+/// ```
+#[derive(Debug, Clone)]
+pub struct RateLimiter<C> {
+    clock: C,
+    rate: u64,
+    period: Duration,
+    burst: u64,
+    state: State,
+}
+
+impl<C> RateLimiter<C>
+where
+    C: MonotonicClock,
+{
+    /// Creates a token-bucket limiter that adds `rate` tokens at the end of every `period`,
+    /// holding at most `burst` tokens. The bucket starts full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate`, `period`, or `burst` is zero.
+    #[must_use]
+    pub fn token_bucket(clock: C, rate: u64, period: Duration, burst: u64) -> Self {
+        Self::validate(rate, period, burst);
+
+        let refilled_at = clock.now();
+
+        Self {
+            clock,
+            rate,
+            period,
+            burst,
+            state: State::TokenBucket {
+                tokens: burst,
+                refilled_at,
+            },
+        }
+    }
+
+    /// Creates a leaky-bucket limiter that drains `rate` permits per `period` continuously
+    /// and holds at most `capacity` permits. The bucket starts empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate`, `period`, or `capacity` is zero.
+    #[must_use]
+    pub fn leaky_bucket(clock: C, rate: u64, period: Duration, capacity: u64) -> Self {
+        Self::validate(rate, period, capacity);
+
+        let leaked_at = clock.now();
+
+        Self {
+            clock,
+            rate,
+            period,
+            burst: capacity,
+            state: State::LeakyBucket {
+                level: 0,
+                leaked_at,
+            },
+        }
+    }
+
+    fn validate(rate: u64, period: Duration, burst: u64) {
+        assert!(rate > 0, "rate must be non-zero");
+        assert!(!period.is_zero(), "period must be non-zero");
+        assert!(burst > 0, "burst must be non-zero");
+    }
+
+    /// Returns the algorithm this limiter uses.
+    #[must_use]
+    pub const fn algorithm(&self) -> RateLimitAlgorithm {
+        match self.state {
+            State::TokenBucket { .. } => RateLimitAlgorithm::TokenBucket,
+            State::LeakyBucket { .. } => RateLimitAlgorithm::LeakyBucket,
+        }
+    }
+
+    /// Returns the largest number of permits that can be acquired at once.
+    #[must_use]
+    pub const fn burst(&self) -> u64 {
+        self.burst
+    }
+
+    /// Changes the largest number of permits that can be acquired at once.
+    ///
+    /// Permits already stored above the new burst size are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is zero.
+    pub fn set_burst(&mut self, burst: u64) {
+        assert!(burst > 0, "burst must be non-zero");
+
+        self.update();
+        self.burst = burst;
+
+        let capacity = self.capacity();
+
+        match &mut self.state {
+            State::TokenBucket { tokens, .. } => *tokens = (*tokens).min(burst),
+            State::LeakyBucket { level, .. } => *level = (*level).min(capacity),
+        }
+    }
+
+    /// Returns the number of permits that can be acquired right now.
+    pub fn available(&mut self) -> u64 {
+        self.update();
+
+        match self.state {
+            State::TokenBucket { tokens, .. } => tokens,
+            State::LeakyBucket { level, .. } => {
+                let free = self.capacity().saturating_sub(level);
+                u64::try_from(free / self.period_nanos()).unwrap_or(u64::MAX)
+            }
+        }
+    }
+
+    /// Acquires `permits` if they are available right now.
+    ///
+    /// Returns `false`, without consuming anything, if acquiring them would exceed the
+    /// configured rate.
+    pub fn try_acquire(&mut self, permits: u64) -> bool {
+        self.update();
+
+        let capacity = self.capacity();
+        let requested = self.scaled(permits);
+
+        match &mut self.state {
+            State::TokenBucket { tokens, .. } => {
+                if *tokens < permits {
+                    return false;
+                }
+
+                *tokens -= permits;
+            }
+            State::LeakyBucket { level, .. } => {
+                // A request too large to represent exceeds the capacity.
+                let Some(filled) = requested
+                    .and_then(|requested| level.checked_add(requested))
+                    .filter(|&filled| filled <= capacity)
+                else {
+                    return false;
+                };
+
+                *level = filled;
+            }
+        }
+
+        true
+    }
+
+    /// Returns how long to wait before `permits` can be acquired.
+    ///
+    /// Returns [`Duration::ZERO`] if they are available right now, and `None` if the
+    /// request exceeds the burst size and can therefore never be satisfied.
+    pub fn time_until_available(&mut self, permits: u64) -> Option<Duration> {
+        if permits > self.burst {
+            return None;
+        }
+
+        self.update();
+
+        let now = self.clock.now();
+
+        let wait = match self.state {
+            State::TokenBucket {
+                tokens,
+                refilled_at,
+            } => {
+                if tokens >= permits {
+                    return Some(Duration::ZERO);
+                }
+
+                let periods = (permits - tokens).div_ceil(self.rate);
+                let since_refill = now.saturating_sub(refilled_at).as_nanos();
+
+                u128::from(periods)
+                    .saturating_mul(self.period_nanos())
+                    .saturating_sub(since_refill)
+            }
+            State::LeakyBucket { level, .. } => {
+                let requested = self.scaled(permits).unwrap_or(u128::MAX);
+                let excess = level
+                    .saturating_add(requested)
+                    .saturating_sub(self.capacity());
+                excess.div_ceil(u128::from(self.rate))
+            }
+        };
+
+        Some(Duration::from_nanos(
+            u64::try_from(wait).unwrap_or(u64::MAX),
+        ))
+    }
+
+    /// Returns a reference to the underlying clock.
+    #[must_use]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    const fn period_nanos(&self) -> u128 {
+        self.period.as_nanos()
+    }
+
+    /// Converts a number of permits into the fixed-point unit used by the leaky bucket,
+    /// or returns `None` if the result does not fit.
+    const fn scaled(&self, permits: u64) -> Option<u128> {
+        (permits as u128).checked_mul(self.period_nanos())
+    }
+
+    /// Returns the burst size in the fixed-point unit of the leaky bucket, saturating so
+    /// that an unrepresentable capacity errs on the side of refusing permits.
+    const fn capacity(&self) -> u128 {
+        match self.scaled(self.burst) {
+            Some(capacity) => capacity,
+            None => u128::MAX,
+        }
+    }
+
+    fn update(&mut self) {
+        let now = self.clock.now();
+        let period = self.period_nanos();
+        let rate = self.rate;
+        let burst = self.burst;
+
+        match &mut self.state {
+            State::TokenBucket {
+                tokens,
+                refilled_at,
+            } => {
+                let elapsed = now.saturating_sub(*refilled_at).as_nanos();
+                let periods = elapsed / period;
+
+                if periods > 0 {
+                    let added = periods.saturating_mul(u128::from(rate));
+                    let refilled = u128::from(*tokens)
+                        .saturating_add(added)
+                        .min(u128::from(burst));
+
+                    // `refilled` is bounded by `burst`, which is a `u64`.
+                    *tokens = u64::try_from(refilled).unwrap_or(burst);
+                    *refilled_at +=
+                        Duration::from_nanos(u64::try_from(periods * period).unwrap_or(u64::MAX));
+                }
+            }
+            State::LeakyBucket { level, leaked_at } => {
+                let elapsed = now.saturating_sub(*leaked_at).as_nanos();
+
+                *level = level.saturating_sub(elapsed.saturating_mul(u128::from(rate)));
+                *leaked_at = now.max(*leaked_at);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct ManualClock(Cell<Duration>);
+
+    impl ManualClock {
+        const fn new() -> Self {
+            Self(Cell::new(Duration::ZERO))
+        }
+
+        fn advance(&self, millis: u64) {
+            self.0.set(self.0.get() + Duration::from_millis(millis));
+        }
+    }
+
+    impl MonotonicClock for ManualClock {
+        fn now(&self) -> Duration {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn test_token_bucket_starts_full() {
+        let clock = ManualClock::new();
+        let mut limiter = RateLimiter::token_bucket(&clock, 1, Duration::from_secs(1), 3);
+
+        assert_eq!(limiter.algorithm(), RateLimitAlgorithm::TokenBucket);
+        assert_eq!(limiter.available(), 3);
+        assert!(limiter.try_acquire(3));
+        assert!(!limiter.try_acquire(1));
+    }
+
+    #[test]
+    fn test_token_bucket_refills_in_whole_periods() {
+        let clock = ManualClock::new();
+        let mut limiter = RateLimiter::token_bucket(&clock, 2, Duration::from_millis(100), 4);
+
+        assert!(limiter.try_acquire(4));
+
+        clock.advance(99);
+        assert_eq!(limiter.available(), 0);
+        assert_eq!(
+            limiter.time_until_available(3),
+            Some(Duration::from_millis(101))
+        );
+
+        clock.advance(1);
+        assert_eq!(limiter.available(), 2);
+
+        clock.advance(1_000);
+        assert_eq!(limiter.available(), 4);
+    }
+
+    #[test]
+    fn test_leaky_bucket_drains_continuously() {
+        let clock = ManualClock::new();
+        let mut limiter = RateLimiter::leaky_bucket(&clock, 10, Duration::from_secs(1), 2);
+
+        assert_eq!(limiter.algorithm(), RateLimitAlgorithm::LeakyBucket);
+        assert!(limiter.try_acquire(2));
+        assert!(!limiter.try_acquire(1));
+        assert_eq!(
+            limiter.time_until_available(1),
+            Some(Duration::from_millis(100))
+        );
+
+        clock.advance(50);
+        assert!(!limiter.try_acquire(1));
+
+        clock.advance(50);
+        assert!(limiter.try_acquire(1));
+    }
+
+    #[test]
+    fn test_request_larger_than_burst() {
+        let clock = ManualClock::new();
+        let mut limiter = RateLimiter::leaky_bucket(&clock, 1, Duration::from_secs(1), 2);
+
+        assert_eq!(limiter.time_until_available(3), None);
+        assert!(!limiter.try_acquire(3));
+    }
+
+    #[test]
+    fn test_set_burst_discards_excess() {
+        let clock = ManualClock::new();
+        let mut limiter = RateLimiter::token_bucket(&clock, 1, Duration::from_secs(1), 10);
+
+        limiter.set_burst(4);
+        assert_eq!(limiter.burst(), 4);
+        assert_eq!(limiter.available(), 4);
+    }
+
+    #[test]
+    fn test_leaky_bucket_overflow_exceeds_capacity() {
+        let clock = ManualClock::new();
+        let mut limiter = RateLimiter::leaky_bucket(&clock, 1, Duration::MAX, u64::MAX);
+
+        // One period is so long that these counts no longer fit the fixed-point unit.
+        assert!(!limiter.try_acquire(u64::MAX));
+        assert!(limiter.try_acquire(10_000_000_000));
+        assert!(!limiter.try_acquire(10_000_000_000));
+        assert_eq!(limiter.available(), 8_446_744_073);
+        assert!(limiter.time_until_available(u64::MAX).is_some());
+    }
+
+    #[test]
+    #[should_panic = "rate must be non-zero"]
+    fn test_zero_rate() {
+        let clock = ManualClock::new();
+        let _ = RateLimiter::token_bucket(&clock, 0, Duration::from_secs(1), 1);
+    }
+}
@@ -0,0 +1,201 @@
+use core::time::Duration;
+
+use super::MonotonicClock;
+
+/// Measures elapsed time against a [`MonotonicClock`].
+///
+/// A stopwatch accumulates time while it is running and can be paused, resumed,
+/// reset, and queried for laps.
+///
+/// # Examples
+///
+/// ```
+/// use core::{cell::Cell, time::Duration};
+///
+/// use libx::time::{MonotonicClock, Stopwatch};
+///
+/// struct ManualClock(Cell<Duration>);
+///
+/// impl MonotonicClock for ManualClock {
+///     fn now(&self) -> Duration {
+///         self.0.get()
+///     }
+/// }
+///
+/// let clock = ManualClock(Cell::new(Duration::ZERO));
+/// let mut stopwatch = Stopwatch::start_new(&clock);
+///
+/// clock.0.set(Duration::from_millis(250));
+/// assert_eq!(stopwatch.lap(), Duration::from_millis(250));
+///
+/// clock.0.set(Duration::from_millis(400));
+/// stopwatch.stop();
+///
+/// clock.0.set(Duration::from_secs(10));
+/// assert_eq!(stopwatch.elapsed(), Duration::from_millis(400));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stopwatch<C> {
+    clock: C,
+    started_at: Option<Duration>,
+    accumulated: Duration,
+    lap_started_at: Duration,
+}
+
+impl<C> Stopwatch<C>
+where
+    C: MonotonicClock,
+{
+    /// Creates a stopped stopwatch with no elapsed time.
+    #[must_use]
+    pub const fn new(clock: C) -> Self {
+        Self {
+            clock,
+            started_at: None,
+            accumulated: Duration::ZERO,
+            lap_started_at: Duration::ZERO,
+        }
+    }
+
+    /// Creates a stopwatch and immediately starts it.
+    #[must_use]
+    pub fn start_new(clock: C) -> Self {
+        let mut stopwatch = Self::new(clock);
+        stopwatch.start();
+        stopwatch
+    }
+
+    /// Starts or resumes measuring time.
+    ///
+    /// Calling this on a running stopwatch has no effect.
+    pub fn start(&mut self) {
+        if self.started_at.is_none() {
+            self.started_at = Some(self.clock.now());
+        }
+    }
+
+    /// Stops measuring time, keeping the elapsed time accumulated so far.
+    ///
+    /// Calling this on a stopped stopwatch has no effect.
+    pub fn stop(&mut self) {
+        if let Some(started_at) = self.started_at.take() {
+            self.accumulated += self.clock.now().saturating_sub(started_at);
+        }
+    }
+
+    /// Stops the stopwatch and discards all elapsed time.
+    pub const fn reset(&mut self) {
+        self.started_at = None;
+        self.accumulated = Duration::ZERO;
+        self.lap_started_at = Duration::ZERO;
+    }
+
+    /// Discards all elapsed time and starts measuring again.
+    pub fn restart(&mut self) {
+        self.reset();
+        self.start();
+    }
+
+    /// Returns `true` if the stopwatch is currently measuring time.
+    #[must_use]
+    pub const fn is_running(&self) -> bool {
+        self.started_at.is_some()
+    }
+
+    /// Returns the total time measured so far.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.started_at.map_or(self.accumulated, |started_at| {
+            self.accumulated + self.clock.now().saturating_sub(started_at)
+        })
+    }
+
+    /// Returns the time measured since the previous lap (or since the stopwatch was
+    /// first started), and begins a new lap.
+    pub fn lap(&mut self) -> Duration {
+        let elapsed = self.elapsed();
+        let lap = elapsed.saturating_sub(self.lap_started_at);
+        self.lap_started_at = elapsed;
+        lap
+    }
+
+    /// Returns a reference to the underlying clock.
+    #[must_use]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::cell::Cell;
+
+    use super::*;
+
+    struct ManualClock(Cell<Duration>);
+
+    impl ManualClock {
+        const fn new() -> Self {
+            Self(Cell::new(Duration::ZERO))
+        }
+
+        fn advance(&self, millis: u64) {
+            self.0.set(self.0.get() + Duration::from_millis(millis));
+        }
+    }
+
+    impl MonotonicClock for ManualClock {
+        fn now(&self) -> Duration {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn test_new_stopwatch_is_stopped() {
+        let clock = ManualClock::new();
+        let stopwatch = Stopwatch::new(&clock);
+
+        clock.advance(100);
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_stop_and_resume_accumulates() {
+        let clock = ManualClock::new();
+        let mut stopwatch = Stopwatch::start_new(&clock);
+
+        clock.advance(100);
+        stopwatch.stop();
+        clock.advance(1_000);
+        stopwatch.start();
+        clock.advance(50);
+
+        assert_eq!(stopwatch.elapsed(), Duration::from_millis(150));
+    }
+
+    #[test]
+    fn test_laps() {
+        let clock = ManualClock::new();
+        let mut stopwatch = Stopwatch::start_new(&clock);
+
+        clock.advance(10);
+        assert_eq!(stopwatch.lap(), Duration::from_millis(10));
+        clock.advance(30);
+        assert_eq!(stopwatch.lap(), Duration::from_millis(30));
+        assert_eq!(stopwatch.elapsed(), Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_restart() {
+        let clock = ManualClock::new();
+        let mut stopwatch = Stopwatch::start_new(&clock);
+
+        clock.advance(500);
+        stopwatch.restart();
+        clock.advance(5);
+
+        assert!(stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), Duration::from_millis(5));
+    }
+}
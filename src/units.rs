@@ -0,0 +1,431 @@
+//! Units of measurement and conversions between them.
+//!
+//! Every [`Unit`] measures a [`Quantity`] and converts to that quantity's base unit with
+//! an affine [`Converter`], `base = value * scale + offset`. The units are declared in a
+//! single table at the bottom of this module, and the scale of a composite unit such as
+//! miles per hour or pounds per square inch is written as the expression that defines
+//! it rather than as a rounded decimal, so adding a unit is a one-line data edit.
+//!
+//! [`Converter`] is public so that downstream code can describe units this module does
+//! not know and convert them through the same base units.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::units::{Quantity, Unit};
+//!
+//! let fahrenheit = Unit::Celsius.convert(100.0, Unit::Fahrenheit).unwrap();
+//! assert!((fahrenheit - 212.0).abs() < 1e-12);
+//!
+//! let km_h = Unit::Knot.convert(10.0, Unit::KilometerPerHour).unwrap();
+//! assert!((km_h - 18.52).abs() < 1e-12);
+//!
+//! assert_eq!("kn".parse::<Unit>().unwrap().quantity(), Quantity::Speed);
+//!
+//! assert!(Unit::Meter.convert(1.0, Unit::Second).is_err());
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use crate::errors::{ParseError, UnitError};
+
+/// The physical quantity a [`Unit`] measures. Units convert only within a quantity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Quantity {
+    Length,
+    Mass,
+    Time,
+    Temperature,
+    Speed,
+    Pressure,
+    Energy,
+    Power,
+    Volume,
+}
+
+impl Quantity {
+    /// Returns the SI unit that the other units of this quantity convert through.
+    #[must_use]
+    pub const fn base_unit(self) -> Unit {
+        match self {
+            Self::Length => Unit::Meter,
+            Self::Mass => Unit::Kilogram,
+            Self::Time => Unit::Second,
+            Self::Temperature => Unit::Kelvin,
+            Self::Speed => Unit::MeterPerSecond,
+            Self::Pressure => Unit::Pascal,
+            Self::Energy => Unit::Joule,
+            Self::Power => Unit::Watt,
+            Self::Volume => Unit::CubicMeter,
+        }
+    }
+}
+
+/// An affine map from values in one unit to values in another:
+/// `value * scale + offset`.
+///
+/// [`Unit::converter`] maps a unit to its base unit; [`Unit::converter_to`] maps between
+/// two units of the same quantity.
+///
+/// # Examples
+///
+/// ```
+/// use libx::units::{Converter, Unit};
+///
+/// // A furlong is an eighth of a mile.
+/// let furlong = Converter::new(Unit::Mile.converter().scale / 8.0, 0.0);
+///
+/// assert!((furlong.to_base(1.0) - 201.168).abs() < 1e-12);
+/// assert!((furlong.from_base(1609.344) - 8.0).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Converter {
+    /// The factor values are multiplied by.
+    pub scale: f64,
+
+    /// The amount added after scaling, which is zero except between temperature scales.
+    pub offset: f64,
+}
+
+impl Converter {
+    /// Creates the map `value * scale + offset`.
+    #[must_use]
+    pub const fn new(scale: f64, offset: f64) -> Self {
+        Self { scale, offset }
+    }
+
+    /// Applies the map.
+    #[must_use]
+    pub const fn to_base(self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// Applies the inverse of the map.
+    #[must_use]
+    pub const fn from_base(self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+
+    /// Returns the map that applies `self` and then the inverse of `other`, which
+    /// converts from the unit of `self` to the unit of `other`.
+    #[must_use]
+    pub const fn to(self, other: Self) -> Self {
+        Self::new(
+            self.scale / other.scale,
+            (self.offset - other.offset) / other.scale,
+        )
+    }
+}
+
+impl Unit {
+    /// Returns the map from a value in this unit to a value in the base unit of its
+    /// quantity.
+    #[must_use]
+    pub const fn converter(self) -> Converter {
+        let (scale, offset) = self.definition();
+        Converter::new(scale, offset)
+    }
+
+    /// Returns the map from values in this unit to values in `other`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::Incompatible`] if the units measure different quantities.
+    pub fn converter_to(self, other: Self) -> Result<Converter, UnitError> {
+        if self.quantity() != other.quantity() {
+            return Err(UnitError::Incompatible {
+                from: self.symbol(),
+                to: other.symbol(),
+            });
+        }
+
+        Ok(self.converter().to(other.converter()))
+    }
+
+    /// Converts `value` from this unit to `other`.
+    ///
+    /// The value passes through the base unit, so conversions between a unit and its
+    /// base are exact whenever the arithmetic is.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UnitError::Incompatible`] if the units measure different quantities.
+    pub fn convert(self, value: f64, other: Self) -> Result<f64, UnitError> {
+        self.converter_to(other)?;
+
+        Ok(other.converter().from_base(self.converter().to_base(value)))
+    }
+
+    /// Returns the unit with the given symbol.
+    #[must_use]
+    pub fn from_symbol(symbol: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|unit| unit.symbol() == symbol)
+    }
+}
+
+impl fmt::Display for Unit {
+    /// Formats the unit as its symbol.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ParseError;
+
+    /// Parses a unit symbol, such as `km/h` or `°C`. Symbols are case-sensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        Self::from_symbol(s).ok_or(ParseError::InvalidSyntax)
+    }
+}
+
+macro_rules! units {
+    ($(
+        $(#[$meta:meta])*
+        $name:ident = $quantity:ident, $symbol:literal, $scale:expr $(, $offset:expr)?;
+    )*) => {
+        /// A unit of measurement.
+        ///
+        /// The variants are generated from the table at the bottom of
+        /// [`units`](crate::units), which gives each unit's quantity, symbol and
+        /// conversion to the base unit.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum Unit {
+            $($(#[$meta])* $name,)*
+        }
+
+        impl Unit {
+            /// Every unit, in the order of the table.
+            pub const ALL: &[Self] = &[$(Self::$name),*];
+
+            /// Returns the quantity the unit measures.
+            #[must_use]
+            pub const fn quantity(self) -> Quantity {
+                match self {
+                    $(Self::$name => Quantity::$quantity,)*
+                }
+            }
+
+            /// Returns the symbol of the unit.
+            #[must_use]
+            pub const fn symbol(self) -> &'static str {
+                match self {
+                    $(Self::$name => $symbol,)*
+                }
+            }
+
+            const fn definition(self) -> (f64, f64) {
+                match self {
+                    $(Self::$name => ($scale, units!(@offset $($offset)?)),)*
+                }
+            }
+        }
+    };
+    (@offset) => { 0.0 };
+    (@offset $offset:expr) => { $offset };
+}
+
+/// Meters in an international inch.
+const INCH: f64 = 0.0254;
+
+/// Meters in an international mile.
+const MILE: f64 = 1_609.344;
+
+/// Meters in a nautical mile.
+const NAUTICAL_MILE: f64 = 1_852.0;
+
+/// Kilograms in an avoirdupois pound.
+const POUND: f64 = 0.453_592_37;
+
+/// Standard gravity in meters per second squared, which turns a pound into a force.
+const STANDARD_GRAVITY: f64 = 9.806_65;
+
+/// Seconds in an hour.
+const HOUR: f64 = 3_600.0;
+
+units! {
+    /// The meter, the base unit of length.
+    Meter = Length, "m", 1.0;
+    /// The kilometer.
+    Kilometer = Length, "km", 1_000.0;
+    /// The centimeter.
+    Centimeter = Length, "cm", 0.01;
+    /// The millimeter.
+    Millimeter = Length, "mm", 0.001;
+    /// The international inch.
+    Inch = Length, "in", INCH;
+    /// The international foot.
+    Foot = Length, "ft", 12.0 * INCH;
+    /// The international yard.
+    Yard = Length, "yd", 36.0 * INCH;
+    /// The international mile.
+    Mile = Length, "mi", MILE;
+    /// The nautical mile.
+    NauticalMile = Length, "nmi", NAUTICAL_MILE;
+
+    /// The kilogram, the base unit of mass.
+    Kilogram = Mass, "kg", 1.0;
+    /// The gram.
+    Gram = Mass, "g", 0.001;
+    /// The milligram.
+    Milligram = Mass, "mg", 1e-6;
+    /// The metric tonne.
+    Tonne = Mass, "t", 1_000.0;
+    /// The avoirdupois pound.
+    Pound = Mass, "lb", POUND;
+    /// The avoirdupois ounce.
+    Ounce = Mass, "oz", POUND / 16.0;
+
+    /// The second, the base unit of time.
+    Second = Time, "s", 1.0;
+    /// The minute.
+    Minute = Time, "min", 60.0;
+    /// The hour.
+    Hour = Time, "h", HOUR;
+    /// The day of 24 hours.
+    Day = Time, "d", 24.0 * HOUR;
+
+    /// The kelvin, the base unit of temperature.
+    Kelvin = Temperature, "K", 1.0;
+    /// The degree Celsius.
+    Celsius = Temperature, "°C", 1.0, 273.15;
+    /// The degree Fahrenheit.
+    Fahrenheit = Temperature, "°F", 5.0 / 9.0, 459.67 * 5.0 / 9.0;
+
+    /// The meter per second, the base unit of speed.
+    MeterPerSecond = Speed, "m/s", 1.0;
+    /// The kilometer per hour.
+    KilometerPerHour = Speed, "km/h", 1_000.0 / HOUR;
+    /// The mile per hour.
+    MilePerHour = Speed, "mph", MILE / HOUR;
+    /// The knot, one nautical mile per hour.
+    Knot = Speed, "kn", NAUTICAL_MILE / HOUR;
+
+    /// The pascal, the base unit of pressure.
+    Pascal = Pressure, "Pa", 1.0;
+    /// The kilopascal.
+    Kilopascal = Pressure, "kPa", 1_000.0;
+    /// The bar.
+    Bar = Pressure, "bar", 100_000.0;
+    /// The standard atmosphere.
+    Atmosphere = Pressure, "atm", 101_325.0;
+    /// The pound-force per square inch.
+    PoundPerSquareInch = Pressure, "psi", POUND * STANDARD_GRAVITY / INCH / INCH;
+    /// The conventional millimeter of mercury.
+    MillimeterOfMercury = Pressure, "mmHg", 133.322_387_415;
+
+    /// The joule, the base unit of energy.
+    Joule = Energy, "J", 1.0;
+    /// The kilojoule.
+    Kilojoule = Energy, "kJ", 1_000.0;
+    /// The thermochemical calorie.
+    Calorie = Energy, "cal", 4.184;
+    /// The kilocalorie, or food calorie.
+    Kilocalorie = Energy, "kcal", 4_184.0;
+    /// The watt-hour.
+    WattHour = Energy, "Wh", HOUR;
+    /// The kilowatt-hour.
+    KilowattHour = Energy, "kWh", 1_000.0 * HOUR;
+
+    /// The watt, the base unit of power.
+    Watt = Power, "W", 1.0;
+    /// The kilowatt.
+    Kilowatt = Power, "kW", 1_000.0;
+    /// The mechanical horsepower of 550 foot-pounds per second.
+    Horsepower = Power, "hp", 550.0 * 12.0 * INCH * POUND * STANDARD_GRAVITY;
+
+    /// The cubic meter, the base unit of volume.
+    CubicMeter = Volume, "m³", 1.0;
+    /// The liter.
+    Liter = Volume, "L", 0.001;
+    /// The milliliter.
+    Milliliter = Volume, "mL", 1e-6;
+    /// The US liquid gallon of 231 cubic inches.
+    Gallon = Volume, "gal", 231.0 * INCH * INCH * INCH;
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn assert_converts(value: f64, from: Unit, expected: f64, to: Unit) {
+        let converted = from.convert(value, to).expect("the units are compatible");
+
+        assert!(
+            (converted - expected).abs() <= 1e-9 * expected.abs().max(1.0),
+            "{value} {from} is {converted} {to}, not {expected}"
+        );
+    }
+
+    #[test]
+    fn test_table_is_consistent() {
+        for (index, &unit) in Unit::ALL.iter().enumerate() {
+            assert_eq!(unit.symbol().parse(), Ok(unit));
+            assert_eq!(unit.to_string(), unit.symbol());
+            assert!(
+                Unit::ALL[..index]
+                    .iter()
+                    .all(|other| other.symbol() != unit.symbol())
+            );
+            assert!(unit.converter().scale > 0.0);
+        }
+
+        for &unit in Unit::ALL {
+            let base = unit.quantity().base_unit();
+            assert_eq!(base.quantity(), unit.quantity());
+            assert_eq!(base.converter(), Converter::new(1.0, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_converts(100.0, Unit::Celsius, 212.0, Unit::Fahrenheit);
+        assert_converts(-40.0, Unit::Fahrenheit, -40.0, Unit::Celsius);
+        assert_converts(0.0, Unit::Kelvin, -273.15, Unit::Celsius);
+        assert_converts(1.0, Unit::Knot, 1.852, Unit::KilometerPerHour);
+        assert_converts(60.0, Unit::MilePerHour, 26.8224, Unit::MeterPerSecond);
+        assert_converts(
+            1.0,
+            Unit::PoundPerSquareInch,
+            6.894_757_293_168,
+            Unit::Kilopascal,
+        );
+        assert_converts(
+            760.0,
+            Unit::MillimeterOfMercury,
+            101_325.014_435_4,
+            Unit::Pascal,
+        );
+        assert_converts(1.0, Unit::KilowattHour, 3_600.0, Unit::Kilojoule);
+        assert_converts(1.0, Unit::Horsepower, 745.699_871_582_270_2, Unit::Watt);
+        assert_converts(1.0, Unit::Gallon, 3.785_411_784, Unit::Liter);
+        assert_converts(1.0, Unit::Mile, 5_280.0, Unit::Foot);
+        assert_converts(1.0, Unit::Pound, 16.0, Unit::Ounce);
+
+        let converter = Unit::Celsius
+            .converter_to(Unit::Fahrenheit)
+            .expect("the units are compatible");
+        assert!((converter.scale - 1.8).abs() < 1e-15);
+        assert!((converter.offset - 32.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            Unit::Meter.convert(1.0, Unit::Second),
+            Err(UnitError::Incompatible { from: "m", to: "s" })
+        );
+        assert_eq!("".parse::<Unit>(), Err(ParseError::Empty));
+        assert_eq!("KM".parse::<Unit>(), Err(ParseError::InvalidSyntax));
+    }
+}
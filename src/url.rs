@@ -0,0 +1,6 @@
+//! Parts of URLs.
+//!
+//! The [`idna`] module converts internationalized domain names to and from the ASCII
+//! form used on the wire.
+
+pub mod idna;
@@ -0,0 +1,494 @@
+//! Internationalized domain names: Punycode and the UTS #46 conversions.
+//!
+//! [`to_ascii`] turns a domain name such as `Bücher.example` into the form DNS uses,
+//! `xn--bcher-kva.example`, and [`to_unicode`] turns it back. Both first map the name as
+//! UTS #46 describes: letters are case folded, the ideographic full stops become dots
+//! and invisible characters such as soft hyphens are removed. Processing is
+//! nontransitional, so `ß` and `ς` are kept rather than mapped to `ss` and `σ`, and
+//! hostname rules apply: ASCII labels may only contain letters, digits and hyphens.
+//!
+//! Without the `idna-mapping` feature, mapping is limited to case folding, full-width
+//! ASCII and the characters named above. With it, the compatibility mappings of
+//! `NFKC_Casefold` are applied too, so `ｆｉ`, `ﬁ` and `ᶠⁱ` all become `fi`. Input is
+//! expected in normalization form C either way.
+//!
+//! [`encode`] and [`decode`] are the raw Punycode conversions of RFC 3492, for a single
+//! label without the `xn--` prefix.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::url::idna;
+//!
+//! assert_eq!(idna::to_ascii("Bücher.Example.").as_deref(), Ok("xn--bcher-kva.example."));
+//! assert_eq!(idna::to_unicode("xn--bcher-kva.example").as_deref(), Ok("bücher.example"));
+//!
+//! assert_eq!(idna::encode("münchen").as_deref(), Ok("mnchen-3ya"));
+//! assert_eq!(idna::decode("mnchen-3ya").as_deref(), Ok("münchen"));
+//! ```
+
+#[cfg(feature = "idna-mapping")]
+mod table;
+
+use alloc::{string::String, vec::Vec};
+
+use crate::errors::IdnaError;
+
+/// The prefix of a Punycode label in a domain name.
+pub const ACE_PREFIX: &str = "xn--";
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+
+/// Encodes `label` in Punycode, without the `xn--` prefix.
+///
+/// # Errors
+///
+/// Returns [`IdnaError::InvalidPunycode`] if the label is too long to encode.
+///
+/// # Examples
+///
+/// ```
+/// use libx::url::idna;
+///
+/// assert_eq!(idna::encode("bücher").as_deref(), Ok("bcher-kva"));
+/// assert_eq!(idna::encode("例え").as_deref(), Ok("r8jz45g"));
+/// ```
+pub fn encode(label: &str) -> Result<String, IdnaError> {
+    let code_points: Vec<u32> = label.chars().map(u32::from).collect();
+    let mut output: String = label.chars().filter(char::is_ascii).collect();
+    let basic = u32::try_from(output.len()).map_err(|_| IdnaError::InvalidPunycode)?;
+    let total = u32::try_from(code_points.len()).map_err(|_| IdnaError::InvalidPunycode)?;
+
+    if basic > 0 {
+        output.push('-');
+    }
+
+    let (mut n, mut delta, mut bias, mut handled) = (INITIAL_N, 0_u32, INITIAL_BIAS, basic);
+
+    while handled < total {
+        let Some(next) = code_points.iter().copied().filter(|&c| c >= n).min() else {
+            break;
+        };
+
+        delta = (next - n)
+            .checked_mul(handled + 1)
+            .and_then(|step| delta.checked_add(step))
+            .ok_or(IdnaError::InvalidPunycode)?;
+        n = next;
+
+        for &code_point in &code_points {
+            if code_point < n {
+                delta = delta.checked_add(1).ok_or(IdnaError::InvalidPunycode)?;
+            } else if code_point == n {
+                let mut q = delta;
+                let mut k = BASE;
+
+                loop {
+                    let t = threshold(k, bias);
+
+                    if q < t {
+                        break;
+                    }
+
+                    output.push(digit(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+
+                output.push(digit(q));
+                bias = adapt(delta, handled + 1, handled == basic);
+                delta = 0;
+                handled += 1;
+            }
+        }
+
+        delta = delta.checked_add(1).ok_or(IdnaError::InvalidPunycode)?;
+        n += 1;
+    }
+
+    Ok(output)
+}
+
+/// Decodes the Punycode `label`, without the `xn--` prefix.
+///
+/// # Errors
+///
+/// Returns [`IdnaError::InvalidPunycode`] if the label is not valid Punycode.
+pub fn decode(label: &str) -> Result<String, IdnaError> {
+    let invalid = IdnaError::InvalidPunycode;
+    let (basic, extended) = label.rsplit_once('-').unwrap_or(("", label));
+
+    if !basic.is_ascii() {
+        return Err(invalid);
+    }
+
+    let mut output: Vec<char> = basic.chars().collect();
+    let (mut n, mut i, mut bias) = (INITIAL_N, 0_u32, INITIAL_BIAS);
+    let mut digits = extended.bytes().peekable();
+
+    while digits.peek().is_some() {
+        let previous = i;
+        let mut weight = 1_u32;
+        let mut k = BASE;
+
+        loop {
+            let value = digits.next().and_then(digit_value).ok_or(invalid)?;
+            i = value
+                .checked_mul(weight)
+                .and_then(|step| i.checked_add(step))
+                .ok_or(invalid)?;
+
+            let t = threshold(k, bias);
+
+            if value < t {
+                break;
+            }
+
+            weight = weight.checked_mul(BASE - t).ok_or(invalid)?;
+            k += BASE;
+        }
+
+        let length = u32::try_from(output.len() + 1).map_err(|_| invalid)?;
+        bias = adapt(i - previous, length, previous == 0);
+        n = n.checked_add(i / length).ok_or(invalid)?;
+        i %= length;
+
+        let character = char::from_u32(n).filter(|c| !c.is_ascii()).ok_or(invalid)?;
+        output.insert(usize::try_from(i).map_err(|_| invalid)?, character);
+        i += 1;
+    }
+
+    Ok(output.into_iter().collect())
+}
+
+/// Returns the threshold of the digit at position `k` for `bias`.
+const fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+/// Returns the bias after a code point was inserted at distance `delta`, with
+/// `points` code points written so far.
+const fn adapt(delta: u32, points: u32, first: bool) -> u32 {
+    let mut delta = if first { delta / DAMP } else { delta / 2 };
+    delta += delta / points;
+
+    let mut k = 0;
+
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+/// Returns the character of the Punycode digit `value`, which is below 36.
+fn digit(value: u32) -> char {
+    let byte = u8::try_from(value).unwrap_or(0);
+
+    char::from(if byte < 26 {
+        b'a' + byte
+    } else {
+        b'0' + byte - 26
+    })
+}
+
+/// Returns the value of the Punycode digit `byte`, in either case.
+fn digit_value(byte: u8) -> Option<u32> {
+    match byte {
+        b'a'..=b'z' => Some(u32::from(byte - b'a')),
+        b'A'..=b'Z' => Some(u32::from(byte - b'A')),
+        b'0'..=b'9' => Some(u32::from(byte - b'0') + 26),
+        _ => None,
+    }
+}
+
+/// Converts `domain` to its ASCII form, with Punycode labels for those that are not
+/// ASCII after mapping.
+///
+/// A trailing dot for the DNS root is kept.
+///
+/// # Errors
+///
+/// Returns an [`IdnaError`] if a label is empty, too long, has misplaced hyphens, holds
+/// a character that is not allowed, or is a malformed `xn--` label, or if the whole name
+/// is longer than 253 bytes.
+pub fn to_ascii(domain: &str) -> Result<String, IdnaError> {
+    let mut output = String::with_capacity(domain.len());
+
+    for (index, label) in labels(&map(domain))?.iter().enumerate() {
+        if index > 0 {
+            output.push('.');
+        }
+
+        let start = output.len();
+
+        if label.is_ascii() {
+            output.push_str(label);
+        } else {
+            output.push_str(ACE_PREFIX);
+            output.push_str(&encode(label)?);
+        }
+
+        if output.len() - start > 63 {
+            return Err(IdnaError::LabelTooLong);
+        }
+    }
+
+    if output.trim_end_matches('.').len() > 253 {
+        return Err(IdnaError::DomainTooLong);
+    }
+
+    Ok(output)
+}
+
+/// Converts `domain` to its Unicode form, decoding Punycode labels.
+///
+/// # Errors
+///
+/// Returns an [`IdnaError`] under the same conditions as [`to_ascii`], except for the
+/// length limits.
+pub fn to_unicode(domain: &str) -> Result<String, IdnaError> {
+    Ok(labels(&map(domain))?.join("."))
+}
+
+/// Splits the mapped `domain` into labels, decoding and checking every one. A trailing
+/// dot gives an empty last label.
+fn labels(domain: &str) -> Result<Vec<String>, IdnaError> {
+    let count = domain.split('.').count();
+
+    domain
+        .split('.')
+        .enumerate()
+        .map(|(index, label)| {
+            if label.is_empty() {
+                return if index + 1 == count && count > 1 {
+                    Ok(String::new())
+                } else {
+                    Err(IdnaError::EmptyLabel)
+                };
+            }
+
+            let decoded = match label.strip_prefix(ACE_PREFIX) {
+                Some(encoded) => {
+                    let decoded = decode(encoded)?;
+
+                    // The label must have been written by `to_ascii` from a label that
+                    // needs Punycode and is already mapped.
+                    if decoded.is_ascii() || map(&decoded) != decoded {
+                        return Err(IdnaError::InvalidPunycode);
+                    }
+
+                    decoded
+                }
+                None => String::from(label),
+            };
+
+            check_label(&decoded)?;
+            Ok(decoded)
+        })
+        .collect()
+}
+
+/// Checks the hyphens and characters of the decoded, non-empty `label`.
+fn check_label(label: &str) -> Result<(), IdnaError> {
+    if label.starts_with('-') || label.ends_with('-') || label.get(2..4) == Some("--") {
+        return Err(IdnaError::InvalidHyphen);
+    }
+
+    label
+        .chars()
+        .find(|&character| !is_allowed(character))
+        .map_or(Ok(()), |character| {
+            Err(IdnaError::DisallowedCharacter(character))
+        })
+}
+
+/// Returns whether the mapped `character` may appear in a label.
+///
+/// ASCII follows the hostname rules. Of the rest, control and whitespace characters,
+/// private use characters, noncharacters and the replacement characters are rejected.
+fn is_allowed(character: char) -> bool {
+    if character.is_ascii() {
+        return character.is_ascii_lowercase() || character.is_ascii_digit() || character == '-';
+    }
+
+    let code_point = u32::from(character);
+
+    !(character.is_control()
+        || character.is_whitespace()
+        || matches!(code_point, 0xE000..=0xF8FF | 0xF_0000..=0xF_FFFD | 0x10_0000..=0x10_FFFD)
+        || matches!(code_point, 0xFDD0..=0xFDEF | 0xFFFC | 0xFFFD)
+        || code_point & 0xFFFE == 0xFFFE)
+}
+
+/// Applies the UTS #46 mapping to `domain`.
+fn map(domain: &str) -> String {
+    let mut output = String::with_capacity(domain.len());
+
+    for character in domain.chars() {
+        match u32::from(character) {
+            0x3002 | 0xFF0E | 0xFF61 => output.push('.'),
+            // Default ignorables are removed.
+            0xAD
+            | 0x34F
+            | 0x180B..=0x180D
+            | 0x200B
+            | 0x2060
+            | 0xFE00..=0xFE0F
+            | 0xFEFF
+            | 0x1_BCA0..=0x1_BCA3
+            | 0xE_0100..=0xE_01EF => {}
+            // The deviation characters are kept by nontransitional processing.
+            0xDF | 0x3C2 | 0x200C | 0x200D => output.push(character),
+            _ => map_character(character, &mut output),
+        }
+    }
+
+    output
+}
+
+/// Writes the mapping of `character`, from the full table.
+#[cfg(feature = "idna-mapping")]
+fn map_character(character: char, output: &mut String) {
+    use core::cmp::Ordering;
+
+    let code_point = u32::from(character);
+    let shifted = table::SHIFTED.binary_search_by(|&(first, last, _)| {
+        if last < code_point {
+            Ordering::Less
+        } else if first > code_point {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    });
+
+    if let Ok(index) = shifted {
+        let (_, _, distance) = table::SHIFTED[index];
+
+        if let Some(mapped) = code_point
+            .checked_add_signed(distance)
+            .and_then(char::from_u32)
+        {
+            output.push(mapped);
+            return;
+        }
+    }
+
+    if let Ok(index) = table::EXPANDED.binary_search_by_key(&code_point, |&(first, _)| first) {
+        output.push_str(table::EXPANDED[index].1);
+        return;
+    }
+
+    output.extend(character.to_lowercase());
+}
+
+/// Writes the mapping of `character`: full-width ASCII is narrowed and everything is
+/// lowercased.
+#[cfg(not(feature = "idna-mapping"))]
+fn map_character(character: char, output: &mut String) {
+    let narrow = match u32::from(character) {
+        code_point @ 0xFF01..=0xFF5E => char::from_u32(code_point - 0xFEE0).unwrap_or(character),
+        _ => character,
+    };
+
+    output.extend(narrow.to_lowercase());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_punycode_samples() {
+        // Samples from RFC 3492, section 7.1.
+        let samples = [
+            (
+                "\u{644}\u{64A}\u{647}\u{645}\u{627}\u{628}\u{62A}\u{643}\u{644}\u{645}\u{648}\
+                 \u{634}\u{639}\u{631}\u{628}\u{64A}\u{61F}",
+                "egbpdaj6bu4bxfgehfvwxn",
+            ),
+            (
+                "\u{4ED6}\u{4EEC}\u{4E3A}\u{4EC0}\u{4E48}\u{4E0D}\u{8BF4}\u{4E2D}\u{6587}",
+                "ihqwcrb4cv8a8dqg056pqjye",
+            ),
+            ("3年B組金八先生", "3B-ww4c5e180e575a65lsy2b"),
+            (
+                "安室奈美恵-with-SUPER-MONKEYS",
+                "-with-SUPER-MONKEYS-pc58ag80a8qai00g7n9n",
+            ),
+            ("Pročprostěnemluvíčesky", "Proprostnemluvesky-uyb24dma41a"),
+            ("-> $1.00 <-", "-> $1.00 <--"),
+        ];
+
+        for (text, encoded) in samples {
+            assert_eq!(encode(text).as_deref(), Ok(encoded));
+            assert_eq!(decode(encoded).as_deref(), Ok(text));
+        }
+
+        assert_eq!(decode("99999999999"), Err(IdnaError::InvalidPunycode));
+        assert_eq!(decode("a+b"), Err(IdnaError::InvalidPunycode));
+        assert_eq!(decode("ü-abc"), Err(IdnaError::InvalidPunycode));
+    }
+
+    #[test]
+    fn test_to_ascii_and_to_unicode() {
+        assert_eq!(to_ascii("example.com").as_deref(), Ok("example.com"));
+        assert_eq!(
+            to_ascii("ÉCOLE。Example").as_deref(),
+            Ok("xn--cole-9oa.example")
+        );
+        assert_eq!(
+            to_ascii("ｅｘａｍｐｌｅ．com").as_deref(),
+            Ok("example.com")
+        );
+        assert_eq!(
+            to_ascii("so\u{AD}ft.example").as_deref(),
+            Ok("soft.example")
+        );
+        assert_eq!(to_ascii("faß.de").as_deref(), Ok("xn--fa-hia.de"));
+        assert_eq!(to_unicode("XN--FA-HIA.de").as_deref(), Ok("faß.de"));
+        assert_eq!(
+            to_unicode("xn--bcher-kva.example.").as_deref(),
+            Ok("bücher.example.")
+        );
+
+        assert_eq!(to_ascii("a..b"), Err(IdnaError::EmptyLabel));
+        assert_eq!(to_ascii("."), Err(IdnaError::EmptyLabel));
+        assert_eq!(to_ascii("-a.b"), Err(IdnaError::InvalidHyphen));
+        assert_eq!(to_ascii("ab--c.d"), Err(IdnaError::InvalidHyphen));
+        assert_eq!(to_ascii("a_b.c"), Err(IdnaError::DisallowedCharacter('_')));
+        assert_eq!(to_ascii("a b"), Err(IdnaError::DisallowedCharacter(' ')));
+        assert_eq!(to_ascii("xn--abc-.d"), Err(IdnaError::InvalidPunycode));
+
+        let unmapped = alloc::format!("xn--{}", encode("Ü").expect("the label is short"));
+        assert_eq!(to_unicode(&unmapped), Err(IdnaError::InvalidPunycode));
+
+        let long = "a".repeat(64);
+        assert_eq!(to_ascii(&long), Err(IdnaError::LabelTooLong));
+        let label = "a".repeat(63);
+        let domain = [label.as_str(); 4].join(".");
+        assert_eq!(to_ascii(&domain), Err(IdnaError::DomainTooLong));
+    }
+
+    #[cfg(feature = "idna-mapping")]
+    #[test]
+    fn test_compatibility_mapping() {
+        assert_eq!(to_ascii("ﬁ.ᶠⁱ.ｆｉ").as_deref(), Ok("fi.fi.fi"));
+        assert_eq!(to_ascii("①.㎏").as_deref(), Ok("1.kg"));
+        assert_eq!(to_ascii("ǅ").as_deref(), to_ascii("dž").as_deref());
+    }
+}
@@ -0,0 +1,1187 @@
+//! The UTS #46 mapping of the characters that do not simply map to their lowercase form.
+//!
+//! Derived from `NFKC(casefold(NFKC(c)))` for every assigned character of Unicode 14.0.0,
+//! leaving out the deviation characters that nontransitional processing keeps, the
+//! default ignorables and the label separators, which [`super::map`] handles itself.
+
+/// Runs of characters that map to one character at a fixed distance: the first
+/// character of the run, the last, and the distance.
+#[rustfmt::skip]
+pub(super) static SHIFTED: [(u32, u32, i32); 2286] = [
+    (0xA0, 0xA0, -128), (0xAA, 0xAA, -73), (0xB2, 0xB3, -128), (0xB5, 0xB5, 775),
+    (0xB9, 0xB9, -136), (0xBA, 0xBA, -75), (0x17F, 0x17F, -268), (0x2B0, 0x2B0, -584),
+    (0x2B1, 0x2B1, -75), (0x2B2, 0x2B2, -584), (0x2B3, 0x2B3, -577), (0x2B4, 0x2B4, -59),
+    (0x2B5, 0x2B5, -58), (0x2B6, 0x2B6, -53), (0x2B7, 0x2B7, -576), (0x2B8, 0x2B8, -575),
+    (0x2E0, 0x2E0, -125), (0x2E1, 0x2E1, -629), (0x2E2, 0x2E2, -623), (0x2E3, 0x2E3, -619),
+    (0x2E4, 0x2E4, -79), (0x340, 0x341, -64), (0x343, 0x343, -48), (0x345, 0x345, 116),
+    (0x374, 0x374, -187), (0x37E, 0x37E, -835), (0x387, 0x387, -720), (0x3D0, 0x3D0, -30),
+    (0x3D1, 0x3D1, -25), (0x3D2, 0x3D2, -13), (0x3D3, 0x3D3, -6), (0x3D4, 0x3D4, -9),
+    (0x3D5, 0x3D5, -15), (0x3D6, 0x3D6, -22), (0x3F0, 0x3F0, -54), (0x3F1, 0x3F1, -48),
+    (0x3F2, 0x3F2, -47), (0x3F5, 0x3F5, -64), (0x3F9, 0x3F9, -54), (0xF0C, 0xF0C, -1),
+    (0x10FC, 0x10FC, -32), (0x13A0, 0x13F5, 0), (0x13F8, 0x13FD, -8), (0x1C80, 0x1C80, -6222),
+    (0x1C81, 0x1C81, -6221), (0x1C82, 0x1C82, -6212), (0x1C83, 0x1C84, -6210),
+    (0x1C85, 0x1C85, -6211), (0x1C86, 0x1C86, -6204), (0x1C87, 0x1C87, -6180),
+    (0x1C88, 0x1C88, 35267), (0x1D2C, 0x1D2C, -7371), (0x1D2D, 0x1D2D, -7239),
+    (0x1D2E, 0x1D2E, -7372), (0x1D30, 0x1D31, -7372), (0x1D32, 0x1D32, -6997),
+    (0x1D33, 0x1D3A, -7372), (0x1D3C, 0x1D3C, -7373), (0x1D3D, 0x1D3D, -6938),
+    (0x1D3E, 0x1D3E, -7374), (0x1D3F, 0x1D3F, -7373), (0x1D40, 0x1D41, -7372),
+    (0x1D42, 0x1D42, -7371), (0x1D43, 0x1D43, -7394), (0x1D44, 0x1D45, -6900),
+    (0x1D46, 0x1D46, -68), (0x1D47, 0x1D47, -7397), (0x1D48, 0x1D49, -7396),
+    (0x1D4A, 0x1D4A, -6897), (0x1D4B, 0x1D4C, -6896), (0x1D4D, 0x1D4D, -7398),
+    (0x1D4F, 0x1D4F, -7396), (0x1D50, 0x1D50, -7395), (0x1D51, 0x1D51, -7174),
+    (0x1D52, 0x1D52, -7395), (0x1D53, 0x1D53, -6911), (0x1D54, 0x1D55, -62),
+    (0x1D56, 0x1D56, -7398), (0x1D57, 0x1D58, -7395), (0x1D59, 0x1D59, -60),
+    (0x1D5A, 0x1D5A, -6891), (0x1D5B, 0x1D5B, -7397), (0x1D5C, 0x1D5C, -55),
+    (0x1D5D, 0x1D5F, -6571), (0x1D60, 0x1D61, -6554), (0x1D62, 0x1D62, -7417),
+    (0x1D63, 0x1D63, -7409), (0x1D64, 0x1D65, -7407), (0x1D66, 0x1D67, -6580),
+    (0x1D68, 0x1D68, -6567), (0x1D69, 0x1D6A, -6563), (0x1D78, 0x1D78, -6459),
+    (0x1D9B, 0x1D9B, -6985), (0x1D9C, 0x1D9C, -7481), (0x1D9D, 0x1D9D, -6984),
+    (0x1D9E, 0x1D9E, -7342), (0x1D9F, 0x1D9F, -6979), (0x1DA0, 0x1DA0, -7482),
+    (0x1DA1, 0x1DA1, -6978), (0x1DA2, 0x1DA2, -6977), (0x1DA3, 0x1DA3, -6974),
+    (0x1DA4, 0x1DA6, -6972), (0x1DA7, 0x1DA7, -44), (0x1DA8, 0x1DA8, -6923),
+    (0x1DA9, 0x1DA9, -6972), (0x1DAA, 0x1DAA, -37), (0x1DAB, 0x1DAB, -6924),
+    (0x1DAC, 0x1DAC, -6971), (0x1DAD, 0x1DAD, -6973), (0x1DAE, 0x1DB1, -6972),
+    (0x1DB2, 0x1DB2, -6970), (0x1DB3, 0x1DB4, -6961), (0x1DB5, 0x1DB5, -7178),
+    (0x1DB6, 0x1DB7, -6957), (0x1DB8, 0x1DB8, -156), (0x1DB9, 0x1DBA, -6958),
+    (0x1DBB, 0x1DBB, -7489), (0x1DBC, 0x1DBE, -6956), (0x1DBF, 0x1DBF, -6663),
+    (0x1E9B, 0x1E9B, -58), (0x1F71, 0x1F71, -7109), (0x1F73, 0x1F73, -7110),
+    (0x1F75, 0x1F75, -7111), (0x1F77, 0x1F77, -7112), (0x1F79, 0x1F79, -7085),
+    (0x1F7B, 0x1F7B, -7086), (0x1F7D, 0x1F7D, -7087), (0x1FBB, 0x1FBB, -7183),
+    (0x1FBE, 0x1FBE, -7173), (0x1FC9, 0x1FC9, -7196), (0x1FCB, 0x1FCB, -7197),
+    (0x1FD3, 0x1FD3, -7235), (0x1FDB, 0x1FDB, -7212), (0x1FE3, 0x1FE3, -7219),
+    (0x1FEB, 0x1FEB, -7198), (0x1FEF, 0x1FEF, -8079), (0x1FF9, 0x1FF9, -7213),
+    (0x1FFB, 0x1FFB, -7213), (0x2000, 0x2000, -8160), (0x2001, 0x2001, -8161),
+    (0x2002, 0x2002, -8162), (0x2003, 0x2003, -8163), (0x2004, 0x2004, -8164),
+    (0x2005, 0x2005, -8165), (0x2006, 0x2006, -8166), (0x2007, 0x2007, -8167),
+    (0x2008, 0x2008, -8168), (0x2009, 0x2009, -8169), (0x200A, 0x200A, -8170), (0x2011, 0x2011, -1),
+    (0x2024, 0x2024, -8182), (0x202F, 0x202F, -8207), (0x205F, 0x205F, -8255),
+    (0x2070, 0x2070, -8256), (0x2071, 0x2071, -8200), (0x2074, 0x2079, -8256),
+    (0x207A, 0x207A, -8271), (0x207B, 0x207B, 407), (0x207C, 0x207C, -8255),
+    (0x207D, 0x207E, -8277), (0x207F, 0x207F, -8209), (0x2080, 0x2089, -8272),
+    (0x208A, 0x208A, -8287), (0x208B, 0x208B, 391), (0x208C, 0x208C, -8271),
+    (0x208D, 0x208E, -8293), (0x2090, 0x2090, -8239), (0x2091, 0x2091, -8236),
+    (0x2092, 0x2092, -8227), (0x2093, 0x2093, -8219), (0x2094, 0x2094, -7739),
+    (0x2095, 0x2095, -8237), (0x2096, 0x2099, -8235), (0x209A, 0x209A, -8234),
+    (0x209B, 0x209C, -8232), (0x2102, 0x2102, -8351), (0x2107, 0x2107, -7852),
+    (0x210A, 0x210B, -8355), (0x210C, 0x210C, -8356), (0x210D, 0x210D, -8357),
+    (0x210E, 0x210E, -8358), (0x210F, 0x210F, -8168), (0x2110, 0x2110, -8359),
+    (0x2111, 0x2111, -8360), (0x2112, 0x2112, -8358), (0x2113, 0x2113, -8359),
+    (0x2115, 0x2115, -8359), (0x2119, 0x211B, -8361), (0x211C, 0x211C, -8362),
+    (0x211D, 0x211D, -8363), (0x2124, 0x2124, -8362), (0x2128, 0x2128, -8366),
+    (0x212C, 0x212D, -8394), (0x212F, 0x212F, -8394), (0x2130, 0x2131, -8395),
+    (0x2133, 0x2133, -8390), (0x2134, 0x2134, -8389), (0x2135, 0x2138, -7013),
+    (0x2139, 0x2139, -8400), (0x213C, 0x213C, -7548), (0x213D, 0x213D, -7562),
+    (0x213E, 0x213E, -7563), (0x213F, 0x213F, -7551), (0x2140, 0x2140, 209),
+    (0x2145, 0x2145, -8417), (0x2146, 0x2147, -8418), (0x2148, 0x2149, -8415),
+    (0x2160, 0x2160, -8439), (0x2164, 0x2164, -8430), (0x2169, 0x2169, -8433),
+    (0x216C, 0x216C, -8448), (0x216D, 0x216E, -8458), (0x216F, 0x216F, -8450),
+    (0x2170, 0x2170, -8455), (0x2174, 0x2174, -8446), (0x2179, 0x2179, -8449),
+    (0x217C, 0x217C, -8464), (0x217D, 0x217E, -8474), (0x217F, 0x217F, -8466),
+    (0x2329, 0x232A, 3295), (0x2460, 0x2468, -9263), (0x24B6, 0x24CF, -9301),
+    (0x24D0, 0x24E9, -9327), (0x24EA, 0x24EA, -9402), (0x2C7C, 0x2C7C, -11282),
+    (0x2C7D, 0x2C7D, -11271), (0x2D6F, 0x2D6F, -14), (0x2E9F, 0x2E9F, 15662),
+    (0x2EF3, 0x2EF3, 28844), (0x2F00, 0x2F00, 7936), (0x2F01, 0x2F01, 7975), (0x2F02, 0x2F02, 7988),
+    (0x2F03, 0x2F03, 7996), (0x2F04, 0x2F04, 8021), (0x2F05, 0x2F05, 8064), (0x2F06, 0x2F06, 8070),
+    (0x2F07, 0x2F07, 8089), (0x2F08, 0x2F08, 8114), (0x2F09, 0x2F09, 8758), (0x2F0A, 0x2F0A, 8795),
+    (0x2F0B, 0x2F0B, 8800), (0x2F0C, 0x2F0C, 8822), (0x2F0D, 0x2F0D, 8841), (0x2F0E, 0x2F0E, 8861),
+    (0x2F0F, 0x2F0F, 8913), (0x2F10, 0x2F10, 8933), (0x2F11, 0x2F11, 8943), (0x2F12, 0x2F12, 9097),
+    (0x2F13, 0x2F13, 9190), (0x2F14, 0x2F14, 9217), (0x2F15, 0x2F15, 9221), (0x2F16, 0x2F16, 9250),
+    (0x2F17, 0x2F17, 9258), (0x2F18, 0x2F18, 9284), (0x2F19, 0x2F19, 9296), (0x2F1A, 0x2F1A, 9320),
+    (0x2F1B, 0x2F1B, 9371), (0x2F1C, 0x2F1C, 9388), (0x2F1D, 0x2F1D, 9414), (0x2F1E, 0x2F1E, 10169),
+    (0x2F1F, 0x2F1F, 10240), (0x2F20, 0x2F20, 10699), (0x2F21, 0x2F21, 10721),
+    (0x2F22, 0x2F22, 10728), (0x2F23, 0x2F23, 10738), (0x2F24, 0x2F24, 10755),
+    (0x2F25, 0x2F25, 10830), (0x2F26, 0x2F26, 11306), (0x2F27, 0x2F27, 11353),
+    (0x2F28, 0x2F28, 11472), (0x2F29, 0x2F29, 11494), (0x2F2A, 0x2F2A, 11512),
+    (0x2F2B, 0x2F2B, 11533), (0x2F2C, 0x2F2C, 11586), (0x2F2D, 0x2F2D, 11588),
+    (0x2F2E, 0x2F2E, 11949), (0x2F2F, 0x2F2F, 11958), (0x2F30, 0x2F30, 11969),
+    (0x2F31, 0x2F31, 11981), (0x2F32, 0x2F32, 12096), (0x2F33, 0x2F33, 12103),
+    (0x2F34, 0x2F34, 12107), (0x2F35, 0x2F35, 12223), (0x2F36, 0x2F36, 12232),
+    (0x2F37, 0x2F37, 12244), (0x2F38, 0x2F38, 12251), (0x2F39, 0x2F39, 12311),
+    (0x2F3A, 0x2F3A, 12327), (0x2F3B, 0x2F3B, 12344), (0x2F3C, 0x2F3C, 12423),
+    (0x2F3D, 0x2F3D, 13003), (0x2F3E, 0x2F3E, 13048), (0x2F3F, 0x2F3F, 13068),
+    (0x2F40, 0x2F40, 13807), (0x2F41, 0x2F41, 13811), (0x2F42, 0x2F42, 13893),
+    (0x2F43, 0x2F43, 13908), (0x2F44, 0x2F44, 13920), (0x2F45, 0x2F45, 13940),
+    (0x2F46, 0x2F46, 13978), (0x2F47, 0x2F47, 13982), (0x2F48, 0x2F48, 14248),
+    (0x2F49, 0x2F49, 14271), (0x2F4A, 0x2F4A, 14302), (0x2F4B, 0x2F4B, 15317),
+    (0x2F4C, 0x2F4C, 15382), (0x2F4D, 0x2F4D, 15404), (0x2F4E, 0x2F4E, 15461),
+    (0x2F4F, 0x2F4F, 15484), (0x2F50, 0x2F50, 15492), (0x2F51, 0x2F51, 15498),
+    (0x2F52, 0x2F52, 15549), (0x2F53, 0x2F53, 15553), (0x2F54, 0x2F54, 15584),
+    (0x2F55, 0x2F55, 16662), (0x2F56, 0x2F56, 17108), (0x2F57, 0x2F57, 17119),
+    (0x2F58, 0x2F58, 17123), (0x2F59, 0x2F59, 17126), (0x2F5A, 0x2F5A, 17133),
+    (0x2F5B, 0x2F5B, 17150), (0x2F5C, 0x2F5C, 17151), (0x2F5D, 0x2F5D, 17231),
+    (0x2F5E, 0x2F5E, 17446), (0x2F5F, 0x2F5F, 17450), (0x2F60, 0x2F60, 17788),
+    (0x2F61, 0x2F61, 17797), (0x2F62, 0x2F62, 17846), (0x2F63, 0x2F63, 17852),
+    (0x2F64, 0x2F64, 17860), (0x2F65, 0x2F65, 17867), (0x2F66, 0x2F66, 17957),
+    (0x2F67, 0x2F67, 17963), (0x2F68, 0x2F68, 18190), (0x2F69, 0x2F69, 18196),
+    (0x2F6A, 0x2F6A, 18244), (0x2F6B, 0x2F6B, 18260), (0x2F6C, 0x2F6C, 18306),
+    (0x2F6D, 0x2F6D, 18542), (0x2F6E, 0x2F6E, 18548), (0x2F6F, 0x2F6F, 18564),
+    (0x2F70, 0x2F70, 18890), (0x2F71, 0x2F71, 19015), (0x2F72, 0x2F72, 19020),
+    (0x2F73, 0x2F73, 19201), (0x2F74, 0x2F74, 19287), (0x2F75, 0x2F75, 19332),
+    (0x2F76, 0x2F76, 19709), (0x2F77, 0x2F77, 19841), (0x2F78, 0x2F78, 20414),
+    (0x2F79, 0x2F79, 20440), (0x2F7A, 0x2F7A, 20496), (0x2F7B, 0x2F7B, 20546),
+    (0x2F7C, 0x2F7C, 20613), (0x2F7D, 0x2F7D, 20623), (0x2F7E, 0x2F7E, 20628),
+    (0x2F7F, 0x2F7F, 20660), (0x2F80, 0x2F80, 20735), (0x2F81, 0x2F81, 20744),
+    (0x2F82, 0x2F82, 21089), (0x2F83, 0x2F83, 21095), (0x2F84, 0x2F84, 21103),
+    (0x2F85, 0x2F85, 21111), (0x2F86, 0x2F86, 21126), (0x2F87, 0x2F87, 21140),
+    (0x2F88, 0x2F88, 21143), (0x2F89, 0x2F89, 21221), (0x2F8A, 0x2F8A, 21224),
+    (0x2F8B, 0x2F8B, 21229), (0x2F8C, 0x2F8C, 22209), (0x2F8D, 0x2F8D, 22238),
+    (0x2F8E, 0x2F8E, 22706), (0x2F8F, 0x2F8F, 22717), (0x2F90, 0x2F90, 22739),
+    (0x2F91, 0x2F91, 23021), (0x2F92, 0x2F92, 23033), (0x2F93, 0x2F93, 23103),
+    (0x2F94, 0x2F94, 23148), (0x2F95, 0x2F95, 23714), (0x2F96, 0x2F96, 23728),
+    (0x2F97, 0x2F97, 23742), (0x2F98, 0x2F98, 23776), (0x2F99, 0x2F99, 23812),
+    (0x2F9A, 0x2F9A, 24010), (0x2F9B, 0x2F9B, 24021), (0x2F9C, 0x2F9C, 24087),
+    (0x2F9D, 0x2F9D, 24334), (0x2F9E, 0x2F9E, 24364), (0x2F9F, 0x2F9F, 24572),
+    (0x2FA0, 0x2FA0, 24592), (0x2FA1, 0x2FA1, 24596), (0x2FA2, 0x2FA2, 24815),
+    (0x2FA3, 0x2FA3, 24998), (0x2FA4, 0x2FA4, 25122), (0x2FA5, 0x2FA5, 25127),
+    (0x2FA6, 0x2FA6, 25131), (0x2FA7, 0x2FA7, 26064), (0x2FA8, 0x2FA8, 26072),
+    (0x2FA9, 0x2FA9, 26227), (0x2FAA, 0x2FAA, 26380), (0x2FAB, 0x2FAB, 26382),
+    (0x2FAC, 0x2FAC, 26428), (0x2FAD, 0x2FAD, 26532), (0x2FAE, 0x2FAE, 26544),
+    (0x2FAF, 0x2FAF, 26547), (0x2FB0, 0x2FB0, 26553), (0x2FB1, 0x2FB1, 26650),
+    (0x2FB2, 0x2FB2, 26683), (0x2FB3, 0x2FB3, 26688), (0x2FB4, 0x2FB4, 26701),
+    (0x2FB5, 0x2FB5, 26867), (0x2FB6, 0x2FB6, 26917), (0x2FB7, 0x2FB7, 26920),
+    (0x2FB8, 0x2FB8, 27102), (0x2FB9, 0x2FB9, 27104), (0x2FBA, 0x2FBA, 27122),
+    (0x2FBB, 0x2FBB, 27373), (0x2FBC, 0x2FBC, 27420), (0x2FBD, 0x2FBD, 27426),
+    (0x2FBE, 0x2FBE, 27495), (0x2FBF, 0x2FBF, 27504), (0x2FC0, 0x2FC0, 27506),
+    (0x2FC1, 0x2FC1, 27515), (0x2FC2, 0x2FC2, 27544), (0x2FC3, 0x2FC3, 27938),
+    (0x2FC4, 0x2FC4, 28337), (0x2FC5, 0x2FC5, 28346), (0x2FC6, 0x2FC6, 28383),
+    (0x2FC7, 0x2FC7, 28404), (0x2FC8, 0x2FC8, 28411), (0x2FC9, 0x2FC9, 28420),
+    (0x2FCA, 0x2FCA, 28423), (0x2FCB, 0x2FCB, 28462), (0x2FCC, 0x2FCC, 28465),
+    (0x2FCD, 0x2FCD, 28481), (0x2FCE, 0x2FCE, 28485), (0x2FCF, 0x2FCF, 28497),
+    (0x2FD0, 0x2FD0, 28523), (0x2FD1, 0x2FD1, 28537), (0x2FD2, 0x2FD2, 28544),
+    (0x2FD3, 0x2FD3, 28602), (0x2FD4, 0x2FD4, 28616), (0x2FD5, 0x2FD5, 28619),
+    (0x3000, 0x3000, -12256), (0x3036, 0x3036, -36), (0x3038, 0x3038, 8969), (0x3039, 0x303A, 8971),
+    (0x3131, 0x3132, -8241), (0x3133, 0x3133, -8073), (0x3134, 0x3134, -8242),
+    (0x3135, 0x3136, -8073), (0x3137, 0x3139, -8244), (0x313A, 0x313F, -8074),
+    (0x3140, 0x3140, -8230), (0x3141, 0x3143, -8251), (0x3144, 0x3144, -8227),
+    (0x3145, 0x314E, -8252), (0x314F, 0x3163, -8174), (0x3164, 0x3164, -8196),
+    (0x3165, 0x3166, -8273), (0x3167, 0x3168, -8096), (0x3169, 0x3169, -8093),
+    (0x316A, 0x316A, -8092), (0x316B, 0x316B, -8088), (0x316C, 0x316C, -8085),
+    (0x316D, 0x316D, -8084), (0x316E, 0x316E, -8274), (0x316F, 0x316F, -8082),
+    (0x3170, 0x3170, -8081), (0x3171, 0x3172, -8276), (0x3173, 0x3173, -8275),
+    (0x3174, 0x3175, -8274), (0x3176, 0x3176, -8271), (0x3177, 0x3177, -8270),
+    (0x3178, 0x317C, -8269), (0x317D, 0x317D, -8267), (0x317E, 0x317E, -8264),
+    (0x317F, 0x317F, -8255), (0x3180, 0x3180, -8249), (0x3181, 0x3181, -8245),
+    (0x3182, 0x3183, -8081), (0x3184, 0x3186, -8237), (0x3187, 0x3188, -8195),
+    (0x3189, 0x3189, -8193), (0x318A, 0x318B, -8185), (0x318C, 0x318C, -8184),
+    (0x318D, 0x318D, -8175), (0x318E, 0x318E, -8173), (0x3192, 0x3192, 7278),
+    (0x3193, 0x3193, 7417), (0x3194, 0x3194, 7285), (0x3195, 0x3195, 9542), (0x3196, 0x3196, 7284),
+    (0x3197, 0x3197, 7318), (0x3198, 0x3198, 7283), (0x3199, 0x3199, 17305), (0x319A, 0x319A, 7359),
+    (0x319B, 0x319B, 7294), (0x319C, 0x319C, 7269), (0x319D, 0x319D, 10124), (0x319E, 0x319E, 9618),
+    (0x319F, 0x319F, 7451), (0x3244, 0x3244, 8971), (0x3245, 0x3245, 11319),
+    (0x3246, 0x3246, 13121), (0x3247, 0x3247, 18760), (0x3260, 0x3260, -8544),
+    (0x3261, 0x3262, -8543), (0x3263, 0x3265, -8542), (0x3266, 0x3266, -8541),
+    (0x3267, 0x3268, -8540), (0x3269, 0x326D, -8539), (0x326E, 0x326E, 31122),
+    (0x326F, 0x326F, 32297), (0x3270, 0x3270, 32884), (0x3271, 0x3271, 34059),
+    (0x3272, 0x3272, 34646), (0x3273, 0x3273, 35233), (0x3274, 0x3274, 36408),
+    (0x3275, 0x3275, 37583), (0x3276, 0x3276, 38170), (0x3277, 0x3277, 39345),
+    (0x3278, 0x3278, 39932), (0x3279, 0x3279, 40519), (0x327A, 0x327A, 41106),
+    (0x327B, 0x327B, 41693), (0x327E, 0x327E, 37938), (0x3280, 0x3280, 7040),
+    (0x3281, 0x3281, 7179), (0x3282, 0x3282, 7047), (0x3283, 0x3283, 9304), (0x3284, 0x3284, 7184),
+    (0x3285, 0x3285, 7912), (0x3286, 0x3286, 7037), (0x3287, 0x3287, 7908), (0x3288, 0x3288, 7125),
+    (0x3289, 0x3289, 8376), (0x328A, 0x328A, 13438), (0x328B, 0x328B, 15840),
+    (0x328C, 0x328C, 14760), (0x328D, 0x328D, 13467), (0x328E, 0x328E, 24387),
+    (0x328F, 0x328F, 9360), (0x3290, 0x3290, 13141), (0x3291, 0x3291, 13721),
+    (0x3292, 0x3292, 13431), (0x3293, 0x3293, 18091), (0x3294, 0x3294, 8569),
+    (0x3295, 0x3295, 16356), (0x3296, 0x3296, 23051), (0x3297, 0x3297, 18118),
+    (0x3298, 0x3298, 8220), (0x3299, 0x3299, 18239), (0x329A, 0x329A, 17053),
+    (0x329B, 0x329B, 9944), (0x329C, 0x329C, 24013), (0x329D, 0x329D, 7821), (0x329E, 0x329E, 8402),
+    (0x329F, 0x329F, 14921), (0x32A0, 0x32A0, 25957), (0x32A1, 0x32A1, 7280),
+    (0x32A2, 0x32A2, 7927), (0x32A3, 0x32A3, 14528), (0x32A4, 0x32A4, 7014), (0x32A5, 0x32A5, 7048),
+    (0x32A6, 0x32A6, 7013), (0x32A7, 0x32A7, 11071), (0x32A8, 0x32A8, 8523), (0x32A9, 0x32A9, 8338),
+    (0x32AA, 0x32AA, 10477), (0x32AB, 0x32AB, 10427), (0x32AC, 0x32AC, 17463),
+    (0x32AD, 0x32AD, 7252), (0x32AE, 0x32AE, 23065), (0x32AF, 0x32AF, 8357), (0x32B0, 0x32B0, 9836),
+    (0x32D0, 0x32D0, -558), (0x32D1, 0x32D1, -557), (0x32D2, 0x32D2, -556), (0x32D3, 0x32D3, -555),
+    (0x32D4, 0x32D5, -554), (0x32D6, 0x32D6, -553), (0x32D7, 0x32D7, -552), (0x32D8, 0x32D8, -551),
+    (0x32D9, 0x32D9, -550), (0x32DA, 0x32DA, -549), (0x32DB, 0x32DB, -548), (0x32DC, 0x32DC, -547),
+    (0x32DD, 0x32DD, -546), (0x32DE, 0x32DE, -545), (0x32DF, 0x32DF, -544), (0x32E0, 0x32E0, -543),
+    (0x32E1, 0x32E1, -541), (0x32E2, 0x32E2, -540), (0x32E3, 0x32E3, -539), (0x32E4, 0x32E9, -538),
+    (0x32EA, 0x32EA, -536), (0x32EB, 0x32EB, -534), (0x32EC, 0x32EC, -532), (0x32ED, 0x32ED, -530),
+    (0x32EE, 0x32F2, -528), (0x32F3, 0x32F3, -527), (0x32F4, 0x32F4, -526), (0x32F5, 0x32FA, -525),
+    (0x32FB, 0x32FE, -524), (0xA69C, 0xA69C, -41554), (0xA69D, 0xA69D, -41553),
+    (0xA770, 0xA770, -1), (0xA7F2, 0xA7F2, -42895), (0xA7F3, 0xA7F3, -42893),
+    (0xA7F4, 0xA7F4, -42883), (0xA7F8, 0xA7F8, -42705), (0xA7F9, 0xA7F9, -42662),
+    (0xAB5C, 0xAB5C, -1077), (0xAB5D, 0xAB5D, -38), (0xAB5E, 0xAB5E, -43251), (0xAB5F, 0xAB5F, -13),
+    (0xAB69, 0xAB69, -43228), (0xAB70, 0xABBF, -38864), (0xF900, 0xF900, -27832),
+    (0xF901, 0xF901, -37389), (0xF902, 0xF902, -27192), (0xF903, 0xF903, -27707),
+    (0xF904, 0xF904, -35379), (0xF905, 0xF905, -43731), (0xF906, 0xF906, -42273),
+    (0xF907, 0xF907, -22891), (0xF908, 0xF908, -22892), (0xF909, 0xF909, -40888),
+    (0xF90A, 0xF90A, -26425), (0xF90B, 0xF90B, -41860), (0xF90C, 0xF90C, -40900),
+    (0xF90D, 0xF90D, -38679), (0xF90E, 0xF90E, -33445), (0xF90F, 0xF90F, -31114),
+    (0xF910, 0xF910, -29393), (0xF911, 0xF911, -29015), (0xF912, 0xF912, -28698),
+    (0xF913, 0xF913, -26756), (0xF914, 0xF914, -36626), (0xF915, 0xF915, -35834),
+    (0xF916, 0xF916, -34877), (0xF917, 0xF917, -34105), (0xF918, 0xF918, -29915),
+    (0xF919, 0xF919, -26543), (0xF91A, 0xF91A, -24361), (0xF91B, 0xF91B, -43673),
+    (0xF91C, 0xF91C, -42407), (0xF91D, 0xF91D, -36377), (0xF91E, 0xF91E, -34563),
+    (0xF91F, 0xF91F, -29426), (0xF920, 0xF920, -23298), (0xF921, 0xF921, -39889),
+    (0xF922, 0xF922, -35127), (0xF923, 0xF923, -29526), (0xF924, 0xF924, -28608),
+    (0xF925, 0xF925, -38492), (0xF926, 0xF926, -30542), (0xF927, 0xF927, -28936),
+    (0xF928, 0xF928, -39518), (0xF929, 0xF929, -37394), (0xF92A, 0xF92A, -35776),
+    (0xF92B, 0xF92B, -34351), (0xF92C, 0xF92C, -26718), (0xF92D, 0xF92D, -43431),
+    (0xF92E, 0xF92E, -42871), (0xF92F, 0xF92F, -42577), (0xF930, 0xF930, -37996),
+    (0xF931, 0xF931, -36446), (0xF932, 0xF932, -34594), (0xF933, 0xF933, -33356),
+    (0xF934, 0xF934, -31027), (0xF935, 0xF935, -29487), (0xF936, 0xF936, -29402),
+    (0xF937, 0xF937, -27464), (0xF938, 0xF938, -25094), (0xF939, 0xF939, -24010),
+    (0xF93A, 0xF93A, -23360), (0xF93B, 0xF93B, -32943), (0xF93C, 0xF93C, -32701),
+    (0xF93D, 0xF93D, -31645), (0xF93E, 0xF93E, -30069), (0xF93F, 0xF93F, -26171),
+    (0xF940, 0xF940, -23233), (0xF941, 0xF941, -28267), (0xF942, 0xF942, -41059),
+    (0xF943, 0xF943, -39487), (0xF944, 0xF944, -31972), (0xF945, 0xF945, -30919),
+    (0xF946, 0xF946, -34532), (0xF947, 0xF947, -32893), (0xF948, 0xF948, -27782),
+    (0xF949, 0xF949, -25170), (0xF94A, 0xF94A, -41074), (0xF94B, 0xF94B, -40169),
+    (0xF94C, 0xF94C, -36665), (0xF94D, 0xF94D, -35699), (0xF94E, 0xF94E, -35391),
+    (0xF94F, 0xF94F, -31776), (0xF950, 0xF950, -31513), (0xF951, 0xF951, -25350),
+    (0xF952, 0xF952, -42624), (0xF953, 0xF953, -30920), (0xF954, 0xF954, -42872),
+    (0xF955, 0xF955, -42889), (0xF956, 0xF956, -32570), (0xF957, 0xF957, -31641),
+    (0xF958, 0xF958, -30055), (0xF959, 0xF959, -25316), (0xF95A, 0xF95A, -28122),
+    (0xF95B, 0xF95B, -38540), (0xF95C, 0xF95C, -36698), (0xF95D, 0xF95D, -28255),
+    (0xF95E, 0xF95E, -43813), (0xF95F, 0xF95F, -40312), (0xF960, 0xF960, -39246),
+    (0xF961, 0xF961, -34266), (0xF962, 0xF962, -33778), (0xF963, 0xF963, -42572),
+    (0xF964, 0xF964, -32873), (0xF965, 0xF965, -43430), (0xF966, 0xF966, -39357),
+    (0xF967, 0xF967, -43866), (0xF968, 0xF968, -35996), (0xF969, 0xF969, -37873),
+    (0xF96A, 0xF96A, -31816), (0xF96B, 0xF96B, -42408), (0xF96C, 0xF96C, -41230),
+    (0xF96D, 0xF96D, -33388), (0xF96E, 0xF96E, -29989), (0xF96F, 0xF96F, -28357),
+    (0xF970, 0xF970, -36278), (0xF971, 0xF971, -27073), (0xF972, 0xF972, -36074),
+    (0xF973, 0xF973, -38517), (0xF974, 0xF974, -30351), (0xF975, 0xF975, -38357),
+    (0xF976, 0xF976, -33809), (0xF977, 0xF977, -43721), (0xF978, 0xF978, -43023),
+    (0xF979, 0xF979, -42928), (0xF97A, 0xF97A, -37113), (0xF97B, 0xF97B, -31892),
+    (0xF97C, 0xF97C, -30477), (0xF97D, 0xF97D, -28331), (0xF97E, 0xF97E, -26543),
+    (0xF97F, 0xF97F, -42634), (0xF980, 0xF980, -42302), (0xF981, 0xF981, -40974),
+    (0xF982, 0xF982, -39574), (0xF983, 0xF983, -37822), (0xF984, 0xF984, -35206),
+    (0xF985, 0xF985, -32859), (0xF986, 0xF986, -25561), (0xF987, 0xF987, -24349),
+    (0xF988, 0xF988, -23281), (0xF989, 0xF989, -23227), (0xF98A, 0xF98A, -42735),
+    (0xF98B, 0xF98B, -37573), (0xF98C, 0xF98C, -36373), (0xF98D, 0xF98D, -27179),
+    (0xF98E, 0xF98E, -39706), (0xF98F, 0xF98F, -38911), (0xF990, 0xF990, -38800),
+    (0xF991, 0xF991, -38135), (0xF992, 0xF992, -35439), (0xF993, 0xF993, -34890),
+    (0xF994, 0xF994, -34059), (0xF995, 0xF995, -32715), (0xF996, 0xF996, -31650),
+    (0xF997, 0xF997, -31016), (0xF998, 0xF998, -27250), (0xF999, 0xF999, -29867),
+    (0xF99A, 0xF99A, -26999), (0xF99B, 0xF99B, -26193), (0xF99C, 0xF99C, -42885),
+    (0xF99D, 0xF99D, -42746), (0xF99E, 0xF99E, -42209), (0xF99F, 0xF99F, -35031),
+    (0xF9A0, 0xF9A0, -28894), (0xF9A1, 0xF9A1, -28407), (0xF9A2, 0xF9A2, -39641),
+    (0xF9A3, 0xF9A3, -39342), (0xF9A4, 0xF9A4, -38441), (0xF9A5, 0xF9A5, -36343),
+    (0xF9A6, 0xF9A6, -32104), (0xF9A7, 0xF9A7, -34354), (0xF9A8, 0xF9A8, -43716),
+    (0xF9A9, 0xF9A9, -41648), (0xF9AA, 0xF9AA, -40387), (0xF9AB, 0xF9AB, -39921),
+    (0xF9AC, 0xF9AC, -39312), (0xF9AD, 0xF9AD, -34299), (0xF9AE, 0xF9AE, -34117),
+    (0xF9AF, 0xF9AF, -31253), (0xF9B0, 0xF9B0, -31082), (0xF9B1, 0xF9B1, -26493),
+    (0xF9B2, 0xF9B2, -25276), (0xF9B3, 0xF9B3, -25195), (0xF9B4, 0xF9B4, -24988),
+    (0xF9B5, 0xF9B5, -43562), (0xF9B6, 0xF9B6, -32776), (0xF9B7, 0xF9B7, -26627),
+    (0xF9B8, 0xF9B8, -25344), (0xF9B9, 0xF9B9, -39128), (0xF9BA, 0xF9BA, -43828),
+    (0xF9BB, 0xF9BB, -43233), (0xF9BC, 0xF9BC, -40398), (0xF9BD, 0xF9BD, -40318),
+    (0xF9BE, 0xF9BE, -37925), (0xF9BF, 0xF9BF, -36797), (0xF9C0, 0xF9C0, -34802),
+    (0xF9C1, 0xF9C1, -33663), (0xF9C2, 0xF9C2, -29894), (0xF9C3, 0xF9C3, -26951),
+    (0xF9C4, 0xF9C4, -23095), (0xF9C5, 0xF9C5, -37693), (0xF9C6, 0xF9C6, -25496),
+    (0xF9C7, 0xF9C7, -42814), (0xF9C8, 0xF9C8, -37453), (0xF9C9, 0xF9C9, -37334),
+    (0xF9CA, 0xF9CA, -35977), (0xF9CB, 0xF9CB, -35631), (0xF9CC, 0xF9CC, -34243),
+    (0xF9CD, 0xF9CD, -33908), (0xF9CE, 0xF9CE, -33123), (0xF9CF, 0xF9CF, -31935),
+    (0xF9D0, 0xF9D0, -24946), (0xF9D1, 0xF9D1, -43108), (0xF9D2, 0xF9D2, -38820),
+    (0xF9D3, 0xF9D3, -25435), (0xF9D4, 0xF9D4, -43433), (0xF9D5, 0xF9D5, -40124),
+    (0xF9D6, 0xF9D6, -35820), (0xF9D7, 0xF9D7, -27309), (0xF9D8, 0xF9D8, -39501),
+    (0xF9D9, 0xF9D9, -39061), (0xF9DA, 0xF9DA, -37315), (0xF9DB, 0xF9DB, -34388),
+    (0xF9DC, 0xF9DC, -25430), (0xF9DD, 0xF9DD, -42932), (0xF9DE, 0xF9DE, -42447),
+    (0xF9DF, 0xF9DF, -40314), (0xF9E0, 0xF9E0, -37837), (0xF9E1, 0xF9E1, -37523),
+    (0xF9E2, 0xF9E2, -37178), (0xF9E3, 0xF9E3, -36094), (0xF9E4, 0xF9E4, -34270),
+    (0xF9E5, 0xF9E5, -33795), (0xF9E6, 0xF9E6, -31341), (0xF9E7, 0xF9E7, -28952),
+    (0xF9E8, 0xF9E8, -28935), (0xF9E9, 0xF9E9, -26653), (0xF9EA, 0xF9EA, -25352),
+    (0xF9EB, 0xF9EB, -42668), (0xF9EC, 0xF9EC, -35634), (0xF9ED, 0xF9ED, -42448),
+    (0xF9EE, 0xF9EE, -34846), (0xF9EF, 0xF9EF, -34135), (0xF9F0, 0xF9F0, -29686),
+    (0xF9F1, 0xF9F1, -25422), (0xF9F2, 0xF9F2, -23963), (0xF9F3, 0xF9F3, -23380),
+    (0xF9F4, 0xF9F4, -37469), (0xF9F5, 0xF9F5, -35882), (0xF9F6, 0xF9F6, -30734),
+    (0xF9F7, 0xF9F7, -32556), (0xF9F8, 0xF9F8, -32472), (0xF9F9, 0xF9F9, -32103),
+    (0xF9FA, 0xF9FA, -34618), (0xF9FB, 0xF9FB, -35170), (0xF9FC, 0xF9FC, -28324),
+    (0xF9FD, 0xF9FD, -43837), (0xF9FE, 0xF9FE, -30408), (0xF9FF, 0xF9FF, -42949),
+    (0xFA00, 0xFA00, -43001), (0xFA01, 0xFA01, -39771), (0xFA02, 0xFA02, -38703),
+    (0xFA03, 0xFA03, -32045), (0xFA04, 0xFA04, -40575), (0xFA05, 0xFA05, -36071),
+    (0xFA06, 0xFA06, -37714), (0xFA07, 0xFA07, -27340), (0xFA08, 0xFA08, -29116),
+    (0xFA09, 0xFA09, -25532), (0xFA0A, 0xFA0A, -28799), (0xFA0B, 0xFA0B, -39736),
+    (0xFA0C, 0xFA0C, -43212), (0xFA0D, 0xFA0D, -42061), (0xFA10, 0xFA10, -41398),
+    (0xFA12, 0xFA12, -37790), (0xFA15, 0xFA15, -43063), (0xFA16, 0xFA16, -34540),
+    (0xFA17, 0xFA17, -33613), (0xFA18, 0xFA18, -32988), (0xFA19, 0xFA19, -32955),
+    (0xFA1A, 0xFA1A, -32949), (0xFA1B, 0xFA1B, -32908), (0xFA1C, 0xFA1C, -25286),
+    (0xFA1D, 0xFA1D, -32095), (0xFA1E, 0xFA1E, -31329), (0xFA20, 0xFA20, -29710),
+    (0xFA22, 0xFA22, -28458), (0xFA25, 0xFA25, -27117), (0xFA26, 0xFA26, -26921),
+    (0xFA2A, 0xFA2A, -24891), (0xFA2B, 0xFA2B, -24879), (0xFA2C, 0xFA2C, -24836),
+    (0xFA2D, 0xFA2D, -23673), (0xFA2E, 0xFA2E, -26960), (0xFA2F, 0xFA2F, -25464),
+    (0xFA30, 0xFA30, -43650), (0xFA31, 0xFA31, -43338), (0xFA32, 0xFA32, -43237),
+    (0xFA33, 0xFA33, -42858), (0xFA34, 0xFA34, -42832), (0xFA35, 0xFA35, -42724),
+    (0xFA36, 0xFA36, -42137), (0xFA37, 0xFA37, -42033), (0xFA38, 0xFA38, -41936),
+    (0xFA39, 0xFA39, -41465), (0xFA3A, 0xFA3A, -41362), (0xFA3B, 0xFA3B, -40407),
+    (0xFA3C, 0xFA3C, -40398), (0xFA3D, 0xFA3D, -39337), (0xFA3E, 0xFA3E, -39126),
+    (0xFA3F, 0xFA3F, -39089), (0xFA40, 0xFA40, -38990), (0xFA41, 0xFA41, -38130),
+    (0xFA42, 0xFA42, -37984), (0xFA43, 0xFA43, -37810), (0xFA44, 0xFA44, -37311),
+    (0xFA45, 0xFA45, -36046), (0xFA46, 0xFA46, -35884), (0xFA47, 0xFA47, -35621),
+    (0xFA48, 0xFA48, -35034), (0xFA49, 0xFA49, -34846), (0xFA4A, 0xFA4A, -34344),
+    (0xFA4B, 0xFA4B, -33210), (0xFA4C, 0xFA4C, -33038), (0xFA4D, 0xFA4D, -33028),
+    (0xFA4E, 0xFA4E, -33030), (0xFA4F, 0xFA4F, -33023), (0xFA50, 0xFA50, -33018),
+    (0xFA51, 0xFA51, -33012), (0xFA52, 0xFA53, -32965), (0xFA54, 0xFA54, -32788),
+    (0xFA55, 0xFA55, -32724), (0xFA56, 0xFA56, -32406), (0xFA57, 0xFA57, -31843),
+    (0xFA58, 0xFA58, -31823), (0xFA59, 0xFA59, -31768), (0xFA5A, 0xFA5A, -31464),
+    (0xFA5B, 0xFA5B, -31318), (0xFA5C, 0xFA5C, -30831), (0xFA5D, 0xFA5D, -30692),
+    (0xFA5E, 0xFA5E, -30693), (0xFA5F, 0xFA5F, -30216), (0xFA60, 0xFA60, -29008),
+    (0xFA61, 0xFA61, -28875), (0xFA62, 0xFA62, -28513), (0xFA63, 0xFA63, -28458),
+    (0xFA64, 0xFA64, -28049), (0xFA65, 0xFA65, -27997), (0xFA66, 0xFA66, -27312),
+    (0xFA67, 0xFA67, -27183), (0xFA68, 0xFA68, -25477), (0xFA69, 0xFA69, -25194),
+    (0xFA6A, 0xFA6A, -25135), (0xFA6B, 0xFA6B, -39414), (0xFA6C, 0xFA6C, 84098),
+    (0xFA6D, 0xFA6D, -30805), (0xFA70, 0xFA70, -44106), (0xFA71, 0xFA71, -43196),
+    (0xFA72, 0xFA72, -43274), (0xFA73, 0xFA73, -43763), (0xFA74, 0xFA74, -43311),
+    (0xFA75, 0xFA75, -43253), (0xFA76, 0xFA76, -42927), (0xFA77, 0xFA77, -42877),
+    (0xFA78, 0xFA78, -42203), (0xFA79, 0xFA79, -42276), (0xFA7A, 0xFA7A, -42209),
+    (0xFA7B, 0xFA7B, -42137), (0xFA7C, 0xFA7C, -41506), (0xFA7D, 0xFA7D, -41418),
+    (0xFA7E, 0xFA7E, -41274), (0xFA7F, 0xFA7F, -41259), (0xFA80, 0xFA80, -40990),
+    (0xFA81, 0xFA81, -40793), (0xFA82, 0xFA82, -39856), (0xFA83, 0xFA83, -39850),
+    (0xFA84, 0xFA84, -39707), (0xFA85, 0xFA85, -39640), (0xFA86, 0xFA86, -39342),
+    (0xFA87, 0xFA87, -39225), (0xFA88, 0xFA88, -39296), (0xFA89, 0xFA89, -39163),
+    (0xFA8A, 0xFA8A, -39210), (0xFA8B, 0xFA8B, -39065), (0xFA8C, 0xFA8C, -39000),
+    (0xFA8D, 0xFA8D, -38601), (0xFA8E, 0xFA8E, -38514), (0xFA8F, 0xFA8F, -38461),
+    (0xFA90, 0xFA90, -38202), (0xFA91, 0xFA91, -37917), (0xFA92, 0xFA92, -37755),
+    (0xFA93, 0xFA93, -37752), (0xFA94, 0xFA94, -37694), (0xFA95, 0xFA95, -36636),
+    (0xFA96, 0xFA96, -36572), (0xFA97, 0xFA97, -36182), (0xFA98, 0xFA98, -35773),
+    (0xFA99, 0xFA99, -35790), (0xFA9A, 0xFA9A, -35704), (0xFA9B, 0xFA9B, -35453),
+    (0xFA9C, 0xFA9C, -35118), (0xFA9D, 0xFA9D, -33526), (0xFA9E, 0xFA9E, -34921),
+    (0xFA9F, 0xFA9F, -34800), (0xFAA0, 0xFAA0, -34678), (0xFAA1, 0xFAA1, -34352),
+    (0xFAA2, 0xFAA2, -34204), (0xFAA3, 0xFAA3, -34152), (0xFAA4, 0xFAA4, -33927),
+    (0xFAA5, 0xFAA5, -33926), (0xFAA6, 0xFAA6, -33756), (0xFAA7, 0xFAA7, -33740),
+    (0xFAA8, 0xFAA8, -33716), (0xFAA9, 0xFAA9, -33631), (0xFAAA, 0xFAAA, -33642),
+    (0xFAAB, 0xFAAB, -33247), (0xFAAC, 0xFAAC, -32763), (0xFAAD, 0xFAAD, -32493),
+    (0xFAAE, 0xFAAE, -32307), (0xFAAF, 0xFAAF, -32084), (0xFAB0, 0xFAB0, -31932),
+    (0xFAB1, 0xFAB1, -31603), (0xFAB2, 0xFAB2, -31405), (0xFAB3, 0xFAB3, -30561),
+    (0xFAB4, 0xFAB4, -30405), (0xFAB5, 0xFAB5, -29500), (0xFAB6, 0xFAB6, -29045),
+    (0xFAB7, 0xFAB7, -28977), (0xFAB8, 0xFAB8, -28962), (0xFAB9, 0xFAB9, -28666),
+    (0xFABA, 0xFABA, -28610), (0xFABB, 0xFABB, -28656), (0xFABC, 0xFABC, -28603),
+    (0xFABD, 0xFABD, -28607), (0xFABE, 0xFABE, -28625), (0xFABF, 0xFABF, -28550),
+    (0xFAC0, 0xFAC0, -28470), (0xFAC1, 0xFAC1, -28089), (0xFAC2, 0xFAC2, -27530),
+    (0xFAC3, 0xFAC3, -27217), (0xFAC4, 0xFAC4, -26923), (0xFAC5, 0xFAC5, -26703),
+    (0xFAC6, 0xFAC6, -25674), (0xFAC7, 0xFAC7, -25572), (0xFAC8, 0xFAC8, -25458),
+    (0xFAC9, 0xFAC9, -25326), (0xFACA, 0xFACA, -25291), (0xFACB, 0xFACB, -25280),
+    (0xFACC, 0xFACC, -25233), (0xFACD, 0xFACD, -24507), (0xFACE, 0xFACE, -23346),
+    (0xFACF, 0xFACF, 77179), (0xFAD0, 0xFAD0, 77172), (0xFAD1, 0xFAD1, 80132),
+    (0xFAD2, 0xFAD2, -48949), (0xFAD3, 0xFAD3, -47803), (0xFAD4, 0xFAD4, -47771),
+    (0xFAD5, 0xFAD5, 87924), (0xFAD6, 0xFAD6, 90618), (0xFAD7, 0xFAD7, 99324),
+    (0xFAD8, 0xFAD8, -23445), (0xFAD9, 0xFAD9, -23371), (0xFB20, 0xFB20, -62782),
+    (0xFB21, 0xFB21, -62801), (0xFB22, 0xFB23, -62799), (0xFB24, 0xFB26, -62793),
+    (0xFB27, 0xFB27, -62783), (0xFB28, 0xFB28, -62782), (0xFB29, 0xFB29, -64254),
+    (0xFB50, 0xFB50, -62687), (0xFB51, 0xFB51, -62688), (0xFB52, 0xFB52, -62679),
+    (0xFB53, 0xFB53, -62680), (0xFB54, 0xFB54, -62681), (0xFB55, 0xFB55, -62682),
+    (0xFB56, 0xFB56, -62680), (0xFB57, 0xFB57, -62681), (0xFB58, 0xFB58, -62682),
+    (0xFB59, 0xFB59, -62683), (0xFB5A, 0xFB5A, -62682), (0xFB5B, 0xFB5B, -62683),
+    (0xFB5C, 0xFB5C, -62684), (0xFB5D, 0xFB5D, -62685), (0xFB5E, 0xFB5E, -62692),
+    (0xFB5F, 0xFB5F, -62693), (0xFB60, 0xFB60, -62694), (0xFB61, 0xFB61, -62695),
+    (0xFB62, 0xFB62, -62691), (0xFB63, 0xFB63, -62692), (0xFB64, 0xFB64, -62693),
+    (0xFB65, 0xFB65, -62694), (0xFB66, 0xFB66, -62701), (0xFB67, 0xFB67, -62702),
+    (0xFB68, 0xFB68, -62703), (0xFB69, 0xFB69, -62704), (0xFB6A, 0xFB6A, -62662),
+    (0xFB6B, 0xFB6B, -62663), (0xFB6C, 0xFB6C, -62664), (0xFB6D, 0xFB6D, -62665),
+    (0xFB6E, 0xFB6E, -62664), (0xFB6F, 0xFB6F, -62665), (0xFB70, 0xFB70, -62666),
+    (0xFB71, 0xFB71, -62667), (0xFB72, 0xFB72, -62702), (0xFB73, 0xFB73, -62703),
+    (0xFB74, 0xFB74, -62704), (0xFB75, 0xFB75, -62705), (0xFB76, 0xFB76, -62707),
+    (0xFB77, 0xFB77, -62708), (0xFB78, 0xFB78, -62709), (0xFB79, 0xFB79, -62710),
+    (0xFB7A, 0xFB7A, -62708), (0xFB7B, 0xFB7B, -62709), (0xFB7C, 0xFB7C, -62710),
+    (0xFB7D, 0xFB7E, -62711), (0xFB7F, 0xFB7F, -62712), (0xFB80, 0xFB80, -62713),
+    (0xFB81, 0xFB81, -62714), (0xFB82, 0xFB82, -62709), (0xFB83, 0xFB83, -62710),
+    (0xFB84, 0xFB84, -62712), (0xFB85, 0xFB85, -62713), (0xFB86, 0xFB86, -62712),
+    (0xFB87, 0xFB87, -62713), (0xFB88, 0xFB88, -62720), (0xFB89, 0xFB89, -62721),
+    (0xFB8A, 0xFB8A, -62706), (0xFB8B, 0xFB8B, -62707), (0xFB8C, 0xFB8C, -62715),
+    (0xFB8D, 0xFB8D, -62716), (0xFB8E, 0xFB8E, -62693), (0xFB8F, 0xFB8F, -62694),
+    (0xFB90, 0xFB90, -62695), (0xFB91, 0xFB91, -62696), (0xFB92, 0xFB92, -62691),
+    (0xFB93, 0xFB93, -62692), (0xFB94, 0xFB94, -62693), (0xFB95, 0xFB95, -62694),
+    (0xFB96, 0xFB96, -62691), (0xFB97, 0xFB97, -62692), (0xFB98, 0xFB98, -62693),
+    (0xFB99, 0xFB99, -62694), (0xFB9A, 0xFB9A, -62697), (0xFB9B, 0xFB9B, -62698),
+    (0xFB9C, 0xFB9C, -62699), (0xFB9D, 0xFB9D, -62700), (0xFB9E, 0xFB9E, -62692),
+    (0xFB9F, 0xFBA0, -62693), (0xFBA1, 0xFBA1, -62694), (0xFBA2, 0xFBA2, -62695),
+    (0xFBA3, 0xFBA3, -62696), (0xFBA4, 0xFBA4, -62692), (0xFBA5, 0xFBA6, -62693),
+    (0xFBA7, 0xFBA7, -62694), (0xFBA8, 0xFBA8, -62695), (0xFBA9, 0xFBA9, -62696),
+    (0xFBAA, 0xFBAA, -62700), (0xFBAB, 0xFBAB, -62701), (0xFBAC, 0xFBAC, -62702),
+    (0xFBAD, 0xFBAD, -62703), (0xFBAE, 0xFBAE, -62684), (0xFBAF, 0xFBB0, -62685),
+    (0xFBB1, 0xFBB1, -62686), (0xFBD3, 0xFBD3, -62758), (0xFBD4, 0xFBD4, -62759),
+    (0xFBD5, 0xFBD5, -62760), (0xFBD6, 0xFBD6, -62761), (0xFBD7, 0xFBD7, -62736),
+    (0xFBD8, 0xFBD8, -62737), (0xFBD9, 0xFBD9, -62739), (0xFBDA, 0xFBDA, -62740),
+    (0xFBDB, 0xFBDB, -62739), (0xFBDC, 0xFBDC, -62740), (0xFBDE, 0xFBDE, -62739),
+    (0xFBDF, 0xFBDF, -62740), (0xFBE0, 0xFBE0, -62747), (0xFBE1, 0xFBE1, -62748),
+    (0xFBE2, 0xFBE2, -62745), (0xFBE3, 0xFBE3, -62746), (0xFBE4, 0xFBE4, -62740),
+    (0xFBE5, 0xFBE5, -62741), (0xFBE6, 0xFBE6, -62742), (0xFBE7, 0xFBE7, -62743),
+    (0xFBE8, 0xFBE8, -62879), (0xFBE9, 0xFBE9, -62880), (0xFBFC, 0xFBFC, -62768),
+    (0xFBFD, 0xFBFD, -62769), (0xFBFE, 0xFBFE, -62770), (0xFBFF, 0xFBFF, -62771),
+    (0xFE10, 0xFE10, -64996), (0xFE11, 0xFE12, -52752), (0xFE13, 0xFE14, -64985),
+    (0xFE15, 0xFE15, -65012), (0xFE16, 0xFE16, -64983), (0xFE17, 0xFE18, -52737),
+    (0xFE31, 0xFE31, -56861), (0xFE32, 0xFE32, -56863), (0xFE33, 0xFE33, -64980),
+    (0xFE34, 0xFE34, -64981), (0xFE35, 0xFE36, -65037), (0xFE37, 0xFE37, -64956),
+    (0xFE38, 0xFE38, -64955), (0xFE39, 0xFE3A, -52773), (0xFE3B, 0xFE3C, -52779),
+    (0xFE3D, 0xFE3E, -52787), (0xFE3F, 0xFE40, -52791), (0xFE41, 0xFE44, -52789),
+    (0xFE47, 0xFE47, -65004), (0xFE48, 0xFE48, -65003), (0xFE4D, 0xFE4D, -65006),
+    (0xFE4E, 0xFE4E, -65007), (0xFE4F, 0xFE4F, -65008), (0xFE50, 0xFE50, -65060),
+    (0xFE51, 0xFE51, -52816), (0xFE52, 0xFE52, -65060), (0xFE54, 0xFE54, -65049),
+    (0xFE55, 0xFE55, -65051), (0xFE56, 0xFE56, -65047), (0xFE57, 0xFE57, -65078),
+    (0xFE58, 0xFE58, -56900), (0xFE59, 0xFE5A, -65073), (0xFE5B, 0xFE5B, -64992),
+    (0xFE5C, 0xFE5C, -64991), (0xFE5D, 0xFE5E, -52809), (0xFE5F, 0xFE5F, -65084),
+    (0xFE60, 0xFE60, -65082), (0xFE61, 0xFE62, -65079), (0xFE63, 0xFE63, -65078),
+    (0xFE64, 0xFE64, -65064), (0xFE65, 0xFE65, -65063), (0xFE66, 0xFE66, -65065),
+    (0xFE68, 0xFE68, -65036), (0xFE69, 0xFE6A, -65093), (0xFE6B, 0xFE6B, -65067),
+    (0xFE80, 0xFE81, -63583), (0xFE82, 0xFE83, -63584), (0xFE84, 0xFE85, -63585),
+    (0xFE86, 0xFE87, -63586), (0xFE88, 0xFE89, -63587), (0xFE8A, 0xFE8A, -63588),
+    (0xFE8B, 0xFE8B, -63589), (0xFE8C, 0xFE8D, -63590), (0xFE8E, 0xFE8F, -63591),
+    (0xFE90, 0xFE90, -63592), (0xFE91, 0xFE91, -63593), (0xFE92, 0xFE93, -63594),
+    (0xFE94, 0xFE95, -63595), (0xFE96, 0xFE96, -63596), (0xFE97, 0xFE97, -63597),
+    (0xFE98, 0xFE99, -63598), (0xFE9A, 0xFE9A, -63599), (0xFE9B, 0xFE9B, -63600),
+    (0xFE9C, 0xFE9D, -63601), (0xFE9E, 0xFE9E, -63602), (0xFE9F, 0xFE9F, -63603),
+    (0xFEA0, 0xFEA1, -63604), (0xFEA2, 0xFEA2, -63605), (0xFEA3, 0xFEA3, -63606),
+    (0xFEA4, 0xFEA5, -63607), (0xFEA6, 0xFEA6, -63608), (0xFEA7, 0xFEA7, -63609),
+    (0xFEA8, 0xFEA9, -63610), (0xFEAA, 0xFEAB, -63611), (0xFEAC, 0xFEAD, -63612),
+    (0xFEAE, 0xFEAF, -63613), (0xFEB0, 0xFEB1, -63614), (0xFEB2, 0xFEB2, -63615),
+    (0xFEB3, 0xFEB3, -63616), (0xFEB4, 0xFEB5, -63617), (0xFEB6, 0xFEB6, -63618),
+    (0xFEB7, 0xFEB7, -63619), (0xFEB8, 0xFEB9, -63620), (0xFEBA, 0xFEBA, -63621),
+    (0xFEBB, 0xFEBB, -63622), (0xFEBC, 0xFEBD, -63623), (0xFEBE, 0xFEBE, -63624),
+    (0xFEBF, 0xFEBF, -63625), (0xFEC0, 0xFEC1, -63626), (0xFEC2, 0xFEC2, -63627),
+    (0xFEC3, 0xFEC3, -63628), (0xFEC4, 0xFEC5, -63629), (0xFEC6, 0xFEC6, -63630),
+    (0xFEC7, 0xFEC7, -63631), (0xFEC8, 0xFEC9, -63632), (0xFECA, 0xFECA, -63633),
+    (0xFECB, 0xFECB, -63634), (0xFECC, 0xFECD, -63635), (0xFECE, 0xFECE, -63636),
+    (0xFECF, 0xFECF, -63637), (0xFED0, 0xFED0, -63638), (0xFED1, 0xFED1, -63632),
+    (0xFED2, 0xFED2, -63633), (0xFED3, 0xFED3, -63634), (0xFED4, 0xFED5, -63635),
+    (0xFED6, 0xFED6, -63636), (0xFED7, 0xFED7, -63637), (0xFED8, 0xFED9, -63638),
+    (0xFEDA, 0xFEDA, -63639), (0xFEDB, 0xFEDB, -63640), (0xFEDC, 0xFEDD, -63641),
+    (0xFEDE, 0xFEDE, -63642), (0xFEDF, 0xFEDF, -63643), (0xFEE0, 0xFEE1, -63644),
+    (0xFEE2, 0xFEE2, -63645), (0xFEE3, 0xFEE3, -63646), (0xFEE4, 0xFEE5, -63647),
+    (0xFEE6, 0xFEE6, -63648), (0xFEE7, 0xFEE7, -63649), (0xFEE8, 0xFEE9, -63650),
+    (0xFEEA, 0xFEEA, -63651), (0xFEEB, 0xFEEB, -63652), (0xFEEC, 0xFEED, -63653),
+    (0xFEEE, 0xFEEF, -63654), (0xFEF0, 0xFEF1, -63655), (0xFEF2, 0xFEF2, -63656),
+    (0xFEF3, 0xFEF3, -63657), (0xFEF4, 0xFEF4, -63658), (0xFF01, 0xFF0D, -65248),
+    (0xFF0F, 0xFF20, -65248), (0xFF21, 0xFF3A, -65216), (0xFF3B, 0xFF5E, -65248),
+    (0xFF5F, 0xFF60, -54746), (0xFF62, 0xFF63, -53078), (0xFF64, 0xFF64, -53091),
+    (0xFF65, 0xFF65, -52842), (0xFF66, 0xFF66, -52852), (0xFF67, 0xFF67, -52934),
+    (0xFF68, 0xFF68, -52933), (0xFF69, 0xFF69, -52932), (0xFF6A, 0xFF6A, -52931),
+    (0xFF6B, 0xFF6B, -52930), (0xFF6C, 0xFF6C, -52873), (0xFF6D, 0xFF6D, -52872),
+    (0xFF6E, 0xFF6E, -52871), (0xFF6F, 0xFF6F, -52908), (0xFF70, 0xFF70, -52852),
+    (0xFF71, 0xFF71, -52943), (0xFF72, 0xFF72, -52942), (0xFF73, 0xFF73, -52941),
+    (0xFF74, 0xFF74, -52940), (0xFF75, 0xFF76, -52939), (0xFF77, 0xFF77, -52938),
+    (0xFF78, 0xFF78, -52937), (0xFF79, 0xFF79, -52936), (0xFF7A, 0xFF7A, -52935),
+    (0xFF7B, 0xFF7B, -52934), (0xFF7C, 0xFF7C, -52933), (0xFF7D, 0xFF7D, -52932),
+    (0xFF7E, 0xFF7E, -52931), (0xFF7F, 0xFF7F, -52930), (0xFF80, 0xFF80, -52929),
+    (0xFF81, 0xFF81, -52928), (0xFF82, 0xFF82, -52926), (0xFF83, 0xFF83, -52925),
+    (0xFF84, 0xFF84, -52924), (0xFF85, 0xFF8A, -52923), (0xFF8B, 0xFF8B, -52921),
+    (0xFF8C, 0xFF8C, -52919), (0xFF8D, 0xFF8D, -52917), (0xFF8E, 0xFF8E, -52915),
+    (0xFF8F, 0xFF93, -52913), (0xFF94, 0xFF94, -52912), (0xFF95, 0xFF95, -52911),
+    (0xFF96, 0xFF9B, -52910), (0xFF9C, 0xFF9C, -52909), (0xFF9D, 0xFF9D, -52906),
+    (0xFF9E, 0xFF9F, -52997), (0xFFA0, 0xFFA0, -60992), (0xFFA1, 0xFFA2, -61089),
+    (0xFFA3, 0xFFA3, -60921), (0xFFA4, 0xFFA4, -61090), (0xFFA5, 0xFFA6, -60921),
+    (0xFFA7, 0xFFA9, -61092), (0xFFAA, 0xFFAF, -60922), (0xFFB0, 0xFFB0, -61078),
+    (0xFFB1, 0xFFB3, -61099), (0xFFB4, 0xFFB4, -61075), (0xFFB5, 0xFFBE, -61100),
+    (0xFFC2, 0xFFC7, -61025), (0xFFCA, 0xFFCF, -61027), (0xFFD2, 0xFFD7, -61029),
+    (0xFFDA, 0xFFDC, -61031), (0xFFE0, 0xFFE1, -65342), (0xFFE2, 0xFFE2, -65334),
+    (0xFFE4, 0xFFE4, -65342), (0xFFE5, 0xFFE5, -65344), (0xFFE6, 0xFFE6, -57149),
+    (0xFFE8, 0xFFE8, -56038), (0xFFE9, 0xFFEC, -56921), (0xFFED, 0xFFED, -55885),
+    (0xFFEE, 0xFFEE, -55843), (0x1_0781, 0x1_0782, -66737), (0x1_0783, 0x1_0783, -67229),
+    (0x1_0784, 0x1_0784, -66795), (0x1_0785, 0x1_0785, -66866), (0x1_0787, 0x1_0787, -66788),
+    (0x1_0788, 0x1_0788, -23586), (0x1_0789, 0x1_0789, -66788), (0x1_078A, 0x1_078A, -66790),
+    (0x1_078B, 0x1_078C, -66869), (0x1_078D, 0x1_078D, -59900), (0x1_078E, 0x1_078E, -66870),
+    (0x1_078F, 0x1_078F, -66865), (0x1_0790, 0x1_0790, -66791), (0x1_0791, 0x1_0791, -66861),
+    (0x1_0792, 0x1_0792, -66864), (0x1_0793, 0x1_0793, -66867), (0x1_0794, 0x1_0794, -66809),
+    (0x1_0795, 0x1_0795, -67182), (0x1_0796, 0x1_0796, -66810), (0x1_0797, 0x1_0797, -66864),
+    (0x1_0798, 0x1_0798, -66836), (0x1_0799, 0x1_079A, -66799), (0x1_079B, 0x1_079B, -66863),
+    (0x1_079C, 0x1_079C, 55144), (0x1_079D, 0x1_079D, -24591), (0x1_079E, 0x1_079E, -66864),
+    (0x1_079F, 0x1_079F, 55142), (0x1_07A0, 0x1_07A0, -66834), (0x1_07A1, 0x1_07A1, 55141),
+    (0x1_07A2, 0x1_07A2, -67242), (0x1_07A3, 0x1_07A4, -66861), (0x1_07A5, 0x1_07A5, -67380),
+    (0x1_07A6, 0x1_07A6, -66860), (0x1_07A7, 0x1_07A7, 55137), (0x1_07A8, 0x1_07A9, -66859),
+    (0x1_07AA, 0x1_07AA, -66858), (0x1_07AB, 0x1_07AB, -66819), (0x1_07AC, 0x1_07AC, -66822),
+    (0x1_07AD, 0x1_07AD, -23622), (0x1_07AE, 0x1_07AE, -66823), (0x1_07AF, 0x1_07AF, -66855),
+    (0x1_07B0, 0x1_07B0, -56127), (0x1_07B2, 0x1_07B2, -66851), (0x1_07B3, 0x1_07B4, -66834),
+    (0x1_07B5, 0x1_07B5, -66845), (0x1_07B6, 0x1_07B8, -67062), (0x1_07B9, 0x1_07B9, 55121),
+    (0x1_07BA, 0x1_07BA, 55140), (0x1_D400, 0x1_D419, -119_711), (0x1_D41A, 0x1_D433, -119_737),
+    (0x1_D434, 0x1_D44D, -119_763), (0x1_D44E, 0x1_D454, -119_789), (0x1_D456, 0x1_D467, -119_789),
+    (0x1_D468, 0x1_D481, -119_815), (0x1_D482, 0x1_D49B, -119_841), (0x1_D49C, 0x1_D49C, -119_867),
+    (0x1_D49E, 0x1_D49F, -119_867), (0x1_D4A2, 0x1_D4A2, -119_867), (0x1_D4A5, 0x1_D4A6, -119_867),
+    (0x1_D4A9, 0x1_D4AC, -119_867), (0x1_D4AE, 0x1_D4B5, -119_867), (0x1_D4B6, 0x1_D4B9, -119_893),
+    (0x1_D4BB, 0x1_D4BB, -119_893), (0x1_D4BD, 0x1_D4C3, -119_893), (0x1_D4C5, 0x1_D4CF, -119_893),
+    (0x1_D4D0, 0x1_D4E9, -119_919), (0x1_D4EA, 0x1_D503, -119_945), (0x1_D504, 0x1_D505, -119_971),
+    (0x1_D507, 0x1_D50A, -119_971), (0x1_D50D, 0x1_D514, -119_971), (0x1_D516, 0x1_D51C, -119_971),
+    (0x1_D51E, 0x1_D537, -119_997), (0x1_D538, 0x1_D539, -120_023), (0x1_D53B, 0x1_D53E, -120_023),
+    (0x1_D540, 0x1_D544, -120_023), (0x1_D546, 0x1_D546, -120_023), (0x1_D54A, 0x1_D550, -120_023),
+    (0x1_D552, 0x1_D56B, -120_049), (0x1_D56C, 0x1_D585, -120_075), (0x1_D586, 0x1_D59F, -120_101),
+    (0x1_D5A0, 0x1_D5B9, -120_127), (0x1_D5BA, 0x1_D5D3, -120_153), (0x1_D5D4, 0x1_D5ED, -120_179),
+    (0x1_D5EE, 0x1_D607, -120_205), (0x1_D608, 0x1_D621, -120_231), (0x1_D622, 0x1_D63B, -120_257),
+    (0x1_D63C, 0x1_D655, -120_283), (0x1_D656, 0x1_D66F, -120_309), (0x1_D670, 0x1_D689, -120_335),
+    (0x1_D68A, 0x1_D6A3, -120_361), (0x1_D6A4, 0x1_D6A4, -120_179), (0x1_D6A5, 0x1_D6A5, -119_918),
+    (0x1_D6A8, 0x1_D6B8, -119_543), (0x1_D6B9, 0x1_D6B9, -119_553), (0x1_D6BA, 0x1_D6C0, -119_543),
+    (0x1_D6C1, 0x1_D6C1, -111_802), (0x1_D6C2, 0x1_D6D2, -119_569), (0x1_D6D3, 0x1_D6D3, -119_568),
+    (0x1_D6D4, 0x1_D6DA, -119_569), (0x1_D6DB, 0x1_D6DB, -111_833), (0x1_D6DC, 0x1_D6DC, -119_591),
+    (0x1_D6DD, 0x1_D6DD, -119_589), (0x1_D6DE, 0x1_D6DE, -119_588), (0x1_D6DF, 0x1_D6DF, -119_577),
+    (0x1_D6E0, 0x1_D6E0, -119_583), (0x1_D6E1, 0x1_D6E1, -119_585), (0x1_D6E2, 0x1_D6F2, -119_601),
+    (0x1_D6F3, 0x1_D6F3, -119_611), (0x1_D6F4, 0x1_D6FA, -119_601), (0x1_D6FB, 0x1_D6FB, -111_860),
+    (0x1_D6FC, 0x1_D70C, -119_627), (0x1_D70D, 0x1_D70D, -119_626), (0x1_D70E, 0x1_D714, -119_627),
+    (0x1_D715, 0x1_D715, -111_891), (0x1_D716, 0x1_D716, -119_649), (0x1_D717, 0x1_D717, -119_647),
+    (0x1_D718, 0x1_D718, -119_646), (0x1_D719, 0x1_D719, -119_635), (0x1_D71A, 0x1_D71A, -119_641),
+    (0x1_D71B, 0x1_D71B, -119_643), (0x1_D71C, 0x1_D72C, -119_659), (0x1_D72D, 0x1_D72D, -119_669),
+    (0x1_D72E, 0x1_D734, -119_659), (0x1_D735, 0x1_D735, -111_918), (0x1_D736, 0x1_D746, -119_685),
+    (0x1_D747, 0x1_D747, -119_684), (0x1_D748, 0x1_D74E, -119_685), (0x1_D74F, 0x1_D74F, -111_949),
+    (0x1_D750, 0x1_D750, -119_707), (0x1_D751, 0x1_D751, -119_705), (0x1_D752, 0x1_D752, -119_704),
+    (0x1_D753, 0x1_D753, -119_693), (0x1_D754, 0x1_D754, -119_699), (0x1_D755, 0x1_D755, -119_701),
+    (0x1_D756, 0x1_D766, -119_717), (0x1_D767, 0x1_D767, -119_727), (0x1_D768, 0x1_D76E, -119_717),
+    (0x1_D76F, 0x1_D76F, -111_976), (0x1_D770, 0x1_D780, -119_743), (0x1_D781, 0x1_D781, -119_742),
+    (0x1_D782, 0x1_D788, -119_743), (0x1_D789, 0x1_D789, -112_007), (0x1_D78A, 0x1_D78A, -119_765),
+    (0x1_D78B, 0x1_D78B, -119_763), (0x1_D78C, 0x1_D78C, -119_762), (0x1_D78D, 0x1_D78D, -119_751),
+    (0x1_D78E, 0x1_D78E, -119_757), (0x1_D78F, 0x1_D78F, -119_759), (0x1_D790, 0x1_D7A0, -119_775),
+    (0x1_D7A1, 0x1_D7A1, -119_785), (0x1_D7A2, 0x1_D7A8, -119_775), (0x1_D7A9, 0x1_D7A9, -112_034),
+    (0x1_D7AA, 0x1_D7BA, -119_801), (0x1_D7BB, 0x1_D7BB, -119_800), (0x1_D7BC, 0x1_D7C2, -119_801),
+    (0x1_D7C3, 0x1_D7C3, -112_065), (0x1_D7C4, 0x1_D7C4, -119_823), (0x1_D7C5, 0x1_D7C5, -119_821),
+    (0x1_D7C6, 0x1_D7C6, -119_820), (0x1_D7C7, 0x1_D7C7, -119_809), (0x1_D7C8, 0x1_D7C8, -119_815),
+    (0x1_D7C9, 0x1_D7C9, -119_817), (0x1_D7CA, 0x1_D7CA, -119_789), (0x1_D7CB, 0x1_D7CB, -119_790),
+    (0x1_D7CE, 0x1_D7D7, -120_734), (0x1_D7D8, 0x1_D7E1, -120_744), (0x1_D7E2, 0x1_D7EB, -120_754),
+    (0x1_D7EC, 0x1_D7F5, -120_764), (0x1_D7F6, 0x1_D7FF, -120_774), (0x1_EE00, 0x1_EE01, -124_889),
+    (0x1_EE02, 0x1_EE02, -124_886), (0x1_EE03, 0x1_EE03, -124_884), (0x1_EE05, 0x1_EE05, -124_861),
+    (0x1_EE06, 0x1_EE06, -124_884), (0x1_EE07, 0x1_EE07, -124_890), (0x1_EE08, 0x1_EE08, -124_881),
+    (0x1_EE09, 0x1_EE09, -124_863), (0x1_EE0A, 0x1_EE0D, -124_871), (0x1_EE0E, 0x1_EE0E, -124_891),
+    (0x1_EE0F, 0x1_EE0F, -124_886), (0x1_EE10, 0x1_EE10, -124_879), (0x1_EE11, 0x1_EE11, -124_892),
+    (0x1_EE12, 0x1_EE12, -124_880), (0x1_EE13, 0x1_EE13, -124_898), (0x1_EE14, 0x1_EE14, -124_896),
+    (0x1_EE15, 0x1_EE16, -124_907), (0x1_EE17, 0x1_EE17, -124_905), (0x1_EE18, 0x1_EE18, -124_904),
+    (0x1_EE19, 0x1_EE19, -124_899), (0x1_EE1A, 0x1_EE1A, -124_898), (0x1_EE1B, 0x1_EE1B, -124_897),
+    (0x1_EE1C, 0x1_EE1C, -124_846), (0x1_EE1D, 0x1_EE1D, -124_771), (0x1_EE1E, 0x1_EE1E, -124_797),
+    (0x1_EE1F, 0x1_EE1F, -124_848), (0x1_EE21, 0x1_EE21, -124_921), (0x1_EE22, 0x1_EE22, -124_918),
+    (0x1_EE24, 0x1_EE24, -124_893), (0x1_EE27, 0x1_EE27, -124_922), (0x1_EE29, 0x1_EE29, -124_895),
+    (0x1_EE2A, 0x1_EE2D, -124_903), (0x1_EE2E, 0x1_EE2E, -124_923), (0x1_EE2F, 0x1_EE2F, -124_918),
+    (0x1_EE30, 0x1_EE30, -124_911), (0x1_EE31, 0x1_EE31, -124_924), (0x1_EE32, 0x1_EE32, -124_912),
+    (0x1_EE34, 0x1_EE34, -124_928), (0x1_EE35, 0x1_EE36, -124_939), (0x1_EE37, 0x1_EE37, -124_937),
+    (0x1_EE39, 0x1_EE39, -124_931), (0x1_EE3B, 0x1_EE3B, -124_929), (0x1_EE42, 0x1_EE42, -124_950),
+    (0x1_EE47, 0x1_EE47, -124_954), (0x1_EE49, 0x1_EE49, -124_927), (0x1_EE4B, 0x1_EE4B, -124_935),
+    (0x1_EE4D, 0x1_EE4D, -124_935), (0x1_EE4E, 0x1_EE4E, -124_955), (0x1_EE4F, 0x1_EE4F, -124_950),
+    (0x1_EE51, 0x1_EE51, -124_956), (0x1_EE52, 0x1_EE52, -124_944), (0x1_EE54, 0x1_EE54, -124_960),
+    (0x1_EE57, 0x1_EE57, -124_969), (0x1_EE59, 0x1_EE59, -124_963), (0x1_EE5B, 0x1_EE5B, -124_961),
+    (0x1_EE5D, 0x1_EE5D, -124_835), (0x1_EE5F, 0x1_EE5F, -124_912), (0x1_EE61, 0x1_EE61, -124_985),
+    (0x1_EE62, 0x1_EE62, -124_982), (0x1_EE64, 0x1_EE64, -124_957), (0x1_EE67, 0x1_EE67, -124_986),
+    (0x1_EE68, 0x1_EE68, -124_977), (0x1_EE69, 0x1_EE69, -124_959), (0x1_EE6A, 0x1_EE6A, -124_967),
+    (0x1_EE6C, 0x1_EE6D, -124_967), (0x1_EE6E, 0x1_EE6E, -124_987), (0x1_EE6F, 0x1_EE6F, -124_982),
+    (0x1_EE70, 0x1_EE70, -124_975), (0x1_EE71, 0x1_EE71, -124_988), (0x1_EE72, 0x1_EE72, -124_976),
+    (0x1_EE74, 0x1_EE74, -124_992), (0x1_EE75, 0x1_EE76, -125_003), (0x1_EE77, 0x1_EE77, -125_001),
+    (0x1_EE79, 0x1_EE79, -124_995), (0x1_EE7A, 0x1_EE7A, -124_994), (0x1_EE7B, 0x1_EE7B, -124_993),
+    (0x1_EE7C, 0x1_EE7C, -124_942), (0x1_EE7E, 0x1_EE7E, -124_893), (0x1_EE80, 0x1_EE81, -125_017),
+    (0x1_EE82, 0x1_EE82, -125_014), (0x1_EE83, 0x1_EE83, -125_012), (0x1_EE84, 0x1_EE85, -124_989),
+    (0x1_EE86, 0x1_EE86, -125_012), (0x1_EE87, 0x1_EE87, -125_018), (0x1_EE88, 0x1_EE88, -125_009),
+    (0x1_EE89, 0x1_EE89, -124_991), (0x1_EE8B, 0x1_EE8D, -124_999), (0x1_EE8E, 0x1_EE8E, -125_019),
+    (0x1_EE8F, 0x1_EE8F, -125_014), (0x1_EE90, 0x1_EE90, -125_007), (0x1_EE91, 0x1_EE91, -125_020),
+    (0x1_EE92, 0x1_EE92, -125_008), (0x1_EE93, 0x1_EE93, -125_026), (0x1_EE94, 0x1_EE94, -125_024),
+    (0x1_EE95, 0x1_EE96, -125_035), (0x1_EE97, 0x1_EE97, -125_033), (0x1_EE98, 0x1_EE98, -125_032),
+    (0x1_EE99, 0x1_EE99, -125_027), (0x1_EE9A, 0x1_EE9A, -125_026), (0x1_EE9B, 0x1_EE9B, -125_025),
+    (0x1_EEA1, 0x1_EEA1, -125_049), (0x1_EEA2, 0x1_EEA2, -125_046), (0x1_EEA3, 0x1_EEA3, -125_044),
+    (0x1_EEA5, 0x1_EEA5, -125_021), (0x1_EEA6, 0x1_EEA6, -125_044), (0x1_EEA7, 0x1_EEA7, -125_050),
+    (0x1_EEA8, 0x1_EEA8, -125_041), (0x1_EEA9, 0x1_EEA9, -125_023), (0x1_EEAB, 0x1_EEAD, -125_031),
+    (0x1_EEAE, 0x1_EEAE, -125_051), (0x1_EEAF, 0x1_EEAF, -125_046), (0x1_EEB0, 0x1_EEB0, -125_039),
+    (0x1_EEB1, 0x1_EEB1, -125_052), (0x1_EEB2, 0x1_EEB2, -125_040), (0x1_EEB3, 0x1_EEB3, -125_058),
+    (0x1_EEB4, 0x1_EEB4, -125_056), (0x1_EEB5, 0x1_EEB6, -125_067), (0x1_EEB7, 0x1_EEB7, -125_065),
+    (0x1_EEB8, 0x1_EEB8, -125_064), (0x1_EEB9, 0x1_EEB9, -125_059), (0x1_EEBA, 0x1_EEBA, -125_058),
+    (0x1_EEBB, 0x1_EEBB, -125_057), (0x1_F12B, 0x1_F12B, -127_176), (0x1_F12C, 0x1_F12C, -127_162),
+    (0x1_F130, 0x1_F149, -127_183), (0x1_F202, 0x1_F202, -115_021), (0x1_F210, 0x1_F210, -102_341),
+    (0x1_F211, 0x1_F211, -104_122), (0x1_F212, 0x1_F212, -106_054), (0x1_F213, 0x1_F213, -115_020),
+    (0x1_F214, 0x1_F214, -107_400), (0x1_F215, 0x1_F215, -104_699), (0x1_F216, 0x1_F216, -92211),
+    (0x1_F217, 0x1_F217, -104_686), (0x1_F218, 0x1_F218, -107_380), (0x1_F219, 0x1_F219, -101_369),
+    (0x1_F21A, 0x1_F21A, -98553), (0x1_F21B, 0x1_F21B, -101_506), (0x1_F21C, 0x1_F21C, -106_447),
+    (0x1_F21D, 0x1_F21D, -103_057), (0x1_F21E, 0x1_F21E, -106_641), (0x1_F21F, 0x1_F21F, -101_487),
+    (0x1_F220, 0x1_F220, -106_499), (0x1_F221, 0x1_F221, -95455), (0x1_F222, 0x1_F222, -97539),
+    (0x1_F223, 0x1_F223, -91514), (0x1_F224, 0x1_F224, -104_756), (0x1_F225, 0x1_F225, -105_964),
+    (0x1_F226, 0x1_F226, -99090), (0x1_F227, 0x1_F227, -102_290), (0x1_F228, 0x1_F228, -102_099),
+    (0x1_F229, 0x1_F229, -107_561), (0x1_F22A, 0x1_F22A, -107_553), (0x1_F22B, 0x1_F22B, -90593),
+    (0x1_F22C, 0x1_F22C, -103_494), (0x1_F22D, 0x1_F22D, -107_520), (0x1_F22E, 0x1_F22E, -106_043),
+    (0x1_F22F, 0x1_F22F, -102_184), (0x1_F230, 0x1_F230, -91328), (0x1_F231, 0x1_F231, -102_366),
+    (0x1_F232, 0x1_F232, -96433), (0x1_F233, 0x1_F233, -96185), (0x1_F234, 0x1_F234, -106_028),
+    (0x1_F235, 0x1_F235, -99253), (0x1_F236, 0x1_F236, -101_165), (0x1_F237, 0x1_F237, -101_167),
+    (0x1_F238, 0x1_F238, -97541), (0x1_F239, 0x1_F239, -106_439), (0x1_F23A, 0x1_F23A, -105_604),
+    (0x1_F23B, 0x1_F23B, -90350), (0x1_F250, 0x1_F250, -103_097), (0x1_F251, 0x1_F251, -106_082),
+    (0x1_FBF0, 0x1_FBF9, -129_984), (0x2_F800, 0x2_F800, -174_531), (0x2_F801, 0x2_F801, -174_537),
+    (0x2_F802, 0x2_F802, -174_529), (0x2_F803, 0x2_F803, -63201), (0x2_F804, 0x2_F804, -174_244),
+    (0x2_F805, 0x2_F805, -174_167), (0x2_F806, 0x2_F806, -174_155), (0x2_F807, 0x2_F807, -174_085),
+    (0x2_F808, 0x2_F808, -173_966), (0x2_F809, 0x2_F809, -173_936), (0x2_F80A, 0x2_F80A, -173_859),
+    (0x2_F80B, 0x2_F80B, -173_884), (0x2_F80C, 0x2_F80C, -181_102), (0x2_F80D, 0x2_F80D, -61907),
+    (0x2_F80E, 0x2_F80E, -173_761), (0x2_F80F, 0x2_F80F, -173_755), (0x2_F810, 0x2_F810, -173_740),
+    (0x2_F811, 0x2_F811, -173_722), (0x2_F812, 0x2_F812, -62198), (0x2_F813, 0x2_F813, -181_082),
+    (0x2_F814, 0x2_F814, -173_741), (0x2_F815, 0x2_F815, -173_704), (0x2_F816, 0x2_F816, -62155),
+    (0x2_F817, 0x2_F817, -173_696), (0x2_F818, 0x2_F818, -173_684), (0x2_F819, 0x2_F819, -174_413),
+    (0x2_F81A, 0x2_F81A, -173_678), (0x2_F81B, 0x2_F81B, -173_670), (0x2_F81C, 0x2_F81C, -26173),
+    (0x2_F81D, 0x2_F81D, -173_608), (0x2_F81E, 0x2_F81E, -173_595), (0x2_F81F, 0x2_F81F, -181_056),
+    (0x2_F820, 0x2_F820, -173_541), (0x2_F821, 0x2_F821, -173_531), (0x2_F822, 0x2_F822, -173_488),
+    (0x2_F823, 0x2_F823, -173_484), (0x2_F824, 0x2_F824, -181_007), (0x2_F825, 0x2_F825, -173_406),
+    (0x2_F826, 0x2_F826, -173_405), (0x2_F827, 0x2_F827, -173_379), (0x2_F828, 0x2_F828, -173_358),
+    (0x2_F829, 0x2_F82A, -173_348), (0x2_F82B, 0x2_F82B, -173_332), (0x2_F82C, 0x2_F82C, -173_283),
+    (0x2_F82D, 0x2_F82D, -173_276), (0x2_F82E, 0x2_F82E, -173_268), (0x2_F82F, 0x2_F82F, -173_244),
+    (0x2_F830, 0x2_F830, -173_235), (0x2_F831, 0x2_F831, -173_234), (0x2_F832, 0x2_F832, -173_235),
+    (0x2_F833, 0x2_F833, -173_236), (0x2_F834, 0x2_F834, -60936), (0x2_F835, 0x2_F835, -165_829),
+    (0x2_F836, 0x2_F836, -173_164), (0x2_F837, 0x2_F837, -173_144), (0x2_F838, 0x2_F838, -60629),
+    (0x2_F839, 0x2_F839, -173_134), (0x2_F83A, 0x2_F83A, -173_129), (0x2_F83B, 0x2_F83B, -173_109),
+    (0x2_F83C, 0x2_F83C, -172_958), (0x2_F83D, 0x2_F83D, -173_061), (0x2_F83E, 0x2_F83E, -173_046),
+    (0x2_F83F, 0x2_F83F, -173_015), (0x2_F840, 0x2_F840, -172_958), (0x2_F841, 0x2_F841, -172_875),
+    (0x2_F842, 0x2_F842, -172_850), (0x2_F843, 0x2_F843, -172_784), (0x2_F844, 0x2_F844, -172_769),
+    (0x2_F845, 0x2_F845, -172_737), (0x2_F846, 0x2_F846, -172_738), (0x2_F847, 0x2_F847, -172_718),
+    (0x2_F848, 0x2_F848, -172_701), (0x2_F849, 0x2_F849, -172_694), (0x2_F84A, 0x2_F84A, -172_680),
+    (0x2_F84B, 0x2_F84B, -172_341), (0x2_F84C, 0x2_F84C, -172_614), (0x2_F84D, 0x2_F84D, -172_342),
+    (0x2_F84E, 0x2_F84E, -172_541), (0x2_F84F, 0x2_F84F, -172_507), (0x2_F850, 0x2_F850, -173_641),
+    (0x2_F851, 0x2_F851, -171_875), (0x2_F852, 0x2_F852, -172_164), (0x2_F853, 0x2_F853, -172_127),
+    (0x2_F854, 0x2_F854, -172_103), (0x2_F855, 0x2_F855, -172_234), (0x2_F856, 0x2_F856, -172_068),
+    (0x2_F857, 0x2_F857, -172_070), (0x2_F858, 0x2_F858, -171_948), (0x2_F859, 0x2_F859, -58229),
+    (0x2_F85A, 0x2_F85A, -171_880), (0x2_F85B, 0x2_F85B, -171_876), (0x2_F85C, 0x2_F85C, -171_862),
+    (0x2_F85D, 0x2_F85D, -171_843), (0x2_F85E, 0x2_F85E, -171_836), (0x2_F85F, 0x2_F85F, -171_773),
+    (0x2_F860, 0x2_F860, -57784), (0x2_F861, 0x2_F861, -57719), (0x2_F862, 0x2_F862, -171_638),
+    (0x2_F863, 0x2_F863, -171_592), (0x2_F864, 0x2_F864, -171_581), (0x2_F865, 0x2_F865, -171_661),
+    (0x2_F866, 0x2_F866, -171_520), (0x2_F867, 0x2_F867, -180_601), (0x2_F868, 0x2_F868, -180_588),
+    (0x2_F869, 0x2_F869, -171_361), (0x2_F86A, 0x2_F86A, -171_308), (0x2_F86B, 0x2_F86B, -171_309),
+    (0x2_F86C, 0x2_F86C, -56996), (0x2_F86D, 0x2_F86D, -171_178), (0x2_F86E, 0x2_F86E, -171_158),
+    (0x2_F86F, 0x2_F86F, -171_144), (0x2_F870, 0x2_F870, -171_133), (0x2_F871, 0x2_F871, -56665),
+    (0x2_F872, 0x2_F872, -171_123), (0x2_F873, 0x2_F873, -171_117), (0x2_F874, 0x2_F874, -170_273),
+    (0x2_F875, 0x2_F875, -171_091), (0x2_F876, 0x2_F876, -180_469), (0x2_F877, 0x2_F877, -171_031),
+    (0x2_F878, 0x2_F878, -171_018), (0x2_F879, 0x2_F879, -170_937), (0x2_F87A, 0x2_F87A, -170_989),
+    (0x2_F87B, 0x2_F87B, -55959), (0x2_F87C, 0x2_F87C, -170_809), (0x2_F87D, 0x2_F87D, -55959),
+    (0x2_F87E, 0x2_F87E, -170_768), (0x2_F87F, 0x2_F87F, -170_772), (0x2_F880, 0x2_F880, -170_756),
+    (0x2_F881, 0x2_F882, -170_656), (0x2_F883, 0x2_F883, -180_308), (0x2_F884, 0x2_F884, -170_631),
+    (0x2_F885, 0x2_F885, -170_589), (0x2_F886, 0x2_F886, -170_569), (0x2_F887, 0x2_F887, -170_526),
+    (0x2_F888, 0x2_F888, -180_262), (0x2_F889, 0x2_F889, -55046), (0x2_F88A, 0x2_F88A, -180_238),
+    (0x2_F88B, 0x2_F88B, -170_459), (0x2_F88C, 0x2_F88C, -170_457), (0x2_F88D, 0x2_F88D, -170_455),
+    (0x2_F88E, 0x2_F88E, -170_436), (0x2_F88F, 0x2_F88F, -21757), (0x2_F890, 0x2_F890, -170_386),
+    (0x2_F891, 0x2_F891, -54624), (0x2_F892, 0x2_F892, -54625), (0x2_F893, 0x2_F893, -161_426),
+    (0x2_F894, 0x2_F894, -170_354), (0x2_F895, 0x2_F895, -170_355), (0x2_F896, 0x2_F896, -180_175),
+    (0x2_F897, 0x2_F897, -50655), (0x2_F898, 0x2_F898, -38590), (0x2_F899, 0x2_F899, -170_295),
+    (0x2_F89A, 0x2_F89A, -170_287), (0x2_F89B, 0x2_F89B, -180_152), (0x2_F89C, 0x2_F89C, -170_242),
+    (0x2_F89D, 0x2_F89D, -170_192), (0x2_F89E, 0x2_F89E, -170_183), (0x2_F89F, 0x2_F89F, -170_150),
+    (0x2_F8A0, 0x2_F8A0, -170_015), (0x2_F8A1, 0x2_F8A1, -180_071), (0x2_F8A2, 0x2_F8A2, -180_102),
+    (0x2_F8A3, 0x2_F8A3, -169_999), (0x2_F8A4, 0x2_F8A4, -53712), (0x2_F8A5, 0x2_F8A5, -169_950),
+    (0x2_F8A6, 0x2_F8A6, -169_822), (0x2_F8A7, 0x2_F8A7, -169_819), (0x2_F8A8, 0x2_F8A8, -169_818),
+    (0x2_F8A9, 0x2_F8A9, -169_821), (0x2_F8AA, 0x2_F8AA, -169_776), (0x2_F8AB, 0x2_F8AB, -169_757),
+    (0x2_F8AC, 0x2_F8AC, -169_722), (0x2_F8AD, 0x2_F8AD, -169_737), (0x2_F8AE, 0x2_F8AE, -169_727),
+    (0x2_F8AF, 0x2_F8AF, -169_681), (0x2_F8B0, 0x2_F8B0, -169_662), (0x2_F8B1, 0x2_F8B1, -169_659),
+    (0x2_F8B2, 0x2_F8B2, -169_634), (0x2_F8B3, 0x2_F8B3, -169_624), (0x2_F8B4, 0x2_F8B4, -169_559),
+    (0x2_F8B5, 0x2_F8B5, -169_476), (0x2_F8B6, 0x2_F8B6, -169_442), (0x2_F8B7, 0x2_F8B7, -169_319),
+    (0x2_F8B8, 0x2_F8B8, -52652), (0x2_F8B9, 0x2_F8B9, -169_340), (0x2_F8BA, 0x2_F8BA, -169_406),
+    (0x2_F8BB, 0x2_F8BB, -169_299), (0x2_F8BC, 0x2_F8BC, -169_273), (0x2_F8BD, 0x2_F8BD, -169_177),
+    (0x2_F8BE, 0x2_F8BE, -52429), (0x2_F8BF, 0x2_F8BF, -169_117), (0x2_F8C0, 0x2_F8C0, -169_211),
+    (0x2_F8C1, 0x2_F8C1, -169_240), (0x2_F8C2, 0x2_F8C2, -179_860), (0x2_F8C3, 0x2_F8C3, -169_050),
+    (0x2_F8C4, 0x2_F8C4, -169_030), (0x2_F8C5, 0x2_F8C5, -169_000), (0x2_F8C6, 0x2_F8C6, -169_039),
+    (0x2_F8C7, 0x2_F8C7, -179_803), (0x2_F8C8, 0x2_F8C8, -168_825), (0x2_F8C9, 0x2_F8C9, -168_797),
+    (0x2_F8CA, 0x2_F8CA, -51392), (0x2_F8CB, 0x2_F8CB, -168_680), (0x2_F8CC, 0x2_F8CC, -168_404),
+    (0x2_F8CD, 0x2_F8CD, -168_580), (0x2_F8CE, 0x2_F8CE, -179_637), (0x2_F8CF, 0x2_F8CF, -168_510),
+    (0x2_F8D0, 0x2_F8D0, -179_656), (0x2_F8D1, 0x2_F8D1, -179_693), (0x2_F8D2, 0x2_F8D2, -173_888),
+    (0x2_F8D3, 0x2_F8D3, -173_886), (0x2_F8D4, 0x2_F8D4, -168_404), (0x2_F8D5, 0x2_F8D5, -168_505),
+    (0x2_F8D6, 0x2_F8D6, -161_833), (0x2_F8D7, 0x2_F8D7, -177_406), (0x2_F8D8, 0x2_F8D8, -168_385),
+    (0x2_F8D9, 0x2_F8D9, -168_382), (0x2_F8DA, 0x2_F8DA, -168_377), (0x2_F8DB, 0x2_F8DB, -168_317),
+    (0x2_F8DC, 0x2_F8DC, -168_329), (0x2_F8DD, 0x2_F8DD, -50458), (0x2_F8DE, 0x2_F8DE, -179_605),
+    (0x2_F8DF, 0x2_F8DF, -168_165), (0x2_F8E0, 0x2_F8E0, -168_283), (0x2_F8E1, 0x2_F8E1, -168_079),
+    (0x2_F8E2, 0x2_F8E2, -168_029), (0x2_F8E3, 0x2_F8E3, -50294), (0x2_F8E4, 0x2_F8E4, -168_022),
+    (0x2_F8E5, 0x2_F8E5, -168_134), (0x2_F8E6, 0x2_F8E6, -167_890), (0x2_F8E7, 0x2_F8E7, -179_530),
+    (0x2_F8E8, 0x2_F8E8, -167_846), (0x2_F8E9, 0x2_F8E9, -167_750), (0x2_F8EA, 0x2_F8EA, -167_680),
+    (0x2_F8EB, 0x2_F8EB, -167_491), (0x2_F8EC, 0x2_F8EC, -49737), (0x2_F8ED, 0x2_F8ED, -167_442),
+    (0x2_F8EE, 0x2_F8EE, -179_414), (0x2_F8EF, 0x2_F8EF, -167_374), (0x2_F8F0, 0x2_F8F0, -49225),
+    (0x2_F8F1, 0x2_F8F1, -167_325), (0x2_F8F2, 0x2_F8F2, -179_364), (0x2_F8F3, 0x2_F8F3, -167_297),
+    (0x2_F8F4, 0x2_F8F4, -167_253), (0x2_F8F5, 0x2_F8F6, -167_227), (0x2_F8F7, 0x2_F8F7, -48746),
+    (0x2_F8F8, 0x2_F8F8, -56301), (0x2_F8F9, 0x2_F8F9, -48639), (0x2_F8FA, 0x2_F8FA, -167_084),
+    (0x2_F8FB, 0x2_F8FB, -48191), (0x2_F8FC, 0x2_F8FC, -166_973), (0x2_F8FD, 0x2_F8FD, -166_960),
+    (0x2_F8FE, 0x2_F8FE, -167_063), (0x2_F8FF, 0x2_F8FF, -166_889), (0x2_F900, 0x2_F900, -166_850),
+    (0x2_F901, 0x2_F901, -166_794), (0x2_F902, 0x2_F902, -166_849), (0x2_F903, 0x2_F903, -166_810),
+    (0x2_F904, 0x2_F904, -166_796), (0x2_F905, 0x2_F905, -166_784), (0x2_F906, 0x2_F906, -48104),
+    (0x2_F907, 0x2_F907, -166_867), (0x2_F908, 0x2_F908, -166_617), (0x2_F909, 0x2_F909, -166_555),
+    (0x2_F90A, 0x2_F90A, -179_159), (0x2_F90B, 0x2_F90B, -166_464), (0x2_F90C, 0x2_F90C, -166_469),
+    (0x2_F90D, 0x2_F90D, -47676), (0x2_F90E, 0x2_F90E, -166_677), (0x2_F90F, 0x2_F90F, -166_305),
+    (0x2_F910, 0x2_F910, -47538), (0x2_F911, 0x2_F911, -47491), (0x2_F912, 0x2_F912, -166_220),
+    (0x2_F913, 0x2_F913, -166_106), (0x2_F914, 0x2_F914, -166_134), (0x2_F915, 0x2_F915, -166_138),
+    (0x2_F916, 0x2_F916, -179_072), (0x2_F917, 0x2_F917, -166_093), (0x2_F918, 0x2_F918, -166_043),
+    (0x2_F919, 0x2_F919, -166_050), (0x2_F91A, 0x2_F91A, -165_997), (0x2_F91B, 0x2_F91B, -62454),
+    (0x2_F91C, 0x2_F91C, -165_847), (0x2_F91D, 0x2_F91D, -46778), (0x2_F91E, 0x2_F91E, -165_762),
+    (0x2_F91F, 0x2_F91F, -46452), (0x2_F920, 0x2_F920, -165_624), (0x2_F921, 0x2_F921, -165_612),
+    (0x2_F922, 0x2_F922, -165_586), (0x2_F923, 0x2_F923, -45851), (0x2_F924, 0x2_F924, -165_540),
+    (0x2_F925, 0x2_F925, -165_520), (0x2_F926, 0x2_F926, -45553), (0x2_F927, 0x2_F927, -45331),
+    (0x2_F928, 0x2_F928, -165_294), (0x2_F929, 0x2_F929, -165_278), (0x2_F92A, 0x2_F92A, -178_814),
+    (0x2_F92B, 0x2_F92B, -165_254), (0x2_F92C, 0x2_F92C, -178_804), (0x2_F92D, 0x2_F92D, -178_805),
+    (0x2_F92E, 0x2_F92E, -165_095), (0x2_F92F, 0x2_F92F, -165_075), (0x2_F930, 0x2_F930, -165_055),
+    (0x2_F931, 0x2_F931, -165_036), (0x2_F932, 0x2_F932, -164_968), (0x2_F933, 0x2_F933, -178_712),
+    (0x2_F934, 0x2_F934, -164_880), (0x2_F935, 0x2_F935, -44287), (0x2_F936, 0x2_F936, -164_856),
+    (0x2_F937, 0x2_F937, -44197), (0x2_F938, 0x2_F938, -164_808), (0x2_F939, 0x2_F939, -55194),
+    (0x2_F93A, 0x2_F93A, -164_650), (0x2_F93B, 0x2_F93B, -43418), (0x2_F93C, 0x2_F93C, -43396),
+    (0x2_F93D, 0x2_F93D, -43257), (0x2_F93E, 0x2_F93E, -178_498), (0x2_F93F, 0x2_F93F, -178_487),
+    (0x2_F940, 0x2_F940, -164_428), (0x2_F941, 0x2_F941, -43086), (0x2_F942, 0x2_F942, -43088),
+    (0x2_F943, 0x2_F943, -43050), (0x2_F944, 0x2_F944, -43025), (0x2_F945, 0x2_F946, -164_391),
+    (0x2_F947, 0x2_F947, -164_392), (0x2_F948, 0x2_F948, -164_350), (0x2_F949, 0x2_F949, -178_448),
+    (0x2_F94A, 0x2_F94A, -164_287), (0x2_F94B, 0x2_F94B, -178_437), (0x2_F94C, 0x2_F94C, -178_358),
+    (0x2_F94D, 0x2_F94D, -42288), (0x2_F94E, 0x2_F94E, -164_096), (0x2_F94F, 0x2_F94F, -164_035),
+    (0x2_F950, 0x2_F950, -163_972), (0x2_F951, 0x2_F951, -178_286), (0x2_F952, 0x2_F952, -41772),
+    (0x2_F953, 0x2_F953, -163_837), (0x2_F954, 0x2_F954, -41658), (0x2_F955, 0x2_F955, -41616),
+    (0x2_F956, 0x2_F956, -163_783), (0x2_F957, 0x2_F957, -163_692), (0x2_F958, 0x2_F958, -178_217),
+    (0x2_F959, 0x2_F959, -163_609), (0x2_F95A, 0x2_F95A, -163_600), (0x2_F95B, 0x2_F95B, -163_596),
+    (0x2_F95C, 0x2_F95C, -40928), (0x2_F95D, 0x2_F95D, -40630), (0x2_F95E, 0x2_F95E, -40631),
+    (0x2_F95F, 0x2_F95F, -163_441), (0x2_F960, 0x2_F960, -178_014), (0x2_F961, 0x2_F961, -40374),
+    (0x2_F962, 0x2_F962, -163_228), (0x2_F963, 0x2_F963, -163_226), (0x2_F964, 0x2_F964, -177_981),
+    (0x2_F965, 0x2_F965, -40165), (0x2_F966, 0x2_F966, -162_964), (0x2_F967, 0x2_F967, -177_863),
+    (0x2_F968, 0x2_F968, -162_944), (0x2_F969, 0x2_F969, -162_950), (0x2_F96A, 0x2_F96A, -162_922),
+    (0x2_F96B, 0x2_F96B, -39397), (0x2_F96C, 0x2_F96C, -162_825), (0x2_F96D, 0x2_F96D, -177_772),
+    (0x2_F96E, 0x2_F96E, -162_727), (0x2_F96F, 0x2_F96F, -162_669), (0x2_F970, 0x2_F970, -162_603),
+    (0x2_F971, 0x2_F971, -177_725), (0x2_F972, 0x2_F972, -38730), (0x2_F973, 0x2_F973, -38700),
+    (0x2_F974, 0x2_F974, -177_691), (0x2_F975, 0x2_F975, -38556), (0x2_F976, 0x2_F976, -162_300),
+    (0x2_F977, 0x2_F977, -38457), (0x2_F978, 0x2_F978, -162_275), (0x2_F979, 0x2_F979, -162_175),
+    (0x2_F97A, 0x2_F97A, -162_165), (0x2_F97B, 0x2_F97B, -38049), (0x2_F97C, 0x2_F97C, -37977),
+    (0x2_F97D, 0x2_F97D, -162_077), (0x2_F97E, 0x2_F97E, -37846), (0x2_F97F, 0x2_F97F, -162_063),
+    (0x2_F980, 0x2_F980, -50721), (0x2_F981, 0x2_F981, -177_580), (0x2_F982, 0x2_F982, -162_000),
+    (0x2_F983, 0x2_F983, -161_920), (0x2_F984, 0x2_F984, -177_529), (0x2_F985, 0x2_F985, -161_863),
+    (0x2_F986, 0x2_F986, -171_729), (0x2_F987, 0x2_F987, -37344), (0x2_F988, 0x2_F988, -37331),
+    (0x2_F989, 0x2_F989, -50678), (0x2_F98A, 0x2_F98A, -50670), (0x2_F98B, 0x2_F98B, -161_674),
+    (0x2_F98C, 0x2_F98C, -161_672), (0x2_F98D, 0x2_F98D, -158_191), (0x2_F98E, 0x2_F98E, -177_443),
+    (0x2_F98F, 0x2_F98F, -161_534), (0x2_F990, 0x2_F990, -161_541), (0x2_F991, 0x2_F991, -161_524),
+    (0x2_F992, 0x2_F992, -173_791), (0x2_F993, 0x2_F993, -161_506), (0x2_F994, 0x2_F994, -161_505),
+    (0x2_F995, 0x2_F995, -161_496), (0x2_F996, 0x2_F996, -161_456), (0x2_F997, 0x2_F997, -36443),
+    (0x2_F998, 0x2_F998, -161_459), (0x2_F999, 0x2_F999, -161_404), (0x2_F99A, 0x2_F99A, -161_335),
+    (0x2_F99B, 0x2_F99B, -161_262), (0x2_F99C, 0x2_F99C, -161_401), (0x2_F99D, 0x2_F99D, -161_248),
+    (0x2_F99E, 0x2_F99E, -161_207), (0x2_F99F, 0x2_F99F, -161_096), (0x2_F9A0, 0x2_F9A0, -161_357),
+    (0x2_F9A1, 0x2_F9A1, -161_239), (0x2_F9A2, 0x2_F9A2, -161_238), (0x2_F9A3, 0x2_F9A3, -161_223),
+    (0x2_F9A4, 0x2_F9A4, -36206), (0x2_F9A5, 0x2_F9A5, -35898), (0x2_F9A6, 0x2_F9A6, -36049),
+    (0x2_F9A7, 0x2_F9A7, -177_276), (0x2_F9A8, 0x2_F9A8, -160_951), (0x2_F9A9, 0x2_F9A9, -160_950),
+    (0x2_F9AA, 0x2_F9AA, -160_916), (0x2_F9AB, 0x2_F9AB, -34273), (0x2_F9AC, 0x2_F9AC, -160_840),
+    (0x2_F9AD, 0x2_F9AD, -35457), (0x2_F9AE, 0x2_F9AE, -177_233), (0x2_F9AF, 0x2_F9AF, -177_230),
+    (0x2_F9B0, 0x2_F9B0, -35327), (0x2_F9B1, 0x2_F9B1, -35039), (0x2_F9B2, 0x2_F9B2, -177_223),
+    (0x2_F9B3, 0x2_F9B3, -160_611), (0x2_F9B4, 0x2_F9B4, -160_600), (0x2_F9B5, 0x2_F9B5, -160_590),
+    (0x2_F9B6, 0x2_F9B6, -160_589), (0x2_F9B7, 0x2_F9B7, -160_526), (0x2_F9B8, 0x2_F9B8, -160_560),
+    (0x2_F9B9, 0x2_F9B9, -160_427), (0x2_F9BA, 0x2_F9BA, -160_472), (0x2_F9BB, 0x2_F9BB, -160_322),
+    (0x2_F9BC, 0x2_F9BC, -160_404), (0x2_F9BD, 0x2_F9BD, -160_338), (0x2_F9BE, 0x2_F9BE, -160_312),
+    (0x2_F9BF, 0x2_F9BF, -177_128), (0x2_F9C0, 0x2_F9C0, -160_223), (0x2_F9C1, 0x2_F9C1, -160_192),
+    (0x2_F9C2, 0x2_F9C2, -177_097), (0x2_F9C3, 0x2_F9C3, -160_099), (0x2_F9C4, 0x2_F9C4, -160_097),
+    (0x2_F9C5, 0x2_F9C5, -33630), (0x2_F9C6, 0x2_F9C6, -159_983), (0x2_F9C7, 0x2_F9C7, -159_977),
+    (0x2_F9C8, 0x2_F9C8, -177_043), (0x2_F9C9, 0x2_F9C9, -159_951), (0x2_F9CA, 0x2_F9CA, -181_519),
+    (0x2_F9CB, 0x2_F9CB, -33053), (0x2_F9CC, 0x2_F9CC, -32870), (0x2_F9CD, 0x2_F9CD, -176_911),
+    (0x2_F9CE, 0x2_F9CE, -176_903), (0x2_F9CF, 0x2_F9CF, -159_535), (0x2_F9D0, 0x2_F9D0, -159_459),
+    (0x2_F9D1, 0x2_F9D1, -159_303), (0x2_F9D2, 0x2_F9D2, -159_101), (0x2_F9D3, 0x2_F9D3, -32043),
+    (0x2_F9D4, 0x2_F9D4, -159_017), (0x2_F9D5, 0x2_F9D5, -158_996), (0x2_F9D6, 0x2_F9D6, -158_907),
+    (0x2_F9D7, 0x2_F9D7, -158_816), (0x2_F9D8, 0x2_F9D8, -31401), (0x2_F9D9, 0x2_F9D9, -61909),
+    (0x2_F9DA, 0x2_F9DA, -158_735), (0x2_F9DB, 0x2_F9DB, -158_751), (0x2_F9DC, 0x2_F9DC, -158_700),
+    (0x2_F9DD, 0x2_F9DD, -61695), (0x2_F9DE, 0x2_F9DE, -158_474), (0x2_F9DF, 0x2_F9DF, -158_375),
+    (0x2_F9E0, 0x2_F9E0, -29710), (0x2_F9E1, 0x2_F9E1, -29684), (0x2_F9E2, 0x2_F9E2, -158_030),
+    (0x2_F9E3, 0x2_F9E3, -157_938), (0x2_F9E4, 0x2_F9E4, -157_907), (0x2_F9E5, 0x2_F9E5, -29367),
+    (0x2_F9E6, 0x2_F9E6, -157_899), (0x2_F9E7, 0x2_F9E7, -157_615), (0x2_F9E8, 0x2_F9E9, -157_457),
+    (0x2_F9EA, 0x2_F9EA, -157_550), (0x2_F9EB, 0x2_F9EB, -157_170), (0x2_F9EC, 0x2_F9EC, -157_143),
+    (0x2_F9ED, 0x2_F9ED, -28147), (0x2_F9EE, 0x2_F9EE, -156_771), (0x2_F9EF, 0x2_F9EF, -176_218),
+    (0x2_F9F0, 0x2_F9F0, -156_729), (0x2_F9F1, 0x2_F9F1, -27770), (0x2_F9F2, 0x2_F9F2, -176_140),
+    (0x2_F9F3, 0x2_F9F3, -156_464), (0x2_F9F4, 0x2_F9F4, -171_074), (0x2_F9F5, 0x2_F9F5, -156_370),
+    (0x2_F9F6, 0x2_F9F6, -26801), (0x2_F9F7, 0x2_F9F7, -26589), (0x2_F9F8, 0x2_F9F8, -176_010),
+    (0x2_F9F9, 0x2_F9F9, -176_003), (0x2_F9FA, 0x2_F9FA, -156_186), (0x2_F9FB, 0x2_F9FB, -26097),
+    (0x2_F9FC, 0x2_F9FC, -175_946), (0x2_F9FD, 0x2_F9FD, -25959), (0x2_F9FE, 0x2_F9FE, -156_147),
+    (0x2_F9FF, 0x2_F9FF, -156_148), (0x2_FA00, 0x2_FA00, -156_119), (0x2_FA01, 0x2_FA01, -25675),
+    (0x2_FA02, 0x2_FA02, -155_936), (0x2_FA03, 0x2_FA03, -175_824), (0x2_FA04, 0x2_FA04, -155_867),
+    (0x2_FA05, 0x2_FA05, -155_742), (0x2_FA06, 0x2_FA06, -155_716), (0x2_FA07, 0x2_FA07, -155_657),
+    (0x2_FA08, 0x2_FA08, -175_674), (0x2_FA09, 0x2_FA09, -24281), (0x2_FA0A, 0x2_FA0A, -155_384),
+    (0x2_FA0B, 0x2_FA0B, -155_083), (0x2_FA0C, 0x2_FA0C, -154_895), (0x2_FA0D, 0x2_FA0D, -175_423),
+    (0x2_FA0E, 0x2_FA0E, -175_393), (0x2_FA0F, 0x2_FA0F, -154_792), (0x2_FA10, 0x2_FA10, -22850),
+    (0x2_FA11, 0x2_FA11, -175_385), (0x2_FA12, 0x2_FA12, -22797), (0x2_FA13, 0x2_FA13, -22533),
+    (0x2_FA14, 0x2_FA14, -22403), (0x2_FA15, 0x2_FA15, -154_458), (0x2_FA16, 0x2_FA16, -175_296),
+    (0x2_FA17, 0x2_FA17, -154_398), (0x2_FA18, 0x2_FA18, -154_394), (0x2_FA19, 0x2_FA19, -154_388),
+    (0x2_FA1A, 0x2_FA1A, -154_379), (0x2_FA1B, 0x2_FA1B, -154_373), (0x2_FA1C, 0x2_FA1C, -154_337),
+    (0x2_FA1D, 0x2_FA1D, -21533),
+];
+
+/// Characters that map to several characters.
+#[rustfmt::skip]
+pub(super) static EXPANDED: [(u32, &str); 1301] = [
+    (0xA8, " \u{308}"), (0xAF, " \u{304}"), (0xB4, " \u{301}"), (0xB8, " \u{327}"),
+    (0xBC, "1\u{2044}4"), (0xBD, "1\u{2044}2"), (0xBE, "3\u{2044}4"), (0x132, "ij"), (0x133, "ij"),
+    (0x13F, "l\u{b7}"), (0x140, "l\u{b7}"), (0x149, "\u{2bc}n"), (0x1C4, "d\u{17e}"),
+    (0x1C5, "d\u{17e}"), (0x1C6, "d\u{17e}"), (0x1C7, "lj"), (0x1C8, "lj"), (0x1C9, "lj"),
+    (0x1CA, "nj"), (0x1CB, "nj"), (0x1CC, "nj"), (0x1F1, "dz"), (0x1F2, "dz"), (0x1F3, "dz"),
+    (0x2D8, " \u{306}"), (0x2D9, " \u{307}"), (0x2DA, " \u{30a}"), (0x2DB, " \u{328}"),
+    (0x2DC, " \u{303}"), (0x2DD, " \u{30b}"), (0x344, "\u{308}\u{301}"), (0x37A, " \u{3b9}"),
+    (0x384, " \u{301}"), (0x385, " \u{308}\u{301}"), (0x587, "\u{565}\u{582}"),
+    (0x675, "\u{627}\u{674}"), (0x676, "\u{648}\u{674}"), (0x677, "\u{6c7}\u{674}"),
+    (0x678, "\u{64a}\u{674}"), (0x958, "\u{915}\u{93c}"), (0x959, "\u{916}\u{93c}"),
+    (0x95A, "\u{917}\u{93c}"), (0x95B, "\u{91c}\u{93c}"), (0x95C, "\u{921}\u{93c}"),
+    (0x95D, "\u{922}\u{93c}"), (0x95E, "\u{92b}\u{93c}"), (0x95F, "\u{92f}\u{93c}"),
+    (0x9DC, "\u{9a1}\u{9bc}"), (0x9DD, "\u{9a2}\u{9bc}"), (0x9DF, "\u{9af}\u{9bc}"),
+    (0xA33, "\u{a32}\u{a3c}"), (0xA36, "\u{a38}\u{a3c}"), (0xA59, "\u{a16}\u{a3c}"),
+    (0xA5A, "\u{a17}\u{a3c}"), (0xA5B, "\u{a1c}\u{a3c}"), (0xA5E, "\u{a2b}\u{a3c}"),
+    (0xB5C, "\u{b21}\u{b3c}"), (0xB5D, "\u{b22}\u{b3c}"), (0xE33, "\u{e4d}\u{e32}"),
+    (0xEB3, "\u{ecd}\u{eb2}"), (0xEDC, "\u{eab}\u{e99}"), (0xEDD, "\u{eab}\u{ea1}"),
+    (0xF43, "\u{f42}\u{fb7}"), (0xF4D, "\u{f4c}\u{fb7}"), (0xF52, "\u{f51}\u{fb7}"),
+    (0xF57, "\u{f56}\u{fb7}"), (0xF5C, "\u{f5b}\u{fb7}"), (0xF69, "\u{f40}\u{fb5}"),
+    (0xF73, "\u{f71}\u{f72}"), (0xF75, "\u{f71}\u{f74}"), (0xF76, "\u{fb2}\u{f80}"),
+    (0xF77, "\u{fb2}\u{f71}\u{f80}"), (0xF78, "\u{fb3}\u{f80}"), (0xF79, "\u{fb3}\u{f71}\u{f80}"),
+    (0xF81, "\u{f71}\u{f80}"), (0xF93, "\u{f92}\u{fb7}"), (0xF9D, "\u{f9c}\u{fb7}"),
+    (0xFA2, "\u{fa1}\u{fb7}"), (0xFA7, "\u{fa6}\u{fb7}"), (0xFAC, "\u{fab}\u{fb7}"),
+    (0xFB9, "\u{f90}\u{fb5}"), (0x1E9A, "a\u{2be}"), (0x1E9E, "ss"), (0x1F80, "\u{1f00}\u{3b9}"),
+    (0x1F81, "\u{1f01}\u{3b9}"), (0x1F82, "\u{1f02}\u{3b9}"), (0x1F83, "\u{1f03}\u{3b9}"),
+    (0x1F84, "\u{1f04}\u{3b9}"), (0x1F85, "\u{1f05}\u{3b9}"), (0x1F86, "\u{1f06}\u{3b9}"),
+    (0x1F87, "\u{1f07}\u{3b9}"), (0x1F88, "\u{1f00}\u{3b9}"), (0x1F89, "\u{1f01}\u{3b9}"),
+    (0x1F8A, "\u{1f02}\u{3b9}"), (0x1F8B, "\u{1f03}\u{3b9}"), (0x1F8C, "\u{1f04}\u{3b9}"),
+    (0x1F8D, "\u{1f05}\u{3b9}"), (0x1F8E, "\u{1f06}\u{3b9}"), (0x1F8F, "\u{1f07}\u{3b9}"),
+    (0x1F90, "\u{1f20}\u{3b9}"), (0x1F91, "\u{1f21}\u{3b9}"), (0x1F92, "\u{1f22}\u{3b9}"),
+    (0x1F93, "\u{1f23}\u{3b9}"), (0x1F94, "\u{1f24}\u{3b9}"), (0x1F95, "\u{1f25}\u{3b9}"),
+    (0x1F96, "\u{1f26}\u{3b9}"), (0x1F97, "\u{1f27}\u{3b9}"), (0x1F98, "\u{1f20}\u{3b9}"),
+    (0x1F99, "\u{1f21}\u{3b9}"), (0x1F9A, "\u{1f22}\u{3b9}"), (0x1F9B, "\u{1f23}\u{3b9}"),
+    (0x1F9C, "\u{1f24}\u{3b9}"), (0x1F9D, "\u{1f25}\u{3b9}"), (0x1F9E, "\u{1f26}\u{3b9}"),
+    (0x1F9F, "\u{1f27}\u{3b9}"), (0x1FA0, "\u{1f60}\u{3b9}"), (0x1FA1, "\u{1f61}\u{3b9}"),
+    (0x1FA2, "\u{1f62}\u{3b9}"), (0x1FA3, "\u{1f63}\u{3b9}"), (0x1FA4, "\u{1f64}\u{3b9}"),
+    (0x1FA5, "\u{1f65}\u{3b9}"), (0x1FA6, "\u{1f66}\u{3b9}"), (0x1FA7, "\u{1f67}\u{3b9}"),
+    (0x1FA8, "\u{1f60}\u{3b9}"), (0x1FA9, "\u{1f61}\u{3b9}"), (0x1FAA, "\u{1f62}\u{3b9}"),
+    (0x1FAB, "\u{1f63}\u{3b9}"), (0x1FAC, "\u{1f64}\u{3b9}"), (0x1FAD, "\u{1f65}\u{3b9}"),
+    (0x1FAE, "\u{1f66}\u{3b9}"), (0x1FAF, "\u{1f67}\u{3b9}"), (0x1FB2, "\u{1f70}\u{3b9}"),
+    (0x1FB3, "\u{3b1}\u{3b9}"), (0x1FB4, "\u{3ac}\u{3b9}"), (0x1FB7, "\u{1fb6}\u{3b9}"),
+    (0x1FBC, "\u{3b1}\u{3b9}"), (0x1FBD, " \u{313}"), (0x1FBF, " \u{313}"), (0x1FC0, " \u{342}"),
+    (0x1FC1, " \u{308}\u{342}"), (0x1FC2, "\u{1f74}\u{3b9}"), (0x1FC3, "\u{3b7}\u{3b9}"),
+    (0x1FC4, "\u{3ae}\u{3b9}"), (0x1FC7, "\u{1fc6}\u{3b9}"), (0x1FCC, "\u{3b7}\u{3b9}"),
+    (0x1FCD, " \u{313}\u{300}"), (0x1FCE, " \u{313}\u{301}"), (0x1FCF, " \u{313}\u{342}"),
+    (0x1FDD, " \u{314}\u{300}"), (0x1FDE, " \u{314}\u{301}"), (0x1FDF, " \u{314}\u{342}"),
+    (0x1FED, " \u{308}\u{300}"), (0x1FEE, " \u{308}\u{301}"), (0x1FF2, "\u{1f7c}\u{3b9}"),
+    (0x1FF3, "\u{3c9}\u{3b9}"), (0x1FF4, "\u{3ce}\u{3b9}"), (0x1FF7, "\u{1ff6}\u{3b9}"),
+    (0x1FFC, "\u{3c9}\u{3b9}"), (0x1FFD, " \u{301}"), (0x1FFE, " \u{314}"), (0x2017, " \u{333}"),
+    (0x2025, ".."), (0x2026, "..."), (0x2033, "\u{2032}\u{2032}"),
+    (0x2034, "\u{2032}\u{2032}\u{2032}"), (0x2036, "\u{2035}\u{2035}"),
+    (0x2037, "\u{2035}\u{2035}\u{2035}"), (0x203C, "!!"), (0x203E, " \u{305}"), (0x2047, "??"),
+    (0x2048, "?!"), (0x2049, "!?"), (0x2057, "\u{2032}\u{2032}\u{2032}\u{2032}"), (0x20A8, "rs"),
+    (0x2100, "a/c"), (0x2101, "a/s"), (0x2103, "\u{b0}c"), (0x2105, "c/o"), (0x2106, "c/u"),
+    (0x2109, "\u{b0}f"), (0x2116, "no"), (0x2120, "sm"), (0x2121, "tel"), (0x2122, "tm"),
+    (0x213B, "fax"), (0x2150, "1\u{2044}7"), (0x2151, "1\u{2044}9"), (0x2152, "1\u{2044}10"),
+    (0x2153, "1\u{2044}3"), (0x2154, "2\u{2044}3"), (0x2155, "1\u{2044}5"), (0x2156, "2\u{2044}5"),
+    (0x2157, "3\u{2044}5"), (0x2158, "4\u{2044}5"), (0x2159, "1\u{2044}6"), (0x215A, "5\u{2044}6"),
+    (0x215B, "1\u{2044}8"), (0x215C, "3\u{2044}8"), (0x215D, "5\u{2044}8"), (0x215E, "7\u{2044}8"),
+    (0x215F, "1\u{2044}"), (0x2161, "ii"), (0x2162, "iii"), (0x2163, "iv"), (0x2165, "vi"),
+    (0x2166, "vii"), (0x2167, "viii"), (0x2168, "ix"), (0x216A, "xi"), (0x216B, "xii"),
+    (0x2171, "ii"), (0x2172, "iii"), (0x2173, "iv"), (0x2175, "vi"), (0x2176, "vii"),
+    (0x2177, "viii"), (0x2178, "ix"), (0x217A, "xi"), (0x217B, "xii"), (0x2189, "0\u{2044}3"),
+    (0x222C, "\u{222b}\u{222b}"), (0x222D, "\u{222b}\u{222b}\u{222b}"),
+    (0x222F, "\u{222e}\u{222e}"), (0x2230, "\u{222e}\u{222e}\u{222e}"), (0x2469, "10"),
+    (0x246A, "11"), (0x246B, "12"), (0x246C, "13"), (0x246D, "14"), (0x246E, "15"), (0x246F, "16"),
+    (0x2470, "17"), (0x2471, "18"), (0x2472, "19"), (0x2473, "20"), (0x2474, "(1)"),
+    (0x2475, "(2)"), (0x2476, "(3)"), (0x2477, "(4)"), (0x2478, "(5)"), (0x2479, "(6)"),
+    (0x247A, "(7)"), (0x247B, "(8)"), (0x247C, "(9)"), (0x247D, "(10)"), (0x247E, "(11)"),
+    (0x247F, "(12)"), (0x2480, "(13)"), (0x2481, "(14)"), (0x2482, "(15)"), (0x2483, "(16)"),
+    (0x2484, "(17)"), (0x2485, "(18)"), (0x2486, "(19)"), (0x2487, "(20)"), (0x2488, "1."),
+    (0x2489, "2."), (0x248A, "3."), (0x248B, "4."), (0x248C, "5."), (0x248D, "6."), (0x248E, "7."),
+    (0x248F, "8."), (0x2490, "9."), (0x2491, "10."), (0x2492, "11."), (0x2493, "12."),
+    (0x2494, "13."), (0x2495, "14."), (0x2496, "15."), (0x2497, "16."), (0x2498, "17."),
+    (0x2499, "18."), (0x249A, "19."), (0x249B, "20."), (0x249C, "(a)"), (0x249D, "(b)"),
+    (0x249E, "(c)"), (0x249F, "(d)"), (0x24A0, "(e)"), (0x24A1, "(f)"), (0x24A2, "(g)"),
+    (0x24A3, "(h)"), (0x24A4, "(i)"), (0x24A5, "(j)"), (0x24A6, "(k)"), (0x24A7, "(l)"),
+    (0x24A8, "(m)"), (0x24A9, "(n)"), (0x24AA, "(o)"), (0x24AB, "(p)"), (0x24AC, "(q)"),
+    (0x24AD, "(r)"), (0x24AE, "(s)"), (0x24AF, "(t)"), (0x24B0, "(u)"), (0x24B1, "(v)"),
+    (0x24B2, "(w)"), (0x24B3, "(x)"), (0x24B4, "(y)"), (0x24B5, "(z)"),
+    (0x2A0C, "\u{222b}\u{222b}\u{222b}\u{222b}"), (0x2A74, "::="), (0x2A75, "=="), (0x2A76, "==="),
+    (0x2ADC, "\u{2add}\u{338}"), (0x309B, " \u{3099}"), (0x309C, " \u{309a}"),
+    (0x309F, "\u{3088}\u{308a}"), (0x30FF, "\u{30b3}\u{30c8}"), (0x3200, "(\u{1100})"),
+    (0x3201, "(\u{1102})"), (0x3202, "(\u{1103})"), (0x3203, "(\u{1105})"), (0x3204, "(\u{1106})"),
+    (0x3205, "(\u{1107})"), (0x3206, "(\u{1109})"), (0x3207, "(\u{110b})"), (0x3208, "(\u{110c})"),
+    (0x3209, "(\u{110e})"), (0x320A, "(\u{110f})"), (0x320B, "(\u{1110})"), (0x320C, "(\u{1111})"),
+    (0x320D, "(\u{1112})"), (0x320E, "(\u{ac00})"), (0x320F, "(\u{b098})"), (0x3210, "(\u{b2e4})"),
+    (0x3211, "(\u{b77c})"), (0x3212, "(\u{b9c8})"), (0x3213, "(\u{bc14})"), (0x3214, "(\u{c0ac})"),
+    (0x3215, "(\u{c544})"), (0x3216, "(\u{c790})"), (0x3217, "(\u{cc28})"), (0x3218, "(\u{ce74})"),
+    (0x3219, "(\u{d0c0})"), (0x321A, "(\u{d30c})"), (0x321B, "(\u{d558})"), (0x321C, "(\u{c8fc})"),
+    (0x321D, "(\u{c624}\u{c804})"), (0x321E, "(\u{c624}\u{d6c4})"), (0x3220, "(\u{4e00})"),
+    (0x3221, "(\u{4e8c})"), (0x3222, "(\u{4e09})"), (0x3223, "(\u{56db})"), (0x3224, "(\u{4e94})"),
+    (0x3225, "(\u{516d})"), (0x3226, "(\u{4e03})"), (0x3227, "(\u{516b})"), (0x3228, "(\u{4e5d})"),
+    (0x3229, "(\u{5341})"), (0x322A, "(\u{6708})"), (0x322B, "(\u{706b})"), (0x322C, "(\u{6c34})"),
+    (0x322D, "(\u{6728})"), (0x322E, "(\u{91d1})"), (0x322F, "(\u{571f})"), (0x3230, "(\u{65e5})"),
+    (0x3231, "(\u{682a})"), (0x3232, "(\u{6709})"), (0x3233, "(\u{793e})"), (0x3234, "(\u{540d})"),
+    (0x3235, "(\u{7279})"), (0x3236, "(\u{8ca1})"), (0x3237, "(\u{795d})"), (0x3238, "(\u{52b4})"),
+    (0x3239, "(\u{4ee3})"), (0x323A, "(\u{547c})"), (0x323B, "(\u{5b66})"), (0x323C, "(\u{76e3})"),
+    (0x323D, "(\u{4f01})"), (0x323E, "(\u{8cc7})"), (0x323F, "(\u{5354})"), (0x3240, "(\u{796d})"),
+    (0x3241, "(\u{4f11})"), (0x3242, "(\u{81ea})"), (0x3243, "(\u{81f3})"), (0x3250, "pte"),
+    (0x3251, "21"), (0x3252, "22"), (0x3253, "23"), (0x3254, "24"), (0x3255, "25"), (0x3256, "26"),
+    (0x3257, "27"), (0x3258, "28"), (0x3259, "29"), (0x325A, "30"), (0x325B, "31"), (0x325C, "32"),
+    (0x325D, "33"), (0x325E, "34"), (0x325F, "35"), (0x327C, "\u{cc38}\u{ace0}"),
+    (0x327D, "\u{c8fc}\u{c758}"), (0x32B1, "36"), (0x32B2, "37"), (0x32B3, "38"), (0x32B4, "39"),
+    (0x32B5, "40"), (0x32B6, "41"), (0x32B7, "42"), (0x32B8, "43"), (0x32B9, "44"), (0x32BA, "45"),
+    (0x32BB, "46"), (0x32BC, "47"), (0x32BD, "48"), (0x32BE, "49"), (0x32BF, "50"),
+    (0x32C0, "1\u{6708}"), (0x32C1, "2\u{6708}"), (0x32C2, "3\u{6708}"), (0x32C3, "4\u{6708}"),
+    (0x32C4, "5\u{6708}"), (0x32C5, "6\u{6708}"), (0x32C6, "7\u{6708}"), (0x32C7, "8\u{6708}"),
+    (0x32C8, "9\u{6708}"), (0x32C9, "10\u{6708}"), (0x32CA, "11\u{6708}"), (0x32CB, "12\u{6708}"),
+    (0x32CC, "hg"), (0x32CD, "erg"), (0x32CE, "ev"), (0x32CF, "ltd"), (0x32FF, "\u{4ee4}\u{548c}"),
+    (0x3300, "\u{30a2}\u{30d1}\u{30fc}\u{30c8}"), (0x3301, "\u{30a2}\u{30eb}\u{30d5}\u{30a1}"),
+    (0x3302, "\u{30a2}\u{30f3}\u{30da}\u{30a2}"), (0x3303, "\u{30a2}\u{30fc}\u{30eb}"),
+    (0x3304, "\u{30a4}\u{30cb}\u{30f3}\u{30b0}"), (0x3305, "\u{30a4}\u{30f3}\u{30c1}"),
+    (0x3306, "\u{30a6}\u{30a9}\u{30f3}"), (0x3307, "\u{30a8}\u{30b9}\u{30af}\u{30fc}\u{30c9}"),
+    (0x3308, "\u{30a8}\u{30fc}\u{30ab}\u{30fc}"), (0x3309, "\u{30aa}\u{30f3}\u{30b9}"),
+    (0x330A, "\u{30aa}\u{30fc}\u{30e0}"), (0x330B, "\u{30ab}\u{30a4}\u{30ea}"),
+    (0x330C, "\u{30ab}\u{30e9}\u{30c3}\u{30c8}"), (0x330D, "\u{30ab}\u{30ed}\u{30ea}\u{30fc}"),
+    (0x330E, "\u{30ac}\u{30ed}\u{30f3}"), (0x330F, "\u{30ac}\u{30f3}\u{30de}"),
+    (0x3310, "\u{30ae}\u{30ac}"), (0x3311, "\u{30ae}\u{30cb}\u{30fc}"),
+    (0x3312, "\u{30ad}\u{30e5}\u{30ea}\u{30fc}"), (0x3313, "\u{30ae}\u{30eb}\u{30c0}\u{30fc}"),
+    (0x3314, "\u{30ad}\u{30ed}"), (0x3315, "\u{30ad}\u{30ed}\u{30b0}\u{30e9}\u{30e0}"),
+    (0x3316, "\u{30ad}\u{30ed}\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    (0x3317, "\u{30ad}\u{30ed}\u{30ef}\u{30c3}\u{30c8}"), (0x3318, "\u{30b0}\u{30e9}\u{30e0}"),
+    (0x3319, "\u{30b0}\u{30e9}\u{30e0}\u{30c8}\u{30f3}"),
+    (0x331A, "\u{30af}\u{30eb}\u{30bc}\u{30a4}\u{30ed}"),
+    (0x331B, "\u{30af}\u{30ed}\u{30fc}\u{30cd}"), (0x331C, "\u{30b1}\u{30fc}\u{30b9}"),
+    (0x331D, "\u{30b3}\u{30eb}\u{30ca}"), (0x331E, "\u{30b3}\u{30fc}\u{30dd}"),
+    (0x331F, "\u{30b5}\u{30a4}\u{30af}\u{30eb}"),
+    (0x3320, "\u{30b5}\u{30f3}\u{30c1}\u{30fc}\u{30e0}"),
+    (0x3321, "\u{30b7}\u{30ea}\u{30f3}\u{30b0}"), (0x3322, "\u{30bb}\u{30f3}\u{30c1}"),
+    (0x3323, "\u{30bb}\u{30f3}\u{30c8}"), (0x3324, "\u{30c0}\u{30fc}\u{30b9}"),
+    (0x3325, "\u{30c7}\u{30b7}"), (0x3326, "\u{30c9}\u{30eb}"), (0x3327, "\u{30c8}\u{30f3}"),
+    (0x3328, "\u{30ca}\u{30ce}"), (0x3329, "\u{30ce}\u{30c3}\u{30c8}"),
+    (0x332A, "\u{30cf}\u{30a4}\u{30c4}"), (0x332B, "\u{30d1}\u{30fc}\u{30bb}\u{30f3}\u{30c8}"),
+    (0x332C, "\u{30d1}\u{30fc}\u{30c4}"), (0x332D, "\u{30d0}\u{30fc}\u{30ec}\u{30eb}"),
+    (0x332E, "\u{30d4}\u{30a2}\u{30b9}\u{30c8}\u{30eb}"), (0x332F, "\u{30d4}\u{30af}\u{30eb}"),
+    (0x3330, "\u{30d4}\u{30b3}"), (0x3331, "\u{30d3}\u{30eb}"),
+    (0x3332, "\u{30d5}\u{30a1}\u{30e9}\u{30c3}\u{30c9}"),
+    (0x3333, "\u{30d5}\u{30a3}\u{30fc}\u{30c8}"),
+    (0x3334, "\u{30d6}\u{30c3}\u{30b7}\u{30a7}\u{30eb}"), (0x3335, "\u{30d5}\u{30e9}\u{30f3}"),
+    (0x3336, "\u{30d8}\u{30af}\u{30bf}\u{30fc}\u{30eb}"), (0x3337, "\u{30da}\u{30bd}"),
+    (0x3338, "\u{30da}\u{30cb}\u{30d2}"), (0x3339, "\u{30d8}\u{30eb}\u{30c4}"),
+    (0x333A, "\u{30da}\u{30f3}\u{30b9}"), (0x333B, "\u{30da}\u{30fc}\u{30b8}"),
+    (0x333C, "\u{30d9}\u{30fc}\u{30bf}"), (0x333D, "\u{30dd}\u{30a4}\u{30f3}\u{30c8}"),
+    (0x333E, "\u{30dc}\u{30eb}\u{30c8}"), (0x333F, "\u{30db}\u{30f3}"),
+    (0x3340, "\u{30dd}\u{30f3}\u{30c9}"), (0x3341, "\u{30db}\u{30fc}\u{30eb}"),
+    (0x3342, "\u{30db}\u{30fc}\u{30f3}"), (0x3343, "\u{30de}\u{30a4}\u{30af}\u{30ed}"),
+    (0x3344, "\u{30de}\u{30a4}\u{30eb}"), (0x3345, "\u{30de}\u{30c3}\u{30cf}"),
+    (0x3346, "\u{30de}\u{30eb}\u{30af}"), (0x3347, "\u{30de}\u{30f3}\u{30b7}\u{30e7}\u{30f3}"),
+    (0x3348, "\u{30df}\u{30af}\u{30ed}\u{30f3}"), (0x3349, "\u{30df}\u{30ea}"),
+    (0x334A, "\u{30df}\u{30ea}\u{30d0}\u{30fc}\u{30eb}"), (0x334B, "\u{30e1}\u{30ac}"),
+    (0x334C, "\u{30e1}\u{30ac}\u{30c8}\u{30f3}"), (0x334D, "\u{30e1}\u{30fc}\u{30c8}\u{30eb}"),
+    (0x334E, "\u{30e4}\u{30fc}\u{30c9}"), (0x334F, "\u{30e4}\u{30fc}\u{30eb}"),
+    (0x3350, "\u{30e6}\u{30a2}\u{30f3}"), (0x3351, "\u{30ea}\u{30c3}\u{30c8}\u{30eb}"),
+    (0x3352, "\u{30ea}\u{30e9}"), (0x3353, "\u{30eb}\u{30d4}\u{30fc}"),
+    (0x3354, "\u{30eb}\u{30fc}\u{30d6}\u{30eb}"), (0x3355, "\u{30ec}\u{30e0}"),
+    (0x3356, "\u{30ec}\u{30f3}\u{30c8}\u{30b2}\u{30f3}"), (0x3357, "\u{30ef}\u{30c3}\u{30c8}"),
+    (0x3358, "0\u{70b9}"), (0x3359, "1\u{70b9}"), (0x335A, "2\u{70b9}"), (0x335B, "3\u{70b9}"),
+    (0x335C, "4\u{70b9}"), (0x335D, "5\u{70b9}"), (0x335E, "6\u{70b9}"), (0x335F, "7\u{70b9}"),
+    (0x3360, "8\u{70b9}"), (0x3361, "9\u{70b9}"), (0x3362, "10\u{70b9}"), (0x3363, "11\u{70b9}"),
+    (0x3364, "12\u{70b9}"), (0x3365, "13\u{70b9}"), (0x3366, "14\u{70b9}"), (0x3367, "15\u{70b9}"),
+    (0x3368, "16\u{70b9}"), (0x3369, "17\u{70b9}"), (0x336A, "18\u{70b9}"), (0x336B, "19\u{70b9}"),
+    (0x336C, "20\u{70b9}"), (0x336D, "21\u{70b9}"), (0x336E, "22\u{70b9}"), (0x336F, "23\u{70b9}"),
+    (0x3370, "24\u{70b9}"), (0x3371, "hpa"), (0x3372, "da"), (0x3373, "au"), (0x3374, "bar"),
+    (0x3375, "ov"), (0x3376, "pc"), (0x3377, "dm"), (0x3378, "dm2"), (0x3379, "dm3"),
+    (0x337A, "iu"), (0x337B, "\u{5e73}\u{6210}"), (0x337C, "\u{662d}\u{548c}"),
+    (0x337D, "\u{5927}\u{6b63}"), (0x337E, "\u{660e}\u{6cbb}"),
+    (0x337F, "\u{682a}\u{5f0f}\u{4f1a}\u{793e}"), (0x3380, "pa"), (0x3381, "na"),
+    (0x3382, "\u{3bc}a"), (0x3383, "ma"), (0x3384, "ka"), (0x3385, "kb"), (0x3386, "mb"),
+    (0x3387, "gb"), (0x3388, "cal"), (0x3389, "kcal"), (0x338A, "pf"), (0x338B, "nf"),
+    (0x338C, "\u{3bc}f"), (0x338D, "\u{3bc}g"), (0x338E, "mg"), (0x338F, "kg"), (0x3390, "hz"),
+    (0x3391, "khz"), (0x3392, "mhz"), (0x3393, "ghz"), (0x3394, "thz"), (0x3395, "\u{3bc}l"),
+    (0x3396, "ml"), (0x3397, "dl"), (0x3398, "kl"), (0x3399, "fm"), (0x339A, "nm"),
+    (0x339B, "\u{3bc}m"), (0x339C, "mm"), (0x339D, "cm"), (0x339E, "km"), (0x339F, "mm2"),
+    (0x33A0, "cm2"), (0x33A1, "m2"), (0x33A2, "km2"), (0x33A3, "mm3"), (0x33A4, "cm3"),
+    (0x33A5, "m3"), (0x33A6, "km3"), (0x33A7, "m\u{2215}s"), (0x33A8, "m\u{2215}s2"),
+    (0x33A9, "pa"), (0x33AA, "kpa"), (0x33AB, "mpa"), (0x33AC, "gpa"), (0x33AD, "rad"),
+    (0x33AE, "rad\u{2215}s"), (0x33AF, "rad\u{2215}s2"), (0x33B0, "ps"), (0x33B1, "ns"),
+    (0x33B2, "\u{3bc}s"), (0x33B3, "ms"), (0x33B4, "pv"), (0x33B5, "nv"), (0x33B6, "\u{3bc}v"),
+    (0x33B7, "mv"), (0x33B8, "kv"), (0x33B9, "mv"), (0x33BA, "pw"), (0x33BB, "nw"),
+    (0x33BC, "\u{3bc}w"), (0x33BD, "mw"), (0x33BE, "kw"), (0x33BF, "mw"), (0x33C0, "k\u{3c9}"),
+    (0x33C1, "m\u{3c9}"), (0x33C2, "a.m."), (0x33C3, "bq"), (0x33C4, "cc"), (0x33C5, "cd"),
+    (0x33C6, "c\u{2215}kg"), (0x33C7, "co."), (0x33C8, "db"), (0x33C9, "gy"), (0x33CA, "ha"),
+    (0x33CB, "hp"), (0x33CC, "in"), (0x33CD, "kk"), (0x33CE, "km"), (0x33CF, "kt"), (0x33D0, "lm"),
+    (0x33D1, "ln"), (0x33D2, "log"), (0x33D3, "lx"), (0x33D4, "mb"), (0x33D5, "mil"),
+    (0x33D6, "mol"), (0x33D7, "ph"), (0x33D8, "p.m."), (0x33D9, "ppm"), (0x33DA, "pr"),
+    (0x33DB, "sr"), (0x33DC, "sv"), (0x33DD, "wb"), (0x33DE, "v\u{2215}m"), (0x33DF, "a\u{2215}m"),
+    (0x33E0, "1\u{65e5}"), (0x33E1, "2\u{65e5}"), (0x33E2, "3\u{65e5}"), (0x33E3, "4\u{65e5}"),
+    (0x33E4, "5\u{65e5}"), (0x33E5, "6\u{65e5}"), (0x33E6, "7\u{65e5}"), (0x33E7, "8\u{65e5}"),
+    (0x33E8, "9\u{65e5}"), (0x33E9, "10\u{65e5}"), (0x33EA, "11\u{65e5}"), (0x33EB, "12\u{65e5}"),
+    (0x33EC, "13\u{65e5}"), (0x33ED, "14\u{65e5}"), (0x33EE, "15\u{65e5}"), (0x33EF, "16\u{65e5}"),
+    (0x33F0, "17\u{65e5}"), (0x33F1, "18\u{65e5}"), (0x33F2, "19\u{65e5}"), (0x33F3, "20\u{65e5}"),
+    (0x33F4, "21\u{65e5}"), (0x33F5, "22\u{65e5}"), (0x33F6, "23\u{65e5}"), (0x33F7, "24\u{65e5}"),
+    (0x33F8, "25\u{65e5}"), (0x33F9, "26\u{65e5}"), (0x33FA, "27\u{65e5}"), (0x33FB, "28\u{65e5}"),
+    (0x33FC, "29\u{65e5}"), (0x33FD, "30\u{65e5}"), (0x33FE, "31\u{65e5}"), (0x33FF, "gal"),
+    (0xFB00, "ff"), (0xFB01, "fi"), (0xFB02, "fl"), (0xFB03, "ffi"), (0xFB04, "ffl"),
+    (0xFB05, "st"), (0xFB06, "st"), (0xFB13, "\u{574}\u{576}"), (0xFB14, "\u{574}\u{565}"),
+    (0xFB15, "\u{574}\u{56b}"), (0xFB16, "\u{57e}\u{576}"), (0xFB17, "\u{574}\u{56d}"),
+    (0xFB1D, "\u{5d9}\u{5b4}"), (0xFB1F, "\u{5f2}\u{5b7}"), (0xFB2A, "\u{5e9}\u{5c1}"),
+    (0xFB2B, "\u{5e9}\u{5c2}"), (0xFB2C, "\u{5e9}\u{5bc}\u{5c1}"),
+    (0xFB2D, "\u{5e9}\u{5bc}\u{5c2}"), (0xFB2E, "\u{5d0}\u{5b7}"), (0xFB2F, "\u{5d0}\u{5b8}"),
+    (0xFB30, "\u{5d0}\u{5bc}"), (0xFB31, "\u{5d1}\u{5bc}"), (0xFB32, "\u{5d2}\u{5bc}"),
+    (0xFB33, "\u{5d3}\u{5bc}"), (0xFB34, "\u{5d4}\u{5bc}"), (0xFB35, "\u{5d5}\u{5bc}"),
+    (0xFB36, "\u{5d6}\u{5bc}"), (0xFB38, "\u{5d8}\u{5bc}"), (0xFB39, "\u{5d9}\u{5bc}"),
+    (0xFB3A, "\u{5da}\u{5bc}"), (0xFB3B, "\u{5db}\u{5bc}"), (0xFB3C, "\u{5dc}\u{5bc}"),
+    (0xFB3E, "\u{5de}\u{5bc}"), (0xFB40, "\u{5e0}\u{5bc}"), (0xFB41, "\u{5e1}\u{5bc}"),
+    (0xFB43, "\u{5e3}\u{5bc}"), (0xFB44, "\u{5e4}\u{5bc}"), (0xFB46, "\u{5e6}\u{5bc}"),
+    (0xFB47, "\u{5e7}\u{5bc}"), (0xFB48, "\u{5e8}\u{5bc}"), (0xFB49, "\u{5e9}\u{5bc}"),
+    (0xFB4A, "\u{5ea}\u{5bc}"), (0xFB4B, "\u{5d5}\u{5b9}"), (0xFB4C, "\u{5d1}\u{5bf}"),
+    (0xFB4D, "\u{5db}\u{5bf}"), (0xFB4E, "\u{5e4}\u{5bf}"), (0xFB4F, "\u{5d0}\u{5dc}"),
+    (0xFBDD, "\u{6c7}\u{674}"), (0xFBEA, "\u{626}\u{627}"), (0xFBEB, "\u{626}\u{627}"),
+    (0xFBEC, "\u{626}\u{6d5}"), (0xFBED, "\u{626}\u{6d5}"), (0xFBEE, "\u{626}\u{648}"),
+    (0xFBEF, "\u{626}\u{648}"), (0xFBF0, "\u{626}\u{6c7}"), (0xFBF1, "\u{626}\u{6c7}"),
+    (0xFBF2, "\u{626}\u{6c6}"), (0xFBF3, "\u{626}\u{6c6}"), (0xFBF4, "\u{626}\u{6c8}"),
+    (0xFBF5, "\u{626}\u{6c8}"), (0xFBF6, "\u{626}\u{6d0}"), (0xFBF7, "\u{626}\u{6d0}"),
+    (0xFBF8, "\u{626}\u{6d0}"), (0xFBF9, "\u{626}\u{649}"), (0xFBFA, "\u{626}\u{649}"),
+    (0xFBFB, "\u{626}\u{649}"), (0xFC00, "\u{626}\u{62c}"), (0xFC01, "\u{626}\u{62d}"),
+    (0xFC02, "\u{626}\u{645}"), (0xFC03, "\u{626}\u{649}"), (0xFC04, "\u{626}\u{64a}"),
+    (0xFC05, "\u{628}\u{62c}"), (0xFC06, "\u{628}\u{62d}"), (0xFC07, "\u{628}\u{62e}"),
+    (0xFC08, "\u{628}\u{645}"), (0xFC09, "\u{628}\u{649}"), (0xFC0A, "\u{628}\u{64a}"),
+    (0xFC0B, "\u{62a}\u{62c}"), (0xFC0C, "\u{62a}\u{62d}"), (0xFC0D, "\u{62a}\u{62e}"),
+    (0xFC0E, "\u{62a}\u{645}"), (0xFC0F, "\u{62a}\u{649}"), (0xFC10, "\u{62a}\u{64a}"),
+    (0xFC11, "\u{62b}\u{62c}"), (0xFC12, "\u{62b}\u{645}"), (0xFC13, "\u{62b}\u{649}"),
+    (0xFC14, "\u{62b}\u{64a}"), (0xFC15, "\u{62c}\u{62d}"), (0xFC16, "\u{62c}\u{645}"),
+    (0xFC17, "\u{62d}\u{62c}"), (0xFC18, "\u{62d}\u{645}"), (0xFC19, "\u{62e}\u{62c}"),
+    (0xFC1A, "\u{62e}\u{62d}"), (0xFC1B, "\u{62e}\u{645}"), (0xFC1C, "\u{633}\u{62c}"),
+    (0xFC1D, "\u{633}\u{62d}"), (0xFC1E, "\u{633}\u{62e}"), (0xFC1F, "\u{633}\u{645}"),
+    (0xFC20, "\u{635}\u{62d}"), (0xFC21, "\u{635}\u{645}"), (0xFC22, "\u{636}\u{62c}"),
+    (0xFC23, "\u{636}\u{62d}"), (0xFC24, "\u{636}\u{62e}"), (0xFC25, "\u{636}\u{645}"),
+    (0xFC26, "\u{637}\u{62d}"), (0xFC27, "\u{637}\u{645}"), (0xFC28, "\u{638}\u{645}"),
+    (0xFC29, "\u{639}\u{62c}"), (0xFC2A, "\u{639}\u{645}"), (0xFC2B, "\u{63a}\u{62c}"),
+    (0xFC2C, "\u{63a}\u{645}"), (0xFC2D, "\u{641}\u{62c}"), (0xFC2E, "\u{641}\u{62d}"),
+    (0xFC2F, "\u{641}\u{62e}"), (0xFC30, "\u{641}\u{645}"), (0xFC31, "\u{641}\u{649}"),
+    (0xFC32, "\u{641}\u{64a}"), (0xFC33, "\u{642}\u{62d}"), (0xFC34, "\u{642}\u{645}"),
+    (0xFC35, "\u{642}\u{649}"), (0xFC36, "\u{642}\u{64a}"), (0xFC37, "\u{643}\u{627}"),
+    (0xFC38, "\u{643}\u{62c}"), (0xFC39, "\u{643}\u{62d}"), (0xFC3A, "\u{643}\u{62e}"),
+    (0xFC3B, "\u{643}\u{644}"), (0xFC3C, "\u{643}\u{645}"), (0xFC3D, "\u{643}\u{649}"),
+    (0xFC3E, "\u{643}\u{64a}"), (0xFC3F, "\u{644}\u{62c}"), (0xFC40, "\u{644}\u{62d}"),
+    (0xFC41, "\u{644}\u{62e}"), (0xFC42, "\u{644}\u{645}"), (0xFC43, "\u{644}\u{649}"),
+    (0xFC44, "\u{644}\u{64a}"), (0xFC45, "\u{645}\u{62c}"), (0xFC46, "\u{645}\u{62d}"),
+    (0xFC47, "\u{645}\u{62e}"), (0xFC48, "\u{645}\u{645}"), (0xFC49, "\u{645}\u{649}"),
+    (0xFC4A, "\u{645}\u{64a}"), (0xFC4B, "\u{646}\u{62c}"), (0xFC4C, "\u{646}\u{62d}"),
+    (0xFC4D, "\u{646}\u{62e}"), (0xFC4E, "\u{646}\u{645}"), (0xFC4F, "\u{646}\u{649}"),
+    (0xFC50, "\u{646}\u{64a}"), (0xFC51, "\u{647}\u{62c}"), (0xFC52, "\u{647}\u{645}"),
+    (0xFC53, "\u{647}\u{649}"), (0xFC54, "\u{647}\u{64a}"), (0xFC55, "\u{64a}\u{62c}"),
+    (0xFC56, "\u{64a}\u{62d}"), (0xFC57, "\u{64a}\u{62e}"), (0xFC58, "\u{64a}\u{645}"),
+    (0xFC59, "\u{64a}\u{649}"), (0xFC5A, "\u{64a}\u{64a}"), (0xFC5B, "\u{630}\u{670}"),
+    (0xFC5C, "\u{631}\u{670}"), (0xFC5D, "\u{649}\u{670}"), (0xFC5E, " \u{64c}\u{651}"),
+    (0xFC5F, " \u{64d}\u{651}"), (0xFC60, " \u{64e}\u{651}"), (0xFC61, " \u{64f}\u{651}"),
+    (0xFC62, " \u{650}\u{651}"), (0xFC63, " \u{651}\u{670}"), (0xFC64, "\u{626}\u{631}"),
+    (0xFC65, "\u{626}\u{632}"), (0xFC66, "\u{626}\u{645}"), (0xFC67, "\u{626}\u{646}"),
+    (0xFC68, "\u{626}\u{649}"), (0xFC69, "\u{626}\u{64a}"), (0xFC6A, "\u{628}\u{631}"),
+    (0xFC6B, "\u{628}\u{632}"), (0xFC6C, "\u{628}\u{645}"), (0xFC6D, "\u{628}\u{646}"),
+    (0xFC6E, "\u{628}\u{649}"), (0xFC6F, "\u{628}\u{64a}"), (0xFC70, "\u{62a}\u{631}"),
+    (0xFC71, "\u{62a}\u{632}"), (0xFC72, "\u{62a}\u{645}"), (0xFC73, "\u{62a}\u{646}"),
+    (0xFC74, "\u{62a}\u{649}"), (0xFC75, "\u{62a}\u{64a}"), (0xFC76, "\u{62b}\u{631}"),
+    (0xFC77, "\u{62b}\u{632}"), (0xFC78, "\u{62b}\u{645}"), (0xFC79, "\u{62b}\u{646}"),
+    (0xFC7A, "\u{62b}\u{649}"), (0xFC7B, "\u{62b}\u{64a}"), (0xFC7C, "\u{641}\u{649}"),
+    (0xFC7D, "\u{641}\u{64a}"), (0xFC7E, "\u{642}\u{649}"), (0xFC7F, "\u{642}\u{64a}"),
+    (0xFC80, "\u{643}\u{627}"), (0xFC81, "\u{643}\u{644}"), (0xFC82, "\u{643}\u{645}"),
+    (0xFC83, "\u{643}\u{649}"), (0xFC84, "\u{643}\u{64a}"), (0xFC85, "\u{644}\u{645}"),
+    (0xFC86, "\u{644}\u{649}"), (0xFC87, "\u{644}\u{64a}"), (0xFC88, "\u{645}\u{627}"),
+    (0xFC89, "\u{645}\u{645}"), (0xFC8A, "\u{646}\u{631}"), (0xFC8B, "\u{646}\u{632}"),
+    (0xFC8C, "\u{646}\u{645}"), (0xFC8D, "\u{646}\u{646}"), (0xFC8E, "\u{646}\u{649}"),
+    (0xFC8F, "\u{646}\u{64a}"), (0xFC90, "\u{649}\u{670}"), (0xFC91, "\u{64a}\u{631}"),
+    (0xFC92, "\u{64a}\u{632}"), (0xFC93, "\u{64a}\u{645}"), (0xFC94, "\u{64a}\u{646}"),
+    (0xFC95, "\u{64a}\u{649}"), (0xFC96, "\u{64a}\u{64a}"), (0xFC97, "\u{626}\u{62c}"),
+    (0xFC98, "\u{626}\u{62d}"), (0xFC99, "\u{626}\u{62e}"), (0xFC9A, "\u{626}\u{645}"),
+    (0xFC9B, "\u{626}\u{647}"), (0xFC9C, "\u{628}\u{62c}"), (0xFC9D, "\u{628}\u{62d}"),
+    (0xFC9E, "\u{628}\u{62e}"), (0xFC9F, "\u{628}\u{645}"), (0xFCA0, "\u{628}\u{647}"),
+    (0xFCA1, "\u{62a}\u{62c}"), (0xFCA2, "\u{62a}\u{62d}"), (0xFCA3, "\u{62a}\u{62e}"),
+    (0xFCA4, "\u{62a}\u{645}"), (0xFCA5, "\u{62a}\u{647}"), (0xFCA6, "\u{62b}\u{645}"),
+    (0xFCA7, "\u{62c}\u{62d}"), (0xFCA8, "\u{62c}\u{645}"), (0xFCA9, "\u{62d}\u{62c}"),
+    (0xFCAA, "\u{62d}\u{645}"), (0xFCAB, "\u{62e}\u{62c}"), (0xFCAC, "\u{62e}\u{645}"),
+    (0xFCAD, "\u{633}\u{62c}"), (0xFCAE, "\u{633}\u{62d}"), (0xFCAF, "\u{633}\u{62e}"),
+    (0xFCB0, "\u{633}\u{645}"), (0xFCB1, "\u{635}\u{62d}"), (0xFCB2, "\u{635}\u{62e}"),
+    (0xFCB3, "\u{635}\u{645}"), (0xFCB4, "\u{636}\u{62c}"), (0xFCB5, "\u{636}\u{62d}"),
+    (0xFCB6, "\u{636}\u{62e}"), (0xFCB7, "\u{636}\u{645}"), (0xFCB8, "\u{637}\u{62d}"),
+    (0xFCB9, "\u{638}\u{645}"), (0xFCBA, "\u{639}\u{62c}"), (0xFCBB, "\u{639}\u{645}"),
+    (0xFCBC, "\u{63a}\u{62c}"), (0xFCBD, "\u{63a}\u{645}"), (0xFCBE, "\u{641}\u{62c}"),
+    (0xFCBF, "\u{641}\u{62d}"), (0xFCC0, "\u{641}\u{62e}"), (0xFCC1, "\u{641}\u{645}"),
+    (0xFCC2, "\u{642}\u{62d}"), (0xFCC3, "\u{642}\u{645}"), (0xFCC4, "\u{643}\u{62c}"),
+    (0xFCC5, "\u{643}\u{62d}"), (0xFCC6, "\u{643}\u{62e}"), (0xFCC7, "\u{643}\u{644}"),
+    (0xFCC8, "\u{643}\u{645}"), (0xFCC9, "\u{644}\u{62c}"), (0xFCCA, "\u{644}\u{62d}"),
+    (0xFCCB, "\u{644}\u{62e}"), (0xFCCC, "\u{644}\u{645}"), (0xFCCD, "\u{644}\u{647}"),
+    (0xFCCE, "\u{645}\u{62c}"), (0xFCCF, "\u{645}\u{62d}"), (0xFCD0, "\u{645}\u{62e}"),
+    (0xFCD1, "\u{645}\u{645}"), (0xFCD2, "\u{646}\u{62c}"), (0xFCD3, "\u{646}\u{62d}"),
+    (0xFCD4, "\u{646}\u{62e}"), (0xFCD5, "\u{646}\u{645}"), (0xFCD6, "\u{646}\u{647}"),
+    (0xFCD7, "\u{647}\u{62c}"), (0xFCD8, "\u{647}\u{645}"), (0xFCD9, "\u{647}\u{670}"),
+    (0xFCDA, "\u{64a}\u{62c}"), (0xFCDB, "\u{64a}\u{62d}"), (0xFCDC, "\u{64a}\u{62e}"),
+    (0xFCDD, "\u{64a}\u{645}"), (0xFCDE, "\u{64a}\u{647}"), (0xFCDF, "\u{626}\u{645}"),
+    (0xFCE0, "\u{626}\u{647}"), (0xFCE1, "\u{628}\u{645}"), (0xFCE2, "\u{628}\u{647}"),
+    (0xFCE3, "\u{62a}\u{645}"), (0xFCE4, "\u{62a}\u{647}"), (0xFCE5, "\u{62b}\u{645}"),
+    (0xFCE6, "\u{62b}\u{647}"), (0xFCE7, "\u{633}\u{645}"), (0xFCE8, "\u{633}\u{647}"),
+    (0xFCE9, "\u{634}\u{645}"), (0xFCEA, "\u{634}\u{647}"), (0xFCEB, "\u{643}\u{644}"),
+    (0xFCEC, "\u{643}\u{645}"), (0xFCED, "\u{644}\u{645}"), (0xFCEE, "\u{646}\u{645}"),
+    (0xFCEF, "\u{646}\u{647}"), (0xFCF0, "\u{64a}\u{645}"), (0xFCF1, "\u{64a}\u{647}"),
+    (0xFCF2, "\u{640}\u{64e}\u{651}"), (0xFCF3, "\u{640}\u{64f}\u{651}"),
+    (0xFCF4, "\u{640}\u{650}\u{651}"), (0xFCF5, "\u{637}\u{649}"), (0xFCF6, "\u{637}\u{64a}"),
+    (0xFCF7, "\u{639}\u{649}"), (0xFCF8, "\u{639}\u{64a}"), (0xFCF9, "\u{63a}\u{649}"),
+    (0xFCFA, "\u{63a}\u{64a}"), (0xFCFB, "\u{633}\u{649}"), (0xFCFC, "\u{633}\u{64a}"),
+    (0xFCFD, "\u{634}\u{649}"), (0xFCFE, "\u{634}\u{64a}"), (0xFCFF, "\u{62d}\u{649}"),
+    (0xFD00, "\u{62d}\u{64a}"), (0xFD01, "\u{62c}\u{649}"), (0xFD02, "\u{62c}\u{64a}"),
+    (0xFD03, "\u{62e}\u{649}"), (0xFD04, "\u{62e}\u{64a}"), (0xFD05, "\u{635}\u{649}"),
+    (0xFD06, "\u{635}\u{64a}"), (0xFD07, "\u{636}\u{649}"), (0xFD08, "\u{636}\u{64a}"),
+    (0xFD09, "\u{634}\u{62c}"), (0xFD0A, "\u{634}\u{62d}"), (0xFD0B, "\u{634}\u{62e}"),
+    (0xFD0C, "\u{634}\u{645}"), (0xFD0D, "\u{634}\u{631}"), (0xFD0E, "\u{633}\u{631}"),
+    (0xFD0F, "\u{635}\u{631}"), (0xFD10, "\u{636}\u{631}"), (0xFD11, "\u{637}\u{649}"),
+    (0xFD12, "\u{637}\u{64a}"), (0xFD13, "\u{639}\u{649}"), (0xFD14, "\u{639}\u{64a}"),
+    (0xFD15, "\u{63a}\u{649}"), (0xFD16, "\u{63a}\u{64a}"), (0xFD17, "\u{633}\u{649}"),
+    (0xFD18, "\u{633}\u{64a}"), (0xFD19, "\u{634}\u{649}"), (0xFD1A, "\u{634}\u{64a}"),
+    (0xFD1B, "\u{62d}\u{649}"), (0xFD1C, "\u{62d}\u{64a}"), (0xFD1D, "\u{62c}\u{649}"),
+    (0xFD1E, "\u{62c}\u{64a}"), (0xFD1F, "\u{62e}\u{649}"), (0xFD20, "\u{62e}\u{64a}"),
+    (0xFD21, "\u{635}\u{649}"), (0xFD22, "\u{635}\u{64a}"), (0xFD23, "\u{636}\u{649}"),
+    (0xFD24, "\u{636}\u{64a}"), (0xFD25, "\u{634}\u{62c}"), (0xFD26, "\u{634}\u{62d}"),
+    (0xFD27, "\u{634}\u{62e}"), (0xFD28, "\u{634}\u{645}"), (0xFD29, "\u{634}\u{631}"),
+    (0xFD2A, "\u{633}\u{631}"), (0xFD2B, "\u{635}\u{631}"), (0xFD2C, "\u{636}\u{631}"),
+    (0xFD2D, "\u{634}\u{62c}"), (0xFD2E, "\u{634}\u{62d}"), (0xFD2F, "\u{634}\u{62e}"),
+    (0xFD30, "\u{634}\u{645}"), (0xFD31, "\u{633}\u{647}"), (0xFD32, "\u{634}\u{647}"),
+    (0xFD33, "\u{637}\u{645}"), (0xFD34, "\u{633}\u{62c}"), (0xFD35, "\u{633}\u{62d}"),
+    (0xFD36, "\u{633}\u{62e}"), (0xFD37, "\u{634}\u{62c}"), (0xFD38, "\u{634}\u{62d}"),
+    (0xFD39, "\u{634}\u{62e}"), (0xFD3A, "\u{637}\u{645}"), (0xFD3B, "\u{638}\u{645}"),
+    (0xFD3C, "\u{627}\u{64b}"), (0xFD3D, "\u{627}\u{64b}"), (0xFD50, "\u{62a}\u{62c}\u{645}"),
+    (0xFD51, "\u{62a}\u{62d}\u{62c}"), (0xFD52, "\u{62a}\u{62d}\u{62c}"),
+    (0xFD53, "\u{62a}\u{62d}\u{645}"), (0xFD54, "\u{62a}\u{62e}\u{645}"),
+    (0xFD55, "\u{62a}\u{645}\u{62c}"), (0xFD56, "\u{62a}\u{645}\u{62d}"),
+    (0xFD57, "\u{62a}\u{645}\u{62e}"), (0xFD58, "\u{62c}\u{645}\u{62d}"),
+    (0xFD59, "\u{62c}\u{645}\u{62d}"), (0xFD5A, "\u{62d}\u{645}\u{64a}"),
+    (0xFD5B, "\u{62d}\u{645}\u{649}"), (0xFD5C, "\u{633}\u{62d}\u{62c}"),
+    (0xFD5D, "\u{633}\u{62c}\u{62d}"), (0xFD5E, "\u{633}\u{62c}\u{649}"),
+    (0xFD5F, "\u{633}\u{645}\u{62d}"), (0xFD60, "\u{633}\u{645}\u{62d}"),
+    (0xFD61, "\u{633}\u{645}\u{62c}"), (0xFD62, "\u{633}\u{645}\u{645}"),
+    (0xFD63, "\u{633}\u{645}\u{645}"), (0xFD64, "\u{635}\u{62d}\u{62d}"),
+    (0xFD65, "\u{635}\u{62d}\u{62d}"), (0xFD66, "\u{635}\u{645}\u{645}"),
+    (0xFD67, "\u{634}\u{62d}\u{645}"), (0xFD68, "\u{634}\u{62d}\u{645}"),
+    (0xFD69, "\u{634}\u{62c}\u{64a}"), (0xFD6A, "\u{634}\u{645}\u{62e}"),
+    (0xFD6B, "\u{634}\u{645}\u{62e}"), (0xFD6C, "\u{634}\u{645}\u{645}"),
+    (0xFD6D, "\u{634}\u{645}\u{645}"), (0xFD6E, "\u{636}\u{62d}\u{649}"),
+    (0xFD6F, "\u{636}\u{62e}\u{645}"), (0xFD70, "\u{636}\u{62e}\u{645}"),
+    (0xFD71, "\u{637}\u{645}\u{62d}"), (0xFD72, "\u{637}\u{645}\u{62d}"),
+    (0xFD73, "\u{637}\u{645}\u{645}"), (0xFD74, "\u{637}\u{645}\u{64a}"),
+    (0xFD75, "\u{639}\u{62c}\u{645}"), (0xFD76, "\u{639}\u{645}\u{645}"),
+    (0xFD77, "\u{639}\u{645}\u{645}"), (0xFD78, "\u{639}\u{645}\u{649}"),
+    (0xFD79, "\u{63a}\u{645}\u{645}"), (0xFD7A, "\u{63a}\u{645}\u{64a}"),
+    (0xFD7B, "\u{63a}\u{645}\u{649}"), (0xFD7C, "\u{641}\u{62e}\u{645}"),
+    (0xFD7D, "\u{641}\u{62e}\u{645}"), (0xFD7E, "\u{642}\u{645}\u{62d}"),
+    (0xFD7F, "\u{642}\u{645}\u{645}"), (0xFD80, "\u{644}\u{62d}\u{645}"),
+    (0xFD81, "\u{644}\u{62d}\u{64a}"), (0xFD82, "\u{644}\u{62d}\u{649}"),
+    (0xFD83, "\u{644}\u{62c}\u{62c}"), (0xFD84, "\u{644}\u{62c}\u{62c}"),
+    (0xFD85, "\u{644}\u{62e}\u{645}"), (0xFD86, "\u{644}\u{62e}\u{645}"),
+    (0xFD87, "\u{644}\u{645}\u{62d}"), (0xFD88, "\u{644}\u{645}\u{62d}"),
+    (0xFD89, "\u{645}\u{62d}\u{62c}"), (0xFD8A, "\u{645}\u{62d}\u{645}"),
+    (0xFD8B, "\u{645}\u{62d}\u{64a}"), (0xFD8C, "\u{645}\u{62c}\u{62d}"),
+    (0xFD8D, "\u{645}\u{62c}\u{645}"), (0xFD8E, "\u{645}\u{62e}\u{62c}"),
+    (0xFD8F, "\u{645}\u{62e}\u{645}"), (0xFD92, "\u{645}\u{62c}\u{62e}"),
+    (0xFD93, "\u{647}\u{645}\u{62c}"), (0xFD94, "\u{647}\u{645}\u{645}"),
+    (0xFD95, "\u{646}\u{62d}\u{645}"), (0xFD96, "\u{646}\u{62d}\u{649}"),
+    (0xFD97, "\u{646}\u{62c}\u{645}"), (0xFD98, "\u{646}\u{62c}\u{645}"),
+    (0xFD99, "\u{646}\u{62c}\u{649}"), (0xFD9A, "\u{646}\u{645}\u{64a}"),
+    (0xFD9B, "\u{646}\u{645}\u{649}"), (0xFD9C, "\u{64a}\u{645}\u{645}"),
+    (0xFD9D, "\u{64a}\u{645}\u{645}"), (0xFD9E, "\u{628}\u{62e}\u{64a}"),
+    (0xFD9F, "\u{62a}\u{62c}\u{64a}"), (0xFDA0, "\u{62a}\u{62c}\u{649}"),
+    (0xFDA1, "\u{62a}\u{62e}\u{64a}"), (0xFDA2, "\u{62a}\u{62e}\u{649}"),
+    (0xFDA3, "\u{62a}\u{645}\u{64a}"), (0xFDA4, "\u{62a}\u{645}\u{649}"),
+    (0xFDA5, "\u{62c}\u{645}\u{64a}"), (0xFDA6, "\u{62c}\u{62d}\u{649}"),
+    (0xFDA7, "\u{62c}\u{645}\u{649}"), (0xFDA8, "\u{633}\u{62e}\u{649}"),
+    (0xFDA9, "\u{635}\u{62d}\u{64a}"), (0xFDAA, "\u{634}\u{62d}\u{64a}"),
+    (0xFDAB, "\u{636}\u{62d}\u{64a}"), (0xFDAC, "\u{644}\u{62c}\u{64a}"),
+    (0xFDAD, "\u{644}\u{645}\u{64a}"), (0xFDAE, "\u{64a}\u{62d}\u{64a}"),
+    (0xFDAF, "\u{64a}\u{62c}\u{64a}"), (0xFDB0, "\u{64a}\u{645}\u{64a}"),
+    (0xFDB1, "\u{645}\u{645}\u{64a}"), (0xFDB2, "\u{642}\u{645}\u{64a}"),
+    (0xFDB3, "\u{646}\u{62d}\u{64a}"), (0xFDB4, "\u{642}\u{645}\u{62d}"),
+    (0xFDB5, "\u{644}\u{62d}\u{645}"), (0xFDB6, "\u{639}\u{645}\u{64a}"),
+    (0xFDB7, "\u{643}\u{645}\u{64a}"), (0xFDB8, "\u{646}\u{62c}\u{62d}"),
+    (0xFDB9, "\u{645}\u{62e}\u{64a}"), (0xFDBA, "\u{644}\u{62c}\u{645}"),
+    (0xFDBB, "\u{643}\u{645}\u{645}"), (0xFDBC, "\u{644}\u{62c}\u{645}"),
+    (0xFDBD, "\u{646}\u{62c}\u{62d}"), (0xFDBE, "\u{62c}\u{62d}\u{64a}"),
+    (0xFDBF, "\u{62d}\u{62c}\u{64a}"), (0xFDC0, "\u{645}\u{62c}\u{64a}"),
+    (0xFDC1, "\u{641}\u{645}\u{64a}"), (0xFDC2, "\u{628}\u{62d}\u{64a}"),
+    (0xFDC3, "\u{643}\u{645}\u{645}"), (0xFDC4, "\u{639}\u{62c}\u{645}"),
+    (0xFDC5, "\u{635}\u{645}\u{645}"), (0xFDC6, "\u{633}\u{62e}\u{64a}"),
+    (0xFDC7, "\u{646}\u{62c}\u{64a}"), (0xFDF0, "\u{635}\u{644}\u{6d2}"),
+    (0xFDF1, "\u{642}\u{644}\u{6d2}"), (0xFDF2, "\u{627}\u{644}\u{644}\u{647}"),
+    (0xFDF3, "\u{627}\u{643}\u{628}\u{631}"), (0xFDF4, "\u{645}\u{62d}\u{645}\u{62f}"),
+    (0xFDF5, "\u{635}\u{644}\u{639}\u{645}"), (0xFDF6, "\u{631}\u{633}\u{648}\u{644}"),
+    (0xFDF7, "\u{639}\u{644}\u{64a}\u{647}"), (0xFDF8, "\u{648}\u{633}\u{644}\u{645}"),
+    (0xFDF9, "\u{635}\u{644}\u{649}"),
+    (0xFDFA, "\u{635}\u{644}\u{649} \u{627}\u{644}\u{644}\u{647} \u{639}\u{644}\u{64a}\u{647} \u{648}\u{633}\u{644}\u{645}"),
+    (0xFDFB, "\u{62c}\u{644} \u{62c}\u{644}\u{627}\u{644}\u{647}"),
+    (0xFDFC, "\u{631}\u{6cc}\u{627}\u{644}"), (0xFE19, "..."), (0xFE30, ".."), (0xFE49, " \u{305}"),
+    (0xFE4A, " \u{305}"), (0xFE4B, " \u{305}"), (0xFE4C, " \u{305}"), (0xFE70, " \u{64b}"),
+    (0xFE71, "\u{640}\u{64b}"), (0xFE72, " \u{64c}"), (0xFE74, " \u{64d}"), (0xFE76, " \u{64e}"),
+    (0xFE77, "\u{640}\u{64e}"), (0xFE78, " \u{64f}"), (0xFE79, "\u{640}\u{64f}"),
+    (0xFE7A, " \u{650}"), (0xFE7B, "\u{640}\u{650}"), (0xFE7C, " \u{651}"),
+    (0xFE7D, "\u{640}\u{651}"), (0xFE7E, " \u{652}"), (0xFE7F, "\u{640}\u{652}"),
+    (0xFEF5, "\u{644}\u{622}"), (0xFEF6, "\u{644}\u{622}"), (0xFEF7, "\u{644}\u{623}"),
+    (0xFEF8, "\u{644}\u{623}"), (0xFEF9, "\u{644}\u{625}"), (0xFEFA, "\u{644}\u{625}"),
+    (0xFEFB, "\u{644}\u{627}"), (0xFEFC, "\u{644}\u{627}"), (0xFFE3, " \u{304}"),
+    (0x1_D15E, "\u{1d157}\u{1d165}"), (0x1_D15F, "\u{1d158}\u{1d165}"),
+    (0x1_D160, "\u{1d158}\u{1d165}\u{1d16e}"), (0x1_D161, "\u{1d158}\u{1d165}\u{1d16f}"),
+    (0x1_D162, "\u{1d158}\u{1d165}\u{1d170}"), (0x1_D163, "\u{1d158}\u{1d165}\u{1d171}"),
+    (0x1_D164, "\u{1d158}\u{1d165}\u{1d172}"), (0x1_D1BB, "\u{1d1b9}\u{1d165}"),
+    (0x1_D1BC, "\u{1d1ba}\u{1d165}"), (0x1_D1BD, "\u{1d1b9}\u{1d165}\u{1d16e}"),
+    (0x1_D1BE, "\u{1d1ba}\u{1d165}\u{1d16e}"), (0x1_D1BF, "\u{1d1b9}\u{1d165}\u{1d16f}"),
+    (0x1_D1C0, "\u{1d1ba}\u{1d165}\u{1d16f}"), (0x1_F100, "0."), (0x1_F101, "0,"), (0x1_F102, "1,"),
+    (0x1_F103, "2,"), (0x1_F104, "3,"), (0x1_F105, "4,"), (0x1_F106, "5,"), (0x1_F107, "6,"),
+    (0x1_F108, "7,"), (0x1_F109, "8,"), (0x1_F10A, "9,"), (0x1_F110, "(a)"), (0x1_F111, "(b)"),
+    (0x1_F112, "(c)"), (0x1_F113, "(d)"), (0x1_F114, "(e)"), (0x1_F115, "(f)"), (0x1_F116, "(g)"),
+    (0x1_F117, "(h)"), (0x1_F118, "(i)"), (0x1_F119, "(j)"), (0x1_F11A, "(k)"), (0x1_F11B, "(l)"),
+    (0x1_F11C, "(m)"), (0x1_F11D, "(n)"), (0x1_F11E, "(o)"), (0x1_F11F, "(p)"), (0x1_F120, "(q)"),
+    (0x1_F121, "(r)"), (0x1_F122, "(s)"), (0x1_F123, "(t)"), (0x1_F124, "(u)"), (0x1_F125, "(v)"),
+    (0x1_F126, "(w)"), (0x1_F127, "(x)"), (0x1_F128, "(y)"), (0x1_F129, "(z)"),
+    (0x1_F12A, "\u{3014}s\u{3015}"), (0x1_F12D, "cd"), (0x1_F12E, "wz"), (0x1_F14A, "hv"),
+    (0x1_F14B, "mv"), (0x1_F14C, "sd"), (0x1_F14D, "ss"), (0x1_F14E, "ppv"), (0x1_F14F, "wc"),
+    (0x1_F16A, "mc"), (0x1_F16B, "md"), (0x1_F16C, "mr"), (0x1_F190, "dj"),
+    (0x1_F200, "\u{307b}\u{304b}"), (0x1_F201, "\u{30b3}\u{30b3}"),
+    (0x1_F240, "\u{3014}\u{672c}\u{3015}"), (0x1_F241, "\u{3014}\u{4e09}\u{3015}"),
+    (0x1_F242, "\u{3014}\u{4e8c}\u{3015}"), (0x1_F243, "\u{3014}\u{5b89}\u{3015}"),
+    (0x1_F244, "\u{3014}\u{70b9}\u{3015}"), (0x1_F245, "\u{3014}\u{6253}\u{3015}"),
+    (0x1_F246, "\u{3014}\u{76d7}\u{3015}"), (0x1_F247, "\u{3014}\u{52dd}\u{3015}"),
+    (0x1_F248, "\u{3014}\u{6557}\u{3015}"),
+];
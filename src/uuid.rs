@@ -0,0 +1,435 @@
+//! Compact unique identifiers.
+//!
+//! A [`Ulid`] is a 128-bit identifier made of a millisecond timestamp and 80 random
+//! bits, written as 26 characters of Crockford's base32 so that identifiers sort by
+//! creation time both as values and as text. [`nanoid`] produces short random strings
+//! over any alphabet, for keys that only need to be unique.
+//!
+//! Both draw their randomness from a [`RandomNumberGenerator`], so they are only as
+//! unpredictable as the generator passed in.
+
+use alloc::string::String;
+use core::{fmt, str::FromStr};
+
+use crate::{errors::ParseError, random::RandomNumberGenerator, time::WallClock};
+
+/// The digits of Crockford's base32, which leaves out `I`, `L`, `O` and `U`.
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+const RANDOM_BITS: u32 = 80;
+
+/// The URL-safe alphabet of 64 characters used by [`nanoid`] by default.
+pub const NANOID_ALPHABET: &str =
+    "_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// The default nanoid length, which gives about as many random bits as a UUID.
+pub const NANOID_LENGTH: usize = 21;
+
+/// A universally unique lexicographically sortable identifier.
+///
+/// The top 48 bits are a Unix timestamp in milliseconds and the low 80 bits are random.
+/// Identifiers compare by timestamp first, and their text form sorts the same way.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{random::SplitMix64, uuid::Ulid};
+///
+/// let mut rng = SplitMix64::new(7);
+/// let earlier = Ulid::new(1_469_922_850_259, &mut rng);
+/// let later = Ulid::new(1_469_922_850_260, &mut rng);
+///
+/// assert!(earlier < later);
+/// assert!(earlier.to_string() < later.to_string());
+/// assert_eq!(earlier.timestamp_ms(), 1_469_922_850_259);
+///
+/// let parsed: Ulid = "01ARZ3NDEKTSV4RRFFQ69G5FAV".parse().unwrap();
+/// assert_eq!(parsed.timestamp_ms(), 1_469_922_850_259);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ulid(u128);
+
+impl Ulid {
+    /// The identifier with every bit clear.
+    pub const NIL: Self = Self(0);
+
+    /// The largest timestamp an identifier can hold.
+    pub const MAX_TIMESTAMP_MS: u64 = (1 << 48) - 1;
+
+    /// Creates an identifier for `timestamp_ms` with random bits from `rng`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp_ms` is above [`MAX_TIMESTAMP_MS`](Self::MAX_TIMESTAMP_MS).
+    #[must_use]
+    pub fn new<R: RandomNumberGenerator + ?Sized>(timestamp_ms: u64, rng: &mut R) -> Self {
+        let random = u128::from(rng.next_u64()) << 16 | u128::from(rng.next_u64() >> 48);
+        Self::from_parts(timestamp_ms, random)
+    }
+
+    /// Creates an identifier for the current time of `clock` with random bits from `rng`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use core::time::Duration;
+    ///
+    /// use libx::{random::SplitMix64, time::WallClock, uuid::Ulid};
+    ///
+    /// struct Fixed;
+    ///
+    /// impl WallClock for Fixed {
+    ///     fn since_unix_epoch(&self) -> Duration {
+    ///         Duration::from_millis(1_469_918_176_385)
+    ///     }
+    /// }
+    ///
+    /// let ulid = Ulid::now(&Fixed, &mut SplitMix64::new(1));
+    /// assert_eq!(ulid.timestamp_ms(), 1_469_918_176_385);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the clock reads after the year 10889.
+    #[must_use]
+    pub fn now<C, R>(clock: &C, rng: &mut R) -> Self
+    where
+        C: WallClock + ?Sized,
+        R: RandomNumberGenerator + ?Sized,
+    {
+        let elapsed = clock.since_unix_epoch().as_millis();
+        Self::new(u64::try_from(elapsed).unwrap_or(u64::MAX), rng)
+    }
+
+    /// Creates an identifier from a timestamp and random bits. Random bits above the low
+    /// 80 are ignored.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp_ms` is above [`MAX_TIMESTAMP_MS`](Self::MAX_TIMESTAMP_MS).
+    #[must_use]
+    pub const fn from_parts(timestamp_ms: u64, random: u128) -> Self {
+        assert!(
+            timestamp_ms <= Self::MAX_TIMESTAMP_MS,
+            "timestamp does not fit in 48 bits"
+        );
+
+        Self((timestamp_ms as u128) << RANDOM_BITS | random & ((1 << RANDOM_BITS) - 1))
+    }
+
+    /// Creates an identifier from its 128 bits.
+    #[must_use]
+    pub const fn from_bits(bits: u128) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the 128 bits of the identifier.
+    #[must_use]
+    pub const fn to_bits(self) -> u128 {
+        self.0
+    }
+
+    /// Creates an identifier from its big-endian bytes.
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self(u128::from_be_bytes(bytes))
+    }
+
+    /// Returns the big-endian bytes of the identifier.
+    #[must_use]
+    pub const fn to_bytes(self) -> [u8; 16] {
+        self.0.to_be_bytes()
+    }
+
+    /// Returns the Unix timestamp in milliseconds.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)] // 48 bits remain after the shift.
+    pub const fn timestamp_ms(self) -> u64 {
+        (self.0 >> RANDOM_BITS) as u64
+    }
+
+    /// Returns the 80 random bits.
+    #[must_use]
+    pub const fn random(self) -> u128 {
+        self.0 & ((1 << RANDOM_BITS) - 1)
+    }
+
+    /// Returns the identifier with the same timestamp and the random bits plus one, or
+    /// `None` if the random bits are all set.
+    #[must_use]
+    pub const fn increment(self) -> Option<Self> {
+        if self.random() == (1 << RANDOM_BITS) - 1 {
+            None
+        } else {
+            Some(Self(self.0 + 1))
+        }
+    }
+}
+
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut text = [0; 26];
+
+        for (index, character) in text.iter_mut().enumerate() {
+            let shift = 125 - 5 * index;
+            *character = CROCKFORD[(self.0 >> shift) as usize & 31];
+        }
+
+        // Every digit is ASCII.
+        f.write_str(core::str::from_utf8(&text).map_err(|_| fmt::Error)?)
+    }
+}
+
+impl FromStr for Ulid {
+    type Err = ParseError;
+
+    /// Parses the 26-character text form. Letters may be in either case, and `I`, `L`
+    /// and `O` are read as `1`, `1` and `0`, as Crockford's base32 allows.
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut bits = 0_u128;
+        let mut length = 0;
+
+        for (position, character) in text.char_indices() {
+            let digit = crockford_digit(character).ok_or(ParseError::InvalidCharacter {
+                character,
+                position,
+            })?;
+
+            // The first digit carries only three bits.
+            if position == 0 && digit > 7 {
+                return Err(ParseError::OutOfRange);
+            }
+
+            bits = bits << 5 | u128::from(digit);
+            length += 1;
+
+            if length > 26 {
+                return Err(ParseError::InvalidSyntax);
+            }
+        }
+
+        if length == 26 {
+            Ok(Self(bits))
+        } else {
+            Err(ParseError::InvalidSyntax)
+        }
+    }
+}
+
+fn crockford_digit(character: char) -> Option<u8> {
+    let upper = match character.to_ascii_uppercase() {
+        'I' | 'L' => '1',
+        'O' => '0',
+        other => other,
+    };
+
+    CROCKFORD
+        .iter()
+        .position(|&digit| char::from(digit) == upper)
+        .and_then(|index| u8::try_from(index).ok())
+}
+
+/// Generates identifiers that increase strictly, even within one millisecond.
+///
+/// The first identifier for each timestamp gets fresh random bits; later ones with the
+/// same or an earlier timestamp reuse the previous identifier plus one, so identifiers
+/// from one generator always sort in the order they were made.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{random::SplitMix64, uuid::UlidGenerator};
+///
+/// let mut generator = UlidGenerator::new(SplitMix64::new(1));
+/// let first = generator.generate(1_000).unwrap();
+/// let second = generator.generate(1_000).unwrap();
+///
+/// assert_eq!(second.to_bits(), first.to_bits() + 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UlidGenerator<R> {
+    rng: R,
+    last: Option<Ulid>,
+}
+
+impl<R: RandomNumberGenerator> UlidGenerator<R> {
+    /// Creates a generator drawing random bits from `rng`.
+    #[must_use]
+    pub const fn new(rng: R) -> Self {
+        Self { rng, last: None }
+    }
+
+    /// Returns the next identifier for `timestamp_ms`, or `None` if the random bits of
+    /// the current millisecond are used up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestamp_ms` is above [`Ulid::MAX_TIMESTAMP_MS`].
+    pub fn generate(&mut self, timestamp_ms: u64) -> Option<Ulid> {
+        let next = match self.last {
+            Some(last) if timestamp_ms <= last.timestamp_ms() => last.increment()?,
+            _ => Ulid::new(timestamp_ms, &mut self.rng),
+        };
+
+        self.last = Some(next);
+        Some(next)
+    }
+}
+
+/// Returns a random string of `length` characters drawn uniformly from `alphabet`.
+///
+/// # Panics
+///
+/// Panics if `alphabet` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use libx::{
+///     random::SplitMix64,
+///     uuid::{NANOID_ALPHABET, NANOID_LENGTH, nanoid, validate_nanoid},
+/// };
+///
+/// let mut rng = SplitMix64::new(3);
+/// let key = nanoid(&mut rng, NANOID_ALPHABET, NANOID_LENGTH);
+///
+/// assert_eq!(key.len(), 21);
+/// assert_eq!(validate_nanoid(&key, NANOID_ALPHABET, NANOID_LENGTH), Ok(()));
+///
+/// let pin = nanoid(&mut rng, "0123456789", 6);
+/// assert!(pin.chars().all(|c| c.is_ascii_digit()));
+/// ```
+pub fn nanoid<R: RandomNumberGenerator + ?Sized>(
+    rng: &mut R,
+    alphabet: &str,
+    length: usize,
+) -> String {
+    let size = alphabet.chars().count();
+    assert!(size > 0, "alphabet must not be empty");
+
+    (0..length)
+        .map(|_| {
+            #[allow(clippy::cast_possible_truncation)] // The index is below the size.
+            let index = rng.next_bounded(size as u64) as usize;
+            alphabet.chars().nth(index).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Checks that `text` is a nanoid of `length` characters from `alphabet`.
+///
+/// # Errors
+///
+/// Returns [`ParseError::Empty`] for empty text, [`ParseError::InvalidCharacter`] for a
+/// character outside `alphabet`, or [`ParseError::InvalidSyntax`] if the length is wrong.
+pub fn validate_nanoid(text: &str, alphabet: &str, length: usize) -> Result<(), ParseError> {
+    if text.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    if let Some((position, character)) = text
+        .char_indices()
+        .find(|&(_, character)| !alphabet.contains(character))
+    {
+        return Err(ParseError::InvalidCharacter {
+            character,
+            position,
+        });
+    }
+
+    if text.chars().count() == length {
+        Ok(())
+    } else {
+        Err(ParseError::InvalidSyntax)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::ToString, vec::Vec};
+
+    use super::*;
+    use crate::random::SplitMix64;
+
+    #[test]
+    fn test_ulid_text_round_trip() {
+        let ulid = Ulid::from_parts(1_469_922_850_259, 0x1234_5678_9abc_def0_1234);
+        let text = ulid.to_string();
+
+        assert_eq!(text.len(), 26);
+        assert_eq!(&text[..10], "01ARZ3NDEK");
+        assert_eq!(text.parse(), Ok(ulid));
+        assert_eq!(text.to_ascii_lowercase().parse(), Ok(ulid));
+        assert_eq!(ulid.random(), 0x1234_5678_9abc_def0_1234);
+        assert_eq!(Ulid::from_bytes(ulid.to_bytes()), ulid);
+
+        assert_eq!(Ulid::NIL.to_string(), "00000000000000000000000000");
+        assert_eq!("0000000000000000000000000O".parse(), Ok(Ulid::NIL));
+        assert_eq!(
+            "7ZZZZZZZZZZZZZZZZZZZZZZZZZ".parse(),
+            Ok(Ulid::from_bits(u128::MAX))
+        );
+    }
+
+    #[test]
+    fn test_ulid_parse_errors() {
+        assert_eq!("".parse::<Ulid>(), Err(ParseError::Empty));
+        assert_eq!(
+            "8ZZZZZZZZZZZZZZZZZZZZZZZZZ".parse::<Ulid>(),
+            Err(ParseError::OutOfRange)
+        );
+        assert_eq!("01ARZ3NDEK".parse::<Ulid>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "01ARZ3NDEKTSV4RRFFQ69G5FAVX".parse::<Ulid>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU".parse::<Ulid>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'U',
+                position: 25
+            })
+        );
+    }
+
+    #[test]
+    fn test_ulid_generator_is_monotonic() {
+        let mut generator = UlidGenerator::new(SplitMix64::new(9));
+        let ids: Vec<Ulid> = [5, 5, 5, 4, 6]
+            .into_iter()
+            .map(|timestamp| generator.generate(timestamp).expect("random bits left"))
+            .collect();
+
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+        assert_eq!(ids[3].timestamp_ms(), 5);
+        assert_eq!(ids[4].timestamp_ms(), 6);
+
+        let full = Ulid::from_parts(1, u128::MAX);
+        assert_eq!(full.increment(), None);
+    }
+
+    #[test]
+    fn test_nanoid() {
+        let mut rng = SplitMix64::new(11);
+        let id = nanoid(&mut rng, "ab", 64);
+
+        assert!(id.contains('a') && id.contains('b'));
+        assert_eq!(nanoid(&mut rng, NANOID_ALPHABET, 0), "");
+        assert_eq!(validate_nanoid("abba", "ab", 4), Ok(()));
+        assert_eq!(
+            validate_nanoid("abba", "ab", 5),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            validate_nanoid("abca", "ab", 4),
+            Err(ParseError::InvalidCharacter {
+                character: 'c',
+                position: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,825 @@
+//! Semantic versions and version requirements.
+//!
+//! [`Version`] follows [Semantic Versioning 2.0.0](https://semver.org): parsing is strict,
+//! and versions are ordered by semver precedence. [`VersionReq`] uses the requirement
+//! syntax popularized by Cargo: comma-separated comparators such as `>=1.2, <2`, where a
+//! bare version like `1.2` means `^1.2`.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::version::{Version, VersionReq};
+//!
+//! let version: Version = "1.4.0-rc.1".parse().unwrap();
+//! assert!(version < Version::new(1, 4, 0));
+//! assert!(version > "1.4.0-beta.11".parse().unwrap());
+//!
+//! let requirement: VersionReq = ">=1.2, <2".parse().unwrap();
+//! assert!(requirement.matches(&Version::new(1, 9, 3)));
+//! assert!(!requirement.matches(&Version::new(2, 0, 0)));
+//! ```
+
+use alloc::{string::String, vec::Vec};
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use crate::errors::ParseError;
+
+/// A semantic version: `major.minor.patch`, an optional pre-release and optional build
+/// metadata.
+///
+/// Versions are ordered by semver precedence: numerically by major, minor and patch,
+/// then with a pre-release below the release it precedes. Build metadata does not
+/// affect precedence, so [`cmp_precedence`](Self::cmp_precedence) treats `1.0.0+a` and
+/// `1.0.0+b` as equal; [`Ord`] breaks that tie by comparing the metadata as text, to
+/// stay consistent with [`Eq`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre_release: String,
+    build: String,
+}
+
+impl Version {
+    /// Creates a release version without build metadata.
+    #[must_use]
+    pub const fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre_release: String::new(),
+            build: String::new(),
+        }
+    }
+
+    /// Sets the pre-release, such as `alpha.1`; an empty string makes a release.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `pre_release` is not a dot-separated list of
+    /// non-empty alphanumeric identifiers, or has a numeric identifier with a leading
+    /// zero.
+    pub fn with_pre_release(mut self, pre_release: &str) -> Result<Self, ParseError> {
+        validate_identifiers(pre_release, 0, true)?;
+        self.pre_release = String::from(pre_release);
+        Ok(self)
+    }
+
+    /// Sets the build metadata, such as `git.1f2e3d`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] if `build` is not a dot-separated list of non-empty
+    /// alphanumeric identifiers.
+    pub fn with_build(mut self, build: &str) -> Result<Self, ParseError> {
+        validate_identifiers(build, 0, false)?;
+        self.build = String::from(build);
+        Ok(self)
+    }
+
+    /// Returns the major version.
+    #[must_use]
+    pub const fn major(&self) -> u64 {
+        self.major
+    }
+
+    /// Returns the minor version.
+    #[must_use]
+    pub const fn minor(&self) -> u64 {
+        self.minor
+    }
+
+    /// Returns the patch version.
+    #[must_use]
+    pub const fn patch(&self) -> u64 {
+        self.patch
+    }
+
+    /// Returns the pre-release, or an empty string for a release.
+    #[must_use]
+    pub fn pre_release(&self) -> &str {
+        &self.pre_release
+    }
+
+    /// Returns the build metadata, or an empty string.
+    #[must_use]
+    pub fn build(&self) -> &str {
+        &self.build
+    }
+
+    /// Returns `true` if the version has a pre-release.
+    #[must_use]
+    pub const fn is_pre_release(&self) -> bool {
+        !self.pre_release.is_empty()
+    }
+
+    /// Compares by semver precedence, ignoring build metadata.
+    #[must_use]
+    pub fn cmp_precedence(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| compare_pre_releases(&self.pre_release, &other.pre_release))
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cmp_precedence(other)
+            .then_with(|| self.build.cmp(&other.build))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+
+        if !self.pre_release.is_empty() {
+            write!(f, "-{}", self.pre_release)?;
+        }
+
+        if !self.build.is_empty() {
+            write!(f, "+{}", self.build)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Version {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (rest, build) = s.split_once('+').unwrap_or((s, ""));
+        let (core, pre_release) = rest.split_once('-').unwrap_or((rest, ""));
+
+        if s.len() > core.len() && rest.len() > core.len() {
+            validate_identifiers(pre_release, core.len() + 1, true)?;
+        }
+
+        if s.len() > rest.len() {
+            validate_identifiers(build, rest.len() + 1, false)?;
+        }
+
+        let mut numbers = core.split('.');
+        let mut position = 0;
+        let mut next_number = || {
+            let part = numbers.next().ok_or(ParseError::InvalidSyntax)?;
+            let number = parse_number(part, position)?;
+            position += part.len() + 1;
+            Ok::<_, ParseError>(number)
+        };
+
+        let version = Self {
+            major: next_number()?,
+            minor: next_number()?,
+            patch: next_number()?,
+            pre_release: String::from(pre_release),
+            build: String::from(build),
+        };
+
+        if numbers.next().is_some() {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        Ok(version)
+    }
+}
+
+/// How a [`Comparator`] relates versions to its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Op {
+    /// `=1.2.3`: the same version; missing parts match anything.
+    Exact,
+
+    /// `>1.2.3`: a later version.
+    Greater,
+
+    /// `>=1.2.3`: the same or a later version.
+    GreaterEq,
+
+    /// `<1.2.3`: an earlier version.
+    Less,
+
+    /// `<=1.2.3`: the same or an earlier version.
+    LessEq,
+
+    /// `~1.2.3`: patch updates; `~1` allows minor updates too.
+    Tilde,
+
+    /// `^1.2.3`: updates that keep the leftmost non-zero part, as in `1.x.y` or `0.2.y`.
+    Caret,
+
+    /// `1.*` or `1.2.x`: any value for the wildcard parts.
+    Wildcard,
+}
+
+/// One condition of a [`VersionReq`], such as `>=1.2`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Comparator {
+    /// How versions are compared.
+    pub op: Op,
+
+    /// The major version.
+    pub major: u64,
+
+    /// The minor version, or `None` if it was left out.
+    pub minor: Option<u64>,
+
+    /// The patch version, or `None` if it was left out.
+    pub patch: Option<u64>,
+
+    /// The pre-release, or an empty string. Only set when `minor` and `patch` are too.
+    pub pre_release: String,
+}
+
+impl Comparator {
+    /// Returns `true` if `version` satisfies the comparator, ignoring the pre-release
+    /// rule that [`VersionReq::matches`] applies.
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        match self.op {
+            Op::Exact | Op::Wildcard => self.matches_exact(version),
+            Op::Greater => self.matches_greater(version),
+            Op::GreaterEq => self.matches_exact(version) || self.matches_greater(version),
+            Op::Less => self.matches_less(version),
+            Op::LessEq => self.matches_exact(version) || self.matches_less(version),
+            Op::Tilde => self.matches_tilde(version),
+            Op::Caret => self.matches_caret(version),
+        }
+    }
+
+    fn matches_exact(&self, version: &Version) -> bool {
+        version.major == self.major
+            && self.minor.is_none_or(|minor| version.minor == minor)
+            && self.patch.is_none_or(|patch| {
+                version.patch == patch && version.pre_release == self.pre_release
+            })
+    }
+
+    fn matches_greater(&self, version: &Version) -> bool {
+        self.compare_parts(version) == Some(Ordering::Greater)
+    }
+
+    fn matches_less(&self, version: &Version) -> bool {
+        self.compare_parts(version) == Some(Ordering::Less)
+    }
+
+    /// Compares `version` with the comparator's version, or returns `None` if they are
+    /// equal in every part the comparator gives.
+    fn compare_parts(&self, version: &Version) -> Option<Ordering> {
+        let ordering = version.major.cmp(&self.major).then_with(|| {
+            self.minor
+                .map_or(Ordering::Equal, |minor| version.minor.cmp(&minor))
+        });
+
+        if ordering.is_ne() || self.minor.is_none() {
+            return ordering.is_ne().then_some(ordering);
+        }
+
+        let patch = self.patch?;
+
+        let ordering = version
+            .patch
+            .cmp(&patch)
+            .then_with(|| compare_pre_releases(&version.pre_release, &self.pre_release));
+
+        ordering.is_ne().then_some(ordering)
+    }
+
+    fn matches_tilde(&self, version: &Version) -> bool {
+        version.major == self.major
+            && self.minor.is_none_or(|minor| version.minor == minor)
+            && self.patch.is_none_or(|patch| {
+                version.patch > patch
+                    || (version.patch == patch
+                        && compare_pre_releases(&version.pre_release, &self.pre_release).is_ge())
+            })
+    }
+
+    fn matches_caret(&self, version: &Version) -> bool {
+        if version.major != self.major {
+            return false;
+        }
+
+        let Some(minor) = self.minor else {
+            return true;
+        };
+
+        let Some(patch) = self.patch else {
+            return if self.major > 0 {
+                version.minor >= minor
+            } else {
+                version.minor == minor
+            };
+        };
+
+        let parts = if self.major > 0 {
+            (version.minor, version.patch).cmp(&(minor, patch))
+        } else if minor > 0 {
+            if version.minor != minor {
+                return false;
+            }
+
+            version.patch.cmp(&patch)
+        } else if (version.minor, version.patch) == (minor, patch) {
+            Ordering::Equal
+        } else {
+            return false;
+        };
+
+        parts
+            .then_with(|| compare_pre_releases(&version.pre_release, &self.pre_release))
+            .is_ge()
+    }
+
+    /// Returns `true` if the comparator names `version`'s exact release with a
+    /// pre-release, which lets pre-releases of that release match.
+    fn allows_pre_release_of(&self, version: &Version) -> bool {
+        self.major == version.major
+            && self.minor == Some(version.minor)
+            && self.patch == Some(version.patch)
+            && !self.pre_release.is_empty()
+    }
+}
+
+impl fmt::Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Exact => "=",
+            Op::Greater => ">",
+            Op::GreaterEq => ">=",
+            Op::Less => "<",
+            Op::LessEq => "<=",
+            Op::Tilde => "~",
+            Op::Caret => "^",
+            Op::Wildcard => "",
+        };
+
+        write!(f, "{op}{}", self.major)?;
+
+        let wildcard = if self.op == Op::Wildcard { ".*" } else { "" };
+
+        match (self.minor, self.patch) {
+            (Some(minor), Some(patch)) => write!(f, ".{minor}.{patch}")?,
+            (Some(minor), None) => write!(f, ".{minor}{wildcard}")?,
+            (None, _) => f.write_str(wildcard)?,
+        }
+
+        if !self.pre_release.is_empty() {
+            write!(f, "-{}", self.pre_release)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (op, rest) = [
+            (">=", Op::GreaterEq),
+            ("<=", Op::LessEq),
+            (">", Op::Greater),
+            ("<", Op::Less),
+            ("=", Op::Exact),
+            ("~", Op::Tilde),
+            ("^", Op::Caret),
+        ]
+        .into_iter()
+        .find_map(|(symbol, op)| s.strip_prefix(symbol).map(|rest| (Some(op), rest)))
+        .unwrap_or((None, s));
+        let rest = rest.trim_start();
+        let offset = s.len() - rest.len();
+
+        if rest.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (core, pre_release) = rest.split_once('-').unwrap_or((rest, ""));
+
+        if rest.len() > core.len() {
+            validate_identifiers(pre_release, offset + core.len() + 1, true)?;
+        }
+
+        let mut parts = Vec::new();
+        let mut position = offset;
+        let mut wildcard = false;
+
+        for part in core.split('.') {
+            if matches!(part, "*" | "x" | "X") {
+                wildcard = true;
+            } else if wildcard {
+                // Nothing but wildcards may follow a wildcard, as in `1.*.*`.
+                return Err(ParseError::InvalidSyntax);
+            } else {
+                parts.push(parse_number(part, position)?);
+            }
+
+            position += part.len() + 1;
+        }
+
+        // A pre-release only qualifies a full `major.minor.patch`, as in `>=1.2.3-alpha`;
+        // this also rules out wildcards such as `1.*-alpha`.
+        if core.split('.').count() > 3 || (rest.len() > core.len() && parts.len() < 3) {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        let op = match op {
+            Some(_) if wildcard && parts.is_empty() => return Err(ParseError::InvalidSyntax),
+            Some(Op::Exact) | None if wildcard => Op::Wildcard,
+            Some(op) => op,
+            None => Op::Caret,
+        };
+
+        let Some(&major) = parts.first() else {
+            return Err(ParseError::InvalidSyntax);
+        };
+
+        Ok(Self {
+            op,
+            major,
+            minor: parts.get(1).copied(),
+            patch: parts.get(2).copied(),
+            pre_release: String::from(pre_release),
+        })
+    }
+}
+
+/// A set of [`Comparator`]s a version must all satisfy.
+///
+/// A version with a pre-release only matches if a comparator names the same
+/// `major.minor.patch` with a pre-release, so `>=1.0.0` does not match `2.0.0-alpha`
+/// but `>=2.0.0-alpha` does.
+///
+/// # Examples
+///
+/// ```
+/// use libx::version::{Version, VersionReq};
+///
+/// let caret: VersionReq = "^0.2.3".parse().unwrap();
+/// assert!(caret.matches(&Version::new(0, 2, 9)));
+/// assert!(!caret.matches(&Version::new(0, 3, 0)));
+///
+/// let any: VersionReq = "*".parse().unwrap();
+/// assert!(any.matches(&Version::new(5, 0, 0)));
+/// assert!(!any.matches(&"5.0.0-beta".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct VersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// The requirement that every release satisfies, written `*`.
+    pub const STAR: Self = Self {
+        comparators: Vec::new(),
+    };
+
+    /// Returns the comparators of the requirement.
+    #[must_use]
+    pub fn comparators(&self) -> &[Comparator] {
+        &self.comparators
+    }
+
+    /// Returns `true` if `version` satisfies every comparator.
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators
+            .iter()
+            .all(|comparator| comparator.matches(version))
+            && (!version.is_pre_release()
+                || self
+                    .comparators
+                    .iter()
+                    .any(|comparator| comparator.allows_pre_release_of(version)))
+    }
+}
+
+impl fmt::Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.comparators.is_empty() {
+            return f.write_str("*");
+        }
+
+        for (index, comparator) in self.comparators.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+
+            write!(f, "{comparator}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        if s == "*" {
+            return Ok(Self::STAR);
+        }
+
+        let comparators = s
+            .split(',')
+            .map(str::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { comparators })
+    }
+}
+
+/// Parses a version number, which may not have leading zeros.
+fn parse_number(part: &str, position: usize) -> Result<u64, ParseError> {
+    if part.is_empty() {
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    if let Some((index, character)) = part.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+        return Err(ParseError::InvalidCharacter {
+            character,
+            position: position + index,
+        });
+    }
+
+    if part.len() > 1 && part.starts_with('0') {
+        return Err(ParseError::InvalidSyntax);
+    }
+
+    part.parse().map_err(|_| ParseError::OutOfRange)
+}
+
+/// Checks a dot-separated list of identifiers starting at byte `position` of the input.
+fn validate_identifiers(
+    identifiers: &str,
+    position: usize,
+    is_pre_release: bool,
+) -> Result<(), ParseError> {
+    if identifiers.is_empty() {
+        return if position == 0 {
+            Ok(())
+        } else {
+            Err(ParseError::InvalidSyntax)
+        };
+    }
+
+    let mut position = position;
+
+    for identifier in identifiers.split('.') {
+        if identifier.is_empty() {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        if let Some((index, character)) = identifier
+            .char_indices()
+            .find(|&(_, c)| !c.is_ascii_alphanumeric() && c != '-')
+        {
+            return Err(ParseError::InvalidCharacter {
+                character,
+                position: position + index,
+            });
+        }
+
+        if is_pre_release
+            && identifier.len() > 1
+            && identifier.starts_with('0')
+            && identifier.bytes().all(|byte| byte.is_ascii_digit())
+        {
+            return Err(ParseError::InvalidSyntax);
+        }
+
+        position += identifier.len() + 1;
+    }
+
+    Ok(())
+}
+
+/// Compares pre-releases by semver precedence, with a release above every pre-release.
+fn compare_pre_releases(left: &str, right: &str) -> Ordering {
+    match (left.is_empty(), right.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let mut left = left.split('.');
+    let mut right = right.split('.');
+
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(left), Some(right)) => {
+                let is_numeric = |identifier: &str| identifier.bytes().all(|b| b.is_ascii_digit());
+
+                let ordering = match (is_numeric(left), is_numeric(right)) {
+                    // Compare lengths first so numbers of any size compare correctly.
+                    (true, true) => left.len().cmp(&right.len()).then_with(|| left.cmp(right)),
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => left.cmp(right),
+                };
+
+                if ordering.is_ne() {
+                    return ordering;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    fn version(text: &str) -> Version {
+        text.parse().expect("a valid version")
+    }
+
+    fn requirement(text: &str) -> VersionReq {
+        text.parse().expect("a valid requirement")
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        let parsed = version("1.0.0-alpha.1+build.2024-01");
+
+        assert_eq!((parsed.major(), parsed.minor(), parsed.patch()), (1, 0, 0));
+        assert_eq!(parsed.pre_release(), "alpha.1");
+        assert_eq!(parsed.build(), "build.2024-01");
+        assert_eq!(parsed.to_string(), "1.0.0-alpha.1+build.2024-01");
+        assert_eq!(version("1.2.3+x-y").pre_release(), "");
+
+        assert_eq!("".parse::<Version>(), Err(ParseError::Empty));
+        assert_eq!("1.2".parse::<Version>(), Err(ParseError::InvalidSyntax));
+        assert_eq!("1.2.3.4".parse::<Version>(), Err(ParseError::InvalidSyntax));
+        assert_eq!("01.2.3".parse::<Version>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "1.2.3-01".parse::<Version>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!("1.2.3-".parse::<Version>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "1.2.3-a..b".parse::<Version>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "1.2.3-a_b".parse::<Version>(),
+            Err(ParseError::InvalidCharacter {
+                character: '_',
+                position: 7
+            })
+        );
+        assert_eq!(
+            "1.x.3".parse::<Version>(),
+            Err(ParseError::InvalidCharacter {
+                character: 'x',
+                position: 2
+            })
+        );
+        assert_eq!(
+            "99999999999999999999.0.0".parse::<Version>(),
+            Err(ParseError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+            "1.1.0",
+            "2.0.0",
+        ]
+        .map(version);
+
+        assert!(ordered.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let (first, second) = (version("1.0.0+a"), version("1.0.0+b"));
+        assert_eq!(first.cmp_precedence(&second), Ordering::Equal);
+        assert!(first < second);
+
+        let built = Version::new(1, 0, 0)
+            .with_pre_release("rc.1")
+            .and_then(|version| version.with_build("sha.5114f85"))
+            .expect("valid identifiers");
+        assert_eq!(built.to_string(), "1.0.0-rc.1+sha.5114f85");
+        assert!(Version::new(1, 0, 0).with_build("a+b").is_err());
+    }
+
+    #[test]
+    fn test_caret_and_tilde() {
+        let caret = |req: &str, ver: &str| requirement(req).matches(&version(ver));
+
+        assert!(caret("1.2.3", "1.9.0"));
+        assert!(!caret("1.2.3", "1.2.2"));
+        assert!(!caret("1.2.3", "2.0.0"));
+        assert!(caret("^0.2.3", "0.2.4"));
+        assert!(!caret("^0.2.3", "0.3.0"));
+        assert!(caret("^0.0.3", "0.0.3"));
+        assert!(!caret("^0.0.3", "0.0.4"));
+        assert!(caret("^0.0", "0.0.9"));
+        assert!(!caret("^0.0", "0.1.0"));
+        assert!(caret("^1", "1.99.0"));
+
+        assert!(caret("~1.2.3", "1.2.9"));
+        assert!(!caret("~1.2.3", "1.3.0"));
+        assert!(caret("~1", "1.5.0"));
+        assert!(!caret("~1.2", "1.1.9"));
+    }
+
+    #[test]
+    fn test_comparisons_and_wildcards() {
+        let matches = |req: &str, ver: &str| requirement(req).matches(&version(ver));
+
+        assert!(matches(">=1.0, <2.0", "1.5.0"));
+        assert!(!matches(">=1.0, <2.0", "2.0.0"));
+        assert!(matches(">1.2", "1.3.0"));
+        assert!(!matches(">1.2", "1.2.9"));
+        assert!(matches("<=1.2", "1.2.9"));
+        assert!(matches("=1.2", "1.2.5"));
+        assert!(!matches("=1.2.3", "1.2.4"));
+        assert!(matches("1.*", "1.9.9"));
+        assert!(matches("1.2.x", "1.2.7"));
+        assert!(!matches("1.2.*", "1.3.0"));
+        assert!(matches("*", "0.0.1"));
+    }
+
+    #[test]
+    fn test_pre_releases() {
+        let matches = |req: &str, ver: &str| requirement(req).matches(&version(ver));
+
+        assert!(!matches(">=1.0.0", "2.0.0-alpha"));
+        assert!(matches(">=2.0.0-alpha", "2.0.0-beta"));
+        assert!(!matches(">=2.0.0-alpha", "2.0.1-beta"));
+        assert!(matches("^1.2.3-rc.1", "1.2.3-rc.2"));
+        assert!(matches("^1.2.3-rc.1", "1.2.3"));
+        assert!(!matches("^1.2.3-rc.2", "1.2.3-rc.1"));
+        assert!(matches("<1.0.0", "0.9.0"));
+    }
+
+    #[test]
+    fn test_requirement_parse_and_display() {
+        assert_eq!(requirement(" >= 1.2 ,<2").to_string(), ">=1.2, <2");
+        assert_eq!(requirement("1.*").to_string(), "1.*");
+        assert_eq!(requirement("*").to_string(), "*");
+        assert_eq!(requirement("1.2.3-pre").to_string(), "^1.2.3-pre");
+        assert_eq!(requirement("=2.x").comparators()[0].op, Op::Wildcard);
+
+        assert_eq!("".parse::<VersionReq>(), Err(ParseError::Empty));
+        assert_eq!("1.0,".parse::<VersionReq>(), Err(ParseError::Empty));
+        assert_eq!(">*".parse::<VersionReq>(), Err(ParseError::InvalidSyntax));
+        assert_eq!(
+            "1.*.2".parse::<VersionReq>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "1.2.3.4".parse::<VersionReq>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "1.*-pre".parse::<VersionReq>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            "=1.2-alpha".parse::<VersionReq>(),
+            Err(ParseError::InvalidSyntax)
+        );
+        assert_eq!(
+            ">=1-alpha".parse::<VersionReq>(),
+            Err(ParseError::InvalidSyntax)
+        );
+    }
+}
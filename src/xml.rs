@@ -0,0 +1,942 @@
+//! Reading and writing XML.
+//!
+//! [`XmlReader`] is a pull parser: each call to [`XmlReader::next_event`] reads the next
+//! piece of a document from a `&str` and returns it as an [`Event`], borrowing from the
+//! input wherever no entity has to be replaced. It checks that the document is
+//! well-formed as it goes: tags must nest, attributes must be unique and every reference
+//! and namespace prefix must be known. Names are resolved against the `xmlns`
+//! declarations in scope, so a consumer can match on a namespace rather than a prefix.
+//!
+//! The reader does not read a DTD. A `<!DOCTYPE>` is reported as it is written, and only
+//! the five predefined entities and character references are replaced.
+//!
+//! [`XmlWriter`] writes a document to any [`fmt::Write`], escaping text and attribute
+//! values and closing elements in order.
+//!
+//! # Examples
+//!
+//! ```
+//! use libx::xml::{Event, XmlReader, XmlWriter};
+//!
+//! let mut writer = XmlWriter::new(String::new());
+//! writer.start_element("feed", &[("xmlns", "http://www.w3.org/2005/Atom")])?;
+//! writer.start_element("title", &[])?;
+//! writer.text("Tom & Jerry")?;
+//! let document = writer.finish()?;
+//! assert_eq!(
+//!     document,
+//!     r#"<feed xmlns="http://www.w3.org/2005/Atom"><title>Tom &amp; Jerry</title></feed>"#
+//! );
+//!
+//! let mut titles = Vec::new();
+//! for event in XmlReader::new(&document) {
+//!     if let Event::Text(text) = event.expect("the document is well-formed") {
+//!         titles.push(text);
+//!     }
+//! }
+//! assert_eq!(titles, ["Tom & Jerry"]);
+//! # Ok::<(), core::fmt::Error>(())
+//! ```
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    errors::XmlError,
+    text::escape::{escape_html, leading_digits},
+};
+
+/// The namespace of the `xml` prefix, which is bound in every document.
+pub const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// The namespace of `xmlns` attributes.
+pub const XMLNS_NAMESPACE: &str = "http://www.w3.org/2000/xmlns/";
+
+/// The name of an element or attribute.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Name<'a> {
+    /// The prefix before the colon, if there is one.
+    pub prefix: Option<&'a str>,
+
+    /// The name without its prefix.
+    pub local: &'a str,
+
+    /// The namespace the name is in, if any.
+    ///
+    /// An element without a prefix is in the default namespace; an attribute without a
+    /// prefix is in no namespace.
+    pub namespace: Option<Cow<'a, str>>,
+}
+
+impl Name<'_> {
+    /// Returns whether the name is `local` in `namespace`.
+    #[must_use]
+    pub fn is(&self, namespace: Option<&str>, local: &str) -> bool {
+        self.local == local && self.namespace.as_deref() == namespace
+    }
+}
+
+impl fmt::Display for Name<'_> {
+    /// Writes the name as it appears in the document, with its prefix.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(prefix) = self.prefix {
+            write!(f, "{prefix}:")?;
+        }
+
+        f.write_str(self.local)
+    }
+}
+
+/// An attribute of a start tag.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Attribute<'a> {
+    /// The name of the attribute.
+    pub name: Name<'a>,
+
+    /// The value, with references replaced and whitespace characters turned into spaces.
+    pub value: Cow<'a, str>,
+}
+
+/// A piece of an XML document read by an [`XmlReader`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Event<'a> {
+    /// A start tag. An empty-element tag such as `<br/>` is read as a start tag followed
+    /// by an end tag.
+    Start {
+        /// The name of the element.
+        name: Name<'a>,
+
+        /// The attributes, in document order, including `xmlns` declarations.
+        attributes: Vec<Attribute<'a>>,
+    },
+
+    /// An end tag.
+    End {
+        /// The name of the element.
+        name: Name<'a>,
+    },
+
+    /// Character data, with references replaced. Whitespace between tags is reported too.
+    Text(Cow<'a, str>),
+
+    /// The contents of a `<![CDATA[...]]>` section.
+    CData(&'a str),
+
+    /// The contents of a `<!--...-->` comment.
+    Comment(&'a str),
+
+    /// A processing instruction, such as the `<?xml version="1.0"?>` declaration.
+    ProcessingInstruction {
+        /// The name after `<?`.
+        target: &'a str,
+
+        /// Everything after the target, without leading whitespace.
+        data: &'a str,
+    },
+
+    /// The contents of a `<!DOCTYPE ...>` declaration, after the keyword.
+    Doctype(&'a str),
+}
+
+/// A namespace declaration in scope.
+#[derive(Debug, Clone)]
+struct Binding<'a> {
+    /// The declared prefix, empty for the default namespace.
+    prefix: &'a str,
+
+    /// The namespace, empty when the default namespace is undeclared.
+    namespace: Cow<'a, str>,
+
+    /// The depth of the element that declared it.
+    depth: usize,
+}
+
+/// A pull parser for XML documents.
+///
+/// The reader is also an iterator of events, which ends after the first error.
+#[derive(Debug, Clone)]
+pub struct XmlReader<'a> {
+    input: &'a str,
+    position: usize,
+    /// The open elements, as written and as resolved.
+    open: Vec<(&'a str, Name<'a>)>,
+    bindings: Vec<Binding<'a>>,
+    /// Whether the last start tag was an empty-element tag, still to be ended.
+    empty: bool,
+    error: Option<XmlError>,
+}
+
+impl<'a> XmlReader<'a> {
+    /// Creates a reader of `input`.
+    #[must_use]
+    pub const fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            position: 0,
+            open: Vec::new(),
+            bindings: Vec::new(),
+            empty: false,
+            error: None,
+        }
+    }
+
+    /// Returns the byte offset of the next event in the input.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of open elements.
+    #[must_use]
+    pub const fn depth(&self) -> usize {
+        self.open.len()
+    }
+
+    /// Reads the next event, or returns `None` at the end of the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`XmlError`] if the document is not well-formed at this point. Every
+    /// later call returns the same error.
+    pub fn next_event(&mut self) -> Result<Option<Event<'a>>, XmlError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        let event = self.read_event();
+
+        if let Err(error) = event {
+            self.error = Some(error);
+        }
+
+        event
+    }
+
+    fn read_event(&mut self) -> Result<Option<Event<'a>>, XmlError> {
+        if self.empty {
+            self.empty = false;
+
+            if let Some((_, name)) = self.open.pop() {
+                return Ok(Some(self.end_element(name)));
+            }
+        }
+
+        let start = self.position;
+        let rest = &self.input[start..];
+
+        if rest.is_empty() {
+            return if self.open.is_empty() {
+                Ok(None)
+            } else {
+                Err(XmlError::UnexpectedEnd)
+            };
+        }
+
+        if !rest.starts_with('<') {
+            let length = rest.find('<').unwrap_or(rest.len());
+            self.position += length;
+            return decode(&rest[..length], start, false).map(|text| Some(Event::Text(text)));
+        }
+
+        let (event, length) = if let Some(body) = rest.strip_prefix("<!--") {
+            let end = body.find("-->").ok_or(XmlError::UnexpectedEnd)?;
+            (Event::Comment(&body[..end]), end + 7)
+        } else if let Some(body) = rest.strip_prefix("<![CDATA[") {
+            let end = body.find("]]>").ok_or(XmlError::UnexpectedEnd)?;
+            (Event::CData(&body[..end]), end + 12)
+        } else if let Some(body) = rest.strip_prefix("<!DOCTYPE") {
+            let end = doctype_length(body).ok_or(XmlError::UnexpectedEnd)?;
+            (Event::Doctype(body[..end].trim_matches(is_space)), end + 10)
+        } else if let Some(body) = rest.strip_prefix("<?") {
+            let end = body.find("?>").ok_or(XmlError::UnexpectedEnd)?;
+            let target = &body[..name_length(body)];
+            let data = &body[target.len()..end];
+
+            if target.is_empty() || !(data.is_empty() || data.starts_with(is_space)) {
+                return Err(XmlError::Syntax { position: start });
+            }
+
+            let data = data.trim_start_matches(is_space);
+            (Event::ProcessingInstruction { target, data }, end + 4)
+        } else if rest.starts_with("</") {
+            return self.end_tag().map(Some);
+        } else {
+            return self.start_tag().map(Some);
+        };
+
+        self.position += length;
+        Ok(Some(event))
+    }
+
+    /// Reads the start tag at the current position.
+    fn start_tag(&mut self) -> Result<Event<'a>, XmlError> {
+        let start = self.position;
+        let qualified = self.name_at(start + 1)?;
+        let mut index = start + 1 + qualified.len();
+        let mut attributes: Vec<(usize, &'a str, Cow<'a, str>)> = Vec::new();
+
+        loop {
+            let spaced = skip_space(self.input, &mut index);
+            let rest = &self.input[index..];
+
+            if let Some(length) = [">", "/>"]
+                .iter()
+                .find(|end| rest.starts_with(*end))
+                .map(|end| end.len())
+            {
+                self.empty = length == 2;
+                index += length;
+                break;
+            }
+
+            if rest.is_empty() {
+                return Err(XmlError::UnexpectedEnd);
+            }
+
+            if !spaced {
+                return Err(XmlError::Syntax { position: index });
+            }
+
+            let attribute = index;
+            let name = self.name_at(index)?;
+            index += name.len();
+            skip_space(self.input, &mut index);
+
+            if !self.input[index..].starts_with('=') {
+                return Err(self.syntax_or_end(index));
+            }
+
+            index += 1;
+            skip_space(self.input, &mut index);
+
+            let Some(quote) = self.input[index..]
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\''))
+            else {
+                return Err(self.syntax_or_end(index));
+            };
+
+            index += 1;
+            let length = self.input[index..]
+                .find(quote)
+                .ok_or(XmlError::UnexpectedEnd)?;
+            let raw = &self.input[index..index + length];
+
+            if let Some(offset) = raw.find('<') {
+                return Err(XmlError::Syntax {
+                    position: index + offset,
+                });
+            }
+
+            if attributes.iter().any(|&(_, other, _)| other == name) {
+                return Err(XmlError::DuplicateAttribute {
+                    position: attribute,
+                });
+            }
+
+            attributes.push((attribute, name, decode(raw, index, true)?));
+            index += length + 1;
+        }
+
+        let depth = self.open.len() + 1;
+
+        for (_, name, value) in &attributes {
+            let prefix = if *name == "xmlns" {
+                Some("")
+            } else {
+                name.strip_prefix("xmlns:")
+            };
+
+            if let Some(prefix) = prefix {
+                self.bindings.push(Binding {
+                    prefix,
+                    namespace: value.clone(),
+                    depth,
+                });
+            }
+        }
+
+        let name = self.resolve(qualified, start + 1, true)?;
+        let attributes = attributes
+            .into_iter()
+            .map(|(position, name, value)| {
+                Ok(Attribute {
+                    name: self.resolve(name, position, false)?,
+                    value,
+                })
+            })
+            .collect::<Result<_, XmlError>>()?;
+
+        self.open.push((qualified, name.clone()));
+        self.position = index;
+        Ok(Event::Start { name, attributes })
+    }
+
+    /// Reads the end tag at the current position.
+    fn end_tag(&mut self) -> Result<Event<'a>, XmlError> {
+        let start = self.position;
+        let qualified = self.name_at(start + 2)?;
+        let mut index = start + 2 + qualified.len();
+        skip_space(self.input, &mut index);
+
+        if !self.input[index..].starts_with('>') {
+            return Err(self.syntax_or_end(index));
+        }
+
+        match self.open.pop() {
+            Some((open, name)) if open == qualified => {
+                self.position = index + 1;
+                Ok(self.end_element(name))
+            }
+            _ => Err(XmlError::MismatchedTag { position: start }),
+        }
+    }
+
+    /// Returns the end event of the element `name`, which was just closed, and drops the
+    /// namespaces it declared.
+    fn end_element(&mut self, name: Name<'a>) -> Event<'a> {
+        let depth = self.open.len();
+        self.bindings.retain(|binding| binding.depth <= depth);
+        Event::End { name }
+    }
+
+    /// Returns the name at `index`.
+    fn name_at(&self, index: usize) -> Result<&'a str, XmlError> {
+        let rest = &self.input[index..];
+
+        match name_length(rest) {
+            0 => Err(self.syntax_or_end(index)),
+            length => Ok(&rest[..length]),
+        }
+    }
+
+    /// Returns the error for unexpected input at `index`.
+    const fn syntax_or_end(&self, index: usize) -> XmlError {
+        if index < self.input.len() {
+            XmlError::Syntax { position: index }
+        } else {
+            XmlError::UnexpectedEnd
+        }
+    }
+
+    /// Splits `qualified`, found at `position`, into its prefix and local name, and finds
+    /// its namespace.
+    fn resolve(
+        &self,
+        qualified: &'a str,
+        position: usize,
+        is_element: bool,
+    ) -> Result<Name<'a>, XmlError> {
+        let (prefix, local) = match qualified.split_once(':') {
+            Some((prefix, local))
+                if !prefix.is_empty() && !local.is_empty() && !local.contains(':') =>
+            {
+                (Some(prefix), local)
+            }
+            Some(_) => return Err(XmlError::Syntax { position }),
+            None => (None, qualified),
+        };
+
+        let namespace = match prefix {
+            Some("xml") => Some(Cow::Borrowed(XML_NAMESPACE)),
+            Some("xmlns") => Some(Cow::Borrowed(XMLNS_NAMESPACE)),
+            None if !is_element => (qualified == "xmlns").then_some(Cow::Borrowed(XMLNS_NAMESPACE)),
+            prefix => {
+                let prefix = prefix.unwrap_or_default();
+                let binding = self
+                    .bindings
+                    .iter()
+                    .rev()
+                    .find(|binding| binding.prefix == prefix);
+
+                match binding {
+                    Some(binding) if !binding.namespace.is_empty() => {
+                        Some(binding.namespace.clone())
+                    }
+                    _ if prefix.is_empty() => None,
+                    _ => return Err(XmlError::UnboundPrefix { position }),
+                }
+            }
+        };
+
+        Ok(Name {
+            prefix,
+            local,
+            namespace,
+        })
+    }
+}
+
+impl<'a> Iterator for XmlReader<'a> {
+    type Item = Result<Event<'a>, XmlError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() {
+            return None;
+        }
+
+        self.next_event().transpose()
+    }
+}
+
+/// Returns whether `character` is XML whitespace.
+const fn is_space(character: char) -> bool {
+    matches!(character, ' ' | '\t' | '\n' | '\r')
+}
+
+/// Moves `index` past the whitespace at it, returning whether there was any.
+fn skip_space(input: &str, index: &mut usize) -> bool {
+    let rest = &input[*index..];
+    let length = rest.len() - rest.trim_start_matches(is_space).len();
+    *index += length;
+    length > 0
+}
+
+/// Returns the length in bytes of the name at the start of `text`, or zero if there is
+/// none.
+fn name_length(text: &str) -> usize {
+    let mut characters = text.char_indices();
+
+    match characters.next() {
+        Some((_, first)) if first.is_alphabetic() || matches!(first, '_' | ':') => characters
+            .find(|&(_, character)| {
+                !(character.is_alphanumeric() || matches!(character, '_' | ':' | '-' | '.'))
+            })
+            .map_or(text.len(), |(index, _)| index),
+        _ => 0,
+    }
+}
+
+/// Returns the length of a doctype declaration up to its `>`, which may follow an
+/// internal subset in brackets that contains `>` itself.
+fn doctype_length(body: &str) -> Option<usize> {
+    let close = body.find('>')?;
+
+    match body[..close].find('[') {
+        Some(open) => {
+            let subset_end = open + body[open..].find(']')?;
+            Some(subset_end + body[subset_end..].find('>')?)
+        }
+        None => Some(close),
+    }
+}
+
+/// Replaces the references in `text`, found at `offset`, and in an attribute value also
+/// turns whitespace characters into spaces.
+fn decode(text: &str, offset: usize, is_attribute: bool) -> Result<Cow<'_, str>, XmlError> {
+    let is_special = |character: char| {
+        character == '&' || (is_attribute && matches!(character, '\t' | '\n' | '\r'))
+    };
+
+    if !text.contains(is_special) {
+        return Ok(Cow::Borrowed(text));
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut index = 0;
+
+    while let Some(found) = text[index..].find(is_special) {
+        let position = index + found;
+        output.push_str(&text[index..position]);
+
+        if !text[position..].starts_with('&') {
+            output.push(' ');
+            index = position + 1;
+            continue;
+        }
+
+        let error = XmlError::InvalidReference {
+            position: offset + position,
+        };
+        let (reference, _) = text[position + 1..].split_once(';').ok_or(error)?;
+
+        output.push(match reference {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let (radix, digits) = reference
+                    .strip_prefix("#x")
+                    .map(|digits| (16, digits))
+                    .or_else(|| reference.strip_prefix('#').map(|digits| (10, digits)))
+                    .ok_or(error)?;
+                let (value, length) = leading_digits(digits, radix, usize::MAX);
+
+                if length == 0 || length != digits.len() || value == 0 {
+                    return Err(error);
+                }
+
+                char::from_u32(value).ok_or(error)?
+            }
+        });
+        index = position + reference.len() + 2;
+    }
+
+    output.push_str(&text[index..]);
+    Ok(Cow::Owned(output))
+}
+
+/// A writer of XML documents.
+///
+/// Elements are closed in the reverse order they were opened, and one closed without
+/// content is written as an empty-element tag. Names are written as given, so they must
+/// be valid XML names.
+#[derive(Debug, Clone)]
+pub struct XmlWriter<W> {
+    sink: W,
+    /// The names of the open elements.
+    open: Vec<String>,
+    /// Whether the last start tag still lacks its `>`.
+    in_start_tag: bool,
+}
+
+impl<W: fmt::Write> XmlWriter<W> {
+    /// Creates a writer that writes to `sink`.
+    #[must_use]
+    pub const fn new(sink: W) -> Self {
+        Self {
+            sink,
+            open: Vec::new(),
+            in_start_tag: false,
+        }
+    }
+
+    /// Writes the `<?xml version="1.0" encoding="UTF-8"?>` declaration and a line feed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn declaration(&mut self) -> fmt::Result {
+        self.sink
+            .write_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n")
+    }
+
+    /// Opens the element `name` with `attributes`, which are escaped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn start_element(&mut self, name: &str, attributes: &[(&str, &str)]) -> fmt::Result {
+        self.close_start_tag()?;
+        write!(self.sink, "<{name}")?;
+
+        for (name, value) in attributes {
+            let value = escape_html(value);
+            write!(self.sink, " {name}=\"")?;
+
+            for character in value.chars() {
+                match character {
+                    '\t' => self.sink.write_str("&#9;")?,
+                    '\n' => self.sink.write_str("&#10;")?,
+                    '\r' => self.sink.write_str("&#13;")?,
+                    _ => self.sink.write_char(character)?,
+                }
+            }
+
+            self.sink.write_char('"')?;
+        }
+
+        self.open.push(name.to_string());
+        self.in_start_tag = true;
+        Ok(())
+    }
+
+    /// Closes the innermost open element.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails or no element is open.
+    pub fn end_element(&mut self) -> fmt::Result {
+        let name = self.open.pop().ok_or(fmt::Error)?;
+
+        if self.in_start_tag {
+            self.in_start_tag = false;
+            self.sink.write_str("/>")
+        } else {
+            write!(self.sink, "</{name}>")
+        }
+    }
+
+    /// Writes `text` as character data, escaped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn text(&mut self, text: &str) -> fmt::Result {
+        self.close_start_tag()?;
+        self.sink.write_str(&escape_html(text))
+    }
+
+    /// Writes `text` in a CDATA section. A `]]>` in the text is split across two
+    /// sections.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn cdata(&mut self, text: &str) -> fmt::Result {
+        self.close_start_tag()?;
+        write!(
+            self.sink,
+            "<![CDATA[{}]]>",
+            text.replace("]]>", "]]]]><![CDATA[>")
+        )
+    }
+
+    /// Writes `text` as a comment. Since a comment cannot contain `--`, a space is put
+    /// between such hyphens.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn comment(&mut self, text: &str) -> fmt::Result {
+        self.close_start_tag()?;
+        self.sink.write_str("<!--")?;
+
+        let mut previous = None;
+
+        for character in text.chars() {
+            if character == '-' && previous == Some('-') {
+                self.sink.write_char(' ')?;
+            }
+
+            self.sink.write_char(character)?;
+            previous = Some(character);
+        }
+
+        // A hyphen before the closing `-->` would make `--` too.
+        if previous == Some('-') {
+            self.sink.write_char(' ')?;
+        }
+
+        self.sink.write_str("-->")
+    }
+
+    /// Writes a processing instruction with `target` and `data`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails or `data` contains `?>`.
+    pub fn processing_instruction(&mut self, target: &str, data: &str) -> fmt::Result {
+        if data.contains("?>") {
+            return Err(fmt::Error);
+        }
+
+        self.close_start_tag()?;
+
+        if data.is_empty() {
+            write!(self.sink, "<?{target}?>")
+        } else {
+            write!(self.sink, "<?{target} {data}?>")
+        }
+    }
+
+    /// Closes every open element and returns the sink.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the sink fails.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        while !self.open.is_empty() {
+            self.end_element()?;
+        }
+
+        Ok(self.sink)
+    }
+
+    /// Ends a start tag that is still open with `>`.
+    fn close_start_tag(&mut self) -> fmt::Result {
+        if self.in_start_tag {
+            self.in_start_tag = false;
+            self.sink.write_char('>')?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name<'a>(prefix: Option<&'a str>, local: &'a str, namespace: Option<&'a str>) -> Name<'a> {
+        Name {
+            prefix,
+            local,
+            namespace: namespace.map(Cow::Borrowed),
+        }
+    }
+
+    #[test]
+    fn test_events() {
+        let document = "<?xml version=\"1.0\"?>\n<!DOCTYPE note [<!ENTITY x \"y\">]>\
+                        <note id='1 &amp; 2'><!-- hi --><to>A&#x26;B</to><br/>\
+                        <![CDATA[<raw>]]></note>";
+        let events: Vec<Event<'_>> = XmlReader::new(document)
+            .collect::<Result<_, _>>()
+            .expect("the document is well-formed");
+
+        assert_eq!(
+            events,
+            [
+                Event::ProcessingInstruction {
+                    target: "xml",
+                    data: "version=\"1.0\""
+                },
+                Event::Text(Cow::Borrowed("\n")),
+                Event::Doctype("note [<!ENTITY x \"y\">]"),
+                Event::Start {
+                    name: name(None, "note", None),
+                    attributes: alloc::vec![Attribute {
+                        name: name(None, "id", None),
+                        value: Cow::Borrowed("1 & 2")
+                    }]
+                },
+                Event::Comment(" hi "),
+                Event::Start {
+                    name: name(None, "to", None),
+                    attributes: Vec::new()
+                },
+                Event::Text(Cow::Borrowed("A&B")),
+                Event::End {
+                    name: name(None, "to", None)
+                },
+                Event::Start {
+                    name: name(None, "br", None),
+                    attributes: Vec::new()
+                },
+                Event::End {
+                    name: name(None, "br", None)
+                },
+                Event::CData("<raw>"),
+                Event::End {
+                    name: name(None, "note", None)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let document = "<root xmlns='urn:a' xmlns:b='urn:b'>\
+                        <b:item b:kind='x' plain='y'/><inner xmlns=''><leaf/></inner></root>";
+        let names: Vec<Name<'_>> = XmlReader::new(document)
+            .filter_map(|event| match event.expect("the document is well-formed") {
+                Event::Start { name, attributes } => Some(
+                    core::iter::once(name)
+                        .chain(attributes.into_iter().map(|attribute| attribute.name))
+                        .collect::<Vec<_>>(),
+                ),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+
+        assert_eq!(
+            names,
+            [
+                name(None, "root", Some("urn:a")),
+                name(None, "xmlns", Some(XMLNS_NAMESPACE)),
+                name(Some("xmlns"), "b", Some(XMLNS_NAMESPACE)),
+                name(Some("b"), "item", Some("urn:b")),
+                name(Some("b"), "kind", Some("urn:b")),
+                name(None, "plain", None),
+                name(None, "inner", None),
+                name(None, "xmlns", Some(XMLNS_NAMESPACE)),
+                name(None, "leaf", None),
+            ]
+        );
+        assert!(names[3].is(Some("urn:b"), "item"));
+
+        let mut reader = XmlReader::new("<a xmlns:p='urn:p'><p:b/></a><p:c/>");
+        let error = reader.find_map(Result::err);
+        assert_eq!(error, Some(XmlError::UnboundPrefix { position: 30 }));
+    }
+
+    #[test]
+    fn test_errors() {
+        let first_error = |document| XmlReader::new(document).find_map(Result::err);
+
+        assert_eq!(
+            first_error("<a></b>"),
+            Some(XmlError::MismatchedTag { position: 3 })
+        );
+        assert_eq!(first_error("<a><b>"), Some(XmlError::UnexpectedEnd));
+        assert_eq!(first_error("<a"), Some(XmlError::UnexpectedEnd));
+        assert_eq!(
+            first_error("<a>&nbsp;</a>"),
+            Some(XmlError::InvalidReference { position: 3 })
+        );
+        assert_eq!(
+            first_error("<a x='1' x='2'/>"),
+            Some(XmlError::DuplicateAttribute { position: 9 })
+        );
+        assert_eq!(
+            first_error("<a x='1'y='2'/>"),
+            Some(XmlError::Syntax { position: 8 })
+        );
+        assert_eq!(first_error("<1/>"), Some(XmlError::Syntax { position: 1 }));
+
+        let mut reader = XmlReader::new("</a>");
+        assert_eq!(
+            reader.next_event(),
+            Err(XmlError::MismatchedTag { position: 0 })
+        );
+        assert_eq!(
+            reader.next_event(),
+            Err(XmlError::MismatchedTag { position: 0 })
+        );
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn test_writer_round_trip() {
+        let mut writer = XmlWriter::new(String::new());
+        writer.declaration().expect("writing to a string succeeds");
+        writer
+            .start_element("doc", &[("title", "\"A\" <&>\n")])
+            .expect("writing to a string succeeds");
+        writer
+            .comment("a--b-")
+            .expect("writing to a string succeeds");
+        writer
+            .start_element("empty", &[])
+            .expect("writing to a string succeeds");
+        writer.end_element().expect("an element is open");
+        writer.cdata("x]]>y").expect("writing to a string succeeds");
+        writer
+            .processing_instruction("pi", "data")
+            .expect("the data has no ?>");
+        writer.text("1 < 2").expect("writing to a string succeeds");
+        let document = writer.finish().expect("writing to a string succeeds");
+
+        assert_eq!(
+            document,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <doc title=\"&quot;A&quot; &lt;&amp;&gt;&#10;\"><!--a- -b- --><empty/>\
+             <![CDATA[x]]]]><![CDATA[>y]]><?pi data?>1 &lt; 2</doc>"
+        );
+
+        let mut reader = XmlReader::new(&document).skip(2);
+        assert_eq!(
+            reader.next(),
+            Some(Ok(Event::Start {
+                name: name(None, "doc", None),
+                attributes: alloc::vec![Attribute {
+                    name: name(None, "title", None),
+                    value: Cow::Borrowed("\"A\" <&>\n")
+                }]
+            }))
+        );
+        assert!(reader.all(|event| event.is_ok()));
+
+        let mut writer = XmlWriter::new(String::new());
+        assert_eq!(writer.end_element(), Err(fmt::Error));
+    }
+}